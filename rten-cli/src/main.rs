@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 use std::error::Error;
 use std::time::Instant;
 
+use rten::ops::DataType;
 use rten::{Dimension, Input, Model, ModelMetadata, NodeId, Output, RunOptions};
 use rten_tensor::prelude::*;
 use rten_tensor::Tensor;
@@ -16,6 +17,12 @@ struct Args {
     /// Enable verbose logging for model execution.
     verbose: bool,
 
+    /// Print the execution plan before running the model.
+    dump_plan: bool,
+
+    /// Check operator outputs for NaN or infinite values.
+    check_finite: bool,
+
     /// Sizes for dynamic dimensions of inputs.
     input_sizes: Vec<DimSize>,
 }
@@ -94,17 +101,25 @@ fn parse_args() -> Result<Args, lexopt::Error> {
     let mut values = VecDeque::new();
     let mut timing = false;
     let mut verbose = false;
+    let mut dump_plan = false;
+    let mut check_finite = false;
     let mut input_sizes = Vec::new();
 
     let mut parser = lexopt::Parser::from_env();
     while let Some(arg) = parser.next()? {
         match arg {
             Value(val) => values.push_back(val.string()?),
+            Long("check-finite") => check_finite = true,
+            Short('p') | Long("plan") => dump_plan = true,
             Short('v') | Long("verbose") => verbose = true,
             Short('V') | Long("version") => {
                 println!("rten {}", env!("CARGO_PKG_VERSION"));
                 std::process::exit(0);
             }
+            Long("ops") => {
+                print_supported_ops();
+                std::process::exit(0);
+            }
             Short('t') | Long("timing") => timing = true,
             Short('s') | Long("shape") => {
                 let value = parser.value()?.string()?;
@@ -123,7 +138,10 @@ Args:
     Path to '.rten' model to inspect and run.
 
 Options:
+  --check-finite Stop if an operator produces NaN or infinite values
   -h, --help     Print help
+  --ops          List supported operators, their attributes and limitations
+  -p, --plan     Print the execution plan before running the model
   -t, --timing   Output timing info
 
   -s, --size <spec>
@@ -147,10 +165,26 @@ Options:
         model,
         timing,
         verbose,
+        dump_plan,
+        check_finite,
         input_sizes,
     })
 }
 
+/// Print the operators supported by this build of RTen.
+fn print_supported_ops() {
+    for op in rten::ops::registry() {
+        let dtypes: Vec<_> = op.dtypes.iter().map(|dt| format!("{:?}", dt)).collect();
+        println!("{} ({})", op.name, dtypes.join(", "));
+        if !op.attrs.is_empty() {
+            println!("  attrs: {}", op.attrs.join(", "));
+        }
+        for limitation in op.limitations {
+            println!("  limitation: {}", limitation);
+        }
+    }
+}
+
 fn format_param_count(n: usize) -> String {
     if n > 1_000_000 {
         format!("{:.1} M", n as f32 / 1_000_000.)
@@ -175,6 +209,29 @@ fn print_metadata(metadata: &ModelMetadata) {
     print_field("Model repository", metadata.model_repository());
     print_field("Run ID", metadata.run_id());
     print_field("Run URL", metadata.run_url());
+    print_field("Producer", metadata.producer_name());
+    print_field("Producer version", metadata.producer_version());
+    print_field("Doc string", metadata.doc_string());
+
+    let opsets: Vec<String> = metadata
+        .opset_imports()
+        .iter()
+        .map(|opset| {
+            let domain = if opset.domain.is_empty() {
+                "ai.onnx"
+            } else {
+                &opset.domain
+            };
+            format!("{} v{}", domain, opset.version)
+        })
+        .collect();
+    if !opsets.is_empty() {
+        print_field("Opsets", Some(opsets.join(", ")));
+    }
+
+    for (key, value) in metadata.custom_fields() {
+        print_field(key, Some(value));
+    }
 }
 
 /// Generate random inputs for `model` using shape metadata and heuristics,
@@ -222,8 +279,14 @@ fn run_with_random_input(
                 })
                 .collect();
 
-            // Guess suitable content for the input based on its name.
+            // Guess suitable content for the input based on its type, if
+            // known, and name.
+            let dtype = info.dtype();
             let tensor = match name {
+                _ if dtype == Some(DataType::Float) => {
+                    Output::from(Tensor::from_simple_fn(&resolved_shape, || rng.f32()))
+                }
+
                 // If this is a mask, use all ones on the assumption that we
                 // don't want to mask anything out.
                 name if name.ends_with("_mask") => {
@@ -242,11 +305,19 @@ fn run_with_random_input(
                     }))
                 }
 
+                // For other integer inputs, use zeros.
+                _ if dtype == Some(DataType::Int64) => {
+                    Output::from(Tensor::<i64>::zeros(&resolved_shape))
+                }
+                _ if matches!(
+                    dtype,
+                    Some(DataType::Int32 | DataType::Int8 | DataType::UInt8 | DataType::Bool)
+                ) =>
+                {
+                    Output::from(Tensor::<i32>::zeros(&resolved_shape))
+                }
+
                 // For anything else, random floats in [0, 1].
-                //
-                // TODO - Value nodes in the model should include data types,
-                // so we can at least be sure to generate values of the correct
-                // type.
                 _ => Output::from(Tensor::from_simple_fn(&resolved_shape, || rng.f32())),
             };
 
@@ -299,6 +370,9 @@ fn run_with_random_input(
         let dtype = match output {
             Output::FloatTensor(_) => "f32",
             Output::IntTensor(_) => "i32",
+            Output::Int64Tensor(_) => "i64",
+            Output::StringTensor(_) => "string",
+            Output::Sequence(_) => "sequence",
         };
         println!(
             "  Output {i} \"{name}\" data type {} shape: {:?}",
@@ -331,8 +405,11 @@ fn print_input_output_list(model: &Model, node_ids: &[NodeId]) {
             continue;
         };
         println!(
-            "  {}: {}",
+            "  {}: {}{}",
             info.name().unwrap_or("(unnamed)"),
+            info.dtype()
+                .map(|dtype| format!("{:?} ", dtype))
+                .unwrap_or_default(),
             info.shape()
                 .map(|dims| format_shape(&dims))
                 .unwrap_or("(unknown shape)".to_string())
@@ -380,6 +457,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         RunOptions {
             timing: args.timing,
             verbose: args.verbose,
+            dump_plan: args.dump_plan,
+            check_finite: args.check_finite,
             ..Default::default()
         },
     )?;
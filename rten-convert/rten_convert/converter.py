@@ -1,12 +1,13 @@
 #!/usr/bin/env python
 
 from argparse import ArgumentParser
-from dataclasses import dataclass
+from collections import ChainMap
+from dataclasses import dataclass, field
 import hashlib
 import json
 from os.path import splitext
 import sys
-from typing import Any, Callable, Literal, Optional, cast
+from typing import Any, Callable, Literal, Mapping, Optional, cast
 
 import flatbuffers
 import numpy as np
@@ -18,6 +19,9 @@ import rten_convert.schema_generated as sg
 
 AttributeValue = int | float | str | list[int]
 
+Compression = Literal["zstd", "lz4"]
+"""Algorithms which can be used to compress constant data."""
+
 
 EMITTED_WARNINGS: set[str] = set()
 
@@ -52,10 +56,22 @@ class ConstantNode(Node):
     shape: list[int]
     data: np.ndarray
 
-    def __init__(self, name: str, shape: list[int], data: np.ndarray):
+    onnx_dtype: Optional[int]
+    """
+    ONNX data type of the source tensor, before any widening to int32.
+    """
+
+    def __init__(
+        self,
+        name: str,
+        shape: list[int],
+        data: np.ndarray,
+        onnx_dtype: Optional[int] = None,
+    ):
         super().__init__(name)
         self.shape = shape
         self.data = data
+        self.onnx_dtype = onnx_dtype
 
         shape_numel = np.prod(shape)
         if shape_numel != data.size:
@@ -64,8 +80,9 @@ class ConstantNode(Node):
             )
 
         # Verify that this is a data type that we'll be able to serialize later.
+        # String tensors are stored as object arrays of `str`.
         match data.dtype:
-            case np.float32 | np.int32:
+            case np.float32 | np.int32 | np.object_:
                 pass
             case _:
                 dtype_name: str = data.dtype.name  # type:ignore[union-attr]
@@ -122,12 +139,25 @@ class ValueNode(Node):
 
     The shape can be missing, or a mix of fixed and symbolic (unknown at model
     export time) sizes.
+
+    Optional values, such as model inputs with an ONNX optional type, may be
+    absent at runtime.
+
+    The data type is an `sg.DataType` value, or `None` if unknown.
     """
 
-    def __init__(self, name: str, shape: list[int | str] | None):
+    def __init__(
+        self,
+        name: str,
+        shape: list[int | str] | None,
+        optional: bool = False,
+        dtype: int | None = None,
+    ):
         super().__init__(name)
 
         self.shape = shape
+        self.optional = optional
+        self.dtype = dtype
 
 
 class Graph:
@@ -144,6 +174,15 @@ class Graph:
         self.inputs = inputs
         self.outputs = outputs
 
+    def Pack(self, builder: flatbuffers.Builder) -> int:
+        """
+        Serialize this graph.
+
+        This allows a graph to be used as the value of a graph-valued field in
+        an operator attributes object, such as `sg.ScanAttrsT.body`.
+        """
+        return build_graph(builder, self)
+
 
 @dataclass
 class Metadata:
@@ -160,12 +199,21 @@ class Metadata:
     code_repository: Optional[str] = None
     commit: Optional[str] = None
     description: Optional[str] = None
+    doc_string: Optional[str] = None
     license: Optional[str] = None
     model_repository: Optional[str] = None
     onnx_hash: Optional[str] = None
+    producer_name: Optional[str] = None
+    producer_version: Optional[str] = None
     run_id: Optional[str] = None
     run_url: Optional[str] = None
 
+    opset_imports: list[tuple[str, int]] = field(default_factory=list)
+    """(domain, version) pairs of operator sets imported by the ONNX model."""
+
+    metadata_props: dict[str, str] = field(default_factory=dict)
+    """Custom key/value metadata from the ONNX model."""
+
 
 # Mapping of ONNX attribute types to the field on an AttributeProto which
 # contains the value. Note that if you try to access the wrong field on an
@@ -176,6 +224,7 @@ value_fields = {
     onnx.AttributeProto.INTS: "ints",
     onnx.AttributeProto.STRING: "s",
     onnx.AttributeProto.TENSOR: "t",
+    onnx.AttributeProto.GRAPH: "g",
 }
 
 
@@ -331,7 +380,10 @@ class ONNXOperatorReader:
         if attr_val is None:
             return
 
-        if input_index < len(self.input_indexes):
+        if (
+            input_index < len(self.input_indexes)
+            and self.input_indexes[input_index] is not None
+        ):
             raise Exception(
                 f'Operator has both an attribute "{attr_name}" and corresponding input at index {input_index}'
             )
@@ -427,7 +479,7 @@ def constant_node_from_onnx_initializer(
             pass
 
         # Int types that can be widened to int32
-        case "bool" | "int8" | "int16":
+        case "bool" | "int8" | "int16" | "uint8":
             data = data.astype(np.int32)
 
         # Types that need to be narrowed
@@ -450,12 +502,20 @@ def constant_node_from_onnx_initializer(
                 )
             data = data.clip(i32.min, i32.max).astype(np.int32)
 
+        # String tensors, which ONNX represents as arrays of `bytes`.
+        case "object":
+            data = np.array(
+                [s.decode("utf-8") for s in data.flat], dtype=object
+            ).reshape(data.shape)
+
         case _:
             raise ValueError(
                 f"Unsupported tensor data type {data.dtype.name} for operator {op_name}"
             )
 
-    return ConstantNode(name=tensor.name, shape=dims, data=data)
+    return ConstantNode(
+        name=tensor.name, shape=dims, data=data, onnx_dtype=tensor.data_type
+    )
 
 
 def constant_node_from_onnx_constant_op(onnx_op: onnx.OperatorProto) -> ConstantNode:
@@ -477,16 +537,47 @@ def constant_node_from_onnx_constant_op(onnx_op: onnx.OperatorProto) -> Constant
 
 
 def value_node_from_onnx_value(value: onnx.ValueInfoProto) -> ValueNode:
-    if value.type.tensor_type.shape.dim:
-        dims = [d.dim_param or d.dim_value for d in value.type.tensor_type.shape.dim]
+    def dim_from_onnx(index: int, dim: onnx.TensorShapeProto.Dimension) -> str | int:
+        if dim.HasField("dim_param"):
+            return dim.dim_param
+        elif dim.HasField("dim_value"):
+            return dim.dim_value
+        else:
+            # Dimensions with neither a fixed size nor a name are unknown.
+            # Give them a unique name rather than treating them as size 0.
+            return f"{value.name}_dim{index}"
+
+    optional = value.type.HasField("optional_type")
+    if optional:
+        tensor_type = value.type.optional_type.elem_type.tensor_type
+    else:
+        tensor_type = value.type.tensor_type
+
+    if tensor_type.shape.dim:
+        dims = [dim_from_onnx(i, d) for i, d in enumerate(tensor_type.shape.dim)]
     else:
         dims = None
-    return ValueNode(name=value.name, shape=dims)
+
+    # The data type is informational, so values with unknown or unsupported
+    # types are converted without one.
+    dtype = None
+    if tensor_type.elem_type != TensorProto.DataType.UNDEFINED:  # type:ignore[attr-defined]
+        try:
+            dtype = convert_data_type(tensor_type.elem_type)
+        except Exception:
+            pass
+
+    return ValueNode(name=value.name, shape=dims, optional=optional, dtype=dtype)
 
 
-def read_pads(op_reader: ONNXOperatorReader) -> tuple[str, list[int]]:
+def read_pads(
+    op_reader: ONNXOperatorReader, spatial_dims: int = 2
+) -> tuple[str, list[int]]:
     """
     Read a padding specification from an ONNX operator.
+
+    :param spatial_dims: Number of spatial dimensions, used to determine the
+      default padding if the attribute is not set.
     """
 
     auto_pad = op_reader.get_attr("auto_pad", "string", "NOTSET")
@@ -497,43 +588,158 @@ def read_pads(op_reader: ONNXOperatorReader) -> tuple[str, list[int]]:
             pads = []
         case "NOTSET":
             pad_mode = "fixed"
-            pads = op_reader.get_attr("pads", "ints", [0, 0, 0, 0])
-            if len(pads) not in [2, 4]:
-                raise Exception('"padding" attribute must have 2 or 4 values')
+            pads = op_reader.get_attr("pads", "ints", [0] * spatial_dims * 2)
+            if len(pads) not in [2, 4, 6]:
+                raise Exception('"padding" attribute must have 2, 4 or 6 values')
         case other:
             raise Exception(f"Unsupported auto_pad value {other}")
 
     return (pad_mode, pads)
 
 
-def read_strides(
-    op_reader: ONNXOperatorReader,
-):
+def read_strides(op_reader: ONNXOperatorReader, spatial_dims: int = 2):
     """
     Read a stride specification from an ONNX operator.
     """
-    strides = op_reader.get_attr("strides", "ints", [1, 1])
-    if len(strides) not in [1, 2]:
-        raise Exception('"strides" attribute must have 1 or 2 values')
+    strides = op_reader.get_attr("strides", "ints", [1] * spatial_dims)
+    if len(strides) not in [1, 2, 3]:
+        raise Exception('"strides" attribute must have 1, 2 or 3 values')
     return strides
 
 
-def read_dilations(
-    op_reader: ONNXOperatorReader,
-):
+def read_dilations(op_reader: ONNXOperatorReader, spatial_dims: int = 2):
     """
     Read a dilation specification from an ONNX operator.
     """
-    dilations = op_reader.get_attr("dilations", "ints", [1, 1])
-    if len(dilations) not in [1, 2]:
-        raise Exception('"dilations" attribute must have 1 or 2 values')
+    dilations = op_reader.get_attr("dilations", "ints", [1] * spatial_dims)
+    if len(dilations) not in [1, 2, 3]:
+        raise Exception('"dilations" attribute must have 1, 2 or 3 values')
     return dilations
 
 
+def convert_data_type(onnx_dtype: int) -> int:
+    """
+    Map an ONNX `TensorProto.DataType` value to an RTen `DataType`.
+    """
+    match onnx_dtype:
+        case TensorProto.DataType.FLOAT:  # type:ignore[attr-defined]
+            return sg.DataType.Float
+        case (
+            TensorProto.DataType.INT32  # type:ignore[attr-defined]
+            | TensorProto.DataType.INT64  # type:ignore[attr-defined]
+        ):
+            return sg.DataType.Int32
+        case TensorProto.DataType.BOOL:  # type:ignore[attr-defined]
+            return sg.DataType.Bool
+        case TensorProto.DataType.INT8:  # type:ignore[attr-defined]
+            return sg.DataType.Int8
+        case TensorProto.DataType.UINT8:  # type:ignore[attr-defined]
+            return sg.DataType.UInt8
+        case TensorProto.DataType.STRING:  # type:ignore[attr-defined]
+            return sg.DataType.String
+        case _:
+            raise Exception(f"Unsupported data type {onnx_dtype}")
+
+
+def quantized_output_dtype(
+    constant_nodes: Mapping[str, ConstantNode], zero_point_name: str
+) -> int:
+    """
+    Determine the output type of a quantized operator from its output zero point.
+
+    ONNX infers the output type from the type of the zero point. If the zero
+    point is not a constant, the ONNX default of uint8 is assumed.
+    """
+    zero_point = constant_nodes.get(zero_point_name)
+    if zero_point is None or zero_point.onnx_dtype is None:
+        return sg.DataType.UInt8
+    dtype = convert_data_type(zero_point.onnx_dtype)
+    if dtype not in (sg.DataType.Int8, sg.DataType.UInt8):
+        raise Exception("Quantized output zero point must be int8 or uint8")
+    return dtype
+
+
+def unpack_4bit(data: np.ndarray) -> np.ndarray:
+    """
+    Unpack pairs of 4-bit values stored in bytes along the last axis.
+
+    The low nibble of each byte is the first value of the pair.
+    """
+    low = data & 0xF
+    high = (data >> 4) & 0xF
+    return np.stack([low, high], axis=-1).reshape(*data.shape[:-1], -1)
+
+
+def pack_quantized_rows(data: np.ndarray, bits: int) -> np.ndarray:
+    """
+    Pack unsigned `bits`-bit values along the last axis of `data` into int32
+    words, with the first value in the least significant bits of each word.
+    """
+    vals_per_word = 32 // bits
+    if data.shape[-1] % vals_per_word != 0:
+        raise Exception(
+            f"Number of quantized values per row must be a multiple of {vals_per_word}"
+        )
+    words = data.astype(np.uint32).reshape(*data.shape[:-1], -1, vals_per_word)
+    shifts = np.arange(vals_per_word, dtype=np.uint32) * bits
+    packed = np.bitwise_or.reduce(words << shifts, axis=-1)
+    return packed.astype(np.uint32).view(np.int32)
+
+
+def read_custom_operator_attrs(
+    op_reader: ONNXOperatorReader,
+) -> sg.CustomOperatorAttrsT:
+    """
+    Convert the attributes of an operator which is not built into RTen.
+
+    Only attributes with int, float, string, ints and floats types are
+    supported.
+    """
+    onnx_op = op_reader.onnx_op
+    attr_types = {
+        onnx.AttributeProto.INT: ("int", sg.CustomAttrType.Int),
+        onnx.AttributeProto.FLOAT: ("float", sg.CustomAttrType.Float),
+        onnx.AttributeProto.STRING: ("string", sg.CustomAttrType.String),
+        onnx.AttributeProto.INTS: ("ints", sg.CustomAttrType.Ints),
+        onnx.AttributeProto.FLOATS: ("floats", sg.CustomAttrType.Floats),
+    }
+
+    attrs = sg.CustomOperatorAttrsT()
+    attrs.domain = onnx_op.domain
+    attrs.opType = onnx_op.op_type
+    attrs.attrs = []
+
+    for onnx_attr in onnx_op.attribute:
+        if onnx_attr.type not in attr_types:
+            raise Exception(
+                f"Unsupported type for attribute {onnx_attr.name} of custom operator {onnx_op.op_type}"
+            )
+        expected_type, value_type = attr_types[onnx_attr.type]
+        val = op_reader.get_attr(onnx_attr.name, expected_type, None)
+
+        attr = sg.CustomAttrT()
+        attr.name = onnx_attr.name
+        attr.valueType = value_type
+        match value_type:
+            case sg.CustomAttrType.Int:
+                attr.intValue = val
+            case sg.CustomAttrType.Float:
+                attr.floatValue = val
+            case sg.CustomAttrType.String:
+                attr.stringValue = val
+            case sg.CustomAttrType.Ints:
+                attr.ints = list(val)
+            case sg.CustomAttrType.Floats:
+                attr.floats = list(val)
+        attrs.attrs.append(attr)
+
+    return attrs
+
+
 def op_node_from_onnx_operator(
     onnx_op: onnx.OperatorProto,
     node_index_from_name: dict[str, int],
-    constant_nodes: dict[str, ConstantNode],
+    constant_nodes: Mapping[str, ConstantNode],
     add_node: Callable[[Node], int],
 ) -> OperatorNode:
     """
@@ -545,7 +751,8 @@ def op_node_from_onnx_operator(
     :param onnx_op: ONNX operator to convert
     :param node_index_from_name: Mapping of constant and value tensor node names
       in the graph to corresponding input names
-    :param constant_nodes: Map of constant value tensor node names
+    :param constant_nodes: Map of constant value tensor node names. This
+      includes constants from enclosing graphs, if the operator is in a subgraph.
     :param add_node: Function that adds a new node to the graph and returns its
       node ID. This is called if an operator attribute needs to be converted
       to a constant input.
@@ -597,7 +804,6 @@ def op_node_from_onnx_operator(
             kernel_shape = op_reader.require_attr("kernel_shape", "ints")
             check_ints_length("kernel_shape", kernel_shape, 2)
             pad_mode, pads = read_pads(op_reader)
-            op_reader.check_attr("ceil_mode", "int", 0)
 
             attrs = sg.AveragePoolAttrsT()
             attrs.kernelSize = kernel_shape
@@ -609,6 +815,8 @@ def op_node_from_onnx_operator(
                 attrs.padMode = sg.PadMode.Fixed
             attrs.strides = read_strides(op_reader)
             attrs.countIncludePad = op_reader.get_bool_attr("count_include_pad", False)
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
+            attrs.dilations = read_dilations(op_reader)
 
         case "BatchNormalization":
             attrs = sg.BatchNormalizationAttrsT()
@@ -619,29 +827,39 @@ def op_node_from_onnx_operator(
             # is unsupported.
             op_reader.ignore_attr("momentum")
 
+        case "BitShift":
+            attrs = sg.BitShiftAttrsT()
+            direction = op_reader.require_attr("direction", "string")
+            match direction:
+                case "LEFT":
+                    attrs.direction = sg.BitShiftDirection.Left
+                case "RIGHT":
+                    attrs.direction = sg.BitShiftDirection.Right
+                case _:
+                    raise ValueError(f'Unsupported BitShift direction "{direction}"')
+
         case "Cast":
             attrs = sg.CastAttrsT()
             to = op_reader.get_attr("to", "int", TensorProto.DataType.FLOAT)  # type:ignore[attr-defined]
-            match to:
-                case TensorProto.DataType.FLOAT:  # type:ignore[attr-defined]
-                    attrs.to = sg.DataType.Float
-                case (
-                    TensorProto.DataType.BOOL  # type:ignore[attr-defined]
-                    | TensorProto.DataType.INT32  # type:ignore[attr-defined]
-                    | TensorProto.DataType.INT64  # type:ignore[attr-defined]
-                ):
-                    attrs.to = sg.DataType.Int32
-                case _:
-                    raise Exception(f"Unsupported target type for cast {to}")
+            attrs.to = convert_data_type(to)
 
         case "Clip":
             op_reader.generate_input_from_attr(1, "min", "float")
             op_reader.generate_input_from_attr(2, "max", "float")
 
+        case "ChannelShuffle":
+            attrs = sg.ChannelShuffleAttrsT()
+            attrs.groups = op_reader.require_attr("groups", "int")
+
         case "Concat":
             attrs = sg.ConcatAttrsT()
             attrs.axis = op_reader.require_attr("axis", "int")
 
+        case "ConcatFromSequence":
+            attrs = sg.ConcatFromSequenceAttrsT()
+            attrs.axis = op_reader.require_attr("axis", "int")
+            attrs.newAxis = op_reader.get_bool_attr("new_axis", False)
+
         case "ConstantOfShape":
             tensor = op_reader.require_attr("value", "tensor")
             const_node = constant_node_from_onnx_initializer(tensor, onnx_op.name)
@@ -671,33 +889,48 @@ def op_node_from_onnx_operator(
 
         case "Conv":
             attrs = sg.ConvAttrsT()
-            attrs.dilations = read_dilations(op_reader)
+
+            # The kernel shape is inferred at runtime from the input weight
+            # tensor, but the number of spatial dims is needed to determine
+            # default values for other attributes.
+            kernel_shape = op_reader.get_attr("kernel_shape", "ints", [0, 0])
+            spatial_dims = len(kernel_shape)
+
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
             attrs.groups = op_reader.get_attr("group", "int", 1)
 
-            pad_mode, pads = read_pads(op_reader)
+            pad_mode, pads = read_pads(op_reader, spatial_dims)
             if pad_mode == "same":
                 attrs.padMode = sg.PadMode.Same
             else:
                 attrs.padMode = sg.PadMode.Fixed
                 attrs.pads = pads
-            attrs.strides = read_strides(op_reader)
-
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            attrs.strides = read_strides(op_reader, spatial_dims)
 
         case "ConvTranspose":
             attrs = sg.ConvTransposeAttrsT()
-            attrs.strides = read_strides(op_reader)
 
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
-            op_reader.check_attr("group", "int", 1)
+            # The kernel shape is inferred at runtime from the input weight
+            # tensor, but the number of spatial dims is needed to determine
+            # default values for other attributes.
+            kernel_shape = op_reader.get_attr("kernel_shape", "ints", [0, 0])
+            spatial_dims = len(kernel_shape)
 
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            attrs.strides = read_strides(op_reader, spatial_dims)
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
+            attrs.groups = op_reader.get_attr("group", "int", 1)
 
-            op_reader.check_attr("output_padding", "ints", [0, 0, 0, 0])
+            output_padding = op_reader.get_attr("output_padding", "ints", [])
+            if any(output_padding):
+                attrs.outputPadding = output_padding
 
-            pad_mode, pads = read_pads(op_reader)
+            # `output_shape` may include the batch and channel dims. Only the
+            # spatial dims are used.
+            output_shape = op_reader.get_attr("output_shape", "ints", [])
+            if output_shape:
+                attrs.outputShape = output_shape[-spatial_dims:]
+
+            pad_mode, pads = read_pads(op_reader, spatial_dims)
             if pad_mode == "same":
                 attrs.padMode = sg.PadMode.Same
             else:
@@ -708,10 +941,21 @@ def op_node_from_onnx_operator(
             op_reader.check_attr("exclusive", "int", 0)
             op_reader.check_attr("reverse", "int", 0)
 
-        case "Elu":
+        case "Dropout":
+            attrs = sg.DropoutAttrsT()
+            attrs.seed = op_reader.get_attr("seed", "int", None)
+
+        case "Celu" | "Elu" | "ThresholdedRelu":
             attrs = sg.EluAttrsT()
             attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
 
+        case "EyeLike":
+            attrs = sg.EyeLikeAttrsT()
+            dtype = op_reader.get_attr("dtype", "int", None)
+            if dtype is not None:
+                attrs.dtype = convert_data_type(dtype)
+            attrs.k = op_reader.get_attr("k", "int", 0)
+
         case "Flatten":
             attrs = sg.FlattenAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 1)
@@ -724,6 +968,44 @@ def op_node_from_onnx_operator(
             attrs = sg.GatherNDAttrsT()
             attrs.batchDims = op_reader.get_attr("batch_dims", "int", 0)
 
+        case "GatherBlockQuantized":
+            attrs = sg.GatherBlockQuantizedAttrsT()
+            attrs.bits = op_reader.get_attr("bits", "int", 4)
+            attrs.blockSize = op_reader.get_attr("block_size", "int", 128)
+            op_reader.check_attr("gather_axis", "int", 0)
+            op_reader.check_attr("quantize_axis", "int", (1, -1))
+
+            if attrs.bits not in (4, 8):
+                raise Exception(f"Unsupported number of bits {attrs.bits}")
+
+            # The quantized table is repacked into int32 words, with one value
+            # per 4 or 8 bits. This modifies the constant in place, so it is
+            # assumed not to be used by other operators.
+            data = constant_nodes.get(onnx_op.input[0])
+            if data is None or len(data.shape) != 2:
+                raise Exception("`data` input must be a 2D constant")
+            values = data.data
+            if attrs.bits == 4:
+                values = unpack_4bit(values)
+            data.data = pack_quantized_rows(values, attrs.bits)
+            data.shape = list(data.data.shape)
+
+            if len(onnx_op.input) > 3 and onnx_op.input[3]:
+                zero_points = constant_nodes.get(onnx_op.input[3])
+                if zero_points is None:
+                    raise Exception("`zero_points` input must be a constant")
+                if attrs.bits == 4:
+                    n_blocks = constant_nodes[onnx_op.input[2]].shape[-1]
+                    zero_points.data = unpack_4bit(zero_points.data)[..., :n_blocks]
+                    zero_points.shape = list(zero_points.data.shape)
+
+        case "Gelu":
+            attrs = sg.GeluAttrsT()
+            approximate = op_reader.get_attr("approximate", "string", "none")
+            if approximate not in ["none", "tanh"]:
+                raise ValueError(f'Unsupported value "{approximate}" for "approximate" attr')
+            attrs.approximate = approximate == "tanh"
+
         case "Gemm":
             attrs = sg.GemmAttrsT()
             attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
@@ -741,11 +1023,32 @@ def op_node_from_onnx_operator(
                 op_reader.get_attr("linear_before_reset", "int", 0)
             )
 
+        case "GridSample":
+            attrs = sg.GridSampleAttrsT()
+            mode = op_reader.get_attr("mode", "string", "linear")
+            match mode:
+                case "linear" | "bilinear":
+                    attrs.mode = sg.ResizeMode.Linear
+                case "nearest":
+                    attrs.mode = sg.ResizeMode.Nearest
+                case _:
+                    raise ValueError(f'Unsupported GridSample mode "{mode}"')
+            attrs.paddingMode = op_reader.get_enum_attr(
+                "padding_mode", sg.GridSamplePadding, "zeros"
+            )
+            attrs.alignCorners = bool(op_reader.get_attr("align_corners", "int", 0))
+
         case "HardSigmoid":
             attrs = sg.HardSigmoidAttrsT()
             attrs.alpha = op_reader.get_attr("alpha", "float", 0.2)
             attrs.beta = op_reader.get_attr("beta", "float", 0.5)
 
+        case "ImageToTensor":
+            attrs = sg.ImageToTensorAttrsT()
+            attrs.scale = op_reader.get_attr("scale", "float", 1.0)
+            attrs.mean = list(op_reader.get_attr("mean", "floats", []))
+            attrs.std = list(op_reader.get_attr("std", "floats", []))
+
         case "InstanceNormalization":
             attrs = sg.BatchNormalizationAttrsT()
             attrs.epsilon = op_reader.get_attr("epsilon", "float", 1e-5)
@@ -763,6 +1066,28 @@ def op_node_from_onnx_operator(
             attrs = sg.SoftmaxAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
 
+        case "LpNormalization":
+            attrs = sg.LpNormalizationAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", -1)
+            attrs.p = op_reader.get_attr("p", "int", 2)
+
+        case "LpPool":
+            attrs = sg.LpPoolAttrsT()
+            kernel_shape = op_reader.require_attr("kernel_shape", "ints")
+            check_ints_length("kernel_shape", kernel_shape, 2)
+            attrs.kernelSize = kernel_shape
+
+            pad_mode, pads = read_pads(op_reader)
+            if pad_mode == "same":
+                attrs.padMode = sg.PadMode.Same
+            else:
+                attrs.padMode = sg.PadMode.Fixed
+                attrs.pads = pads
+            attrs.strides = read_strides(op_reader)
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
+            attrs.dilations = read_dilations(op_reader)
+            attrs.p = op_reader.get_attr("p", "int", 2)
+
         case "LSTM":
             attrs = sg.LSTMAttrsT()
             attrs.direction = op_reader.get_enum_attr(
@@ -777,6 +1102,46 @@ def op_node_from_onnx_operator(
             op_reader.check_attr("input_forget", "int", 0)
             op_reader.check_attr("layout", "int", 0)
 
+        case "MatMulNBits":
+            attrs = sg.MatMulNBitsAttrsT()
+            attrs.bits = op_reader.get_attr("bits", "int", 4)
+            attrs.blockSize = op_reader.require_attr("block_size", "int")
+            n = op_reader.require_attr("N", "int")
+
+            if attrs.bits not in (4, 8):
+                raise Exception(f"Unsupported number of bits {attrs.bits}")
+            if len(onnx_op.input) > 4 and any(onnx_op.input[4:]):
+                raise Exception("`g_idx` and `bias` inputs are not supported")
+
+            # The quantized weights have shape `[N, n_blocks, blob_size]` and
+            # are repacked into `[N, words]` int32 words. Like
+            # `GatherBlockQuantized`, this modifies constants in place.
+            weights = constant_nodes.get(onnx_op.input[1])
+            scales = constant_nodes.get(onnx_op.input[2])
+            if weights is None or scales is None:
+                raise Exception("`B` and `scales` inputs must be constants")
+            values = weights.data.reshape(n, -1)
+            if attrs.bits == 4:
+                values = unpack_4bit(values)
+            weights.data = pack_quantized_rows(values, attrs.bits)
+            weights.shape = list(weights.data.shape)
+
+            scales.data = scales.data.reshape(n, -1)
+            scales.shape = list(scales.data.shape)
+            n_blocks = scales.shape[1]
+
+            if len(onnx_op.input) > 3 and onnx_op.input[3]:
+                zero_points = constant_nodes.get(onnx_op.input[3])
+                if zero_points is None:
+                    raise Exception("`zero_points` input must be a constant")
+                if zero_points.data.dtype == np.float32:
+                    raise Exception("Float `zero_points` are not supported")
+                zp_data = zero_points.data.reshape(n, -1)
+                if attrs.bits == 4:
+                    zp_data = unpack_4bit(zp_data)
+                zero_points.data = zp_data[:, :n_blocks]
+                zero_points.shape = list(zero_points.data.shape)
+
         case "MaxPool":
             attrs = sg.MaxPoolAttrsT()
             kernel_shape = op_reader.require_attr("kernel_shape", "ints")
@@ -791,14 +1156,31 @@ def op_node_from_onnx_operator(
                 attrs.pads = pads
             attrs.strides = read_strides(op_reader)
 
-            op_reader.check_attr("ceil_mode", "int", 0)
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
+            attrs.dilations = read_dilations(op_reader)
             op_reader.check_attr("storage_order", "int", 0)
 
+        case "MeanVarianceNormalization":
+            attrs = sg.MeanVarianceNormalizationAttrsT()
+            attrs.axes = op_reader.get_attr("axes", "ints", [0, 2, 3])
+
         case "Mod":
             attrs = sg.ModAttrsT()
             attrs.fmod = bool(op_reader.get_attr("fmod", "int", 0))
 
+        case "Multinomial":
+            attrs = sg.MultinomialAttrsT()
+            dtype = op_reader.get_attr("dtype", "int", TensorProto.DataType.INT32)  # type:ignore[attr-defined]
+            match dtype:
+                case TensorProto.DataType.INT32:  # type:ignore[attr-defined]
+                    attrs.dtype = sg.DataType.Int32
+                case TensorProto.DataType.INT64:  # type:ignore[attr-defined]
+                    attrs.dtype = sg.DataType.Int64
+                case _:
+                    raise ValueError(f"Unsupported Multinomial dtype {dtype}")
+            attrs.sampleSize = op_reader.get_attr("sample_size", "int", 1)
+            attrs.seed = op_reader.get_attr("seed", "float", None)
+
         case "NonMaxSuppression":
             attrs = sg.NonMaxSuppressionAttrsT()
             center_point_box = op_reader.get_attr("center_point_box", "int", 0)
@@ -807,10 +1189,20 @@ def op_node_from_onnx_operator(
                 1: sg.NMSBoxOrder.CenterWidthHeight,
             }[center_point_box]
 
+        case "Normalize":
+            attrs = sg.NormalizeAttrsT()
+            attrs.mean = list(op_reader.require_attr("mean", "floats"))
+            attrs.std = list(op_reader.require_attr("std", "floats"))
+
         case "OneHot":
             attrs = sg.OneHotAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", -1)
 
+        case "Optional":
+            # The element type is only needed to create empty optionals, which
+            # are replaced with absent inputs during conversion.
+            op_reader.ignore_attr("type")
+
         case "RandomNormal" | "RandomNormalLike":
             match op_type:
                 case "RandomNormal":
@@ -839,6 +1231,7 @@ def op_node_from_onnx_operator(
 
         case (
             "ReduceL2"
+            | "ReduceLogSumExp"
             | "ReduceMax"
             | "ReduceMean"
             | "ReduceMin"
@@ -852,6 +1245,12 @@ def op_node_from_onnx_operator(
 
             op_reader.check_attr("noop_with_empty_axes", "int", 0)
 
+        case "ReduceStd" | "ReduceVar":
+            attrs = sg.ReduceVarAttrsT()
+            attrs.axes = op_reader.get_attr("axes", "ints", None)
+            attrs.keepDims = bool(op_reader.get_attr("keepdims", "int", 1))
+            attrs.correction = op_reader.get_attr("correction", "int", 0)
+
         case "Reshape":
             attrs = sg.ReshapeAttrsT()
             attrs.allowZero = bool(op_reader.get_attr("allowzero", "int", 0))
@@ -862,10 +1261,8 @@ def op_node_from_onnx_operator(
                 "mode", sg.ResizeMode, "nearest", fallback="linear"
             )
 
-            op_reader.check_attr("antialias", "int", 0)
-
-            # We only support resizing HW dimensions of NCHW tensor
-            op_reader.check_attr("axes", "ints", [2, 3])
+            attrs.antialias = op_reader.get_bool_attr("antialias", False)
+            attrs.axes = op_reader.get_attr("axes", "ints", None)
 
             attrs.coordMode = op_reader.get_enum_attr(
                 "coordinate_transformation_mode", sg.CoordTransformMode, "half_pixel"
@@ -873,7 +1270,9 @@ def op_node_from_onnx_operator(
 
             op_reader.check_attr("cubic_coeff_a", "float", -0.75, on_mismatch="warn")
             op_reader.check_attr("exclude_outside", "int", 0)
-            op_reader.check_attr("extrapolation_value", "float", 0.0)
+            attrs.extrapolationValue = op_reader.get_attr(
+                "extrapolation_value", "float", 0.0
+            )
             op_reader.check_attr("keep_aspect_ratio_policy", "string", "stretch")
 
             attrs.nearestMode = op_reader.get_enum_attr(
@@ -881,7 +1280,51 @@ def op_node_from_onnx_operator(
             )
 
         case "Pad":
-            op_reader.check_attr("mode", "string", "constant")
+            attrs = sg.PadAttrsT()
+            attrs.mode = op_reader.get_enum_attr("mode", sg.PadOpMode, "constant")
+
+        case "PermuteChannels":
+            attrs = sg.PermuteChannelsAttrsT()
+            attrs.order = list(op_reader.require_attr("order", "ints"))
+
+        case "QLinearConv":
+            attrs = sg.QLinearConvAttrsT()
+
+            attrs.dilations = read_dilations(op_reader, 2)
+            attrs.groups = op_reader.get_attr("group", "int", 1)
+
+            pad_mode, pads = read_pads(op_reader, 2)
+            if pad_mode == "same":
+                attrs.padMode = sg.PadMode.Same
+            else:
+                attrs.padMode = sg.PadMode.Fixed
+                attrs.pads = pads
+            attrs.strides = read_strides(op_reader, 2)
+            attrs.outputDtype = quantized_output_dtype(
+                constant_nodes, onnx_op.input[7]
+            )
+
+        case "QLinearMatMul":
+            attrs = sg.QLinearMatMulAttrsT()
+            attrs.outputDtype = quantized_output_dtype(
+                constant_nodes, onnx_op.input[7]
+            )
+
+        case "Scan":
+            attrs = sg.ScanAttrsT()
+            body = op_reader.require_attr("body", "graph")
+            attrs.body = graph_from_onnx_graph(  # type:ignore[assignment]
+                body, outer_constants=constant_nodes
+            )
+            attrs.numScanInputs = op_reader.require_attr("num_scan_inputs", "int")
+            attrs.scanInputAxes = op_reader.get_attr("scan_input_axes", "ints", None)
+            attrs.scanInputDirections = op_reader.get_attr(
+                "scan_input_directions", "ints", None
+            )
+            attrs.scanOutputAxes = op_reader.get_attr("scan_output_axes", "ints", None)
+            attrs.scanOutputDirections = op_reader.get_attr(
+                "scan_output_directions", "ints", None
+            )
 
         case "ScatterElements":
             attrs = sg.ScatterElementsAttrsT()
@@ -896,6 +1339,11 @@ def op_node_from_onnx_operator(
                 "reduction", sg.ScatterReduction, "none"
             )
 
+        case "Selu":
+            attrs = sg.SeluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.67326319217681884765625)
+            attrs.gamma = op_reader.get_attr("gamma", "float", 1.05070102214813232421875)
+
         case "Shape":
             op_reader.check_attr("end", "int", 0)
             op_reader.check_attr("start", "int", 0)
@@ -907,9 +1355,21 @@ def op_node_from_onnx_operator(
         case "Split":
             attrs = sg.SplitAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
-            op_reader.check_attr("num_outputs", "int", 0)
+            attrs.num_outputs = op_reader.get_attr("num_outputs", "int", None)
             op_reader.generate_input_from_attr(1, "split", "ints")
 
+            # If split sizes are not specified, the input is split into
+            # equal-sized chunks, one per output.
+            input_indexes = op_reader.input_indexes
+            has_split_input = len(input_indexes) > 1 and input_indexes[1] is not None
+            if not has_split_input and attrs.num_outputs is None:
+                attrs.num_outputs = len(onnx_op.output)
+
+        case "SplitToSequence":
+            attrs = sg.SplitToSequenceAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", 0)
+            attrs.keepDims = op_reader.get_bool_attr("keepdims", True)
+
         case "Squeeze":
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
@@ -931,7 +1391,15 @@ def op_node_from_onnx_operator(
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
     if not hasattr(sg.OperatorType, op_type):
-        raise Exception(f"Unsupported operator {op_type}")
+        # Operators which are not built into RTen, such as contrib operators
+        # from vendor-specific domains, are converted to custom operators.
+        # An implementation must be registered when the model is loaded.
+        qualified_name = f"{onnx_op.domain}.{op_type}" if onnx_op.domain else op_type
+        warn_once(
+            f"WARNING: Converting unsupported operator {qualified_name} to a custom operator"
+        )
+        attrs = read_custom_operator_attrs(op_reader)
+        op_type = "Custom"
 
     # Display a warning for any attributes that were not handled above.
     for attr in op_reader.unhandled_attrs():
@@ -961,9 +1429,18 @@ def duplicate_node_names(nodes: list[onnx.ValueInfoProto]) -> list[str]:
     return dupes
 
 
-def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
+def graph_from_onnx_graph(
+    onnx_graph: onnx.GraphProto,
+    outer_constants: Mapping[str, ConstantNode] | None = None,
+) -> Graph:
     """
     Parse an ONNX model into a graph representation compatible with this library.
+
+    :param onnx_graph: The ONNX graph to convert
+    :param outer_constants: Constants from enclosing graphs, if this is a
+      subgraph such as the body of a control flow operator. Subgraphs in the
+      converted model cannot reference nodes from enclosing graphs, so any
+      constants which are used are copied into the subgraph.
     """
 
     nodes: list[Node] = []
@@ -1034,21 +1511,56 @@ def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
     for value_info in onnx_graph.output:
         add_value_node(value_info)
 
+    # Shapes of intermediate values, if the model has been annotated by ONNX
+    # shape inference.
+    intermediate_value_info = {info.name: info for info in onnx_graph.value_info}
+
+    # Constants which are visible to operators in this graph and its subgraphs.
+    visible_constants = ChainMap(constant_map, dict(outer_constants or {}))
+
+    # Outputs of `Optional` operators which produce an empty optional. These
+    # are represented as omitted inputs in operators which use them.
+    empty_optionals: set[str] = set()
+
     for operator in onnx_graph.node:
         if operator.op_type == "Constant":
             continue
 
+        if operator.op_type == "Optional" and not any(operator.input):
+            empty_optionals.update(operator.output)
+            continue
+
+        if any(name in empty_optionals for name in operator.input):
+            operator_copy = onnx.NodeProto()
+            operator_copy.CopyFrom(operator)
+            operator_copy.input[:] = [
+                "" if name in empty_optionals else name for name in operator.input
+            ]
+            operator = operator_copy
+
         for output_name in operator.output:
             # If this output is also a model output, it will have been
             # registered already.
             if output_name in value_name_to_index:
                 continue
-            value_node = ValueNode(output_name, shape=None)
+            if output_name in intermediate_value_info:
+                value_node = value_node_from_onnx_value(
+                    intermediate_value_info[output_name]
+                )
+            else:
+                value_node = ValueNode(output_name, shape=None)
             add_node(value_node)
 
+        if outer_constants:
+            for input_name in operator.input:
+                if input_name in value_name_to_index:
+                    continue
+                if input_name in outer_constants:
+                    add_node(outer_constants[input_name])
+
         try:
             op_node = op_node_from_onnx_operator(
-                operator, value_name_to_index, constant_map, add_node=add_node
+                operator, value_name_to_index, visible_constants, add_node=add_node
             )
             add_node(op_node)
         except Exception as ex:
@@ -1080,31 +1592,166 @@ def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
     return Graph(nodes=nodes, inputs=inputs, outputs=outputs)
 
 
-def build_constant_node(builder: flatbuffers.Builder, constant: ConstantNode):
+def compress_data(data: bytes, compression: Compression) -> bytes:
+    """
+    Compress constant data using the given algorithm.
+
+    The compression libraries are imported lazily, so they only need to be
+    installed if compression is used.
+    """
+    match compression:
+        case "zstd":
+            import zstandard
+
+            return zstandard.ZstdCompressor(level=19).compress(data)
+        case "lz4":
+            import lz4.block
+
+            # The uncompressed size is prepended to the output, as expected
+            # by the loader.
+            return lz4.block.compress(
+                data, mode="high_compression", store_size=True
+            )
+        case _:
+            raise ValueError(f"Unsupported compression {compression}")
+
+
+def build_compressed_data(
+    builder: flatbuffers.Builder, data: np.ndarray, compression: Compression
+) -> Optional[int]:
+    """
+    Serialize numeric constant data in compressed form.
+
+    Returns `None` if compression does not reduce the size of the data, in
+    which case the caller should store it uncompressed.
+    """
+    match data.dtype:
+        case np.float32:
+            dtype = sg.DataType.Float
+        case np.int32:
+            dtype = sg.DataType.Int32
+        case _:
+            return None
+
+    raw_data = data.astype(data.dtype.newbyteorder("<")).tobytes()
+    compressed = compress_data(raw_data, compression)
+    if len(compressed) >= len(raw_data):
+        return None
+
+    data_vec = builder.CreateByteVector(compressed)
+    sg.CompressedDataStart(builder)
+    sg.CompressedDataAddCompression(
+        builder,
+        sg.Compression.Zstd if compression == "zstd" else sg.Compression.Lz4,
+    )
+    sg.CompressedDataAddDtype(builder, dtype)
+    sg.CompressedDataAddData(builder, data_vec)
+    return sg.CompressedDataEnd(builder)
+
+
+def float32_to_bfloat16(data: np.ndarray) -> np.ndarray:
+    """
+    Convert float32 values to bfloat16, rounding to nearest with ties to even.
+
+    Returns a uint16 array containing the bit patterns of the bfloat16 values.
+    """
+    bits = data.astype(np.float32).view(np.uint32)
+    rounding = np.uint32(0x7FFF) + ((bits >> 16) & 1)
+    bf16 = ((bits + rounding) >> 16).astype(np.uint16)
+
+    # Truncate NaNs and set the quiet bit, so they don't round to infinity.
+    nans = np.isnan(data)
+    bf16[nans] = ((bits[nans] >> 16) | 0x40).astype(np.uint16)
+    return bf16
+
+
+def bf16_weight_ids(graph: Graph) -> set[int]:
+    """
+    Find float constants which can be stored as bfloat16.
+
+    RTen only supports bfloat16 weights as the "B" input of `MatMul`, so this
+    returns the indexes of 2D float constants which are only used in that
+    position.
+    """
+    candidates = {
+        i
+        for i, node in enumerate(graph.nodes)
+        if isinstance(node, ConstantNode)
+        and node.data.dtype == np.float32
+        and len(node.shape) == 2
+    }
+    used_as_weights: set[int] = set()
+    for node in graph.nodes:
+        if not isinstance(node, OperatorNode):
+            continue
+        for i, input_id in enumerate(node.inputs):
+            if input_id not in candidates:
+                continue
+            if node.op_type == "MatMul" and i == 1:
+                used_as_weights.add(input_id)
+            else:
+                candidates.discard(input_id)
+    return candidates & used_as_weights
+
+
+def build_constant_node(
+    builder: flatbuffers.Builder,
+    constant: ConstantNode,
+    compression: Optional[Compression] = None,
+    bf16: bool = False,
+):
     """
     Serialize a constant tensor value (eg. model weights) into a FlatBuffers model.
+
+    :param compression: Algorithm used to compress numeric data
+    :param bf16: Store float data as bfloat16. bfloat16 data is not compressed.
     """
     shape_vec = write_vec(
         builder, sg.ConstantNodeStartShapeVector, constant.shape, "u32"
     )
 
-    # Convert data to NumPy array then serialize. This is much faster than
-    # serializing a Python array element by element.
-    data_vec = builder.CreateNumpyVector(constant.data.flatten())
+    compressed_data = None
+    if compression and constant.data.dtype != np.object_ and not bf16:
+        compressed_data = build_compressed_data(builder, constant.data, compression)
 
-    match constant.data.dtype:
-        case np.float32:
-            sg.FloatDataStart(builder)
-            sg.FloatDataAddData(builder, data_vec)
-            const_data = sg.FloatDataEnd(builder)
-            const_data_type = sg.ConstantData.FloatData
-        case np.int32:
-            sg.IntDataStart(builder)
-            sg.IntDataAddData(builder, data_vec)
-            const_data = sg.IntDataEnd(builder)
-            const_data_type = sg.ConstantData.IntData
-        case _:
-            raise ValueError(f"Unsupported data array type {constant.data.dtype.name}")  # type:ignore[union-attr]
+    if bf16:
+        data_vec = builder.CreateNumpyVector(
+            float32_to_bfloat16(constant.data.flatten())
+        )
+        sg.BFloat16DataStart(builder)
+        sg.BFloat16DataAddData(builder, data_vec)
+        const_data = sg.BFloat16DataEnd(builder)
+        const_data_type = sg.ConstantData.BFloat16Data
+    elif compressed_data is not None:
+        const_data = compressed_data
+        const_data_type = sg.ConstantData.CompressedData
+    else:
+        # Numeric data is converted to a NumPy array then serialized. This is
+        # much faster than serializing a Python array element by element.
+        match constant.data.dtype:
+            case np.float32:
+                data_vec = builder.CreateNumpyVector(constant.data.flatten())
+                sg.FloatDataStart(builder)
+                sg.FloatDataAddData(builder, data_vec)
+                const_data = sg.FloatDataEnd(builder)
+                const_data_type = sg.ConstantData.FloatData
+            case np.int32:
+                data_vec = builder.CreateNumpyVector(constant.data.flatten())
+                sg.IntDataStart(builder)
+                sg.IntDataAddData(builder, data_vec)
+                const_data = sg.IntDataEnd(builder)
+                const_data_type = sg.ConstantData.IntData
+            case np.object_:
+                strings = [builder.CreateString(s) for s in constant.data.flat]
+                data_vec = write_vec(
+                    builder, sg.StringDataStartDataVector, strings, "offset"
+                )
+                sg.StringDataStart(builder)
+                sg.StringDataAddData(builder, data_vec)
+                const_data = sg.StringDataEnd(builder)
+                const_data_type = sg.ConstantData.StringData
+            case _:
+                raise ValueError(f"Unsupported data array type {constant.data.dtype.name}")  # type:ignore[union-attr]
 
     sg.ConstantNodeStart(builder)
     sg.ConstantNodeAddShape(builder, shape_vec)
@@ -1191,6 +1838,10 @@ def build_value_node(builder: flatbuffers.Builder, value: ValueNode):
     sg.ValueNodeStart(builder)
     if shape_vec:
         sg.ValueNodeAddShape(builder, shape_vec)
+    if value.optional:
+        sg.ValueNodeAddOptional(builder, True)
+    if value.dtype is not None:
+        sg.ValueNodeAddDtype(builder, value.dtype)
     return sg.ValueNodeEnd(builder)
 
 
@@ -1198,14 +1849,17 @@ METADATA_BUILDER_FNS = {
     "code_repository": sg.MetadataAddCodeRepository,
     "commit": sg.MetadataAddCommit,
     "description": sg.MetadataAddDescription,
+    "doc_string": sg.MetadataAddDocString,
     "license": sg.MetadataAddLicense,
     "model_repository": sg.MetadataAddModelRepository,
     "onnx_hash": sg.MetadataAddOnnxHash,
+    "producer_name": sg.MetadataAddProducerName,
+    "producer_version": sg.MetadataAddProducerVersion,
     "run_id": sg.MetadataAddRunId,
     "run_url": sg.MetadataAddRunUrl,
 }
 """
-Map of metadata field to function that serializes this field.
+Map of string metadata field to function that serializes this field.
 """
 
 
@@ -1217,27 +1871,65 @@ def build_metadata(builder: flatbuffers.Builder, metadata: Metadata):
     # Map of field name to flatbuffer string offset.
     field_values = {}
 
-    for field in METADATA_BUILDER_FNS.keys():
-        if val := getattr(metadata, field):
-            field_values[field] = builder.CreateString(val)
+    for field_name in METADATA_BUILDER_FNS.keys():
+        if val := getattr(metadata, field_name):
+            field_values[field_name] = builder.CreateString(val)
+
+    opset_imports = []
+    for domain, version in metadata.opset_imports:
+        opset = sg.OpsetImportT()
+        opset.domain = domain
+        opset.version = version
+        opset_imports.append(opset.Pack(builder))
+    sg.MetadataStartOpsetImportsVector(builder, len(opset_imports))
+    for opset_offset in reversed(opset_imports):
+        builder.PrependUOffsetTRelative(opset_offset)
+    opset_imports_vec = builder.EndVector()
+
+    props = []
+    for key, value in metadata.metadata_props.items():
+        prop = sg.MetadataPropT()
+        prop.key = key
+        prop.value = value
+        props.append(prop.Pack(builder))
+    sg.MetadataStartMetadataPropsVector(builder, len(props))
+    for prop_offset in reversed(props):
+        builder.PrependUOffsetTRelative(prop_offset)
+    props_vec = builder.EndVector()
 
     sg.MetadataStart(builder)
-    for field, builder_fn in METADATA_BUILDER_FNS.items():
-        if val := field_values.get(field):
+    for field_name, builder_fn in METADATA_BUILDER_FNS.items():
+        if val := field_values.get(field_name):
             builder_fn(builder, val)
+    sg.MetadataAddOpsetImports(builder, opset_imports_vec)
+    sg.MetadataAddMetadataProps(builder, props_vec)
     return sg.MetadataEnd(builder)
 
 
-def build_graph(builder: flatbuffers.Builder, graph: Graph):
+def build_graph(
+    builder: flatbuffers.Builder,
+    graph: Graph,
+    compression: Optional[Compression] = None,
+    bf16_weights: bool = False,
+):
     """
     Serialize a computation graph into a flatbuffers model.
+
+    :param compression: Algorithm used to compress constants in the graph.
+      Constants in subgraphs are not compressed.
+    :param bf16_weights: Store `MatMul` weights as bfloat16. Constants in
+      subgraphs are not converted.
     """
+    bf16_ids = bf16_weight_ids(graph) if bf16_weights else set()
+
     node_offsets = []
-    for node in graph.nodes:
+    for node_index, node in enumerate(graph.nodes):
         match node:
             case ConstantNode():
                 data_type = sg.NodeKind.ConstantNode
-                data = build_constant_node(builder, node)
+                data = build_constant_node(
+                    builder, node, compression, bf16=node_index in bf16_ids
+                )
             case OperatorNode():
                 data_type = sg.NodeKind.OperatorNode
                 data = build_operator_node(builder, node)
@@ -1266,7 +1958,13 @@ def build_graph(builder: flatbuffers.Builder, graph: Graph):
     return sg.GraphEnd(builder)
 
 
-def write_model(graph: Graph, metadata: Metadata, out_path: str):
+def write_model(
+    graph: Graph,
+    metadata: Metadata,
+    out_path: str,
+    compression: Optional[Compression] = None,
+    bf16_weights: bool = False,
+):
     """
     Serialize a model into a flatbuffers model.
 
@@ -1276,11 +1974,15 @@ def write_model(graph: Graph, metadata: Metadata, out_path: str):
     :param graph: The main graph for the model
     :param metadata: Model metadata
     :param out_path: Output .rten model path
+    :param compression: Algorithm used to compress constant data, such as
+      weights. Loading compressed models requires the corresponding feature
+      to be enabled in the `rten` crate.
+    :param bf16_weights: Store `MatMul` weights as bfloat16, halving their size
     """
 
     builder = flatbuffers.Builder(initialSize=1024)
 
-    graph = build_graph(builder, graph)
+    graph = build_graph(builder, graph, compression, bf16_weights)
     metadata = build_metadata(builder, metadata)
 
     sg.ModelStart(builder)
@@ -1305,25 +2007,44 @@ def sha256(filename: str) -> str:
     return hasher.hexdigest()
 
 
-def generate_metadata(onnx_path: str, metadata_path: Optional[str] = None) -> Metadata:
+def generate_metadata(
+    onnx_path: str,
+    metadata_path: Optional[str] = None,
+    onnx_model: Optional[onnx.ModelProto] = None,
+) -> Metadata:
     """
     Generate metadata to embed into RTen model.
 
     :param onnx_path: Path to .onnx file
     :param metadata_path: Path to JSON file containing additional metadata
+    :param onnx_model: The loaded ONNX model. If provided, provenance
+      information such as the producer, opset imports and custom metadata
+      properties are copied from it.
     """
     onnx_hash = sha256(onnx_path)
 
-    fields = {"onnx_hash": onnx_hash}
+    fields: dict[str, Any] = {"onnx_hash": onnx_hash}
+    if onnx_model:
+        fields["producer_name"] = onnx_model.producer_name or None
+        fields["producer_version"] = onnx_model.producer_version or None
+        fields["doc_string"] = onnx_model.doc_string or None
+        fields["opset_imports"] = [
+            (opset.domain, opset.version) for opset in onnx_model.opset_import
+        ]
+        fields["metadata_props"] = {
+            prop.key: prop.value for prop in onnx_model.metadata_props
+        }
+
     if metadata_path:
         with open(metadata_path) as fp:
             metadata_dict = json.load(fp)
 
-        for field in METADATA_BUILDER_FNS.keys():
-            if field == "onnx_hash":
+        for field_name in METADATA_BUILDER_FNS.keys():
+            if field_name == "onnx_hash":
                 # This is handled separately.
                 continue
-            fields[field] = metadata_dict.get(field)
+            if field_name in metadata_dict or field_name not in fields:
+                fields[field_name] = metadata_dict.get(field_name)
 
     return Metadata(**fields)
 
@@ -1335,18 +2056,35 @@ def main():
         "-m", "--metadata", help="Path to JSON file containing model metadata."
     )
     parser.add_argument("out_name", help="Output model file name", nargs="?")
+    parser.add_argument(
+        "--compress",
+        choices=["zstd", "lz4"],
+        help="Compress weights using the given algorithm. Requires the "
+        "`zstandard` or `lz4` Python package.",
+    )
+    parser.add_argument(
+        "--bf16-weights",
+        action="store_true",
+        help="Store MatMul weights as bfloat16, halving their size.",
+    )
     args = parser.parse_args()
 
     model = onnx.load(args.model)
     graph = graph_from_onnx_graph(model.graph)
-    metadata = generate_metadata(args.model, args.metadata)
+    metadata = generate_metadata(args.model, args.metadata, onnx_model=model)
 
     output_path = args.out_name
     if output_path is None:
         model_basename = splitext(args.model)[0]
         output_path = f"{model_basename}.rten"
 
-    write_model(graph, metadata, output_path)
+    write_model(
+        graph,
+        metadata,
+        output_path,
+        compression=args.compress,
+        bf16_weights=args.bf16_weights,
+    )
 
 
 if __name__ == "__main__":
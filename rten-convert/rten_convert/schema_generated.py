@@ -109,6 +109,49 @@ class OperatorType(object):
     RandomNormalLike = 99
     Softplus = 100
     GatherND = 101
+    ReduceLogSumExp = 102
+    EyeLike = 103
+    LpNormalization = 104
+    MeanVarianceNormalization = 105
+    GatherBlockQuantized = 106
+    GridSample = 107
+    MatMulNBits = 108
+    Normalize = 109
+    ChannelShuffle = 110
+    PermuteChannels = 111
+    ReduceVar = 112
+    ReduceStd = 113
+    GlobalMaxPool = 114
+    LpPool = 115
+    Dropout = 116
+    Gelu = 117
+    Mish = 118
+    Softsign = 119
+    Selu = 120
+    Celu = 121
+    ThresholdedRelu = 122
+    ImageToTensor = 123
+    BitwiseAnd = 124
+    BitwiseOr = 125
+    BitwiseXor = 126
+    BitwiseNot = 127
+    BitShift = 128
+    CastLike = 129
+    Multinomial = 130
+    Scan = 131
+    SequenceConstruct = 132
+    SequenceInsert = 133
+    SequenceAt = 134
+    SequenceLength = 135
+    ConcatFromSequence = 136
+    SplitToSequence = 137
+    Optional = 138
+    OptionalHasElement = 139
+    OptionalGetElement = 140
+    Custom = 141
+    QLinearConv = 142
+    QLinearMatMul = 143
+    ScaledDotProductAttention = 144
 
 
 class RNNDirection(object):
@@ -125,12 +168,20 @@ class PadMode(object):
 class DataType(object):
     Int32 = 0
     Float = 1
+    Int64 = 2
+    String = 3
+    Int8 = 4
+    UInt8 = 5
+    Bool = 6
+    BFloat16 = 7
 
 
 class CoordTransformMode(object):
     HalfPixel = 0
     Asymmetric = 1
     AlignCorners = 2
+    PytorchHalfPixel = 3
+    TfCropAndResize = 4
 
 
 class NearestMode(object):
@@ -183,6 +234,31 @@ class OperatorAttrs(object):
     RandomNormalAttrs = 34
     RandomNormalLikeAttrs = 35
     GatherNDAttrs = 36
+    EyeLikeAttrs = 37
+    PadAttrs = 38
+    LpNormalizationAttrs = 39
+    MeanVarianceNormalizationAttrs = 40
+    GatherBlockQuantizedAttrs = 41
+    GridSampleAttrs = 42
+    MatMulNBitsAttrs = 43
+    NormalizeAttrs = 44
+    ChannelShuffleAttrs = 45
+    PermuteChannelsAttrs = 46
+    ReduceVarAttrs = 47
+    LpPoolAttrs = 48
+    GeluAttrs = 49
+    SeluAttrs = 50
+    ImageToTensorAttrs = 51
+    BitShiftAttrs = 52
+    MultinomialAttrs = 53
+    DropoutAttrs = 54
+    ScanAttrs = 55
+    ConcatFromSequenceAttrs = 56
+    SplitToSequenceAttrs = 57
+    CustomOperatorAttrs = 58
+    QLinearConvAttrs = 59
+    QLinearMatMulAttrs = 60
+    ScaledDotProductAttentionAttrs = 61
 
 def OperatorAttrsCreator(unionType, table):
     from flatbuffers.table import Table
@@ -260,6 +336,56 @@ def OperatorAttrsCreator(unionType, table):
         return RandomNormalLikeAttrsT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == OperatorAttrs().GatherNDAttrs:
         return GatherNDAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().EyeLikeAttrs:
+        return EyeLikeAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().PadAttrs:
+        return PadAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().LpNormalizationAttrs:
+        return LpNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().MeanVarianceNormalizationAttrs:
+        return MeanVarianceNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GatherBlockQuantizedAttrs:
+        return GatherBlockQuantizedAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GridSampleAttrs:
+        return GridSampleAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().MatMulNBitsAttrs:
+        return MatMulNBitsAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().NormalizeAttrs:
+        return NormalizeAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ChannelShuffleAttrs:
+        return ChannelShuffleAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().PermuteChannelsAttrs:
+        return PermuteChannelsAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ReduceVarAttrs:
+        return ReduceVarAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().LpPoolAttrs:
+        return LpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GeluAttrs:
+        return GeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().SeluAttrs:
+        return SeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ImageToTensorAttrs:
+        return ImageToTensorAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().BitShiftAttrs:
+        return BitShiftAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().MultinomialAttrs:
+        return MultinomialAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().DropoutAttrs:
+        return DropoutAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ScanAttrs:
+        return ScanAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ConcatFromSequenceAttrs:
+        return ConcatFromSequenceAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().SplitToSequenceAttrs:
+        return SplitToSequenceAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().CustomOperatorAttrs:
+        return CustomOperatorAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().QLinearConvAttrs:
+        return QLinearConvAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().QLinearMatMulAttrs:
+        return QLinearMatMulAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ScaledDotProductAttentionAttrs:
+        return ScaledDotProductAttentionAttrsT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
@@ -279,11 +405,37 @@ def ScalarCreator(unionType, table):
     return None
 
 
+class BitShiftDirection(object):
+    Left = 0
+    Right = 1
+
+
+class CustomAttrType(object):
+    Int = 0
+    Float = 1
+    String = 2
+    Ints = 3
+    Floats = 4
+
+
+class GridSamplePadding(object):
+    Zeros = 0
+    Border = 1
+    Reflection = 2
+
+
 class NMSBoxOrder(object):
     TopLeftBottomRight = 0
     CenterWidthHeight = 1
 
 
+class PadOpMode(object):
+    Constant = 0
+    Reflect = 1
+    Edge = 2
+    Wrap = 3
+
+
 class ScatterReduction(object):
     None_ = 0
     Add = 1
@@ -311,10 +463,18 @@ def NodeKindCreator(unionType, table):
     return None
 
 
+class Compression(object):
+    Zstd = 0
+    Lz4 = 1
+
+
 class ConstantData(object):
     NONE = 0
     FloatData = 1
     IntData = 2
+    StringData = 3
+    CompressedData = 4
+    BFloat16Data = 5
 
 def ConstantDataCreator(unionType, table):
     from flatbuffers.table import Table
@@ -324,6 +484,12 @@ def ConstantDataCreator(unionType, table):
         return FloatDataT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == ConstantData().IntData:
         return IntDataT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == ConstantData().StringData:
+        return StringDataT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == ConstantData().CompressedData:
+        return CompressedDataT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == ConstantData().BFloat16Data:
+        return BFloat16DataT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
@@ -534,8 +700,42 @@ class AveragePoolAttrs(object):
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
+    # AveragePoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # AveragePoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
 def AveragePoolAttrsStart(builder):
-    builder.StartObject(5)
+    builder.StartObject(7)
 
 def AveragePoolAttrsAddKernelSize(builder, kernelSize):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
@@ -561,6 +761,15 @@ def AveragePoolAttrsStartStridesVector(builder, numElems):
 def AveragePoolAttrsAddCountIncludePad(builder, countIncludePad):
     builder.PrependBoolSlot(4, countIncludePad, 0)
 
+def AveragePoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(5, ceilMode, 0)
+
+def AveragePoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def AveragePoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def AveragePoolAttrsEnd(builder):
     return builder.EndObject()
 
@@ -579,6 +788,8 @@ class AveragePoolAttrsT(object):
         self.pads = None  # type: List[int]
         self.strides = None  # type: List[int]
         self.countIncludePad = False  # type: bool
+        self.ceilMode = False  # type: bool
+        self.dilations = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -624,6 +835,14 @@ class AveragePoolAttrsT(object):
             else:
                 self.strides = averagePoolAttrs.StridesAsNumpy()
         self.countIncludePad = averagePoolAttrs.CountIncludePad()
+        self.ceilMode = averagePoolAttrs.CeilMode()
+        if not averagePoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(averagePoolAttrs.DilationsLength()):
+                    self.dilations.append(averagePoolAttrs.Dilations(i))
+            else:
+                self.dilations = averagePoolAttrs.DilationsAsNumpy()
 
     # AveragePoolAttrsT
     def Pack(self, builder):
@@ -651,6 +870,14 @@ class AveragePoolAttrsT(object):
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                AveragePoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
         AveragePoolAttrsStart(builder)
         if self.kernelSize is not None:
             AveragePoolAttrsAddKernelSize(builder, kernelSize)
@@ -660,6 +887,9 @@ class AveragePoolAttrsT(object):
         if self.strides is not None:
             AveragePoolAttrsAddStrides(builder, strides)
         AveragePoolAttrsAddCountIncludePad(builder, self.countIncludePad)
+        AveragePoolAttrsAddCeilMode(builder, self.ceilMode)
+        if self.dilations is not None:
+            AveragePoolAttrsAddDilations(builder, dilations)
         averagePoolAttrs = AveragePoolAttrsEnd(builder)
         return averagePoolAttrs
 
@@ -741,6 +971,83 @@ class BatchNormalizationAttrsT(object):
         return batchNormalizationAttrs
 
 
+class BitShiftAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = BitShiftAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsBitShiftAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def BitShiftAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # BitShiftAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # BitShiftAttrs
+    def Direction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def BitShiftAttrsStart(builder):
+    builder.StartObject(1)
+
+def BitShiftAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def BitShiftAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class BitShiftAttrsT(object):
+
+    # BitShiftAttrsT
+    def __init__(self):
+        self.direction = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        bitShiftAttrs = BitShiftAttrs()
+        bitShiftAttrs.Init(buf, pos)
+        return cls.InitFromObj(bitShiftAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, bitShiftAttrs):
+        x = BitShiftAttrsT()
+        x._UnPack(bitShiftAttrs)
+        return x
+
+    # BitShiftAttrsT
+    def _UnPack(self, bitShiftAttrs):
+        if bitShiftAttrs is None:
+            return
+        self.direction = bitShiftAttrs.Direction()
+
+    # BitShiftAttrsT
+    def Pack(self, builder):
+        BitShiftAttrsStart(builder)
+        BitShiftAttrsAddDirection(builder, self.direction)
+        bitShiftAttrs = BitShiftAttrsEnd(builder)
+        return bitShiftAttrs
+
+
 class CastAttrs(object):
     __slots__ = ['_tab']
 
@@ -818,6 +1125,83 @@ class CastAttrsT(object):
         return castAttrs
 
 
+class ChannelShuffleAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ChannelShuffleAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsChannelShuffleAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ChannelShuffleAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ChannelShuffleAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ChannelShuffleAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+def ChannelShuffleAttrsStart(builder):
+    builder.StartObject(1)
+
+def ChannelShuffleAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(0, groups, 0)
+
+def ChannelShuffleAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ChannelShuffleAttrsT(object):
+
+    # ChannelShuffleAttrsT
+    def __init__(self):
+        self.groups = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        channelShuffleAttrs = ChannelShuffleAttrs()
+        channelShuffleAttrs.Init(buf, pos)
+        return cls.InitFromObj(channelShuffleAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, channelShuffleAttrs):
+        x = ChannelShuffleAttrsT()
+        x._UnPack(channelShuffleAttrs)
+        return x
+
+    # ChannelShuffleAttrsT
+    def _UnPack(self, channelShuffleAttrs):
+        if channelShuffleAttrs is None:
+            return
+        self.groups = channelShuffleAttrs.Groups()
+
+    # ChannelShuffleAttrsT
+    def Pack(self, builder):
+        ChannelShuffleAttrsStart(builder)
+        ChannelShuffleAttrsAddGroups(builder, self.groups)
+        channelShuffleAttrs = ChannelShuffleAttrsEnd(builder)
+        return channelShuffleAttrs
+
+
 class ConcatAttrs(object):
     __slots__ = ['_tab']
 
@@ -1049,6 +1433,96 @@ class FloatScalarT(object):
         return floatScalar
 
 
+class ConcatFromSequenceAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ConcatFromSequenceAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsConcatFromSequenceAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ConcatFromSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ConcatFromSequenceAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ConcatFromSequenceAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # ConcatFromSequenceAttrs
+    def NewAxis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def ConcatFromSequenceAttrsStart(builder):
+    builder.StartObject(2)
+
+def ConcatFromSequenceAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def ConcatFromSequenceAttrsAddNewAxis(builder, newAxis):
+    builder.PrependBoolSlot(1, newAxis, 0)
+
+def ConcatFromSequenceAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ConcatFromSequenceAttrsT(object):
+
+    # ConcatFromSequenceAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+        self.newAxis = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        concatFromSequenceAttrs = ConcatFromSequenceAttrs()
+        concatFromSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(concatFromSequenceAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, concatFromSequenceAttrs):
+        x = ConcatFromSequenceAttrsT()
+        x._UnPack(concatFromSequenceAttrs)
+        return x
+
+    # ConcatFromSequenceAttrsT
+    def _UnPack(self, concatFromSequenceAttrs):
+        if concatFromSequenceAttrs is None:
+            return
+        self.axis = concatFromSequenceAttrs.Axis()
+        self.newAxis = concatFromSequenceAttrs.NewAxis()
+
+    # ConcatFromSequenceAttrsT
+    def Pack(self, builder):
+        ConcatFromSequenceAttrsStart(builder)
+        ConcatFromSequenceAttrsAddAxis(builder, self.axis)
+        ConcatFromSequenceAttrsAddNewAxis(builder, self.newAxis)
+        concatFromSequenceAttrs = ConcatFromSequenceAttrsEnd(builder)
+        return concatFromSequenceAttrs
+
+
 class ConstantOfShapeAttrs(object):
     __slots__ = ['_tab']
 
@@ -1479,23 +1953,132 @@ class ConvTransposeAttrs(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         return o == 0
 
-def ConvTransposeAttrsStart(builder):
-    builder.StartObject(3)
-
-def ConvTransposeAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
-
-def ConvTransposeAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # ConvTransposeAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def ConvTransposeAttrsAddPadMode(builder, padMode):
-    builder.PrependUint8Slot(1, padMode, 1)
+    # ConvTransposeAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def ConvTransposeAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # ConvTransposeAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def ConvTransposeAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # ConvTransposeAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def OutputPadding(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def OutputShape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
+def ConvTransposeAttrsStart(builder):
+    builder.StartObject(7)
+
+def ConvTransposeAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def ConvTransposeAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(1, padMode, 1)
+
+def ConvTransposeAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvTransposeAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def ConvTransposeAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(4, groups, 1)
+
+def ConvTransposeAttrsAddOutputPadding(builder, outputPadding):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(outputPadding), 0)
+
+def ConvTransposeAttrsStartOutputPaddingVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddOutputShape(builder, outputShape):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(outputShape), 0)
+
+def ConvTransposeAttrsStartOutputShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
 def ConvTransposeAttrsEnd(builder):
     return builder.EndObject()
@@ -1513,6 +2096,10 @@ class ConvTransposeAttrsT(object):
         self.strides = None  # type: List[int]
         self.padMode = 1  # type: int
         self.pads = None  # type: List[int]
+        self.dilations = None  # type: List[int]
+        self.groups = 1  # type: int
+        self.outputPadding = None  # type: List[int]
+        self.outputShape = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -1550,6 +2137,28 @@ class ConvTransposeAttrsT(object):
                     self.pads.append(convTransposeAttrs.Pads(i))
             else:
                 self.pads = convTransposeAttrs.PadsAsNumpy()
+        if not convTransposeAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(convTransposeAttrs.DilationsLength()):
+                    self.dilations.append(convTransposeAttrs.Dilations(i))
+            else:
+                self.dilations = convTransposeAttrs.DilationsAsNumpy()
+        self.groups = convTransposeAttrs.Groups()
+        if not convTransposeAttrs.OutputPaddingIsNone():
+            if np is None:
+                self.outputPadding = []
+                for i in range(convTransposeAttrs.OutputPaddingLength()):
+                    self.outputPadding.append(convTransposeAttrs.OutputPadding(i))
+            else:
+                self.outputPadding = convTransposeAttrs.OutputPaddingAsNumpy()
+        if not convTransposeAttrs.OutputShapeIsNone():
+            if np is None:
+                self.outputShape = []
+                for i in range(convTransposeAttrs.OutputShapeLength()):
+                    self.outputShape.append(convTransposeAttrs.OutputShape(i))
+            else:
+                self.outputShape = convTransposeAttrs.OutputShapeAsNumpy()
 
     # ConvTransposeAttrsT
     def Pack(self, builder):
@@ -1569,67 +2178,214 @@ class ConvTransposeAttrsT(object):
                 for i in reversed(range(len(self.pads))):
                     builder.PrependUint32(self.pads[i])
                 pads = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                ConvTransposeAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        if self.outputPadding is not None:
+            if np is not None and type(self.outputPadding) is np.ndarray:
+                outputPadding = builder.CreateNumpyVector(self.outputPadding)
+            else:
+                ConvTransposeAttrsStartOutputPaddingVector(builder, len(self.outputPadding))
+                for i in reversed(range(len(self.outputPadding))):
+                    builder.PrependUint32(self.outputPadding[i])
+                outputPadding = builder.EndVector()
+        if self.outputShape is not None:
+            if np is not None and type(self.outputShape) is np.ndarray:
+                outputShape = builder.CreateNumpyVector(self.outputShape)
+            else:
+                ConvTransposeAttrsStartOutputShapeVector(builder, len(self.outputShape))
+                for i in reversed(range(len(self.outputShape))):
+                    builder.PrependUint32(self.outputShape[i])
+                outputShape = builder.EndVector()
         ConvTransposeAttrsStart(builder)
         if self.strides is not None:
             ConvTransposeAttrsAddStrides(builder, strides)
         ConvTransposeAttrsAddPadMode(builder, self.padMode)
         if self.pads is not None:
             ConvTransposeAttrsAddPads(builder, pads)
+        if self.dilations is not None:
+            ConvTransposeAttrsAddDilations(builder, dilations)
+        ConvTransposeAttrsAddGroups(builder, self.groups)
+        if self.outputPadding is not None:
+            ConvTransposeAttrsAddOutputPadding(builder, outputPadding)
+        if self.outputShape is not None:
+            ConvTransposeAttrsAddOutputShape(builder, outputShape)
         convTransposeAttrs = ConvTransposeAttrsEnd(builder)
         return convTransposeAttrs
 
 
-class EluAttrs(object):
+class CustomAttr(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EluAttrs()
+        x = CustomAttr()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEluAttrs(cls, buf, offset=0):
+    def GetRootAsCustomAttr(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CustomAttrBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EluAttrs
+    # CustomAttr
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EluAttrs
-    def Alpha(self):
+    # CustomAttr
+    def Name(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # CustomAttr
+    def ValueType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # CustomAttr
+    def IntValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, o + self._tab.Pos)
+        return 0
+
+    # CustomAttr
+    def FloatValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-def EluAttrsStart(builder):
-    builder.StartObject(1)
+    # CustomAttr
+    def StringValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
 
-def EluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # CustomAttr
+    def Ints(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 8))
+        return 0
 
-def EluAttrsEnd(builder):
+    # CustomAttr
+    def IntsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int64Flags, o)
+        return 0
+
+    # CustomAttr
+    def IntsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CustomAttr
+    def IntsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # CustomAttr
+    def Floats(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # CustomAttr
+    def FloatsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
+
+    # CustomAttr
+    def FloatsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CustomAttr
+    def FloatsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
+def CustomAttrStart(builder):
+    builder.StartObject(7)
+
+def CustomAttrAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
+
+def CustomAttrAddValueType(builder, valueType):
+    builder.PrependUint8Slot(1, valueType, 0)
+
+def CustomAttrAddIntValue(builder, intValue):
+    builder.PrependInt64Slot(2, intValue, 0)
+
+def CustomAttrAddFloatValue(builder, floatValue):
+    builder.PrependFloat32Slot(3, floatValue, 0.0)
+
+def CustomAttrAddStringValue(builder, stringValue):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(stringValue), 0)
+
+def CustomAttrAddInts(builder, ints):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(ints), 0)
+
+def CustomAttrStartIntsVector(builder, numElems):
+    return builder.StartVector(8, numElems, 8)
+
+def CustomAttrAddFloats(builder, floats):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(floats), 0)
+
+def CustomAttrStartFloatsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def CustomAttrEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class EluAttrsT(object):
+class CustomAttrT(object):
 
-    # EluAttrsT
+    # CustomAttrT
     def __init__(self):
-        self.alpha = 0.0  # type: float
+        self.name = None  # type: str
+        self.valueType = 0  # type: int
+        self.intValue = 0  # type: int
+        self.floatValue = 0.0  # type: float
+        self.stringValue = None  # type: str
+        self.ints = None  # type: List[int]
+        self.floats = None  # type: List[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        eluAttrs = EluAttrs()
-        eluAttrs.Init(buf, pos)
-        return cls.InitFromObj(eluAttrs)
+        customAttr = CustomAttr()
+        customAttr.Init(buf, pos)
+        return cls.InitFromObj(customAttr)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1637,70 +2393,511 @@ class EluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, eluAttrs):
-        x = EluAttrsT()
-        x._UnPack(eluAttrs)
+    def InitFromObj(cls, customAttr):
+        x = CustomAttrT()
+        x._UnPack(customAttr)
         return x
 
-    # EluAttrsT
-    def _UnPack(self, eluAttrs):
-        if eluAttrs is None:
+    # CustomAttrT
+    def _UnPack(self, customAttr):
+        if customAttr is None:
             return
-        self.alpha = eluAttrs.Alpha()
+        self.name = customAttr.Name()
+        self.valueType = customAttr.ValueType()
+        self.intValue = customAttr.IntValue()
+        self.floatValue = customAttr.FloatValue()
+        self.stringValue = customAttr.StringValue()
+        if not customAttr.IntsIsNone():
+            if np is None:
+                self.ints = []
+                for i in range(customAttr.IntsLength()):
+                    self.ints.append(customAttr.Ints(i))
+            else:
+                self.ints = customAttr.IntsAsNumpy()
+        if not customAttr.FloatsIsNone():
+            if np is None:
+                self.floats = []
+                for i in range(customAttr.FloatsLength()):
+                    self.floats.append(customAttr.Floats(i))
+            else:
+                self.floats = customAttr.FloatsAsNumpy()
 
-    # EluAttrsT
+    # CustomAttrT
     def Pack(self, builder):
-        EluAttrsStart(builder)
-        EluAttrsAddAlpha(builder, self.alpha)
-        eluAttrs = EluAttrsEnd(builder)
-        return eluAttrs
-
-
-class FlattenAttrs(object):
+        if self.name is not None:
+            name = builder.CreateString(self.name)
+        if self.stringValue is not None:
+            stringValue = builder.CreateString(self.stringValue)
+        if self.ints is not None:
+            if np is not None and type(self.ints) is np.ndarray:
+                ints = builder.CreateNumpyVector(self.ints)
+            else:
+                CustomAttrStartIntsVector(builder, len(self.ints))
+                for i in reversed(range(len(self.ints))):
+                    builder.PrependInt64(self.ints[i])
+                ints = builder.EndVector()
+        if self.floats is not None:
+            if np is not None and type(self.floats) is np.ndarray:
+                floats = builder.CreateNumpyVector(self.floats)
+            else:
+                CustomAttrStartFloatsVector(builder, len(self.floats))
+                for i in reversed(range(len(self.floats))):
+                    builder.PrependFloat32(self.floats[i])
+                floats = builder.EndVector()
+        CustomAttrStart(builder)
+        if self.name is not None:
+            CustomAttrAddName(builder, name)
+        CustomAttrAddValueType(builder, self.valueType)
+        CustomAttrAddIntValue(builder, self.intValue)
+        CustomAttrAddFloatValue(builder, self.floatValue)
+        if self.stringValue is not None:
+            CustomAttrAddStringValue(builder, stringValue)
+        if self.ints is not None:
+            CustomAttrAddInts(builder, ints)
+        if self.floats is not None:
+            CustomAttrAddFloats(builder, floats)
+        customAttr = CustomAttrEnd(builder)
+        return customAttr
+
+
+class CustomOperatorAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FlattenAttrs()
+        x = CustomOperatorAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+    def GetRootAsCustomOperatorAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CustomOperatorAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FlattenAttrs
+    # CustomOperatorAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FlattenAttrs
-    def Axis(self):
+    # CustomOperatorAttrs
+    def Domain(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # CustomOperatorAttrs
+    def OpType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # CustomOperatorAttrs
+    def Attrs(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = CustomAttr()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # CustomOperatorAttrs
+    def AttrsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
         return 0
 
-def FlattenAttrsStart(builder):
-    builder.StartObject(1)
+    # CustomOperatorAttrs
+    def AttrsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
 
-def FlattenAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def CustomOperatorAttrsStart(builder):
+    builder.StartObject(3)
 
-def FlattenAttrsEnd(builder):
-    return builder.EndObject()
+def CustomOperatorAttrsAddDomain(builder, domain):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(domain), 0)
 
+def CustomOperatorAttrsAddOpType(builder, opType):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(opType), 0)
 
+def CustomOperatorAttrsAddAttrs(builder, attrs):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(attrs), 0)
 
-class FlattenAttrsT(object):
+def CustomOperatorAttrsStartAttrsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-    # FlattenAttrsT
-    def __init__(self):
-        self.axis = 0  # type: int
+def CustomOperatorAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class CustomOperatorAttrsT(object):
+
+    # CustomOperatorAttrsT
+    def __init__(self):
+        self.domain = None  # type: str
+        self.opType = None  # type: str
+        self.attrs = None  # type: List[CustomAttrT]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        customOperatorAttrs = CustomOperatorAttrs()
+        customOperatorAttrs.Init(buf, pos)
+        return cls.InitFromObj(customOperatorAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, customOperatorAttrs):
+        x = CustomOperatorAttrsT()
+        x._UnPack(customOperatorAttrs)
+        return x
+
+    # CustomOperatorAttrsT
+    def _UnPack(self, customOperatorAttrs):
+        if customOperatorAttrs is None:
+            return
+        self.domain = customOperatorAttrs.Domain()
+        self.opType = customOperatorAttrs.OpType()
+        if not customOperatorAttrs.AttrsIsNone():
+            self.attrs = []
+            for i in range(customOperatorAttrs.AttrsLength()):
+                if customOperatorAttrs.Attrs(i) is None:
+                    self.attrs.append(None)
+                else:
+                    customAttr_ = CustomAttrT.InitFromObj(customOperatorAttrs.Attrs(i))
+                    self.attrs.append(customAttr_)
+
+    # CustomOperatorAttrsT
+    def Pack(self, builder):
+        if self.domain is not None:
+            domain = builder.CreateString(self.domain)
+        if self.opType is not None:
+            opType = builder.CreateString(self.opType)
+        if self.attrs is not None:
+            attrslist = []
+            for i in range(len(self.attrs)):
+                attrslist.append(self.attrs[i].Pack(builder))
+            CustomOperatorAttrsStartAttrsVector(builder, len(self.attrs))
+            for i in reversed(range(len(self.attrs))):
+                builder.PrependUOffsetTRelative(attrslist[i])
+            attrs = builder.EndVector()
+        CustomOperatorAttrsStart(builder)
+        if self.domain is not None:
+            CustomOperatorAttrsAddDomain(builder, domain)
+        if self.opType is not None:
+            CustomOperatorAttrsAddOpType(builder, opType)
+        if self.attrs is not None:
+            CustomOperatorAttrsAddAttrs(builder, attrs)
+        customOperatorAttrs = CustomOperatorAttrsEnd(builder)
+        return customOperatorAttrs
+
+
+class DropoutAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = DropoutAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsDropoutAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def DropoutAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # DropoutAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # DropoutAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
+
+def DropoutAttrsStart(builder):
+    builder.StartObject(1)
+
+def DropoutAttrsAddSeed(builder, seed):
+    builder.PrependInt32Slot(0, seed, None)
+
+def DropoutAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class DropoutAttrsT(object):
+
+    # DropoutAttrsT
+    def __init__(self):
+        self.seed = None  # type: Optional[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        dropoutAttrs = DropoutAttrs()
+        dropoutAttrs.Init(buf, pos)
+        return cls.InitFromObj(dropoutAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, dropoutAttrs):
+        x = DropoutAttrsT()
+        x._UnPack(dropoutAttrs)
+        return x
+
+    # DropoutAttrsT
+    def _UnPack(self, dropoutAttrs):
+        if dropoutAttrs is None:
+            return
+        self.seed = dropoutAttrs.Seed()
+
+    # DropoutAttrsT
+    def Pack(self, builder):
+        DropoutAttrsStart(builder)
+        DropoutAttrsAddSeed(builder, self.seed)
+        dropoutAttrs = DropoutAttrsEnd(builder)
+        return dropoutAttrs
+
+
+class EluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def EluAttrsStart(builder):
+    builder.StartObject(1)
+
+def EluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def EluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EluAttrsT(object):
+
+    # EluAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        eluAttrs = EluAttrs()
+        eluAttrs.Init(buf, pos)
+        return cls.InitFromObj(eluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, eluAttrs):
+        x = EluAttrsT()
+        x._UnPack(eluAttrs)
+        return x
+
+    # EluAttrsT
+    def _UnPack(self, eluAttrs):
+        if eluAttrs is None:
+            return
+        self.alpha = eluAttrs.Alpha()
+
+    # EluAttrsT
+    def Pack(self, builder):
+        EluAttrsStart(builder)
+        EluAttrsAddAlpha(builder, self.alpha)
+        eluAttrs = EluAttrsEnd(builder)
+        return eluAttrs
+
+
+class EyeLikeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EyeLikeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEyeLikeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EyeLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EyeLikeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EyeLikeAttrs
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
+
+    # EyeLikeAttrs
+    def K(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def EyeLikeAttrsStart(builder):
+    builder.StartObject(2)
+
+def EyeLikeAttrsAddDtype(builder, dtype):
+    builder.PrependUint8Slot(0, dtype, None)
+
+def EyeLikeAttrsAddK(builder, k):
+    builder.PrependInt32Slot(1, k, 0)
+
+def EyeLikeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EyeLikeAttrsT(object):
+
+    # EyeLikeAttrsT
+    def __init__(self):
+        self.dtype = None  # type: Optional[int]
+        self.k = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        eyeLikeAttrs = EyeLikeAttrs()
+        eyeLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(eyeLikeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, eyeLikeAttrs):
+        x = EyeLikeAttrsT()
+        x._UnPack(eyeLikeAttrs)
+        return x
+
+    # EyeLikeAttrsT
+    def _UnPack(self, eyeLikeAttrs):
+        if eyeLikeAttrs is None:
+            return
+        self.dtype = eyeLikeAttrs.Dtype()
+        self.k = eyeLikeAttrs.K()
+
+    # EyeLikeAttrsT
+    def Pack(self, builder):
+        EyeLikeAttrsStart(builder)
+        EyeLikeAttrsAddDtype(builder, self.dtype)
+        EyeLikeAttrsAddK(builder, self.k)
+        eyeLikeAttrs = EyeLikeAttrsEnd(builder)
+        return eyeLikeAttrs
+
+
+class FlattenAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = FlattenAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # FlattenAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # FlattenAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def FlattenAttrsStart(builder):
+    builder.StartObject(1)
+
+def FlattenAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def FlattenAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class FlattenAttrsT(object):
+
+    # FlattenAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -1977,13 +3174,180 @@ class GatherNDAttrsT(object):
         return gatherNdattrs
 
 
-class GemmAttrs(object):
+class GatherBlockQuantizedAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GemmAttrs()
+        x = GatherBlockQuantizedAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGatherBlockQuantizedAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GatherBlockQuantizedAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GatherBlockQuantizedAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GatherBlockQuantizedAttrs
+    def Bits(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 4
+
+    # GatherBlockQuantizedAttrs
+    def BlockSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 128
+
+def GatherBlockQuantizedAttrsStart(builder):
+    builder.StartObject(2)
+
+def GatherBlockQuantizedAttrsAddBits(builder, bits):
+    builder.PrependUint32Slot(0, bits, 4)
+
+def GatherBlockQuantizedAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(1, blockSize, 128)
+
+def GatherBlockQuantizedAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GatherBlockQuantizedAttrsT(object):
+
+    # GatherBlockQuantizedAttrsT
+    def __init__(self):
+        self.bits = 4  # type: int
+        self.blockSize = 128  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gatherBlockQuantizedAttrs = GatherBlockQuantizedAttrs()
+        gatherBlockQuantizedAttrs.Init(buf, pos)
+        return cls.InitFromObj(gatherBlockQuantizedAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gatherBlockQuantizedAttrs):
+        x = GatherBlockQuantizedAttrsT()
+        x._UnPack(gatherBlockQuantizedAttrs)
+        return x
+
+    # GatherBlockQuantizedAttrsT
+    def _UnPack(self, gatherBlockQuantizedAttrs):
+        if gatherBlockQuantizedAttrs is None:
+            return
+        self.bits = gatherBlockQuantizedAttrs.Bits()
+        self.blockSize = gatherBlockQuantizedAttrs.BlockSize()
+
+    # GatherBlockQuantizedAttrsT
+    def Pack(self, builder):
+        GatherBlockQuantizedAttrsStart(builder)
+        GatherBlockQuantizedAttrsAddBits(builder, self.bits)
+        GatherBlockQuantizedAttrsAddBlockSize(builder, self.blockSize)
+        gatherBlockQuantizedAttrs = GatherBlockQuantizedAttrsEnd(builder)
+        return gatherBlockQuantizedAttrs
+
+
+class GeluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GeluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGeluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GeluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GeluAttrs
+    def Approximate(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GeluAttrsStart(builder):
+    builder.StartObject(1)
+
+def GeluAttrsAddApproximate(builder, approximate):
+    builder.PrependBoolSlot(0, approximate, 0)
+
+def GeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GeluAttrsT(object):
+
+    # GeluAttrsT
+    def __init__(self):
+        self.approximate = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        geluAttrs = GeluAttrs()
+        geluAttrs.Init(buf, pos)
+        return cls.InitFromObj(geluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, geluAttrs):
+        x = GeluAttrsT()
+        x._UnPack(geluAttrs)
+        return x
+
+    # GeluAttrsT
+    def _UnPack(self, geluAttrs):
+        if geluAttrs is None:
+            return
+        self.approximate = geluAttrs.Approximate()
+
+    # GeluAttrsT
+    def Pack(self, builder):
+        GeluAttrsStart(builder)
+        GeluAttrsAddApproximate(builder, self.approximate)
+        geluAttrs = GeluAttrsEnd(builder)
+        return geluAttrs
+
+
+class GemmAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GemmAttrs()
         x.Init(buf, n + offset)
         return x
 
@@ -2196,6 +3560,109 @@ class GRUAttrsT(object):
         return gruattrs
 
 
+class GridSampleAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GridSampleAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGridSampleAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GridSampleAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GridSampleAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GridSampleAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # GridSampleAttrs
+    def PaddingMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # GridSampleAttrs
+    def AlignCorners(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GridSampleAttrsStart(builder):
+    builder.StartObject(3)
+
+def GridSampleAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def GridSampleAttrsAddPaddingMode(builder, paddingMode):
+    builder.PrependUint8Slot(1, paddingMode, 0)
+
+def GridSampleAttrsAddAlignCorners(builder, alignCorners):
+    builder.PrependBoolSlot(2, alignCorners, 0)
+
+def GridSampleAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GridSampleAttrsT(object):
+
+    # GridSampleAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+        self.paddingMode = 0  # type: int
+        self.alignCorners = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gridSampleAttrs = GridSampleAttrs()
+        gridSampleAttrs.Init(buf, pos)
+        return cls.InitFromObj(gridSampleAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gridSampleAttrs):
+        x = GridSampleAttrsT()
+        x._UnPack(gridSampleAttrs)
+        return x
+
+    # GridSampleAttrsT
+    def _UnPack(self, gridSampleAttrs):
+        if gridSampleAttrs is None:
+            return
+        self.mode = gridSampleAttrs.Mode()
+        self.paddingMode = gridSampleAttrs.PaddingMode()
+        self.alignCorners = gridSampleAttrs.AlignCorners()
+
+    # GridSampleAttrsT
+    def Pack(self, builder):
+        GridSampleAttrsStart(builder)
+        GridSampleAttrsAddMode(builder, self.mode)
+        GridSampleAttrsAddPaddingMode(builder, self.paddingMode)
+        GridSampleAttrsAddAlignCorners(builder, self.alignCorners)
+        gridSampleAttrs = GridSampleAttrsEnd(builder)
+        return gridSampleAttrs
+
+
 class HardSigmoidAttrs(object):
     __slots__ = ['_tab']
 
@@ -2286,57 +3753,129 @@ class HardSigmoidAttrsT(object):
         return hardSigmoidAttrs
 
 
-class LeakyReluAttrs(object):
+class ImageToTensorAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LeakyReluAttrs()
+        x = ImageToTensorAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
+    def GetRootAsImageToTensorAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ImageToTensorAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LeakyReluAttrs
+    # ImageToTensorAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LeakyReluAttrs
-    def Alpha(self):
+    # ImageToTensorAttrs
+    def Scale(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+        return 1.0
 
-def LeakyReluAttrsStart(builder):
-    builder.StartObject(1)
+    # ImageToTensorAttrs
+    def Mean(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def LeakyReluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # ImageToTensorAttrs
+    def MeanAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
 
-def LeakyReluAttrsEnd(builder):
+    # ImageToTensorAttrs
+    def MeanLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ImageToTensorAttrs
+    def MeanIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+    # ImageToTensorAttrs
+    def Std(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ImageToTensorAttrs
+    def StdAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
+
+    # ImageToTensorAttrs
+    def StdLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ImageToTensorAttrs
+    def StdIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+def ImageToTensorAttrsStart(builder):
+    builder.StartObject(3)
+
+def ImageToTensorAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(0, scale, 1.0)
+
+def ImageToTensorAttrsAddMean(builder, mean):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(mean), 0)
+
+def ImageToTensorAttrsStartMeanVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ImageToTensorAttrsAddStd(builder, std):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(std), 0)
+
+def ImageToTensorAttrsStartStdVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ImageToTensorAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class LeakyReluAttrsT(object):
+class ImageToTensorAttrsT(object):
 
-    # LeakyReluAttrsT
+    # ImageToTensorAttrsT
     def __init__(self):
-        self.alpha = 0.0  # type: float
+        self.scale = 1.0  # type: float
+        self.mean = None  # type: List[float]
+        self.std = None  # type: List[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        leakyReluAttrs = LeakyReluAttrs()
-        leakyReluAttrs.Init(buf, pos)
-        return cls.InitFromObj(leakyReluAttrs)
+        imageToTensorAttrs = ImageToTensorAttrs()
+        imageToTensorAttrs.Init(buf, pos)
+        return cls.InitFromObj(imageToTensorAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2344,87 +3883,198 @@ class LeakyReluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, leakyReluAttrs):
-        x = LeakyReluAttrsT()
-        x._UnPack(leakyReluAttrs)
+    def InitFromObj(cls, imageToTensorAttrs):
+        x = ImageToTensorAttrsT()
+        x._UnPack(imageToTensorAttrs)
         return x
 
-    # LeakyReluAttrsT
-    def _UnPack(self, leakyReluAttrs):
-        if leakyReluAttrs is None:
+    # ImageToTensorAttrsT
+    def _UnPack(self, imageToTensorAttrs):
+        if imageToTensorAttrs is None:
             return
-        self.alpha = leakyReluAttrs.Alpha()
+        self.scale = imageToTensorAttrs.Scale()
+        if not imageToTensorAttrs.MeanIsNone():
+            if np is None:
+                self.mean = []
+                for i in range(imageToTensorAttrs.MeanLength()):
+                    self.mean.append(imageToTensorAttrs.Mean(i))
+            else:
+                self.mean = imageToTensorAttrs.MeanAsNumpy()
+        if not imageToTensorAttrs.StdIsNone():
+            if np is None:
+                self.std = []
+                for i in range(imageToTensorAttrs.StdLength()):
+                    self.std.append(imageToTensorAttrs.Std(i))
+            else:
+                self.std = imageToTensorAttrs.StdAsNumpy()
 
-    # LeakyReluAttrsT
+    # ImageToTensorAttrsT
     def Pack(self, builder):
-        LeakyReluAttrsStart(builder)
-        LeakyReluAttrsAddAlpha(builder, self.alpha)
-        leakyReluAttrs = LeakyReluAttrsEnd(builder)
-        return leakyReluAttrs
+        if self.mean is not None:
+            if np is not None and type(self.mean) is np.ndarray:
+                mean = builder.CreateNumpyVector(self.mean)
+            else:
+                ImageToTensorAttrsStartMeanVector(builder, len(self.mean))
+                for i in reversed(range(len(self.mean))):
+                    builder.PrependFloat32(self.mean[i])
+                mean = builder.EndVector()
+        if self.std is not None:
+            if np is not None and type(self.std) is np.ndarray:
+                std = builder.CreateNumpyVector(self.std)
+            else:
+                ImageToTensorAttrsStartStdVector(builder, len(self.std))
+                for i in reversed(range(len(self.std))):
+                    builder.PrependFloat32(self.std[i])
+                std = builder.EndVector()
+        ImageToTensorAttrsStart(builder)
+        ImageToTensorAttrsAddScale(builder, self.scale)
+        if self.mean is not None:
+            ImageToTensorAttrsAddMean(builder, mean)
+        if self.std is not None:
+            ImageToTensorAttrsAddStd(builder, std)
+        imageToTensorAttrs = ImageToTensorAttrsEnd(builder)
+        return imageToTensorAttrs
 
 
-class LSTMAttrs(object):
+class LeakyReluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LSTMAttrs()
+        x = LeakyReluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLSTMAttrs(cls, buf, offset=0):
+    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LSTMAttrs
+    # LeakyReluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LSTMAttrs
-    def Direction(self):
+    # LeakyReluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def LeakyReluAttrsStart(builder):
+    builder.StartObject(1)
+
+def LeakyReluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def LeakyReluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class LeakyReluAttrsT(object):
+
+    # LeakyReluAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        leakyReluAttrs = LeakyReluAttrs()
+        leakyReluAttrs.Init(buf, pos)
+        return cls.InitFromObj(leakyReluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, leakyReluAttrs):
+        x = LeakyReluAttrsT()
+        x._UnPack(leakyReluAttrs)
+        return x
+
+    # LeakyReluAttrsT
+    def _UnPack(self, leakyReluAttrs):
+        if leakyReluAttrs is None:
+            return
+        self.alpha = leakyReluAttrs.Alpha()
+
+    # LeakyReluAttrsT
+    def Pack(self, builder):
+        LeakyReluAttrsStart(builder)
+        LeakyReluAttrsAddAlpha(builder, self.alpha)
+        leakyReluAttrs = LeakyReluAttrsEnd(builder)
+        return leakyReluAttrs
+
+
+class LpNormalizationAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LpNormalizationAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLpNormalizationAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LpNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LpNormalizationAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LpNormalizationAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # LSTMAttrs
-    def HiddenSize(self):
+    # LpNormalizationAttrs
+    def P(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 2
 
-def LSTMAttrsStart(builder):
+def LpNormalizationAttrsStart(builder):
     builder.StartObject(2)
 
-def LSTMAttrsAddDirection(builder, direction):
-    builder.PrependUint8Slot(0, direction, 0)
+def LpNormalizationAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def LSTMAttrsAddHiddenSize(builder, hiddenSize):
-    builder.PrependUint32Slot(1, hiddenSize, 0)
+def LpNormalizationAttrsAddP(builder, p):
+    builder.PrependInt32Slot(1, p, 2)
 
-def LSTMAttrsEnd(builder):
+def LpNormalizationAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class LSTMAttrsT(object):
+class LpNormalizationAttrsT(object):
 
-    # LSTMAttrsT
+    # LpNormalizationAttrsT
     def __init__(self):
-        self.direction = 0  # type: int
-        self.hiddenSize = 0  # type: int
+        self.axis = 0  # type: int
+        self.p = 2  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        lstmattrs = LSTMAttrs()
-        lstmattrs.Init(buf, pos)
-        return cls.InitFromObj(lstmattrs)
+        lpNormalizationAttrs = LpNormalizationAttrs()
+        lpNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpNormalizationAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2432,50 +4082,50 @@ class LSTMAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, lstmattrs):
-        x = LSTMAttrsT()
-        x._UnPack(lstmattrs)
+    def InitFromObj(cls, lpNormalizationAttrs):
+        x = LpNormalizationAttrsT()
+        x._UnPack(lpNormalizationAttrs)
         return x
 
-    # LSTMAttrsT
-    def _UnPack(self, lstmattrs):
-        if lstmattrs is None:
+    # LpNormalizationAttrsT
+    def _UnPack(self, lpNormalizationAttrs):
+        if lpNormalizationAttrs is None:
             return
-        self.direction = lstmattrs.Direction()
-        self.hiddenSize = lstmattrs.HiddenSize()
+        self.axis = lpNormalizationAttrs.Axis()
+        self.p = lpNormalizationAttrs.P()
 
-    # LSTMAttrsT
+    # LpNormalizationAttrsT
     def Pack(self, builder):
-        LSTMAttrsStart(builder)
-        LSTMAttrsAddDirection(builder, self.direction)
-        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
-        lstmattrs = LSTMAttrsEnd(builder)
-        return lstmattrs
+        LpNormalizationAttrsStart(builder)
+        LpNormalizationAttrsAddAxis(builder, self.axis)
+        LpNormalizationAttrsAddP(builder, self.p)
+        lpNormalizationAttrs = LpNormalizationAttrsEnd(builder)
+        return lpNormalizationAttrs
 
 
-class MaxPoolAttrs(object):
+class LpPoolAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = MaxPoolAttrs()
+        x = LpPoolAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+    def GetRootAsLpPoolAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def KernelSize(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
@@ -2483,33 +4133,33 @@ class MaxPoolAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def KernelSizeAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def KernelSizeLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def KernelSizeIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def PadMode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def Pads(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
@@ -2517,26 +4167,26 @@ class MaxPoolAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def PadsAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def PadsLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def PadsIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         return o == 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def Strides(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
@@ -2544,50 +4194,103 @@ class MaxPoolAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def StridesAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def StridesLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
+    # LpPoolAttrs
     def StridesIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         return o == 0
 
-def MaxPoolAttrsStart(builder):
-    builder.StartObject(4)
+    # LpPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def MaxPoolAttrsAddKernelSize(builder, kernelSize):
+    # LpPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # LpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 2
+
+def LpPoolAttrsStart(builder):
+    builder.StartObject(7)
+
+def LpPoolAttrsAddKernelSize(builder, kernelSize):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
 
-def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
+def LpPoolAttrsStartKernelSizeVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsAddPadMode(builder, padMode):
+def LpPoolAttrsAddPadMode(builder, padMode):
     builder.PrependUint8Slot(1, padMode, 0)
 
-def MaxPoolAttrsAddPads(builder, pads):
+def LpPoolAttrsAddPads(builder, pads):
     builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
 
-def MaxPoolAttrsStartPadsVector(builder, numElems):
+def LpPoolAttrsStartPadsVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsAddStrides(builder, strides):
+def LpPoolAttrsAddStrides(builder, strides):
     builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
 
-def MaxPoolAttrsStartStridesVector(builder, numElems):
+def LpPoolAttrsStartStridesVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsEnd(builder):
+def LpPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(4, ceilMode, 0)
+
+def LpPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def LpPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddP(builder, p):
+    builder.PrependInt32Slot(6, p, 2)
+
+def LpPoolAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -2596,20 +4299,23 @@ try:
 except:
     pass
 
-class MaxPoolAttrsT(object):
+class LpPoolAttrsT(object):
 
-    # MaxPoolAttrsT
+    # LpPoolAttrsT
     def __init__(self):
         self.kernelSize = None  # type: List[int]
         self.padMode = 0  # type: int
         self.pads = None  # type: List[int]
         self.strides = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+        self.dilations = None  # type: List[int]
+        self.p = 2  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        maxPoolAttrs = MaxPoolAttrs()
-        maxPoolAttrs.Init(buf, pos)
-        return cls.InitFromObj(maxPoolAttrs)
+        lpPoolAttrs = LpPoolAttrs()
+        lpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpPoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2617,45 +4323,54 @@ class MaxPoolAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, maxPoolAttrs):
-        x = MaxPoolAttrsT()
-        x._UnPack(maxPoolAttrs)
+    def InitFromObj(cls, lpPoolAttrs):
+        x = LpPoolAttrsT()
+        x._UnPack(lpPoolAttrs)
         return x
 
-    # MaxPoolAttrsT
-    def _UnPack(self, maxPoolAttrs):
-        if maxPoolAttrs is None:
+    # LpPoolAttrsT
+    def _UnPack(self, lpPoolAttrs):
+        if lpPoolAttrs is None:
             return
-        if not maxPoolAttrs.KernelSizeIsNone():
+        if not lpPoolAttrs.KernelSizeIsNone():
             if np is None:
                 self.kernelSize = []
-                for i in range(maxPoolAttrs.KernelSizeLength()):
-                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
+                for i in range(lpPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(lpPoolAttrs.KernelSize(i))
             else:
-                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
-        self.padMode = maxPoolAttrs.PadMode()
-        if not maxPoolAttrs.PadsIsNone():
+                self.kernelSize = lpPoolAttrs.KernelSizeAsNumpy()
+        self.padMode = lpPoolAttrs.PadMode()
+        if not lpPoolAttrs.PadsIsNone():
             if np is None:
                 self.pads = []
-                for i in range(maxPoolAttrs.PadsLength()):
-                    self.pads.append(maxPoolAttrs.Pads(i))
+                for i in range(lpPoolAttrs.PadsLength()):
+                    self.pads.append(lpPoolAttrs.Pads(i))
             else:
-                self.pads = maxPoolAttrs.PadsAsNumpy()
-        if not maxPoolAttrs.StridesIsNone():
+                self.pads = lpPoolAttrs.PadsAsNumpy()
+        if not lpPoolAttrs.StridesIsNone():
             if np is None:
                 self.strides = []
-                for i in range(maxPoolAttrs.StridesLength()):
-                    self.strides.append(maxPoolAttrs.Strides(i))
+                for i in range(lpPoolAttrs.StridesLength()):
+                    self.strides.append(lpPoolAttrs.Strides(i))
             else:
-                self.strides = maxPoolAttrs.StridesAsNumpy()
+                self.strides = lpPoolAttrs.StridesAsNumpy()
+        self.ceilMode = lpPoolAttrs.CeilMode()
+        if not lpPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(lpPoolAttrs.DilationsLength()):
+                    self.dilations.append(lpPoolAttrs.Dilations(i))
+            else:
+                self.dilations = lpPoolAttrs.DilationsAsNumpy()
+        self.p = lpPoolAttrs.P()
 
-    # MaxPoolAttrsT
+    # LpPoolAttrsT
     def Pack(self, builder):
         if self.kernelSize is not None:
             if np is not None and type(self.kernelSize) is np.ndarray:
                 kernelSize = builder.CreateNumpyVector(self.kernelSize)
             else:
-                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                LpPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
                 for i in reversed(range(len(self.kernelSize))):
                     builder.PrependUint32(self.kernelSize[i])
                 kernelSize = builder.EndVector()
@@ -2663,7 +4378,7 @@ class MaxPoolAttrsT(object):
             if np is not None and type(self.pads) is np.ndarray:
                 pads = builder.CreateNumpyVector(self.pads)
             else:
-                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                LpPoolAttrsStartPadsVector(builder, len(self.pads))
                 for i in reversed(range(len(self.pads))):
                     builder.PrependUint32(self.pads[i])
                 pads = builder.EndVector()
@@ -2671,73 +4386,96 @@ class MaxPoolAttrsT(object):
             if np is not None and type(self.strides) is np.ndarray:
                 strides = builder.CreateNumpyVector(self.strides)
             else:
-                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                LpPoolAttrsStartStridesVector(builder, len(self.strides))
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
-        MaxPoolAttrsStart(builder)
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                LpPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        LpPoolAttrsStart(builder)
         if self.kernelSize is not None:
-            MaxPoolAttrsAddKernelSize(builder, kernelSize)
-        MaxPoolAttrsAddPadMode(builder, self.padMode)
+            LpPoolAttrsAddKernelSize(builder, kernelSize)
+        LpPoolAttrsAddPadMode(builder, self.padMode)
         if self.pads is not None:
-            MaxPoolAttrsAddPads(builder, pads)
+            LpPoolAttrsAddPads(builder, pads)
         if self.strides is not None:
-            MaxPoolAttrsAddStrides(builder, strides)
-        maxPoolAttrs = MaxPoolAttrsEnd(builder)
-        return maxPoolAttrs
+            LpPoolAttrsAddStrides(builder, strides)
+        LpPoolAttrsAddCeilMode(builder, self.ceilMode)
+        if self.dilations is not None:
+            LpPoolAttrsAddDilations(builder, dilations)
+        LpPoolAttrsAddP(builder, self.p)
+        lpPoolAttrs = LpPoolAttrsEnd(builder)
+        return lpPoolAttrs
 
 
-class ModAttrs(object):
+class LSTMAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ModAttrs()
+        x = LSTMAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsModAttrs(cls, buf, offset=0):
+    def GetRootAsLSTMAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ModAttrs
+    # LSTMAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ModAttrs
-    def Fmod(self):
+    # LSTMAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def ModAttrsStart(builder):
-    builder.StartObject(1)
+    # LSTMAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def ModAttrsAddFmod(builder, fmod):
-    builder.PrependBoolSlot(0, fmod, 0)
+def LSTMAttrsStart(builder):
+    builder.StartObject(2)
 
-def ModAttrsEnd(builder):
+def LSTMAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def LSTMAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def LSTMAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ModAttrsT(object):
+class LSTMAttrsT(object):
 
-    # ModAttrsT
+    # LSTMAttrsT
     def __init__(self):
-        self.fmod = False  # type: bool
+        self.direction = 0  # type: int
+        self.hiddenSize = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        modAttrs = ModAttrs()
-        modAttrs.Init(buf, pos)
-        return cls.InitFromObj(modAttrs)
+        lstmattrs = LSTMAttrs()
+        lstmattrs.Init(buf, pos)
+        return cls.InitFromObj(lstmattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2745,76 +4483,89 @@ class ModAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, modAttrs):
-        x = ModAttrsT()
-        x._UnPack(modAttrs)
+    def InitFromObj(cls, lstmattrs):
+        x = LSTMAttrsT()
+        x._UnPack(lstmattrs)
         return x
 
-    # ModAttrsT
-    def _UnPack(self, modAttrs):
-        if modAttrs is None:
+    # LSTMAttrsT
+    def _UnPack(self, lstmattrs):
+        if lstmattrs is None:
             return
-        self.fmod = modAttrs.Fmod()
+        self.direction = lstmattrs.Direction()
+        self.hiddenSize = lstmattrs.HiddenSize()
 
-    # ModAttrsT
+    # LSTMAttrsT
     def Pack(self, builder):
-        ModAttrsStart(builder)
-        ModAttrsAddFmod(builder, self.fmod)
-        modAttrs = ModAttrsEnd(builder)
-        return modAttrs
+        LSTMAttrsStart(builder)
+        LSTMAttrsAddDirection(builder, self.direction)
+        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
+        lstmattrs = LSTMAttrsEnd(builder)
+        return lstmattrs
 
 
-class NonMaxSuppressionAttrs(object):
+class MatMulNBitsAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = NonMaxSuppressionAttrs()
+        x = MatMulNBitsAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
+    def GetRootAsMatMulNBitsAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MatMulNBitsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # NonMaxSuppressionAttrs
+    # MatMulNBitsAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # NonMaxSuppressionAttrs
-    def BoxOrder(self):
+    # MatMulNBitsAttrs
+    def Bits(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 4
 
-def NonMaxSuppressionAttrsStart(builder):
-    builder.StartObject(1)
+    # MatMulNBitsAttrs
+    def BlockSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 128
 
-def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
-    builder.PrependUint8Slot(0, boxOrder, 0)
+def MatMulNBitsAttrsStart(builder):
+    builder.StartObject(2)
 
-def NonMaxSuppressionAttrsEnd(builder):
+def MatMulNBitsAttrsAddBits(builder, bits):
+    builder.PrependUint32Slot(0, bits, 4)
+
+def MatMulNBitsAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(1, blockSize, 128)
+
+def MatMulNBitsAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class NonMaxSuppressionAttrsT(object):
+class MatMulNBitsAttrsT(object):
 
-    # NonMaxSuppressionAttrsT
+    # MatMulNBitsAttrsT
     def __init__(self):
-        self.boxOrder = 0  # type: int
+        self.bits = 4  # type: int
+        self.blockSize = 128  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
-        nonMaxSuppressionAttrs.Init(buf, pos)
-        return cls.InitFromObj(nonMaxSuppressionAttrs)
+        matMulNbitsAttrs = MatMulNBitsAttrs()
+        matMulNbitsAttrs.Init(buf, pos)
+        return cls.InitFromObj(matMulNbitsAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2822,191 +4573,205 @@ class NonMaxSuppressionAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, nonMaxSuppressionAttrs):
-        x = NonMaxSuppressionAttrsT()
-        x._UnPack(nonMaxSuppressionAttrs)
+    def InitFromObj(cls, matMulNbitsAttrs):
+        x = MatMulNBitsAttrsT()
+        x._UnPack(matMulNbitsAttrs)
         return x
 
-    # NonMaxSuppressionAttrsT
-    def _UnPack(self, nonMaxSuppressionAttrs):
-        if nonMaxSuppressionAttrs is None:
+    # MatMulNBitsAttrsT
+    def _UnPack(self, matMulNbitsAttrs):
+        if matMulNbitsAttrs is None:
             return
-        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
+        self.bits = matMulNbitsAttrs.Bits()
+        self.blockSize = matMulNbitsAttrs.BlockSize()
 
-    # NonMaxSuppressionAttrsT
+    # MatMulNBitsAttrsT
     def Pack(self, builder):
-        NonMaxSuppressionAttrsStart(builder)
-        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
-        return nonMaxSuppressionAttrs
+        MatMulNBitsAttrsStart(builder)
+        MatMulNBitsAttrsAddBits(builder, self.bits)
+        MatMulNBitsAttrsAddBlockSize(builder, self.blockSize)
+        matMulNbitsAttrs = MatMulNBitsAttrsEnd(builder)
+        return matMulNbitsAttrs
 
 
-class OneHotAttrs(object):
+class MaxPoolAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = OneHotAttrs()
+        x = MaxPoolAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsOneHotAttrs(cls, buf, offset=0):
+    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # OneHotAttrs
+    # MaxPoolAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # OneHotAttrs
-    def Axis(self):
+    # MaxPoolAttrs
+    def KernelSize(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-def OneHotAttrsStart(builder):
-    builder.StartObject(1)
-
-def OneHotAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
-
-def OneHotAttrsEnd(builder):
-    return builder.EndObject()
-
-
-
-class OneHotAttrsT(object):
-
-    # OneHotAttrsT
-    def __init__(self):
-        self.axis = 0  # type: int
-
-    @classmethod
-    def InitFromBuf(cls, buf, pos):
-        oneHotAttrs = OneHotAttrs()
-        oneHotAttrs.Init(buf, pos)
-        return cls.InitFromObj(oneHotAttrs)
-
-    @classmethod
-    def InitFromPackedBuf(cls, buf, pos=0):
-        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
-        return cls.InitFromBuf(buf, pos+n)
-
-    @classmethod
-    def InitFromObj(cls, oneHotAttrs):
-        x = OneHotAttrsT()
-        x._UnPack(oneHotAttrs)
-        return x
-
-    # OneHotAttrsT
-    def _UnPack(self, oneHotAttrs):
-        if oneHotAttrs is None:
-            return
-        self.axis = oneHotAttrs.Axis()
-
-    # OneHotAttrsT
-    def Pack(self, builder):
-        OneHotAttrsStart(builder)
-        OneHotAttrsAddAxis(builder, self.axis)
-        oneHotAttrs = OneHotAttrsEnd(builder)
-        return oneHotAttrs
-
-
-class RandomNormalAttrs(object):
-    __slots__ = ['_tab']
+    # MaxPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-    @classmethod
-    def GetRootAs(cls, buf, offset=0):
-        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalAttrs()
-        x.Init(buf, n + offset)
-        return x
+    # MaxPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-    @classmethod
-    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
-        """This method is deprecated. Please switch to GetRootAs."""
-        return cls.GetRootAs(buf, offset)
-    @classmethod
-    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
-        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+    # MaxPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
 
-    # RandomNormalAttrs
-    def Init(self, buf, pos):
-        self._tab = flatbuffers.table.Table(buf, pos)
+    # MaxPoolAttrs
+    def PadMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomNormalAttrs
-    def Mean(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+    # MaxPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-    # RandomNormalAttrs
-    def Scale(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+    # MaxPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-    # RandomNormalAttrs
-    def Seed(self):
+    # MaxPoolAttrs
+    def PadsLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
+            return self._tab.VectorLen(o)
+        return 0
 
-    # RandomNormalAttrs
-    def Shape(self, j):
+    # MaxPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # MaxPoolAttrs
+    def Strides(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             a = self._tab.Vector(o)
             return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # RandomNormalAttrs
-    def ShapeAsNumpy(self):
+    # MaxPoolAttrs
+    def StridesAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
         return 0
 
-    # RandomNormalAttrs
-    def ShapeLength(self):
+    # MaxPoolAttrs
+    def StridesLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # RandomNormalAttrs
-    def ShapeIsNone(self):
+    # MaxPoolAttrs
+    def StridesIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         return o == 0
 
-def RandomNormalAttrsStart(builder):
-    builder.StartObject(4)
-
-def RandomNormalAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
+    # MaxPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def RandomNormalAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
+    # MaxPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def RandomNormalAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+    # MaxPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def RandomNormalAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+    # MaxPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def RandomNormalAttrsStartShapeVector(builder, numElems):
+    # MaxPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+def MaxPoolAttrsStart(builder):
+    builder.StartObject(6)
+
+def MaxPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def RandomNormalAttrsEnd(builder):
+def MaxPoolAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(1, padMode, 0)
+
+def MaxPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def MaxPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def MaxPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(4, ceilMode, 0)
+
+def MaxPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def MaxPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -3015,20 +4780,22 @@ try:
 except:
     pass
 
-class RandomNormalAttrsT(object):
+class MaxPoolAttrsT(object):
 
-    # RandomNormalAttrsT
+    # MaxPoolAttrsT
     def __init__(self):
-        self.mean = 0.0  # type: float
-        self.scale = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
-        self.shape = None  # type: List[int]
+        self.kernelSize = None  # type: List[int]
+        self.padMode = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.strides = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+        self.dilations = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalAttrs = RandomNormalAttrs()
-        randomNormalAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalAttrs)
+        maxPoolAttrs = MaxPoolAttrs()
+        maxPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(maxPoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3036,119 +4803,2362 @@ class RandomNormalAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalAttrs):
-        x = RandomNormalAttrsT()
-        x._UnPack(randomNormalAttrs)
+    def InitFromObj(cls, maxPoolAttrs):
+        x = MaxPoolAttrsT()
+        x._UnPack(maxPoolAttrs)
         return x
 
-    # RandomNormalAttrsT
-    def _UnPack(self, randomNormalAttrs):
-        if randomNormalAttrs is None:
+    # MaxPoolAttrsT
+    def _UnPack(self, maxPoolAttrs):
+        if maxPoolAttrs is None:
             return
-        self.mean = randomNormalAttrs.Mean()
-        self.scale = randomNormalAttrs.Scale()
-        self.seed = randomNormalAttrs.Seed()
-        if not randomNormalAttrs.ShapeIsNone():
+        if not maxPoolAttrs.KernelSizeIsNone():
             if np is None:
-                self.shape = []
-                for i in range(randomNormalAttrs.ShapeLength()):
-                    self.shape.append(randomNormalAttrs.Shape(i))
+                self.kernelSize = []
+                for i in range(maxPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
             else:
-                self.shape = randomNormalAttrs.ShapeAsNumpy()
+                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
+        self.padMode = maxPoolAttrs.PadMode()
+        if not maxPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(maxPoolAttrs.PadsLength()):
+                    self.pads.append(maxPoolAttrs.Pads(i))
+            else:
+                self.pads = maxPoolAttrs.PadsAsNumpy()
+        if not maxPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(maxPoolAttrs.StridesLength()):
+                    self.strides.append(maxPoolAttrs.Strides(i))
+            else:
+                self.strides = maxPoolAttrs.StridesAsNumpy()
+        self.ceilMode = maxPoolAttrs.CeilMode()
+        if not maxPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(maxPoolAttrs.DilationsLength()):
+                    self.dilations.append(maxPoolAttrs.Dilations(i))
+            else:
+                self.dilations = maxPoolAttrs.DilationsAsNumpy()
 
-    # RandomNormalAttrsT
+    # MaxPoolAttrsT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
             else:
-                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomNormalAttrsStart(builder)
-        RandomNormalAttrsAddMean(builder, self.mean)
-        RandomNormalAttrsAddScale(builder, self.scale)
-        RandomNormalAttrsAddSeed(builder, self.seed)
-        if self.shape is not None:
-            RandomNormalAttrsAddShape(builder, shape)
-        randomNormalAttrs = RandomNormalAttrsEnd(builder)
-        return randomNormalAttrs
+                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                MaxPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        MaxPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            MaxPoolAttrsAddKernelSize(builder, kernelSize)
+        MaxPoolAttrsAddPadMode(builder, self.padMode)
+        if self.pads is not None:
+            MaxPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            MaxPoolAttrsAddStrides(builder, strides)
+        MaxPoolAttrsAddCeilMode(builder, self.ceilMode)
+        if self.dilations is not None:
+            MaxPoolAttrsAddDilations(builder, dilations)
+        maxPoolAttrs = MaxPoolAttrsEnd(builder)
+        return maxPoolAttrs
 
 
-class RandomNormalLikeAttrs(object):
+class MeanVarianceNormalizationAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalLikeAttrs()
+        x = MeanVarianceNormalizationAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
+    def GetRootAsMeanVarianceNormalizationAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MeanVarianceNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomNormalLikeAttrs
+    # MeanVarianceNormalizationAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomNormalLikeAttrs
-    def Mean(self):
+    # MeanVarianceNormalizationAttrs
+    def Axes(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-    # RandomNormalLikeAttrs
-    def Scale(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+    # MeanVarianceNormalizationAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
 
-    # RandomNormalLikeAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+    # MeanVarianceNormalizationAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
+            return self._tab.VectorLen(o)
+        return 0
 
-def RandomNormalLikeAttrsStart(builder):
-    builder.StartObject(3)
+    # MeanVarianceNormalizationAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
 
-def RandomNormalLikeAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
+def MeanVarianceNormalizationAttrsStart(builder):
+    builder.StartObject(1)
+
+def MeanVarianceNormalizationAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+
+def MeanVarianceNormalizationAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MeanVarianceNormalizationAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class MeanVarianceNormalizationAttrsT(object):
+
+    # MeanVarianceNormalizationAttrsT
+    def __init__(self):
+        self.axes = None  # type: List[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        meanVarianceNormalizationAttrs = MeanVarianceNormalizationAttrs()
+        meanVarianceNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(meanVarianceNormalizationAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, meanVarianceNormalizationAttrs):
+        x = MeanVarianceNormalizationAttrsT()
+        x._UnPack(meanVarianceNormalizationAttrs)
+        return x
+
+    # MeanVarianceNormalizationAttrsT
+    def _UnPack(self, meanVarianceNormalizationAttrs):
+        if meanVarianceNormalizationAttrs is None:
+            return
+        if not meanVarianceNormalizationAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(meanVarianceNormalizationAttrs.AxesLength()):
+                    self.axes.append(meanVarianceNormalizationAttrs.Axes(i))
+            else:
+                self.axes = meanVarianceNormalizationAttrs.AxesAsNumpy()
+
+    # MeanVarianceNormalizationAttrsT
+    def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                MeanVarianceNormalizationAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        MeanVarianceNormalizationAttrsStart(builder)
+        if self.axes is not None:
+            MeanVarianceNormalizationAttrsAddAxes(builder, axes)
+        meanVarianceNormalizationAttrs = MeanVarianceNormalizationAttrsEnd(builder)
+        return meanVarianceNormalizationAttrs
+
+
+class ModAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ModAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsModAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ModAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ModAttrs
+    def Fmod(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def ModAttrsStart(builder):
+    builder.StartObject(1)
+
+def ModAttrsAddFmod(builder, fmod):
+    builder.PrependBoolSlot(0, fmod, 0)
+
+def ModAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ModAttrsT(object):
+
+    # ModAttrsT
+    def __init__(self):
+        self.fmod = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        modAttrs = ModAttrs()
+        modAttrs.Init(buf, pos)
+        return cls.InitFromObj(modAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, modAttrs):
+        x = ModAttrsT()
+        x._UnPack(modAttrs)
+        return x
+
+    # ModAttrsT
+    def _UnPack(self, modAttrs):
+        if modAttrs is None:
+            return
+        self.fmod = modAttrs.Fmod()
+
+    # ModAttrsT
+    def Pack(self, builder):
+        ModAttrsStart(builder)
+        ModAttrsAddFmod(builder, self.fmod)
+        modAttrs = ModAttrsEnd(builder)
+        return modAttrs
+
+
+class MultinomialAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MultinomialAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMultinomialAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def MultinomialAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # MultinomialAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MultinomialAttrs
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # MultinomialAttrs
+    def SampleSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 1
+
+    # MultinomialAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def MultinomialAttrsStart(builder):
+    builder.StartObject(3)
+
+def MultinomialAttrsAddDtype(builder, dtype):
+    builder.PrependUint8Slot(0, dtype, 0)
+
+def MultinomialAttrsAddSampleSize(builder, sampleSize):
+    builder.PrependInt32Slot(1, sampleSize, 1)
+
+def MultinomialAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def MultinomialAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class MultinomialAttrsT(object):
+
+    # MultinomialAttrsT
+    def __init__(self):
+        self.dtype = 0  # type: int
+        self.sampleSize = 1  # type: int
+        self.seed = None  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        multinomialAttrs = MultinomialAttrs()
+        multinomialAttrs.Init(buf, pos)
+        return cls.InitFromObj(multinomialAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, multinomialAttrs):
+        x = MultinomialAttrsT()
+        x._UnPack(multinomialAttrs)
+        return x
+
+    # MultinomialAttrsT
+    def _UnPack(self, multinomialAttrs):
+        if multinomialAttrs is None:
+            return
+        self.dtype = multinomialAttrs.Dtype()
+        self.sampleSize = multinomialAttrs.SampleSize()
+        self.seed = multinomialAttrs.Seed()
+
+    # MultinomialAttrsT
+    def Pack(self, builder):
+        MultinomialAttrsStart(builder)
+        MultinomialAttrsAddDtype(builder, self.dtype)
+        MultinomialAttrsAddSampleSize(builder, self.sampleSize)
+        MultinomialAttrsAddSeed(builder, self.seed)
+        multinomialAttrs = MultinomialAttrsEnd(builder)
+        return multinomialAttrs
+
+
+class NonMaxSuppressionAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = NonMaxSuppressionAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # NonMaxSuppressionAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # NonMaxSuppressionAttrs
+    def BoxOrder(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def NonMaxSuppressionAttrsStart(builder):
+    builder.StartObject(1)
+
+def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
+    builder.PrependUint8Slot(0, boxOrder, 0)
+
+def NonMaxSuppressionAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class NonMaxSuppressionAttrsT(object):
+
+    # NonMaxSuppressionAttrsT
+    def __init__(self):
+        self.boxOrder = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
+        nonMaxSuppressionAttrs.Init(buf, pos)
+        return cls.InitFromObj(nonMaxSuppressionAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, nonMaxSuppressionAttrs):
+        x = NonMaxSuppressionAttrsT()
+        x._UnPack(nonMaxSuppressionAttrs)
+        return x
+
+    # NonMaxSuppressionAttrsT
+    def _UnPack(self, nonMaxSuppressionAttrs):
+        if nonMaxSuppressionAttrs is None:
+            return
+        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
+
+    # NonMaxSuppressionAttrsT
+    def Pack(self, builder):
+        NonMaxSuppressionAttrsStart(builder)
+        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
+        return nonMaxSuppressionAttrs
+
+
+class NormalizeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = NormalizeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsNormalizeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def NormalizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # NormalizeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # NormalizeAttrs
+    def Mean(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # NormalizeAttrs
+    def MeanAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
+
+    # NormalizeAttrs
+    def MeanLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # NormalizeAttrs
+    def MeanIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # NormalizeAttrs
+    def Std(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # NormalizeAttrs
+    def StdAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
+
+    # NormalizeAttrs
+    def StdLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # NormalizeAttrs
+    def StdIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+def NormalizeAttrsStart(builder):
+    builder.StartObject(2)
+
+def NormalizeAttrsAddMean(builder, mean):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(mean), 0)
+
+def NormalizeAttrsStartMeanVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def NormalizeAttrsAddStd(builder, std):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(std), 0)
+
+def NormalizeAttrsStartStdVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def NormalizeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class NormalizeAttrsT(object):
+
+    # NormalizeAttrsT
+    def __init__(self):
+        self.mean = None  # type: List[float]
+        self.std = None  # type: List[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        normalizeAttrs = NormalizeAttrs()
+        normalizeAttrs.Init(buf, pos)
+        return cls.InitFromObj(normalizeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, normalizeAttrs):
+        x = NormalizeAttrsT()
+        x._UnPack(normalizeAttrs)
+        return x
+
+    # NormalizeAttrsT
+    def _UnPack(self, normalizeAttrs):
+        if normalizeAttrs is None:
+            return
+        if not normalizeAttrs.MeanIsNone():
+            if np is None:
+                self.mean = []
+                for i in range(normalizeAttrs.MeanLength()):
+                    self.mean.append(normalizeAttrs.Mean(i))
+            else:
+                self.mean = normalizeAttrs.MeanAsNumpy()
+        if not normalizeAttrs.StdIsNone():
+            if np is None:
+                self.std = []
+                for i in range(normalizeAttrs.StdLength()):
+                    self.std.append(normalizeAttrs.Std(i))
+            else:
+                self.std = normalizeAttrs.StdAsNumpy()
+
+    # NormalizeAttrsT
+    def Pack(self, builder):
+        if self.mean is not None:
+            if np is not None and type(self.mean) is np.ndarray:
+                mean = builder.CreateNumpyVector(self.mean)
+            else:
+                NormalizeAttrsStartMeanVector(builder, len(self.mean))
+                for i in reversed(range(len(self.mean))):
+                    builder.PrependFloat32(self.mean[i])
+                mean = builder.EndVector()
+        if self.std is not None:
+            if np is not None and type(self.std) is np.ndarray:
+                std = builder.CreateNumpyVector(self.std)
+            else:
+                NormalizeAttrsStartStdVector(builder, len(self.std))
+                for i in reversed(range(len(self.std))):
+                    builder.PrependFloat32(self.std[i])
+                std = builder.EndVector()
+        NormalizeAttrsStart(builder)
+        if self.mean is not None:
+            NormalizeAttrsAddMean(builder, mean)
+        if self.std is not None:
+            NormalizeAttrsAddStd(builder, std)
+        normalizeAttrs = NormalizeAttrsEnd(builder)
+        return normalizeAttrs
+
+
+class OneHotAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = OneHotAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsOneHotAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # OneHotAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # OneHotAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def OneHotAttrsStart(builder):
+    builder.StartObject(1)
+
+def OneHotAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def OneHotAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class OneHotAttrsT(object):
+
+    # OneHotAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        oneHotAttrs = OneHotAttrs()
+        oneHotAttrs.Init(buf, pos)
+        return cls.InitFromObj(oneHotAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, oneHotAttrs):
+        x = OneHotAttrsT()
+        x._UnPack(oneHotAttrs)
+        return x
+
+    # OneHotAttrsT
+    def _UnPack(self, oneHotAttrs):
+        if oneHotAttrs is None:
+            return
+        self.axis = oneHotAttrs.Axis()
+
+    # OneHotAttrsT
+    def Pack(self, builder):
+        OneHotAttrsStart(builder)
+        OneHotAttrsAddAxis(builder, self.axis)
+        oneHotAttrs = OneHotAttrsEnd(builder)
+        return oneHotAttrs
+
+
+class PadAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PadAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPadAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def PadAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # PadAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PadAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def PadAttrsStart(builder):
+    builder.StartObject(1)
+
+def PadAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def PadAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class PadAttrsT(object):
+
+    # PadAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        padAttrs = PadAttrs()
+        padAttrs.Init(buf, pos)
+        return cls.InitFromObj(padAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, padAttrs):
+        x = PadAttrsT()
+        x._UnPack(padAttrs)
+        return x
+
+    # PadAttrsT
+    def _UnPack(self, padAttrs):
+        if padAttrs is None:
+            return
+        self.mode = padAttrs.Mode()
+
+    # PadAttrsT
+    def Pack(self, builder):
+        PadAttrsStart(builder)
+        PadAttrsAddMode(builder, self.mode)
+        padAttrs = PadAttrsEnd(builder)
+        return padAttrs
+
+
+class PermuteChannelsAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PermuteChannelsAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPermuteChannelsAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def PermuteChannelsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # PermuteChannelsAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PermuteChannelsAttrs
+    def Order(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # PermuteChannelsAttrs
+    def OrderAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # PermuteChannelsAttrs
+    def OrderLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # PermuteChannelsAttrs
+    def OrderIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def PermuteChannelsAttrsStart(builder):
+    builder.StartObject(1)
+
+def PermuteChannelsAttrsAddOrder(builder, order):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(order), 0)
+
+def PermuteChannelsAttrsStartOrderVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def PermuteChannelsAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class PermuteChannelsAttrsT(object):
+
+    # PermuteChannelsAttrsT
+    def __init__(self):
+        self.order = None  # type: List[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        permuteChannelsAttrs = PermuteChannelsAttrs()
+        permuteChannelsAttrs.Init(buf, pos)
+        return cls.InitFromObj(permuteChannelsAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, permuteChannelsAttrs):
+        x = PermuteChannelsAttrsT()
+        x._UnPack(permuteChannelsAttrs)
+        return x
+
+    # PermuteChannelsAttrsT
+    def _UnPack(self, permuteChannelsAttrs):
+        if permuteChannelsAttrs is None:
+            return
+        if not permuteChannelsAttrs.OrderIsNone():
+            if np is None:
+                self.order = []
+                for i in range(permuteChannelsAttrs.OrderLength()):
+                    self.order.append(permuteChannelsAttrs.Order(i))
+            else:
+                self.order = permuteChannelsAttrs.OrderAsNumpy()
+
+    # PermuteChannelsAttrsT
+    def Pack(self, builder):
+        if self.order is not None:
+            if np is not None and type(self.order) is np.ndarray:
+                order = builder.CreateNumpyVector(self.order)
+            else:
+                PermuteChannelsAttrsStartOrderVector(builder, len(self.order))
+                for i in reversed(range(len(self.order))):
+                    builder.PrependUint32(self.order[i])
+                order = builder.EndVector()
+        PermuteChannelsAttrsStart(builder)
+        if self.order is not None:
+            PermuteChannelsAttrsAddOrder(builder, order)
+        permuteChannelsAttrs = PermuteChannelsAttrsEnd(builder)
+        return permuteChannelsAttrs
+
+
+class QLinearConvAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = QLinearConvAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsQLinearConvAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def QLinearConvAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # QLinearConvAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # QLinearConvAttrs
+    def PadMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # QLinearConvAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # QLinearConvAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # QLinearConvAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # QLinearConvAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+    # QLinearConvAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # QLinearConvAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # QLinearConvAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # QLinearConvAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # QLinearConvAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # QLinearConvAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # QLinearConvAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # QLinearConvAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # QLinearConvAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # QLinearConvAttrs
+    def OutputDtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 5
+
+def QLinearConvAttrsStart(builder):
+    builder.StartObject(6)
+
+def QLinearConvAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(0, padMode, 0)
+
+def QLinearConvAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def QLinearConvAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def QLinearConvAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(2, groups, 0)
+
+def QLinearConvAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def QLinearConvAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def QLinearConvAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def QLinearConvAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def QLinearConvAttrsAddOutputDtype(builder, outputDtype):
+    builder.PrependUint8Slot(5, outputDtype, 5)
+
+def QLinearConvAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class QLinearConvAttrsT(object):
+
+    # QLinearConvAttrsT
+    def __init__(self):
+        self.padMode = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.groups = 0  # type: int
+        self.strides = None  # type: List[int]
+        self.dilations = None  # type: List[int]
+        self.outputDtype = 5  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        qLinearConvAttrs = QLinearConvAttrs()
+        qLinearConvAttrs.Init(buf, pos)
+        return cls.InitFromObj(qLinearConvAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, qLinearConvAttrs):
+        x = QLinearConvAttrsT()
+        x._UnPack(qLinearConvAttrs)
+        return x
+
+    # QLinearConvAttrsT
+    def _UnPack(self, qLinearConvAttrs):
+        if qLinearConvAttrs is None:
+            return
+        self.padMode = qLinearConvAttrs.PadMode()
+        if not qLinearConvAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(qLinearConvAttrs.PadsLength()):
+                    self.pads.append(qLinearConvAttrs.Pads(i))
+            else:
+                self.pads = qLinearConvAttrs.PadsAsNumpy()
+        self.groups = qLinearConvAttrs.Groups()
+        if not qLinearConvAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(qLinearConvAttrs.StridesLength()):
+                    self.strides.append(qLinearConvAttrs.Strides(i))
+            else:
+                self.strides = qLinearConvAttrs.StridesAsNumpy()
+        if not qLinearConvAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(qLinearConvAttrs.DilationsLength()):
+                    self.dilations.append(qLinearConvAttrs.Dilations(i))
+            else:
+                self.dilations = qLinearConvAttrs.DilationsAsNumpy()
+        self.outputDtype = qLinearConvAttrs.OutputDtype()
+
+    # QLinearConvAttrsT
+    def Pack(self, builder):
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                QLinearConvAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                QLinearConvAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                QLinearConvAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        QLinearConvAttrsStart(builder)
+        QLinearConvAttrsAddPadMode(builder, self.padMode)
+        if self.pads is not None:
+            QLinearConvAttrsAddPads(builder, pads)
+        QLinearConvAttrsAddGroups(builder, self.groups)
+        if self.strides is not None:
+            QLinearConvAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            QLinearConvAttrsAddDilations(builder, dilations)
+        QLinearConvAttrsAddOutputDtype(builder, self.outputDtype)
+        qLinearConvAttrs = QLinearConvAttrsEnd(builder)
+        return qLinearConvAttrs
+
+
+class QLinearMatMulAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = QLinearMatMulAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsQLinearMatMulAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def QLinearMatMulAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # QLinearMatMulAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # QLinearMatMulAttrs
+    def OutputDtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 5
+
+def QLinearMatMulAttrsStart(builder):
+    builder.StartObject(1)
+
+def QLinearMatMulAttrsAddOutputDtype(builder, outputDtype):
+    builder.PrependUint8Slot(0, outputDtype, 5)
+
+def QLinearMatMulAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class QLinearMatMulAttrsT(object):
+
+    # QLinearMatMulAttrsT
+    def __init__(self):
+        self.outputDtype = 5  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        qLinearMatMulAttrs = QLinearMatMulAttrs()
+        qLinearMatMulAttrs.Init(buf, pos)
+        return cls.InitFromObj(qLinearMatMulAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, qLinearMatMulAttrs):
+        x = QLinearMatMulAttrsT()
+        x._UnPack(qLinearMatMulAttrs)
+        return x
+
+    # QLinearMatMulAttrsT
+    def _UnPack(self, qLinearMatMulAttrs):
+        if qLinearMatMulAttrs is None:
+            return
+        self.outputDtype = qLinearMatMulAttrs.OutputDtype()
+
+    # QLinearMatMulAttrsT
+    def Pack(self, builder):
+        QLinearMatMulAttrsStart(builder)
+        QLinearMatMulAttrsAddOutputDtype(builder, self.outputDtype)
+        qLinearMatMulAttrs = QLinearMatMulAttrsEnd(builder)
+        return qLinearMatMulAttrs
+
+
+class RandomNormalAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = RandomNormalAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # RandomNormalAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # RandomNormalAttrs
+    def Mean(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomNormalAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomNormalAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # RandomNormalAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+def RandomNormalAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomNormalAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
+
+def RandomNormalAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
+
+def RandomNormalAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomNormalAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def RandomNormalAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomNormalAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class RandomNormalAttrsT(object):
+
+    # RandomNormalAttrsT
+    def __init__(self):
+        self.mean = 0.0  # type: float
+        self.scale = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
+        self.shape = None  # type: List[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        randomNormalAttrs = RandomNormalAttrs()
+        randomNormalAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, randomNormalAttrs):
+        x = RandomNormalAttrsT()
+        x._UnPack(randomNormalAttrs)
+        return x
+
+    # RandomNormalAttrsT
+    def _UnPack(self, randomNormalAttrs):
+        if randomNormalAttrs is None:
+            return
+        self.mean = randomNormalAttrs.Mean()
+        self.scale = randomNormalAttrs.Scale()
+        self.seed = randomNormalAttrs.Seed()
+        if not randomNormalAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomNormalAttrs.ShapeLength()):
+                    self.shape.append(randomNormalAttrs.Shape(i))
+            else:
+                self.shape = randomNormalAttrs.ShapeAsNumpy()
+
+    # RandomNormalAttrsT
+    def Pack(self, builder):
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomNormalAttrsStart(builder)
+        RandomNormalAttrsAddMean(builder, self.mean)
+        RandomNormalAttrsAddScale(builder, self.scale)
+        RandomNormalAttrsAddSeed(builder, self.seed)
+        if self.shape is not None:
+            RandomNormalAttrsAddShape(builder, shape)
+        randomNormalAttrs = RandomNormalAttrsEnd(builder)
+        return randomNormalAttrs
+
+
+class RandomNormalLikeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = RandomNormalLikeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # RandomNormalLikeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # RandomNormalLikeAttrs
+    def Mean(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomNormalLikeAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomNormalLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def RandomNormalLikeAttrsStart(builder):
+    builder.StartObject(3)
+
+def RandomNormalLikeAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
+
+def RandomNormalLikeAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
+
+def RandomNormalLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomNormalLikeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class RandomNormalLikeAttrsT(object):
+
+    # RandomNormalLikeAttrsT
+    def __init__(self):
+        self.mean = 0.0  # type: float
+        self.scale = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        randomNormalLikeAttrs = RandomNormalLikeAttrs()
+        randomNormalLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalLikeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, randomNormalLikeAttrs):
+        x = RandomNormalLikeAttrsT()
+        x._UnPack(randomNormalLikeAttrs)
+        return x
+
+    # RandomNormalLikeAttrsT
+    def _UnPack(self, randomNormalLikeAttrs):
+        if randomNormalLikeAttrs is None:
+            return
+        self.mean = randomNormalLikeAttrs.Mean()
+        self.scale = randomNormalLikeAttrs.Scale()
+        self.seed = randomNormalLikeAttrs.Seed()
+
+    # RandomNormalLikeAttrsT
+    def Pack(self, builder):
+        RandomNormalLikeAttrsStart(builder)
+        RandomNormalLikeAttrsAddMean(builder, self.mean)
+        RandomNormalLikeAttrsAddScale(builder, self.scale)
+        RandomNormalLikeAttrsAddSeed(builder, self.seed)
+        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
+        return randomNormalLikeAttrs
+
+
+class RandomUniformAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = RandomUniformAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # RandomUniformAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # RandomUniformAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # RandomUniformAttrs
+    def High(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomUniformAttrs
+    def Low(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomUniformAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def RandomUniformAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomUniformAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def RandomUniformAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomUniformAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(1, high, 0.0)
+
+def RandomUniformAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(2, low, 0.0)
+
+def RandomUniformAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(3, seed, None)
+
+def RandomUniformAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class RandomUniformAttrsT(object):
+
+    # RandomUniformAttrsT
+    def __init__(self):
+        self.shape = None  # type: List[int]
+        self.high = 0.0  # type: float
+        self.low = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        randomUniformAttrs = RandomUniformAttrs()
+        randomUniformAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, randomUniformAttrs):
+        x = RandomUniformAttrsT()
+        x._UnPack(randomUniformAttrs)
+        return x
+
+    # RandomUniformAttrsT
+    def _UnPack(self, randomUniformAttrs):
+        if randomUniformAttrs is None:
+            return
+        if not randomUniformAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomUniformAttrs.ShapeLength()):
+                    self.shape.append(randomUniformAttrs.Shape(i))
+            else:
+                self.shape = randomUniformAttrs.ShapeAsNumpy()
+        self.high = randomUniformAttrs.High()
+        self.low = randomUniformAttrs.Low()
+        self.seed = randomUniformAttrs.Seed()
+
+    # RandomUniformAttrsT
+    def Pack(self, builder):
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomUniformAttrsStart(builder)
+        if self.shape is not None:
+            RandomUniformAttrsAddShape(builder, shape)
+        RandomUniformAttrsAddHigh(builder, self.high)
+        RandomUniformAttrsAddLow(builder, self.low)
+        RandomUniformAttrsAddSeed(builder, self.seed)
+        randomUniformAttrs = RandomUniformAttrsEnd(builder)
+        return randomUniformAttrs
+
+
+class RandomUniformLikeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = RandomUniformLikeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # RandomUniformLikeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # RandomUniformLikeAttrs
+    def High(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomUniformLikeAttrs
+    def Low(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomUniformLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def RandomUniformLikeAttrsStart(builder):
+    builder.StartObject(3)
+
+def RandomUniformLikeAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(0, high, 0.0)
+
+def RandomUniformLikeAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(1, low, 0.0)
+
+def RandomUniformLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomUniformLikeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class RandomUniformLikeAttrsT(object):
+
+    # RandomUniformLikeAttrsT
+    def __init__(self):
+        self.high = 0.0  # type: float
+        self.low = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        randomUniformLikeAttrs = RandomUniformLikeAttrs()
+        randomUniformLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformLikeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, randomUniformLikeAttrs):
+        x = RandomUniformLikeAttrsT()
+        x._UnPack(randomUniformLikeAttrs)
+        return x
+
+    # RandomUniformLikeAttrsT
+    def _UnPack(self, randomUniformLikeAttrs):
+        if randomUniformLikeAttrs is None:
+            return
+        self.high = randomUniformLikeAttrs.High()
+        self.low = randomUniformLikeAttrs.Low()
+        self.seed = randomUniformLikeAttrs.Seed()
+
+    # RandomUniformLikeAttrsT
+    def Pack(self, builder):
+        RandomUniformLikeAttrsStart(builder)
+        RandomUniformLikeAttrsAddHigh(builder, self.high)
+        RandomUniformLikeAttrsAddLow(builder, self.low)
+        RandomUniformLikeAttrsAddSeed(builder, self.seed)
+        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
+        return randomUniformLikeAttrs
+
+
+class ReduceMeanAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ReduceMeanAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ReduceMeanAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ReduceMeanAttrs
+    def Axes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ReduceMeanAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ReduceMeanAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ReduceMeanAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # ReduceMeanAttrs
+    def KeepDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def ReduceMeanAttrsStart(builder):
+    builder.StartObject(2)
+
+def ReduceMeanAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+
+def ReduceMeanAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ReduceMeanAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 0)
+
+def ReduceMeanAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class ReduceMeanAttrsT(object):
+
+    # ReduceMeanAttrsT
+    def __init__(self):
+        self.axes = None  # type: List[int]
+        self.keepDims = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        reduceMeanAttrs = ReduceMeanAttrs()
+        reduceMeanAttrs.Init(buf, pos)
+        return cls.InitFromObj(reduceMeanAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, reduceMeanAttrs):
+        x = ReduceMeanAttrsT()
+        x._UnPack(reduceMeanAttrs)
+        return x
+
+    # ReduceMeanAttrsT
+    def _UnPack(self, reduceMeanAttrs):
+        if reduceMeanAttrs is None:
+            return
+        if not reduceMeanAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(reduceMeanAttrs.AxesLength()):
+                    self.axes.append(reduceMeanAttrs.Axes(i))
+            else:
+                self.axes = reduceMeanAttrs.AxesAsNumpy()
+        self.keepDims = reduceMeanAttrs.KeepDims()
+
+    # ReduceMeanAttrsT
+    def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        ReduceMeanAttrsStart(builder)
+        if self.axes is not None:
+            ReduceMeanAttrsAddAxes(builder, axes)
+        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
+        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
+        return reduceMeanAttrs
+
+
+class ReduceVarAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ReduceVarAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsReduceVarAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ReduceVarAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ReduceVarAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ReduceVarAttrs
+    def Axes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ReduceVarAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ReduceVarAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ReduceVarAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # ReduceVarAttrs
+    def KeepDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # ReduceVarAttrs
+    def Correction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+def ReduceVarAttrsStart(builder):
+    builder.StartObject(3)
+
+def ReduceVarAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+
+def ReduceVarAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ReduceVarAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 0)
+
+def ReduceVarAttrsAddCorrection(builder, correction):
+    builder.PrependUint32Slot(2, correction, 0)
+
+def ReduceVarAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class ReduceVarAttrsT(object):
+
+    # ReduceVarAttrsT
+    def __init__(self):
+        self.axes = None  # type: List[int]
+        self.keepDims = False  # type: bool
+        self.correction = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        reduceVarAttrs = ReduceVarAttrs()
+        reduceVarAttrs.Init(buf, pos)
+        return cls.InitFromObj(reduceVarAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, reduceVarAttrs):
+        x = ReduceVarAttrsT()
+        x._UnPack(reduceVarAttrs)
+        return x
+
+    # ReduceVarAttrsT
+    def _UnPack(self, reduceVarAttrs):
+        if reduceVarAttrs is None:
+            return
+        if not reduceVarAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(reduceVarAttrs.AxesLength()):
+                    self.axes.append(reduceVarAttrs.Axes(i))
+            else:
+                self.axes = reduceVarAttrs.AxesAsNumpy()
+        self.keepDims = reduceVarAttrs.KeepDims()
+        self.correction = reduceVarAttrs.Correction()
+
+    # ReduceVarAttrsT
+    def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                ReduceVarAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        ReduceVarAttrsStart(builder)
+        if self.axes is not None:
+            ReduceVarAttrsAddAxes(builder, axes)
+        ReduceVarAttrsAddKeepDims(builder, self.keepDims)
+        ReduceVarAttrsAddCorrection(builder, self.correction)
+        reduceVarAttrs = ReduceVarAttrsEnd(builder)
+        return reduceVarAttrs
+
+
+class ReshapeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ReshapeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsReshapeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ReshapeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ReshapeAttrs
+    def AllowZero(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def ReshapeAttrsStart(builder):
+    builder.StartObject(1)
+
+def ReshapeAttrsAddAllowZero(builder, allowZero):
+    builder.PrependBoolSlot(0, allowZero, 0)
+
+def ReshapeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ReshapeAttrsT(object):
+
+    # ReshapeAttrsT
+    def __init__(self):
+        self.allowZero = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        reshapeAttrs = ReshapeAttrs()
+        reshapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(reshapeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, reshapeAttrs):
+        x = ReshapeAttrsT()
+        x._UnPack(reshapeAttrs)
+        return x
+
+    # ReshapeAttrsT
+    def _UnPack(self, reshapeAttrs):
+        if reshapeAttrs is None:
+            return
+        self.allowZero = reshapeAttrs.AllowZero()
+
+    # ReshapeAttrsT
+    def Pack(self, builder):
+        ReshapeAttrsStart(builder)
+        ReshapeAttrsAddAllowZero(builder, self.allowZero)
+        reshapeAttrs = ReshapeAttrsEnd(builder)
+        return reshapeAttrs
+
+
+class ResizeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ResizeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsResizeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ResizeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ResizeAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ResizeAttrs
+    def CoordMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ResizeAttrs
+    def NearestMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ResizeAttrs
+    def Antialias(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # ResizeAttrs
+    def Axes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ResizeAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ResizeAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ResizeAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # ResizeAttrs
+    def ExtrapolationValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def ResizeAttrsStart(builder):
+    builder.StartObject(6)
+
+def ResizeAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def ResizeAttrsAddCoordMode(builder, coordMode):
+    builder.PrependUint8Slot(1, coordMode, 0)
+
+def ResizeAttrsAddNearestMode(builder, nearestMode):
+    builder.PrependUint8Slot(2, nearestMode, 0)
+
+def ResizeAttrsAddAntialias(builder, antialias):
+    builder.PrependBoolSlot(3, antialias, 0)
+
+def ResizeAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+
+def ResizeAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ResizeAttrsAddExtrapolationValue(builder, extrapolationValue):
+    builder.PrependFloat32Slot(5, extrapolationValue, 0.0)
+
+def ResizeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class ResizeAttrsT(object):
+
+    # ResizeAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+        self.coordMode = 0  # type: int
+        self.nearestMode = 0  # type: int
+        self.antialias = False  # type: bool
+        self.axes = None  # type: List[int]
+        self.extrapolationValue = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        resizeAttrs = ResizeAttrs()
+        resizeAttrs.Init(buf, pos)
+        return cls.InitFromObj(resizeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, resizeAttrs):
+        x = ResizeAttrsT()
+        x._UnPack(resizeAttrs)
+        return x
+
+    # ResizeAttrsT
+    def _UnPack(self, resizeAttrs):
+        if resizeAttrs is None:
+            return
+        self.mode = resizeAttrs.Mode()
+        self.coordMode = resizeAttrs.CoordMode()
+        self.nearestMode = resizeAttrs.NearestMode()
+        self.antialias = resizeAttrs.Antialias()
+        if not resizeAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(resizeAttrs.AxesLength()):
+                    self.axes.append(resizeAttrs.Axes(i))
+            else:
+                self.axes = resizeAttrs.AxesAsNumpy()
+        self.extrapolationValue = resizeAttrs.ExtrapolationValue()
+
+    # ResizeAttrsT
+    def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                ResizeAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        ResizeAttrsStart(builder)
+        ResizeAttrsAddMode(builder, self.mode)
+        ResizeAttrsAddCoordMode(builder, self.coordMode)
+        ResizeAttrsAddNearestMode(builder, self.nearestMode)
+        ResizeAttrsAddAntialias(builder, self.antialias)
+        if self.axes is not None:
+            ResizeAttrsAddAxes(builder, axes)
+        ResizeAttrsAddExtrapolationValue(builder, self.extrapolationValue)
+        resizeAttrs = ResizeAttrsEnd(builder)
+        return resizeAttrs
+
+
+class ScaledDotProductAttentionAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ScaledDotProductAttentionAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsScaledDotProductAttentionAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ScaledDotProductAttentionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ScaledDotProductAttentionAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ScaledDotProductAttentionAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 1.0
 
-def RandomNormalLikeAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
+def ScaledDotProductAttentionAttrsStart(builder):
+    builder.StartObject(1)
 
-def RandomNormalLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def ScaledDotProductAttentionAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(0, scale, 1.0)
 
-def RandomNormalLikeAttrsEnd(builder):
+def ScaledDotProductAttentionAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class RandomNormalLikeAttrsT(object):
+class ScaledDotProductAttentionAttrsT(object):
 
-    # RandomNormalLikeAttrsT
+    # ScaledDotProductAttentionAttrsT
     def __init__(self):
-        self.mean = 0.0  # type: float
-        self.scale = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.scale = 1.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalLikeAttrs = RandomNormalLikeAttrs()
-        randomNormalLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalLikeAttrs)
+        scaledDotProductAttentionAttrs = ScaledDotProductAttentionAttrs()
+        scaledDotProductAttentionAttrs.Init(buf, pos)
+        return cls.InitFromObj(scaledDotProductAttentionAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3156,118 +7166,206 @@ class RandomNormalLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalLikeAttrs):
-        x = RandomNormalLikeAttrsT()
-        x._UnPack(randomNormalLikeAttrs)
+    def InitFromObj(cls, scaledDotProductAttentionAttrs):
+        x = ScaledDotProductAttentionAttrsT()
+        x._UnPack(scaledDotProductAttentionAttrs)
         return x
 
-    # RandomNormalLikeAttrsT
-    def _UnPack(self, randomNormalLikeAttrs):
-        if randomNormalLikeAttrs is None:
+    # ScaledDotProductAttentionAttrsT
+    def _UnPack(self, scaledDotProductAttentionAttrs):
+        if scaledDotProductAttentionAttrs is None:
             return
-        self.mean = randomNormalLikeAttrs.Mean()
-        self.scale = randomNormalLikeAttrs.Scale()
-        self.seed = randomNormalLikeAttrs.Seed()
+        self.scale = scaledDotProductAttentionAttrs.Scale()
 
-    # RandomNormalLikeAttrsT
+    # ScaledDotProductAttentionAttrsT
     def Pack(self, builder):
-        RandomNormalLikeAttrsStart(builder)
-        RandomNormalLikeAttrsAddMean(builder, self.mean)
-        RandomNormalLikeAttrsAddScale(builder, self.scale)
-        RandomNormalLikeAttrsAddSeed(builder, self.seed)
-        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
-        return randomNormalLikeAttrs
+        ScaledDotProductAttentionAttrsStart(builder)
+        ScaledDotProductAttentionAttrsAddScale(builder, self.scale)
+        scaledDotProductAttentionAttrs = ScaledDotProductAttentionAttrsEnd(builder)
+        return scaledDotProductAttentionAttrs
 
 
-class RandomUniformAttrs(object):
+class ScanAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformAttrs()
+        x = ScanAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
+    def GetRootAsScanAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformAttrs
+    # ScanAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformAttrs
-    def Shape(self, j):
+    # ScanAttrs
+    def Body(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = Graph()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # ScanAttrs
+    def NumScanInputs(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # ScanAttrs
+    def ScanInputAxes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # RandomUniformAttrs
-    def ShapeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+    # ScanAttrs
+    def ScanInputAxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
         return 0
 
-    # RandomUniformAttrs
-    def ShapeLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+    # ScanAttrs
+    def ScanInputAxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # RandomUniformAttrs
-    def ShapeIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+    # ScanAttrs
+    def ScanInputAxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         return o == 0
 
-    # RandomUniformAttrs
-    def High(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+    # ScanAttrs
+    def ScanInputDirections(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-    # RandomUniformAttrs
-    def Low(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+    # ScanAttrs
+    def ScanInputDirectionsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
 
-    # RandomUniformAttrs
-    def Seed(self):
+    # ScanAttrs
+    def ScanInputDirectionsLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
+            return self._tab.VectorLen(o)
+        return 0
 
-def RandomUniformAttrsStart(builder):
-    builder.StartObject(4)
+    # ScanAttrs
+    def ScanInputDirectionsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
 
-def RandomUniformAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+    # ScanAttrs
+    def ScanOutputAxes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def RandomUniformAttrsStartShapeVector(builder, numElems):
+    # ScanAttrs
+    def ScanOutputAxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ScanAttrs
+    def ScanOutputAxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ScanAttrs
+    def ScanOutputAxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # ScanAttrs
+    def ScanOutputDirections(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ScanAttrs
+    def ScanOutputDirectionsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ScanAttrs
+    def ScanOutputDirectionsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ScanAttrs
+    def ScanOutputDirectionsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+def ScanAttrsStart(builder):
+    builder.StartObject(6)
+
+def ScanAttrsAddBody(builder, body):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(body), 0)
+
+def ScanAttrsAddNumScanInputs(builder, numScanInputs):
+    builder.PrependInt32Slot(1, numScanInputs, 0)
+
+def ScanAttrsAddScanInputAxes(builder, scanInputAxes):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(scanInputAxes), 0)
+
+def ScanAttrsStartScanInputAxesVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def RandomUniformAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(1, high, 0.0)
+def ScanAttrsAddScanInputDirections(builder, scanInputDirections):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(scanInputDirections), 0)
 
-def RandomUniformAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(2, low, 0.0)
+def ScanAttrsStartScanInputDirectionsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-def RandomUniformAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(3, seed, None)
+def ScanAttrsAddScanOutputAxes(builder, scanOutputAxes):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(scanOutputAxes), 0)
 
-def RandomUniformAttrsEnd(builder):
+def ScanAttrsStartScanOutputAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ScanAttrsAddScanOutputDirections(builder, scanOutputDirections):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(scanOutputDirections), 0)
+
+def ScanAttrsStartScanOutputDirectionsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ScanAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -3276,20 +7374,22 @@ try:
 except:
     pass
 
-class RandomUniformAttrsT(object):
+class ScanAttrsT(object):
 
-    # RandomUniformAttrsT
+    # ScanAttrsT
     def __init__(self):
-        self.shape = None  # type: List[int]
-        self.high = 0.0  # type: float
-        self.low = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.body = None  # type: Optional[GraphT]
+        self.numScanInputs = 0  # type: int
+        self.scanInputAxes = None  # type: List[int]
+        self.scanInputDirections = None  # type: List[int]
+        self.scanOutputAxes = None  # type: List[int]
+        self.scanOutputDirections = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformAttrs = RandomUniformAttrs()
-        randomUniformAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformAttrs)
+        scanAttrs = ScanAttrs()
+        scanAttrs.Init(buf, pos)
+        return cls.InitFromObj(scanAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3297,119 +7397,240 @@ class RandomUniformAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformAttrs):
-        x = RandomUniformAttrsT()
-        x._UnPack(randomUniformAttrs)
+    def InitFromObj(cls, scanAttrs):
+        x = ScanAttrsT()
+        x._UnPack(scanAttrs)
+        return x
+
+    # ScanAttrsT
+    def _UnPack(self, scanAttrs):
+        if scanAttrs is None:
+            return
+        if scanAttrs.Body() is not None:
+            self.body = GraphT.InitFromObj(scanAttrs.Body())
+        self.numScanInputs = scanAttrs.NumScanInputs()
+        if not scanAttrs.ScanInputAxesIsNone():
+            if np is None:
+                self.scanInputAxes = []
+                for i in range(scanAttrs.ScanInputAxesLength()):
+                    self.scanInputAxes.append(scanAttrs.ScanInputAxes(i))
+            else:
+                self.scanInputAxes = scanAttrs.ScanInputAxesAsNumpy()
+        if not scanAttrs.ScanInputDirectionsIsNone():
+            if np is None:
+                self.scanInputDirections = []
+                for i in range(scanAttrs.ScanInputDirectionsLength()):
+                    self.scanInputDirections.append(scanAttrs.ScanInputDirections(i))
+            else:
+                self.scanInputDirections = scanAttrs.ScanInputDirectionsAsNumpy()
+        if not scanAttrs.ScanOutputAxesIsNone():
+            if np is None:
+                self.scanOutputAxes = []
+                for i in range(scanAttrs.ScanOutputAxesLength()):
+                    self.scanOutputAxes.append(scanAttrs.ScanOutputAxes(i))
+            else:
+                self.scanOutputAxes = scanAttrs.ScanOutputAxesAsNumpy()
+        if not scanAttrs.ScanOutputDirectionsIsNone():
+            if np is None:
+                self.scanOutputDirections = []
+                for i in range(scanAttrs.ScanOutputDirectionsLength()):
+                    self.scanOutputDirections.append(scanAttrs.ScanOutputDirections(i))
+            else:
+                self.scanOutputDirections = scanAttrs.ScanOutputDirectionsAsNumpy()
+
+    # ScanAttrsT
+    def Pack(self, builder):
+        if self.body is not None:
+            body = self.body.Pack(builder)
+        if self.scanInputAxes is not None:
+            if np is not None and type(self.scanInputAxes) is np.ndarray:
+                scanInputAxes = builder.CreateNumpyVector(self.scanInputAxes)
+            else:
+                ScanAttrsStartScanInputAxesVector(builder, len(self.scanInputAxes))
+                for i in reversed(range(len(self.scanInputAxes))):
+                    builder.PrependInt32(self.scanInputAxes[i])
+                scanInputAxes = builder.EndVector()
+        if self.scanInputDirections is not None:
+            if np is not None and type(self.scanInputDirections) is np.ndarray:
+                scanInputDirections = builder.CreateNumpyVector(self.scanInputDirections)
+            else:
+                ScanAttrsStartScanInputDirectionsVector(builder, len(self.scanInputDirections))
+                for i in reversed(range(len(self.scanInputDirections))):
+                    builder.PrependInt32(self.scanInputDirections[i])
+                scanInputDirections = builder.EndVector()
+        if self.scanOutputAxes is not None:
+            if np is not None and type(self.scanOutputAxes) is np.ndarray:
+                scanOutputAxes = builder.CreateNumpyVector(self.scanOutputAxes)
+            else:
+                ScanAttrsStartScanOutputAxesVector(builder, len(self.scanOutputAxes))
+                for i in reversed(range(len(self.scanOutputAxes))):
+                    builder.PrependInt32(self.scanOutputAxes[i])
+                scanOutputAxes = builder.EndVector()
+        if self.scanOutputDirections is not None:
+            if np is not None and type(self.scanOutputDirections) is np.ndarray:
+                scanOutputDirections = builder.CreateNumpyVector(self.scanOutputDirections)
+            else:
+                ScanAttrsStartScanOutputDirectionsVector(builder, len(self.scanOutputDirections))
+                for i in reversed(range(len(self.scanOutputDirections))):
+                    builder.PrependInt32(self.scanOutputDirections[i])
+                scanOutputDirections = builder.EndVector()
+        ScanAttrsStart(builder)
+        if self.body is not None:
+            ScanAttrsAddBody(builder, body)
+        ScanAttrsAddNumScanInputs(builder, self.numScanInputs)
+        if self.scanInputAxes is not None:
+            ScanAttrsAddScanInputAxes(builder, scanInputAxes)
+        if self.scanInputDirections is not None:
+            ScanAttrsAddScanInputDirections(builder, scanInputDirections)
+        if self.scanOutputAxes is not None:
+            ScanAttrsAddScanOutputAxes(builder, scanOutputAxes)
+        if self.scanOutputDirections is not None:
+            ScanAttrsAddScanOutputDirections(builder, scanOutputDirections)
+        scanAttrs = ScanAttrsEnd(builder)
+        return scanAttrs
+
+
+class ScatterElementsAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ScatterElementsAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ScatterElementsAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ScatterElementsAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # ScatterElementsAttrs
+    def Reduction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def ScatterElementsAttrsStart(builder):
+    builder.StartObject(2)
+
+def ScatterElementsAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def ScatterElementsAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(1, reduction, 0)
+
+def ScatterElementsAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ScatterElementsAttrsT(object):
+
+    # ScatterElementsAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+        self.reduction = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        scatterElementsAttrs = ScatterElementsAttrs()
+        scatterElementsAttrs.Init(buf, pos)
+        return cls.InitFromObj(scatterElementsAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, scatterElementsAttrs):
+        x = ScatterElementsAttrsT()
+        x._UnPack(scatterElementsAttrs)
         return x
 
-    # RandomUniformAttrsT
-    def _UnPack(self, randomUniformAttrs):
-        if randomUniformAttrs is None:
+    # ScatterElementsAttrsT
+    def _UnPack(self, scatterElementsAttrs):
+        if scatterElementsAttrs is None:
             return
-        if not randomUniformAttrs.ShapeIsNone():
-            if np is None:
-                self.shape = []
-                for i in range(randomUniformAttrs.ShapeLength()):
-                    self.shape.append(randomUniformAttrs.Shape(i))
-            else:
-                self.shape = randomUniformAttrs.ShapeAsNumpy()
-        self.high = randomUniformAttrs.High()
-        self.low = randomUniformAttrs.Low()
-        self.seed = randomUniformAttrs.Seed()
+        self.axis = scatterElementsAttrs.Axis()
+        self.reduction = scatterElementsAttrs.Reduction()
 
-    # RandomUniformAttrsT
+    # ScatterElementsAttrsT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
-            else:
-                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomUniformAttrsStart(builder)
-        if self.shape is not None:
-            RandomUniformAttrsAddShape(builder, shape)
-        RandomUniformAttrsAddHigh(builder, self.high)
-        RandomUniformAttrsAddLow(builder, self.low)
-        RandomUniformAttrsAddSeed(builder, self.seed)
-        randomUniformAttrs = RandomUniformAttrsEnd(builder)
-        return randomUniformAttrs
+        ScatterElementsAttrsStart(builder)
+        ScatterElementsAttrsAddAxis(builder, self.axis)
+        ScatterElementsAttrsAddReduction(builder, self.reduction)
+        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
+        return scatterElementsAttrs
 
 
-class RandomUniformLikeAttrs(object):
+class ScatterNDAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformLikeAttrs()
+        x = ScatterNDAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
+    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformLikeAttrs
+    # ScatterNDAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformLikeAttrs
-    def High(self):
+    # ScatterNDAttrs
+    def Reduction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomUniformLikeAttrs
-    def Low(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomUniformLikeAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-def RandomUniformLikeAttrsStart(builder):
-    builder.StartObject(3)
-
-def RandomUniformLikeAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(0, high, 0.0)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def RandomUniformLikeAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(1, low, 0.0)
+def ScatterNDAttrsStart(builder):
+    builder.StartObject(1)
 
-def RandomUniformLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def ScatterNDAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(0, reduction, 0)
 
-def RandomUniformLikeAttrsEnd(builder):
+def ScatterNDAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class RandomUniformLikeAttrsT(object):
+class ScatterNDAttrsT(object):
 
-    # RandomUniformLikeAttrsT
+    # ScatterNDAttrsT
     def __init__(self):
-        self.high = 0.0  # type: float
-        self.low = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.reduction = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformLikeAttrs = RandomUniformLikeAttrs()
-        randomUniformLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformLikeAttrs)
+        scatterNdattrs = ScatterNDAttrs()
+        scatterNdattrs.Init(buf, pos)
+        return cls.InitFromObj(scatterNdattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3417,118 +7638,87 @@ class RandomUniformLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformLikeAttrs):
-        x = RandomUniformLikeAttrsT()
-        x._UnPack(randomUniformLikeAttrs)
+    def InitFromObj(cls, scatterNdattrs):
+        x = ScatterNDAttrsT()
+        x._UnPack(scatterNdattrs)
         return x
 
-    # RandomUniformLikeAttrsT
-    def _UnPack(self, randomUniformLikeAttrs):
-        if randomUniformLikeAttrs is None:
+    # ScatterNDAttrsT
+    def _UnPack(self, scatterNdattrs):
+        if scatterNdattrs is None:
             return
-        self.high = randomUniformLikeAttrs.High()
-        self.low = randomUniformLikeAttrs.Low()
-        self.seed = randomUniformLikeAttrs.Seed()
+        self.reduction = scatterNdattrs.Reduction()
 
-    # RandomUniformLikeAttrsT
+    # ScatterNDAttrsT
     def Pack(self, builder):
-        RandomUniformLikeAttrsStart(builder)
-        RandomUniformLikeAttrsAddHigh(builder, self.high)
-        RandomUniformLikeAttrsAddLow(builder, self.low)
-        RandomUniformLikeAttrsAddSeed(builder, self.seed)
-        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
-        return randomUniformLikeAttrs
+        ScatterNDAttrsStart(builder)
+        ScatterNDAttrsAddReduction(builder, self.reduction)
+        scatterNdattrs = ScatterNDAttrsEnd(builder)
+        return scatterNdattrs
 
 
-class ReduceMeanAttrs(object):
+class SeluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReduceMeanAttrs()
+        x = SeluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
+    def GetRootAsSeluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReduceMeanAttrs
+    # SeluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReduceMeanAttrs
-    def Axes(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesLength(self):
+    # SeluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # ReduceMeanAttrs
-    def KeepDims(self):
+    # SeluAttrs
+    def Gamma(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ReduceMeanAttrsStart(builder):
+def SeluAttrsStart(builder):
     builder.StartObject(2)
 
-def ReduceMeanAttrsAddAxes(builder, axes):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
-
-def ReduceMeanAttrsStartAxesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def SeluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
 
-def ReduceMeanAttrsAddKeepDims(builder, keepDims):
-    builder.PrependBoolSlot(1, keepDims, 0)
+def SeluAttrsAddGamma(builder, gamma):
+    builder.PrependFloat32Slot(1, gamma, 0.0)
 
-def ReduceMeanAttrsEnd(builder):
+def SeluAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class ReduceMeanAttrsT(object):
+class SeluAttrsT(object):
 
-    # ReduceMeanAttrsT
+    # SeluAttrsT
     def __init__(self):
-        self.axes = None  # type: List[int]
-        self.keepDims = False  # type: bool
+        self.alpha = 0.0  # type: float
+        self.gamma = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reduceMeanAttrs = ReduceMeanAttrs()
-        reduceMeanAttrs.Init(buf, pos)
-        return cls.InitFromObj(reduceMeanAttrs)
+        seluAttrs = SeluAttrs()
+        seluAttrs.Init(buf, pos)
+        return cls.InitFromObj(seluAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3536,93 +7726,78 @@ class ReduceMeanAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reduceMeanAttrs):
-        x = ReduceMeanAttrsT()
-        x._UnPack(reduceMeanAttrs)
+    def InitFromObj(cls, seluAttrs):
+        x = SeluAttrsT()
+        x._UnPack(seluAttrs)
         return x
 
-    # ReduceMeanAttrsT
-    def _UnPack(self, reduceMeanAttrs):
-        if reduceMeanAttrs is None:
+    # SeluAttrsT
+    def _UnPack(self, seluAttrs):
+        if seluAttrs is None:
             return
-        if not reduceMeanAttrs.AxesIsNone():
-            if np is None:
-                self.axes = []
-                for i in range(reduceMeanAttrs.AxesLength()):
-                    self.axes.append(reduceMeanAttrs.Axes(i))
-            else:
-                self.axes = reduceMeanAttrs.AxesAsNumpy()
-        self.keepDims = reduceMeanAttrs.KeepDims()
+        self.alpha = seluAttrs.Alpha()
+        self.gamma = seluAttrs.Gamma()
 
-    # ReduceMeanAttrsT
+    # SeluAttrsT
     def Pack(self, builder):
-        if self.axes is not None:
-            if np is not None and type(self.axes) is np.ndarray:
-                axes = builder.CreateNumpyVector(self.axes)
-            else:
-                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
-                for i in reversed(range(len(self.axes))):
-                    builder.PrependInt32(self.axes[i])
-                axes = builder.EndVector()
-        ReduceMeanAttrsStart(builder)
-        if self.axes is not None:
-            ReduceMeanAttrsAddAxes(builder, axes)
-        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
-        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
-        return reduceMeanAttrs
+        SeluAttrsStart(builder)
+        SeluAttrsAddAlpha(builder, self.alpha)
+        SeluAttrsAddGamma(builder, self.gamma)
+        seluAttrs = SeluAttrsEnd(builder)
+        return seluAttrs
 
 
-class ReshapeAttrs(object):
+class SoftmaxAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReshapeAttrs()
+        x = SoftmaxAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReshapeAttrs(cls, buf, offset=0):
+    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReshapeAttrs
+    # SoftmaxAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReshapeAttrs
-    def AllowZero(self):
+    # SoftmaxAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def ReshapeAttrsStart(builder):
+def SoftmaxAttrsStart(builder):
     builder.StartObject(1)
 
-def ReshapeAttrsAddAllowZero(builder, allowZero):
-    builder.PrependBoolSlot(0, allowZero, 0)
+def SoftmaxAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def ReshapeAttrsEnd(builder):
+def SoftmaxAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ReshapeAttrsT(object):
+class SoftmaxAttrsT(object):
 
-    # ReshapeAttrsT
+    # SoftmaxAttrsT
     def __init__(self):
-        self.allowZero = False  # type: bool
+        self.axis = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reshapeAttrs = ReshapeAttrs()
-        reshapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(reshapeAttrs)
+        softmaxAttrs = SoftmaxAttrs()
+        softmaxAttrs.Init(buf, pos)
+        return cls.InitFromObj(softmaxAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3630,98 +7805,87 @@ class ReshapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reshapeAttrs):
-        x = ReshapeAttrsT()
-        x._UnPack(reshapeAttrs)
+    def InitFromObj(cls, softmaxAttrs):
+        x = SoftmaxAttrsT()
+        x._UnPack(softmaxAttrs)
         return x
 
-    # ReshapeAttrsT
-    def _UnPack(self, reshapeAttrs):
-        if reshapeAttrs is None:
+    # SoftmaxAttrsT
+    def _UnPack(self, softmaxAttrs):
+        if softmaxAttrs is None:
             return
-        self.allowZero = reshapeAttrs.AllowZero()
+        self.axis = softmaxAttrs.Axis()
 
-    # ReshapeAttrsT
+    # SoftmaxAttrsT
     def Pack(self, builder):
-        ReshapeAttrsStart(builder)
-        ReshapeAttrsAddAllowZero(builder, self.allowZero)
-        reshapeAttrs = ReshapeAttrsEnd(builder)
-        return reshapeAttrs
+        SoftmaxAttrsStart(builder)
+        SoftmaxAttrsAddAxis(builder, self.axis)
+        softmaxAttrs = SoftmaxAttrsEnd(builder)
+        return softmaxAttrs
 
 
-class ResizeAttrs(object):
+class SplitAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ResizeAttrs()
+        x = SplitAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsResizeAttrs(cls, buf, offset=0):
+    def GetRootAsSplitAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SplitAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ResizeAttrs
+    # SplitAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ResizeAttrs
-    def Mode(self):
+    # SplitAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ResizeAttrs
-    def CoordMode(self):
+    # SplitAttrs
+    def NumOutputs(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # ResizeAttrs
-    def NearestMode(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-def ResizeAttrsStart(builder):
-    builder.StartObject(3)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
 
-def ResizeAttrsAddMode(builder, mode):
-    builder.PrependUint8Slot(0, mode, 0)
+def SplitAttrsStart(builder):
+    builder.StartObject(2)
 
-def ResizeAttrsAddCoordMode(builder, coordMode):
-    builder.PrependUint8Slot(1, coordMode, 0)
+def SplitAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def ResizeAttrsAddNearestMode(builder, nearestMode):
-    builder.PrependUint8Slot(2, nearestMode, 0)
+def SplitAttrsAddNumOutputs(builder, numOutputs):
+    builder.PrependUint32Slot(1, numOutputs, None)
 
-def ResizeAttrsEnd(builder):
+def SplitAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ResizeAttrsT(object):
+class SplitAttrsT(object):
 
-    # ResizeAttrsT
+    # SplitAttrsT
     def __init__(self):
-        self.mode = 0  # type: int
-        self.coordMode = 0  # type: int
-        self.nearestMode = 0  # type: int
+        self.axis = 0  # type: int
+        self.numOutputs = None  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        resizeAttrs = ResizeAttrs()
-        resizeAttrs.Init(buf, pos)
-        return cls.InitFromObj(resizeAttrs)
+        splitAttrs = SplitAttrs()
+        splitAttrs.Init(buf, pos)
+        return cls.InitFromObj(splitAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3729,91 +7893,89 @@ class ResizeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, resizeAttrs):
-        x = ResizeAttrsT()
-        x._UnPack(resizeAttrs)
+    def InitFromObj(cls, splitAttrs):
+        x = SplitAttrsT()
+        x._UnPack(splitAttrs)
         return x
 
-    # ResizeAttrsT
-    def _UnPack(self, resizeAttrs):
-        if resizeAttrs is None:
+    # SplitAttrsT
+    def _UnPack(self, splitAttrs):
+        if splitAttrs is None:
             return
-        self.mode = resizeAttrs.Mode()
-        self.coordMode = resizeAttrs.CoordMode()
-        self.nearestMode = resizeAttrs.NearestMode()
+        self.axis = splitAttrs.Axis()
+        self.numOutputs = splitAttrs.NumOutputs()
 
-    # ResizeAttrsT
+    # SplitAttrsT
     def Pack(self, builder):
-        ResizeAttrsStart(builder)
-        ResizeAttrsAddMode(builder, self.mode)
-        ResizeAttrsAddCoordMode(builder, self.coordMode)
-        ResizeAttrsAddNearestMode(builder, self.nearestMode)
-        resizeAttrs = ResizeAttrsEnd(builder)
-        return resizeAttrs
+        SplitAttrsStart(builder)
+        SplitAttrsAddAxis(builder, self.axis)
+        SplitAttrsAddNumOutputs(builder, self.numOutputs)
+        splitAttrs = SplitAttrsEnd(builder)
+        return splitAttrs
 
 
-class ScatterElementsAttrs(object):
+class SplitToSequenceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterElementsAttrs()
+        x = SplitToSequenceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
+    def GetRootAsSplitToSequenceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SplitToSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterElementsAttrs
+    # SplitToSequenceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterElementsAttrs
+    # SplitToSequenceAttrs
     def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ScatterElementsAttrs
-    def Reduction(self):
+    # SplitToSequenceAttrs
+    def KeepDims(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
 
-def ScatterElementsAttrsStart(builder):
+def SplitToSequenceAttrsStart(builder):
     builder.StartObject(2)
 
-def ScatterElementsAttrsAddAxis(builder, axis):
+def SplitToSequenceAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
 
-def ScatterElementsAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(1, reduction, 0)
+def SplitToSequenceAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 1)
 
-def ScatterElementsAttrsEnd(builder):
+def SplitToSequenceAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ScatterElementsAttrsT(object):
+class SplitToSequenceAttrsT(object):
 
-    # ScatterElementsAttrsT
+    # SplitToSequenceAttrsT
     def __init__(self):
         self.axis = 0  # type: int
-        self.reduction = 0  # type: int
+        self.keepDims = True  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterElementsAttrs = ScatterElementsAttrs()
-        scatterElementsAttrs.Init(buf, pos)
-        return cls.InitFromObj(scatterElementsAttrs)
+        splitToSequenceAttrs = SplitToSequenceAttrs()
+        splitToSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(splitToSequenceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3821,78 +7983,100 @@ class ScatterElementsAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterElementsAttrs):
-        x = ScatterElementsAttrsT()
-        x._UnPack(scatterElementsAttrs)
+    def InitFromObj(cls, splitToSequenceAttrs):
+        x = SplitToSequenceAttrsT()
+        x._UnPack(splitToSequenceAttrs)
         return x
 
-    # ScatterElementsAttrsT
-    def _UnPack(self, scatterElementsAttrs):
-        if scatterElementsAttrs is None:
+    # SplitToSequenceAttrsT
+    def _UnPack(self, splitToSequenceAttrs):
+        if splitToSequenceAttrs is None:
             return
-        self.axis = scatterElementsAttrs.Axis()
-        self.reduction = scatterElementsAttrs.Reduction()
+        self.axis = splitToSequenceAttrs.Axis()
+        self.keepDims = splitToSequenceAttrs.KeepDims()
 
-    # ScatterElementsAttrsT
+    # SplitToSequenceAttrsT
     def Pack(self, builder):
-        ScatterElementsAttrsStart(builder)
-        ScatterElementsAttrsAddAxis(builder, self.axis)
-        ScatterElementsAttrsAddReduction(builder, self.reduction)
-        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
-        return scatterElementsAttrs
+        SplitToSequenceAttrsStart(builder)
+        SplitToSequenceAttrsAddAxis(builder, self.axis)
+        SplitToSequenceAttrsAddKeepDims(builder, self.keepDims)
+        splitToSequenceAttrs = SplitToSequenceAttrsEnd(builder)
+        return splitToSequenceAttrs
 
 
-class ScatterNDAttrs(object):
+class TopKAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterNDAttrs()
+        x = TopKAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
+    def GetRootAsTopKAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def TopKAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterNDAttrs
+    # TopKAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterNDAttrs
-    def Reduction(self):
+    # TopKAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-def ScatterNDAttrsStart(builder):
-    builder.StartObject(1)
+    # TopKAttrs
+    def Largest(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ScatterNDAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(0, reduction, 0)
+    # TopKAttrs
+    def Sorted(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ScatterNDAttrsEnd(builder):
+def TopKAttrsStart(builder):
+    builder.StartObject(3)
+
+def TopKAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def TopKAttrsAddLargest(builder, largest):
+    builder.PrependBoolSlot(1, largest, 0)
+
+def TopKAttrsAddSorted(builder, sorted):
+    builder.PrependBoolSlot(2, sorted, 0)
+
+def TopKAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ScatterNDAttrsT(object):
+class TopKAttrsT(object):
 
-    # ScatterNDAttrsT
+    # TopKAttrsT
     def __init__(self):
-        self.reduction = 0  # type: int
+        self.axis = 0  # type: int
+        self.largest = False  # type: bool
+        self.sorted = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterNdattrs = ScatterNDAttrs()
-        scatterNdattrs.Init(buf, pos)
-        return cls.InitFromObj(scatterNdattrs)
+        topKattrs = TopKAttrs()
+        topKattrs.Init(buf, pos)
+        return cls.InitFromObj(topKattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3900,76 +8084,107 @@ class ScatterNDAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterNdattrs):
-        x = ScatterNDAttrsT()
-        x._UnPack(scatterNdattrs)
+    def InitFromObj(cls, topKattrs):
+        x = TopKAttrsT()
+        x._UnPack(topKattrs)
         return x
 
-    # ScatterNDAttrsT
-    def _UnPack(self, scatterNdattrs):
-        if scatterNdattrs is None:
+    # TopKAttrsT
+    def _UnPack(self, topKattrs):
+        if topKattrs is None:
             return
-        self.reduction = scatterNdattrs.Reduction()
+        self.axis = topKattrs.Axis()
+        self.largest = topKattrs.Largest()
+        self.sorted = topKattrs.Sorted()
 
-    # ScatterNDAttrsT
+    # TopKAttrsT
     def Pack(self, builder):
-        ScatterNDAttrsStart(builder)
-        ScatterNDAttrsAddReduction(builder, self.reduction)
-        scatterNdattrs = ScatterNDAttrsEnd(builder)
-        return scatterNdattrs
+        TopKAttrsStart(builder)
+        TopKAttrsAddAxis(builder, self.axis)
+        TopKAttrsAddLargest(builder, self.largest)
+        TopKAttrsAddSorted(builder, self.sorted)
+        topKattrs = TopKAttrsEnd(builder)
+        return topKattrs
 
 
-class SoftmaxAttrs(object):
+class TransposeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SoftmaxAttrs()
+        x = TransposeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
+    def GetRootAsTransposeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def TransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SoftmaxAttrs
-    def Init(self, buf, pos):
-        self._tab = flatbuffers.table.Table(buf, pos)
+    # TransposeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # TransposeAttrs
+    def Perm(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # TransposeAttrs
+    def PermAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-    # SoftmaxAttrs
-    def Axis(self):
+    # TransposeAttrs
+    def PermLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.VectorLen(o)
         return 0
 
-def SoftmaxAttrsStart(builder):
+    # TransposeAttrs
+    def PermIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def TransposeAttrsStart(builder):
     builder.StartObject(1)
 
-def SoftmaxAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def TransposeAttrsAddPerm(builder, perm):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(perm), 0)
 
-def SoftmaxAttrsEnd(builder):
+def TransposeAttrsStartPermVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def TransposeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class SoftmaxAttrsT(object):
+class TransposeAttrsT(object):
 
-    # SoftmaxAttrsT
+    # TransposeAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.perm = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        softmaxAttrs = SoftmaxAttrs()
-        softmaxAttrs.Init(buf, pos)
-        return cls.InitFromObj(softmaxAttrs)
+        transposeAttrs = TransposeAttrs()
+        transposeAttrs.Init(buf, pos)
+        return cls.InitFromObj(transposeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3977,76 +8192,91 @@ class SoftmaxAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, softmaxAttrs):
-        x = SoftmaxAttrsT()
-        x._UnPack(softmaxAttrs)
+    def InitFromObj(cls, transposeAttrs):
+        x = TransposeAttrsT()
+        x._UnPack(transposeAttrs)
         return x
 
-    # SoftmaxAttrsT
-    def _UnPack(self, softmaxAttrs):
-        if softmaxAttrs is None:
+    # TransposeAttrsT
+    def _UnPack(self, transposeAttrs):
+        if transposeAttrs is None:
             return
-        self.axis = softmaxAttrs.Axis()
+        if not transposeAttrs.PermIsNone():
+            if np is None:
+                self.perm = []
+                for i in range(transposeAttrs.PermLength()):
+                    self.perm.append(transposeAttrs.Perm(i))
+            else:
+                self.perm = transposeAttrs.PermAsNumpy()
 
-    # SoftmaxAttrsT
+    # TransposeAttrsT
     def Pack(self, builder):
-        SoftmaxAttrsStart(builder)
-        SoftmaxAttrsAddAxis(builder, self.axis)
-        softmaxAttrs = SoftmaxAttrsEnd(builder)
-        return softmaxAttrs
+        if self.perm is not None:
+            if np is not None and type(self.perm) is np.ndarray:
+                perm = builder.CreateNumpyVector(self.perm)
+            else:
+                TransposeAttrsStartPermVector(builder, len(self.perm))
+                for i in reversed(range(len(self.perm))):
+                    builder.PrependUint32(self.perm[i])
+                perm = builder.EndVector()
+        TransposeAttrsStart(builder)
+        if self.perm is not None:
+            TransposeAttrsAddPerm(builder, perm)
+        transposeAttrs = TransposeAttrsEnd(builder)
+        return transposeAttrs
 
 
-class SplitAttrs(object):
+class TriluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SplitAttrs()
+        x = TriluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSplitAttrs(cls, buf, offset=0):
+    def GetRootAsTriluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SplitAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def TriluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SplitAttrs
+    # TriluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SplitAttrs
-    def Axis(self):
+    # TriluAttrs
+    def Upper(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def SplitAttrsStart(builder):
+def TriluAttrsStart(builder):
     builder.StartObject(1)
 
-def SplitAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def TriluAttrsAddUpper(builder, upper):
+    builder.PrependBoolSlot(0, upper, 0)
 
-def SplitAttrsEnd(builder):
+def TriluAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class SplitAttrsT(object):
+class TriluAttrsT(object):
 
-    # SplitAttrsT
+    # TriluAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.upper = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        splitAttrs = SplitAttrs()
-        splitAttrs.Init(buf, pos)
-        return cls.InitFromObj(splitAttrs)
+        triluAttrs = TriluAttrs()
+        triluAttrs.Init(buf, pos)
+        return cls.InitFromObj(triluAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4054,98 +8284,173 @@ class SplitAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, splitAttrs):
-        x = SplitAttrsT()
-        x._UnPack(splitAttrs)
+    def InitFromObj(cls, triluAttrs):
+        x = TriluAttrsT()
+        x._UnPack(triluAttrs)
         return x
 
-    # SplitAttrsT
-    def _UnPack(self, splitAttrs):
-        if splitAttrs is None:
+    # TriluAttrsT
+    def _UnPack(self, triluAttrs):
+        if triluAttrs is None:
             return
-        self.axis = splitAttrs.Axis()
+        self.upper = triluAttrs.Upper()
 
-    # SplitAttrsT
+    # TriluAttrsT
     def Pack(self, builder):
-        SplitAttrsStart(builder)
-        SplitAttrsAddAxis(builder, self.axis)
-        splitAttrs = SplitAttrsEnd(builder)
-        return splitAttrs
+        TriluAttrsStart(builder)
+        TriluAttrsAddUpper(builder, self.upper)
+        triluAttrs = TriluAttrsEnd(builder)
+        return triluAttrs
 
 
-class TopKAttrs(object):
+class OperatorNode(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = TopKAttrs()
+        x = OperatorNode()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsTopKAttrs(cls, buf, offset=0):
+    def GetRootAsOperatorNode(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def TopKAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def OperatorNodeBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # TopKAttrs
+    # OperatorNode
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # TopKAttrs
-    def Axis(self):
+    # OperatorNode
+    def Type(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # TopKAttrs
-    def Largest(self):
+    # OperatorNode
+    def AttrsType(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-    # TopKAttrs
-    def Sorted(self):
+    # OperatorNode
+    def Attrs(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
+        return None
 
-def TopKAttrsStart(builder):
-    builder.StartObject(3)
+    # OperatorNode
+    def Inputs(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # OperatorNode
+    def InputsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # OperatorNode
+    def InputsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # OperatorNode
+    def InputsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # OperatorNode
+    def Outputs(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # OperatorNode
+    def OutputsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # OperatorNode
+    def OutputsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # OperatorNode
+    def OutputsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+def OperatorNodeStart(builder):
+    builder.StartObject(5)
+
+def OperatorNodeAddType(builder, type):
+    builder.PrependUint8Slot(0, type, 0)
+
+def OperatorNodeAddAttrsType(builder, attrsType):
+    builder.PrependUint8Slot(1, attrsType, 0)
+
+def OperatorNodeAddAttrs(builder, attrs):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(attrs), 0)
+
+def OperatorNodeAddInputs(builder, inputs):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(inputs), 0)
 
-def TopKAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def OperatorNodeStartInputsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-def TopKAttrsAddLargest(builder, largest):
-    builder.PrependBoolSlot(1, largest, 0)
+def OperatorNodeAddOutputs(builder, outputs):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(outputs), 0)
 
-def TopKAttrsAddSorted(builder, sorted):
-    builder.PrependBoolSlot(2, sorted, 0)
+def OperatorNodeStartOutputsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-def TopKAttrsEnd(builder):
+def OperatorNodeEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List, Union
+except:
+    pass
 
-class TopKAttrsT(object):
+class OperatorNodeT(object):
 
-    # TopKAttrsT
+    # OperatorNodeT
     def __init__(self):
-        self.axis = 0  # type: int
-        self.largest = False  # type: bool
-        self.sorted = False  # type: bool
+        self.type = 0  # type: int
+        self.attrsType = 0  # type: int
+        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT, EluAttrsT, RandomUniformLikeAttrsT, RandomNormalAttrsT, RandomNormalLikeAttrsT, GatherNDAttrsT, EyeLikeAttrsT, PadAttrsT, LpNormalizationAttrsT, MeanVarianceNormalizationAttrsT, GatherBlockQuantizedAttrsT, GridSampleAttrsT, MatMulNBitsAttrsT, NormalizeAttrsT, ChannelShuffleAttrsT, PermuteChannelsAttrsT, ReduceVarAttrsT, LpPoolAttrsT, GeluAttrsT, SeluAttrsT, ImageToTensorAttrsT, BitShiftAttrsT, MultinomialAttrsT, DropoutAttrsT, ScanAttrsT, ConcatFromSequenceAttrsT, SplitToSequenceAttrsT, CustomOperatorAttrsT, QLinearConvAttrsT, QLinearMatMulAttrsT, ScaledDotProductAttentionAttrsT]
+        self.inputs = None  # type: List[int]
+        self.outputs = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        topKattrs = TopKAttrs()
-        topKattrs.Init(buf, pos)
-        return cls.InitFromObj(topKattrs)
+        operatorNode = OperatorNode()
+        operatorNode.Init(buf, pos)
+        return cls.InitFromObj(operatorNode)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4153,88 +8458,125 @@ class TopKAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, topKattrs):
-        x = TopKAttrsT()
-        x._UnPack(topKattrs)
+    def InitFromObj(cls, operatorNode):
+        x = OperatorNodeT()
+        x._UnPack(operatorNode)
         return x
 
-    # TopKAttrsT
-    def _UnPack(self, topKattrs):
-        if topKattrs is None:
+    # OperatorNodeT
+    def _UnPack(self, operatorNode):
+        if operatorNode is None:
             return
-        self.axis = topKattrs.Axis()
-        self.largest = topKattrs.Largest()
-        self.sorted = topKattrs.Sorted()
+        self.type = operatorNode.Type()
+        self.attrsType = operatorNode.AttrsType()
+        self.attrs = OperatorAttrsCreator(self.attrsType, operatorNode.Attrs())
+        if not operatorNode.InputsIsNone():
+            if np is None:
+                self.inputs = []
+                for i in range(operatorNode.InputsLength()):
+                    self.inputs.append(operatorNode.Inputs(i))
+            else:
+                self.inputs = operatorNode.InputsAsNumpy()
+        if not operatorNode.OutputsIsNone():
+            if np is None:
+                self.outputs = []
+                for i in range(operatorNode.OutputsLength()):
+                    self.outputs.append(operatorNode.Outputs(i))
+            else:
+                self.outputs = operatorNode.OutputsAsNumpy()
 
-    # TopKAttrsT
+    # OperatorNodeT
     def Pack(self, builder):
-        TopKAttrsStart(builder)
-        TopKAttrsAddAxis(builder, self.axis)
-        TopKAttrsAddLargest(builder, self.largest)
-        TopKAttrsAddSorted(builder, self.sorted)
-        topKattrs = TopKAttrsEnd(builder)
-        return topKattrs
+        if self.attrs is not None:
+            attrs = self.attrs.Pack(builder)
+        if self.inputs is not None:
+            if np is not None and type(self.inputs) is np.ndarray:
+                inputs = builder.CreateNumpyVector(self.inputs)
+            else:
+                OperatorNodeStartInputsVector(builder, len(self.inputs))
+                for i in reversed(range(len(self.inputs))):
+                    builder.PrependInt32(self.inputs[i])
+                inputs = builder.EndVector()
+        if self.outputs is not None:
+            if np is not None and type(self.outputs) is np.ndarray:
+                outputs = builder.CreateNumpyVector(self.outputs)
+            else:
+                OperatorNodeStartOutputsVector(builder, len(self.outputs))
+                for i in reversed(range(len(self.outputs))):
+                    builder.PrependInt32(self.outputs[i])
+                outputs = builder.EndVector()
+        OperatorNodeStart(builder)
+        OperatorNodeAddType(builder, self.type)
+        OperatorNodeAddAttrsType(builder, self.attrsType)
+        if self.attrs is not None:
+            OperatorNodeAddAttrs(builder, attrs)
+        if self.inputs is not None:
+            OperatorNodeAddInputs(builder, inputs)
+        if self.outputs is not None:
+            OperatorNodeAddOutputs(builder, outputs)
+        operatorNode = OperatorNodeEnd(builder)
+        return operatorNode
 
 
-class TransposeAttrs(object):
+class FloatData(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = TransposeAttrs()
+        x = FloatData()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsTransposeAttrs(cls, buf, offset=0):
+    def GetRootAsFloatData(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def TransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def FloatDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # TransposeAttrs
+    # FloatData
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # TransposeAttrs
-    def Perm(self, j):
+    # FloatData
+    def Data(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # TransposeAttrs
-    def PermAsNumpy(self):
+    # FloatData
+    def DataAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
         return 0
 
-    # TransposeAttrs
-    def PermLength(self):
+    # FloatData
+    def DataLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # TransposeAttrs
-    def PermIsNone(self):
+    # FloatData
+    def DataIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
-def TransposeAttrsStart(builder):
+def FloatDataStart(builder):
     builder.StartObject(1)
 
-def TransposeAttrsAddPerm(builder, perm):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(perm), 0)
+def FloatDataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
 
-def TransposeAttrsStartPermVector(builder, numElems):
+def FloatDataStartDataVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def TransposeAttrsEnd(builder):
+def FloatDataEnd(builder):
     return builder.EndObject()
 
 
@@ -4243,17 +8585,17 @@ try:
 except:
     pass
 
-class TransposeAttrsT(object):
+class FloatDataT(object):
 
-    # TransposeAttrsT
+    # FloatDataT
     def __init__(self):
-        self.perm = None  # type: List[int]
+        self.data = None  # type: List[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        transposeAttrs = TransposeAttrs()
-        transposeAttrs.Init(buf, pos)
-        return cls.InitFromObj(transposeAttrs)
+        floatData = FloatData()
+        floatData.Init(buf, pos)
+        return cls.InitFromObj(floatData)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4261,91 +8603,118 @@ class TransposeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, transposeAttrs):
-        x = TransposeAttrsT()
-        x._UnPack(transposeAttrs)
+    def InitFromObj(cls, floatData):
+        x = FloatDataT()
+        x._UnPack(floatData)
         return x
 
-    # TransposeAttrsT
-    def _UnPack(self, transposeAttrs):
-        if transposeAttrs is None:
+    # FloatDataT
+    def _UnPack(self, floatData):
+        if floatData is None:
             return
-        if not transposeAttrs.PermIsNone():
+        if not floatData.DataIsNone():
             if np is None:
-                self.perm = []
-                for i in range(transposeAttrs.PermLength()):
-                    self.perm.append(transposeAttrs.Perm(i))
+                self.data = []
+                for i in range(floatData.DataLength()):
+                    self.data.append(floatData.Data(i))
             else:
-                self.perm = transposeAttrs.PermAsNumpy()
+                self.data = floatData.DataAsNumpy()
 
-    # TransposeAttrsT
+    # FloatDataT
     def Pack(self, builder):
-        if self.perm is not None:
-            if np is not None and type(self.perm) is np.ndarray:
-                perm = builder.CreateNumpyVector(self.perm)
+        if self.data is not None:
+            if np is not None and type(self.data) is np.ndarray:
+                data = builder.CreateNumpyVector(self.data)
             else:
-                TransposeAttrsStartPermVector(builder, len(self.perm))
-                for i in reversed(range(len(self.perm))):
-                    builder.PrependUint32(self.perm[i])
-                perm = builder.EndVector()
-        TransposeAttrsStart(builder)
-        if self.perm is not None:
-            TransposeAttrsAddPerm(builder, perm)
-        transposeAttrs = TransposeAttrsEnd(builder)
-        return transposeAttrs
+                FloatDataStartDataVector(builder, len(self.data))
+                for i in reversed(range(len(self.data))):
+                    builder.PrependFloat32(self.data[i])
+                data = builder.EndVector()
+        FloatDataStart(builder)
+        if self.data is not None:
+            FloatDataAddData(builder, data)
+        floatData = FloatDataEnd(builder)
+        return floatData
 
 
-class TriluAttrs(object):
+class IntData(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = TriluAttrs()
+        x = IntData()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsTriluAttrs(cls, buf, offset=0):
+    def GetRootAsIntData(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def TriluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def IntDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # TriluAttrs
+    # IntData
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # TriluAttrs
-    def Upper(self):
+    # IntData
+    def Data(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def TriluAttrsStart(builder):
+    # IntData
+    def DataAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # IntData
+    def DataLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # IntData
+    def DataIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def IntDataStart(builder):
     builder.StartObject(1)
 
-def TriluAttrsAddUpper(builder, upper):
-    builder.PrependBoolSlot(0, upper, 0)
+def IntDataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
 
-def TriluAttrsEnd(builder):
+def IntDataStartDataVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def IntDataEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class TriluAttrsT(object):
+class IntDataT(object):
 
-    # TriluAttrsT
+    # IntDataT
     def __init__(self):
-        self.upper = False  # type: bool
+        self.data = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        triluAttrs = TriluAttrs()
-        triluAttrs.Init(buf, pos)
-        return cls.InitFromObj(triluAttrs)
+        intData = IntData()
+        intData.Init(buf, pos)
+        return cls.InitFromObj(intData)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4353,173 +8722,111 @@ class TriluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, triluAttrs):
-        x = TriluAttrsT()
-        x._UnPack(triluAttrs)
+    def InitFromObj(cls, intData):
+        x = IntDataT()
+        x._UnPack(intData)
         return x
 
-    # TriluAttrsT
-    def _UnPack(self, triluAttrs):
-        if triluAttrs is None:
+    # IntDataT
+    def _UnPack(self, intData):
+        if intData is None:
             return
-        self.upper = triluAttrs.Upper()
+        if not intData.DataIsNone():
+            if np is None:
+                self.data = []
+                for i in range(intData.DataLength()):
+                    self.data.append(intData.Data(i))
+            else:
+                self.data = intData.DataAsNumpy()
 
-    # TriluAttrsT
+    # IntDataT
     def Pack(self, builder):
-        TriluAttrsStart(builder)
-        TriluAttrsAddUpper(builder, self.upper)
-        triluAttrs = TriluAttrsEnd(builder)
-        return triluAttrs
+        if self.data is not None:
+            if np is not None and type(self.data) is np.ndarray:
+                data = builder.CreateNumpyVector(self.data)
+            else:
+                IntDataStartDataVector(builder, len(self.data))
+                for i in reversed(range(len(self.data))):
+                    builder.PrependInt32(self.data[i])
+                data = builder.EndVector()
+        IntDataStart(builder)
+        if self.data is not None:
+            IntDataAddData(builder, data)
+        intData = IntDataEnd(builder)
+        return intData
 
 
-class OperatorNode(object):
+class StringData(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = OperatorNode()
+        x = StringData()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsOperatorNode(cls, buf, offset=0):
+    def GetRootAsStringData(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def OperatorNodeBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def StringDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # OperatorNode
+    # StringData
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # OperatorNode
-    def Type(self):
+    # StringData
+    def Data(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # OperatorNode
-    def AttrsType(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # OperatorNode
-    def Attrs(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            from flatbuffers.table import Table
-            obj = Table(bytearray(), 0)
-            self._tab.Union(obj, o)
-            return obj
-        return None
-
-    # OperatorNode
-    def Inputs(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # OperatorNode
-    def InputsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
-        return 0
-
-    # OperatorNode
-    def InputsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # OperatorNode
-    def InputsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
-
-    # OperatorNode
-    def Outputs(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # OperatorNode
-    def OutputsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
-        return 0
+            return self._tab.String(a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return ""
 
-    # OperatorNode
-    def OutputsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+    # StringData
+    def DataLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # OperatorNode
-    def OutputsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+    # StringData
+    def DataIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
-def OperatorNodeStart(builder):
-    builder.StartObject(5)
-
-def OperatorNodeAddType(builder, type):
-    builder.PrependUint8Slot(0, type, 0)
-
-def OperatorNodeAddAttrsType(builder, attrsType):
-    builder.PrependUint8Slot(1, attrsType, 0)
-
-def OperatorNodeAddAttrs(builder, attrs):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(attrs), 0)
-
-def OperatorNodeAddInputs(builder, inputs):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(inputs), 0)
-
-def OperatorNodeStartInputsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def StringDataStart(builder):
+    builder.StartObject(1)
 
-def OperatorNodeAddOutputs(builder, outputs):
-    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(outputs), 0)
+def StringDataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
 
-def OperatorNodeStartOutputsVector(builder, numElems):
+def StringDataStartDataVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def OperatorNodeEnd(builder):
+def StringDataEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import List, Union
+    from typing import List
 except:
     pass
 
-class OperatorNodeT(object):
+class StringDataT(object):
 
-    # OperatorNodeT
+    # StringDataT
     def __init__(self):
-        self.type = 0  # type: int
-        self.attrsType = 0  # type: int
-        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT, EluAttrsT, RandomUniformLikeAttrsT, RandomNormalAttrsT, RandomNormalLikeAttrsT, GatherNDAttrsT]
-        self.inputs = None  # type: List[int]
-        self.outputs = None  # type: List[int]
+        self.data = None  # type: List[str]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        operatorNode = OperatorNode()
-        operatorNode.Init(buf, pos)
-        return cls.InitFromObj(operatorNode)
+        stringData = StringData()
+        stringData.Init(buf, pos)
+        return cls.InitFromObj(stringData)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4527,125 +8834,116 @@ class OperatorNodeT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, operatorNode):
-        x = OperatorNodeT()
-        x._UnPack(operatorNode)
+    def InitFromObj(cls, stringData):
+        x = StringDataT()
+        x._UnPack(stringData)
         return x
 
-    # OperatorNodeT
-    def _UnPack(self, operatorNode):
-        if operatorNode is None:
+    # StringDataT
+    def _UnPack(self, stringData):
+        if stringData is None:
             return
-        self.type = operatorNode.Type()
-        self.attrsType = operatorNode.AttrsType()
-        self.attrs = OperatorAttrsCreator(self.attrsType, operatorNode.Attrs())
-        if not operatorNode.InputsIsNone():
-            if np is None:
-                self.inputs = []
-                for i in range(operatorNode.InputsLength()):
-                    self.inputs.append(operatorNode.Inputs(i))
-            else:
-                self.inputs = operatorNode.InputsAsNumpy()
-        if not operatorNode.OutputsIsNone():
-            if np is None:
-                self.outputs = []
-                for i in range(operatorNode.OutputsLength()):
-                    self.outputs.append(operatorNode.Outputs(i))
-            else:
-                self.outputs = operatorNode.OutputsAsNumpy()
+        if not stringData.DataIsNone():
+            self.data = []
+            for i in range(stringData.DataLength()):
+                self.data.append(stringData.Data(i))
 
-    # OperatorNodeT
+    # StringDataT
     def Pack(self, builder):
-        if self.attrs is not None:
-            attrs = self.attrs.Pack(builder)
-        if self.inputs is not None:
-            if np is not None and type(self.inputs) is np.ndarray:
-                inputs = builder.CreateNumpyVector(self.inputs)
-            else:
-                OperatorNodeStartInputsVector(builder, len(self.inputs))
-                for i in reversed(range(len(self.inputs))):
-                    builder.PrependInt32(self.inputs[i])
-                inputs = builder.EndVector()
-        if self.outputs is not None:
-            if np is not None and type(self.outputs) is np.ndarray:
-                outputs = builder.CreateNumpyVector(self.outputs)
-            else:
-                OperatorNodeStartOutputsVector(builder, len(self.outputs))
-                for i in reversed(range(len(self.outputs))):
-                    builder.PrependInt32(self.outputs[i])
-                outputs = builder.EndVector()
-        OperatorNodeStart(builder)
-        OperatorNodeAddType(builder, self.type)
-        OperatorNodeAddAttrsType(builder, self.attrsType)
-        if self.attrs is not None:
-            OperatorNodeAddAttrs(builder, attrs)
-        if self.inputs is not None:
-            OperatorNodeAddInputs(builder, inputs)
-        if self.outputs is not None:
-            OperatorNodeAddOutputs(builder, outputs)
-        operatorNode = OperatorNodeEnd(builder)
-        return operatorNode
+        if self.data is not None:
+            datalist = []
+            for i in range(len(self.data)):
+                datalist.append(builder.CreateString(self.data[i]))
+            StringDataStartDataVector(builder, len(self.data))
+            for i in reversed(range(len(self.data))):
+                builder.PrependUOffsetTRelative(datalist[i])
+            data = builder.EndVector()
+        StringDataStart(builder)
+        if self.data is not None:
+            StringDataAddData(builder, data)
+        stringData = StringDataEnd(builder)
+        return stringData
 
 
-class FloatData(object):
+class CompressedData(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FloatData()
+        x = CompressedData()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFloatData(cls, buf, offset=0):
+    def GetRootAsCompressedData(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FloatDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CompressedDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FloatData
+    # CompressedData
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FloatData
-    def Data(self, j):
+    # CompressedData
+    def Compression(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # CompressedData
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # CompressedData
+    def Data(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 1))
         return 0
 
-    # FloatData
+    # CompressedData
     def DataAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint8Flags, o)
         return 0
 
-    # FloatData
+    # CompressedData
     def DataLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # FloatData
+    # CompressedData
     def DataIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         return o == 0
 
-def FloatDataStart(builder):
-    builder.StartObject(1)
+def CompressedDataStart(builder):
+    builder.StartObject(3)
 
-def FloatDataAddData(builder, data):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
+def CompressedDataAddCompression(builder, compression):
+    builder.PrependUint8Slot(0, compression, 0)
+
+def CompressedDataAddDtype(builder, dtype):
+    builder.PrependUint8Slot(1, dtype, 0)
+
+def CompressedDataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
 
-def FloatDataStartDataVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def CompressedDataStartDataVector(builder, numElems):
+    return builder.StartVector(1, numElems, 1)
 
-def FloatDataEnd(builder):
+def CompressedDataEnd(builder):
     return builder.EndObject()
 
 
@@ -4654,17 +8952,19 @@ try:
 except:
     pass
 
-class FloatDataT(object):
+class CompressedDataT(object):
 
-    # FloatDataT
+    # CompressedDataT
     def __init__(self):
-        self.data = None  # type: List[float]
+        self.compression = 0  # type: int
+        self.dtype = 0  # type: int
+        self.data = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        floatData = FloatData()
-        floatData.Init(buf, pos)
-        return cls.InitFromObj(floatData)
+        compressedData = CompressedData()
+        compressedData.Init(buf, pos)
+        return cls.InitFromObj(compressedData)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4672,99 +8972,103 @@ class FloatDataT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, floatData):
-        x = FloatDataT()
-        x._UnPack(floatData)
+    def InitFromObj(cls, compressedData):
+        x = CompressedDataT()
+        x._UnPack(compressedData)
         return x
 
-    # FloatDataT
-    def _UnPack(self, floatData):
-        if floatData is None:
+    # CompressedDataT
+    def _UnPack(self, compressedData):
+        if compressedData is None:
             return
-        if not floatData.DataIsNone():
+        self.compression = compressedData.Compression()
+        self.dtype = compressedData.Dtype()
+        if not compressedData.DataIsNone():
             if np is None:
                 self.data = []
-                for i in range(floatData.DataLength()):
-                    self.data.append(floatData.Data(i))
+                for i in range(compressedData.DataLength()):
+                    self.data.append(compressedData.Data(i))
             else:
-                self.data = floatData.DataAsNumpy()
+                self.data = compressedData.DataAsNumpy()
 
-    # FloatDataT
+    # CompressedDataT
     def Pack(self, builder):
         if self.data is not None:
             if np is not None and type(self.data) is np.ndarray:
                 data = builder.CreateNumpyVector(self.data)
             else:
-                FloatDataStartDataVector(builder, len(self.data))
+                CompressedDataStartDataVector(builder, len(self.data))
                 for i in reversed(range(len(self.data))):
-                    builder.PrependFloat32(self.data[i])
+                    builder.PrependUint8(self.data[i])
                 data = builder.EndVector()
-        FloatDataStart(builder)
+        CompressedDataStart(builder)
+        CompressedDataAddCompression(builder, self.compression)
+        CompressedDataAddDtype(builder, self.dtype)
         if self.data is not None:
-            FloatDataAddData(builder, data)
-        floatData = FloatDataEnd(builder)
-        return floatData
+            CompressedDataAddData(builder, data)
+        compressedData = CompressedDataEnd(builder)
+        return compressedData
 
 
-class IntData(object):
+class BFloat16Data(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = IntData()
+        x = BFloat16Data()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsIntData(cls, buf, offset=0):
+    def GetRootAsBFloat16Data(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def IntDataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def BFloat16DataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # IntData
+    # BFloat16Data
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # IntData
+    # BFloat16Data
     def Data(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Uint16Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 2))
         return 0
 
-    # IntData
+    # BFloat16Data
     def DataAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint16Flags, o)
         return 0
 
-    # IntData
+    # BFloat16Data
     def DataLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # IntData
+    # BFloat16Data
     def DataIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
-def IntDataStart(builder):
+def BFloat16DataStart(builder):
     builder.StartObject(1)
 
-def IntDataAddData(builder, data):
+def BFloat16DataAddData(builder, data):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
 
-def IntDataStartDataVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def BFloat16DataStartDataVector(builder, numElems):
+    return builder.StartVector(2, numElems, 2)
 
-def IntDataEnd(builder):
+def BFloat16DataEnd(builder):
     return builder.EndObject()
 
 
@@ -4773,17 +9077,17 @@ try:
 except:
     pass
 
-class IntDataT(object):
+class BFloat16DataT(object):
 
-    # IntDataT
+    # BFloat16DataT
     def __init__(self):
         self.data = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        intData = IntData()
-        intData.Init(buf, pos)
-        return cls.InitFromObj(intData)
+        bFloat16Data = BFloat16Data()
+        bFloat16Data.Init(buf, pos)
+        return cls.InitFromObj(bFloat16Data)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4791,38 +9095,38 @@ class IntDataT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, intData):
-        x = IntDataT()
-        x._UnPack(intData)
+    def InitFromObj(cls, bFloat16Data):
+        x = BFloat16DataT()
+        x._UnPack(bFloat16Data)
         return x
 
-    # IntDataT
-    def _UnPack(self, intData):
-        if intData is None:
+    # BFloat16DataT
+    def _UnPack(self, bFloat16Data):
+        if bFloat16Data is None:
             return
-        if not intData.DataIsNone():
+        if not bFloat16Data.DataIsNone():
             if np is None:
                 self.data = []
-                for i in range(intData.DataLength()):
-                    self.data.append(intData.Data(i))
+                for i in range(bFloat16Data.DataLength()):
+                    self.data.append(bFloat16Data.Data(i))
             else:
-                self.data = intData.DataAsNumpy()
+                self.data = bFloat16Data.DataAsNumpy()
 
-    # IntDataT
+    # BFloat16DataT
     def Pack(self, builder):
         if self.data is not None:
             if np is not None and type(self.data) is np.ndarray:
                 data = builder.CreateNumpyVector(self.data)
             else:
-                IntDataStartDataVector(builder, len(self.data))
+                BFloat16DataStartDataVector(builder, len(self.data))
                 for i in reversed(range(len(self.data))):
-                    builder.PrependInt32(self.data[i])
+                    builder.PrependUint16(self.data[i])
                 data = builder.EndVector()
-        IntDataStart(builder)
+        BFloat16DataStart(builder)
         if self.data is not None:
-            IntDataAddData(builder, data)
-        intData = IntDataEnd(builder)
-        return intData
+            BFloat16DataAddData(builder, data)
+        bFloat16Data = BFloat16DataEnd(builder)
+        return bFloat16Data
 
 
 class ConstantNode(object):
@@ -4921,7 +9225,7 @@ class ConstantNodeT(object):
     def __init__(self):
         self.shape = None  # type: List[int]
         self.dataType = 0  # type: int
-        self.data = None  # type: Union[None, FloatDataT, IntDataT]
+        self.data = None  # type: Union[None, FloatDataT, IntDataT, StringDataT, CompressedDataT, BFloat16DataT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -5115,8 +9419,22 @@ class ValueNode(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
+    # ValueNode
+    def Optional(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # ValueNode
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
+
 def ValueNodeStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(3)
 
 def ValueNodeAddShape(builder, shape):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
@@ -5124,6 +9442,12 @@ def ValueNodeAddShape(builder, shape):
 def ValueNodeStartShapeVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
+def ValueNodeAddOptional(builder, optional):
+    builder.PrependBoolSlot(1, optional, 0)
+
+def ValueNodeAddDtype(builder, dtype):
+    builder.PrependUint8Slot(2, dtype, None)
+
 def ValueNodeEnd(builder):
     return builder.EndObject()
 
@@ -5138,6 +9462,8 @@ class ValueNodeT(object):
     # ValueNodeT
     def __init__(self):
         self.shape = None  # type: List[DimT]
+        self.optional = False  # type: bool
+        self.dtype = None  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -5168,6 +9494,8 @@ class ValueNodeT(object):
                 else:
                     dim_ = DimT.InitFromObj(valueNode.Shape(i))
                     self.shape.append(dim_)
+        self.optional = valueNode.Optional()
+        self.dtype = valueNode.Dtype()
 
     # ValueNodeT
     def Pack(self, builder):
@@ -5182,6 +9510,8 @@ class ValueNodeT(object):
         ValueNodeStart(builder)
         if self.shape is not None:
             ValueNodeAddShape(builder, shape)
+        ValueNodeAddOptional(builder, self.optional)
+        ValueNodeAddDtype(builder, self.dtype)
         valueNode = ValueNodeEnd(builder)
         return valueNode
 
@@ -5521,6 +9851,195 @@ class GraphT(object):
         return graph
 
 
+class OpsetImport(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = OpsetImport()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsOpsetImport(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def OpsetImportBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # OpsetImport
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # OpsetImport
+    def Domain(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # OpsetImport
+    def Version(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, o + self._tab.Pos)
+        return 0
+
+def OpsetImportStart(builder):
+    builder.StartObject(2)
+
+def OpsetImportAddDomain(builder, domain):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(domain), 0)
+
+def OpsetImportAddVersion(builder, version):
+    builder.PrependInt64Slot(1, version, 0)
+
+def OpsetImportEnd(builder):
+    return builder.EndObject()
+
+
+
+class OpsetImportT(object):
+
+    # OpsetImportT
+    def __init__(self):
+        self.domain = None  # type: str
+        self.version = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        opsetImport = OpsetImport()
+        opsetImport.Init(buf, pos)
+        return cls.InitFromObj(opsetImport)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, opsetImport):
+        x = OpsetImportT()
+        x._UnPack(opsetImport)
+        return x
+
+    # OpsetImportT
+    def _UnPack(self, opsetImport):
+        if opsetImport is None:
+            return
+        self.domain = opsetImport.Domain()
+        self.version = opsetImport.Version()
+
+    # OpsetImportT
+    def Pack(self, builder):
+        if self.domain is not None:
+            domain = builder.CreateString(self.domain)
+        OpsetImportStart(builder)
+        if self.domain is not None:
+            OpsetImportAddDomain(builder, domain)
+        OpsetImportAddVersion(builder, self.version)
+        opsetImport = OpsetImportEnd(builder)
+        return opsetImport
+
+
+class MetadataProp(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MetadataProp()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMetadataProp(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def MetadataPropBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # MetadataProp
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MetadataProp
+    def Key(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # MetadataProp
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def MetadataPropStart(builder):
+    builder.StartObject(2)
+
+def MetadataPropAddKey(builder, key):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(key), 0)
+
+def MetadataPropAddValue(builder, value):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(value), 0)
+
+def MetadataPropEnd(builder):
+    return builder.EndObject()
+
+
+
+class MetadataPropT(object):
+
+    # MetadataPropT
+    def __init__(self):
+        self.key = None  # type: str
+        self.value = None  # type: str
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        metadataProp = MetadataProp()
+        metadataProp.Init(buf, pos)
+        return cls.InitFromObj(metadataProp)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, metadataProp):
+        x = MetadataPropT()
+        x._UnPack(metadataProp)
+        return x
+
+    # MetadataPropT
+    def _UnPack(self, metadataProp):
+        if metadataProp is None:
+            return
+        self.key = metadataProp.Key()
+        self.value = metadataProp.Value()
+
+    # MetadataPropT
+    def Pack(self, builder):
+        if self.key is not None:
+            key = builder.CreateString(self.key)
+        if self.value is not None:
+            value = builder.CreateString(self.value)
+        MetadataPropStart(builder)
+        if self.key is not None:
+            MetadataPropAddKey(builder, key)
+        if self.value is not None:
+            MetadataPropAddValue(builder, value)
+        metadataProp = MetadataPropEnd(builder)
+        return metadataProp
+
+
 class Metadata(object):
     __slots__ = ['_tab']
 
@@ -5599,8 +10118,77 @@ class Metadata(object):
             return self._tab.String(o + self._tab.Pos)
         return None
 
+    # Metadata
+    def ProducerName(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(20))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Metadata
+    def ProducerVersion(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(22))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Metadata
+    def DocString(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(24))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Metadata
+    def OpsetImports(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(26))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = OpsetImport()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # Metadata
+    def OpsetImportsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(26))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Metadata
+    def OpsetImportsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(26))
+        return o == 0
+
+    # Metadata
+    def MetadataProps(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(28))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = MetadataProp()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # Metadata
+    def MetadataPropsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(28))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Metadata
+    def MetadataPropsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(28))
+        return o == 0
+
 def MetadataStart(builder):
-    builder.StartObject(8)
+    builder.StartObject(13)
 
 def MetadataAddOnnxHash(builder, onnxHash):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(onnxHash), 0)
@@ -5626,10 +10214,35 @@ def MetadataAddRunId(builder, runId):
 def MetadataAddRunUrl(builder, runUrl):
     builder.PrependUOffsetTRelativeSlot(7, flatbuffers.number_types.UOffsetTFlags.py_type(runUrl), 0)
 
+def MetadataAddProducerName(builder, producerName):
+    builder.PrependUOffsetTRelativeSlot(8, flatbuffers.number_types.UOffsetTFlags.py_type(producerName), 0)
+
+def MetadataAddProducerVersion(builder, producerVersion):
+    builder.PrependUOffsetTRelativeSlot(9, flatbuffers.number_types.UOffsetTFlags.py_type(producerVersion), 0)
+
+def MetadataAddDocString(builder, docString):
+    builder.PrependUOffsetTRelativeSlot(10, flatbuffers.number_types.UOffsetTFlags.py_type(docString), 0)
+
+def MetadataAddOpsetImports(builder, opsetImports):
+    builder.PrependUOffsetTRelativeSlot(11, flatbuffers.number_types.UOffsetTFlags.py_type(opsetImports), 0)
+
+def MetadataStartOpsetImportsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MetadataAddMetadataProps(builder, metadataProps):
+    builder.PrependUOffsetTRelativeSlot(12, flatbuffers.number_types.UOffsetTFlags.py_type(metadataProps), 0)
+
+def MetadataStartMetadataPropsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def MetadataEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
 class MetadataT(object):
 
@@ -5643,6 +10256,11 @@ class MetadataT(object):
         self.modelRepository = None  # type: str
         self.runId = None  # type: str
         self.runUrl = None  # type: str
+        self.producerName = None  # type: str
+        self.producerVersion = None  # type: str
+        self.docString = None  # type: str
+        self.opsetImports = None  # type: List[OpsetImportT]
+        self.metadataProps = None  # type: List[MetadataPropT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -5673,6 +10291,25 @@ class MetadataT(object):
         self.modelRepository = metadata.ModelRepository()
         self.runId = metadata.RunId()
         self.runUrl = metadata.RunUrl()
+        self.producerName = metadata.ProducerName()
+        self.producerVersion = metadata.ProducerVersion()
+        self.docString = metadata.DocString()
+        if not metadata.OpsetImportsIsNone():
+            self.opsetImports = []
+            for i in range(metadata.OpsetImportsLength()):
+                if metadata.OpsetImports(i) is None:
+                    self.opsetImports.append(None)
+                else:
+                    opsetImport_ = OpsetImportT.InitFromObj(metadata.OpsetImports(i))
+                    self.opsetImports.append(opsetImport_)
+        if not metadata.MetadataPropsIsNone():
+            self.metadataProps = []
+            for i in range(metadata.MetadataPropsLength()):
+                if metadata.MetadataProps(i) is None:
+                    self.metadataProps.append(None)
+                else:
+                    metadataProp_ = MetadataPropT.InitFromObj(metadata.MetadataProps(i))
+                    self.metadataProps.append(metadataProp_)
 
     # MetadataT
     def Pack(self, builder):
@@ -5692,6 +10329,28 @@ class MetadataT(object):
             runId = builder.CreateString(self.runId)
         if self.runUrl is not None:
             runUrl = builder.CreateString(self.runUrl)
+        if self.producerName is not None:
+            producerName = builder.CreateString(self.producerName)
+        if self.producerVersion is not None:
+            producerVersion = builder.CreateString(self.producerVersion)
+        if self.docString is not None:
+            docString = builder.CreateString(self.docString)
+        if self.opsetImports is not None:
+            opsetImportslist = []
+            for i in range(len(self.opsetImports)):
+                opsetImportslist.append(self.opsetImports[i].Pack(builder))
+            MetadataStartOpsetImportsVector(builder, len(self.opsetImports))
+            for i in reversed(range(len(self.opsetImports))):
+                builder.PrependUOffsetTRelative(opsetImportslist[i])
+            opsetImports = builder.EndVector()
+        if self.metadataProps is not None:
+            metadataPropslist = []
+            for i in range(len(self.metadataProps)):
+                metadataPropslist.append(self.metadataProps[i].Pack(builder))
+            MetadataStartMetadataPropsVector(builder, len(self.metadataProps))
+            for i in reversed(range(len(self.metadataProps))):
+                builder.PrependUOffsetTRelative(metadataPropslist[i])
+            metadataProps = builder.EndVector()
         MetadataStart(builder)
         if self.onnxHash is not None:
             MetadataAddOnnxHash(builder, onnxHash)
@@ -5709,6 +10368,16 @@ class MetadataT(object):
             MetadataAddRunId(builder, runId)
         if self.runUrl is not None:
             MetadataAddRunUrl(builder, runUrl)
+        if self.producerName is not None:
+            MetadataAddProducerName(builder, producerName)
+        if self.producerVersion is not None:
+            MetadataAddProducerVersion(builder, producerVersion)
+        if self.docString is not None:
+            MetadataAddDocString(builder, docString)
+        if self.opsetImports is not None:
+            MetadataAddOpsetImports(builder, opsetImports)
+        if self.metadataProps is not None:
+            MetadataAddMetadataProps(builder, metadataProps)
         metadata = MetadataEnd(builder)
         return metadata
 
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 
 use rten::{FloatOperators, Model, Operators};
@@ -309,17 +309,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         image = image.resize_image([rescaled_height, rescaled_width])?;
     }
 
-    let pixel_input_id = model.node_id("pixel_values")?;
-    let logits_output_id = model.node_id("logits")?;
-    let boxes_output_id = model.node_id("pred_boxes")?;
-
-    let [logits, boxes] = model.run_n(
-        &[(pixel_input_id, image.view().into())],
-        [logits_output_id, boxes_output_id],
-        None,
-    )?;
-    let logits: NdTensor<f32, 3> = logits.try_into()?;
-    let boxes: NdTensor<f32, 3> = boxes.try_into()?;
+    let inputs = HashMap::from([("pixel_values", image.view().into())]);
+    let mut outputs = model.run_map(&inputs, &["logits", "pred_boxes"], None)?;
+    let logits: NdTensor<f32, 3> = outputs.take("logits")?;
+    let boxes: NdTensor<f32, 3> = outputs.take("pred_boxes")?;
 
     let probs: NdTensor<f32, 3> = logits.softmax(-1 /* axis */)?.try_into()?;
     let classes: NdTensor<i32, 2> = logits
@@ -1,30 +1,39 @@
 use std::collections::VecDeque;
 use std::error::Error;
 
-use rten::ctc::CtcDecoder;
-use rten::Model;
+use rten::ctc::{group_words, CtcDecoder};
+use rten::{FloatOperators, Model};
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensor, Tensor};
 
 struct Args {
     model: String,
     wav_file: String,
+
+    /// Print the start and end time of each word.
+    timestamps: bool,
 }
 
 fn parse_args() -> Result<Args, lexopt::Error> {
     use lexopt::prelude::*;
 
     let mut values = VecDeque::new();
+    let mut timestamps = false;
 
     let mut parser = lexopt::Parser::from_env();
     while let Some(arg) = parser.next()? {
         match arg {
             Value(val) => values.push_back(val.string()?),
+            Long("timestamps") => timestamps = true,
             Long("help") => {
                 println!(
                     "Recognize speech in .wav files.
 
 Usage: {bin_name} <model_path> <wav_file>
+
+Options:
+
+  --timestamps  Print the start and end time of each word
 ",
                     bin_name = parser.bin_name().unwrap_or("wav2vec2")
                 );
@@ -37,7 +46,11 @@ Usage: {bin_name} <model_path> <wav_file>
     let model = values.pop_front().ok_or("missing `model` arg")?;
     let wav_file = values.pop_front().ok_or("missing `wav_file` arg")?;
 
-    let args = Args { model, wav_file };
+    let args = Args {
+        model,
+        wav_file,
+        timestamps,
+    };
 
     Ok(args)
 }
@@ -97,6 +110,8 @@ fn read_wav_file(path: &str) -> Result<Vec<f32>, hound::Error> {
 ///    cargo run --release --bin wav2vec2 wav2vec.rten output.wav
 ///    ```
 ///
+///    Add `--timestamps` to also print the time span of each word.
+///
 /// [^1]: <https://ai.meta.com/blog/wav2vec-20-learning-the-structure-of-speech-from-raw-audio/>
 /// [^2]: <https://huggingface.co/facebook/wav2vec2-base-960h>
 /// [^3]: <https://huggingface.co/docs/optimum/main/en/exporters/onnx/usage_guides/export_a_model>
@@ -126,5 +141,30 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     println!("{}", text);
 
+    if args.timestamps {
+        // Align the transcript to the model's output to find the positions
+        // of each word. The model produces one output per 20ms of audio.
+        let frame_secs = 0.02;
+        let log_probs = result.slice::<2, _>([0]).softmax(-1)?.map(|x| x.ln());
+        let labels: Vec<u32> = hypothesis.steps().iter().map(|step| step.label).collect();
+        let separator = vocab.chars().position(|c| c == '|').unwrap() as u32 + 1;
+
+        if let Some(steps) = decoder.align(log_probs.nd_view(), &labels) {
+            for word in group_words(&steps, separator) {
+                let word_text: String = text
+                    .chars()
+                    .skip(word.steps.start)
+                    .take(word.steps.len())
+                    .collect();
+                println!(
+                    "{:.2}s - {:.2}s: {}",
+                    word.start as f32 * frame_secs,
+                    word.end as f32 * frame_secs,
+                    word_text
+                );
+            }
+        }
+    }
+
     Ok(())
 }
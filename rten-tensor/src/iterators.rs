@@ -141,10 +141,11 @@ impl IndexingIterBase {
         let dims = zip(padded_tensor_shape, shape.iter())
             .enumerate()
             .map(|(dim, (&actual_len, &broadcast_len))| {
-                // If the dimension is being broadcast, set its stride to 0 so
-                // that when we increment in this dimension, we just repeat
-                // elements. Otherwise, use the real stride.
-                let offset_step = if actual_len == broadcast_len {
+                // If the dimension is being broadcast, or is one of the
+                // padding dimensions, set its stride to 0 so that when we
+                // increment in this dimension, we just repeat elements.
+                // Otherwise, use the real stride.
+                let offset_step = if dim >= added_dims && actual_len == broadcast_len {
                     layout.stride(dim - added_dims) as isize
                 } else {
                     0
@@ -173,6 +173,20 @@ pub trait Layout {
     }
 }
 
+/// Return the range of storage used by a slice whose first element is at
+/// `offset` and which spans `len` elements.
+///
+/// Empty slices map to an empty range at the start of the storage, since the
+/// offset computed for an empty slice may lie beyond the end of the (possibly
+/// empty) storage.
+fn slice_data_range(offset: usize, len: usize) -> Range<usize> {
+    if len == 0 {
+        0..0
+    } else {
+        offset..offset + len
+    }
+}
+
 /// Provides convenience methods for querying the shape and strides of a matrix.
 pub trait MatrixLayout {
     fn rows(&self) -> usize;
@@ -473,7 +487,7 @@ impl<const N: usize> NdLayout<N> {
         assert!(ndim == M, "sliced dims != {}", M);
 
         let layout = NdLayout { shape, strides };
-        (offset..offset + layout.min_data_len(), layout)
+        (slice_data_range(offset, layout.min_data_len()), layout)
     }
 
     pub fn resize_dim(&mut self, dim: usize, new_size: usize) {
@@ -538,10 +552,12 @@ impl Layout for DynLayout {
         let shape = self.shape();
         let strides = self.strides();
         let mut valid = index.as_ref().len() == shape.len();
-        let mut offset = 0;
+        let mut offset: usize = 0;
         for (idx, (size, stride)) in index.as_ref().iter().zip(shape.iter().zip(strides.iter())) {
             valid = valid && idx < size;
-            offset += idx * stride;
+            // The offset is discarded if the index is invalid, so wrapping
+            // arithmetic avoids an overflow panic for large invalid indices.
+            offset = offset.wrapping_add(idx.wrapping_mul(*stride));
         }
         valid.then_some(offset)
     }
@@ -700,7 +716,7 @@ impl DynLayout {
             slice_layout(self.shape(), self.strides(), out_shape, out_strides, range)?;
 
         let layout = Self { shape_and_strides };
-        Ok((offset..offset + layout.min_data_len(), layout))
+        Ok((slice_data_range(offset, layout.min_data_len()), layout))
     }
 
     pub fn resize_dim(&mut self, dim: usize, new_size: usize) {
@@ -322,6 +322,10 @@ impl SliceRange {
 
         if self.step > 0 {
             IndexRange::new(resolved.start, resolved.end as isize, self.step)
+        } else if resolved.is_empty() {
+            // A backwards range may start before the first element, in which
+            // case its start cannot be mapped to an index.
+            IndexRange::new(0, 0, self.step)
         } else {
             IndexRange::new(
                 dim_size - 1 - resolved.start,
@@ -617,6 +621,18 @@ mod tests {
                 dim_size: 4,
                 indices: vec![],
             },
+            // -ve step, start before first element
+            Case {
+                range: SliceItem::range(-4, Some(0), -3),
+                dim_size: 3,
+                indices: vec![],
+            },
+            // -ve step, empty dimension
+            Case {
+                range: SliceItem::range(-1, None, -1),
+                dim_size: 0,
+                indices: vec![],
+            },
             // Single index
             Case {
                 range: SliceItem::Index(2),
@@ -540,9 +540,10 @@ impl<S: StorageMut, L: MutLayout> TensorBase<S, L> {
 
     /// Return the data in this tensor as a slice if it is contiguous.
     pub fn data_mut(&mut self) -> Option<&mut [S::Elem]> {
-        self.layout.is_contiguous().then_some(unsafe {
+        let len = self.layout.len();
+        self.layout.is_contiguous().then(|| unsafe {
             // Safety: We verified the layout is contiguous.
-            self.data.as_slice_mut()
+            &mut self.data.as_slice_mut()[..len]
         })
     }
 
@@ -1093,9 +1094,11 @@ impl<'a, T, L: Clone + MutLayout> TensorBase<ViewData<'a, T>, L> {
     /// the order of elements in the slice is the same as the logical order
     /// yielded by `iter`, and there are no gaps.
     pub fn data(&self) -> Option<&'a [T]> {
-        self.layout.is_contiguous().then_some(unsafe {
-            // Safety: Storage is contigous
-            self.data.as_slice()
+        self.layout.is_contiguous().then(|| unsafe {
+            // Safety: Storage is contigous. The storage may be longer than
+            // the layout if the layout is empty, eg. after broadcasting to a
+            // shape with a zero-sized dimension.
+            &self.data.as_slice()[..self.layout.len()]
         })
     }
 
@@ -1806,6 +1809,7 @@ where
 pub trait Scalar {}
 
 impl Scalar for i32 {}
+impl Scalar for i64 {}
 impl Scalar for f32 {}
 
 // The `T: Scalar` bound avoids ambiguity when choosing a `Tensor::from`
@@ -2103,6 +2107,13 @@ mod tests {
         let view = tensor.broadcast(dest_shape.as_slice());
         assert_eq!(view.shape(), dest_shape);
         assert_eq!(view.to_vec(), expected_data);
+
+        // Broadcast to a shape with a zero-sized dimension. The resulting
+        // layout is considered contiguous, but has no elements.
+        let row = NdTensor::from_data([1, 2], vec![1., 2.]);
+        let view = row.broadcast([2, 0, 2]);
+        assert_eq!(view.data(), Some([].as_slice()));
+        assert_eq!(view.to_tensor().shape(), [2, 0, 2]);
     }
 
     #[test]
@@ -2110,6 +2121,12 @@ mod tests {
         let tensor = NdTensor::from_data([1], vec![3]);
         let elems: Vec<_> = tensor.broadcast_iter(&[2, 2]).copied().collect();
         assert_eq!(elems, &[3, 3, 3, 3]);
+
+        // Broadcast a zero-size tensor with added dimensions.
+        let tensor = Tensor::<i32>::zeros(&[0, 1, 3, 2]);
+        let mut elems = tensor.broadcast_iter(&[1, 0, 2, 3, 2]);
+        assert_eq!(elems.len(), 0);
+        assert_eq!(elems.next(), None);
     }
 
     #[test]
@@ -2369,6 +2386,7 @@ mod tests {
         assert_eq!(tensor.get([1, 1]), Some(&4.));
         assert_eq!(tensor.get([2, 1]), None); // Invalid index
         assert_eq!(tensor.get([1, 2, 3]), None); // Incorrect dim count
+        assert_eq!(tensor.get([usize::MAX, 1]), None); // Offset would overflow
 
         // Impl for views
         assert_eq!(tensor.view().get([1, 1]), Some(&4.));
@@ -3004,6 +3022,11 @@ mod tests {
         let row_two = tensor.slice_dyn(1);
         assert_eq!(row_two[[0]], 3.);
         assert_eq!(row_two[[1]], 4.);
+
+        // Slice an empty tensor along a non-empty dimension.
+        let empty = Tensor::<f32>::zeros(&[0, 3]);
+        let col = empty.slice_dyn((.., 2));
+        assert_eq!(col.shape(), &[0]);
     }
 
     #[test]
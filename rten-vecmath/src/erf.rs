@@ -22,7 +22,7 @@ pub fn erf(x: f32) -> f32 {
 ///
 /// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
 #[inline(always)]
-unsafe fn simd_erf<S: SimdFloat>(x: S) -> S {
+pub(crate) unsafe fn simd_erf<S: SimdFloat>(x: S) -> S {
     let neg_mask = x.lt(S::zero());
 
     // x = x.abs()
@@ -0,0 +1,146 @@
+//! Vectorized Gaussian Error Linear Unit (GELU) activation.
+
+#![allow(clippy::excessive_precision)]
+
+use std::mem::MaybeUninit;
+
+use crate::erf::simd_erf;
+use crate::simd_vec::SimdFloat;
+use crate::tanh::simd_tanh;
+use crate::{dispatch_unary_op, dispatch_unary_op_in_place, SimdUnaryOp};
+
+const SQRT_2_PI: f32 = 0.7978845608028654; // sqrt(2 / pi)
+
+/// Computes `0.5 * x * (1 + erf(x / sqrt(2)))`.
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[inline(always)]
+unsafe fn simd_gelu<S: SimdFloat>(x: S) -> S {
+    let half_x = x.mul(S::splat(0.5));
+    let y = simd_erf(x.mul(S::splat(std::f32::consts::FRAC_1_SQRT_2)));
+    half_x.mul_add(y, half_x)
+}
+
+/// Computes the tanh approximation of GELU,
+/// `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`.
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[inline(always)]
+unsafe fn simd_gelu_tanh<S: SimdFloat>(x: S) -> S {
+    let half_x = x.mul(S::splat(0.5));
+    let x3 = x.mul(x).mul(x);
+    let inner = x3.mul_add(S::splat(0.044715), x).mul(S::splat(SQRT_2_PI));
+    half_x.mul_add(simd_tanh(inner), half_x)
+}
+
+/// Computes the GELU function using the exact, erf-based formulation.
+pub fn gelu(x: f32) -> f32 {
+    // Safety: f32 is available on all platforms
+    unsafe { simd_gelu(x) }
+}
+
+/// Computes the GELU function using the tanh approximation.
+pub fn gelu_tanh(x: f32) -> f32 {
+    // Safety: f32 is available on all platforms
+    unsafe { simd_gelu_tanh(x) }
+}
+
+struct SimdGelu {}
+impl SimdUnaryOp for SimdGelu {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_gelu(x)
+    }
+}
+
+struct SimdGeluTanh {}
+impl SimdUnaryOp for SimdGeluTanh {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_gelu_tanh(x)
+    }
+}
+
+/// Vectorized GELU function.
+///
+/// This is a vectorized version of [gelu] that computes the function for each
+/// element in `xs` and writes the result to `out`. `xs` and `out` must be equal
+/// in length.
+pub fn vec_gelu(xs: &[f32], out: &mut [MaybeUninit<f32>]) {
+    dispatch_unary_op(xs, out, SimdGelu {});
+}
+
+/// Variant of [vec_gelu] that modifies elements in-place.
+pub fn vec_gelu_in_place(xs: &mut [f32]) {
+    dispatch_unary_op_in_place(xs, SimdGelu {});
+}
+
+/// Vectorized tanh approximation of the GELU function.
+///
+/// This is a vectorized version of [gelu_tanh]. It is faster than [vec_gelu]
+/// but less accurate.
+pub fn vec_gelu_tanh(xs: &[f32], out: &mut [MaybeUninit<f32>]) {
+    dispatch_unary_op(xs, out, SimdGeluTanh {});
+}
+
+/// Variant of [vec_gelu_tanh] that modifies elements in-place.
+pub fn vec_gelu_tanh_in_place(xs: &mut [f32]) {
+    dispatch_unary_op_in_place(xs, SimdGeluTanh {});
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_gelu, vec_gelu_tanh};
+
+    use crate::testing::{arange, benchmark_op, check_f32s_are_equal_atol, triples, AsUninit};
+
+    fn reference_gelu(x: f32) -> f32 {
+        let x = x as f64;
+        (0.5 * x * (1. + libm::erf(x / std::f64::consts::SQRT_2))) as f32
+    }
+
+    fn reference_gelu_tanh(x: f32) -> f32 {
+        let x = x as f64;
+        let k = (2. / std::f64::consts::PI).sqrt();
+        (0.5 * x * (1. + (k * (x + 0.044715 * x.powi(3))).tanh())) as f32
+    }
+
+    #[test]
+    fn test_gelu() {
+        let input: Vec<_> = arange(-8., 8., 0.001f32).collect();
+        let mut actual = vec![0.; input.len()];
+        let expected: Vec<_> = input.iter().copied().map(reference_gelu).collect();
+
+        vec_gelu(&input, actual.as_mut_slice().as_uninit());
+
+        // The error is dominated by the error of `erf`, scaled by `x / 2`.
+        check_f32s_are_equal_atol(triples(&input, &actual, &expected), 4e-6);
+    }
+
+    #[test]
+    fn test_gelu_tanh() {
+        let input: Vec<_> = arange(-8., 8., 0.001f32).collect();
+        let mut actual = vec![0.; input.len()];
+        let expected: Vec<_> = input.iter().copied().map(reference_gelu_tanh).collect();
+
+        vec_gelu_tanh(&input, actual.as_mut_slice().as_uninit());
+        check_f32s_are_equal_atol(triples(&input, &actual, &expected), 4e-6);
+
+        // The tanh approximation should be close to the exact function.
+        let exact: Vec<_> = input.iter().copied().map(reference_gelu).collect();
+        check_f32s_are_equal_atol(triples(&input, &actual, &exact), 1e-3);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_gelu() {
+        benchmark_op(
+            |xs, ys| {
+                xs.iter()
+                    .zip(ys.iter_mut())
+                    .for_each(|(x, y)| *y = reference_gelu(*x))
+            },
+            vec_gelu,
+        );
+    }
+}
@@ -49,8 +49,12 @@ use std::mem::MaybeUninit;
 
 mod erf;
 mod exp;
+mod gelu;
+mod log;
+mod mul_add;
 pub mod simd_vec;
 mod softmax;
+mod sum;
 mod tanh;
 mod ulp;
 
@@ -59,8 +63,14 @@ mod testing;
 
 pub use erf::{erf, vec_erf, vec_erf_in_place};
 pub use exp::{exp, sigmoid, vec_exp, vec_exp_in_place, vec_sigmoid, vec_sigmoid_in_place};
+pub use gelu::{
+    gelu, gelu_tanh, vec_gelu, vec_gelu_in_place, vec_gelu_tanh, vec_gelu_tanh_in_place,
+};
+pub use log::{log, softplus, vec_log, vec_log_in_place, vec_softplus, vec_softplus_in_place};
+pub use mul_add::vec_mul_add_in_place;
 use simd_vec::SimdFloat;
 pub use softmax::{vec_softmax, vec_softmax_in_place};
+pub use sum::{vec_dot, vec_dot_packed, vec_sum, vec_sum_square, vec_sum_square_sub};
 pub use tanh::{tanh, vec_tanh, vec_tanh_in_place};
 
 /// Detect availability of AVX-512 on macOS, where `is_x86_feature_detected`
@@ -605,3 +615,68 @@ fn dispatch_simd_op<Op: SimdOp>(input: PtrLen<f32>, out: MutPtrLen<MaybeUninit<f
     // Generic fallback.
     unsafe { op.eval::<f32>(input, out) };
 }
+
+/// Trait for evaluating a SIMD operation which reduces its inputs to a single
+/// value, such as a sum or dot product.
+trait SimdReduceOp {
+    /// Evaluate the reduction and return the result.
+    unsafe fn eval<S: SimdFloat>(&self) -> f32;
+}
+
+/// Evaluate a vectorized reduction to a scalar value.
+///
+/// This function will dispatch to the best SIMD implementation for the current
+/// platform.
+#[allow(unused_imports)]
+#[allow(unreachable_code)] // Ignore fallback, if unused
+fn dispatch_simd_reduce_op<Op: SimdReduceOp>(op: Op) -> f32 {
+    #[cfg(feature = "avx512")]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx512f")]
+    #[target_feature(enable = "avx512vl")]
+    unsafe fn simd_reduce_op_avx512<Op: SimdReduceOp>(op: Op) -> f32 {
+        use std::arch::x86_64::__m512;
+        op.eval::<__m512>()
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    #[target_feature(enable = "fma")]
+    unsafe fn simd_reduce_op_avx<Op: SimdReduceOp>(op: Op) -> f32 {
+        use std::arch::x86_64::__m256;
+        op.eval::<__m256>()
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        #[cfg(feature = "avx512")]
+        if crate::is_avx512_supported() {
+            return unsafe { simd_reduce_op_avx512(op) };
+        }
+
+        if is_x86_feature_detected!("fma") && is_x86_feature_detected!("avx2") {
+            // Safety: We've checked that AVX2 + FMA are available.
+            return unsafe { simd_reduce_op_avx(op) };
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[cfg(target_feature = "simd128")]
+    {
+        use crate::simd_vec::wasm::v128f;
+
+        // Safety: The WASM runtime will have verified SIMD instructions
+        // are accepted when loading the binary.
+        return unsafe { op.eval::<v128f>() };
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        use std::arch::aarch64::float32x4_t;
+
+        return unsafe { op.eval::<float32x4_t>() };
+    }
+
+    // Generic fallback.
+    unsafe { op.eval::<f32>() }
+}
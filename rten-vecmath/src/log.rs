@@ -0,0 +1,299 @@
+//! Vectorized natural logarithm and closely related functions.
+
+#![allow(clippy::excessive_precision)]
+
+use std::mem::MaybeUninit;
+
+use crate::exp::simd_exp;
+use crate::simd_vec::{SimdFloat, SimdInt};
+use crate::{dispatch_unary_op, dispatch_unary_op_in_place, SimdUnaryOp};
+
+const SQRT_HALF: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+// `log(2)` split into large and small parts, such that the large part is
+// exactly representable with few enough bits that `e * LOG2_HI` is exact.
+const LOG2_HI: f32 = 0.693359375;
+const LOG2_LO: f32 = -2.12194440e-4;
+
+// Coefficients of polynomial used to approximate `log(1 + x) - x + x^2/2`
+// for `x` in `[sqrt(0.5) - 1, sqrt(2) - 1]`. From the Cephes library.
+const LOG_POLY: [f32; 9] = [
+    3.3333331174e-1,
+    -2.4999993993e-1,
+    2.0000714765e-1,
+    -1.6668057665e-1,
+    1.4249322787e-1,
+    -1.2420140846e-1,
+    1.1676998740e-1,
+    -1.1514610310e-1,
+    7.0376836292e-2,
+];
+
+/// Computes the natural logarithm of `x`. Functionally equivalent to
+/// [f32::ln].
+///
+/// This is scalar variant of [vec_log] that uses exactly the same algorithm.
+pub fn log(x: f32) -> f32 {
+    // Safety: f32 is available on all systems.
+    unsafe { simd_log(x) }
+}
+
+/// Vectorized natural logarithm.
+///
+/// Method outline:
+///
+///  1. Split `x` into a mantissa and exponent such that `x = m * 2**e` and
+///     `sqrt(0.5) <= m < sqrt(2)`. This uses bit manipulation of the float
+///     representation. Subnormal inputs are scaled up first.
+///
+///  2. Compute `log(m)` using a polynomial approximation of `log(1 + r)` where
+///     `r = m - 1`.
+///
+///  3. Compute the result as `log(x) = log(m) + e * ln2`.
+///
+/// This has a maximum error of 2 ULPs compared to `f32::ln` in the Rust
+/// standard library.
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[inline(always)]
+pub(crate) unsafe fn simd_log<S: SimdFloat>(x: S) -> S {
+    // Scale subnormal inputs into the normal range.
+    let subnormal = x.lt(S::splat(f32::MIN_POSITIVE));
+    let scaled_x = x.blend(x.mul(S::splat((1 << 23) as f32)), subnormal);
+
+    // Extract exponent and mantissa, such that the mantissa is in `[0.5, 1)`.
+    let bits = scaled_x.reinterpret_as_int();
+    let exp = bits.shr::<23>().sub(S::Int::splat(126));
+    let mantissa = bits.sub(exp.shl::<23>()).reinterpret_as_float();
+
+    // Convert exponent to float. This is exact for the range of exponents
+    // which can occur here.
+    let rounding_magic = S::splat(12582912.); // 0x3 << 22
+    let exp = exp
+        .add(rounding_magic.reinterpret_as_int())
+        .reinterpret_as_float()
+        .sub(rounding_magic);
+    let exp = exp.blend(exp.sub(S::splat(23.)), subnormal);
+
+    // Adjust range of mantissa from `[0.5, 1)` to `[sqrt(0.5), sqrt(2))`.
+    let small_mantissa = mantissa.lt(S::splat(SQRT_HALF));
+    let exp = exp.blend(exp.sub(S::one()), small_mantissa);
+    let r = mantissa
+        .blend(mantissa.add(mantissa), small_mantissa)
+        .sub(S::one());
+
+    // Approximate `log(1 + r)`.
+    let r2 = r.mul(r);
+    let mut y = S::splat(LOG_POLY[LOG_POLY.len() - 1]);
+    for coeff in LOG_POLY.iter().rev().skip(1) {
+        y = y.mul_add(r, S::splat(*coeff));
+    }
+    let y = y.mul(r).mul(r2);
+    let y = exp.mul_add(S::splat(LOG2_LO), y);
+    let y = r2.mul_add(S::splat(-0.5), y);
+    let y = r.add(y);
+    let y = exp.mul_add(S::splat(LOG2_HI), y);
+
+    // Handle special cases. Comparisons with NaN are false, so NaN inputs
+    // are passed through unchanged.
+    let y = y.blend(S::splat(f32::NEG_INFINITY), x.le(S::zero()));
+    let y = y.blend(S::splat(f32::NAN), x.lt(S::zero()));
+    let y = y.blend(S::splat(f32::INFINITY), x.ge(S::splat(f32::INFINITY)));
+    x.blend(y, x.ge(x))
+}
+
+/// Compute `softplus(x) = log(1 + exp(x))`.
+///
+/// This is evaluated as `max(x, 0) + log1p(exp(-|x|))` to avoid overflow for
+/// large positive inputs and loss of precision for large negative inputs.
+///
+/// This has a maximum error of 2 ULPs compared to a reference implementation
+/// using `f64` arithmetic.
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[inline(always)]
+unsafe fn simd_softplus<S: SimdFloat>(x: S) -> S {
+    let t = simd_exp(x.abs().neg());
+
+    // Compute `log1p(t)` as `log(u) * t / (u - 1)` where `u = 1 + t`. This
+    // compensates for the rounding error in `u`.
+    let u = S::one().add(t);
+    let d = u.sub(S::one());
+    let log1p = simd_log(u).mul(t.div(d));
+    let log1p = log1p.blend(t, d.le(S::zero()));
+
+    x.max(S::zero()).add(log1p)
+}
+
+/// Computes `log(1 + exp(x))`.
+///
+/// This is a scalar variant of [vec_softplus] that uses the same algorithm.
+pub fn softplus(x: f32) -> f32 {
+    // Safety: f32 is available on all systems.
+    unsafe { simd_softplus(x) }
+}
+
+struct SimdLog {}
+impl SimdUnaryOp for SimdLog {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_log(x)
+    }
+}
+
+/// Vectorized natural logarithm.
+///
+/// This is a vectorized version of [log] that computes the function for each
+/// element in `xs` and writes the result to `out`. `xs` and `out` must be equal
+/// in length.
+///
+/// `out` will be fully initialized after this function returns.
+pub fn vec_log(xs: &[f32], out: &mut [MaybeUninit<f32>]) {
+    dispatch_unary_op(xs, out, SimdLog {});
+}
+
+/// Variant of [vec_log] that modifies elements in-place.
+pub fn vec_log_in_place(xs: &mut [f32]) {
+    dispatch_unary_op_in_place(xs, SimdLog {});
+}
+
+struct SimdSoftplus {}
+impl SimdUnaryOp for SimdSoftplus {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_softplus(x)
+    }
+}
+
+/// Vectorized softplus function.
+///
+/// This is a vectorized version of [softplus] that computes the function for
+/// each element in `xs` and writes the result to `out`. `xs` and `out` must be
+/// equal in length.
+///
+/// `out` will be fully initialized after this function returns.
+pub fn vec_softplus(xs: &[f32], out: &mut [MaybeUninit<f32>]) {
+    dispatch_unary_op(xs, out, SimdSoftplus {});
+}
+
+/// Variant of [vec_softplus] that modifies elements in-place.
+pub fn vec_softplus_in_place(xs: &mut [f32]) {
+    dispatch_unary_op_in_place(xs, SimdSoftplus {});
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{
+        arange, benchmark_op, check_f32s_are_equal_ulps, check_with_all_f32s, AsUninit,
+    };
+    use crate::{log, softplus, vec_log, vec_softplus};
+
+    // Maximum error of `vec_log` compared to Rust standard library
+    // implementation.
+    const MAX_LOG_ERROR_ULPS: f32 = 2.0;
+
+    // Maximum error of `vec_softplus` compared to reference implementation
+    // below.
+    const MAX_SOFTPLUS_ERROR_ULPS: f32 = 2.0;
+
+    fn reference_softplus(x: f32) -> f32 {
+        let x = x as f64;
+        (x.max(0.) + (-x.abs()).exp().ln_1p()) as f32
+    }
+
+    #[test]
+    fn test_log() {
+        let cases = [
+            0.,
+            -0.,
+            -1.,
+            1.,
+            2.,
+            0.5,
+            std::f32::consts::E,
+            f32::MIN_POSITIVE,
+            f32::MIN_POSITIVE / 4.,
+            f32::MAX,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+
+        for case in cases {
+            let expected = case.ln();
+            let actual = log(case);
+            if expected.is_finite() {
+                check_f32s_are_equal_ulps([(case, actual, expected)].into_iter(), 1.0);
+            } else {
+                assert!(
+                    actual == expected || (actual.is_nan() && expected.is_nan()),
+                    "log({}) = {} but expected {}",
+                    case,
+                    actual,
+                    expected
+                );
+            }
+        }
+        assert!(log(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_vec_log() {
+        let cases: Vec<_> = arange(0.001, 20., 0.001f32)
+            .chain(arange(1e-30, 1e-20, 1e-24))
+            .chain(arange(1e20, 1e30, 1e26))
+            .collect();
+        let expected: Vec<_> = cases.iter().copied().map(f32::ln).collect();
+
+        let mut actual = cases.clone();
+        vec_log(&cases, actual.as_mut_slice().as_uninit());
+
+        let results = cases
+            .iter()
+            .zip(actual.iter().zip(expected.iter()))
+            .map(|(x, (actual, expected))| (*x, *actual, *expected));
+
+        check_f32s_are_equal_ulps(results, MAX_LOG_ERROR_ULPS);
+    }
+
+    #[test]
+    #[ignore] // Ignored by default due to long runtime
+    fn test_log_exhaustive() {
+        check_with_all_f32s(
+            |x| {
+                let mut y = [0.; 1];
+                vec_log(&[x], y.as_mut().as_uninit());
+                (y[0], x.ln())
+            },
+            MAX_LOG_ERROR_ULPS,
+            "testing vec_log",
+        );
+    }
+
+    #[test]
+    fn test_softplus() {
+        let cases: Vec<_> = arange(-20., 20., 0.001f32).collect();
+        let expected: Vec<_> = cases.iter().copied().map(reference_softplus).collect();
+
+        let mut actual = cases.clone();
+        vec_softplus(&cases, actual.as_mut_slice().as_uninit());
+
+        let results = cases
+            .iter()
+            .zip(actual.iter().zip(expected.iter()))
+            .map(|(x, (actual, expected))| (*x, *actual, *expected));
+        check_f32s_are_equal_ulps(results, MAX_SOFTPLUS_ERROR_ULPS);
+
+        // Large inputs should not overflow.
+        assert_eq!(softplus(100.), 100.);
+        assert_eq!(softplus(-200.), 0.);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_log() {
+        benchmark_op(
+            |xs, ys| xs.iter().zip(ys.iter_mut()).for_each(|(x, y)| *y = x.ln()),
+            vec_log,
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::mem::MaybeUninit;
+
+use crate::simd_vec::SimdFloat;
+use crate::{dispatch_simd_op, MutPtrLen, PtrLen, SimdOp};
+
+/// Compute `out[i] += xs[i] * scale` for each element.
+///
+/// Safety: `xs` and `out` must be valid pointers to buffers of the same
+/// length, and `out` must be initialized.
+#[inline(always)]
+unsafe fn simd_mul_add<S: SimdFloat>(
+    xs: PtrLen<f32>,
+    out: MutPtrLen<MaybeUninit<f32>>,
+    scale: f32,
+) {
+    assert!(xs.len == out.len);
+
+    let out = out.assume_init();
+    let scale_vec = S::splat(scale);
+
+    let mut n = xs.len;
+    let mut x_ptr = xs.ptr;
+    let mut out_ptr = out.ptr;
+
+    while n >= S::LEN {
+        let x = S::load(x_ptr);
+        let y = S::load(out_ptr);
+        x.mul_add(scale_vec, y).store(out_ptr);
+
+        n -= S::LEN;
+        x_ptr = x_ptr.add(S::LEN);
+        out_ptr = out_ptr.add(S::LEN);
+    }
+
+    for i in 0..n {
+        *out_ptr.add(i) = (*x_ptr.add(i)).mul_add(scale, *out_ptr.add(i));
+    }
+}
+
+struct SimdMulAdd {
+    scale: f32,
+}
+
+impl SimdOp for SimdMulAdd {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, xs: PtrLen<f32>, out: MutPtrLen<MaybeUninit<f32>>) {
+        simd_mul_add::<S>(xs, out, self.scale)
+    }
+}
+
+/// Add `xs` scaled by `scale` to `out`, ie. `out[i] += xs[i] * scale`.
+///
+/// This is the inner loop of direct convolution methods, such as depthwise
+/// convolution.
+///
+/// Panics if `xs` and `out` have different lengths.
+pub fn vec_mul_add_in_place(out: &mut [f32], xs: &[f32], scale: f32) {
+    assert_eq!(xs.len(), out.len(), "inputs must have the same length");
+    let out: MutPtrLen<f32> = out.into();
+    dispatch_simd_op(xs.into(), out.as_uninit(), SimdMulAdd { scale });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vec_mul_add_in_place;
+
+    #[test]
+    fn test_vec_mul_add_in_place() {
+        // Test lengths which are smaller, equal to and larger than the SIMD
+        // vector width on all platforms, with and without a remainder.
+        for len in [0, 1, 3, 4, 8, 15, 16, 17, 33, 100] {
+            let xs: Vec<f32> = (0..len).map(|x| x as f32).collect();
+            let mut actual: Vec<f32> = (0..len).map(|x| 0.5 * x as f32).collect();
+            let expected: Vec<f32> = actual.iter().zip(&xs).map(|(y, x)| y + x * 2.).collect();
+
+            vec_mul_add_in_place(&mut actual, &xs, 2.);
+
+            assert_eq!(actual, expected, "mismatch for len {}", len);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "inputs must have the same length")]
+    fn test_vec_mul_add_length_mismatch() {
+        vec_mul_add_in_place(&mut [1., 2.], &[1.], 2.);
+    }
+}
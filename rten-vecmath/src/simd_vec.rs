@@ -125,6 +125,9 @@ pub trait SimdInt: SimdVal {
     /// Shift the bits in each element left by `count`.
     unsafe fn shl<const COUNT: i32>(self) -> Self;
 
+    /// Shift the bits in each element right by `count`, shifting in sign bits.
+    unsafe fn shr<const COUNT: i32>(self) -> Self;
+
     /// Reinterpret the bits of each element as a float.
     unsafe fn reinterpret_as_float(self) -> Self::Float;
 
@@ -197,6 +200,9 @@ pub trait SimdFloat: SimdVal {
     /// Convert this float to an int with truncation.
     unsafe fn to_int_trunc(self) -> Self::Int;
 
+    /// Reinterpret the bits of each element as an int.
+    unsafe fn reinterpret_as_int(self) -> Self::Int;
+
     /// Compute `self * rhs`.
     unsafe fn mul(self, rhs: Self) -> Self;
 
@@ -247,6 +253,19 @@ pub trait SimdFloat: SimdVal {
     /// floats.
     unsafe fn load(ptr: *const f32) -> Self;
 
+    /// Load `Self::LEN` floats from the memory address at `ptr`, which is
+    /// aligned to `align_of::<Self>()`.
+    ///
+    /// Implementations may use a faster instruction that requires alignment.
+    /// The default implementation uses [`load`](SimdFloat::load).
+    ///
+    /// Safety: The caller must ensure `ptr` is aligned to `align_of::<Self>()`
+    /// and points to at least `Self::LEN` floats.
+    #[inline]
+    unsafe fn load_aligned(ptr: *const f32) -> Self {
+        Self::load(ptr)
+    }
+
     /// Load `Self::LEN` values from the base memory address at `ptr` plus
     /// offsets in `offsets`, excluding elements where `mask` is off.
     ///
@@ -268,6 +287,38 @@ pub trait SimdFloat: SimdVal {
     /// at least `Self::LEN` floats.
     unsafe fn store(self, ptr: *mut f32);
 
+    /// Load the first `len` floats from the memory address at `ptr`, setting
+    /// the remaining elements of the result to zero.
+    ///
+    /// Implementations may use a masked load instruction. The default
+    /// implementation copies the elements via a temporary buffer.
+    ///
+    /// Safety: The caller must ensure `len <= Self::LEN` and `ptr` points to
+    /// at least `len` floats.
+    #[inline]
+    unsafe fn load_partial(ptr: *const f32, len: usize) -> Self {
+        debug_assert!(len <= Self::LEN);
+        let mut elements = [0.; MAX_LEN];
+        std::ptr::copy_nonoverlapping(ptr, elements.as_mut_ptr(), len);
+        Self::load(elements.as_ptr())
+    }
+
+    /// Store the first `len` elements of this vector to the memory address
+    /// at `ptr`.
+    ///
+    /// Implementations may use a masked store instruction. The default
+    /// implementation copies the elements via a temporary buffer.
+    ///
+    /// Safety: The caller must ensure `len <= Self::LEN` and `ptr` points to
+    /// a buffer with space for at least `len` floats.
+    #[inline]
+    unsafe fn store_partial(self, ptr: *mut f32, len: usize) {
+        debug_assert!(len <= Self::LEN);
+        let mut elements = [0.; MAX_LEN];
+        self.store(elements.as_mut_ptr());
+        std::ptr::copy_nonoverlapping(elements.as_ptr(), ptr, len);
+    }
+
     /// Reduce the elements in this vector to a single value using `f`, then
     /// return a new vector with the accumulated value broadcast to each lane.
     #[inline]
@@ -354,12 +405,13 @@ impl SimdInt for i32 {
 
     #[inline]
     unsafe fn add(self, rhs: Self) -> Self {
-        self + rhs
+        // Wrap on overflow, to match the behavior of SIMD instructions.
+        self.wrapping_add(rhs)
     }
 
     #[inline]
     unsafe fn sub(self, rhs: Self) -> Self {
-        self - rhs
+        self.wrapping_sub(rhs)
     }
 
     #[inline]
@@ -367,6 +419,11 @@ impl SimdInt for i32 {
         self << COUNT
     }
 
+    #[inline]
+    unsafe fn shr<const COUNT: i32>(self) -> Self {
+        self >> COUNT
+    }
+
     #[inline]
     unsafe fn reinterpret_as_float(self) -> Self::Float {
         f32::from_bits(self as u32)
@@ -433,6 +490,11 @@ impl SimdFloat for f32 {
         self as i32
     }
 
+    #[inline]
+    unsafe fn reinterpret_as_int(self) -> Self::Int {
+        self.to_bits() as i32
+    }
+
     #[inline]
     unsafe fn mul(self, rhs: Self) -> Self {
         self * rhs
@@ -1,8 +1,8 @@
 use std::arch::wasm32::{
     f32x4_abs, f32x4_add, f32x4_div, f32x4_extract_lane, f32x4_ge, f32x4_le, f32x4_lt, f32x4_max,
     f32x4_mul, f32x4_splat, f32x4_sub, i32x4_add, i32x4_eq, i32x4_ge, i32x4_gt, i32x4_le, i32x4_lt,
-    i32x4_shl, i32x4_shuffle, i32x4_splat, i32x4_sub, i32x4_trunc_sat_f32x4, v128, v128_and,
-    v128_bitselect, v128_load, v128_store,
+    i32x4_shl, i32x4_shr, i32x4_shuffle, i32x4_splat, i32x4_sub, i32x4_trunc_sat_f32x4, v128,
+    v128_and, v128_bitselect, v128_load, v128_store,
 };
 
 use crate::simd_vec::{SimdFloat, SimdInt, SimdMask, SimdVal};
@@ -83,6 +83,11 @@ impl SimdInt for v128i {
         Self(i32x4_shl(self.0, COUNT as u32))
     }
 
+    #[inline]
+    unsafe fn shr<const COUNT: i32>(self) -> Self {
+        Self(i32x4_shr(self.0, COUNT as u32))
+    }
+
     #[inline]
     unsafe fn reinterpret_as_float(self) -> Self::Float {
         v128f(self.0)
@@ -138,6 +143,11 @@ impl SimdFloat for v128f {
         v128i(i32x4_trunc_sat_f32x4(self.0))
     }
 
+    #[inline]
+    unsafe fn reinterpret_as_int(self) -> Self::Int {
+        v128i(self.0)
+    }
+
     #[inline]
     unsafe fn mul(self, rhs: Self) -> Self {
         Self(f32x4_mul(self.0, rhs.0))
@@ -1,12 +1,14 @@
 use std::arch::x86_64::{
     __m256, __m256i, _mm256_add_epi32, _mm256_add_ps, _mm256_and_si256, _mm256_andnot_ps,
-    _mm256_blendv_epi8, _mm256_blendv_ps, _mm256_castps256_ps128, _mm256_castsi256_ps,
-    _mm256_cmp_ps, _mm256_cmpeq_epi32, _mm256_cmpgt_epi32, _mm256_cvttps_epi32, _mm256_div_ps,
-    _mm256_extractf128_ps, _mm256_fmadd_ps, _mm256_loadu_ps, _mm256_loadu_si256,
-    _mm256_mask_i32gather_ps, _mm256_max_ps, _mm256_mul_ps, _mm256_or_si256, _mm256_set1_epi32,
-    _mm256_set1_ps, _mm256_setzero_si256, _mm256_slli_epi32, _mm256_storeu_ps, _mm256_storeu_si256,
-    _mm256_sub_epi32, _mm256_sub_ps, _mm_add_ps, _mm_cvtss_f32, _mm_movehl_ps, _mm_prefetch,
-    _mm_shuffle_ps, _CMP_GE_OQ, _CMP_LE_OQ, _CMP_LT_OQ, _MM_HINT_ET0, _MM_HINT_T0,
+    _mm256_blendv_epi8, _mm256_blendv_ps, _mm256_castps256_ps128, _mm256_castps_si256,
+    _mm256_castsi256_ps, _mm256_cmp_ps, _mm256_cmpeq_epi32, _mm256_cmpgt_epi32,
+    _mm256_cvttps_epi32, _mm256_div_ps, _mm256_extractf128_ps, _mm256_fmadd_ps, _mm256_load_ps,
+    _mm256_loadu_ps, _mm256_loadu_si256, _mm256_mask_i32gather_ps, _mm256_maskload_ps,
+    _mm256_maskstore_ps, _mm256_max_ps, _mm256_mul_ps, _mm256_or_si256, _mm256_set1_epi32,
+    _mm256_set1_ps, _mm256_setr_epi32, _mm256_setzero_si256, _mm256_slli_epi32, _mm256_srai_epi32,
+    _mm256_storeu_ps, _mm256_storeu_si256, _mm256_sub_epi32, _mm256_sub_ps, _mm_add_ps,
+    _mm_cvtss_f32, _mm_movehl_ps, _mm_prefetch, _mm_shuffle_ps, _CMP_GE_OQ, _CMP_LE_OQ, _CMP_LT_OQ,
+    _MM_HINT_ET0, _MM_HINT_T0,
 };
 
 use crate::simd_vec::{SimdFloat, SimdInt, SimdMask, SimdVal};
@@ -94,6 +96,12 @@ impl SimdInt for __m256i {
         _mm256_slli_epi32(self, COUNT)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn shr<const COUNT: i32>(self) -> Self {
+        _mm256_srai_epi32(self, COUNT)
+    }
+
     #[inline]
     #[target_feature(enable = "avx2")]
     unsafe fn reinterpret_as_float(self) -> Self::Float {
@@ -162,6 +170,12 @@ impl SimdFloat for __m256 {
         _mm256_cvttps_epi32(self)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reinterpret_as_int(self) -> Self::Int {
+        _mm256_castps_si256(self)
+    }
+
     #[inline]
     #[target_feature(enable = "avx2")]
     unsafe fn mul(self, rhs: Self) -> Self {
@@ -210,6 +224,12 @@ impl SimdFloat for __m256 {
         _mm256_loadu_ps(ptr)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load_aligned(ptr: *const f32) -> Self {
+        _mm256_load_ps(ptr)
+    }
+
     #[inline]
     #[target_feature(enable = "avx2")]
     unsafe fn gather_mask(ptr: *const f32, offsets: Self::Int, mask: Self::Mask) -> Self {
@@ -222,6 +242,18 @@ impl SimdFloat for __m256 {
         _mm256_storeu_ps(ptr, self)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load_partial(ptr: *const f32, len: usize) -> Self {
+        _mm256_maskload_ps(ptr, partial_mask_avx(len))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store_partial(self, ptr: *mut f32, len: usize) {
+        _mm256_maskstore_ps(ptr, partial_mask_avx(len), self)
+    }
+
     #[inline]
     #[target_feature(enable = "avx2")]
     unsafe fn sum(self) -> f32 {
@@ -251,15 +283,25 @@ impl SimdFloat for __m256 {
     }
 }
 
+/// Return a mask for [`_mm256_maskload_ps`] and [`_mm256_maskstore_ps`]
+/// which selects the first `len` lanes.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn partial_mask_avx(len: usize) -> __m256i {
+    let lanes = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+    _mm256_cmpgt_epi32(_mm256_set1_epi32(len as i32), lanes)
+}
+
 #[cfg(feature = "avx512")]
 use std::arch::x86_64::{
     __m512, __m512i, __mmask16, _mm512_abs_ps, _mm512_add_epi32, _mm512_add_ps,
-    _mm512_castsi512_ps, _mm512_cmp_epi32_mask, _mm512_cmp_ps_mask, _mm512_cvttps_epi32,
-    _mm512_div_ps, _mm512_fmadd_ps, _mm512_loadu_ps, _mm512_loadu_si512, _mm512_mask_blend_epi32,
-    _mm512_mask_blend_ps, _mm512_mask_i32gather_ps, _mm512_max_ps, _mm512_mul_ps,
+    _mm512_castps_si512, _mm512_castsi512_ps, _mm512_cmp_epi32_mask, _mm512_cmp_ps_mask,
+    _mm512_cvttps_epi32, _mm512_div_ps, _mm512_fmadd_ps, _mm512_load_ps, _mm512_loadu_ps,
+    _mm512_loadu_si512, _mm512_mask_blend_epi32, _mm512_mask_blend_ps, _mm512_mask_i32gather_ps,
+    _mm512_mask_storeu_ps, _mm512_maskz_loadu_ps, _mm512_max_ps, _mm512_mul_ps,
     _mm512_reduce_add_ps, _mm512_set1_epi32, _mm512_set1_ps, _mm512_setzero_si512,
-    _mm512_sllv_epi32, _mm512_storeu_ps, _mm512_storeu_si512, _mm512_sub_epi32, _mm512_sub_ps,
-    _MM_CMPINT_EQ, _MM_CMPINT_LE, _MM_CMPINT_LT,
+    _mm512_sllv_epi32, _mm512_srav_epi32, _mm512_storeu_ps, _mm512_storeu_si512, _mm512_sub_epi32,
+    _mm512_sub_ps, _MM_CMPINT_EQ, _MM_CMPINT_LE, _MM_CMPINT_LT,
 };
 
 #[cfg(feature = "avx512")]
@@ -349,6 +391,13 @@ impl SimdInt for __m512i {
         _mm512_sllv_epi32(self, count)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn shr<const COUNT: i32>(self) -> Self {
+        let count = Self::splat(COUNT);
+        _mm512_srav_epi32(self, count)
+    }
+
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn reinterpret_as_float(self) -> Self::Float {
@@ -358,13 +407,13 @@ impl SimdInt for __m512i {
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn load(ptr: *const i32) -> Self {
-        _mm512_loadu_si512(ptr)
+        _mm512_loadu_si512(ptr as *const __m512i)
     }
 
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn store(self, ptr: *mut i32) {
-        _mm512_storeu_si512(ptr, self)
+        _mm512_storeu_si512(ptr as *mut __m512i, self)
     }
 }
 
@@ -415,6 +464,12 @@ impl SimdFloat for __m512 {
         _mm512_cvttps_epi32(self)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn reinterpret_as_int(self) -> Self::Int {
+        _mm512_castps_si512(self)
+    }
+
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn mul(self, rhs: Self) -> Self {
@@ -463,6 +518,12 @@ impl SimdFloat for __m512 {
         _mm512_loadu_ps(ptr)
     }
 
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load_aligned(ptr: *const f32) -> Self {
+        _mm512_load_ps(ptr)
+    }
+
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn store(self, ptr: *mut f32) {
@@ -472,7 +533,21 @@ impl SimdFloat for __m512 {
     #[inline]
     #[target_feature(enable = "avx512f")]
     unsafe fn gather_mask(ptr: *const f32, offsets: Self::Int, mask: Self::Mask) -> Self {
-        _mm512_mask_i32gather_ps::<4>(Self::zero(), mask, offsets, ptr as *const u8)
+        _mm512_mask_i32gather_ps::<4>(Self::zero(), mask, offsets, ptr)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load_partial(ptr: *const f32, len: usize) -> Self {
+        let mask = ((1u32 << len) - 1) as __mmask16;
+        _mm512_maskz_loadu_ps(mask, ptr)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn store_partial(self, ptr: *mut f32, len: usize) {
+        let mask = ((1u32 << len) - 1) as __mmask16;
+        _mm512_mask_storeu_ps(ptr, mask, self)
     }
 
     #[inline]
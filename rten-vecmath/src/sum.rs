@@ -0,0 +1,351 @@
+use crate::simd_vec::{SimdFloat, SimdInt};
+use crate::{dispatch_simd_reduce_op, vec_fold, PtrLen, SimdReduceOp, MAX_LEN};
+
+/// Horizontal sum of `(x - offset)^2` for each element `x` in `xs`.
+#[inline(always)]
+unsafe fn simd_sum_square_sub<S: SimdFloat>(xs: PtrLen<f32>, offset: f32) -> f32 {
+    let offset_vec = S::splat(offset);
+    let sum = vec_fold(
+        xs,
+        S::zero(),
+        #[inline(always)]
+        |sum, x| {
+            let d = x.sub(offset_vec);
+            d.mul_add(d, sum)
+        },
+        offset, /* pad */
+    );
+    sum.sum()
+}
+
+/// Dot product of `a` and `b`.
+///
+/// Safety: `a` and `b` must be valid pointers to buffers of the same length.
+#[inline(always)]
+unsafe fn simd_dot<S: SimdFloat>(a: PtrLen<f32>, b: PtrLen<f32>) -> f32 {
+    assert!(a.len == b.len);
+
+    let mut n = a.len;
+    let mut a_ptr = a.ptr;
+    let mut b_ptr = b.ptr;
+    let mut accum = S::zero();
+
+    while n >= S::LEN {
+        let x = S::load(a_ptr);
+        let y = S::load(b_ptr);
+        accum = x.mul_add(y, accum);
+        n -= S::LEN;
+        a_ptr = a_ptr.add(S::LEN);
+        b_ptr = b_ptr.add(S::LEN);
+    }
+
+    // Handle remainder with zero-padded vectors.
+    if n > 0 {
+        assert!(S::LEN <= MAX_LEN);
+        let mut a_rem = [0.; MAX_LEN];
+        let mut b_rem = [0.; MAX_LEN];
+        for i in 0..n {
+            a_rem[i] = *a_ptr.add(i);
+            b_rem[i] = *b_ptr.add(i);
+        }
+        let x = S::load(a_rem.as_ptr());
+        let y = S::load(b_rem.as_ptr());
+        accum = x.mul_add(y, accum);
+    }
+
+    accum.sum()
+}
+
+/// Dot product of `a` with the unsigned `BITS`-bit values packed into
+/// `words`, after subtracting `zero_point` from each value.
+///
+/// Each SIMD lane processes one word. Values are extracted in order from the
+/// least significant bits using `x - ((x >> BITS) << BITS)`, which only needs
+/// arithmetic shifts, and converted to floats by adding them to the bits of
+/// `2^23`. The elements of `a` which correspond to each value are gathered
+/// with a stride of `32 / BITS`.
+///
+/// Safety: `a` must have `32 / BITS` elements for each element of `words`.
+#[inline(always)]
+unsafe fn simd_dot_packed<S: SimdFloat, const BITS: i32>(
+    words: PtrLen<i32>,
+    zero_point: i32,
+    a: PtrLen<f32>,
+) -> f32 {
+    let vals_per_word = (32 / BITS) as usize;
+    assert!(a.len == words.len * vals_per_word);
+
+    // `2^23` as a float. For `0 <= x < 2^23`, the float whose bits are
+    // `TWO_POW_23_BITS + x` is `2^23 + x`.
+    const TWO_POW_23_BITS: i32 = 0x4B00_0000;
+    let int_offset = S::Int::splat(TWO_POW_23_BITS);
+    let float_offset = S::splat(8388608. + zero_point as f32);
+
+    assert!(S::LEN <= MAX_LEN);
+    let mut lane_offsets = [0; MAX_LEN];
+    for (i, offset) in lane_offsets.iter_mut().enumerate().take(S::LEN) {
+        *offset = (i * vals_per_word) as i32;
+    }
+    let lane_offsets = S::Int::load(lane_offsets.as_ptr());
+    let one = S::Int::splat(1);
+
+    let mut n = words.len;
+    let mut words_ptr = words.ptr;
+    let mut a_ptr = a.ptr;
+    let mut accum = S::zero();
+
+    while n > 0 {
+        let n_lanes = n.min(S::LEN);
+        let (mut x, mask) = if n_lanes == S::LEN {
+            (S::Int::load(words_ptr), S::Int::zero().eq(S::Int::zero()))
+        } else {
+            // Handle remainder with zero-padded words. The corresponding
+            // elements of `a` are masked out, so the padding contributes
+            // zero to the sum.
+            let mut rem = [0; MAX_LEN];
+            for i in 0..n_lanes {
+                rem[i] = *words_ptr.add(i);
+            }
+            let mask = lane_offsets.lt(S::Int::splat((n_lanes * vals_per_word) as i32));
+            (S::Int::load(rem.as_ptr()), mask)
+        };
+
+        let mut offsets = lane_offsets;
+        for _ in 0..vals_per_word {
+            let rest = x.shr::<BITS>();
+            let val = x
+                .sub(rest.shl::<BITS>())
+                .add(int_offset)
+                .reinterpret_as_float()
+                .sub(float_offset);
+            let a_vals = S::gather_mask(a_ptr, offsets, mask);
+            accum = val.mul_add(a_vals, accum);
+            x = rest;
+            offsets = offsets.add(one);
+        }
+
+        n -= n_lanes;
+        words_ptr = words_ptr.add(n_lanes);
+        a_ptr = a_ptr.add(n_lanes * vals_per_word);
+    }
+
+    accum.sum()
+}
+
+struct SimdSum<'a> {
+    xs: &'a [f32],
+}
+
+impl<'a> SimdReduceOp for SimdSum<'a> {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self) -> f32 {
+        vec_fold(
+            self.xs.into(),
+            S::zero(),
+            #[inline(always)]
+            |sum, x| sum.add(x),
+            0., /* pad */
+        )
+        .sum()
+    }
+}
+
+struct SimdSumSquareSub<'a> {
+    xs: &'a [f32],
+    offset: f32,
+}
+
+impl<'a> SimdReduceOp for SimdSumSquareSub<'a> {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self) -> f32 {
+        simd_sum_square_sub::<S>(self.xs.into(), self.offset)
+    }
+}
+
+struct SimdDot<'a> {
+    a: &'a [f32],
+    b: &'a [f32],
+}
+
+impl<'a> SimdReduceOp for SimdDot<'a> {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self) -> f32 {
+        simd_dot::<S>(self.a.into(), self.b.into())
+    }
+}
+
+struct SimdDotPacked<'a, const BITS: i32> {
+    words: &'a [i32],
+    zero_point: i32,
+    a: &'a [f32],
+}
+
+impl<'a, const BITS: i32> SimdReduceOp for SimdDotPacked<'a, BITS> {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self) -> f32 {
+        simd_dot_packed::<S, BITS>(self.words.into(), self.zero_point, self.a.into())
+    }
+}
+
+/// Return the sum of elements in `xs`.
+pub fn vec_sum(xs: &[f32]) -> f32 {
+    dispatch_simd_reduce_op(SimdSum { xs })
+}
+
+/// Return the sum of the squares of elements in `xs`.
+pub fn vec_sum_square(xs: &[f32]) -> f32 {
+    vec_sum_square_sub(xs, 0.)
+}
+
+/// Return the sum of `(x - offset)^2` for each element `x` in `xs`.
+///
+/// This is used to compute the variance of `xs` given its mean.
+pub fn vec_sum_square_sub(xs: &[f32], offset: f32) -> f32 {
+    dispatch_simd_reduce_op(SimdSumSquareSub { xs, offset })
+}
+
+/// Return the dot product of `a` and `b`.
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn vec_dot(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "inputs must have the same length");
+    dispatch_simd_reduce_op(SimdDot { a, b })
+}
+
+/// Return the dot product of `a` with a vector of unsigned `bits`-bit values
+/// packed into `words`, after subtracting `zero_point` from each value.
+///
+/// Each word packs `32 / bits` values, with the first value in the least
+/// significant bits. This is used to compute dot products with rows of
+/// block-quantized matrices without dequantizing them first.
+///
+/// Panics if `bits` is not 4 or 8, or if `a` does not have `32 / bits`
+/// elements for each word.
+pub fn vec_dot_packed(words: &[i32], bits: u32, zero_point: i32, a: &[f32]) -> f32 {
+    assert!(bits == 4 || bits == 8, "bits must be 4 or 8");
+    assert_eq!(
+        a.len(),
+        words.len() * (32 / bits) as usize,
+        "input lengths do not match"
+    );
+    if bits == 4 {
+        dispatch_simd_reduce_op(SimdDotPacked::<4> {
+            words,
+            zero_point,
+            a,
+        })
+    } else {
+        dispatch_simd_reduce_op(SimdDotPacked::<8> {
+            words,
+            zero_point,
+            a,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_dot, vec_dot_packed, vec_sum, vec_sum_square, vec_sum_square_sub};
+
+    /// Check that the result of a reduction `actual` matches a reference
+    /// value computed with f64 precision.
+    fn check_reduce_result(len: usize, actual: f32, expected: f64) {
+        assert!(
+            (actual as f64 - expected).abs() < 1e-4,
+            "len {} expected {} actual {}",
+            len,
+            expected,
+            actual
+        );
+    }
+
+    /// Generate a deterministic sequence of values in `[-1, 1]`.
+    fn test_values(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| ((i * 7919) % 1000) as f32 / 500. - 1.)
+            .collect()
+    }
+
+    #[test]
+    fn test_vec_sum() {
+        // Test lengths which are smaller, equal to and larger than the SIMD
+        // vector width on all platforms, with and without a remainder.
+        for len in [0, 1, 3, 4, 8, 15, 16, 17, 33, 100] {
+            let xs = test_values(len);
+            let expected: f64 = xs.iter().map(|x| *x as f64).sum();
+            let actual = vec_sum(&xs);
+            check_reduce_result(len, actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_vec_sum_square() {
+        for len in [0, 1, 3, 4, 8, 15, 16, 17, 33, 100] {
+            let xs = test_values(len);
+            let expected: f64 = xs.iter().map(|x| (*x as f64).powi(2)).sum();
+            let actual = vec_sum_square(&xs);
+            check_reduce_result(len, actual, expected);
+
+            let offset = 0.25;
+            let expected: f64 = xs.iter().map(|x| (*x as f64 - offset).powi(2)).sum();
+            let actual = vec_sum_square_sub(&xs, offset as f32);
+            check_reduce_result(len, actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_vec_dot() {
+        for len in [0, 1, 3, 4, 8, 15, 16, 17, 33, 100] {
+            let a = test_values(len);
+            let b: Vec<f32> = test_values(len + 3).into_iter().skip(3).collect();
+            let expected: f64 = a.iter().zip(&b).map(|(a, b)| *a as f64 * *b as f64).sum();
+            let actual = vec_dot(&a, &b);
+            check_reduce_result(len, actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_vec_dot_packed() {
+        for bits in [4, 8] {
+            let vals_per_word = 32 / bits as usize;
+            let max_val = (1u32 << bits) - 1;
+            let zero_point = 1 << (bits - 1);
+
+            // Test word counts which are smaller, equal to and larger than
+            // the SIMD vector width on all platforms.
+            for n_words in [0, 1, 3, 4, 8, 15, 16, 17, 33] {
+                let words: Vec<i32> = (0..n_words)
+                    .map(|i| (i as u32).wrapping_mul(0x9E37_79B9) as i32)
+                    .collect();
+                let a = test_values(n_words * vals_per_word);
+
+                let expected: f64 = a
+                    .iter()
+                    .enumerate()
+                    .map(|(i, a)| {
+                        let word = words[i / vals_per_word] as u32;
+                        let shift = (i % vals_per_word) as u32 * bits;
+                        let val = ((word >> shift) & max_val) as i32 - zero_point;
+                        val as f64 * *a as f64
+                    })
+                    .sum();
+                let actual = vec_dot_packed(&words, bits, zero_point, &a);
+
+                assert!(
+                    (actual as f64 - expected).abs() < 1e-3,
+                    "bits {} words {} expected {} actual {}",
+                    bits,
+                    n_words,
+                    expected,
+                    actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "inputs must have the same length")]
+    fn test_vec_dot_length_mismatch() {
+        vec_dot(&[1., 2.], &[1.]);
+    }
+}
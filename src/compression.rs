@@ -0,0 +1,155 @@
+//! Compression and decompression of constant data in model files.
+//!
+//! Support for each algorithm is enabled by a crate feature (`zstd` or `lz4`).
+//! Models which use an algorithm that is not enabled fail to load with an
+//! error that names the required feature.
+
+use crate::schema_generated as sg;
+
+/// Algorithm used to compress the data of constants in a model.
+///
+/// See [ModelBuilder::add_compressed_float_constant](crate::ModelBuilder::add_compressed_float_constant).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// Zstandard compression. Loading requires the `zstd` feature.
+    Zstd,
+    /// LZ4 compression. Loading requires the `lz4` feature.
+    Lz4,
+}
+
+impl From<Compression> for sg::Compression {
+    fn from(val: Compression) -> sg::Compression {
+        match val {
+            Compression::Zstd => sg::Compression::Zstd,
+            Compression::Lz4 => sg::Compression::Lz4,
+        }
+    }
+}
+
+/// Decompress the data of a `CompressedData` constant.
+///
+/// `expected_len` is the size in bytes of the decompressed data. Decompression
+/// stops once this size is exceeded, so that corrupt or malicious data cannot
+/// expand to more than `expected_len` bytes, and an error is returned if the
+/// decompressed size is different.
+#[cfg_attr(not(any(feature = "zstd", feature = "lz4")), allow(unused_variables))]
+pub fn decompress(
+    compression: sg::Compression,
+    data: &[u8],
+    expected_len: usize,
+) -> Result<Vec<u8>, String> {
+    let size_mismatch = |len: usize| {
+        format!(
+            "decompressed size {} does not match expected size {}",
+            len, expected_len
+        )
+    };
+
+    match compression {
+        #[cfg(feature = "zstd")]
+        sg::Compression::Zstd => {
+            use std::io::Read;
+
+            let decoder =
+                ruzstd::decoding::StreamingDecoder::new(data).map_err(|e| e.to_string())?;
+
+            // Read up to one byte more than expected, to detect data which
+            // is too long without decompressing all of it.
+            let mut output = Vec::new();
+            decoder
+                .take(expected_len as u64 + 1)
+                .read_to_end(&mut output)
+                .map_err(|e| e.to_string())?;
+            if output.len() != expected_len {
+                return Err(size_mismatch(output.len()));
+            }
+            Ok(output)
+        }
+        // LZ4 data uses the block format, prefixed with the uncompressed size
+        // as a little-endian u32. This matches the output of
+        // `lz4.block.compress` in Python.
+        #[cfg(feature = "lz4")]
+        sg::Compression::Lz4 => {
+            if data.len() < 4 {
+                return Err("missing lz4 size prefix".to_string());
+            }
+            let (size, block) = data.split_at(4);
+            let size = u32::from_le_bytes(size.try_into().unwrap()) as usize;
+            if size != expected_len {
+                return Err(size_mismatch(size));
+            }
+            let mut output = vec![0; expected_len];
+            let len =
+                lz4_flex::block::decompress_into(block, &mut output).map_err(|e| e.to_string())?;
+            if len != expected_len {
+                return Err(size_mismatch(len));
+            }
+            Ok(output)
+        }
+        other => Err(unsupported_error(other)),
+    }
+}
+
+/// Compress data for storage in a `CompressedData` constant.
+///
+/// This is the inverse of [`decompress`].
+#[cfg_attr(not(any(feature = "zstd", feature = "lz4")), allow(unused_variables))]
+pub fn compress(compression: sg::Compression, data: &[u8]) -> Result<Vec<u8>, String> {
+    match compression {
+        #[cfg(feature = "zstd")]
+        sg::Compression::Zstd => Ok(ruzstd::encoding::compress_to_vec(
+            data,
+            ruzstd::encoding::CompressionLevel::Fastest,
+        )),
+        #[cfg(feature = "lz4")]
+        sg::Compression::Lz4 => Ok(lz4_flex::block::compress_prepend_size(data)),
+        other => Err(unsupported_error(other)),
+    }
+}
+
+fn unsupported_error(compression: sg::Compression) -> String {
+    match compression {
+        sg::Compression::Zstd => "zstd compression requires the `zstd` feature".to_string(),
+        sg::Compression::Lz4 => "lz4 compression requires the `lz4` feature".to_string(),
+        other => format!("unknown compression type {:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, decompress};
+    use crate::schema_generated as sg;
+
+    #[test]
+    fn test_compress_decompress() {
+        let data: Vec<u8> = (0..1000).map(|x| (x % 7) as u8).collect();
+
+        for compression in [sg::Compression::Zstd, sg::Compression::Lz4] {
+            let compressed = compress(compression, &data).unwrap();
+            assert!(compressed.len() < data.len());
+            let decompressed = decompress(compression, &compressed, data.len()).unwrap();
+            assert_eq!(decompressed, data);
+        }
+    }
+
+    #[test]
+    fn test_decompress_size_mismatch() {
+        let data: Vec<u8> = (0..1000).map(|x| (x % 7) as u8).collect();
+
+        for compression in [sg::Compression::Zstd, sg::Compression::Lz4] {
+            let compressed = compress(compression, &data).unwrap();
+            for expected_len in [0, data.len() - 1, data.len() + 1] {
+                let result = decompress(compression, &compressed, expected_len);
+                assert!(result.is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_decompress_invalid_data() {
+        for compression in [sg::Compression::Zstd, sg::Compression::Lz4] {
+            let result = decompress(compression, &[1, 2, 3], 3);
+            assert!(result.is_err());
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use std::num::NonZeroU32;
+use std::ops::Range;
 
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensor, NdTensorView};
@@ -40,6 +41,71 @@ pub struct DecodeStep {
     pub pos: u32,
 }
 
+/// Span of input positions aligned to a label by [CtcDecoder::align].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlignedStep {
+    /// Class label.
+    pub label: u32,
+
+    /// First position in the input sequence that is aligned to this label.
+    pub start: u32,
+
+    /// Position after the last one in the input sequence that is aligned to
+    /// this label.
+    pub end: u32,
+
+    /// Mean log probability of the label over the positions in
+    /// `start..end`.
+    pub score: f32,
+}
+
+/// Span of input positions that correspond to a word, produced by
+/// [group_words].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordSpan {
+    /// Range of aligned steps that make up the word.
+    pub steps: Range<usize>,
+
+    /// First position in the input sequence that is aligned to the word.
+    pub start: u32,
+
+    /// Position after the last one in the input sequence that is aligned to
+    /// the word.
+    pub end: u32,
+}
+
+/// Group a sequence of aligned labels into words.
+///
+/// Words are separated by one or more `separator` labels (eg. a space or `|`
+/// character), which are not included in the output spans. Positions can be
+/// converted to timestamps by multiplying by the duration of each input
+/// frame. For wav2vec2 models this is 20ms.
+pub fn group_words(steps: &[AlignedStep], separator: u32) -> Vec<WordSpan> {
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, step) in steps.iter().enumerate() {
+        if step.label == separator {
+            if let Some(start) = word_start.take() {
+                words.push(WordSpan {
+                    steps: start..i,
+                    start: steps[start].start,
+                    end: steps[i - 1].end,
+                });
+            }
+        } else if word_start.is_none() {
+            word_start = Some(i);
+        }
+    }
+    if let Some(start) = word_start {
+        words.push(WordSpan {
+            steps: start..steps.len(),
+            start: steps[start].start,
+            end: steps[steps.len() - 1].end,
+        });
+    }
+    words
+}
+
 /// A search state for beam decoding by [CtcDecoder]. This consists of a
 /// decoded sequence and associated probabilities.
 #[derive(Debug)]
@@ -212,6 +278,119 @@ impl CtcDecoder {
         CtcHypothesis::from_beam_state(self.decode_beam_impl(prob_seq, beam_size).remove(0))
     }
 
+    /// Align a label sequence to the input sequence.
+    ///
+    /// This performs forced alignment, finding the most likely path through
+    /// `prob_seq` which decodes to `labels`, and returns the span of input
+    /// positions that correspond to each label. This can be used to get
+    /// timestamps for a transcript, which may come from decoding `prob_seq`
+    /// or from another source. See [group_words] to get word-level spans.
+    ///
+    /// `prob_seq` is a `[sequence, n_labels]` matrix of log probabilities of
+    /// labels at each time step, where the label value 0 is reserved for the
+    /// CTC blank label. If a model outputs logits, apply a log-softmax first.
+    ///
+    /// Returns `None` if `labels` contains an invalid label or there is no
+    /// path which produces `labels`. This happens if the input sequence is
+    /// too short, since repeated labels must be separated by a blank.
+    pub fn align(
+        &self,
+        prob_seq: NdTensorView<f32, 2>,
+        labels: &[u32],
+    ) -> Option<Vec<AlignedStep>> {
+        let [seq, n_labels] = prob_seq.shape();
+        if labels
+            .iter()
+            .any(|&label| label == 0 || label as usize >= n_labels)
+        {
+            return None;
+        }
+
+        // Labels of the states in the alignment lattice. These are the target
+        // labels with blanks before, between and after them.
+        let n_states = labels.len() * 2 + 1;
+        let state_label = |state: usize| {
+            if state % 2 == 1 {
+                labels[state / 2] as usize
+            } else {
+                0
+            }
+        };
+
+        // Log probabilities of the best path ending in each state at the
+        // previous and current time steps.
+        let mut prev_prob = vec![f32::NEG_INFINITY; n_states];
+        let mut curr_prob = vec![f32::NEG_INFINITY; n_states];
+
+        // Number of states skipped by the best transition into each state
+        // at each time step (0, 1 or 2).
+        let mut skips = NdTensor::<u8, 2>::zeros([seq, n_states]);
+
+        for pos in 0..seq {
+            for state in 0..n_states {
+                let prob = prob_seq[[pos, state_label(state)]];
+                if pos == 0 {
+                    curr_prob[state] = if state < 2 { prob } else { f32::NEG_INFINITY };
+                    continue;
+                }
+
+                // A path can stay in the same state, advance to the next
+                // state, or skip a blank between two different labels.
+                let max_skip =
+                    if state % 2 == 1 && state >= 2 && state_label(state) != state_label(state - 2)
+                    {
+                        2
+                    } else {
+                        state.min(1)
+                    };
+                let (best_skip, best_prob) = (0..=max_skip)
+                    .map(|skip| (skip, prev_prob[state - skip]))
+                    .fold((0, f32::NEG_INFINITY), |best, (skip, prob)| {
+                        if prob > best.1 {
+                            (skip, prob)
+                        } else {
+                            best
+                        }
+                    });
+                skips[[pos, state]] = best_skip as u8;
+                curr_prob[state] = best_prob + prob;
+            }
+            std::mem::swap(&mut prev_prob, &mut curr_prob);
+        }
+
+        // The path must end in the last label or the trailing blank.
+        let mut state = (n_states.saturating_sub(2)..n_states)
+            .filter(|&state| prev_prob[state] > f32::NEG_INFINITY)
+            .max_by(|&a, &b| prev_prob[a].total_cmp(&prev_prob[b]))?;
+
+        // Trace the best path backwards and record the span of each label.
+        let mut steps: Vec<AlignedStep> = labels
+            .iter()
+            .map(|&label| AlignedStep {
+                label,
+                start: 0,
+                end: 0,
+                score: 0.,
+            })
+            .collect();
+        for pos in (0..seq).rev() {
+            if state % 2 == 1 {
+                let step = &mut steps[state / 2];
+                if step.end == 0 {
+                    step.end = pos as u32 + 1;
+                }
+                step.start = pos as u32;
+                step.score += prob_seq[[pos, step.label as usize]];
+            }
+            state -= skips[[pos, state]] as usize;
+        }
+        for step in steps.iter_mut() {
+            step.score /= (step.end - step.start) as f32;
+        }
+
+        Some(steps)
+    }
+
     fn decode_beam_impl(&self, prob_seq: NdTensorView<f32, 2>, beam_size: u32) -> Vec<BeamState> {
         let [seq, n_labels] = prob_seq.shape();
 
@@ -389,7 +568,7 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::NdTensor;
 
-    use super::{log_sum_exp, CtcDecoder, CtcHypothesis};
+    use super::{group_words, log_sum_exp, AlignedStep, CtcDecoder, CtcHypothesis, WordSpan};
 
     const ALPHABET: &str = " abcdefghijklmnopqrstuvwxyz";
 
@@ -530,4 +709,101 @@ mod tests {
         let expected_score = log_sum_exp([input[[0, blank_label]] + input[[1, blank_label]]]);
         assert_eq!(beam_output.score(), expected_score);
     }
+
+    #[test]
+    fn test_align() {
+        let decoder = CtcDecoder::new();
+
+        // Input where each label is spread over several positions, with
+        // blanks ("-") between some of them.
+        let seq = encode_str("--ffoo--oo--bbaarr", false);
+        let input = onehot_tensor(&seq);
+        let labels = encode_str("foobar", false);
+
+        let steps = decoder.align(input.view(), &labels).unwrap();
+        let spans: Vec<_> = steps.iter().map(|s| (s.start, s.end)).collect();
+        assert_eq!(
+            spans,
+            [(2, 4), (4, 6), (8, 10), (12, 14), (14, 16), (16, 18)]
+        );
+        assert!(steps.iter().all(|s| s.score == 0.));
+
+        // Repeated labels need at least one blank between them, so there is
+        // no path if the input is too short.
+        let input = onehot_tensor(&encode_str("foo", false));
+        assert_eq!(decoder.align(input.view(), &labels), None);
+
+        // Invalid labels.
+        assert_eq!(decoder.align(input.view(), &[0]), None);
+        assert_eq!(decoder.align(input.view(), &[ALPHABET.len() as u32]), None);
+
+        // Empty label sequence.
+        let input = onehot_tensor(&encode_str("---", false));
+        assert_eq!(decoder.align(input.view(), &[]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_align_uses_probabilities() {
+        let decoder = CtcDecoder::new();
+        let a_label = 1;
+        let b_label = 2;
+
+        // The greedy path is "bbb", but the target is "ab". Assigning the
+        // second position to "b" rather than "a" gives the most likely path.
+        let mut input = NdTensor::<f32, 2>::full([3, 3], 0.1);
+        input[[0, b_label]] = 0.6;
+        input[[0, a_label]] = 0.3;
+        input[[1, b_label]] = 0.5;
+        input[[1, a_label]] = 0.4;
+        input[[2, b_label]] = 0.8;
+        input.apply(|x| x.ln());
+
+        let steps = decoder.align(input.view(), &[a_label as u32, b_label as u32]);
+        let expected_score = (input[[1, b_label]] + input[[2, b_label]]) / 2.;
+        assert_eq!(
+            steps,
+            Some(vec![
+                AlignedStep {
+                    label: a_label as u32,
+                    start: 0,
+                    end: 1,
+                    score: input[[0, a_label]],
+                },
+                AlignedStep {
+                    label: b_label as u32,
+                    start: 1,
+                    end: 3,
+                    score: expected_score,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_group_words() {
+        let decoder = CtcDecoder::new();
+        let space = encode_str(" ", false)[0];
+        let input = onehot_tensor(&encode_str("  hhi  tthere  ", false));
+        let labels = encode_str(" hi there ", false);
+
+        let steps = decoder.align(input.view(), &labels).unwrap();
+        let words = group_words(&steps, space);
+        assert_eq!(
+            words,
+            [
+                WordSpan {
+                    steps: 1..3,
+                    start: 2,
+                    end: 5,
+                },
+                WordSpan {
+                    steps: 4..9,
+                    start: 7,
+                    end: 13,
+                },
+            ]
+        );
+
+        assert_eq!(group_words(&[], space), []);
+    }
 }
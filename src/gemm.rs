@@ -4,30 +4,72 @@
 //! network operators. The primary functionality is general matrix
 //! multiplication (gemm) with ML-oriented additions, but there are also
 //! operations like vector-scalar products.
+//!
+//! The public API of this module is [sgemm], a BLAS-style single-precision
+//! matrix multiplication which uses the same optimized kernels as RTen's
+//! operators, and [sgemm_bf16], a variant which takes a "B" matrix stored as
+//! [`BF16`](crate::BF16).
+//!
+//! If the `blas` feature is enabled, float matrix multiplications are
+//! delegated to an external BLAS library where the input layouts allow.
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::mem::MaybeUninit;
 use std::ops::Range;
 
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
-use rten_tensor::{Alloc, GlobalAlloc, Matrix, MatrixLayout, MatrixMut, NdTensorView};
+use rten_tensor::{Alloc, GlobalAlloc, Matrix, MatrixLayout, MatrixMut, NdTensor, NdTensorView};
 
 use crate::iter_util::{range_chunks, MaybeParIter};
+use crate::number::BF16;
 use crate::tensor_pool::ExtractBuffer;
 
+#[cfg(feature = "blas")]
+mod blas;
 mod kernels;
 mod packing;
 
 use kernels::{BaseKernel, Kernel};
 
+/// Alignment, in bytes, of packed blocks of GEMM inputs.
+///
+/// This is the cache line size on common architectures, and a multiple of the
+/// alignment of the SIMD vector types used by the kernels. Packed "B" blocks
+/// are loaded by kernels using aligned loads.
+const PACKED_ALIGN: usize = 64;
+
+/// Clear `buf` and reserve space for `len` packed elements which start at a
+/// [`PACKED_ALIGN`]-aligned address.
+///
+/// After this returns, `buf.len()` is the offset of the aligned start, with
+/// zero-valued padding elements before it. Returns the uninitialized space for
+/// the packed elements.
+fn reserve_aligned(buf: &mut Vec<f32>, len: usize) -> &mut [MaybeUninit<f32>] {
+    let max_padding = PACKED_ALIGN / std::mem::size_of::<f32>() - 1;
+
+    buf.clear();
+    buf.reserve(len + max_padding);
+
+    let offset = buf.as_ptr().align_offset(PACKED_ALIGN);
+    assert!(offset <= max_padding);
+    buf.resize(offset, 0.);
+
+    &mut buf.spare_capacity_mut()[..len]
+}
+
 /// Left-hand or "A" GEMM input that has been pre-packed.
-#[derive(Clone)]
-pub struct PackedAMatrix<'a> {
+pub(crate) struct PackedAMatrix<'a> {
     /// Sequence of packed row panels.
     data: Cow<'a, [f32]>,
 
+    /// Offset of the first panel in `data`. Panels start at an address that
+    /// is aligned to [`PACKED_ALIGN`].
+    offset: usize,
+
     /// Number of elements in each row panel.
     panel_len: usize,
 
@@ -44,7 +86,7 @@ pub struct PackedAMatrix<'a> {
 impl<'a> PackedAMatrix<'a> {
     fn block(&self, row_block_idx: usize, depth_block_idx: usize) -> &[f32] {
         let panel_idx = depth_block_idx * self.row_blocks + row_block_idx;
-        let offset = panel_idx * self.panel_len;
+        let offset = self.offset + panel_idx * self.panel_len;
         &self.data[offset..offset + self.panel_len]
     }
 }
@@ -61,11 +103,14 @@ impl<'a> ExtractBuffer for PackedAMatrix<'a> {
 }
 
 /// Right-hand or "B" GEMM input that has been pre-packed.
-#[derive(Clone)]
-pub struct PackedBMatrix {
+pub(crate) struct PackedBMatrix {
     /// Sequence of packed column panels.
     data: Vec<f32>,
 
+    /// Offset of the first panel in `data`. Panels start at an address that
+    /// is aligned to [`PACKED_ALIGN`].
+    offset: usize,
+
     /// Number of elements in each column panel.
     panel_len: usize,
 
@@ -77,12 +122,26 @@ pub struct PackedBMatrix {
 
     /// Number of columns in the unpacked matrix.
     cols: usize,
+
+    /// Name of the kernel that the matrix was packed for. The packed layout
+    /// depends on the kernel's tile size.
+    kernel_name: &'static str,
 }
 
 impl PackedBMatrix {
+    /// Return the number of rows in the unpacked matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the number of columns in the unpacked matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
     fn block(&self, col_block_idx: usize, depth_block_idx: usize) -> &[f32] {
         let panel_idx = col_block_idx * self.depth_blocks + depth_block_idx;
-        let offset = panel_idx * self.panel_len;
+        let offset = self.offset + panel_idx * self.panel_len;
         &self.data[offset..offset + self.panel_len]
     }
 }
@@ -97,7 +156,7 @@ impl ExtractBuffer for PackedBMatrix {
 
 /// Left-hand or "A" input for a GEMM operation.
 #[derive(Copy, Clone)]
-pub enum GemmInputA<'a> {
+pub(crate) enum GemmInputA<'a> {
     /// A standard unpacked matrix.
     Unpacked(Matrix<'a>),
 
@@ -134,7 +193,7 @@ impl<'a> GemmInputA<'a> {
 ///
 /// Implementations of [`pack_b`](VirtualMatrix::pack_b) must initialize the
 /// entire buffer passed to them.
-pub unsafe trait VirtualMatrix: Sync {
+pub(crate) unsafe trait VirtualMatrix: Sync {
     /// Return the number of rows in the virtual matrix.
     fn rows(&self) -> usize;
 
@@ -166,10 +225,14 @@ pub unsafe trait VirtualMatrix: Sync {
 
 /// Right-hand or "B" input for a GEMM operation.
 #[derive(Copy, Clone)]
-pub enum GemmInputB<'a> {
+pub(crate) enum GemmInputB<'a> {
     /// A standard unpacked matrix.
     Unpacked(Matrix<'a>),
 
+    /// An unpacked matrix stored as `BF16`. Elements are converted to `f32`
+    /// when blocks are packed.
+    UnpackedBF16(Matrix<'a, BF16>),
+
     /// A matrix which has been pre-packed by [GemmExecutor::prepack_b].
     Packed(&'a PackedBMatrix),
 
@@ -182,6 +245,7 @@ impl<'a> GemmInputB<'a> {
     pub fn rows(&self) -> usize {
         match self {
             Self::Unpacked(m) => m.rows(),
+            Self::UnpackedBF16(m) => m.rows(),
             Self::Packed(pm) => pm.rows,
             Self::Virtual(vm) => vm.rows(),
         }
@@ -190,6 +254,7 @@ impl<'a> GemmInputB<'a> {
     pub fn cols(&self) -> usize {
         match self {
             Self::Unpacked(m) => m.cols(),
+            Self::UnpackedBF16(m) => m.cols(),
             Self::Packed(pm) => pm.cols,
             Self::Virtual(vm) => vm.cols(),
         }
@@ -201,7 +266,7 @@ impl<'a> GemmInputB<'a> {
 /// This computes `output = alpha * (a @ b) + beta * output` where `@` is
 /// matrix multiplication.
 #[allow(unused)]
-pub fn gemm(
+pub(crate) fn gemm(
     out_data: &mut [f32],
     out_row_stride: usize,
     a: Matrix,
@@ -222,6 +287,143 @@ pub fn gemm(
     );
 }
 
+/// Errors reported by [sgemm].
+#[derive(Clone, Debug, PartialEq)]
+pub enum GemmError {
+    /// The number of columns in `op(a)` does not match the number of rows in
+    /// `op(b)`.
+    KSizeMismatch,
+
+    /// The shape of the output matrix `c` does not match the shape of
+    /// `op(a) @ op(b)`.
+    OutputSizeMismatch,
+}
+
+impl Display for GemmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GemmError::KSizeMismatch => {
+                write!(f, "columns of matrix `a` do not match rows of matrix `b`")
+            }
+            GemmError::OutputSizeMismatch => {
+                write!(f, "output matrix `c` does not match shape of `a @ b`")
+            }
+        }
+    }
+}
+
+impl Error for GemmError {}
+
+/// Perform a single-precision General Matrix Multiplication ("sgemm").
+///
+/// This computes `c = alpha * (op(a) @ op(b)) + beta * c`, where `@` is matrix
+/// multiplication and `op(x)` is `x` transposed if the corresponding
+/// `trans_a` / `trans_b` flag is set, or `x` otherwise. As in BLAS, when `beta`
+/// is zero the existing contents of `c` are ignored, so it may contain NaNs.
+///
+/// The inputs and output can have any strides. The output is written in-place
+/// if it is contiguous, otherwise the result is computed in a temporary
+/// buffer and copied into `c`.
+///
+/// This uses the fastest kernel available for the current system, and runs
+/// in parallel using the thread pool returned by
+/// [thread_pool](crate::thread_pool) if called from within that pool, or
+/// Rayon's global pool otherwise.
+///
+/// ```
+/// use rten::gemm::sgemm;
+/// use rten_tensor::NdTensor;
+/// use rten_tensor::prelude::*;
+///
+/// let a = NdTensor::from_data([2, 3], vec![1., 2., 3., 4., 5., 6.]);
+/// let b = NdTensor::from_data([2, 3], vec![1., 0., 1., 0., 1., 0.]);
+/// let mut c = NdTensor::zeros([2, 2]);
+///
+/// // Compute `c = a @ b^T`.
+/// sgemm(1., a.view(), b.view(), 0., c.view_mut(), false, true).unwrap();
+/// assert_eq!(c.to_vec(), [4., 2., 10., 5.]);
+/// ```
+pub fn sgemm(
+    alpha: f32,
+    a: Matrix,
+    b: Matrix,
+    beta: f32,
+    c: MatrixMut,
+    trans_a: bool,
+    trans_b: bool,
+) -> Result<(), GemmError> {
+    let a = if trans_a { a.transposed() } else { a };
+    let b = if trans_b { b.transposed() } else { b };
+    sgemm_impl(alpha, a, GemmInputB::Unpacked(b), beta, c)
+}
+
+/// Variant of [sgemm] where the "B" matrix is stored as `BF16`.
+///
+/// Elements of `b` are converted to `f32` as blocks are packed for the kernel,
+/// so computation happens at full precision, but `b` only needs half the
+/// memory of an `f32` matrix. This is useful for large weight matrices.
+///
+/// ```
+/// use rten::gemm::sgemm_bf16;
+/// use rten::BF16;
+/// use rten_tensor::NdTensor;
+/// use rten_tensor::prelude::*;
+///
+/// let a = NdTensor::from_data([2, 3], vec![1., 2., 3., 4., 5., 6.]);
+/// let b = NdTensor::from_data([2, 3], vec![1., 0., 1., 0., 1., 0.]).map(|x| BF16::from_f32(*x));
+/// let mut c = NdTensor::zeros([2, 2]);
+///
+/// // Compute `c = a @ b^T`.
+/// sgemm_bf16(1., a.view(), b.view(), 0., c.view_mut(), false, true).unwrap();
+/// assert_eq!(c.to_vec(), [4., 2., 10., 5.]);
+/// ```
+pub fn sgemm_bf16(
+    alpha: f32,
+    a: Matrix,
+    b: Matrix<BF16>,
+    beta: f32,
+    c: MatrixMut,
+    trans_a: bool,
+    trans_b: bool,
+) -> Result<(), GemmError> {
+    let a = if trans_a { a.transposed() } else { a };
+    let b = if trans_b { b.transposed() } else { b };
+    sgemm_impl(alpha, a, GemmInputB::UnpackedBF16(b), beta, c)
+}
+
+fn sgemm_impl(
+    alpha: f32,
+    a: Matrix,
+    b: GemmInputB,
+    beta: f32,
+    mut c: MatrixMut,
+) -> Result<(), GemmError> {
+    if a.cols() != b.rows() {
+        return Err(GemmError::KSizeMismatch);
+    }
+    if c.shape() != [a.rows(), b.cols()] {
+        return Err(GemmError::OutputSizeMismatch);
+    }
+
+    let gemm = GemmExecutor::new();
+    let a = GemmInputA::Unpacked(a);
+    let out_row_stride = c.cols();
+
+    if let Some(out_data) = c.data_mut() {
+        gemm.gemm(out_data, out_row_stride, a, b, alpha, beta);
+    } else {
+        let mut tmp = if beta == 0. {
+            NdTensor::zeros(c.shape())
+        } else {
+            c.to_tensor()
+        };
+        gemm.gemm(tmp.data_mut().unwrap(), out_row_stride, a, b, alpha, beta);
+        c.copy_from(&tmp);
+    }
+
+    Ok(())
+}
+
 /// Executes matrix multiplication operations.
 ///
 /// For simple use cases, the standalone [gemm] function can be used.
@@ -234,14 +436,14 @@ pub fn gemm(
 /// operations. In this case the work to pack (re-layout) the input for maximum
 /// computational efficiency, which is normally does internally on each call,
 /// can be done just once for the reused input.
-pub struct GemmExecutor {
+pub(crate) struct GemmExecutor {
     kernel: Box<dyn Kernel>,
     kernel_type: KernelType,
 }
 
 /// Arguments for [GemmExecutor::with_kernel] specifying which kernel to use.
 #[derive(Clone, Copy, Debug)]
-pub enum KernelType {
+pub(crate) enum KernelType {
     /// Use the fallback/base kernel. Always available.
     Base,
 
@@ -347,11 +549,11 @@ impl GemmExecutor {
         let depth_blocks = a.cols().div_ceil(kc);
 
         let packed_len = depth_blocks * row_blocks * panel_len;
-        let mut data = alloc.alloc(packed_len);
+        let mut data = alloc.alloc(packed_len + PACKED_ALIGN / std::mem::size_of::<f32>());
 
         // Pack blocks in the order they will be accessed by the GEMM
         // implementation.
-        let mut out_panels = data.spare_capacity_mut()[..packed_len].chunks_exact_mut(panel_len);
+        let mut out_panels = reserve_aligned(&mut data, packed_len).chunks_exact_mut(panel_len);
         let mut n_init = 0;
         for depth_range in range_chunks(0..a.cols(), kc) {
             for row_range in range_chunks(0..a.rows(), mc) {
@@ -367,14 +569,17 @@ impl GemmExecutor {
             }
         }
 
-        // Safety: We used `pack_a_block` to initialize `packed_len` elements.
+        // Safety: We used `pack_a_block` to initialize `packed_len` elements
+        // after the padding.
         assert!(n_init == packed_len);
+        let offset = data.len();
         unsafe {
-            data.set_len(packed_len);
+            data.set_len(offset + packed_len);
         }
 
         PackedAMatrix {
             data: Cow::Owned(data),
+            offset,
             rows: a.rows(),
             cols: a.cols(),
             panel_len,
@@ -406,11 +611,11 @@ impl GemmExecutor {
         let col_blocks = b.cols().div_ceil(nc);
 
         let packed_len = col_blocks * depth_blocks * panel_len;
-        let mut out = alloc.alloc(packed_len);
+        let mut out = alloc.alloc(packed_len + PACKED_ALIGN / std::mem::size_of::<f32>());
 
         // Pack blocks in the order they will be accessed by the GEMM
         // implementation.
-        let mut out_panels = out.spare_capacity_mut()[..packed_len].chunks_exact_mut(panel_len);
+        let mut out_panels = reserve_aligned(&mut out, packed_len).chunks_exact_mut(panel_len);
         let mut n_init = 0;
         for col_range in range_chunks(0..b.cols(), nc) {
             for depth_range in range_chunks(0..b.rows(), kc) {
@@ -426,18 +631,22 @@ impl GemmExecutor {
             }
         }
 
-        // Safety: We used `pack_b_block` to initialize `packed_len` elements.
+        // Safety: We used `pack_b_block` to initialize `packed_len` elements
+        // after the padding.
         assert!(n_init == packed_len);
+        let offset = out.len();
         unsafe {
-            out.set_len(packed_len);
+            out.set_len(offset + packed_len);
         }
 
         PackedBMatrix {
             data: out,
+            offset,
             rows: b.rows(),
             cols: b.cols(),
             depth_blocks,
             panel_len,
+            kernel_name: self.kernel.name(),
         }
     }
 
@@ -761,6 +970,30 @@ fn gemm_impl(
         return;
     }
 
+    if let GemmInputB::Packed(pm) = b {
+        assert_eq!(
+            pm.kernel_name,
+            kernel.name(),
+            "Packed `b` matrix was packed for a different kernel"
+        );
+    }
+
+    // Delegate to an external BLAS library if enabled and the input layouts
+    // are supported.
+    #[cfg(feature = "blas")]
+    if let (GemmInputA::Unpacked(a), GemmInputB::Unpacked(b)) = (a, b) {
+        if blas::enabled() && blas::sgemm(alpha, a, b, beta, out_data, out_row_stride) {
+            if let Some(bias) = bias {
+                for (row, bias) in out_data.chunks_mut(out_row_stride).zip(bias) {
+                    for x in &mut row[..b.cols()] {
+                        *x += bias;
+                    }
+                }
+            }
+            return;
+        }
+    }
+
     // Construct a Matrix from the implied dimensions, to validate the slice length.
     let mut output_mat = MatrixMut::<f32>::from_data_with_strides(
         [a.rows(), b.cols()],
@@ -784,6 +1017,31 @@ fn gemm_impl(
         return;
     }
 
+    // Use optimized path for matrix-vector products. Since `A x = (x^T A^T)^T`,
+    // this can be computed as a vector-matrix product with the transposed
+    // matrix. The output is a column vector, which is contiguous if the row
+    // stride is 1.
+    if let (1, 1, GemmInputA::Unpacked(a), GemmInputB::Unpacked(b)) =
+        (b.cols(), out_row_stride, a, b)
+    {
+        let mut output_vec = output_mat.permuted_mut([1, 0]);
+        gemv(
+            kernel,
+            b.slice::<1, _>((.., 0)),
+            a.transposed(),
+            output_vec.view_mut(),
+            alpha,
+            beta,
+            None,
+        );
+        if let Some(bias) = bias {
+            for (x, bias) in output_vec.iter_mut().zip(bias) {
+                *x += bias;
+            }
+        }
+        return;
+    }
+
     let output_tiles = OutputTiles::new(output_mat, kernel.mr(), kernel.nr());
 
     // Sizes of blocks that the width (nc), depth (kc) and height (mc)
@@ -797,9 +1055,8 @@ fn gemm_impl(
 
     // Buffers for packed blocks of the matrix.
     //
-    // These currently have no alignment specified. The paper mentioned above
-    // suggests that aligning to cache-line (ie. 64-byte) boundaries may help
-    // performance.
+    // Packed blocks are aligned to cache-line (ie. 64-byte) boundaries, as
+    // suggested by the paper mentioned above. See `reserve_aligned`.
     thread_local!(static PACKED_A: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) });
     thread_local!(static PACKED_B: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) });
 
@@ -829,11 +1086,11 @@ fn gemm_impl(
                 let packed_b_size = (col_end - col_start).next_multiple_of(nr) * panel_length;
 
                 let packed_b = match b {
-                    GemmInputB::Unpacked(_) | GemmInputB::Virtual(_) => PACKED_B.with(|cell| {
+                    GemmInputB::Unpacked(_)
+                    | GemmInputB::UnpackedBF16(_)
+                    | GemmInputB::Virtual(_) => PACKED_B.with(|cell| {
                         let mut packed_b = cell.take();
-                        packed_b.clear();
-                        packed_b.reserve(packed_b_size);
-                        let packed_b_slice = &mut packed_b.spare_capacity_mut()[..packed_b_size];
+                        let packed_b_slice = reserve_aligned(&mut packed_b, packed_b_size);
 
                         match b {
                             GemmInputB::Unpacked(b) => kernel.pack_b_block(
@@ -842,6 +1099,12 @@ fn gemm_impl(
                                 depth_range.clone(),
                                 col_start..col_end,
                             ),
+                            GemmInputB::UnpackedBF16(b) => kernel.pack_b_block_bf16(
+                                packed_b_slice,
+                                b,
+                                depth_range.clone(),
+                                col_start..col_end,
+                            ),
                             GemmInputB::Virtual(vm) => vm.pack_b(
                                 packed_b_slice,
                                 kernel.nr(),
@@ -851,12 +1114,14 @@ fn gemm_impl(
                             GemmInputB::Packed(_) => unreachable!(),
                         }
 
-                        // Safety: The packing call initialized `packed_b_size` elements.
+                        // Safety: The packing call initialized `packed_b_size`
+                        // elements after the padding.
+                        let offset = packed_b.len();
                         unsafe {
-                            packed_b.set_len(packed_b_size);
+                            packed_b.set_len(offset + packed_b_size);
                         }
                         thread_local_packed_b = Some(packed_b);
-                        thread_local_packed_b.as_deref().unwrap()
+                        &thread_local_packed_b.as_deref().unwrap()[offset..]
                     }),
                     GemmInputB::Packed(pm) => pm.block(col_idx, depth_idx),
                 };
@@ -881,21 +1146,20 @@ fn gemm_impl(
                         let packed_a = match a {
                             GemmInputA::Unpacked(a) => PACKED_A.with(|cell| {
                                 let mut packed_a = cell.take();
-                                packed_a.clear();
-                                packed_a.reserve(packed_a_size);
                                 kernel.pack_a_block(
-                                    &mut packed_a.spare_capacity_mut()[..packed_a_size],
+                                    reserve_aligned(&mut packed_a, packed_a_size),
                                     a,
                                     row_start..row_end,
                                     depth_range.clone(),
                                 );
                                 // Safety: `pack_a_block` will have initialized
-                                // `packed_a_size` elements.
+                                // `packed_a_size` elements after the padding.
+                                let offset = packed_a.len();
                                 unsafe {
-                                    packed_a.set_len(packed_a_size);
+                                    packed_a.set_len(offset + packed_a_size);
                                 }
                                 thread_local_packed_a = Some(packed_a);
-                                thread_local_packed_a.as_deref().unwrap()
+                                &thread_local_packed_a.as_deref().unwrap()[offset..]
                             }),
                             GemmInputA::Packed(pm) => pm.block(row_idx, depth_idx),
                         };
@@ -948,14 +1212,8 @@ fn gemm_block(
     beta: f32,
     bias: Option<&[f32]>,
 ) {
-    // Maximum tile size of all supported kernels.
-    const MAX_MR: usize = 8;
-    const MAX_NR: usize = 32;
-
     let (mr, nr) = (kernel.mr(), kernel.nr());
 
-    assert!(nr <= MAX_NR && mr <= MAX_MR);
-
     let b_panel_size = panel_length * nr;
     let a_panel_size = mr * panel_length;
 
@@ -978,55 +1236,20 @@ fn gemm_block(
                 //    every output tile is processed by one thread at a time.
                 let out_tile = unsafe { output.tile(row_tile, col_tile) };
 
-                if out_tile.used_rows == mr && out_tile.used_cols == nr {
-                    // Safety:
-                    //  - Tile size is MR * NR
-                    unsafe {
-                        kernel.kernel(
-                            out_tile.ptr,
-                            out_tile.row_stride,
-                            a_panel,
-                            b_panel,
-                            panel_length,
-                            alpha,
-                            beta,
-                        );
-                    }
-                } else {
-                    // If this is not a full size tile, run the kernel on a
-                    // temporary buffer that is the size of a full tile, then
-                    // copy the results back to the output. This allows the same
-                    // kernel implementation to be used whether the tile is
-                    // full-sized or not.
-                    let mut tmp_out_tile =
-                        [std::mem::MaybeUninit::<f32>::uninit(); MAX_MR * MAX_NR];
-
-                    // Safety:
-                    //  - Tile size is <= MAX_MR * MAX_NR
-                    unsafe {
-                        kernel.kernel(
-                            std::mem::transmute(tmp_out_tile.as_mut_ptr()),
-                            nr,
-                            a_panel,
-                            b_panel,
-                            panel_length,
-                            alpha,
-                            0., // Multiplication with `beta` is handled below.
-                        );
-                    }
-
-                    for i in 0..out_tile.used_rows {
-                        for j in 0..out_tile.used_cols {
-                            // Safety: Row and column indices are < used rows /
-                            // cols in this tile.
-                            unsafe {
-                                let out_el = out_tile.ptr.add(out_tile.row_stride * i + j);
-                                let tmp = if beta == 0. { 0. } else { *out_el };
-                                *out_el = beta * tmp
-                                    + tmp_out_tile.get_unchecked(i * nr + j).assume_init();
-                            }
-                        }
-                    }
+                // Safety:
+                //  - Used rows and columns of the tile are <= MR and NR
+                unsafe {
+                    kernel.kernel(
+                        out_tile.ptr,
+                        out_tile.row_stride,
+                        out_tile.used_rows,
+                        out_tile.used_cols,
+                        a_panel,
+                        b_panel,
+                        panel_length,
+                        alpha,
+                        beta,
+                    );
                 }
 
                 // Add bias vector on first write to an output tile.
@@ -1059,7 +1282,11 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{Matrix, MatrixLayout, NdTensor, Tensor};
 
-    use super::{gemm, GemmExecutor, GemmInputA, GemmInputB, KernelType, VirtualMatrix};
+    use super::{
+        gemm, sgemm, sgemm_bf16, GemmError, GemmExecutor, GemmInputA, GemmInputB, KernelType,
+        VirtualMatrix, PACKED_ALIGN,
+    };
+    use crate::number::BF16;
 
     fn reference_matmul_alpha_beta(a: &Tensor, b: &Tensor, alpha: f32, beta: f32) -> Tensor {
         let [a_rows, _a_cols]: [usize; 2] = a.shape().try_into().expect("input should be a matrix");
@@ -1116,6 +1343,25 @@ mod tests {
         );
     }
 
+    /// Return the kernels which are supported on the current system.
+    fn available_kernels() -> Vec<KernelType> {
+        let mut kernels = vec![KernelType::Base];
+        #[cfg(target_arch = "x86_64")]
+        kernels.push(KernelType::Fma);
+        #[cfg(feature = "avx512")]
+        #[cfg(target_arch = "x86_64")]
+        kernels.push(KernelType::Avx512);
+        #[cfg(target_arch = "aarch64")]
+        kernels.push(KernelType::ArmNeon);
+        #[cfg(target_arch = "wasm32")]
+        kernels.push(KernelType::Wasm);
+
+        kernels
+            .into_iter()
+            .filter(|&kernel| GemmExecutor::with_kernel(kernel).is_some())
+            .collect()
+    }
+
     /// Very slow but simple reference implementation. This should produce the
     /// same results as the optimized GEMM, but small numerical differences will
     /// appear in problems with a large K dimension, due to the different
@@ -1383,6 +1629,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gemm_edge_tiles() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        // Choose sizes which are not a multiple of any kernel's tile size, so
+        // that the tiles at the bottom and right edges are partially used.
+        let (m, n, k) = (13, 37, 5);
+        let a = Tensor::rand(&[m, k], &mut rng);
+        let b = Tensor::rand(&[k, n], &mut rng);
+
+        // The output is followed by padding, which must not be modified.
+        let padding = -100.;
+        let padding_len = 64;
+
+        for kernel in available_kernels() {
+            let gemm = GemmExecutor::with_kernel(kernel).unwrap();
+
+            for beta in [0., 0.5] {
+                let mut expected = Tensor::rand(&[m, n], &mut rng);
+                let mut out = expected.to_vec();
+                out.resize(m * n + padding_len, padding);
+
+                gemm.gemm(
+                    &mut out[..m * n],
+                    n,
+                    GemmInputA::Unpacked(a.nd_view()),
+                    GemmInputB::Unpacked(b.nd_view()),
+                    1., /* alpha */
+                    beta,
+                );
+                reference_gemm(&mut expected, &a, &b, 1., beta, None);
+
+                assert!(
+                    out[m * n..].iter().all(|&x| x == padding),
+                    "kernel {} wrote outside of the output",
+                    gemm.kernel_name()
+                );
+                out.truncate(m * n);
+                expect_equal(&Tensor::from_data(&[m, n], out), &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_gemm_bias() -> Result<(), Box<dyn Error>> {
         let mut rng = XorShiftRng::new(1234);
@@ -1479,6 +1770,153 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sgemm() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let (m, n, k) = (20, 30, 10);
+        let a = Tensor::rand(&[m, k], &mut rng);
+        let b = Tensor::rand(&[k, n], &mut rng);
+        let a_t = a.transposed().to_tensor();
+        let b_t = b.transposed().to_tensor();
+
+        let mut expected = Tensor::rand(&[m, n], &mut rng);
+        let initial_c = expected.clone();
+        reference_gemm(&mut expected, &a, &b, 0.5, 2., None);
+
+        for (a, b, trans_a, trans_b) in [
+            (&a, &b, false, false),
+            (&a_t, &b, true, false),
+            (&a, &b_t, false, true),
+            (&a_t, &b_t, true, true),
+        ] {
+            let mut c = initial_c.clone();
+            sgemm(
+                0.5,
+                a.nd_view(),
+                b.nd_view(),
+                2.,
+                c.nd_view_mut(),
+                trans_a,
+                trans_b,
+            )?;
+            expect_equal(&c, &expected)?;
+        }
+
+        // Non-contiguous output.
+        let mut c_t = initial_c.transposed().to_tensor();
+        sgemm(
+            0.5,
+            a.nd_view(),
+            b.nd_view(),
+            2.,
+            c_t.permuted_mut(&[1, 0]).nd_view_mut(),
+            false,
+            false,
+        )?;
+        expect_equal(&c_t.transposed().to_tensor(), &expected)?;
+
+        // Mismatched shapes.
+        let mut c = initial_c.clone();
+        let result = sgemm(
+            1.,
+            a.nd_view(),
+            a.nd_view(),
+            0.,
+            c.nd_view_mut(),
+            false,
+            false,
+        );
+        assert_eq!(result, Err(GemmError::KSizeMismatch));
+        let result = sgemm(
+            1.,
+            a.nd_view(),
+            a.nd_view(),
+            0.,
+            c.nd_view_mut(),
+            false,
+            true,
+        );
+        assert_eq!(result, Err(GemmError::OutputSizeMismatch));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sgemm_bf16() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case {
+            m: usize,
+            n: usize,
+            k: usize,
+        }
+
+        let cases = [
+            // Single block along all dimensions.
+            Case {
+                m: 10,
+                k: 20,
+                n: 30,
+            },
+            // Vector-matrix product.
+            Case { m: 1, k: 20, n: 30 },
+            // Multiple depth and column blocks.
+            Case {
+                m: 10,
+                k: DEPTH_BLOCK_SIZE + 50,
+                n: COL_BLOCK_SIZE + 50,
+            },
+        ];
+
+        for Case { m, n, k } in cases {
+            let a = Tensor::rand(&[m, k], &mut rng);
+            let b = Tensor::rand(&[k, n], &mut rng).map(|x| BF16::from_f32(*x));
+            let b_t = b.transposed().to_tensor();
+
+            // Converting `BF16` to `f32` is exact, so the result should match
+            // a GEMM with the converted matrix.
+            let b_f32 = b.map(|x| x.to_f32());
+            let mut expected = Tensor::rand(&[m, n], &mut rng);
+            let initial_c = expected.clone();
+            reference_gemm(&mut expected, &a, &b_f32, 0.5, 2., None);
+
+            for (b, trans_b) in [(&b, false), (&b_t, true)] {
+                let mut c = initial_c.clone();
+                sgemm_bf16(
+                    0.5,
+                    a.nd_view(),
+                    b.nd_view(),
+                    2.,
+                    c.nd_view_mut(),
+                    false,
+                    trans_b,
+                )?;
+                expect_equal(&c, &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepacked_blocks_are_aligned() {
+        let mut rng = XorShiftRng::new(1234);
+        let gemm = GemmExecutor::new();
+        let is_aligned = |block: &[f32]| block.as_ptr().align_offset(PACKED_ALIGN) == 0;
+
+        let a = Tensor::rand(&[10, DEPTH_BLOCK_SIZE + 3], &mut rng);
+        let packed_a = gemm.prepack_a(a.nd_view());
+        assert!(is_aligned(packed_a.block(0, 0)));
+
+        let b = Tensor::rand(&[DEPTH_BLOCK_SIZE + 3, COL_BLOCK_SIZE + 5], &mut rng);
+        let packed_b = gemm.prepack_b(b.nd_view());
+        for col_block in 0..2 {
+            for depth_block in 0..2 {
+                assert!(is_aligned(packed_b.block(col_block, depth_block)));
+            }
+        }
+    }
+
     #[test]
     fn test_gemm_virtual() -> Result<(), Box<dyn Error>> {
         let mut rng = XorShiftRng::new(1234);
@@ -1773,6 +2211,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gemv_matrix_vector() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            m: usize,
+            k: usize,
+            alpha: f32,
+            beta: f32,
+            bias: bool,
+            transpose_a: bool,
+        }
+
+        impl Default for Case {
+            fn default() -> Case {
+                Case {
+                    m: 16,
+                    k: 16,
+                    alpha: 1.,
+                    beta: 0.,
+                    bias: false,
+                    transpose_a: false,
+                }
+            }
+        }
+
+        let cases = [
+            // Smallest possible input
+            Case {
+                m: 1,
+                k: 1,
+                ..Default::default()
+            },
+            // Neither `m` nor `k` is a multiple of the tile sizes
+            Case {
+                m: 21,
+                k: 21,
+                ..Default::default()
+            },
+            // `m` exceeds the column block size, `k` exceeds depth block size
+            Case {
+                m: 300,
+                k: 300,
+                ..Default::default()
+            },
+            // Non-standard alpha and beta values
+            Case {
+                m: 21,
+                k: 20,
+                alpha: 0.5,
+                beta: 0.5,
+                ..Default::default()
+            },
+            // Test with bias
+            Case {
+                m: 21,
+                k: 20,
+                bias: true,
+                ..Default::default()
+            },
+            // Column-major matrix
+            Case {
+                m: 21,
+                k: 20,
+                transpose_a: true,
+                ..Default::default()
+            },
+            Case {
+                m: 21,
+                k: 20,
+                beta: 1.,
+                transpose_a: true,
+                ..Default::default()
+            },
+        ];
+
+        let mut rng = XorShiftRng::new(1234);
+
+        for Case {
+            m,
+            k,
+            alpha,
+            beta,
+            bias,
+            transpose_a,
+        } in cases
+        {
+            let a = if transpose_a {
+                let mut a = Tensor::rand(&[k, m], &mut rng);
+                a.transpose();
+                a
+            } else {
+                Tensor::rand(&[m, k], &mut rng)
+            };
+            let b = Tensor::rand(&[k, 1], &mut rng);
+            let bias: Option<Vec<f32>> = bias.then(|| (0..m).map(|i| i as f32).collect());
+
+            let mut result = Tensor::rand(&[m, 1], &mut rng);
+            let mut expected = result.clone();
+
+            run_gemm(&mut result, &a, &b, alpha, beta, bias.as_deref(), None);
+            reference_gemm(&mut expected, &a, &b, alpha, beta, bias.as_deref());
+
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
     use crate::timer::Timer;
 
     // Run with `cargo test --release bench_gemm -- --nocapture --ignored`
@@ -1818,10 +2363,19 @@ mod tests {
             },
         ];
 
-        println!("Testing kernel {}", GemmExecutor::new().kernel_name());
+        // Benchmark every kernel supported on the current system, so that
+        // eg. the AVX 2 and AVX 512 kernels can be compared on the same CPU.
+        for kernel in available_kernels() {
+            let gemm = GemmExecutor::with_kernel(kernel).unwrap();
+            println!("Testing kernel {}", gemm.kernel_name());
 
-        for case in cases {
-            let Case { m, n, k } = case;
+            for case in &cases {
+                bench_gemm_case(case, kernel);
+            }
+        }
+
+        fn bench_gemm_case(case: &Case, kernel: KernelType) {
+            let &Case { m, n, k } = case;
 
             // Adjust number of iterations based on a target amount of work,
             // so that each case takes roughly the same amount of time, assuming
@@ -1841,7 +2395,7 @@ mod tests {
             let mut t = Timer::new();
             t.start();
             for _i in 0..iters {
-                run_gemm(&mut result, &a, &b, 1., 0., None, None);
+                run_gemm(&mut result, &a, &b, 1., 0., None, Some(kernel));
             }
             t.end();
 
@@ -1878,6 +2432,32 @@ mod tests {
         }
     }
 
+    #[test]
+    #[ignore]
+    fn bench_sgemm() {
+        let (m, n, k) = (512, 512, 512);
+        let iters = 100;
+        let mut rng = XorShiftRng::new(1234);
+        let a = NdTensor::rand([m, k], &mut rng);
+        let b = NdTensor::rand([k, n], &mut rng);
+        let mut c = NdTensor::zeros([m, n]);
+
+        for (trans_a, trans_b) in [(false, false), (true, false), (false, true), (true, true)] {
+            run_bench(
+                10,
+                Some(&format!(
+                    "m {} n {} k {} trans_a {} trans_b {} iters {}",
+                    m, n, k, trans_a, trans_b, iters
+                )),
+                || {
+                    for _i in 0..iters {
+                        sgemm(1., a.view(), b.view(), 0., c.view_mut(), trans_a, trans_b).unwrap();
+                    }
+                },
+            );
+        }
+    }
+
     // Like `bench_pack_a`, but this does include allocation costs, so is
     // relevant for ops which prepack inputs (eg. batched matmul).
     #[test]
@@ -0,0 +1,125 @@
+//! Matrix multiplication using an external BLAS library.
+//!
+//! When the `blas` feature is enabled, GEMM operations whose inputs have a
+//! layout supported by BLAS are delegated to `cblas_sgemm`. The library
+//! providing this function must be linked into the final binary. On macOS the
+//! Accelerate framework is linked automatically. On other platforms link a
+//! library such as OpenBLAS or MKL, for example by adding a dependency on the
+//! `openblas-src` crate or using `RUSTFLAGS="-l openblas"`.
+//!
+//! To compare performance against RTen's own kernels, set the `RTEN_USE_BLAS`
+//! environment variable to `0` to disable use of BLAS at runtime.
+
+use std::env;
+use std::ffi::c_int;
+use std::sync::OnceLock;
+
+use rten_tensor::prelude::*;
+use rten_tensor::{Matrix, MatrixLayout};
+
+const CBLAS_ROW_MAJOR: c_int = 101;
+const CBLAS_NO_TRANS: c_int = 111;
+const CBLAS_TRANS: c_int = 112;
+
+#[cfg_attr(target_os = "macos", link(name = "Accelerate", kind = "framework"))]
+extern "C" {
+    fn cblas_sgemm(
+        layout: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f32,
+        a: *const f32,
+        lda: c_int,
+        b: *const f32,
+        ldb: c_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: c_int,
+    );
+}
+
+/// Return true if GEMM operations should be delegated to BLAS.
+pub fn enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        env::var_os("RTEN_USE_BLAS")
+            .map(|val| val != "0")
+            .unwrap_or(true)
+    })
+}
+
+/// Return the CBLAS transpose flag and leading dimension for a row-major
+/// matrix, or `None` if neither dimension of `mat` is contiguous.
+fn cblas_layout(mat: Matrix) -> Option<(c_int, c_int)> {
+    let [rows, cols] = mat.shape();
+    let [row_stride, col_stride] = mat.strides();
+    let (trans, ld) = if col_stride == 1 && row_stride >= cols.max(1) {
+        (CBLAS_NO_TRANS, row_stride)
+    } else if row_stride == 1 && col_stride >= rows.max(1) {
+        (CBLAS_TRANS, col_stride)
+    } else {
+        return None;
+    };
+    Some((trans, ld.try_into().ok()?))
+}
+
+/// Compute `c = alpha * (a @ b) + beta * c` using BLAS, where `c` is a
+/// row-major matrix with row stride `c_row_stride`.
+///
+/// Returns `false` without modifying `c` if the layout or size of the inputs
+/// is not supported by BLAS.
+pub fn sgemm(
+    alpha: f32,
+    a: Matrix,
+    b: Matrix,
+    beta: f32,
+    c: &mut [f32],
+    c_row_stride: usize,
+) -> bool {
+    let (m, k) = (a.rows(), a.cols());
+    let n = b.cols();
+    assert_eq!(b.rows(), k);
+    assert!(c_row_stride >= n);
+    assert!(
+        m == 0 || c.len() >= (m - 1) * c_row_stride + n,
+        "Output buffer should be large enough"
+    );
+
+    let (Some((trans_a, lda)), Some((trans_b, ldb))) = (cblas_layout(a), cblas_layout(b)) else {
+        return false;
+    };
+    let (Ok(m), Ok(n), Ok(k), Ok(ldc)) = (
+        c_int::try_from(m),
+        c_int::try_from(n),
+        c_int::try_from(k),
+        c_int::try_from(c_row_stride),
+    ) else {
+        return false;
+    };
+
+    // Safety: The layouts of `a` and `b` were validated above and the length
+    // of `c` was checked against the output size.
+    unsafe {
+        cblas_sgemm(
+            CBLAS_ROW_MAJOR,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a.data_ptr(),
+            lda,
+            b.data_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        );
+    }
+
+    true
+}
@@ -3,9 +3,11 @@ use std::ops::Range;
 
 use rten_tensor::{Matrix, MatrixLayout, Storage};
 use rten_vecmath::simd_vec::SimdFloat;
+use rten_vecmath::vec_dot;
 
 use crate::gemm::packing::{pack_a_block, pack_b_block};
 use crate::iter_util::{range_chunks_exact, unroll_loop};
+use crate::number::BF16;
 
 #[cfg(target_arch = "aarch64")]
 pub mod aarch64;
@@ -153,15 +155,19 @@ unsafe fn simd_gemv_transposed<S: SimdFloat>(
         }
     }
 
-    let last_col_tile = col_tiles.remainder();
-    if !last_col_tile.is_empty() {
-        simd_gemv_fallback(
-            &mut out[last_col_tile.clone()],
-            a,
-            b.slice::<2, _>((.., last_col_tile)),
-            alpha,
-            beta,
-        );
+    // Columns of `b` are contiguous, so the remaining columns can each be
+    // handled using a vectorized dot product.
+    for col in col_tiles.remainder() {
+        let b_col = b
+            .slice::<1, _>((.., col))
+            .data()
+            .expect("column should be contiguous");
+        let acc = vec_dot(a, b_col);
+        out[col] = if beta == 0. {
+            alpha * acc
+        } else {
+            alpha * acc + beta * out[col]
+        };
     }
 }
 
@@ -194,7 +200,9 @@ fn simd_gemv_fallback(out: &mut [f32], a: &[f32], b: Matrix, alpha: f32, beta: f
 ///
 /// `S` specifies the SIMD vector type, `MR` is the number of rows in the tile
 /// and `NR_REGS` specifies the number of columns in the tile as a multiple of
-/// the SIMD register width.
+/// the SIMD register width. Only the first `used_rows` rows and `used_cols`
+/// columns of the tile are written, using masked loads and stores for the
+/// final register of each row.
 ///
 /// See [Kernel::kernel].
 ///
@@ -203,6 +211,8 @@ fn simd_gemv_fallback(out: &mut [f32], a: &[f32], b: Matrix, alpha: f32, beta: f
 unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
     tile_ptr: *mut f32,
     tile_row_stride: usize,
+    used_rows: usize,
+    used_cols: usize,
     a: &[f32],
     b: &[f32],
     depth: usize,
@@ -213,6 +223,11 @@ unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
     assert!(a.len() >= depth * MR);
     assert!(b.len() >= depth * NR_REGS * S::LEN);
     assert!(depth > 0);
+    assert!(used_rows <= MR && used_cols <= NR_REGS * S::LEN);
+
+    // Packed B panels are aligned, so we can use aligned loads. Each row of
+    // the panel is a multiple of the vector size.
+    assert!(b.as_ptr().cast::<S>().is_aligned());
 
     let a_ptr = a.as_ptr();
     let b_ptr = b.as_ptr();
@@ -228,7 +243,7 @@ unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
         S::prefetch(b_ptr.add((k + 1) * NR_REGS * S::LEN));
 
         for i in 0..NR_REGS {
-            b_rows[i] = S::load(b_ptr.add(b_off + i * S::LEN));
+            b_rows[i] = S::load_aligned(b_ptr.add(b_off + i * S::LEN));
         }
 
         for i in 0..MR {
@@ -242,7 +257,7 @@ unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
     });
 
     // Prefetch output before the final computation loop
-    for i in 0..MR {
+    for i in 0..used_rows {
         S::prefetch_write(tile_ptr.add(tile_row_stride * i));
     }
 
@@ -252,7 +267,7 @@ unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
     let b_off = k * NR_REGS * S::LEN;
 
     for i in 0..NR_REGS {
-        b_rows[i] = S::load(b_ptr.add(b_off + i * S::LEN));
+        b_rows[i] = S::load_aligned(b_ptr.add(b_off + i * S::LEN));
     }
 
     for i in 0..MR {
@@ -266,6 +281,30 @@ unsafe fn simd_gemm<S: SimdFloat, const MR: usize, const NR_REGS: usize>(
 
     let get_out_ptr = |i, j| tile_ptr.add(tile_row_stride * i + j * S::LEN);
 
+    // Write to an output tile at the edge of the output matrix, which is
+    // smaller than the kernel's tile size.
+    if used_rows < MR || used_cols < NR_REGS * S::LEN {
+        let alpha_broadcast = S::splat(alpha);
+        let beta_broadcast = S::splat(beta);
+
+        for i in 0..used_rows {
+            for j in 0..NR_REGS {
+                let len = used_cols.saturating_sub(j * S::LEN).min(S::LEN);
+                if len == 0 {
+                    break;
+                }
+                let out_ptr = get_out_ptr(i, j);
+                let mut out_val = tmp[i][j].mul(alpha_broadcast);
+                if beta != 0. {
+                    let prev_val = S::load_partial(out_ptr, len);
+                    out_val = prev_val.mul_add(beta_broadcast, out_val);
+                }
+                out_val.store_partial(out_ptr, len);
+            }
+        }
+        return;
+    }
+
     // Write to output tile.
     //
     // We have special cases for zero/one values of alpha and beta, both for
@@ -360,18 +399,39 @@ pub unsafe trait Kernel: Sync {
         cols: Range<usize>,
     );
 
+    /// Variant of [Kernel::pack_b_block] for a "B" input stored as `BF16`.
+    /// Elements are converted to `f32` as they are packed.
+    fn pack_b_block_bf16(
+        &self,
+        out: &mut [MaybeUninit<f32>],
+        b: Matrix<BF16>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    );
+
     /// Compute a tile of the output matrix. The output is stored in row-major
     /// order with `MR` rows and `NR` columns, a row stride of `tile_row_stride`
     /// and column stride of 1.
     ///
+    /// Tiles at the edge of the output matrix may be smaller than `MR x NR`.
+    /// Only the first `used_rows` rows and `used_cols` columns of the tile are
+    /// read or written. The packed inputs still contain a full-sized tile.
+    ///
+    /// `b` must be aligned to the alignment of the kernel's SIMD vector type.
+    /// Blocks packed by [Kernel::pack_b_block] into a buffer aligned to
+    /// [`PACKED_ALIGN`](crate::gemm::PACKED_ALIGN) satisfy this.
+    ///
     /// # Safety
     ///
-    /// The caller must ensure that `tile_ptr` points to a buffer of the correct
-    /// size.
+    /// The caller must ensure that `tile_ptr` points to a buffer with space
+    /// for `used_rows` rows of `used_cols` columns, and that these are at most
+    /// `MR` and `NR` respectively.
     unsafe fn kernel(
         &self,
         tile_ptr: *mut f32,
         tile_row_stride: usize,
+        used_rows: usize,
+        used_cols: usize,
         a: &[f32],
         b: &[f32],
         depth: usize,
@@ -455,13 +515,25 @@ unsafe impl Kernel for BaseKernel {
         rows: Range<usize>,
         cols: Range<usize>,
     ) {
-        pack_b_block::<{ Self::NR }>(out, b, rows, cols);
+        pack_b_block::<{ Self::NR }, _>(out, b, rows, cols);
+    }
+
+    fn pack_b_block_bf16(
+        &self,
+        out: &mut [MaybeUninit<f32>],
+        b: Matrix<BF16>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) {
+        pack_b_block::<{ Self::NR }, _>(out, b, rows, cols);
     }
 
     unsafe fn kernel(
         &self,
         tile_ptr: *mut f32,
         tile_row_stride: usize,
+        used_rows: usize,
+        used_cols: usize,
         a: &[f32],
         b: &[f32],
         depth: usize,
@@ -471,6 +543,16 @@ unsafe impl Kernel for BaseKernel {
         const MR: usize = BaseKernel::MR;
         const NR: usize = BaseKernel::NR;
         const NR_REGS: usize = NR / <f32 as SimdFloat>::LEN;
-        simd_gemm::<f32, MR, NR_REGS>(tile_ptr, tile_row_stride, a, b, depth, alpha, beta);
+        simd_gemm::<f32, MR, NR_REGS>(
+            tile_ptr,
+            tile_row_stride,
+            used_rows,
+            used_cols,
+            a,
+            b,
+            depth,
+            alpha,
+            beta,
+        );
     }
 }
@@ -7,6 +7,7 @@ use rten_vecmath::simd_vec::SimdFloat;
 
 use super::{simd_gemm, simd_gemv, Kernel};
 use crate::gemm::packing::{pack_a_block, pack_b_block};
+use crate::number::BF16;
 
 #[derive(Default)]
 pub struct WasmKernel {
@@ -58,13 +59,25 @@ unsafe impl Kernel for WasmKernel {
         rows: Range<usize>,
         cols: Range<usize>,
     ) {
-        pack_b_block::<{ Self::NR }>(out, b, rows, cols);
+        pack_b_block::<{ Self::NR }, _>(out, b, rows, cols);
+    }
+
+    fn pack_b_block_bf16(
+        &self,
+        out: &mut [MaybeUninit<f32>],
+        b: Matrix<BF16>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) {
+        pack_b_block::<{ Self::NR }, _>(out, b, rows, cols);
     }
 
     unsafe fn kernel(
         &self,
         tile_ptr: *mut f32,
         tile_row_stride: usize,
+        used_rows: usize,
+        used_cols: usize,
         a: &[f32],
         b: &[f32],
         depth: usize,
@@ -75,7 +88,17 @@ unsafe impl Kernel for WasmKernel {
         const NR: usize = WasmKernel::NR;
         const NR_REGS: usize = NR / <v128f as SimdFloat>::LEN;
 
-        simd_gemm::<v128f, MR, NR_REGS>(tile_ptr, tile_row_stride, a, b, depth, alpha, beta);
+        simd_gemm::<v128f, MR, NR_REGS>(
+            tile_ptr,
+            tile_row_stride,
+            used_rows,
+            used_cols,
+            a,
+            b,
+            depth,
+            alpha,
+            beta,
+        );
     }
 
     fn gemv_kernel(&self, out: &mut [f32], a: &[f32], b: Matrix, alpha: f32, beta: f32) {
@@ -13,6 +13,7 @@ use rten_vecmath::is_avx512_supported;
 
 use super::{simd_gemm, simd_gemv, Kernel};
 use crate::gemm::packing::{pack_a_block, pack_b_block};
+use crate::number::BF16;
 
 /// Optimized kernel for x64 CPUs that support AVX + FMA instructions.
 #[derive(Default)]
@@ -43,13 +44,13 @@ unsafe fn pack_a_block_avx<const MR: usize>(
 /// Wrapper for `pack_b_block` which enables AVX instructions.
 #[target_feature(enable = "avx2")]
 #[target_feature(enable = "fma")]
-unsafe fn pack_b_block_avx<const NR: usize>(
+unsafe fn pack_b_block_avx<const NR: usize, T: Copy + Into<f32>>(
     out: &mut [MaybeUninit<f32>],
-    b: Matrix,
+    b: Matrix<T>,
     rows: Range<usize>,
     cols: Range<usize>,
 ) {
-    pack_b_block::<NR>(out, b, rows, cols);
+    pack_b_block::<NR, T>(out, b, rows, cols);
 }
 
 // Safety - The `new` fn tests for AVX-2 / FMA support.
@@ -93,7 +94,20 @@ unsafe impl Kernel for FmaKernel {
     ) {
         // Safety: Kernel can only be constructed if AVX is supported.
         unsafe {
-            pack_b_block_avx::<{ Self::NR }>(out, b, rows, cols);
+            pack_b_block_avx::<{ Self::NR }, _>(out, b, rows, cols);
+        }
+    }
+
+    fn pack_b_block_bf16(
+        &self,
+        out: &mut [MaybeUninit<f32>],
+        b: Matrix<BF16>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) {
+        // Safety: Kernel can only be constructed if AVX is supported.
+        unsafe {
+            pack_b_block_avx::<{ Self::NR }, _>(out, b, rows, cols);
         }
     }
 
@@ -103,6 +117,8 @@ unsafe impl Kernel for FmaKernel {
         &self,
         tile_ptr: *mut f32,
         tile_row_stride: usize,
+        used_rows: usize,
+        used_cols: usize,
         a: &[f32],
         b: &[f32],
         depth: usize,
@@ -113,7 +129,17 @@ unsafe impl Kernel for FmaKernel {
         const NR: usize = FmaKernel::NR;
         const NR_REGS: usize = NR / <__m256 as SimdFloat>::LEN;
 
-        simd_gemm::<__m256, MR, NR_REGS>(tile_ptr, tile_row_stride, a, b, depth, alpha, beta);
+        simd_gemm::<__m256, MR, NR_REGS>(
+            tile_ptr,
+            tile_row_stride,
+            used_rows,
+            used_cols,
+            a,
+            b,
+            depth,
+            alpha,
+            beta,
+        );
     }
 
     fn gemv_kernel(&self, out: &mut [f32], a: &[f32], b: Matrix, alpha: f32, beta: f32) {
@@ -145,8 +171,11 @@ impl Avx512Kernel {
     // See https://github.com/robertknight/rten/issues/17.
     const MR: usize = 6;
 
-    // 2 x 16-f32-wide registers.
-    const NR: usize = 32;
+    // 4 x 16-f32-wide registers. With `MR = 6` this uses 24 accumulator
+    // registers plus 4 for the current row of "B", out of the 32 registers
+    // available with AVX-512. This was faster than 6 x 32, 8 x 48, 12 x 32
+    // and 14 x 32 tiles in `bench_gemm`.
+    const NR: usize = 64;
 }
 
 // Safety - The `new` fn checks for AVX-512 support.
@@ -190,7 +219,20 @@ unsafe impl Kernel for Avx512Kernel {
     ) {
         // Safety: We assume AVX-512 implies availability of AVX 2.
         unsafe {
-            pack_b_block_avx::<{ Self::NR }>(out, b, rows, cols);
+            pack_b_block_avx::<{ Self::NR }, _>(out, b, rows, cols);
+        }
+    }
+
+    fn pack_b_block_bf16(
+        &self,
+        out: &mut [MaybeUninit<f32>],
+        b: Matrix<BF16>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) {
+        // Safety: We assume AVX-512 implies availability of AVX 2.
+        unsafe {
+            pack_b_block_avx::<{ Self::NR }, _>(out, b, rows, cols);
         }
     }
 
@@ -200,6 +242,8 @@ unsafe impl Kernel for Avx512Kernel {
         &self,
         tile_ptr: *mut f32,
         tile_row_stride: usize,
+        used_rows: usize,
+        used_cols: usize,
         a: &[f32],
         b: &[f32],
         depth: usize,
@@ -210,7 +254,17 @@ unsafe impl Kernel for Avx512Kernel {
         const NR: usize = Avx512Kernel::NR;
         const NR_REGS: usize = NR / <__m512 as SimdFloat>::LEN;
 
-        simd_gemm::<__m512, MR, NR_REGS>(tile_ptr, tile_row_stride, a, b, depth, alpha, beta)
+        simd_gemm::<__m512, MR, NR_REGS>(
+            tile_ptr,
+            tile_row_stride,
+            used_rows,
+            used_cols,
+            a,
+            b,
+            depth,
+            alpha,
+            beta,
+        )
     }
 
     fn gemv_kernel(&self, out: &mut [f32], a: &[f32], b: Matrix, alpha: f32, beta: f32) {
@@ -96,6 +96,9 @@ pub fn pack_a_block<const MR: usize>(
 /// NR` and uses row-major order. If `cols.len()` is not a multiple of
 /// `NR`, the final panel is zero-padded.
 ///
+/// `b` may use a narrower storage type than `f32`, such as [`BF16`](crate::BF16),
+/// in which case elements are converted to `f32` as they are packed.
+///
 /// Panics if the output buffer is not exactly the correct size.
 ///
 /// # Safety
@@ -103,9 +106,9 @@ pub fn pack_a_block<const MR: usize>(
 /// When this function returns, all elements of `out` will have been initialized
 /// either to a value from `b`, or zero.
 #[inline] // Allow caller to control `target_feature`s
-pub fn pack_b_block<const NR: usize>(
+pub fn pack_b_block<const NR: usize, T: Copy + Into<f32>>(
     out: &mut [MaybeUninit<f32>],
-    b: Matrix,
+    b: Matrix<T>,
     rows: Range<usize>,
     cols: Range<usize>,
 ) {
@@ -146,7 +149,7 @@ pub fn pack_b_block<const NR: usize>(
                         // Safety: Indexes are less than lengths asserted above.
                         unsafe {
                             out.get_unchecked_mut(out_offset + col)
-                                .write(*b_data.get_unchecked(in_offset + col));
+                                .write((*b_data.get_unchecked(in_offset + col)).into());
                         }
                     }
                 }
@@ -157,8 +160,9 @@ pub fn pack_b_block<const NR: usize>(
                     for col in 0..NR {
                         // Safety: Indexes are less than lengths asserted above.
                         unsafe {
-                            out.get_unchecked_mut(out_offset + col)
-                                .write(*b_data.get_unchecked(in_offset + col * b_col_stride));
+                            out.get_unchecked_mut(out_offset + col).write(
+                                (*b_data.get_unchecked(in_offset + col * b_col_stride)).into(),
+                            );
                         }
                     }
                 }
@@ -175,7 +179,7 @@ pub fn pack_b_block<const NR: usize>(
                         b_row_offset + (cols.start + panel_start_col + col) * b_col_stride;
 
                     out[out_row_offset + col].write(if out_col < b_cols {
-                        unsafe { *b_data.get_unchecked(b_offset) }
+                        unsafe { (*b_data.get_unchecked(b_offset)).into() }
                     } else {
                         0.0
                     });
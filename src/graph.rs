@@ -1,6 +1,12 @@
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::future::Future;
 use std::iter::zip;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use rten_tensor::prelude::*;
 use rten_tensor::{DynLayout, Tensor, TensorView};
@@ -9,14 +15,23 @@ use rten_tensor::{DynLayout, Tensor, TensorView};
 // mostly `NodeId`s which we allocate ourselves, so this is not a concern.
 // Instead we want faster hashing.
 use rustc_hash::{FxHashMap, FxHashSet};
+use smallvec::SmallVec;
 
 use crate::constant_storage::ArcTensorView;
 use crate::env::env_flag;
-use crate::ops::{Input, InputList, OpError, Operator, Output};
-use crate::tensor_pool::{ExtractBuffer, TensorPool};
+use crate::memory_plan::{assign_arena_slots, ArenaAllocation, MemoryPlan, StepMemory};
+use crate::number::BF16;
+use crate::op_registry::OpAttrs;
+use crate::ops::{
+    is_broadcast_compatible, unsqueeze_for_broadcast, DataType, Input, InputList, OpError,
+    Operator, Output, PrepackedInput,
+};
+use crate::tensor_pool::{ArenaPool, BufferArena};
 use crate::threading;
 use crate::timer::Timer;
-use crate::timing::{InputShape, RunTiming, TimingRecord, TimingSort};
+use crate::timing::{
+    InputShape, Profile, ProfileRecord, Profiler, RunTiming, TimingRecord, TimingSort,
+};
 
 /// Represents the size of a dimension of a runtime-provided value, such as
 /// an operator input, output or intermediate value.
@@ -36,11 +51,62 @@ pub struct OperatorNode {
     inputs: Vec<Option<NodeId>>,
     outputs: Vec<Option<NodeId>>,
     operator: Box<dyn Operator + Send + Sync>,
+
+    /// Domain and type of a custom operator, as registered with
+    /// [OpRegistry::register_custom_op](crate::OpRegistry::register_custom_op).
+    custom_type: Option<(String, String)>,
+
+    /// Prepacked forms of constant inputs, indexed by input position. See
+    /// [Graph::prepack_weights].
+    prepacked: Vec<Option<PrepackedInput>>,
+}
+
+impl OperatorNode {
+    /// Return the IDs of the operator's inputs. `None` entries are omitted
+    /// optional inputs.
+    pub fn inputs(&self) -> &[Option<NodeId>] {
+        &self.inputs
+    }
+
+    /// Return the IDs of the operator's outputs.
+    pub fn outputs(&self) -> &[Option<NodeId>] {
+        &self.outputs
+    }
+
+    /// Return the operator which this node executes.
+    pub fn operator(&self) -> &(dyn Operator + Send + Sync) {
+        self.operator.as_ref()
+    }
+
+    /// Return the `(domain, op_type)` of a custom operator, or `None` for
+    /// built-in operators.
+    pub fn custom_op_type(&self) -> Option<(&str, &str)> {
+        self.custom_type
+            .as_ref()
+            .map(|(domain, op_type)| (domain.as_str(), op_type.as_str()))
+    }
 }
 
 pub struct ValueNode {
     name: Option<String>,
     shape: Option<Vec<Dimension>>,
+    optional: bool,
+    dtype: Option<DataType>,
+}
+
+impl ValueNode {
+    /// Return the expected shape of this value, if known.
+    pub fn shape(&self) -> Option<&[Dimension]> {
+        self.shape.as_deref()
+    }
+
+    /// Return true if this value may be absent when the graph is run.
+    ///
+    /// If an optional graph input is not provided, operators which use it
+    /// receive `None` for the corresponding input.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
 }
 
 /// Data for a constant node (ie. model weights) in a [Graph].
@@ -67,14 +133,14 @@ pub struct ConstantNode<T> {
 }
 
 impl<T> ConstantNode<T> {
-    fn view(&self) -> TensorView<T> {
+    pub fn view(&self) -> TensorView<T> {
         match &self.data {
             ConstantNodeData::Owned(data) => data.view(),
             ConstantNodeData::Arc(data) => data.view(),
         }
     }
 
-    fn layout(&self) -> &DynLayout {
+    pub fn layout(&self) -> &DynLayout {
         match &self.data {
             ConstantNodeData::Owned(data) => data.layout(),
             ConstantNodeData::Arc(data) => data.layout(),
@@ -85,6 +151,9 @@ impl<T> ConstantNode<T> {
 pub enum Constant {
     Float(ConstantNode<f32>),
     Int(ConstantNode<i32>),
+    Int64(ConstantNode<i64>),
+    String(ConstantNode<String>),
+    BFloat16(ConstantNode<BF16>),
 }
 
 impl Constant {
@@ -92,6 +161,20 @@ impl Constant {
         match self {
             Constant::Float(f) => f.layout(),
             Constant::Int(i) => i.layout(),
+            Constant::Int64(i) => i.layout(),
+            Constant::String(s) => s.layout(),
+            Constant::BFloat16(b) => b.layout(),
+        }
+    }
+
+    /// Return a view of this constant's value as an operator input.
+    pub fn as_input(&self) -> Input<'_> {
+        match self {
+            Constant::Float(node) => Input::FloatTensor(node.view()),
+            Constant::Int(node) => Input::IntTensor(node.view()),
+            Constant::Int64(node) => Input::Int64Tensor(node.view()),
+            Constant::String(node) => Input::StringTensor(node.view()),
+            Constant::BFloat16(node) => Input::BFloat16Tensor(node.view()),
         }
     }
 }
@@ -108,6 +191,24 @@ impl From<ConstantNode<i32>> for Constant {
     }
 }
 
+impl From<ConstantNode<i64>> for Constant {
+    fn from(node: ConstantNode<i64>) -> Constant {
+        Constant::Int64(node)
+    }
+}
+
+impl From<ConstantNode<String>> for Constant {
+    fn from(node: ConstantNode<String>) -> Constant {
+        Constant::String(node)
+    }
+}
+
+impl From<ConstantNode<BF16>> for Constant {
+    fn from(node: ConstantNode<BF16>) -> Constant {
+        Constant::BFloat16(node)
+    }
+}
+
 pub enum Node {
     Operator(OperatorNode),
     Constant(Constant),
@@ -122,12 +223,30 @@ impl Node {
             Node::Constant(constant) => match constant {
                 Constant::Float(node) => &node.name,
                 Constant::Int(node) => &node.name,
+                Constant::Int64(node) => &node.name,
+                Constant::String(node) => &node.name,
+                Constant::BFloat16(node) => &node.name,
             },
             Node::Value(node) => &node.name,
         };
         maybe_name.as_ref().map(|s| s.as_str())
     }
 
+    fn set_name(&mut self, name: Option<String>) {
+        let name_ref = match self {
+            Node::Operator(node) => &mut node.name,
+            Node::Constant(constant) => match constant {
+                Constant::Float(node) => &mut node.name,
+                Constant::Int(node) => &mut node.name,
+                Constant::Int64(node) => &mut node.name,
+                Constant::String(node) => &mut node.name,
+                Constant::BFloat16(node) => &mut node.name,
+            },
+            Node::Value(node) => &mut node.name,
+        };
+        *name_ref = name;
+    }
+
     /// Return the tensor shape associated with this node.
     ///
     /// For constants this is the shape of the tensor. Operator nodes have no
@@ -142,6 +261,22 @@ impl Node {
             Node::Value(node) => node.shape.clone(),
         }
     }
+
+    /// Return the data type associated with this node.
+    ///
+    /// For constants this is the type of the tensor. Operator nodes have no
+    /// data type. For values this is the expected type, if known.
+    pub fn dtype(&self) -> Option<DataType> {
+        match self {
+            Node::Operator(_) => None,
+            Node::Constant(Constant::Float(_)) => Some(DataType::Float),
+            Node::Constant(Constant::Int(_)) => Some(DataType::Int32),
+            Node::Constant(Constant::Int64(_)) => Some(DataType::Int64),
+            Node::Constant(Constant::String(_)) => Some(DataType::String),
+            Node::Constant(Constant::BFloat16(_)) => Some(DataType::BFloat16),
+            Node::Value(node) => node.dtype,
+        }
+    }
 }
 
 /// ID of a node in a [Model](crate::Model) graph.
@@ -161,11 +296,108 @@ pub enum RunError {
     PlanningError(String),
 
     /// Execution of an operator failed
-    OperatorError { name: String, error: OpError },
+    OperatorError {
+        /// Name of the operator node
+        name: String,
+
+        /// Type of the operator (eg. "MatMul")
+        op_type: String,
+
+        /// Shapes of the operator's inputs, in the order they are listed in
+        /// the graph. Omitted inputs have a shape of `None`. This is empty if
+        /// the input shapes are not known.
+        input_shapes: Vec<Option<Vec<usize>>>,
+
+        /// The error returned by the operator
+        error: OpError,
+    },
+
+    /// The inputs to an operator which broadcasts its inputs did not have
+    /// compatible shapes.
+    BroadcastError {
+        /// Name of the operator node
+        name: String,
+
+        /// Shapes of the operator's inputs
+        shapes: Vec<Vec<usize>>,
+
+        /// An `(input_index, axis)` pair indicating where a size-1 axis
+        /// could be inserted to make the shapes compatible, if one exists.
+        unsqueeze: Option<(usize, usize)>,
+    },
 
     /// The output of a graph operator did not match expectations (eg. the
     /// count, types or shapes of outputs did not match what was expected.)
     OutputMismatch(&'static str),
+
+    /// A value was provided which is valid in general, but not supported in
+    /// the context where it was used (eg. a sequence as a constant).
+    UnsupportedValue(&'static str),
+
+    /// The run was cancelled using a [CancelToken].
+    Cancelled,
+
+    /// An operator produced an output containing NaN or infinite values,
+    /// while [RunOptions::check_finite] was enabled.
+    NonFiniteOutput {
+        /// Name of the operator node
+        name: String,
+
+        /// Type of the operator (eg. "MatMul")
+        op_type: String,
+
+        /// Index of the first output which contains non-finite values
+        output: usize,
+
+        /// Statistics for each of the operator's inputs, in the order they
+        /// are listed in the graph. This is `None` for omitted inputs and
+        /// inputs which are not float tensors.
+        input_stats: Vec<Option<ValueStats>>,
+    },
+}
+
+/// Summary statistics for the elements of a float tensor.
+///
+/// Equality is bitwise, so that statistics which are NaN compare equal.
+#[derive(Clone, Copy, Debug)]
+pub struct ValueStats {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+}
+
+impl ValueStats {
+    /// Compute statistics for `input`, if it is a float tensor.
+    fn from_input(input: &Input) -> Option<ValueStats> {
+        let Input::FloatTensor(tensor) = input else {
+            return None;
+        };
+        let (min, max, sum) = tensor.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY, 0.),
+            |(min, max, sum), &x| (min.min(x), max.max(x), sum + x),
+        );
+        Some(ValueStats {
+            min,
+            max,
+            mean: sum / tensor.len() as f32,
+        })
+    }
+}
+
+impl PartialEq for ValueStats {
+    fn eq(&self, other: &ValueStats) -> bool {
+        self.min.to_bits() == other.min.to_bits()
+            && self.max.to_bits() == other.max.to_bits()
+            && self.mean.to_bits() == other.mean.to_bits()
+    }
+}
+
+impl Eq for ValueStats {}
+
+impl fmt::Display for ValueStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "min {} max {} mean {}", self.min, self.max, self.mean)
+    }
 }
 
 impl fmt::Display for RunError {
@@ -176,9 +408,66 @@ impl fmt::Display for RunError {
             RunError::PlanningError(ref err) => write!(f, "planning error {:?}", err),
             RunError::OperatorError {
                 name,
+                op_type,
+                input_shapes,
                 error: ref err,
-            } => write!(f, "operator \"{}\" failed: {:?}", name, err),
+            } => {
+                write!(f, "operator \"{}\" ({}) failed: {:?}", name, op_type, err)?;
+                if !input_shapes.is_empty() {
+                    write!(f, ". Input shapes: ")?;
+                    for (i, shape) in input_shapes.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        match shape {
+                            Some(shape) => write!(f, "{:?}", shape)?,
+                            None => write!(f, "(none)")?,
+                        }
+                    }
+                }
+                Ok(())
+            }
+            RunError::BroadcastError {
+                name,
+                shapes,
+                unsqueeze,
+            } => {
+                write!(
+                    f,
+                    "operator \"{}\" failed: cannot broadcast input shapes {:?}",
+                    name, shapes
+                )?;
+                if let Some((input, axis)) = unsqueeze {
+                    write!(
+                        f,
+                        ". Inserting an axis at index {} of input {} would fix this \
+                         (see `RunOptions::lenient_broadcast`)",
+                        axis, input
+                    )?;
+                }
+                Ok(())
+            }
             RunError::OutputMismatch(err) => write!(f, "output mismatch {:?}", err),
+            RunError::UnsupportedValue(err) => write!(f, "unsupported value: {}", err),
+            RunError::Cancelled => write!(f, "run was cancelled"),
+            RunError::NonFiniteOutput {
+                name,
+                op_type,
+                output,
+                input_stats,
+            } => {
+                write!(
+                    f,
+                    "operator \"{}\" ({}) produced non-finite values in output {}",
+                    name, op_type, output
+                )?;
+                for (i, stats) in input_stats.iter().enumerate() {
+                    if let Some(stats) = stats {
+                        write!(f, ". Input {}: {}", i, stats)?;
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -192,6 +481,49 @@ fn all_unique<T, F: Fn(&T, &T) -> bool>(xs: &[T], eq: F) -> bool {
         .all(|x| xs.iter().filter(|y| eq(x, y)).count() == 1)
 }
 
+/// Future which returns `Pending` once, after waking the task, so that the
+/// executor can run other tasks before resuming.
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Execution plan for computing a fixed set of outputs from a fixed set of
+/// inputs.
+///
+/// See [Graph::prepare].
+pub struct RunPlan<'a> {
+    inputs: Vec<NodeId>,
+    outputs: Vec<NodeId>,
+
+    /// Operators to run, in order.
+    steps: Vec<(NodeId, &'a OperatorNode)>,
+}
+
+impl RunPlan<'_> {
+    /// Return the IDs of the input nodes.
+    pub fn inputs(&self) -> &[NodeId] {
+        &self.inputs
+    }
+
+    /// Return the IDs of the output nodes.
+    pub fn outputs(&self) -> &[NodeId] {
+        &self.outputs
+    }
+}
+
 /// Options for creating a graph execution plan.
 #[derive(Default)]
 struct PlanOptions {
@@ -261,10 +593,226 @@ pub struct RunOptions {
     /// timing reports.
     pub timing_by_shape: bool,
 
+    /// Profiler which collects per-operator execution times and input and
+    /// output shapes. Unlike [RunOptions::timing], which prints a report
+    /// when the run completes, the collected [Profile] can be retrieved using
+    /// [Profiler::take_profile] after the run.
+    pub profiler: Option<Profiler>,
+
     /// Whether to log information about each graph operation as it is executed,
     /// including input shapes and execution time. This will slow down
     /// execution.
     pub verbose: bool,
+
+    /// Whether to print the execution plan before the graph is run. This
+    /// lists the operators that will be executed, in order, together with
+    /// their inputs, outputs and whether they are expected to run in-place.
+    ///
+    /// See also [Model::plan_summary](crate::Model::plan_summary).
+    pub dump_plan: bool,
+
+    /// Whether to automatically fix up inputs to broadcasting operators
+    /// (eg. `Add`) whose shapes are incompatible because one input is missing
+    /// a size-1 axis.
+    ///
+    /// When enabled, if inserting a single size-1 axis into an input whose
+    /// rank is one less than the other inputs makes the shapes compatible,
+    /// the axis is inserted and execution continues. This is intended as a
+    /// workaround for models with missing `Unsqueeze` operators. Operators
+    /// are run as normal if their shapes are already compatible.
+    pub lenient_broadcast: bool,
+
+    /// Arena from which buffers for intermediate values are allocated, and
+    /// to which they are returned at the end of the run.
+    ///
+    /// If not set, each run uses a fresh pool of buffers. See [BufferArena].
+    pub arena: Option<BufferArena>,
+
+    /// Seed for operators which generate random values, such as
+    /// `RandomUniform`.
+    ///
+    /// If set, each random operator in the graph which does not specify its
+    /// own seed is given a seed derived from this value and the operator's
+    /// node ID. This makes the outputs of a run reproducible, while operators
+    /// in the same graph still produce different values. If not set, random
+    /// operators are seeded from a non-deterministic source.
+    pub seed: Option<u64>,
+
+    /// Token which can be used to cancel the run from another thread or
+    /// task. The token is checked before each operator is executed. If it
+    /// has been cancelled, the run stops and returns [RunError::Cancelled].
+    pub cancel: Option<CancelToken>,
+
+    /// Callbacks which are invoked before and after each operator is
+    /// executed. See [RunHooks].
+    pub hooks: Option<RunHooks>,
+
+    /// Whether to check the float outputs of each operator for NaN or
+    /// infinite values.
+    ///
+    /// If a non-finite value is found, the run stops and returns
+    /// [RunError::NonFiniteOutput], which identifies the operator and
+    /// includes statistics for its inputs. This is useful for finding where
+    /// a model that produces invalid outputs first goes wrong, but slows
+    /// down execution.
+    pub check_finite: bool,
+}
+
+/// Information about an operator which is passed to [RunHooks] callbacks.
+#[derive(Debug)]
+pub struct NodeEvent<'a> {
+    /// ID of the operator node.
+    pub node_id: NodeId,
+
+    /// Name of the operator node.
+    pub name: &'a str,
+
+    /// Type of the operator (eg. "MatMul").
+    pub op_type: &'a str,
+
+    /// Shapes of the operator's inputs, in the order they are listed in the
+    /// graph. Omitted inputs have a shape of `None`.
+    pub input_shapes: &'a [Option<Vec<usize>>],
+}
+
+type BeforeNodeFn = dyn Fn(&NodeEvent) + Send + Sync;
+type AfterNodeFn = dyn Fn(&NodeEvent, &[Output]) + Send + Sync;
+
+/// Callbacks which observe the execution of each operator in a graph run.
+///
+/// Hooks can be used for custom logging, checking values or dumping
+/// intermediate activations, without modifying the execution loop. They are
+/// set using [RunOptions::hooks].
+///
+/// The callbacks are invoked only for operators which are executed. An
+/// operator whose output is replaced by a view of its input (see
+/// [Operator::broadcast_shape], [Operator::transpose_perm] and
+/// [Operator::reshape_shape]) is skipped. Callbacks are not invoked for
+/// operators in subgraphs.
+#[derive(Clone, Default)]
+pub struct RunHooks {
+    before_node: Option<Arc<BeforeNodeFn>>,
+    after_node: Option<Arc<AfterNodeFn>>,
+}
+
+impl RunHooks {
+    pub fn new() -> RunHooks {
+        RunHooks::default()
+    }
+
+    /// Set a callback which is invoked before each operator is executed.
+    pub fn before_node<F: Fn(&NodeEvent) + Send + Sync + 'static>(mut self, f: F) -> RunHooks {
+        self.before_node = Some(Arc::new(f));
+        self
+    }
+
+    /// Set a callback which is invoked after each operator is executed
+    /// successfully, with the operator's outputs.
+    ///
+    /// Outputs which are not used by later operators or requested by the
+    /// caller may be omitted. See [Operator::run_with_used_outputs].
+    pub fn after_node<F: Fn(&NodeEvent, &[Output]) + Send + Sync + 'static>(
+        mut self,
+        f: F,
+    ) -> RunHooks {
+        self.after_node = Some(Arc::new(f));
+        self
+    }
+}
+
+impl fmt::Debug for RunHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunHooks").finish_non_exhaustive()
+    }
+}
+
+/// Token used to cancel a graph run which is in progress.
+///
+/// Clones of a token share the same state, so a clone can be passed to a run
+/// via [RunOptions::cancel] and the original used to cancel it, for example
+/// when a request is aborted.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Request cancellation of runs using this token.
+    ///
+    /// Runs stop before executing the next operator. An operator which is
+    /// already executing is not interrupted.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Return true if [CancelToken::cancel] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Derive the seed for the random operator with ID `node_id` from the
+/// run-wide seed `seed`.
+///
+/// This uses the SplitMix64 finalizer so that nearby node IDs produce
+/// unrelated seeds.
+fn node_seed(seed: u64, node_id: NodeId) -> u64 {
+    let mut z = seed ^ (node_id as u64).wrapping_mul(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// A value which is not materialized by the graph executor, because it is a
+/// view of another value. See [Operator::broadcast_shape],
+/// [Operator::transpose_perm] and [Operator::reshape_shape].
+struct ValueView {
+    /// ID of the value that this is a view of.
+    source: NodeId,
+
+    /// Shape of the view.
+    shape: Vec<usize>,
+
+    kind: ViewKind,
+}
+
+/// Specifies how a [ValueView] is created from its source.
+enum ViewKind {
+    /// Broadcast the source to the view's shape.
+    Broadcast,
+
+    /// Permute the axes of the source.
+    Transpose(Vec<usize>),
+
+    /// Reshape a contiguous source to the view's shape.
+    Reshape,
+}
+
+impl ValueView {
+    /// Create the view from the value of the source.
+    fn apply<'a>(&self, source: Input<'a>) -> Input<'a> {
+        match source {
+            Input::FloatTensor(t) => Input::FloatTensor(self.apply_tensor(t)),
+            Input::IntTensor(t) => Input::IntTensor(self.apply_tensor(t)),
+            Input::Int64Tensor(t) => Input::Int64Tensor(self.apply_tensor(t)),
+            Input::StringTensor(t) => Input::StringTensor(self.apply_tensor(t)),
+            Input::BFloat16Tensor(t) => Input::BFloat16Tensor(self.apply_tensor(t)),
+            // Operators only return a view shape for tensor inputs.
+            Input::Sequence(_) => unreachable!("sequences cannot be viewed"),
+        }
+    }
+
+    fn apply_tensor<'a, T>(&self, tensor: TensorView<'a, T>) -> TensorView<'a, T> {
+        match &self.kind {
+            ViewKind::Broadcast => tensor.broadcast(self.shape.as_slice()),
+            ViewKind::Transpose(perm) => tensor.permuted(perm.as_slice()),
+            ViewKind::Reshape => tensor.reshaped(self.shape.as_slice()),
+        }
+    }
 }
 
 /// A graph defines how to produce output values from a set of dynamic input
@@ -276,7 +824,9 @@ pub struct RunOptions {
 /// weights produced during training, a dynamically supplied or produced input
 /// or output value, or a computation step.
 pub struct Graph {
-    nodes: Vec<Node>,
+    /// Nodes indexed by ID. Entries for nodes that have been removed are
+    /// `None`, so that the IDs of other nodes are unaffected.
+    nodes: Vec<Option<Node>>,
 }
 
 impl Graph {
@@ -305,15 +855,50 @@ impl Graph {
         inputs: &[Option<NodeId>],
         outputs: &[Option<NodeId>],
     ) -> NodeId {
-        self.nodes.push(Node::Operator(OperatorNode {
+        self.nodes.push(Some(Node::Operator(OperatorNode {
             name: name.map(|s| s.to_owned()),
             inputs: Vec::from(inputs),
             outputs: Vec::from(outputs),
             operator: op,
-        }));
+            custom_type: None,
+            prepacked: Vec::new(),
+        })));
         self.nodes.len() - 1
     }
 
+    /// Record that an operator node is a custom operator with a given domain
+    /// and type.
+    ///
+    /// Panics if `id` is not an operator node.
+    pub fn set_custom_op_type(&mut self, id: NodeId, domain: &str, op_type: &str) {
+        match self.get_node_mut(id) {
+            Some(Node::Operator(op_node)) => {
+                op_node.custom_type = Some((domain.to_string(), op_type.to_string()))
+            }
+            _ => panic!("node {} is not an operator", id),
+        }
+    }
+
+    /// Replace the operator and inputs of an existing operator node, keeping
+    /// its name and outputs.
+    ///
+    /// Returns `false` if `id` does not refer to an operator node.
+    pub fn replace_op(
+        &mut self,
+        id: NodeId,
+        op: Box<dyn Operator + Send + Sync>,
+        inputs: &[Option<NodeId>],
+    ) -> bool {
+        let Some(Node::Operator(op_node)) = self.get_node_mut(id) else {
+            return false;
+        };
+        op_node.operator = op;
+        op_node.inputs = Vec::from(inputs);
+        op_node.custom_type = None;
+        op_node.prepacked.clear();
+        true
+    }
+
     /// Add a constant node to the graph.
     ///
     /// `name` is an identifier for this node that is used in debug messages etc.
@@ -328,10 +913,59 @@ impl Graph {
             name: name.map(|s| s.to_owned()),
             data: value.into(),
         };
-        self.nodes.push(Node::Constant(node.into()));
+        self.nodes.push(Some(Node::Constant(node.into())));
         self.nodes.len() - 1
     }
 
+    /// Replace the value of an existing constant node, keeping its name.
+    ///
+    /// Returns `false` if `id` does not refer to a constant node.
+    pub fn set_constant<T, V>(&mut self, id: NodeId, value: V) -> bool
+    where
+        V: Into<ConstantNodeData<T>>,
+        ConstantNode<T>: Into<Constant>,
+    {
+        let Some(Node::Constant(constant)) = self.get_node(id) else {
+            return false;
+        };
+        let name = match constant {
+            Constant::Float(node) => node.name.clone(),
+            Constant::Int(node) => node.name.clone(),
+            Constant::Int64(node) => node.name.clone(),
+            Constant::String(node) => node.name.clone(),
+            Constant::BFloat16(node) => node.name.clone(),
+        };
+        let node = ConstantNode {
+            name,
+            data: value.into(),
+        };
+        self.nodes[id] = Some(Node::Constant(node.into()));
+        self.repack_constant(id);
+        true
+    }
+
+    /// Replace a value node with a constant node, keeping its name.
+    ///
+    /// This is used to bind an input to a fixed value, or to replace the
+    /// output of an operator with a precomputed value. Returns `false` if `id`
+    /// does not refer to a value node.
+    pub fn replace_with_constant<T, V>(&mut self, id: NodeId, value: V) -> bool
+    where
+        V: Into<ConstantNodeData<T>>,
+        ConstantNode<T>: Into<Constant>,
+    {
+        let Some(Node::Value(value_node)) = self.get_node(id) else {
+            return false;
+        };
+        let node = ConstantNode {
+            name: value_node.name.clone(),
+            data: value.into(),
+        };
+        self.nodes[id] = Some(Node::Constant(node.into()));
+        self.repack_constant(id);
+        true
+    }
+
     /// Add a value node to the graph.
     ///
     /// `name` is an identifier for this node that is used in debug messages etc.
@@ -343,13 +977,85 @@ impl Graph {
     ///
     /// Returns the ID of the added node.
     pub fn add_value(&mut self, name: Option<&str>, shape: Option<Vec<Dimension>>) -> NodeId {
-        self.nodes.push(Node::Value(ValueNode {
+        self.nodes.push(Some(Node::Value(ValueNode {
             name: name.map(|s| s.to_owned()),
             shape,
-        }));
+            optional: false,
+            dtype: None,
+        })));
         self.nodes.len() - 1
     }
 
+    /// Set the expected data type of a value node.
+    ///
+    /// Panics if `id` is not a value node.
+    pub fn set_value_dtype(&mut self, id: NodeId, dtype: DataType) {
+        match self.get_node_mut(id) {
+            Some(Node::Value(value)) => value.dtype = Some(dtype),
+            _ => panic!("node {} is not a value", id),
+        }
+    }
+
+    /// Add a value node which may be absent when the graph is run.
+    ///
+    /// See [ValueNode::is_optional].
+    pub fn add_optional_value(
+        &mut self,
+        name: Option<&str>,
+        shape: Option<Vec<Dimension>>,
+    ) -> NodeId {
+        let id = self.add_value(name, shape);
+        if let Some(Node::Value(value)) = self.get_node_mut(id) {
+            value.optional = true;
+        }
+        id
+    }
+
+    /// Remove a node from the graph and return it.
+    ///
+    /// The IDs of other nodes are not affected. References to the removed node
+    /// from the inputs or outputs of operators are left in place, so the
+    /// caller is responsible for removing or rerouting these first.
+    pub fn remove_node(&mut self, id: NodeId) -> Option<Node> {
+        self.nodes.get_mut(id).and_then(|node| node.take())
+    }
+
+    /// Set the name of a node.
+    ///
+    /// Returns `false` if `id` does not refer to a node.
+    pub fn set_node_name(&mut self, id: NodeId, name: Option<&str>) -> bool {
+        let Some(node) = self.get_node_mut(id) else {
+            return false;
+        };
+        node.set_name(name.map(|s| s.to_owned()));
+        true
+    }
+
+    /// Replace all uses of `old_id` as an operator input with `new_id`.
+    ///
+    /// Returns the number of operator inputs that were updated.
+    pub fn replace_input(&mut self, old_id: NodeId, new_id: NodeId) -> usize {
+        let mut count = 0;
+        for node in self.nodes.iter_mut().flatten() {
+            let Node::Operator(op_node) = node else {
+                continue;
+            };
+            for input in op_node.inputs.iter_mut() {
+                if *input == Some(old_id) {
+                    *input = Some(new_id);
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Return true if `id` is an optional value, which is absent if it is not
+    /// supplied as an input or produced by an operator.
+    fn is_optional_input(&self, id: NodeId) -> bool {
+        matches!(self.get_node(id), Some(Node::Value(value)) if value.optional)
+    }
+
     /// Return the debug name for a node.
     pub fn node_name(&self, id: NodeId) -> String {
         self.get_node(id)
@@ -360,62 +1066,357 @@ impl Graph {
 
     /// Retrieve a node by ID
     pub fn get_node(&self, id: NodeId) -> Option<&Node> {
-        self.nodes.get(id)
+        self.nodes.get(id).and_then(|node| node.as_ref())
     }
 
-    /// Return the total number of parameters in all constant nodes in the graph.
-    pub fn total_params(&self) -> usize {
+    fn get_node_mut(&mut self, id: NodeId) -> Option<&mut Node> {
+        self.nodes.get_mut(id).and_then(|node| node.as_mut())
+    }
+
+    /// Return an iterator over `(id, node)` pairs for all nodes in the graph.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)> {
         self.nodes
             .iter()
-            .map(|node| match node {
-                Node::Operator(_) => 0,
-                Node::Value(_) => 0,
-                Node::Constant(constant) => constant.layout().len(),
-            })
-            .sum()
+            .enumerate()
+            .filter_map(|(id, node)| node.as_ref().map(|node| (id, node)))
     }
 
-    /// Compute a set of output values given a set of inputs, using the
-    /// processing steps and constant values defined by the graph.
-    pub fn run(
-        &self,
-        inputs: &[(NodeId, Input)],
-        outputs: &[NodeId],
-        opts: Option<RunOptions>,
-    ) -> Result<Vec<Output>, RunError> {
-        let plan = self.create_plan(
-            inputs,
-            outputs,
-            PlanOptions {
-                allow_missing_inputs: false,
-            },
-        )?;
+    /// Prepack constant inputs of operators, such as weight matrices, so that
+    /// the work does not need to be repeated each time the graph is run.
+    ///
+    /// This calls [Operator::prepack] for each input listed by
+    /// [Operator::prepack_inputs] which is a constant. Prepacked inputs use
+    /// additional memory, as the original constants are retained.
+    ///
+    /// Returns the number of inputs that were prepacked.
+    pub fn prepack_weights(&mut self) -> usize {
+        let mut n_prepacked = 0;
+        for id in 0..self.nodes.len() {
+            let Some(Node::Operator(op_node)) = self.get_node(id) else {
+                continue;
+            };
 
-        threading::thread_pool().run(|| self.run_plan(inputs, &plan, outputs, opts))
-    }
+            let mut prepacked: Vec<Option<PrepackedInput>> = Vec::new();
+            for index in op_node.operator.prepack_inputs() {
+                let Some(packed) = self.prepack_input(op_node, index) else {
+                    continue;
+                };
+                if prepacked.len() <= index {
+                    prepacked.resize_with(index + 1, || None);
+                }
+                prepacked[index] = Some(packed);
+                n_prepacked += 1;
+            }
 
-    fn run_plan(
+            if let Some(Node::Operator(op_node)) = self.get_node_mut(id) {
+                op_node.prepacked = prepacked;
+            }
+        }
+        n_prepacked
+    }
+
+    /// Return the prepacked form of the input at `index` of an operator, if
+    /// the input is a constant that the operator can prepack.
+    fn prepack_input(&self, op_node: &OperatorNode, index: usize) -> Option<PrepackedInput> {
+        let Some(Node::Constant(constant)) = op_node
+            .inputs
+            .get(index)
+            .copied()
+            .flatten()
+            .and_then(|input_id| self.get_node(input_id))
+        else {
+            return None;
+        };
+        op_node.operator.prepack(index, constant.as_input())
+    }
+
+    /// Update the prepacked inputs of operators which use `constant_id`,
+    /// after the value of the constant has changed.
+    ///
+    /// Only inputs which were previously prepacked are updated, so this does
+    /// nothing if [Graph::prepack_weights] has not been called.
+    fn repack_constant(&mut self, constant_id: NodeId) {
+        for id in 0..self.nodes.len() {
+            let Some(Node::Operator(op_node)) = self.get_node(id) else {
+                continue;
+            };
+            let repacked: Vec<(usize, Option<PrepackedInput>)> = op_node
+                .prepacked
+                .iter()
+                .enumerate()
+                .filter(|(index, packed)| {
+                    packed.is_some() && op_node.inputs.get(*index) == Some(&Some(constant_id))
+                })
+                .map(|(index, _)| (index, self.prepack_input(op_node, index)))
+                .collect();
+            if repacked.is_empty() {
+                continue;
+            }
+            if let Some(Node::Operator(op_node)) = self.get_node_mut(id) {
+                for (index, packed) in repacked {
+                    op_node.prepacked[index] = packed;
+                }
+            }
+        }
+    }
+
+    /// Return the total number of parameters in all constant nodes in the graph.
+    pub fn total_params(&self) -> usize {
+        self.iter()
+            .map(|(_, node)| match node {
+                Node::Operator(_) => 0,
+                Node::Value(_) => 0,
+                Node::Constant(constant) => constant.layout().len(),
+            })
+            .sum()
+    }
+
+    /// Compute a set of output values given a set of inputs, using the
+    /// processing steps and constant values defined by the graph.
+    pub fn run(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        self.run_owned(inputs, Vec::new(), outputs, opts)
+    }
+
+    /// Compute a set of output values given a set of inputs, taking ownership
+    /// of some of the inputs.
+    ///
+    /// This is like [Graph::run], except that the values in `owned_inputs`
+    /// are moved into the graph. Operators which support in-place execution
+    /// can then update these values in-place, once there are no other users
+    /// of them. For a graph consisting of a chain of such operators, the
+    /// output will re-use the input's buffer instead of allocating a new one.
+    pub fn run_owned(
+        &self,
+        inputs: &[(NodeId, Input)],
+        owned_inputs: Vec<(NodeId, Output)>,
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let input_ids: Vec<_> = inputs
+            .iter()
+            .map(|(id, _)| *id)
+            .chain(owned_inputs.iter().map(|(id, _)| *id))
+            .collect();
+        let plan = self.create_plan(
+            &input_ids,
+            outputs,
+            PlanOptions {
+                allow_missing_inputs: false,
+            },
+        )?;
+
+        threading::thread_pool().run(|| self.run_plan(inputs, owned_inputs, &plan, outputs, opts))
+    }
+
+    /// Create an execution plan for computing `outputs` from `inputs`, which
+    /// can be run repeatedly using [Graph::run_prepared].
+    ///
+    /// This avoids the cost of planning on each run, for applications which
+    /// run the graph many times with the same inputs and outputs.
+    pub fn prepare(&self, inputs: &[NodeId], outputs: &[NodeId]) -> Result<RunPlan<'_>, RunError> {
+        let steps = self.create_plan(inputs, outputs, PlanOptions::default())?;
+        Ok(RunPlan {
+            inputs: inputs.to_vec(),
+            outputs: outputs.to_vec(),
+            steps,
+        })
+    }
+
+    /// Execute a plan created by [Graph::prepare].
+    ///
+    /// `inputs` and `owned_inputs` must provide a value for each of the plan's
+    /// inputs, except for optional inputs (see [ValueNode::is_optional]).
+    pub fn run_prepared(
+        &self,
+        plan: &RunPlan,
+        inputs: &[(NodeId, Input)],
+        owned_inputs: Vec<(NodeId, Output)>,
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let input_ids: Vec<_> = inputs
+            .iter()
+            .map(|(id, _)| *id)
+            .chain(owned_inputs.iter().map(|(id, _)| *id))
+            .collect();
+        if !all_unique(&input_ids, |x, y| x == y) {
+            return Err(RunError::PlanningError("input IDs are not unique".into()));
+        }
+        if let Some(id) = input_ids.iter().find(|id| !plan.inputs.contains(id)) {
+            return Err(RunError::PlanningError(format!(
+                "Input \"{}\" is not an input of the plan",
+                self.node_name(*id)
+            )));
+        }
+        if let Some(id) = plan
+            .inputs
+            .iter()
+            .find(|id| !input_ids.contains(id) && !self.is_optional_input(**id))
+        {
+            return Err(RunError::PlanningError(format!(
+                "Missing input \"{}\"",
+                self.node_name(*id)
+            )));
+        }
+
+        threading::thread_pool()
+            .run(|| self.run_plan(inputs, owned_inputs, &plan.steps, &plan.outputs, opts))
+    }
+
+    /// Compute a set of output values given a set of inputs, yielding to the
+    /// async executor after each operator is executed.
+    ///
+    /// This is like [Graph::run], but allows other tasks to make progress
+    /// during a long run on single-threaded executors, such as in a browser
+    /// where blocking would make the UI unresponsive. Combined with
+    /// [RunOptions::cancel], it also allows a run to be abandoned part way
+    /// through.
+    ///
+    /// Each operator is executed as a separate step, so graph-wide
+    /// optimizations such as avoiding materializing the output of `Expand`
+    /// are not applied. The timing, profiling, verbose logging and plan
+    /// dumping options are ignored.
+    pub async fn run_async(
+        &self,
+        inputs: &[(NodeId, Input<'_>)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let opts = opts.unwrap_or_default();
+        let input_ids: Vec<_> = inputs.iter().map(|(id, _)| *id).collect();
+        let plan = self.create_plan(
+            &input_ids,
+            outputs,
+            PlanOptions {
+                allow_missing_inputs: false,
+            },
+        )?;
+
+        // Count the remaining uses of each value by later steps, or as an
+        // output, so that values can be freed or moved into the last step
+        // which uses them.
+        let mut refcount = NodeRefCount::new();
+        for (_, op_node) in plan.iter() {
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                refcount.inc(node_id);
+            }
+        }
+        for node_id in outputs {
+            refcount.inc(*node_id);
+        }
+
+        let mut temp_values: FxHashMap<NodeId, Output> = FxHashMap::default();
+        for &(op_node_id, op_node) in plan.iter() {
+            if opts
+                .cancel
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+            {
+                return Err(RunError::Cancelled);
+            }
+
+            // Values whose last use is this operator are moved into the step,
+            // so the operator can run in-place.
+            let mut owned_inputs = Vec::new();
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                let uses = op_node.inputs.iter().filter(|id| **id == Some(node_id));
+                if refcount.count(node_id) == uses.count() {
+                    if let Some(value) = temp_values.remove(&node_id) {
+                        owned_inputs.push((node_id, value));
+                    }
+                }
+            }
+
+            let step_outputs: Vec<NodeId> = op_node
+                .outputs
+                .iter()
+                .filter_map(|node| *node)
+                .filter(|id| refcount.count(*id) > 0)
+                .collect();
+            let step_opts = RunOptions {
+                lenient_broadcast: opts.lenient_broadcast,
+                seed: opts.seed,
+                arena: opts.arena.clone(),
+                hooks: opts.hooks.clone(),
+                check_finite: opts.check_finite,
+                ..Default::default()
+            };
+
+            let values = {
+                let step_inputs: Vec<(NodeId, Input)> = inputs
+                    .iter()
+                    .cloned()
+                    .chain(op_node.inputs.iter().filter_map(|node_id| {
+                        let node_id = (*node_id)?;
+                        temp_values.get(&node_id).map(|val| (node_id, val.into()))
+                    }))
+                    .collect();
+                threading::thread_pool().run(|| {
+                    self.run_plan(
+                        &step_inputs,
+                        owned_inputs,
+                        &[(op_node_id, op_node)],
+                        &step_outputs,
+                        Some(step_opts),
+                    )
+                })?
+            };
+
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                if refcount.dec(node_id) == Some(0) {
+                    temp_values.remove(&node_id);
+                }
+            }
+            temp_values.extend(step_outputs.into_iter().zip(values));
+
+            YieldNow { yielded: false }.await;
+        }
+
+        // Collect the outputs, which may also be inputs or constants.
+        threading::thread_pool().run(|| {
+            self.run_plan(
+                inputs,
+                temp_values.into_iter().collect(),
+                &[],
+                outputs,
+                None,
+            )
+        })
+    }
+
+    fn run_plan(
         &self,
         inputs: &[(NodeId, Input)],
+        owned_inputs: Vec<(NodeId, Output)>,
         plan: &[(NodeId, &OperatorNode)],
         outputs: &[NodeId],
         opts: Option<RunOptions>,
     ) -> Result<Vec<Output>, RunError> {
         let opts = opts.unwrap_or_default();
 
+        if opts.dump_plan {
+            let input_ids: Vec<_> = inputs.iter().map(|(id, _)| *id).collect();
+            let owned_ids: Vec<_> = owned_inputs.iter().map(|(id, _)| *id).collect();
+            print!(
+                "{}",
+                self.format_plan(plan, &input_ids, &owned_ids, outputs)
+            );
+        }
+
+        let profiling = opts.profiler.is_some();
         let mut run_timer = Timer::new();
-        if opts.timing {
+        if opts.timing || profiling {
             run_timer.start();
         }
 
         let inputs_by_id: FxHashMap<NodeId, Input> = inputs.iter().cloned().collect();
         let get_value_from_constant_or_input = |node_id: NodeId| -> Option<Input> {
-            if let Some(Node::Constant(constant)) = self.nodes.get(node_id) {
-                let value = match constant {
-                    Constant::Float(node) => Input::FloatTensor(node.view()),
-                    Constant::Int(node) => Input::IntTensor(node.view()),
-                };
-                Some(value)
+            if let Some(Node::Constant(constant)) = self.get_node(node_id) {
+                Some(constant.as_input())
             } else {
                 inputs_by_id.get(&node_id).cloned()
             }
@@ -426,7 +1427,7 @@ impl Graph {
         let mut temp_value_refcount = NodeRefCount::new();
         for (_, op_node) in plan.iter() {
             for node_id in op_node.inputs.iter().filter_map(|node| *node) {
-                if let Some(Node::Value(_)) = self.nodes.get(node_id) {
+                if let Some(Node::Value(_)) = self.get_node(node_id) {
                     temp_value_refcount.inc(node_id);
                 }
             }
@@ -438,30 +1439,225 @@ impl Graph {
             temp_value_refcount.inc(*node_id);
         }
 
-        // Create a pool to re-use buffers across execution steps.
+        // Create a pool to re-use buffers across execution steps, or take
+        // the buffers from the arena if one was provided.
         //
         // If the feature flag is off, we still create the pool, but never
         // release buffers back into it, so all allocations use the system
         // allocator.
-        let pool = TensorPool::new();
+        let pool = opts
+            .arena
+            .as_ref()
+            .map(|arena| arena.take_pool())
+            .unwrap_or_else(ArenaPool::new);
         let use_pool = env_flag("RTEN_USE_POOL", true);
 
-        // Execute the plan
-        let mut temp_values: FxHashMap<NodeId, Output> = FxHashMap::default();
-        let record_timing = opts.timing || opts.verbose;
+        // Execute the plan. Owned inputs are treated like values produced
+        // by earlier operators, so they can be updated in-place.
+        let mut temp_values: FxHashMap<NodeId, Output> = owned_inputs.into_iter().collect();
+
+        // Values which are the output of a broadcasting operator such as
+        // `Expand`, or of a `Transpose`, and are only used by operators which
+        // support broadcast or transposed inputs respectively, are not
+        // materialized. Instead consumers receive a view of the source value.
+        let mut value_views: FxHashMap<NodeId, ValueView> = FxHashMap::default();
+        let mut supports_broadcast_view: FxHashMap<NodeId, bool> = FxHashMap::default();
+        let mut supports_transposed_view: FxHashMap<NodeId, bool> = FxHashMap::default();
+        for (_, op_node) in plan.iter() {
+            let broadcast = op_node.operator.supports_broadcast_inputs();
+            let transposed = op_node.operator.supports_transposed_inputs();
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                *supports_broadcast_view.entry(node_id).or_insert(true) &= broadcast;
+                *supports_transposed_view.entry(node_id).or_insert(true) &= transposed;
+            }
+        }
+
+        let record_timing = opts.timing || opts.verbose || profiling;
         let mut op_elapsed: Vec<TimingRecord> = if record_timing {
             Vec::with_capacity(plan.len())
         } else {
             Vec::new()
         };
+        let mut profile_records: Vec<ProfileRecord> = Vec::new();
         let mut alloc_timer = Timer::new();
 
         for (step, (op_node_id, op_node)) in plan.iter().enumerate() {
+            if opts
+                .cancel
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+            {
+                return Err(RunError::Cancelled);
+            }
+
             let mut op_timer = Timer::new();
             if record_timing {
                 op_timer.start();
             }
 
+            // Skip broadcasting, transposing and reshaping operators if
+            // consumers can use a view of the input instead. Reshaping
+            // operators are skipped only if they cannot reuse the input's
+            // buffer by running in place.
+            if let (Some(Some(source_id)), &[Some(output_id)]) =
+                (op_node.inputs.first(), op_node.outputs.as_slice())
+            {
+                let is_output = outputs.contains(&output_id);
+                let broadcast_supported =
+                    supports_broadcast_view.get(&output_id) == Some(&true) && !is_output;
+                let transpose_supported =
+                    supports_transposed_view.get(&output_id) == Some(&true) && !is_output;
+                let can_reuse_input = temp_values.contains_key(source_id)
+                    && temp_value_refcount.count(*source_id) == 1;
+                let reshape_supported = !is_output && !can_reuse_input;
+                let view = if broadcast_supported || transpose_supported || reshape_supported {
+                    let op_inputs: Vec<Option<Input>> = op_node
+                        .inputs
+                        .iter()
+                        .map(|node_id| {
+                            node_id.and_then(|id| {
+                                get_value_from_constant_or_input(id)
+                                    .or_else(|| temp_values.get(&id).map(|val| val.into()))
+                            })
+                        })
+                        .collect();
+                    let broadcast_view = broadcast_supported
+                        .then(|| {
+                            op_node
+                                .operator
+                                .broadcast_shape(InputList::from_optional(op_inputs.clone()))
+                                .ok()
+                                .flatten()
+                        })
+                        .flatten()
+                        .map(|shape| ValueView {
+                            source: *source_id,
+                            shape,
+                            kind: ViewKind::Broadcast,
+                        });
+                    let transpose_view = || {
+                        let source_shape = op_inputs.first()?.as_ref()?.shape();
+                        let perm = transpose_supported
+                            .then(|| {
+                                op_node
+                                    .operator
+                                    .transpose_perm(InputList::from_optional(op_inputs.clone()))
+                                    .ok()
+                                    .flatten()
+                            })
+                            .flatten()?;
+                        Some(ValueView {
+                            source: *source_id,
+                            shape: perm.iter().map(|&axis| source_shape[axis]).collect(),
+                            kind: ViewKind::Transpose(perm),
+                        })
+                    };
+                    let reshape_view = || {
+                        let source = op_inputs.first()?.as_ref()?;
+                        if !reshape_supported || !source.is_contiguous() {
+                            return None;
+                        }
+                        let shape = op_node
+                            .operator
+                            .reshape_shape(InputList::from_optional(op_inputs.clone()))
+                            .ok()
+                            .flatten()?;
+                        Some(ValueView {
+                            source: *source_id,
+                            shape,
+                            kind: ViewKind::Reshape,
+                        })
+                    };
+                    broadcast_view.or_else(transpose_view).or_else(reshape_view)
+                } else {
+                    None
+                };
+
+                if let Some(view) = view {
+                    // Keep the source alive until all uses of the view are
+                    // done.
+                    for _ in 0..temp_value_refcount.count(output_id) {
+                        temp_value_refcount.inc(*source_id);
+                    }
+                    let view_kind = match view.kind {
+                        ViewKind::Broadcast => "broadcast",
+                        ViewKind::Transpose(_) => "transposed",
+                        ViewKind::Reshape => "reshaped",
+                    };
+                    value_views.insert(output_id, view);
+                    for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                        if temp_value_refcount.dec(node_id) == Some(0) {
+                            temp_values.remove(&node_id);
+                        }
+                    }
+
+                    if opts.verbose {
+                        println!(
+                            "#{} {} ({}) [{} view]",
+                            step,
+                            op_node.operator.name(),
+                            op_node.name.as_deref().unwrap_or(""),
+                            view_kind,
+                        );
+                    }
+                    continue;
+                }
+            }
+
+            // For operators which broadcast their inputs, check the input
+            // shapes up front so that a mismatch can be reported with
+            // context, or fixed up if lenient broadcasting is enabled.
+            let mut broadcast_mismatch = None;
+            if op_node.operator.supports_broadcast_inputs() {
+                // Shapes are stored inline to avoid allocating for every
+                // operator in the common case where the check passes.
+                let shapes: SmallVec<[SmallVec<[usize; 4]>; 4]> = op_node
+                    .inputs
+                    .iter()
+                    .filter_map(|node_id| {
+                        let id = (*node_id)?;
+                        if let Some(value) = get_value_from_constant_or_input(id) {
+                            Some(SmallVec::from_slice(value.shape()))
+                        } else if let Some(value) = temp_values.get(&id) {
+                            Some(SmallVec::from_slice(value.shape()))
+                        } else {
+                            value_views
+                                .get(&id)
+                                .map(|view| SmallVec::from_slice(&view.shape))
+                        }
+                    })
+                    .collect();
+                if !is_broadcast_compatible(&shapes) {
+                    let shape_refs: Vec<&[usize]> = shapes.iter().map(|s| s.as_slice()).collect();
+                    let unsqueeze = unsqueeze_for_broadcast(&shape_refs);
+                    let shapes = shapes.iter().map(|s| s.to_vec()).collect();
+                    broadcast_mismatch = Some((shapes, unsqueeze));
+                }
+            }
+            let lenient_unsqueeze = broadcast_mismatch
+                .as_ref()
+                .and_then(|(_, unsqueeze)| *unsqueeze)
+                .filter(|_| opts.lenient_broadcast);
+            if let (Some((input, axis)), true) = (lenient_unsqueeze, opts.verbose) {
+                println!(
+                    "#{} {} ({}) [inserting axis {} into input {} for broadcasting]",
+                    step,
+                    op_node.operator.name(),
+                    op_node.name.as_deref().unwrap_or(""),
+                    axis,
+                    input
+                );
+            }
+
+            // Determine which outputs are used by later operators in the plan
+            // or were requested by the caller. Operators may skip computing
+            // the others.
+            let used_outputs: SmallVec<[bool; 4]> = op_node
+                .outputs
+                .iter()
+                .map(|id| id.is_some_and(|id| temp_value_refcount.count(id) > 0))
+                .collect();
+
             // Choose the input that we'll try to modify in-place to avoid
             // allocating a new buffer for the output. This will be passed as
             // the first input to `Operator::run_in_place`.
@@ -469,7 +1665,13 @@ impl Graph {
             // For non-commutative ops we have to use the first input. For
             // commutative ops we can swap inputs around if that enables us to
             // run an op in place.
-            let in_place_input_id = if op_node.operator.can_run_in_place() {
+            //
+            // In-place execution only produces a single output, so it is not
+            // used if any other outputs are needed.
+            let in_place_input_id = if op_node.operator.can_run_in_place()
+                && lenient_unsqueeze.is_none()
+                && used_outputs.iter().skip(1).all(|used| !used)
+            {
                 if op_node.operator.is_commutative() {
                     // Pick the largest input by number of elements. This
                     // assumes that commutative op outputs will have a shape
@@ -509,7 +1711,7 @@ impl Graph {
             });
 
             // Collect all or remaining inputs for the operator
-            let mut op_inputs: Vec<Option<Input>> = Vec::with_capacity(op_node.inputs.len());
+            let mut op_inputs: SmallVec<[Option<Input>; 4]> = SmallVec::new();
             for node_id in op_node.inputs.iter() {
                 if in_place_input.is_some() && *node_id == in_place_input_id {
                     continue;
@@ -521,9 +1723,20 @@ impl Graph {
                     } else if let Some(value) = temp_values.get(node_id) {
                         let input = match value {
                             Output::IntTensor(t) => Input::IntTensor(t.view()),
+                            Output::Int64Tensor(t) => Input::Int64Tensor(t.view()),
                             Output::FloatTensor(t) => Input::FloatTensor(t.view()),
+                            Output::StringTensor(t) => Input::StringTensor(t.view()),
+                            Output::Sequence(seq) => Input::Sequence(seq),
                         };
                         op_inputs.push(Some(input));
+                    } else if let Some(view) = value_views.get(node_id) {
+                        let source = get_value_from_constant_or_input(view.source)
+                            .or_else(|| temp_values.get(&view.source).map(|val| val.into()))
+                            .expect("missing source for view");
+                        op_inputs.push(Some(view.apply(source)));
+                    } else if self.is_optional_input(*node_id) {
+                        // Optional graph input which was not provided.
+                        op_inputs.push(None);
                     } else {
                         // If this is reached, there was a bug in plan creation.
                         panic!(
@@ -537,8 +1750,17 @@ impl Graph {
                 }
             }
 
-            // Collect input shapes if we'll need them for timing or logging.
-            let input_shapes = if opts.timing_by_shape || opts.verbose {
+            if let Some((index, axis)) = lenient_unsqueeze {
+                match op_inputs.get_mut(index) {
+                    Some(Some(Input::FloatTensor(t))) => t.insert_axis(axis),
+                    Some(Some(Input::IntTensor(t))) => t.insert_axis(axis),
+                    _ => {}
+                }
+            }
+
+            // Collect input shapes if we'll need them for timing, profiling or
+            // logging.
+            let input_shapes = if opts.timing_by_shape || opts.verbose || profiling {
                 let mut shapes: Vec<InputShape> = Vec::new();
                 if let Some(ref input) = in_place_input {
                     shapes.push(Some(input.shape().into()));
@@ -551,15 +1773,87 @@ impl Graph {
                 Vec::new()
             };
 
-            let op_result = if let Some(input) = in_place_input {
+            // Record the shape of the in-place input, which is consumed by
+            // the operator, so it can be included in any error.
+            let in_place_shape: InputShape =
+                in_place_input.as_ref().map(|input| input.shape().into());
+
+            // Get the shapes of the operator's inputs in graph order. The
+            // in-place input is taken from the copy recorded above, since it
+            // is moved into the operator.
+            let graph_order_input_shapes = || -> Vec<Option<Vec<usize>>> {
                 op_node
-                    .operator
-                    .run_in_place(&pool, input, InputList::from_optional(op_inputs))
-                    .map(|out| [out].into())
+                    .inputs
+                    .iter()
+                    .map(|id| {
+                        let id = (*id)?;
+                        if in_place_shape.is_some() && Some(id) == in_place_input_id {
+                            return in_place_shape.as_ref().map(|shape| shape.to_vec());
+                        }
+                        get_value_from_constant_or_input(id)
+                            .map(|val| val.shape().to_vec())
+                            .or_else(|| temp_values.get(&id).map(|val| val.shape().to_vec()))
+                            .or_else(|| value_views.get(&id).map(|view| view.shape.clone()))
+                    })
+                    .collect()
+            };
+
+            let hook_input_shapes = if opts.hooks.is_some() {
+                graph_order_input_shapes()
             } else {
+                Vec::new()
+            };
+            let node_event = NodeEvent {
+                node_id: *op_node_id,
+                name: op_node.name.as_deref().unwrap_or(""),
+                op_type: op_node.operator.name(),
+                input_shapes: &hook_input_shapes,
+            };
+            // Compute input statistics before running, as the in-place input
+            // may be modified.
+            let input_stats: Vec<Option<ValueStats>> = if opts.check_finite {
                 op_node
-                    .operator
-                    .run(&pool, InputList::from_optional(op_inputs))
+                    .inputs
+                    .iter()
+                    .map(|id| {
+                        let id = (*id)?;
+                        if let (Some(input), true) =
+                            (&in_place_input, Some(id) == in_place_input_id)
+                        {
+                            return ValueStats::from_input(&input.into());
+                        }
+                        // A view has the same statistics as its source.
+                        let id = value_views.get(&id).map(|view| view.source).unwrap_or(id);
+                        get_value_from_constant_or_input(id)
+                            .or_else(|| temp_values.get(&id).map(|val| val.into()))
+                            .and_then(|val| ValueStats::from_input(&val))
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            if let Some(before_node) = opts.hooks.as_ref().and_then(|h| h.before_node.as_ref()) {
+                before_node(&node_event);
+            }
+
+            // Give random operators a seed derived from the run's seed.
+            let seeded_op = opts
+                .seed
+                .and_then(|seed| op_node.operator.with_seed(node_seed(seed, *op_node_id)));
+            let operator = seeded_op.as_deref().unwrap_or(op_node.operator.as_ref());
+
+            let op_result = if let Some(input) = in_place_input {
+                operator
+                    .run_in_place(&pool, input, op_inputs.into_iter().collect())
+                    .map(|out| [out].into())
+            } else {
+                let inputs: InputList = op_inputs.into_iter().collect();
+                operator.run_with_used_outputs(
+                    &pool,
+                    inputs.with_prepacked(&op_node.prepacked),
+                    &used_outputs,
+                )
             };
 
             if record_timing {
@@ -573,6 +1867,20 @@ impl Graph {
                 });
             }
 
+            if let (true, Ok(outputs)) = (profiling, op_result.as_ref()) {
+                profile_records.push(ProfileRecord {
+                    node_id: *op_node_id,
+                    node_name: op_node.name.clone().unwrap_or_default(),
+                    op_type: op_node.operator.name().to_string(),
+                    input_shapes: input_shapes
+                        .iter()
+                        .map(|shape| shape.as_ref().map(|s| s.to_vec()))
+                        .collect(),
+                    output_shapes: outputs.iter().map(|out| out.shape().to_vec()).collect(),
+                    elapsed_ms: op_timer.elapsed_ms(),
+                });
+            }
+
             // Log verbose info if enabled. This is done before we check the
             // result so that in the event of an error, the verbose log includes
             // the failing operator's inputs.
@@ -606,45 +1914,95 @@ impl Graph {
 
             let outputs = match op_result {
                 Ok(outputs) => outputs,
+                Err(OpError::IncompatibleInputShapes(_)) if broadcast_mismatch.is_some() => {
+                    let (shapes, unsqueeze) = broadcast_mismatch.unwrap();
+                    return Err(RunError::BroadcastError {
+                        name: op_node.name.as_deref().unwrap_or("").to_string(),
+                        shapes,
+                        unsqueeze,
+                    });
+                }
                 Err(op_error) => {
                     let err = RunError::OperatorError {
                         name: op_node.name.as_deref().unwrap_or("").to_string(),
+                        op_type: op_node.operator.name().to_string(),
+                        input_shapes: graph_order_input_shapes(),
                         error: op_error,
                     };
                     return Err(err);
                 }
             };
 
-            if op_node.outputs.len() != outputs.len() {
+            if let Some(after_node) = opts.hooks.as_ref().and_then(|h| h.after_node.as_ref()) {
+                after_node(&node_event, &outputs);
+            }
+
+            if opts.check_finite {
+                let non_finite_output =
+                    zip(outputs.iter(), used_outputs.iter()).position(|(output, used)| {
+                        *used
+                            && matches!(output, Output::FloatTensor(t) if t.iter().any(|x| !x.is_finite()))
+                    });
+                if let Some(output) = non_finite_output {
+                    return Err(RunError::NonFiniteOutput {
+                        name: op_node.name.as_deref().unwrap_or("").to_string(),
+                        op_type: op_node.operator.name().to_string(),
+                        output,
+                        input_stats,
+                    });
+                }
+            }
+
+            // The number of outputs may vary depending on the operator's
+            // attributes and inputs (eg. `Split`), and operators may omit
+            // unused trailing outputs. Every output that is used must be
+            // produced however.
+            if used_outputs.iter().skip(outputs.len()).any(|used| *used) {
                 return Err(RunError::OutputMismatch(
-                    "operator output count did not match expected count",
+                    "operator produced fewer outputs than expected",
                 ));
             }
 
-            for (&output_id, output) in zip(op_node.outputs.iter(), outputs.into_iter()) {
-                if let Some(output_id) = output_id {
-                    temp_values.insert(output_id, output);
+            let mut unused_outputs = Vec::new();
+            for (index, output) in outputs.into_iter().enumerate() {
+                match op_node.outputs.get(index) {
+                    Some(&Some(output_id)) if used_outputs[index] => {
+                        temp_values.insert(output_id, output);
+                    }
+                    _ => unused_outputs.push(output),
+                }
+            }
+
+            // Return buffers of outputs that are not used to the pool.
+            if use_pool {
+                for output in unused_outputs {
+                    pool.add_output(output);
                 }
             }
 
             // Remove temporary values that are no longer needed
             record_timing.then(|| alloc_timer.start());
             for node_id in op_node.inputs.iter().filter_map(|node| *node) {
-                let rc = temp_value_refcount.dec(node_id);
-                if rc == Some(0) {
-                    if let (true, Some(tensor)) = (use_pool, temp_values.remove(&node_id)) {
-                        match tensor {
-                            Output::FloatTensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
-                            Output::IntTensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
-                        };
+                // Uses of views are also uses of the source value.
+                let source_id = value_views.get(&node_id).map(|view| view.source);
+                for node_id in std::iter::once(node_id).chain(source_id) {
+                    let rc = temp_value_refcount.dec(node_id);
+                    if rc == Some(0) {
+                        value_views.remove(&node_id);
+                        if let (true, Some(value)) = (use_pool, temp_values.remove(&node_id)) {
+                            pool.add_output(value);
+                        }
                     }
                 }
             }
             record_timing.then(|| alloc_timer.end());
         }
 
-        if opts.timing {
+        if opts.timing || profiling {
             run_timer.end();
+        }
+
+        if opts.timing {
             println!(
                 "Graph run of {} ops finished in {}ms",
                 plan.len(),
@@ -663,6 +2021,10 @@ impl Graph {
             print!("{}", timing.display(opts.timing_sort, opts.timing_by_shape));
         }
 
+        if let Some(profiler) = opts.profiler.as_ref() {
+            profiler.set_profile(Profile::new(profile_records, run_timer.elapsed_ms()));
+        }
+
         // Return the requested outputs
         let result = outputs
             .iter()
@@ -670,7 +2032,11 @@ impl Graph {
                 if let Some(value) = get_value_from_constant_or_input(*output_id) {
                     match value {
                         Input::IntTensor(t) => Output::IntTensor(t.to_tensor()),
+                        Input::Int64Tensor(t) => Output::Int64Tensor(t.to_tensor()),
                         Input::FloatTensor(t) => Output::FloatTensor(t.to_tensor()),
+                        Input::StringTensor(t) => Output::StringTensor(t.to_tensor()),
+                        Input::Sequence(seq) => Output::Sequence(seq.clone()),
+                        Input::BFloat16Tensor(t) => Output::FloatTensor(t.map(|x| x.to_f32())),
                     }
                 } else {
                     // During execution planning we verified that each output
@@ -682,62 +2048,436 @@ impl Graph {
         Ok(result)
     }
 
-    /// Run part of the graph required to produce `outputs`, given an
-    /// incomplete set of `inputs`.
+    /// Return a human-readable description of the plan that would be
+    /// executed to compute `outputs` given values for `inputs`.
     ///
-    /// It is expected that `inputs` is missing some values which are required
-    /// to produce `outputs`. This method will nevertheless produce an
-    /// evaluation plan and evaluate as many intermediate nodes as possible,
-    /// stopping when an operator is reached that transitively depends on a
-    /// missing input. The result is the list of IDs and values of the leaf
-    /// nodes of the subgraph that was evaluated. These intermediate values can
-    /// later be passed to calls to `run` when the missing values are available.
-    pub fn partial_run(
-        &self,
-        inputs: &[(NodeId, Input)],
-        outputs: &[NodeId],
-        opts: Option<RunOptions>,
-    ) -> Result<Vec<(NodeId, Output)>, RunError> {
+    /// The summary lists the operators that will be run, in order, together
+    /// with their input and output values and whether they are expected to
+    /// run in-place. This is the same output that is printed when
+    /// [RunOptions::dump_plan] is enabled.
+    pub fn plan_summary(&self, inputs: &[NodeId], outputs: &[NodeId]) -> Result<String, RunError> {
         let plan = self.create_plan(
             inputs,
             outputs,
             PlanOptions {
-                allow_missing_inputs: true,
+                allow_missing_inputs: false,
             },
         )?;
-        let input_ids: Vec<_> = inputs.iter().map(|(id, _)| id).copied().collect();
-        let (pruned_plan, pruned_plan_output_ids) = self.prune_plan(&plan, &input_ids, outputs);
-        let outputs = threading::thread_pool()
-            .run(|| self.run_plan(inputs, &pruned_plan, &pruned_plan_output_ids, opts))?;
-        let output_ids_and_values: Vec<_> =
-            pruned_plan_output_ids.into_iter().zip(outputs).collect();
-        Ok(output_ids_and_values)
+        Ok(self.format_plan(&plan, inputs, &[], outputs))
     }
 
-    /// Prune a plan so that it contains only operators which can be executed
-    /// given an initial set of inputs.
+    /// Analyze the memory required to compute `outputs` given inputs with
+    /// the shapes in `input_shapes`, without running the graph.
     ///
-    /// Returns a tuple of `(pruned_plan, new_outputs)` where `new_outputs`
-    /// contains the IDs of leaf nodes in the pruned plan. These are the values
-    /// that can still be generated by the reduced plan, and are either in
-    /// the original `outputs` list or are inputs to parts of the plan that
-    /// were pruned away.
-    fn prune_plan<'a>(
+    /// The shapes of intermediate values are determined using
+    /// [Graph::infer_shapes]. Each value is considered alive from the step
+    /// which produces it until the last step which uses it, or the end of
+    /// the run for outputs. Operators are assumed to run in-place under the
+    /// same conditions as in [Graph::run], if the in-place input has the same
+    /// size as the output.
+    pub fn memory_plan(
         &self,
-        plan: &[(NodeId, &'a OperatorNode)],
-        inputs: &[NodeId],
+        input_shapes: &[(NodeId, Vec<usize>)],
         outputs: &[NodeId],
-    ) -> (Vec<(NodeId, &'a OperatorNode)>, Vec<NodeId>) {
-        let mut resolved_values = self.init_resolved_values(inputs.iter().copied());
-        let mut pruned_plan = Vec::new();
-
-        // IDs of input nodes for pruned operators that we can still generate
-        // with the pruned plan.
-        let mut pruned_ops_resolved_inputs = FxHashSet::<NodeId>::default();
+    ) -> Result<MemoryPlan, RunError> {
+        // Tensor elements are currently all 4 bytes (f32 or i32).
+        const ELEMENT_SIZE: usize = 4;
 
-        // Walk forwards through the plan and prune away steps that cannot be
-        // computed due to missing inputs.
-        for (node_id, op_node) in plan {
+        let input_ids: Vec<NodeId> = input_shapes.iter().map(|(id, _)| *id).collect();
+        let plan = self.create_plan(
+            &input_ids,
+            outputs,
+            PlanOptions {
+                allow_missing_inputs: false,
+            },
+        )?;
+
+        let symbolic_input_shapes: Vec<(NodeId, Vec<Dimension>)> = input_shapes
+            .iter()
+            .map(|(id, shape)| (*id, shape.iter().copied().map(Dimension::Fixed).collect()))
+            .collect();
+        let shapes = self.infer_shapes(&symbolic_input_shapes)?;
+        let value_bytes = |id: NodeId| -> Option<usize> {
+            let mut len = 1;
+            for dim in shapes.get(&id)? {
+                match dim {
+                    Dimension::Fixed(size) => len *= size,
+                    Dimension::Symbolic(_) => return None,
+                }
+            }
+            Some(len * ELEMENT_SIZE)
+        };
+
+        // Count uses of temporary values, as in `run_plan`.
+        let mut temp_value_refcount = NodeRefCount::new();
+        for (_, op_node) in plan.iter() {
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                if let Some(Node::Value(_)) = self.get_node(node_id) {
+                    temp_value_refcount.inc(node_id);
+                }
+            }
+        }
+        for node_id in outputs {
+            temp_value_refcount.inc(*node_id);
+        }
+
+        // Sizes of values produced by earlier steps which are still alive.
+        let mut live_values: FxHashMap<NodeId, usize> = FxHashMap::default();
+        let mut live_bytes = 0;
+        let mut steps = Vec::with_capacity(plan.len());
+
+        // Arena allocations for values of known size, and a map of live
+        // value ID to index in `allocations`.
+        let mut allocations: Vec<ArenaAllocation> = Vec::new();
+        let mut live_allocations: FxHashMap<NodeId, usize> = FxHashMap::default();
+
+        for (step, (op_node_id, op_node)) in plan.iter().enumerate() {
+            let output_sizes: Vec<Option<usize>> = op_node
+                .outputs
+                .iter()
+                .map(|id| id.and_then(value_bytes))
+                .collect();
+            let output_bytes: Option<usize> = output_sizes.iter().copied().sum();
+
+            // Find an input whose buffer can be re-used for the output.
+            let in_place_input =
+                if op_node.operator.can_run_in_place() && op_node.outputs.len() == 1 {
+                    let candidates = if op_node.operator.is_commutative() {
+                        &op_node.inputs[..]
+                    } else {
+                        &op_node.inputs[..op_node.inputs.len().min(1)]
+                    };
+                    candidates.iter().filter_map(|id| *id).find(|id| {
+                        temp_value_refcount.count(*id) == 1
+                            && live_values.contains_key(id)
+                            && live_values.get(id).copied() == output_bytes
+                    })
+                } else {
+                    None
+                };
+
+            if let Some(input_id) = in_place_input {
+                let size = live_values.remove(&input_id).unwrap_or(0);
+                temp_value_refcount.dec(input_id);
+                let alloc_idx = live_allocations.remove(&input_id);
+                if let Some(output_id) = op_node.outputs[0] {
+                    live_values.insert(output_id, size);
+                    if let Some(alloc_idx) = alloc_idx {
+                        live_allocations.insert(output_id, alloc_idx);
+                    }
+                }
+            } else {
+                for (output_id, size) in zip(op_node.outputs.iter(), output_sizes.iter()) {
+                    if let Some(output_id) = output_id {
+                        if let Some(size) = size {
+                            live_allocations.insert(*output_id, allocations.len());
+                            allocations.push(ArenaAllocation {
+                                value_id: *output_id,
+                                slot: 0,
+                                size: *size,
+                                first_step: step,
+                                last_step: plan.len(),
+                            });
+                        }
+                        let size = size.unwrap_or(0);
+                        live_values.insert(*output_id, size);
+                        live_bytes += size;
+                    }
+                }
+            }
+
+            steps.push(StepMemory {
+                node_id: *op_node_id,
+                node_name: op_node.name.as_deref().unwrap_or("").to_string(),
+                op_type: op_node.operator.name().to_string(),
+                output_bytes: if in_place_input.is_some() {
+                    Some(0)
+                } else {
+                    output_bytes
+                },
+                in_place: in_place_input.is_some(),
+                live_bytes,
+            });
+
+            // Free values which are no longer needed. This includes outputs
+            // of this step which are not used.
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                if Some(node_id) == in_place_input {
+                    continue;
+                }
+                if temp_value_refcount.dec(node_id) == Some(0) {
+                    live_bytes -= live_values.remove(&node_id).unwrap_or(0);
+                    if let Some(idx) = live_allocations.remove(&node_id) {
+                        allocations[idx].last_step = step;
+                    }
+                }
+            }
+            for output_id in op_node.outputs.iter().filter_map(|node| *node) {
+                if temp_value_refcount.count(output_id) == 0 {
+                    live_bytes -= live_values.remove(&output_id).unwrap_or(0);
+                    if let Some(idx) = live_allocations.remove(&output_id) {
+                        allocations[idx].last_step = step;
+                    }
+                }
+            }
+        }
+
+        let input_bytes = input_shapes
+            .iter()
+            .map(|(_, shape)| shape.iter().product::<usize>() * ELEMENT_SIZE)
+            .sum();
+
+        let slot_sizes = assign_arena_slots(&mut allocations);
+
+        Ok(MemoryPlan {
+            steps,
+            input_bytes,
+            allocations,
+            slot_sizes,
+        })
+    }
+
+    /// Infer the shapes of values in the graph, without running it.
+    ///
+    /// Shapes are propagated from the inputs through each operator using
+    /// [Operator::infer_shapes]. The shapes of inputs are taken from
+    /// `input_shapes` if specified, or the shapes of value nodes otherwise.
+    /// Shapes may contain symbolic dimensions.
+    ///
+    /// Returns a map of value node ID to shape, for all values whose shape is
+    /// known. If an operator's input shapes are known to be incompatible,
+    /// an [RunError::OperatorError] is returned with the operator's name.
+    pub fn infer_shapes(
+        &self,
+        input_shapes: &[(NodeId, Vec<Dimension>)],
+    ) -> Result<FxHashMap<NodeId, Vec<Dimension>>, RunError> {
+        let op_outputs: Vec<NodeId> = self
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Node::Operator(op_node) => Some(op_node.outputs.iter().filter_map(|id| *id)),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let plan = self.create_plan(
+            &[],
+            &op_outputs,
+            PlanOptions {
+                allow_missing_inputs: true,
+            },
+        )?;
+
+        let mut shapes: FxHashMap<NodeId, Vec<Dimension>> = self
+            .iter()
+            .filter_map(|(id, node)| match node {
+                Node::Operator(_) => None,
+                _ => node.shape().map(|shape| (id, shape)),
+            })
+            .collect();
+        shapes.extend(input_shapes.iter().cloned());
+
+        for (_, op_node) in plan {
+            let inferred = {
+                let input_shapes: Vec<Option<&[Dimension]>> = op_node
+                    .inputs
+                    .iter()
+                    .map(|id| id.and_then(|id| shapes.get(&id)).map(|s| s.as_slice()))
+                    .collect();
+                op_node
+                    .operator
+                    .infer_shapes(&input_shapes)
+                    .map_err(|error| RunError::OperatorError {
+                        name: op_node.name.as_deref().unwrap_or("").to_string(),
+                        op_type: op_node.operator.name().to_string(),
+                        input_shapes: Vec::new(),
+                        error,
+                    })?
+            };
+
+            // Inferred shapes take precedence over shapes recorded in the
+            // graph, which may be less specific.
+            for (output_id, shape) in zip(op_node.outputs.iter(), inferred.into_iter().flatten()) {
+                if let Some(output_id) = output_id {
+                    shapes.insert(*output_id, shape);
+                }
+            }
+        }
+
+        Ok(shapes)
+    }
+
+    /// Format an execution plan for debugging.
+    ///
+    /// Whether an operator can actually run in-place is decided during
+    /// execution. The in-place annotations here mirror the rules used by
+    /// [Graph::run_plan]: the operator must support in-place execution and
+    /// the input must be a temporary value which has no other remaining
+    /// consumers. For commutative operators the executor will choose the
+    /// largest input, which is only known at runtime, so all eligible inputs
+    /// are listed.
+    fn format_plan(
+        &self,
+        plan: &[(NodeId, &OperatorNode)],
+        inputs: &[NodeId],
+        owned_inputs: &[NodeId],
+        outputs: &[NodeId],
+    ) -> String {
+        use std::fmt::Write;
+
+        // Count uses of temporary values, as in `run_plan`.
+        let mut temp_value_refcount = NodeRefCount::new();
+        for (_, op_node) in plan.iter() {
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                if let Some(Node::Value(_)) = self.get_node(node_id) {
+                    temp_value_refcount.inc(node_id);
+                }
+            }
+        }
+        for node_id in outputs {
+            temp_value_refcount.inc(*node_id);
+        }
+
+        // Values produced by operators earlier in the plan, or inputs owned by
+        // the graph. Only these can be updated in-place.
+        let mut temp_values: FxHashSet<NodeId> = owned_inputs.iter().copied().collect();
+
+        let mut out = String::new();
+        let input_names: Vec<_> = inputs
+            .iter()
+            .chain(owned_inputs)
+            .map(|id| self.node_name(*id))
+            .collect();
+        let output_names: Vec<_> = outputs.iter().map(|id| self.node_name(*id)).collect();
+        writeln!(out, "Plan of {} steps", plan.len()).unwrap();
+        writeln!(out, "  inputs: {}", input_names.join(", ")).unwrap();
+        writeln!(out, "  outputs: {}", output_names.join(", ")).unwrap();
+
+        for (step, (_, op_node)) in plan.iter().enumerate() {
+            let in_place_candidates: Vec<NodeId> = if op_node.operator.can_run_in_place() {
+                let candidates = if op_node.operator.is_commutative() {
+                    &op_node.inputs[..]
+                } else {
+                    &op_node.inputs[..op_node.inputs.len().min(1)]
+                };
+                candidates
+                    .iter()
+                    .filter_map(|id| *id)
+                    .filter(|id| temp_values.contains(id) && temp_value_refcount.count(*id) == 1)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            write!(
+                out,
+                "#{} {} ({})",
+                step,
+                op_node.operator.name(),
+                op_node.name.as_deref().unwrap_or("")
+            )
+            .unwrap();
+            if !in_place_candidates.is_empty() {
+                let names: Vec<_> = in_place_candidates
+                    .iter()
+                    .map(|id| self.node_name(*id))
+                    .collect();
+                write!(out, " [in-place: {}]", names.join(" | ")).unwrap();
+            }
+            writeln!(out).unwrap();
+
+            for (index, id) in op_node.inputs.iter().enumerate() {
+                let name = id.map(|id| self.node_name(id));
+                writeln!(
+                    out,
+                    "  input {}: {}",
+                    index,
+                    name.as_deref().unwrap_or("(none)")
+                )
+                .unwrap();
+            }
+            for (index, id) in op_node.outputs.iter().enumerate() {
+                let name = id.map(|id| self.node_name(id));
+                writeln!(
+                    out,
+                    "  output {}: {}",
+                    index,
+                    name.as_deref().unwrap_or("(none)")
+                )
+                .unwrap();
+            }
+
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                temp_value_refcount.dec(node_id);
+            }
+            temp_values.extend(op_node.outputs.iter().filter_map(|node| *node));
+        }
+
+        out
+    }
+
+    /// Run part of the graph required to produce `outputs`, given an
+    /// incomplete set of `inputs`.
+    ///
+    /// It is expected that `inputs` is missing some values which are required
+    /// to produce `outputs`. This method will nevertheless produce an
+    /// evaluation plan and evaluate as many intermediate nodes as possible,
+    /// stopping when an operator is reached that transitively depends on a
+    /// missing input. The result is the list of IDs and values of the leaf
+    /// nodes of the subgraph that was evaluated. These intermediate values can
+    /// later be passed to calls to `run` when the missing values are available.
+    pub fn partial_run(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<(NodeId, Output)>, RunError> {
+        let input_ids: Vec<_> = inputs.iter().map(|(id, _)| *id).collect();
+        let plan = self.create_plan(
+            &input_ids,
+            outputs,
+            PlanOptions {
+                allow_missing_inputs: true,
+            },
+        )?;
+        let (pruned_plan, pruned_plan_output_ids) = self.prune_plan(&plan, &input_ids, outputs);
+        let outputs = threading::thread_pool().run(|| {
+            self.run_plan(
+                inputs,
+                Vec::new(),
+                &pruned_plan,
+                &pruned_plan_output_ids,
+                opts,
+            )
+        })?;
+        let output_ids_and_values: Vec<_> =
+            pruned_plan_output_ids.into_iter().zip(outputs).collect();
+        Ok(output_ids_and_values)
+    }
+
+    /// Prune a plan so that it contains only operators which can be executed
+    /// given an initial set of inputs.
+    ///
+    /// Returns a tuple of `(pruned_plan, new_outputs)` where `new_outputs`
+    /// contains the IDs of leaf nodes in the pruned plan. These are the values
+    /// that can still be generated by the reduced plan, and are either in
+    /// the original `outputs` list or are inputs to parts of the plan that
+    /// were pruned away.
+    fn prune_plan<'a>(
+        &self,
+        plan: &[(NodeId, &'a OperatorNode)],
+        inputs: &[NodeId],
+        outputs: &[NodeId],
+    ) -> (Vec<(NodeId, &'a OperatorNode)>, Vec<NodeId>) {
+        let mut resolved_values = self.init_resolved_values(inputs.iter().copied());
+        let mut pruned_plan = Vec::new();
+
+        // IDs of input nodes for pruned operators that we can still generate
+        // with the pruned plan.
+        let mut pruned_ops_resolved_inputs = FxHashSet::<NodeId>::default();
+
+        // Walk forwards through the plan and prune away steps that cannot be
+        // computed due to missing inputs.
+        for (node_id, op_node) in plan {
             let all_inputs_available = op_node
                 .inputs
                 .iter()
@@ -775,7 +2515,7 @@ impl Graph {
     fn init_resolved_values<I: Iterator<Item = NodeId>>(&self, inputs: I) -> FxHashSet<NodeId> {
         inputs
             .chain(
-                self.nodes.iter().enumerate().filter_map(|(node_id, node)| {
+                self.iter().filter_map(|(node_id, node)| {
                     matches!(node, Node::Constant(_)).then_some(node_id)
                 }),
             )
@@ -791,7 +2531,7 @@ impl Graph {
     /// omitted from the plan.
     fn create_plan(
         &self,
-        inputs: &[(NodeId, Input)],
+        inputs: &[NodeId],
         outputs: &[NodeId],
         options: PlanOptions,
     ) -> Result<Vec<(NodeId, &OperatorNode)>, RunError> {
@@ -799,13 +2539,13 @@ impl Graph {
             return Err(RunError::PlanningError("output IDs are not unique".into()));
         }
 
-        if !all_unique(inputs, |(x_id, _), (y_id, _)| x_id == y_id) {
+        if !all_unique(inputs, |x, y| x == y) {
             return Err(RunError::PlanningError("input IDs are not unique".into()));
         }
 
         // Map of output node to source operator
         let mut operator_nodes = FxHashMap::default();
-        for (node_id, node) in self.nodes.iter().enumerate() {
+        for (node_id, node) in self.iter() {
             if let Node::Operator(op_node) = node {
                 for output_id in op_node.outputs.iter().filter_map(|node| *node) {
                     operator_nodes.insert(output_id, (node_id, op_node));
@@ -842,7 +2582,9 @@ impl Graph {
                         self.operator_nodes.get(&input).copied()
                     {
                         self.visit(input_op_id, input_op_node)?;
-                    } else if self.options.allow_missing_inputs {
+                    } else if self.options.allow_missing_inputs
+                        || self.graph.is_optional_input(input)
+                    {
                         continue;
                     } else {
                         let msg = format!(
@@ -877,7 +2619,14 @@ impl Graph {
                     {
                         self.visit(op_node_id, op_node)?;
                     } else {
-                        let msg = format!("Missing output {}", output_id);
+                        // Report values which exist but are not computed by
+                        // any operator by name, eg. inputs which were not
+                        // provided.
+                        let msg = if self.graph.get_node(*output_id).is_some() {
+                            format!("Missing output \"{}\"", self.graph.node_name(*output_id))
+                        } else {
+                            format!("Missing output {}", output_id)
+                        };
                         return Err(RunError::PlanningError(msg));
                     }
                 }
@@ -886,8 +2635,7 @@ impl Graph {
         }
 
         // Set of values that are available after executing the plan
-        let resolved_values: FxHashSet<NodeId> =
-            self.init_resolved_values(inputs.iter().map(|(node_id, _)| *node_id));
+        let resolved_values: FxHashSet<NodeId> = self.init_resolved_values(inputs.iter().copied());
 
         let builder = PlanBuilder {
             graph: self,
@@ -900,20 +2648,108 @@ impl Graph {
     }
 }
 
+/// A graph which is run by a control flow operator, such as the body of a
+/// `Scan` loop.
+///
+/// In addition to the graph itself, this records which nodes are the inputs
+/// and outputs of the subgraph, and the attributes of its operators.
+pub struct Subgraph {
+    graph: Graph,
+    input_ids: Vec<NodeId>,
+    output_ids: Vec<NodeId>,
+    op_attrs: HashMap<NodeId, OpAttrs>,
+}
+
+impl Subgraph {
+    pub(crate) fn new(
+        graph: Graph,
+        input_ids: Vec<NodeId>,
+        output_ids: Vec<NodeId>,
+        op_attrs: HashMap<NodeId, OpAttrs>,
+    ) -> Subgraph {
+        Subgraph {
+            graph,
+            input_ids,
+            output_ids,
+            op_attrs,
+        }
+    }
+
+    pub(crate) fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Return the IDs of the subgraph's input nodes.
+    pub fn input_ids(&self) -> &[NodeId] {
+        &self.input_ids
+    }
+
+    /// Return the IDs of the subgraph's output nodes.
+    pub fn output_ids(&self) -> &[NodeId] {
+        &self.output_ids
+    }
+
+    pub(crate) fn op_attrs(&self) -> &HashMap<NodeId, OpAttrs> {
+        &self.op_attrs
+    }
+
+    /// Run the subgraph and return its outputs.
+    ///
+    /// `inputs` specifies the value for each of the subgraph's inputs, in the
+    /// same order as [Subgraph::input_ids].
+    pub fn run(&self, inputs: &[Input]) -> Result<Vec<Output>, RunError> {
+        if inputs.len() != self.input_ids.len() {
+            return Err(RunError::PlanningError(format!(
+                "subgraph expects {} inputs but {} were provided",
+                self.input_ids.len(),
+                inputs.len()
+            )));
+        }
+        let inputs: Vec<(NodeId, Input)> =
+            zip(self.input_ids.iter().copied(), inputs.iter().cloned()).collect();
+        self.graph.run(&inputs, &self.output_ids, None)
+    }
+}
+
+impl fmt::Debug for Subgraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subgraph")
+            .field("nodes", &self.graph.nodes.len())
+            .field("input_ids", &self.input_ids)
+            .field("output_ids", &self.output_ids)
+            .finish()
+    }
+}
+
+/// Subgraphs are compared by identity, since comparing the graphs themselves
+/// would require comparing operators.
+impl PartialEq for Subgraph {
+    fn eq(&self, other: &Subgraph) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
+    use std::future::Future;
     use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
 
     use rten_tensor::prelude::*;
     use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
     use rten_tensor::{tensor, Tensor, TensorView};
 
-    use crate::graph::{Dimension, Graph, RunError};
+    use crate::graph::{
+        CancelToken, Dimension, Graph, NodeId, RunError, RunHooks, RunOptions, ValueStats,
+    };
+    use crate::ops;
     use crate::ops::{
-        Add, Concat, Conv, InputList, IntoOpResult, OpError, Operator, Output, Relu, Shape,
+        add, Add, Concat, Conv, Expand, InputList, IntoOpResult, MatMul, OpError, Operator, Output,
+        Relu, Reshape, Shape, SymbolicShape, Transpose,
     };
-    use crate::tensor_pool::TensorPool;
+    use crate::tensor_pool::{BufferArena, TensorPool};
+    use crate::timing::{OpTypeSummary, Profiler, TimingSort};
 
     #[derive(Clone, Debug, Default)]
     struct Metrics {
@@ -957,6 +2793,33 @@ mod tests {
             self.inner.is_commutative()
         }
 
+        fn supports_broadcast_inputs(&self) -> bool {
+            self.inner.supports_broadcast_inputs()
+        }
+
+        fn infer_shapes(
+            &self,
+            inputs: &[Option<&[Dimension]>],
+        ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+            self.inner.infer_shapes(inputs)
+        }
+
+        fn broadcast_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+            self.inner.broadcast_shape(inputs)
+        }
+
+        fn supports_transposed_inputs(&self) -> bool {
+            self.inner.supports_transposed_inputs()
+        }
+
+        fn transpose_perm(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+            self.inner.transpose_perm(inputs)
+        }
+
+        fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+            self.inner.reshape_shape(inputs)
+        }
+
         fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
             {
                 let mut m = self.metrics.lock().unwrap();
@@ -997,12 +2860,12 @@ mod tests {
         let conv_out = g.add_value(Some("conv_out"), None);
         g.add_op(
             Some("conv"),
-            Box::new(Conv {
-                dilations: vec![1, 1],
-                groups: 1,
-                padding: [1, 1, 1, 1].into(),
-                strides: vec![1, 1],
-            }),
+            Box::new(Conv::new(
+                1,          /* groups */
+                vec![1, 1], /* dilations */
+                [1, 1, 1, 1].into(),
+                vec![1, 1], /* strides */
+            )),
             &[input_id, weights_id].map(Some),
             &[conv_out].map(Some),
         );
@@ -1077,6 +2940,48 @@ mod tests {
         assert_eq!(g.node_name(anon_op_id), format!("[ID: {}]", anon_op_id));
     }
 
+    #[test]
+    fn test_remove_and_rename_nodes() {
+        let mut g = Graph::new();
+
+        let input_id = g.add_value(Some("input"), None);
+        let relu_out_id = g.add_value(Some("relu_out"), None);
+        let relu_op_id = g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out_id)],
+        );
+        let sigmoid_out_id = g.add_value(Some("sigmoid_out"), None);
+        let sigmoid_op_id = g.add_op(
+            Some("sigmoid"),
+            Box::new(ops::Sigmoid {}),
+            &[Some(relu_out_id)],
+            &[Some(sigmoid_out_id)],
+        );
+
+        // Bypass the `Relu` and remove it.
+        assert_eq!(g.replace_input(relu_out_id, input_id), 1);
+        assert!(g.remove_node(relu_op_id).is_some());
+        assert!(g.remove_node(relu_out_id).is_some());
+        assert!(g.remove_node(relu_out_id).is_none());
+        assert!(g.get_node(relu_op_id).is_none());
+        assert_eq!(g.iter().count(), 3);
+
+        // IDs of the remaining nodes are unchanged.
+        assert_eq!(g.node_name(sigmoid_op_id), "sigmoid");
+        assert!(g.set_node_name(sigmoid_op_id, Some("activation")));
+        assert_eq!(g.node_name(sigmoid_op_id), "activation");
+        assert!(!g.set_node_name(relu_op_id, Some("relu")));
+
+        let input = tensor!([-1., 1.]);
+        let results = g
+            .run(&[(input_id, (&input).into())], &[sigmoid_out_id], None)
+            .unwrap();
+        let output: Tensor<f32> = results[0].clone().into_float().unwrap();
+        expect_equal(&output, &input.map(|x| 1. / (1. + (-x).exp()))).unwrap();
+    }
+
     #[test]
     fn test_graph_node_shapes() {
         let mut g = Graph::new();
@@ -1291,23 +3196,60 @@ mod tests {
     }
 
     #[test]
-    fn test_no_outputs() {
-        let g = Graph::new();
-        let results = g.run(&[], &[], None).unwrap();
-        assert_eq!(results.len(), 0);
-    }
-
-    #[test]
-    fn test_duplicate_inputs() {
+    fn test_prepack_weights() -> Result<(), Box<dyn Error>> {
         let mut g = Graph::new();
+        let weights = Tensor::from_data(&[2, 2], vec![1., 2., 3., 4.]);
         let input_id = g.add_value(Some("input"), None);
-        let input = tensor!([1.]);
-        let result = g.run(
-            &[(input_id, (&input).into()), (input_id, (&input).into())],
-            &[input_id],
-            None,
-        );
-        assert_eq!(
+        let weights_id = g.add_constant(Some("weights"), weights.clone());
+
+        // `MatMul` whose "B" input is a constant, and so can be prepacked.
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(input_id), Some(weights_id)],
+            &[Some(matmul_out)],
+        );
+
+        // `MatMul` whose "B" input is computed, and so can't be prepacked.
+        let matmul_2_out = g.add_value(Some("matmul_2_out"), None);
+        g.add_op(
+            Some("matmul_2"),
+            Box::new(MatMul {}),
+            &[Some(weights_id), Some(matmul_out)],
+            &[Some(matmul_2_out)],
+        );
+
+        assert_eq!(g.prepack_weights(), 1);
+
+        let input = Tensor::from_data(&[2, 2], vec![1., 0., 0., 1.]);
+        let results = g
+            .run(&[(input_id, (&input).into())], &[matmul_2_out], None)
+            .unwrap();
+        let expected = Tensor::from_data(&[2, 2], vec![7., 10., 15., 22.]);
+        expect_equal(results[0].as_float_ref().unwrap(), &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_outputs() {
+        let g = Graph::new();
+        let results = g.run(&[], &[], None).unwrap();
+        assert_eq!(results.len(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_inputs() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let input = tensor!([1.]);
+        let result = g.run(
+            &[(input_id, (&input).into()), (input_id, (&input).into())],
+            &[input_id],
+            None,
+        );
+        assert_eq!(
             result,
             Err(RunError::PlanningError("input IDs are not unique".into()))
         );
@@ -1336,6 +3278,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_operator_error_context() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let weights_id = g.add_constant(Some("weights"), Tensor::<f32>::zeros(&[4, 5]));
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(relu_out), Some(weights_id)],
+            &[Some(matmul_out)],
+        );
+
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+        let err = g
+            .run(&[(input_id, (&input).into())], &[matmul_out], None)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            RunError::OperatorError {
+                name: "matmul".to_string(),
+                op_type: "MatMul".to_string(),
+                input_shapes: vec![Some(vec![2, 3]), Some(vec![4, 5])],
+                error: OpError::IncompatibleInputShapes(
+                    "Columns of first matrix does not match rows of second matrix"
+                ),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator \"matmul\" (MatMul) failed: IncompatibleInputShapes(\"Columns of first \
+             matrix does not match rows of second matrix\"). Input shapes: [2, 3], [4, 5]"
+        );
+
+        // The shape of an input that was passed to the operator for in-place
+        // execution is also reported.
+        let softmax_out = g.add_value(Some("softmax_out"), None);
+        g.add_op(
+            Some("softmax"),
+            Box::new(ops::Softmax { axis: 4 }),
+            &[Some(relu_out)],
+            &[Some(softmax_out)],
+        );
+        let err = g
+            .run(&[(input_id, (&input).into())], &[softmax_out], None)
+            .err()
+            .unwrap();
+        let RunError::OperatorError {
+            op_type,
+            input_shapes,
+            ..
+        } = err
+        else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert_eq!(op_type, "Softmax");
+        assert_eq!(input_shapes, [Some(vec![2, 3])]);
+    }
+
     #[test]
     fn test_call_op_with_missing_input() {
         let mut g = Graph::new();
@@ -1352,6 +3361,8 @@ mod tests {
             results.err(),
             Some(RunError::OperatorError {
                 name: "shape".to_string(),
+                op_type: "Shape".to_string(),
+                input_shapes: vec![None],
                 error: OpError::MissingInputs
             })
         );
@@ -1473,6 +3484,947 @@ mod tests {
         assert_eq!(results[1].as_float_ref().unwrap()[[0, 0]], 2.0);
     }
 
+    #[test]
+    fn test_run_owned_in_place() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let mut prev_id = input_id;
+        for i in 0..3 {
+            let out_id = g.add_value(Some(&format!("op{}_out", i)), None);
+            g.add_op(
+                Some(&format!("op{}", i)),
+                Box::new(AddOneInPlace {}),
+                &[Some(prev_id)],
+                &[Some(out_id)],
+            );
+            prev_id = out_id;
+        }
+
+        let input = Tensor::<f32>::zeros(&[2, 2]);
+        let input_ptr = input.data().unwrap().as_ptr();
+
+        // All operators should run in-place, so the output should re-use
+        // the input's buffer.
+        let results = g
+            .run_owned(&[], vec![(input_id, input.into())], &[prev_id], None)
+            .unwrap();
+        let output = results[0].as_float_ref().unwrap();
+        assert_eq!(output.to_vec(), &[3.0; 4]);
+        assert_eq!(output.data().unwrap().as_ptr(), input_ptr);
+    }
+
+    /// Poll a future to completion on the current thread, returning its
+    /// result and the number of times it returned `Pending`.
+    fn block_on<F: Future>(fut: F) -> (F::Output, usize) {
+        let mut fut = std::pin::pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut pending_count = 0;
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => return (result, pending_count),
+                Poll::Pending => pending_count += 1,
+            }
+        }
+    }
+
+    /// Create a graph with a chain of `n` operators which each add one to
+    /// their input. Returns the graph and the IDs of the input and each
+    /// operator's output.
+    fn add_one_chain(n: usize) -> (Graph, NodeId, Vec<NodeId>) {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let mut output_ids = Vec::new();
+        let mut prev_id = input_id;
+        for i in 0..n {
+            let out_id = g.add_value(Some(&format!("op{}_out", i)), None);
+            g.add_op(
+                Some(&format!("op{}", i)),
+                Box::new(AddOneInPlace {}),
+                &[Some(prev_id)],
+                &[Some(out_id)],
+            );
+            output_ids.push(out_id);
+            prev_id = out_id;
+        }
+        (g, input_id, output_ids)
+    }
+
+    #[test]
+    fn test_run_async() {
+        let (g, input_id, output_ids) = add_one_chain(3);
+        let input = Tensor::<f32>::zeros(&[2, 2]);
+
+        // The run should yield once after each operator. `AddOneInPlace` only
+        // adds one when run in-place, so this also checks that values are
+        // updated in-place under the same conditions as in a non-async run.
+        // Here only the last operator can run in-place, as the output of the
+        // first is requested.
+        let (results, yield_count) = block_on(g.run_async(
+            &[(input_id, input.view().into())],
+            &[output_ids[0], output_ids[2]],
+            None,
+        ));
+        let results = results.unwrap();
+        assert_eq!(yield_count, 3);
+        assert_eq!(results[0].as_float_ref().unwrap().to_vec(), &[0.0; 4]);
+        assert_eq!(results[1].as_float_ref().unwrap().to_vec(), &[1.0; 4]);
+
+        // Inputs can also be outputs.
+        let (results, yield_count) =
+            block_on(g.run_async(&[(input_id, input.view().into())], &[input_id], None));
+        assert_eq!(yield_count, 0);
+        assert_eq!(results.unwrap()[0].as_float_ref().unwrap(), &input);
+
+        // Planning errors are reported.
+        let (results, _) = block_on(g.run_async(&[], &[output_ids[0]], None));
+        assert!(matches!(results, Err(RunError::PlanningError(_))));
+    }
+
+    #[test]
+    fn test_cancel_run() {
+        let (g, input_id, output_ids) = add_one_chain(3);
+        let input = Tensor::<f32>::zeros(&[2, 2]);
+        let inputs = [(input_id, input.view().into())];
+
+        // Run which is cancelled before it starts.
+        let token = CancelToken::new();
+        token.cancel();
+        let result = g.run(
+            &inputs,
+            &output_ids,
+            Some(RunOptions {
+                cancel: Some(token.clone()),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result.err(), Some(RunError::Cancelled));
+
+        // Async run which is cancelled after the first operator.
+        let token = CancelToken::new();
+        let opts = RunOptions {
+            cancel: Some(token.clone()),
+            ..Default::default()
+        };
+        let mut fut = std::pin::pin!(g.run_async(&inputs, &output_ids, Some(opts)));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        token.cancel();
+        assert_eq!(
+            fut.as_mut().poll(&mut cx),
+            Poll::Ready(Err(RunError::Cancelled))
+        );
+    }
+
+    #[test]
+    fn test_run_hooks() {
+        let (g, input_id, output_ids) = add_one_chain(2);
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let before_log = log.clone();
+        let after_log = log.clone();
+        let hooks = RunHooks::new()
+            .before_node(move |event| {
+                before_log.lock().unwrap().push(format!(
+                    "before {} ({}) {:?}",
+                    event.name, event.op_type, event.input_shapes
+                ));
+            })
+            .after_node(move |event, outputs| {
+                let output = outputs[0].as_float_ref().unwrap();
+                after_log.lock().unwrap().push(format!(
+                    "after {} {:?} {:?}",
+                    event.name,
+                    output.shape(),
+                    output.iter().next()
+                ));
+            });
+
+        g.run(
+            &[(input_id, input.view().into())],
+            &[output_ids[1]],
+            Some(RunOptions {
+                hooks: Some(hooks),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+        let log = log.lock().unwrap();
+        assert_eq!(
+            *log,
+            [
+                "before op0 (AddOneInPlace) [Some([2, 3])]",
+                "after op0 [2, 3] Some(0.0)",
+                "before op1 (AddOneInPlace) [Some([2, 3])]",
+                "after op1 [2, 3] Some(1.0)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_finite() {
+        let mut g = Graph::new();
+        let x_id = g.add_value(Some("x"), None);
+        let y_id = g.add_value(Some("y"), None);
+        let div_out = g.add_value(Some("div_out"), None);
+        g.add_op(
+            Some("div"),
+            Box::new(ops::Div {}),
+            &[Some(x_id), Some(y_id)],
+            &[Some(div_out)],
+        );
+
+        let x = tensor!([1., 2., 3.]);
+        let y = tensor!([1., 0., 2.]);
+        let inputs = [(x_id, x.view().into()), (y_id, y.view().into())];
+
+        // Non-finite values are allowed by default.
+        let result = g.run(&inputs, &[div_out], None).unwrap();
+        assert_eq!(
+            result[0].as_float_ref().unwrap().to_vec(),
+            &[1., f32::INFINITY, 1.5]
+        );
+
+        let result = g.run(
+            &inputs,
+            &[div_out],
+            Some(RunOptions {
+                check_finite: true,
+                ..Default::default()
+            }),
+        );
+        let expected = RunError::NonFiniteOutput {
+            name: "div".into(),
+            op_type: "Div".into(),
+            output: 0,
+            input_stats: vec![
+                Some(ValueStats {
+                    min: 1.,
+                    max: 3.,
+                    mean: 2.,
+                }),
+                Some(ValueStats {
+                    min: 0.,
+                    max: 2.,
+                    mean: 1.,
+                }),
+            ],
+        };
+        let err = result.err().unwrap();
+        assert_eq!(err, expected);
+        assert_eq!(
+            err.to_string(),
+            "operator \"div\" (Div) produced non-finite values in output 0. \
+             Input 0: min 1 max 3 mean 2. Input 1: min 0 max 2 mean 1"
+        );
+    }
+
+    #[test]
+    fn test_profiler() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let weights_id = g.add_constant(Some("weights"), Tensor::<f32>::zeros(&[3, 4]));
+        let relu_out = g.add_value(Some("relu_out"), None);
+        let relu_id = g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        let matmul_id = g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(relu_out), Some(weights_id)],
+            &[Some(matmul_out)],
+        );
+        let relu_2_out = g.add_value(Some("relu_2_out"), None);
+        g.add_op(
+            Some("relu_2"),
+            Box::new(Relu {}),
+            &[Some(matmul_out)],
+            &[Some(relu_2_out)],
+        );
+
+        let profiler = Profiler::new();
+        assert!(profiler.take_profile().is_none());
+
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+        let opts = RunOptions {
+            profiler: Some(profiler.clone()),
+            ..Default::default()
+        };
+        g.run(&[(input_id, (&input).into())], &[relu_2_out], Some(opts))
+            .unwrap();
+
+        let profile = profiler.take_profile().unwrap();
+        assert!(profiler.take_profile().is_none());
+
+        let records = profile.records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].node_id, relu_id);
+        assert_eq!(records[0].node_name, "relu");
+        assert_eq!(records[0].op_type, "Relu");
+        assert_eq!(records[1].node_id, matmul_id);
+        assert_eq!(records[1].op_type, "MatMul");
+        assert_eq!(
+            records[1].input_shapes,
+            [Some(vec![2, 3]), Some(vec![3, 4])]
+        );
+        assert_eq!(records[1].output_shapes, [vec![2, 4]]);
+
+        let mut summary = profile.summary_by_op_type(TimingSort::ByName);
+        for op_type in summary.iter_mut() {
+            op_type.total_ms = 0.;
+        }
+        assert_eq!(
+            summary,
+            [
+                OpTypeSummary {
+                    op_type: "MatMul".to_string(),
+                    count: 1,
+                    total_ms: 0.,
+                },
+                OpTypeSummary {
+                    op_type: "Relu".to_string(),
+                    count: 2,
+                    total_ms: 0.,
+                },
+            ]
+        );
+
+        let text = profile.to_text(TimingSort::ByTime);
+        assert!(text.contains("Op type"));
+        assert!(text.contains("relu_2"));
+
+        let json = profile.to_json();
+        assert!(json.starts_with("{\"total_ms\":"));
+        assert!(json.contains("\"name\":\"matmul\",\"op_type\":\"MatMul\""));
+        assert!(json.contains("\"input_shapes\":[[2,3],[3,4]],\"output_shapes\":[[2,4]]"));
+    }
+
+    #[test]
+    fn test_expand_broadcast_view() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let shape_id = g.add_constant(Some("shape"), tensor!([2, 3]));
+        let other_id = g.add_value(Some("other"), None);
+
+        let expand_op = TrackUsage::new(Expand {});
+        let expand_metrics = expand_op.metrics();
+        let expand_out = g.add_value(Some("expand_out"), None);
+        g.add_op(
+            Some("expand"),
+            Box::new(expand_op),
+            &[Some(input_id), Some(shape_id)],
+            &[Some(expand_out)],
+        );
+        let add_out = g.add_value(Some("add_out"), None);
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[Some(expand_out), Some(other_id)],
+            &[Some(add_out)],
+        );
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(add_out)],
+            &[Some(relu_out)],
+        );
+
+        let input = tensor!((1, 3); [1., 2., 3.]);
+        let other = tensor!((2, 1); [10., 20.]);
+        let inputs = [(input_id, (&input).into()), (other_id, (&other).into())];
+        let expected = tensor!((2, 3); [11., 12., 13., 21., 22., 23.]);
+
+        // `Add` supports broadcast inputs, so `Expand` should not be run.
+        let result = g.run(&inputs, &[relu_out], None)?;
+        expect_equal(result[0].as_float_ref().unwrap(), &expected)?;
+        assert_eq!(expand_metrics.lock().unwrap().run_count, 0);
+
+        // If the output of `Expand` is requested, it must be materialized.
+        let result = g.run(&inputs, &[expand_out, relu_out], None)?;
+        expect_equal(
+            result[0].as_float_ref().unwrap(),
+            &tensor!((2, 3); [1., 2., 3., 1., 2., 3.]),
+        )?;
+        expect_equal(result[1].as_float_ref().unwrap(), &expected)?;
+        assert_eq!(expand_metrics.lock().unwrap().run_count, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transpose_view() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let a_id = g.add_value(Some("a"), None);
+        let b_id = g.add_value(Some("b"), None);
+
+        let transpose_op = TrackUsage::new(Transpose {
+            perm: Some(vec![0, 2, 1]),
+        });
+        let transpose_metrics = transpose_op.metrics();
+        let transpose_out = g.add_value(Some("transpose_out"), None);
+        g.add_op(
+            Some("transpose"),
+            Box::new(transpose_op),
+            &[Some(b_id)],
+            &[Some(transpose_out)],
+        );
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(a_id), Some(transpose_out)],
+            &[Some(matmul_out)],
+        );
+
+        let a = Tensor::arange(0., 12., None).into_shape([2, 2, 3].as_slice());
+        let b = Tensor::arange(0., 24., None).into_shape([2, 4, 3].as_slice());
+        let inputs = [(a_id, (&a).into()), (b_id, (&b).into())];
+        let expected = ops::matmul(
+            &TensorPool::new(),
+            a.view(),
+            b.permuted([0, 2, 1].as_slice()),
+        )?;
+
+        // `MatMul` supports transposed inputs, so `Transpose` should not be
+        // run.
+        let result = g.run(&inputs, &[matmul_out], None)?;
+        expect_equal(result[0].as_float_ref().unwrap(), &expected)?;
+        assert_eq!(transpose_metrics.lock().unwrap().run_count, 0);
+
+        // If the output of `Transpose` is requested, it must be materialized.
+        let result = g.run(&inputs, &[transpose_out, matmul_out], None)?;
+        assert_eq!(result[0].as_float_ref().unwrap().shape(), &[2, 3, 4]);
+        expect_equal(result[1].as_float_ref().unwrap(), &expected)?;
+        assert_eq!(transpose_metrics.lock().unwrap().run_count, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshape_view() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let shape_id = g.add_constant(Some("shape"), tensor!([4]));
+
+        let reshape_op = TrackUsage::new(Reshape { allow_zero: false });
+        let reshape_metrics = reshape_op.metrics();
+        let reshape_out = g.add_value(Some("reshape_out"), None);
+        g.add_op(
+            Some("reshape"),
+            Box::new(reshape_op),
+            &[Some(input_id), Some(shape_id)],
+            &[Some(reshape_out)],
+        );
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(reshape_out)],
+            &[Some(relu_out)],
+        );
+
+        let input = tensor!((2, 2); [1., -2., 3., -4.]);
+        let expected = tensor!([1., 0., 3., 0.]);
+
+        // The input is borrowed, so `Reshape` cannot run in place. Instead
+        // `Relu` receives a reshaped view of the input.
+        let result = g.run(&[(input_id, (&input).into())], &[relu_out], None)?;
+        expect_equal(result[0].as_float_ref().unwrap(), &expected)?;
+        let metrics = reshape_metrics.lock().unwrap().clone();
+        assert_eq!(metrics.run_count, 0);
+        assert_eq!(metrics.run_in_place_count, 0);
+
+        // If the input is owned, `Reshape` runs in place.
+        let result = g.run_owned(
+            &[],
+            vec![(input_id, input.clone().into())],
+            &[relu_out],
+            None,
+        )?;
+        expect_equal(result[0].as_float_ref().unwrap(), &expected)?;
+        assert_eq!(reshape_metrics.lock().unwrap().run_in_place_count, 1);
+
+        // If the output of `Reshape` is requested, it must be materialized.
+        let result = g.run(&[(input_id, (&input).into())], &[reshape_out], None)?;
+        expect_equal(
+            result[0].as_float_ref().unwrap(),
+            &tensor!([1., -2., 3., -4.]),
+        )?;
+        assert_eq!(reshape_metrics.lock().unwrap().run_count, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_broadcast_error() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let a_id = g.add_value(Some("a"), None);
+        let b_id = g.add_value(Some("b"), None);
+        let add_out = g.add_value(Some("add_out"), None);
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[Some(a_id), Some(b_id)],
+            &[Some(add_out)],
+        );
+
+        let a = Tensor::<f32>::arange(0., 24., None).into_shape([2, 3, 4].as_slice());
+        let b = Tensor::<f32>::arange(0., 8., None).into_shape([2, 4].as_slice());
+        let inputs = [(a_id, (&a).into()), (b_id, (&b).into())];
+
+        // Shapes which can be fixed by inserting an axis into one input.
+        let err = g.run(&inputs, &[add_out], None).err().unwrap();
+        assert_eq!(
+            err,
+            RunError::BroadcastError {
+                name: "add".to_string(),
+                shapes: vec![vec![2, 3, 4], vec![2, 4]],
+                unsqueeze: Some((1, 1)),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator \"add\" failed: cannot broadcast input shapes [[2, 3, 4], [2, 4]]. \
+             Inserting an axis at index 1 of input 1 would fix this \
+             (see `RunOptions::lenient_broadcast`)"
+        );
+
+        // With lenient broadcasting, the axis is inserted automatically.
+        let opts = RunOptions {
+            lenient_broadcast: true,
+            ..Default::default()
+        };
+        let result = g.run(&inputs, &[add_out], Some(opts))?;
+        let mut b_unsqueezed = b.view();
+        b_unsqueezed.insert_axis(1);
+        let expected = add(&TensorPool::new(), a.view(), b_unsqueezed)?;
+        expect_equal(result[0].as_float_ref().unwrap(), &expected)?;
+
+        // Shapes which cannot be fixed by inserting a single axis.
+        let c = Tensor::<f32>::zeros(&[5]);
+        let opts = RunOptions {
+            lenient_broadcast: true,
+            ..Default::default()
+        };
+        let err = g
+            .run(
+                &[(a_id, (&a).into()), (b_id, (&c).into())],
+                &[add_out],
+                Some(opts),
+            )
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            RunError::BroadcastError {
+                name: "add".to_string(),
+                shapes: vec![vec![2, 3, 4], vec![5]],
+                unsqueeze: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_variable_output_count() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let first_id = g.add_value(Some("first"), None);
+        let extra_id = g.add_value(Some("extra"), None);
+
+        // Operator which produces two outputs, where the graph only uses the
+        // first and omits the second.
+        g.add_op(
+            Some("split"),
+            Box::new(ops::Split {
+                axis: 0,
+                num_outputs: Some(2),
+            }),
+            &[Some(input_id)],
+            &[Some(first_id)],
+        );
+
+        // Operator which produces two outputs, where the graph expects three.
+        g.add_op(
+            Some("split_extra"),
+            Box::new(ops::Split {
+                axis: 0,
+                num_outputs: Some(2),
+            }),
+            &[Some(input_id)],
+            &[None, None, Some(extra_id)],
+        );
+
+        let input = tensor!([1., 2., 3., 4.]);
+        let inputs = [(input_id, (&input).into())];
+        let result = g.run(&inputs, &[first_id], None)?;
+        expect_equal(result[0].as_float_ref().unwrap(), &tensor!([1., 2.]))?;
+
+        let result = g.run(&inputs, &[extra_id], None);
+        assert_eq!(
+            result.err(),
+            Some(RunError::OutputMismatch(
+                "operator produced fewer outputs than expected"
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unused_outputs() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let output_id = g.add_value(Some("output"), None);
+        let mask_id = g.add_value(Some("mask"), None);
+        g.add_op(
+            Some("dropout"),
+            Box::new(ops::Dropout { seed: None }),
+            &[Some(input_id)],
+            &[Some(output_id), Some(mask_id)],
+        );
+
+        let input = tensor!([1., 2., 3.]);
+        let input_ptr = input.data().unwrap().as_ptr();
+
+        // If only the first output is used, the operator can run in-place.
+        let results = g.run_owned(&[], vec![(input_id, input.into())], &[output_id], None)?;
+        assert_eq!(results.len(), 1);
+        let output = results[0].as_float_ref().unwrap();
+        assert_eq!(output.to_vec(), &[1., 2., 3.]);
+        assert_eq!(output.data().unwrap().as_ptr(), input_ptr);
+
+        // If other outputs are used, the operator must not run in-place.
+        let input = tensor!([1., 2., 3.]);
+        let results = g.run_owned(
+            &[],
+            vec![(input_id, input.into())],
+            &[output_id, mask_id],
+            None,
+        )?;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_float_ref().unwrap().to_vec(), &[1., 2., 3.]);
+        assert_eq!(results[1].as_int_ref().unwrap().to_vec(), &[1, 1, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_shapes() {
+        let mut g = Graph::new();
+        let batch = Dimension::Symbolic("batch".to_string());
+        let a_id = g.add_value(Some("a"), Some(vec![batch.clone(), Dimension::Fixed(3)]));
+        let b_id = g.add_constant(Some("b"), Tensor::<f32>::zeros(&[3, 4]));
+        let c_id = g.add_value(Some("c"), None);
+
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(a_id), Some(b_id)],
+            &[Some(matmul_out)],
+        );
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(matmul_out)],
+            &[Some(relu_out)],
+        );
+        let add_out = g.add_value(Some("add_out"), None);
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[Some(relu_out), Some(c_id)],
+            &[Some(add_out)],
+        );
+
+        // Shapes are propagated through operators. The shape of `add_out` is
+        // unknown because the shape of `c` is unknown.
+        let shapes = g.infer_shapes(&[]).unwrap();
+        let expected = vec![batch.clone(), Dimension::Fixed(4)];
+        assert_eq!(shapes.get(&matmul_out), Some(&expected));
+        assert_eq!(shapes.get(&relu_out), Some(&expected));
+        assert_eq!(shapes.get(&add_out), None);
+
+        // Specifying input shapes.
+        let shapes = g
+            .infer_shapes(&[(c_id, vec![Dimension::Fixed(1)])])
+            .unwrap();
+        assert_eq!(shapes.get(&add_out), Some(&expected));
+
+        // Incompatible input shapes are reported with the operator name.
+        let result = g.infer_shapes(&[(c_id, vec![Dimension::Fixed(5)])]);
+        assert_eq!(
+            result.err(),
+            Some(RunError::OperatorError {
+                name: "add".to_string(),
+                op_type: "Add".to_string(),
+                input_shapes: Vec::new(),
+                error: OpError::IncompatibleInputShapes("Cannot broadcast inputs"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_memory_plan() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let weights_id = g.add_constant(Some("weights"), Tensor::<f32>::zeros(&[3, 4]));
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        let relu_2_out = g.add_value(Some("relu_2_out"), None);
+        g.add_op(
+            Some("relu_2"),
+            Box::new(Relu {}),
+            &[Some(relu_out)],
+            &[Some(relu_2_out)],
+        );
+        let matmul_out = g.add_value(Some("matmul_out"), None);
+        let matmul_id = g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[Some(relu_2_out), Some(weights_id)],
+            &[Some(matmul_out)],
+        );
+
+        let plan = g
+            .memory_plan(&[(input_id, vec![2, 3])], &[matmul_out])
+            .unwrap();
+        assert_eq!(plan.input_bytes(), 24);
+        assert!(plan.is_complete());
+
+        let steps: Vec<_> = plan
+            .steps()
+            .iter()
+            .map(|step| {
+                (
+                    step.node_name.as_str(),
+                    step.output_bytes,
+                    step.in_place,
+                    step.live_bytes,
+                )
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                // The graph input is not owned, so the output is a new buffer.
+                ("relu", Some(24), false, 24),
+                // The input is a temporary value, so it is updated in-place.
+                ("relu_2", Some(0), true, 24),
+                // Both the input and output are alive while MatMul runs.
+                ("matmul", Some(32), false, 56),
+            ]
+        );
+
+        assert_eq!(plan.peak_bytes(), 56);
+        assert_eq!(plan.peak_step().map(|step| step.node_id), Some(matmul_id));
+        let top: Vec<_> = plan
+            .top_allocations(2)
+            .iter()
+            .map(|step| step.node_name.as_str())
+            .collect();
+        assert_eq!(top, ["matmul", "relu"]);
+
+        let report = plan.to_string();
+        assert!(report.contains("Peak intermediate values: 56 B at \"matmul\" (MatMul)"));
+        assert!(report.contains("Static arena: 56 B in 2 buffers"));
+
+        // The output of `relu` is updated in-place by `relu_2`, so the same
+        // buffer is alive until `matmul` runs, and cannot be shared with the
+        // output of `matmul`.
+        let allocs: Vec<_> = plan
+            .allocations()
+            .iter()
+            .map(|alloc| {
+                (
+                    alloc.value_id,
+                    alloc.slot,
+                    alloc.size,
+                    alloc.first_step,
+                    alloc.last_step,
+                )
+            })
+            .collect();
+        assert_eq!(allocs, [(relu_out, 0, 24, 0, 2), (matmul_out, 1, 32, 2, 3)]);
+        assert_eq!(plan.slot_sizes(), [24, 32]);
+
+        // Missing input shapes are reported as an error.
+        let result = g.memory_plan(&[], &[matmul_out]);
+        assert!(matches!(result, Err(RunError::PlanningError(_))));
+
+        // Incompatible input shapes are reported as an error.
+        let result = g.memory_plan(&[(input_id, vec![2, 5])], &[matmul_out]);
+        assert!(matches!(result, Err(RunError::OperatorError { .. })));
+    }
+
+    #[test]
+    fn test_run_with_arena() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+        let relu_out = g.add_value(Some("relu_out"), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        let add_out = g.add_value(Some("add_out"), None);
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[Some(relu_out), Some(input_id)],
+            &[Some(add_out)],
+        );
+        let input = Tensor::from_data(&[2, 2], vec![1., -2., 3., -4.]);
+
+        let plan = g
+            .memory_plan(&[(input_id, vec![2, 2])], &[add_out])
+            .unwrap();
+        let arena = BufferArena::from_plan(&plan);
+        assert_eq!(arena.len(), plan.slot_sizes().len());
+
+        let run = || {
+            g.run(
+                &[(input_id, input.view().into())],
+                &[add_out],
+                Some(RunOptions {
+                    arena: Some(arena.clone()),
+                    ..Default::default()
+                }),
+            )
+            .unwrap()
+        };
+
+        // Allocations for intermediate values are satisfied by buffers
+        // preallocated from the plan.
+        let mut output = run().remove(0);
+        assert_eq!(arena.alloc_count(), arena.hit_count());
+        assert_eq!(
+            output.as_float_ref().unwrap().to_vec(),
+            vec![2., -2., 6., -4.]
+        );
+
+        // After returning the output's buffer, later runs don't allocate.
+        for _ in 0..3 {
+            arena.reclaim(output);
+            let allocs_before = arena.alloc_count();
+            let hits_before = arena.hit_count();
+            output = run().remove(0);
+            assert!(arena.alloc_count() > allocs_before);
+            assert_eq!(
+                arena.alloc_count() - allocs_before,
+                arena.hit_count() - hits_before
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_with_seed() {
+        let mut g = Graph::new();
+        let mut add_random_op = |name: &str| {
+            let output = g.add_value(Some(name), None);
+            g.add_op(
+                Some(name),
+                Box::new(ops::RandomUniform {
+                    low: 0.,
+                    high: 1.,
+                    shape: vec![4, 4],
+                    seed: None,
+                }),
+                &[],
+                &[Some(output)],
+            );
+            output
+        };
+        let out_a = add_random_op("a");
+        let out_b = add_random_op("b");
+
+        let run = |seed: Option<u64>| -> Vec<Tensor> {
+            g.run(
+                &[],
+                &[out_a, out_b],
+                Some(RunOptions {
+                    seed,
+                    ..Default::default()
+                }),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|output| output.into_float().unwrap())
+            .collect()
+        };
+
+        // Runs with the same seed produce the same values, but different
+        // operators in the graph produce different values.
+        let outputs = run(Some(1234));
+        assert_eq!(outputs, run(Some(1234)));
+        assert_ne!(outputs[0], outputs[1]);
+
+        // Runs with different seeds, or no seed, produce different values.
+        assert_ne!(outputs, run(Some(5678)));
+        assert_ne!(run(None), run(None));
+    }
+
+    #[test]
+    fn test_plan_summary() {
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None);
+
+        let op1_out = g.add_value(Some("op1_out"), None);
+        g.add_op(
+            Some("op1"),
+            Box::new(AddOneInPlace {}),
+            &[Some(input_id)],
+            &[Some(op1_out)],
+        );
+        let op2_out = g.add_value(Some("op2_out"), None);
+        g.add_op(
+            Some("op2"),
+            Box::new(AddOneInPlace {}),
+            &[Some(op1_out)],
+            &[Some(op2_out)],
+        );
+
+        let summary = g.plan_summary(&[input_id], &[op2_out]).unwrap();
+        let lines: Vec<_> = summary.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "Plan of 2 steps",
+                "  inputs: input",
+                "  outputs: op2_out",
+                "#0 AddOneInPlace (op1)",
+                "  input 0: input",
+                "  output 0: op1_out",
+                "#1 AddOneInPlace (op2) [in-place: op1_out]",
+                "  input 0: op1_out",
+                "  output 0: op2_out",
+            ]
+        );
+
+        // If an intermediate value is also a requested output, the operator
+        // that consumes it cannot run in place.
+        let summary = g.plan_summary(&[input_id], &[op1_out, op2_out]).unwrap();
+        assert!(!summary.contains("in-place"));
+
+        let err = g.plan_summary(&[], &[op2_out]).err().unwrap();
+        assert!(matches!(err, RunError::PlanningError(_)));
+    }
+
     // Test that the graph executor will swap inputs to commutative ops if
     // necessary to enable running in-place.
     #[test]
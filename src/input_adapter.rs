@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use crate::graph::{Dimension, Graph, Node, NodeId};
+use crate::op_registry::{OpAttrs, OpRegistry};
+use crate::ops::DataType;
+
+/// Memory layout of an image tensor.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ImageLayout {
+    /// Channels-first layout (batch, channels, height, width). This is the
+    /// layout used by most vision models.
+    #[default]
+    NCHW,
+
+    /// Channels-last layout (batch, height, width, channels). This is the
+    /// layout typically used by decoded images and camera frames.
+    NHWC,
+}
+
+/// Describes the format in which an input will be supplied when a model is
+/// run, if this differs from the format the model expects.
+///
+/// The model input is assumed to be a float image tensor in NCHW layout.
+/// When a model is loaded with an adapter for an input, operators are inserted
+/// into the graph which convert from the format described by the adapter to
+/// the format expected by the model. This is done in the following order:
+///
+///  1. Cast to float, if [`dtype`](InputAdapter::dtype) is `Int32`
+///  2. Transpose to NCHW, if [`layout`](InputAdapter::layout) is NHWC
+///  3. Multiply by [`scale`](InputAdapter::scale)
+///  4. Subtract [`mean`](InputAdapter::mean) and divide by
+///     [`std`](InputAdapter::std) for each channel
+///
+/// For NHWC inputs, these steps are performed by a single `ImageToTensor`
+/// operator, which makes one pass over the pixels.
+///
+/// For example, an application which captures camera frames as HWC `u8` pixel
+/// values can supply them as an `Int32` NHWC tensor, set `scale` to `1. /
+/// 255.` and set `mean` and `std` to the model's normalization parameters.
+///
+/// See [`ModelOptions::with_input_adapter`](crate::ModelOptions::with_input_adapter).
+#[derive(Clone, Debug, Default)]
+pub struct InputAdapter {
+    /// Layout of the tensor supplied for this input.
+    pub layout: ImageLayout,
+
+    /// Data type of the tensor supplied for this input. If `None`, this is
+    /// the same as the model input.
+    pub dtype: Option<DataType>,
+
+    /// Value to multiply the input by, before the mean is subtracted. If
+    /// `None`, this defaults to one.
+    pub scale: Option<f32>,
+
+    /// Per-channel value to subtract from the input. If `None`, this defaults
+    /// to zero.
+    pub mean: Option<Vec<f32>>,
+
+    /// Per-channel value to divide the input by, after subtracting the mean.
+    /// If `None`, this defaults to one.
+    pub std: Option<Vec<f32>>,
+}
+
+impl InputAdapter {
+    /// Return the types and attributes of the sequence of operators needed
+    /// to convert from the external format to the format expected by the
+    /// model.
+    fn operators(&self) -> Result<Vec<(&'static str, OpAttrs)>, String> {
+        let mut ops = Vec::new();
+
+        if self.layout == ImageLayout::NHWC {
+            let mean = self.mean.clone().unwrap_or_default();
+            let std = self.std.clone().unwrap_or_default();
+            if !mean.is_empty() && !std.is_empty() && mean.len() != std.len() {
+                return Err("mean and std lengths differ".to_string());
+            }
+            ops.push((
+                "ImageToTensor",
+                OpAttrs::new()
+                    .with("scale", self.scale.unwrap_or(1.))
+                    .with("mean", mean)
+                    .with("std", std),
+            ));
+            return Ok(ops);
+        }
+
+        if let Some(DataType::Int32) = self.dtype {
+            ops.push(("Cast", OpAttrs::new().with("to", "float")));
+        }
+
+        let (mean, std) = match (&self.mean, &self.std) {
+            (None, None) => (None, None),
+            (Some(mean), None) => (Some(mean.clone()), Some(vec![1.; mean.len()])),
+            (None, Some(std)) => (Some(vec![0.; std.len()]), Some(std.clone())),
+            (Some(mean), Some(std)) => (Some(mean.clone()), Some(std.clone())),
+        };
+        if self.scale.is_some() && mean.is_none() {
+            return Err("scale requires mean or std for NCHW inputs".to_string());
+        }
+        if let (Some(mut mean), Some(mut std)) = (mean, std) {
+            if mean.len() != std.len() {
+                return Err("mean and std lengths differ".to_string());
+            }
+
+            // `(x * scale - mean) / std` is equivalent to
+            // `(x - mean / scale) / (std / scale)`.
+            if let Some(scale) = self.scale {
+                mean.iter_mut().for_each(|m| *m /= scale);
+                std.iter_mut().for_each(|s| *s /= scale);
+            }
+            ops.push((
+                "Normalize",
+                OpAttrs::new().with("mean", mean).with("std", std),
+            ));
+        }
+
+        Ok(ops)
+    }
+
+    /// Return the shape of the external input given the shape of the model
+    /// input.
+    fn input_shape(&self, model_shape: &[Dimension]) -> Result<Vec<Dimension>, String> {
+        if self.layout == ImageLayout::NCHW {
+            return Ok(model_shape.to_vec());
+        }
+        match model_shape {
+            [n, c, h, w] => Ok([n, h, w, c].map(|dim| dim.clone()).to_vec()),
+            _ => Err("NHWC adapter requires a 4D input".to_string()),
+        }
+    }
+}
+
+/// Insert operators into `graph` which convert from the format described by
+/// `adapter` to the format expected by the value node `input_id`.
+///
+/// Operators are created using `registry` and their attributes are recorded
+/// in `op_attrs`.
+///
+/// Returns the ID of a new value node which replaces `input_id` as the input
+/// to supply when running the graph.
+pub(crate) fn insert_input_adapter(
+    graph: &mut Graph,
+    registry: &OpRegistry,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    input_id: NodeId,
+    adapter: &InputAdapter,
+) -> Result<NodeId, String> {
+    let Some(Node::Value(_)) = graph.get_node(input_id) else {
+        return Err("input is not a value node".to_string());
+    };
+    let name = graph.node_name(input_id);
+    let shape = graph
+        .get_node(input_id)
+        .and_then(|node| node.shape())
+        .map(|shape| adapter.input_shape(&shape))
+        .transpose()?;
+
+    let ops = adapter.operators()?;
+    if ops.is_empty() {
+        return Ok(input_id);
+    }
+
+    let new_input_id = graph.add_value(Some(&name), shape);
+    let n_ops = ops.len();
+    let mut prev_id = new_input_id;
+    for (i, (op_type, attrs)) in ops.into_iter().enumerate() {
+        let op = registry
+            .read_op(op_type, &attrs)
+            .map_err(|err| err.to_string())?;
+        let op_name = format!("{}.{}", name, op_type);
+        let output_id = if i == n_ops - 1 {
+            input_id
+        } else {
+            graph.add_value(Some(&format!("{}_out", op_name)), None)
+        };
+        let op_id = graph.add_op(Some(&op_name), op, &[Some(prev_id)], &[Some(output_id)]);
+        op_attrs.insert(op_id, attrs);
+        prev_id = output_id;
+    }
+
+    Ok(new_input_id)
+}
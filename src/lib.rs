@@ -91,14 +91,19 @@
 #[allow(unused)] // Docs only
 use rten_tensor::{NdTensor, Tensor};
 
+mod compression;
 mod constant_storage;
 mod env;
-mod gemm;
 mod graph;
+mod input_adapter;
 mod iter_util;
+mod memory_plan;
 mod model;
 mod model_metadata;
 mod number;
+mod onnx_export;
+mod op_registry;
+mod optimize;
 mod slice_reductions;
 mod tensor_pool;
 mod threading;
@@ -112,21 +117,38 @@ mod wasm_api;
 // a separate crate in future.
 pub mod ctc;
 
+pub mod gemm;
 pub mod ops;
+pub mod quantize;
+pub mod sparse;
 
-pub use graph::{Dimension, NodeId, RunOptions};
-pub use model::{Model, ModelLoadError, ModelOptions, NodeInfo, OpRegistry, ReadOp, ReadOpError};
-pub use model_metadata::ModelMetadata;
-pub use ops::{FloatOperators, Input, Operators, Output};
-pub use tensor_pool::{ExtractBuffer, PoolRef, TensorPool};
+pub use compression::Compression;
+pub use graph::{
+    CancelToken, Dimension, NodeEvent, NodeId, RunHooks, RunOptions, Subgraph, ValueStats,
+};
+pub use input_adapter::{ImageLayout, InputAdapter};
+pub use memory_plan::{ArenaAllocation, MemoryPlan, StepMemory};
+pub use model::{
+    Model, ModelEditError, ModelLoadError, ModelOptions, NamedOutputs, NodeInfo, PendingModel,
+    Session, ValueInfo,
+};
+pub use model_builder::{MetadataArgs, ModelBuildError, ModelBuilder};
+pub use model_metadata::{ModelMetadata, OpsetImport};
+pub use number::BF16;
+pub use op_registry::{
+    AttrValue, OpAttrs, OpRegistry, OperatorFactory, OperatorPack, ReadOp, ReadOpError,
+    ReadOpResult,
+};
+pub use ops::{FloatOperators, Input, Operators, Output, OutputElement, OutputTypeError, Sequence};
+pub use tensor_pool::{BufferArena, ExtractBuffer, PoolRef, TensorPool};
 pub use threading::{thread_pool, ThreadPool};
 pub use timer::Timer;
-pub use timing::TimingSort;
+pub use timing::{OpTypeSummary, Profile, ProfileRecord, Profiler, TimingSort};
 
 #[allow(dead_code, unused_imports)]
 mod schema_generated;
 
-// This is currently exposed for use in ocrs tests. That crate should probably
-// create an abstraction around model execution instead.
+// `ModelBuilder` is exported from the crate root. The module path is kept for
+// compatibility with existing users, such as the ocrs tests.
 #[doc(hidden)]
 pub mod model_builder;
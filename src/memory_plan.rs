@@ -0,0 +1,294 @@
+//! Static analysis of memory usage during graph execution.
+
+use std::fmt;
+
+use crate::graph::NodeId;
+use crate::timing::Table;
+
+/// Memory usage for a single step of an execution plan.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepMemory {
+    /// ID of the operator node in the graph
+    pub node_id: NodeId,
+
+    /// Name of the operator node in the graph
+    pub node_name: String,
+
+    /// Operator type (eg. `MatMul`)
+    pub op_type: String,
+
+    /// Size in bytes of the buffers allocated for the operator's outputs.
+    ///
+    /// This is `None` if the shape of any output could not be determined.
+    /// Outputs which re-use an input buffer (see `in_place`) are not counted.
+    pub output_bytes: Option<usize>,
+
+    /// Whether the operator is expected to run in-place, re-using the buffer
+    /// of one of its inputs for the output.
+    pub in_place: bool,
+
+    /// Total size in bytes of intermediate values which are alive while this
+    /// step executes. This includes the operator's inputs and outputs, as well
+    /// as values produced by earlier steps which are used by later steps.
+    pub live_bytes: usize,
+}
+
+/// Placement of an intermediate value in a static memory arena.
+///
+/// Values whose lifetimes do not overlap share the same buffer, or "slot", in
+/// the arena. Each slot is a separate buffer, which is preallocated by
+/// [BufferArena::from_plan](crate::BufferArena::from_plan).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArenaAllocation {
+    /// ID of the value node in the graph. If an operator runs in-place, this
+    /// is the first value which used the buffer.
+    pub value_id: NodeId,
+
+    /// Index of the slot the value is assigned to.
+    pub slot: usize,
+
+    /// Size of the value in bytes.
+    pub size: usize,
+
+    /// Index of the plan step which produces the value.
+    pub first_step: usize,
+
+    /// Index of the last plan step which uses the value. This is the number
+    /// of steps in the plan for values which are outputs of the graph.
+    pub last_step: usize,
+}
+
+/// Assign each allocation in `allocs` to a slot such that allocations with
+/// overlapping lifetimes use different slots.
+///
+/// Returns the size of each slot. Allocations are processed in order of
+/// `first_step`, reusing the smallest free slot which is large enough or
+/// growing the largest free slot if none are.
+pub(crate) fn assign_arena_slots(allocs: &mut [ArenaAllocation]) -> Vec<usize> {
+    allocs.sort_by_key(|alloc| alloc.first_step);
+
+    let mut slot_sizes: Vec<usize> = Vec::new();
+
+    // Step after which each slot becomes free.
+    let mut slot_last_step: Vec<usize> = Vec::new();
+
+    for alloc in allocs.iter_mut() {
+        let is_free = |slot: usize| slot_last_step[slot] < alloc.first_step;
+        let best_fit = (0..slot_sizes.len())
+            .filter(|&slot| is_free(slot) && slot_sizes[slot] >= alloc.size)
+            .min_by_key(|&slot| slot_sizes[slot]);
+        let slot = best_fit
+            .or_else(|| {
+                (0..slot_sizes.len())
+                    .filter(|&slot| is_free(slot))
+                    .max_by_key(|&slot| slot_sizes[slot])
+            })
+            .unwrap_or_else(|| {
+                slot_sizes.push(0);
+                slot_last_step.push(0);
+                slot_sizes.len() - 1
+            });
+        slot_sizes[slot] = slot_sizes[slot].max(alloc.size);
+        slot_last_step[slot] = alloc.last_step;
+        alloc.slot = slot;
+    }
+
+    slot_sizes
+}
+
+/// Analysis of the memory required to execute a graph.
+///
+/// This is produced by [Model::memory_plan](crate::Model::memory_plan), which
+/// computes the lifetimes of intermediate values from the execution plan and
+/// the shapes of values inferred from the input shapes.
+///
+/// The sizes reported assume 4 bytes per element, which is the size of all
+/// tensor element types currently supported. Memory used by model weights is
+/// not included, nor are temporary buffers allocated within operators.
+#[derive(Clone, Debug)]
+pub struct MemoryPlan {
+    pub(crate) steps: Vec<StepMemory>,
+    pub(crate) input_bytes: usize,
+    pub(crate) allocations: Vec<ArenaAllocation>,
+    pub(crate) slot_sizes: Vec<usize>,
+}
+
+impl MemoryPlan {
+    /// Return memory usage for each step of the plan, in execution order.
+    pub fn steps(&self) -> &[StepMemory] {
+        &self.steps
+    }
+
+    /// Return the total size in bytes of the graph inputs.
+    ///
+    /// Inputs are owned by the caller and are alive for the whole run.
+    pub fn input_bytes(&self) -> usize {
+        self.input_bytes
+    }
+
+    /// Return the step at which the size of live intermediate values is
+    /// greatest, or `None` if the plan is empty.
+    pub fn peak_step(&self) -> Option<&StepMemory> {
+        // Use the first step with the maximum value.
+        self.steps.iter().rev().max_by_key(|step| step.live_bytes)
+    }
+
+    /// Return the peak size in bytes of live intermediate values.
+    ///
+    /// The total memory needed for the run is approximately this plus
+    /// [MemoryPlan::input_bytes] and the size of the model weights.
+    pub fn peak_bytes(&self) -> usize {
+        self.peak_step().map(|step| step.live_bytes).unwrap_or(0)
+    }
+
+    /// Return true if the shapes of all intermediate values were known.
+    ///
+    /// If false, the sizes reported are lower bounds, as values of unknown
+    /// size are not counted.
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(|step| step.output_bytes.is_some())
+    }
+
+    /// Return the arena slot assigned to each intermediate value whose
+    /// size is known, in order of the step which produces it.
+    pub fn allocations(&self) -> &[ArenaAllocation] {
+        &self.allocations
+    }
+
+    /// Return the sizes in bytes of the slots in the static arena.
+    ///
+    /// See [crate::BufferArena::from_plan].
+    pub fn slot_sizes(&self) -> &[usize] {
+        &self.slot_sizes
+    }
+
+    /// Return the `n` steps with the largest output allocations, in
+    /// descending order of size.
+    pub fn top_allocations(&self, n: usize) -> Vec<&StepMemory> {
+        let mut steps: Vec<&StepMemory> = self.steps.iter().collect();
+        steps.sort_by_key(|step| std::cmp::Reverse(step.output_bytes.unwrap_or(0)));
+        steps.truncate(n);
+        steps
+    }
+}
+
+/// Format a size in bytes in the largest unit for which it is >= 1.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024. && unit < UNITS.len() - 1 {
+        size /= 1024.;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", size, UNITS[unit])
+    }
+}
+
+/// [Display](fmt::Display)-able table of the largest allocations in a plan.
+struct AllocationTable<'a> {
+    rows: Vec<&'a StepMemory>,
+}
+
+impl<'a> Table for AllocationTable<'a> {
+    fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn headings(&self) -> &[&str] {
+        &["Node", "Op type", "Output size", "Live size"]
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let row = self.rows.get(row).expect("invalid row");
+        match col {
+            0 => row.node_name.clone(),
+            1 => row.op_type.clone(),
+            2 => match (row.output_bytes, row.in_place) {
+                (_, true) => "(in-place)".to_string(),
+                (Some(bytes), false) => format_bytes(bytes),
+                (None, false) => "?".to_string(),
+            },
+            3 => format_bytes(row.live_bytes),
+            _ => panic!("invalid column"),
+        }
+    }
+}
+
+impl fmt::Display for MemoryPlan {
+    /// Format a report listing the peak memory usage and the largest
+    /// allocations.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Inputs: {}", format_bytes(self.input_bytes))?;
+        write!(
+            f,
+            "Peak intermediate values: {}",
+            format_bytes(self.peak_bytes())
+        )?;
+        if let Some(step) = self.peak_step() {
+            write!(f, " at \"{}\" ({})", step.node_name, step.op_type)?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Static arena: {} in {} buffers",
+            format_bytes(self.slot_sizes.iter().sum()),
+            self.slot_sizes.len()
+        )?;
+        if !self.is_complete() {
+            writeln!(
+                f,
+                "Note: Some shapes could not be determined. Sizes are lower bounds."
+            )?;
+        }
+        writeln!(f)?;
+        AllocationTable {
+            rows: self.top_allocations(10),
+        }
+        .display(0 /* indent */)
+        .fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assign_arena_slots, format_bytes, ArenaAllocation};
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MB");
+    }
+
+    #[test]
+    fn test_assign_arena_slots() {
+        let alloc = |value_id, size, first_step, last_step| ArenaAllocation {
+            value_id,
+            slot: 0,
+            size,
+            first_step,
+            last_step,
+        };
+        let mut allocs = [
+            alloc(1, 100, 0, 1),
+            alloc(2, 50, 1, 2),
+            // Can reuse the slot of value 1, which is freed after step 1.
+            alloc(3, 80, 2, 3),
+            // Can reuse the slot of value 2, which is grown.
+            alloc(4, 60, 3, 4),
+        ];
+        let slot_sizes = assign_arena_slots(&mut allocs);
+        assert_eq!(slot_sizes, [100, 60]);
+
+        let placements: Vec<_> = allocs
+            .iter()
+            .map(|alloc| (alloc.value_id, alloc.slot))
+            .collect();
+        assert_eq!(placements, [(1, 0), (2, 1), (3, 0), (4, 1)]);
+    }
+}
@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::thread::JoinHandle;
 
 #[cfg(feature = "mmap")]
 use std::fs::File;
@@ -12,19 +13,30 @@ use std::fs::File;
 use memmap2::Mmap;
 
 use rten_tensor::Tensor;
-use smallvec::smallvec;
 
+use crate::compression::decompress;
 use crate::constant_storage::{ArcSlice, ArcTensorView, ConstantStorage};
 use crate::env::str_as_bool;
-use crate::graph::{ConstantNodeData, Dimension, Graph, Node, NodeId, RunError, RunOptions};
+use crate::graph::{
+    ConstantNodeData, Dimension, Graph, Node, NodeId, RunError, RunOptions, RunPlan, Subgraph,
+};
+use crate::input_adapter::{insert_input_adapter, InputAdapter};
+use crate::memory_plan::MemoryPlan;
+use crate::model_builder::{ModelBuildError, ModelBuilder};
 use crate::model_metadata::ModelMetadata;
-use crate::ops;
-use crate::ops::{
-    BoxOrder, CoordTransformMode, DataType, Direction, Input, NearestMode, Operator, Output,
-    Padding, ResizeMode, Scalar, ScatterReduction,
+use crate::number::BF16;
+use crate::onnx_export::export_onnx;
+use crate::op_registry::{OpAttrs, OpRegistry, ReadOpError};
+use crate::ops::{DataType, Input, Output};
+use crate::optimize::{fold_constants, fuse_attention, fuse_gelu};
+use crate::quantize::{
+    activation_candidates, quantize_activations, quantize_weights, ActivationCandidate,
+    QuantizeOptions,
 };
 use crate::schema_generated as sg;
-use crate::schema_generated::{root_as_model, OperatorNode, OperatorType, PadMode};
+use crate::schema_generated::{root_as_model, OperatorNode};
+use crate::tensor_pool::BufferArena;
+use crate::threading::thread_pool;
 use crate::timing::TimingSort;
 
 /// The central type used to execute RTen machine learning models.
@@ -86,6 +98,23 @@ use crate::timing::TimingSort;
 /// evaluating the part of the graph that depends only on the constant inputs
 /// once, outside the loop. To do this use [Model::partial_run].
 ///
+/// ## Thread safety
+///
+/// `Model` is `Send` and `Sync`, and running a model only requires a shared
+/// reference. A single loaded model can therefore be used to run inferences
+/// from multiple threads concurrently, for example by wrapping it in an
+/// [`Arc`], without copying its weights.
+///
+/// Each run has its own state. Intermediate values, and the
+/// [`TensorPool`](crate::TensorPool) used to allocate their buffers, are
+/// created per call and freed when the call returns, unless a
+/// [`BufferArena`](crate::BufferArena) is specified in the [`RunOptions`].
+/// An arena can be shared between concurrent runs, but each run takes all of
+/// its buffers at the start, so it is more effective to use one arena per
+/// thread. Operators within each run are executed using the global thread
+/// pool (see [`thread_pool`](crate::thread_pool)), which is shared by
+/// concurrent runs.
+///
 /// ## Custom operator registries
 ///
 /// By default all supported ONNX operators are available for use by the model.
@@ -97,6 +126,9 @@ pub struct Model {
     output_ids: Vec<NodeId>,
     graph: Graph,
     metadata: ModelMetadata,
+
+    /// Attributes used to construct each operator node in the graph.
+    op_attrs: HashMap<NodeId, OpAttrs>,
 }
 
 /// Provides access to metadata about a graph node.
@@ -116,6 +148,205 @@ impl<'a> NodeInfo<'a> {
     pub fn shape(&self) -> Option<Vec<Dimension>> {
         self.node.shape()
     }
+
+    /// Return the names of the symbolic dimensions in the node's shape.
+    ///
+    /// The result has one entry per dimension, which is `None` for fixed
+    /// dimensions. Returns `None` if the node has no shape information.
+    ///
+    /// Symbolic names can be used to identify dimensions which share a size,
+    /// for example the batch or sequence length of different inputs.
+    pub fn dim_names(&self) -> Option<Vec<Option<&str>>> {
+        let Node::Value(value) = self.node else {
+            // Constants always have fixed shapes.
+            return self
+                .node
+                .shape()
+                .map(|shape| shape.iter().map(|_| None).collect());
+        };
+        value.shape().map(|shape| {
+            shape
+                .iter()
+                .map(|dim| match dim {
+                    Dimension::Fixed(_) => None,
+                    Dimension::Symbolic(name) => Some(name.as_str()),
+                })
+                .collect()
+        })
+    }
+
+    /// Return true if this is an optional value, such as a model input which
+    /// can be omitted when the model is run.
+    pub fn is_optional(&self) -> bool {
+        matches!(self.node, Node::Value(value) if value.is_optional())
+    }
+
+    /// Return the data type associated with a node, if known.
+    ///
+    /// For model inputs and outputs this is the expected type of the value
+    /// at runtime. Models converted by older versions of the converter do
+    /// not record types for values.
+    pub fn dtype(&self) -> Option<DataType> {
+        self.node.dtype()
+    }
+}
+
+/// Description of a model input or output.
+///
+/// This is returned by [Model::input_info] and [Model::output_info].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueInfo {
+    /// ID of the value's node in the graph.
+    pub id: NodeId,
+
+    /// Name of the value, if present.
+    pub name: Option<String>,
+
+    /// Expected data type of the value, if known.
+    pub dtype: Option<DataType>,
+
+    /// Expected shape of the value, if known.
+    ///
+    /// Dimensions whose size is not fixed are identified by symbolic names,
+    /// such as "batch" or "sequence". Dimensions with the same name in
+    /// different inputs are expected to have the same size.
+    pub shape: Option<Vec<Dimension>>,
+
+    /// True if this value may be omitted. See [NodeInfo::is_optional].
+    pub optional: bool,
+}
+
+/// Outputs of a model run which can be looked up by name.
+///
+/// This is returned by [Model::run_map].
+#[derive(Debug)]
+pub struct NamedOutputs {
+    outputs: Vec<(String, Output)>,
+}
+
+impl NamedOutputs {
+    /// Return the number of outputs.
+    pub fn len(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Return true if there are no outputs.
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+
+    /// Return the output with a given name.
+    pub fn get(&self, name: &str) -> Option<&Output> {
+        self.outputs
+            .iter()
+            .find(|(output_name, _)| output_name == name)
+            .map(|(_, output)| output)
+    }
+
+    /// Remove and return the output with a given name.
+    pub fn remove(&mut self, name: &str) -> Option<Output> {
+        let index = self
+            .outputs
+            .iter()
+            .position(|(output_name, _)| output_name == name)?;
+        Some(self.outputs.remove(index).1)
+    }
+
+    /// Remove the output with a given name and convert it to a tensor of a
+    /// specific type.
+    ///
+    /// `T` can be any type that an [Output] can be converted to, such as
+    /// `Tensor<f32>` or `NdTensor<i32, 2>`.
+    pub fn take<T>(&mut self, name: &str) -> Result<T, RunError>
+    where
+        T: TryFrom<Output>,
+    {
+        self.remove(name)
+            .ok_or_else(|| RunError::InvalidNodeName(name.to_string()))?
+            .try_into()
+            .map_err(|_| RunError::OutputMismatch("output has incorrect type or rank"))
+    }
+
+    /// Return an iterator over the names of the outputs.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.outputs.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+/// A model prepared for repeated execution with a fixed set of inputs and
+/// outputs.
+///
+/// Sessions are created using [Model::prepare]. The execution plan is created
+/// once when the session is prepared, instead of on each run. Buffers for
+/// intermediate values are kept in an arena which is shared across runs, so
+/// that once the session has been run, later runs with the same input shapes
+/// avoid most allocations. Buffers of outputs can be returned to the arena
+/// using [Session::reclaim].
+pub struct Session<'a> {
+    model: &'a Model,
+    plan: RunPlan<'a>,
+    arena: BufferArena,
+}
+
+impl<'a> Session<'a> {
+    /// Use `arena` to allocate buffers for intermediate values.
+    ///
+    /// This can be used to pre-allocate buffers for the first run, using
+    /// [BufferArena::from_plan].
+    pub fn with_arena(mut self, arena: BufferArena) -> Session<'a> {
+        self.arena = arena;
+        self
+    }
+
+    /// Return the IDs of the input nodes.
+    pub fn input_ids(&self) -> &[NodeId] {
+        self.plan.inputs()
+    }
+
+    /// Return the IDs of the output nodes.
+    pub fn output_ids(&self) -> &[NodeId] {
+        self.plan.outputs()
+    }
+
+    /// Return the arena used to allocate buffers for intermediate values.
+    pub fn arena(&self) -> &BufferArena {
+        &self.arena
+    }
+
+    /// Run the model and return the outputs, in the order they were
+    /// specified when the session was prepared.
+    ///
+    /// `inputs` must include a value for each of the session's inputs, except
+    /// for optional inputs. If `opts` specifies an arena, it is used instead
+    /// of the session's arena.
+    pub fn run(
+        &self,
+        inputs: &[(NodeId, Input)],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let mut opts = self.model.run_options(opts);
+        if opts.arena.is_none() {
+            opts.arena = Some(self.arena.clone());
+        }
+        self.model
+            .graph
+            .run_prepared(&self.plan, inputs, Vec::new(), Some(opts))
+    }
+
+    /// Return the buffer of an output to the session's arena, so it can be
+    /// re-used by a future run.
+    pub fn reclaim(&self, output: Output) {
+        self.arena.reclaim(output);
+    }
+}
+
+impl IntoIterator for NamedOutputs {
+    type Item = (String, Output);
+    type IntoIter = std::vec::IntoIter<(String, Output)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.outputs.into_iter()
+    }
 }
 
 /// Parse profiling flags from the `RTEN_TIMING` environment variable and
@@ -150,14 +381,15 @@ fn parse_timing_config(config: &str, opts: &mut RunOptions) {
 /// a subset of operators available.
 pub struct ModelOptions {
     registry: OpRegistry,
+    input_adapters: Vec<(String, InputAdapter)>,
+    optimize: bool,
+    prepack_weights: bool,
 }
 
 impl ModelOptions {
     /// Create a set of options with all operators enabled.
     pub fn with_all_ops() -> ModelOptions {
-        ModelOptions {
-            registry: OpRegistry::with_all_ops(),
-        }
+        Self::with_ops(OpRegistry::with_all_ops())
     }
 
     /// Create a set of options with a custom set of operators enabled.
@@ -165,7 +397,72 @@ impl ModelOptions {
     /// This can be used to reduce binary size by excluding operators that
     /// the model will not use, or use custom implementations of operators.
     pub fn with_ops(ops: OpRegistry) -> ModelOptions {
-        ModelOptions { registry: ops }
+        ModelOptions {
+            registry: ops,
+            input_adapters: Vec::new(),
+            optimize: true,
+            prepack_weights: false,
+        }
+    }
+
+    /// Declare that the input named `name` will be supplied in the format
+    /// described by `adapter`.
+    ///
+    /// When the model is loaded, operators are inserted to convert the input
+    /// to the format the model expects. The input's node ID and shape, as
+    /// returned by [`Model::node_id`] and [`Model::input_shape`], will then
+    /// refer to the adapted input.
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use rten::ops::DataType;
+    /// use rten::{ImageLayout, InputAdapter, ModelOptions};
+    ///
+    /// let model = ModelOptions::with_all_ops()
+    ///     .with_input_adapter(
+    ///         "pixel_values",
+    ///         InputAdapter {
+    ///             layout: ImageLayout::NHWC,
+    ///             dtype: Some(DataType::Int32),
+    ///             scale: Some(1. / 255.),
+    ///             mean: Some(vec![0.485, 0.456, 0.406]),
+    ///             std: Some(vec![0.229, 0.224, 0.225]),
+    ///         },
+    ///     )
+    ///     .load_file("model.rten")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_input_adapter(mut self, name: &str, adapter: InputAdapter) -> ModelOptions {
+        self.input_adapters.push((name.to_string(), adapter));
+        self
+    }
+
+    /// Set whether graph optimizations are applied when the model is loaded.
+    ///
+    /// Optimizations such as operator fusion replace parts of the graph with
+    /// more efficient operators. Disabling them runs the model exactly as
+    /// converted, which is useful when debugging differences in outputs, or
+    /// when inspecting intermediate values by requesting them as outputs from
+    /// [`Model::run`]. Optimizations are enabled by default.
+    pub fn with_optimization(mut self, enabled: bool) -> ModelOptions {
+        self.optimize = enabled;
+        self
+    }
+
+    /// Set whether constant weights are prepacked when the model is loaded.
+    ///
+    /// Operators such as `MatMul` normally re-arrange their weights into a
+    /// layout that is efficient for matrix multiplication each time they are
+    /// run. Prepacking does this once, at load time, which reduces the time
+    /// taken by each run at the cost of extra memory to hold the packed
+    /// copies of the weights. Prepacking is disabled by default.
+    ///
+    /// `MatMul` weights which are mostly zero, such as in pruned models, are
+    /// instead converted to a sparse format. See [`crate::sparse`].
+    pub fn prepack_weights(mut self, enabled: bool) -> ModelOptions {
+        self.prepack_weights = enabled;
+        self
     }
 
     /// Load the model from a file. See [`Model::load_file`].
@@ -177,7 +474,7 @@ impl ModelOptions {
     /// Load the model from a data buffer. See [`Model::load`].
     pub fn load(&self, data: Vec<u8>) -> Result<Model, ModelLoadError> {
         let storage = Arc::new(ConstantStorage::Buffer(data));
-        Model::load_impl(storage, &self.registry)
+        Model::load_impl(storage, self)
     }
 
     /// Load the model from a memory-mapped view of a file. See [`Model::load_mmap`].
@@ -190,7 +487,97 @@ impl ModelOptions {
         let file = File::open(path).map_err(ModelLoadError::ReadFailed)?;
         let mmap = Mmap::map(&file).map_err(ModelLoadError::ReadFailed)?;
         let storage = Arc::new(ConstantStorage::Mmap(mmap));
-        Model::load_impl(storage, &self.registry)
+        Model::load_impl(storage, self)
+    }
+
+    /// Load the model from a data buffer on a background thread. See
+    /// [`Model::load_in_background`].
+    pub fn load_in_background(self, data: Vec<u8>) -> PendingModel {
+        PendingModel::spawn(move || self.load(data))
+    }
+
+    /// Read and load the model from a file on a background thread. See
+    /// [`Model::load_in_background`].
+    pub fn load_file_in_background<P: AsRef<Path>>(self, path: P) -> PendingModel {
+        let path: PathBuf = path.as_ref().into();
+        PendingModel::spawn(move || self.load_file(path))
+    }
+}
+
+/// A model which is being loaded and prepared on a background thread.
+///
+/// This is returned by [`Model::load_in_background`] and related methods. Use
+/// [`is_ready`](PendingModel::is_ready) to poll for completion, for example
+/// from a UI event loop, and [`wait`](PendingModel::wait) to get the loaded
+/// model.
+pub struct PendingModel {
+    state: PendingState,
+}
+
+enum PendingState {
+    Loading(JoinHandle<Result<Model, ModelLoadError>>),
+    Done(Box<Result<Model, ModelLoadError>>),
+}
+
+impl PendingModel {
+    /// Run `load` on a new thread and return a handle to its result.
+    ///
+    /// If threads are not supported on the current platform (eg. WebAssembly),
+    /// `load` is run synchronously instead.
+    fn spawn<F: FnOnce() -> Result<Model, ModelLoadError> + Send + 'static>(
+        load: F,
+    ) -> PendingModel {
+        let prepare = move || {
+            let model = load()?;
+
+            // Start the thread pool used for inference, so this cost is not
+            // paid by the first run of the model.
+            thread_pool();
+
+            Ok(model)
+        };
+
+        // Wrap `prepare` so it can be reclaimed if spawning the thread fails.
+        let prepare = Arc::new(std::sync::Mutex::new(Some(prepare)));
+        let thread_prepare = prepare.clone();
+        let spawned = std::thread::Builder::new()
+            .name("rten-load".to_string())
+            .spawn(move || {
+                let prepare = thread_prepare.lock().unwrap().take().unwrap();
+                prepare()
+            });
+
+        let state = match spawned {
+            Ok(handle) => PendingState::Loading(handle),
+            Err(_) => {
+                let prepare = prepare.lock().unwrap().take().unwrap();
+                PendingState::Done(Box::new(prepare()))
+            }
+        };
+        PendingModel { state }
+    }
+
+    /// Return true if loading has finished, either successfully or with an
+    /// error.
+    ///
+    /// If this returns true, [`wait`](PendingModel::wait) will return without
+    /// blocking.
+    pub fn is_ready(&self) -> bool {
+        match &self.state {
+            PendingState::Loading(handle) => handle.is_finished(),
+            PendingState::Done(_) => true,
+        }
+    }
+
+    /// Block until the model has finished loading and return it.
+    pub fn wait(self) -> Result<Model, ModelLoadError> {
+        match self.state {
+            PendingState::Loading(handle) => match handle.join() {
+                Ok(result) => result,
+                Err(panic) => std::panic::resume_unwind(panic),
+            },
+            PendingState::Done(result) => *result,
+        }
     }
 }
 
@@ -239,130 +626,112 @@ impl Model {
         ModelOptions::with_all_ops().load_mmap(path)
     }
 
+    /// Load a serialized model on a background thread.
+    ///
+    /// Loading a large model involves deserializing and validating its graph,
+    /// which can take a noticeable amount of time. This method returns
+    /// immediately with a [`PendingModel`] handle, allowing the application to
+    /// remain responsive while the model is prepared.
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use rten::Model;
+    ///
+    /// let data = std::fs::read("model.rten")?;
+    /// let pending = Model::load_in_background(data);
+    ///
+    /// while !pending.is_ready() {
+    ///     // Update UI, show progress indicator etc.
+    ///     # break;
+    /// }
+    ///
+    /// let model = pending.wait()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load_in_background(data: Vec<u8>) -> PendingModel {
+        ModelOptions::with_all_ops().load_in_background(data)
+    }
+
+    /// Read and load a serialized model from a file on a background thread.
+    ///
+    /// See [`Model::load_in_background`].
+    pub fn load_file_in_background<P: AsRef<Path>>(path: P) -> PendingModel {
+        ModelOptions::with_all_ops().load_file_in_background(path)
+    }
+
     fn load_impl(
         storage: Arc<ConstantStorage>,
-        registry: &OpRegistry,
+        options: &ModelOptions,
     ) -> Result<Model, ModelLoadError> {
+        let registry = &options.registry;
         let model = root_as_model(storage.data()).map_err(ModelLoadError::ParseFailed)?;
 
         if model.schema_version() != 1 {
             return Err(ModelLoadError::SchemaVersionUnsupported);
         }
 
-        let mut graph = Graph::new();
-
-        let node_count = model.graph().nodes().map(|ns| ns.len()).unwrap_or(0);
-
-        // Map of model node name to graph node ID
-        let mut node_id_from_name: HashMap<String, NodeId> = HashMap::with_capacity(node_count);
-
-        // Map of model node index to graph node ID
-        let mut node_id_from_index: HashMap<usize, NodeId> = HashMap::with_capacity(node_count);
+        let LoadedGraph {
+            mut graph,
+            node_ids: mut node_id_from_name,
+            mut input_ids,
+            output_ids,
+            mut op_attrs,
+        } = load_graph(&storage, options, model.graph())?;
+
+        for (name, adapter) in &options.input_adapters {
+            let input_pos = node_id_from_name
+                .get(name)
+                .and_then(|id| input_ids.iter().position(|input_id| input_id == id))
+                .ok_or_else(|| {
+                    ModelLoadError::GraphError(format!(
+                        "input adapter specified for unknown input \"{}\"",
+                        name
+                    ))
+                })?;
+            let adapted_id = insert_input_adapter(
+                &mut graph,
+                registry,
+                &mut op_attrs,
+                input_ids[input_pos],
+                adapter,
+            )
+            .map_err(|err| {
+                ModelLoadError::GraphError(format!("failed to adapt input \"{}\": {}", name, err))
+            })?;
+            input_ids[input_pos] = adapted_id;
+            node_id_from_name.insert(name.clone(), adapted_id);
+        }
 
-        let mut add_node_id = |name: Option<&str>, graph_node| {
-            if let Some(name) = name {
-                node_id_from_name.insert(name.to_string(), graph_node);
+        // Evaluate parts of the graph which only depend on constants, then
+        // replace decomposed activation functions and attention with fused
+        // operators.
+        if options.optimize {
+            if fold_constants(&mut graph, &mut op_attrs) > 0 {
+                node_id_from_name.retain(|_, id| graph.get_node(*id).is_some());
             }
-        };
-
-        let input_ids = model
-            .graph()
-            .inputs()
-            .map(|ids| ids.iter().map(|id| id as NodeId).collect())
-            .unwrap_or_default();
-
-        let output_ids = model
-            .graph()
-            .outputs()
-            .map(|ids| ids.iter().map(|id| id as NodeId).collect())
-            .unwrap_or_default();
-
-        if let Some(nodes) = model.graph().nodes() {
-            for (node_index, node) in nodes.iter().enumerate() {
-                if let Some(operator) = node.data_as_operator_node() {
-                    let op = registry
-                        .read_op(&operator)
-                        .map_err(ModelLoadError::OperatorInvalid)?;
-
-                    let mut inputs: Vec<Option<NodeId>> = Vec::new();
-                    if let Some(op_input_ids) = operator.inputs() {
-                        for node_index in op_input_ids.iter() {
-                            if node_index < 0 {
-                                inputs.push(None);
-                                continue;
-                            }
-                            let index_usize = node_index as usize;
-                            if let Some(node_id) = node_id_from_index.get(&index_usize) {
-                                inputs.push(Some(*node_id))
-                            } else {
-                                return Err(ModelLoadError::GraphError(
-                                    "operator input is invalid".to_string(),
-                                ));
-                            }
-                        }
-                    }
-
-                    let mut outputs: Vec<Option<NodeId>> = Vec::new();
-                    if let Some(op_output_ids) = operator.outputs() {
-                        for node_index in op_output_ids.iter() {
-                            if node_index < 0 {
-                                outputs.push(None);
-                                continue;
-                            }
-                            let index_usize = node_index as usize;
-                            if let Some(node_id) = node_id_from_index.get(&index_usize) {
-                                outputs.push(Some(*node_id))
-                            } else {
-                                return Err(ModelLoadError::GraphError(
-                                    "operator output is invalid".to_string(),
-                                ));
-                            }
-                        }
-                    }
+            fuse_gelu(&mut graph, registry, &mut op_attrs, &output_ids);
+            fuse_attention(&mut graph, registry, &mut op_attrs, &output_ids);
+        }
 
-                    let graph_node = graph.add_op(node.name(), op, &inputs, &outputs);
-
-                    add_node_id(node.name(), graph_node);
-                    node_id_from_index.insert(node_index, graph_node);
-                } else if let Some(value_node) = node.data_as_value_node() {
-                    let shape: Option<Vec<Dimension>> = value_node.shape().map(|shape| {
-                        shape
-                            .iter()
-                            .map(|dim| {
-                                if let Some(name) = dim.name() {
-                                    Dimension::Symbolic(name.to_string())
-                                } else {
-                                    Dimension::Fixed(dim.value() as usize)
-                                }
-                            })
-                            .collect()
-                    });
-                    let graph_node = graph.add_value(node.name(), shape);
-
-                    add_node_id(node.name(), graph_node);
-                    node_id_from_index.insert(node_index, graph_node);
-                } else if let Some(constant) = node.data_as_constant_node() {
-                    let shape: Vec<usize> = constant.shape().iter().map(|x| x as usize).collect();
-                    let graph_node = if let Some(float_data) = constant.data_as_float_data() {
-                        let const_data =
-                            constant_node_from_flatbuffers_vec(&storage, float_data.data(), &shape);
-                        graph.add_constant(node.name(), const_data)
-                    } else if let Some(int_data) = constant.data_as_int_data() {
-                        let const_data =
-                            constant_node_from_flatbuffers_vec(&storage, int_data.data(), &shape);
-                        graph.add_constant(node.name(), const_data)
-                    } else {
-                        return Err(ModelLoadError::GraphError(
-                            "unsupported constant data type".to_string(),
-                        ));
-                    };
-
-                    add_node_id(node.name(), graph_node);
-                    node_id_from_index.insert(node_index, graph_node);
-                } else {
-                    return Err(ModelLoadError::GraphError("unknown node type".to_string()));
-                }
-            }
+        // Check that the shapes recorded in the model are compatible with
+        // the operators, so that errors are reported with the offending node
+        // instead of when the model is run.
+        graph.infer_shapes(&[]).map_err(|err| match err {
+            RunError::OperatorError {
+                name,
+                op_type,
+                error,
+                ..
+            } => ModelLoadError::GraphError(format!(
+                "incompatible input shapes for operator \"{}\" ({}): {}",
+                name, op_type, error
+            )),
+            err => ModelLoadError::GraphError(err.to_string()),
+        })?;
+
+        if options.prepack_weights {
+            graph.prepack_weights();
         }
 
         let metadata = model
@@ -376,6 +745,7 @@ impl Model {
             output_ids,
             graph,
             metadata,
+            op_attrs,
         };
         Ok(model)
     }
@@ -416,859 +786,1351 @@ impl Model {
         &self.output_ids
     }
 
-    /// Return the total number of parameters in the model's weights.
-    pub fn total_params(&self) -> usize {
-        self.graph.total_params()
+    /// Return descriptions of the model's inputs, in the same order as
+    /// [Model::input_ids].
+    ///
+    /// The description of each input includes its expected data type and
+    /// shape, which can be used to allocate inputs of the right type and
+    /// size.
+    pub fn input_info(&self) -> Vec<ValueInfo> {
+        self.value_info(&self.input_ids)
     }
 
-    /// Convenience method that returns the expected input shape for the index'th input.
-    ///
-    /// The shape may contain a mix of fixed and symbolic dimensions.
-    pub fn input_shape(&self, index: usize) -> Option<Vec<Dimension>> {
-        let input_id = self.input_ids.get(index)?;
-        let node_info = self.node_info(*input_id)?;
-        node_info.shape()
+    /// Return descriptions of the model's outputs, in the same order as
+    /// [Model::output_ids].
+    pub fn output_info(&self) -> Vec<ValueInfo> {
+        self.value_info(&self.output_ids)
     }
 
-    /// Execute the model and return the outputs specified by `outputs`.
+    fn value_info(&self, ids: &[NodeId]) -> Vec<ValueInfo> {
+        ids.iter()
+            .filter_map(|&id| {
+                let info = self.node_info(id)?;
+                Some(ValueInfo {
+                    id,
+                    name: info.name().map(|name| name.to_string()),
+                    dtype: info.dtype(),
+                    shape: info.shape(),
+                    optional: info.is_optional(),
+                })
+            })
+            .collect()
+    }
+
+    /// Replace the value of a constant node, such as a weight, in the model.
     ///
-    /// This method allows for running a model with a variable number of inputs
-    /// and outputs of different types. See [Model::run_one] or [Model::run_n]
-    /// for the common case of running a model with a single or statically
-    /// known number of inputs and outputs.
+    /// This allows experimenting with different weights without having to
+    /// re-generate the model file. The new value may have a different shape
+    /// and data type than the original, but it must be compatible with the
+    /// operators that use it. Sequences are not supported as constant values.
     ///
-    /// The input and output nodes are specified via IDs looked up via `find_node`.
-    pub fn run(
-        &self,
-        inputs: &[(NodeId, Input)],
-        outputs: &[NodeId],
-        opts: Option<RunOptions>,
-    ) -> Result<Vec<Output>, RunError> {
-        let mut opts = opts.unwrap_or_default();
-        if let Some(timing_var) = env::var_os("RTEN_TIMING") {
-            let timing_var = timing_var.to_string_lossy();
-            parse_timing_config(&timing_var, &mut opts);
+    /// If the model was loaded with
+    /// [`prepack_weights`](ModelOptions::prepack_weights) enabled, the
+    /// prepacked copies of the constant are updated as well.
+    pub fn override_constant(&mut self, id: NodeId, value: Output) -> Result<(), RunError> {
+        let updated = match value {
+            Output::FloatTensor(t) => self.graph.set_constant(id, t),
+            Output::IntTensor(t) => self.graph.set_constant(id, t),
+            Output::Int64Tensor(t) => self.graph.set_constant(id, t),
+            Output::StringTensor(t) => self.graph.set_constant(id, t),
+            Output::Sequence(_) => {
+                return Err(RunError::UnsupportedValue(
+                    "constant values cannot be sequences",
+                ))
+            }
+        };
+        if updated {
+            Ok(())
+        } else {
+            Err(RunError::InvalidNodeId)
         }
-        self.graph.run(inputs, outputs, Some(opts))
     }
 
-    /// Run a model and retrieve `N` outputs.
+    /// Change the name of a node.
     ///
-    /// This is a simplified version of [Model::run] for the common case of
-    /// executing a model with a statically known number of outputs.
-    pub fn run_n<const N: usize>(
-        &self,
-        inputs: &[(NodeId, Input)],
-        outputs: [NodeId; N],
-        opts: Option<RunOptions>,
-    ) -> Result<[Output; N], RunError> {
-        let result = self.run(inputs, &outputs, opts)?;
-        Ok(result.try_into().expect("wrong output count"))
+    /// After renaming, the node can be found using [Model::find_node] with
+    /// the new name, but not the old one.
+    pub fn rename_node(&mut self, id: NodeId, name: &str) -> Result<(), ModelEditError> {
+        let old_name = self
+            .graph
+            .get_node(id)
+            .ok_or(ModelEditError::InvalidNodeId)?
+            .name()
+            .map(|name| name.to_string());
+        match self.node_ids.get(name) {
+            Some(&other_id) if other_id != id => {
+                return Err(ModelEditError::DuplicateName(name.to_string()));
+            }
+            _ => {}
+        }
+        if let Some(old_name) = old_name {
+            self.node_ids.remove(&old_name);
+        }
+        self.graph.set_node_name(id, Some(name));
+        self.node_ids.insert(name.to_string(), id);
+        Ok(())
     }
 
-    /// Run a model with a single input and output.
+    /// Remove a node from the model's graph.
     ///
-    /// This is a simplified version of [Model::run] for the common case of
-    /// executing a model with a single input and output.
-    pub fn run_one(&self, input: Input, opts: Option<RunOptions>) -> Result<Output, RunError> {
-        let &input_id = self.input_ids().first().ok_or(RunError::InvalidNodeId)?;
-        let &output_id = self.output_ids().first().ok_or(RunError::InvalidNodeId)?;
-        self.run_n(&[(input_id, input)], [output_id], opts)
-            .map(|[result]| result)
-    }
+    /// Removing an operator leaves its output values in the graph. Values and
+    /// constants can only be removed once they are no longer used as an
+    /// operator input or output, or as a model input or output. See also
+    /// [Model::remove_unused_nodes].
+    pub fn remove_node(&mut self, id: NodeId) -> Result<(), ModelEditError> {
+        let node = self
+            .graph
+            .get_node(id)
+            .ok_or(ModelEditError::InvalidNodeId)?;
+        if !matches!(node, Node::Operator(_)) {
+            let in_use = self.input_ids.contains(&id)
+                || self.output_ids.contains(&id)
+                || self.graph.iter().any(|(_, node)| match node {
+                    Node::Operator(op_node) => op_node
+                        .inputs()
+                        .iter()
+                        .chain(op_node.outputs())
+                        .any(|&node_id| node_id == Some(id)),
+                    _ => false,
+                });
+            if in_use {
+                return Err(ModelEditError::NodeInUse);
+            }
+        }
 
-    /// Run the model using an incomplete set of inputs.
-    ///
-    /// Unlike [`run`](Model::run) this will not fail if some values required to
-    /// compute `outputs` are missing. Instead it will compute as many
-    /// intermediate values as possible using the provided inputs and return the
-    /// leaf values of the subgraph that was executed. These intermediate
-    /// outputs can then be passed to future calls to [`run`](Model::run) when
-    /// the other inputs are available.
-    ///
-    /// This method can speed up autoregressive / recurrent models where the
-    /// model is run in a loop during inference, but some inputs are constant
-    /// across each iteration of the loop. In such cases, execution times can be
-    /// reduced by performing a `partial_run` once outside the loop, providing
-    /// the constant inputs, and the results can be provided together with the
-    /// the remaining inputs to `run` calls inside the loop.
-    pub fn partial_run(
-        &self,
-        inputs: &[(NodeId, Input)],
-        outputs: &[NodeId],
-        opts: Option<RunOptions>,
-    ) -> Result<Vec<(NodeId, Output)>, RunError> {
-        self.graph.partial_run(inputs, outputs, opts)
+        self.remove_graph_node(id);
+        Ok(())
     }
-}
 
-fn padding_from_attrs(mode: PadMode, pads: Option<flatbuffers::Vector<'_, u32>>) -> Padding {
-    match (mode, pads) {
-        (PadMode::Same, _) => Padding::Same,
-        (PadMode::Fixed, Some(pads)) => Padding::Fixed(pads.iter().map(|p| p as usize).collect()),
-        _ => Padding::Fixed(smallvec!(0; 4)),
+    /// Remove a node from the graph, along with its name and attributes.
+    fn remove_graph_node(&mut self, id: NodeId) {
+        if let Some(node) = self.graph.remove_node(id) {
+            if let Some(name) = node.name() {
+                self.node_ids.remove(name);
+            }
+        }
+        self.op_attrs.remove(&id);
     }
-}
 
-/// Read the first N items from `iter` into an array.
-///
-/// Panics if the iterator yields fewer than N items.
-fn array_from_iter<const N: usize, T: Default + Copy, I: Iterator<Item = T>>(
-    mut iter: I,
-) -> [T; N] {
-    let mut result = [T::default(); N];
-    for i in 0..N {
-        result[i] = iter.next().expect("incorrect array size");
-    }
-    result
-}
-
-fn vec_from_attr(attr: Option<flatbuffers::Vector<u32>>, default: &[usize]) -> Vec<usize> {
-    attr.map(|val| val.iter().map(|x| x as usize).collect())
-        .unwrap_or_else(|| default.to_vec())
-}
-
-/// Result of deserializing an operator node from a model file.
-pub type ReadOpResult = Result<Box<dyn Operator + Send + Sync>, ReadOpError>;
-
-/// A function that deserializes an operator node.
-pub type ReadOpFunction = dyn Fn(&OperatorNode) -> ReadOpResult;
-
-/// Trait that deserializes an operator from a `.rten` file into an [Operator]
-/// implementation.
-///
-/// This trait is implemented for all operators in [crate::ops].
-pub trait ReadOp: Operator + Sized + Send + Sync {
-    /// Return the type enum value for this operator.
-    fn op_type() -> sg::OperatorType;
-
-    /// Deserialize an operator.
+    /// Replace uses of node `old_id` as an operator input or model output
+    /// with `new_id`.
     ///
-    /// The node's type must correspond to the result of `op_type`.
-    fn read(op: &OperatorNode) -> Result<Self, ReadOpError>;
+    /// This can be used to bypass part of the graph, for example to return
+    /// the output of a model before a post-processing step is applied. The
+    /// nodes that are no longer used can then be removed using
+    /// [Model::remove_unused_nodes].
+    pub fn replace_uses(&mut self, old_id: NodeId, new_id: NodeId) -> Result<(), ModelEditError> {
+        for id in [old_id, new_id] {
+            match self.graph.get_node(id) {
+                Some(Node::Operator(_)) | None => return Err(ModelEditError::InvalidNodeId),
+                Some(_) => {}
+            }
+        }
+        self.graph.replace_input(old_id, new_id);
+        for output_id in self.output_ids.iter_mut() {
+            if *output_id == old_id {
+                *output_id = new_id;
+            }
+        }
+        Ok(())
+    }
 
-    /// Deserialize an operator and box it into a `Box<dyn Operator>`.
+    /// Set the IDs of the model's input nodes.
     ///
-    /// The node's type must correspond to the result of `op_type`.
-    fn read_boxed(op: &OperatorNode) -> ReadOpResult
-    where
-        Self: 'static,
-    {
-        let op = Self::read(op)?;
-        Ok(Box::new(op))
+    /// Each input must be a value node.
+    pub fn set_input_ids(&mut self, ids: &[NodeId]) -> Result<(), ModelEditError> {
+        if !ids
+            .iter()
+            .all(|&id| matches!(self.graph.get_node(id), Some(Node::Value(_))))
+        {
+            return Err(ModelEditError::InvalidNodeId);
+        }
+        self.input_ids = ids.to_vec();
+        Ok(())
     }
-}
-
-/// Convenience macro to simplify implementing [ReadOp].
-macro_rules! impl_read_op {
-    ($op:ident) => {
-        impl ReadOp for ops::$op {
-            fn op_type() -> OperatorType {
-                OperatorType::$op
-            }
 
-            fn read(_op: &OperatorNode) -> Result<Self, ReadOpError> {
-                Ok(ops::$op {})
-            }
+    /// Set the IDs of the model's output nodes.
+    ///
+    /// Each output must be a value or constant node.
+    pub fn set_output_ids(&mut self, ids: &[NodeId]) -> Result<(), ModelEditError> {
+        if !ids.iter().all(|&id| {
+            matches!(
+                self.graph.get_node(id),
+                Some(Node::Value(_) | Node::Constant(_))
+            )
+        }) {
+            return Err(ModelEditError::InvalidNodeId);
         }
-    };
+        self.output_ids = ids.to_vec();
+        Ok(())
+    }
 
-    ($op:ident, axis, $attrs_method:ident) => {
-        impl ReadOp for ops::$op {
-            fn op_type() -> OperatorType {
-                OperatorType::$op
-            }
+    /// Return the attributes of an operator node.
+    pub fn operator_attrs(&self, id: NodeId) -> Option<&OpAttrs> {
+        self.op_attrs.get(&id)
+    }
 
-            fn read(op: &OperatorNode) -> Result<Self, ReadOpError> {
-                let attrs = op.$attrs_method().ok_or(ReadOpError::AttrError)?;
-                let op = ops::$op {
-                    axis: attrs.axis() as isize,
-                };
-                Ok(op)
-            }
+    /// Change the attributes of an operator node.
+    ///
+    /// The operator is re-created from the new attributes using `registry`,
+    /// which must include the operator's type.
+    pub fn set_operator_attrs(
+        &mut self,
+        id: NodeId,
+        attrs: OpAttrs,
+        registry: &OpRegistry,
+    ) -> Result<(), ModelEditError> {
+        let Some(Node::Operator(op_node)) = self.graph.get_node(id) else {
+            return Err(ModelEditError::InvalidNodeId);
+        };
+        let custom_type = op_node
+            .custom_op_type()
+            .map(|(domain, op_type)| (domain.to_string(), op_type.to_string()));
+        let op = match &custom_type {
+            Some((domain, op_type)) => registry.read_custom_op(domain, op_type, &attrs),
+            None => registry.read_op(op_node.operator().name(), &attrs),
         }
-    };
+        .map_err(ModelEditError::OperatorInvalid)?;
+        let inputs = op_node.inputs().to_vec();
 
-    ($op:ident, reduce_axis, $attrs_method:ident) => {
-        impl ReadOp for ops::$op {
-            fn op_type() -> OperatorType {
-                OperatorType::$op
-            }
+        self.graph.replace_op(id, op, &inputs);
+        if let Some((domain, op_type)) = custom_type {
+            self.graph.set_custom_op_type(id, &domain, &op_type);
+        }
+        self.op_attrs.insert(id, attrs);
+        Ok(())
+    }
 
-            fn read(op: &OperatorNode) -> Result<Self, ReadOpError> {
-                let attrs = op.$attrs_method().ok_or(ReadOpError::AttrError)?;
-                let op = ops::$op {
-                    axis: attrs.axis() as isize,
-                    keep_dims: attrs.keep_dims(),
-                };
-                Ok(op)
+    /// Remove nodes which are not needed to compute the model's outputs.
+    ///
+    /// Model inputs are kept even if they are unused. Returns the number of
+    /// nodes that were removed.
+    pub fn remove_unused_nodes(&mut self) -> usize {
+        let mut producers: HashMap<NodeId, NodeId> = HashMap::new();
+        for (op_id, node) in self.graph.iter() {
+            if let Node::Operator(op_node) = node {
+                for output_id in op_node.outputs().iter().flatten() {
+                    producers.insert(*output_id, op_id);
+                }
             }
         }
-    };
 
-    ($op:ident, reduce_axes, $attrs_method:ident) => {
-        impl ReadOp for ops::$op {
-            fn op_type() -> OperatorType {
-                OperatorType::$op
+        let mut used: HashSet<NodeId> = self.input_ids.iter().copied().collect();
+        let mut pending: Vec<NodeId> = self.output_ids.clone();
+        while let Some(id) = pending.pop() {
+            if !used.insert(id) {
+                continue;
             }
-
-            fn read(op: &OperatorNode) -> Result<Self, ReadOpError> {
-                let attrs = op.$attrs_method().ok_or(ReadOpError::AttrError)?;
-                let axes = attrs.axes().map(|axes| axes.iter().collect());
-                let op = ops::$op {
-                    axes,
-                    keep_dims: attrs.keep_dims(),
+            let Some(&op_id) = producers.get(&id) else {
+                continue;
+            };
+            if used.insert(op_id) {
+                let Some(Node::Operator(op_node)) = self.graph.get_node(op_id) else {
+                    continue;
                 };
-                Ok(op)
+                pending.extend(op_node.inputs().iter().flatten());
+                used.extend(op_node.outputs().iter().flatten());
             }
         }
-    };
-
-    ($op:ident, $attrs_method:ident, $read_op:expr) => {
-        impl ReadOp for ops::$op {
-            fn op_type() -> OperatorType {
-                OperatorType::$op
-            }
 
-            fn read(op: &OperatorNode) -> Result<Self, ReadOpError> {
-                let attrs = op.$attrs_method().ok_or(ReadOpError::AttrError)?;
-                #[allow(clippy::redundant_closure_call)]
-                let op = { $read_op(attrs)? };
-                Ok(op)
-            }
+        let unused: Vec<NodeId> = self
+            .graph
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| !used.contains(id))
+            .collect();
+        for &id in &unused {
+            self.remove_graph_node(id);
         }
-    };
-}
-
-impl_read_op!(Abs);
-impl_read_op!(Acos);
-impl_read_op!(Add);
-impl_read_op!(And);
-impl_read_op!(ArgMax, reduce_axis, attrs_as_arg_max_attrs);
-impl_read_op!(ArgMin, reduce_axis, attrs_as_arg_max_attrs);
-impl_read_op!(Asin);
-impl_read_op!(Atan);
-impl_read_op!(
-    AveragePool,
-    attrs_as_average_pool_attrs,
-    |attrs: sg::AveragePoolAttrs| {
-        let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
-        let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-        let strides = attrs
-            .strides()
-            .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-            .unwrap_or([1, 1]);
-
-        Ok(ops::AveragePool {
-            kernel_size,
-            padding,
-            count_include_pad: attrs.count_include_pad(),
-            strides,
-        })
+        unused.len()
     }
-);
-impl_read_op!(
-    BatchNormalization,
-    attrs_as_batch_normalization_attrs,
-    |attrs: sg::BatchNormalizationAttrs| {
-        Ok(ops::BatchNormalization {
-            epsilon: attrs.epsilon(),
-        })
-    }
-);
-impl_read_op!(Cast, attrs_as_cast_attrs, |attrs: sg::CastAttrs| {
-    let to = match attrs.to() {
-        sg::DataType::Int32 => DataType::Int32,
-        sg::DataType::Float => DataType::Float,
-        _ => DataType::Float,
-    };
-    Ok(ops::Cast { to })
-});
-impl_read_op!(Ceil);
-impl_read_op!(Clip);
-impl_read_op!(Concat, axis, attrs_as_concat_attrs);
-impl_read_op!(Conv, attrs_as_conv_attrs, |attrs: sg::ConvAttrs| {
-    let groups = attrs.groups() as usize;
-    let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-    let strides = vec_from_attr(attrs.strides(), &[1, 1]);
-    let dilations = vec_from_attr(attrs.dilations(), &[1, 1]);
-    Ok(ops::Conv {
-        groups,
-        padding,
-        strides,
-        dilations,
-    })
-});
-impl_read_op!(
-    ConstantOfShape,
-    attrs_as_constant_of_shape_attrs,
-    |attrs: sg::ConstantOfShapeAttrs| {
-        let value = if let Some(int_val) = attrs.value_as_int_scalar() {
-            Scalar::Int(int_val.value())
-        } else if let Some(float_val) = attrs.value_as_float_scalar() {
-            Scalar::Float(float_val.value())
-        } else {
-            Scalar::Int(0)
+
+    /// Permanently bind a model input to a fixed value.
+    ///
+    /// The input is converted to a constant and removed from
+    /// [Model::input_ids]. Operators which now depend only on constants are
+    /// evaluated once, and their outputs stored as constants, so that this
+    /// work is not repeated on each run. Nodes which are no longer needed to
+    /// compute the model's outputs are then removed, as with
+    /// [Model::remove_unused_nodes].
+    ///
+    /// This is useful when an input has the same value for every run in a
+    /// given deployment, such as a fixed image size.
+    pub fn set_constant_input(&mut self, id: NodeId, value: Output) -> Result<(), ModelEditError> {
+        if !self.input_ids.contains(&id) {
+            return Err(ModelEditError::InvalidNodeId);
+        }
+        match value {
+            Output::FloatTensor(t) => self.graph.replace_with_constant(id, t),
+            Output::IntTensor(t) => self.graph.replace_with_constant(id, t),
+            Output::Int64Tensor(t) => self.graph.replace_with_constant(id, t),
+            Output::StringTensor(t) => self.graph.replace_with_constant(id, t),
+            Output::Sequence(_) => {
+                return Err(ModelEditError::UnsupportedValue(
+                    "constant values cannot be sequences",
+                ))
+            }
         };
-        Ok(ops::ConstantOfShape { value })
-    }
-);
-impl_read_op!(
-    ConvTranspose,
-    attrs_as_conv_transpose_attrs,
-    |attrs: sg::ConvTransposeAttrs| {
-        let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-        let strides = vec_from_attr(attrs.strides(), &[1, 1]);
-        Ok(ops::ConvTranspose { padding, strides })
-    }
-);
-impl_read_op!(Cos);
-impl_read_op!(CumSum);
-impl_read_op!(Div);
-impl_read_op!(Elu, attrs_as_elu_attrs, |attrs: sg::EluAttrs| {
-    Ok(ops::Elu {
-        alpha: attrs.alpha(),
-    })
-});
-impl_read_op!(Equal);
-impl_read_op!(Erf);
-impl_read_op!(Exp);
-impl_read_op!(Expand);
-impl_read_op!(Flatten, axis, attrs_as_flatten_attrs);
-impl_read_op!(Floor);
-impl_read_op!(Gather, axis, attrs_as_gather_attrs);
-impl_read_op!(GatherElements, axis, attrs_as_gather_attrs);
-impl_read_op!(
-    GatherND,
-    attrs_as_gather_ndattrs,
-    |attrs: sg::GatherNDAttrs| {
-        Ok(ops::GatherND {
-            batch_dims: attrs.batch_dims() as usize,
-        })
-    }
-);
-impl_read_op!(Gemm, attrs_as_gemm_attrs, |attrs: sg::GemmAttrs| {
-    Ok(ops::Gemm {
-        alpha: attrs.alpha(),
-        beta: attrs.beta(),
-        transpose_a: attrs.transpose_a(),
-        transpose_b: attrs.transpose_b(),
-    })
-});
-impl_read_op!(GlobalAveragePool);
-impl_read_op!(Greater);
-impl_read_op!(GreaterOrEqual);
-impl_read_op!(GRU, attrs_as_gruattrs, |attrs: sg::GRUAttrs| {
-    let hidden_size = attrs.hidden_size() as usize;
-    let direction = match attrs.direction() {
-        sg::RNNDirection::Forward => Direction::Forward,
-        sg::RNNDirection::Reverse => Direction::Reverse,
-        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
-        _ => Direction::Forward,
-    };
+        self.input_ids.retain(|input_id| *input_id != id);
 
-    Ok(ops::GRU {
-        direction,
-        hidden_size,
-        linear_before_reset: attrs.linear_before_reset(),
-    })
-});
-impl_read_op!(
-    HardSigmoid,
-    attrs_as_hard_sigmoid_attrs,
-    |attrs: sg::HardSigmoidAttrs| {
-        Ok(ops::HardSigmoid {
-            alpha: attrs.alpha(),
-            beta: attrs.beta(),
-        })
+        fold_constants(&mut self.graph, &mut self.op_attrs);
+        self.node_ids
+            .retain(|_, node_id| self.graph.get_node(*node_id).is_some());
+        self.remove_unused_nodes();
+        Ok(())
     }
-);
-impl_read_op!(HardSwish);
-impl_read_op!(Identity);
-impl_read_op!(
-    InstanceNormalization,
-    attrs_as_batch_normalization_attrs,
-    |attrs: sg::BatchNormalizationAttrs| {
-        Ok(ops::InstanceNormalization {
-            epsilon: Some(attrs.epsilon()),
-        })
+
+    /// Quantize the weights of `MatMul` operators.
+    ///
+    /// See [quantize_model](crate::quantize::quantize_model).
+    pub(crate) fn quantize_weights(&mut self, options: &QuantizeOptions) -> usize {
+        quantize_weights(&mut self.graph, &mut self.op_attrs, options)
     }
-);
-impl_read_op!(
-    LayerNormalization,
-    attrs_as_layer_normalization_attrs,
-    |attrs: sg::LayerNormalizationAttrs| {
-        Ok(ops::LayerNormalization {
-            axis: attrs.axis() as isize,
-            epsilon: Some(attrs.epsilon()),
-        })
+
+    /// Return the operators whose activations can be quantized.
+    ///
+    /// See [quantize_model](crate::quantize::quantize_model).
+    pub(crate) fn activation_candidates(
+        &self,
+        options: &QuantizeOptions,
+    ) -> Vec<ActivationCandidate> {
+        activation_candidates(&self.graph, &self.op_attrs, options)
     }
-);
-impl_read_op!(
-    LeakyRelu,
-    attrs_as_leaky_relu_attrs,
-    |attrs: sg::LeakyReluAttrs| {
-        Ok(ops::LeakyRelu {
-            alpha: attrs.alpha(),
-        })
+
+    /// Replace operators with quantized operators, using calibrated
+    /// activation ranges.
+    ///
+    /// See [quantize_model](crate::quantize::quantize_model).
+    pub(crate) fn quantize_activations(
+        &mut self,
+        candidates: &[ActivationCandidate],
+        ranges: &HashMap<NodeId, (f32, f32)>,
+        options: &QuantizeOptions,
+    ) -> usize {
+        quantize_activations(
+            &mut self.graph,
+            &mut self.op_attrs,
+            candidates,
+            ranges,
+            options,
+        )
     }
-);
-impl_read_op!(Less);
-impl_read_op!(LessOrEqual);
-impl_read_op!(Log);
-impl_read_op!(LogSoftmax, axis, attrs_as_softmax_attrs);
-impl_read_op!(LSTM, attrs_as_lstmattrs, |attrs: sg::LSTMAttrs| {
-    let hidden_size = attrs.hidden_size() as usize;
-    let direction = match attrs.direction() {
-        sg::RNNDirection::Forward => Direction::Forward,
-        sg::RNNDirection::Reverse => Direction::Reverse,
-        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
-        _ => Direction::Forward,
-    };
-    Ok(ops::LSTM {
-        direction,
-        hidden_size,
-    })
-});
-impl_read_op!(MatMul);
-impl_read_op!(Max);
-impl_read_op!(
-    MaxPool,
-    attrs_as_max_pool_attrs,
-    |attrs: sg::MaxPoolAttrs| {
-        let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
-        let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-        let strides = attrs
-            .strides()
-            .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-            .unwrap_or([1, 1]);
-
-        Ok(ops::MaxPool {
-            kernel_size,
-            padding,
-            strides,
-        })
+
+    /// Return the total number of parameters in the model's weights.
+    pub fn total_params(&self) -> usize {
+        self.graph.total_params()
     }
-);
-impl_read_op!(Mean);
-impl_read_op!(Min);
-impl_read_op!(Mod, attrs_as_mod_attrs, |attrs: sg::ModAttrs| {
-    Ok(ops::Mod { fmod: attrs.fmod() })
-});
-impl_read_op!(Mul);
-impl_read_op!(Neg);
-impl_read_op!(
-    NonMaxSuppression,
-    attrs_as_non_max_suppression_attrs,
-    |attrs: sg::NonMaxSuppressionAttrs| {
-        let box_order = match attrs.box_order() {
-            sg::NMSBoxOrder::CenterWidthHeight => BoxOrder::CenterWidthHeight,
-            sg::NMSBoxOrder::TopLeftBottomRight => BoxOrder::TopLeftBottomRight,
-            _ => BoxOrder::TopLeftBottomRight,
-        };
-        Ok(ops::NonMaxSuppression { box_order })
-    }
-);
-impl_read_op!(NonZero);
-impl_read_op!(Not);
-impl_read_op!(OneHot, axis, attrs_as_one_hot_attrs);
-impl_read_op!(Or);
-impl_read_op!(Pad);
-impl_read_op!(Pow);
-
-#[cfg(feature = "random")]
-impl_read_op!(
-    RandomNormal,
-    attrs_as_random_normal_attrs,
-    |attrs: sg::RandomNormalAttrs| {
-        let shape = attrs
-            .shape()
-            .map(|shape| shape.iter().map(|size| size as usize).collect())
-            .unwrap_or_default();
 
-        Ok(ops::RandomNormal {
-            shape,
-            mean: attrs.mean(),
-            scale: attrs.scale(),
-            seed: attrs.seed(),
-        })
+    /// Convenience method that returns the expected input shape for the index'th input.
+    ///
+    /// The shape may contain a mix of fixed and symbolic dimensions.
+    pub fn input_shape(&self, index: usize) -> Option<Vec<Dimension>> {
+        let input_id = self.input_ids.get(index)?;
+        let node_info = self.node_info(*input_id)?;
+        node_info.shape()
     }
-);
-#[cfg(feature = "random")]
-impl_read_op!(
-    RandomNormalLike,
-    attrs_as_random_normal_like_attrs,
-    |attrs: sg::RandomNormalLikeAttrs| {
-        Ok(ops::RandomNormalLike {
-            mean: attrs.mean(),
-            scale: attrs.scale(),
-            seed: attrs.seed(),
-        })
+
+    /// Convenience method that returns the expected output shape for the index'th output.
+    ///
+    /// The shape may contain a mix of fixed and symbolic dimensions.
+    pub fn output_shape(&self, index: usize) -> Option<Vec<Dimension>> {
+        let output_id = self.output_ids.get(index)?;
+        let node_info = self.node_info(*output_id)?;
+        node_info.shape()
     }
-);
-#[cfg(feature = "random")]
-impl_read_op!(
-    RandomUniform,
-    attrs_as_random_uniform_attrs,
-    |attrs: sg::RandomUniformAttrs| {
-        let shape = attrs
-            .shape()
-            .map(|shape| shape.iter().map(|size| size as usize).collect())
-            .unwrap_or_default();
 
-        Ok(ops::RandomUniform {
-            shape,
-            high: attrs.high(),
-            low: attrs.low(),
-            seed: attrs.seed(),
-        })
+    /// Infer the shapes of values in the model, given the shapes of inputs.
+    ///
+    /// Shapes are propagated from the inputs through each operator without
+    /// running the model. Inputs which are not listed in `input_shapes` use
+    /// the shapes recorded in the model. Shapes may contain a mix of fixed and
+    /// symbolic dimensions.
+    ///
+    /// This can be used to check that inputs have compatible shapes before
+    /// running the model. If the shapes are incompatible, an error is
+    /// returned with the name of the operator where the problem occurs.
+    ///
+    /// Returns a map of value node ID to shape, for all values whose shape
+    /// could be determined.
+    pub fn infer_shapes(
+        &self,
+        input_shapes: &[(NodeId, Vec<Dimension>)],
+    ) -> Result<HashMap<NodeId, Vec<Dimension>>, RunError> {
+        let shapes = self.graph.infer_shapes(input_shapes)?;
+        Ok(shapes.into_iter().collect())
     }
-);
-#[cfg(feature = "random")]
-impl_read_op!(
-    RandomUniformLike,
-    attrs_as_random_uniform_like_attrs,
-    |attrs: sg::RandomUniformLikeAttrs| {
-        Ok(ops::RandomUniformLike {
-            high: attrs.high(),
-            low: attrs.low(),
-            seed: attrs.seed(),
-        })
+
+    /// Analyze the memory required to compute `outputs` given inputs with
+    /// the shapes in `input_shapes`, without running the model.
+    ///
+    /// The result reports the peak size of intermediate values during
+    /// execution, and the operators which make the largest allocations. This
+    /// can be used to check whether a model will fit in a memory-constrained
+    /// environment, such as WebAssembly, before deploying it. Shapes of
+    /// intermediate values are inferred as in [Model::infer_shapes]. If the
+    /// shape of some values cannot be determined, the reported sizes are a
+    /// lower bound (see [MemoryPlan::is_complete]).
+    ///
+    /// All inputs required to compute `outputs` must be specified.
+    pub fn memory_plan(
+        &self,
+        input_shapes: &[(NodeId, Vec<usize>)],
+        outputs: &[NodeId],
+    ) -> Result<MemoryPlan, RunError> {
+        self.graph.memory_plan(input_shapes, outputs)
     }
-);
-
-impl_read_op!(Range);
-impl_read_op!(Reciprocal);
-impl_read_op!(ReduceL2, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceMax, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceMean, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceMin, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceProd, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceSum, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(ReduceSumSquare, reduce_axes, attrs_as_reduce_mean_attrs);
-impl_read_op!(Relu);
-impl_read_op!(
-    Reshape,
-    attrs_as_reshape_attrs,
-    |attrs: sg::ReshapeAttrs| {
-        Ok(ops::Reshape {
-            allow_zero: attrs.allow_zero(),
-        })
+
+    /// Execute the model and return the outputs specified by `outputs`.
+    ///
+    /// This method allows for running a model with a variable number of inputs
+    /// and outputs of different types. See [Model::run_one] or [Model::run_n]
+    /// for the common case of running a model with a single or statically
+    /// known number of inputs and outputs.
+    ///
+    /// The input and output nodes are specified via IDs looked up via `find_node`.
+    /// Outputs can be any value in the graph, including intermediate values
+    /// which are not outputs of the model. See also
+    /// [`ModelOptions::with_optimization`].
+    pub fn run(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let opts = self.run_options(opts);
+        self.graph.run(inputs, outputs, Some(opts))
     }
-);
-impl_read_op!(Resize, attrs_as_resize_attrs, |attrs: sg::ResizeAttrs| {
-    let mode = match attrs.mode() {
-        sg::ResizeMode::Nearest => ResizeMode::Nearest,
-        sg::ResizeMode::Linear => ResizeMode::Linear,
-        _ => ResizeMode::Nearest,
-    };
-    let nearest_mode = match attrs.nearest_mode() {
-        sg::NearestMode::Floor => NearestMode::Floor,
-        sg::NearestMode::Ceil => NearestMode::Ceil,
-        sg::NearestMode::RoundPreferFloor => NearestMode::RoundPreferFloor,
-        sg::NearestMode::RoundPreferCeil => NearestMode::RoundPreferCeil,
-        _ => NearestMode::default(),
-    };
 
-    let coord_mode = match attrs.coord_mode() {
-        sg::CoordTransformMode::Asymmetric => CoordTransformMode::Asymmetric,
-        sg::CoordTransformMode::HalfPixel => CoordTransformMode::HalfPixel,
-        sg::CoordTransformMode::AlignCorners => CoordTransformMode::AlignCorners,
-        _ => CoordTransformMode::default(),
-    };
+    /// Execute the model asynchronously, yielding to the executor after each
+    /// operator.
+    ///
+    /// This is useful in environments such as the browser, where a long
+    /// blocking run would make the UI unresponsive, or in servers which need
+    /// to abort requests. To stop a run part way through, set
+    /// [RunOptions::cancel].
+    ///
+    /// Each operator is executed as a separate step, so some optimizations
+    /// which apply across operators in [Model::run] are not used. The
+    /// timing, profiling and logging options in [RunOptions] are ignored.
+    pub async fn run_async(
+        &self,
+        inputs: &[(NodeId, Input<'_>)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let opts = self.run_options(opts);
+        self.graph.run_async(inputs, outputs, Some(opts)).await
+    }
 
-    Ok(ops::Resize {
-        mode,
-        coord_mode,
-        nearest_mode,
-    })
-});
-impl_read_op!(Round);
-impl_read_op!(
-    ScatterElements,
-    attrs_as_scatter_elements_attrs,
-    |attrs: sg::ScatterElementsAttrs| {
-        Ok(ops::ScatterElements {
-            axis: attrs.axis() as isize,
-            reduction: convert_reduction(attrs.reduction())?,
-        })
+    /// Return the options for a run, applying any overrides from the
+    /// environment.
+    fn run_options(&self, opts: Option<RunOptions>) -> RunOptions {
+        let mut opts = opts.unwrap_or_default();
+        if let Some(timing_var) = env::var_os("RTEN_TIMING") {
+            let timing_var = timing_var.to_string_lossy();
+            parse_timing_config(&timing_var, &mut opts);
+        }
+        opts
     }
-);
-impl_read_op!(
-    ScatterND,
-    attrs_as_scatter_ndattrs,
-    |attrs: sg::ScatterNDAttrs| {
-        Ok(ops::ScatterND {
-            reduction: convert_reduction(attrs.reduction())?,
+
+    /// Create a session for running the model repeatedly with a fixed set of
+    /// inputs and outputs.
+    ///
+    /// This is useful for latency-sensitive applications which run a model
+    /// many times, as it avoids the cost of planning execution on each run
+    /// and re-uses buffers across runs. See [Session].
+    pub fn prepare(&self, inputs: &[NodeId], outputs: &[NodeId]) -> Result<Session<'_>, RunError> {
+        Ok(Session {
+            model: self,
+            plan: self.graph.prepare(inputs, outputs)?,
+            arena: BufferArena::new(),
         })
     }
-);
-impl_read_op!(Shape);
-impl_read_op!(Sigmoid);
-impl_read_op!(Sign);
-impl_read_op!(Sin);
-impl_read_op!(Size);
-impl_read_op!(Slice);
-impl_read_op!(Softmax, axis, attrs_as_softmax_attrs);
-impl_read_op!(Softplus);
-impl_read_op!(Split, axis, attrs_as_split_attrs);
-impl_read_op!(Sqrt);
-impl_read_op!(Squeeze);
-impl_read_op!(Sub);
-impl_read_op!(Sum);
-impl_read_op!(Tan);
-impl_read_op!(Tanh);
-impl_read_op!(Tile);
-impl_read_op!(TopK, attrs_as_top_kattrs, |attrs: sg::TopKAttrs| {
-    let largest = attrs.largest();
-    let sorted = attrs.sorted();
-    let axis = attrs.axis();
-    Ok(ops::TopK {
-        axis: Some(axis as isize),
-        largest,
-        sorted,
-    })
-});
-impl_read_op!(
-    Transpose,
-    attrs_as_transpose_attrs,
-    |attrs: sg::TransposeAttrs| {
-        let perm = attrs
-            .perm()
-            .map(|perm| perm.iter().map(|dim| dim as usize).collect());
-        Ok(ops::Transpose { perm })
-    }
-);
-impl_read_op!(Trilu, attrs_as_trilu_attrs, |attrs: sg::TriluAttrs| {
-    Ok(ops::Trilu {
-        upper: attrs.upper(),
-    })
-});
-impl_read_op!(Unsqueeze);
-impl_read_op!(Where);
-impl_read_op!(Xor);
 
-/// Registry used to instantiate operators when loading a model file.
-///
-/// New registries have no operators registered by default. If you want to get
-/// one that has all the built-in operators pre-registered, use
-/// [OpRegistry::with_all_ops]. Alternatively you can create a new registry and
-/// just selectively register the operators you need using
-/// [OpRegistry::register_op]. This can be useful to reduce binary sizes or
-/// customize the implementation of an operator.
-#[derive(Default)]
-pub struct OpRegistry {
-    ops: HashMap<sg::OperatorType, Box<ReadOpFunction>>,
-}
+    /// Run a model, transferring ownership of the inputs to the model.
+    ///
+    /// This is like [Model::run], except that operators can update the
+    /// inputs in-place once they are no longer needed by other parts of the
+    /// model. For models whose output has the same shape as the input, such as
+    /// denoising or enhancement models, this can allow the output to re-use
+    /// the input buffer instead of allocating a new one. This reduces peak
+    /// memory usage on memory-constrained devices.
+    pub fn run_owned(
+        &self,
+        inputs: Vec<(NodeId, Output)>,
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<Output>, RunError> {
+        let opts = self.run_options(opts);
+        self.graph.run_owned(&[], inputs, outputs, Some(opts))
+    }
 
-impl OpRegistry {
-    /// Create a new empty registry.
-    pub fn new() -> OpRegistry {
-        OpRegistry {
-            ops: HashMap::new(),
-        }
+    /// Run a model and retrieve `N` outputs.
+    ///
+    /// This is a simplified version of [Model::run] for the common case of
+    /// executing a model with a statically known number of outputs.
+    pub fn run_n<const N: usize>(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: [NodeId; N],
+        opts: Option<RunOptions>,
+    ) -> Result<[Output; N], RunError> {
+        let result = self.run(inputs, &outputs, opts)?;
+        Ok(result.try_into().expect("wrong output count"))
     }
 
-    /// Register the default/built-in implementation of an operator.
-    pub fn register_op<Op: ReadOp + 'static>(&mut self) {
-        self.register_op_with_factory(
-            Op::op_type(),
-            Box::new(|op: &OperatorNode| Op::read_boxed(op)),
-        );
+    /// Run a model with a single input and output.
+    ///
+    /// This is a simplified version of [Model::run] for the common case of
+    /// executing a model with a single input and output.
+    pub fn run_one(&self, input: Input, opts: Option<RunOptions>) -> Result<Output, RunError> {
+        let &input_id = self.input_ids().first().ok_or(RunError::InvalidNodeId)?;
+        let &output_id = self.output_ids().first().ok_or(RunError::InvalidNodeId)?;
+        self.run_n(&[(input_id, input)], [output_id], opts)
+            .map(|[result]| result)
     }
 
-    /// Deserialize an operator from a model file using the operators in the
-    /// registry.
-    fn read_op(&self, op: &OperatorNode) -> ReadOpResult {
-        self.ops
-            .get(&op.type_())
-            .ok_or_else(|| {
-                ReadOpError::UnsupportedOperator(
-                    op.type_().variant_name().unwrap_or("(unknown)").to_string(),
-                )
-            })
-            .and_then(|read_fn| read_fn(op))
+    /// Run a model with inputs and outputs identified by name.
+    ///
+    /// This is like [Model::run], except that nodes are looked up by name
+    /// instead of needing to be resolved to IDs using [Model::node_id] first.
+    /// Outputs are returned in a [NamedOutputs] collection which can be used
+    /// to retrieve each output by name, optionally converting it to a
+    /// specific tensor type.
+    pub fn run_map(
+        &self,
+        inputs: &HashMap<&str, Input>,
+        outputs: &[&str],
+        opts: Option<RunOptions>,
+    ) -> Result<NamedOutputs, RunError> {
+        let inputs: Vec<(NodeId, Input)> = inputs
+            .iter()
+            .map(|(name, input)| Ok((self.node_id(name)?, input.clone())))
+            .collect::<Result<_, RunError>>()?;
+        let output_ids: Vec<NodeId> = outputs
+            .iter()
+            .map(|name| self.node_id(name))
+            .collect::<Result<_, _>>()?;
+        let values = self.run(&inputs, &output_ids, opts)?;
+        Ok(NamedOutputs {
+            outputs: outputs
+                .iter()
+                .map(|name| name.to_string())
+                .zip(values)
+                .collect(),
+        })
     }
 
-    /// Register an operator with a custom factory to deserialize it from a
-    /// model file.
-    fn register_op_with_factory(
-        &mut self,
-        op_type: sg::OperatorType,
-        factory: Box<ReadOpFunction>,
-    ) {
-        self.ops.insert(op_type, factory);
+    /// Run the model using an incomplete set of inputs.
+    ///
+    /// Unlike [`run`](Model::run) this will not fail if some values required to
+    /// compute `outputs` are missing. Instead it will compute as many
+    /// intermediate values as possible using the provided inputs and return the
+    /// leaf values of the subgraph that was executed. These intermediate
+    /// outputs can then be passed to future calls to [`run`](Model::run) when
+    /// the other inputs are available.
+    ///
+    /// This method can speed up autoregressive / recurrent models where the
+    /// model is run in a loop during inference, but some inputs are constant
+    /// across each iteration of the loop. In such cases, execution times can be
+    /// reduced by performing a `partial_run` once outside the loop, providing
+    /// the constant inputs, and the results can be provided together with the
+    /// the remaining inputs to `run` calls inside the loop.
+    pub fn partial_run(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+    ) -> Result<Vec<(NodeId, Output)>, RunError> {
+        self.graph.partial_run(inputs, outputs, opts)
     }
 
-    /// Create a new registry with all built-in operators registered.
-    pub fn with_all_ops() -> OpRegistry {
-        let mut reg = OpRegistry::new();
+    /// Return a human-readable description of the sequence of operators that
+    /// will be executed to compute `outputs` from `inputs`.
+    ///
+    /// This is useful to understand how a model will be executed, without
+    /// running it. The same information can be printed as part of a run by
+    /// enabling [RunOptions::dump_plan].
+    pub fn plan_summary(&self, inputs: &[NodeId], outputs: &[NodeId]) -> Result<String, RunError> {
+        self.graph.plan_summary(inputs, outputs)
+    }
 
-        macro_rules! register_op {
-            ($op:ident) => {
-                reg.register_op::<ops::$op>()
-            };
-        }
+    /// Serialize the model's graph in the ONNX protobuf format.
+    ///
+    /// This is intended for debugging and interop, for example to inspect the
+    /// graph in a viewer such as [Netron](https://netron.app) or to compare
+    /// results with other runtimes. The exported graph reflects the model as
+    /// loaded, including any nodes added by input adapters (see
+    /// [`ModelOptions::with_input_adapter`]).
+    ///
+    /// Operators which exist in ONNX are exported in the default domain, with
+    /// attributes converted to their ONNX names where these differ. Operators
+    /// specific to RTen are exported with the `ai.rten` domain. Since RTen
+    /// models don't record the element types of inputs and outputs, these are
+    /// exported as float.
+    pub fn export_onnx(&self) -> Vec<u8> {
+        export_onnx(
+            &self.graph,
+            &self.input_ids,
+            &self.output_ids,
+            &self.op_attrs,
+        )
+    }
 
-        register_op!(Abs);
-        register_op!(Acos);
-        register_op!(Add);
-        register_op!(And);
-        register_op!(ArgMax);
-        register_op!(ArgMin);
-        register_op!(Asin);
-        register_op!(Atan);
-        register_op!(AveragePool);
-        register_op!(BatchNormalization);
-        register_op!(Cast);
-        register_op!(Ceil);
-        register_op!(Clip);
-        register_op!(Concat);
-        register_op!(Conv);
-        register_op!(ConstantOfShape);
-        register_op!(ConvTranspose);
-        register_op!(Cos);
-        register_op!(CumSum);
-        register_op!(Div);
-        register_op!(Elu);
-        register_op!(Equal);
-        register_op!(Erf);
-        register_op!(Exp);
-        register_op!(Expand);
-        register_op!(Flatten);
-        register_op!(Floor);
-        register_op!(Gather);
-        register_op!(GatherElements);
-        register_op!(GatherND);
-        register_op!(Gemm);
-        register_op!(GlobalAveragePool);
-        register_op!(Greater);
-        register_op!(GreaterOrEqual);
-        register_op!(GRU);
-        register_op!(HardSigmoid);
-        register_op!(HardSwish);
-        register_op!(Identity);
-        register_op!(InstanceNormalization);
-        register_op!(LayerNormalization);
-        register_op!(LeakyRelu);
-        register_op!(Less);
-        register_op!(LessOrEqual);
-        register_op!(Log);
-        register_op!(LogSoftmax);
-        register_op!(LSTM);
-        register_op!(MatMul);
-        register_op!(Max);
-        register_op!(MaxPool);
-        register_op!(Mean);
-        register_op!(Min);
-        register_op!(Mod);
-        register_op!(Mul);
-        register_op!(Neg);
-        register_op!(NonMaxSuppression);
-        register_op!(NonZero);
-        register_op!(Not);
-        register_op!(OneHot);
-        register_op!(Or);
-        register_op!(Pad);
-        register_op!(Pow);
-
-        #[cfg(feature = "random")]
-        register_op!(RandomNormal);
-        #[cfg(feature = "random")]
-        register_op!(RandomNormalLike);
-        #[cfg(feature = "random")]
-        register_op!(RandomUniform);
-        #[cfg(feature = "random")]
-        register_op!(RandomUniformLike);
-
-        register_op!(Range);
-        register_op!(Reciprocal);
-        register_op!(ReduceL2);
-        register_op!(ReduceMax);
-        register_op!(ReduceMean);
-        register_op!(ReduceMin);
-        register_op!(ReduceProd);
-        register_op!(ReduceSum);
-        register_op!(ReduceSumSquare);
-        register_op!(Relu);
-        register_op!(Reshape);
-        register_op!(Resize);
-        register_op!(Round);
-        register_op!(ScatterElements);
-        register_op!(ScatterND);
-        register_op!(Shape);
-        register_op!(Sigmoid);
-        register_op!(Sign);
-        register_op!(Sin);
-        register_op!(Size);
-        register_op!(Slice);
-        register_op!(Softmax);
-        register_op!(Softplus);
-        register_op!(Split);
-        register_op!(Sqrt);
-        register_op!(Squeeze);
-        register_op!(Sub);
-        register_op!(Sum);
-        register_op!(Tan);
-        register_op!(Tanh);
-        register_op!(Tile);
-        register_op!(TopK);
-        register_op!(Transpose);
-        register_op!(Trilu);
-        register_op!(Unsqueeze);
-        register_op!(Where);
-        register_op!(Xor);
-
-        reg
+    /// Serialize the model in the `.rten` format.
+    ///
+    /// The result can be loaded using [Model::load] or written to a file. Like
+    /// [Model::export_onnx], the serialized graph reflects the model as
+    /// loaded, including any nodes added by input adapters and graph
+    /// optimizations. Constants are written uncompressed.
+    ///
+    /// Fails if the model contains values which cannot be stored in the
+    /// `.rten` format, such as int64 constants.
+    ///
+    /// To construct a new model from scratch, use [ModelBuilder](crate::ModelBuilder).
+    pub fn serialize(&self) -> Result<Vec<u8>, ModelBuildError> {
+        let mut builder = ModelBuilder::new();
+        builder.add_graph(
+            &self.graph,
+            &self.input_ids,
+            &self.output_ids,
+            &self.op_attrs,
+        )?;
+        builder.add_model_metadata(&self.metadata);
+        Ok(builder.finish())
     }
 }
 
-/// Error type for errors that occur when de-serializing an operator.
-#[derive(Debug, PartialEq)]
-pub enum ReadOpError {
-    /// The operator attributes were missing or of the wrong type.
-    AttrError,
-    /// The operator type is incorrect or unsupported.
-    UnsupportedOperator(String),
+/// Return the name used in an [OpAttrs] dictionary for a FlatBuffers enum
+/// attribute value.
+macro_rules! enum_attr_name {
+    ($val:expr, $enum:ident, { $($variant:ident => $name:literal),* $(,)? }) => {
+        match $val {
+            $(sg::$enum::$variant => $name,)*
+            _ => return Err(ReadOpError::AttrError),
+        }
+    };
 }
 
-impl Display for ReadOpError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ReadOpError::AttrError => write!(f, "invalid attributes for operator"),
-            ReadOpError::UnsupportedOperator(name) => {
-                write!(f, "operator {name} is not supported or not enabled")
-            }
-        }
-    }
+/// Convert the attributes of an operator node in a `.rten` model file into a
+/// generic attribute dictionary.
+/// Graph loaded from a model file, together with the information about its
+/// nodes that is not stored in the [Graph] itself.
+struct LoadedGraph {
+    graph: Graph,
+    node_ids: HashMap<String, NodeId>,
+    input_ids: Vec<NodeId>,
+    output_ids: Vec<NodeId>,
+    op_attrs: HashMap<NodeId, OpAttrs>,
 }
 
-impl Error for ReadOpError {}
+/// Load a graph from a model file.
+///
+/// Subgraphs referenced by operator attributes are loaded recursively.
+fn load_graph(
+    storage: &Arc<ConstantStorage>,
+    options: &ModelOptions,
+    sg_graph: sg::Graph,
+) -> Result<LoadedGraph, ModelLoadError> {
+    let registry = &options.registry;
+    let mut graph = Graph::new();
 
-fn convert_reduction(r: sg::ScatterReduction) -> Result<Option<ScatterReduction>, ReadOpError> {
-    let reduction = match r {
-        sg::ScatterReduction::None => None,
-        sg::ScatterReduction::Add => Some(ScatterReduction::Add),
-        sg::ScatterReduction::Mul => Some(ScatterReduction::Mul),
-        sg::ScatterReduction::Min => Some(ScatterReduction::Min),
-        sg::ScatterReduction::Max => Some(ScatterReduction::Max),
-        _ => {
-            return Err(ReadOpError::AttrError);
-        }
-    };
-    Ok(reduction)
-}
+    let node_count = sg_graph.nodes().map(|ns| ns.len()).unwrap_or(0);
 
-/// Errors reported by [Model::load].
-#[derive(Debug)]
-pub enum ModelLoadError {
-    SchemaVersionUnsupported,
+    // Map of model node name to graph node ID
+    let mut node_id_from_name: HashMap<String, NodeId> = HashMap::with_capacity(node_count);
 
-    /// An error occurred reading the file from disk.
-    ReadFailed(std::io::Error),
+    // Map of model node index to graph node ID
+    let mut node_id_from_index: HashMap<usize, NodeId> = HashMap::with_capacity(node_count);
 
-    /// An error occurred parsing the FlatBuffers file.
-    ParseFailed(flatbuffers::InvalidFlatbuffer),
+    // Map of operator node ID to the operator's attributes
+    let mut op_attrs: HashMap<NodeId, OpAttrs> = HashMap::new();
+
+    let mut add_node_id = |name: Option<&str>, graph_node| {
+        if let Some(name) = name {
+            node_id_from_name.insert(name.to_string(), graph_node);
+        }
+    };
+
+    let input_ids: Vec<NodeId> = sg_graph
+        .inputs()
+        .map(|ids| ids.iter().map(|id| id as NodeId).collect())
+        .unwrap_or_default();
+
+    let output_ids: Vec<NodeId> = sg_graph
+        .outputs()
+        .map(|ids| ids.iter().map(|id| id as NodeId).collect())
+        .unwrap_or_default();
+
+    if let Some(nodes) = sg_graph.nodes() {
+        for (node_index, node) in nodes.iter().enumerate() {
+            if let Some(operator) = node.data_as_operator_node() {
+                let op_type = operator.type_().variant_name().ok_or_else(|| {
+                    ModelLoadError::OperatorInvalid(ReadOpError::UnsupportedOperator(
+                        "(unknown)".to_string(),
+                    ))
+                })?;
+                let mut attrs =
+                    read_op_attrs(&operator).map_err(ModelLoadError::OperatorInvalid)?;
+
+                // Graph-valued attributes are loaded here rather than in
+                // `read_op_attrs`, as they need the registry and model storage.
+                if let Some(scan_attrs) = operator.attrs_as_scan_attrs() {
+                    let body = load_subgraph(storage, options, scan_attrs.body())?;
+                    attrs.insert("body", body);
+                }
+                let custom_type = operator
+                    .attrs_as_custom_operator_attrs()
+                    .map(|custom_attrs| {
+                        (
+                            custom_attrs.domain().unwrap_or_default(),
+                            custom_attrs.op_type().unwrap_or_default(),
+                        )
+                    });
+                let op = if let Some((domain, custom_op_type)) = custom_type {
+                    registry.read_custom_op(domain, custom_op_type, &attrs)
+                } else {
+                    registry.read_op(op_type, &attrs)
+                }
+                .map_err(ModelLoadError::OperatorInvalid)?;
+
+                let mut inputs: Vec<Option<NodeId>> = Vec::new();
+                if let Some(op_input_ids) = operator.inputs() {
+                    for node_index in op_input_ids.iter() {
+                        if node_index < 0 {
+                            inputs.push(None);
+                            continue;
+                        }
+                        let index_usize = node_index as usize;
+                        if let Some(node_id) = node_id_from_index.get(&index_usize) {
+                            inputs.push(Some(*node_id))
+                        } else {
+                            return Err(ModelLoadError::GraphError(
+                                "operator input is invalid".to_string(),
+                            ));
+                        }
+                    }
+                }
+
+                let mut outputs: Vec<Option<NodeId>> = Vec::new();
+                if let Some(op_output_ids) = operator.outputs() {
+                    for node_index in op_output_ids.iter() {
+                        if node_index < 0 {
+                            outputs.push(None);
+                            continue;
+                        }
+                        let index_usize = node_index as usize;
+                        if let Some(node_id) = node_id_from_index.get(&index_usize) {
+                            outputs.push(Some(*node_id))
+                        } else {
+                            return Err(ModelLoadError::GraphError(
+                                "operator output is invalid".to_string(),
+                            ));
+                        }
+                    }
+                }
+
+                let graph_node = graph.add_op(node.name(), op, &inputs, &outputs);
+                if let Some((domain, custom_op_type)) = custom_type {
+                    graph.set_custom_op_type(graph_node, domain, custom_op_type);
+                }
+                op_attrs.insert(graph_node, attrs);
+
+                add_node_id(node.name(), graph_node);
+                node_id_from_index.insert(node_index, graph_node);
+            } else if let Some(value_node) = node.data_as_value_node() {
+                let shape: Option<Vec<Dimension>> = value_node.shape().map(|shape| {
+                    shape
+                        .iter()
+                        .map(|dim| {
+                            if let Some(name) = dim.name() {
+                                Dimension::Symbolic(name.to_string())
+                            } else {
+                                Dimension::Fixed(dim.value() as usize)
+                            }
+                        })
+                        .collect()
+                });
+                let graph_node = if value_node.optional() {
+                    graph.add_optional_value(node.name(), shape)
+                } else {
+                    graph.add_value(node.name(), shape)
+                };
+                if let Some(dtype) = value_node.dtype().and_then(convert_dtype) {
+                    graph.set_value_dtype(graph_node, dtype);
+                }
+
+                add_node_id(node.name(), graph_node);
+                node_id_from_index.insert(node_index, graph_node);
+            } else if let Some(constant) = node.data_as_constant_node() {
+                let shape: Vec<usize> = constant.shape().iter().map(|x| x as usize).collect();
+                let graph_node = if let Some(float_data) = constant.data_as_float_data() {
+                    let const_data =
+                        constant_node_from_flatbuffers_vec(storage, float_data.data(), &shape);
+                    graph.add_constant(node.name(), const_data)
+                } else if let Some(int_data) = constant.data_as_int_data() {
+                    let const_data =
+                        constant_node_from_flatbuffers_vec(storage, int_data.data(), &shape);
+                    graph.add_constant(node.name(), const_data)
+                } else if let Some(string_data) = constant.data_as_string_data() {
+                    // Strings are variable-length, so unlike numeric data
+                    // they are copied out of the model buffer.
+                    let elts: Vec<String> =
+                        string_data.data().iter().map(|s| s.to_string()).collect();
+                    let const_data = Tensor::from_data(&shape, elts);
+                    graph.add_constant(node.name(), const_data)
+                } else if let Some(bf16_data) = constant.data_as_bfloat_16_data() {
+                    let const_data =
+                        bf16_constant_node_from_flatbuffers_vec(storage, bf16_data.data(), &shape);
+                    graph.add_constant(node.name(), const_data)
+                } else if let Some(compressed_data) = constant.data_as_compressed_data() {
+                    add_compressed_constant(&mut graph, node.name(), &shape, compressed_data)?
+                } else {
+                    return Err(ModelLoadError::GraphError(
+                        "unsupported constant data type".to_string(),
+                    ));
+                };
+
+                add_node_id(node.name(), graph_node);
+                node_id_from_index.insert(node_index, graph_node);
+            } else {
+                return Err(ModelLoadError::GraphError("unknown node type".to_string()));
+            }
+        }
+    }
+
+    Ok(LoadedGraph {
+        graph,
+        node_ids: node_id_from_name,
+        input_ids,
+        output_ids,
+        op_attrs,
+    })
+}
+
+/// Load a subgraph, such as the body of a control flow operator.
+fn load_subgraph(
+    storage: &Arc<ConstantStorage>,
+    options: &ModelOptions,
+    sg_graph: sg::Graph,
+) -> Result<Subgraph, ModelLoadError> {
+    let LoadedGraph {
+        mut graph,
+        input_ids,
+        output_ids,
+        mut op_attrs,
+        ..
+    } = load_graph(storage, options, sg_graph)?;
+    if options.optimize {
+        fuse_gelu(&mut graph, &options.registry, &mut op_attrs, &output_ids);
+        fuse_attention(&mut graph, &options.registry, &mut op_attrs, &output_ids);
+    }
+    if options.prepack_weights {
+        graph.prepack_weights();
+    }
+    Ok(Subgraph::new(graph, input_ids, output_ids, op_attrs))
+}
+
+/// Convert a data type from a model file to the corresponding runtime type.
+fn convert_dtype(dtype: sg::DataType) -> Option<DataType> {
+    Some(match dtype {
+        sg::DataType::Int32 => DataType::Int32,
+        sg::DataType::Float => DataType::Float,
+        sg::DataType::Int64 => DataType::Int64,
+        sg::DataType::String => DataType::String,
+        sg::DataType::Int8 => DataType::Int8,
+        sg::DataType::UInt8 => DataType::UInt8,
+        sg::DataType::Bool => DataType::Bool,
+        sg::DataType::BFloat16 => DataType::BFloat16,
+        _ => return None,
+    })
+}
+
+fn read_op_attrs(op: &OperatorNode) -> Result<OpAttrs, ReadOpError> {
+    let mut attrs = OpAttrs::new();
+
+    // Get the attributes table of type `$attrs_method` or fail if the node
+    // has the wrong attributes type.
+    macro_rules! attrs_table {
+        ($attrs_method:ident) => {
+            op.$attrs_method().ok_or(ReadOpError::AttrError)?
+        };
+    }
+
+    // Copy an optional vector field into the dictionary.
+    macro_rules! insert_vec {
+        ($name:expr, $vec:expr) => {
+            if let Some(vec) = $vec {
+                attrs.insert($name, vec.iter().collect::<Vec<_>>());
+            }
+        };
+    }
+
+    let pad_mode_name = |mode: sg::PadMode| -> Result<&'static str, ReadOpError> {
+        Ok(enum_attr_name!(mode, PadMode, { Same => "same", Fixed => "fixed" }))
+    };
+    let direction_name = |dir: sg::RNNDirection| -> Result<&'static str, ReadOpError> {
+        Ok(enum_attr_name!(dir, RNNDirection, {
+            Forward => "forward",
+            Reverse => "reverse",
+            Bidirectional => "bidirectional",
+        }))
+    };
+    let quantized_dtype_name = |dtype: sg::DataType| -> Result<&'static str, ReadOpError> {
+        Ok(enum_attr_name!(dtype, DataType, { Int8 => "int8", UInt8 => "uint8" }))
+    };
+    let reduction_name = |r: sg::ScatterReduction| -> Result<&'static str, ReadOpError> {
+        Ok(enum_attr_name!(r, ScatterReduction, {
+            None => "none",
+            Add => "add",
+            Mul => "mul",
+            Min => "min",
+            Max => "max",
+        }))
+    };
+
+    match op.attrs_type() {
+        sg::OperatorAttrs::NONE => {}
+        sg::OperatorAttrs::ArgMaxAttrs => {
+            let a = attrs_table!(attrs_as_arg_max_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("keep_dims", a.keep_dims());
+        }
+        sg::OperatorAttrs::AveragePoolAttrs => {
+            let a = attrs_table!(attrs_as_average_pool_attrs);
+            insert_vec!("kernel_size", Some(a.kernel_size()));
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            insert_vec!("strides", a.strides());
+            attrs.insert("count_include_pad", a.count_include_pad());
+            attrs.insert("ceil_mode", a.ceil_mode());
+            insert_vec!("dilations", a.dilations());
+        }
+        sg::OperatorAttrs::BatchNormalizationAttrs => {
+            let a = attrs_table!(attrs_as_batch_normalization_attrs);
+            attrs.insert("epsilon", a.epsilon());
+        }
+        sg::OperatorAttrs::BitShiftAttrs => {
+            let a = attrs_table!(attrs_as_bit_shift_attrs);
+            let direction = enum_attr_name!(a.direction(), BitShiftDirection, {
+                Left => "left",
+                Right => "right",
+            });
+            attrs.insert("direction", direction);
+        }
+        sg::OperatorAttrs::CastAttrs => {
+            let a = attrs_table!(attrs_as_cast_attrs);
+            let to = enum_attr_name!(a.to(), DataType, {
+                Int32 => "int32",
+                Float => "float",
+                Int64 => "int64",
+                String => "string",
+                Int8 => "int8",
+                UInt8 => "uint8",
+                Bool => "bool",
+            });
+            attrs.insert("to", to);
+        }
+        sg::OperatorAttrs::ChannelShuffleAttrs => {
+            let a = attrs_table!(attrs_as_channel_shuffle_attrs);
+            attrs.insert("groups", a.groups());
+        }
+        sg::OperatorAttrs::ConcatAttrs => {
+            let a = attrs_table!(attrs_as_concat_attrs);
+            attrs.insert("axis", a.axis());
+        }
+        sg::OperatorAttrs::ConcatFromSequenceAttrs => {
+            let a = attrs_table!(attrs_as_concat_from_sequence_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("new_axis", a.new_axis());
+        }
+        sg::OperatorAttrs::ConstantOfShapeAttrs => {
+            let a = attrs_table!(attrs_as_constant_of_shape_attrs);
+            if let Some(int_val) = a.value_as_int_scalar() {
+                attrs.insert("value", int_val.value());
+            } else if let Some(float_val) = a.value_as_float_scalar() {
+                attrs.insert("value", float_val.value());
+            }
+        }
+        sg::OperatorAttrs::ConvAttrs => {
+            let a = attrs_table!(attrs_as_conv_attrs);
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            attrs.insert("groups", a.groups());
+            insert_vec!("strides", a.strides());
+            insert_vec!("dilations", a.dilations());
+        }
+        sg::OperatorAttrs::ConvTransposeAttrs => {
+            let a = attrs_table!(attrs_as_conv_transpose_attrs);
+            insert_vec!("strides", a.strides());
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            insert_vec!("dilations", a.dilations());
+            attrs.insert("groups", a.groups());
+            insert_vec!("output_padding", a.output_padding());
+            insert_vec!("output_shape", a.output_shape());
+        }
+        sg::OperatorAttrs::CustomOperatorAttrs => {
+            let a = attrs_table!(attrs_as_custom_operator_attrs);
+            for attr in a.attrs().into_iter().flatten() {
+                let name = attr.name().ok_or(ReadOpError::AttrError)?;
+                match attr.value_type() {
+                    sg::CustomAttrType::Int => attrs.insert(name, attr.int_value()),
+                    sg::CustomAttrType::Float => attrs.insert(name, attr.float_value()),
+                    sg::CustomAttrType::String => {
+                        attrs.insert(name, attr.string_value().unwrap_or_default())
+                    }
+                    sg::CustomAttrType::Ints => insert_vec!(name, attr.ints()),
+                    sg::CustomAttrType::Floats => insert_vec!(name, attr.floats()),
+                    _ => return Err(ReadOpError::InvalidAttr(name.to_string())),
+                }
+            }
+        }
+        sg::OperatorAttrs::DropoutAttrs => {
+            let a = attrs_table!(attrs_as_dropout_attrs);
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+        }
+        sg::OperatorAttrs::EluAttrs => {
+            let a = attrs_table!(attrs_as_elu_attrs);
+            attrs.insert("alpha", a.alpha());
+        }
+        sg::OperatorAttrs::EyeLikeAttrs => {
+            let a = attrs_table!(attrs_as_eye_like_attrs);
+            if let Some(dtype) = a.dtype() {
+                let dtype = enum_attr_name!(dtype, DataType, {
+                    Int32 => "int32",
+                    Float => "float",
+                    Int64 => "int64",
+                    String => "string",
+                    Int8 => "int8",
+                    UInt8 => "uint8",
+                    Bool => "bool",
+                });
+                attrs.insert("dtype", dtype);
+            }
+            attrs.insert("k", a.k());
+        }
+        sg::OperatorAttrs::FlattenAttrs => {
+            let a = attrs_table!(attrs_as_flatten_attrs);
+            attrs.insert("axis", a.axis());
+        }
+        sg::OperatorAttrs::GatherAttrs => {
+            let a = attrs_table!(attrs_as_gather_attrs);
+            attrs.insert("axis", a.axis());
+        }
+        sg::OperatorAttrs::GatherNDAttrs => {
+            let a = attrs_table!(attrs_as_gather_ndattrs);
+            attrs.insert("batch_dims", a.batch_dims());
+        }
+        sg::OperatorAttrs::GatherBlockQuantizedAttrs => {
+            let a = attrs_table!(attrs_as_gather_block_quantized_attrs);
+            attrs.insert("bits", a.bits());
+            attrs.insert("block_size", a.block_size());
+        }
+        sg::OperatorAttrs::GeluAttrs => {
+            let a = attrs_table!(attrs_as_gelu_attrs);
+            attrs.insert("approximate", a.approximate());
+        }
+        sg::OperatorAttrs::GemmAttrs => {
+            let a = attrs_table!(attrs_as_gemm_attrs);
+            attrs.insert("alpha", a.alpha());
+            attrs.insert("beta", a.beta());
+            attrs.insert("transpose_a", a.transpose_a());
+            attrs.insert("transpose_b", a.transpose_b());
+        }
+        sg::OperatorAttrs::GRUAttrs => {
+            let a = attrs_table!(attrs_as_gruattrs);
+            attrs.insert("direction", direction_name(a.direction())?);
+            attrs.insert("hidden_size", a.hidden_size());
+            attrs.insert("linear_before_reset", a.linear_before_reset());
+        }
+        sg::OperatorAttrs::GridSampleAttrs => {
+            let a = attrs_table!(attrs_as_grid_sample_attrs);
+            let mode = enum_attr_name!(a.mode(), ResizeMode, {
+                Nearest => "nearest",
+                Linear => "linear",
+            });
+            let padding_mode = enum_attr_name!(a.padding_mode(), GridSamplePadding, {
+                Zeros => "zeros",
+                Border => "border",
+                Reflection => "reflection",
+            });
+            attrs.insert("mode", mode);
+            attrs.insert("padding_mode", padding_mode);
+            attrs.insert("align_corners", a.align_corners());
+        }
+        sg::OperatorAttrs::HardSigmoidAttrs => {
+            let a = attrs_table!(attrs_as_hard_sigmoid_attrs);
+            attrs.insert("alpha", a.alpha());
+            attrs.insert("beta", a.beta());
+        }
+        sg::OperatorAttrs::ImageToTensorAttrs => {
+            let a = attrs_table!(attrs_as_image_to_tensor_attrs);
+            attrs.insert("scale", a.scale());
+            insert_vec!("mean", a.mean());
+            insert_vec!("std", a.std());
+        }
+        sg::OperatorAttrs::LayerNormalizationAttrs => {
+            let a = attrs_table!(attrs_as_layer_normalization_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("epsilon", a.epsilon());
+        }
+        sg::OperatorAttrs::LeakyReluAttrs => {
+            let a = attrs_table!(attrs_as_leaky_relu_attrs);
+            attrs.insert("alpha", a.alpha());
+        }
+        sg::OperatorAttrs::LpNormalizationAttrs => {
+            let a = attrs_table!(attrs_as_lp_normalization_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("p", a.p());
+        }
+        sg::OperatorAttrs::LSTMAttrs => {
+            let a = attrs_table!(attrs_as_lstmattrs);
+            attrs.insert("direction", direction_name(a.direction())?);
+            attrs.insert("hidden_size", a.hidden_size());
+        }
+        sg::OperatorAttrs::MatMulNBitsAttrs => {
+            let a = attrs_table!(attrs_as_mat_mul_nbits_attrs);
+            attrs.insert("bits", a.bits());
+            attrs.insert("block_size", a.block_size());
+        }
+        sg::OperatorAttrs::LpPoolAttrs => {
+            let a = attrs_table!(attrs_as_lp_pool_attrs);
+            insert_vec!("kernel_size", Some(a.kernel_size()));
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            insert_vec!("strides", a.strides());
+            attrs.insert("ceil_mode", a.ceil_mode());
+            insert_vec!("dilations", a.dilations());
+            attrs.insert("p", a.p());
+        }
+        sg::OperatorAttrs::MaxPoolAttrs => {
+            let a = attrs_table!(attrs_as_max_pool_attrs);
+            insert_vec!("kernel_size", Some(a.kernel_size()));
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            insert_vec!("strides", a.strides());
+            attrs.insert("ceil_mode", a.ceil_mode());
+            insert_vec!("dilations", a.dilations());
+        }
+        sg::OperatorAttrs::MeanVarianceNormalizationAttrs => {
+            let a = attrs_table!(attrs_as_mean_variance_normalization_attrs);
+            insert_vec!("axes", a.axes());
+        }
+        sg::OperatorAttrs::ModAttrs => {
+            let a = attrs_table!(attrs_as_mod_attrs);
+            attrs.insert("fmod", a.fmod());
+        }
+        sg::OperatorAttrs::MultinomialAttrs => {
+            let a = attrs_table!(attrs_as_multinomial_attrs);
+            let dtype = enum_attr_name!(a.dtype(), DataType, {
+                Int32 => "int32",
+                Float => "float",
+                Int64 => "int64",
+                String => "string",
+                Int8 => "int8",
+                UInt8 => "uint8",
+                Bool => "bool",
+            });
+            attrs.insert("dtype", dtype);
+            attrs.insert("sample_size", a.sample_size());
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+        }
+        sg::OperatorAttrs::NonMaxSuppressionAttrs => {
+            let a = attrs_table!(attrs_as_non_max_suppression_attrs);
+            let box_order = enum_attr_name!(a.box_order(), NMSBoxOrder, {
+                TopLeftBottomRight => "top_left_bottom_right",
+                CenterWidthHeight => "center_width_height",
+            });
+            attrs.insert("box_order", box_order);
+        }
+        sg::OperatorAttrs::NormalizeAttrs => {
+            let a = attrs_table!(attrs_as_normalize_attrs);
+            insert_vec!("mean", a.mean());
+            insert_vec!("std", a.std());
+        }
+        sg::OperatorAttrs::OneHotAttrs => {
+            let a = attrs_table!(attrs_as_one_hot_attrs);
+            attrs.insert("axis", a.axis());
+        }
+        sg::OperatorAttrs::PadAttrs => {
+            let a = attrs_table!(attrs_as_pad_attrs);
+            let mode = enum_attr_name!(a.mode(), PadOpMode, {
+                Constant => "constant",
+                Reflect => "reflect",
+                Edge => "edge",
+                Wrap => "wrap",
+            });
+            attrs.insert("mode", mode);
+        }
+        sg::OperatorAttrs::PermuteChannelsAttrs => {
+            let a = attrs_table!(attrs_as_permute_channels_attrs);
+            insert_vec!("order", a.order());
+        }
+        sg::OperatorAttrs::QLinearConvAttrs => {
+            let a = attrs_table!(attrs_as_qlinear_conv_attrs);
+            attrs.insert("pad_mode", pad_mode_name(a.pad_mode())?);
+            insert_vec!("pads", a.pads());
+            attrs.insert("groups", a.groups());
+            insert_vec!("strides", a.strides());
+            insert_vec!("dilations", a.dilations());
+            attrs.insert("output_dtype", quantized_dtype_name(a.output_dtype())?);
+        }
+        sg::OperatorAttrs::QLinearMatMulAttrs => {
+            let a = attrs_table!(attrs_as_qlinear_mat_mul_attrs);
+            attrs.insert("output_dtype", quantized_dtype_name(a.output_dtype())?);
+        }
+        sg::OperatorAttrs::RandomNormalAttrs => {
+            let a = attrs_table!(attrs_as_random_normal_attrs);
+            attrs.insert("mean", a.mean());
+            attrs.insert("scale", a.scale());
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+            insert_vec!("shape", a.shape());
+        }
+        sg::OperatorAttrs::RandomNormalLikeAttrs => {
+            let a = attrs_table!(attrs_as_random_normal_like_attrs);
+            attrs.insert("mean", a.mean());
+            attrs.insert("scale", a.scale());
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+        }
+        sg::OperatorAttrs::RandomUniformAttrs => {
+            let a = attrs_table!(attrs_as_random_uniform_attrs);
+            insert_vec!("shape", a.shape());
+            attrs.insert("high", a.high());
+            attrs.insert("low", a.low());
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+        }
+        sg::OperatorAttrs::RandomUniformLikeAttrs => {
+            let a = attrs_table!(attrs_as_random_uniform_like_attrs);
+            attrs.insert("high", a.high());
+            attrs.insert("low", a.low());
+            if let Some(seed) = a.seed() {
+                attrs.insert("seed", seed);
+            }
+        }
+        sg::OperatorAttrs::ReduceMeanAttrs => {
+            let a = attrs_table!(attrs_as_reduce_mean_attrs);
+            insert_vec!("axes", a.axes());
+            attrs.insert("keep_dims", a.keep_dims());
+        }
+        sg::OperatorAttrs::ReduceVarAttrs => {
+            let a = attrs_table!(attrs_as_reduce_var_attrs);
+            insert_vec!("axes", a.axes());
+            attrs.insert("keep_dims", a.keep_dims());
+            attrs.insert("correction", a.correction());
+        }
+        sg::OperatorAttrs::ReshapeAttrs => {
+            let a = attrs_table!(attrs_as_reshape_attrs);
+            attrs.insert("allow_zero", a.allow_zero());
+        }
+        sg::OperatorAttrs::ResizeAttrs => {
+            let a = attrs_table!(attrs_as_resize_attrs);
+            let mode = enum_attr_name!(a.mode(), ResizeMode, {
+                Nearest => "nearest",
+                Linear => "linear",
+            });
+            let coord_mode = enum_attr_name!(a.coord_mode(), CoordTransformMode, {
+                HalfPixel => "half_pixel",
+                Asymmetric => "asymmetric",
+                AlignCorners => "align_corners",
+                PytorchHalfPixel => "pytorch_half_pixel",
+                TfCropAndResize => "tf_crop_and_resize",
+            });
+            let nearest_mode = enum_attr_name!(a.nearest_mode(), NearestMode, {
+                Floor => "floor",
+                Ceil => "ceil",
+                RoundPreferFloor => "round_prefer_floor",
+                RoundPreferCeil => "round_prefer_ceil",
+            });
+            attrs.insert("mode", mode);
+            attrs.insert("coord_mode", coord_mode);
+            attrs.insert("nearest_mode", nearest_mode);
+            attrs.insert("antialias", a.antialias());
+            insert_vec!("axes", a.axes());
+            attrs.insert("extrapolation_value", a.extrapolation_value());
+        }
+        sg::OperatorAttrs::ScaledDotProductAttentionAttrs => {
+            let a = attrs_table!(attrs_as_scaled_dot_product_attention_attrs);
+            attrs.insert("scale", a.scale());
+        }
+        sg::OperatorAttrs::ScanAttrs => {
+            let a = attrs_table!(attrs_as_scan_attrs);
+            attrs.insert("num_scan_inputs", a.num_scan_inputs());
+            insert_vec!("scan_input_axes", a.scan_input_axes());
+            insert_vec!("scan_input_directions", a.scan_input_directions());
+            insert_vec!("scan_output_axes", a.scan_output_axes());
+            insert_vec!("scan_output_directions", a.scan_output_directions());
+        }
+        sg::OperatorAttrs::ScatterElementsAttrs => {
+            let a = attrs_table!(attrs_as_scatter_elements_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("reduction", reduction_name(a.reduction())?);
+        }
+        sg::OperatorAttrs::ScatterNDAttrs => {
+            let a = attrs_table!(attrs_as_scatter_ndattrs);
+            attrs.insert("reduction", reduction_name(a.reduction())?);
+        }
+        sg::OperatorAttrs::SeluAttrs => {
+            let a = attrs_table!(attrs_as_selu_attrs);
+            attrs.insert("alpha", a.alpha());
+            attrs.insert("gamma", a.gamma());
+        }
+        sg::OperatorAttrs::SoftmaxAttrs => {
+            let a = attrs_table!(attrs_as_softmax_attrs);
+            attrs.insert("axis", a.axis());
+        }
+        sg::OperatorAttrs::SplitAttrs => {
+            let a = attrs_table!(attrs_as_split_attrs);
+            attrs.insert("axis", a.axis());
+            if let Some(num_outputs) = a.num_outputs() {
+                attrs.insert("num_outputs", num_outputs);
+            }
+        }
+        sg::OperatorAttrs::SplitToSequenceAttrs => {
+            let a = attrs_table!(attrs_as_split_to_sequence_attrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("keep_dims", a.keep_dims());
+        }
+        sg::OperatorAttrs::TopKAttrs => {
+            let a = attrs_table!(attrs_as_top_kattrs);
+            attrs.insert("axis", a.axis());
+            attrs.insert("largest", a.largest());
+            attrs.insert("sorted", a.sorted());
+        }
+        sg::OperatorAttrs::TransposeAttrs => {
+            let a = attrs_table!(attrs_as_transpose_attrs);
+            insert_vec!("perm", a.perm());
+        }
+        sg::OperatorAttrs::TriluAttrs => {
+            let a = attrs_table!(attrs_as_trilu_attrs);
+            attrs.insert("upper", a.upper());
+        }
+        _ => return Err(ReadOpError::AttrError),
+    }
+
+    Ok(attrs)
+}
+
+/// Errors reported by [Model::load].
+#[derive(Debug)]
+pub enum ModelLoadError {
+    SchemaVersionUnsupported,
+
+    /// An error occurred reading the file from disk.
+    ReadFailed(std::io::Error),
+
+    /// An error occurred parsing the FlatBuffers file.
+    ParseFailed(flatbuffers::InvalidFlatbuffer),
 
     /// An error occurred deserializing an operator.
     OperatorInvalid(ReadOpError),
@@ -1276,6 +2138,9 @@ pub enum ModelLoadError {
     /// An error occurred while traversing the model's graph to instantiate
     /// nodes and connections.
     GraphError(String),
+
+    /// An error occurred decompressing the data of a constant.
+    DecompressionFailed(String),
 }
 
 impl Display for ModelLoadError {
@@ -1286,12 +2151,93 @@ impl Display for ModelLoadError {
             ModelLoadError::ParseFailed(e) => write!(f, "parse error: {e}"),
             ModelLoadError::OperatorInvalid(e) => write!(f, "operator error: {e}"),
             ModelLoadError::GraphError(e) => write!(f, "graph error: {e}"),
+            ModelLoadError::DecompressionFailed(e) => write!(f, "decompression error: {e}"),
         }
     }
 }
 
 impl Error for ModelLoadError {}
 
+/// Errors reported when editing a model's graph.
+#[derive(Debug, PartialEq)]
+pub enum ModelEditError {
+    /// The node ID does not exist or refers to the wrong type of node.
+    InvalidNodeId,
+
+    /// The node cannot be removed because it is still used.
+    NodeInUse,
+
+    /// Another node already has the given name.
+    DuplicateName(String),
+
+    /// An operator could not be created from the new attributes.
+    OperatorInvalid(ReadOpError),
+
+    /// The value cannot be used as a constant.
+    UnsupportedValue(&'static str),
+}
+
+impl Display for ModelEditError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelEditError::InvalidNodeId => write!(f, "invalid node ID"),
+            ModelEditError::NodeInUse => write!(f, "node is still in use"),
+            ModelEditError::DuplicateName(name) => write!(f, "duplicate node name \"{name}\""),
+            ModelEditError::OperatorInvalid(e) => write!(f, "operator error: {e}"),
+            ModelEditError::UnsupportedValue(e) => write!(f, "unsupported value: {e}"),
+        }
+    }
+}
+
+impl Error for ModelEditError {}
+
+/// Decompress the data of a compressed constant and add it to the graph.
+///
+/// Unlike uncompressed constants, the decompressed data is always copied into
+/// an owned tensor.
+fn add_compressed_constant(
+    graph: &mut Graph,
+    name: Option<&str>,
+    shape: &[usize],
+    compressed_data: sg::CompressedData,
+) -> Result<NodeId, ModelLoadError> {
+    let data = compressed_data
+        .data()
+        .ok_or_else(|| ModelLoadError::GraphError("missing compressed data".to_string()))?;
+
+    // Compute the expected size before decompressing, so that the size of
+    // the decompressed data is bounded by the shape.
+    let expected_len = shape
+        .iter()
+        .try_fold(4usize, |len, &size| len.checked_mul(size))
+        .ok_or_else(|| {
+            ModelLoadError::DecompressionFailed(format!(
+                "size of constant with shape {:?} is too large",
+                shape
+            ))
+        })?;
+    let bytes = decompress(compressed_data.compression(), data.bytes(), expected_len)
+        .map_err(ModelLoadError::DecompressionFailed)?;
+    let words = bytes.chunks_exact(4).map(|w| w.try_into().unwrap());
+
+    let node_id = match compressed_data.dtype() {
+        sg::DataType::Float => {
+            let elts: Vec<f32> = words.map(f32::from_le_bytes).collect();
+            graph.add_constant(name, Tensor::from_data(shape, elts))
+        }
+        sg::DataType::Int32 => {
+            let elts: Vec<i32> = words.map(i32::from_le_bytes).collect();
+            graph.add_constant(name, Tensor::from_data(shape, elts))
+        }
+        _ => {
+            return Err(ModelLoadError::GraphError(
+                "unsupported compressed constant data type".to_string(),
+            ))
+        }
+    };
+    Ok(node_id)
+}
+
 /// Convert a vector from a FlatBuffers file into data for a graph constant node.
 ///
 /// If the data in the file is suitably aligned, as should be the case, and the
@@ -1320,19 +2266,51 @@ fn constant_node_from_flatbuffers_vec<'a, T: Copy + flatbuffers::Follow<'a, Inne
     }
 }
 
+/// Variant of [constant_node_from_flatbuffers_vec] for `BF16` data, which is
+/// stored in the file as a vector of `u16` bit patterns.
+fn bf16_constant_node_from_flatbuffers_vec(
+    storage: &Arc<ConstantStorage>,
+    fb_vec: flatbuffers::Vector<u16>,
+    shape: &[usize],
+) -> ConstantNodeData<BF16> {
+    let bytes = fb_vec.bytes();
+    if (bytes.as_ptr() as usize) % std::mem::align_of::<BF16>() == 0 {
+        // Safety: We checked that the data is correctly aligned, and `BF16`
+        // is a `repr(transparent)` wrapper around a `u16`.
+        let typed_slice = unsafe {
+            let typed_slice = std::mem::transmute::<&[u8], &[BF16]>(bytes);
+            &typed_slice[..fb_vec.len()]
+        };
+        let storage =
+            ArcSlice::new(storage.clone(), typed_slice).expect("storage does not contain data");
+        ArcTensorView::from_data(shape, storage).into()
+    } else {
+        let storage: Vec<BF16> = fb_vec.iter().map(BF16::from_bits).collect();
+        Tensor::from_data(shape, storage).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
     use rten_tensor::prelude::*;
-    use rten_tensor::{tensor, Tensor};
-
-    use crate::graph::{Dimension, RunError};
-    use crate::model::{Model, ModelOptions};
-    use crate::model_builder::{MetadataArgs, ModelBuilder, OpType};
-    use crate::ops;
-    use crate::ops::{
-        BoxOrder, CoordTransformMode, NearestMode, OpError, Output, ResizeMode, Scalar,
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{tensor, NdTensor, Tensor};
+
+    use crate::graph::{CancelToken, Dimension, RunError, RunOptions};
+    use crate::model::{Model, ModelOptions, Session, ValueInfo};
+    use crate::model_builder::{MetadataArgs, ModelBuildError, ModelBuilder};
+    use crate::number::BF16;
+    use crate::ops::{DataType, InputList, IntoOpResult, OpError, Operator, Output};
+    use crate::tensor_pool::TensorPool;
+    use crate::{
+        Compression, ImageLayout, InputAdapter, ModelEditError, ModelLoadError, OpAttrs,
+        OpRegistry, ReadOpError, ReadOpResult,
     };
-    use crate::{ModelLoadError, OpRegistry, ReadOpError};
 
     fn generate_model_buffer() -> Vec<u8> {
         let mut builder = ModelBuilder::new();
@@ -1353,13 +2331,24 @@ mod tests {
         builder.add_output(output_node);
 
         let concat_out = builder.add_value("concat_out", None);
-        builder.add_operator(
-            "concat",
-            OpType::Concat(ops::Concat { axis: 0 }),
-            &[const_node, input_node].map(Some),
-            &[concat_out],
-        );
-        builder.add_operator("relu", OpType::Relu, &[Some(concat_out)], &[output_node]);
+        builder
+            .add_operator(
+                "concat",
+                "Concat",
+                &OpAttrs::new().with("axis", 0),
+                &[const_node, input_node].map(Some),
+                &[concat_out],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "relu",
+                "Relu",
+                &OpAttrs::new(),
+                &[Some(concat_out)],
+                &[output_node],
+            )
+            .unwrap();
 
         builder.add_metadata(MetadataArgs {
             onnx_hash: Some("abc".to_string()),
@@ -1386,178 +2375,1533 @@ mod tests {
     }
 
     #[test]
-    fn test_model_input_output_ids() {
-        let buffer = generate_model_buffer();
-
-        let model = Model::load(buffer).unwrap();
+    fn test_model_input_output_ids() {
+        let buffer = generate_model_buffer();
+
+        let model = Model::load(buffer).unwrap();
+
+        // Valid model IDs
+        let input_id = model.find_node("input").unwrap();
+        let output_id = model.find_node("output").unwrap();
+
+        assert_eq!(model.input_ids(), &[input_id]);
+        assert_eq!(model.output_ids(), &[output_id]);
+
+        // Get the same node ID via a convenience method which returns a
+        // Result.
+        assert_eq!(model.node_id("input"), Ok(input_id));
+
+        // Invalid model ID
+        assert_eq!(model.find_node("does_not_exist"), None);
+        assert_eq!(
+            model.node_id("does_not_exist"),
+            Err(RunError::InvalidNodeName("does_not_exist".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_operator() {
+        let buffer = generate_model_buffer();
+        let registry = OpRegistry::new();
+        let result = ModelOptions::with_ops(registry).load(buffer);
+
+        let matches = match result.err() {
+            Some(ModelLoadError::OperatorInvalid(ReadOpError::UnsupportedOperator(op)))
+                if op == "Concat" =>
+            {
+                true
+            }
+            _ => false,
+        };
+        assert!(matches);
+    }
+
+    #[test]
+    fn test_shape_info() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        let input_id = model.input_ids()[0];
+
+        let shape = model
+            .node_info(input_id)
+            .and_then(|ni| ni.shape())
+            .expect("input shape missing");
+        assert_eq!(shape, &[1, 2, 2].map(Dimension::Fixed));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        assert_eq!(model.metadata().onnx_hash(), Some("abc"));
+        assert_eq!(model.metadata().description(), None);
+    }
+
+    #[test]
+    fn test_input_shape() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        assert_eq!(
+            model.input_shape(0),
+            Some(vec![
+                Dimension::Fixed(1),
+                Dimension::Fixed(2),
+                Dimension::Fixed(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_override_constant() {
+        let mut builder = ModelBuilder::new();
+        let const_node = builder.add_float_constant(&tensor!([1., 2.]));
+        let input_node = builder.add_value("input", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "add",
+                "Add",
+                &OpAttrs::new(),
+                &[const_node, input_node].map(Some),
+                &[output_node],
+            )
+            .unwrap();
+        let mut model = Model::load(builder.finish()).unwrap();
+
+        let input = tensor!([3., 4.]);
+        let run = |model: &Model| {
+            let result = model
+                .run(
+                    &[(input_node as usize, (&input).into())],
+                    &[output_node as usize],
+                    None,
+                )
+                .unwrap();
+            result[0].as_float_ref().unwrap().to_vec()
+        };
+        assert_eq!(run(&model), &[4., 6.]);
+
+        model
+            .override_constant(const_node as usize, tensor!([10., 20.]).into())
+            .unwrap();
+        assert_eq!(run(&model), &[13., 24.]);
+
+        // Overriding a node which is not a constant fails.
+        let err = model.override_constant(input_node as usize, tensor!([1., 2.]).into());
+        assert_eq!(err, Err(RunError::InvalidNodeId));
+    }
+
+    #[test]
+    fn test_symbolic_dim_names() {
+        let mut builder = ModelBuilder::new();
+        let shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Fixed(3),
+        ];
+        let input_node = builder.add_value("input", Some(&shape));
+        let output_node = builder.add_value("output", Some(&shape));
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "relu",
+                "Relu",
+                &OpAttrs::new(),
+                &[Some(input_node)],
+                &[output_node],
+            )
+            .unwrap();
+        let model = Model::load(builder.finish()).unwrap();
+
+        assert_eq!(model.input_shape(0).as_deref(), Some(shape.as_slice()));
+        assert_eq!(model.output_shape(0).as_deref(), Some(shape.as_slice()));
+
+        let input_info = model.node_info(model.input_ids()[0]).unwrap();
+        assert_eq!(input_info.dim_names(), Some(vec![Some("batch"), None]));
+
+        let relu_info = model.node_info(model.find_node("relu").unwrap()).unwrap();
+        assert_eq!(relu_info.dim_names(), None);
+    }
+
+    #[test]
+    fn test_input_output_info() {
+        let mut builder = ModelBuilder::new();
+        let shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Symbolic("sequence".to_string()),
+        ];
+        let input_node = builder.add_typed_value("input_ids", Some(&shape), DataType::Int32);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "neg",
+                "Neg",
+                &OpAttrs::new(),
+                &[Some(input_node)],
+                &[output_node],
+            )
+            .unwrap();
+        let model = Model::load(builder.finish()).unwrap();
+
+        let input_info = model.input_info();
+        assert_eq!(
+            input_info,
+            [ValueInfo {
+                id: model.input_ids()[0],
+                name: Some("input_ids".to_string()),
+                dtype: Some(DataType::Int32),
+                shape: Some(shape.to_vec()),
+                optional: false,
+            }]
+        );
+
+        let output_info = model.output_info();
+        assert_eq!(
+            output_info,
+            [ValueInfo {
+                id: model.output_ids()[0],
+                name: Some("output".to_string()),
+                dtype: None,
+                shape: None,
+                optional: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_load_and_run_model() {
+        let buffer = generate_model_buffer();
+
+        let model = Model::load(buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let input = generate_input();
+
+        // Test a normal model run.
+        let result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        let result_tensor = check_output(result);
+
+        // Test a partial run. Since we are providing all inputs, this works the
+        // same as `Model::run`. See `Graph::partial_run` tests for other cases.
+        let partial_run_result = model
+            .partial_run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        assert_eq!(
+            partial_run_result,
+            vec![(output_id, Output::FloatTensor(result_tensor))]
+        );
+    }
+
+    #[test]
+    fn test_load_in_background() {
+        let buffer = generate_model_buffer();
+        let pending = Model::load_in_background(buffer);
+        let model = pending.wait().unwrap();
+
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let input = generate_input();
+        let result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        check_output(result);
+
+        // Errors are reported when waiting for the result.
+        let pending = Model::load_in_background(vec![1, 2, 3]);
+        while !pending.is_ready() {
+            std::thread::yield_now();
+        }
+        assert!(matches!(
+            pending.wait(),
+            Err(ModelLoadError::ParseFailed(_))
+        ));
+
+        let pending = Model::load_file_in_background("does-not-exist.rten");
+        assert!(matches!(pending.wait(), Err(ModelLoadError::ReadFailed(_))));
+    }
+
+    #[test]
+    fn test_input_adapter() {
+        let mut builder = ModelBuilder::new();
+        let input_shape = [1, 2, 2, 3].map(Dimension::Fixed);
+        let input_node = builder.add_value("input", Some(&input_shape));
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "relu",
+                "Relu",
+                &OpAttrs::new(),
+                &[Some(input_node)],
+                &[output_node],
+            )
+            .unwrap();
+        let buffer = builder.finish();
+
+        let adapter = InputAdapter {
+            layout: ImageLayout::NHWC,
+            dtype: Some(DataType::Int32),
+            scale: None,
+            mean: Some(vec![1., 2.]),
+            std: Some(vec![2., 4.]),
+        };
+        let model = ModelOptions::with_all_ops()
+            .with_input_adapter("input", adapter.clone())
+            .load(buffer.clone())
+            .unwrap();
+
+        // Input ID and shape should refer to the adapted input.
+        let input_id = model.node_id("input").unwrap();
+        assert_eq!(model.input_ids(), &[input_id]);
+        assert_eq!(
+            model.input_shape(0),
+            Some([1, 2, 3, 2].map(Dimension::Fixed).to_vec())
+        );
+
+        // Run the model with an NHWC int input.
+        let input = tensor!((1, 2, 3, 2); [1, 2, 3, 6, 5, 10, 7, 14, 9, 18, 11, 22]);
+        let output_id = model.output_ids()[0];
+        let mut result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        let result = result.remove(0).into_float().unwrap();
+        assert_eq!(
+            result,
+            tensor!((1, 2, 2, 3); [0., 1., 2., 3., 4., 5., 0., 1., 2., 3., 4., 5.])
+        );
+
+        // Equivalent adapter which scales the input before normalizing it.
+        let scaled_adapter = InputAdapter {
+            scale: Some(0.5),
+            mean: Some(vec![0.5, 1.]),
+            std: Some(vec![1., 2.]),
+            ..adapter.clone()
+        };
+        let model = ModelOptions::with_all_ops()
+            .with_input_adapter("input", scaled_adapter)
+            .load(buffer.clone())
+            .unwrap();
+        let mut scaled_result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        assert_eq!(scaled_result.remove(0).into_float().unwrap(), result);
+
+        // Unknown input name
+        let result = ModelOptions::with_all_ops()
+            .with_input_adapter("not_an_input", adapter.clone())
+            .load(buffer.clone());
+        assert!(matches!(
+            result.err(),
+            Some(ModelLoadError::GraphError(err)) if err.contains("unknown input")
+        ));
+
+        // NHWC adapter for input with wrong rank
+        let mut builder = ModelBuilder::new();
+        let input_node = builder.add_value("input", Some(&[Dimension::Fixed(3)]));
+        builder.add_input(input_node);
+        let result = ModelOptions::with_all_ops()
+            .with_input_adapter("input", adapter)
+            .load(builder.finish());
+        assert!(matches!(
+            result.err(),
+            Some(ModelLoadError::GraphError(err)) if err.contains("requires a 4D input")
+        ));
+    }
+
+    #[test]
+    fn test_run_owned() {
+        let mut builder = ModelBuilder::new();
+        let input_node = builder.add_value("input", None);
+        let relu_out = builder.add_value("relu_out", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "relu",
+                "Relu",
+                &OpAttrs::new(),
+                &[Some(input_node)],
+                &[relu_out],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "neg",
+                "Neg",
+                &OpAttrs::new(),
+                &[Some(relu_out)],
+                &[output_node],
+            )
+            .unwrap();
+        let model = Model::load(builder.finish()).unwrap();
+
+        let input = Tensor::from_data(&[2, 2], vec![-1., 2., -3., 4.]);
+        let input_ptr = input.data().unwrap().as_ptr();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let mut result = model
+            .run_owned(vec![(input_id, input.into())], &[output_id], None)
+            .unwrap();
+        let result = result.remove(0).into_float().unwrap();
+
+        // Output should have re-used the input buffer.
+        assert_eq!(result.to_vec(), &[0., -2., 0., -4.]);
+        assert_eq!(result.data().unwrap().as_ptr(), input_ptr);
+    }
+
+    #[test]
+    fn test_infer_shapes() {
+        let build_model = |b_shape: &[Dimension]| {
+            let mut builder = ModelBuilder::new();
+            let a_shape = [Dimension::Symbolic("batch".into()), Dimension::Fixed(4)];
+            let a = builder.add_value("a", Some(&a_shape));
+            let b = builder.add_value("b", Some(b_shape));
+            let output = builder.add_value("output", None);
+            builder.add_input(a);
+            builder.add_input(b);
+            builder.add_output(output);
+            builder
+                .add_operator(
+                    "matmul",
+                    "MatMul",
+                    &OpAttrs::new(),
+                    &[Some(a), Some(b)],
+                    &[output],
+                )
+                .unwrap();
+            builder.finish()
+        };
+
+        // Compatible shapes
+        let model = Model::load(build_model(&[Dimension::Fixed(4), Dimension::Fixed(5)])).unwrap();
+        let shapes = model.infer_shapes(&[]).unwrap();
+        let output_id = model.output_ids()[0];
+        assert_eq!(
+            shapes.get(&output_id),
+            Some(&vec![
+                Dimension::Symbolic("batch".into()),
+                Dimension::Fixed(5)
+            ])
+        );
+
+        // Input shapes can be overridden.
+        let a_id = model.input_ids()[0];
+        let shapes = model
+            .infer_shapes(&[(a_id, vec![Dimension::Fixed(2), Dimension::Fixed(4)])])
+            .unwrap();
+        assert_eq!(
+            shapes.get(&output_id),
+            Some(&vec![Dimension::Fixed(2), Dimension::Fixed(5)])
+        );
+        let result = model.infer_shapes(&[(a_id, vec![Dimension::Fixed(2), Dimension::Fixed(3)])]);
+        assert_eq!(
+            result.err(),
+            Some(RunError::OperatorError {
+                name: "matmul".to_string(),
+                op_type: "MatMul".to_string(),
+                input_shapes: Vec::new(),
+                error: OpError::IncompatibleInputShapes(
+                    "Columns of first matrix does not match rows of second matrix"
+                ),
+            })
+        );
+
+        // Incompatible shapes are reported when the model is loaded.
+        let result = Model::load(build_model(&[Dimension::Fixed(3), Dimension::Fixed(5)]));
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some(
+                "graph error: incompatible input shapes for operator \"matmul\" (MatMul): \
+                 incompatible input shapes: Columns of first matrix does not match rows of second matrix"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_export_onnx() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+
+        let onnx_model = model.export_onnx();
+
+        // Detailed checks of the output are in the `onnx_export` module.
+        // Here we just check that the operator types are present.
+        let contains = |s: &str| {
+            onnx_model
+                .windows(s.len())
+                .any(|window| window == s.as_bytes())
+        };
+        assert!(contains("Concat"));
+        assert!(contains("Relu"));
+        assert!(contains("main_graph"));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+
+        let reloaded = Model::load(model.serialize().unwrap()).unwrap();
+
+        assert_eq!(reloaded.metadata().onnx_hash(), Some("abc"));
+        let input_id = reloaded.node_id("input").unwrap();
+        let output_id = reloaded.node_id("output").unwrap();
+        assert_eq!(reloaded.input_ids(), &[input_id]);
+        assert_eq!(reloaded.output_ids(), &[output_id]);
+        assert_eq!(
+            reloaded.node_info(input_id).and_then(|ni| ni.shape()),
+            Some([1, 2, 2].map(Dimension::Fixed).to_vec())
+        );
+
+        let input = generate_input();
+        let result = reloaded
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        check_output(result);
+    }
+
+    #[test]
+    fn test_model_builder_errors() {
+        let mut builder = ModelBuilder::new();
+        let input = builder.add_value("input", None);
+        let output = builder.add_value("output", None);
+
+        let mut add_op = |op_type: &str, attrs: OpAttrs| {
+            builder.add_operator("op", op_type, &attrs, &[Some(input)], &[output])
+        };
+        let invalid_attr = |op_type: &str, name: &str| ModelBuildError::InvalidAttr {
+            op_type: op_type.to_string(),
+            name: name.to_string(),
+        };
+
+        assert_eq!(
+            add_op("NotAnOp", OpAttrs::new()),
+            Err(ModelBuildError::UnknownOperator("NotAnOp".to_string()))
+        );
+        assert_eq!(
+            add_op("Concat", OpAttrs::new().with("axis", "zero")),
+            Err(invalid_attr("Concat", "axis"))
+        );
+        assert_eq!(
+            add_op("Resize", OpAttrs::new().with("mode", "cubic")),
+            Err(invalid_attr("Resize", "mode"))
+        );
+        assert_eq!(
+            add_op("EyeLike", OpAttrs::new().with("dtype", "float16")),
+            Err(invalid_attr("EyeLike", "dtype"))
+        );
+        assert_eq!(
+            add_op("Scan", OpAttrs::new().with("body", 0)),
+            Err(invalid_attr("Scan", "body"))
+        );
+        assert_eq!(
+            add_op("Relu", OpAttrs::new().with("alpha", 1.0)),
+            Err(invalid_attr("Relu", "alpha"))
+        );
+        assert_eq!(builder.end_subgraph(), Err(ModelBuildError::NoSubgraph));
+    }
+
+    #[test]
+    fn test_serialize_unsupported_value() {
+        let mut builder = ModelBuilder::new();
+        let const_node = builder.add_int_constant(&tensor!([1, 2]));
+        let input_node = builder.add_value("input", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "add",
+                "Add",
+                &OpAttrs::new(),
+                &[const_node, input_node].map(Some),
+                &[output_node],
+            )
+            .unwrap();
+        let mut model = Model::load(builder.finish()).unwrap();
+
+        model
+            .override_constant(const_node as usize, Tensor::<i64>::from([1, 2]).into())
+            .unwrap();
+        assert_eq!(
+            model.serialize().err(),
+            Some(ModelBuildError::UnsupportedValue(
+                "int64 constants are not supported by the model format"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_edit_graph() {
+        let buffer = generate_model_buffer();
+        let mut model = Model::load(buffer).unwrap();
+
+        let input_id = model.node_id("input").unwrap();
+        let concat_out = model.node_id("concat_out").unwrap();
+        let output_id = model.node_id("output").unwrap();
+        let relu_id = model.node_id("relu").unwrap();
+
+        // Nodes that are still in use cannot be removed.
+        assert_eq!(model.remove_node(output_id), Err(ModelEditError::NodeInUse));
+        assert_eq!(model.remove_node(1000), Err(ModelEditError::InvalidNodeId));
+
+        // Strip the final `Relu` from the model.
+        model.replace_uses(output_id, concat_out).unwrap();
+        assert_eq!(model.output_ids(), &[concat_out]);
+        assert_eq!(model.remove_unused_nodes(), 2);
+        assert!(model.node_info(relu_id).is_none());
+        assert!(model.find_node("output").is_none());
+
+        // Rename the new output.
+        assert_eq!(
+            model.rename_node(concat_out, "input"),
+            Err(ModelEditError::DuplicateName("input".to_string()))
+        );
+        model.rename_node(concat_out, "features").unwrap();
+        assert!(model.find_node("concat_out").is_none());
+        assert_eq!(model.find_node("features"), Some(concat_out));
+
+        let input = generate_input();
+        let result = model
+            .run(&[(input_id, (&input).into())], &[concat_out], None)
+            .unwrap();
+        let output: Tensor<f32> = result[0].clone().into_float().unwrap();
+        assert_eq!(output.shape(), &[2, 2, 2]);
+        assert_eq!(output.to_vec(), &[0.5, -0.5, 0.1, -0.1, 1., 2., -1., -2.]);
+
+        // Change the axis of the `Concat` operator.
+        let concat_id = model.node_id("concat").unwrap();
+        let registry = OpRegistry::with_all_ops();
+        let err =
+            model.set_operator_attrs(concat_id, OpAttrs::new().with("axis", "invalid"), &registry);
+        assert!(matches!(err, Err(ModelEditError::OperatorInvalid(_))));
+        model
+            .set_operator_attrs(concat_id, OpAttrs::new().with("axis", 2), &registry)
+            .unwrap();
+        assert_eq!(
+            model.operator_attrs(concat_id),
+            Some(&OpAttrs::new().with("axis", 2))
+        );
+        let output: Tensor<f32> = model
+            .run_one((&input).into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(output.shape(), &[1, 2, 4]);
+
+        // The edited model can be serialized and reloaded.
+        let reloaded = Model::load(model.serialize().unwrap()).unwrap();
+        assert_eq!(
+            reloaded.output_ids(),
+            &[reloaded.node_id("features").unwrap()]
+        );
+        assert!(reloaded.find_node("relu").is_none());
+    }
+
+    #[test]
+    fn test_set_constant_input() {
+        let mut builder = ModelBuilder::new();
+        let x = builder.add_value("x", None);
+        let scale = builder.add_value("scale", None);
+        let two = builder.add_float_constant(&tensor!(2.));
+        let scale_out = builder.add_value("scale_out", None);
+        let output = builder.add_value("output", None);
+        builder.add_input(x);
+        builder.add_input(scale);
+        builder.add_output(output);
+        builder
+            .add_operator(
+                "mul",
+                "Mul",
+                &OpAttrs::new(),
+                &[scale, two].map(Some),
+                &[scale_out],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "add",
+                "Add",
+                &OpAttrs::new(),
+                &[x, scale_out].map(Some),
+                &[output],
+            )
+            .unwrap();
+        let mut model = Model::load(builder.finish()).unwrap();
+
+        let x_id = model.node_id("x").unwrap();
+        let scale_id = model.node_id("scale").unwrap();
+        let output_id = model.node_id("output").unwrap();
+
+        assert_eq!(
+            model.set_constant_input(output_id, tensor!(1.).into()),
+            Err(ModelEditError::InvalidNodeId)
+        );
+
+        model
+            .set_constant_input(scale_id, tensor!(3.).into())
+            .unwrap();
+        assert_eq!(model.input_ids(), &[x_id]);
+
+        // The `Mul` only depended on constants, so it has been evaluated and
+        // removed.
+        assert!(model.find_node("mul").is_none());
+        let plan = model.plan_summary(&[x_id], &[output_id]).unwrap();
+        assert!(plan.contains("Add") && !plan.contains("Mul"));
+
+        let output: Tensor<f32> = model
+            .run_one((&tensor!([1., 2.])).into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(output.to_vec(), &[7., 8.]);
+    }
+
+    #[test]
+    fn test_load_file() {
+        let buffer = generate_model_buffer();
+        std::fs::write("model-load-test.rten", buffer).unwrap();
+
+        let model = Model::load_file("model-load-test.rten").unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let input = generate_input();
+        let result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        check_output(result);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_load_mmap() {
+        let buffer = generate_model_buffer();
+        std::fs::write("model-load-test.rten", buffer).unwrap();
+
+        let model = unsafe { Model::load_mmap("model-load-test.rten").unwrap() };
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let input = generate_input();
+        let result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap();
+        check_output(result);
+    }
+
+    #[test]
+    fn test_run_one() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+
+        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
+        let result: Tensor<f32> = model
+            .run_one((&input).into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(result.shape(), &[2, 2, 2]);
+        assert_eq!(result.to_vec(), &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]);
+    }
+
+    #[test]
+    fn test_run_map() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+
+        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
+        let inputs = HashMap::from([("input", (&input).into())]);
+        let mut outputs = model
+            .run_map(&inputs, &["output", "concat_out"], None)
+            .unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs.names().collect::<Vec<_>>(),
+            ["output", "concat_out"]
+        );
+        assert_eq!(outputs.get("concat_out").unwrap().shape(), &[2, 2, 2]);
+
+        let result: NdTensor<f32, 3> = outputs.take("output").unwrap();
+        assert_eq!(result.to_vec(), &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]);
+        assert!(outputs.get("output").is_none());
+
+        // Output with the wrong type or rank.
+        let result: Result<NdTensor<f32, 2>, _> = outputs.take("concat_out");
+        assert_eq!(
+            result.err(),
+            Some(RunError::OutputMismatch(
+                "output has incorrect type or rank"
+            ))
+        );
+
+        // Invalid input or output names.
+        let result = model.run_map(&inputs, &["not_an_output"], None);
+        assert_eq!(
+            result.err(),
+            Some(RunError::InvalidNodeName("not_an_output".into()))
+        );
+        let inputs = HashMap::from([("not_an_input", (&input).into())]);
+        let result = model.run_map(&inputs, &["output"], None);
+        assert_eq!(
+            result.err(),
+            Some(RunError::InvalidNodeName("not_an_input".into()))
+        );
+    }
+
+    #[test]
+    fn test_session() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let session = model.prepare(&[input_id], &[output_id]).unwrap();
+        assert_eq!(session.input_ids(), &[input_id]);
+        assert_eq!(session.output_ids(), &[output_id]);
+
+        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
+        for _ in 0..2 {
+            let mut result = session.run(&[(input_id, (&input).into())], None).unwrap();
+            assert_eq!(result.len(), 1);
+            let output = result.remove(0);
+            assert_eq!(
+                output.as_float_ref().unwrap().to_vec(),
+                &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]
+            );
+            session.reclaim(output);
+        }
+
+        // After the first run, buffers are re-used from the session's arena.
+        assert!(session.arena().hit_count() > 0);
+        assert!(!session.arena().is_empty());
+
+        // Missing input.
+        let result = session.run(&[], None);
+        assert_eq!(
+            result.err(),
+            Some(RunError::PlanningError("Missing input \"input\"".into()))
+        );
+
+        // Input which is not part of the plan.
+        let concat_out = model.node_id("concat_out").unwrap();
+        let result = session.run(
+            &[(input_id, (&input).into()), (concat_out, (&input).into())],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::PlanningError(
+                "Input \"concat_out\" is not an input of the plan".into()
+            ))
+        );
+
+        // Invalid output.
+        let result = model.prepare(&[input_id], &[1234]);
+        assert!(matches!(result, Err(RunError::PlanningError(_))));
+    }
+
+    #[test]
+    fn test_run_async() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
+        let inputs = [(input_id, (&input).into())];
+        let outputs = [output_id];
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut fut = std::pin::pin!(model.run_async(&inputs, &outputs, None));
+        let result = loop {
+            if let Poll::Ready(result) = fut.as_mut().poll(&mut cx) {
+                break result;
+            }
+        };
+        let expected = model.run(&inputs, &outputs, None).unwrap();
+        assert_eq!(result.unwrap(), expected);
+
+        // Cancel the run after the first operator.
+        let token = CancelToken::new();
+        let opts = RunOptions {
+            cancel: Some(token.clone()),
+            ..Default::default()
+        };
+        let mut fut = std::pin::pin!(model.run_async(&inputs, &outputs, Some(opts)));
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        token.cancel();
+        assert_eq!(
+            fut.as_mut().poll(&mut cx),
+            Poll::Ready(Err(RunError::Cancelled))
+        );
+    }
+
+    #[test]
+    fn test_model_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Model>();
+        assert_send_sync::<Session>();
+    }
+
+    #[test]
+    fn test_concurrent_runs() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let session = model.prepare(&[input_id], &[output_id]).unwrap();
+
+        std::thread::scope(|s| {
+            for thread_idx in 0..8 {
+                let model = &model;
+                let session = &session;
+                s.spawn(move || {
+                    for i in 0..50 {
+                        let x = (thread_idx * 50 + i) as f32;
+                        let input = Tensor::full(&[1, 2, 2], x);
+                        let expected = [0.5, 0., 0.1, 0., x, x, x, x];
+
+                        let result: Tensor<f32> = model
+                            .run_one((&input).into(), None)
+                            .unwrap()
+                            .try_into()
+                            .unwrap();
+                        assert_eq!(result.to_vec(), expected);
+
+                        let mut result = session.run(&[(input_id, (&input).into())], None).unwrap();
+                        let result: Tensor<f32> = result.remove(0).try_into().unwrap();
+                        assert_eq!(result.to_vec(), expected);
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_omitted_optional_inputs() {
+        let mut builder = ModelBuilder::new();
+
+        let output_node = builder.add_value("output", None);
+        builder.add_output(output_node);
+        builder
+            .add_operator("shape", "Shape", &OpAttrs::new(), &[None], &[output_node])
+            .unwrap();
+
+        let buffer = builder.finish();
+        let model = Model::load(buffer).unwrap();
+
+        let result = model.run(&[], &[output_node as usize], None);
+
+        assert_eq!(
+            result.err(),
+            Some(RunError::OperatorError {
+                name: "shape".to_string(),
+                op_type: "Shape".to_string(),
+                input_shapes: vec![None],
+                error: OpError::MissingInputs
+            })
+        );
+    }
+
+    #[test]
+    fn test_omitted_optional_input_before_provided_input() {
+        let mut builder = ModelBuilder::new();
+
+        let input_node = builder.add_value("input", None);
+        let max = builder.add_float_constant(&tensor!(5.));
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "clip",
+                "Clip",
+                &OpAttrs::new(),
+                &[Some(input_node), None, Some(max)],
+                &[output_node],
+            )
+            .unwrap();
+
+        let buffer = builder.finish();
+        let model = Model::load(buffer).unwrap();
+
+        let input = tensor!([-10., 2., 10.]);
+        let result = model
+            .run(
+                &[(input_node as usize, (&input).into())],
+                &[output_node as usize],
+                None,
+            )
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+
+        assert_eq!(result.to_vec(), &[-10., 2., 5.]);
+    }
+
+    #[test]
+    fn test_optional_model_input() {
+        let mut builder = ModelBuilder::new();
+
+        let input_node = builder.add_value("input", None);
+        let min_node = builder.add_optional_value("min", None);
+        let max = builder.add_float_constant(&tensor!(5.));
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_input(min_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "clip",
+                "Clip",
+                &OpAttrs::new(),
+                &[Some(input_node), Some(min_node), Some(max)],
+                &[output_node],
+            )
+            .unwrap();
+
+        let buffer = builder.finish();
+        let model = Model::load(buffer).unwrap();
+
+        let input_id = model.find_node("input").unwrap();
+        let min_id = model.find_node("min").unwrap();
+        let output_id = model.find_node("output").unwrap();
+        assert!(!model.node_info(input_id).unwrap().is_optional());
+        assert!(model.node_info(min_id).unwrap().is_optional());
+
+        let input = tensor!([-10., 2., 10.]);
+        let min = tensor!(0.);
+
+        // Run with the optional input omitted.
+        let result = model
+            .run(&[(input_id, (&input).into())], &[output_id], None)
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[-10., 2., 5.]);
+
+        // Run with the optional input provided.
+        let result = model
+            .run(
+                &[(input_id, (&input).into()), (min_id, (&min).into())],
+                &[output_id],
+                None,
+            )
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[0., 2., 5.]);
+    }
+
+    #[test]
+    fn test_custom_operator() {
+        #[derive(Debug)]
+        struct AddScalar {
+            value: f32,
+        }
 
-        // Valid model IDs
-        let input_id = model.find_node("input").unwrap();
-        let output_id = model.find_node("output").unwrap();
+        impl Operator for AddScalar {
+            fn name(&self) -> &str {
+                "AddScalar"
+            }
 
-        assert_eq!(model.input_ids(), &[input_id]);
-        assert_eq!(model.output_ids(), &[output_id]);
+            fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+                let input = inputs.require_as::<f32>(0)?;
+                input.map_in(pool, |x| x + self.value).into_op_result()
+            }
+        }
 
-        // Get the same node ID via a convenience method which returns a
-        // Result.
-        assert_eq!(model.node_id("input"), Ok(input_id));
+        let mut builder = ModelBuilder::new();
+        let input_node = builder.add_value("input", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_custom_operator(
+                "add_scalar",
+                "com.example",
+                "AddScalar",
+                &OpAttrs::new()
+                    .with("value", 2.0)
+                    .with("mode", "fast")
+                    .with("axes", vec![1i64, 2])
+                    .with("enabled", true),
+                &[Some(input_node)],
+                &[output_node],
+            )
+            .unwrap();
+        let buffer = builder.finish();
 
-        // Invalid model ID
-        assert_eq!(model.find_node("does_not_exist"), None);
-        assert_eq!(
-            model.node_id("does_not_exist"),
-            Err(RunError::InvalidNodeName("does_not_exist".to_string()))
+        // Loading fails if no implementation is registered.
+        let result = ModelOptions::with_all_ops().load(buffer.clone());
+        assert!(matches!(
+            result.err(),
+            Some(ModelLoadError::OperatorInvalid(ReadOpError::UnsupportedOperator(op)))
+                if op == "com.example.AddScalar"
+        ));
+
+        let mut registry = OpRegistry::with_all_ops();
+        registry.register_custom_op(
+            "com.example",
+            "AddScalar",
+            |attrs: &OpAttrs| -> ReadOpResult {
+                assert_eq!(attrs.require::<&str>("mode")?, "fast");
+                assert_eq!(attrs.require::<Vec<i64>>("axes")?, [1, 2]);
+                assert!(attrs.require::<bool>("enabled")?);
+                Ok(Box::new(AddScalar {
+                    value: attrs.require("value")?,
+                }))
+            },
         );
-    }
+        let model = ModelOptions::with_ops(registry).load(buffer).unwrap();
 
-    #[test]
-    fn test_unsupported_operator() {
-        let buffer = generate_model_buffer();
-        let registry = OpRegistry::new();
-        let result = ModelOptions::with_ops(registry).load(buffer);
+        let input = tensor!([1., 2., 3.]);
+        let result = model
+            .run_one((&input).into(), None)
+            .unwrap()
+            .into_float()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[3., 4., 5.]);
 
-        let matches = match result.err() {
+        // Custom operators keep their domain and type when serialized.
+        let result = ModelOptions::with_all_ops().load(model.serialize().unwrap());
+        assert!(matches!(
+            result.err(),
             Some(ModelLoadError::OperatorInvalid(ReadOpError::UnsupportedOperator(op)))
-                if op == "Concat" =>
-            {
-                true
-            }
-            _ => false,
-        };
-        assert!(matches);
+                if op == "com.example.AddScalar"
+        ));
     }
 
     #[test]
-    fn test_shape_info() {
-        let buffer = generate_model_buffer();
+    fn test_string_constant() {
+        let mut builder = ModelBuilder::new();
+
+        let strings: Tensor<String> =
+            Tensor::from_data(&[2], vec!["42".to_string(), "-1.5".to_string()]);
+        let const_node = builder.add_string_constant(&strings);
+        let string_out = builder.add_value("string_out", None);
+        let float_out = builder.add_value("float_out", None);
+        builder.add_output(string_out);
+        builder.add_output(float_out);
+        builder
+            .add_operator(
+                "identity",
+                "Identity",
+                &OpAttrs::new(),
+                &[Some(const_node)],
+                &[string_out],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "cast",
+                "Cast",
+                &OpAttrs::new().with("to", "float"),
+                &[Some(const_node)],
+                &[float_out],
+            )
+            .unwrap();
+
+        let buffer = builder.finish();
         let model = Model::load(buffer).unwrap();
-        let input_id = model.input_ids()[0];
+        let mut result = model
+            .run(&[], &[string_out as usize, float_out as usize], None)
+            .unwrap();
 
-        let shape = model
-            .node_info(input_id)
-            .and_then(|ni| ni.shape())
-            .expect("input shape missing");
-        assert_eq!(shape, &[1, 2, 2].map(Dimension::Fixed));
+        let floats = result.remove(1).into_float().unwrap();
+        assert_eq!(floats, tensor!([42., -1.5]));
+        let result_strings = result.remove(0).into_strings().unwrap();
+        assert_eq!(result_strings, strings);
     }
 
     #[test]
-    fn test_metadata() {
-        let buffer = generate_model_buffer();
-        let model = Model::load(buffer).unwrap();
-        assert_eq!(model.metadata().onnx_hash(), Some("abc"));
-        assert_eq!(model.metadata().description(), None);
+    fn test_compressed_constant() {
+        for compression in [Compression::Zstd, Compression::Lz4] {
+            let mut builder = ModelBuilder::new();
+
+            let floats = Tensor::from_data(&[2, 3], vec![0.5, -0.5, 1.0, 2.0, 3.0, 4.0]);
+            let ints = Tensor::from_data(&[4], vec![1, 2, -3, 4]);
+            let float_node = builder
+                .add_compressed_float_constant(&floats, compression)
+                .unwrap();
+            let int_node = builder
+                .add_compressed_int_constant(&ints, compression)
+                .unwrap();
+            let float_out = builder.add_value("float_out", None);
+            let int_out = builder.add_value("int_out", None);
+            builder.add_output(float_out);
+            builder.add_output(int_out);
+            builder
+                .add_operator(
+                    "float_identity",
+                    "Identity",
+                    &OpAttrs::new(),
+                    &[Some(float_node)],
+                    &[float_out],
+                )
+                .unwrap();
+            builder
+                .add_operator(
+                    "int_identity",
+                    "Identity",
+                    &OpAttrs::new(),
+                    &[Some(int_node)],
+                    &[int_out],
+                )
+                .unwrap();
+
+            let buffer = builder.finish();
+            let model = Model::load(buffer).unwrap();
+            let mut result = model
+                .run(&[], &[float_out as usize, int_out as usize], None)
+                .unwrap();
+
+            assert_eq!(result.remove(1).into_int().unwrap(), ints);
+            assert_eq!(result.remove(0).into_float().unwrap(), floats);
+        }
     }
 
     #[test]
-    fn test_input_shape() {
-        let buffer = generate_model_buffer();
-        let model = Model::load(buffer).unwrap();
-        assert_eq!(
-            model.input_shape(0),
-            Some(vec![
-                Dimension::Fixed(1),
-                Dimension::Fixed(2),
-                Dimension::Fixed(2),
-            ])
-        );
+    fn test_bf16_constant() {
+        let mut rng = XorShiftRng::new(1234);
+        let weights = Tensor::rand(&[16, 8], &mut rng).map(|x| BF16::from_f32(*x));
+        let input = Tensor::rand(&[5, 16], &mut rng);
+
+        let mut builder = ModelBuilder::new();
+        let input_node = builder.add_value("input", None);
+        let weights_node = builder.add_bf16_constant(&weights);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+        builder
+            .add_operator(
+                "matmul",
+                "MatMul",
+                &OpAttrs::new(),
+                &[Some(input_node), Some(weights_node)],
+                &[output_node],
+            )
+            .unwrap();
+
+        let model = Model::load(builder.finish()).unwrap();
+
+        // Constants are converted to `f32` when returned as outputs.
+        let weights_f32 = weights.map(|x| x.to_f32());
+        let mut result = model.run(&[], &[weights_node as usize], None).unwrap();
+        assert_eq!(result.remove(0).into_float().unwrap(), weights_f32);
+
+        // Converting `BF16` to `f32` is exact, so the output should match a
+        // MatMul with the converted weights.
+        let expected =
+            crate::ops::matmul(&TensorPool::new(), input.view(), weights_f32.view()).unwrap();
+        let result = model
+            .run_one(input.view().into(), None)
+            .unwrap()
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &expected).unwrap();
+
+        // The weights keep their type when the model is serialized.
+        let model = Model::load(model.serialize().unwrap()).unwrap();
+        let weights_dtype = model
+            .node_info(weights_node as usize)
+            .and_then(|info| info.dtype());
+        assert_eq!(weights_dtype, Some(DataType::BFloat16));
+        let result = model
+            .run_one(input.view().into(), None)
+            .unwrap()
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &expected).unwrap();
     }
 
     #[test]
-    fn test_load_and_run_model() {
-        let buffer = generate_model_buffer();
+    fn test_run_intermediate_output() {
+        // Build a model computing `0.5 * x * (1 + erf(x / sqrt(2)))`, which
+        // is fused into a `Gelu` operator when optimizations are enabled.
+        let mut builder = ModelBuilder::new();
+        let input_node = builder.add_value("input", None);
+        let sqrt_2 = builder.add_float_constant(&tensor!(std::f32::consts::SQRT_2));
+        let one = builder.add_float_constant(&tensor!(1.));
+        let half = builder.add_float_constant(&tensor!(0.5));
+        let div_out = builder.add_value("div_out", None);
+        let erf_out = builder.add_value("erf_out", None);
+        let add_out = builder.add_value("add_out", None);
+        let mul_out = builder.add_value("mul_out", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
 
-        let model = Model::load(buffer).unwrap();
-        let input_id = model.input_ids()[0];
-        let output_id = model.output_ids()[0];
+        let ops = [
+            ("Div", [input_node, sqrt_2], div_out),
+            ("Add", [one, erf_out], add_out),
+            ("Mul", [add_out, input_node], mul_out),
+            ("Mul", [mul_out, half], output_node),
+        ];
+        builder
+            .add_operator("erf", "Erf", &OpAttrs::new(), &[Some(div_out)], &[erf_out])
+            .unwrap();
+        for (i, (op_type, inputs, output)) in ops.into_iter().enumerate() {
+            builder
+                .add_operator(
+                    &format!("op_{}", i),
+                    op_type,
+                    &OpAttrs::new(),
+                    &inputs.map(Some),
+                    &[output],
+                )
+                .unwrap();
+        }
+        let buffer = builder.finish();
 
-        let input = generate_input();
+        let input = tensor!([-1., 0., 1.]);
 
-        // Test a normal model run.
-        let result = model
-            .run(&[(input_id, (&input).into())], &[output_id], None)
+        // With optimizations disabled, the model is run as converted and any
+        // intermediate value can be requested alongside the model outputs.
+        let model = ModelOptions::with_all_ops()
+            .with_optimization(false)
+            .load(buffer.clone())
             .unwrap();
-        let result_tensor = check_output(result);
+        let input_id = model.node_id("input").unwrap();
+        let output_id = model.node_id("output").unwrap();
+        let plan = model.plan_summary(&[input_id], &[output_id]).unwrap();
+        assert!(plan.contains("Erf") && !plan.contains("Gelu"));
+
+        let inputs = HashMap::from([("input", (&input).into())]);
+        let mut outputs = model
+            .run_map(&inputs, &["div_out", "erf_out", "output"], None)
+            .unwrap();
+        let div_out: Tensor<f32> = outputs.take("div_out").unwrap();
+        let erf_out: Tensor<f32> = outputs.take("erf_out").unwrap();
+        let output: Tensor<f32> = outputs.take("output").unwrap();
+
+        let expected_div = input.map(|x| x / std::f32::consts::SQRT_2);
+        expect_equal(&div_out, &expected_div).unwrap();
+        assert_eq!(erf_out.shape(), &[3]);
+        assert_eq!(erf_out[[1]], 0.);
+
+        // With optimizations enabled, the output is computed by a fused
+        // operator and should match the unoptimized model.
+        let optimized_model = Model::load(buffer).unwrap();
+        let plan = optimized_model
+            .plan_summary(&[input_id], &[output_id])
+            .unwrap();
+        assert!(plan.contains("Gelu") && !plan.contains("Erf"));
 
-        // Test a partial run. Since we are providing all inputs, this works the
-        // same as `Model::run`. See `Graph::partial_run` tests for other cases.
-        let partial_run_result = model
-            .partial_run(&[(input_id, (&input).into())], &[output_id], None)
+        let expected: Tensor<f32> = optimized_model
+            .run_one((&input).into(), None)
+            .unwrap()
+            .try_into()
             .unwrap();
+        expect_equal(&output, &expected).unwrap();
+
+        // Values which are not computed by any operator are reported by name.
+        let result = optimized_model.run(&[], &[input_id], None);
         assert_eq!(
-            partial_run_result,
-            vec![(output_id, Output::FloatTensor(result_tensor))]
+            result.err(),
+            Some(RunError::PlanningError(
+                "Missing output \"input\"".to_string()
+            ))
         );
     }
 
     #[test]
-    fn test_load_file() {
-        let buffer = generate_model_buffer();
-        std::fs::write("model-load-test.rten", buffer).unwrap();
+    fn test_prepack_weights() {
+        let mut rng = XorShiftRng::new(1234);
+        let dense_weights = Tensor::<f32>::rand(&[16, 24], &mut rng);
+
+        // Weights which are sparse enough to be prepacked in sparse form.
+        let mut sparse_weights = Tensor::<f32>::zeros(&[16, 24]);
+        for i in 0..16 {
+            sparse_weights[[i, (i * 7) % 24]] = 1. + i as f32;
+        }
 
-        let model = Model::load_file("model-load-test.rten").unwrap();
-        let input_id = model.input_ids()[0];
-        let output_id = model.output_ids()[0];
+        for weights in [dense_weights, sparse_weights] {
+            let mut builder = ModelBuilder::new();
+            let input_node = builder.add_value("input", None);
+            let weights_node = builder.add_float_constant(&weights);
+            let output_node = builder.add_value("output", None);
+            builder.add_input(input_node);
+            builder.add_output(output_node);
+            builder
+                .add_operator(
+                    "matmul",
+                    "MatMul",
+                    &OpAttrs::new(),
+                    &[Some(input_node), Some(weights_node)],
+                    &[output_node],
+                )
+                .unwrap();
+            let buffer = builder.finish();
 
-        let input = generate_input();
-        let result = model
-            .run(&[(input_id, (&input).into())], &[output_id], None)
-            .unwrap();
-        check_output(result);
+            let model = Model::load(buffer.clone()).unwrap();
+            let prepacked_model = ModelOptions::with_all_ops()
+                .prepack_weights(true)
+                .load(buffer)
+                .unwrap();
+
+            for input_shape in [&[1, 16][..], &[5, 16], &[2, 3, 16]] {
+                let input = Tensor::<f32>::rand(input_shape, &mut rng);
+                let expected: Tensor<f32> = model
+                    .run_one((&input).into(), None)
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let output: Tensor<f32> = prepacked_model
+                    .run_one((&input).into(), None)
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                expect_equal(&output, &expected).unwrap();
+            }
+        }
     }
 
-    #[cfg(feature = "mmap")]
     #[test]
-    fn test_load_mmap() {
-        let buffer = generate_model_buffer();
-        std::fs::write("model-load-test.rten", buffer).unwrap();
+    fn test_override_prepacked_constant() {
+        let dense_weights = Tensor::<f32>::full(&[16, 24], 1.);
 
-        let model = unsafe { Model::load_mmap("model-load-test.rten").unwrap() };
-        let input_id = model.input_ids()[0];
-        let output_id = model.output_ids()[0];
+        // Weights which are sparse enough to be prepacked in sparse form.
+        let mut sparse_weights = Tensor::<f32>::zeros(&[16, 24]);
+        for i in 0..16 {
+            sparse_weights[[i, (i * 7) % 24]] = 1.;
+        }
 
-        let input = generate_input();
-        let result = model
-            .run(&[(input_id, (&input).into())], &[output_id], None)
-            .unwrap();
-        check_output(result);
-    }
+        for weights in [dense_weights, sparse_weights] {
+            let new_weights = weights.map(|x| x * 2.);
+
+            let mut builder = ModelBuilder::new();
+            let input_node = builder.add_value("input", None);
+            let weights_node = builder.add_float_constant(&weights);
+            let output_node = builder.add_value("output", None);
+            builder.add_input(input_node);
+            builder.add_output(output_node);
+            builder
+                .add_operator(
+                    "matmul",
+                    "MatMul",
+                    &OpAttrs::new(),
+                    &[Some(input_node), Some(weights_node)],
+                    &[output_node],
+                )
+                .unwrap();
+            let buffer = builder.finish();
 
-    #[test]
-    fn test_run_one() {
-        let buffer = generate_model_buffer();
-        let model = Model::load(buffer).unwrap();
+            let mut model = ModelOptions::with_all_ops()
+                .prepack_weights(true)
+                .load(buffer)
+                .unwrap();
+            model
+                .override_constant(weights_node as usize, new_weights.clone().into())
+                .unwrap();
 
-        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
-        let result: Tensor<f32> = model
-            .run_one((&input).into(), None)
-            .unwrap()
-            .try_into()
-            .unwrap();
+            // Use an input of ones with several rows, so that the prepacked
+            // weights are used rather than a vector-matrix product. Each
+            // output row is then the column sums of the weights.
+            let input = Tensor::<f32>::full(&[5, 16], 1.);
+            let output: Tensor<f32> = model
+                .run_one((&input).into(), None)
+                .unwrap()
+                .try_into()
+                .unwrap();
 
-        assert_eq!(result.shape(), &[2, 2, 2]);
-        assert_eq!(result.to_vec(), &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]);
+            let col_sums: Vec<f32> = (0..24)
+                .map(|col| (0..16).map(|row| new_weights[[row, col]]).sum())
+                .collect();
+            let expected = Tensor::from_data(&[1, 24], col_sums)
+                .broadcast([5, 24].as_slice())
+                .to_tensor();
+            assert_eq!(output, expected);
+        }
     }
 
     #[test]
-    fn test_omitted_optional_inputs() {
+    fn test_scan_subgraph() {
         let mut builder = ModelBuilder::new();
 
-        let output_node = builder.add_value("output", None);
-        builder.add_output(output_node);
-        builder.add_operator("shape", OpType::Shape, &[None], &[output_node]);
+        // Build a body which computes a cumulative sum.
+        builder.begin_subgraph();
+        let sum_in = builder.add_value("sum_in", None);
+        let x_in = builder.add_value("x_in", None);
+        let sum_out = builder.add_value("sum_out", None);
+        let scan_out = builder.add_value("scan_out", None);
+        builder.add_input(sum_in);
+        builder.add_input(x_in);
+        builder.add_output(sum_out);
+        builder.add_output(scan_out);
+        builder
+            .add_operator(
+                "add",
+                "Add",
+                &OpAttrs::new(),
+                &[Some(sum_in), Some(x_in)],
+                &[sum_out],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "identity",
+                "Identity",
+                &OpAttrs::new(),
+                &[Some(sum_out)],
+                &[scan_out],
+            )
+            .unwrap();
+        let body = builder.end_subgraph().unwrap();
+
+        let init = builder.add_value("init", None);
+        let xs = builder.add_value("xs", None);
+        let final_sum = builder.add_value("final_sum", None);
+        let cumsum = builder.add_value("cumsum", None);
+        builder.add_input(init);
+        builder.add_input(xs);
+        builder.add_output(final_sum);
+        builder.add_output(cumsum);
+        builder
+            .add_operator(
+                "scan",
+                "Scan",
+                &OpAttrs::new().with("body", body).with("num_scan_inputs", 1),
+                &[Some(init), Some(xs)],
+                &[final_sum, cumsum],
+            )
+            .unwrap();
 
         let buffer = builder.finish();
         let model = Model::load(buffer).unwrap();
 
-        let result = model.run(&[], &[output_node as usize], None);
+        let init_val = tensor!([0.]);
+        let xs_val = tensor!((3, 1); [1., 2., 3.]);
+        let mut result = model
+            .run(
+                &[
+                    (init as usize, (&init_val).into()),
+                    (xs as usize, (&xs_val).into()),
+                ],
+                &[final_sum as usize, cumsum as usize],
+                None,
+            )
+            .unwrap();
 
-        assert_eq!(
-            result.err(),
-            Some(RunError::OperatorError {
-                name: "shape".to_string(),
-                error: OpError::MissingInputs
-            })
-        );
+        let cumsum_val = result.remove(1).into_float().unwrap();
+        assert_eq!(cumsum_val, tensor!((3, 1); [1., 3., 6.]));
+        let final_val = result.remove(0).into_float().unwrap();
+        assert_eq!(final_val, tensor!([6.]));
     }
 
     // This test exercises basic execution of all operators. It doesn't check
@@ -1581,14 +3925,18 @@ mod tests {
         // Names of all operator output nodes.
         let mut op_outputs = Vec::new();
 
-        let mut add_operator =
-            |builder: &mut ModelBuilder, name: &str, op: OpType, input_nodes: &[Option<u32>]| {
-                let output_name = format!("{}_out", name);
-                let op_output_node = builder.add_value(&output_name, None);
-                builder.add_operator(name, op, input_nodes, &[op_output_node]);
-                op_outputs.push(output_name);
-                op_output_node
-            };
+        let mut add_operator = |builder: &mut ModelBuilder,
+                                name: &str,
+                                attrs: OpAttrs,
+                                input_nodes: &[Option<u32>]| {
+            let output_name = format!("{}_out", name);
+            let op_output_node = builder.add_value(&output_name, None);
+            builder
+                .add_operator(name, name, &attrs, input_nodes, &[op_output_node])
+                .unwrap();
+            op_outputs.push(output_name);
+            op_output_node
+        };
 
         // Add a new operator node and associated output value node to the model.
         //
@@ -1598,16 +3946,16 @@ mod tests {
                 add_operator(
                     &mut builder,
                     stringify!($op_name),
-                    OpType::$op_name,
+                    OpAttrs::new(),
                     &$op_inputs.map(Some),
                 )
             };
 
-            ($op_name:ident, $op_inputs:expr, $attrs: tt) => {
+            ($op_name:ident, $op_inputs:expr, { $($attr:ident: $value:expr),* $(,)? }) => {
                 add_operator(
                     &mut builder,
                     stringify!($op_name),
-                    OpType::$op_name(ops::$op_name $attrs),
+                    OpAttrs::new()$(.with(stringify!($attr), $value))*,
                     &$op_inputs.map(Some),
                 )
             };
@@ -1624,8 +3972,10 @@ mod tests {
         add_operator!(AveragePool, [input_node], {
             kernel_size: [2, 2],
             strides: [2, 2],
-            padding: [0, 0, 0, 0].into(),
+            pads: [0, 0, 0, 0],
             count_include_pad: false,
+            ceil_mode: true,
+            dilations: [1, 1],
         });
 
         // Dummy value for BatchNormalization inputs which are vectors with
@@ -1644,8 +3994,17 @@ mod tests {
             { epsilon: 1e-5 }
         );
 
-        add_operator!(Cast, [input_node], { to: ops::DataType::Float });
+        add_operator!(BitShift, [input_bool, input_bool], { direction: "right" });
+        add_operator!(BitwiseAnd, [input_bool, input_bool]);
+        add_operator!(BitwiseNot, [input_bool]);
+        add_operator!(BitwiseOr, [input_bool, input_bool]);
+        add_operator!(BitwiseXor, [input_bool, input_bool]);
+
+        add_operator!(Cast, [input_node], { to: "float" });
+        add_operator!(CastLike, [input_node, input_bool]);
         add_operator!(Ceil, [input_node]);
+        add_operator!(Celu, [input_node], { alpha: 1.0 });
+        add_operator!(ChannelShuffle, [input_node], { groups: 1u32 });
 
         let clip_min = builder.add_float_constant(&tensor!(1.));
         let clip_max = builder.add_float_constant(&tensor!(6.));
@@ -1653,18 +4012,21 @@ mod tests {
         add_operator!(Concat, [input_node, input_node], { axis: 0 });
 
         let shape = builder.add_int_constant(&Tensor::from_data(&[3], vec![1, 5, 10]));
-        add_operator!(ConstantOfShape, [shape], { value: Scalar::Int(42) });
+        add_operator!(ConstantOfShape, [shape], { value: 42 });
 
         add_operator!(Conv, [input_node, kernel], {
             dilations: vec![1, 1],
             groups: 1,
-            padding: [1, 1, 1, 1].into(),
+            pads: [1, 1, 1, 1],
             strides: vec![1, 1],
         });
 
         add_operator!(ConvTranspose, [input_node, kernel], {
             strides: vec![2, 2],
-            padding: [0, 0, 0, 0].into(),
+            pads: [0, 0, 0, 0],
+            dilations: vec![1, 1],
+            groups: 1,
+            output_padding: vec![1, 1],
         });
         add_operator!(Cos, [input_node]);
         add_operator!(Div, [input_node, input_node]);
@@ -1672,6 +4034,7 @@ mod tests {
         add_operator!(Equal, [input_node, input_node]);
         add_operator!(Erf, [input_node]);
         add_operator!(Exp, [input_node]);
+        add_operator!(EyeLike, [input_2d], { dtype: "int32", k: 1 });
 
         let expand_shape_val = tensor!([2, 2, 3, 3]);
         let expand_shape = builder.add_int_constant(&expand_shape_val);
@@ -1688,6 +4051,22 @@ mod tests {
         let gather_elements_indices = builder.add_int_constant(&gather_elements_indices_val);
         add_operator!(GatherElements, [input_node, gather_elements_indices], { axis: 0 });
 
+        let gbq_data = builder.add_int_constant(&tensor!((2, 1); [0x12345678, -1]));
+        let gbq_scales = builder.add_float_constant(&tensor!((2, 1); [0.5, 0.25]));
+        add_operator!(GatherBlockQuantized, [gbq_data, gather_indices, gbq_scales], {
+            bits: 4u32,
+            block_size: 8u32,
+        });
+
+        let grid_val = Tensor::from_data(&[1, 2, 2, 2], vec![-1., -1., 1., -1., -1., 1., 0.5, 0.5]);
+        let grid = builder.add_float_constant(&grid_val);
+        add_operator!(GridSample, [input_node, grid], {
+            mode: "linear",
+            padding_mode: "border",
+            align_corners: false,
+        });
+
+        add_operator!(Gelu, [input_node], { approximate: false });
         add_operator!(Gemm, [input_2d, input_2d], {
             alpha: 1.0,
             beta: 1.0,
@@ -1695,6 +4074,7 @@ mod tests {
             transpose_b: false,
         });
         add_operator!(GlobalAveragePool, [input_node]);
+        add_operator!(GlobalMaxPool, [input_node]);
         add_operator!(Greater, [input_node, input_node]);
         add_operator!(GreaterOrEqual, [input_node, input_node]);
         add_operator!(HardSigmoid, [input_node], {
@@ -1706,6 +4086,12 @@ mod tests {
         // TODO - Add GRU operator
 
         add_operator!(Identity, [input_node]);
+        add_operator!(Dropout, [input_node], { seed: 1 });
+        add_operator!(ImageToTensor, [input_node], {
+            scale: 0.5,
+            mean: vec![0.5f32; 3],
+            std: vec![0.25f32; 3],
+        });
 
         let instance_norm_scale_val = tensor!([1.0]);
         let instance_norm_scale = builder.add_float_constant(&instance_norm_scale_val);
@@ -1713,7 +4099,7 @@ mod tests {
         let instance_norm_bias = builder.add_float_constant(&instance_norm_bias_val);
         add_operator!(InstanceNormalization, [
             input_node, instance_norm_scale, instance_norm_bias
-        ], { epsilon: Some(1e-5) });
+        ], { epsilon: 1e-5 });
 
         let layer_norm_scale_val = tensor!([1.0]);
         let layer_norm_scale = builder.add_float_constant(&layer_norm_scale_val);
@@ -1721,29 +4107,52 @@ mod tests {
         let layer_norm_bias = builder.add_float_constant(&layer_norm_bias_val);
         add_operator!(LayerNormalization, [
             input_node, layer_norm_scale, layer_norm_bias
-        ], { axis: -1, epsilon: Some(1e-5) });
+        ], { axis: -1, epsilon: 1e-5 });
 
         add_operator!(LeakyRelu, [input_node], { alpha: 0.01 });
         add_operator!(Less, [input_node, input_node]);
         add_operator!(LessOrEqual, [input_node, input_node]);
         add_operator!(Log, [input_node]);
         add_operator!(LogSoftmax, [input_node], { axis: 1 });
+        add_operator!(LpNormalization, [input_node], { axis: 1, p: 2 });
+        add_operator!(LpPool, [input_node], {
+            kernel_size: [2, 2],
+            strides: [2, 2],
+            pads: [0, 0, 0, 0],
+            ceil_mode: false,
+            dilations: [1, 1],
+            p: 2,
+        });
 
         // TODO - Add LSTM operator
 
         add_operator!(MatMul, [input_2d, input_2d]);
+        add_operator!(MatMulNBits, [input_node, gbq_data, gbq_scales], {
+            bits: 4u32,
+            block_size: 8u32,
+        });
         add_operator!(Max, [input_node, input_node]);
         add_operator!(MaxPool, [input_node], {
             kernel_size: [2, 2],
             strides: [2, 2],
-            padding: [0, 0, 0, 0].into(),
+            pads: [0, 0, 0, 0],
+            ceil_mode: true,
+            dilations: [1, 1],
         });
         add_operator!(Mean, [input_node, input_node]);
+        add_operator!(MeanVarianceNormalization, [input_node], {
+            axes: vec![0i32, 2, 3],
+        });
         add_operator!(Min, [input_node, input_node]);
+        add_operator!(Mish, [input_node]);
         add_operator!(Mod, [input_node, input_node], {
             fmod: false,
         });
         add_operator!(Mul, [input_node, input_node]);
+        add_operator!(Multinomial, [input_2d], {
+            dtype: "int64",
+            sample_size: 2,
+        });
         add_operator!(Neg, [input_node]);
 
         let nms_n_boxes = 10;
@@ -1756,10 +4165,14 @@ mod tests {
         let nms_score_threshold = builder.add_float_constant(&tensor!(0.2));
 
         add_operator!(NonMaxSuppression, [nms_boxes, nms_scores, nms_max_outputs_per_class, nms_iou_threshold, nms_score_threshold], {
-            box_order: BoxOrder::CenterWidthHeight,
+            box_order: "center_width_height",
         });
 
         add_operator!(NonZero, [input_node]);
+        add_operator!(Normalize, [input_node], {
+            mean: vec![0.5f32],
+            std: vec![0.25f32],
+        });
         add_operator!(Not, [input_bool]);
 
         let onehot_indices = builder.add_int_constant(&tensor!([0, 1, 2]));
@@ -1769,33 +4182,33 @@ mod tests {
             axis: -1,
         });
 
+        add_operator!(Optional, [input_node]);
+        add_operator!(OptionalGetElement, [input_node]);
+        add_operator!(OptionalHasElement, [input_node]);
         add_operator!(Or, [input_bool, input_bool]);
 
         let pads = builder.add_int_constant(&Tensor::from_data(&[8], vec![0, 0, 1, 1, 0, 0, 1, 1]));
-        add_operator!(Pad, [input_node, pads]);
+        add_operator!(Pad, [input_node, pads], { mode: "reflect" });
+        add_operator!(PermuteChannels, [input_node], { order: vec![0u32, 0] });
         add_operator!(Pow, [input_node, input_node]);
 
         add_operator!(RandomNormal, [], {
             shape: vec![50, 50],
             mean: 0.,
             scale: 1.,
-            seed: None,
         });
         add_operator!(RandomNormalLike, [input_node], {
             mean: 0.,
             scale: 1.,
-            seed: None,
         });
         add_operator!(RandomUniform, [], {
             shape: vec![50, 50],
             low: 0.,
             high: 1.,
-            seed: None,
         });
         add_operator!(RandomUniformLike, [input_node], {
             low: 0.,
             high: 1.,
-            seed: None,
         });
 
         let range_start_node = builder.add_value("range_start", None);
@@ -1807,30 +4220,38 @@ mod tests {
         );
 
         add_operator!(Reciprocal, [input_node]);
+        add_operator!(ReduceL2, [input_node], {
+            keep_dims: false,
+        });
+        add_operator!(ReduceLogSumExp, [input_node], {
+            keep_dims: false,
+        });
         add_operator!(ReduceMean, [input_node], {
-            axes: None,
             keep_dims: false,
         });
         add_operator!(ReduceMax, [input_node], {
-            axes: None,
             keep_dims: false,
         });
         add_operator!(ReduceMin, [input_node], {
-            axes: None,
             keep_dims: false,
         });
         add_operator!(ReduceProd, [input_node], {
-            axes: None,
             keep_dims: false,
         });
         add_operator!(ReduceSum, [input_node], {
-            axes: None,
             keep_dims: false,
         });
+        add_operator!(ReduceStd, [input_node], {
+            keep_dims: false,
+            correction: 1u32,
+        });
         add_operator!(ReduceSumSquare, [input_node], {
-            axes: None,
             keep_dims: false,
         });
+        add_operator!(ReduceVar, [input_node], {
+            keep_dims: false,
+            correction: 1u32,
+        });
         add_operator!(Relu, [input_node]);
 
         let new_shape = builder.add_int_constant(&Tensor::from_data(&[1], vec![9]));
@@ -1843,14 +4264,25 @@ mod tests {
         let resize_roi = builder.add_float_constant(&resize_roi_val);
         let resize_scales = builder.add_float_constant(&resize_scales_val);
         add_operator!(Resize, [input_node, resize_roi, resize_scales], {
-            mode: ResizeMode::Nearest,
-            nearest_mode: NearestMode::default(),
-            coord_mode: CoordTransformMode::default()
+            mode: "nearest",
+            nearest_mode: "round_prefer_floor",
+            coord_mode: "half_pixel",
         });
 
         add_operator!(Round, [input_node]);
+        add_operator!(ScaledDotProductAttention, [input_node, input_node, input_node], {
+            scale: 0.5,
+        });
 
         add_operator!(Shape, [input_node]);
+        add_operator!(Selu, [input_node], { alpha: 1.5, gamma: 1.0 });
+
+        let seq = add_operator!(SequenceConstruct, [input_node, input_node]);
+        let seq_pos = builder.add_int_constant(&Tensor::from_scalar(-1));
+        add_operator!(SequenceInsert, [seq, input_node, seq_pos]);
+        add_operator!(SequenceAt, [seq, seq_pos]);
+        add_operator!(SequenceLength, [seq]);
+        add_operator!(ConcatFromSequence, [seq], { axis: 0, new_axis: true });
         add_operator!(Sigmoid, [input_node]);
         add_operator!(Sign, [input_node]);
         add_operator!(Sin, [input_node]);
@@ -1863,7 +4295,7 @@ mod tests {
         add_operator!(
             ScatterElements,
             [input_node, scatter_elem_indices, scatter_elem_updates],
-            { axis: 0, reduction: None }
+            { axis: 0, reduction: "none" }
         );
 
         let const_0 = builder.add_int_constant(&Tensor::from_data(&[1], vec![0]));
@@ -1871,6 +4303,7 @@ mod tests {
         add_operator!(Slice, [input_node, const_0, const_1, const_0]);
 
         add_operator!(Softplus, [input_node]);
+        add_operator!(Softsign, [input_node]);
         add_operator!(Softmax, [input_node], { axis: 1 });
         add_operator!(Sqrt, [input_node]);
         add_operator!(Squeeze, [input_node]);
@@ -1878,17 +4311,35 @@ mod tests {
         let split_splits = builder.add_int_constant(&tensor!([1, 2]));
         let split_out_1 = builder.add_value("Split_out_1", None);
         let split_out_2 = builder.add_value("Split_out_2", None);
-        builder.add_operator(
-            "Split",
-            OpType::Split(ops::Split { axis: 1 }),
-            &[input_2d, split_splits].map(Some),
-            &[split_out_1, split_out_2],
-        );
+        builder
+            .add_operator(
+                "Split",
+                "Split",
+                &OpAttrs::new().with("axis", 1),
+                &[input_2d, split_splits].map(Some),
+                &[split_out_1, split_out_2],
+            )
+            .unwrap();
+
+        let split_even_out_1 = builder.add_value("Split_even_out_1", None);
+        let split_even_out_2 = builder.add_value("Split_even_out_2", None);
+        builder
+            .add_operator(
+                "Split_even",
+                "Split",
+                &OpAttrs::new().with("axis", 1).with("num_outputs", 2u32),
+                &[Some(input_2d)],
+                &[split_even_out_1, split_even_out_2],
+            )
+            .unwrap();
+
+        add_operator!(SplitToSequence, [input_node], { axis: 2, keep_dims: false });
 
         add_operator!(Sub, [input_node, input_node]);
         add_operator!(Sum, [input_node, input_node]);
         add_operator!(Tan, [input_node]);
         add_operator!(Tanh, [input_node]);
+        add_operator!(ThresholdedRelu, [input_node], { alpha: 1.0 });
 
         let tile_repeats = builder.add_int_constant(&tensor!([1, 2, 3, 4]));
         add_operator!(Tile, [input_node, tile_repeats]);
@@ -1896,18 +4347,20 @@ mod tests {
         let topk_k = builder.add_int_constant(&tensor!(3));
         let topk_out_values = builder.add_value("TopK_out_values", None);
         let topk_out_indices = builder.add_value("TopK_out_indices", None);
-        builder.add_operator(
-            "TopK",
-            OpType::TopK(ops::TopK {
-                largest: true,
-                sorted: true,
-                axis: Some(-1),
-            }),
-            &[input_2d, topk_k].map(Some),
-            &[topk_out_values, topk_out_indices],
-        );
+        builder
+            .add_operator(
+                "TopK",
+                "TopK",
+                &OpAttrs::new()
+                    .with("largest", true)
+                    .with("sorted", true)
+                    .with("axis", -1),
+                &[input_2d, topk_k].map(Some),
+                &[topk_out_values, topk_out_indices],
+            )
+            .unwrap();
 
-        add_operator!(Transpose, [input_node], { perm: None });
+        add_operator!(Transpose, [input_node]);
 
         add_operator!(Trilu, [input_node], { upper: true });
 
@@ -1925,6 +4378,11 @@ mod tests {
 
         let model = Model::load(buffer).unwrap();
 
+        // Check that all operators survive a round trip through serialization.
+        let serialized = model.serialize().unwrap();
+        let reloaded = Model::load(serialized.clone()).unwrap();
+        assert_eq!(reloaded.serialize().unwrap(), serialized);
+
         // Most ops are tested with one of several standard inputs:
         //
         //  - 4D float tensor (like an NCHW image)
@@ -1935,11 +4393,15 @@ mod tests {
         let input_bool_data: Tensor<i32> = tensor!([0, 1, 1]);
         for output in op_outputs {
             if [
+                "EyeLike_out",
                 "Gemm_out",
                 "MatMul_out",
+                "Multinomial_out",
                 "Range_out",
                 "Split_out_1",
                 "Split_out_2",
+                "Split_even_out_1",
+                "Split_even_out_2",
                 "TopK_out_indices",
                 "TopK_out_values",
                 "Where_out",
@@ -1966,10 +4428,14 @@ mod tests {
 
         // Outputs of ops tested with a 2D input.
         let outputs = vec![
+            "EyeLike_out",
             "Gemm_out",
             "MatMul_out",
+            "Multinomial_out",
             "Split_out_1",
             "Split_out_2",
+            "Split_even_out_1",
+            "Split_even_out_2",
             "TopK_out_indices",
             "TopK_out_values",
         ];
@@ -1,171 +1,126 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, Vector, WIPOffset};
 use rten_tensor::prelude::*;
-use rten_tensor::Tensor;
-
-use crate::graph::Dimension;
-use crate::ops::{
-    ArgMax, ArgMin, AveragePool, BatchNormalization, BoxOrder, Cast, Concat, ConstantOfShape, Conv,
-    ConvTranspose, CoordTransformMode, DataType, Elu, Flatten, Gather, GatherElements, GatherND,
-    Gemm, HardSigmoid, InstanceNormalization, LayerNormalization, LeakyRelu, LogSoftmax, MaxPool,
-    Mod, NearestMode, NonMaxSuppression, OneHot, Padding, ReduceMax, ReduceMean, ReduceMin,
-    ReduceProd, ReduceSum, ReduceSumSquare, Reshape, Resize, ResizeMode, Scalar, ScatterElements,
-    ScatterReduction, Softmax, Split, TopK, Transpose, Trilu,
-};
-use crate::schema_generated as sg;
+use rten_tensor::{Tensor, TensorView};
 
-#[cfg(feature = "random")]
-use crate::ops::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
-
-/// Enum of all the built-in operators
-pub enum OpType {
-    Abs,
-    Acos,
-    Add,
-    And,
-    ArgMax(ArgMax),
-    ArgMin(ArgMin),
-    Asin,
-    Atan,
-    AveragePool(AveragePool),
-    BatchNormalization(BatchNormalization),
-    Cast(Cast),
-    Ceil,
-    Clip,
-    Concat(Concat),
-    ConstantOfShape(ConstantOfShape),
-    Conv(Conv),
-    ConvTranspose(ConvTranspose),
-    Cos,
-    Div,
-    Elu(Elu),
-    Equal,
-    Erf,
-    Exp,
-    Expand,
-    Flatten(Flatten),
-    Floor,
-    Gather(Gather),
-    GatherElements(GatherElements),
-    GatherND(GatherND),
-    Gemm(Gemm),
-    GlobalAveragePool,
-    Greater,
-    GreaterOrEqual,
-    HardSigmoid(HardSigmoid),
-    HardSwish,
-    Identity,
-    InstanceNormalization(InstanceNormalization),
-    LayerNormalization(LayerNormalization),
-    LeakyRelu(LeakyRelu),
-    Less,
-    LessOrEqual,
-    Log,
-    LogSoftmax(LogSoftmax),
-    MatMul,
-    Max,
-    MaxPool(MaxPool),
-    Mean,
-    Min,
-    Mod(Mod),
-    Mul,
-    Neg,
-    NonMaxSuppression(NonMaxSuppression),
-    NonZero,
-    Not,
-    OneHot(OneHot),
-    Or,
-    Pad,
-    Pow,
-
-    #[cfg(feature = "random")]
-    RandomNormal(RandomNormal),
-    #[cfg(feature = "random")]
-    RandomNormalLike(RandomNormalLike),
-    #[cfg(feature = "random")]
-    RandomUniform(RandomUniform),
-    #[cfg(feature = "random")]
-    RandomUniformLike(RandomUniformLike),
-
-    Range,
-    Reciprocal,
-    ReduceMax(ReduceMax),
-    ReduceMean(ReduceMean),
-    ReduceMin(ReduceMin),
-    ReduceProd(ReduceProd),
-    ReduceSum(ReduceSum),
-    ReduceSumSquare(ReduceSumSquare),
-    Relu,
-    Reshape(Reshape),
-    Resize(Resize),
-    Round,
-    ScatterElements(ScatterElements),
-    Shape,
-    Sigmoid,
-    Sign,
-    Sin,
-    Size,
-    Slice,
-    Softmax(Softmax),
-    Softplus,
-    Split(Split),
-    Sqrt,
-    Squeeze,
-    Sub,
-    Sum,
-    Tan,
-    Tanh,
-    Tile,
-    TopK(TopK),
-    Transpose(Transpose),
-    Trilu(Trilu),
-    Unsqueeze,
-    Where,
-    Xor,
-}
+use crate::compression::{compress, Compression};
+use crate::graph::{Constant, Dimension, Graph, Node, NodeId};
+use crate::model_metadata::ModelMetadata;
+use crate::number::BF16;
+use crate::op_registry::{AttrValue, OpAttrs};
+use crate::ops::DataType;
+use crate::schema_generated as sg;
 
-/// Builds a serialized FlatBuffers representation of a model using the schema
-/// defined in schema.fbs.
+/// Builds a model in the `.rten` format.
+///
+/// Models for deployment are normally created by converting ONNX models with
+/// the `rten-convert` tool. `ModelBuilder` allows tools written in Rust to
+/// construct models directly. To save a model after loading and modifying it,
+/// use [Model::serialize](crate::Model::serialize).
+///
+/// Nodes are added using the `add_*` methods, which return an ID for the node
+/// that is used to reference it as the input or output of operators. Once all
+/// nodes have been added, [ModelBuilder::finish] returns the serialized model,
+/// which can be passed to [Model::load](crate::Model::load) or written to a
+/// file. Methods which add operators return a [ModelBuildError] if the
+/// operator type or attributes are not valid.
 ///
-/// This exists for use in model-loading tests. Models for deployment are
-/// normally built by converting ONNX models using the Python scripts.
+/// ```
+/// use rten::{Model, ModelBuilder, OpAttrs};
+/// use rten_tensor::prelude::*;
+/// use rten_tensor::Tensor;
+///
+/// let mut builder = ModelBuilder::new();
+/// let input = builder.add_value("input", None);
+/// let bias = builder.add_float_constant(&Tensor::from([1., 2., 3.]));
+/// let output = builder.add_value("output", None);
+/// builder.add_input(input);
+/// builder.add_output(output);
+/// builder.add_operator("add", "Add", &OpAttrs::new(), &[Some(input), Some(bias)], &[output])?;
+///
+/// let model = Model::load(builder.finish())?;
+/// let result = model
+///     .run_one(Tensor::from([1., 1., 1.]).view().into(), None)?
+///     .into_float()
+///     .unwrap();
+/// assert_eq!(result.to_vec(), [2., 3., 4.]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
 pub struct ModelBuilder<'a> {
     builder: FlatBufferBuilder<'a>,
     nodes: Vec<WIPOffset<sg::Node<'a>>>,
     input_ids: Vec<u32>,
     output_ids: Vec<u32>,
     metadata: Option<WIPOffset<sg::Metadata<'a>>>,
+
+    /// Nodes, inputs and outputs of the enclosing graphs while a subgraph is
+    /// being built.
+    parent_graphs: Vec<PartialGraph<'a>>,
+
+    /// Subgraphs that have been finished, indexed by the IDs returned from
+    /// [ModelBuilder::end_subgraph].
+    subgraphs: Vec<WIPOffset<sg::Graph<'a>>>,
 }
 
+/// Nodes, inputs and outputs of a graph that is under construction.
+type PartialGraph<'a> = (Vec<WIPOffset<sg::Node<'a>>>, Vec<u32>, Vec<u32>);
+
 enum NodeData<'a> {
     Constant(WIPOffset<sg::ConstantNode<'a>>),
     Value(WIPOffset<sg::ValueNode<'a>>),
     Operator(WIPOffset<sg::OperatorNode<'a>>),
 }
 
-/// Arguments for [ModelBuilder::add_metadata].
-pub struct MetadataArgs {
-    pub onnx_hash: Option<String>,
-}
+/// Errors reported by [ModelBuilder].
+#[derive(Debug, PartialEq)]
+pub enum ModelBuildError {
+    /// The operator type is not supported by the model format.
+    UnknownOperator(String),
+
+    /// An operator attribute is missing, has a value of the wrong type, or
+    /// is not supported by the operator.
+    InvalidAttr { op_type: String, name: String },
+
+    /// [ModelBuilder::end_subgraph] was called without a matching call to
+    /// [ModelBuilder::begin_subgraph].
+    NoSubgraph,
 
-struct PadArgs {
-    pad_mode: sg::PadMode,
-    pads: Option<Vec<usize>>,
+    /// Compressing the data of a constant failed.
+    CompressionFailed(String),
+
+    /// A value cannot be stored in the model format.
+    UnsupportedValue(&'static str),
 }
 
-fn pad_args_from_padding(padding: Padding) -> PadArgs {
-    match padding {
-        Padding::Same => PadArgs {
-            pad_mode: sg::PadMode::Same,
-            pads: None,
-        },
-        Padding::Fixed(pads) => PadArgs {
-            pad_mode: sg::PadMode::Fixed,
-            pads: Some(pads.iter().copied().collect()),
-        },
+impl Display for ModelBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelBuildError::UnknownOperator(op_type) => {
+                write!(f, "unknown operator type {op_type}")
+            }
+            ModelBuildError::InvalidAttr { op_type, name } => {
+                write!(f, "{op_type}: attribute \"{name}\" is missing or invalid")
+            }
+            ModelBuildError::NoSubgraph => write!(f, "no subgraph is being built"),
+            ModelBuildError::CompressionFailed(e) => write!(f, "compression failed: {e}"),
+            ModelBuildError::UnsupportedValue(e) => write!(f, "unsupported value: {e}"),
+        }
     }
 }
 
+impl Error for ModelBuildError {}
+
+/// Arguments for [ModelBuilder::add_metadata].
+pub struct MetadataArgs {
+    /// SHA-256 hash of the ONNX model the model was converted from.
+    pub onnx_hash: Option<String>,
+}
+
 impl<'a> ModelBuilder<'a> {
+    /// Create a builder for an empty model.
     pub fn new() -> ModelBuilder<'a> {
         let builder = FlatBufferBuilder::with_capacity(1024);
         ModelBuilder {
@@ -174,6 +129,8 @@ impl<'a> ModelBuilder<'a> {
             input_ids: Vec::new(),
             output_ids: Vec::new(),
             metadata: None,
+            parent_graphs: Vec::new(),
+            subgraphs: Vec::new(),
         }
     }
 
@@ -195,6 +152,10 @@ impl<'a> ModelBuilder<'a> {
 
     /// Add a constant node (eg. weights, biases) to the model
     pub fn add_float_constant(&mut self, input: &Tensor) -> u32 {
+        self.add_float_constant_node(None, input.view())
+    }
+
+    fn add_float_constant_node(&mut self, name: Option<&str>, input: TensorView) -> u32 {
         let elts: Vec<f32> = input.to_vec();
         let data_vec = self.builder.create_vector(&elts);
 
@@ -206,6 +167,7 @@ impl<'a> ModelBuilder<'a> {
         );
 
         self.add_constant_node(
+            name,
             input.shape(),
             sg::ConstantData::FloatData,
             float_data.as_union_value(),
@@ -214,6 +176,10 @@ impl<'a> ModelBuilder<'a> {
 
     /// Add a constant node (eg. weights, biases) to the model
     pub fn add_int_constant(&mut self, input: &Tensor<i32>) -> u32 {
+        self.add_int_constant_node(None, input.view())
+    }
+
+    fn add_int_constant_node(&mut self, name: Option<&str>, input: TensorView<i32>) -> u32 {
         let elts: Vec<i32> = input.to_vec();
         let data_vec = self.builder.create_vector(&elts);
 
@@ -225,14 +191,125 @@ impl<'a> ModelBuilder<'a> {
         );
 
         self.add_constant_node(
+            name,
             input.shape(),
             sg::ConstantData::IntData,
             int_data.as_union_value(),
         )
     }
 
+    /// Add a constant node containing `BF16` weights to the model.
+    ///
+    /// These use half the space of `f32` weights. Operators which support
+    /// them, such as the "B" input of `MatMul`, convert values to `f32` as
+    /// they are used.
+    pub fn add_bf16_constant(&mut self, input: &Tensor<BF16>) -> u32 {
+        self.add_bf16_constant_node(None, input.view())
+    }
+
+    fn add_bf16_constant_node(&mut self, name: Option<&str>, input: TensorView<BF16>) -> u32 {
+        let elts: Vec<u16> = input.iter().map(|x| x.to_bits()).collect();
+        let data_vec = self.builder.create_vector(&elts);
+
+        let bf16_data = sg::BFloat16Data::create(
+            &mut self.builder,
+            &sg::BFloat16DataArgs {
+                data: Some(data_vec),
+            },
+        );
+
+        self.add_constant_node(
+            name,
+            input.shape(),
+            sg::ConstantData::BFloat16Data,
+            bf16_data.as_union_value(),
+        )
+    }
+
+    /// Add a constant node containing strings to the model
+    pub fn add_string_constant(&mut self, input: &Tensor<String>) -> u32 {
+        self.add_string_constant_node(None, input.view())
+    }
+
+    fn add_string_constant_node(&mut self, name: Option<&str>, input: TensorView<String>) -> u32 {
+        let elts: Vec<_> = input
+            .iter()
+            .map(|s| self.builder.create_string(s))
+            .collect();
+        let data_vec = self.builder.create_vector(&elts);
+
+        let string_data = sg::StringData::create(
+            &mut self.builder,
+            &sg::StringDataArgs {
+                data: Some(data_vec),
+            },
+        );
+
+        self.add_constant_node(
+            name,
+            input.shape(),
+            sg::ConstantData::StringData,
+            string_data.as_union_value(),
+        )
+    }
+
+    /// Add a constant node whose data is compressed using `compression`.
+    ///
+    /// Fails if support for `compression` is not enabled.
+    pub fn add_compressed_float_constant(
+        &mut self,
+        input: &Tensor,
+        compression: Compression,
+    ) -> Result<u32, ModelBuildError> {
+        let bytes: Vec<u8> = input.iter().flat_map(|x| x.to_le_bytes()).collect();
+        self.add_compressed_constant(input.shape(), sg::DataType::Float, &bytes, compression)
+    }
+
+    /// Add a constant node containing integers whose data is compressed using
+    /// `compression`.
+    ///
+    /// Fails if support for `compression` is not enabled.
+    pub fn add_compressed_int_constant(
+        &mut self,
+        input: &Tensor<i32>,
+        compression: Compression,
+    ) -> Result<u32, ModelBuildError> {
+        let bytes: Vec<u8> = input.iter().flat_map(|x| x.to_le_bytes()).collect();
+        self.add_compressed_constant(input.shape(), sg::DataType::Int32, &bytes, compression)
+    }
+
+    fn add_compressed_constant(
+        &mut self,
+        shape: &[usize],
+        dtype: sg::DataType,
+        bytes: &[u8],
+        compression: Compression,
+    ) -> Result<u32, ModelBuildError> {
+        let compression = compression.into();
+        let compressed =
+            compress(compression, bytes).map_err(ModelBuildError::CompressionFailed)?;
+        let data_vec = self.builder.create_vector(&compressed);
+
+        let compressed_data = sg::CompressedData::create(
+            &mut self.builder,
+            &sg::CompressedDataArgs {
+                compression,
+                dtype,
+                data: Some(data_vec),
+            },
+        );
+
+        Ok(self.add_constant_node(
+            None,
+            shape,
+            sg::ConstantData::CompressedData,
+            compressed_data.as_union_value(),
+        ))
+    }
+
     fn add_constant_node(
         &mut self,
+        name: Option<&str>,
         shape: &[usize],
         data_type: sg::ConstantData,
         data: WIPOffset<UnionWIPOffset>,
@@ -248,11 +325,36 @@ impl<'a> ModelBuilder<'a> {
                 data: Some(data),
             },
         );
-        self.add_node(None, NodeData::Constant(const_node))
+        self.add_node(name, NodeData::Constant(const_node))
     }
 
     /// Add a value node to the model
     pub fn add_value(&mut self, id: &str, shape: Option<&[Dimension]>) -> u32 {
+        self.add_value_node(Some(id), shape, false, None)
+    }
+
+    /// Add a value node to the model which may be absent at runtime.
+    pub fn add_optional_value(&mut self, id: &str, shape: Option<&[Dimension]>) -> u32 {
+        self.add_value_node(Some(id), shape, true, None)
+    }
+
+    /// Add a value node to the model with an expected data type.
+    pub fn add_typed_value(
+        &mut self,
+        id: &str,
+        shape: Option<&[Dimension]>,
+        dtype: DataType,
+    ) -> u32 {
+        self.add_value_node(Some(id), shape, false, Some(convert_dtype(dtype)))
+    }
+
+    fn add_value_node(
+        &mut self,
+        id: Option<&str>,
+        shape: Option<&[Dimension]>,
+        optional: bool,
+        dtype: Option<sg::DataType>,
+    ) -> u32 {
         let shape = shape.map(|shape| {
             let dim_vec: Vec<_> = shape
                 .iter()
@@ -278,8 +380,15 @@ impl<'a> ModelBuilder<'a> {
                 .collect();
             self.builder.create_vector(&dim_vec[..])
         });
-        let value_node = sg::ValueNode::create(&mut self.builder, &sg::ValueNodeArgs { shape });
-        self.add_node(Some(id), NodeData::Value(value_node))
+        let value_node = sg::ValueNode::create(
+            &mut self.builder,
+            &sg::ValueNodeArgs {
+                shape,
+                optional,
+                dtype,
+            },
+        );
+        self.add_node(id, NodeData::Value(value_node))
     }
 
     /// Convert a `Vec<T>` of elements to a `Vec<U>` and add them to the model buffer
@@ -294,460 +403,848 @@ impl<'a> ModelBuilder<'a> {
         })
     }
 
-    /// Add an operator node to the model
-    pub fn add_operator(
+    /// Serialize the attributes for an operator of type `op_type`.
+    ///
+    /// Returns the type and offset of the FlatBuffers attributes table, or
+    /// `(NONE, None)` if the operator has no attributes.
+    fn create_op_attrs(
         &mut self,
-        id: &str,
-        op_info: OpType,
-        inputs: &[Option<u32>],
-        outputs: &[u32],
-    ) -> u32 {
-        // Generate an (op_type, attr_type, attrs) tuple for an operator with
-        // no attributes.
-        macro_rules! op {
-            ($op_name:ident) => {
-                (sg::OperatorType::$op_name, sg::OperatorAttrs::NONE, None)
+        op_type: &str,
+        attrs: &OpAttrs,
+    ) -> Result<(sg::OperatorAttrs, Option<WIPOffset<UnionWIPOffset>>), ModelBuildError> {
+        let invalid_attr = |name: &str| ModelBuildError::InvalidAttr {
+            op_type: op_type.to_string(),
+            name: name.to_string(),
+        };
+
+        // Get an attribute value, or a default if not set.
+        macro_rules! attr {
+            ($name:literal, $default:expr) => {
+                attrs
+                    .get_or($name, $default)
+                    .map_err(|_| invalid_attr($name))?
+            };
+        }
+
+        // Get an optional attribute value.
+        macro_rules! opt_attr {
+            ($name:literal) => {
+                attrs.get_as($name).map_err(|_| invalid_attr($name))?
+            };
+        }
+
+        // Get an enum-valued attribute and convert it to the FlatBuffers
+        // enum type.
+        macro_rules! enum_attr {
+            ($name:literal, $enum:ident, $default:ident, { $($val:literal => $variant:ident),* $(,)? }) => {
+                match opt_attr!($name) {
+                    $(Some($val) => sg::$enum::$variant,)*
+                    None => sg::$enum::$default,
+                    Some(_) => return Err(invalid_attr($name)),
+                }
             };
         }
 
-        /// Generate an (op_type, attr_type, attrs) tuple for an operator with
-        /// attributes.
-        macro_rules! op_with_attrs {
-            ($op_name:ident, $attr_type:ident, $args: expr) => {{
+        // Generate an (attr_type, attrs) tuple for an operator with
+        // attributes.
+        macro_rules! attrs_table {
+            ($attr_type:ident, $args: expr) => {{
                 let args = ($args);
                 let attrs = sg::$attr_type::create(&mut self.builder, &args).as_union_value();
-                (
-                    sg::OperatorType::$op_name,
-                    sg::OperatorAttrs::$attr_type,
-                    Some(attrs),
-                )
+                (sg::OperatorAttrs::$attr_type, Some(attrs))
             }};
         }
 
-        macro_rules! reduce_attrs {
-            ($args:expr) => {{
-                let axes = self.create_vec($args.axes, |axis| axis);
-                sg::ReduceMeanAttrsArgs {
-                    axes,
-                    keep_dims: $args.keep_dims,
-                }
-            }};
+        macro_rules! pad_mode {
+            ($default:ident) => {
+                enum_attr!("pad_mode", PadMode, $default, { "same" => Same, "fixed" => Fixed })
+            };
+        }
+
+        macro_rules! direction {
+            () => {
+                enum_attr!("direction", RNNDirection, Forward, {
+                    "forward" => Forward,
+                    "reverse" => Reverse,
+                    "bidirectional" => Bidirectional,
+                })
+            };
+        }
+
+        macro_rules! quantized_dtype {
+            () => {
+                enum_attr!("output_dtype", DataType, UInt8, {
+                    "int8" => Int8,
+                    "uint8" => UInt8,
+                })
+            };
+        }
+
+        macro_rules! scatter_reduction {
+            () => {
+                enum_attr!("reduction", ScatterReduction, None, {
+                    "none" => None,
+                    "add" => Add,
+                    "mul" => Mul,
+                    "min" => Min,
+                    "max" => Max,
+                })
+            };
         }
 
-        // Convert internal operator and attribute types to corresponding
-        // FlatBuffers types, and write attribute data into buffer.
-        let (op_type, attrs_type, attrs) = match op_info {
-            OpType::Abs => op!(Abs),
-            OpType::Acos => op!(Acos),
-            OpType::Add => op!(Add),
-            OpType::And => op!(And),
-            OpType::ArgMax(args) => op_with_attrs!(ArgMax, ArgMaxAttrs, {
+        let op_attrs = match op_type {
+            "ArgMax" | "ArgMin" => attrs_table!(
+                ArgMaxAttrs,
                 sg::ArgMaxAttrsArgs {
-                    axis: args.axis as i32,
-                    keep_dims: args.keep_dims,
+                    axis: attr!("axis", 0),
+                    keep_dims: attr!("keep_dims", true),
                 }
-            }),
-            OpType::ArgMin(args) => op_with_attrs!(ArgMin, ArgMaxAttrs, {
-                sg::ArgMaxAttrsArgs {
-                    axis: args.axis as i32,
-                    keep_dims: args.keep_dims,
-                }
-            }),
-            OpType::Asin => op!(Asin),
-            OpType::Atan => op!(Atan),
-            OpType::AveragePool(args) => op_with_attrs!(AveragePool, AveragePoolAttrs, {
-                let pad_args = pad_args_from_padding(args.padding);
-                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
-                sg::AveragePoolAttrsArgs {
-                    kernel_size,
-                    pad_mode: pad_args.pad_mode,
-                    pads,
-                    strides,
-                    count_include_pad: args.count_include_pad,
-                }
-            }),
-            OpType::BatchNormalization(args) => op_with_attrs!(
-                BatchNormalization,
+            ),
+            "AveragePool" => {
+                let kernel_size = self.create_vec(opt_attr!("kernel_size"), |sz: u32| sz);
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                attrs_table!(
+                    AveragePoolAttrs,
+                    sg::AveragePoolAttrsArgs {
+                        kernel_size,
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        strides,
+                        count_include_pad: attr!("count_include_pad", false),
+                        ceil_mode: attr!("ceil_mode", false),
+                        dilations,
+                    }
+                )
+            }
+            "BatchNormalization" | "InstanceNormalization" => attrs_table!(
                 BatchNormalizationAttrs,
                 sg::BatchNormalizationAttrsArgs {
-                    epsilon: args.epsilon
+                    epsilon: attr!("epsilon", 1e-5),
                 }
             ),
-            OpType::Cast(args) => op_with_attrs!(
-                Cast,
+            "BitShift" => attrs_table!(
+                BitShiftAttrs,
+                sg::BitShiftAttrsArgs {
+                    direction: enum_attr!("direction", BitShiftDirection, Left, {
+                        "left" => Left,
+                        "right" => Right,
+                    }),
+                }
+            ),
+            "Cast" => attrs_table!(
                 CastAttrs,
                 sg::CastAttrsArgs {
-                    to: match args.to {
-                        DataType::Int32 => sg::DataType::Int32,
-                        DataType::Float => sg::DataType::Float,
-                    },
+                    to: enum_attr!("to", DataType, Float, {
+                        "int32" => Int32,
+                        "float" => Float,
+                        "int64" => Int64,
+                        "string" => String,
+                        "int8" => Int8,
+                        "uint8" => UInt8,
+                        "bool" => Bool,
+                    }),
                 }
             ),
-            OpType::Ceil => op!(Ceil),
-            OpType::Clip => op!(Clip),
-            OpType::Concat(args) => op_with_attrs!(
-                Concat,
+            "ChannelShuffle" => attrs_table!(
+                ChannelShuffleAttrs,
+                sg::ChannelShuffleAttrsArgs {
+                    groups: attr!("groups", 1),
+                }
+            ),
+            "Concat" => attrs_table!(
                 ConcatAttrs,
                 sg::ConcatAttrsArgs {
-                    axis: args.axis as i32,
-                }
-            ),
-            OpType::ConstantOfShape(args) => {
-                op_with_attrs!(ConstantOfShape, ConstantOfShapeAttrs, {
-                    match args.value {
-                        Scalar::Int(int_value) => sg::ConstantOfShapeAttrsArgs {
-                            value_type: sg::Scalar::IntScalar,
-                            value: Some(
-                                sg::IntScalar::create(
-                                    &mut self.builder,
-                                    &sg::IntScalarArgs { value: int_value },
-                                )
-                                .as_union_value(),
-                            ),
-                        },
-                        Scalar::Float(float_value) => sg::ConstantOfShapeAttrsArgs {
-                            value_type: sg::Scalar::FloatScalar,
-                            value: Some(
-                                sg::FloatScalar::create(
-                                    &mut self.builder,
-                                    &sg::FloatScalarArgs { value: float_value },
-                                )
-                                .as_union_value(),
-                            ),
-                        },
+                    axis: attr!("axis", 0),
+                }
+            ),
+            "ConcatFromSequence" => attrs_table!(
+                ConcatFromSequenceAttrs,
+                sg::ConcatFromSequenceAttrsArgs {
+                    axis: attr!("axis", 0),
+                    new_axis: attr!("new_axis", false),
+                }
+            ),
+            "ConstantOfShape" => {
+                let (value_type, value) = match attrs.get("value") {
+                    Some(AttrValue::Float(value)) => (
+                        sg::Scalar::FloatScalar,
+                        sg::FloatScalar::create(
+                            &mut self.builder,
+                            &sg::FloatScalarArgs { value: *value },
+                        )
+                        .as_union_value(),
+                    ),
+                    _ => (
+                        sg::Scalar::IntScalar,
+                        sg::IntScalar::create(
+                            &mut self.builder,
+                            &sg::IntScalarArgs {
+                                value: attr!("value", 0),
+                            },
+                        )
+                        .as_union_value(),
+                    ),
+                };
+                attrs_table!(
+                    ConstantOfShapeAttrs,
+                    sg::ConstantOfShapeAttrsArgs {
+                        value_type,
+                        value: Some(value),
                     }
-                })
+                )
             }
-            OpType::Conv(args) => op_with_attrs!(Conv, ConvAttrs, {
-                let pad_args = pad_args_from_padding(args.padding);
-                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
-                let strides = self.create_vec(Some(args.strides), |s| s as u32);
-
-                sg::ConvAttrsArgs {
-                    dilations,
-                    groups: args.groups as u32,
-                    pad_mode: pad_args.pad_mode,
-                    pads,
-                    strides,
-                }
-            }),
-            OpType::ConvTranspose(args) => op_with_attrs!(ConvTranspose, ConvTransposeAttrs, {
-                let pad_args = pad_args_from_padding(args.padding);
-                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let strides = self.create_vec(Some(args.strides), |s| s as u32);
-                sg::ConvTransposeAttrsArgs {
-                    strides,
-                    pad_mode: pad_args.pad_mode,
-                    pads,
-                }
-            }),
-            OpType::Cos => op!(Cos),
-            OpType::Div => op!(Div),
-            OpType::Elu(args) => {
-                op_with_attrs!(Elu, EluAttrs, sg::EluAttrsArgs { alpha: args.alpha })
+            "Conv" => {
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                attrs_table!(
+                    ConvAttrs,
+                    sg::ConvAttrsArgs {
+                        dilations,
+                        groups: attr!("groups", 1),
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        strides,
+                    }
+                )
             }
-            OpType::Equal => op!(Equal),
-            OpType::Erf => op!(Erf),
-            OpType::Exp => op!(Exp),
-            OpType::Expand => op!(Expand),
-            OpType::Flatten(args) => op_with_attrs!(
-                Flatten,
-                FlattenAttrs,
-                sg::FlattenAttrsArgs {
-                    axis: args.axis as i32,
+            "ConvTranspose" => {
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                let output_padding = self.create_vec(opt_attr!("output_padding"), |p: u32| p);
+                let output_shape = self.create_vec(opt_attr!("output_shape"), |s: u32| s);
+                attrs_table!(
+                    ConvTransposeAttrs,
+                    sg::ConvTransposeAttrsArgs {
+                        strides,
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        dilations,
+                        groups: attr!("groups", 1),
+                        output_padding,
+                        output_shape,
+                    }
+                )
+            }
+            "Dropout" => attrs_table!(
+                DropoutAttrs,
+                sg::DropoutAttrsArgs {
+                    seed: opt_attr!("seed"),
                 }
             ),
-            OpType::Floor => op!(Floor),
-            OpType::Gather(args) => op_with_attrs!(
-                Gather,
-                GatherAttrs,
-                sg::GatherAttrsArgs {
-                    axis: args.axis as i32,
+            "Celu" | "Elu" | "ThresholdedRelu" => attrs_table!(
+                EluAttrs,
+                sg::EluAttrsArgs {
+                    alpha: attr!("alpha", 1.0),
+                }
+            ),
+            "EyeLike" => attrs_table!(
+                EyeLikeAttrs,
+                sg::EyeLikeAttrsArgs {
+                    dtype: match opt_attr!("dtype") {
+                        Some("int32") => Some(sg::DataType::Int32),
+                        Some("float") => Some(sg::DataType::Float),
+                        Some("int64") => Some(sg::DataType::Int64),
+                        Some("string") => Some(sg::DataType::String),
+                        Some("int8") => Some(sg::DataType::Int8),
+                        Some("uint8") => Some(sg::DataType::UInt8),
+                        Some("bool") => Some(sg::DataType::Bool),
+                        None => None,
+                        Some(_) => return Err(invalid_attr("dtype")),
+                    },
+                    k: attr!("k", 0),
                 }
             ),
-            OpType::GatherElements(args) => op_with_attrs!(
-                GatherElements,
+            "Flatten" => attrs_table!(
+                FlattenAttrs,
+                sg::FlattenAttrsArgs {
+                    axis: attr!("axis", 1),
+                }
+            ),
+            "Gather" | "GatherElements" => attrs_table!(
                 GatherAttrs,
                 sg::GatherAttrsArgs {
-                    axis: args.axis as i32,
+                    axis: attr!("axis", 0),
                 }
             ),
-            OpType::GatherND(args) => op_with_attrs!(
-                GatherND,
+            "GatherND" => attrs_table!(
                 GatherNDAttrs,
                 sg::GatherNDAttrsArgs {
-                    batch_dims: args.batch_dims as i32,
+                    batch_dims: attr!("batch_dims", 0),
+                }
+            ),
+            "GatherBlockQuantized" => attrs_table!(
+                GatherBlockQuantizedAttrs,
+                sg::GatherBlockQuantizedAttrsArgs {
+                    bits: attr!("bits", 4),
+                    block_size: attr!("block_size", 128),
                 }
             ),
-            OpType::Gemm(args) => op_with_attrs!(
-                Gemm,
+            "Gelu" => attrs_table!(
+                GeluAttrs,
+                sg::GeluAttrsArgs {
+                    approximate: attr!("approximate", false),
+                }
+            ),
+            "Gemm" => attrs_table!(
                 GemmAttrs,
                 sg::GemmAttrsArgs {
-                    alpha: args.alpha,
-                    beta: args.beta,
-                    transpose_a: args.transpose_a,
-                    transpose_b: args.transpose_b,
+                    alpha: attr!("alpha", 1.0),
+                    beta: attr!("beta", 1.0),
+                    transpose_a: attr!("transpose_a", false),
+                    transpose_b: attr!("transpose_b", false),
                 }
             ),
-            OpType::GlobalAveragePool => op!(GlobalAveragePool),
-            OpType::Greater => op!(Greater),
-            OpType::GreaterOrEqual => op!(GreaterOrEqual),
-            OpType::HardSigmoid(args) => op_with_attrs!(
-                HardSigmoid,
-                HardSigmoidAttrs,
-                sg::HardSigmoidAttrsArgs {
-                    alpha: args.alpha,
-                    beta: args.beta
+            "GRU" => attrs_table!(
+                GRUAttrs,
+                sg::GRUAttrsArgs {
+                    direction: direction!(),
+                    hidden_size: attr!("hidden_size", 0),
+                    linear_before_reset: attr!("linear_before_reset", false),
                 }
             ),
-            OpType::HardSwish => op!(HardSwish),
-            OpType::Identity => op!(Identity),
-            OpType::InstanceNormalization(args) => op_with_attrs!(
-                InstanceNormalization,
-                BatchNormalizationAttrs,
-                sg::BatchNormalizationAttrsArgs {
-                    epsilon: args.epsilon.unwrap_or(1e-5)
+            "GridSample" => attrs_table!(
+                GridSampleAttrs,
+                sg::GridSampleAttrsArgs {
+                    mode: enum_attr!("mode", ResizeMode, Linear, {
+                        "nearest" => Nearest,
+                        "linear" => Linear,
+                    }),
+                    padding_mode: enum_attr!("padding_mode", GridSamplePadding, Zeros, {
+                        "zeros" => Zeros,
+                        "border" => Border,
+                        "reflection" => Reflection,
+                    }),
+                    align_corners: attr!("align_corners", false),
+                }
+            ),
+            "HardSigmoid" => attrs_table!(
+                HardSigmoidAttrs,
+                sg::HardSigmoidAttrsArgs {
+                    alpha: attr!("alpha", 0.2),
+                    beta: attr!("beta", 0.5),
                 }
             ),
-            OpType::LayerNormalization(args) => op_with_attrs!(
-                LayerNormalization,
+            "ImageToTensor" => {
+                let mean = self.create_vec(opt_attr!("mean"), |x: f32| x);
+                let std = self.create_vec(opt_attr!("std"), |x: f32| x);
+                attrs_table!(
+                    ImageToTensorAttrs,
+                    sg::ImageToTensorAttrsArgs {
+                        scale: attr!("scale", 1.0),
+                        mean,
+                        std,
+                    }
+                )
+            }
+            "LayerNormalization" => attrs_table!(
                 LayerNormalizationAttrs,
                 sg::LayerNormalizationAttrsArgs {
-                    axis: args.axis as i32,
-                    epsilon: args.epsilon.unwrap_or(1e-5)
+                    axis: attr!("axis", -1),
+                    epsilon: attr!("epsilon", 1e-5),
                 }
             ),
-            OpType::LeakyRelu(args) => op_with_attrs!(
-                LeakyRelu,
+            "LeakyRelu" => attrs_table!(
                 LeakyReluAttrs,
-                sg::LeakyReluAttrsArgs { alpha: args.alpha }
+                sg::LeakyReluAttrsArgs {
+                    alpha: attr!("alpha", 0.01),
+                }
             ),
-            OpType::Less => op!(Less),
-            OpType::LessOrEqual => op!(LessOrEqual),
-            OpType::Log => op!(Log),
-            OpType::LogSoftmax(args) => op_with_attrs!(
-                LogSoftmax,
+            "LogSoftmax" | "Softmax" => attrs_table!(
                 SoftmaxAttrs,
                 sg::SoftmaxAttrsArgs {
-                    axis: args.axis as i32,
-                }
-            ),
-            OpType::MatMul => op!(MatMul),
-            OpType::Max => op!(Max),
-            OpType::MaxPool(args) => op_with_attrs!(MaxPool, MaxPoolAttrs, {
-                let pad_args = pad_args_from_padding(args.padding);
-                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
-                sg::MaxPoolAttrsArgs {
-                    kernel_size,
-                    pad_mode: pad_args.pad_mode,
-                    pads,
-                    strides,
-                }
-            }),
-            OpType::Mean => op!(Mean),
-            OpType::Min => op!(Min),
-            OpType::Mod(args) => {
-                op_with_attrs!(Mod, ModAttrs, sg::ModAttrsArgs { fmod: args.fmod })
+                    axis: attr!("axis", -1),
+                }
+            ),
+            "LpNormalization" => attrs_table!(
+                LpNormalizationAttrs,
+                sg::LpNormalizationAttrsArgs {
+                    axis: attr!("axis", -1),
+                    p: attr!("p", 2),
+                }
+            ),
+            "LSTM" => attrs_table!(
+                LSTMAttrs,
+                sg::LSTMAttrsArgs {
+                    direction: direction!(),
+                    hidden_size: attr!("hidden_size", 0),
+                }
+            ),
+            "MatMulNBits" => attrs_table!(
+                MatMulNBitsAttrs,
+                sg::MatMulNBitsAttrsArgs {
+                    bits: attr!("bits", 4),
+                    block_size: attr!("block_size", 128),
+                }
+            ),
+            "LpPool" => {
+                let kernel_size = self.create_vec(opt_attr!("kernel_size"), |sz: u32| sz);
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                attrs_table!(
+                    LpPoolAttrs,
+                    sg::LpPoolAttrsArgs {
+                        kernel_size,
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        strides,
+                        ceil_mode: attr!("ceil_mode", false),
+                        dilations,
+                        p: attr!("p", 2),
+                    }
+                )
             }
-            OpType::Mul => op!(Mul),
-            OpType::Neg => op!(Neg),
-            OpType::NonMaxSuppression(args) => {
-                op_with_attrs!(
-                    NonMaxSuppression,
-                    NonMaxSuppressionAttrs,
-                    sg::NonMaxSuppressionAttrsArgs {
-                        box_order: match args.box_order {
-                            BoxOrder::TopLeftBottomRight => sg::NMSBoxOrder::TopLeftBottomRight,
-                            BoxOrder::CenterWidthHeight => sg::NMSBoxOrder::CenterWidthHeight,
-                        }
+            "MaxPool" => {
+                let kernel_size = self.create_vec(opt_attr!("kernel_size"), |sz: u32| sz);
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                attrs_table!(
+                    MaxPoolAttrs,
+                    sg::MaxPoolAttrsArgs {
+                        kernel_size,
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        strides,
+                        ceil_mode: attr!("ceil_mode", false),
+                        dilations,
                     }
                 )
             }
-            OpType::NonZero => op!(NonZero),
-            OpType::Not => op!(Not),
-            OpType::Or => op!(Or),
-            OpType::OneHot(args) => {
-                op_with_attrs!(
-                    OneHot,
-                    OneHotAttrs,
-                    sg::OneHotAttrsArgs {
-                        axis: args.axis as i32
+            "MeanVarianceNormalization" => {
+                let axes = self.create_vec(opt_attr!("axes"), |axis: i32| axis);
+                attrs_table!(
+                    MeanVarianceNormalizationAttrs,
+                    sg::MeanVarianceNormalizationAttrsArgs { axes }
+                )
+            }
+            "Mod" => attrs_table!(
+                ModAttrs,
+                sg::ModAttrsArgs {
+                    fmod: attr!("fmod", false),
+                }
+            ),
+            "Multinomial" => attrs_table!(
+                MultinomialAttrs,
+                sg::MultinomialAttrsArgs {
+                    dtype: enum_attr!("dtype", DataType, Int32, {
+                        "int32" => Int32,
+                        "float" => Float,
+                        "int64" => Int64,
+                        "string" => String,
+                        "int8" => Int8,
+                        "uint8" => UInt8,
+                        "bool" => Bool,
+                    }),
+                    sample_size: attr!("sample_size", 1),
+                    seed: opt_attr!("seed"),
+                }
+            ),
+            "NonMaxSuppression" => attrs_table!(
+                NonMaxSuppressionAttrs,
+                sg::NonMaxSuppressionAttrsArgs {
+                    box_order: enum_attr!("box_order", NMSBoxOrder, TopLeftBottomRight, {
+                        "top_left_bottom_right" => TopLeftBottomRight,
+                        "center_width_height" => CenterWidthHeight,
+                    }),
+                }
+            ),
+            "Normalize" => {
+                let mean = self.create_vec(opt_attr!("mean"), |x: f32| x);
+                let std = self.create_vec(opt_attr!("std"), |x: f32| x);
+                attrs_table!(NormalizeAttrs, sg::NormalizeAttrsArgs { mean, std })
+            }
+            "OneHot" => attrs_table!(
+                OneHotAttrs,
+                sg::OneHotAttrsArgs {
+                    axis: attr!("axis", -1),
+                }
+            ),
+            "Pad" => attrs_table!(
+                PadAttrs,
+                sg::PadAttrsArgs {
+                    mode: enum_attr!("mode", PadOpMode, Constant, {
+                        "constant" => Constant,
+                        "reflect" => Reflect,
+                        "edge" => Edge,
+                        "wrap" => Wrap,
+                    }),
+                }
+            ),
+            "PermuteChannels" => {
+                let order = self.create_vec(opt_attr!("order"), |c: u32| c);
+                attrs_table!(PermuteChannelsAttrs, sg::PermuteChannelsAttrsArgs { order })
+            }
+            "QLinearConv" => {
+                let pads = self.create_vec(opt_attr!("pads"), |pad: u32| pad);
+                let dilations = self.create_vec(opt_attr!("dilations"), |d: u32| d);
+                let strides = self.create_vec(opt_attr!("strides"), |s: u32| s);
+                attrs_table!(
+                    QLinearConvAttrs,
+                    sg::QLinearConvAttrsArgs {
+                        dilations,
+                        groups: attr!("groups", 1),
+                        pad_mode: pad_mode!(Fixed),
+                        pads,
+                        strides,
+                        output_dtype: quantized_dtype!(),
                     }
                 )
             }
-            OpType::Pad => op!(Pad),
-            OpType::Pow => op!(Pow),
-
-            #[cfg(feature = "random")]
-            OpType::RandomNormal(args) => {
-                let shape = self.create_vec(Some(args.shape), |size| size as u32);
-                op_with_attrs!(RandomNormal, RandomNormalAttrs, {
+            "QLinearMatMul" => attrs_table!(
+                QLinearMatMulAttrs,
+                sg::QLinearMatMulAttrsArgs {
+                    output_dtype: quantized_dtype!(),
+                }
+            ),
+            "RandomNormal" => {
+                let shape = self.create_vec(opt_attr!("shape"), |size: u32| size);
+                attrs_table!(
+                    RandomNormalAttrs,
                     sg::RandomNormalAttrsArgs {
-                        mean: args.mean,
-                        scale: args.scale,
-                        seed: args.seed,
+                        mean: attr!("mean", 0.),
+                        scale: attr!("scale", 1.),
+                        seed: opt_attr!("seed"),
                         shape,
                     }
-                })
-            }
-
-            #[cfg(feature = "random")]
-            OpType::RandomNormalLike(args) => {
-                op_with_attrs!(RandomNormalLike, RandomNormalLikeAttrs, {
-                    sg::RandomNormalLikeAttrsArgs {
-                        mean: args.mean,
-                        scale: args.scale,
-                        seed: args.seed,
-                    }
-                })
+                )
             }
-
-            #[cfg(feature = "random")]
-            OpType::RandomUniform(args) => {
-                let shape = self.create_vec(Some(args.shape), |size| size as u32);
-                op_with_attrs!(RandomUniform, RandomUniformAttrs, {
+            "RandomNormalLike" => attrs_table!(
+                RandomNormalLikeAttrs,
+                sg::RandomNormalLikeAttrsArgs {
+                    mean: attr!("mean", 0.),
+                    scale: attr!("scale", 1.),
+                    seed: opt_attr!("seed"),
+                }
+            ),
+            "RandomUniform" => {
+                let shape = self.create_vec(opt_attr!("shape"), |size: u32| size);
+                attrs_table!(
+                    RandomUniformAttrs,
                     sg::RandomUniformAttrsArgs {
-                        high: args.high,
-                        low: args.low,
-                        seed: args.seed,
+                        high: attr!("high", 1.),
+                        low: attr!("low", 0.),
+                        seed: opt_attr!("seed"),
                         shape,
                     }
-                })
+                )
             }
-
-            #[cfg(feature = "random")]
-            OpType::RandomUniformLike(args) => {
-                op_with_attrs!(RandomUniformLike, RandomUniformLikeAttrs, {
-                    sg::RandomUniformLikeAttrsArgs {
-                        high: args.high,
-                        low: args.low,
-                        seed: args.seed,
+            "RandomUniformLike" => attrs_table!(
+                RandomUniformLikeAttrs,
+                sg::RandomUniformLikeAttrsArgs {
+                    high: attr!("high", 1.),
+                    low: attr!("low", 0.),
+                    seed: opt_attr!("seed"),
+                }
+            ),
+            "ReduceL2" | "ReduceLogSumExp" | "ReduceMax" | "ReduceMean" | "ReduceMin"
+            | "ReduceProd" | "ReduceSum" | "ReduceSumSquare" => {
+                let axes = self.create_vec(opt_attr!("axes"), |axis: i32| axis);
+                attrs_table!(
+                    ReduceMeanAttrs,
+                    sg::ReduceMeanAttrsArgs {
+                        axes,
+                        keep_dims: attr!("keep_dims", true),
                     }
-                })
-            }
-
-            OpType::Range => op!(Range),
-            OpType::Reciprocal => op!(Reciprocal),
-            OpType::ReduceMax(args) => {
-                op_with_attrs!(ReduceMax, ReduceMeanAttrs, reduce_attrs!(args))
-            }
-            OpType::ReduceMean(args) => {
-                op_with_attrs!(ReduceMean, ReduceMeanAttrs, reduce_attrs!(args))
-            }
-            OpType::ReduceMin(args) => {
-                op_with_attrs!(ReduceMin, ReduceMeanAttrs, reduce_attrs!(args))
-            }
-            OpType::ReduceProd(args) => {
-                op_with_attrs!(ReduceProd, ReduceMeanAttrs, reduce_attrs!(args))
-            }
-            OpType::ReduceSum(args) => {
-                op_with_attrs!(ReduceSum, ReduceMeanAttrs, reduce_attrs!(args))
+                )
             }
-            OpType::ReduceSumSquare(args) => {
-                op_with_attrs!(ReduceSumSquare, ReduceMeanAttrs, reduce_attrs!(args))
+            "ReduceStd" | "ReduceVar" => {
+                let axes = self.create_vec(opt_attr!("axes"), |axis: i32| axis);
+                attrs_table!(
+                    ReduceVarAttrs,
+                    sg::ReduceVarAttrsArgs {
+                        axes,
+                        keep_dims: attr!("keep_dims", true),
+                        correction: attr!("correction", 0),
+                    }
+                )
             }
-            OpType::Relu => op!(Relu),
-            OpType::Reshape(args) => op_with_attrs!(Reshape, ReshapeAttrs, {
+            "Reshape" => attrs_table!(
+                ReshapeAttrs,
                 sg::ReshapeAttrsArgs {
-                    allow_zero: args.allow_zero,
+                    allow_zero: attr!("allow_zero", false),
                 }
-            }),
-            OpType::Resize(args) => op_with_attrs!(Resize, ResizeAttrs, {
-                let mode = match args.mode {
-                    ResizeMode::Nearest => sg::ResizeMode::Nearest,
-                    ResizeMode::Linear => sg::ResizeMode::Linear,
-                };
-                let coord_mode = match args.coord_mode {
-                    CoordTransformMode::Asymmetric => sg::CoordTransformMode::Asymmetric,
-                    CoordTransformMode::HalfPixel => sg::CoordTransformMode::HalfPixel,
-                    CoordTransformMode::AlignCorners => sg::CoordTransformMode::AlignCorners,
-                };
-                let nearest_mode = match args.nearest_mode {
-                    NearestMode::Ceil => sg::NearestMode::Ceil,
-                    NearestMode::Floor => sg::NearestMode::Floor,
-                    NearestMode::RoundPreferCeil => sg::NearestMode::RoundPreferCeil,
-                    NearestMode::RoundPreferFloor => sg::NearestMode::RoundPreferFloor,
-                };
-                sg::ResizeAttrsArgs {
-                    mode,
-                    coord_mode,
-                    nearest_mode,
-                }
-            }),
-            OpType::Round => op!(Round),
-            OpType::ScatterElements(args) => {
-                op_with_attrs!(ScatterElements, ScatterElementsAttrs, {
-                    let reduction = match args.reduction {
-                        None => sg::ScatterReduction::None,
-                        Some(ScatterReduction::Add) => sg::ScatterReduction::Add,
-                        Some(ScatterReduction::Mul) => sg::ScatterReduction::Mul,
-                        Some(ScatterReduction::Min) => sg::ScatterReduction::Min,
-                        Some(ScatterReduction::Max) => sg::ScatterReduction::Max,
-                    };
-                    sg::ScatterElementsAttrsArgs {
-                        axis: args.axis as i32,
-                        reduction,
+            ),
+            "Resize" => {
+                let axes = self.create_vec(opt_attr!("axes"), |axis: i32| axis);
+                attrs_table!(
+                    ResizeAttrs,
+                    sg::ResizeAttrsArgs {
+                        mode: enum_attr!("mode", ResizeMode, Nearest, {
+                            "nearest" => Nearest,
+                            "linear" => Linear,
+                        }),
+                        coord_mode: enum_attr!("coord_mode", CoordTransformMode, HalfPixel, {
+                            "half_pixel" => HalfPixel,
+                            "asymmetric" => Asymmetric,
+                            "align_corners" => AlignCorners,
+                            "pytorch_half_pixel" => PytorchHalfPixel,
+                            "tf_crop_and_resize" => TfCropAndResize,
+                        }),
+                        nearest_mode: enum_attr!("nearest_mode", NearestMode, RoundPreferFloor, {
+                            "floor" => Floor,
+                            "ceil" => Ceil,
+                            "round_prefer_floor" => RoundPreferFloor,
+                            "round_prefer_ceil" => RoundPreferCeil,
+                        }),
+                        antialias: attr!("antialias", false),
+                        axes,
+                        extrapolation_value: attr!("extrapolation_value", 0.),
                     }
-                })
+                )
             }
-            OpType::Shape => op!(Shape),
-            OpType::Sigmoid => op!(Sigmoid),
-            OpType::Slice => op!(Slice),
-            OpType::Sin => op!(Sin),
-            OpType::Sign => op!(Sign),
-            OpType::Size => op!(Size),
-            OpType::Softmax(args) => op_with_attrs!(
-                Softmax,
-                SoftmaxAttrs,
-                sg::SoftmaxAttrsArgs {
-                    axis: args.axis as i32,
+            "Scan" => {
+                let body_id: u32 = opt_attr!("body").ok_or_else(|| invalid_attr("body"))?;
+                let body = *self
+                    .subgraphs
+                    .get(body_id as usize)
+                    .ok_or_else(|| invalid_attr("body"))?;
+                let scan_input_axes = self.create_vec(opt_attr!("scan_input_axes"), |x: i32| x);
+                let scan_input_directions =
+                    self.create_vec(opt_attr!("scan_input_directions"), |x: i32| x);
+                let scan_output_axes = self.create_vec(opt_attr!("scan_output_axes"), |x: i32| x);
+                let scan_output_directions =
+                    self.create_vec(opt_attr!("scan_output_directions"), |x: i32| x);
+                attrs_table!(
+                    ScanAttrs,
+                    sg::ScanAttrsArgs {
+                        body: Some(body),
+                        num_scan_inputs: attr!("num_scan_inputs", 0),
+                        scan_input_axes,
+                        scan_input_directions,
+                        scan_output_axes,
+                        scan_output_directions,
+                    }
+                )
+            }
+            "ScaledDotProductAttention" => attrs_table!(
+                ScaledDotProductAttentionAttrs,
+                sg::ScaledDotProductAttentionAttrsArgs {
+                    scale: attr!("scale", 1.0),
                 }
             ),
-            OpType::Softplus => op!(Softplus),
-            OpType::Split(args) => op_with_attrs!(Split, SplitAttrs, {
+            "ScatterElements" => attrs_table!(
+                ScatterElementsAttrs,
+                sg::ScatterElementsAttrsArgs {
+                    axis: attr!("axis", 0),
+                    reduction: scatter_reduction!(),
+                }
+            ),
+            "ScatterND" => attrs_table!(
+                ScatterNDAttrs,
+                sg::ScatterNDAttrsArgs {
+                    reduction: scatter_reduction!(),
+                }
+            ),
+            "Selu" => attrs_table!(
+                SeluAttrs,
+                sg::SeluAttrsArgs {
+                    alpha: attr!("alpha", 1.673_263_2),
+                    gamma: attr!("gamma", 1.050_701),
+                }
+            ),
+            "Split" => attrs_table!(
+                SplitAttrs,
                 sg::SplitAttrsArgs {
-                    axis: args.axis as i32,
-                }
-            }),
-            OpType::Sqrt => op!(Sqrt),
-            OpType::Squeeze => op!(Squeeze),
-            OpType::Sub => op!(Sub),
-            OpType::Sum => op!(Sum),
-            OpType::Tan => op!(Tan),
-            OpType::Tanh => op!(Tanh),
-            OpType::Tile => op!(Tile),
-            OpType::TopK(args) => op_with_attrs!(TopK, TopKAttrs, {
+                    axis: attr!("axis", 0),
+                    num_outputs: opt_attr!("num_outputs"),
+                }
+            ),
+            "SplitToSequence" => attrs_table!(
+                SplitToSequenceAttrs,
+                sg::SplitToSequenceAttrsArgs {
+                    axis: attr!("axis", 0),
+                    keep_dims: attr!("keep_dims", true),
+                }
+            ),
+            "TopK" => attrs_table!(
+                TopKAttrs,
                 sg::TopKAttrsArgs {
-                    axis: args.axis.unwrap_or(-1) as i32,
-                    largest: args.largest,
-                    sorted: args.sorted,
-                }
-            }),
-            OpType::Transpose(args) => op_with_attrs!(Transpose, TransposeAttrs, {
-                let perm = self.create_vec(args.perm, |dim| dim as u32);
-                sg::TransposeAttrsArgs { perm }
-            }),
-            OpType::Trilu(args) => op_with_attrs!(Trilu, TriluAttrs, {
-                sg::TriluAttrsArgs { upper: args.upper }
-            }),
-            OpType::Unsqueeze => op!(Unsqueeze),
-            OpType::Where => op!(Where),
-            OpType::Xor => op!(Xor),
+                    axis: attr!("axis", -1),
+                    largest: attr!("largest", true),
+                    sorted: attr!("sorted", true),
+                }
+            ),
+            "Transpose" => {
+                let perm = self.create_vec(opt_attr!("perm"), |dim: u32| dim);
+                attrs_table!(TransposeAttrs, sg::TransposeAttrsArgs { perm })
+            }
+            "Trilu" => attrs_table!(
+                TriluAttrs,
+                sg::TriluAttrsArgs {
+                    upper: attr!("upper", true),
+                }
+            ),
+            _ => {
+                if let Some((name, _)) = attrs.iter().next() {
+                    return Err(invalid_attr(name));
+                }
+                (sg::OperatorAttrs::NONE, None)
+            }
         };
+        Ok(op_attrs)
+    }
+
+    /// Add an operator node to the model.
+    ///
+    /// `op_type` is the name of the operator (eg. "Conv") and `attrs`
+    /// specifies its attributes. See [OpAttrs] for details of how attribute
+    /// values are represented. Graph-valued attributes, such as the body of a
+    /// `Scan` operator, are specified using IDs returned by
+    /// [ModelBuilder::end_subgraph].
+    ///
+    /// Fails if `op_type` is not a known operator, or an attribute is not
+    /// supported by the operator or has a value of the wrong type.
+    pub fn add_operator(
+        &mut self,
+        id: &str,
+        op_type: &str,
+        attrs: &OpAttrs,
+        inputs: &[Option<u32>],
+        outputs: &[u32],
+    ) -> Result<u32, ModelBuildError> {
+        let outputs: Vec<_> = outputs.iter().copied().map(Some).collect();
+        self.add_builtin_operator_node(Some(id), op_type, attrs, inputs, &outputs)
+    }
 
-        let input_ids: Vec<i32> = inputs
+    fn add_builtin_operator_node(
+        &mut self,
+        id: Option<&str>,
+        op_type: &str,
+        attrs: &OpAttrs,
+        inputs: &[Option<u32>],
+        outputs: &[Option<u32>],
+    ) -> Result<u32, ModelBuildError> {
+        let fb_op_type = sg::OperatorType::ENUM_VALUES
             .iter()
-            .map(|&id| match id {
-                Some(id) => id as i32,
-                None => -1,
+            .copied()
+            .find(|ty| ty.variant_name() == Some(op_type))
+            .ok_or_else(|| ModelBuildError::UnknownOperator(op_type.to_string()))?;
+        let (attrs_type, attrs) = self.create_op_attrs(op_type, attrs)?;
+        Ok(self.add_operator_node(id, fb_op_type, attrs_type, attrs, inputs, outputs))
+    }
+
+    /// Add a custom operator node to the model.
+    ///
+    /// Custom operators are identified by `domain` and `op_type`, and are
+    /// instantiated using implementations registered with
+    /// [OpRegistry::register_custom_op](crate::OpRegistry::register_custom_op).
+    /// Boolean attributes are stored as ints. Graph-valued attributes are not
+    /// supported.
+    pub fn add_custom_operator(
+        &mut self,
+        id: &str,
+        domain: &str,
+        op_type: &str,
+        attrs: &OpAttrs,
+        inputs: &[Option<u32>],
+        outputs: &[u32],
+    ) -> Result<u32, ModelBuildError> {
+        let outputs: Vec<_> = outputs.iter().copied().map(Some).collect();
+        self.add_custom_operator_node(Some(id), domain, op_type, attrs, inputs, &outputs)
+    }
+
+    fn add_custom_operator_node(
+        &mut self,
+        id: Option<&str>,
+        domain: &str,
+        op_type: &str,
+        attrs: &OpAttrs,
+        inputs: &[Option<u32>],
+        outputs: &[Option<u32>],
+    ) -> Result<u32, ModelBuildError> {
+        // Sort attributes so the serialized model is deterministic.
+        let mut attr_list: Vec<_> = attrs.iter().collect();
+        attr_list.sort_by_key(|(name, _)| *name);
+
+        let custom_attrs = attr_list
+            .into_iter()
+            .map(|(name, value)| {
+                let mut args = sg::CustomAttrArgs {
+                    name: Some(self.builder.create_string(name)),
+                    ..Default::default()
+                };
+                match value {
+                    AttrValue::Bool(b) => {
+                        args.value_type = sg::CustomAttrType::Int;
+                        args.int_value = *b as i64;
+                    }
+                    AttrValue::Int(i) => {
+                        args.value_type = sg::CustomAttrType::Int;
+                        args.int_value = *i;
+                    }
+                    AttrValue::Float(f) => {
+                        args.value_type = sg::CustomAttrType::Float;
+                        args.float_value = *f;
+                    }
+                    AttrValue::String(s) => {
+                        args.value_type = sg::CustomAttrType::String;
+                        args.string_value = Some(self.builder.create_string(s));
+                    }
+                    AttrValue::Ints(ints) => {
+                        args.value_type = sg::CustomAttrType::Ints;
+                        args.ints = Some(self.builder.create_vector(ints));
+                    }
+                    AttrValue::Floats(floats) => {
+                        args.value_type = sg::CustomAttrType::Floats;
+                        args.floats = Some(self.builder.create_vector(floats));
+                    }
+                    AttrValue::Graph(_) => {
+                        return Err(ModelBuildError::InvalidAttr {
+                            op_type: op_type.to_string(),
+                            name: name.to_string(),
+                        });
+                    }
+                }
+                Ok(sg::CustomAttr::create(&mut self.builder, &args))
             })
-            .collect();
-        let output_ids: Vec<i32> = outputs.iter().map(|&id| id as i32).collect();
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let custom_attrs = self.builder.create_vector(&custom_attrs);
+        let domain = self.builder.create_string(domain);
+        let custom_op_type = self.builder.create_string(op_type);
+        let attrs = sg::CustomOperatorAttrs::create(
+            &mut self.builder,
+            &sg::CustomOperatorAttrsArgs {
+                domain: Some(domain),
+                op_type: Some(custom_op_type),
+                attrs: Some(custom_attrs),
+            },
+        );
+        Ok(self.add_operator_node(
+            id,
+            sg::OperatorType::Custom,
+            sg::OperatorAttrs::CustomOperatorAttrs,
+            Some(attrs.as_union_value()),
+            inputs,
+            outputs,
+        ))
+    }
+
+    fn add_operator_node(
+        &mut self,
+        id: Option<&str>,
+        op_type: sg::OperatorType,
+        attrs_type: sg::OperatorAttrs,
+        attrs: Option<WIPOffset<UnionWIPOffset>>,
+        inputs: &[Option<u32>],
+        outputs: &[Option<u32>],
+    ) -> u32 {
+        // Omitted inputs and unused outputs are represented by negative IDs.
+        let node_ids = |ids: &[Option<u32>]| -> Vec<i32> {
+            ids.iter()
+                .map(|&id| match id {
+                    Some(id) => id as i32,
+                    None => -1,
+                })
+                .collect()
+        };
+        let input_ids = node_ids(inputs);
+        let output_ids = node_ids(outputs);
 
         let input_vec = self.builder.create_vector(&input_ids);
         let output_vec = self.builder.create_vector(&output_ids);
@@ -761,7 +1258,7 @@ impl<'a> ModelBuilder<'a> {
                 outputs: Some(output_vec),
             },
         );
-        self.add_node(Some(id), NodeData::Operator(op_node))
+        self.add_node(id, NodeData::Operator(op_node))
     }
 
     /// Mark a node in the graph as an input.
@@ -774,6 +1271,158 @@ impl<'a> ModelBuilder<'a> {
         self.output_ids.push(node_id);
     }
 
+    /// Start building a subgraph, such as the body of a control flow
+    /// operator.
+    ///
+    /// Until the matching call to [ModelBuilder::end_subgraph], nodes, inputs
+    /// and outputs are added to the subgraph instead of the current graph.
+    /// Node IDs are local to the graph they were added to.
+    pub fn begin_subgraph(&mut self) {
+        let nodes = std::mem::take(&mut self.nodes);
+        let input_ids = std::mem::take(&mut self.input_ids);
+        let output_ids = std::mem::take(&mut self.output_ids);
+        self.parent_graphs.push((nodes, input_ids, output_ids));
+    }
+
+    /// Finish building a subgraph started with [ModelBuilder::begin_subgraph].
+    ///
+    /// Returns an ID which can be used as the value of a graph-valued operator
+    /// attribute.
+    pub fn end_subgraph(&mut self) -> Result<u32, ModelBuildError> {
+        let (nodes, input_ids, output_ids) = self
+            .parent_graphs
+            .pop()
+            .ok_or(ModelBuildError::NoSubgraph)?;
+        let nodes = std::mem::replace(&mut self.nodes, nodes);
+        let input_ids = std::mem::replace(&mut self.input_ids, input_ids);
+        let output_ids = std::mem::replace(&mut self.output_ids, output_ids);
+        let graph = self.create_graph(&nodes, &input_ids, &output_ids);
+        self.subgraphs.push(graph);
+        Ok((self.subgraphs.len() - 1) as u32)
+    }
+
+    fn create_graph(
+        &mut self,
+        nodes: &[WIPOffset<sg::Node<'a>>],
+        input_ids: &[u32],
+        output_ids: &[u32],
+    ) -> WIPOffset<sg::Graph<'a>> {
+        let inputs_vec = self.builder.create_vector(input_ids);
+        let outputs_vec = self.builder.create_vector(output_ids);
+        let nodes_vec = self.builder.create_vector(nodes);
+
+        sg::Graph::create(
+            &mut self.builder,
+            &sg::GraphArgs {
+                nodes: Some(nodes_vec),
+                inputs: Some(inputs_vec),
+                outputs: Some(outputs_vec),
+            },
+        )
+    }
+
+    /// Add the nodes of an existing graph to the model and mark its inputs
+    /// and outputs.
+    ///
+    /// `op_attrs` specifies the attributes of each operator node. Operators
+    /// without an entry are written without attributes.
+    pub(crate) fn add_graph(
+        &mut self,
+        graph: &Graph,
+        input_ids: &[NodeId],
+        output_ids: &[NodeId],
+        op_attrs: &HashMap<NodeId, OpAttrs>,
+    ) -> Result<(), ModelBuildError> {
+        let mut node_index: HashMap<NodeId, u32> = HashMap::new();
+
+        // Operators refer to their inputs and outputs by index, so constants
+        // and values are added first.
+        for (id, node) in graph.iter() {
+            let index = match node {
+                Node::Constant(constant) => match constant {
+                    Constant::Float(data) => self.add_float_constant_node(node.name(), data.view()),
+                    Constant::Int(data) => self.add_int_constant_node(node.name(), data.view()),
+                    Constant::String(data) => {
+                        self.add_string_constant_node(node.name(), data.view())
+                    }
+                    Constant::BFloat16(data) => {
+                        self.add_bf16_constant_node(node.name(), data.view())
+                    }
+                    Constant::Int64(_) => {
+                        return Err(ModelBuildError::UnsupportedValue(
+                            "int64 constants are not supported by the model format",
+                        ));
+                    }
+                },
+                Node::Value(value) => self.add_value_node(
+                    node.name(),
+                    value.shape(),
+                    value.is_optional(),
+                    node.dtype().map(convert_dtype),
+                ),
+                Node::Operator(_) => continue,
+            };
+            node_index.insert(id, index);
+        }
+
+        let empty_attrs = OpAttrs::new();
+        for (id, node) in graph.iter() {
+            let Node::Operator(op_node) = node else {
+                continue;
+            };
+            let node_ids = |ids: &[Option<NodeId>]| -> Vec<Option<u32>> {
+                ids.iter().map(|id| id.map(|id| node_index[&id])).collect()
+            };
+            let inputs = node_ids(op_node.inputs());
+            let outputs = node_ids(op_node.outputs());
+            let attrs = op_attrs.get(&id).unwrap_or(&empty_attrs);
+
+            if let Some((domain, op_type)) = op_node.custom_op_type() {
+                self.add_custom_operator_node(
+                    node.name(),
+                    domain,
+                    op_type,
+                    attrs,
+                    &inputs,
+                    &outputs,
+                )?;
+                continue;
+            }
+
+            // Subgraphs are written before the operator that uses them, and
+            // referenced by ID in the attributes.
+            let mut op_attrs = attrs.clone();
+            for (name, value) in attrs.iter() {
+                if let AttrValue::Graph(subgraph) = value {
+                    self.begin_subgraph();
+                    self.add_graph(
+                        subgraph.graph(),
+                        subgraph.input_ids(),
+                        subgraph.output_ids(),
+                        subgraph.op_attrs(),
+                    )?;
+                    let subgraph_id = self.end_subgraph()?;
+                    op_attrs.insert(name, subgraph_id as i64);
+                }
+            }
+            let op_type = op_node.operator().name();
+            self.add_builtin_operator_node(node.name(), op_type, &op_attrs, &inputs, &outputs)?;
+        }
+
+        for id in input_ids {
+            self.add_input(node_index[id]);
+        }
+        for id in output_ids {
+            self.add_output(node_index[id]);
+        }
+        Ok(())
+    }
+
+    /// Add metadata copied from a loaded model.
+    pub(crate) fn add_model_metadata(&mut self, metadata: &ModelMetadata) {
+        self.metadata = Some(metadata.serialize(&mut self.builder));
+    }
+
     /// Add model metadata
     pub fn add_metadata(&mut self, metadata: MetadataArgs) {
         let hash = metadata
@@ -789,18 +1438,10 @@ impl<'a> ModelBuilder<'a> {
 
     /// Finish writing the model data to the buffer and return the buffer's contents.
     pub fn finish(mut self) -> Vec<u8> {
-        let inputs_vec = self.builder.create_vector(&self.input_ids[..]);
-        let outputs_vec = self.builder.create_vector(&self.output_ids[..]);
-        let nodes_vec = self.builder.create_vector(&self.nodes[..]);
-
-        let graph = sg::Graph::create(
-            &mut self.builder,
-            &sg::GraphArgs {
-                nodes: Some(nodes_vec),
-                inputs: Some(inputs_vec),
-                outputs: Some(outputs_vec),
-            },
-        );
+        let nodes = std::mem::take(&mut self.nodes);
+        let input_ids = std::mem::take(&mut self.input_ids);
+        let output_ids = std::mem::take(&mut self.output_ids);
+        let graph = self.create_graph(&nodes, &input_ids, &output_ids);
 
         let model = sg::Model::create(
             &mut self.builder,
@@ -821,3 +1462,17 @@ impl<'a> Default for ModelBuilder<'a> {
         Self::new()
     }
 }
+
+/// Convert a data type to the corresponding FlatBuffers enum value.
+fn convert_dtype(dtype: DataType) -> sg::DataType {
+    match dtype {
+        DataType::Int32 => sg::DataType::Int32,
+        DataType::Float => sg::DataType::Float,
+        DataType::Int64 => sg::DataType::Int64,
+        DataType::String => sg::DataType::String,
+        DataType::Int8 => sg::DataType::Int8,
+        DataType::UInt8 => sg::DataType::UInt8,
+        DataType::Bool => sg::DataType::Bool,
+        DataType::BFloat16 => sg::DataType::BFloat16,
+    }
+}
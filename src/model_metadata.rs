@@ -1,3 +1,5 @@
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
 use crate::schema_generated as sg;
 
 /// Metadata for an RTen model.
@@ -8,6 +10,8 @@ use crate::schema_generated as sg;
 ///  - The license
 ///  - Details of the training run that produced the model
 ///  - Related URLs
+///  - Provenance information copied from the source ONNX model, such as the
+///    producer and imported operator sets
 #[derive(Default)]
 pub struct ModelMetadata {
     onnx_hash: Option<String>,
@@ -18,6 +22,23 @@ pub struct ModelMetadata {
     model_repository: Option<String>,
     run_id: Option<String>,
     run_url: Option<String>,
+    producer_name: Option<String>,
+    producer_version: Option<String>,
+    doc_string: Option<String>,
+    opset_imports: Vec<OpsetImport>,
+    custom: Vec<(String, String)>,
+}
+
+/// Operator set imported by the ONNX model that an RTen model was created
+/// from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpsetImport {
+    /// Operator set domain, eg. `"com.microsoft"`. This is empty for the
+    /// default ONNX domain.
+    pub domain: String,
+
+    /// Version of the operator set.
+    pub version: i64,
 }
 
 impl ModelMetadata {
@@ -32,9 +53,100 @@ impl ModelMetadata {
             model_repository: metadata.model_repository().map(|s| s.to_string()),
             run_id: metadata.run_id().map(|s| s.to_string()),
             run_url: metadata.run_url().map(|s| s.to_string()),
+            producer_name: metadata.producer_name().map(|s| s.to_string()),
+            producer_version: metadata.producer_version().map(|s| s.to_string()),
+            doc_string: metadata.doc_string().map(|s| s.to_string()),
+            opset_imports: metadata
+                .opset_imports()
+                .into_iter()
+                .flatten()
+                .map(|opset| OpsetImport {
+                    domain: opset.domain().unwrap_or_default().to_string(),
+                    version: opset.version(),
+                })
+                .collect(),
+            custom: metadata
+                .metadata_props()
+                .into_iter()
+                .flatten()
+                .filter_map(|prop| Some((prop.key()?.to_string(), prop.value()?.to_string())))
+                .collect(),
         }
     }
 
+    /// Serialize this metadata into a flatbuffers file.
+    ///
+    /// This is the inverse of [ModelMetadata::deserialize].
+    pub(crate) fn serialize<'a>(
+        &self,
+        builder: &mut FlatBufferBuilder<'a>,
+    ) -> WIPOffset<sg::Metadata<'a>> {
+        let mut string = |s: &Option<String>| s.as_ref().map(|s| builder.create_string(s));
+        let onnx_hash = string(&self.onnx_hash);
+        let description = string(&self.description);
+        let license = string(&self.license);
+        let commit = string(&self.commit);
+        let code_repository = string(&self.code_repository);
+        let model_repository = string(&self.model_repository);
+        let run_id = string(&self.run_id);
+        let run_url = string(&self.run_url);
+        let producer_name = string(&self.producer_name);
+        let producer_version = string(&self.producer_version);
+        let doc_string = string(&self.doc_string);
+
+        let opset_imports: Vec<_> = self
+            .opset_imports
+            .iter()
+            .map(|opset| {
+                let domain = builder.create_string(&opset.domain);
+                sg::OpsetImport::create(
+                    builder,
+                    &sg::OpsetImportArgs {
+                        domain: Some(domain),
+                        version: opset.version,
+                    },
+                )
+            })
+            .collect();
+        let opset_imports = builder.create_vector(&opset_imports);
+
+        let metadata_props: Vec<_> = self
+            .custom
+            .iter()
+            .map(|(key, value)| {
+                let key = builder.create_string(key);
+                let value = builder.create_string(value);
+                sg::MetadataProp::create(
+                    builder,
+                    &sg::MetadataPropArgs {
+                        key: Some(key),
+                        value: Some(value),
+                    },
+                )
+            })
+            .collect();
+        let metadata_props = builder.create_vector(&metadata_props);
+
+        sg::Metadata::create(
+            builder,
+            &sg::MetadataArgs {
+                onnx_hash,
+                description,
+                license,
+                commit,
+                code_repository,
+                model_repository,
+                run_id,
+                run_url,
+                producer_name,
+                producer_version,
+                doc_string,
+                opset_imports: Some(opset_imports),
+                metadata_props: Some(metadata_props),
+            },
+        )
+    }
+
     /// Return the SHA-256 hash of the ONNX model used to generate this RTen
     /// model.
     pub fn onnx_hash(&self) -> Option<&str> {
@@ -88,11 +200,51 @@ impl ModelMetadata {
     pub fn run_url(&self) -> Option<&str> {
         self.run_url.as_deref()
     }
+
+    /// Return the name of the tool that produced the ONNX model used to
+    /// generate this RTen model, eg. "pytorch".
+    pub fn producer_name(&self) -> Option<&str> {
+        self.producer_name.as_deref()
+    }
+
+    /// Return the version of the tool that produced the ONNX model used to
+    /// generate this RTen model.
+    pub fn producer_version(&self) -> Option<&str> {
+        self.producer_version.as_deref()
+    }
+
+    /// Return the documentation string from the ONNX model used to generate
+    /// this RTen model.
+    pub fn doc_string(&self) -> Option<&str> {
+        self.doc_string.as_deref()
+    }
+
+    /// Return the operator sets imported by the ONNX model used to generate
+    /// this RTen model.
+    pub fn opset_imports(&self) -> &[OpsetImport] {
+        &self.opset_imports
+    }
+
+    /// Return the value of a custom metadata field.
+    ///
+    /// Custom fields are copied from the `metadata_props` of the ONNX model
+    /// used to generate this RTen model.
+    pub fn get_custom(&self, key: &str) -> Option<&str> {
+        self.custom
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Return an iterator over `(key, value)` pairs of custom metadata fields.
+    pub fn custom_fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.custom.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ModelMetadata;
+    use super::{ModelMetadata, OpsetImport};
     use crate::schema_generated as sg;
     use flatbuffers::FlatBufferBuilder;
 
@@ -109,6 +261,32 @@ mod tests {
         let run_id = builder.create_string("1234");
         let run_url =
             builder.create_string("https://wandb.ai/robertknight/text-detection/runs/1234");
+        let producer_name = builder.create_string("pytorch");
+        let producer_version = builder.create_string("2.3.0");
+        let doc_string = builder.create_string("Model docs");
+
+        let opset_imports = [("", 17), ("com.microsoft", 1)].map(|(domain, version)| {
+            let domain = builder.create_string(domain);
+            sg::OpsetImport::create(
+                &mut builder,
+                &sg::OpsetImportArgs {
+                    domain: Some(domain),
+                    version,
+                },
+            )
+        });
+        let opset_imports = builder.create_vector(&opset_imports);
+
+        let prop_key = builder.create_string("author");
+        let prop_value = builder.create_string("Jane Doe");
+        let prop = sg::MetadataProp::create(
+            &mut builder,
+            &sg::MetadataPropArgs {
+                key: Some(prop_key),
+                value: Some(prop_value),
+            },
+        );
+        let metadata_props = builder.create_vector(&[prop]);
 
         let mut meta_builder = sg::MetadataBuilder::new(&mut builder);
         meta_builder.add_onnx_hash(onnx_hash);
@@ -119,6 +297,11 @@ mod tests {
         meta_builder.add_model_repository(model_repository);
         meta_builder.add_run_id(run_id);
         meta_builder.add_run_url(run_url);
+        meta_builder.add_producer_name(producer_name);
+        meta_builder.add_producer_version(producer_version);
+        meta_builder.add_doc_string(doc_string);
+        meta_builder.add_opset_imports(opset_imports);
+        meta_builder.add_metadata_props(metadata_props);
         let metadata = meta_builder.finish();
 
         builder.finish_minimal(metadata);
@@ -144,5 +327,27 @@ mod tests {
             model_metadata.run_url(),
             Some("https://wandb.ai/robertknight/text-detection/runs/1234")
         );
+        assert_eq!(model_metadata.producer_name(), Some("pytorch"));
+        assert_eq!(model_metadata.producer_version(), Some("2.3.0"));
+        assert_eq!(model_metadata.doc_string(), Some("Model docs"));
+        assert_eq!(
+            model_metadata.opset_imports(),
+            &[
+                OpsetImport {
+                    domain: String::new(),
+                    version: 17
+                },
+                OpsetImport {
+                    domain: "com.microsoft".to_string(),
+                    version: 1
+                }
+            ]
+        );
+        assert_eq!(model_metadata.get_custom("author"), Some("Jane Doe"));
+        assert_eq!(model_metadata.get_custom("missing"), None);
+        assert_eq!(
+            model_metadata.custom_fields().collect::<Vec<_>>(),
+            [("author", "Jane Doe")]
+        );
     }
 }
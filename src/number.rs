@@ -18,6 +18,12 @@ impl AsBool for i32 {
     }
 }
 
+impl AsBool for i64 {
+    fn as_bool(&self) -> bool {
+        *self != 0
+    }
+}
+
 /// Trait indicating whether type is an integer or float.
 pub trait IsInt {
     fn is_int() -> bool;
@@ -35,6 +41,12 @@ impl IsInt for i32 {
     }
 }
 
+impl IsInt for i64 {
+    fn is_int() -> bool {
+        true
+    }
+}
+
 /// Trait providing additive and multiplicative identities.
 pub trait Identities {
     fn one() -> Self;
@@ -60,6 +72,15 @@ impl Identities for i32 {
     }
 }
 
+impl Identities for i64 {
+    fn one() -> i64 {
+        1
+    }
+    fn zero() -> i64 {
+        0
+    }
+}
+
 pub trait MinMax {
     /// Return the maximum value for this type.
     fn max_val() -> Self;
@@ -150,9 +171,83 @@ macro_rules! impl_fastdiv {
 // unsigned type.
 impl_fastdiv!(usize);
 
+/// A 16-bit "brain" floating point number.
+///
+/// This has the same exponent range as an `f32` but only 8 bits of mantissa
+/// precision. It is used as a storage format for weights, halving their memory
+/// usage. Values are converted to `f32` for computation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(transparent)]
+pub struct BF16(u16);
+
+impl BF16 {
+    /// Create a value from its bit representation.
+    pub const fn from_bits(bits: u16) -> BF16 {
+        BF16(bits)
+    }
+
+    /// Return the bit representation of this value.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Convert an `f32` to the nearest `BF16`, rounding ties to even.
+    pub fn from_f32(x: f32) -> BF16 {
+        let bits = x.to_bits();
+        if x.is_nan() {
+            // Truncate and set the quiet bit, so NaNs don't round to infinity.
+            return BF16((bits >> 16) as u16 | 0x40);
+        }
+        let round_bit = 0x7fff + ((bits >> 16) & 1);
+        BF16((bits.wrapping_add(round_bit) >> 16) as u16)
+    }
+
+    /// Convert this value to an `f32`. This conversion is exact.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+impl From<BF16> for f32 {
+    #[inline]
+    fn from(x: BF16) -> f32 {
+        x.to_f32()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FastDiv;
+    use super::{FastDiv, BF16};
+
+    #[test]
+    fn test_bf16_from_f32() {
+        // Values which are exactly representable.
+        for x in [0., -0., 1., -2.5, 256., f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(BF16::from_f32(x).to_f32(), x);
+        }
+
+        // Rounding to nearest, with ties to even.
+        let one_ulp = f32::from_bits(1.0f32.to_bits() + 0x10000);
+        let half_ulp = f32::from_bits(1.0f32.to_bits() + 0x8000);
+        assert_eq!(
+            BF16::from_f32(f32::from_bits(1.0f32.to_bits() + 0x7fff)).to_f32(),
+            1.
+        );
+        assert_eq!(
+            BF16::from_f32(f32::from_bits(1.0f32.to_bits() + 0x8001)).to_f32(),
+            one_ulp
+        );
+        assert_eq!(BF16::from_f32(half_ulp).to_f32(), 1.);
+        assert_eq!(
+            BF16::from_f32(f32::from_bits(one_ulp.to_bits() + 0x8000)).to_f32(),
+            f32::from_bits(one_ulp.to_bits() + 0x10000)
+        );
+
+        // NaNs stay NaN.
+        assert!(BF16::from_f32(f32::NAN).to_f32().is_nan());
+        assert!(BF16::from_f32(f32::from_bits(0x7f800001)).to_f32().is_nan());
+    }
 
     #[test]
     fn test_fast_div_divide() {
@@ -0,0 +1,665 @@
+//! Serialization of a [Graph] into the ONNX protobuf format.
+//!
+//! This uses a minimal hand-written protobuf encoder covering only the parts
+//! of the ONNX schema (see `onnx.proto` in the ONNX repository) needed to
+//! describe a graph of operators, constants and values.
+
+use std::collections::{BTreeSet, HashMap};
+
+use rten_tensor::prelude::*;
+
+use crate::graph::{Constant, Dimension, Graph, Node, NodeId};
+use crate::op_registry::{AttrValue, OpAttrs};
+
+/// ONNX IR version written to exported models.
+const IR_VERSION: i64 = 8;
+
+/// Version of the default ONNX operator set used by exported models.
+const OPSET_VERSION: i64 = 17;
+
+/// Domain used for operators which are specific to RTen.
+pub const RTEN_DOMAIN: &str = "ai.rten";
+
+/// Return the domain for an operator type, or an empty string for operators
+/// in the default ONNX domain.
+fn op_domain(op_type: &str) -> &'static str {
+    match op_type {
+        "ChannelShuffle"
+        | "ImageToTensor"
+        | "Normalize"
+        | "PermuteChannels"
+        | "ReduceStd"
+        | "ReduceVar"
+        | "ScaledDotProductAttention" => RTEN_DOMAIN,
+        "GatherBlockQuantized" | "Gelu" | "MatMulNBits" => "com.microsoft",
+        _ => "",
+    }
+}
+
+/// Convert the attributes of an operator into ONNX attributes.
+///
+/// Most attributes are passed through unchanged. Attributes whose names or
+/// values differ from the ONNX spec are converted.
+fn onnx_attrs(op_type: &str, attrs: &OpAttrs) -> Vec<(String, AttrValue)> {
+    let mut onnx_attrs = Vec::with_capacity(attrs.len());
+    for (name, value) in attrs.iter() {
+        let (name, value) = match (name, value) {
+            ("keep_dims", _) => ("keepdims", value.clone()),
+            ("kernel_size", _) => ("kernel_shape", value.clone()),
+            ("allow_zero", _) => ("allowzero", value.clone()),
+            ("groups", _) if op_domain(op_type).is_empty() => ("group", value.clone()),
+            ("coord_mode", _) => ("coordinate_transformation_mode", value.clone()),
+            ("pad_mode", AttrValue::String(mode)) => match mode.as_str() {
+                "same" => ("auto_pad", AttrValue::String("SAME_UPPER".into())),
+                _ => continue,
+            },
+            ("to" | "dtype", AttrValue::String(dtype)) if op_domain(op_type).is_empty() => {
+                match dtype.as_str() {
+                    "float" => (name, AttrValue::Int(DATA_TYPE_FLOAT)),
+                    "int32" => (name, AttrValue::Int(DATA_TYPE_INT32)),
+                    "int64" => (name, AttrValue::Int(DATA_TYPE_INT64)),
+                    "string" => (name, AttrValue::Int(DATA_TYPE_STRING)),
+                    "int8" => (name, AttrValue::Int(DATA_TYPE_INT8)),
+                    "uint8" => (name, AttrValue::Int(DATA_TYPE_UINT8)),
+                    "bool" => (name, AttrValue::Int(DATA_TYPE_BOOL)),
+                    _ => (name, value.clone()),
+                }
+            }
+            // `approximate` is a string attribute in ONNX. It is omitted when
+            // using the default exact formulation.
+            ("approximate", AttrValue::Bool(approximate)) => match approximate {
+                true => ("approximate", AttrValue::String("tanh".into())),
+                false => continue,
+            },
+            ("direction", AttrValue::String(dir)) if op_type == "BitShift" => {
+                (name, AttrValue::String(dir.to_uppercase()))
+            }
+            ("box_order", AttrValue::String(order)) => (
+                "center_point_box",
+                AttrValue::Int((order == "center_width_height") as i64),
+            ),
+            _ => (name, value.clone()),
+        };
+        let value = match value {
+            AttrValue::Bool(b) => AttrValue::Int(b as i64),
+            _ => value,
+        };
+        onnx_attrs.push((name.to_string(), value));
+    }
+
+    // Sort for deterministic output.
+    onnx_attrs.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    onnx_attrs
+}
+
+// Values of the `TensorProto.DataType` enum.
+const DATA_TYPE_FLOAT: i64 = 1;
+const DATA_TYPE_UINT8: i64 = 2;
+const DATA_TYPE_INT8: i64 = 3;
+const DATA_TYPE_INT32: i64 = 6;
+const DATA_TYPE_INT64: i64 = 7;
+const DATA_TYPE_STRING: i64 = 8;
+const DATA_TYPE_BOOL: i64 = 9;
+const DATA_TYPE_BFLOAT16: i64 = 16;
+
+// Values of the `AttributeProto.AttributeType` enum.
+const ATTR_FLOAT: i64 = 1;
+const ATTR_INT: i64 = 2;
+const ATTR_STRING: i64 = 3;
+const ATTR_GRAPH: i64 = 5;
+const ATTR_FLOATS: i64 = 6;
+const ATTR_INTS: i64 = 7;
+
+/// Protobuf wire types.
+#[derive(Copy, Clone)]
+enum WireType {
+    Varint = 0,
+    LengthDelimited = 2,
+    Fixed32 = 5,
+}
+
+/// Writer for the protobuf binary encoding.
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn varint(&mut self, mut val: u64) {
+        while val >= 0x80 {
+            self.buf.push((val as u8 & 0x7f) | 0x80);
+            val >>= 7;
+        }
+        self.buf.push(val as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: WireType) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn int(&mut self, field: u32, val: i64) {
+        self.key(field, WireType::Varint);
+        self.varint(val as u64);
+    }
+
+    fn float(&mut self, field: u32, val: f32) {
+        self.key(field, WireType::Fixed32);
+        self.buf.extend(val.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, data: &[u8]) {
+        self.key(field, WireType::LengthDelimited);
+        self.varint(data.len() as u64);
+        self.buf.extend_from_slice(data);
+    }
+
+    fn string(&mut self, field: u32, val: &str) {
+        self.bytes(field, val.as_bytes());
+    }
+
+    fn packed_ints(&mut self, field: u32, vals: impl IntoIterator<Item = i64>) {
+        let mut packed = ProtoWriter::default();
+        for val in vals {
+            packed.varint(val as u64);
+        }
+        self.bytes(field, &packed.buf);
+    }
+
+    fn packed_floats(&mut self, field: u32, vals: &[f32]) {
+        let packed: Vec<u8> = vals.iter().flat_map(|x| x.to_le_bytes()).collect();
+        self.bytes(field, &packed);
+    }
+
+    /// Write an embedded message whose fields are written by `write`.
+    fn message<F: FnOnce(&mut ProtoWriter)>(&mut self, field: u32, write: F) {
+        let mut msg = ProtoWriter::default();
+        write(&mut msg);
+        self.bytes(field, &msg.buf);
+    }
+}
+
+/// Write an `AttributeProto`.
+///
+/// Operator domains used by graph-valued attributes are added to `domains`.
+fn write_attr(
+    w: &mut ProtoWriter,
+    name: &str,
+    value: &AttrValue,
+    domains: &mut BTreeSet<&'static str>,
+) {
+    w.string(1, name);
+    match value {
+        AttrValue::Float(f) => {
+            w.float(2, *f);
+            w.int(20, ATTR_FLOAT);
+        }
+        AttrValue::Int(i) => {
+            w.int(3, *i);
+            w.int(20, ATTR_INT);
+        }
+        AttrValue::Bool(b) => {
+            w.int(3, *b as i64);
+            w.int(20, ATTR_INT);
+        }
+        AttrValue::String(s) => {
+            w.string(4, s);
+            w.int(20, ATTR_STRING);
+        }
+        AttrValue::Floats(fs) => {
+            w.packed_floats(7, fs);
+            w.int(20, ATTR_FLOATS);
+        }
+        AttrValue::Ints(is) => {
+            w.packed_ints(8, is.iter().copied());
+            w.int(20, ATTR_INTS);
+        }
+        AttrValue::Graph(subgraph) => {
+            w.message(6, |w| {
+                write_graph(
+                    w,
+                    name,
+                    subgraph.graph(),
+                    subgraph.input_ids(),
+                    subgraph.output_ids(),
+                    subgraph.op_attrs(),
+                    domains,
+                )
+            });
+            w.int(20, ATTR_GRAPH);
+        }
+    }
+}
+
+/// Write a `TensorProto` for a constant.
+fn write_tensor(w: &mut ProtoWriter, name: &str, constant: &Constant) {
+    // String tensors can't use `raw_data`, so each element is written to the
+    // repeated `string_data` field instead.
+    if let Constant::String(node) = constant {
+        w.packed_ints(1, node.layout().shape().iter().map(|&size| size as i64));
+        w.int(2, DATA_TYPE_STRING);
+        for s in node.view().iter() {
+            w.bytes(6, s.as_bytes());
+        }
+        w.string(8, name);
+        return;
+    }
+
+    let (shape, data_type, raw_data): (&[usize], i64, Vec<u8>) = match constant {
+        Constant::Float(node) => {
+            let view = node.view();
+            let data = view.iter().flat_map(|x| x.to_le_bytes()).collect();
+            (node.layout().shape(), DATA_TYPE_FLOAT, data)
+        }
+        Constant::Int(node) => {
+            let view = node.view();
+            let data = view.iter().flat_map(|x| x.to_le_bytes()).collect();
+            (node.layout().shape(), DATA_TYPE_INT32, data)
+        }
+        Constant::Int64(node) => {
+            let view = node.view();
+            let data = view.iter().flat_map(|x| x.to_le_bytes()).collect();
+            (node.layout().shape(), DATA_TYPE_INT64, data)
+        }
+        Constant::BFloat16(node) => {
+            let view = node.view();
+            let data = view
+                .iter()
+                .flat_map(|x| x.to_bits().to_le_bytes())
+                .collect();
+            (node.layout().shape(), DATA_TYPE_BFLOAT16, data)
+        }
+        Constant::String(_) => unreachable!(),
+    };
+    w.packed_ints(1, shape.iter().map(|&size| size as i64));
+    w.int(2, data_type);
+    w.string(8, name);
+    w.bytes(9, &raw_data);
+}
+
+/// Write a `ValueInfoProto` for a graph input or output.
+fn write_value_info(w: &mut ProtoWriter, name: &str, shape: Option<&[Dimension]>) {
+    w.string(1, name);
+
+    // TypeProto
+    w.message(2, |w| {
+        // TypeProto.Tensor
+        w.message(1, |w| {
+            w.int(1, DATA_TYPE_FLOAT);
+            if let Some(shape) = shape {
+                // TensorShapeProto
+                w.message(2, |w| {
+                    for dim in shape {
+                        w.message(1, |w| match dim {
+                            Dimension::Fixed(size) => w.int(1, *size as i64),
+                            Dimension::Symbolic(name) => w.string(2, name),
+                        });
+                    }
+                });
+            }
+        });
+    });
+}
+
+/// Assign a unique ONNX value name to each value and constant node in the
+/// graph.
+///
+/// Nodes use their existing names where possible. Unnamed nodes, and nodes
+/// whose name is already taken, get a name derived from their ID. Names are
+/// assigned to `priority_ids` (eg. graph inputs and outputs) first, so that
+/// they keep their original names.
+fn value_names(graph: &Graph, priority_ids: &[NodeId]) -> HashMap<NodeId, String> {
+    let mut names = HashMap::new();
+    let mut used = BTreeSet::new();
+
+    let value_ids = graph
+        .iter()
+        .filter(|(_, node)| !matches!(node, Node::Operator(_)))
+        .map(|(id, _)| id);
+
+    for id in priority_ids.iter().copied().chain(value_ids) {
+        if names.contains_key(&id) {
+            continue;
+        }
+        let name = match graph.get_node(id).and_then(|n| n.name()) {
+            Some(name) if !used.contains(name) => name.to_string(),
+            Some(name) => format!("{}_{}", name, id),
+            None => format!("node_{}", id),
+        };
+        used.insert(name.clone());
+        names.insert(id, name);
+    }
+
+    names
+}
+
+/// Write a `GraphProto` for `graph`.
+///
+/// `op_attrs` specifies the attributes of each operator node. Operators
+/// without an entry are exported without attributes. The domains of exported
+/// operators are added to `domains`.
+fn write_graph(
+    graph_proto: &mut ProtoWriter,
+    name: &str,
+    graph: &Graph,
+    input_ids: &[NodeId],
+    output_ids: &[NodeId],
+    op_attrs: &HashMap<NodeId, OpAttrs>,
+    domains: &mut BTreeSet<&'static str>,
+) {
+    let io_ids: Vec<NodeId> = input_ids.iter().chain(output_ids).copied().collect();
+    let names = value_names(graph, &io_ids);
+
+    for (id, node) in graph.iter() {
+        match node {
+            Node::Operator(op_node) => {
+                let op_type = op_node.operator().name();
+                let domain = op_domain(op_type);
+                if !domain.is_empty() {
+                    domains.insert(domain);
+                }
+
+                graph_proto.message(1, |w| {
+                    for input in op_node.inputs() {
+                        // Omitted optional inputs are represented by an empty name.
+                        let name = input.map(|id| names[&id].as_str()).unwrap_or("");
+                        w.string(1, name);
+                    }
+                    for output in op_node.outputs() {
+                        let name = output.map(|id| names[&id].as_str()).unwrap_or("");
+                        w.string(2, name);
+                    }
+                    if let Some(name) = node.name() {
+                        w.string(3, name);
+                    }
+                    w.string(4, op_type);
+                    if let Some(attrs) = op_attrs.get(&id) {
+                        for (name, value) in onnx_attrs(op_type, attrs) {
+                            w.message(5, |w| write_attr(w, &name, &value, domains));
+                        }
+                    }
+                    if !domain.is_empty() {
+                        w.string(7, domain);
+                    }
+                });
+            }
+            Node::Constant(constant) => {
+                graph_proto.message(5, |w| write_tensor(w, &names[&id], constant));
+            }
+            Node::Value(_) => {}
+        }
+    }
+    graph_proto.string(2, name);
+    for (field, ids) in [(11, input_ids), (12, output_ids)] {
+        for id in ids {
+            let shape = graph.get_node(*id).and_then(|n| n.shape());
+            graph_proto.message(field, |w| write_value_info(w, &names[id], shape.as_deref()));
+        }
+    }
+}
+
+/// Serialize `graph` as an ONNX `ModelProto`.
+///
+/// `op_attrs` specifies the attributes of each operator node. Operators
+/// without an entry are exported without attributes.
+pub fn export_onnx(
+    graph: &Graph,
+    input_ids: &[NodeId],
+    output_ids: &[NodeId],
+    op_attrs: &HashMap<NodeId, OpAttrs>,
+) -> Vec<u8> {
+    let mut domains = BTreeSet::new();
+    let mut graph_proto = ProtoWriter::default();
+    write_graph(
+        &mut graph_proto,
+        "main_graph",
+        graph,
+        input_ids,
+        output_ids,
+        op_attrs,
+        &mut domains,
+    );
+
+    let mut model = ProtoWriter::default();
+    model.int(1, IR_VERSION);
+    model.string(2, "rten");
+    model.string(3, env!("CARGO_PKG_VERSION"));
+    model.bytes(7, &graph_proto.buf);
+    model.message(8, |w| {
+        w.string(1, "");
+        w.int(2, OPSET_VERSION);
+    });
+    for domain in domains {
+        model.message(8, |w| {
+            w.string(1, domain);
+            w.int(2, 1);
+        });
+    }
+    model.buf
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rten_tensor::Tensor;
+
+    use super::{export_onnx, RTEN_DOMAIN};
+    use crate::graph::{Dimension, Graph, Subgraph};
+    use crate::op_registry::{OpAttrs, OpRegistry};
+
+    /// A decoded protobuf field value.
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Varint(u64),
+        Fixed32(u32),
+        Bytes(Vec<u8>),
+    }
+
+    /// Minimal protobuf decoder which returns the `(field, value)` pairs in a
+    /// message.
+    fn decode(mut buf: &[u8]) -> Vec<(u32, Value)> {
+        fn varint(buf: &mut &[u8]) -> u64 {
+            let mut val = 0;
+            let mut shift = 0;
+            loop {
+                let byte = buf[0];
+                *buf = &buf[1..];
+                val |= ((byte & 0x7f) as u64) << shift;
+                if byte & 0x80 == 0 {
+                    return val;
+                }
+                shift += 7;
+            }
+        }
+
+        let mut fields = Vec::new();
+        while !buf.is_empty() {
+            let key = varint(&mut buf);
+            let field = (key >> 3) as u32;
+            let value = match key & 0x7 {
+                0 => Value::Varint(varint(&mut buf)),
+                2 => {
+                    let len = varint(&mut buf) as usize;
+                    let (data, rest) = buf.split_at(len);
+                    buf = rest;
+                    Value::Bytes(data.to_vec())
+                }
+                5 => {
+                    let (data, rest) = buf.split_at(4);
+                    buf = rest;
+                    Value::Fixed32(u32::from_le_bytes(data.try_into().unwrap()))
+                }
+                wire_type => panic!("unsupported wire type {}", wire_type),
+            };
+            fields.push((field, value));
+        }
+        fields
+    }
+
+    /// Return the values of all occurrences of `field` in a message.
+    fn get_all(msg: &[(u32, Value)], field: u32) -> Vec<&Value> {
+        msg.iter()
+            .filter(|(f, _)| *f == field)
+            .map(|(_, v)| v)
+            .collect()
+    }
+
+    fn get_str(msg: &[(u32, Value)], field: u32) -> Vec<String> {
+        get_all(msg, field)
+            .into_iter()
+            .map(|v| match v {
+                Value::Bytes(b) => String::from_utf8(b.clone()).unwrap(),
+                _ => panic!("expected string"),
+            })
+            .collect()
+    }
+
+    fn get_msgs(msg: &[(u32, Value)], field: u32) -> Vec<Vec<(u32, Value)>> {
+        get_all(msg, field)
+            .into_iter()
+            .map(|v| match v {
+                Value::Bytes(b) => decode(b),
+                _ => panic!("expected message"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_export_onnx() {
+        let registry = OpRegistry::with_all_ops();
+        let mut graph = Graph::new();
+        let mut op_attrs = HashMap::new();
+
+        let input_id = graph.add_value(
+            Some("input"),
+            Some(vec![
+                Dimension::Symbolic("batch".to_string()),
+                Dimension::Fixed(3),
+            ]),
+        );
+        let weights_id =
+            graph.add_constant(Some("weights"), Tensor::from_data(&[3], vec![1., 2., 3.]));
+        let mul_out = graph.add_value(None, None);
+        let output_id = graph.add_value(Some("output"), None);
+
+        let mul_attrs = OpAttrs::new();
+        let mul_id = graph.add_op(
+            Some("mul"),
+            registry.read_op("Mul", &mul_attrs).unwrap(),
+            &[Some(input_id), Some(weights_id)],
+            &[Some(mul_out)],
+        );
+        op_attrs.insert(mul_id, mul_attrs);
+
+        let norm_attrs = OpAttrs::new().with("mean", vec![0.5]).with("std", vec![2.]);
+        let norm_id = graph.add_op(
+            Some("normalize"),
+            registry.read_op("Normalize", &norm_attrs).unwrap(),
+            &[Some(mul_out)],
+            &[Some(output_id)],
+        );
+        op_attrs.insert(norm_id, norm_attrs);
+
+        let buf = export_onnx(&graph, &[input_id], &[output_id], &op_attrs);
+        let model = decode(&buf);
+
+        assert_eq!(get_all(&model, 1), [&Value::Varint(8)]);
+        assert_eq!(get_str(&model, 2), ["rten"]);
+
+        let opsets: Vec<_> = get_msgs(&model, 8)
+            .iter()
+            .map(|opset| get_str(opset, 1).remove(0))
+            .collect();
+        assert_eq!(opsets, ["", RTEN_DOMAIN]);
+
+        let graph_proto = get_msgs(&model, 7).remove(0);
+
+        // Operator nodes
+        let nodes = get_msgs(&graph_proto, 1);
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(get_str(&nodes[0], 4), ["Mul"]);
+        assert_eq!(get_str(&nodes[0], 1), ["input", "weights"]);
+        assert_eq!(get_str(&nodes[0], 2), ["node_2"]);
+        assert!(get_str(&nodes[0], 7).is_empty());
+
+        assert_eq!(get_str(&nodes[1], 4), ["Normalize"]);
+        assert_eq!(get_str(&nodes[1], 7), [RTEN_DOMAIN]);
+        let attr_names: Vec<_> = get_msgs(&nodes[1], 5)
+            .iter()
+            .map(|attr| get_str(attr, 1).remove(0))
+            .collect();
+        assert_eq!(attr_names, ["mean", "std"]);
+
+        // Initializers
+        let initializers = get_msgs(&graph_proto, 5);
+        assert_eq!(initializers.len(), 1);
+        assert_eq!(get_str(&initializers[0], 8), ["weights"]);
+        let raw_data: Vec<u8> = [1f32, 2., 3.]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        assert_eq!(get_all(&initializers[0], 9), [&Value::Bytes(raw_data)]);
+
+        // Inputs and outputs
+        let inputs = get_msgs(&graph_proto, 11);
+        assert_eq!(get_str(&inputs[0], 1), ["input"]);
+        let outputs = get_msgs(&graph_proto, 12);
+        assert_eq!(get_str(&outputs[0], 1), ["output"]);
+    }
+
+    #[test]
+    fn test_export_graph_attr() {
+        let registry = OpRegistry::with_all_ops();
+
+        let mut body = Graph::new();
+        let body_in = body.add_value(Some("state_in"), None);
+        let body_out = body.add_value(Some("state_out"), None);
+        let identity_attrs = OpAttrs::new();
+        let identity_id = body.add_op(
+            Some("identity"),
+            registry.read_op("Identity", &identity_attrs).unwrap(),
+            &[Some(body_in)],
+            &[Some(body_out)],
+        );
+        let body = Subgraph::new(
+            body,
+            vec![body_in],
+            vec![body_out],
+            [(identity_id, identity_attrs)].into(),
+        );
+
+        let mut graph = Graph::new();
+        let input_id = graph.add_value(Some("input"), None);
+        let output_id = graph.add_value(Some("output"), None);
+        let scan_attrs = OpAttrs::new().with("body", body).with("num_scan_inputs", 0);
+        let scan_id = graph.add_op(
+            Some("scan"),
+            registry.read_op("Scan", &scan_attrs).unwrap(),
+            &[Some(input_id)],
+            &[Some(output_id)],
+        );
+        let op_attrs = [(scan_id, scan_attrs)].into();
+
+        let buf = export_onnx(&graph, &[input_id], &[output_id], &op_attrs);
+        let model = decode(&buf);
+        let graph_proto = get_msgs(&model, 7).remove(0);
+        let nodes = get_msgs(&graph_proto, 1);
+        assert_eq!(get_str(&nodes[0], 4), ["Scan"]);
+
+        let attrs = get_msgs(&nodes[0], 5);
+        let body_attr = attrs
+            .iter()
+            .find(|attr| get_str(attr, 1) == ["body"])
+            .unwrap();
+        assert_eq!(get_all(body_attr, 20), [&Value::Varint(5)]);
+
+        let body_proto = get_msgs(body_attr, 6).remove(0);
+        let body_nodes = get_msgs(&body_proto, 1);
+        assert_eq!(body_nodes.len(), 1);
+        assert_eq!(get_str(&body_nodes[0], 4), ["Identity"]);
+        assert_eq!(get_str(&body_nodes[0], 1), ["state_in"]);
+        assert_eq!(get_str(&get_msgs(&body_proto, 11)[0], 1), ["state_in"]);
+        assert_eq!(get_str(&get_msgs(&body_proto, 12)[0], 1), ["state_out"]);
+    }
+}
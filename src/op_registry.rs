@@ -0,0 +1,1467 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use smallvec::smallvec;
+
+use crate::graph::Subgraph;
+use crate::ops;
+use crate::ops::{
+    BitShiftDirection, BoxOrder, CoordTransformMode, DataType, Direction, GridSamplePadding,
+    NearestMode, Operator, PadMode, Padding, ResizeMode, Scalar, ScatterReduction,
+};
+
+/// Value of an operator attribute.
+///
+/// Attribute values use a small set of generic types, similar to ONNX
+/// attributes. Enum-like attributes (eg. the `mode` of a `Resize` operator) are
+/// represented as strings using snake_case names (eg. `"nearest"`). Graph
+/// attributes, such as the body of a `Scan` operator, are loaded into a
+/// [Subgraph] which is shared by the operator and the attribute dictionary.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrValue {
+    Bool(bool),
+    Int(i64),
+    Float(f32),
+    String(String),
+    Ints(Vec<i64>),
+    Floats(Vec<f32>),
+    Graph(Arc<Subgraph>),
+}
+
+macro_rules! impl_attr_value_from {
+    ($variant:ident, $type:ty) => {
+        impl From<$type> for AttrValue {
+            fn from(val: $type) -> AttrValue {
+                AttrValue::$variant(val.into())
+            }
+        }
+    };
+
+    ($variant:ident, $type:ty, as $inner:ty) => {
+        impl From<$type> for AttrValue {
+            fn from(val: $type) -> AttrValue {
+                AttrValue::$variant(val as $inner)
+            }
+        }
+    };
+}
+
+impl_attr_value_from!(Bool, bool);
+impl_attr_value_from!(Float, f32);
+impl_attr_value_from!(Int, i32, as i64);
+impl_attr_value_from!(Int, i64, as i64);
+impl_attr_value_from!(Int, isize, as i64);
+impl_attr_value_from!(Int, u32, as i64);
+impl_attr_value_from!(Int, usize, as i64);
+impl_attr_value_from!(String, &str);
+impl_attr_value_from!(String, String);
+impl_attr_value_from!(Floats, Vec<f32>);
+impl_attr_value_from!(Graph, Subgraph);
+impl_attr_value_from!(Graph, Arc<Subgraph>);
+
+macro_rules! impl_attr_value_from_ints {
+    ($type:ty) => {
+        impl From<Vec<$type>> for AttrValue {
+            fn from(val: Vec<$type>) -> AttrValue {
+                AttrValue::Ints(val.into_iter().map(|x| x as i64).collect())
+            }
+        }
+    };
+}
+
+impl_attr_value_from_ints!(i32);
+impl_attr_value_from_ints!(i64);
+impl_attr_value_from_ints!(u32);
+impl_attr_value_from_ints!(usize);
+
+impl<const N: usize> From<[usize; N]> for AttrValue {
+    fn from(val: [usize; N]) -> AttrValue {
+        Vec::from(val).into()
+    }
+}
+
+impl<'a> TryFrom<&'a AttrValue> for bool {
+    type Error = ReadOpError;
+
+    fn try_from(val: &'a AttrValue) -> Result<bool, ReadOpError> {
+        match val {
+            AttrValue::Bool(b) => Ok(*b),
+            // ONNX represents boolean attributes as ints.
+            AttrValue::Int(i) => Ok(*i != 0),
+            _ => Err(ReadOpError::AttrError),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a AttrValue> for f32 {
+    type Error = ReadOpError;
+
+    fn try_from(val: &'a AttrValue) -> Result<f32, ReadOpError> {
+        match val {
+            AttrValue::Float(f) => Ok(*f),
+            _ => Err(ReadOpError::AttrError),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a AttrValue> for &'a str {
+    type Error = ReadOpError;
+
+    fn try_from(val: &'a AttrValue) -> Result<&'a str, ReadOpError> {
+        match val {
+            AttrValue::String(s) => Ok(s.as_str()),
+            _ => Err(ReadOpError::AttrError),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a AttrValue> for Vec<f32> {
+    type Error = ReadOpError;
+
+    fn try_from(val: &'a AttrValue) -> Result<Vec<f32>, ReadOpError> {
+        match val {
+            AttrValue::Floats(fs) => Ok(fs.clone()),
+            _ => Err(ReadOpError::AttrError),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a AttrValue> for Arc<Subgraph> {
+    type Error = ReadOpError;
+
+    fn try_from(val: &'a AttrValue) -> Result<Arc<Subgraph>, ReadOpError> {
+        match val {
+            AttrValue::Graph(graph) => Ok(graph.clone()),
+            _ => Err(ReadOpError::AttrError),
+        }
+    }
+}
+
+/// Implement conversions from integer attributes into a scalar integer type
+/// and vectors of that type. Conversions fail if a value is out of range.
+macro_rules! impl_int_attr_conversions {
+    ($type:ty) => {
+        impl<'a> TryFrom<&'a AttrValue> for $type {
+            type Error = ReadOpError;
+
+            fn try_from(val: &'a AttrValue) -> Result<$type, ReadOpError> {
+                match val {
+                    AttrValue::Int(i) => (*i).try_into().map_err(|_| ReadOpError::AttrError),
+                    _ => Err(ReadOpError::AttrError),
+                }
+            }
+        }
+
+        impl<'a> TryFrom<&'a AttrValue> for Vec<$type> {
+            type Error = ReadOpError;
+
+            fn try_from(val: &'a AttrValue) -> Result<Vec<$type>, ReadOpError> {
+                match val {
+                    AttrValue::Ints(ints) => ints
+                        .iter()
+                        .map(|&i| i.try_into().map_err(|_| ReadOpError::AttrError))
+                        .collect(),
+                    _ => Err(ReadOpError::AttrError),
+                }
+            }
+        }
+    };
+}
+
+impl_int_attr_conversions!(i32);
+impl_int_attr_conversions!(i64);
+impl_int_attr_conversions!(isize);
+impl_int_attr_conversions!(u32);
+impl_int_attr_conversions!(usize);
+
+/// Dictionary of attributes for an operator, keyed by name.
+///
+/// This is the format-independent representation of operator configuration
+/// that is used to construct operators via an [OpRegistry]. Model loaders
+/// convert the attributes stored in a model file into an `OpAttrs` before
+/// instantiating the operator.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OpAttrs {
+    attrs: HashMap<String, AttrValue>,
+}
+
+impl OpAttrs {
+    /// Create an empty attribute dictionary.
+    pub fn new() -> OpAttrs {
+        OpAttrs {
+            attrs: HashMap::new(),
+        }
+    }
+
+    /// Set the value of an attribute.
+    pub fn insert<V: Into<AttrValue>>(&mut self, name: &str, value: V) {
+        self.attrs.insert(name.to_string(), value.into());
+    }
+
+    /// Set the value of an attribute and return the updated dictionary.
+    ///
+    /// This is a convenience method for building attribute dictionaries
+    /// with a chain of calls, eg. `OpAttrs::new().with("axis", 1)`.
+    pub fn with<V: Into<AttrValue>>(mut self, name: &str, value: V) -> OpAttrs {
+        self.insert(name, value);
+        self
+    }
+
+    /// Return the raw value of an attribute.
+    pub fn get(&self, name: &str) -> Option<&AttrValue> {
+        self.attrs.get(name)
+    }
+
+    /// Return the value of an optional attribute converted to type `T`.
+    ///
+    /// Returns an error if the attribute is present but has a type that cannot
+    /// be converted to `T`.
+    pub fn get_as<'a, T>(&'a self, name: &str) -> Result<Option<T>, ReadOpError>
+    where
+        T: TryFrom<&'a AttrValue, Error = ReadOpError>,
+    {
+        self.get(name)
+            .map(|val| {
+                val.try_into()
+                    .map_err(|_| ReadOpError::InvalidAttr(name.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Return the value of an attribute converted to type `T`, or `default`
+    /// if the attribute is not present.
+    pub fn get_or<'a, T>(&'a self, name: &str, default: T) -> Result<T, ReadOpError>
+    where
+        T: TryFrom<&'a AttrValue, Error = ReadOpError>,
+    {
+        self.get_as(name).map(|val| val.unwrap_or(default))
+    }
+
+    /// Return the value of a required attribute converted to type `T`.
+    pub fn require<'a, T>(&'a self, name: &str) -> Result<T, ReadOpError>
+    where
+        T: TryFrom<&'a AttrValue, Error = ReadOpError>,
+    {
+        self.get_as(name)?
+            .ok_or_else(|| ReadOpError::InvalidAttr(name.to_string()))
+    }
+
+    /// Return an iterator over `(name, value)` pairs of attributes.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AttrValue)> {
+        self.attrs.iter().map(|(name, val)| (name.as_str(), val))
+    }
+
+    /// Return the number of attributes in the dictionary.
+    pub fn len(&self) -> usize {
+        self.attrs.len()
+    }
+
+    /// Return true if the dictionary has no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attrs.is_empty()
+    }
+}
+
+/// Result of deserializing an operator node from a model file.
+pub type ReadOpResult = Result<Box<dyn Operator + Send + Sync>, ReadOpError>;
+
+/// A function that constructs an operator from its attributes.
+pub type ReadOpFunction = dyn Fn(&OpAttrs) -> ReadOpResult + Send + Sync;
+
+/// Trait that constructs an [Operator] implementation from a dictionary of
+/// attributes.
+///
+/// This trait is implemented for all operators in [crate::ops].
+pub trait ReadOp: Operator + Sized + Send + Sync {
+    /// Return the name of the operator type, eg. "Conv".
+    ///
+    /// This matches the name of the corresponding ONNX operator.
+    fn op_type() -> &'static str;
+
+    /// Construct an operator from its attributes.
+    fn read(attrs: &OpAttrs) -> Result<Self, ReadOpError>;
+
+    /// Construct an operator and box it into a `Box<dyn Operator>`.
+    fn read_boxed(attrs: &OpAttrs) -> ReadOpResult
+    where
+        Self: 'static,
+    {
+        let op = Self::read(attrs)?;
+        Ok(Box::new(op))
+    }
+}
+
+/// Constructs an [Operator] from a dictionary of attributes.
+///
+/// This is the interface for supplying implementations of custom operators via
+/// [OpRegistry::register_custom_op]. Implementations read attribute values
+/// using methods such as [OpAttrs::require] and [OpAttrs::get_or]. The trait
+/// is implemented for closures of the form `Fn(&OpAttrs) -> ReadOpResult`.
+/// Such closures need their argument and return types to be annotated, eg.
+/// `|attrs: &OpAttrs| -> ReadOpResult { ... }`.
+pub trait OperatorFactory: Send + Sync {
+    /// Construct an operator from its attributes.
+    fn create(&self, attrs: &OpAttrs) -> ReadOpResult;
+}
+
+impl<F> OperatorFactory for F
+where
+    F: Fn(&OpAttrs) -> ReadOpResult + Send + Sync,
+{
+    fn create(&self, attrs: &OpAttrs) -> ReadOpResult {
+        self(attrs)
+    }
+}
+
+/// A collection of custom operators that can be registered together.
+///
+/// This allows operators which are not built into RTen to be distributed in
+/// separate crates. A crate implements this trait to register each of its
+/// operators, and applications add all of them to a registry with a single
+/// call to [OpRegistry::register_pack] before loading a model.
+pub trait OperatorPack {
+    /// Register the operators in this pack with `registry`.
+    fn register(&self, registry: &mut OpRegistry);
+}
+
+/// Read the padding attributes (`pad_mode` and `pads`) for an operator.
+fn read_padding(attrs: &OpAttrs, default_mode: &str) -> Result<Padding, ReadOpError> {
+    let mode: &str = attrs.get_or("pad_mode", default_mode)?;
+    match mode {
+        "same" => Ok(Padding::Same),
+        "fixed" => {
+            let pads: Option<Vec<usize>> = attrs.get_as("pads")?;
+            Ok(pads
+                .map(Padding::from)
+                .unwrap_or(Padding::Fixed(smallvec!(0; 4))))
+        }
+        _ => Err(ReadOpError::InvalidAttr("pad_mode".to_string())),
+    }
+}
+
+/// Read an attribute consisting of a fixed number of sizes.
+fn read_sizes<const N: usize>(
+    attrs: &OpAttrs,
+    name: &str,
+    default: Option<[usize; N]>,
+) -> Result<[usize; N], ReadOpError> {
+    let sizes: Option<Vec<usize>> = attrs.get_as(name)?;
+    match (sizes, default) {
+        (Some(sizes), _) => sizes
+            .try_into()
+            .map_err(|_| ReadOpError::InvalidAttr(name.to_string())),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(ReadOpError::InvalidAttr(name.to_string())),
+    }
+}
+
+/// Read an enum-valued attribute using a function that maps names to values.
+fn read_enum<T, F: Fn(&str) -> Option<T>>(
+    attrs: &OpAttrs,
+    name: &str,
+    default: T,
+    parse: F,
+) -> Result<T, ReadOpError> {
+    match attrs.get_as::<&str>(name)? {
+        Some(val) => parse(val).ok_or_else(|| ReadOpError::InvalidAttr(name.to_string())),
+        None => Ok(default),
+    }
+}
+
+fn parse_data_type(val: &str) -> Option<DataType> {
+    match val {
+        "int32" => Some(DataType::Int32),
+        "float" => Some(DataType::Float),
+        "int64" => Some(DataType::Int64),
+        "string" => Some(DataType::String),
+        "int8" => Some(DataType::Int8),
+        "uint8" => Some(DataType::UInt8),
+        "bool" => Some(DataType::Bool),
+        _ => None,
+    }
+}
+
+fn read_direction(attrs: &OpAttrs) -> Result<Direction, ReadOpError> {
+    read_enum(attrs, "direction", Direction::Forward, |val| match val {
+        "forward" => Some(Direction::Forward),
+        "reverse" => Some(Direction::Reverse),
+        "bidirectional" => Some(Direction::Bidirectional),
+        _ => None,
+    })
+}
+
+fn read_scatter_reduction(attrs: &OpAttrs) -> Result<Option<ScatterReduction>, ReadOpError> {
+    read_enum(attrs, "reduction", None, |val| match val {
+        "none" => Some(None),
+        "add" => Some(Some(ScatterReduction::Add)),
+        "mul" => Some(Some(ScatterReduction::Mul)),
+        "min" => Some(Some(ScatterReduction::Min)),
+        "max" => Some(Some(ScatterReduction::Max)),
+        _ => None,
+    })
+}
+
+/// Convenience macro to simplify implementing [ReadOp].
+macro_rules! impl_read_op {
+    ($op:ident) => {
+        impl ReadOp for ops::$op {
+            fn op_type() -> &'static str {
+                stringify!($op)
+            }
+
+            fn read(_attrs: &OpAttrs) -> Result<Self, ReadOpError> {
+                Ok(ops::$op {})
+            }
+        }
+    };
+
+    ($op:ident, axis) => {
+        impl_read_op!($op, |attrs: &OpAttrs| {
+            Ok(ops::$op {
+                axis: attrs.get_or("axis", 0)?,
+            })
+        });
+    };
+
+    ($op:ident, reduce_axis) => {
+        impl_read_op!($op, |attrs: &OpAttrs| {
+            Ok(ops::$op {
+                axis: attrs.get_or("axis", 0)?,
+                keep_dims: attrs.get_or("keep_dims", true)?,
+            })
+        });
+    };
+
+    ($op:ident, reduce_axes) => {
+        impl_read_op!($op, |attrs: &OpAttrs| {
+            Ok(ops::$op {
+                axes: attrs.get_as("axes")?,
+                keep_dims: attrs.get_or("keep_dims", true)?,
+            })
+        });
+    };
+
+    ($op:ident, reduce_var) => {
+        impl_read_op!($op, |attrs: &OpAttrs| {
+            Ok(ops::$op {
+                axes: attrs.get_as("axes")?,
+                keep_dims: attrs.get_or("keep_dims", true)?,
+                correction: attrs.get_or("correction", 0)?,
+            })
+        });
+    };
+
+    ($op:ident, $read_op:expr) => {
+        impl ReadOp for ops::$op {
+            fn op_type() -> &'static str {
+                stringify!($op)
+            }
+
+            fn read(attrs: &OpAttrs) -> Result<Self, ReadOpError> {
+                #[allow(clippy::redundant_closure_call)]
+                let op = { $read_op(attrs)? };
+                Ok(op)
+            }
+        }
+    };
+}
+
+impl_read_op!(Abs);
+impl_read_op!(Acos);
+impl_read_op!(Add);
+impl_read_op!(And);
+impl_read_op!(ArgMax, reduce_axis);
+impl_read_op!(ArgMin, reduce_axis);
+impl_read_op!(Asin);
+impl_read_op!(Atan);
+impl_read_op!(AveragePool, |attrs: &OpAttrs| {
+    Ok(ops::AveragePool {
+        kernel_size: read_sizes(attrs, "kernel_size", None)?,
+        padding: read_padding(attrs, "fixed")?,
+        count_include_pad: attrs.get_or("count_include_pad", false)?,
+        strides: read_sizes(attrs, "strides", Some([1, 1]))?,
+        dilations: read_sizes(attrs, "dilations", Some([1, 1]))?,
+        ceil_mode: attrs.get_or("ceil_mode", false)?,
+    })
+});
+impl_read_op!(BatchNormalization, |attrs: &OpAttrs| {
+    Ok(ops::BatchNormalization {
+        epsilon: attrs.get_or("epsilon", 1e-5)?,
+    })
+});
+impl_read_op!(BitShift, |attrs: &OpAttrs| {
+    let direction = read_enum(
+        attrs,
+        "direction",
+        BitShiftDirection::Left,
+        |val| match val {
+            "left" => Some(BitShiftDirection::Left),
+            "right" => Some(BitShiftDirection::Right),
+            _ => None,
+        },
+    )?;
+    Ok(ops::BitShift { direction })
+});
+impl_read_op!(BitwiseAnd);
+impl_read_op!(BitwiseNot);
+impl_read_op!(BitwiseOr);
+impl_read_op!(BitwiseXor);
+impl_read_op!(Cast, |attrs: &OpAttrs| {
+    let to = read_enum(attrs, "to", DataType::Float, parse_data_type)?;
+    Ok(ops::Cast { to })
+});
+impl_read_op!(CastLike);
+impl_read_op!(Ceil);
+impl_read_op!(Celu, |attrs: &OpAttrs| {
+    Ok(ops::Celu {
+        alpha: attrs.get_or("alpha", 1.0)?,
+    })
+});
+impl_read_op!(ChannelShuffle, |attrs: &OpAttrs| {
+    Ok(ops::ChannelShuffle {
+        groups: attrs.require("groups")?,
+    })
+});
+impl_read_op!(Clip);
+impl_read_op!(Concat, axis);
+impl_read_op!(ConcatFromSequence, |attrs: &OpAttrs| {
+    Ok(ops::ConcatFromSequence {
+        axis: attrs.require("axis")?,
+        new_axis: attrs.get_or("new_axis", false)?,
+    })
+});
+impl_read_op!(Conv, |attrs: &OpAttrs| {
+    Ok(ops::Conv::new(
+        attrs.get_or("groups", 1)?,
+        attrs.get_or("dilations", vec![1, 1])?,
+        read_padding(attrs, "fixed")?,
+        attrs.get_or("strides", vec![1, 1])?,
+    ))
+});
+impl_read_op!(ConstantOfShape, |attrs: &OpAttrs| {
+    let value = match attrs.get("value") {
+        Some(AttrValue::Int(val)) => Scalar::Int(
+            (*val)
+                .try_into()
+                .map_err(|_| ReadOpError::InvalidAttr("value".to_string()))?,
+        ),
+        Some(AttrValue::Float(val)) => Scalar::Float(*val),
+        Some(_) => return Err(ReadOpError::InvalidAttr("value".to_string())),
+        None => Scalar::Int(0),
+    };
+    Ok(ops::ConstantOfShape { value })
+});
+impl_read_op!(ConvTranspose, |attrs: &OpAttrs| {
+    Ok(ops::ConvTranspose {
+        padding: read_padding(attrs, "fixed")?,
+        groups: attrs.get_or("groups", 1)?,
+        strides: attrs.get_or("strides", vec![1, 1])?,
+        dilations: attrs.get_or("dilations", Vec::new())?,
+        output_padding: attrs.get_or("output_padding", Vec::new())?,
+        output_shape: attrs.get_as("output_shape")?,
+    })
+});
+impl_read_op!(Cos);
+impl_read_op!(CumSum);
+impl_read_op!(Div);
+impl_read_op!(Dropout, |attrs: &OpAttrs| {
+    Ok(ops::Dropout {
+        seed: attrs.get_as("seed")?,
+    })
+});
+impl_read_op!(Elu, |attrs: &OpAttrs| {
+    Ok(ops::Elu {
+        alpha: attrs.get_or("alpha", 1.0)?,
+    })
+});
+impl_read_op!(Equal);
+impl_read_op!(Erf);
+impl_read_op!(Exp);
+impl_read_op!(Expand);
+impl_read_op!(EyeLike, |attrs: &OpAttrs| {
+    Ok(ops::EyeLike {
+        dtype: read_enum(attrs, "dtype", None, |val| parse_data_type(val).map(Some))?,
+        k: attrs.get_or("k", 0)?,
+    })
+});
+impl_read_op!(Flatten, |attrs: &OpAttrs| {
+    Ok(ops::Flatten {
+        axis: attrs.get_or("axis", 1)?,
+    })
+});
+impl_read_op!(Floor);
+impl_read_op!(Gather, axis);
+impl_read_op!(GatherElements, axis);
+impl_read_op!(GatherND, |attrs: &OpAttrs| {
+    Ok(ops::GatherND {
+        batch_dims: attrs.get_or("batch_dims", 0)?,
+    })
+});
+impl_read_op!(GatherBlockQuantized, |attrs: &OpAttrs| {
+    Ok(ops::GatherBlockQuantized {
+        bits: attrs.get_or("bits", 4)?,
+        block_size: attrs.get_or("block_size", 128)?,
+    })
+});
+impl_read_op!(Gelu, |attrs: &OpAttrs| {
+    Ok(ops::Gelu {
+        approximate: attrs.get_or("approximate", false)?,
+    })
+});
+impl_read_op!(Gemm, |attrs: &OpAttrs| {
+    Ok(ops::Gemm {
+        alpha: attrs.get_or("alpha", 1.0)?,
+        beta: attrs.get_or("beta", 1.0)?,
+        transpose_a: attrs.get_or("transpose_a", false)?,
+        transpose_b: attrs.get_or("transpose_b", false)?,
+    })
+});
+impl_read_op!(GlobalAveragePool);
+impl_read_op!(GlobalMaxPool);
+impl_read_op!(Greater);
+impl_read_op!(GreaterOrEqual);
+impl_read_op!(GRU, |attrs: &OpAttrs| {
+    Ok(ops::GRU {
+        direction: read_direction(attrs)?,
+        hidden_size: attrs.require("hidden_size")?,
+        linear_before_reset: attrs.get_or("linear_before_reset", false)?,
+    })
+});
+impl_read_op!(GridSample, |attrs: &OpAttrs| {
+    let mode = read_enum(attrs, "mode", ResizeMode::Linear, |val| match val {
+        "nearest" => Some(ResizeMode::Nearest),
+        "linear" => Some(ResizeMode::Linear),
+        _ => None,
+    })?;
+    let padding_mode =
+        read_enum(
+            attrs,
+            "padding_mode",
+            GridSamplePadding::default(),
+            |val| match val {
+                "zeros" => Some(GridSamplePadding::Zeros),
+                "border" => Some(GridSamplePadding::Border),
+                "reflection" => Some(GridSamplePadding::Reflection),
+                _ => None,
+            },
+        )?;
+    Ok(ops::GridSample {
+        mode,
+        padding_mode,
+        align_corners: attrs.get_or("align_corners", false)?,
+    })
+});
+impl_read_op!(HardSigmoid, |attrs: &OpAttrs| {
+    Ok(ops::HardSigmoid {
+        alpha: attrs.get_or("alpha", 0.2)?,
+        beta: attrs.get_or("beta", 0.5)?,
+    })
+});
+impl_read_op!(HardSwish);
+impl_read_op!(Identity);
+impl_read_op!(ImageToTensor, |attrs: &OpAttrs| {
+    Ok(ops::ImageToTensor {
+        scale: attrs.get_or("scale", 1.0)?,
+        mean: attrs.get_or("mean", Vec::new())?,
+        std: attrs.get_or("std", Vec::new())?,
+    })
+});
+impl_read_op!(InstanceNormalization, |attrs: &OpAttrs| {
+    Ok(ops::InstanceNormalization {
+        epsilon: attrs.get_as("epsilon")?,
+    })
+});
+impl_read_op!(LayerNormalization, |attrs: &OpAttrs| {
+    Ok(ops::LayerNormalization {
+        axis: attrs.get_or("axis", -1)?,
+        epsilon: attrs.get_as("epsilon")?,
+    })
+});
+impl_read_op!(LeakyRelu, |attrs: &OpAttrs| {
+    Ok(ops::LeakyRelu {
+        alpha: attrs.get_or("alpha", 0.01)?,
+    })
+});
+impl_read_op!(Less);
+impl_read_op!(LessOrEqual);
+impl_read_op!(Log);
+impl_read_op!(LogSoftmax, |attrs: &OpAttrs| {
+    Ok(ops::LogSoftmax {
+        axis: attrs.get_or("axis", -1)?,
+    })
+});
+impl_read_op!(LpNormalization, |attrs: &OpAttrs| {
+    Ok(ops::LpNormalization {
+        axis: attrs.get_or("axis", -1)?,
+        p: attrs.get_or("p", 2)?,
+    })
+});
+impl_read_op!(LpPool, |attrs: &OpAttrs| {
+    Ok(ops::LpPool {
+        kernel_size: read_sizes(attrs, "kernel_size", None)?,
+        padding: read_padding(attrs, "fixed")?,
+        strides: read_sizes(attrs, "strides", Some([1, 1]))?,
+        dilations: read_sizes(attrs, "dilations", Some([1, 1]))?,
+        ceil_mode: attrs.get_or("ceil_mode", false)?,
+        p: attrs.get_or("p", 2)?,
+    })
+});
+impl_read_op!(LSTM, |attrs: &OpAttrs| {
+    Ok(ops::LSTM {
+        direction: read_direction(attrs)?,
+        hidden_size: attrs.require("hidden_size")?,
+    })
+});
+impl_read_op!(MatMul);
+impl_read_op!(MatMulNBits, |attrs: &OpAttrs| {
+    Ok(ops::MatMulNBits {
+        bits: attrs.get_or("bits", 4)?,
+        block_size: attrs.get_or("block_size", 128)?,
+    })
+});
+impl_read_op!(Max);
+impl_read_op!(MaxPool, |attrs: &OpAttrs| {
+    Ok(ops::MaxPool {
+        kernel_size: read_sizes(attrs, "kernel_size", None)?,
+        padding: read_padding(attrs, "fixed")?,
+        strides: read_sizes(attrs, "strides", Some([1, 1]))?,
+        dilations: read_sizes(attrs, "dilations", Some([1, 1]))?,
+        ceil_mode: attrs.get_or("ceil_mode", false)?,
+    })
+});
+impl_read_op!(Mean);
+impl_read_op!(MeanVarianceNormalization, |attrs: &OpAttrs| {
+    Ok(ops::MeanVarianceNormalization {
+        axes: attrs.get_or("axes", vec![0, 2, 3])?,
+    })
+});
+impl_read_op!(Min);
+impl_read_op!(Mod, |attrs: &OpAttrs| {
+    Ok(ops::Mod {
+        fmod: attrs.get_or("fmod", false)?,
+    })
+});
+impl_read_op!(Mish);
+impl_read_op!(Mul);
+#[cfg(feature = "random")]
+impl_read_op!(Multinomial, |attrs: &OpAttrs| {
+    Ok(ops::Multinomial {
+        dtype: read_enum(attrs, "dtype", DataType::Int32, parse_data_type)?,
+        sample_size: attrs.get_or("sample_size", 1)?,
+        seed: attrs.get_as("seed")?,
+    })
+});
+impl_read_op!(Neg);
+impl_read_op!(NonMaxSuppression, |attrs: &OpAttrs| {
+    let box_order = read_enum(
+        attrs,
+        "box_order",
+        BoxOrder::TopLeftBottomRight,
+        |val| match val {
+            "top_left_bottom_right" => Some(BoxOrder::TopLeftBottomRight),
+            "center_width_height" => Some(BoxOrder::CenterWidthHeight),
+            _ => None,
+        },
+    )?;
+    Ok(ops::NonMaxSuppression { box_order })
+});
+impl_read_op!(NonZero);
+impl_read_op!(Normalize, |attrs: &OpAttrs| {
+    Ok(ops::Normalize {
+        mean: attrs.require("mean")?,
+        std: attrs.require("std")?,
+    })
+});
+impl_read_op!(Not);
+impl_read_op!(OneHot, |attrs: &OpAttrs| {
+    Ok(ops::OneHot {
+        axis: attrs.get_or("axis", -1)?,
+    })
+});
+impl_read_op!(Optional);
+impl_read_op!(OptionalGetElement);
+impl_read_op!(OptionalHasElement);
+impl_read_op!(Or);
+impl_read_op!(Pad, |attrs: &OpAttrs| {
+    let mode = read_enum(attrs, "mode", PadMode::default(), |val| match val {
+        "constant" => Some(PadMode::Constant),
+        "reflect" => Some(PadMode::Reflect),
+        "edge" => Some(PadMode::Edge),
+        "wrap" => Some(PadMode::Wrap),
+        _ => None,
+    })?;
+    Ok(ops::Pad { mode })
+});
+impl_read_op!(PermuteChannels, |attrs: &OpAttrs| {
+    Ok(ops::PermuteChannels {
+        order: attrs.require("order")?,
+    })
+});
+impl_read_op!(Pow);
+impl_read_op!(QLinearConv, |attrs: &OpAttrs| {
+    Ok(ops::QLinearConv {
+        groups: attrs.get_or("groups", 1)?,
+        dilations: attrs.get_or("dilations", vec![1, 1])?,
+        padding: read_padding(attrs, "fixed")?,
+        strides: attrs.get_or("strides", vec![1, 1])?,
+        output_dtype: read_enum(attrs, "output_dtype", DataType::UInt8, parse_data_type)?,
+    })
+});
+impl_read_op!(QLinearMatMul, |attrs: &OpAttrs| {
+    let output_dtype = read_enum(attrs, "output_dtype", DataType::UInt8, parse_data_type)?;
+    Ok(ops::QLinearMatMul { output_dtype })
+});
+
+#[cfg(feature = "random")]
+impl_read_op!(RandomNormal, |attrs: &OpAttrs| {
+    Ok(ops::RandomNormal {
+        shape: attrs.get_or("shape", Vec::new())?,
+        mean: attrs.get_or("mean", 0.)?,
+        scale: attrs.get_or("scale", 1.)?,
+        seed: attrs.get_as("seed")?,
+    })
+});
+#[cfg(feature = "random")]
+impl_read_op!(RandomNormalLike, |attrs: &OpAttrs| {
+    Ok(ops::RandomNormalLike {
+        mean: attrs.get_or("mean", 0.)?,
+        scale: attrs.get_or("scale", 1.)?,
+        seed: attrs.get_as("seed")?,
+    })
+});
+#[cfg(feature = "random")]
+impl_read_op!(RandomUniform, |attrs: &OpAttrs| {
+    Ok(ops::RandomUniform {
+        shape: attrs.get_or("shape", Vec::new())?,
+        high: attrs.get_or("high", 1.)?,
+        low: attrs.get_or("low", 0.)?,
+        seed: attrs.get_as("seed")?,
+    })
+});
+#[cfg(feature = "random")]
+impl_read_op!(RandomUniformLike, |attrs: &OpAttrs| {
+    Ok(ops::RandomUniformLike {
+        high: attrs.get_or("high", 1.)?,
+        low: attrs.get_or("low", 0.)?,
+        seed: attrs.get_as("seed")?,
+    })
+});
+
+impl_read_op!(Range);
+impl_read_op!(Reciprocal);
+impl_read_op!(ReduceL2, reduce_axes);
+impl_read_op!(ReduceLogSumExp, reduce_axes);
+impl_read_op!(ReduceMax, reduce_axes);
+impl_read_op!(ReduceMean, reduce_axes);
+impl_read_op!(ReduceMin, reduce_axes);
+impl_read_op!(ReduceProd, reduce_axes);
+impl_read_op!(ReduceStd, reduce_var);
+impl_read_op!(ReduceSum, reduce_axes);
+impl_read_op!(ReduceSumSquare, reduce_axes);
+impl_read_op!(ReduceVar, reduce_var);
+impl_read_op!(Relu);
+impl_read_op!(Reshape, |attrs: &OpAttrs| {
+    Ok(ops::Reshape {
+        allow_zero: attrs.get_or("allow_zero", false)?,
+    })
+});
+impl_read_op!(Resize, |attrs: &OpAttrs| {
+    let mode = read_enum(attrs, "mode", ResizeMode::default(), |val| match val {
+        "nearest" => Some(ResizeMode::Nearest),
+        "linear" => Some(ResizeMode::Linear),
+        _ => None,
+    })?;
+    let nearest_mode = read_enum(
+        attrs,
+        "nearest_mode",
+        NearestMode::default(),
+        |val| match val {
+            "floor" => Some(NearestMode::Floor),
+            "ceil" => Some(NearestMode::Ceil),
+            "round_prefer_floor" => Some(NearestMode::RoundPreferFloor),
+            "round_prefer_ceil" => Some(NearestMode::RoundPreferCeil),
+            _ => None,
+        },
+    )?;
+    let coord_mode = read_enum(
+        attrs,
+        "coord_mode",
+        CoordTransformMode::default(),
+        |val| match val {
+            "asymmetric" => Some(CoordTransformMode::Asymmetric),
+            "half_pixel" => Some(CoordTransformMode::HalfPixel),
+            "align_corners" => Some(CoordTransformMode::AlignCorners),
+            "pytorch_half_pixel" => Some(CoordTransformMode::PytorchHalfPixel),
+            "tf_crop_and_resize" => Some(CoordTransformMode::TfCropAndResize),
+            _ => None,
+        },
+    )?;
+
+    Ok(ops::Resize {
+        mode,
+        coord_mode,
+        nearest_mode,
+        antialias: attrs.get_or("antialias", false)?,
+        axes: attrs.get_as("axes")?,
+        extrapolation_value: attrs.get_or("extrapolation_value", 0.)?,
+    })
+});
+impl_read_op!(Round);
+impl_read_op!(ScaledDotProductAttention, |attrs: &OpAttrs| {
+    Ok(ops::ScaledDotProductAttention {
+        scale: attrs.get_or("scale", 1.0)?,
+    })
+});
+impl_read_op!(Scan, |attrs: &OpAttrs| {
+    Ok(ops::Scan {
+        body: attrs.require("body")?,
+        num_scan_inputs: attrs.require("num_scan_inputs")?,
+        scan_input_axes: attrs.get_as("scan_input_axes")?,
+        scan_input_directions: attrs.get_as("scan_input_directions")?,
+        scan_output_axes: attrs.get_as("scan_output_axes")?,
+        scan_output_directions: attrs.get_as("scan_output_directions")?,
+    })
+});
+impl_read_op!(ScatterElements, |attrs: &OpAttrs| {
+    Ok(ops::ScatterElements {
+        axis: attrs.get_or("axis", 0)?,
+        reduction: read_scatter_reduction(attrs)?,
+    })
+});
+impl_read_op!(ScatterND, |attrs: &OpAttrs| {
+    Ok(ops::ScatterND {
+        reduction: read_scatter_reduction(attrs)?,
+    })
+});
+impl_read_op!(Selu, |attrs: &OpAttrs| {
+    Ok(ops::Selu {
+        alpha: attrs.get_or("alpha", 1.673_263_2)?,
+        gamma: attrs.get_or("gamma", 1.050_701)?,
+    })
+});
+impl_read_op!(SequenceAt);
+impl_read_op!(SequenceConstruct);
+impl_read_op!(SequenceInsert);
+impl_read_op!(SequenceLength);
+impl_read_op!(Shape);
+impl_read_op!(Sigmoid);
+impl_read_op!(Sign);
+impl_read_op!(Sin);
+impl_read_op!(Size);
+impl_read_op!(Slice);
+impl_read_op!(Softmax, |attrs: &OpAttrs| {
+    Ok(ops::Softmax {
+        axis: attrs.get_or("axis", -1)?,
+    })
+});
+impl_read_op!(Softplus);
+impl_read_op!(Softsign);
+impl_read_op!(Split, |attrs: &OpAttrs| {
+    Ok(ops::Split {
+        axis: attrs.get_or("axis", 0)?,
+        num_outputs: attrs.get_as("num_outputs")?,
+    })
+});
+impl_read_op!(SplitToSequence, |attrs: &OpAttrs| {
+    Ok(ops::SplitToSequence {
+        axis: attrs.get_or("axis", 0)?,
+        keep_dims: attrs.get_or("keep_dims", true)?,
+    })
+});
+impl_read_op!(Sqrt);
+impl_read_op!(Squeeze);
+impl_read_op!(Sub);
+impl_read_op!(Sum);
+impl_read_op!(Tan);
+impl_read_op!(Tanh);
+impl_read_op!(ThresholdedRelu, |attrs: &OpAttrs| {
+    Ok(ops::ThresholdedRelu {
+        alpha: attrs.get_or("alpha", 1.0)?,
+    })
+});
+impl_read_op!(Tile);
+impl_read_op!(TopK, |attrs: &OpAttrs| {
+    Ok(ops::TopK {
+        axis: Some(attrs.get_or("axis", -1)?),
+        largest: attrs.get_or("largest", true)?,
+        sorted: attrs.get_or("sorted", true)?,
+    })
+});
+impl_read_op!(Transpose, |attrs: &OpAttrs| {
+    Ok(ops::Transpose {
+        perm: attrs.get_as("perm")?,
+    })
+});
+impl_read_op!(Trilu, |attrs: &OpAttrs| {
+    Ok(ops::Trilu {
+        upper: attrs.get_or("upper", true)?,
+    })
+});
+impl_read_op!(Unsqueeze);
+impl_read_op!(Where);
+impl_read_op!(Xor);
+
+/// Registry used to instantiate operators when loading a model file.
+///
+/// The registry maps operator type names (eg. "Conv") to functions that
+/// construct the operator from an [OpAttrs] attribute dictionary. This makes
+/// operator construction independent of the format that the model was loaded
+/// from.
+///
+/// New registries have no operators registered by default. If you want to get
+/// one that has all the built-in operators pre-registered, use
+/// [OpRegistry::with_all_ops]. Alternatively you can create a new registry and
+/// just selectively register the operators you need using
+/// [OpRegistry::register_op]. This can be useful to reduce binary sizes or
+/// customize the implementation of an operator.
+///
+/// Models may also contain operators which are not built into RTen, such as
+/// operators from vendor-specific ONNX domains (eg. `com.microsoft`). These
+/// are stored in the model as custom operators, identified by domain and
+/// operator type, and can be executed by registering an implementation using
+/// [OpRegistry::register_custom_op] before the model is loaded. Crates which
+/// provide collections of such operators can expose them as an
+/// [OperatorPack].
+#[derive(Default)]
+pub struct OpRegistry {
+    ops: HashMap<String, Box<ReadOpFunction>>,
+
+    /// Factories for custom operators, keyed by `(domain, op_type)`.
+    custom_ops: HashMap<(String, String), Box<dyn OperatorFactory>>,
+}
+
+impl OpRegistry {
+    /// Create a new empty registry.
+    pub fn new() -> OpRegistry {
+        OpRegistry {
+            ops: HashMap::new(),
+            custom_ops: HashMap::new(),
+        }
+    }
+
+    /// Register the default/built-in implementation of an operator.
+    pub fn register_op<Op: ReadOp + 'static>(&mut self) {
+        self.register_op_with_factory(Op::op_type(), Box::new(|attrs| Op::read_boxed(attrs)));
+    }
+
+    /// Register an implementation of a custom operator.
+    ///
+    /// `domain` and `op_type` identify the operator, eg. `"com.microsoft"`
+    /// and `"FastGelu"`. The domain is empty for operators in the default
+    /// ONNX domain. `factory` constructs the operator from the attributes
+    /// stored in the model. If an implementation is already registered for
+    /// the operator, it is replaced.
+    pub fn register_custom_op<F: OperatorFactory + 'static>(
+        &mut self,
+        domain: &str,
+        op_type: &str,
+        factory: F,
+    ) {
+        self.custom_ops
+            .insert((domain.to_string(), op_type.to_string()), Box::new(factory));
+    }
+
+    /// Register all the operators in an [OperatorPack].
+    pub fn register_pack<P: OperatorPack + ?Sized>(&mut self, pack: &P) {
+        pack.register(self);
+    }
+
+    /// Return an iterator over the `(domain, op_type)` identifiers of custom
+    /// operators in the registry.
+    pub fn custom_op_types(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.custom_ops
+            .keys()
+            .map(|(domain, op_type)| (domain.as_str(), op_type.as_str()))
+    }
+
+    /// Return an iterator over the types of operators in the registry.
+    ///
+    /// See [crate::ops::registry] for more information about the built-in
+    /// operators.
+    pub fn op_types(&self) -> impl Iterator<Item = &str> {
+        self.ops.keys().map(|op_type| op_type.as_str())
+    }
+
+    /// Construct an operator of type `op_type` from its attributes, using the
+    /// operators in the registry.
+    pub(crate) fn read_op(&self, op_type: &str, attrs: &OpAttrs) -> ReadOpResult {
+        self.ops
+            .get(op_type)
+            .ok_or_else(|| ReadOpError::UnsupportedOperator(op_type.to_string()))
+            .and_then(|read_fn| read_fn(attrs))
+    }
+
+    /// Construct a custom operator from its attributes, using the
+    /// implementations registered with [OpRegistry::register_custom_op].
+    pub(crate) fn read_custom_op(
+        &self,
+        domain: &str,
+        op_type: &str,
+        attrs: &OpAttrs,
+    ) -> ReadOpResult {
+        self.custom_ops
+            .get(&(domain.to_string(), op_type.to_string()))
+            .ok_or_else(|| {
+                let name = if domain.is_empty() {
+                    op_type.to_string()
+                } else {
+                    format!("{domain}.{op_type}")
+                };
+                ReadOpError::UnsupportedOperator(name)
+            })
+            .and_then(|factory| factory.create(attrs))
+    }
+
+    /// Register an operator with a custom factory to construct it from its
+    /// attributes.
+    fn register_op_with_factory(&mut self, op_type: &str, factory: Box<ReadOpFunction>) {
+        self.ops.insert(op_type.to_string(), factory);
+    }
+
+    /// Create a new registry with all built-in operators registered.
+    pub fn with_all_ops() -> OpRegistry {
+        let mut reg = OpRegistry::new();
+
+        macro_rules! register_op {
+            ($op:ident) => {
+                reg.register_op::<ops::$op>()
+            };
+        }
+
+        register_op!(Abs);
+        register_op!(Acos);
+        register_op!(Add);
+        register_op!(And);
+        register_op!(ArgMax);
+        register_op!(ArgMin);
+        register_op!(Asin);
+        register_op!(Atan);
+        register_op!(AveragePool);
+        register_op!(BatchNormalization);
+        register_op!(BitShift);
+        register_op!(BitwiseAnd);
+        register_op!(BitwiseNot);
+        register_op!(BitwiseOr);
+        register_op!(BitwiseXor);
+        register_op!(Cast);
+        register_op!(CastLike);
+        register_op!(Ceil);
+        register_op!(Celu);
+        register_op!(ChannelShuffle);
+        register_op!(Clip);
+        register_op!(Concat);
+        register_op!(ConcatFromSequence);
+        register_op!(Conv);
+        register_op!(ConstantOfShape);
+        register_op!(ConvTranspose);
+        register_op!(Cos);
+        register_op!(CumSum);
+        register_op!(Div);
+        register_op!(Dropout);
+        register_op!(Elu);
+        register_op!(Equal);
+        register_op!(Erf);
+        register_op!(Exp);
+        register_op!(Expand);
+        register_op!(EyeLike);
+        register_op!(Flatten);
+        register_op!(Floor);
+        register_op!(Gather);
+        register_op!(GatherElements);
+        register_op!(GatherND);
+        register_op!(GatherBlockQuantized);
+        register_op!(Gelu);
+        register_op!(Gemm);
+        register_op!(GlobalAveragePool);
+        register_op!(GlobalMaxPool);
+        register_op!(Greater);
+        register_op!(GreaterOrEqual);
+        register_op!(GRU);
+        register_op!(GridSample);
+        register_op!(HardSigmoid);
+        register_op!(HardSwish);
+        register_op!(Identity);
+        register_op!(ImageToTensor);
+        register_op!(InstanceNormalization);
+        register_op!(LayerNormalization);
+        register_op!(LeakyRelu);
+        register_op!(Less);
+        register_op!(LessOrEqual);
+        register_op!(Log);
+        register_op!(LogSoftmax);
+        register_op!(LpNormalization);
+        register_op!(LpPool);
+        register_op!(LSTM);
+        register_op!(MatMul);
+        register_op!(MatMulNBits);
+        register_op!(Max);
+        register_op!(MaxPool);
+        register_op!(Mean);
+        register_op!(MeanVarianceNormalization);
+        register_op!(Min);
+        register_op!(Mish);
+        register_op!(Mod);
+        register_op!(Mul);
+        #[cfg(feature = "random")]
+        register_op!(Multinomial);
+        register_op!(Neg);
+        register_op!(NonMaxSuppression);
+        register_op!(NonZero);
+        register_op!(Normalize);
+        register_op!(Not);
+        register_op!(OneHot);
+        register_op!(Optional);
+        register_op!(OptionalGetElement);
+        register_op!(OptionalHasElement);
+        register_op!(Or);
+        register_op!(Pad);
+        register_op!(PermuteChannels);
+        register_op!(Pow);
+        register_op!(QLinearConv);
+        register_op!(QLinearMatMul);
+
+        #[cfg(feature = "random")]
+        register_op!(RandomNormal);
+        #[cfg(feature = "random")]
+        register_op!(RandomNormalLike);
+        #[cfg(feature = "random")]
+        register_op!(RandomUniform);
+        #[cfg(feature = "random")]
+        register_op!(RandomUniformLike);
+
+        register_op!(Range);
+        register_op!(Reciprocal);
+        register_op!(ReduceL2);
+        register_op!(ReduceLogSumExp);
+        register_op!(ReduceMax);
+        register_op!(ReduceMean);
+        register_op!(ReduceMin);
+        register_op!(ReduceProd);
+        register_op!(ReduceStd);
+        register_op!(ReduceSum);
+        register_op!(ReduceSumSquare);
+        register_op!(ReduceVar);
+        register_op!(Relu);
+        register_op!(Reshape);
+        register_op!(Resize);
+        register_op!(Round);
+        register_op!(ScaledDotProductAttention);
+        register_op!(Scan);
+        register_op!(ScatterElements);
+        register_op!(ScatterND);
+        register_op!(Selu);
+        register_op!(SequenceAt);
+        register_op!(SequenceConstruct);
+        register_op!(SequenceInsert);
+        register_op!(SequenceLength);
+        register_op!(Shape);
+        register_op!(Sigmoid);
+        register_op!(Sign);
+        register_op!(Sin);
+        register_op!(Size);
+        register_op!(Slice);
+        register_op!(Softmax);
+        register_op!(Softplus);
+        register_op!(Softsign);
+        register_op!(Split);
+        register_op!(SplitToSequence);
+        register_op!(Sqrt);
+        register_op!(Squeeze);
+        register_op!(Sub);
+        register_op!(Sum);
+        register_op!(Tan);
+        register_op!(Tanh);
+        register_op!(ThresholdedRelu);
+        register_op!(Tile);
+        register_op!(TopK);
+        register_op!(Transpose);
+        register_op!(Trilu);
+        register_op!(Unsqueeze);
+        register_op!(Where);
+        register_op!(Xor);
+
+        reg
+    }
+}
+
+/// Error type for errors that occur when de-serializing an operator.
+#[derive(Debug, PartialEq)]
+pub enum ReadOpError {
+    /// The operator attributes were missing or of the wrong type.
+    AttrError,
+    /// A required attribute was missing, or an attribute had an invalid
+    /// value or type.
+    InvalidAttr(String),
+    /// The operator type is incorrect or unsupported.
+    UnsupportedOperator(String),
+}
+
+impl Display for ReadOpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadOpError::AttrError => write!(f, "invalid attributes for operator"),
+            ReadOpError::InvalidAttr(name) => {
+                write!(f, "attribute \"{name}\" is missing or invalid")
+            }
+            ReadOpError::UnsupportedOperator(name) => {
+                write!(f, "operator {name} is not supported or not enabled")
+            }
+        }
+    }
+}
+
+impl Error for ReadOpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AttrValue, OpAttrs, OpRegistry, OperatorFactory, OperatorPack, ReadOp, ReadOpError,
+        ReadOpResult,
+    };
+    use crate::ops;
+    use crate::ops::{Padding, ResizeMode};
+
+    #[test]
+    fn test_attr_conversions() {
+        let attrs = OpAttrs::new()
+            .with("axis", -1)
+            .with("keep_dims", true)
+            .with("alpha", 0.5)
+            .with("pads", vec![1usize, 2, 3, 4])
+            .with("mode", "linear");
+
+        assert_eq!(attrs.len(), 5);
+        assert_eq!(attrs.get("axis"), Some(&AttrValue::Int(-1)));
+        assert_eq!(attrs.get_as::<isize>("axis"), Ok(Some(-1)));
+        assert_eq!(attrs.get_as::<bool>("keep_dims"), Ok(Some(true)));
+        assert_eq!(attrs.get_as::<f32>("alpha"), Ok(Some(0.5)));
+        assert_eq!(
+            attrs.get_as::<Vec<usize>>("pads"),
+            Ok(Some(vec![1, 2, 3, 4]))
+        );
+        assert_eq!(attrs.get_as::<&str>("mode"), Ok(Some("linear")));
+        assert_eq!(attrs.get_as::<f32>("missing"), Ok(None));
+        assert_eq!(attrs.get_or("missing", 2.0), Ok(2.0));
+
+        // Type mismatches and out-of-range conversions.
+        assert_eq!(
+            attrs.get_as::<usize>("axis"),
+            Err(ReadOpError::InvalidAttr("axis".to_string()))
+        );
+        assert_eq!(
+            attrs.get_as::<f32>("mode"),
+            Err(ReadOpError::InvalidAttr("mode".to_string()))
+        );
+        assert_eq!(
+            attrs.require::<f32>("missing"),
+            Err(ReadOpError::InvalidAttr("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_read_op_from_attrs() {
+        let attrs = OpAttrs::new()
+            .with("kernel_size", [3, 3])
+            .with("pad_mode", "same");
+        let op = ops::MaxPool::read(&attrs).unwrap();
+        assert_eq!(op.kernel_size, [3, 3]);
+        assert_eq!(op.strides, [1, 1]);
+        assert!(matches!(op.padding, Padding::Same));
+
+        let attrs = OpAttrs::new().with("mode", "linear");
+        let op = ops::Resize::read(&attrs).unwrap();
+        assert!(matches!(op.mode, ResizeMode::Linear));
+
+        let attrs = OpAttrs::new().with("mode", "cubic");
+        let err = ops::Resize::read(&attrs).err();
+        assert_eq!(err, Some(ReadOpError::InvalidAttr("mode".to_string())));
+
+        let attrs = OpAttrs::new().with("kernel_size", [3, 3, 3]);
+        let err = ops::MaxPool::read(&attrs).err();
+        assert_eq!(
+            err,
+            Some(ReadOpError::InvalidAttr("kernel_size".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_registry_read_op() {
+        let mut registry = OpRegistry::new();
+        registry.register_op::<ops::Concat>();
+
+        let op = registry
+            .read_op("Concat", &OpAttrs::new().with("axis", 1))
+            .unwrap();
+        assert_eq!(op.name(), "Concat");
+
+        let err = registry.read_op("Relu", &OpAttrs::new()).err();
+        assert_eq!(
+            err,
+            Some(ReadOpError::UnsupportedOperator("Relu".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_register_pack() {
+        struct LeakyReluFactory {
+            default_alpha: f32,
+        }
+
+        impl OperatorFactory for LeakyReluFactory {
+            fn create(&self, attrs: &OpAttrs) -> ReadOpResult {
+                Ok(Box::new(ops::LeakyRelu {
+                    alpha: attrs.get_or("alpha", self.default_alpha)?,
+                }))
+            }
+        }
+
+        struct ExamplePack {}
+
+        impl OperatorPack for ExamplePack {
+            fn register(&self, registry: &mut OpRegistry) {
+                registry.register_custom_op(
+                    "com.example",
+                    "LeakyRelu",
+                    LeakyReluFactory { default_alpha: 0.1 },
+                );
+                registry.register_custom_op("com.example", "Gelu", |attrs: &OpAttrs| {
+                    ops::Gelu::read_boxed(attrs)
+                });
+            }
+        }
+
+        let mut registry = OpRegistry::new();
+        registry.register_pack(&ExamplePack {});
+
+        let mut op_types: Vec<_> = registry.custom_op_types().collect();
+        op_types.sort();
+        assert_eq!(
+            op_types,
+            [("com.example", "Gelu"), ("com.example", "LeakyRelu")]
+        );
+
+        let op = registry
+            .read_custom_op("com.example", "LeakyRelu", &OpAttrs::new())
+            .unwrap();
+        assert_eq!(op.name(), "LeakyRelu");
+        let op = registry
+            .read_custom_op("com.example", "Gelu", &OpAttrs::new())
+            .unwrap();
+        assert_eq!(op.name(), "Gelu");
+
+        // Custom operators are distinct from built-in operators of the same
+        // name, and are identified by domain.
+        assert!(registry.read_op("Gelu", &OpAttrs::new()).is_err());
+        let err = registry
+            .read_custom_op("com.other", "Gelu", &OpAttrs::new())
+            .err();
+        assert_eq!(
+            err,
+            Some(ReadOpError::UnsupportedOperator(
+                "com.other.Gelu".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,369 @@
+use rayon::prelude::*;
+
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensorView, Tensor, TensorView};
+use rten_vecmath::vec_softmax_in_place;
+
+use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::ops::binary_elementwise::broadcast_shapes;
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Broadcast `x` to a batch of matrices with shape `[...batch, ...matrix]`.
+fn broadcast_batch<'a>(
+    x: TensorView<'a>,
+    batch: &[usize],
+    matrix: [usize; 2],
+) -> Result<TensorView<'a>, OpError> {
+    let shape = [batch, &matrix].concat();
+    if x.can_broadcast_to(&shape) {
+        Ok(x.broadcast(shape.as_slice()))
+    } else {
+        Err(OpError::IncompatibleInputShapes("Cannot broadcast shapes"))
+    }
+}
+
+/// Compute scaled dot-product attention, `softmax(scale * QKᵀ + mask) V`.
+///
+/// `query` has shape `[..., Sq, D]`, `key_t` is the transposed key with shape
+/// `[..., D, Sk]` and `value` has shape `[..., Sk, Dv]`. The optional `mask`
+/// is added to the attention scores and must be broadcastable to
+/// `[..., Sq, Sk]`. Leading batch dimensions are broadcast as in `MatMul`.
+/// The output has shape `[..., Sq, Dv]`.
+pub fn scaled_dot_product_attention(
+    pool: &TensorPool,
+    query: TensorView,
+    key_t: TensorView,
+    value: TensorView,
+    mask: Option<TensorView>,
+    scale: f32,
+) -> Result<Tensor, OpError> {
+    if query.ndim() < 2 || key_t.ndim() < 2 || value.ndim() < 2 {
+        return Err(OpError::InvalidValue("Inputs must have >= 2 dimensions"));
+    }
+
+    let matrix_shape = |x: &TensorView| [x.size(x.ndim() - 2), x.size(x.ndim() - 1)];
+    let batch_shape = |x: &TensorView| x.shape()[..x.ndim().saturating_sub(2)].to_vec();
+
+    let [q_rows, head_size] = matrix_shape(&query);
+    let [kt_rows, kv_len] = matrix_shape(&key_t);
+    let [v_rows, v_head_size] = matrix_shape(&value);
+
+    if kt_rows != head_size {
+        return Err(OpError::IncompatibleInputShapes(
+            "Query and key head sizes do not match",
+        ));
+    }
+    if v_rows != kv_len {
+        return Err(OpError::IncompatibleInputShapes(
+            "Key and value sequence lengths do not match",
+        ));
+    }
+
+    let mut batch = [batch_shape(&key_t), batch_shape(&value)]
+        .iter()
+        .try_fold(batch_shape(&query), |batch, shape| {
+            broadcast_shapes(&batch, shape)
+        })
+        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast shapes"))?;
+    if let Some(mask) = &mask {
+        batch = broadcast_shapes(&batch, &batch_shape(mask))
+            .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast shapes"))?;
+    }
+
+    let query = broadcast_batch(query, &batch, [q_rows, head_size])?;
+    let key_t = broadcast_batch(key_t, &batch, [head_size, kv_len])?;
+    let value = broadcast_batch(value, &batch, [kv_len, v_head_size])?;
+    let mask = mask
+        .map(|mask| broadcast_batch(mask, &batch, [q_rows, kv_len]))
+        .transpose()?;
+
+    let out_shape = [batch.as_slice(), &[q_rows, v_head_size]].concat();
+    let n_batch: usize = batch.iter().product();
+    let scores_len = q_rows * kv_len;
+    if n_batch * q_rows * v_head_size == 0 || kv_len == 0 {
+        // With no keys, the weighted sum of values is empty.
+        return Ok(Tensor::zeros(&out_shape));
+    }
+
+    let gemm = GemmExecutor::new();
+
+    // Compute `scale * QKᵀ` for each matrix in the batch. The scale is applied
+    // by the GEMM, so it doesn't need a separate pass over the scores.
+    let mut scores = Tensor::uninit_in(pool, &[n_batch, q_rows, kv_len]);
+    query
+        .inner_iter::<2>()
+        .zip(key_t.inner_iter::<2>())
+        .zip(scores.data_mut().unwrap().chunks_mut(scores_len))
+        .par_bridge()
+        .for_each(|((q_mat, kt_mat), scores_mat)| {
+            gemm.gemm_uninit(
+                scores_mat,
+                kv_len,
+                GemmInputA::Unpacked(q_mat),
+                GemmInputB::Unpacked(kt_mat),
+                scale,
+            );
+        });
+
+    // Safety: The loop above initialized all elements.
+    let mut scores = unsafe { scores.assume_init() }.auto_return(pool);
+
+    // Add the mask and normalize each row of scores into attention weights.
+    let normalize = |scores_mat: &mut [f32], mask_mat: Option<NdTensorView<f32, 2>>| {
+        for (row, scores_row) in scores_mat.chunks_mut(kv_len).enumerate() {
+            if let Some(mask_mat) = &mask_mat {
+                for (score, mask) in scores_row
+                    .iter_mut()
+                    .zip(mask_mat.slice::<1, _>(row).iter())
+                {
+                    *score += mask;
+                }
+            }
+            vec_softmax_in_place(scores_row);
+        }
+    };
+    let scores_chunks = scores.data_mut().unwrap().chunks_mut(scores_len);
+    if let Some(mask) = &mask {
+        scores_chunks
+            .zip(mask.inner_iter::<2>())
+            .par_bridge()
+            .for_each(|(scores_mat, mask_mat)| normalize(scores_mat, Some(mask_mat)));
+    } else {
+        scores_chunks
+            .par_bridge()
+            .for_each(|scores_mat| normalize(scores_mat, None));
+    }
+
+    // Compute the weighted sum of values.
+    let mut output = Tensor::uninit_in(pool, &out_shape);
+    let out_len = q_rows * v_head_size;
+    scores
+        .inner_iter::<2>()
+        .zip(value.inner_iter::<2>())
+        .zip(output.data_mut().unwrap().chunks_mut(out_len))
+        .par_bridge()
+        .for_each(|((weights_mat, v_mat), out_mat)| {
+            gemm.gemm_uninit(
+                out_mat,
+                v_head_size,
+                GemmInputA::Unpacked(weights_mat),
+                GemmInputB::Unpacked(v_mat),
+                1., // alpha
+            );
+        });
+
+    // Safety: The loop above initialized all output elements.
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Scaled dot-product attention.
+///
+/// This is not an ONNX operator. It replaces the
+/// `MatMul(Softmax(scale * MatMul(Q, Kᵀ) + mask), V)` subgraphs that models
+/// use to compute attention. See [scaled_dot_product_attention].
+///
+/// The key input is taken in transposed form so that the operator can
+/// replace the subgraph without changing how `Kᵀ` is computed. Transposed
+/// views of inputs are supported, so the `Transpose` operators that produce
+/// `Q`, `Kᵀ` and `V` do not need to copy their inputs.
+#[derive(Debug)]
+pub struct ScaledDotProductAttention {
+    pub scale: f32,
+}
+
+impl Operator for ScaledDotProductAttention {
+    fn name(&self) -> &str {
+        "ScaledDotProductAttention"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let query = inputs.require_as(0)?;
+        let key_t = inputs.require_as(1)?;
+        let value = inputs.require_as(2)?;
+        let mask = inputs.get_as(3)?;
+        scaled_dot_product_attention(pool, query, key_t, value, mask, self.scale).into_op_result()
+    }
+
+    fn supports_transposed_inputs(&self) -> bool {
+        // Each matrix in the batch is passed to the GEMM as a strided view.
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{Tensor, TensorView};
+
+    use super::scaled_dot_product_attention;
+    use crate::ops::tests::new_pool;
+    use crate::ops::{add, matmul, mul, softmax, OpError};
+    use crate::tensor_pool::TensorPool;
+
+    /// Compute attention using separate operators.
+    fn reference_attention(
+        pool: &TensorPool,
+        query: TensorView,
+        key_t: TensorView,
+        value: TensorView,
+        mask: Option<TensorView>,
+        scale: f32,
+    ) -> Tensor {
+        let scores = matmul(pool, query, key_t).unwrap();
+        let mut scores = mul(pool, scores.view(), Tensor::from_scalar(scale).view()).unwrap();
+        if let Some(mask) = mask {
+            scores = add(pool, scores.view(), mask).unwrap();
+        }
+        let weights = softmax(pool, scores.view(), -1).unwrap();
+        matmul(pool, weights.view(), value).unwrap()
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case<'a> {
+            query: &'a [usize],
+            key_t: &'a [usize],
+            value: &'a [usize],
+            mask: Option<&'a [usize]>,
+        }
+
+        let cases = [
+            // Single matrix
+            Case {
+                query: &[5, 8],
+                key_t: &[8, 7],
+                value: &[7, 4],
+                mask: None,
+            },
+            // Batch and head dimensions, with a padding mask
+            Case {
+                query: &[2, 3, 5, 8],
+                key_t: &[2, 3, 8, 7],
+                value: &[2, 3, 7, 8],
+                mask: Some(&[2, 1, 1, 7]),
+            },
+            // Batched 3D inputs with a causal mask
+            Case {
+                query: &[6, 5, 8],
+                key_t: &[6, 8, 5],
+                value: &[6, 5, 8],
+                mask: Some(&[5, 5]),
+            },
+            // Keys and values shared across heads
+            Case {
+                query: &[2, 3, 1, 8],
+                key_t: &[2, 1, 8, 7],
+                value: &[2, 1, 7, 4],
+                mask: None,
+            },
+            // Mask with more batch dimensions than the inputs
+            Case {
+                query: &[5, 8],
+                key_t: &[8, 7],
+                value: &[7, 4],
+                mask: Some(&[3, 5, 7]),
+            },
+        ];
+
+        for Case {
+            query,
+            key_t,
+            value,
+            mask,
+        } in cases
+        {
+            let query = Tensor::<f32>::rand(query, &mut rng);
+            let key_t = Tensor::<f32>::rand(key_t, &mut rng);
+            let value = Tensor::<f32>::rand(value, &mut rng);
+            let mask = mask.map(|shape| Tensor::<f32>::rand(shape, &mut rng));
+            let scale = 1. / (query.size(query.ndim() - 1) as f32).sqrt();
+
+            let expected = reference_attention(
+                &pool,
+                query.view(),
+                key_t.view(),
+                value.view(),
+                mask.as_ref().map(|m| m.view()),
+                scale,
+            );
+            let result = scaled_dot_product_attention(
+                &pool,
+                query.view(),
+                key_t.view(),
+                value.view(),
+                mask.as_ref().map(|m| m.view()),
+                scale,
+            )?;
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_transposed_inputs() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Inputs in `[batch, seq, heads, head_size]` layout, as produced by
+        // reshaping the output of a linear projection, are transposed to
+        // `[batch, heads, seq, head_size]` without copying.
+        let query = Tensor::<f32>::rand(&[2, 5, 3, 8], &mut rng);
+        let key = Tensor::<f32>::rand(&[2, 7, 3, 8], &mut rng);
+        let value = Tensor::<f32>::rand(&[2, 7, 3, 8], &mut rng);
+
+        let query = query.permuted(&[0, 2, 1, 3]);
+        let key_t = key.permuted(&[0, 2, 3, 1]);
+        let value = value.permuted(&[0, 2, 1, 3]);
+
+        let expected =
+            reference_attention(&pool, query.view(), key_t.view(), value.view(), None, 0.5);
+        let result = scaled_dot_product_attention(&pool, query, key_t, value, None, 0.5)?;
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_invalid() {
+        let pool = new_pool();
+        let query = Tensor::<f32>::zeros(&[2, 5, 8]);
+        let value = Tensor::<f32>::zeros(&[2, 7, 4]);
+
+        let key_t = Tensor::<f32>::zeros(&[2, 4, 7]);
+        let result =
+            scaled_dot_product_attention(&pool, query.view(), key_t.view(), value.view(), None, 1.);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Query and key head sizes do not match"
+            ))
+        );
+
+        let key_t = Tensor::<f32>::zeros(&[2, 8, 6]);
+        let result =
+            scaled_dot_product_attention(&pool, query.view(), key_t.view(), value.view(), None, 1.);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Key and value sequence lengths do not match"
+            ))
+        );
+
+        let key_t = Tensor::<f32>::zeros(&[3, 8, 7]);
+        let result =
+            scaled_dot_product_attention(&pool, query.view(), key_t.view(), value.view(), None, 1.);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes("Cannot broadcast shapes"))
+        );
+    }
+}
@@ -4,8 +4,10 @@ use std::iter::{repeat, zip};
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 
+use crate::graph::Dimension;
 use crate::number::{AsBool, Identities, IsInt};
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::shape_inference::infer_broadcast_shape;
+use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, SymbolicShape};
 use crate::tensor_pool::TensorPool;
 
 /// Given the shapes of two inputs to a binary operation, return the shape
@@ -43,6 +45,64 @@ pub fn broadcast_shapes(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
     Some(result)
 }
 
+/// Return true if all of `shapes` can be broadcast to a common shape.
+///
+/// This is equivalent to folding `shapes` with [broadcast_shapes] and checking
+/// the result, but does not allocate.
+pub fn is_broadcast_compatible<S: AsRef<[usize]>>(shapes: &[S]) -> bool {
+    let max_rank = shapes
+        .iter()
+        .map(|shape| shape.as_ref().len())
+        .max()
+        .unwrap_or(0);
+    (1..=max_rank).all(|rev_dim| {
+        let mut size = 1;
+        for shape in shapes {
+            let shape = shape.as_ref();
+            let Some(dim) = shape.len().checked_sub(rev_dim) else {
+                continue;
+            };
+            match (size, shape[dim]) {
+                (_, 1) => {}
+                (1, dim_size) => size = dim_size,
+                (size, dim_size) if size != dim_size => return false,
+                _ => {}
+            }
+        }
+        true
+    })
+}
+
+/// Find an axis of size 1 which could be inserted into one of `shapes` to
+/// make all of them broadcast-compatible.
+///
+/// Only inputs whose rank is one less than the largest rank are considered.
+/// This is the common case of a model which is missing an `Unsqueeze`.
+/// Returns an `(input_index, axis)` tuple, or `None` if no single insertion
+/// fixes the mismatch.
+pub fn unsqueeze_for_broadcast(shapes: &[&[usize]]) -> Option<(usize, usize)> {
+    let max_rank = shapes.iter().map(|shape| shape.len()).max()?;
+    for (i, shape) in shapes.iter().enumerate() {
+        if shape.len() + 1 != max_rank {
+            continue;
+        }
+        for axis in 0..=shape.len() {
+            let mut unsqueezed = shape.to_vec();
+            unsqueezed.insert(axis, 1);
+            let broadcasts = shapes
+                .iter()
+                .enumerate()
+                .map(|(j, s)| if j == i { &unsqueezed[..] } else { s })
+                .try_fold(Vec::new(), |acc, shape| broadcast_shapes(&acc, shape))
+                .is_some();
+            if broadcasts {
+                return Some((i, axis));
+            }
+        }
+    }
+    None
+}
+
 /// Return true if an elementwise binary operation can be performed in-place
 /// on `a` given `b` as the other argument.
 fn can_run_binary_op_in_place<L1: Layout, L2: Layout>(a: &L1, b: &L2) -> bool {
@@ -353,6 +413,11 @@ macro_rules! run_typed_op {
                 let b = $inputs.require_as::<i32>(1)?;
                 $op_func($pool, a, b).into_op_result()
             }
+            Input::Int64Tensor(a) => {
+                let b = $inputs.require_as::<i64>(1)?;
+                $op_func($pool, a, b).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
     ($inputs:expr, $op_func:ident) => {
@@ -384,6 +449,16 @@ macro_rules! run_typed_op_in_place {
                     $op_func($pool, a.view(), b.view()).map(|t| t.into())
                 }
             }
+            Output::Int64Tensor(mut a) => {
+                let b = $other.require_as::<i64>(0)?;
+                if can_run_binary_op_in_place(&a, &b) {
+                    $in_place_op_func(a.view_mut(), b.view());
+                    Ok(a.into())
+                } else {
+                    $op_func($pool, a.view(), b.view()).map(|t| t.into())
+                }
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
 }
@@ -413,6 +488,17 @@ impl Operator for Add {
         "Add"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(pool, inputs, add)
     }
@@ -439,7 +525,7 @@ impl Operator for Add {
 ///
 /// These accept two i32 tensors and produce an i32 result.
 macro_rules! logical_boolean_op {
-    ($op:ident, $op_fn:ident, $expr:expr) => {
+    ($op:ident, $op_fn:ident, $op_in_place_fn:ident, $expr:expr) => {
         pub fn $op_fn<T: AsBool + Copy + Debug>(
             pool: &TensorPool,
             a: TensorView<T>,
@@ -449,6 +535,11 @@ macro_rules! logical_boolean_op {
             binary_op(pool, a, b, |x, y| $expr(x.as_bool(), y.as_bool()).into())
         }
 
+        pub fn $op_in_place_fn(a: TensorViewMut<i32>, b: TensorView<i32>) {
+            #[allow(clippy::redundant_closure_call)]
+            binary_op_in_place(a, b, |x, y| $expr(x.as_bool(), y.as_bool()).into())
+        }
+
         #[derive(Debug)]
         pub struct $op {}
 
@@ -457,10 +548,18 @@ macro_rules! logical_boolean_op {
                 stringify!($op)
             }
 
+            fn supports_broadcast_inputs(&self) -> bool {
+                true
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &[Option<&[Dimension]>],
+            ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+                infer_broadcast_shape(inputs)
+            }
+
             fn is_commutative(&self) -> bool {
-                // These ops are marked as commutative because that is
-                // technically true, but this will have no effect until
-                // `run_in_place` is implemented.
                 true
             }
 
@@ -469,13 +568,193 @@ macro_rules! logical_boolean_op {
                 let b: TensorView<i32> = inputs.require_as(1)?;
                 $op_fn(pool, a, b).into_op_result()
             }
+
+            fn can_run_in_place(&self) -> bool {
+                true
+            }
+
+            fn run_in_place(
+                &self,
+                pool: &TensorPool,
+                input: Output,
+                other: InputList,
+            ) -> Result<Output, OpError> {
+                let mut a = input.into_int().ok_or(OpError::IncorrectInputType)?;
+                let b: TensorView<i32> = other.require_as(0)?;
+                if can_run_binary_op_in_place(&a, &b) {
+                    $op_in_place_fn(a.view_mut(), b);
+                    Ok(a.into())
+                } else {
+                    $op_fn(pool, a.view(), b).map(|t| t.into())
+                }
+            }
         }
     };
 }
 
-logical_boolean_op!(And, and, |x, y| x && y);
-logical_boolean_op!(Or, or, |x, y| x || y);
-logical_boolean_op!(Xor, xor, |x, y| x ^ y);
+logical_boolean_op!(And, and, and_in_place, |x, y| x && y);
+logical_boolean_op!(Or, or, or_in_place, |x, y| x || y);
+logical_boolean_op!(Xor, xor, xor_in_place, |x, y| x ^ y);
+
+/// Define a bitwise operator.
+///
+/// These accept two i32 tensors and produce an i32 result.
+macro_rules! bitwise_op {
+    ($op:ident, $op_fn:ident, $op_in_place_fn:ident, $expr:expr) => {
+        pub fn $op_fn(
+            pool: &TensorPool,
+            a: TensorView<i32>,
+            b: TensorView<i32>,
+        ) -> Result<Tensor<i32>, OpError> {
+            binary_commutative_op(pool, a, b, $expr)
+        }
+
+        pub fn $op_in_place_fn(a: TensorViewMut<i32>, b: TensorView<i32>) {
+            binary_op_in_place(a, b, $expr)
+        }
+
+        #[derive(Debug)]
+        pub struct $op {}
+
+        impl Operator for $op {
+            fn name(&self) -> &str {
+                stringify!($op)
+            }
+
+            fn supports_broadcast_inputs(&self) -> bool {
+                true
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &[Option<&[Dimension]>],
+            ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+                infer_broadcast_shape(inputs)
+            }
+
+            fn is_commutative(&self) -> bool {
+                true
+            }
+
+            fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+                let a: TensorView<i32> = inputs.require_as(0)?;
+                let b: TensorView<i32> = inputs.require_as(1)?;
+                $op_fn(pool, a, b).into_op_result()
+            }
+
+            fn can_run_in_place(&self) -> bool {
+                true
+            }
+
+            fn run_in_place(
+                &self,
+                pool: &TensorPool,
+                input: Output,
+                other: InputList,
+            ) -> Result<Output, OpError> {
+                let mut a = input.into_int().ok_or(OpError::IncorrectInputType)?;
+                let b: TensorView<i32> = other.require_as(0)?;
+                if can_run_binary_op_in_place(&a, &b) {
+                    $op_in_place_fn(a.view_mut(), b);
+                    Ok(a.into())
+                } else {
+                    $op_fn(pool, a.view(), b).map(|t| t.into())
+                }
+            }
+        }
+    };
+}
+
+bitwise_op!(BitwiseAnd, bitwise_and, bitwise_and_in_place, |x, y| x & y);
+bitwise_op!(BitwiseOr, bitwise_or, bitwise_or_in_place, |x, y| x | y);
+bitwise_op!(BitwiseXor, bitwise_xor, bitwise_xor_in_place, |x, y| x ^ y);
+
+/// Direction of a [BitShift] operation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BitShiftDirection {
+    Left,
+    Right,
+}
+
+/// Shift the bits of `x` by `shift` places.
+///
+/// Values are treated as unsigned, so right shifts fill with zeros. Shift
+/// amounts which are negative or greater than 31 produce zero.
+fn shift_bits(x: i32, shift: i32, direction: BitShiftDirection) -> i32 {
+    let (x, shift) = (x as u32, shift as u32);
+    if shift >= u32::BITS {
+        return 0;
+    }
+    match direction {
+        BitShiftDirection::Left => (x << shift) as i32,
+        BitShiftDirection::Right => (x >> shift) as i32,
+    }
+}
+
+/// Shift the bits of elements in `a` by the corresponding amounts in `b`.
+///
+/// See [shift_bits] for details of how values are shifted.
+pub fn bit_shift(
+    pool: &TensorPool,
+    a: TensorView<i32>,
+    b: TensorView<i32>,
+    direction: BitShiftDirection,
+) -> Result<Tensor<i32>, OpError> {
+    binary_op(pool, a, b, |x, y| shift_bits(x, y, direction))
+}
+
+/// Perform an in-place elementwise bit shift.
+pub fn bit_shift_in_place(a: TensorViewMut<i32>, b: TensorView<i32>, direction: BitShiftDirection) {
+    binary_op_in_place(a, b, |x, y| shift_bits(x, y, direction))
+}
+
+#[derive(Debug)]
+pub struct BitShift {
+    pub direction: BitShiftDirection,
+}
+
+impl Operator for BitShift {
+    fn name(&self) -> &str {
+        "BitShift"
+    }
+
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let a: TensorView<i32> = inputs.require_as(0)?;
+        let b: TensorView<i32> = inputs.require_as(1)?;
+        bit_shift(pool, a, b, self.direction).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        input: Output,
+        other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut a = input.into_int().ok_or(OpError::IncorrectInputType)?;
+        let b: TensorView<i32> = other.require_as(0)?;
+        if can_run_binary_op_in_place(&a, &b) {
+            bit_shift_in_place(a.view_mut(), b, self.direction);
+            Ok(a.into())
+        } else {
+            bit_shift(pool, a.view(), b, self.direction).map(|t| t.into())
+        }
+    }
+}
 
 /// Perform elementwise division of two tensors.
 pub fn div<
@@ -513,6 +792,21 @@ pub fn div_in_place<
     }
 }
 
+/// Return an error if `divisor` is an integer tensor which contains zeros.
+///
+/// Integer division by zero would otherwise panic.
+fn check_int_divisor(divisor: Option<Input>) -> Result<(), OpError> {
+    match divisor {
+        Some(Input::IntTensor(divisor)) if divisor.iter().any(|x| *x == 0) => {
+            Err(OpError::InvalidValue("Integer division by zero"))
+        }
+        Some(Input::Int64Tensor(divisor)) if divisor.iter().any(|x| *x == 0) => {
+            Err(OpError::InvalidValue("Integer division by zero"))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[derive(Debug)]
 pub struct Div {}
 
@@ -521,7 +815,19 @@ impl Operator for Div {
         "Div"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        check_int_divisor(inputs.get(1))?;
         run_typed_op!(pool, inputs, div)
     }
 
@@ -535,10 +841,12 @@ impl Operator for Div {
         input: Output,
         other: InputList,
     ) -> Result<Output, OpError> {
+        check_int_divisor(other.get(0))?;
         run_typed_op_in_place!(pool, input, other, div_in_place, div)
     }
 }
 
+#[derive(Copy, Clone)]
 enum BooleanOp {
     Equal,
     Less,
@@ -547,27 +855,34 @@ enum BooleanOp {
     GreaterOrEqual,
 }
 
-fn boolean_op<T: Copy + Debug + PartialEq + PartialOrd>(
-    pool: &TensorPool,
-    a: TensorView<T>,
-    b: TensorView<T>,
-    op: BooleanOp,
-) -> Result<Tensor<i32>, OpError> {
-    binary_op(pool, a, b, |x, y| {
-        i32::from(match op {
+impl BooleanOp {
+    fn eval<T: PartialEq + PartialOrd>(self, x: T, y: T) -> i32 {
+        i32::from(match self {
             BooleanOp::Equal => x == y,
             BooleanOp::Less => x < y,
             BooleanOp::LessOrEqual => x <= y,
             BooleanOp::Greater => x > y,
             BooleanOp::GreaterOrEqual => x >= y,
         })
-    })
+    }
+}
+
+fn boolean_op<T: Copy + Debug + PartialEq + PartialOrd>(
+    pool: &TensorPool,
+    a: TensorView<T>,
+    b: TensorView<T>,
+    op: BooleanOp,
+) -> Result<Tensor<i32>, OpError> {
+    binary_op(pool, a, b, |x, y| op.eval(x, y))
 }
 
 /// Define a boolean comparison operator which supports all numeric tensor
 /// types.
+///
+/// The operator can run in-place when the first operand is an i32 tensor,
+/// as the output has the same type.
 macro_rules! boolean_cmp_op {
-    ($name:ident, $func:ident) => {
+    ($name:ident, $func:ident, $in_place_func:ident) => {
         pub fn $func<T: Copy + Debug + PartialEq + PartialOrd>(
             pool: &TensorPool,
             a: TensorView<T>,
@@ -576,6 +891,10 @@ macro_rules! boolean_cmp_op {
             boolean_op(pool, a, b, BooleanOp::$name)
         }
 
+        pub fn $in_place_func(a: TensorViewMut<i32>, b: TensorView<i32>) {
+            binary_op_in_place(a, b, |x, y| BooleanOp::$name.eval(x, y))
+        }
+
         #[derive(Debug)]
         pub struct $name {}
 
@@ -584,25 +903,67 @@ macro_rules! boolean_cmp_op {
                 stringify!($name)
             }
 
+            fn supports_broadcast_inputs(&self) -> bool {
+                true
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &[Option<&[Dimension]>],
+            ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+                infer_broadcast_shape(inputs)
+            }
+
             fn is_commutative(&self) -> bool {
-                // `Equal` is marked as commutative, but this will have no
-                // effect until an in-place version of the operator is
-                // implemented for bool inputs.
                 stringify!($name) == "Equal"
             }
 
             fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
                 run_typed_op!(pool, inputs, $func)
             }
+
+            fn can_run_in_place(&self) -> bool {
+                true
+            }
+
+            fn run_in_place(
+                &self,
+                pool: &TensorPool,
+                input: Output,
+                other: InputList,
+            ) -> Result<Output, OpError> {
+                match input {
+                    Output::IntTensor(mut a) => {
+                        let b = other.require_as::<i32>(0)?;
+                        if can_run_binary_op_in_place(&a, &b) {
+                            $in_place_func(a.view_mut(), b);
+                            Ok(a.into())
+                        } else {
+                            $func(pool, a.view(), b).map(|t| t.into())
+                        }
+                    }
+                    // The output type differs from the input, so a new
+                    // tensor is required.
+                    Output::FloatTensor(a) => {
+                        let b = other.require_as::<f32>(0)?;
+                        $func(pool, a.view(), b).map(|t| t.into())
+                    }
+                    Output::Int64Tensor(a) => {
+                        let b = other.require_as::<i64>(0)?;
+                        $func(pool, a.view(), b).map(|t| t.into())
+                    }
+                    _ => Err(OpError::IncorrectInputType),
+                }
+            }
         }
     };
 }
 
-boolean_cmp_op!(Equal, equal);
-boolean_cmp_op!(Greater, greater);
-boolean_cmp_op!(GreaterOrEqual, greater_or_equal);
-boolean_cmp_op!(Less, less);
-boolean_cmp_op!(LessOrEqual, less_or_equal);
+boolean_cmp_op!(Equal, equal, equal_in_place);
+boolean_cmp_op!(Greater, greater, greater_in_place);
+boolean_cmp_op!(GreaterOrEqual, greater_or_equal, greater_or_equal_in_place);
+boolean_cmp_op!(Less, less, less_in_place);
+boolean_cmp_op!(LessOrEqual, less_or_equal, less_or_equal_in_place);
 
 /// Calculate the remainder of `x / y` using floored division. See
 /// [DivMode] for an explanation.
@@ -668,8 +1029,20 @@ impl Operator for Mod {
         "Mod"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let a = inputs.require(0)?;
+        check_int_divisor(inputs.get(1))?;
         let mode = if self.fmod {
             DivMode::TruncDiv
         } else {
@@ -685,6 +1058,11 @@ impl Operator for Mod {
                 let b = inputs.require_as::<i32>(1)?;
                 mod_op(pool, a, b, mode).into_op_result()
             }
+            Input::Int64Tensor(a) => {
+                let b = inputs.require_as::<i64>(1)?;
+                mod_op(pool, a, b, mode).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -714,6 +1092,17 @@ impl Operator for Mul {
         "Mul"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(pool, inputs, mul)
     }
@@ -773,6 +1162,17 @@ impl Operator for Pow {
         "Pow"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let a = inputs.require_as(0)?;
         let b = inputs.require_as(1)?;
@@ -826,6 +1226,17 @@ impl Operator for Sub {
         "Sub"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(pool, inputs, sub)
     }
@@ -901,6 +1312,17 @@ impl Operator for Where {
         "Where"
     }
 
+    fn supports_broadcast_inputs(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let condition = inputs.require_as::<i32>(0)?;
         let x = inputs.require(1)?;
@@ -914,6 +1336,11 @@ impl Operator for Where {
                 let y: TensorView<i32> = y.try_into()?;
                 where_op(pool, condition, x, y).into_op_result()
             }
+            Input::Int64Tensor(x) => {
+                let y: TensorView<i64> = y.try_into()?;
+                where_op(pool, condition, x, y).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -926,12 +1353,14 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{tensor, Tensor};
 
-    use super::{fast_broadcast_cycles, fast_broadcast_cycles_repeats};
+    use super::{fast_broadcast_cycles, fast_broadcast_cycles_repeats, is_broadcast_compatible};
     use crate::ops::tests::new_pool;
     use crate::ops::{
-        add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-        less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place,
-        where_op, xor, Add, DivMode, OpError, Operator, Output,
+        add, add_in_place, and, and_in_place, bit_shift, bitwise_and, bitwise_and_in_place,
+        bitwise_or, bitwise_xor, div, div_in_place, equal, greater, greater_or_equal, less,
+        less_in_place, less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub,
+        sub_in_place, where_op, xor, Add, And, BitShift, BitShiftDirection, Div, DivMode, Equal,
+        Less, Mod, OpError, Operator, Output,
     };
 
     #[test]
@@ -972,6 +1401,17 @@ mod tests {
         assert_eq!(params, Some((15, 1)));
     }
 
+    #[test]
+    fn test_is_broadcast_compatible() {
+        let empty: [&[usize]; 0] = [];
+        assert!(is_broadcast_compatible(&empty));
+        assert!(is_broadcast_compatible(&[&[2, 3][..], &[3]]));
+        assert!(is_broadcast_compatible(&[&[2, 1, 4][..], &[3, 1], &[]]));
+        assert!(is_broadcast_compatible(&[&[1, 3][..], &[2, 1], &[2, 3]]));
+        assert!(!is_broadcast_compatible(&[&[2, 3][..], &[2]]));
+        assert!(!is_broadcast_compatible(&[&[1, 3][..], &[2, 1], &[4, 3]]));
+    }
+
     #[test]
     fn test_fast_broadcast_cycles() {
         // Scalar
@@ -1037,6 +1477,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_int64_ops() {
+        let pool = new_pool();
+        let a = tensor!([3_000_000_000i64, -7, 8]);
+        let b = tensor!([2i64, 2, 2]);
+
+        let result = Add {}
+            .run(&pool, (&a, &b).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([3_000_000_002i64, -5, 10]));
+
+        let result = Div {}
+            .run_in_place(&pool, Output::Int64Tensor(a.clone()), (&b).into())
+            .unwrap()
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([1_500_000_000i64, -3, 4]));
+
+        let result = Mod { fmod: false }
+            .run(&pool, (&a, &tensor!([7i64])).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([3_000_000_000i64 % 7, 0, 1]));
+
+        // Comparisons produce i32 results.
+        let result = Equal {}
+            .run_in_place(
+                &pool,
+                Output::Int64Tensor(a.clone()),
+                (&tensor!(8i64)).into(),
+            )
+            .unwrap()
+            .into_int()
+            .unwrap();
+        assert_eq!(result, tensor!([0, 0, 1]));
+
+        // Mixing i32 and i64 operands is an error.
+        let result = Add {}.run(&pool, (&a, &tensor!([1, 2, 3])).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
+
+        let result = Div {}.run(&pool, (&a, &tensor!(0i64)).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Integer division by zero"))
+        );
+    }
+
     #[test]
     fn test_add_broadcasted() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1171,6 +1663,81 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_bitwise_ops() {
+        let pool = new_pool();
+        let a = tensor!([0b1100, 0b1010, -1, 0]);
+        let b = tensor!([0b1010, 0b0110, 0x00ff, -1]);
+
+        let result = bitwise_and(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, tensor!([0b1000, 0b0010, 0x00ff, 0]));
+
+        let result = bitwise_or(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, tensor!([0b1110, 0b1110, -1, -1]));
+
+        let result = bitwise_xor(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, tensor!([0b0110, 0b1100, !0x00ff, -1]));
+
+        // Broadcasting and in-place evaluation.
+        let mut a = tensor!((2, 2); [1, 2, 3, 4]);
+        bitwise_and_in_place(a.view_mut(), tensor!([1, 6]).view());
+        assert_eq!(a, tensor!((2, 2); [1, 2, 1, 4]));
+    }
+
+    #[test]
+    fn test_bit_shift() {
+        let pool = new_pool();
+        let a = tensor!([1, 16, -1, 3, 3]);
+        let b = tensor!([2, 2, 28, 32, -1]);
+
+        let result = bit_shift(&pool, a.view(), b.view(), BitShiftDirection::Left).unwrap();
+        assert_eq!(result, tensor!([4, 64, 0xf << 28, 0, 0]));
+
+        // Values are treated as unsigned, so right shifts fill with zeros.
+        let result = bit_shift(&pool, a.view(), b.view(), BitShiftDirection::Right).unwrap();
+        assert_eq!(result, tensor!([0, 4, 0xf, 0, 0]));
+
+        // Run operator with broadcasting and in-place.
+        let op = BitShift {
+            direction: BitShiftDirection::Right,
+        };
+        let a = tensor!((2, 2); [2, 4, 8, 16]);
+        let b = tensor!([1]);
+        let result = op
+            .run_in_place(&pool, Output::IntTensor(a), (&b).into())
+            .unwrap()
+            .into_int()
+            .unwrap();
+        assert_eq!(result, tensor!((2, 2); [1, 2, 4, 8]));
+    }
+
+    #[test]
+    fn test_and_in_place() {
+        let pool = new_pool();
+        let mut a = tensor!([0, 1, 0, 1]);
+        let b = tensor!([0, 0, 1, 1]);
+        let expected = tensor!([0, 0, 0, 1]);
+        and_in_place(a.view_mut(), b.view());
+        assert_eq!(&a, &expected);
+
+        // Run operator in-place where `b` can be broadcast to `a`.
+        let a = tensor!((2, 2); [0, 1, 2, 0]);
+        let b = tensor!([1, 0]);
+        let result = And {}
+            .run_in_place(&pool, Output::IntTensor(a), (&b).into())
+            .unwrap();
+        assert_eq!(result.as_int_ref().unwrap(), &tensor!((2, 2); [0, 0, 1, 0]));
+
+        // Run operator in-place where `a` must be broadcast. This falls back
+        // to allocating a new output.
+        let a = tensor!([1, 0]);
+        let b = tensor!((2, 2); [0, 1, 2, 0]);
+        let result = And {}
+            .run_in_place(&pool, Output::IntTensor(a), (&b).into())
+            .unwrap();
+        assert_eq!(result.as_int_ref().unwrap(), &tensor!((2, 2); [0, 0, 1, 0]));
+    }
+
     #[test]
     fn test_div() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1258,6 +1825,32 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_cmp_ops_in_place() {
+        let pool = new_pool();
+
+        let mut a = tensor!([1, 2, 3]);
+        let b = tensor!([2, 2, 2]);
+        less_in_place(a.view_mut(), b.view());
+        assert_eq!(&a, &tensor!([1, 0, 0]));
+
+        // Int input is updated in-place.
+        let a = tensor!([1, 2, 3]);
+        let b = tensor!([2]);
+        let result = Equal {}
+            .run_in_place(&pool, Output::IntTensor(a), (&b).into())
+            .unwrap();
+        assert_eq!(result.as_int_ref().unwrap(), &tensor!([0, 1, 0]));
+
+        // Float input requires a new output, as the output type differs.
+        let a = tensor!([1., 2., 3.]);
+        let b = tensor!([2.]);
+        let result = Less {}
+            .run_in_place(&pool, Output::FloatTensor(a), (&b).into())
+            .unwrap();
+        assert_eq!(result.as_int_ref().unwrap(), &tensor!([1, 0, 0]));
+    }
+
     #[test]
     fn test_greater() {
         let pool = new_pool();
@@ -1363,6 +1956,28 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_int_division_by_zero() {
+        let pool = new_pool();
+        let a = tensor!([1, 2, 3]);
+        let b = tensor!([1, 0, 1]);
+        let expected_err = Some(OpError::InvalidValue("Integer division by zero"));
+
+        let result = Div {}.run(&pool, (&a, &b).into());
+        assert_eq!(result.err(), expected_err);
+
+        let result = Div {}.run_in_place(&pool, Output::IntTensor(a.clone()), (&b).into());
+        assert_eq!(result.err(), expected_err);
+
+        let result = Mod { fmod: false }.run(&pool, (&a, &b).into());
+        assert_eq!(result.err(), expected_err);
+
+        // Float division by zero is allowed.
+        let a = tensor!([1., 2.]);
+        let b = tensor!([0., 1.]);
+        assert!(Div {}.run(&pool, (&a, &b).into()).is_ok());
+    }
+
     #[test]
     fn test_mul() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
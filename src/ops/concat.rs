@@ -1,63 +1,18 @@
 use std::mem::MaybeUninit;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{Iter, NdTensorView, Tensor, TensorView};
+use rten_tensor::{NdTensorView, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::static_dims;
+use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
 use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{map_input, map_output, static_dims};
 
-enum ChunkSource<'a, T: Copy> {
-    Slice(&'a [T]),
-    Iter(Iter<'a, T>),
-}
-
-/// Reads chunks of a tensor, where each chunk consists of one iteration over
-/// N innermost dimensions.
-struct TensorChunks<'a, T: Copy> {
-    source: ChunkSource<'a, T>,
-    chunk_size: usize,
-}
-
-impl<'a, T: Copy> TensorChunks<'a, T> {
-    fn new(tensor: &'a TensorView<'a, T>, from_dim: usize) -> TensorChunks<'a, T> {
-        TensorChunks {
-            source: if let Some(data) = tensor.data() {
-                ChunkSource::Slice(data)
-            } else {
-                ChunkSource::Iter(tensor.iter())
-            },
-            chunk_size: tensor.shape()[from_dim..].iter().product(),
-        }
-    }
-
-    /// Return total remaining elements.
-    fn remaining_len(&self) -> usize {
-        match self.source {
-            ChunkSource::Slice(it) => it.len(),
-            ChunkSource::Iter(ref it) => it.len(),
-        }
-    }
-
-    /// Add the next chunk of elements from this tensor to `dest`.
-    fn append_next_chunk(&mut self, dest: &mut Vec<T>) {
-        match self.source {
-            ChunkSource::Slice(ref mut it) => {
-                // Take advantage of `Vec::extend`'s fast path for slices.
-                let (start, end) = it.split_at(self.chunk_size);
-                *it = end;
-                dest.extend(start);
-            }
-            ChunkSource::Iter(ref mut it) => dest.extend(it.by_ref().take(self.chunk_size)),
-        }
-    }
-}
-
-pub fn concat<T: Copy>(
-    pool: &TensorPool,
+/// Check that `inputs` can be concatenated along `axis` and return the shape
+/// of the output.
+fn concat_output_shape<T>(
     inputs: &[TensorView<T>],
     axis: isize,
-) -> Result<Tensor<T>, OpError> {
+) -> Result<(Vec<usize>, usize), OpError> {
     let first_shape = inputs[0].shape();
     let axis = resolve_axis(first_shape.len(), axis)?;
 
@@ -81,20 +36,105 @@ pub fn concat<T: Copy>(
     for other in &inputs[1..] {
         out_shape[axis] += other.size(axis);
     }
-    let mut out_data: Vec<T> = pool.alloc(out_shape.iter().product());
+    Ok((out_shape, axis))
+}
 
-    let mut input_iters: Vec<TensorChunks<'_, T>> = inputs
+/// Concatenate any number of tensors along `axis`.
+///
+/// The output is viewed as a sequence of `outer` blocks, where `outer` is the
+/// product of the dimensions before `axis`. Each block consists of one
+/// contiguous chunk from each input in turn, which is copied in bulk.
+pub fn concat<T: Copy>(
+    pool: &TensorPool,
+    inputs: &[TensorView<T>],
+    axis: isize,
+) -> Result<Tensor<T>, OpError> {
+    let (out_shape, axis) = concat_output_shape(inputs, axis)?;
+    let mut output = Tensor::uninit_in(pool, &out_shape);
+    if output.is_empty() {
+        // Safety: There are no elements to initialize.
+        return Ok(unsafe { output.assume_init() });
+    }
+
+    let inputs: Vec<_> = inputs
         .iter()
-        .map(|tensor| TensorChunks::new(tensor, axis))
+        .map(|input| input.to_contiguous_in(pool).auto_return(pool))
         .collect();
+    let chunk_sizes: Vec<usize> = inputs
+        .iter()
+        .map(|input| input.shape()[axis..].iter().product())
+        .collect();
+    let outer: usize = out_shape[..axis].iter().product();
+
+    let mut out_chunks = output.data_mut().unwrap();
+    let mut n_init = 0;
+    for i in 0..outer {
+        for (input, &chunk_size) in inputs.iter().zip(&chunk_sizes) {
+            let src = &input.data().unwrap()[i * chunk_size..][..chunk_size];
+            let (dest, rest) = out_chunks.split_at_mut(chunk_size);
+            n_init += write_slice(dest, src).len();
+            out_chunks = rest;
+        }
+    }
+    assert!(n_init == output.len());
+
+    // Safety: All elements were initialized by the loop above.
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Concatenate `rest` onto the end of `first`, reusing `first`'s buffer.
+///
+/// This is possible if all dimensions before `axis` have size 1, so the
+/// output consists of the data from `first` followed by the data from each
+/// input in `rest`. Otherwise a new buffer is allocated, as with [concat].
+///
+/// Only the copy of the first input is avoided. The inputs in `rest` are
+/// always copied, since operators allocate their own outputs and the graph
+/// executor cannot have the producers of these inputs write directly into
+/// the concatenated buffer.
+pub fn concat_in_place<T: Copy>(
+    pool: &TensorPool,
+    first: Tensor<T>,
+    rest: &[TensorView<T>],
+    axis: isize,
+) -> Result<Tensor<T>, OpError> {
+    let mut inputs = vec![first.view()];
+    inputs.extend(rest.iter().cloned());
+    let (out_shape, axis) = concat_output_shape(&inputs, axis)?;
+
+    if !first.is_contiguous() || out_shape[..axis].iter().any(|&size| size != 1) {
+        let output = concat(pool, &inputs, axis as isize)?;
+        pool.add(first.into_non_contiguous_data());
+        return Ok(output);
+    }
 
-    while input_iters.iter().any(|it| it.remaining_len() > 0) {
-        for iter in input_iters.iter_mut() {
-            iter.append_next_chunk(&mut out_data);
+    let mut data = first.into_data();
+    data.reserve_exact(out_shape.iter().product::<usize>() - data.len());
+    for input in rest {
+        match input.data() {
+            Some(input_data) => data.extend_from_slice(input_data),
+            None => data.extend(input.iter().copied()),
         }
     }
+    Ok(Tensor::from_data(&out_shape, data))
+}
 
-    Ok(Tensor::from_data(&out_shape, out_data))
+/// Join tensors with the same shape along a new axis inserted at `axis`.
+pub(crate) fn stack<T: Copy>(
+    pool: &TensorPool,
+    items: &[TensorView<T>],
+    axis: isize,
+) -> Result<Tensor<T>, OpError> {
+    let axis = resolve_axis(items[0].ndim() + 1, axis)?;
+    let items: Vec<_> = items
+        .iter()
+        .map(|item| {
+            let mut item = item.clone();
+            item.insert_axis(axis);
+            item
+        })
+        .collect();
+    concat(pool, &items, axis as isize)
 }
 
 #[derive(Debug)]
@@ -109,22 +149,34 @@ impl Operator for Concat {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let first = inputs.require(0)?;
-        match first {
-            Input::FloatTensor(_) => {
-                let mut typed_inputs: Vec<TensorView> = Vec::new();
-                for input in inputs.iter() {
-                    typed_inputs.push(input.try_into()?);
-                }
-                concat(pool, &typed_inputs, self.axis).into_op_result()
+        map_input!(first, first, {
+            let mut typed_inputs = vec![first];
+            for input in inputs.iter().skip(1) {
+                typed_inputs.push(input.try_into()?);
             }
-            Input::IntTensor(_) => {
-                let mut typed_inputs: Vec<TensorView<i32>> = Vec::new();
-                for input in inputs.iter() {
-                    typed_inputs.push(input.try_into()?);
-                }
-                concat(pool, &typed_inputs, self.axis).into_op_result()
+            concat(pool, &typed_inputs, self.axis).into_op_result()
+        })
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        // Concat can reuse the first input's buffer if the other inputs are
+        // appended to the end of it. See `concat_in_place`.
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        first: Output,
+        inputs: InputList,
+    ) -> Result<Output, OpError> {
+        map_output!(first, first, {
+            let mut typed_inputs = Vec::new();
+            for input in inputs.iter() {
+                typed_inputs.push(input.try_into()?);
             }
-        }
+            concat_in_place(pool, first, &typed_inputs, self.axis).map(|t| t.into())
+        })
     }
 }
 
@@ -240,10 +292,9 @@ impl Operator for Tile {
         let repeats = inputs.require_as::<i32>(1)?;
         let repeats = static_dims!(repeats, 1)?;
 
-        match input {
-            Input::IntTensor(input) => tile(pool, input, repeats).into_op_result(),
-            Input::FloatTensor(input) => tile(pool, input, repeats).into_op_result(),
-        }
+        map_input!(input, input, {
+            tile(pool, input, repeats).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -264,10 +315,9 @@ impl Operator for Tile {
             return Ok(output);
         }
 
-        match output {
-            Output::IntTensor(input) => tile(pool, input.view(), repeats).map(|t| t.into()),
-            Output::FloatTensor(input) => tile(pool, input.view(), repeats).map(|t| t.into()),
-        }
+        map_output!(output, input, {
+            tile(pool, input.view(), repeats).map(|t| t.into())
+        })
     }
 }
 
@@ -279,8 +329,9 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{tensor, Tensor};
 
+    use super::concat_in_place;
     use crate::ops::tests::new_pool;
-    use crate::ops::{concat, tile, OpError};
+    use crate::ops::{concat, tile, Concat, OpError, Operator};
 
     fn from_slice<T: Clone>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
@@ -321,6 +372,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_concat_non_contiguous() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let a = Tensor::from_data(&[2, 3], vec![1, 2, 3, 4, 5, 6]);
+        let b = Tensor::from_data(&[3, 2], vec![7, 8, 9, 10, 11, 12]);
+
+        let result = concat(&pool, &[a.view(), b.transposed(), a.view()], 1).unwrap();
+        assert_eq!(result.shape(), &[2, 9]);
+        assert_eq!(
+            result.to_vec(),
+            &[1, 2, 3, 7, 9, 11, 1, 2, 3, 4, 5, 6, 8, 10, 12, 4, 5, 6]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_in_place() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let b = Tensor::from_data(&[1, 1, 2], vec![5, 6]);
+        let c = Tensor::from_data(&[1, 1, 2], vec![7, 8]);
+
+        // Inputs are appended to the first input, reusing its buffer.
+        let mut data = Vec::with_capacity(8);
+        data.extend([1, 2, 3, 4]);
+        let a = Tensor::from_data(&[1, 2, 2], data);
+        let a_ptr = a.data().unwrap().as_ptr();
+        let result = concat_in_place(&pool, a, &[b.view(), c.view()], 1)?;
+        assert_eq!(result.shape(), &[1, 4, 2]);
+        assert_eq!(result.to_vec(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(result.data().unwrap().as_ptr(), a_ptr);
+
+        // If there are non-unit dimensions before the axis, a new buffer is
+        // needed.
+        let a = Tensor::from_data(&[2, 1, 2], vec![1, 2, 3, 4]);
+        let b = Tensor::from_data(&[2, 1, 1], vec![5, 6]);
+        let result = concat_in_place(&pool, a, &[b.view()], 2)?;
+        assert_eq!(result.shape(), &[2, 1, 3]);
+        assert_eq!(result.to_vec(), &[1, 2, 5, 3, 4, 6]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_concat_invalid_inputs() {
         let pool = new_pool();
@@ -351,6 +445,13 @@ mod tests {
                 "Dimensions must be the same except for concat axis"
             ))
         );
+
+        // Inputs with different element types
+        let op = Concat { axis: 0 };
+        let a = Tensor::<f32>::zeros(&[2]);
+        let b = Tensor::<i32>::zeros(&[2]);
+        let result = op.run(&pool, (&a, &b).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
     }
 
     #[test]
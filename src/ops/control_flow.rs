@@ -0,0 +1,338 @@
+use std::sync::Arc;
+
+use rten_tensor::prelude::*;
+use rten_tensor::TensorView;
+
+use crate::graph::Subgraph;
+use crate::ops::concat::stack;
+use crate::ops::slice::select;
+use crate::ops::{resolve_axis, Input, InputList, OpError, Operator, Output};
+use crate::tensor_pool::TensorPool;
+
+/// Return the slice of `input` at `index` along `axis`, removing the axis.
+fn select_input<'a>(input: &Input<'a>, axis: usize, index: usize) -> Result<Input<'a>, OpError> {
+    let slice = match input {
+        Input::FloatTensor(t) => select(t, axis, index).into(),
+        Input::IntTensor(t) => select(t, axis, index).into(),
+        Input::Int64Tensor(t) => select(t, axis, index).into(),
+        Input::StringTensor(t) => select(t, axis, index).into(),
+        Input::Sequence(_) | Input::BFloat16Tensor(_) => return Err(OpError::IncorrectInputType),
+    };
+    Ok(slice)
+}
+
+/// Join outputs with the same shape and type along a new axis inserted at
+/// `axis`.
+fn stack_outputs(pool: &TensorPool, items: &[Output], axis: isize) -> Result<Output, OpError> {
+    fn typed_views<'a, T>(items: &'a [Output]) -> Result<Vec<TensorView<'a, T>>, OpError>
+    where
+        TensorView<'a, T>: TryFrom<Input<'a>, Error = OpError>,
+    {
+        items
+            .iter()
+            .map(|item| Input::from(item).try_into())
+            .collect()
+    }
+
+    match &items[0] {
+        Output::FloatTensor(_) => stack(pool, &typed_views::<f32>(items)?, axis).map(Output::from),
+        Output::IntTensor(_) => stack(pool, &typed_views::<i32>(items)?, axis).map(Output::from),
+        Output::Int64Tensor(_) => stack(pool, &typed_views::<i64>(items)?, axis).map(Output::from),
+        Output::StringTensor(_) | Output::Sequence(_) => Err(OpError::IncorrectOutputType),
+    }
+}
+
+/// Iterate over one or more sequences, running a subgraph for each step.
+///
+/// The inputs to the operator are `N` initial state values followed by `M`
+/// scan inputs, where `M` is [Scan::num_scan_inputs]. The body subgraph has
+/// `N + M` inputs and `N + K` outputs. For each step, the body is run with the
+/// current state values and the slices of each scan input at the current
+/// position. The first `N` outputs of the body are the state values for the
+/// next step, and the remaining `K` outputs are the scan outputs for the
+/// current step.
+///
+/// The operator's outputs are the `N` final state values followed by the `K`
+/// scan outputs of each step, stacked along a new axis.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Scan.html>.
+#[derive(Debug)]
+pub struct Scan {
+    pub body: Arc<Subgraph>,
+    pub num_scan_inputs: usize,
+
+    /// Axis of each scan input which is iterated over. Defaults to 0.
+    pub scan_input_axes: Option<Vec<isize>>,
+
+    /// Direction in which each scan input is iterated over. 0 for forwards or
+    /// 1 for backwards. Defaults to 0.
+    pub scan_input_directions: Option<Vec<usize>>,
+
+    /// Axis of each scan output along which the per-step values are stacked.
+    /// Defaults to 0.
+    pub scan_output_axes: Option<Vec<isize>>,
+
+    /// Order in which per-step values of each scan output are stacked. 0 to
+    /// append or 1 to prepend. Defaults to 0.
+    pub scan_output_directions: Option<Vec<usize>>,
+}
+
+/// Return whether the sequence at `index` in a list of scan directions should
+/// be reversed.
+fn is_reversed(directions: &Option<Vec<usize>>, index: usize) -> Result<bool, OpError> {
+    match directions.as_ref().and_then(|dirs| dirs.get(index)) {
+        None | Some(0) => Ok(false),
+        Some(1) => Ok(true),
+        Some(_) => Err(OpError::InvalidValue("Scan direction must be 0 or 1")),
+    }
+}
+
+impl Operator for Scan {
+    fn name(&self) -> &str {
+        "Scan"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let n_inputs = inputs.iter_optional().count();
+        let n_state = n_inputs
+            .checked_sub(self.num_scan_inputs)
+            .ok_or(OpError::MissingInputs)?;
+        if self.body.input_ids().len() != n_inputs {
+            return Err(OpError::InvalidValue(
+                "Scan body inputs do not match operator inputs",
+            ));
+        }
+        let n_scan_outputs =
+            self.body
+                .output_ids()
+                .len()
+                .checked_sub(n_state)
+                .ok_or(OpError::InvalidValue(
+                    "Scan body has fewer outputs than state variables",
+                ))?;
+
+        let initial_state: Vec<Input> = (0..n_state)
+            .map(|i| inputs.require(i))
+            .collect::<Result<_, _>>()?;
+
+        // Resolve the axis and direction of each scan input, and check that
+        // the sequences have the same length.
+        let mut scan_inputs = Vec::with_capacity(self.num_scan_inputs);
+        let mut seq_len = None;
+        for i in 0..self.num_scan_inputs {
+            let input = inputs.require(n_state + i)?;
+            let axis = self
+                .scan_input_axes
+                .as_ref()
+                .and_then(|axes| axes.get(i))
+                .copied()
+                .unwrap_or(0);
+            let axis = resolve_axis(input.ndim(), axis)?;
+            let len = input.size(axis);
+            if seq_len.is_some_and(|seq_len| seq_len != len) {
+                return Err(OpError::IncompatibleInputShapes(
+                    "Scan inputs have different sequence lengths",
+                ));
+            }
+            seq_len = Some(len);
+            let reversed = is_reversed(&self.scan_input_directions, i)?;
+            scan_inputs.push((input, axis, reversed));
+        }
+        let seq_len = seq_len.unwrap_or(0);
+
+        if seq_len == 0 && n_scan_outputs > 0 {
+            return Err(OpError::UnsupportedValue(
+                "Scan with scan outputs requires a non-empty sequence",
+            ));
+        }
+
+        let mut state: Option<Vec<Output>> = None;
+        let mut scan_outputs: Vec<Vec<Output>> = (0..n_scan_outputs)
+            .map(|_| Vec::with_capacity(seq_len))
+            .collect();
+
+        for step in 0..seq_len {
+            let mut body_outputs = {
+                let mut body_inputs: Vec<Input> = match &state {
+                    Some(state) => state.iter().map(Input::from).collect(),
+                    None => initial_state.clone(),
+                };
+                for (input, axis, reversed) in &scan_inputs {
+                    let index = if *reversed { seq_len - 1 - step } else { step };
+                    body_inputs.push(select_input(input, *axis, index)?);
+                }
+                self.body
+                    .run(&body_inputs)
+                    .map_err(|err| OpError::SubgraphError(Box::new(err)))?
+            };
+
+            for (outputs, output) in scan_outputs.iter_mut().zip(body_outputs.drain(n_state..)) {
+                outputs.push(output);
+            }
+            state = Some(body_outputs);
+        }
+
+        let mut outputs: Vec<Output> = match state {
+            Some(state) => state,
+            None => initial_state
+                .into_iter()
+                .map(|input| input.to_output_in(pool))
+                .collect(),
+        };
+
+        for (i, mut items) in scan_outputs.into_iter().enumerate() {
+            if is_reversed(&self.scan_output_directions, i)? {
+                items.reverse();
+            }
+            let axis = self
+                .scan_output_axes
+                .as_ref()
+                .and_then(|axes| axes.get(i))
+                .copied()
+                .unwrap_or(0);
+            outputs.push(stack_outputs(pool, &items, axis)?);
+        }
+
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::sync::Arc;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::{tensor, Tensor};
+
+    use crate::graph::{Graph, Subgraph};
+    use crate::ops::tests::new_pool;
+    use crate::ops::{Add, Identity, InputList, OpError, Operator, Scan};
+
+    /// Create a Scan body which computes a running sum.
+    ///
+    /// The body has inputs `(sum, x)` and outputs `(sum + x, sum + x)`.
+    fn cumsum_body() -> Arc<Subgraph> {
+        let mut graph = Graph::new();
+        let sum = graph.add_value(Some("sum"), None);
+        let x = graph.add_value(Some("x"), None);
+        let sum_out = graph.add_value(Some("sum_out"), None);
+        let scan_out = graph.add_value(Some("scan_out"), None);
+        graph.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[Some(sum), Some(x)],
+            &[Some(sum_out)],
+        );
+        graph.add_op(
+            Some("identity"),
+            Box::new(Identity {}),
+            &[Some(sum_out)],
+            &[Some(scan_out)],
+        );
+        Arc::new(Subgraph::new(
+            graph,
+            vec![sum, x],
+            vec![sum_out, scan_out],
+            HashMap::new(),
+        ))
+    }
+
+    fn cumsum_op() -> Scan {
+        Scan {
+            body: cumsum_body(),
+            num_scan_inputs: 1,
+            scan_input_axes: None,
+            scan_input_directions: None,
+            scan_output_axes: None,
+            scan_output_directions: None,
+        }
+    }
+
+    #[test]
+    fn test_scan() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let init = tensor!([0., 0.]);
+        let xs = tensor!((3, 2); [1., 2., 3., 4., 5., 6.]);
+
+        let op = cumsum_op();
+        let results = op.run(
+            &pool,
+            InputList::from(&[init.view().into(), xs.view().into()]),
+        )?;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_float_ref().unwrap(), &tensor!([9., 12.]));
+        assert_eq!(
+            results[1].as_float_ref().unwrap(),
+            &tensor!((3, 2); [1., 2., 4., 6., 9., 12.])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_axes_and_directions() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let init = tensor!([0., 0.]);
+
+        // Scan over axis 1 in reverse, and stack outputs along axis 1 in
+        // reverse.
+        let xs = tensor!((2, 3); [1., 3., 5., 2., 4., 6.]);
+        let op = Scan {
+            scan_input_axes: Some(vec![-1]),
+            scan_input_directions: Some(vec![1]),
+            scan_output_axes: Some(vec![1]),
+            scan_output_directions: Some(vec![1]),
+            ..cumsum_op()
+        };
+        let results = op.run(
+            &pool,
+            InputList::from(&[init.view().into(), xs.view().into()]),
+        )?;
+        assert_eq!(results[0].as_float_ref().unwrap(), &tensor!([9., 12.]));
+        assert_eq!(
+            results[1].as_float_ref().unwrap(),
+            &tensor!((2, 3); [9., 8., 5., 12., 10., 6.])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_invalid() {
+        let pool = new_pool();
+        let init = tensor!([0., 0.]);
+
+        // Sequence with a mismatched element shape.
+        let xs = tensor!((3, 3); [1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let op = cumsum_op();
+        let result = op.run(
+            &pool,
+            InputList::from(&[init.view().into(), xs.view().into()]),
+        );
+        assert!(matches!(result, Err(OpError::SubgraphError(_))));
+
+        // Empty sequence.
+        let xs = Tensor::<f32>::zeros(&[0, 2]);
+        let result = op.run(
+            &pool,
+            InputList::from(&[init.view().into(), xs.view().into()]),
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue(
+                "Scan with scan outputs requires a non-empty sequence"
+            ))
+        );
+
+        // Body inputs don't match operator inputs.
+        let result = op.run(&pool, InputList::from(&[init.view().into()]));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Scan body inputs do not match operator inputs"
+            ))
+        );
+    }
+}
@@ -2,6 +2,7 @@ use std::iter::zip;
 use std::mem::MaybeUninit;
 
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
@@ -9,6 +10,7 @@ use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
 
 use crate::check_dims;
 use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::ops::binary_elementwise::add_in_place;
 use crate::ops::pooling::calc_output_size_and_padding;
 use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output, Padding};
 use crate::tensor_pool::{AutoReturn, TensorPool};
@@ -17,7 +19,7 @@ mod depthwise;
 mod im2col;
 
 use depthwise::conv_2d_depthwise;
-use im2col::VirtualIm2Col;
+use im2col::{Im2ColParams, Im2ColPlan, VirtualIm2Col};
 
 /// Specialization of conv_2d for pointwise convolutions over one image. This
 /// can be reduced to tensor reshaping and matrix multiplication.
@@ -71,7 +73,8 @@ fn conv_2d_pointwise(
 ///
 /// For a 2D convolution `input` has dimensions NCHW while `kernel` has OGHW
 /// where `G` is `C / groups`. 1D convolutions are similar except the "H"
-/// dimension is omitted.
+/// dimension is omitted. 3D convolutions have an additional "D" dimension,
+/// so `input` is NCDHW and `kernel` is OGDHW.
 ///
 /// - `padding` specifies the amount of horizontal and vertical padding respectively
 ///   that is added to each side.
@@ -92,17 +95,116 @@ pub fn conv(
     groups: usize,
     strides: &[usize],
     dilations: &[usize],
+) -> Result<Tensor, OpError> {
+    conv_impl(
+        pool, input, kernel, bias, padding, groups, strides, dilations, None,
+    )
+}
+
+/// Perform a 2D convolution of an `NCHW` input with an `OCHW` kernel.
+///
+/// This is a variant of [conv] for the common case of 2D images, where the
+/// ranks of the inputs and output are checked at compile time.
+#[allow(clippy::too_many_arguments)]
+pub fn conv_2d(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 4>,
+    kernel: NdTensorView<f32, 4>,
+    bias: Option<NdTensorView<f32, 1>>,
+    padding: Padding,
+    groups: usize,
+    strides: [usize; 2],
+    dilations: [usize; 2],
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let output = conv(
+        pool,
+        input.as_dyn(),
+        kernel.as_dyn(),
+        bias.as_ref().map(|b| b.as_dyn()),
+        padding,
+        groups,
+        &strides,
+        &dilations,
+    )?;
+    Ok(output.try_into().expect("output should have 4 dims"))
+}
+
+/// Cache of the im2col plan used by the most recent run of a [Conv] operator.
+///
+/// The plan depends only on the input shape and convolution parameters, so
+/// caching it avoids recomputing it on every run when an operator is
+/// repeatedly run with the same input shape, as is typical for video or
+/// other fixed-size pipelines.
+#[derive(Default)]
+struct Im2ColCache {
+    plan: Mutex<Option<Arc<Im2ColPlan>>>,
+}
+
+impl Im2ColCache {
+    /// Return the cached plan if it matches `params`, or create and cache a
+    /// new one otherwise.
+    fn get_or_create(&self, params: Im2ColParams) -> Arc<Im2ColPlan> {
+        let mut plan = self.plan.lock().unwrap();
+        match plan.as_ref() {
+            Some(plan) if *plan.params() == params => plan.clone(),
+            _ => {
+                let new_plan = Arc::new(Im2ColPlan::new(params));
+                *plan = Some(new_plan.clone());
+                new_plan
+            }
+        }
+    }
+
+    /// Return the parameters of the cached plan, if any.
+    #[cfg(test)]
+    fn cached_params(&self) -> Option<Im2ColParams> {
+        self.plan
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|plan| *plan.params())
+    }
+}
+
+impl std::fmt::Debug for Im2ColCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .plan
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|plan| *plan.params());
+        f.debug_struct("Im2ColCache")
+            .field("params", &params)
+            .finish()
+    }
+}
+
+/// Implementation of [conv] which optionally uses a cache of im2col plans.
+#[allow(clippy::too_many_arguments)]
+fn conv_impl(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel: TensorView,
+    bias: Option<TensorView>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    im2col_cache: Option<&Im2ColCache>,
 ) -> Result<Tensor, OpError> {
     // Handle 1D convolution by expanding to 2D and then removing the extra
     // dimension from the result.
-    if let &[n, c, w] = input.shape() {
-        let [out_c, k_in_c, k_w] = check_dims!(kernel, 3, "OCW");
+    if input.ndim() == 3 {
+        check_dims!(kernel, 3, "OCW");
 
+        // Insert axes rather than reshaping, so that non-contiguous views
+        // don't need to be copied.
         let mut input_2d = input.clone();
-        input_2d.reshape(&[n, c, 1, w]);
+        input_2d.insert_axis(2);
 
         let mut kernel_2d = kernel.clone();
-        kernel_2d.reshape(&[out_c, k_in_c, 1, k_w]);
+        kernel_2d.insert_axis(2);
 
         let padding_2d = padding.expand_1d_to_2d()?;
 
@@ -120,7 +222,7 @@ pub fn conv(
             }
         };
 
-        let result_2d = conv(
+        let result_2d = conv_impl(
             pool,
             input_2d,
             kernel_2d,
@@ -129,6 +231,7 @@ pub fn conv(
             groups,
             &strides_2d,
             &dilations_2d,
+            im2col_cache,
         );
 
         return result_2d.map(|mut t| {
@@ -138,6 +241,20 @@ pub fn conv(
         });
     }
 
+    if input.ndim() == 5 {
+        return conv_3d(
+            pool,
+            input,
+            kernel,
+            bias,
+            padding,
+            groups,
+            strides,
+            dilations,
+            im2col_cache,
+        );
+    }
+
     let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
     let [out_c, k_in_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
     check_dims!(bias?, 1);
@@ -217,8 +334,29 @@ pub fn conv(
     let bias = bias.map(|b| b.to_contiguous());
     let bias = bias.as_ref().map(|b| b.view());
 
+    // The input can have any layout since it is read via im2col, but the
+    // kernel must be contiguous so it can be reshaped into a matrix.
+    let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
+
     let n_init = AtomicUsize::new(0);
 
+    // The im2col plan is the same for every image and group, since they all
+    // have the same shape and strides.
+    let im2col_params = Im2ColParams {
+        image_shape: [in_channels_per_group, in_h, in_w],
+        image_strides: input.strides()[1..].try_into().unwrap(),
+        kernel: [k_h, k_w],
+        padding: fixed_padding,
+        strides: [stride_y, stride_x],
+        dilations: [dilation_y, dilation_x],
+        panel_width: gemm.b_panel_width(),
+    };
+    let im2col_plan = if let Some(cache) = im2col_cache {
+        cache.get_or_create(im2col_params)
+    } else {
+        Arc::new(Im2ColPlan::new(im2col_params))
+    };
+
     for group in 0..groups {
         let in_chan_start = group * in_channels_per_group;
         let in_chan_end = in_chan_start + in_channels_per_group;
@@ -246,15 +384,8 @@ pub fn conv(
                 let mut out_mat = out_item.reshaped_mut([out_channels_per_group, out_h * out_w]);
                 let out_row_stride = out_mat.stride(0);
 
-                let im2col = VirtualIm2Col::new(
-                    gemm.kernel_type(),
-                    in_item.nd_view(),
-                    [k_h, k_w],
-                    fixed_padding,
-                    [stride_y, stride_x],
-                    [dilation_y, dilation_x],
-                    gemm.b_panel_width(),
-                );
+                let im2col =
+                    VirtualIm2Col::new(gemm.kernel_type(), in_item.nd_view(), &im2col_plan);
 
                 gemm.gemm_uninit_bias(
                     out_mat.data_mut().unwrap(),
@@ -279,12 +410,151 @@ pub fn conv(
     Ok(output)
 }
 
+/// Perform a 3D convolution of an NCDHW `input` with an OCDHW `kernel`.
+///
+/// The convolution is decomposed into a sum of 2D convolutions, one for each
+/// position along the depth axis of the kernel. For each kernel depth
+/// position, the input depth slices used by every output depth position are
+/// gathered into a batch, so that each 2D convolution uses a single large
+/// im2col + GEMM operation.
+#[allow(clippy::too_many_arguments)]
+fn conv_3d(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel: TensorView,
+    bias: Option<TensorView>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    im2col_cache: Option<&Im2ColCache>,
+) -> Result<Tensor, OpError> {
+    let [batch, in_c, in_d, in_h, in_w] = check_dims!(input, 5, "NCDHW");
+    let [out_c, _, k_d, _, _] = check_dims!(kernel, 5, "OCDHW");
+
+    let [stride_d, stride_y, stride_x]: [usize; 3] = strides
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 stride values"))?;
+    let [dilation_d, dilation_y, dilation_x]: [usize; 3] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 dilation values"))?;
+    if stride_d == 0 {
+        return Err(OpError::InvalidValue("Strides must be > 0"));
+    }
+    if dilation_d == 0 {
+        return Err(OpError::InvalidValue("Dilations must be > 0"));
+    }
+    if k_d == 0 {
+        return Err(OpError::InvalidValue("Kernel size must be > 0"));
+    }
+
+    // Split padding into depth padding and padding for the 2D convolutions.
+    let dilated_k_d = (k_d - 1) * dilation_d + 1;
+    let (out_d, pad_front, padding_2d) = match padding {
+        Padding::Same => {
+            let out_d = in_d.div_ceil(stride_d);
+            let pad_total = ((out_d.max(1) - 1) * stride_d + dilated_k_d).saturating_sub(in_d);
+            (out_d, pad_total / 2, Padding::Same)
+        }
+        Padding::Fixed(pads) => {
+            let &[pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right] = pads.as_slice()
+            else {
+                return Err(OpError::InvalidValue("expected 6 pad values"));
+            };
+            let padded_in_d = in_d + pad_front + pad_back;
+            if padded_in_d < dilated_k_d {
+                return Err(OpError::InvalidValue("Input too small for kernel size"));
+            }
+            let out_d = (padded_in_d - dilated_k_d) / stride_d + 1;
+            let padding_2d = [pad_top, pad_left, pad_bottom, pad_right].into();
+            (out_d, pad_front, padding_2d)
+        }
+    };
+
+    let mut output: Option<Tensor> = None;
+
+    for kz in 0..k_d {
+        // Gather the input depth slices used with this kernel depth position
+        // for each output depth position. Slices that fall in the padding
+        // region are left as zeros.
+        let mut input_2d = NdTensor::zeros_in(pool, [batch, out_d, in_c, in_h, in_w]);
+        for n in 0..batch {
+            for z in 0..out_d {
+                let in_z = (z * stride_d + kz * dilation_d) as isize - pad_front as isize;
+                if (0..in_d as isize).contains(&in_z) {
+                    input_2d
+                        .slice_mut::<3, _>([n, z])
+                        .copy_from(&input.slice::<3, _>((n, .., in_z as usize)));
+                }
+            }
+        }
+        let input_2d = input_2d.auto_return(pool);
+        let input_2d = input_2d.reshaped([batch * out_d, in_c, in_h, in_w].as_slice());
+
+        let kernel_2d = kernel
+            .slice_dyn((.., .., kz))
+            .to_contiguous_in(pool)
+            .auto_return(pool);
+
+        // Add the bias only once.
+        let bias = if kz == 0 { bias.clone() } else { None };
+
+        let output_2d = conv_impl(
+            pool,
+            input_2d,
+            kernel_2d.view(),
+            bias,
+            padding_2d.clone(),
+            groups,
+            &[stride_y, stride_x],
+            &[dilation_y, dilation_x],
+            im2col_cache,
+        )?;
+
+        if let Some(output) = output.as_mut() {
+            let output_2d = output_2d.auto_return(pool);
+            add_in_place(output.view_mut(), output_2d.view());
+        } else {
+            output = Some(output_2d);
+        }
+    }
+
+    // Convert output from `[N * D, C, H, W]` to `[N, C, D, H, W]`.
+    let mut output = output.unwrap();
+    let [_, _, out_h, out_w] = output.shape().try_into().unwrap();
+    output.reshape(&[batch, out_d, out_c, out_h, out_w]);
+    output.permute(&[0, 2, 1, 3, 4]);
+    let output = output.auto_return(pool);
+
+    Ok(output.to_tensor_in(pool))
+}
+
 #[derive(Debug)]
 pub struct Conv {
     pub groups: usize,
     pub dilations: Vec<usize>,
     pub padding: Padding,
     pub strides: Vec<usize>,
+
+    /// Im2col plan from the most recent run.
+    im2col_cache: Im2ColCache,
+}
+
+impl Conv {
+    pub fn new(
+        groups: usize,
+        dilations: Vec<usize>,
+        padding: Padding,
+        strides: Vec<usize>,
+    ) -> Conv {
+        Conv {
+            groups,
+            dilations,
+            padding,
+            strides,
+            im2col_cache: Im2ColCache::default(),
+        }
+    }
 }
 
 impl Operator for Conv {
@@ -296,7 +566,7 @@ impl Operator for Conv {
         let input = inputs.require_as(0)?;
         let weight = inputs.require_as(1)?;
         let bias = inputs.get_as(2)?;
-        conv(
+        conv_impl(
             pool,
             input,
             weight,
@@ -305,6 +575,7 @@ impl Operator for Conv {
             self.groups,
             &self.strides,
             &self.dilations,
+            Some(&self.im2col_cache),
         )
         .into_op_result()
     }
@@ -331,9 +602,11 @@ fn col2im(
     columns: &NdTensorView<f32, 5>,
     padding: [usize; 4],
     strides: [usize; 2],
+    dilations: [usize; 2],
     bias: Option<NdTensorView<f32, 1>>,
 ) {
     let [stride_h, stride_w] = strides;
+    let [dilation_h, dilation_w] = dilations;
     let [pad_top, pad_left, _pad_bottom, _pad_right] = padding;
     let [col_chans, kernel_h, kernel_w, _img_h, _img_w] = columns.shape();
     let [out_chans, out_h, out_w] = output.shape();
@@ -353,13 +626,13 @@ fn col2im(
                 let [img_h, img_w] = in_img.shape();
 
                 for y in 0..img_h {
-                    let out_y = y * stride_h + k_y;
+                    let out_y = y * stride_h + k_y * dilation_h;
                     if out_y < pad_top || out_y >= out_h + pad_top {
                         continue;
                     }
 
                     for x in 0..img_w {
-                        let out_x = x * stride_w + k_x;
+                        let out_x = x * stride_w + k_x * dilation_w;
                         if out_x < pad_left || out_x >= out_w + pad_left {
                             continue;
                         }
@@ -378,55 +651,85 @@ fn col2im(
 ///
 /// See formulae in https://onnx.ai/onnx/operators/onnx__ConvTranspose.html.
 ///
-/// Returns a tuple of (out_shape, padding).
+/// If `output_shape` is specified, the padding is calculated so that the
+/// output has this size, and `padding` is only used to determine whether
+/// padding is distributed as for `Padding::Same`.
+///
+/// Returns a tuple of (out_shape, padding), where padding is in the order
+/// [top, left, bottom, right].
 fn conv_transpose_output_size_and_padding(
     input_shape: [usize; 2],
     kernel_shape: [usize; 2],
     padding: Padding,
     strides: [usize; 2],
+    dilations: [usize; 2],
+    output_padding: [usize; 2],
+    output_shape: Option<[usize; 2]>,
 ) -> Result<([usize; 2], [usize; 4]), OpError> {
     let [in_h, in_w] = input_shape;
     let [stride_h, stride_w] = strides;
-    let [k_h, k_w] = kernel_shape;
+    let [dilation_h, dilation_w] = dilations;
 
     if stride_h == 0 || stride_w == 0 {
         return Err(OpError::InvalidValue("Strides must be > 0"));
     }
 
+    if dilation_h == 0 || dilation_w == 0 {
+        return Err(OpError::InvalidValue("Dilations must be > 0"));
+    }
+
     if in_h == 0 || in_w == 0 {
         return Err(OpError::InvalidValue("Input width and height must be > 0"));
     }
 
-    match padding {
-        Padding::Same => {
-            // Per spec, pad the input so that:
-            // output_shape[i] = input_shape[i] * strides[i] for each axis i.
-            let out_h = in_h * stride_h;
-            let out_w = in_w * stride_w;
-
-            let pad_h = ((in_h - 1) * stride_h + k_h).checked_sub(out_h);
-            let pad_w = ((in_w - 1) * stride_w + k_w).checked_sub(out_w);
-
-            let (Some(pad_h), Some(pad_w)) = (pad_h, pad_w) else {
-                // We can't achieve an output size of (out_h, out_w) even with
-                // no padding.
-                return Err(OpError::InvalidValue("Input is too small"));
-            };
+    if output_padding[0] >= stride_h.max(dilation_h)
+        || output_padding[1] >= stride_w.max(dilation_w)
+    {
+        return Err(OpError::InvalidValue(
+            "Output padding must be smaller than stride or dilation",
+        ));
+    }
 
-            // If the total padding is not even, we assign the remaining unit to
-            // the ends of the axis. This matches the ONNX "SAME_UPPER"
-            // value for `auto_pad`.
-            let pad_top = pad_h / 2;
-            let pad_bottom = pad_h.div_ceil(2);
-            let pad_left = pad_w / 2;
-            let pad_right = pad_w.div_ceil(2);
+    // Size of each spatial axis of the output before padding is removed.
+    let [k_h, k_w] = kernel_shape;
+    let full_h = (in_h - 1) * stride_h + (k_h - 1) * dilation_h + 1 + output_padding[0];
+    let full_w = (in_w - 1) * stride_w + (k_w - 1) * dilation_w + 1 + output_padding[1];
+
+    // Distribute the total padding needed to reach a target output size.
+    //
+    // If the total padding is not even, we assign the remaining unit to
+    // the ends of the axis. This matches the ONNX "SAME_UPPER" value for
+    // `auto_pad`.
+    let split_padding = |out_h: usize, out_w: usize| {
+        let pad_h = full_h.checked_sub(out_h);
+        let pad_w = full_w.checked_sub(out_w);
+
+        let (Some(pad_h), Some(pad_w)) = (pad_h, pad_w) else {
+            // We can't achieve an output size of (out_h, out_w) even with
+            // no padding.
+            return Err(OpError::InvalidValue("Input is too small"));
+        };
 
-            Ok(([out_h, out_w], [pad_top, pad_bottom, pad_left, pad_right]))
-        }
+        let pad_top = pad_h / 2;
+        let pad_bottom = pad_h.div_ceil(2);
+        let pad_left = pad_w / 2;
+        let pad_right = pad_w.div_ceil(2);
+
+        Ok(([out_h, out_w], [pad_top, pad_left, pad_bottom, pad_right]))
+    };
+
+    if let Some([out_h, out_w]) = output_shape {
+        return split_padding(out_h, out_w);
+    }
+
+    match padding {
+        // Per spec, pad the input so that:
+        // output_shape[i] = input_shape[i] * strides[i] for each axis i.
+        Padding::Same => split_padding(in_h * stride_h, in_w * stride_w),
         Padding::Fixed(pads) => match pads.as_slice() {
             &[pad_top, pad_left, pad_bottom, pad_right] => {
-                let out_h = ((in_h - 1) * stride_h + k_h).checked_sub(pad_top + pad_bottom);
-                let out_w = ((in_w - 1) * stride_w + k_w).checked_sub(pad_left + pad_right);
+                let out_h = full_h.checked_sub(pad_top + pad_bottom);
+                let out_w = full_w.checked_sub(pad_left + pad_right);
 
                 let (Some(out_h), Some(out_w)) = (out_h, out_w) else {
                     return Err(OpError::InvalidValue("Input is too small"));
@@ -442,25 +745,39 @@ fn conv_transpose_output_size_and_padding(
 /// Perform a transposed 2D convolution of a tensor by a kernel.
 ///
 /// `input` has dimensions NCHW and `kernel` has dimensions COHW where `O` is
-/// the number of output channels.
+/// the number of output channels per group. The output has `O * groups`
+/// channels.
+///
+/// `dilations` and `output_padding` default to ones and zeros respectively if
+/// empty. `output_padding` specifies extra space added to the end of each
+/// spatial axis of the output. If `output_shape` is
+/// specified, it determines the size of the spatial axes of the output, and
+/// the padding is inferred from it.
+#[allow(clippy::too_many_arguments)]
 pub fn conv_transpose(
     pool: &TensorPool,
     input: TensorView,
     kernel: TensorView,
     bias: Option<TensorView>,
     padding: Padding,
+    groups: usize,
     strides: &[usize],
+    dilations: &[usize],
+    output_padding: &[usize],
+    output_shape: Option<&[usize]>,
 ) -> Result<Tensor, OpError> {
     // Handle 1D transposed convolution by expanding to 2D and then removing
     // the extra dimension from the result.
-    if let &[n, c, w] = input.shape() {
-        let [out_c, k_in_c, k_w] = check_dims!(kernel, 3, "OCW");
+    if input.ndim() == 3 {
+        check_dims!(kernel, 3, "OCW");
 
+        // Insert axes rather than reshaping, so that non-contiguous views
+        // don't need to be copied.
         let mut input_2d = input.clone();
-        input_2d.reshape(&[n, c, 1, w]);
+        input_2d.insert_axis(2);
 
         let mut kernel_2d = kernel.clone();
-        kernel_2d.reshape(&[out_c, k_in_c, 1, k_w]);
+        kernel_2d.insert_axis(2);
 
         let padding_2d = padding.expand_1d_to_2d()?;
 
@@ -471,7 +788,42 @@ pub fn conv_transpose(
             }
         };
 
-        let result_2d = conv_transpose(pool, input_2d, kernel_2d, bias, padding_2d, &strides_2d);
+        let dilations_2d = match *dilations {
+            [] => [1, 1],
+            [dilation] => [1, dilation],
+            _ => {
+                return Err(OpError::InvalidValue("expected 1 dilation value"));
+            }
+        };
+
+        let output_padding_2d = match *output_padding {
+            [] => [0, 0],
+            [pad] => [0, pad],
+            _ => {
+                return Err(OpError::InvalidValue("expected 1 output padding value"));
+            }
+        };
+
+        let output_shape_2d = match output_shape {
+            None => None,
+            Some(&[size]) => Some([1, size]),
+            Some(_) => {
+                return Err(OpError::InvalidValue("expected 1 output shape value"));
+            }
+        };
+
+        let result_2d = conv_transpose(
+            pool,
+            input_2d,
+            kernel_2d,
+            bias,
+            padding_2d,
+            groups,
+            &strides_2d,
+            &dilations_2d,
+            &output_padding_2d,
+            output_shape_2d.as_ref().map(|s| s.as_slice()),
+        );
 
         return result_2d.map(|mut t| {
             let [n, c, _h, w]: [usize; 4] = t.shape().try_into().expect("expected 4D output");
@@ -481,10 +833,10 @@ pub fn conv_transpose(
     }
 
     let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
-    let [k_in_c, out_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
+    let [k_in_c, out_channels_per_group, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
     check_dims!(bias?, 1);
 
-    let bias = bias.map(|b| b.nd_view());
+    let bias = bias.map(|b| b.nd_view::<1>());
 
     if in_c != k_in_c {
         return Err(OpError::IncompatibleInputShapes(
@@ -492,18 +844,59 @@ pub fn conv_transpose(
         ));
     }
 
+    if groups == 0 || in_c % groups != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels must be divisible by group count",
+        ));
+    }
+
+    let out_c = out_channels_per_group * groups;
+    let in_channels_per_group = in_c / groups;
+
+    if bias.is_some_and(|b| b.size(0) != out_c) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Bias length does not match output channels",
+        ));
+    }
+
     let &[stride_h, stride_w] = strides else {
         return Err(OpError::InvalidValue("expected 2 stride values"));
     };
 
+    let [dilation_h, dilation_w] = match *dilations {
+        [] => [1, 1],
+        [dilation_h, dilation_w] => [dilation_h, dilation_w],
+        _ => {
+            return Err(OpError::InvalidValue("expected 2 dilation values"));
+        }
+    };
+
+    let output_padding = match *output_padding {
+        [] => [0, 0],
+        [pad_h, pad_w] => [pad_h, pad_w],
+        _ => {
+            return Err(OpError::InvalidValue("expected 2 output padding values"));
+        }
+    };
+
+    let output_shape = match output_shape {
+        None => None,
+        Some(&[out_h, out_w]) => Some([out_h, out_w]),
+        Some(_) => {
+            return Err(OpError::InvalidValue("expected 2 output shape values"));
+        }
+    };
+
     let (out_shape, fixed_padding) = conv_transpose_output_size_and_padding(
         [in_h, in_w],
         [k_h, k_w],
         padding,
         [stride_h, stride_w],
+        [dilation_h, dilation_w],
+        output_padding,
+        output_shape,
     )?;
     let [out_h, out_w] = out_shape;
-    let [pad_top, pad_left, pad_bottom, pad_right] = fixed_padding;
 
     let mut output = Tensor::uninit_in(pool, [batch, out_c, out_h, out_w].as_slice());
 
@@ -512,36 +905,51 @@ pub fn conv_transpose(
     let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
 
     let mut col2im_mat =
-        NdTensor::uninit_in(pool, [out_c * k_h * k_w, in_h * in_w]).auto_return(pool);
-    let kernel_mat = kernel.reshaped([k_in_c, out_c * k_h * k_w]).transposed();
+        NdTensor::uninit_in(pool, [out_channels_per_group * k_h * k_w, in_h * in_w])
+            .auto_return(pool);
     let gemm = GemmExecutor::new();
 
     // The implementation here is the inverse of the im2col-based convolution.
     let mut n_init = 0;
     for n in 0..batch {
-        let input_mat = input.slice::<3, _>([n]).reshaped([in_c, in_h * in_w]);
-
-        let col2im_row_stride = col2im_mat.stride(0);
-        gemm.gemm_uninit(
-            col2im_mat.data_mut().unwrap(),
-            col2im_row_stride,
-            GemmInputA::Unpacked(kernel_mat),
-            GemmInputB::Unpacked(input_mat),
-            1., /* alpha */
-        );
+        let mut out_img = output.slice_mut::<3, _>([n]);
+
+        for group in 0..groups {
+            let in_chans = group * in_channels_per_group..(group + 1) * in_channels_per_group;
+            let out_chans = group * out_channels_per_group..(group + 1) * out_channels_per_group;
+
+            let input_mat = input
+                .slice::<3, _>([n])
+                .slice::<3, _>(in_chans.clone())
+                .reshaped([in_channels_per_group, in_h * in_w]);
+            let kernel_mat = kernel
+                .slice::<4, _>(in_chans)
+                .reshaped([in_channels_per_group, out_channels_per_group * k_h * k_w])
+                .transposed();
+
+            let col2im_row_stride = col2im_mat.stride(0);
+            gemm.gemm_uninit(
+                col2im_mat.data_mut().unwrap(),
+                col2im_row_stride,
+                GemmInputA::Unpacked(kernel_mat),
+                GemmInputB::Unpacked(input_mat),
+                1., /* alpha */
+            );
 
-        // Safety: `gemm_uninit` initialized col2im_mat.
-        let col2im_mat = unsafe { col2im_mat.view().assume_init() };
-        let mut out_img = output.slice_mut(n);
+            // Safety: `gemm_uninit` initialized col2im_mat.
+            let col2im_mat = unsafe { col2im_mat.view().assume_init() };
+            let mut out_group = out_img.slice_mut::<3, _>(out_chans.clone());
 
-        col2im(
-            &mut out_img,
-            &col2im_mat.reshaped([out_c, k_h, k_w, in_h, in_w]),
-            [pad_top, pad_left, pad_right, pad_bottom],
-            [stride_h, stride_w],
-            bias,
-        );
-        n_init += out_img.len();
+            col2im(
+                &mut out_group,
+                &col2im_mat.reshaped([out_channels_per_group, k_h, k_w, in_h, in_w]),
+                fixed_padding,
+                [stride_h, stride_w],
+                [dilation_h, dilation_w],
+                bias.map(|b| b.slice::<1, _>(out_chans)),
+            );
+            n_init += out_group.len();
+        }
     }
 
     assert!(n_init == output.len());
@@ -552,7 +960,11 @@ pub fn conv_transpose(
 #[derive(Debug)]
 pub struct ConvTranspose {
     pub padding: Padding,
+    pub groups: usize,
     pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
+    pub output_padding: Vec<usize>,
+    pub output_shape: Option<Vec<usize>>,
 }
 
 impl Operator for ConvTranspose {
@@ -570,7 +982,11 @@ impl Operator for ConvTranspose {
             weight,
             bias,
             self.padding.clone(),
+            self.groups,
             &self.strides,
+            &self.dilations,
+            &self.output_padding,
+            self.output_shape.as_deref(),
         )
         .into_op_result()
     }
@@ -583,12 +999,12 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{expect_equal, ExpectEqualError};
-    use rten_tensor::{Tensor, TensorView};
+    use rten_tensor::{NdTensor, Tensor, TensorView};
 
     use crate::ops::pooling::calc_output_size_and_padding;
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
-    use crate::ops::{conv, conv_transpose, Conv, OpError, Operator, Padding};
+    use crate::ops::{conv, conv_2d, conv_transpose, Conv, OpError, Operator, Padding};
     use crate::tensor_pool::AutoReturn;
 
     use super::conv_transpose_output_size_and_padding;
@@ -703,6 +1119,40 @@ mod tests {
 
     /// Basic tests for conv. These compare the results against values
     /// computed from PyTorch as well as the reference implementation.
+    #[test]
+    fn test_conv_2d() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+        let input = NdTensor::rand([1, 4, 6, 6], &mut rng);
+        let kernel = NdTensor::rand([6, 2, 3, 3], &mut rng);
+        let bias = NdTensor::rand([6], &mut rng);
+
+        let result = conv_2d(
+            &pool,
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            Padding::Fixed([1, 1, 1, 1].into()),
+            2, /* groups */
+            [2, 2],
+            [1, 1],
+        )?;
+        let expected = conv(
+            &pool,
+            input.as_dyn(),
+            kernel.as_dyn(),
+            Some(bias.as_dyn()),
+            Padding::Fixed([1, 1, 1, 1].into()),
+            2, /* groups */
+            &[2, 2],
+            &[1, 1],
+        )?;
+        assert_eq!(result.shape(), [1, 6, 3, 3]);
+        expect_equal(&result.as_dyn(), &expected.view())?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_conv() -> Result<(), Box<dyn Error>> {
         let kernel = Tensor::from_data(
@@ -783,12 +1233,7 @@ mod tests {
         );
 
         let pool = new_pool();
-        let op = Conv {
-            padding: Padding::Same,
-            groups: 1,
-            strides: vec![1, 1],
-            dilations: vec![1, 1],
-        };
+        let op = Conv::new(1, vec![1, 1], Padding::Same, vec![1, 1]);
         let result = op
             .run(&pool, (&input, &kernel).into())
             .unwrap()
@@ -810,6 +1255,62 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_conv_caches_im2col_plan() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let kernel = Tensor::rand(&[4, 3, 3, 3], &mut rng);
+        let op = Conv::new(1, vec![1, 1], [1, 1, 1, 1].into(), vec![1, 1]);
+        let pool = new_pool();
+
+        let run_op = |input: &Tensor| -> Result<(), Box<dyn Error>> {
+            let result = op
+                .run(&pool, (input, &kernel).into())?
+                .remove(0)
+                .into_float()
+                .unwrap();
+            let expected = reference_conv(
+                input.view(),
+                kernel.view(),
+                None,
+                [1, 1, 1, 1].into(),
+                1,
+                &[1, 1],
+                &[1, 1],
+            );
+            expect_equal(&result, &expected)?;
+            Ok(())
+        };
+
+        assert!(op.im2col_cache.cached_params().is_none());
+
+        // The first run creates a plan.
+        let input = Tensor::rand(&[2, 3, 8, 8], &mut rng);
+        run_op(&input)?;
+        let params = op.im2col_cache.cached_params().unwrap();
+        assert_eq!(params.image_shape, [3, 8, 8]);
+
+        // A subsequent run with the same shape re-uses it.
+        let input = Tensor::rand(&[2, 3, 8, 8], &mut rng);
+        run_op(&input)?;
+        assert_eq!(op.im2col_cache.cached_params(), Some(params));
+
+        // A run with a different shape replaces it.
+        let input = Tensor::rand(&[1, 3, 5, 7], &mut rng);
+        run_op(&input)?;
+        let params = op.im2col_cache.cached_params().unwrap();
+        assert_eq!(params.image_shape, [3, 5, 7]);
+
+        // A run with different strides also replaces it.
+        let mut input = Tensor::rand(&[1, 3, 7, 5], &mut rng);
+        input.permute(&[0, 1, 3, 2]);
+        run_op(&input)?;
+        let params = op.im2col_cache.cached_params().unwrap();
+        assert_eq!(params.image_shape, [3, 5, 7]);
+        assert_eq!(params.image_strides, [35, 1, 5]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_conv_uneven_padding() -> Result<(), Box<dyn Error>> {
         let mut rng = XorShiftRng::new(1234);
@@ -1152,6 +1653,290 @@ mod tests {
         assert_eq!(result.shape(), &[n, out_c, in_w]);
     }
 
+    #[test]
+    fn test_conv_1d_dilated_grouped() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let [n, in_c, out_c, in_w, k_w] = [2, 4, 6, 20, 3];
+        let groups = 2;
+        let input = Tensor::rand(&[n, in_c, in_w], &mut rng);
+        let kernel = Tensor::rand(&[out_c, in_c / groups, k_w], &mut rng);
+        let bias = Tensor::rand(&[out_c], &mut rng);
+
+        let pool = new_pool();
+        let result = conv(
+            &pool,
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            [1, 2].into(),
+            groups,
+            &[2], /* stride */
+            &[2], /* dilation */
+        )?;
+
+        // Compare against a 2D convolution where the "H" dimension has size 1.
+        let expected = reference_conv(
+            input.reshaped([n, in_c, 1, in_w].as_slice()),
+            kernel.reshaped([out_c, in_c / groups, 1, k_w].as_slice()),
+            Some(bias.view()),
+            [0, 1, 0, 2].into(),
+            groups,
+            &[1, 2],
+            &[1, 2],
+        );
+        let out_w = expected.size(3);
+        assert_eq!(result.shape(), &[n, out_c, out_w]);
+        expect_equal(
+            &result,
+            &expected.reshaped([n, out_c, out_w].as_slice()).to_tensor(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Un-optimized reference implementation of 3D convolution with fixed
+    /// padding.
+    fn reference_conv_3d(
+        input: TensorView,
+        kernel: TensorView,
+        bias: Option<TensorView>,
+        pads: [usize; 6],
+        groups: usize,
+        strides: [usize; 3],
+        dilations: [usize; 3],
+    ) -> Tensor {
+        let [batch, in_chans, in_d, in_h, in_w]: [usize; 5] = input.shape().try_into().unwrap();
+        let [out_chans, k_in_chans, k_d, k_h, k_w]: [usize; 5] = kernel.shape().try_into().unwrap();
+        let in_size = [in_d, in_h, in_w];
+        let k_size = [k_d, k_h, k_w];
+        let out_size: [usize; 3] = std::array::from_fn(|i| {
+            (in_size[i] + pads[i] + pads[i + 3] - dilations[i] * (k_size[i] - 1) - 1) / strides[i]
+                + 1
+        });
+        let out_chans_per_group = out_chans / groups;
+
+        let mut output = NdTensor::zeros([batch, out_chans, out_size[0], out_size[1], out_size[2]]);
+        for [n, out_c, z, y, x] in output.indices() {
+            let group = out_c / out_chans_per_group;
+            let mut accum = bias.as_ref().map(|b| b[[out_c]]).unwrap_or(0.);
+            for k_c in 0..k_in_chans {
+                let in_c = group * k_in_chans + k_c;
+                for k_z in 0..k_d {
+                    for k_y in 0..k_h {
+                        for k_x in 0..k_w {
+                            let in_pos = [
+                                (z * strides[0] + k_z * dilations[0]) as isize - pads[0] as isize,
+                                (y * strides[1] + k_y * dilations[1]) as isize - pads[1] as isize,
+                                (x * strides[2] + k_x * dilations[2]) as isize - pads[2] as isize,
+                            ];
+                            if in_pos
+                                .iter()
+                                .zip(in_size)
+                                .all(|(&pos, size)| pos >= 0 && pos < size as isize)
+                            {
+                                let [in_z, in_y, in_x] = in_pos.map(|pos| pos as usize);
+                                accum += input[[n, in_c, in_z, in_y, in_x]]
+                                    * kernel[[out_c, k_c, k_z, k_y, k_x]];
+                            }
+                        }
+                    }
+                }
+            }
+            output[[n, out_c, z, y, x]] = accum;
+        }
+        assert_eq!(in_chans, k_in_chans * groups);
+
+        output.into_dyn()
+    }
+
+    #[test]
+    fn test_conv_3d() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            input_shape: [usize; 5],
+            kernel_shape: [usize; 5],
+            pads: [usize; 6],
+            groups: usize,
+            strides: [usize; 3],
+            dilations: [usize; 3],
+        }
+
+        let cases = [
+            // Basic convolution without padding
+            Case {
+                input_shape: [1, 3, 4, 5, 6],
+                kernel_shape: [4, 3, 2, 3, 3],
+                pads: [0; 6],
+                groups: 1,
+                strides: [1, 1, 1],
+                dilations: [1, 1, 1],
+            },
+            // Padding, strides and batches
+            Case {
+                input_shape: [2, 2, 5, 5, 5],
+                kernel_shape: [3, 2, 3, 3, 3],
+                pads: [1, 1, 1, 2, 1, 0],
+                groups: 1,
+                strides: [2, 1, 2],
+                dilations: [1, 1, 1],
+            },
+            // Dilations
+            Case {
+                input_shape: [1, 2, 7, 6, 6],
+                kernel_shape: [2, 2, 3, 2, 2],
+                pads: [0; 6],
+                groups: 1,
+                strides: [1, 1, 1],
+                dilations: [2, 1, 2],
+            },
+            // Grouped convolution
+            Case {
+                input_shape: [1, 4, 3, 4, 4],
+                kernel_shape: [6, 2, 2, 2, 2],
+                pads: [1; 6],
+                groups: 2,
+                strides: [1, 1, 1],
+                dilations: [1, 1, 1],
+            },
+            // Depthwise convolution
+            Case {
+                input_shape: [1, 3, 3, 4, 4],
+                kernel_shape: [3, 1, 3, 3, 3],
+                pads: [1; 6],
+                groups: 3,
+                strides: [1, 1, 1],
+                dilations: [1, 1, 1],
+            },
+        ];
+
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        for Case {
+            input_shape,
+            kernel_shape,
+            pads,
+            groups,
+            strides,
+            dilations,
+        } in cases
+        {
+            let input = Tensor::rand(&input_shape, &mut rng);
+            let kernel = Tensor::rand(&kernel_shape, &mut rng);
+            let bias = Tensor::rand(&[kernel_shape[0]], &mut rng);
+
+            let result = conv(
+                &pool,
+                input.view(),
+                kernel.view(),
+                Some(bias.view()),
+                pads.into(),
+                groups,
+                &strides,
+                &dilations,
+            )?;
+            let expected = reference_conv_3d(
+                input.view(),
+                kernel.view(),
+                Some(bias.view()),
+                pads,
+                groups,
+                strides,
+                dilations,
+            );
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_3d_same_padding() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let input = Tensor::rand(&[1, 2, 5, 4, 6], &mut rng);
+        let kernel = Tensor::rand(&[3, 2, 3, 3, 3], &mut rng);
+
+        let pool = new_pool();
+        let result = conv(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::Same,
+            1,
+            &[2, 1, 1],
+            &[1, 1, 1],
+        )?;
+
+        // With a stride of 2 and a size of 5 along the depth axis, the total
+        // depth padding is 2, divided equally between front and back.
+        let expected = reference_conv_3d(
+            input.view(),
+            kernel.view(),
+            None,
+            [1; 6],
+            1,
+            [2, 1, 1],
+            [1, 1, 1],
+        );
+        assert_eq!(result.shape(), &[1, 3, 3, 4, 6]);
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_3d_invalid() {
+        let mut rng = XorShiftRng::new(1234);
+        let input = Tensor::rand(&[1, 1, 2, 2, 2], &mut rng);
+        let kernel = Tensor::rand(&[1, 1, 3, 1, 1], &mut rng);
+        let pool = new_pool();
+
+        let result = conv(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::zero::<3>(),
+            1,
+            &[1, 1],
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("expected 3 stride values"))
+        );
+
+        let result = conv(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::zero::<2>(),
+            1,
+            &[1, 1, 1],
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("expected 6 pad values"))
+        );
+
+        let result = conv(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::zero::<3>(),
+            1,
+            &[1, 1, 1],
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Input too small for kernel size"))
+        );
+    }
+
     #[test]
     fn test_conv_transpose() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1173,7 +1958,11 @@ mod tests {
             kernel.view(),
             None,
             Padding::zero::<2>(),
+            1,
             &[2, 2],
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1189,7 +1978,11 @@ mod tests {
             kernel.view(),
             Some(bias.view()),
             Padding::zero::<2>(),
+            1,
             &[2, 2],
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         expect_equal(&result, &expected_with_bias)?;
@@ -1215,7 +2008,11 @@ mod tests {
             kernel.view(),
             None,
             Padding::Fixed([1, 1, 1, 1].into()),
+            1,
             &strides,
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1228,7 +2025,11 @@ mod tests {
             kernel.view(),
             None,
             Padding::Same,
+            1,
             &strides,
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         assert_eq!(
@@ -1259,7 +2060,11 @@ mod tests {
             kernel.view(),
             None,
             Padding::zero::<1>(),
+            1,
             &[2],
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1272,7 +2077,11 @@ mod tests {
             kernel.view(),
             Some(bias.view()),
             Padding::zero::<1>(),
+            1,
             &[2],
+            &[],
+            &[],
+            None,
         )
         .unwrap();
         expect_equal(&result, &expected_with_bias)?;
@@ -1280,6 +2089,171 @@ mod tests {
         Ok(())
     }
 
+    /// Un-optimized reference implementation of 2D transposed convolution,
+    /// given the output size and padding.
+    #[allow(clippy::too_many_arguments)]
+    fn reference_conv_transpose(
+        input: TensorView,
+        kernel: TensorView,
+        bias: Option<TensorView>,
+        groups: usize,
+        strides: [usize; 2],
+        dilations: [usize; 2],
+        pads: [usize; 2],
+        out_size: [usize; 2],
+    ) -> Tensor {
+        let [batch, in_chans, in_h, in_w]: [usize; 4] = input.shape().try_into().unwrap();
+        let [_, out_chans_per_group, k_h, k_w]: [usize; 4] = kernel.shape().try_into().unwrap();
+        let in_chans_per_group = in_chans / groups;
+        let out_chans = out_chans_per_group * groups;
+
+        let mut output = NdTensor::zeros([batch, out_chans, out_size[0], out_size[1]]);
+        for [n, out_c, y, x] in output.indices() {
+            output[[n, out_c, y, x]] = bias.as_ref().map(|b| b[[out_c]]).unwrap_or(0.);
+        }
+
+        for [n, in_c, y, x] in NdTensor::<f32, 4>::zeros([batch, in_chans, in_h, in_w]).indices() {
+            let group = in_c / in_chans_per_group;
+            for k_c in 0..out_chans_per_group {
+                let out_c = group * out_chans_per_group + k_c;
+                for k_y in 0..k_h {
+                    for k_x in 0..k_w {
+                        let out_y =
+                            (y * strides[0] + k_y * dilations[0]) as isize - pads[0] as isize;
+                        let out_x =
+                            (x * strides[1] + k_x * dilations[1]) as isize - pads[1] as isize;
+                        if out_y < 0
+                            || out_x < 0
+                            || out_y >= out_size[0] as isize
+                            || out_x >= out_size[1] as isize
+                        {
+                            continue;
+                        }
+                        output[[n, out_c, out_y as usize, out_x as usize]] +=
+                            input[[n, in_c, y, x]] * kernel[[in_c, k_c, k_y, k_x]];
+                    }
+                }
+            }
+        }
+
+        output.into_dyn()
+    }
+
+    #[test]
+    fn test_conv_transpose_groups_dilations() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Groups, dilations, output padding and asymmetric padding.
+        let input = Tensor::rand(&[1, 4, 3, 3], &mut rng);
+        let kernel = Tensor::rand(&[4, 3, 2, 3], &mut rng);
+        let result = conv_transpose(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::Fixed([1, 0, 0, 1].into()),
+            2,
+            &[2, 2],
+            &[2, 1],
+            &[1, 1],
+            None,
+        )?;
+        let expected = reference_conv_transpose(
+            input.view(),
+            kernel.view(),
+            None,
+            2,
+            [2, 2],
+            [2, 1],
+            [1, 0],
+            [7, 7],
+        );
+        expect_equal(&result, &expected)?;
+
+        // Depthwise transposed conv with bias and an explicit output shape.
+        let input = Tensor::rand(&[2, 2, 4, 4], &mut rng);
+        let kernel = Tensor::rand(&[2, 1, 3, 3], &mut rng);
+        let bias = Tensor::rand(&[2], &mut rng);
+        let result = conv_transpose(
+            &pool,
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            Padding::zero::<2>(),
+            2,
+            &[2, 2],
+            &[1, 1],
+            &[],
+            Some(&[8, 8]),
+        )?;
+        let expected = reference_conv_transpose(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            2,
+            [2, 2],
+            [1, 1],
+            [0, 0],
+            [8, 8],
+        );
+        expect_equal(&result, &expected)?;
+
+        // 1D transposed conv with groups and dilations.
+        let input = Tensor::rand(&[1, 2, 5], &mut rng);
+        let kernel = Tensor::rand(&[2, 2, 3], &mut rng);
+        let result = conv_transpose(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::Fixed([1, 1].as_slice().into()),
+            2,
+            &[2],
+            &[2],
+            &[1],
+            None,
+        )?;
+        let mut input_2d = input.clone();
+        input_2d.reshape(&[1, 2, 1, 5]);
+        let mut kernel_2d = kernel.clone();
+        kernel_2d.reshape(&[2, 2, 1, 3]);
+        let mut expected = reference_conv_transpose(
+            input_2d.view(),
+            kernel_2d.view(),
+            None,
+            2,
+            [1, 2],
+            [1, 2],
+            [0, 1],
+            [1, 12],
+        );
+        expected.reshape(&[1, 4, 12]);
+        expect_equal(&result, &expected)?;
+
+        // Invalid group count
+        let result = conv_transpose(
+            &pool,
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::zero::<1>(),
+            3,
+            &[1],
+            &[],
+            &[],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Input channels must be divisible by group count"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_conv_transpose_output_size_and_padding() {
         struct Case {
@@ -1287,6 +2261,9 @@ mod tests {
             kernel_shape: [usize; 2],
             padding: Padding,
             strides: [usize; 2],
+            dilations: [usize; 2],
+            output_padding: [usize; 2],
+            output_shape: Option<[usize; 2]>,
             expected: Result<([usize; 2], [usize; 4]), OpError>,
         }
 
@@ -1297,6 +2274,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::zero::<2>(),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([7, 7], [0, 0, 0, 0])),
             },
             // Zero padding, stride of 3
@@ -1305,6 +2285,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::zero::<2>(),
                 strides: [3, 3],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([15, 15], [0, 0, 0, 0])),
             },
             // Non-zero padding, stride of 1
@@ -1313,6 +2296,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::Fixed([1, 1, 1, 1].into()),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([5, 5], [1, 1, 1, 1])),
             },
             Case {
@@ -1320,6 +2306,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::Fixed([2, 2, 2, 2].into()),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([3, 3], [2, 2, 2, 2])),
             },
             // Uneven padding
@@ -1328,6 +2317,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::Fixed([1, 2, 1, 2].into()),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([5, 3], [1, 2, 1, 2])),
             },
             // Same padding
@@ -1336,6 +2328,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::Same,
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Ok(([5, 5], [1, 1, 1, 1])),
             },
             // Same padding. Case where output size is smaller than
@@ -1345,6 +2340,9 @@ mod tests {
                 kernel_shape: [1, 1],
                 padding: Padding::Same,
                 strides: [3, 3],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Err(OpError::InvalidValue("Input is too small")),
             },
             // Padding too large
@@ -1353,6 +2351,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::Fixed([4, 4, 4, 4].into()),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Err(OpError::InvalidValue("Input is too small")),
             },
             // Invalid strides
@@ -1361,6 +2362,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::zero::<2>(),
                 strides: [0, 0],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Err(OpError::InvalidValue("Strides must be > 0")),
             },
             // Empty input
@@ -1369,6 +2373,9 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::zero::<2>(),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Err(OpError::InvalidValue("Input width and height must be > 0")),
             },
             // Wrong padding size for input spatial shape.
@@ -1377,8 +2384,91 @@ mod tests {
                 kernel_shape: [3, 3],
                 padding: Padding::zero::<1>(),
                 strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
                 expected: Err(OpError::InvalidValue("Wrong number of pad values")),
             },
+            // Same padding with an odd amount of padding. The extra padding
+            // is added at the end.
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [2, 2],
+                padding: Padding::Same,
+                strides: [1, 1],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: None,
+                expected: Ok(([5, 5], [0, 0, 1, 1])),
+            },
+            // Dilations
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::zero::<2>(),
+                strides: [1, 1],
+                dilations: [2, 3],
+                output_padding: [0, 0],
+                output_shape: None,
+                expected: Ok(([9, 11], [0, 0, 0, 0])),
+            },
+            // Output padding
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::Fixed([1, 1, 1, 1].into()),
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: [1, 0],
+                output_shape: None,
+                expected: Ok(([10, 9], [1, 1, 1, 1])),
+            },
+            // Output padding too large
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::zero::<2>(),
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: [2, 0],
+                output_shape: None,
+                expected: Err(OpError::InvalidValue(
+                    "Output padding must be smaller than stride or dilation",
+                )),
+            },
+            // Explicit output shape. Padding is inferred and `pads` is ignored.
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::Fixed([4, 4, 4, 4].into()),
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: Some([10, 8]),
+                expected: Ok(([10, 8], [0, 1, 1, 2])),
+            },
+            // Explicit output shape which is too large
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::zero::<2>(),
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: [0, 0],
+                output_shape: Some([12, 12]),
+                expected: Err(OpError::InvalidValue("Input is too small")),
+            },
+            // Invalid dilations
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [3, 3],
+                padding: Padding::zero::<2>(),
+                strides: [1, 1],
+                dilations: [0, 1],
+                output_padding: [0, 0],
+                output_shape: None,
+                expected: Err(OpError::InvalidValue("Dilations must be > 0")),
+            },
         ];
 
         for Case {
@@ -1386,11 +2476,21 @@ mod tests {
             kernel_shape,
             padding,
             strides,
+            dilations,
+            output_padding,
+            output_shape,
             expected,
         } in cases
         {
-            let result =
-                conv_transpose_output_size_and_padding(input_shape, kernel_shape, padding, strides);
+            let result = conv_transpose_output_size_and_padding(
+                input_shape,
+                kernel_shape,
+                padding,
+                strides,
+                dilations,
+                output_padding,
+                output_shape,
+            );
             assert_eq!(result, expected);
         }
     }
@@ -1468,6 +2568,7 @@ mod tests {
                 &columns.view(),
                 [0, 0, 0, 0], // Padding
                 [stride_y, stride_x],
+                [1, 1], // Dilations
                 None,
             );
         });
@@ -1479,6 +2580,7 @@ mod tests {
                 &columns.view(),
                 [1, 1, 1, 1], // Padding
                 [stride_y, stride_x],
+                [1, 1], // Dilations
                 None,
             );
         });
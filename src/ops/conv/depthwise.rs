@@ -5,6 +5,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor};
+use rten_vecmath::vec_mul_add_in_place;
 use smallvec::SmallVec;
 
 use crate::iter_util::{range_chunks, unroll_loop};
@@ -108,6 +109,13 @@ fn conv_2d_depthwise_block(
                     let src_els = src.len().div_ceil(stride_w);
                     debug_assert!(src_els == dest.len());
 
+                    // Use a vectorized loop for the common case of unit
+                    // stride, where input elements are contiguous.
+                    if stride_w == 1 {
+                        vec_mul_add_in_place(dest, src, scale);
+                        continue;
+                    }
+
                     unroll_loop!(0..src_els, i, 4, {
                         unsafe {
                             *dest.get_unchecked_mut(i) += *src.get_unchecked(i * stride_w) * scale;
@@ -31,17 +31,31 @@ struct ColOffsets {
     x: Vec<i32>,
 }
 
-/// Unrolls patches of an image as columns of a virtual matrix.
-///
-/// The input image has shape [C,H,W] and is transformed into a matrix with
-/// shape [C * Kh * kW, Oh * Ow] where Kh/Kw are convolution kernel sizes and
-/// Oh/Ow are the number of patches in the Y and X directions.
+/// Parameters which determine the layout of a [VirtualIm2Col] matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Im2ColParams {
+    /// Shape of the `[C, H, W]` input image.
+    pub image_shape: [usize; 3],
+
+    /// Strides of the input image.
+    pub image_strides: [usize; 3],
+
+    pub kernel: [usize; 2],
+    pub padding: [usize; 4],
+    pub strides: [usize; 2],
+    pub dilations: [usize; 2],
+
+    /// Width of column panels used by the GEMM kernel.
+    pub panel_width: usize,
+}
+
+/// Lookup tables which map coordinates in a [VirtualIm2Col] matrix to offsets
+/// in the input image.
 ///
-/// The transform is virtual because the matrix is not actually materialized
-/// in memory. Instead blocks of it are produced on-demand during a matrix
-/// multiplication operation.
-pub struct VirtualIm2Col<'a> {
-    image: NdTensorView<'a, f32, 3>,
+/// A plan depends only on the [Im2ColParams] and not the content of the
+/// image, so it can be re-used across images with the same shape and strides.
+pub struct Im2ColPlan {
+    params: Im2ColParams,
 
     /// Map of im2col row index to input image coordinate, premultiplied with
     /// the corresponding stride.
@@ -63,30 +77,25 @@ pub struct VirtualIm2Col<'a> {
     /// Maximum valid sum of `row_offsets.x + col_offsets.x`. Values above this
     /// correspond to the padding region.
     max_x_offset: i32,
-
-    /// Gemm kernel that is going to be used.
-    gemm_kernel: KernelType,
 }
 
-impl<'a> VirtualIm2Col<'a> {
-    /// Create a virtual im2col matrix from a [C, H, W] input tensor and
-    /// convolution parameters.
-    pub fn new(
-        gemm_kernel: KernelType,
-        image: NdTensorView<'a, f32, 3>,
-        kernel: [usize; 2],
-        padding: [usize; 4],
-        strides: [usize; 2],
-        dilations: [usize; 2],
-        panel_width: usize,
-    ) -> VirtualIm2Col {
+impl Im2ColPlan {
+    /// Build the lookup tables for an im2col transform with the given
+    /// parameters.
+    pub fn new(params: Im2ColParams) -> Im2ColPlan {
+        let Im2ColParams {
+            image_shape: [chans, h, w],
+            image_strides,
+            kernel: [k_h, k_w],
+            padding,
+            strides: [stride_h, stride_w],
+            dilations: [dilation_y, dilation_x],
+            panel_width,
+        } = params;
+
         // Ensure image has at least one cell.
-        assert!(image.len() > 0);
+        assert!(chans * h * w > 0);
 
-        let [chans, h, w] = image.shape();
-        let [k_h, k_w] = kernel;
-        let [stride_h, stride_w] = strides;
-        let [dilation_y, dilation_x] = dilations;
         let [pad_top, pad_left, _pad_bottom, _pad_right] = padding;
         let (y_patches, x_patches, _) = calc_output_size_and_padding(
             (h, w),
@@ -98,7 +107,7 @@ impl<'a> VirtualIm2Col<'a> {
         .expect("invalid im2col params");
 
         let [im_stride_c, im_stride_h, im_stride_w]: [i32; 3] =
-            image.strides().map(|s| s.try_into().unwrap());
+            image_strides.map(|s| s.try_into().unwrap());
 
         // Build lookup table of row index in the virtual im2col matrix to
         // offsets in the image.
@@ -153,16 +162,15 @@ impl<'a> VirtualIm2Col<'a> {
 
         // Compute max valid X / Y offsets for testing whether an element is in
         // the padding region or not.
-        let max_y_offset: i32 = ((image.size(1) - 1) * image.stride(1))
+        let max_y_offset: i32 = ((h - 1) * image_strides[1])
             .try_into()
             .expect("invalid im2col params");
-        let max_x_offset: i32 = ((image.size(2) - 1) * image.stride(2))
+        let max_x_offset: i32 = ((w - 1) * image_strides[2])
             .try_into()
             .expect("invalid im2col params");
 
-        VirtualIm2Col {
-            gemm_kernel,
-            image,
+        Im2ColPlan {
+            params,
             row_offsets: RowOffsets {
                 chan: row_chan_offsets,
                 y: row_y_offsets,
@@ -179,6 +187,48 @@ impl<'a> VirtualIm2Col<'a> {
         }
     }
 
+    /// Return the parameters this plan was created with.
+    pub fn params(&self) -> &Im2ColParams {
+        &self.params
+    }
+}
+
+/// Unrolls patches of an image as columns of a virtual matrix.
+///
+/// The input image has shape [C,H,W] and is transformed into a matrix with
+/// shape [C * Kh * kW, Oh * Ow] where Kh/Kw are convolution kernel sizes and
+/// Oh/Ow are the number of patches in the Y and X directions.
+///
+/// The transform is virtual because the matrix is not actually materialized
+/// in memory. Instead blocks of it are produced on-demand during a matrix
+/// multiplication operation, using the lookup tables in an [Im2ColPlan].
+pub struct VirtualIm2Col<'a> {
+    image: NdTensorView<'a, f32, 3>,
+
+    plan: &'a Im2ColPlan,
+
+    /// Gemm kernel that is going to be used.
+    gemm_kernel: KernelType,
+}
+
+impl<'a> VirtualIm2Col<'a> {
+    /// Create a virtual im2col matrix from a [C, H, W] input tensor and a
+    /// plan created for images with the same shape and strides.
+    pub fn new(
+        gemm_kernel: KernelType,
+        image: NdTensorView<'a, f32, 3>,
+        plan: &'a Im2ColPlan,
+    ) -> VirtualIm2Col<'a> {
+        assert_eq!(image.shape(), plan.params.image_shape);
+        assert_eq!(image.strides(), plan.params.image_strides);
+
+        VirtualIm2Col {
+            gemm_kernel,
+            image,
+            plan,
+        }
+    }
+
     /// Pack part of an image according to the requirements of
     /// [VirtualMatrix::pack_b].
     ///
@@ -199,11 +249,12 @@ impl<'a> VirtualIm2Col<'a> {
         let used_size = rows.len() * col_range.len();
         assert_eq!(out.len(), used_size);
 
-        let col_y_offsets = &self.col_offsets.y[col_range.clone()];
-        let col_x_offsets = &self.col_offsets.x[col_range.clone()];
-        let row_chan_offsets = &self.row_offsets.chan[rows.clone()];
-        let row_y_offsets = &self.row_offsets.y[rows.clone()];
-        let row_x_offsets = &self.row_offsets.x[rows.clone()];
+        let plan = self.plan;
+        let col_y_offsets = &plan.col_offsets.y[col_range.clone()];
+        let col_x_offsets = &plan.col_offsets.x[col_range.clone()];
+        let row_chan_offsets = &plan.row_offsets.chan[rows.clone()];
+        let row_y_offsets = &plan.row_offsets.y[rows.clone()];
+        let row_x_offsets = &plan.row_offsets.x[rows.clone()];
 
         let img_ptr = self.image.storage().as_ptr();
 
@@ -219,8 +270,8 @@ impl<'a> VirtualIm2Col<'a> {
             let col_x_offset: [S::Int; NR_REGS] = std::array::from_fn(|i| {
                 S::Int::load(col_x_offsets.as_ptr().add(start_col + S::LEN * i))
             });
-            let max_x_offset = S::Int::splat(self.max_x_offset);
-            let max_y_offset = S::Int::splat(self.max_y_offset);
+            let max_x_offset = S::Int::splat(plan.max_x_offset);
+            let max_y_offset = S::Int::splat(plan.max_y_offset);
 
             for ((&row_chan_offset, &row_y_offset), &row_x_offset) in row_chan_offsets
                 .iter()
@@ -284,18 +335,18 @@ impl<'a> VirtualIm2Col<'a> {
         cols: Range<usize>,
     ) {
         use std::arch::x86_64::__m512;
-        self.pack_b_impl::<__m512, 2>(out, panel_width, rows.clone(), cols.clone());
+        self.pack_b_impl::<__m512, 4>(out, panel_width, rows.clone(), cols.clone());
     }
 }
 
 // Safety: `pack_b` initializes the entire buffer passed to it.
 unsafe impl<'a> VirtualMatrix for VirtualIm2Col<'a> {
     fn rows(&self) -> usize {
-        self.row_offsets.chan.len()
+        self.plan.row_offsets.chan.len()
     }
 
     fn cols(&self) -> usize {
-        self.n_cols
+        self.plan.n_cols
     }
 
     fn pack_b(
@@ -308,7 +359,7 @@ unsafe impl<'a> VirtualMatrix for VirtualIm2Col<'a> {
         match (self.gemm_kernel, panel_width) {
             #[cfg(feature = "avx512")]
             #[cfg(target_arch = "x86_64")]
-            (KernelType::Avx512, 32) => unsafe {
+            (KernelType::Avx512, 64) => unsafe {
                 assert!(is_avx512_supported());
                 self.pack_b_impl_avx512(out, panel_width, rows.clone(), cols.clone());
             },
@@ -1,7 +1,154 @@
+use std::iter::zip;
+use std::mem::MaybeUninit;
+use std::str::FromStr;
+
 use rten_tensor::prelude::*;
+use rten_tensor::{Tensor, TensorView};
 
+use crate::check_dims;
 use crate::ops::{DataType, Input, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::tensor_pool::TensorPool;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Parse each element of a string tensor as a number of type `T`, then
+/// convert it to the output type using `convert`.
+///
+/// Leading and trailing whitespace is ignored. Floats accept the special
+/// values `inf`, `-inf` and `NaN` in any case.
+fn parse_strings<T: FromStr, U>(
+    pool: &TensorPool,
+    input: TensorView<String>,
+    convert: impl Fn(T) -> U,
+) -> Result<Tensor<U>, OpError> {
+    let mut data = pool.alloc(input.len());
+    for s in input.iter() {
+        let value = s
+            .trim()
+            .parse()
+            .map_err(|_| OpError::InvalidValue("Cannot parse string as a number"))?;
+        data.push(convert(value));
+    }
+    Ok(Tensor::from_data(input.shape(), data))
+}
+
+/// Convert a tensor to the element type `dtype`.
+///
+/// The conversions follow the ONNX `Cast` operator:
+///
+/// - Float to integer conversions truncate towards zero. Out-of-range values
+///   saturate and NaN is converted to zero.
+/// - Integer to narrower integer conversions keep the low bits, so
+///   out-of-range values wrap.
+/// - Conversions to bool map zero to false (0) and all other values,
+///   including NaN, to true (1).
+/// - Numbers are converted to strings using their shortest round-trip
+///   representation, and strings are parsed as numbers of the target type.
+///
+/// `Int8`, `UInt8` and `Bool` values are stored in `i32` tensors. `BF16`
+/// inputs are converted as if they were `f32`, but casting to `BF16` is not
+/// supported.
+pub fn cast(pool: &TensorPool, input: Input, dtype: DataType) -> Result<Output, OpError> {
+    if let Input::BFloat16Tensor(t) = input {
+        let t = t.map_in(pool, |x| x.to_f32());
+        return cast(pool, t.view().into(), dtype);
+    }
+
+    let output = match dtype {
+        DataType::Int32 => match input {
+            Input::IntTensor(t) => t.map_in(pool, |x| *x).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| *x as i32).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| *x as i32).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: i32| x)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::Int64 => match input {
+            Input::Int64Tensor(t) => t.map_in(pool, |x| *x).into(),
+            Input::IntTensor(t) => t.map_in(pool, |x| *x as i64).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| *x as i64).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: i64| x)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::Float => match input {
+            Input::FloatTensor(t) => t.map_in(pool, |x| *x).into(),
+            Input::IntTensor(t) => t.map_in(pool, |x| *x as f32).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| *x as f32).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: f32| x)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::String => match input {
+            Input::StringTensor(t) => t.map_in(pool, |x| x.clone()).into(),
+            Input::IntTensor(t) => t.map_in(pool, |x| x.to_string()).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| x.to_string()).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| x.to_string()).into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::Int8 => match input {
+            Input::IntTensor(t) => t.map_in(pool, |x| *x as i8 as i32).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| *x as i8 as i32).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| *x as i8 as i32).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: i8| x as i32)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::UInt8 => match input {
+            Input::IntTensor(t) => t.map_in(pool, |x| *x as u8 as i32).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| *x as u8 as i32).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| *x as u8 as i32).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: u8| x as i32)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::Bool => match input {
+            Input::IntTensor(t) => t.map_in(pool, |x| (*x != 0) as i32).into(),
+            Input::Int64Tensor(t) => t.map_in(pool, |x| (*x != 0) as i32).into(),
+            Input::FloatTensor(t) => t.map_in(pool, |x| (*x != 0.) as i32).into(),
+            Input::StringTensor(t) => parse_strings(pool, t, |x: f32| (x != 0.) as i32)?.into(),
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        },
+        DataType::BFloat16 => {
+            return Err(OpError::UnsupportedValue(
+                "Cast to bfloat16 is not supported",
+            ))
+        }
+    };
+    Ok(output)
+}
+
+/// Variant of [cast] which converts `input` in place if possible.
+///
+/// This is possible when the input is already of the target type, or when
+/// the input is an `i32` tensor and the target type is stored as `i32`.
+pub fn cast_in_place(pool: &TensorPool, input: Output, dtype: DataType) -> Result<Output, OpError> {
+    match (input, dtype) {
+        (
+            Output::IntTensor(mut t),
+            DataType::Int32 | DataType::Int8 | DataType::UInt8 | DataType::Bool,
+        ) => {
+            match dtype {
+                DataType::Int8 => t.apply(|x| *x as i8 as i32),
+                DataType::UInt8 => t.apply(|x| *x as u8 as i32),
+                DataType::Bool => t.apply(|x| (*x != 0) as i32),
+                _ => {}
+            }
+            Ok(t.into())
+        }
+        (Output::Int64Tensor(t), DataType::Int64) => Ok(t.into()),
+        (Output::FloatTensor(t), DataType::Float) => Ok(t.into()),
+        (Output::StringTensor(t), DataType::String) => Ok(t.into()),
+        (input, dtype) => cast(pool, (&input).into(), dtype),
+    }
+}
 
 #[derive(Debug)]
 pub struct Cast {
@@ -15,17 +162,7 @@ impl Operator for Cast {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
-        let result: Output = match self.to {
-            DataType::Int32 => match input {
-                Input::IntTensor(t) => t.map_in(pool, |x| *x).into(),
-                Input::FloatTensor(t) => t.map_in(pool, |x| *x as i32).into(),
-            },
-            DataType::Float => match input {
-                Input::FloatTensor(t) => t.map_in(pool, |x| *x).into(),
-                Input::IntTensor(t) => t.map_in(pool, |x| *x as f32).into(),
-            },
-        };
-        result.into_op_result()
+        cast(pool, input, self.to).into_op_result()
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -38,13 +175,137 @@ impl Operator for Cast {
         input: Output,
         _: InputList,
     ) -> Result<Output, OpError> {
-        match (input, self.to) {
-            (Output::IntTensor(t), DataType::Int32) => Ok(t.into()),
-            (Output::FloatTensor(t), DataType::Float) => Ok(t.into()),
-            (input, _) => self
-                .run(pool, InputList::from(&[(&input).into()]))
-                .map(|mut outputs| outputs.remove(0)),
+        cast_in_place(pool, input, self.to)
+    }
+}
+
+/// Cast the first input to the element type of the second input.
+#[derive(Debug)]
+pub struct CastLike {}
+
+impl Operator for CastLike {
+    fn name(&self) -> &str {
+        "CastLike"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        let target = inputs.require(1)?;
+        cast(pool, input, target.dtype()).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        input: Output,
+        other: InputList,
+    ) -> Result<Output, OpError> {
+        let target = other.require(0)?;
+        cast_in_place(pool, input, target.dtype())
+    }
+}
+
+/// Return per-channel values for `image_to_tensor`, using `default` for all
+/// channels if `vals` is empty.
+fn channel_params(vals: &[f32], chans: usize, default: f32) -> Result<Vec<f32>, OpError> {
+    match vals.len() {
+        0 => Ok(vec![default; chans]),
+        len if len == chans => Ok(vals.to_vec()),
+        _ => Err(OpError::InvalidValue(
+            "`mean` and `std` must have one value per channel",
+        )),
+    }
+}
+
+fn image_to_tensor_impl<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    scale: f32,
+    mean: &[f32],
+    std: &[f32],
+    to_f32: impl Fn(T) -> f32,
+) -> Result<Tensor, OpError> {
+    let [batch, height, width, chans] = check_dims!(input, 4, "NHWC");
+    let mean = channel_params(mean, chans, 0.)?;
+    let std = channel_params(std, chans, 1.)?;
+
+    // Fold the scale and normalization into one multiply-add per element.
+    let mul: Vec<f32> = std.iter().map(|s| scale / s).collect();
+    let add: Vec<f32> = zip(&mean, &std).map(|(m, s)| -m / s).collect();
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let mut output = Tensor::uninit_in(pool, &[batch, chans, height, width]);
+
+    let plane_size = height * width;
+    let image_size = plane_size * chans;
+    if image_size > 0 {
+        let in_data = input.data().unwrap();
+        let out_data = output.data_mut().unwrap();
+        for (in_img, out_img) in zip(
+            in_data.chunks_exact(image_size),
+            out_data.chunks_exact_mut(image_size),
+        ) {
+            for (i, pixel) in in_img.chunks_exact(chans).enumerate() {
+                for (c, &x) in pixel.iter().enumerate() {
+                    out_img[c * plane_size + i] = MaybeUninit::new(to_f32(x) * mul[c] + add[c]);
+                }
+            }
+        }
+    }
+
+    // Safety: Every element of the output was initialized above, unless it
+    // is empty.
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Convert a batch of images in NHWC layout to a float tensor in NCHW layout.
+///
+/// Each value `x` in channel `c` is converted to `(x * scale - mean[c]) /
+/// std[c]`. If `mean` or `std` are empty, they default to zero and one for
+/// every channel.
+///
+/// This combines the cast, transpose and normalization that are needed to
+/// prepare decoded images or camera frames for a vision model into a single
+/// pass over the pixels. Integer inputs are typically 8-bit pixel values, in
+/// which case a `scale` of `1. / 255.` maps them to `[0, 1]`.
+pub fn image_to_tensor(
+    pool: &TensorPool,
+    input: Input,
+    scale: f32,
+    mean: &[f32],
+    std: &[f32],
+) -> Result<Tensor, OpError> {
+    match input {
+        Input::FloatTensor(input) => image_to_tensor_impl(pool, input, scale, mean, std, |x| x),
+        Input::IntTensor(input) => {
+            image_to_tensor_impl(pool, input, scale, mean, std, |x| x as f32)
         }
+        Input::Int64Tensor(_)
+        | Input::StringTensor(_)
+        | Input::Sequence(_)
+        | Input::BFloat16Tensor(_) => Err(OpError::IncorrectInputType),
+    }
+}
+
+#[derive(Debug)]
+pub struct ImageToTensor {
+    pub scale: f32,
+    pub mean: Vec<f32>,
+    pub std: Vec<f32>,
+}
+
+impl Operator for ImageToTensor {
+    fn name(&self) -> &str {
+        "ImageToTensor"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        image_to_tensor(pool, input, self.scale, &self.mean, &self.std).into_op_result()
     }
 }
 
@@ -52,11 +313,12 @@ impl Operator for Cast {
 mod tests {
     use std::error::Error;
 
-    use rten_tensor::tensor;
+    use rten_tensor::prelude::*;
     use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{tensor, Tensor};
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{Cast, DataType, Operator};
+    use crate::ops::{Cast, CastLike, DataType, ImageToTensor, InputList, OpError, Operator};
 
     #[test]
     fn test_cast() -> Result<(), Box<dyn Error>> {
@@ -142,4 +404,271 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cast_int64() {
+        let pool = new_pool();
+        let int64_input = tensor!([1i64, -2, 3_000_000_000]);
+
+        // Cast from int64 => int32. Out-of-range values wrap.
+        let result = Cast {
+            to: DataType::Int32,
+        }
+        .run(&pool, (&int64_input).into())
+        .unwrap()
+        .remove(0)
+        .into_int()
+        .unwrap();
+        assert_eq!(result, tensor!([1, -2, -1_294_967_296]));
+
+        // Cast from int32 and float => int64
+        let cast_to_int64 = Cast {
+            to: DataType::Int64,
+        };
+        let result = cast_to_int64
+            .run(&pool, (&tensor!([1, -2, 3])).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([1i64, -2, 3]));
+
+        let result = cast_to_int64
+            .run(&pool, (&tensor!([1.5, -2.5, 8589934592.])).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([1i64, -2, 1 << 33]));
+
+        // Cast from int64 => float
+        let result = Cast {
+            to: DataType::Float,
+        }
+        .run(&pool, (&int64_input).into())
+        .unwrap()
+        .remove(0)
+        .into_float()
+        .unwrap();
+        assert_eq!(result, tensor!([1., -2., 3e9]));
+    }
+
+    #[test]
+    fn test_cast_narrow_types() {
+        let pool = new_pool();
+        let float_input = tensor!([-1.5, 0., 0.5, 200., 300., f32::NAN]);
+        let int_input = tensor!([-1, 0, 1, 200, 300, 256]);
+
+        struct Case {
+            to: DataType,
+            from_float: Tensor<i32>,
+            from_int: Tensor<i32>,
+        }
+
+        let cases = [
+            // Floats truncate towards zero and saturate. Ints wrap.
+            Case {
+                to: DataType::Int8,
+                from_float: tensor!([-1, 0, 0, 127, 127, 0]),
+                from_int: tensor!([-1, 0, 1, -56, 44, 0]),
+            },
+            Case {
+                to: DataType::UInt8,
+                from_float: tensor!([0, 0, 0, 200, 255, 0]),
+                from_int: tensor!([255, 0, 1, 200, 44, 0]),
+            },
+            // Zero is false and all other values, including NaN, are true.
+            Case {
+                to: DataType::Bool,
+                from_float: tensor!([1, 0, 1, 1, 1, 1]),
+                from_int: tensor!([1, 0, 1, 1, 1, 1]),
+            },
+        ];
+
+        for Case {
+            to,
+            from_float,
+            from_int,
+        } in cases
+        {
+            let cast = Cast { to };
+            let result = cast
+                .run(&pool, (&float_input).into())
+                .unwrap()
+                .remove(0)
+                .into_int()
+                .unwrap();
+            assert_eq!(result, from_float, "float => {:?}", to);
+
+            let result = cast
+                .run(&pool, (&int_input).into())
+                .unwrap()
+                .remove(0)
+                .into_int()
+                .unwrap();
+            assert_eq!(result, from_int, "int => {:?}", to);
+
+            // In-place cast from int.
+            let result = cast
+                .run_in_place(&pool, int_input.clone().into(), InputList::new())
+                .unwrap()
+                .into_int()
+                .unwrap();
+            assert_eq!(result, from_int, "int => {:?} in place", to);
+        }
+    }
+
+    #[test]
+    fn test_cast_like() {
+        let pool = new_pool();
+        let input = tensor!([1.5, -2.5]);
+
+        let result = CastLike {}
+            .run(&pool, (&input, &tensor!([0i64])).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, tensor!([1i64, -2]));
+
+        let result = CastLike {}
+            .run_in_place(&pool, input.clone().into(), (&tensor!([0])).into())
+            .unwrap()
+            .into_int()
+            .unwrap();
+        assert_eq!(result, tensor!([1, -2]));
+
+        // Casting to the same type is a no-op.
+        let result = CastLike {}
+            .run_in_place(&pool, input.clone().into(), (&input).into())
+            .unwrap()
+            .into_float()
+            .unwrap();
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_cast_string() {
+        let pool = new_pool();
+        let strings =
+            |vals: &[&str]| Tensor::from_vec(vals.iter().map(|s| s.to_string()).collect());
+
+        // Cast from numbers => string
+        let cast_to_string = Cast {
+            to: DataType::String,
+        };
+        let result = cast_to_string
+            .run(&pool, (&tensor!([1.5, -2., f32::INFINITY])).into())
+            .unwrap()
+            .remove(0)
+            .into_strings()
+            .unwrap();
+        assert_eq!(result, strings(&["1.5", "-2", "inf"]));
+
+        let result = cast_to_string
+            .run(&pool, (&tensor!([3_000_000_000i64, -1])).into())
+            .unwrap()
+            .remove(0)
+            .into_strings()
+            .unwrap();
+        assert_eq!(result, strings(&["3000000000", "-1"]));
+
+        // Cast from string => numbers
+        let input = strings(&["1.5", " -2 ", "INF"]);
+        let result = Cast {
+            to: DataType::Float,
+        }
+        .run(&pool, (&input).into())
+        .unwrap()
+        .remove(0)
+        .into_float()
+        .unwrap();
+        assert_eq!(result, tensor!([1.5, -2., f32::INFINITY]));
+
+        let input = strings(&["42", "-3000000000"]);
+        let result = Cast {
+            to: DataType::Int64,
+        }
+        .run(&pool, (&input).into())
+        .unwrap()
+        .remove(0)
+        .into_int64()
+        .unwrap();
+        assert_eq!(result, tensor!([42i64, -3_000_000_000]));
+
+        // Strings which are not valid numbers of the target type.
+        let result = Cast {
+            to: DataType::Int32,
+        }
+        .run(&pool, (&strings(&["1.5"])).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Cannot parse string as a number"))
+        );
+    }
+
+    #[test]
+    fn test_image_to_tensor() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // 2x3 RGB image with 8-bit pixel values, in NHWC layout.
+        let input = Tensor::from_data(&[1, 2, 3, 3], (0..18).map(|x| x * 10).collect::<Vec<i32>>());
+        let scale = 1. / 255.;
+        let mean = [0.485, 0.456, 0.406];
+        let std = [0.229, 0.224, 0.225];
+
+        let expected = input
+            .permuted(&[0, 3, 1, 2])
+            .map(|&x| x as f32 * scale)
+            .to_tensor();
+        let mut expected = expected;
+        for (c, mut chan) in expected.axis_iter_mut(1).enumerate() {
+            chan.apply(|x| (x - mean[c]) / std[c]);
+        }
+
+        let op = ImageToTensor {
+            scale,
+            mean: mean.into(),
+            std: std.into(),
+        };
+        let result = op
+            .run(&pool, (&input).into())
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+        assert_eq!(result.shape(), &[1, 3, 2, 3]);
+        expect_equal(&result, &expected)?;
+
+        // Float input with default mean and std.
+        let float_input = input.map(|&x| x as f32);
+        let op = ImageToTensor {
+            scale: 1.,
+            mean: Vec::new(),
+            std: Vec::new(),
+        };
+        let result = op
+            .run(&pool, (&float_input).into())
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &float_input.permuted(&[0, 3, 1, 2]).to_tensor())?;
+
+        // Mismatched channel count.
+        let op = ImageToTensor {
+            scale,
+            mean: vec![0.5; 2],
+            std: Vec::new(),
+        };
+        let result = op.run(&pool, (&input).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`mean` and `std` must have one value per channel"
+            ))
+        );
+
+        Ok(())
+    }
 }
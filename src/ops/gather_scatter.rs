@@ -1,14 +1,41 @@
 use std::iter::zip;
 
+use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{to_slice_items, NdTensorView, SliceItem, Tensor, TensorView, TensorViewMut};
 use smallvec::SmallVec;
 
+use crate::ops::quantize::BlockQuantizedMatrix;
 use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
-use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, Output,
-};
+use crate::ops::{resolve_axis, resolve_index, InputList, IntoOpResult, OpError, Operator, Output};
 use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{map_input, static_dims};
+
+/// Error returned when an entry in the `indices` input of a gather or scatter
+/// operator is out of range.
+const INDEX_OUT_OF_RANGE: OpError = OpError::InvalidValue("Entry in `indices` is out of range");
+
+/// Resolve an entry from the `indices` input of a gather or scatter operator
+/// to a position along a dimension of size `size`.
+///
+/// Negative entries count backwards from the end of the dimension. Returns
+/// `None` if the entry is out of range.
+#[inline]
+fn resolve_entry(size: usize, index: i32) -> Option<usize> {
+    resolve_index(size, index as isize)
+}
+
+/// Resolve an index tuple from the `indices` input of `GatherND` or
+/// `ScatterND`. The tuple indexes the leading dimensions of a tensor with a
+/// given `shape`.
+fn resolve_index_tuple(shape: &[usize], index: &[i32]) -> Result<SmallVec<[usize; 5]>, OpError> {
+    if index.len() > shape.len() {
+        return Err(INDEX_OUT_OF_RANGE);
+    }
+    zip(shape, index)
+        .map(|(&size, &idx)| resolve_entry(size, idx).ok_or(INDEX_OUT_OF_RANGE))
+        .collect()
+}
 
 /// Gather elements from `input` specified by `indices`.
 ///
@@ -24,11 +51,12 @@ pub fn gather<T: Copy + Default>(
 ) -> Result<Tensor<T>, OpError> {
     let axis = resolve_axis(input.ndim(), axis)?;
 
-    for index in indices.iter().copied() {
-        let size = input.size(axis) as i32;
-        if index < -size || index >= size {
-            return Err(OpError::InvalidValue("Entry in `indices` is out of range"));
-        }
+    let axis_size = input.size(axis);
+    if indices
+        .iter()
+        .any(|&index| resolve_entry(axis_size, index).is_none())
+    {
+        return Err(INDEX_OUT_OF_RANGE);
     }
 
     let full_range =
@@ -80,11 +108,10 @@ impl Operator for Gather {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
-        let indices = inputs.require_as::<i32>(1)?;
-        match input {
-            Input::IntTensor(input) => gather(pool, input, self.axis, indices).into_op_result(),
-            Input::FloatTensor(input) => gather(pool, input, self.axis, indices).into_op_result(),
-        }
+        let indices = inputs.require_as_index(1)?;
+        map_input!(input, input, {
+            gather(pool, input, self.axis, indices.view()).into_op_result()
+        })
     }
 }
 
@@ -113,7 +140,7 @@ fn gather_elements_4d<T: Copy + Default>(
     let mut indices_valid = true;
 
     let indices_shape = indices.shape();
-    let axis_size = input.size(axis) as isize;
+    let axis_size = input.size(axis);
 
     // Use nested loops to iterate over indices in `indices` as this is faster
     // than `indices.indices()`.
@@ -123,16 +150,10 @@ fn gather_elements_4d<T: Copy + Default>(
                 for i3 in 0..indices_shape[3] {
                     let (out_el, index) = out_index_iter.next().unwrap();
 
-                    // nb. If axis_val is < -axis_size, it will wrap around to a value
-                    // that is still out of range.
-                    let index = *index as isize;
-
+                    // Out of range indices are mapped to an invalid position,
+                    // which is detected by `input.get` below.
                     let mut in_index = [i0, i1, i2, i3];
-                    in_index[axis] = if index < 0 {
-                        (index + axis_size) as usize
-                    } else {
-                        index as usize
-                    };
+                    in_index[axis] = resolve_entry(axis_size, *index).unwrap_or(usize::MAX);
 
                     let maybe_el = input.get(in_index).copied();
                     *out_el = maybe_el.unwrap_or_default();
@@ -143,7 +164,7 @@ fn gather_elements_4d<T: Copy + Default>(
     }
 
     if !indices_valid {
-        return Err(OpError::InvalidValue("Entry in `indices` is out of range"));
+        return Err(INDEX_OUT_OF_RANGE);
     }
 
     Ok(())
@@ -188,23 +209,19 @@ pub fn gather_elements<T: Copy + Default>(
             axis + pad,
         )?;
     } else {
-        let axis_size = input.size(axis) as isize;
+        let axis_size = input.size(axis);
         let mut indices_valid = true;
         for ((mut in_index, out_el), index) in
             output.indices().zip(output.iter_mut()).zip(indices.iter())
         {
-            // nb. If axis_val is < -axis_size, it will wrap around to a value
-            // that is still out of range.
-            let index = *index as isize;
-            let axis_val = if index < 0 { index + axis_size } else { index };
-            in_index[axis] = axis_val as usize;
+            in_index[axis] = resolve_entry(axis_size, *index).unwrap_or(usize::MAX);
 
             let maybe_el = input.get(in_index).copied();
             *out_el = maybe_el.unwrap_or_default();
             indices_valid &= maybe_el.is_some();
         }
         if !indices_valid {
-            return Err(OpError::InvalidValue("Entry in `indices` is out of range"));
+            return Err(INDEX_OUT_OF_RANGE);
         }
     }
 
@@ -224,14 +241,9 @@ impl Operator for GatherElements {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let indices = inputs.require_as::<i32>(1)?;
-        match input {
-            Input::IntTensor(input) => {
-                gather_elements(pool, input, indices, self.axis).into_op_result()
-            }
-            Input::FloatTensor(input) => {
-                gather_elements(pool, input, indices, self.axis).into_op_result()
-            }
-        }
+        map_input!(input, input, {
+            gather_elements(pool, input, indices, self.axis).into_op_result()
+        })
     }
 }
 
@@ -294,10 +306,9 @@ pub fn gather_nd<T: Clone + Default>(
         let idx_slices = indices.data().unwrap().chunks(idx_tuple_size);
 
         for (out_slice, idx) in out_slices.zip(idx_slices) {
-            let slice_items = to_slice_items(idx);
-            let in_slice = input
-                .try_slice_dyn(slice_items.as_slice())
-                .map_err(|_| OpError::InvalidValue("Invalid index"))?;
+            let idx = resolve_index_tuple(input.shape(), idx)?;
+            let slice_items = to_slice_items(&idx);
+            let in_slice = input.slice_dyn(slice_items.as_slice());
 
             for (out, x) in out_slice.iter_mut().zip(in_slice.iter()) {
                 *out = x.clone();
@@ -321,14 +332,94 @@ impl Operator for GatherND {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let indices = inputs.require_as::<i32>(1)?;
-        match input {
-            Input::IntTensor(input) => {
-                gather_nd(pool, input, indices, self.batch_dims).into_op_result()
-            }
-            Input::FloatTensor(input) => {
-                gather_nd(pool, input, indices, self.batch_dims).into_op_result()
-            }
-        }
+        map_input!(input, input, {
+            gather_nd(pool, input, indices, self.batch_dims).into_op_result()
+        })
+    }
+}
+
+/// Gather rows from a block-quantized 2D table and dequantize them.
+///
+/// This is a fused equivalent of dequantizing `data` and then gathering rows
+/// from it along axis 0, which avoids materializing the dequantized table.
+/// It is used for quantized embedding tables.
+///
+/// `data` has shape `[rows, words]` and each `i32` word packs `32 / bits`
+/// unsigned quantized values, with the first value in the least significant
+/// bits. The dequantized table has `cols = words * 32 / bits` columns.
+///
+/// Each row is divided into blocks of `block_size` columns. `scales` and
+/// `zero_points` have shape `[rows, ceil(cols / block_size)]` and specify the
+/// quantization parameters for each block. If `zero_points` is not provided,
+/// the zero point is `2^(bits - 1)`.
+///
+/// See <https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.GatherBlockQuantized>.
+pub fn gather_block_quantized(
+    pool: &TensorPool,
+    data: NdTensorView<i32, 2>,
+    indices: TensorView<i32>,
+    scales: NdTensorView<f32, 2>,
+    zero_points: Option<NdTensorView<i32, 2>>,
+    bits: u32,
+    block_size: usize,
+) -> Result<Tensor<f32>, OpError> {
+    let data = BlockQuantizedMatrix::new(data, scales, zero_points, bits, block_size)?;
+    let rows = data.rows();
+    let cols = data.cols();
+
+    let rows_idx = indices
+        .iter()
+        .map(|&index| resolve_entry(rows, index).ok_or(INDEX_OUT_OF_RANGE))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let out_shape = [indices.shape(), &[cols]].concat();
+    let mut output = Tensor::zeros_in(pool, &out_shape);
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(cols)
+        .zip(rows_idx)
+        .for_each(|(out_row, row)| data.dequantize_row(row, out_row));
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct GatherBlockQuantized {
+    pub bits: u32,
+    pub block_size: usize,
+}
+
+impl Operator for GatherBlockQuantized {
+    fn name(&self) -> &str {
+        "GatherBlockQuantized"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let data = inputs.require_as::<i32>(0)?;
+        let data = static_dims!(data, 2)?;
+        let indices = inputs.require_as::<i32>(1)?;
+        let scales = inputs.require_as::<f32>(2)?;
+        let scales = static_dims!(scales, 2)?;
+        let zero_points = inputs
+            .get_as::<i32>(3)?
+            .map(|zp| static_dims!(zp, 2))
+            .transpose()?;
+
+        gather_block_quantized(
+            pool,
+            data,
+            indices,
+            scales,
+            zero_points,
+            self.bits,
+            self.block_size,
+        )
+        .into_op_result()
     }
 }
 
@@ -396,20 +487,9 @@ pub fn scatter_elements<
 
     let mut output = data.to_tensor_in(pool);
     for (index, update) in zip(updates.indices(), updates.iter()) {
-        let target_index: SmallVec<[usize; 5]> = index
-            .iter()
-            .enumerate()
-            .filter_map(|(dim, idx)| {
-                if dim == axis {
-                    resolve_index(data.size(dim), indices[&index] as isize)
-                } else {
-                    Some(*idx)
-                }
-            })
-            .collect();
-        if target_index.len() < data.ndim() {
-            return Err(OpError::InvalidValue("Index is invalid"));
-        }
+        let mut target_index: SmallVec<[usize; 5]> = SmallVec::from_slice(&index);
+        target_index[axis] =
+            resolve_entry(data.size(axis), indices[&index]).ok_or(INDEX_OUT_OF_RANGE)?;
 
         let out_el = &mut output[target_index];
         *out_el = scatter_reduce(*out_el, *update, reduction);
@@ -433,17 +513,11 @@ impl Operator for ScatterElements {
         let indices = inputs.require_as::<i32>(1)?;
         let updates = inputs.require(2)?;
 
-        match (data, updates) {
-            (Input::IntTensor(data), Input::IntTensor(updates)) => {
-                scatter_elements(pool, data, indices, updates, self.axis, self.reduction)
-                    .into_op_result()
-            }
-            (Input::FloatTensor(data), Input::FloatTensor(updates)) => {
-                scatter_elements(pool, data, indices, updates, self.axis, self.reduction)
-                    .into_op_result()
-            }
-            _ => Err(OpError::IncorrectInputType),
-        }
+        map_input!(data, data, {
+            let updates = updates.try_into()?;
+            scatter_elements(pool, data, indices, updates, self.axis, self.reduction)
+                .into_op_result()
+        })
     }
 }
 
@@ -497,15 +571,8 @@ pub fn scatter_nd<
 
     let mut output = data.to_tensor_in(pool);
     for (index, update_slice) in index_slices.zip(update_slices) {
-        let mut output_slice_offset = 0;
-        for (i, (size, stride)) in index
-            .iter()
-            .zip(output.shape().iter().zip(output.strides().iter()))
-        {
-            let idx = resolve_index(*size, *i as isize)
-                .ok_or(OpError::InvalidValue("invalid scatter index"))?;
-            output_slice_offset += idx * stride;
-        }
+        let index = resolve_index_tuple(output.shape(), index)?;
+        let output_slice_offset: usize = zip(index, output.strides()).map(|(i, s)| i * s).sum();
         let out_data = output.data_mut().unwrap();
         let out_slice = &mut out_data[output_slice_offset..][..update_slice_len];
 
@@ -531,15 +598,10 @@ impl Operator for ScatterND {
         let indices = inputs.require_as::<i32>(1)?;
         let updates = inputs.require(2)?;
 
-        match (data, updates) {
-            (Input::IntTensor(data), Input::IntTensor(updates)) => {
-                scatter_nd(pool, data, indices, updates, self.reduction).into_op_result()
-            }
-            (Input::FloatTensor(data), Input::FloatTensor(updates)) => {
-                scatter_nd(pool, data, indices, updates, self.reduction).into_op_result()
-            }
-            _ => Err(OpError::IncorrectInputType),
-        }
+        map_input!(data, data, {
+            let updates = updates.try_into()?;
+            scatter_nd(pool, data, indices, updates, self.reduction).into_op_result()
+        })
     }
 }
 
@@ -552,9 +614,10 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{tensor, Tensor};
 
-    use crate::ops::tests::new_pool;
+    use crate::ops::tests::{new_pool, run_op};
     use crate::ops::{
-        gather, gather_elements, gather_nd, scatter_elements, scatter_nd, OpError, ScatterReduction,
+        gather, gather_block_quantized, gather_elements, gather_nd, scatter_elements, scatter_nd,
+        Gather, OpError, ScatterReduction,
     };
 
     #[test]
@@ -577,6 +640,24 @@ mod tests {
         assert_eq!(result, tensor!([3, 4]));
     }
 
+    #[test]
+    fn test_gather_int64() {
+        // Gather int64 data using int64 indices, as ONNX models do.
+        let input = tensor!([10i64, 20, 3_000_000_000]);
+        let indices = tensor!([2i64, 0, -1]);
+        let op = Gather { axis: 0 };
+        let result: Tensor<i64> = run_op(&op, (input.view(), indices.view())).unwrap();
+        assert_eq!(result, tensor!([3_000_000_000i64, 10, 3_000_000_000]));
+
+        // Out-of-range indices are rejected rather than wrapping.
+        let indices = tensor!([1i64 << 32]);
+        let result: Result<Tensor<i64>, _> = run_op(&op, (input.view(), indices.view()));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Entry in `indices` is out of range"))
+        );
+    }
+
     #[test]
     fn test_gather() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -760,6 +841,26 @@ mod tests {
                 indices: [[1], [0]].into(),
                 expected: Ok([[2, 3], [4, 5]].into()),
             },
+            // Negative indices
+            Case {
+                batch_dims: 0,
+                data: [[0, 1], [2, 3]].into(),
+                indices: [[-1, -2], [0, -1]].into(),
+                expected: Ok([2, 1].into()),
+            },
+            // Out of range indices
+            Case {
+                batch_dims: 0,
+                data: [[0, 1], [2, 3]].into(),
+                indices: [[0, 2]].into(),
+                expected: Err(OpError::InvalidValue("Entry in `indices` is out of range")),
+            },
+            Case {
+                batch_dims: 0,
+                data: [[0, 1], [2, 3]].into(),
+                indices: [[-3]].into(),
+                expected: Err(OpError::InvalidValue("Entry in `indices` is out of range")),
+            },
         ];
 
         let pool = new_pool();
@@ -775,6 +876,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gather_block_quantized() {
+        let pool = new_pool();
+
+        // 4-bit values, with one word (8 values) per row.
+        let data = tensor!((2, 1); [0x12345678, -1]);
+
+        // Default zero point, one block per row.
+        let scales = tensor!((2, 1); [0.5, 0.25]);
+        let indices = tensor!((2, 1); [0, -1]);
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            None,
+            4,
+            8,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            tensor!((2, 1, 8); [
+                0., -0.5, -1., -1.5, -2., -2.5, -3., -3.5, // Row 0
+                1.75, 1.75, 1.75, 1.75, 1.75, 1.75, 1.75, 1.75, // Row 1
+            ])
+        );
+
+        // Explicit zero points, two blocks per row.
+        let scales = tensor!((2, 2); [1., 2., 1., 1.]);
+        let zero_points = tensor!((2, 2); [0, 8, 0, 0]);
+        let indices = tensor!(0);
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            Some(zero_points.nd_view()),
+            4,
+            4,
+        )
+        .unwrap();
+        assert_eq!(result, tensor!([8., 7., 6., 5., -8., -10., -12., -14.]));
+
+        // 8-bit values.
+        let data = tensor!((1, 1); [0x04030201]);
+        let scales = tensor!((1, 1); [2.]);
+        let zero_points = tensor!((1, 1); [1]);
+        let indices = tensor!([0]);
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            Some(zero_points.nd_view()),
+            8,
+            4,
+        )
+        .unwrap();
+        assert_eq!(result, tensor!((1, 4); [0., 2., 4., 6.]));
+    }
+
+    #[test]
+    fn test_gather_block_quantized_invalid() {
+        let pool = new_pool();
+        let data = tensor!((2, 1); [0x12345678, -1]);
+        let scales = tensor!((2, 1); [0.5, 0.25]);
+        let indices = tensor!([0]);
+
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            None,
+            3,
+            8,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("`bits` must be 4 or 8"))
+        );
+
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            None,
+            4,
+            4,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`scales` shape does not match `data` and `block_size`"
+            ))
+        );
+
+        let indices = tensor!([2]);
+        let result = gather_block_quantized(
+            &pool,
+            data.nd_view(),
+            indices.view(),
+            scales.nd_view(),
+            None,
+            4,
+            8,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Entry in `indices` is out of range"))
+        );
+    }
+
     #[test]
     fn test_scatter_elements() {
         let pool = new_pool();
@@ -822,6 +1038,33 @@ mod tests {
         )
         .unwrap();
         assert_eq!(result, expected);
+
+        // Negative and out of range indices
+        let indices = tensor!((1, 2); [-1, 5]);
+        let result = scatter_elements(
+            &pool,
+            data.view(),
+            indices.view(),
+            updates.view(),
+            1, /* axis */
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Entry in `indices` is out of range"))
+        );
+
+        let indices = tensor!((1, 2); [-1, -5]);
+        let result = scatter_elements(
+            &pool,
+            data.view(),
+            indices.view(),
+            updates.view(),
+            1, /* axis */
+            None,
+        )
+        .unwrap();
+        assert_eq!(result, tensor!((1, 5); [2.1, 2., 3., 4., 1.1]));
     }
 
     #[test]
@@ -1018,7 +1261,7 @@ mod tests {
                 data: Tensor::arange(1., 5., None),
                 indices: tensor!((4, 1); [0, 1, 2, 4]),
                 updates: tensor!([1., 2., 3., 4.]),
-                expected: OpError::InvalidValue("invalid scatter index"),
+                expected: OpError::InvalidValue("Entry in `indices` is out of range"),
             },
         ];
 
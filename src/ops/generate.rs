@@ -4,8 +4,10 @@ use std::ops;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, Tensor, TensorView};
 
+use crate::number::Identities;
 use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, Output, Scalar,
+    resolve_axis, resolve_index, DataType, Input, InputList, IntoOpResult, OpError, Operator,
+    Output, Scalar,
 };
 use crate::static_dims;
 use crate::tensor_pool::TensorPool;
@@ -14,9 +16,15 @@ pub fn constant_of_shape<T: Copy>(
     pool: &TensorPool,
     value: T,
     shape: &NdTensorView<i32, 1>,
-) -> Tensor<T> {
-    let shape: Vec<_> = shape.iter().map(|el| *el as usize).collect();
-    Tensor::full_in(pool, &shape, value)
+) -> Result<Tensor<T>, OpError> {
+    let shape: Vec<_> = shape
+        .iter()
+        .map(|&el| {
+            el.try_into()
+                .map_err(|_| OpError::InvalidValue("Shape must not contain negative sizes"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Tensor::full_in(pool, &shape, value))
 }
 
 #[derive(Debug)]
@@ -40,6 +48,68 @@ impl Operator for ConstantOfShape {
     }
 }
 
+/// Generate a 2D tensor with ones on the `k`th diagonal and zeros elsewhere.
+///
+/// A positive `k` refers to a diagonal above the main diagonal and a negative
+/// `k` to one below it.
+pub fn eye_like<T: Copy + Identities>(pool: &TensorPool, shape: [usize; 2], k: i32) -> Tensor<T> {
+    let [rows, cols] = shape;
+    let mut output = Tensor::full_in(pool, &shape, T::zero());
+    for y in 0..rows {
+        let x = y as isize + k as isize;
+        if x >= 0 && (x as usize) < cols {
+            output[[y, x as usize]] = T::one();
+        }
+    }
+    output
+}
+
+#[derive(Debug)]
+pub struct EyeLike {
+    /// Data type of the output. Defaults to the type of the input.
+    pub dtype: Option<DataType>,
+    pub k: i32,
+}
+
+impl Operator for EyeLike {
+    fn name(&self) -> &str {
+        "EyeLike"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        let shape: [usize; 2] = input
+            .shape()
+            .try_into()
+            .map_err(|_| OpError::InvalidValue("Input must be a 2D tensor"))?;
+
+        let dtype = self.dtype.unwrap_or(match input {
+            Input::FloatTensor(_) => DataType::Float,
+            Input::IntTensor(_) => DataType::Int32,
+            Input::Int64Tensor(_) => DataType::Int64,
+            Input::StringTensor(_) => DataType::String,
+            Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        });
+        match dtype {
+            DataType::Float => eye_like::<f32>(pool, shape, self.k).into_op_result(),
+            // Ones and zeros have the same representation in all types which
+            // are stored as `i32`.
+            DataType::Int32 | DataType::Int8 | DataType::UInt8 | DataType::Bool => {
+                eye_like::<i32>(pool, shape, self.k).into_op_result()
+            }
+            DataType::Int64 => eye_like::<i64>(pool, shape, self.k).into_op_result(),
+            DataType::String => Err(OpError::UnsupportedValue(
+                "EyeLike does not support string outputs",
+            )),
+            DataType::BFloat16 => Err(OpError::UnsupportedValue(
+                "EyeLike does not support bfloat16 outputs",
+            )),
+        }
+    }
+}
+
 pub fn onehot<T: Copy + Default + PartialEq>(
     pool: &TensorPool,
     indices: TensorView<i32>,
@@ -102,11 +172,17 @@ impl Operator for OneHot {
                 let (on_value, off_value) = extract_on_off_values(values)?;
                 onehot(pool, indices, self.axis, depth, on_value, off_value).into_op_result()
             }
+            Input::Int64Tensor(values) => {
+                let values = static_dims!(values, 1)?;
+                let (on_value, off_value) = extract_on_off_values(values)?;
+                onehot(pool, indices, self.axis, depth, on_value, off_value).into_op_result()
+            }
             Input::FloatTensor(values) => {
                 let values = static_dims!(values, 1)?;
                 let (on_value, off_value) = extract_on_off_values(values)?;
                 onehot(pool, indices, self.axis, depth, on_value, off_value).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -158,6 +234,13 @@ impl Operator for Range {
                 let delta = delta.try_into()?;
                 range::<i32>(start, limit, delta).into_op_result()
             }
+            Input::Int64Tensor(_) => {
+                let start = start.try_into()?;
+                let limit = limit.try_into()?;
+                let delta = delta.try_into()?;
+                range::<i64>(start, limit, delta).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -168,7 +251,9 @@ mod tests {
     use rten_tensor::{tensor, Tensor};
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{onehot, range, ConstantOfShape, OpError, Operator, Scalar};
+    use crate::ops::{
+        eye_like, onehot, range, ConstantOfShape, DataType, EyeLike, OpError, Operator, Scalar,
+    };
 
     #[test]
     fn test_constant_of_shape() {
@@ -187,6 +272,73 @@ mod tests {
 
         assert_eq!(result.shape(), &[1, 5, 10]);
         assert_eq!(result.to_vec(), vec![42; result.shape().iter().product()]);
+
+        let shape = Tensor::from_vec(vec![1, -5]);
+        let result = op.run(&pool, (&shape).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Shape must not contain negative sizes"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_eye_like() {
+        let pool = new_pool();
+
+        // Square, main diagonal
+        let result = eye_like::<i32>(&pool, [3, 3], 0);
+        assert_eq!(result, tensor!((3, 3); [1, 0, 0, 0, 1, 0, 0, 0, 1]));
+
+        // Non-square, diagonal above main diagonal
+        let result = eye_like::<f32>(&pool, [2, 4], 1);
+        assert_eq!(result, tensor!((2, 4); [0., 1., 0., 0., 0., 0., 1., 0.]));
+
+        // Non-square, diagonal below main diagonal
+        let result = eye_like::<i32>(&pool, [4, 2], -2);
+        assert_eq!(result, tensor!((4, 2); [0, 0, 0, 0, 1, 0, 0, 1]));
+
+        // Diagonal entirely outside the tensor
+        let result = eye_like::<i32>(&pool, [2, 2], 3);
+        assert_eq!(result, tensor!((2, 2); [0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_eye_like_op() {
+        let pool = new_pool();
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+
+        // Output type defaults to input type.
+        let op = EyeLike { dtype: None, k: 0 };
+        let result = op
+            .run(&pool, (&input).into())
+            .unwrap()
+            .remove(0)
+            .into_float()
+            .unwrap();
+        assert_eq!(result, tensor!((2, 3); [1., 0., 0., 0., 1., 0.]));
+
+        // Explicit output type.
+        let op = EyeLike {
+            dtype: Some(DataType::Int32),
+            k: 0,
+        };
+        let result = op
+            .run(&pool, (&input).into())
+            .unwrap()
+            .remove(0)
+            .into_int()
+            .unwrap();
+        assert_eq!(result, tensor!((2, 3); [1, 0, 0, 0, 1, 0]));
+
+        // Non-2D input.
+        let input = Tensor::<f32>::zeros(&[2, 3, 4]);
+        let result = op.run(&pool, (&input).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Input must be a 2D tensor"))
+        );
     }
 
     #[test]
@@ -0,0 +1,418 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView};
+
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output, ResizeMode};
+use crate::static_dims;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Specifies how [grid_sample] handles grid locations outside the input.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum GridSamplePadding {
+    /// Use zero for locations outside the input.
+    #[default]
+    Zeros,
+
+    /// Use the values at the border of the input.
+    Border,
+
+    /// Reflect locations at the border of the input until they are inside it.
+    Reflection,
+}
+
+/// Map a normalized grid coordinate in `[-1, 1]` to a pixel coordinate along
+/// an axis of size `size`.
+fn unnormalize(coord: f32, size: usize, align_corners: bool) -> f32 {
+    let size = size as f32;
+    if align_corners {
+        (coord + 1.) / 2. * (size - 1.)
+    } else {
+        ((coord + 1.) * size - 1.) / 2.
+    }
+}
+
+/// Reflect `coord` into the range `[twice_low / 2, twice_high / 2]`.
+fn reflect_coord(coord: f32, twice_low: f32, twice_high: f32) -> f32 {
+    if twice_low == twice_high {
+        return 0.;
+    }
+    let min = twice_low / 2.;
+    let span = (twice_high - twice_low) / 2.;
+    let coord = (coord - min).abs();
+    let extra = coord % span;
+    let flips = (coord / span).floor() as i64;
+    if flips % 2 == 0 {
+        extra + min
+    } else {
+        span - extra + min
+    }
+}
+
+/// Map a normalized grid coordinate to a pixel coordinate, applying the
+/// padding mode for coordinates outside the input.
+fn source_coord(coord: f32, size: usize, padding: GridSamplePadding, align_corners: bool) -> f32 {
+    let coord = unnormalize(coord, size, align_corners);
+    let max_coord = size as f32 - 1.;
+    match padding {
+        GridSamplePadding::Zeros => coord,
+        GridSamplePadding::Border => coord.clamp(0., max_coord),
+        GridSamplePadding::Reflection => {
+            let coord = if align_corners {
+                reflect_coord(coord, 0., 2. * max_coord)
+            } else {
+                reflect_coord(coord, -1., 2. * size as f32 - 1.)
+            };
+            coord.clamp(0., max_coord)
+        }
+    }
+}
+
+/// Sample pixels from an `NCHW` input at locations specified by a `grid`.
+///
+/// `grid` has shape `[N, H_out, W_out, 2]` and specifies `(x, y)` sampling
+/// locations, normalized such that `-1` and `1` refer to the left/top and
+/// right/bottom edges of the input. If `align_corners` is true, these values
+/// refer to the centers of the corner pixels instead.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__GridSample.html>.
+pub fn grid_sample(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 4>,
+    grid: NdTensorView<f32, 4>,
+    mode: ResizeMode,
+    padding: GridSamplePadding,
+    align_corners: bool,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, chans, in_h, in_w] = input.shape();
+    let [grid_batch, out_h, out_w, coord_size] = grid.shape();
+    if grid_batch != batch {
+        return Err(OpError::IncompatibleInputShapes(
+            "`grid` batch size does not match input",
+        ));
+    }
+    if coord_size != 2 {
+        return Err(OpError::InvalidValue("`grid` last dimension must be 2"));
+    }
+
+    let mut output = NdTensor::zeros_in(pool, [batch, chans, out_h, out_w]);
+    if output.is_empty() {
+        return Ok(output);
+    }
+    if in_h == 0 || in_w == 0 {
+        // All sample locations are outside the input.
+        return Ok(output);
+    }
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let in_chans = input.data().unwrap().par_chunks(in_h * in_w);
+    let out_chans = output.data_mut().unwrap().par_chunks_mut(out_h * out_w);
+
+    // Source coordinates for each output location, for each batch item.
+    let coords: Vec<(f32, f32)> = (0..batch)
+        .flat_map(|n| (0..out_h).flat_map(move |y| (0..out_w).map(move |x| (n, y, x))))
+        .map(|(n, y, x)| {
+            let src_x = source_coord(grid[[n, y, x, 0]], in_w, padding, align_corners);
+            let src_y = source_coord(grid[[n, y, x, 1]], in_h, padding, align_corners);
+            (src_x, src_y)
+        })
+        .collect();
+
+    // Get the pixel at `(y, x)`, or zero if outside the input.
+    let pixel = |chan: &[f32], y: isize, x: isize| -> f32 {
+        if (0..in_h as isize).contains(&y) && (0..in_w as isize).contains(&x) {
+            chan[y as usize * in_w + x as usize]
+        } else {
+            0.
+        }
+    };
+
+    out_chans
+        .zip(in_chans)
+        .enumerate()
+        .for_each(|(n_c, (out_chan, in_chan))| {
+            let n = n_c / chans;
+            let batch_coords = &coords[n * out_h * out_w..(n + 1) * out_h * out_w];
+            for (out, &(x, y)) in out_chan.iter_mut().zip(batch_coords) {
+                *out = match mode {
+                    ResizeMode::Nearest => {
+                        let x = x.round_ties_even() as isize;
+                        let y = y.round_ties_even() as isize;
+                        pixel(in_chan, y, x)
+                    }
+                    ResizeMode::Linear => {
+                        let x0 = x.floor();
+                        let y0 = y.floor();
+                        let wx = x - x0;
+                        let wy = y - y0;
+                        let (x0, y0) = (x0 as isize, y0 as isize);
+
+                        let top =
+                            (1. - wx) * pixel(in_chan, y0, x0) + wx * pixel(in_chan, y0, x0 + 1);
+                        let bottom = (1. - wx) * pixel(in_chan, y0 + 1, x0)
+                            + wx * pixel(in_chan, y0 + 1, x0 + 1);
+                        (1. - wy) * top + wy * bottom
+                    }
+                };
+            }
+        });
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct GridSample {
+    pub mode: ResizeMode,
+    pub padding_mode: GridSamplePadding,
+    pub align_corners: bool,
+}
+
+impl Default for GridSample {
+    fn default() -> GridSample {
+        GridSample {
+            mode: ResizeMode::Linear,
+            padding_mode: GridSamplePadding::Zeros,
+            align_corners: false,
+        }
+    }
+}
+
+impl Operator for GridSample {
+    fn name(&self) -> &str {
+        "GridSample"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as::<f32>(0)?;
+        let input = static_dims!(input, 4, "NCHW")?;
+        let grid = inputs.require_as::<f32>(1)?;
+        let grid = static_dims!(grid, 4)?;
+
+        let output = grid_sample(
+            pool,
+            input,
+            grid,
+            self.mode,
+            self.padding_mode,
+            self.align_corners,
+        )?;
+        output.into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use crate::ops::tests::{expect_eq_1e4, new_pool};
+    use crate::ops::{grid_sample, GridSample, GridSamplePadding, OpError, Operator, ResizeMode};
+
+    // Reference values for these tests can be computed with PyTorch's
+    // `torch.nn.functional.grid_sample`.
+
+    /// Sample points used by [test_grid_sample], as normalized `(x, y)` coords.
+    /// These include points inside the input, on the edges and outside it.
+    const SAMPLE_POINTS: [[f32; 2]; 6] = [
+        [-1., -1.],
+        [0., 0.],
+        [0.5, -0.25],
+        [1., 1.],
+        [1.5, 0.],
+        [-1.25, 0.75],
+    ];
+
+    #[test]
+    fn test_grid_sample() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            mode: ResizeMode,
+            padding: GridSamplePadding,
+            align_corners: bool,
+            expected: [f32; 6],
+        }
+
+        use GridSamplePadding::{Border, Reflection, Zeros};
+        use ResizeMode::{Linear, Nearest};
+
+        let cases = [
+            // Bilinear
+            Case {
+                mode: Linear,
+                padding: Zeros,
+                align_corners: false,
+                expected: [0.25, 3.5, 3.5, 1.5, 0., 0.375],
+            },
+            Case {
+                mode: Linear,
+                padding: Zeros,
+                align_corners: true,
+                expected: [1., 3.5, 3.625, 6., 2.25, 2.71875],
+            },
+            Case {
+                mode: Linear,
+                padding: Border,
+                align_corners: false,
+                expected: [1., 3.5, 3.5, 6., 4.5, 4.],
+            },
+            Case {
+                mode: Linear,
+                padding: Border,
+                align_corners: true,
+                expected: [1., 3.5, 3.625, 6., 4.5, 3.625],
+            },
+            Case {
+                mode: Linear,
+                padding: Reflection,
+                align_corners: false,
+                expected: [1., 3.5, 3.5, 6., 4.25, 4.],
+            },
+            Case {
+                mode: Linear,
+                padding: Reflection,
+                align_corners: true,
+                expected: [1., 3.5, 3.625, 6., 4., 3.875],
+            },
+            // Nearest
+            Case {
+                mode: Nearest,
+                padding: Zeros,
+                align_corners: false,
+                expected: [1., 2., 3., 0., 0., 0.],
+            },
+            Case {
+                mode: Nearest,
+                padding: Zeros,
+                align_corners: true,
+                expected: [1., 2., 3., 6., 3., 4.],
+            },
+            Case {
+                mode: Nearest,
+                padding: Border,
+                align_corners: false,
+                expected: [1., 2., 3., 6., 3., 4.],
+            },
+            Case {
+                mode: Nearest,
+                padding: Reflection,
+                align_corners: false,
+                expected: [1., 2., 3., 6., 3., 4.],
+            },
+        ];
+
+        let pool = new_pool();
+        let input = NdTensor::from_data([1, 1, 2, 3], vec![1., 2., 3., 4., 5., 6.]);
+        let grid = NdTensor::from_data([1, 1, 6, 2], SAMPLE_POINTS.concat());
+
+        for Case {
+            mode,
+            padding,
+            align_corners,
+            expected,
+        } in cases
+        {
+            let result = grid_sample(
+                &pool,
+                input.view(),
+                grid.view(),
+                mode,
+                padding,
+                align_corners,
+            )?;
+            let expected = NdTensor::from_data([1, 1, 1, 6], expected.to_vec());
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_sample_identity() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // Input with multiple batch items and channels.
+        let input = NdTensor::from_fn([2, 3, 4, 5], |[n, c, y, x]| {
+            (n * 1000 + c * 100 + y * 10 + x) as f32
+        });
+
+        // With `align_corners`, a grid that spans `[-1, 1]` evenly on each
+        // axis samples exactly at pixel centers.
+        let [batch, _chans, height, width] = input.shape();
+        let grid = NdTensor::from_fn([batch, height, width, 2], |[_n, y, x, coord]| {
+            if coord == 0 {
+                -1. + 2. * x as f32 / (width - 1) as f32
+            } else {
+                -1. + 2. * y as f32 / (height - 1) as f32
+            }
+        });
+
+        for mode in [ResizeMode::Nearest, ResizeMode::Linear] {
+            let result = grid_sample(
+                &pool,
+                input.view(),
+                grid.view(),
+                mode,
+                GridSamplePadding::Zeros,
+                true, /* align_corners */
+            )?;
+            expect_eq_1e4(&result, &input)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_sample_op() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 1, 2, 2], vec![1., 2., 3., 4.]);
+        let grid = Tensor::from_data(&[1, 1, 1, 2], vec![0., 0.]);
+
+        let op = GridSample::default();
+        let result = op
+            .run(&pool, (&input, &grid).into())?
+            .remove(0)
+            .into_float()
+            .unwrap();
+
+        assert_eq!(result.shape(), &[1, 1, 1, 1]);
+        assert_eq!(result.to_vec(), &[2.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_sample_invalid() {
+        let pool = new_pool();
+        let input = NdTensor::<f32, 4>::zeros([1, 1, 2, 2]);
+
+        let grid = NdTensor::zeros([2, 1, 1, 2]);
+        let result = grid_sample(
+            &pool,
+            input.view(),
+            grid.view(),
+            ResizeMode::Linear,
+            GridSamplePadding::Zeros,
+            false,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`grid` batch size does not match input"
+            ))
+        );
+
+        let grid = NdTensor::zeros([1, 1, 1, 3]);
+        let result = grid_sample(
+            &pool,
+            input.view(),
+            grid.view(),
+            ResizeMode::Linear,
+            GridSamplePadding::Zeros,
+            false,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("`grid` last dimension must be 2"))
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::graph::Dimension;
+use crate::map_input;
+use crate::ops::shape_inference::infer_unary_shape;
+use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, SymbolicShape};
 use crate::tensor_pool::TensorPool;
 
-fn identity<T: Copy>(pool: &TensorPool, src: TensorView<T>) -> Tensor<T> {
+fn identity<T: Clone>(pool: &TensorPool, src: TensorView<T>) -> Tensor<T> {
     src.to_tensor_in(pool)
 }
 
@@ -19,8 +22,9 @@ impl Operator for Identity {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let result: Output = match input {
-            Input::IntTensor(t) => identity(pool, t).into(),
-            Input::FloatTensor(t) => identity(pool, t).into(),
+            Input::StringTensor(t) => identity(pool, t).into(),
+            Input::Sequence(seq) => seq.clone().into(),
+            input => map_input!(input, t, { identity(pool, t).into() }),
         };
         result.into_op_result()
     }
@@ -29,6 +33,13 @@ impl Operator for Identity {
         true
     }
 
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
     fn run_in_place(
         &self,
         _pool: &TensorPool,
@@ -37,17 +48,186 @@ impl Operator for Identity {
     ) -> Result<Output, OpError> {
         Ok(input)
     }
+
+    fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        match inputs.require(0)? {
+            Input::Sequence(_) => Ok(None),
+            input => Ok(Some(input.shape().to_vec())),
+        }
+    }
+}
+
+/// Dropout operator.
+///
+/// In inference mode this is an identity operation. The optional second
+/// output is a mask of retained elements, which is all ones. The mask is only
+/// computed if it is used.
+///
+/// In training mode, which is enabled by the `training_mode` input, elements
+/// are randomly set to zero with probability `ratio` and the remaining
+/// elements are scaled by `1 / (1 - ratio)`. This requires the `random`
+/// feature.
+#[derive(Debug)]
+pub struct Dropout {
+    /// Seed for the random number generator used in training mode.
+    pub seed: Option<i32>,
+}
+
+impl Dropout {
+    /// Return the probability of dropping elements, given the `ratio` and
+    /// `training_mode` inputs.
+    ///
+    /// Returns `None` if dropout is disabled, in which case the operator is an
+    /// identity operation.
+    fn drop_ratio(ratio: Option<f32>, training_mode: Option<i32>) -> Result<Option<f32>, OpError> {
+        if training_mode.unwrap_or(0) == 0 {
+            return Ok(None);
+        }
+        let ratio = ratio.unwrap_or(0.5);
+        if !(0. ..1.).contains(&ratio) {
+            return Err(OpError::InvalidValue("Dropout ratio must be in [0, 1)"));
+        }
+        Ok(Some(ratio).filter(|&ratio| ratio > 0.))
+    }
+
+    /// Randomly zero elements of `output` and scale the remaining elements.
+    ///
+    /// Returns a mask which is 1 for retained elements and 0 otherwise.
+    #[cfg(feature = "random")]
+    fn drop_elements(
+        &self,
+        pool: &TensorPool,
+        output: &mut Tensor<f32>,
+        ratio: f32,
+    ) -> Result<Tensor<i32>, OpError> {
+        let mut rng = match self.seed {
+            Some(seed) => fastrand::Rng::with_seed(seed as u64),
+            None => fastrand::Rng::new(),
+        };
+        let scale = 1. / (1. - ratio);
+        let mut mask = Tensor::zeros_in(pool, output.shape());
+        for (x, keep) in output.iter_mut().zip(mask.iter_mut()) {
+            if rng.f32() >= ratio {
+                *x *= scale;
+                *keep = 1;
+            } else {
+                *x = 0.;
+            }
+        }
+        Ok(mask)
+    }
+
+    #[cfg(not(feature = "random"))]
+    fn drop_elements(
+        &self,
+        _pool: &TensorPool,
+        _output: &mut Tensor<f32>,
+        _ratio: f32,
+    ) -> Result<Tensor<i32>, OpError> {
+        Err(OpError::UnsupportedValue(
+            "Dropout training mode requires the `random` feature",
+        ))
+    }
+}
+
+impl Operator for Dropout {
+    fn name(&self) -> &str {
+        "Dropout"
+    }
+
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(Dropout {
+            seed: Some((seed >> 32) as i32),
+        }))
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        self.run_with_used_outputs(pool, inputs, &[true, true])
+    }
+
+    fn run_with_used_outputs(
+        &self,
+        pool: &TensorPool,
+        inputs: InputList,
+        used_outputs: &[bool],
+    ) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        let ratio = Self::drop_ratio(inputs.get_as_scalar(1)?, inputs.get_as_scalar(2)?)?;
+        let mask_used = used_outputs.get(1).copied().unwrap_or(false);
+
+        if let Some(ratio) = ratio {
+            let input: TensorView<f32> = input.try_into()?;
+            let mut output = input.to_tensor_in(pool);
+            let mask = self.drop_elements(pool, &mut output, ratio)?;
+            let mut outputs = vec![output.into()];
+            if mask_used {
+                outputs.push(mask.into());
+            }
+            return Ok(outputs);
+        }
+
+        let shape = input.shape().to_vec();
+        let output: Output = match input {
+            Input::StringTensor(t) => identity(pool, t).into(),
+            Input::Sequence(_) => return Err(OpError::IncorrectInputType),
+            input => map_input!(input, t, { identity(pool, t).into() }),
+        };
+        let mut outputs = vec![output];
+
+        if mask_used {
+            let mask = Tensor::<i32>::full_in(pool, &shape, 1);
+            outputs.push(mask.into());
+        }
+
+        Ok(outputs)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        // The mask has the same shape as the output.
+        Ok(infer_unary_shape(inputs).map(|mut shapes| {
+            shapes.push(shapes[0].clone());
+            shapes
+        }))
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        input: Output,
+        other: InputList,
+    ) -> Result<Output, OpError> {
+        // `other` excludes the first input, so `ratio` and `training_mode` are
+        // at indices 0 and 1.
+        let Some(ratio) = Self::drop_ratio(other.get_as_scalar(0)?, other.get_as_scalar(1)?)?
+        else {
+            return Ok(input);
+        };
+        let mut output: Tensor<f32> = input.try_into()?;
+        self.drop_elements(pool, &mut output, ratio)?;
+        Ok(output.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
+    use rten_tensor::prelude::*;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::Tensor;
+    use rten_tensor::{tensor, Tensor};
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{Identity, Operator};
+    use crate::ops::{Dropout, Identity, InputList, OpError, Operator};
 
     #[test]
     fn test_identity() -> Result<(), Box<dyn Error>> {
@@ -74,4 +254,87 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_dropout() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let op = Dropout { seed: None };
+        let input = tensor!((2, 2); [1., 2., 3., 4.]);
+
+        // When the mask is not used, it is not computed.
+        let results = op.run_with_used_outputs(&pool, (&input).into(), &[true, false])?;
+        assert_eq!(results.len(), 1);
+        expect_equal(results[0].as_float_ref().unwrap(), &input)?;
+
+        // When the mask is used, all elements are retained.
+        let results = op.run_with_used_outputs(&pool, (&input).into(), &[true, true])?;
+        assert_eq!(results.len(), 2);
+        expect_equal(results[0].as_float_ref().unwrap(), &input)?;
+        assert_eq!(
+            results[1].as_int_ref().unwrap(),
+            &tensor!((2, 2); [1, 1, 1, 1])
+        );
+
+        // Training mode with a zero ratio is an identity operation.
+        let training_mode = tensor!(1);
+        let ratio = tensor!(0.);
+        let results = op.run(
+            &pool,
+            InputList::from(&[(&input).into(), (&ratio).into(), (&training_mode).into()]),
+        )?;
+        expect_equal(results[0].as_float_ref().unwrap(), &input)?;
+        assert_eq!(
+            results[1].as_int_ref().unwrap(),
+            &tensor!((2, 2); [1, 1, 1, 1])
+        );
+
+        // Invalid ratio.
+        let ratio = tensor!(1.);
+        let result = op.run(
+            &pool,
+            InputList::from(&[(&input).into(), (&ratio).into(), (&training_mode).into()]),
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Dropout ratio must be in [0, 1)"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dropout_training_mode() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let op = Dropout { seed: Some(1234) };
+        let input = Tensor::full(&[100], 1.);
+        let ratio = tensor!(0.5);
+        let training_mode = tensor!(1);
+        let inputs = [(&input).into(), (&ratio).into(), (&training_mode).into()];
+
+        let results = op.run(&pool, InputList::from(&inputs))?;
+        let output = results[0].as_float_ref().unwrap();
+        let mask = results[1].as_int_ref().unwrap();
+
+        // Retained elements are scaled by `1 / (1 - ratio)` and the others are
+        // zeroed.
+        for (&x, &keep) in output.iter().zip(mask.iter()) {
+            assert_eq!(x, if keep == 1 { 2. } else { 0. });
+        }
+        let n_kept = mask.iter().filter(|&&keep| keep == 1).count();
+        assert!((30..70).contains(&n_kept), "kept {} of 100", n_kept);
+
+        // Results are reproducible with a fixed seed.
+        let results_2 = op.run(&pool, InputList::from(&inputs))?;
+        assert_eq!(results_2[1].as_int_ref().unwrap(), mask);
+
+        // In-place execution gives the same output.
+        let in_place_output = op.run_in_place(
+            &pool,
+            input.clone().into(),
+            InputList::from(&[(&ratio).into(), (&training_mode).into()]),
+        )?;
+        assert_eq!(in_place_output.as_float_ref().unwrap(), output);
+
+        Ok(())
+    }
 }
@@ -7,18 +7,78 @@ use rten_tensor::{is_valid_permutation, NdTensorView, Tensor, TensorView};
 use smallvec::SmallVec;
 
 use crate::ops::binary_elementwise::{broadcast_shapes, fast_broadcast_cycles_repeats};
+use crate::ops::gather;
 use crate::ops::{
-    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    index_to_i32, resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator,
+    Output,
 };
-use crate::static_dims;
-use crate::tensor_pool::TensorPool;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{map_input, map_output, static_dims};
+
+/// Shuffle the channels of an `NC*` tensor, as used in ShuffleNet.
+///
+/// The channels are divided into `groups` groups, and the output interleaves
+/// channels from each group. This is equivalent to reshaping the input to
+/// `[N, groups, C / groups, ...]`, swapping the second and third dimensions
+/// and then reshaping back to the original shape.
+pub fn channel_shuffle<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    groups: usize,
+) -> Result<Tensor<T>, OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    let chans = input.size(1);
+    if groups == 0 || chans % groups != 0 {
+        return Err(OpError::InvalidValue(
+            "Channel count must be divisible by group count",
+        ));
+    }
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let grouped_shape: SmallVec<[usize; 5]> = [input.size(0), groups, chans / groups]
+        .into_iter()
+        .chain(input.shape()[2..].iter().copied())
+        .collect();
+    let mut grouped = input.reshaped(grouped_shape.as_slice());
+    grouped.move_axis(1, 2);
+
+    let mut output = Tensor::uninit_in(pool, grouped.shape()).init_from(&grouped);
+    output.reshape(input.shape());
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct ChannelShuffle {
+    pub groups: usize,
+}
+
+impl Operator for ChannelShuffle {
+    fn name(&self) -> &str {
+        "ChannelShuffle"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        map_input!(input, input, {
+            channel_shuffle(pool, input, self.groups).into_op_result()
+        })
+    }
+}
 
 /// Return the tensor shape resulting from broadcasting `input_shape` with `shape`.
 fn expand_output_shape(
     input_shape: &[usize],
     shape: &NdTensorView<i32, 1>,
 ) -> Result<Vec<usize>, OpError> {
-    let shape_vec: Vec<_> = shape.iter().map(|el| *el as usize).collect();
+    let shape_vec: Vec<usize> = shape
+        .iter()
+        .map(|&el| {
+            el.try_into()
+                .map_err(|_| OpError::InvalidValue("Shape must not contain negative sizes"))
+        })
+        .collect::<Result<_, _>>()?;
     broadcast_shapes(input_shape, &shape_vec).ok_or(OpError::IncompatibleInputShapes(
         "Cannot broadcast input with target shape",
     ))
@@ -93,10 +153,9 @@ impl Operator for Expand {
         let shape = inputs.require_as(1)?;
         let shape = static_dims!(shape, 1)?;
 
-        match input {
-            Input::FloatTensor(input) => expand(pool, input, &shape).into_op_result(),
-            Input::IntTensor(input) => expand(pool, input, &shape).into_op_result(),
-        }
+        map_input!(input, input, {
+            expand(pool, input, &shape).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -105,6 +164,16 @@ impl Operator for Expand {
         true
     }
 
+    fn broadcast_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = inputs.require(0)?;
+        if matches!(input, Input::Sequence(_)) {
+            return Err(OpError::IncorrectInputType);
+        }
+        let shape = inputs.require_as(1)?;
+        let shape = static_dims!(shape, 1)?;
+        expand_output_shape(input.shape(), &shape).map(Some)
+    }
+
     fn run_in_place(
         &self,
         pool: &TensorPool,
@@ -119,14 +188,22 @@ impl Operator for Expand {
             return Ok(input);
         }
 
-        let output: Output = match input {
-            Output::FloatTensor(input) => expand_to(pool, input.view(), &out_shape).into(),
-            Output::IntTensor(input) => expand_to(pool, input.view(), &out_shape).into(),
-        };
+        let output: Output = map_output!(input, input, {
+            expand_to(pool, input.view(), &out_shape).into()
+        });
         Ok(output)
     }
 }
 
+/// Return the first input of a layout operator, which must be a tensor.
+fn tensor_input<'a>(inputs: &InputList<'a>) -> Result<Input<'a>, OpError> {
+    let input = inputs.require(0)?;
+    if matches!(input, Input::Sequence(_)) {
+        return Err(OpError::IncorrectInputType);
+    }
+    Ok(input)
+}
+
 fn flattened_shape(shape: &[usize], axis: isize) -> Result<[usize; 2], OpError> {
     let resolved_axis = resolve_axis(shape.len(), axis)?;
     let outer_size = shape.iter().take(resolved_axis).product();
@@ -168,10 +245,9 @@ impl Operator for Flatten {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
 
-        match input {
-            Input::FloatTensor(input) => flatten(pool, input, self.axis).into_op_result(),
-            Input::IntTensor(input) => flatten(pool, input, self.axis).into_op_result(),
-        }
+        map_input!(input, input, {
+            flatten(pool, input, self.axis).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -184,16 +260,54 @@ impl Operator for Flatten {
         input: Output,
         _: InputList,
     ) -> Result<Output, OpError> {
-        match input {
-            Output::IntTensor(mut output) => {
-                flatten_in_place(pool, &mut output, self.axis)?;
-                Ok(output.into())
-            }
-            Output::FloatTensor(mut output) => {
-                flatten_in_place(pool, &mut output, self.axis)?;
-                Ok(output.into())
-            }
-        }
+        map_output!(input, mut output, {
+            flatten_in_place(pool, &mut output, self.axis)?;
+            Ok(output.into())
+        })
+    }
+
+    fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = tensor_input(&inputs)?;
+        flattened_shape(input.shape(), self.axis).map(|shape| Some(shape.to_vec()))
+    }
+}
+
+/// Reorder or select channels of an `NC*` tensor.
+///
+/// Channel `i` of the output is channel `order[i]` of the input. For example
+/// an `order` of `[2, 1, 0]` converts an RGB image to BGR and vice versa.
+pub fn permute_channels<T: Copy + Default>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    order: &[usize],
+) -> Result<Tensor<T>, OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    let chans = input.size(1);
+    if order.iter().any(|&c| c >= chans) {
+        return Err(OpError::InvalidValue("Channel index is out of range"));
+    }
+    let indices: Vec<i32> = order.iter().map(|&c| c as i32).collect();
+    let indices = Tensor::from_data(&[indices.len()], indices);
+    gather(pool, input, 1, indices.view())
+}
+
+#[derive(Debug)]
+pub struct PermuteChannels {
+    pub order: Vec<usize>,
+}
+
+impl Operator for PermuteChannels {
+    fn name(&self) -> &str {
+        "PermuteChannels"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        map_input!(input, input, {
+            permute_channels(pool, input, &self.order).into_op_result()
+        })
     }
 }
 
@@ -260,6 +374,12 @@ fn resolve_shape(
         ));
     }
 
+    if unspecified_dim.is_none() && specified_dims_size != input_len {
+        return Err(OpError::InvalidValue(
+            "Output length must match input length",
+        ));
+    }
+
     Ok(shape
         .iter()
         .enumerate()
@@ -305,13 +425,12 @@ impl Operator for Reshape {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
-        let shape = inputs.require_as(1)?;
+        let shape = inputs.require_as_index(1)?;
         let shape = static_dims!(shape, 1)?;
 
-        match input {
-            Input::IntTensor(t) => reshape(pool, t, &shape, self.allow_zero).into_op_result(),
-            Input::FloatTensor(t) => reshape(pool, t, &shape, self.allow_zero).into_op_result(),
-        }
+        map_input!(input, t, {
+            reshape(pool, t, &shape, self.allow_zero).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -324,19 +443,20 @@ impl Operator for Reshape {
         input: Output,
         other: InputList,
     ) -> Result<Output, OpError> {
-        let shape = other.require_as(0)?;
+        let shape = other.require_as_index(0)?;
         let shape = static_dims!(shape, 1)?;
 
-        match input {
-            Output::IntTensor(mut output) => {
-                reshape_in_place(pool, &mut output, &shape, self.allow_zero)?;
-                Ok(output.into())
-            }
-            Output::FloatTensor(mut output) => {
-                reshape_in_place(pool, &mut output, &shape, self.allow_zero)?;
-                Ok(output.into())
-            }
-        }
+        map_output!(input, mut output, {
+            reshape_in_place(pool, &mut output, &shape, self.allow_zero)?;
+            Ok(output.into())
+        })
+    }
+
+    fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = tensor_input(&inputs)?;
+        let shape = inputs.require_as_index(1)?;
+        let shape = static_dims!(shape, 1)?;
+        resolve_shape(input.shape(), &shape, self.allow_zero).map(|shape| Some(shape.to_vec()))
     }
 }
 
@@ -354,7 +474,9 @@ impl Operator for Shape {
         // Allocate output from pool for consistency with other operators,
         // even though the buffer is tiny, so there is no performance benefit.
         let mut data = pool.alloc(input.ndim());
-        data.extend(input.shape().iter().map(|&el| el as i32));
+        for &size in input.shape() {
+            data.push(index_to_i32(size)?);
+        }
 
         let shape = Tensor::from_data(&[input.ndim()], data);
         shape.into_op_result()
@@ -371,7 +493,7 @@ impl Operator for Size {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
-        let len = input.len() as i32;
+        let len = index_to_i32(input.len())?;
 
         // Allocate output from pool for consistency with other operators,
         // even though the buffer is tiny, so there is no performance benefit.
@@ -382,19 +504,20 @@ impl Operator for Size {
     }
 }
 
-pub fn squeeze_in_place<T: Clone>(
-    input: &mut Tensor<T>,
+/// Return the shape of the output of a `Squeeze` operation.
+fn squeezed_shape(
+    shape: &[usize],
     axes: Option<NdTensorView<i32, 1>>,
-) -> Result<(), OpError> {
+) -> Result<SmallVec<[usize; 5]>, OpError> {
     let axes = axes
-        .map(|axes| resolve_axes(input.ndim(), axes.iter()))
+        .map(|axes| resolve_axes(shape.len(), axes.iter()))
         .transpose()?;
     if let Some(ref axes) = axes {
         for &axis in axes.iter() {
-            if axis >= input.ndim() {
+            if axis >= shape.len() {
                 return Err(OpError::InvalidValue("Axis is invalid"));
             }
-            if input.size(axis) != 1 {
+            if shape[axis] != 1 {
                 return Err(OpError::InvalidValue(
                     "Can only remove dimensions of size 1",
                 ));
@@ -402,19 +525,25 @@ pub fn squeeze_in_place<T: Clone>(
         }
     }
 
-    let new_shape: SmallVec<[usize; 5]> = input
-        .shape()
+    Ok(shape
         .iter()
         .enumerate()
         .filter(|(dim, &size)| {
             if let Some(ref axes) = axes {
                 !axes.contains(dim)
             } else {
-                size > 1
+                size != 1
             }
         })
         .map(|(_, &size)| size)
-        .collect();
+        .collect())
+}
+
+pub fn squeeze_in_place<T: Clone>(
+    input: &mut Tensor<T>,
+    axes: Option<NdTensorView<i32, 1>>,
+) -> Result<(), OpError> {
+    let new_shape = squeezed_shape(input.shape(), axes)?;
     input.reshape(&new_shape);
     Ok(())
 }
@@ -442,10 +571,7 @@ impl Operator for Squeeze {
         let axes = inputs.get_as(1)?;
         let axes = axes.map(|axes| static_dims!(axes, 1)).transpose()?;
 
-        match input {
-            Input::FloatTensor(t) => squeeze(pool, t, axes).into_op_result(),
-            Input::IntTensor(t) => squeeze(pool, t, axes).into_op_result(),
-        }
+        map_input!(input, t, { squeeze(pool, t, axes).into_op_result() })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -461,18 +587,19 @@ impl Operator for Squeeze {
         let axes = other.get_as(0)?;
         let axes = axes.map(|axes| static_dims!(axes, 1)).transpose()?;
 
-        let result = match input {
-            Output::FloatTensor(mut t) => {
-                squeeze_in_place(&mut t, axes)?;
-                t.into()
-            }
-            Output::IntTensor(mut t) => {
-                squeeze_in_place(&mut t, axes)?;
-                t.into()
-            }
-        };
+        let result = map_output!(input, mut t, {
+            squeeze_in_place(&mut t, axes)?;
+            t.into()
+        });
         Ok(result)
     }
+
+    fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = tensor_input(&inputs)?;
+        let axes = inputs.get_as(1)?;
+        let axes = axes.map(|axes| static_dims!(axes, 1)).transpose()?;
+        squeezed_shape(input.shape(), axes).map(|shape| Some(shape.to_vec()))
+    }
 }
 
 pub fn transpose<T: Copy>(
@@ -511,19 +638,34 @@ impl Operator for Transpose {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let perm_slice = self.perm.as_deref();
-        match input {
-            Input::FloatTensor(input) => transpose(pool, input, perm_slice).into_op_result(),
-            Input::IntTensor(input) => transpose(pool, input, perm_slice).into_op_result(),
+        map_input!(input, input, {
+            transpose(pool, input, perm_slice).into_op_result()
+        })
+    }
+
+    fn transpose_perm(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = inputs.require(0)?;
+        if matches!(input, Input::Sequence(_)) {
+            return Err(OpError::IncorrectInputType);
+        }
+        let ndim = input.ndim();
+        match &self.perm {
+            Some(perm) if !is_valid_permutation(ndim, perm) => {
+                Err(OpError::InvalidValue("Permutation is invalid"))
+            }
+            Some(perm) => Ok(Some(perm.clone())),
+            None => Ok(Some((0..ndim).rev().collect())),
         }
     }
 }
 
-pub fn unsqueeze_in_place<T: Clone>(
-    mut input: Tensor<T>,
+/// Return the shape of the output of an `Unsqueeze` operation.
+fn unsqueezed_shape(
+    shape: &[usize],
     axes: &NdTensorView<i32, 1>,
-) -> Result<Tensor<T>, OpError> {
-    let mut new_shape: SmallVec<[usize; 5]> = input.shape().iter().copied().collect();
-    let mut sorted_axes = resolve_axes(input.ndim() + axes.len(), axes.iter())?;
+) -> Result<SmallVec<[usize; 5]>, OpError> {
+    let mut new_shape: SmallVec<[usize; 5]> = shape.iter().copied().collect();
+    let mut sorted_axes = resolve_axes(shape.len() + axes.len(), axes.iter())?;
     sorted_axes.sort();
 
     let axes_unique =
@@ -535,6 +677,14 @@ pub fn unsqueeze_in_place<T: Clone>(
     for axis in sorted_axes {
         new_shape.insert(axis, 1);
     }
+    Ok(new_shape)
+}
+
+pub fn unsqueeze_in_place<T: Clone>(
+    mut input: Tensor<T>,
+    axes: &NdTensorView<i32, 1>,
+) -> Result<Tensor<T>, OpError> {
+    let new_shape = unsqueezed_shape(input.shape(), axes)?;
 
     input.make_contiguous();
     input.reshape(&new_shape);
@@ -563,10 +713,9 @@ impl Operator for Unsqueeze {
         let axes = inputs.require_as(1)?;
         let axes = static_dims!(axes, 1)?;
 
-        match input {
-            Input::FloatTensor(input) => unsqueeze(pool, input, &axes).into_op_result(),
-            Input::IntTensor(input) => unsqueeze(pool, input, &axes).into_op_result(),
-        }
+        map_input!(input, input, {
+            unsqueeze(pool, input, &axes).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -582,10 +731,16 @@ impl Operator for Unsqueeze {
         let axes = inputs.require_as(0)?;
         let axes = static_dims!(axes, 1)?;
 
-        match output {
-            Output::FloatTensor(t) => unsqueeze_in_place(t, &axes).map(Output::FloatTensor),
-            Output::IntTensor(t) => unsqueeze_in_place(t, &axes).map(Output::IntTensor),
-        }
+        map_output!(output, t, {
+            unsqueeze_in_place(t, &axes).map(|t| t.into())
+        })
+    }
+
+    fn reshape_shape(&self, inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        let input = tensor_input(&inputs)?;
+        let axes = inputs.require_as(1)?;
+        let axes = static_dims!(axes, 1)?;
+        unsqueezed_shape(input.shape(), &axes).map(|shape| Some(shape.to_vec()))
     }
 }
 
@@ -597,14 +752,48 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::{ndtensor, tensor, Tensor};
+    use rten_tensor::{ndtensor, tensor, NdTensor, Tensor};
 
     use crate::ops::layout::{
-        expand, flatten, reshape, reshape_in_place, squeeze, squeeze_in_place, transpose,
-        unsqueeze, Reshape, Shape, Size,
+        channel_shuffle, expand, flatten, permute_channels, reshape, reshape_in_place, squeeze,
+        squeeze_in_place, transpose, unsqueeze, Flatten, Reshape, Shape, Size, Squeeze, Transpose,
+        Unsqueeze,
     };
     use crate::ops::tests::new_pool;
-    use crate::ops::{OpError, Operator};
+    use crate::ops::{Identity, InputList, OpError, Operator};
+
+    #[test]
+    fn test_channel_shuffle() {
+        let pool = new_pool();
+
+        // NCHW input with 6 channels, each filled with its channel index.
+        let input = Tensor::from_fn(&[2, 6, 2, 2], |idx| idx[1] as i32);
+
+        let result = channel_shuffle(&pool, input.view(), 2).unwrap();
+        assert_eq!(result.shape(), input.shape());
+        for item in result.axis_iter(0) {
+            let chans: Vec<i32> = item.axis_iter(0).map(|chan| chan[[0, 0]]).collect();
+            assert_eq!(chans, &[0, 3, 1, 4, 2, 5]);
+        }
+
+        // One group leaves the input unchanged.
+        let result = channel_shuffle(&pool, input.view(), 1).unwrap();
+        assert_eq!(result, input);
+
+        let result = channel_shuffle(&pool, input.view(), 4);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Channel count must be divisible by group count"
+            ))
+        );
+
+        let result = channel_shuffle(&pool, tensor!([1, 2, 3]).view(), 1);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Input must have at least 2 dims"))
+        );
+    }
 
     #[test]
     fn test_expand() {
@@ -665,6 +854,16 @@ mod tests {
                 "Cannot broadcast input with target shape"
             ))
         );
+
+        // Negative size in target shape
+        let shape = ndtensor!([-1, 3]);
+        let result = expand(&pool, input.view(), &shape.view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Shape must not contain negative sizes"
+            ))
+        );
     }
 
     #[test]
@@ -684,6 +883,26 @@ mod tests {
         assert_eq!(result.shape(), &[1, 24]);
     }
 
+    #[test]
+    fn test_permute_channels() {
+        let pool = new_pool();
+
+        // RGB to BGR
+        let input = tensor!((1, 3, 1, 2); [1., 2., 3., 4., 5., 6.]);
+        let result = permute_channels(&pool, input.view(), &[2, 1, 0]).unwrap();
+        assert_eq!(result, tensor!((1, 3, 1, 2); [5., 6., 3., 4., 1., 2.]));
+
+        // Select and duplicate channels
+        let result = permute_channels(&pool, input.view(), &[1, 1]).unwrap();
+        assert_eq!(result, tensor!((1, 2, 1, 2); [3., 4., 3., 4.]));
+
+        let result = permute_channels(&pool, input.view(), &[3]);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Channel index is out of range"))
+        );
+    }
+
     #[test]
     fn test_reshape_with_unspecified_dim() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -826,6 +1045,22 @@ mod tests {
             true, /* allow_zero */
         );
         assert_eq!(result.err(), expected_err);
+
+        // Case when there is no unspecified dim and the lengths differ
+        let input = Tensor::from_data(&[1, 2], vec![1, 2]);
+        let shape = NdTensor::from_data([0], vec![]);
+        let result = reshape(
+            &pool,
+            input.view(),
+            &shape.view(),
+            false, /* allow_zero */
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Output length must match input length"
+            ))
+        );
     }
 
     #[test]
@@ -861,9 +1096,45 @@ mod tests {
 
         expect_equal(&result, &expected)?;
 
+        // Int64 data and shape.
+        let input = Tensor::from_data(&[2, 2], vec![1i64, 2, 3, 4]);
+        let shape = Tensor::from([-1i64]);
+        let result = op
+            .run(&pool, (&input, &shape).into())
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result, Tensor::from([1i64, 2, 3, 4]));
+
         Ok(())
     }
 
+    #[test]
+    fn test_reshape_shape() {
+        let input = Tensor::<f32>::zeros(&[2, 1, 3]);
+        let shape = tensor!([3, -1]);
+        let axes = tensor!([0]);
+
+        let check = |op: &dyn Operator, inputs: InputList, expected: &[usize]| {
+            let shape = op.reshape_shape(inputs).unwrap();
+            assert_eq!(shape.as_deref(), Some(expected), "{}", op.name());
+        };
+        check(
+            &Reshape { allow_zero: false },
+            (&input, &shape).into(),
+            &[3, 2],
+        );
+        check(&Flatten { axis: 2 }, (&input).into(), &[2, 3]);
+        check(&Squeeze {}, (&input).into(), &[2, 3]);
+        check(&Unsqueeze {}, (&input, &axes).into(), &[1, 2, 1, 3]);
+        check(&Identity {}, (&input).into(), &[2, 1, 3]);
+
+        // Transposing changes the order of elements, so it is not a reshape.
+        let op = Transpose { perm: None };
+        assert_eq!(op.reshape_shape((&input).into()).unwrap(), None);
+    }
+
     #[test]
     fn test_shape() {
         let pool = new_pool();
@@ -890,6 +1161,15 @@ mod tests {
             .unwrap();
         assert_eq!(result.shape(), &[4]);
         assert_eq!(result.to_vec(), &[1, 1, 2, 2]);
+
+        // Input with a dimension that is too large for the output.
+        let input = tensor!(1.0);
+        let input = input.broadcast([1, 1 << 31]);
+        let result = op.run(&pool, input.into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue("Size or index exceeds i32::MAX"))
+        );
     }
 
     #[test]
@@ -905,6 +1185,15 @@ mod tests {
             .unwrap();
         assert_eq!(result.ndim(), 0);
         assert_eq!(result.item(), Some(&4));
+
+        // Input with more elements than can be represented in the output.
+        let input = tensor!(1.0);
+        let input = input.broadcast([1 << 16, 1 << 16]);
+        let result = op.run(&pool, input.into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue("Size or index exceeds i32::MAX"))
+        );
     }
 
     #[test]
@@ -930,6 +1219,11 @@ mod tests {
         let result = squeeze(&pool, input.view(), Some(ndtensor!([0]).view())).unwrap();
         expect_equal(&result, &expected)?;
 
+        // Zero-size axes are preserved.
+        let input = Tensor::<f32>::zeros(&[1, 0, 1, 3]);
+        let result = squeeze(&pool, input.view(), None).unwrap();
+        assert_eq!(result.shape(), &[0, 3]);
+
         Ok(())
     }
 
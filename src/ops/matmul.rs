@@ -1,13 +1,22 @@
 use rayon::prelude::*;
+use smallvec::SmallVec;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{Tensor, TensorView};
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 
 use crate::check_dims;
-use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB, PackedBMatrix};
+use crate::graph::Dimension;
+use crate::number::BF16;
 use crate::ops::binary_elementwise::broadcast_shapes;
 use crate::ops::layout::expand_to;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::quantize::BlockQuantizedMatrix;
+use crate::ops::shape_inference::infer_matmul_shape;
+use crate::ops::{
+    Input, InputList, IntoOpResult, OpError, Operator, Output, PrepackedInput, SymbolicShape,
+};
+use crate::sparse::{self, sparse_matmul, CsrMatrix, SPARSE_DENSITY_THRESHOLD};
+use crate::static_dims;
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
 #[derive(Debug)]
@@ -40,6 +49,12 @@ pub fn gemm_op(
     let a = if transpose_a { a.transposed() } else { a };
     let b = if transpose_b { b.transposed() } else { b };
 
+    if a.size(1) != b.size(0) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+
     let out_shape = &[a.size(0), b.size(1)][..];
     let gemm = GemmExecutor::new();
 
@@ -101,6 +116,11 @@ impl Operator for Gemm {
         )
         .into_op_result()
     }
+
+    fn supports_transposed_inputs(&self) -> bool {
+        // The GEMM packing code handles inputs with arbitrary strides.
+        true
+    }
 }
 
 /// Hints for how a batched MatMul should be performed. This exists to enable
@@ -117,13 +137,54 @@ enum MatmulStrategy {
 }
 
 pub fn matmul(pool: &TensorPool, a: TensorView, b: TensorView) -> Result<Tensor, OpError> {
-    matmul_impl(pool, a, b, MatmulStrategy::Auto)
+    matmul_impl(pool, a, b, None, MatmulStrategy::Auto)
 }
 
+/// Multiply two matrices.
+///
+/// This is a variant of [matmul] for the common case of 2D inputs, where the
+/// ranks of the inputs and output are checked at compile time.
+pub fn matmul_2d(
+    pool: &TensorPool,
+    a: NdTensorView<f32, 2>,
+    b: NdTensorView<f32, 2>,
+) -> Result<NdTensor<f32, 2>, OpError> {
+    let [a_rows, a_cols] = a.shape();
+    let [b_rows, b_cols] = b.shape();
+
+    if a_cols != b_rows {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+
+    let mut output = NdTensor::uninit_in(pool, [a_rows, b_cols]);
+    if output.is_empty() {
+        return Ok(NdTensor::zeros([a_rows, b_cols]));
+    }
+
+    let out_row_stride = output.stride(0);
+    GemmExecutor::new().gemm_uninit(
+        output.data_mut().unwrap(),
+        out_row_stride,
+        GemmInputA::Unpacked(a),
+        GemmInputB::Unpacked(b),
+        1., // alpha
+    );
+
+    // Safety: `gemm_uninit` initialized all output elements.
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Multiply `a` by `b`, optionally using a copy of `b` that has already been
+/// packed by [GemmExecutor::prepack_b].
+///
+/// `packed_b` is ignored if `b` is not a matrix of the same shape.
 fn matmul_impl(
     pool: &TensorPool,
     a: TensorView,
     b: TensorView,
+    packed_b: Option<&PackedBMatrix>,
     strategy: MatmulStrategy,
 ) -> Result<Tensor, OpError> {
     if a.ndim() < 2 || b.ndim() < 2 {
@@ -159,11 +220,13 @@ fn matmul_impl(
     //
     // The upside is that one larger matmul is likely to be more efficient than
     // `A` smaller matmuls. This is especially true if `M` is small (eg. 1).
-    if strategy == MatmulStrategy::Auto && a.ndim() > 2 && b.ndim() == 2 {
-        // nb. We assume `a` is likely already contiguous, so this will be cheap.
-        let a_contig = a.to_contiguous_in(pool).auto_return(pool);
-        let a_matrix = a_contig.reshaped([num_a_matrices * a_rows, a_cols].as_slice());
-        let mut output = matmul(pool, a_matrix, b.clone())?;
+    //
+    // If `a` is not contiguous, for example because it is a transposed view,
+    // reshaping would require a copy. In that case each matrix in the batch
+    // is multiplied separately instead, using a strided view of `a`.
+    if strategy == MatmulStrategy::Auto && a.ndim() > 2 && b.ndim() == 2 && a.is_contiguous() {
+        let a_matrix = a.reshaped([num_a_matrices * a_rows, a_cols].as_slice());
+        let mut output = matmul_impl(pool, a_matrix, b.clone(), packed_b, strategy)?;
         output.reshape(out_shape);
         return Ok(output);
     }
@@ -191,19 +254,25 @@ fn matmul_impl(
 
     // Prepack re-used inputs to amortize packing cost.
     //
-    // We don't prepack when the "A" matrix is a vector because that uses a
-    // special case vector-matrix algorithm that doesn't benefit from packing.
-    let prepacked_a = (num_a_matrices == 1 && num_b_matrices > 1 && a_rows > 1).then(|| {
+    // We don't prepack when the "A" matrix is a row vector or the "B" matrix
+    // is a column vector because those use special case vector-matrix
+    // algorithms that don't benefit from packing.
+    let is_gemv = a_rows == 1 || b_cols == 1;
+    let prepacked_a = (num_a_matrices == 1 && num_b_matrices > 1 && !is_gemv).then(|| {
         let a_matrix = a.inner_iter::<2>().next().unwrap();
         gemm.prepack_a_in(pool, a_matrix).auto_return(pool)
     });
     let prepacked_a = prepacked_a.as_deref();
 
-    let prepacked_b = (num_a_matrices > 1 && num_b_matrices == 1 && a_rows > 1).then(|| {
-        let b_matrix = b.inner_iter::<2>().next().unwrap();
-        gemm.prepack_b_in(pool, b_matrix).auto_return(pool)
+    let packed_b = packed_b.filter(|packed| {
+        !is_gemv && b.ndim() == 2 && [packed.rows(), packed.cols()] == [b_rows, b_cols]
     });
-    let prepacked_b = prepacked_b.as_deref();
+    let prepacked_b = (num_a_matrices > 1 && num_b_matrices == 1 && !is_gemv && packed_b.is_none())
+        .then(|| {
+            let b_matrix = b.inner_iter::<2>().next().unwrap();
+            gemm.prepack_b_in(pool, b_matrix).auto_return(pool)
+        });
+    let prepacked_b = prepacked_b.as_deref().or(packed_b);
 
     a_broadcast
         .inner_iter::<2>()
@@ -238,6 +307,71 @@ fn matmul_impl(
     Ok(output)
 }
 
+/// Multiply `a` by a sparse matrix, given its transpose `b_t`.
+///
+/// `a` may have leading batch dimensions, which are flattened into the rows
+/// of the matrix that is multiplied by `b`.
+fn matmul_sparse(pool: &TensorPool, a: TensorView, b_t: &CsrMatrix) -> Result<Tensor, OpError> {
+    let a_prefix = &a.shape()[..a.ndim() - 2];
+    let [a_rows, a_cols] = [a.size(a.ndim() - 2), a.size(a.ndim() - 1)];
+    let num_a_matrices: usize = a_prefix.iter().product();
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+    let a_matrix = a.reshaped([num_a_matrices * a_rows, a_cols]);
+    let output = sparse_matmul(pool, a_matrix.view(), b_t)?;
+
+    let out_shape = [a_prefix, &[a_rows, b_t.rows()]].concat();
+    Ok(output.into_shape(out_shape.as_slice()))
+}
+
+/// Multiply `a` by a matrix `b` whose elements are stored as `BF16`.
+///
+/// `a` may have leading batch dimensions, which are flattened into the rows
+/// of the matrix that is multiplied by `b`. Elements of `b` are converted to
+/// `f32` as blocks are packed by the GEMM, so `b` is never converted in full.
+fn matmul_bf16(
+    pool: &TensorPool,
+    a: TensorView,
+    b: NdTensorView<BF16, 2>,
+) -> Result<Tensor, OpError> {
+    if a.ndim() < 2 {
+        return Err(OpError::InvalidValue("Inputs must have >= 2 dimensions"));
+    }
+
+    let a_prefix = &a.shape()[..a.ndim() - 2];
+    let [a_rows, a_cols] = [a.size(a.ndim() - 2), a.size(a.ndim() - 1)];
+    let [b_rows, b_cols] = b.shape();
+    let num_a_matrices: usize = a_prefix.iter().product();
+
+    if a_cols != b_rows {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+
+    let out_shape = [a_prefix, &[a_rows, b_cols]].concat();
+    let mut output = Tensor::uninit_in(pool, &[num_a_matrices * a_rows, b_cols]);
+    if output.is_empty() {
+        return Ok(Tensor::zeros(&out_shape));
+    }
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+    let a_matrix = a.reshaped([num_a_matrices * a_rows, a_cols]);
+
+    let out_row_stride = output.stride(0);
+    GemmExecutor::new().gemm_uninit(
+        output.data_mut().unwrap(),
+        out_row_stride,
+        GemmInputA::Unpacked(a_matrix.view()),
+        GemmInputB::UnpackedBF16(b),
+        1., // alpha
+    );
+
+    // Safety: `gemm_uninit` initialized all output elements.
+    let output = unsafe { output.assume_init() };
+    Ok(output.into_shape(out_shape.as_slice()))
+}
+
 #[derive(Debug)]
 pub struct MatMul {}
 
@@ -248,8 +382,206 @@ impl Operator for MatMul {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let a = inputs.require_as(0)?;
+
+        if let Input::BFloat16Tensor(b) = inputs.require(1)? {
+            return if b.ndim() == 2 {
+                matmul_bf16(pool, a, b.nd_view())
+            } else {
+                let b = b.map_in(pool, |x| x.to_f32()).auto_return(pool);
+                matmul(pool, a, b.view())
+            }
+            .into_op_result();
+        }
+
         let b = inputs.require_as(1)?;
-        matmul(pool, a, b).into_op_result()
+        let prepacked_b = inputs.get_prepacked(1);
+
+        if let Some(b_t) = prepacked_b.and_then(|packed| packed.as_sparse_b_matrix()) {
+            if a.ndim() >= 2 && b.shape() == [b_t.cols(), b_t.rows()] {
+                return matmul_sparse(pool, a, b_t).into_op_result();
+            }
+        }
+
+        let packed_b = prepacked_b.and_then(|packed| packed.as_float_b_matrix());
+        matmul_impl(pool, a, b, packed_b, MatmulStrategy::Auto).into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> SmallVec<[usize; 1]> {
+        [1].into()
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        // `BF16` weights are not prepacked, as that would convert them to
+        // `f32` and undo the memory saving.
+        match (index, input) {
+            (1, Input::FloatTensor(b)) if b.ndim() == 2 => {
+                let b = b.nd_view();
+                if sparse::density(b) <= SPARSE_DENSITY_THRESHOLD {
+                    Some(PrepackedInput::sparse_b_matrix(b))
+                } else {
+                    Some(PrepackedInput::float_b_matrix(b))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn supports_transposed_inputs(&self) -> bool {
+        // Each matrix in the batch is passed to the GEMM as a strided view.
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        infer_matmul_shape(inputs)
+    }
+}
+
+/// Number of rows of the quantized matrix that are dequantized at a time by
+/// [matmul_nbits].
+const NBITS_TILE_ROWS: usize = 64;
+
+/// Multiply `a` by the transpose of a block-quantized matrix `b`.
+///
+/// `a` has shape `[..., K]` and the output has shape `[..., N]`. `b` holds a
+/// quantized `[N, K]` weight matrix, packed into `i32` words with shape
+/// `[N, K * bits / 32]`. Each row of `b` is divided into blocks of
+/// `block_size` values, and `scales` and `zero_points` have shape
+/// `[N, ceil(K / block_size)]`. See [gather_block_quantized](crate::ops::gather_block_quantized)
+/// for details of the packing. The number of quantized columns may exceed
+/// `K` if `K` is not a multiple of `block_size`, in which case the excess
+/// columns are ignored.
+///
+/// Weights are dequantized on the fly, a few rows at a time, so the
+/// dequantized matrix is never fully materialized. For vector-matrix products,
+/// which are common when generating text with language models, each output is
+/// computed by a fused dequantize-and-dot kernel instead.
+///
+/// See <https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.MatMulNBits>.
+pub fn matmul_nbits(
+    pool: &TensorPool,
+    a: TensorView,
+    b: NdTensorView<i32, 2>,
+    scales: NdTensorView<f32, 2>,
+    zero_points: Option<NdTensorView<i32, 2>>,
+    bits: u32,
+    block_size: usize,
+) -> Result<Tensor, OpError> {
+    let b = BlockQuantizedMatrix::new(b, scales, zero_points, bits, block_size)?;
+    if a.ndim() < 1 {
+        return Err(OpError::InvalidValue("`a` must have at least 1 dimension"));
+    }
+
+    let k = a.size(a.ndim() - 1);
+    let b_cols = b.cols();
+    if k > b_cols || k.div_ceil(block_size) != b_cols.div_ceil(block_size) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of `a` do not match columns of quantized `b`",
+        ));
+    }
+
+    let n = b.rows();
+    let a_prefix = &a.shape()[..a.ndim() - 1];
+    let m: usize = a_prefix.iter().product();
+    let out_shape = [a_prefix, &[n]].concat();
+    if m == 0 || n == 0 {
+        return Ok(Tensor::zeros(&out_shape));
+    }
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+
+    if m == 1 && b.blocks_are_word_aligned() {
+        let a_row = a.data().unwrap();
+
+        let mut output = Tensor::zeros_in(pool, &out_shape);
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(NBITS_TILE_ROWS)
+            .enumerate()
+            .for_each(|(tile, out_tile)| {
+                let row_start = tile * NBITS_TILE_ROWS;
+                for (i, out) in out_tile.iter_mut().enumerate() {
+                    *out = b.dot_row(row_start + i, a_row);
+                }
+            });
+        return Ok(output);
+    }
+
+    let a_mat = NdTensorView::from_data([m, k], a.data().unwrap());
+
+    // Compute the transposed output, `b @ a^T`, so that each task produces a
+    // contiguous block of output rows from a tile of dequantized rows of `b`.
+    let gemm = GemmExecutor::new();
+    let prepacked_a = (m > 1).then(|| {
+        gemm.prepack_b_in(pool, a_mat.transposed())
+            .auto_return(pool)
+    });
+    let prepacked_a = prepacked_a.as_deref();
+
+    let mut out_t = NdTensor::zeros_in(pool, [n, m]).auto_return(pool);
+    out_t
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(NBITS_TILE_ROWS * m)
+        .enumerate()
+        .for_each(|(tile, out_tile)| {
+            let row_start = tile * NBITS_TILE_ROWS;
+            let n_rows = out_tile.len() / m;
+
+            let mut b_tile = NdTensor::zeros([n_rows, b_cols]);
+            for (i, row) in b_tile.data_mut().unwrap().chunks_mut(b_cols).enumerate() {
+                b.dequantize_row(row_start + i, row);
+            }
+
+            let a_input = if let Some(packed) = prepacked_a {
+                GemmInputB::Packed(packed)
+            } else {
+                GemmInputB::Unpacked(a_mat.transposed())
+            };
+            gemm.gemm(
+                out_tile,
+                m,
+                GemmInputA::Unpacked(b_tile.slice::<2, _>((.., ..k))),
+                a_input,
+                1., /* alpha */
+                0., /* beta */
+            );
+        });
+
+    let mut output = out_t.transposed().to_tensor_in(pool).into_dyn();
+    output.reshape(&out_shape);
+    Ok(output)
+}
+
+/// Matrix multiplication with a block-quantized weight matrix.
+///
+/// See [matmul_nbits].
+#[derive(Debug)]
+pub struct MatMulNBits {
+    pub bits: u32,
+    pub block_size: usize,
+}
+
+impl Operator for MatMulNBits {
+    fn name(&self) -> &str {
+        "MatMulNBits"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let a = inputs.require_as::<f32>(0)?;
+        let b = inputs.require_as::<i32>(1)?;
+        let b = static_dims!(b, 2)?;
+        let scales = inputs.require_as::<f32>(2)?;
+        let scales = static_dims!(scales, 2)?;
+        let zero_points = inputs
+            .get_as::<i32>(3)?
+            .map(|zp| static_dims!(zp, 2))
+            .transpose()?;
+
+        matmul_nbits(pool, a, b, scales, zero_points, self.bits, self.block_size).into_op_result()
     }
 }
 
@@ -261,13 +593,18 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::{Tensor, TensorView, TensorViewMut};
+    use rten_tensor::{NdTensor, Tensor, TensorView, TensorViewMut};
 
     use crate::gemm::gemm;
+    use crate::number::BF16;
     use crate::ops::tests::new_pool;
+    use crate::ops::{InputList, Operator};
     use crate::tensor_pool::AutoReturn;
 
-    use super::{gemm_op, matmul, matmul_impl, MatmulStrategy, OpError};
+    use super::{
+        gemm_op, matmul, matmul_2d, matmul_impl, matmul_nbits, MatMul, MatmulStrategy, OpError,
+    };
+    use crate::ops::gather_block_quantized;
 
     fn gemm_tensors(c: &mut Tensor, a: &Tensor, b: &Tensor, alpha: f32, beta: f32) {
         c.make_contiguous();
@@ -405,6 +742,41 @@ mod tests {
                 "Cannot broadcast c to output shape"
             ))
         );
+
+        let result = gemm_op(&pool, a.view(), b.view(), None, 1.0, 1.0, true, false);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Columns of first matrix does not match rows of second matrix"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_matmul_2d() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        for (m, k, n) in [(1, 5, 7), (4, 5, 7), (3, 0, 2), (0, 4, 3)] {
+            let a = NdTensor::rand([m, k], &mut rng);
+            let b = NdTensor::rand([k, n], &mut rng);
+            let result = matmul_2d(&pool, a.view(), b.view())?;
+            let expected = matmul(&pool, a.as_dyn(), b.as_dyn())?;
+            assert_eq!(result.shape(), [m, n]);
+            expect_equal(&result.as_dyn(), &expected.view())?;
+        }
+
+        let a = NdTensor::rand([2, 3], &mut rng);
+        let b = NdTensor::rand([4, 2], &mut rng);
+        let result = matmul_2d(&pool, a.view(), b.view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Columns of first matrix does not match rows of second matrix"
+            ))
+        );
+
+        Ok(())
     }
 
     #[test]
@@ -463,6 +835,103 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_matmul_transposed_inputs() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Inputs are transposed views, as passed by the graph executor in
+        // place of the output of a `Transpose` operator.
+        let a = Tensor::rand(&[2, 10, 3], &mut rng);
+        let b = Tensor::rand(&[2, 8, 10], &mut rng);
+        let c = Tensor::rand(&[10, 8], &mut rng);
+        let a_t = a.permuted([0, 2, 1].as_slice());
+        let b_t = b.permuted([0, 2, 1].as_slice());
+
+        for (a, b) in [
+            (a_t.clone(), b_t.clone()),
+            (a_t.clone(), b_t.slice::<2, _>(0).into()),
+            (a_t.clone(), c.view()),
+        ] {
+            let out_shape = [&a.shape()[..a.ndim() - 1], &[b.size(b.ndim() - 1)]].concat();
+            let mut expected = Tensor::zeros(&out_shape);
+            reference_matmul(expected.view_mut(), a.view(), b.view());
+            let result = matmul(&pool, a, b).unwrap();
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matmul_bf16() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case<'a> {
+            a_shape: &'a [usize],
+            b_shape: &'a [usize],
+        }
+
+        let cases = [
+            // Matrix-matrix product
+            Case {
+                a_shape: &[5, 10],
+                b_shape: &[10, 8],
+            },
+            // Vector-matrix product
+            Case {
+                a_shape: &[1, 10],
+                b_shape: &[10, 8],
+            },
+            // Batched `a`
+            Case {
+                a_shape: &[2, 3, 5, 10],
+                b_shape: &[10, 8],
+            },
+            // Batched `b`, which is converted to `f32`.
+            Case {
+                a_shape: &[2, 5, 10],
+                b_shape: &[2, 10, 8],
+            },
+        ];
+
+        for Case { a_shape, b_shape } in cases {
+            let a = Tensor::rand(a_shape, &mut rng);
+            let b = Tensor::rand(b_shape, &mut rng).map(|x| BF16::from_f32(*x));
+
+            // Converting `BF16` to `f32` is exact, so the result should match
+            // a MatMul with the converted weights.
+            let b_f32 = b.map(|x| x.to_f32());
+            let expected = matmul(&pool, a.view(), b_f32.view()).unwrap();
+
+            let op = MatMul {};
+            let inputs = [a.view().into(), b.view().into()];
+            let result: Tensor = op
+                .run(&pool, InputList::from(&inputs))
+                .unwrap()
+                .remove(0)
+                .try_into()
+                .unwrap();
+            expect_equal(&result, &expected)?;
+
+            // The weights can also be passed as a transposed view.
+            if b.ndim() == 2 {
+                let b_t = b.transposed().to_tensor();
+                let inputs = [a.view().into(), b_t.transposed().into()];
+                let result: Tensor = op
+                    .run(&pool, InputList::from(&inputs))
+                    .unwrap()
+                    .remove(0)
+                    .try_into()
+                    .unwrap();
+                expect_equal(&result, &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_matmul_invalid() -> Result<(), Box<dyn Error>> {
         struct Case<'a> {
@@ -542,6 +1011,153 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_matmul_nbits() -> Result<(), Box<dyn Error>> {
+        struct Case<'a> {
+            a_shape: &'a [usize],
+            n: usize,
+            bits: u32,
+            block_size: usize,
+            zero_points: bool,
+        }
+
+        let cases = [
+            // Vector-matrix product
+            Case {
+                a_shape: &[32],
+                n: 5,
+                bits: 4,
+                block_size: 16,
+                zero_points: false,
+            },
+            // Matrix-matrix product with more rows than one tile
+            Case {
+                a_shape: &[3, 32],
+                n: 70,
+                bits: 4,
+                block_size: 16,
+                zero_points: true,
+            },
+            // Batched input
+            Case {
+                a_shape: &[2, 3, 16],
+                n: 4,
+                bits: 8,
+                block_size: 8,
+                zero_points: true,
+            },
+            // `K` is not a multiple of the block size
+            Case {
+                a_shape: &[2, 20],
+                n: 3,
+                bits: 4,
+                block_size: 16,
+                zero_points: false,
+            },
+            // Vector-matrix product where `K` is not a multiple of the block
+            // size and there are more rows than one tile
+            Case {
+                a_shape: &[1, 1, 40],
+                n: 70,
+                bits: 4,
+                block_size: 16,
+                zero_points: true,
+            },
+            // Vector-matrix product where `K` ends part-way through a word
+            Case {
+                a_shape: &[36],
+                n: 3,
+                bits: 4,
+                block_size: 16,
+                zero_points: true,
+            },
+            // Vector-matrix product where blocks don't start at word boundaries
+            Case {
+                a_shape: &[12],
+                n: 3,
+                bits: 8,
+                block_size: 6,
+                zero_points: true,
+            },
+        ];
+
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        for Case {
+            a_shape,
+            n,
+            bits,
+            block_size,
+            zero_points,
+        } in cases
+        {
+            let k = a_shape[a_shape.len() - 1];
+            let n_blocks = k.div_ceil(block_size);
+            let words = n_blocks * block_size * bits as usize / 32;
+
+            let a = Tensor::rand(a_shape, &mut rng);
+            let b = Tensor::from_simple_fn(&[n, words], || rng.next_u64() as i32);
+            let scales = Tensor::rand(&[n, n_blocks], &mut rng);
+            let zero_points = zero_points.then(|| {
+                Tensor::from_simple_fn(&[n, n_blocks], || (rng.next_u64() % (1 << bits)) as i32)
+            });
+
+            let result = matmul_nbits(
+                &pool,
+                a.view(),
+                b.nd_view(),
+                scales.nd_view(),
+                zero_points.as_ref().map(|zp| zp.nd_view()),
+                bits,
+                block_size,
+            )?;
+
+            // Compute the expected result by dequantizing the whole matrix.
+            let indices = Tensor::arange(0, n as i32, None);
+            let b_dequant = gather_block_quantized(
+                &pool,
+                b.nd_view(),
+                indices.view(),
+                scales.nd_view(),
+                zero_points.as_ref().map(|zp| zp.nd_view()),
+                bits,
+                block_size,
+            )?;
+            let b_dequant = b_dequant.slice::<2, _>((.., ..k)).transposed().to_tensor();
+            let a_mat = a.reshaped([a.len() / k, k].as_slice());
+            let mut expected = matmul(&pool, a_mat, b_dequant.as_dyn())?;
+            expected.reshape(&[&a_shape[..a_shape.len() - 1], &[n]].concat());
+
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matmul_nbits_invalid() {
+        let pool = new_pool();
+        let b = Tensor::<i32>::zeros(&[4, 2]);
+        let scales = Tensor::<f32>::zeros(&[4, 1]);
+
+        let a = Tensor::<f32>::zeros(&[2, 24]);
+        let result = matmul_nbits(&pool, a.view(), b.nd_view(), scales.nd_view(), None, 4, 16);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Columns of `a` do not match columns of quantized `b`"
+            ))
+        );
+
+        let a = Tensor::<f32>::zeros(&[]);
+        let result = matmul_nbits(&pool, a.view(), b.nd_view(), scales.nd_view(), None, 4, 16);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("`a` must have at least 1 dimension"))
+        );
+    }
+
     #[test]
     #[ignore]
     fn bench_matmul() {
@@ -585,7 +1201,7 @@ mod tests {
                 );
                 let pool = new_pool();
                 run_bench(trials, Some(&desc), || {
-                    matmul_impl(&pool, a.view(), b.view(), strategy)
+                    matmul_impl(&pool, a.view(), b.view(), None, strategy)
                         .unwrap()
                         .auto_return(&pool);
                 });
@@ -19,23 +19,36 @@ use std::fmt::{Debug, Display};
 use smallvec::SmallVec;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{DynLayout, NdTensor, NdTensorView, Tensor, TensorView};
+use rten_tensor::{CowData, DynLayout, NdTensor, NdTensorView, Tensor, TensorBase, TensorView};
 
+use crate::gemm::{GemmExecutor, PackedBMatrix};
+use crate::graph::{Dimension, RunError};
+use crate::number::BF16;
+use crate::sparse::CsrMatrix;
 use crate::tensor_pool::TensorPool;
 
+mod attention;
 mod binary_elementwise;
 mod concat;
+mod control_flow;
 mod conv;
 mod convert;
-mod gather;
+mod gather_scatter;
 mod generate;
+mod grid_sample;
 mod identity;
 mod layout;
 mod matmul;
 mod non_max_suppression;
 mod norm;
+mod op_info;
+mod optional;
 mod pad;
+mod paste_masks;
 mod pooling;
+#[cfg(test)]
+mod property_tests;
+mod quantize;
 
 #[cfg(feature = "random")]
 mod random;
@@ -43,67 +56,99 @@ mod random;
 mod reduce;
 mod resize;
 mod rnn;
+mod sequence;
+pub(crate) mod shape_inference;
 mod slice;
 mod split;
+#[cfg(test)]
+mod strided_tests;
 mod trilu;
 mod unary_elementwise;
 mod variadic_elementwise;
 
+pub use attention::{scaled_dot_product_attention, ScaledDotProductAttention};
 pub use binary_elementwise::{
-    add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-    less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place, where_op,
-    xor, Add, And, Div, DivMode, Equal, Greater, GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or,
-    Pow, Sub, Where, Xor,
+    add, add_in_place, and, and_in_place, bit_shift, bit_shift_in_place, bitwise_and,
+    bitwise_and_in_place, bitwise_or, bitwise_or_in_place, bitwise_xor, bitwise_xor_in_place, div,
+    div_in_place, equal, equal_in_place, greater, greater_in_place, greater_or_equal,
+    greater_or_equal_in_place, less, less_in_place, less_or_equal, less_or_equal_in_place, mod_op,
+    mul, mul_in_place, or, or_in_place, pow, pow_in_place, sub, sub_in_place, where_op, xor,
+    xor_in_place, Add, And, BitShift, BitShiftDirection, BitwiseAnd, BitwiseOr, BitwiseXor, Div,
+    DivMode, Equal, Greater, GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or, Pow, Sub, Where, Xor,
 };
+pub(crate) use binary_elementwise::{is_broadcast_compatible, unsqueeze_for_broadcast};
 pub use concat::{concat, tile, Concat, Tile};
-pub use conv::{conv, conv_transpose, Conv, ConvTranspose};
-pub use convert::Cast;
-pub use gather::{
-    gather, gather_elements, gather_nd, scatter_elements, scatter_nd, Gather, GatherElements,
-    GatherND, ScatterElements, ScatterND, ScatterReduction,
+pub use control_flow::Scan;
+pub use conv::{conv, conv_2d, conv_transpose, Conv, ConvTranspose};
+pub use convert::{cast, cast_in_place, image_to_tensor, Cast, CastLike, ImageToTensor};
+pub use gather_scatter::{
+    gather, gather_block_quantized, gather_elements, gather_nd, scatter_elements, scatter_nd,
+    Gather, GatherBlockQuantized, GatherElements, GatherND, ScatterElements, ScatterND,
+    ScatterReduction,
+};
+pub use generate::{
+    constant_of_shape, eye_like, onehot, range, ConstantOfShape, EyeLike, OneHot, Range,
 };
-pub use generate::{constant_of_shape, onehot, range, ConstantOfShape, OneHot, Range};
-pub use identity::Identity;
+pub use grid_sample::{grid_sample, GridSample, GridSamplePadding};
+pub use identity::{Dropout, Identity};
 pub use layout::{
-    expand, flatten, reshape, squeeze, squeeze_in_place, Expand, Flatten, Reshape, Shape, Size,
-    Squeeze, Transpose, Unsqueeze,
+    channel_shuffle, expand, flatten, permute_channels, reshape, squeeze, squeeze_in_place,
+    ChannelShuffle, Expand, Flatten, PermuteChannels, Reshape, Shape, Size, Squeeze, Transpose,
+    Unsqueeze,
 };
-pub use matmul::{gemm_op, matmul, Gemm, MatMul};
+pub use matmul::{gemm_op, matmul, matmul_2d, matmul_nbits, Gemm, MatMul, MatMulNBits};
 pub use non_max_suppression::{non_max_suppression, BoxOrder, NonMaxSuppression};
 pub use norm::{
     batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-    softmax, BatchNormalization, InstanceNormalization, LayerNormalization, LogSoftmax, Softmax,
+    lp_normalization, mean_variance_normalization, normalize, normalize_in_place, softmax,
+    softmax_last_axis, BatchNormalization, InstanceNormalization, LayerNormalization, LogSoftmax,
+    LpNormalization, MeanVarianceNormalization, Normalize, Softmax,
 };
-pub use pad::{pad, Pad};
+pub use op_info::{op_info, registry, OpInfo};
+pub use optional::{Optional, OptionalGetElement, OptionalHasElement};
+pub use pad::{pad, Pad, PadMode};
+pub use paste_masks::paste_masks;
 pub use pooling::{
-    average_pool, global_average_pool, max_pool, AveragePool, GlobalAveragePool, MaxPool,
+    average_pool, global_average_pool, global_max_pool, lp_pool, max_pool, AveragePool,
+    GlobalAveragePool, GlobalMaxPool, LpPool, MaxPool,
 };
 
 #[cfg(feature = "random")]
-pub use random::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
+pub use random::{
+    multinomial, Multinomial, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike,
+};
 
+pub use quantize::{qlinear_conv, qlinear_matmul, QLinearConv, QLinearMatMul, QuantParams};
 pub use reduce::{
-    arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean, reduce_min,
-    reduce_prod, reduce_sum, reduce_sum_square, topk, ArgMax, ArgMin, CumSum, NonZero, ReduceL2,
-    ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, TopK,
+    arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_log_sum_exp, reduce_max, reduce_mean,
+    reduce_min, reduce_prod, reduce_std, reduce_sum, reduce_sum_square, reduce_var, topk, ArgMax,
+    ArgMin, CumSum, NonZero, ReduceL2, ReduceLogSumExp, ReduceMax, ReduceMean, ReduceMin,
+    ReduceProd, ReduceStd, ReduceSum, ReduceSumSquare, ReduceVar, TopK,
 };
 pub use resize::{
     resize, resize_image, CoordTransformMode, NearestMode, Resize, ResizeMode, ResizeTarget,
 };
 pub use rnn::{gru, lstm, Direction, GRU, LSTM};
+pub use sequence::{
+    ConcatFromSequence, Sequence, SequenceAt, SequenceConstruct, SequenceInsert, SequenceLength,
+    SplitToSequence,
+};
 pub use slice::{slice, slice_in_place, Slice};
 pub use split::{split, Split};
 pub use trilu::{trilu, Trilu};
 pub use unary_elementwise::{
-    abs, abs_in_place, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil,
-    ceil_in_place, clip, clip_in_place, cos, cos_in_place, elu, elu_in_place, erf, erf_in_place,
-    exp, exp_in_place, floor, floor_in_place, hard_sigmoid, hard_sigmoid_in_place, hard_swish,
-    hard_swish_in_place, leaky_relu, leaky_relu_in_place, log, log_in_place, neg, neg_in_place,
+    abs, abs_in_place, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, bitwise_not,
+    bitwise_not_in_place, ceil, ceil_in_place, celu, celu_in_place, clip, clip_in_place, cos,
+    cos_in_place, elu, elu_in_place, erf, erf_in_place, exp, exp_in_place, floor, floor_in_place,
+    gelu, gelu_in_place, hard_sigmoid, hard_sigmoid_in_place, hard_swish, hard_swish_in_place,
+    leaky_relu, leaky_relu_in_place, log, log_in_place, mish, mish_in_place, neg, neg_in_place,
     not, not_in_place, reciprocal, reciprocal_in_place, relu, relu_in_place, round, round_in_place,
-    sigmoid, sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, softplus, softplus_in_place,
-    sqrt, sqrt_in_place, tan, tan_in_place, tanh, tanh_in_place, Abs, Acos, Asin, Atan, Ceil, Clip,
-    Cos, Elu, Erf, Exp, Floor, HardSigmoid, HardSwish, LeakyRelu, Log, Neg, Not, Reciprocal, Relu,
-    Round, Sigmoid, Sign, Sin, Softplus, Sqrt, Tan, Tanh,
+    selu, selu_in_place, sigmoid, sigmoid_in_place, sign, sign_in_place, sin, sin_in_place,
+    softplus, softplus_in_place, softsign, softsign_in_place, sqrt, sqrt_in_place, tan,
+    tan_in_place, tanh, tanh_in_place, thresholded_relu, thresholded_relu_in_place, Abs, Acos,
+    Asin, Atan, BitwiseNot, Ceil, Celu, Clip, Cos, Elu, Erf, Exp, Floor, Gelu, HardSigmoid,
+    HardSwish, LeakyRelu, Log, Mish, Neg, Not, Reciprocal, Relu, Round, Selu, Sigmoid, Sign, Sin,
+    Softplus, Softsign, Sqrt, Tan, Tanh, ThresholdedRelu,
 };
 pub use variadic_elementwise::{max, mean, min, sum, Max, Mean, Min, Sum};
 
@@ -151,24 +196,77 @@ impl<S: AsRef<[usize]>> From<S> for Padding {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DataType {
     Int32,
     Float,
+    Int64,
+    String,
+
+    /// Signed 8-bit integer, stored in an `i32` tensor.
+    Int8,
+    /// Unsigned 8-bit integer, stored in an `i32` tensor.
+    UInt8,
+    /// Boolean, stored in an `i32` tensor as 0 or 1.
+    Bool,
+    /// 16-bit "brain" float. This is only used for constant weights. See
+    /// [Input::BFloat16Tensor].
+    BFloat16,
 }
 
-/// Enum of the different types of input tensor that an operator can accept.
+/// Enum of the different types of input value that an operator can accept.
 #[derive(Clone)]
 pub enum Input<'a> {
     FloatTensor(TensorView<'a, f32>),
     IntTensor(TensorView<'a, i32>),
+    Int64Tensor(TensorView<'a, i64>),
+    StringTensor(TensorView<'a, String>),
+    Sequence(&'a Sequence),
+
+    /// A constant weight stored as `BF16`.
+    ///
+    /// Operators do not produce `BF16` outputs, so this type of input only
+    /// comes from constants in the model. Operators which don't support it
+    /// reject it with [OpError::IncorrectInputType].
+    BFloat16Tensor(TensorView<'a, BF16>),
 }
 
 impl<'a> Input<'a> {
+    /// Return the element type of this tensor, or of the tensors in a
+    /// sequence.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Input::FloatTensor(_) => DataType::Float,
+            Input::IntTensor(_) => DataType::Int32,
+            Input::Int64Tensor(_) => DataType::Int64,
+            Input::StringTensor(_) => DataType::String,
+            Input::Sequence(seq) => seq.dtype(),
+            Input::BFloat16Tensor(_) => DataType::BFloat16,
+        }
+    }
+
+    /// Copy this value into an owned [Output], allocating tensors from `pool`.
+    ///
+    /// `BF16` values are converted to `f32`.
+    pub(crate) fn to_output_in(&self, pool: &TensorPool) -> Output {
+        match self {
+            Input::FloatTensor(t) => t.to_tensor_in(pool).into(),
+            Input::IntTensor(t) => t.to_tensor_in(pool).into(),
+            Input::Int64Tensor(t) => t.to_tensor_in(pool).into(),
+            Input::StringTensor(t) => t.to_tensor_in(pool).into(),
+            Input::Sequence(seq) => Output::Sequence((*seq).clone()),
+            Input::BFloat16Tensor(t) => t.map_in(pool, |x| x.to_f32()).into(),
+        }
+    }
+
     fn layout(&self) -> &DynLayout {
         match self {
             Input::FloatTensor(t) => t.layout(),
             Input::IntTensor(t) => t.layout(),
+            Input::Int64Tensor(t) => t.layout(),
+            Input::StringTensor(t) => t.layout(),
+            Input::Sequence(seq) => seq.layout(),
+            Input::BFloat16Tensor(t) => t.layout(),
         }
     }
 }
@@ -236,6 +334,50 @@ impl<'a> TryFrom<Input<'a>> for TensorView<'a, i32> {
     }
 }
 
+impl<'a> TryFrom<Input<'a>> for TensorView<'a, i64> {
+    type Error = OpError;
+
+    fn try_from(input: Input<'a>) -> Result<TensorView<'a, i64>, Self::Error> {
+        match input {
+            Input::Int64Tensor(t) => Ok(t),
+            _ => Err(OpError::IncorrectInputType),
+        }
+    }
+}
+
+impl<'a> TryFrom<Input<'a>> for TensorView<'a, String> {
+    type Error = OpError;
+
+    fn try_from(input: Input<'a>) -> Result<TensorView<'a, String>, Self::Error> {
+        match input {
+            Input::StringTensor(t) => Ok(t),
+            _ => Err(OpError::IncorrectInputType),
+        }
+    }
+}
+
+impl<'a> TryFrom<Input<'a>> for TensorView<'a, BF16> {
+    type Error = OpError;
+
+    fn try_from(input: Input<'a>) -> Result<TensorView<'a, BF16>, Self::Error> {
+        match input {
+            Input::BFloat16Tensor(t) => Ok(t),
+            _ => Err(OpError::IncorrectInputType),
+        }
+    }
+}
+
+impl<'a> TryFrom<Input<'a>> for &'a Sequence {
+    type Error = OpError;
+
+    fn try_from(input: Input<'a>) -> Result<&'a Sequence, Self::Error> {
+        match input {
+            Input::Sequence(seq) => Ok(seq),
+            _ => Err(OpError::IncorrectInputType),
+        }
+    }
+}
+
 impl<'a> TryFrom<Input<'a>> for f32 {
     type Error = OpError;
 
@@ -260,6 +402,18 @@ impl<'a> TryFrom<Input<'a>> for i32 {
     }
 }
 
+impl<'a> TryFrom<Input<'a>> for i64 {
+    type Error = OpError;
+
+    fn try_from(input: Input<'a>) -> Result<i64, Self::Error> {
+        let tensor: TensorView<'a, _> = input.try_into()?;
+        tensor
+            .item()
+            .copied()
+            .ok_or(OpError::InvalidValue("Expected scalar value"))
+    }
+}
+
 macro_rules! impl_input_conversions {
     ($variant:ident, $element_type:ty) => {
         impl<'a> From<&'a Tensor<$element_type>> for Input<'a> {
@@ -284,21 +438,36 @@ macro_rules! impl_input_conversions {
 
 impl_input_conversions!(FloatTensor, f32);
 impl_input_conversions!(IntTensor, i32);
+impl_input_conversions!(Int64Tensor, i64);
+impl_input_conversions!(StringTensor, String);
+impl_input_conversions!(BFloat16Tensor, BF16);
 
 impl<'a> From<&'a Output> for Input<'a> {
     fn from(output: &'a Output) -> Input {
         match output {
             Output::FloatTensor(t) => Input::FloatTensor(t.view()),
             Output::IntTensor(t) => Input::IntTensor(t.view()),
+            Output::Int64Tensor(t) => Input::Int64Tensor(t.view()),
+            Output::StringTensor(t) => Input::StringTensor(t.view()),
+            Output::Sequence(seq) => Input::Sequence(seq),
         }
     }
 }
 
-/// Enum of the different types of output tensor that an operator can produce.
+impl<'a> From<&'a Sequence> for Input<'a> {
+    fn from(seq: &'a Sequence) -> Input<'a> {
+        Input::Sequence(seq)
+    }
+}
+
+/// Enum of the different types of output value that an operator can produce.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Output {
     FloatTensor(Tensor<f32>),
     IntTensor(Tensor<i32>),
+    Int64Tensor(Tensor<i64>),
+    StringTensor(Tensor<String>),
+    Sequence(Sequence),
 }
 
 impl Output {
@@ -318,6 +487,38 @@ impl Output {
         }
     }
 
+    pub fn into_int64(self) -> Option<Tensor<i64>> {
+        if let Output::Int64Tensor(t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_int64_ref(&self) -> Option<&Tensor<i64>> {
+        if let Output::Int64Tensor(t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn into_strings(self) -> Option<Tensor<String>> {
+        if let Output::StringTensor(t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_strings_ref(&self) -> Option<&Tensor<String>> {
+        if let Output::StringTensor(t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
     pub fn into_float(self) -> Option<Tensor<f32>> {
         if let Output::FloatTensor(t) = self {
             Some(t)
@@ -334,10 +535,73 @@ impl Output {
         }
     }
 
+    pub fn into_sequence(self) -> Option<Sequence> {
+        if let Output::Sequence(seq) = self {
+            Some(seq)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_sequence_ref(&self) -> Option<&Sequence> {
+        if let Output::Sequence(seq) = self {
+            Some(seq)
+        } else {
+            None
+        }
+    }
+
+    /// Convert this output into a tensor with element type `T`.
+    ///
+    /// Unlike [Output::into_float] etc., the error reports the type of value
+    /// that was found if the conversion fails.
+    pub fn into_tensor<T: OutputElement>(self) -> Result<Tensor<T>, OutputTypeError> {
+        let err = match &self {
+            Output::Sequence(_) => OutputTypeError::Sequence,
+            output => OutputTypeError::IncorrectType {
+                expected: T::DTYPE,
+                actual: output.dtype(),
+            },
+        };
+        T::from_output(self).ok_or(err)
+    }
+
+    /// Convert this output into a tensor with element type `T` and rank `N`.
+    ///
+    /// If the conversion fails, the error reports the type or rank of the
+    /// value that was found.
+    pub fn into_ndtensor<T: OutputElement, const N: usize>(
+        self,
+    ) -> Result<NdTensor<T, N>, OutputTypeError> {
+        let tensor = self.into_tensor::<T>()?;
+        let ndim = tensor.ndim();
+        tensor
+            .try_into()
+            .map_err(|_| OutputTypeError::IncorrectRank {
+                expected: N,
+                actual: ndim,
+            })
+    }
+
+    /// Return the element type of this tensor, or of the tensors in a
+    /// sequence.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Output::FloatTensor(_) => DataType::Float,
+            Output::IntTensor(_) => DataType::Int32,
+            Output::Int64Tensor(_) => DataType::Int64,
+            Output::StringTensor(_) => DataType::String,
+            Output::Sequence(seq) => seq.dtype(),
+        }
+    }
+
     fn layout(&self) -> &DynLayout {
         match self {
             Output::IntTensor(t) => t.layout(),
+            Output::Int64Tensor(t) => t.layout(),
             Output::FloatTensor(t) => t.layout(),
+            Output::StringTensor(t) => t.layout(),
+            Output::Sequence(seq) => seq.layout(),
         }
     }
 }
@@ -383,9 +647,67 @@ impl Layout for Output {
     }
 }
 
+/// Tensor element types which can be extracted from an [Output] using
+/// [Output::into_tensor].
+pub trait OutputElement: Sized {
+    /// Data type of output tensors with this element type.
+    const DTYPE: DataType;
+
+    /// Extract the tensor from `output` if it has this element type.
+    fn from_output(output: Output) -> Option<Tensor<Self>>;
+}
+
+/// Error when converting an [Output] into a tensor of a specific element type
+/// or rank.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OutputTypeError {
+    /// The output is a tensor with a different element type.
+    IncorrectType {
+        expected: DataType,
+        actual: DataType,
+    },
+
+    /// The output is a tensor with a different number of dimensions.
+    IncorrectRank { expected: usize, actual: usize },
+
+    /// The output is a sequence rather than a tensor.
+    Sequence,
+}
+
+impl Display for OutputTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputTypeError::IncorrectType { expected, actual } => write!(
+                f,
+                "expected tensor with element type {:?} but found {:?}",
+                expected, actual
+            ),
+            OutputTypeError::IncorrectRank { expected, actual } => write!(
+                f,
+                "expected tensor with {} dims but found {} dims",
+                expected, actual
+            ),
+            OutputTypeError::Sequence => write!(f, "expected tensor but found sequence"),
+        }
+    }
+}
+
+impl Error for OutputTypeError {}
+
 /// Declare conversions between `Output` and `Tensor<T>` / `NdTensor<T, N>`.
 macro_rules! impl_output_conversions {
-    ($variant:ident, $element_type:ty) => {
+    ($variant:ident, $element_type:ty, $dtype:ident) => {
+        impl OutputElement for $element_type {
+            const DTYPE: DataType = DataType::$dtype;
+
+            fn from_output(output: Output) -> Option<Tensor<$element_type>> {
+                match output {
+                    Output::$variant(t) => Some(t),
+                    _ => None,
+                }
+            }
+        }
+
         // Tensor<T> => Output
         impl From<Tensor<$element_type>> for Output {
             fn from(t: Tensor<$element_type>) -> Output {
@@ -439,8 +761,27 @@ macro_rules! impl_output_conversions {
     };
 }
 
-impl_output_conversions!(FloatTensor, f32);
-impl_output_conversions!(IntTensor, i32);
+impl_output_conversions!(FloatTensor, f32, Float);
+impl_output_conversions!(IntTensor, i32, Int32);
+impl_output_conversions!(Int64Tensor, i64, Int64);
+impl_output_conversions!(StringTensor, String, String);
+
+impl From<Sequence> for Output {
+    fn from(seq: Sequence) -> Output {
+        Output::Sequence(seq)
+    }
+}
+
+impl TryFrom<Output> for Sequence {
+    type Error = OpError;
+
+    fn try_from(o: Output) -> Result<Sequence, OpError> {
+        match o {
+            Output::Sequence(seq) => Ok(seq),
+            _ => Err(OpError::IncorrectOutputType),
+        }
+    }
+}
 
 /// Trait for values that can be converted into the result type used by
 /// `Operator::run`.
@@ -520,6 +861,9 @@ pub enum OpError {
 
     /// An input or attribute has a value that is valid, but not currently supported.
     UnsupportedValue(&'static str),
+
+    /// Running a subgraph, such as the body of a control flow operator, failed.
+    SubgraphError(Box<RunError>),
 }
 
 impl Display for OpError {
@@ -537,6 +881,7 @@ impl Display for OpError {
             OpError::UnsupportedValue(details) => {
                 write!(f, "unsupported input or attribute value: {}", details)
             }
+            OpError::SubgraphError(err) => write!(f, "subgraph failed: {}", err),
         }
     }
 }
@@ -604,6 +949,61 @@ macro_rules! check_dims {
     };
 }
 
+/// Evaluate `$block` with `$typed_input` bound to the typed tensor view
+/// contained in an [Input], for each supported element type.
+///
+/// This avoids duplicating code in operators which support all numeric element
+/// types, such as data movement operators. `$block` is instantiated once per
+/// element type, so it must type-check for each of them. String and `BF16`
+/// tensors are rejected with [OpError::IncorrectInputType] from the enclosing
+/// function.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! map_input {
+    ($input:expr, $typed_input:ident, $block:block) => {
+        match $input {
+            $crate::ops::Input::FloatTensor($typed_input) => $block,
+            $crate::ops::Input::IntTensor($typed_input) => $block,
+            $crate::ops::Input::Int64Tensor($typed_input) => $block,
+            $crate::ops::Input::StringTensor(_)
+            | $crate::ops::Input::Sequence(_)
+            | $crate::ops::Input::BFloat16Tensor(_) => {
+                return Err($crate::ops::OpError::IncorrectInputType);
+            }
+        }
+    };
+}
+
+/// Evaluate `$block` with `$typed_output` bound to the typed tensor contained
+/// in an [Output], for each supported element type.
+///
+/// This is the [Output] counterpart to [map_input].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! map_output {
+    ($output:expr, $typed_output:ident, $block:block) => {
+        match $output {
+            $crate::ops::Output::FloatTensor($typed_output) => $block,
+            $crate::ops::Output::IntTensor($typed_output) => $block,
+            $crate::ops::Output::Int64Tensor($typed_output) => $block,
+            $crate::ops::Output::StringTensor(_) | $crate::ops::Output::Sequence(_) => {
+                return Err($crate::ops::OpError::IncorrectInputType);
+            }
+        }
+    };
+
+    ($output:expr, mut $typed_output:ident, $block:block) => {
+        match $output {
+            $crate::ops::Output::FloatTensor(mut $typed_output) => $block,
+            $crate::ops::Output::IntTensor(mut $typed_output) => $block,
+            $crate::ops::Output::Int64Tensor(mut $typed_output) => $block,
+            $crate::ops::Output::StringTensor(_) | $crate::ops::Output::Sequence(_) => {
+                return Err($crate::ops::OpError::IncorrectInputType);
+            }
+        }
+    };
+}
+
 /// Convert a tensor with dynamic dimension count to an `NdTensorView`, or
 /// return an `OpError::InvalidValue` if the dimension count is incorrect.
 #[doc(hidden)]
@@ -642,6 +1042,75 @@ macro_rules! static_dims {
     }};
 }
 
+/// A constant operator input which has been converted ahead of time into a
+/// form that is faster to use. See [Operator::prepack].
+pub struct PrepackedInput {
+    kind: PrepackedKind,
+}
+
+enum PrepackedKind {
+    /// A matrix packed for use as the "B" input of a GEMM.
+    FloatBMatrix(PackedBMatrix),
+
+    /// A sparse "B" input of a matrix multiplication, stored transposed.
+    SparseBMatrix(CsrMatrix),
+}
+
+impl PrepackedInput {
+    /// Pack a matrix for use as the "B" input of GEMMs performed by
+    /// [GemmExecutor::new].
+    pub(crate) fn float_b_matrix(b: NdTensorView<f32, 2>) -> PrepackedInput {
+        PrepackedInput {
+            kind: PrepackedKind::FloatBMatrix(GemmExecutor::new().prepack_b(b)),
+        }
+    }
+
+    /// Convert a sparse matrix for use as the "B" input of [sparse_matmul].
+    ///
+    /// [sparse_matmul]: crate::sparse::sparse_matmul
+    pub(crate) fn sparse_b_matrix(b: NdTensorView<f32, 2>) -> PrepackedInput {
+        PrepackedInput {
+            kind: PrepackedKind::SparseBMatrix(CsrMatrix::from_dense(b.transposed())),
+        }
+    }
+
+    /// Return the packed "B" matrix, if this is one.
+    pub(crate) fn as_float_b_matrix(&self) -> Option<&PackedBMatrix> {
+        match &self.kind {
+            PrepackedKind::FloatBMatrix(packed) => Some(packed),
+            PrepackedKind::SparseBMatrix(_) => None,
+        }
+    }
+
+    /// Return the transpose of a sparse "B" matrix, if this is one.
+    pub(crate) fn as_sparse_b_matrix(&self) -> Option<&CsrMatrix> {
+        match &self.kind {
+            PrepackedKind::FloatBMatrix(_) => None,
+            PrepackedKind::SparseBMatrix(b_t) => Some(b_t),
+        }
+    }
+}
+
+impl Debug for PrepackedInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            PrepackedKind::FloatBMatrix(packed) => write!(
+                f,
+                "PrepackedInput::FloatBMatrix({}x{})",
+                packed.rows(),
+                packed.cols()
+            ),
+            PrepackedKind::SparseBMatrix(b_t) => write!(
+                f,
+                "PrepackedInput::SparseBMatrix({}x{}, nnz {})",
+                b_t.cols(),
+                b_t.rows(),
+                b_t.nnz()
+            ),
+        }
+    }
+}
+
 /// An Operator performs a computation step when executing a data flow graph.
 ///
 /// Operators take zero or more dynamic input values, plus a set of static
@@ -659,6 +1128,53 @@ pub trait Operator: Debug {
     /// should be allocated from `pool`.
     fn run(&self, pool: &TensorPool, input: InputList) -> Result<Vec<Output>, OpError>;
 
+    /// Execute the operator, computing only the outputs that are used.
+    ///
+    /// `used_outputs[i]` is true if the i-th output is used. Operators which
+    /// can skip work for unused outputs (eg. an optional mask output) may
+    /// override this. Unused trailing outputs may be omitted from the result.
+    /// The values returned for other unused outputs are ignored.
+    ///
+    /// The default implementation calls [Operator::run].
+    fn run_with_used_outputs(
+        &self,
+        pool: &TensorPool,
+        input: InputList,
+        _used_outputs: &[bool],
+    ) -> Result<Vec<Output>, OpError> {
+        self.run(pool, input)
+    }
+
+    /// Return a copy of this operator which uses `seed` to initialize its
+    /// random number generator.
+    ///
+    /// This is used to make runs of operators which generate random values
+    /// reproducible when [RunOptions::seed](crate::RunOptions::seed) is set.
+    /// Operators which do not use random numbers, or which already have a
+    /// fixed seed, return `None`. This is the default.
+    fn with_seed(&self, _seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        None
+    }
+
+    /// Return the indices of inputs which this operator can prepack if they
+    /// are constants. See [Operator::prepack].
+    fn prepack_inputs(&self) -> SmallVec<[usize; 1]> {
+        SmallVec::new()
+    }
+
+    /// Convert the constant input at `index` into a form that is faster to
+    /// use when the operator is run, such as a packed GEMM weight matrix.
+    ///
+    /// This is called for each of the inputs returned by
+    /// [Operator::prepack_inputs] which is a constant, when a model is loaded
+    /// with [ModelOptions::prepack_weights](crate::ModelOptions::prepack_weights)
+    /// enabled. The result is stored with the graph node and made available
+    /// to the operator via [InputList::get_prepacked]. Returns `None` if
+    /// the input cannot be prepacked.
+    fn prepack(&self, _index: usize, _input: Input) -> Option<PrepackedInput> {
+        None
+    }
+
     /// Return true if this operator supports in-place execution via
     /// `run_in_place`.
     ///
@@ -678,6 +1194,79 @@ pub trait Operator: Debug {
         false
     }
 
+    /// Return true if this operator efficiently supports inputs which are
+    /// broadcast views, ie. views where some strides are zero.
+    ///
+    /// If true, the graph executor may pass a broadcast view of a value to
+    /// this operator, instead of materializing the output of a broadcasting
+    /// operator such as [Expand]. See [Operator::broadcast_shape].
+    fn supports_broadcast_inputs(&self) -> bool {
+        false
+    }
+
+    /// Return the shape the first input is broadcast to, if the only effect
+    /// of this operator is to broadcast its first input.
+    ///
+    /// If this returns a shape, and all consumers of the output return true
+    /// from [Operator::supports_broadcast_inputs], the graph executor may
+    /// skip running this operator and pass consumers a broadcast view of the
+    /// first input instead.
+    fn broadcast_shape(&self, _inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        Ok(None)
+    }
+
+    /// Return true if this operator efficiently supports inputs which are
+    /// transposed views, ie. views whose axes have been permuted without
+    /// copying the data.
+    ///
+    /// If true, the graph executor may pass a transposed view of a value to
+    /// this operator, instead of materializing the output of a
+    /// [Transpose]. See [Operator::transpose_perm].
+    fn supports_transposed_inputs(&self) -> bool {
+        false
+    }
+
+    /// Return the permutation applied to the axes of the first input, if the
+    /// only effect of this operator is to transpose its first input.
+    ///
+    /// If this returns a permutation, and all consumers of the output return
+    /// true from [Operator::supports_transposed_inputs], the graph executor
+    /// may skip running this operator and pass consumers a transposed view
+    /// of the first input instead.
+    fn transpose_perm(&self, _inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        Ok(None)
+    }
+
+    /// Return the shape of the output, if the only effect of this operator is
+    /// to change the shape of its first input without reordering elements.
+    ///
+    /// If this returns a shape, the input is contiguous and the operator
+    /// cannot reuse the input's buffer by running in place (see
+    /// [Operator::run_in_place]), the graph executor may skip running this
+    /// operator and pass consumers a reshaped view of the first input
+    /// instead. Unlike broadcast and transposed views, reshaped views are
+    /// contiguous, so consumers do not need to opt in.
+    fn reshape_shape(&self, _inputs: InputList) -> Result<Option<Vec<usize>>, OpError> {
+        Ok(None)
+    }
+
+    /// Infer the shapes of this operator's outputs from the shapes of its
+    /// inputs, without running it.
+    ///
+    /// `inputs` contains the shape of each input, or `None` if the input is
+    /// omitted or its shape is unknown. Returns `Ok(None)` if the output
+    /// shapes cannot be determined, or an error if the input shapes are known
+    /// to be incompatible.
+    ///
+    /// This is used to validate graphs before they are run. The default
+    /// implementation returns `Ok(None)`.
+    fn infer_shapes(
+        &self,
+        _inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(None)
+    }
+
     /// Execute this operator in-place on an existing tensor.
     ///
     /// This may only be called if `can_run_in_place` returns true.
@@ -699,6 +1288,11 @@ pub trait Operator: Debug {
     }
 }
 
+/// Shape of a value whose dimensions may be fixed or symbolic.
+///
+/// This is used for shape inference. See [Operator::infer_shapes].
+pub type SymbolicShape = Vec<Dimension>;
+
 /// List of inputs for an operator evaluation.
 ///
 /// Conceptually this is like a `&[Option<Input>]` with methods to conveniently
@@ -706,25 +1300,98 @@ pub trait Operator: Debug {
 /// the wrong type.
 ///
 /// An InputList can be constructed from a tensor reference or tuple of tensor
-/// references using `into`.
+/// references using `into`, or collected from an iterator of optional inputs.
 pub struct InputList<'a> {
-    inputs: Vec<Option<Input<'a>>>,
+    inputs: InputVec<'a>,
+
+    /// Prepacked forms of inputs, indexed by input position. See
+    /// [Operator::prepack].
+    prepacked: &'a [Option<PrepackedInput>],
+}
+
+/// Maximum number of inputs that an [InputList] stores inline.
+const INLINE_INPUTS: usize = 4;
+
+/// Storage for the inputs in an [InputList].
+///
+/// Most operators have only a few inputs, so these are stored inline to avoid
+/// a heap allocation for each operator that is run. This is used instead of
+/// `SmallVec` because `SmallVec` is invariant over its element type, which
+/// would prevent an `InputList<'a>` from being used where a shorter lifetime
+/// is expected.
+#[allow(clippy::large_enum_variant)]
+enum InputVec<'a> {
+    Inline {
+        len: usize,
+        items: [Option<Input<'a>>; INLINE_INPUTS],
+    },
+    Heap(Vec<Option<Input<'a>>>),
+}
+
+impl<'a> InputVec<'a> {
+    fn new() -> Self {
+        InputVec::Inline {
+            len: 0,
+            items: Default::default(),
+        }
+    }
+
+    fn push(&mut self, item: Option<Input<'a>>) {
+        match self {
+            InputVec::Inline { len, items } if *len < INLINE_INPUTS => {
+                items[*len] = item;
+                *len += 1;
+            }
+            InputVec::Inline { items, .. } => {
+                let mut vec: Vec<_> = items.iter_mut().map(|item| item.take()).collect();
+                vec.push(item);
+                *self = InputVec::Heap(vec);
+            }
+            InputVec::Heap(vec) => vec.push(item),
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for InputVec<'a> {
+    type Target = [Option<Input<'a>>];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            InputVec::Inline { len, items } => &items[..*len],
+            InputVec::Heap(vec) => vec,
+        }
+    }
 }
 
 impl<'a> InputList<'a> {
     /// Construct an empty input list.
     pub fn new() -> InputList<'static> {
-        InputList { inputs: vec![] }
+        InputList {
+            inputs: InputVec::new(),
+            prepacked: &[],
+        }
     }
 
     pub fn from<'b>(inputs: &[Input<'b>]) -> InputList<'b> {
-        InputList {
-            inputs: inputs.iter().cloned().map(Some).collect(),
-        }
+        inputs.iter().cloned().map(Some).collect()
     }
 
     pub fn from_optional(inputs: Vec<Option<Input>>) -> InputList {
-        InputList { inputs }
+        inputs.into_iter().collect()
+    }
+
+    /// Attach the prepacked forms of inputs, indexed by input position.
+    pub fn with_prepacked(mut self, prepacked: &'a [Option<PrepackedInput>]) -> Self {
+        self.prepacked = prepacked;
+        self
+    }
+
+    /// Get the prepacked form of the input at `index`, if available.
+    ///
+    /// This is only available for constant inputs which were prepacked when
+    /// the model was loaded. See [Operator::prepack].
+    pub fn get_prepacked(&self, index: usize) -> Option<&'a PrepackedInput> {
+        self.prepacked.get(index).and_then(|p| p.as_ref())
     }
 
     /// Get an optional input.
@@ -776,6 +1443,45 @@ impl<'a> InputList<'a> {
         self.require(index).and_then(|input| input.try_into())
     }
 
+    /// Get an optional input which contains indices, axes or a shape, as an
+    /// `i32` tensor.
+    ///
+    /// ONNX uses `int64` for these inputs. `i64` tensors are converted with
+    /// values saturated to the `i32` range, which preserves the meaning of
+    /// `INT64_MIN` and `INT64_MAX` when they are used to mean "unbounded", as
+    /// in `Slice`. Other values outside the `i32` range are out of bounds for
+    /// any tensor dimension and so are still rejected by the operator.
+    pub fn get_as_index(
+        &self,
+        index: usize,
+    ) -> Result<Option<TensorBase<CowData<'a, i32>, DynLayout>>, OpError> {
+        match self.get(index) {
+            Some(Input::IntTensor(t)) => Ok(Some(t.as_cow())),
+            Some(Input::Int64Tensor(t)) => Ok(Some(
+                t.map(|&x| x.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+                    .into_cow(),
+            )),
+            Some(
+                Input::FloatTensor(_)
+                | Input::StringTensor(_)
+                | Input::Sequence(_)
+                | Input::BFloat16Tensor(_),
+            ) => Err(OpError::IncorrectInputType),
+            None => Ok(None),
+        }
+    }
+
+    /// Get a required input which contains indices, axes or a shape, as an
+    /// `i32` tensor.
+    ///
+    /// See [InputList::get_as_index].
+    pub fn require_as_index(
+        &self,
+        index: usize,
+    ) -> Result<TensorBase<CowData<'a, i32>, DynLayout>, OpError> {
+        self.get_as_index(index)?.ok_or(OpError::MissingInputs)
+    }
+
     /// Return an iterator over provided inputs.
     ///
     /// If the InputList was constructed with `from_optional`, this will skip
@@ -783,6 +1489,27 @@ impl<'a> InputList<'a> {
     pub fn iter<'b>(&'b self) -> impl Iterator<Item = Input<'a>> + 'b {
         self.inputs.iter().filter_map(|inp| inp.clone())
     }
+
+    /// Return an iterator over all input positions.
+    ///
+    /// Unlike [InputList::iter], this yields `None` for omitted optional
+    /// inputs, so the position of each item matches its input index.
+    pub fn iter_optional<'b>(&'b self) -> impl Iterator<Item = Option<Input<'a>>> + 'b {
+        self.inputs.iter().cloned()
+    }
+}
+
+impl<'a> FromIterator<Option<Input<'a>>> for InputList<'a> {
+    fn from_iter<I: IntoIterator<Item = Option<Input<'a>>>>(iter: I) -> InputList<'a> {
+        let mut inputs = InputVec::new();
+        for item in iter {
+            inputs.push(item);
+        }
+        InputList {
+            inputs,
+            prepacked: &[],
+        }
+    }
 }
 
 impl<'a, I: Into<Input<'a>>> From<I> for InputList<'a> {
@@ -850,13 +1577,24 @@ pub fn resolve_axes<'a, I: ExactSizeIterator<Item = &'a i32>>(
     Ok(resolved_axes)
 }
 
+/// Convert a size or index to an `i32`, for use in the output of operators
+/// such as `Shape` or `ArgMax`, which return int tensors.
+///
+/// Returns an error instead of wrapping around if the value is too large, as
+/// can happen with tensors that have more than `i32::MAX` elements.
+fn index_to_i32(index: usize) -> Result<i32, OpError> {
+    index
+        .try_into()
+        .map_err(|_| OpError::UnsupportedValue("Size or index exceeds i32::MAX"))
+}
+
 #[cfg(test)]
 mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::test_util::{expect_equal_with_tolerance, ExpectEqualError};
-    use rten_tensor::NdTensor;
+    use rten_tensor::{NdTensor, Tensor};
 
-    use super::{Input, InputList, OpError, Operator, Output};
+    use super::{DataType, Input, InputList, OpError, Operator, Output, OutputTypeError, Sequence};
     use crate::tensor_pool::TensorPool;
 
     /// Create an empty tensor pool.
@@ -907,4 +1645,96 @@ mod tests {
         assert!(matches!(input, Input::FloatTensor(_)));
         assert_eq!(input.shape(), &[5, 5]);
     }
+
+    #[test]
+    fn test_input_list_collect() {
+        let tensor = NdTensor::<f32, 1>::arange(0., 8., None);
+
+        // Lists that fit inline and that spill to the heap should behave the
+        // same.
+        for len in [0, 3, 4, 5, 8] {
+            let inputs: InputList = (0..len)
+                .map(|i| (i % 3 != 1).then(|| tensor.slice::<1, _>(..i).into()))
+                .collect();
+            assert_eq!(inputs.iter_optional().count(), len);
+            for i in 0..len {
+                let input = inputs.get(i);
+                assert_eq!(input.is_some(), i % 3 != 1);
+                if let Some(input) = input {
+                    assert_eq!(input.shape(), &[i]);
+                }
+            }
+            assert!(inputs.get(len).is_none());
+        }
+    }
+
+    #[test]
+    fn test_input_list_get_as_index() {
+        let int32_indices = NdTensor::from([1, -2, 3]);
+        let int64_indices = NdTensor::from([1i64, -2, i64::MAX, i64::MIN]);
+        let floats = NdTensor::from([1., 2.]);
+        let inputs = InputList::from(&[
+            int32_indices.view().into(),
+            int64_indices.view().into(),
+            floats.view().into(),
+        ]);
+
+        let indices = inputs.require_as_index(0).unwrap();
+        assert_eq!(indices.to_vec(), [1, -2, 3]);
+
+        // `i64` values are saturated to the `i32` range.
+        let indices = inputs.require_as_index(1).unwrap();
+        assert_eq!(indices.to_vec(), [1, -2, i32::MAX, i32::MIN]);
+
+        assert_eq!(
+            inputs.require_as_index(2).err(),
+            Some(OpError::IncorrectInputType)
+        );
+        assert!(inputs.get_as_index(3).unwrap().is_none());
+        assert_eq!(
+            inputs.require_as_index(3).err(),
+            Some(OpError::MissingInputs)
+        );
+    }
+
+    #[test]
+    fn test_output_into_tensor() {
+        let output: Output = Tensor::from([1., 2., 3.]).into();
+        let tensor = output.clone().into_tensor::<f32>().unwrap();
+        assert_eq!(tensor.to_vec(), [1., 2., 3.]);
+        let tensor = output.clone().into_ndtensor::<f32, 1>().unwrap();
+        assert_eq!(tensor.to_vec(), [1., 2., 3.]);
+
+        let err = output.clone().into_tensor::<i32>().err().unwrap();
+        assert_eq!(
+            err,
+            OutputTypeError::IncorrectType {
+                expected: DataType::Int32,
+                actual: DataType::Float,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected tensor with element type Int32 but found Float"
+        );
+
+        let err = output.into_ndtensor::<f32, 2>().err().unwrap();
+        assert_eq!(
+            err,
+            OutputTypeError::IncorrectRank {
+                expected: 2,
+                actual: 1,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected tensor with 2 dims but found 1 dims"
+        );
+
+        let output: Output = Sequence::new(DataType::Float).into();
+        assert_eq!(
+            output.into_tensor::<f32>().err(),
+            Some(OutputTypeError::Sequence)
+        );
+    }
 }
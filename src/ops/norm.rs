@@ -1,11 +1,11 @@
 use rayon::prelude::*;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, Tensor, TensorView};
-use rten_vecmath::vec_softmax_in_place;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+use rten_vecmath::{vec_softmax_in_place, vec_sum_square, vec_sum_square_sub};
 use smallvec::SmallVec;
 
-use crate::ops::{add, mul, reduce_mean, sub};
+use crate::ops::{add, mul, reduce_mean, reduce_var, sub};
 use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
 use crate::slice_reductions::{slice_max, slice_sum};
 use crate::static_dims;
@@ -163,7 +163,7 @@ pub fn instance_normalization_in_place(
         ));
     }
 
-    // Needed for `slice_sum` below.
+    // Needed for `slice_sum` and `vec_sum_square_sub` below.
     input.make_contiguous();
 
     for n in 0..batch {
@@ -171,15 +171,9 @@ pub fn instance_normalization_in_place(
             let mut slice = input.slice_mut_dyn([n, c]);
             let chan_scale = scale[[c]];
             let chan_bias = bias[[c]];
-            let chan_mean = slice_sum(slice.data().unwrap()) / slice.len() as f32;
-            let chan_variance = slice
-                .iter()
-                .map(|x| {
-                    let diff = *x - chan_mean;
-                    diff * diff
-                })
-                .sum::<f32>()
-                / slice.len() as f32;
+            let chan_data = slice.data().unwrap();
+            let chan_mean = slice_sum(chan_data) / chan_data.len() as f32;
+            let chan_variance = vec_sum_square_sub(chan_data, chan_mean) / chan_data.len() as f32;
 
             // The instance norm formula, from the ONNX spec, is:
             //
@@ -276,15 +270,15 @@ pub fn layer_normalization(
         true, /* keep_dims */
     )?
     .auto_return(pool);
-    let d = sub(pool, input, mean.view())?.auto_return(pool);
-    let dd = mul(pool, d.view(), d.view())?.auto_return(pool);
-    let var = reduce_mean(
+    let var = reduce_var(
         pool,
-        dd.view(),
+        input.view(),
         Some(normalized_axes.as_slice()),
         true, /* keep_dims */
+        0,    /* correction */
     )?
     .auto_return(pool);
+    let d = sub(pool, input, mean.view())?.auto_return(pool);
     let inverse_std_dev = var
         .map_in(pool, |x| 1. / (x + epsilon).sqrt())
         .auto_return(pool);
@@ -322,6 +316,178 @@ impl Operator for LayerNormalization {
     }
 }
 
+/// Normalize 1D lanes of a tensor along `axis` to have unit Lp norm.
+///
+/// `p` must be 1 or 2. Lanes whose norm is zero are set to zero.
+pub fn lp_normalization(
+    pool: &TensorPool,
+    input: TensorView,
+    axis: isize,
+    p: u32,
+) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor_in(pool);
+    lp_normalization_in_place(&mut output, axis, p)?;
+    Ok(output)
+}
+
+pub fn lp_normalization_in_place(output: &mut Tensor, axis: isize, p: u32) -> Result<(), OpError> {
+    let norm: fn(&[f32]) -> f32 = match p {
+        1 => |lane| lane.iter().map(|x| x.abs()).sum(),
+        2 => |lane| vec_sum_square(lane).sqrt(),
+        _ => return Err(OpError::InvalidValue("`p` must be 1 or 2")),
+    };
+    softmax_lanes(output, axis, |lane| {
+        let norm = norm(lane);
+        let scale = if norm != 0. { 1. / norm } else { 0. };
+        for el in lane.iter_mut() {
+            *el *= scale;
+        }
+    })
+}
+
+#[derive(Debug)]
+pub struct LpNormalization {
+    pub axis: isize,
+    pub p: u32,
+}
+
+impl Operator for LpNormalization {
+    fn name(&self) -> &str {
+        "LpNormalization"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        lp_normalization(pool, input, self.axis, self.p).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut output = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        lp_normalization_in_place(&mut output, self.axis, self.p)?;
+        Ok(output.into())
+    }
+}
+
+/// Normalize the input to have zero mean and unit variance over `axes`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__MeanVarianceNormalization.html>.
+pub fn mean_variance_normalization(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: &[i32],
+) -> Result<Tensor, OpError> {
+    // Epsilon used by the ONNX function definition of this operator.
+    const EPSILON: f32 = 1e-9;
+
+    let mean = reduce_mean(pool, input.view(), Some(axes), true /* keep_dims */)?.auto_return(pool);
+    let d = sub(pool, input, mean.view())?.auto_return(pool);
+    let dd = mul(pool, d.view(), d.view())?.auto_return(pool);
+    let var = reduce_mean(pool, dd.view(), Some(axes), true /* keep_dims */)?.auto_return(pool);
+    let inverse_std_dev = var
+        .map_in(pool, |x| 1. / (x + EPSILON).sqrt())
+        .auto_return(pool);
+    mul(pool, d.view(), inverse_std_dev.view())
+}
+
+#[derive(Debug)]
+pub struct MeanVarianceNormalization {
+    pub axes: Vec<i32>,
+}
+
+impl Operator for MeanVarianceNormalization {
+    fn name(&self) -> &str {
+        "MeanVarianceNormalization"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        mean_variance_normalization(pool, input, &self.axes).into_op_result()
+    }
+}
+
+/// Normalize each channel of the `NC*` tensor `input` in-place using a
+/// per-channel mean and standard deviation.
+///
+/// This computes `(x - mean[c]) / std[c]` for each element `x` in channel `c`.
+/// It is typically used to preprocess images for a model.
+pub fn normalize_in_place(input: &mut Tensor, mean: &[f32], std: &[f32]) -> Result<(), OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    let chans = input.size(1);
+    if mean.len() != chans || std.len() != chans {
+        return Err(OpError::InvalidValue(
+            "`mean` and `std` must have one value per channel",
+        ));
+    }
+
+    for mut item in input.axis_iter_mut(0) {
+        for (c, mut chan) in item.axis_iter_mut(0).enumerate() {
+            let chan_mean = mean[c];
+            let inv_std = 1. / std[c];
+            chan.apply(|x| (x - chan_mean) * inv_std);
+        }
+    }
+
+    Ok(())
+}
+
+/// Normalize each channel of the `NC*` tensor `input` using a per-channel
+/// mean and standard deviation.
+///
+/// See [normalize_in_place].
+pub fn normalize(
+    pool: &TensorPool,
+    input: TensorView,
+    mean: &[f32],
+    std: &[f32],
+) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor_in(pool);
+    normalize_in_place(&mut output, mean, std)?;
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct Normalize {
+    pub mean: Vec<f32>,
+    pub std: Vec<f32>,
+}
+
+impl Operator for Normalize {
+    fn name(&self) -> &str {
+        "Normalize"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        normalize(pool, input, &self.mean, &self.std).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut output = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        normalize_in_place(&mut output, &self.mean, &self.std)?;
+        Ok(output.into())
+    }
+}
+
 pub fn log_softmax(pool: &TensorPool, input: TensorView, axis: isize) -> Result<Tensor, OpError> {
     let mut output = input.to_tensor_in(pool);
     log_softmax_in_place(&mut output, axis)?;
@@ -354,11 +520,13 @@ fn softmax_lanes<F: Fn(&mut [f32]) + Send + Sync>(
         output.size(output.ndim() - 1)
     };
 
-    output
-        .data_mut()
-        .unwrap()
-        .par_chunks_mut(lane_size)
-        .for_each(apply_op);
+    if lane_size > 0 {
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(lane_size)
+            .for_each(apply_op);
+    }
 
     if resolved_axis != output.ndim() - 1 {
         output.move_axis(output.ndim() - 1, resolved_axis);
@@ -437,6 +605,29 @@ pub fn softmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpError>
     Ok(())
 }
 
+/// Compute softmax over the last axis of a tensor.
+///
+/// This is a variant of [softmax] for inputs whose rank is known at compile
+/// time. Since the axis does not need to be resolved at runtime, this cannot
+/// fail.
+pub fn softmax_last_axis<const N: usize>(
+    pool: &TensorPool,
+    input: NdTensorView<f32, N>,
+) -> NdTensor<f32, N> {
+    const { assert!(N > 0, "input must have at least one dimension") };
+
+    let mut output = input.to_tensor_in(pool);
+    let lane_size = output.size(N - 1);
+    if lane_size > 0 {
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(lane_size)
+            .for_each(vec_softmax_in_place);
+    }
+    output
+}
+
 #[derive(Debug)]
 pub struct Softmax {
     pub axis: isize,
@@ -475,13 +666,14 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::{tensor, Tensor};
+    use rten_tensor::{tensor, NdTensor, Tensor};
 
     use crate::ops::tests::{expect_eq_1e4, new_pool};
     use crate::ops::OpError;
     use crate::ops::{
         batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-        softmax,
+        lp_normalization, mean_variance_normalization, normalize, softmax, softmax_last_axis,
+        InputList, Normalize, Operator,
     };
 
     #[test]
@@ -684,6 +876,95 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_lp_normalization() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!((3, 2); [3., 4., 0., 0., 1., -2.]);
+
+        // L2 norm over last axis. Lanes with a zero norm are set to zero.
+        let result = lp_normalization(&pool, input.view(), -1, 2).unwrap();
+        let expected = tensor!((3, 2); [0.6, 0.8, 0., 0., 0.4472, -0.8944]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // L1 norm over last axis.
+        let result = lp_normalization(&pool, input.view(), 1, 1).unwrap();
+        let expected = tensor!((3, 2); [0.4286, 0.5714, 0., 0., 0.3333, -0.6667]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // L2 norm over first axis.
+        let result = lp_normalization(&pool, input.view(), 0, 2).unwrap();
+        let expected = tensor!((3, 2); [0.9487, 0.8944, 0., 0., 0.3162, -0.4472]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Unsupported norm.
+        let result = lp_normalization(&pool, input.view(), 0, 3);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("`p` must be 1 or 2"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_variance_normalization() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!((1, 2, 2, 2); [1., 2., 3., 4., 10., 10., 10., 10.]);
+
+        // Normalize each channel.
+        let result = mean_variance_normalization(&pool, input.view(), &[0, 2, 3]).unwrap();
+        let expected = tensor!((1, 2, 2, 2); [
+            -1.3416, -0.4472, 0.4472, 1.3416, // Channel 0
+            0., 0., 0., 0., // Channel 1
+        ]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Normalize each row.
+        let result = mean_variance_normalization(&pool, input.view(), &[-1]).unwrap();
+        let expected = tensor!((1, 2, 2, 2); [-1., 1., -1., 1., 0., 0., 0., 0.]);
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!((2, 2, 1, 2); [0., 1., 2., 3., 4., 5., 6., 7.]);
+        let mean = [1., 2.];
+        let std = [0.5, 2.];
+        let expected = tensor!((2, 2, 1, 2); [
+            -2., 0., 0., 0.5, // Item 0
+            6., 8., 2., 2.5, // Item 1
+        ]);
+
+        let result = normalize(&pool, input.view(), &mean, &std)?;
+        expect_equal(&result, &expected)?;
+
+        // In-place operator
+        let op = Normalize {
+            mean: mean.into(),
+            std: std.into(),
+        };
+        let result = op
+            .run_in_place(&pool, input.into(), InputList::new())?
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &expected)?;
+
+        // Mismatched parameter count
+        let input = Tensor::zeros(&[1, 3, 2, 2]);
+        let result = normalize(&pool, input.view(), &mean, &std);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`mean` and `std` must have one value per channel"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_softmax() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -722,6 +1003,29 @@ mod tests {
     }
 
     // Test softmax with non-contiguous input.
+    #[test]
+    fn test_softmax_last_axis() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        let input = NdTensor::rand([2, 3, 5], &mut rng);
+        let result = softmax_last_axis(&pool, input.view());
+        let expected = softmax(&pool, input.as_dyn(), -1)?;
+        expect_equal(&result.as_dyn(), &expected.view())?;
+
+        // Transposed input
+        let result = softmax_last_axis(&pool, input.transposed());
+        let expected = softmax(&pool, input.as_dyn().transposed(), -1)?;
+        expect_equal(&result.as_dyn(), &expected.view())?;
+
+        // Empty lanes
+        let input = NdTensor::<f32, 2>::zeros([2, 0]);
+        let result = softmax_last_axis(&pool, input.view());
+        assert_eq!(result.shape(), [2, 0]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_softmax_transposed() -> Result<(), Box<dyn Error>> {
         let mut input = Tensor::from_data(
@@ -0,0 +1,339 @@
+//! Machine-readable descriptions of the built-in operators.
+//!
+//! This is used by tools to report whether a model is compatible with RTen,
+//! without needing to maintain a separate list of supported operators.
+
+use crate::ops::DataType;
+
+/// Describes a built-in operator and the subset of its ONNX specification
+/// which RTen supports.
+#[derive(Clone, Debug)]
+pub struct OpInfo {
+    /// Operator type name. This matches the name of the ONNX operator.
+    pub name: &'static str,
+
+    /// Data types supported for the main input(s) of the operator.
+    ///
+    /// Some inputs may require a specific type (eg. shapes and indices are
+    /// usually `Int32`). See the operator's documentation for details.
+    pub dtypes: &'static [DataType],
+
+    /// Names of attributes which are read when the operator is loaded.
+    /// Other attributes are ignored.
+    pub attrs: &'static [&'static str],
+
+    /// Known differences from the ONNX specification.
+    pub limitations: &'static [&'static str],
+}
+
+impl OpInfo {
+    /// Return true if the operator supports inputs of type `dtype`.
+    pub fn supports_dtype(&self, dtype: DataType) -> bool {
+        self.dtypes.contains(&dtype)
+    }
+}
+
+const FLOAT: &[DataType] = &[DataType::Float];
+const INT: &[DataType] = &[DataType::Int32];
+const FLOAT_INT: &[DataType] = &[DataType::Float, DataType::Int32];
+const FLOAT_INT_INT64: &[DataType] = &[DataType::Float, DataType::Int32, DataType::Int64];
+const ALL: &[DataType] = &[
+    DataType::Float,
+    DataType::Int32,
+    DataType::Int64,
+    DataType::String,
+];
+
+const POOL_2D: &str = "Only 2D pooling (NCHW inputs) is supported";
+const CONV_TRANSPOSE_2D: &str = "Only 1D and 2D transposed convolutions are supported";
+const QLINEAR_CONV_2D: &str = "Only 2D quantized convolutions (NCHW inputs) are supported";
+const QLINEAR_MATMUL_2D: &str = "The `b` input must be a 2D matrix";
+
+macro_rules! op_info {
+    ($name:ident, $dtypes:expr $(, attrs: [$($attr:literal),*])? $(, limitations: [$($limit:expr),*])?) => {
+        OpInfo {
+            name: stringify!($name),
+            dtypes: $dtypes,
+            attrs: &[$($($attr),*)?],
+            limitations: &[$($($limit),*)?],
+        }
+    };
+}
+
+static OPS: &[OpInfo] = &[
+    op_info!(Abs, FLOAT_INT),
+    op_info!(Acos, FLOAT),
+    op_info!(Add, FLOAT_INT_INT64),
+    op_info!(And, INT),
+    op_info!(ArgMax, FLOAT, attrs: ["axis", "keep_dims"]),
+    op_info!(ArgMin, FLOAT, attrs: ["axis", "keep_dims"]),
+    op_info!(Asin, FLOAT),
+    op_info!(Atan, FLOAT),
+    op_info!(
+        AveragePool,
+        FLOAT,
+        attrs: ["kernel_size", "pad_mode", "pads", "count_include_pad", "strides", "dilations", "ceil_mode"],
+        limitations: [POOL_2D]
+    ),
+    op_info!(BatchNormalization, FLOAT, attrs: ["epsilon"]),
+    op_info!(BitShift, INT, attrs: ["direction"]),
+    op_info!(BitwiseAnd, INT),
+    op_info!(BitwiseNot, INT),
+    op_info!(BitwiseOr, INT),
+    op_info!(BitwiseXor, INT),
+    op_info!(Cast, ALL, attrs: ["to"]),
+    op_info!(CastLike, ALL),
+    op_info!(Ceil, FLOAT),
+    op_info!(Celu, FLOAT, attrs: ["alpha"]),
+    op_info!(ChannelShuffle, FLOAT_INT, attrs: ["groups"]),
+    op_info!(Clip, FLOAT_INT),
+    op_info!(Concat, FLOAT_INT, attrs: ["axis"]),
+    op_info!(ConcatFromSequence, FLOAT_INT_INT64, attrs: ["axis", "new_axis"]),
+    op_info!(ConstantOfShape, FLOAT_INT, attrs: ["value"]),
+    op_info!(
+        Conv,
+        FLOAT,
+        attrs: ["groups", "dilations", "pad_mode", "pads", "strides"]
+    ),
+    op_info!(
+        ConvTranspose,
+        FLOAT,
+        attrs: ["pad_mode", "pads", "groups", "strides", "dilations", "output_padding", "output_shape"],
+        limitations: [CONV_TRANSPOSE_2D]
+    ),
+    op_info!(Cos, FLOAT),
+    op_info!(CumSum, FLOAT_INT),
+    op_info!(Div, FLOAT_INT_INT64),
+    op_info!(
+        Dropout,
+        FLOAT_INT,
+        attrs: ["seed"],
+        limitations: ["Training mode requires the `random` feature and float inputs"]
+    ),
+    op_info!(Elu, FLOAT, attrs: ["alpha"]),
+    op_info!(Equal, FLOAT_INT_INT64),
+    op_info!(Erf, FLOAT),
+    op_info!(Exp, FLOAT),
+    op_info!(Expand, FLOAT_INT),
+    op_info!(EyeLike, FLOAT_INT, attrs: ["dtype", "k"]),
+    op_info!(Flatten, FLOAT_INT, attrs: ["axis"]),
+    op_info!(Floor, FLOAT),
+    op_info!(Gather, FLOAT_INT_INT64, attrs: ["axis"]),
+    op_info!(GatherBlockQuantized, INT, attrs: ["bits", "block_size"]),
+    op_info!(GatherElements, FLOAT_INT, attrs: ["axis"]),
+    op_info!(GatherND, FLOAT_INT, attrs: ["batch_dims"]),
+    op_info!(Gelu, FLOAT, attrs: ["approximate"]),
+    op_info!(
+        Gemm,
+        FLOAT,
+        attrs: ["alpha", "beta", "transpose_a", "transpose_b"]
+    ),
+    op_info!(GlobalAveragePool, FLOAT, limitations: [POOL_2D]),
+    op_info!(GlobalMaxPool, FLOAT, limitations: [POOL_2D]),
+    op_info!(Greater, FLOAT_INT_INT64),
+    op_info!(GreaterOrEqual, FLOAT_INT_INT64),
+    op_info!(
+        GridSample,
+        FLOAT,
+        attrs: ["mode", "padding_mode", "align_corners"],
+        limitations: ["Only 4D inputs are supported", "The \"cubic\" mode is not supported"]
+    ),
+    op_info!(
+        GRU,
+        FLOAT,
+        attrs: ["direction", "hidden_size", "linear_before_reset"],
+        limitations: ["Activation functions, clipping and sequence lengths are not supported"]
+    ),
+    op_info!(HardSigmoid, FLOAT, attrs: ["alpha", "beta"]),
+    op_info!(HardSwish, FLOAT),
+    op_info!(Identity, ALL),
+    op_info!(ImageToTensor, FLOAT_INT, attrs: ["scale", "mean", "std"]),
+    op_info!(InstanceNormalization, FLOAT, attrs: ["epsilon"]),
+    op_info!(LayerNormalization, FLOAT, attrs: ["axis", "epsilon"]),
+    op_info!(LeakyRelu, FLOAT, attrs: ["alpha"]),
+    op_info!(Less, FLOAT_INT_INT64),
+    op_info!(LessOrEqual, FLOAT_INT_INT64),
+    op_info!(Log, FLOAT),
+    op_info!(LogSoftmax, FLOAT, attrs: ["axis"]),
+    op_info!(LpNormalization, FLOAT, attrs: ["axis", "p"]),
+    op_info!(
+        LpPool,
+        FLOAT,
+        attrs: ["kernel_size", "pad_mode", "pads", "strides", "dilations", "ceil_mode", "p"],
+        limitations: [POOL_2D]
+    ),
+    op_info!(
+        LSTM,
+        FLOAT,
+        attrs: ["direction", "hidden_size"],
+        limitations: ["Activation functions, clipping, peepholes and sequence lengths are not supported"]
+    ),
+    op_info!(MatMul, FLOAT),
+    op_info!(MatMulNBits, FLOAT, attrs: ["bits", "block_size"]),
+    op_info!(Max, FLOAT_INT),
+    op_info!(
+        MaxPool,
+        FLOAT,
+        attrs: ["kernel_size", "pad_mode", "pads", "strides", "dilations", "ceil_mode"],
+        limitations: [POOL_2D, "The `Indices` output is not supported"]
+    ),
+    op_info!(Mean, FLOAT),
+    op_info!(MeanVarianceNormalization, FLOAT, attrs: ["axes"]),
+    op_info!(Min, FLOAT_INT),
+    op_info!(Mish, FLOAT),
+    op_info!(Mod, FLOAT_INT_INT64, attrs: ["fmod"]),
+    op_info!(Mul, FLOAT_INT_INT64),
+    #[cfg(feature = "random")]
+    op_info!(Multinomial, FLOAT, attrs: ["dtype", "sample_size", "seed"]),
+    op_info!(Neg, FLOAT_INT),
+    op_info!(NonMaxSuppression, FLOAT, attrs: ["box_order"]),
+    op_info!(NonZero, FLOAT_INT),
+    op_info!(Normalize, FLOAT, attrs: ["mean", "std"]),
+    op_info!(Not, INT),
+    op_info!(OneHot, FLOAT_INT, attrs: ["axis"]),
+    op_info!(Optional, ALL),
+    op_info!(OptionalGetElement, ALL),
+    op_info!(OptionalHasElement, ALL),
+    op_info!(Or, INT),
+    op_info!(Pad, FLOAT_INT, attrs: ["mode"]),
+    op_info!(PermuteChannels, FLOAT_INT, attrs: ["order"]),
+    op_info!(Pow, FLOAT),
+    op_info!(
+        QLinearConv,
+        INT,
+        attrs: ["pad_mode", "pads", "groups", "strides", "dilations", "output_dtype"],
+        limitations: [QLINEAR_CONV_2D]
+    ),
+    op_info!(QLinearMatMul, INT, attrs: ["output_dtype"], limitations: [QLINEAR_MATMUL_2D]),
+    #[cfg(feature = "random")]
+    op_info!(RandomNormal, FLOAT, attrs: ["shape", "mean", "scale", "seed"]),
+    #[cfg(feature = "random")]
+    op_info!(RandomNormalLike, FLOAT, attrs: ["mean", "scale", "seed"]),
+    #[cfg(feature = "random")]
+    op_info!(RandomUniform, FLOAT, attrs: ["shape", "high", "low", "seed"]),
+    #[cfg(feature = "random")]
+    op_info!(RandomUniformLike, FLOAT, attrs: ["high", "low", "seed"]),
+    op_info!(Range, FLOAT_INT),
+    op_info!(Reciprocal, FLOAT),
+    op_info!(ReduceL2, FLOAT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceLogSumExp, FLOAT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceMax, FLOAT_INT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceMean, FLOAT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceMin, FLOAT_INT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceProd, FLOAT_INT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceStd, FLOAT, attrs: ["axes", "keep_dims", "correction"]),
+    op_info!(ReduceSum, FLOAT_INT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceSumSquare, FLOAT_INT, attrs: ["axes", "keep_dims"]),
+    op_info!(ReduceVar, FLOAT, attrs: ["axes", "keep_dims", "correction"]),
+    op_info!(Relu, FLOAT),
+    op_info!(Reshape, FLOAT_INT_INT64, attrs: ["allow_zero"]),
+    op_info!(
+        Resize,
+        FLOAT,
+        attrs: ["mode", "nearest_mode", "coord_mode", "antialias", "axes", "extrapolation_value"],
+        limitations: ["The \"cubic\" mode is not supported"]
+    ),
+    op_info!(Round, FLOAT),
+    op_info!(ScaledDotProductAttention, FLOAT, attrs: ["scale"]),
+    op_info!(
+        Scan,
+        ALL,
+        attrs: [
+            "body",
+            "num_scan_inputs",
+            "scan_input_axes",
+            "scan_input_directions",
+            "scan_output_axes",
+            "scan_output_directions"
+        ],
+        limitations: [
+            "The body can only reference constants from the outer graph",
+            "String scan outputs are not supported"
+        ]
+    ),
+    op_info!(ScatterElements, FLOAT_INT, attrs: ["axis", "reduction"]),
+    op_info!(ScatterND, FLOAT_INT, attrs: ["reduction"]),
+    op_info!(Selu, FLOAT, attrs: ["alpha", "gamma"]),
+    op_info!(SequenceAt, ALL),
+    op_info!(SequenceConstruct, ALL),
+    op_info!(SequenceInsert, ALL),
+    op_info!(SequenceLength, ALL),
+    op_info!(Shape, FLOAT_INT),
+    op_info!(Sigmoid, FLOAT),
+    op_info!(Sign, FLOAT_INT),
+    op_info!(Sin, FLOAT),
+    op_info!(Size, FLOAT_INT),
+    op_info!(Slice, FLOAT_INT_INT64),
+    op_info!(Softmax, FLOAT, attrs: ["axis"]),
+    op_info!(Softplus, FLOAT),
+    op_info!(Softsign, FLOAT),
+    op_info!(Split, FLOAT_INT, attrs: ["axis", "num_outputs"]),
+    op_info!(SplitToSequence, FLOAT_INT_INT64, attrs: ["axis", "keep_dims"]),
+    op_info!(Sqrt, FLOAT),
+    op_info!(Squeeze, FLOAT_INT),
+    op_info!(Sub, FLOAT_INT_INT64),
+    op_info!(Sum, FLOAT_INT),
+    op_info!(Tan, FLOAT),
+    op_info!(Tanh, FLOAT),
+    op_info!(ThresholdedRelu, FLOAT, attrs: ["alpha"]),
+    op_info!(Tile, FLOAT_INT),
+    op_info!(TopK, FLOAT_INT, attrs: ["axis", "largest", "sorted"]),
+    op_info!(Transpose, FLOAT_INT, attrs: ["perm"]),
+    op_info!(Trilu, FLOAT_INT, attrs: ["upper"]),
+    op_info!(Unsqueeze, FLOAT_INT),
+    op_info!(Where, FLOAT_INT_INT64),
+    op_info!(Xor, INT),
+];
+
+/// Return descriptions of all the built-in operators supported by this build
+/// of RTen, sorted by name.
+///
+/// This includes operators that are only available when optional crate
+/// features are enabled, if those features are enabled.
+pub fn registry() -> &'static [OpInfo] {
+    OPS
+}
+
+/// Return the description of the built-in operator with a given ONNX name.
+pub fn op_info(name: &str) -> Option<&'static OpInfo> {
+    OPS.iter().find(|op| op.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{op_info, registry};
+    use crate::ops::DataType;
+    use crate::OpRegistry;
+
+    #[test]
+    fn test_registry_matches_op_registry() {
+        let op_registry = OpRegistry::with_all_ops();
+        let mut expected: Vec<_> = op_registry.op_types().collect();
+        expected.sort();
+
+        let names: Vec<_> = registry().iter().map(|op| op.name).collect();
+
+        // Registry should be sorted by name, ignoring case.
+        let mut sorted = names.clone();
+        sorted.sort_by_key(|name| name.to_lowercase());
+        assert_eq!(names, sorted);
+
+        // Registry should contain exactly the operators in `OpRegistry`.
+        sorted.sort();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn test_op_info() {
+        let conv = op_info("Conv").unwrap();
+        assert!(conv.supports_dtype(DataType::Float));
+        assert!(!conv.supports_dtype(DataType::Int32));
+        assert!(conv.attrs.contains(&"strides"));
+
+        let add = op_info("Add").unwrap();
+        assert!(add.supports_dtype(DataType::Int32));
+        assert!(add.attrs.is_empty());
+
+        assert!(op_info("NotAnOperator").is_none());
+    }
+}
@@ -4,11 +4,11 @@ use rten_tensor::prelude::*;
 use rten_tensor::{MutLayout, NdTensorView, Storage, Tensor, TensorBase, TensorView};
 
 use crate::number::{Identities, IsInt};
-use crate::ops::OpError;
 use crate::ops::{
     arg_max, div, matmul, mul, pad, reduce_l2, reduce_max, reduce_mean, reduce_min, reduce_sum,
     resize_image, softmax, topk,
 };
+use crate::ops::{OpError, PadMode};
 use crate::tensor_pool::TensorPool;
 use crate::threading::thread_pool;
 
@@ -124,7 +124,7 @@ impl<T: Send, S: Storage<Elem = T>, L: MutLayout> Operators for TensorBase<S, L>
         Self::Elem: Copy,
     {
         let view = self.as_dyn();
-        use_thread_pool(move || pad(&TensorPool::new(), view, &padding, val))
+        use_thread_pool(move || pad(&TensorPool::new(), view, &padding, PadMode::Constant, val))
     }
 
     fn topk(
@@ -0,0 +1,103 @@
+//! Operators for optional values.
+//!
+//! Optional values are represented as operator inputs which may be absent. An
+//! empty optional is an input which is `None`, such as an optional graph input
+//! which was not provided when the graph was run.
+
+use rten_tensor::Tensor;
+
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
+use crate::tensor_pool::TensorPool;
+
+/// Wrap a value as an optional.
+///
+/// Since optional values are represented by inputs which may be absent, this
+/// returns the input unchanged. ONNX `Optional` operators with no input, which
+/// produce an empty optional, are removed when a model is converted and uses
+/// of their output become absent inputs.
+#[derive(Debug)]
+pub struct Optional {}
+
+impl Operator for Optional {
+    fn name(&self) -> &str {
+        "Optional"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        input.to_output_in(pool).into_op_result()
+    }
+}
+
+/// Return a boolean scalar indicating whether an optional input is present.
+#[derive(Debug)]
+pub struct OptionalHasElement {}
+
+impl Operator for OptionalHasElement {
+    fn name(&self) -> &str {
+        "OptionalHasElement"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let mut output = Tensor::zeros_in(pool, &[]);
+        output[[]] = inputs.get(0).is_some() as i32;
+        output.into_op_result()
+    }
+}
+
+/// Return the value of an optional input, or an error if it is absent.
+#[derive(Debug)]
+pub struct OptionalGetElement {}
+
+impl Operator for OptionalGetElement {
+    fn name(&self) -> &str {
+        "OptionalGetElement"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs
+            .get(0)
+            .ok_or(OpError::InvalidValue("Optional input has no value"))?;
+        input.to_output_in(pool).into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::tensor;
+
+    use super::{Optional, OptionalGetElement, OptionalHasElement};
+    use crate::ops::tests::new_pool;
+    use crate::ops::{InputList, OpError, Operator};
+
+    #[test]
+    fn test_optional_ops() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let x = tensor!([1., 2., 3.]);
+        let present = || InputList::from(&[x.view().into()]);
+        let absent = || InputList::from_optional(vec![None]);
+
+        let result = Optional {}.run(&pool, present())?;
+        assert_eq!(result[0].as_float_ref().unwrap(), &x);
+
+        let result = OptionalHasElement {}.run(&pool, present())?;
+        assert_eq!(result[0].as_int_ref().unwrap(), &tensor!(1));
+        let result = OptionalHasElement {}.run(&pool, absent())?;
+        assert_eq!(result[0].as_int_ref().unwrap(), &tensor!(0));
+        let result = OptionalHasElement {}.run(&pool, InputList::new())?;
+        assert_eq!(result[0].as_int_ref().unwrap(), &tensor!(0));
+
+        let result = OptionalGetElement {}.run(&pool, present())?;
+        assert_eq!(result[0].as_float_ref().unwrap(), &x);
+        let result = OptionalGetElement {}.run(&pool, absent());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Optional input has no value"))
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,93 @@
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, SliceItem, Tensor, TensorView};
+use rten_tensor::{NdTensor, NdTensorView, SliceItem, Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::static_dims;
-use crate::tensor_pool::TensorPool;
+use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{map_input, static_dims};
 
+/// Specifies how the values of padded elements are determined.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PadMode {
+    /// Pad with a constant value.
+    #[default]
+    Constant,
+
+    /// Pad with values mirrored across the edge of the input, excluding the
+    /// edge element itself.
+    Reflect,
+
+    /// Pad by repeating the edge element.
+    Edge,
+
+    /// Pad with values taken from the opposite side of the input, as if it
+    /// was tiled.
+    Wrap,
+}
+
+/// Map an index along an axis of the padded output, offset so that the
+/// input starts at zero, to an index along an input axis of size `len`.
+///
+/// Returns `None` if the element should be filled with the constant value.
+fn source_index(mode: PadMode, index: isize, len: usize) -> Option<usize> {
+    let len = len as isize;
+    if (0..len).contains(&index) {
+        return Some(index as usize);
+    }
+    let index = match mode {
+        PadMode::Constant => return None,
+        PadMode::Edge => index.clamp(0, len - 1),
+        PadMode::Reflect if len == 1 => 0,
+        PadMode::Reflect => {
+            let period = 2 * (len - 1);
+            let index = index.rem_euclid(period);
+            if index < len {
+                index
+            } else {
+                period - index
+            }
+        }
+        PadMode::Wrap => index.rem_euclid(len),
+    };
+    Some(index as usize)
+}
+
+/// Fill `output` with elements of `input`, using `offsets` to map indices
+/// along each output axis to offsets in `input`.
+///
+/// `base` is the offset in `input` of the first element of `output`, or
+/// `None` if `output` lies entirely in a constant-padded region.
+fn fill_padded<T: Copy>(
+    output: &mut [T],
+    input: &[T],
+    offsets: &[Vec<Option<usize>>],
+    base: Option<usize>,
+    const_val: T,
+) {
+    let Some((axis_offsets, inner_offsets)) = offsets.split_first() else {
+        output[0] = base.map(|base| input[base]).unwrap_or(const_val);
+        return;
+    };
+    let Some(base) = base else {
+        output.fill(const_val);
+        return;
+    };
+    let inner_len = output.len() / axis_offsets.len();
+    for (out_chunk, offset) in output.chunks_mut(inner_len).zip(axis_offsets) {
+        let chunk_base = offset.map(|offset| base + offset);
+        fill_padded(out_chunk, input, inner_offsets, chunk_base, const_val);
+    }
+}
+
+/// Pad a tensor.
+///
+/// `padding` specifies the amount of padding to add at the start of each
+/// axis, followed by the amount to add at the end of each axis. Negative
+/// amounts remove elements from the corresponding edge.
 pub fn pad<T: Copy>(
     pool: &TensorPool,
     input: TensorView<T>,
     padding: &NdTensorView<i32, 1>,
+    mode: PadMode,
     const_val: T,
 ) -> Result<Tensor<T>, OpError> {
     if padding.size(0) != input.ndim() * 2 {
@@ -16,41 +95,99 @@ pub fn pad<T: Copy>(
             "padding length should be 2 * input dims",
         ));
     }
-    if !padding.iter().all(|x| *x >= 0) {
-        return Err(OpError::InvalidValue("Pad only supports positive pads"));
+
+    let mut out_shape = Vec::with_capacity(input.ndim());
+    for (i, &size) in input.shape().iter().enumerate() {
+        let start_pad = padding[[i]] as isize;
+        let end_pad = padding[[input.ndim() + i]] as isize;
+        let out_size = start_pad + size as isize + end_pad;
+        if out_size < 0 {
+            return Err(OpError::InvalidValue(
+                "Negative pads exceed the size of the input",
+            ));
+        }
+        if size == 0 && out_size > 0 && mode != PadMode::Constant {
+            return Err(OpError::InvalidValue(
+                "Cannot pad empty axis with non-constant mode",
+            ));
+        }
+        out_shape.push(out_size as usize);
     }
 
-    let out_shape: Vec<_> = input
-        .shape()
-        .iter()
-        .enumerate()
-        .map(|(i, size)| {
-            let start_pad = padding[[i]] as usize;
-            let end_pad = padding[[input.ndim() + i]] as usize;
-            start_pad + size + end_pad
-        })
-        .collect();
+    if mode == PadMode::Constant && padding.iter().all(|x| *x >= 0) {
+        let non_pad_region: Vec<SliceItem> = input
+            .shape()
+            .iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let start_pad = padding[[i]] as usize;
+                (start_pad..start_pad + size).into()
+            })
+            .collect();
+
+        let mut output = Tensor::full_in(pool, &out_shape, const_val);
+        output
+            .slice_mut_dyn(non_pad_region.as_slice())
+            .copy_from(&input);
+
+        return Ok(output);
+    }
+
+    let len = out_shape.iter().product();
+    let mut data = pool.alloc(len);
+    data.resize(len, const_val);
+    if len == 0 {
+        return Ok(Tensor::from_data(&out_shape, data));
+    }
 
-    let non_pad_region: Vec<SliceItem> = input
-        .shape()
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let offsets: Vec<Vec<Option<usize>>> = out_shape
         .iter()
         .enumerate()
-        .map(|(i, size)| {
-            let start_pad = padding[[i]] as usize;
-            (start_pad..start_pad + size).into()
+        .map(|(i, &out_size)| {
+            let start_pad = padding[[i]] as isize;
+            let size = input.size(i);
+            let stride = input.stride(i);
+            (0..out_size as isize)
+                .map(|x| source_index(mode, x - start_pad, size).map(|idx| idx * stride))
+                .collect()
         })
         .collect();
+    fill_padded(
+        &mut data,
+        input.data().unwrap(),
+        &offsets,
+        Some(0),
+        const_val,
+    );
 
-    let mut output = Tensor::full_in(pool, &out_shape, const_val);
-    output
-        .slice_mut_dyn(non_pad_region.as_slice())
-        .copy_from(&input);
+    Ok(Tensor::from_data(&out_shape, data))
+}
 
-    Ok(output)
+/// Expand pads for a subset of axes into pads for all `ndim` axes.
+fn expand_pads(
+    pads: NdTensorView<i32, 1>,
+    axes: NdTensorView<i32, 1>,
+    ndim: usize,
+) -> Result<NdTensor<i32, 1>, OpError> {
+    if pads.size(0) != axes.size(0) * 2 {
+        return Err(OpError::InvalidValue(
+            "padding length should be 2 * number of axes",
+        ));
+    }
+    let mut full_pads = NdTensor::zeros([ndim * 2]);
+    for (i, &axis) in axes.iter().enumerate() {
+        let axis = resolve_axis(ndim, axis as isize)?;
+        full_pads[[axis]] = pads[[i]];
+        full_pads[[ndim + axis]] = pads[[axes.size(0) + i]];
+    }
+    Ok(full_pads)
 }
 
 #[derive(Debug)]
-pub struct Pad {}
+pub struct Pad {
+    pub mode: PadMode,
+}
 
 impl Operator for Pad {
     fn name(&self) -> &str {
@@ -63,22 +200,19 @@ impl Operator for Pad {
         let pads = static_dims!(pads, 1)?;
         let axes = inputs.get_as::<i32>(3)?;
 
-        if axes.is_some() {
-            return Err(OpError::UnsupportedValue(
-                "Pad operator does not yet support `axes` input",
-            ));
-        }
+        let full_pads;
+        let pads = if let Some(axes) = axes {
+            let axes = static_dims!(axes, 1)?;
+            full_pads = expand_pads(pads, axes, input.ndim())?;
+            full_pads.view()
+        } else {
+            pads
+        };
 
-        match input {
-            Input::IntTensor(t) => {
-                let const_val = inputs.get_as_scalar::<i32>(2)?;
-                pad(pool, t, &pads, const_val.unwrap_or(0)).into_op_result()
-            }
-            Input::FloatTensor(t) => {
-                let const_val = inputs.get_as_scalar::<f32>(2)?;
-                pad(pool, t, &pads, const_val.unwrap_or(0.0)).into_op_result()
-            }
-        }
+        map_input!(input, t, {
+            let const_val = inputs.get_as_scalar(2)?;
+            pad(pool, t, &pads, self.mode, const_val.unwrap_or_default()).into_op_result()
+        })
     }
 }
 
@@ -88,10 +222,10 @@ mod tests {
 
     use rten_tensor::prelude::*;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::Tensor;
+    use rten_tensor::{tensor, Tensor};
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{pad, OpError, Operator, Pad};
+    use crate::ops::{pad, InputList, OpError, Operator, Pad, PadMode};
 
     fn from_slice<T: Clone>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
@@ -110,18 +244,32 @@ mod tests {
             ],
         );
         let const_pads = &[1, 1, 1, 1];
-        let result = pad(&pool, input.view(), &const_pads.into(), 0.0).unwrap();
+        let result = pad(
+            &pool,
+            input.view(),
+            &const_pads.into(),
+            PadMode::Constant,
+            0.0,
+        )
+        .unwrap();
         expect_equal(&result, &expected)?;
 
         // Zero padding (no-op)
         let zero_pads = &[0, 0, 0, 0];
-        let result = pad(&pool, input.view(), &zero_pads.into(), 0.0).unwrap();
+        let result = pad(
+            &pool,
+            input.view(),
+            &zero_pads.into(),
+            PadMode::Constant,
+            0.0,
+        )
+        .unwrap();
         expect_equal(&result, &input)?;
 
         // Un-even padding
         let input = Tensor::from_data(&[1, 2, 2], vec![1, 2, 3, 4]);
         let pads = &[0, 0, 0, 0, 1, 0];
-        let result = pad(&pool, input.view(), &pads.into(), 0).unwrap();
+        let result = pad(&pool, input.view(), &pads.into(), PadMode::Constant, 0).unwrap();
         assert_eq!(result.shape(), &[1, 3, 2]);
         assert_eq!(result.data().unwrap(), &[1, 2, 3, 4, 0, 0]);
 
@@ -139,11 +287,132 @@ mod tests {
             ],
         );
         let const_pads = &[1, 1, 1, 1];
-        let result = pad(&pool, input.view(), &const_pads.into(), 9.).unwrap();
+        let result = pad(
+            &pool,
+            input.view(),
+            &const_pads.into(),
+            PadMode::Constant,
+            9.,
+        )
+        .unwrap();
         expect_equal(&result, &expected)?;
         Ok(())
     }
 
+    #[test]
+    fn test_pad_modes() {
+        struct Case {
+            mode: PadMode,
+            pads: Vec<i32>,
+            expected: Tensor<i32>,
+        }
+
+        let input = tensor!((2, 3); [1, 2, 3, 4, 5, 6]);
+
+        let cases = [
+            Case {
+                mode: PadMode::Reflect,
+                pads: vec![1, 2, 1, 2],
+                expected: tensor!((4, 7); [
+                    6, 5, 4, 5, 6, 5, 4, // 1
+                    3, 2, 1, 2, 3, 2, 1, // 2
+                    6, 5, 4, 5, 6, 5, 4, // 3
+                    3, 2, 1, 2, 3, 2, 1, // 4
+                ]),
+            },
+            Case {
+                mode: PadMode::Edge,
+                pads: vec![1, 2, 1, 2],
+                expected: tensor!((4, 7); [
+                    1, 1, 1, 2, 3, 3, 3, // 1
+                    1, 1, 1, 2, 3, 3, 3, // 2
+                    4, 4, 4, 5, 6, 6, 6, // 3
+                    4, 4, 4, 5, 6, 6, 6, // 4
+                ]),
+            },
+            Case {
+                mode: PadMode::Wrap,
+                pads: vec![1, 2, 1, 2],
+                expected: tensor!((4, 7); [
+                    5, 6, 4, 5, 6, 4, 5, // 1
+                    2, 3, 1, 2, 3, 1, 2, // 2
+                    5, 6, 4, 5, 6, 4, 5, // 3
+                    2, 3, 1, 2, 3, 1, 2, // 4
+                ]),
+            },
+            // Reflect padding larger than the input size.
+            Case {
+                mode: PadMode::Reflect,
+                pads: vec![0, 4, 0, 0],
+                expected: tensor!((2, 7); [
+                    1, 2, 3, 2, 1, 2, 3, // 1
+                    4, 5, 6, 5, 4, 5, 6, // 2
+                ]),
+            },
+            // Negative pads remove elements.
+            Case {
+                mode: PadMode::Constant,
+                pads: vec![0, -1, 0, 0],
+                expected: tensor!((2, 2); [2, 3, 5, 6]),
+            },
+            Case {
+                mode: PadMode::Reflect,
+                pads: vec![0, -1, 0, 1],
+                expected: tensor!((2, 3); [2, 3, 2, 5, 6, 5]),
+            },
+        ];
+
+        let pool = new_pool();
+        for Case {
+            mode,
+            pads,
+            expected,
+        } in cases
+        {
+            let pads = Tensor::from_vec(pads);
+            let result = pad(&pool, input.view(), &pads.nd_view(), mode, 0).unwrap();
+            assert_eq!(result, expected, "mode {:?}", mode);
+
+            // Non-contiguous input.
+            let transposed = input.transposed().to_tensor();
+            let result = pad(&pool, transposed.transposed(), &pads.nd_view(), mode, 0).unwrap();
+            assert_eq!(result, expected, "mode {:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_pad_axes() {
+        let pool = new_pool();
+        let input = tensor!((2, 3); [1, 2, 3, 4, 5, 6]);
+        let pads = tensor!([1, 1]);
+        let const_val = Tensor::from_scalar(9);
+        let expected = tensor!((2, 5); [
+            9, 1, 2, 3, 9, // 1
+            9, 4, 5, 6, 9, // 2
+        ]);
+        let op = Pad {
+            mode: PadMode::Constant,
+        };
+
+        for axes in [tensor!([1]), tensor!([-1])] {
+            let result = op
+                .run(
+                    &pool,
+                    InputList::from(&[
+                        (&input).into(),
+                        (&pads).into(),
+                        (&const_val).into(),
+                        (&axes).into(),
+                    ]),
+                )
+                .unwrap()
+                .remove(0)
+                .into_int()
+                .unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
     #[test]
     fn test_pad_op() -> Result<(), Box<dyn Error>> {
         let input = Tensor::from_data(&[2, 2], vec![1.0, 2.0, 3.0, 4.0]);
@@ -156,7 +425,9 @@ mod tests {
         );
 
         let pool = new_pool();
-        let op = Pad {};
+        let op = Pad {
+            mode: PadMode::Constant,
+        };
         let result = op
             .run(&pool, (&input, &pads).into())
             .unwrap()
@@ -172,7 +443,9 @@ mod tests {
     fn test_pad_invalid_inputs() {
         let pool = new_pool();
         let input = Tensor::from_data(&[2, 2], vec![1.0, 2.0, 3.0, 4.0]);
-        let op = Pad {};
+        let op = Pad {
+            mode: PadMode::Constant,
+        };
 
         // Wrong padding vector length.
         let invalid_pads = from_slice(&[1]);
@@ -184,12 +457,48 @@ mod tests {
             ))
         );
 
-        // Unsupported padding amounts.
-        let invalid_pads = from_slice(&[1, 1, 1, -1]);
+        // Negative padding larger than the input.
+        let invalid_pads = from_slice(&[0, -3, 0, 0]);
         let result = op.run(&pool, (&input, &invalid_pads).into());
         assert_eq!(
             result.err(),
-            Some(OpError::InvalidValue("Pad only supports positive pads"))
+            Some(OpError::InvalidValue(
+                "Negative pads exceed the size of the input"
+            ))
+        );
+
+        // Wrong padding vector length for axes.
+        let axes = from_slice(&[0]);
+        let pads = from_slice(&[1, 1, 1, 1]);
+        let const_val = Tensor::from_scalar(0.);
+        let result = op.run(
+            &pool,
+            InputList::from(&[
+                (&input).into(),
+                (&pads).into(),
+                (&const_val).into(),
+                (&axes).into(),
+            ]),
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "padding length should be 2 * number of axes"
+            ))
+        );
+
+        // Non-constant padding of an empty axis.
+        let empty = Tensor::<f32>::zeros(&[0, 2]);
+        let pads = from_slice(&[1, 0, 0, 0]);
+        let op = Pad {
+            mode: PadMode::Edge,
+        };
+        let result = op.run(&pool, (&empty, &pads).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Cannot pad empty axis with non-constant mode"
+            ))
         );
 
         // Wrong constant value type.
@@ -0,0 +1,208 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView};
+
+use crate::ops::OpError;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Paste per-instance masks into a full-size image.
+///
+/// This is the post-processing step for instance segmentation models such as
+/// Mask R-CNN, which predict a small, fixed-size mask for each detected box.
+///
+/// `masks` has shape `[N, mask_h, mask_w]` and `boxes` has shape `[N, 4]`
+/// with `[x1, y1, x2, y2]` coordinates in image pixels. Models which output
+/// masks with shape `[N, 1, mask_h, mask_w]` should remove the channel
+/// dimension first.
+///
+/// Each mask is resized with bilinear interpolation to fit its box, and
+/// pixels outside the box are set to zero. If `threshold` is set, the output
+/// is binarized, with values greater than the threshold set to 1 and all
+/// others set to 0.
+///
+/// Returns a tensor of shape `[N, height, width]` where `[height, width]` is
+/// `image_size`. This matches the behavior of `paste_masks_in_image` in
+/// torchvision.
+pub fn paste_masks(
+    pool: &TensorPool,
+    masks: NdTensorView<f32, 3>,
+    boxes: NdTensorView<f32, 2>,
+    image_size: [usize; 2],
+    threshold: Option<f32>,
+) -> Result<NdTensor<f32, 3>, OpError> {
+    let [n_masks, mask_h, mask_w] = masks.shape();
+    let [n_boxes, n_coords] = boxes.shape();
+
+    if n_coords != 4 {
+        return Err(OpError::InvalidValue(
+            "`boxes` last dimension should have size 4",
+        ));
+    }
+    if n_masks != n_boxes {
+        return Err(OpError::IncompatibleInputShapes(
+            "`masks` and `boxes` have different lengths",
+        ));
+    }
+
+    let [height, width] = image_size;
+    let mut output = NdTensor::zeros_in(pool, [n_masks, height, width]);
+    if output.is_empty() || mask_h == 0 || mask_w == 0 {
+        return Ok(output);
+    }
+
+    let masks = masks.to_contiguous_in(pool).auto_return(pool);
+
+    // Get the mask value at `(y, x)`, or zero if outside the mask.
+    let pixel = |mask: &[f32], y: isize, x: isize| -> f32 {
+        if (0..mask_h as isize).contains(&y) && (0..mask_w as isize).contains(&x) {
+            mask[y as usize * mask_w + x as usize]
+        } else {
+            0.
+        }
+    };
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(height * width)
+        .zip(masks.data().unwrap().par_chunks(mask_h * mask_w))
+        .enumerate()
+        .for_each(|(n, (out_img, mask))| {
+            let [x1, y1, x2, y2] = boxes.slice(n).to_array();
+            let (box_w, box_h) = (x2 - x1, y2 - y1);
+            if box_w <= 0. || box_h <= 0. {
+                return;
+            }
+
+            // Range of output pixels affected by the mask. This extends one
+            // pixel beyond the box so that the mask edges are interpolated
+            // with the zero padding around them.
+            let clip = |coord: f32, size: usize| (coord.max(0.) as usize).min(size);
+            let x_range = clip(x1.floor() - 1., width)..clip(x2.ceil() + 1., width);
+            let y_range = clip(y1.floor() - 1., height)..clip(y2.ceil() + 1., height);
+
+            for y in y_range {
+                let src_y = (y as f32 + 0.5 - y1) / box_h * mask_h as f32 - 0.5;
+                let y0 = src_y.floor();
+                let y_weight = src_y - y0;
+                let y0 = y0 as isize;
+
+                let out_row = &mut out_img[y * width..(y + 1) * width];
+                for x in x_range.clone() {
+                    let src_x = (x as f32 + 0.5 - x1) / box_w * mask_w as f32 - 0.5;
+                    let x0 = src_x.floor();
+                    let x_weight = src_x - x0;
+                    let x0 = x0 as isize;
+
+                    let top =
+                        pixel(mask, y0, x0) * (1. - x_weight) + pixel(mask, y0, x0 + 1) * x_weight;
+                    let bottom = pixel(mask, y0 + 1, x0) * (1. - x_weight)
+                        + pixel(mask, y0 + 1, x0 + 1) * x_weight;
+                    let value = top * (1. - y_weight) + bottom * y_weight;
+
+                    out_row[x] = match threshold {
+                        Some(threshold) => (value > threshold) as i32 as f32,
+                        None => value,
+                    };
+                }
+            }
+        });
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::prelude::*;
+    use rten_tensor::NdTensor;
+
+    use crate::ops::tests::new_pool;
+    use crate::ops::{paste_masks, OpError};
+
+    #[test]
+    fn test_paste_masks() {
+        let pool = new_pool();
+
+        // A uniform mask pasted into a box aligned with the pixel grid should
+        // fill the box. Values fade out just outside the box edges due to
+        // interpolation with the zero padding around the mask.
+        let masks = NdTensor::full([1, 2, 2], 1.);
+        let boxes = NdTensor::from([[2., 1., 6., 5.]]);
+        let output = paste_masks(&pool, masks.view(), boxes.view(), [8, 8], None).unwrap();
+        assert_eq!(output.shape(), [1, 8, 8]);
+
+        for y in 0..8 {
+            for x in 0..8 {
+                let inside = (1..5).contains(&y) && (2..6).contains(&x);
+                let value = output[[0, y, x]];
+                if inside {
+                    assert!(value > 0.5, "expected high value at ({}, {})", y, x);
+                } else {
+                    assert!(value < 0.5, "expected low value at ({}, {})", y, x);
+                }
+            }
+        }
+
+        // Pixels in the interior of the box should equal the mask value, and
+        // pixels more than one pixel from the box should be zero.
+        assert_eq!(output[[0, 2, 3]], 1.);
+        assert_eq!(output[[0, 3, 4]], 1.);
+        assert_eq!(output[[0, 7, 7]], 0.);
+        assert_eq!(output[[0, 2, 0]], 0.);
+    }
+
+    #[test]
+    fn test_paste_masks_threshold() {
+        let pool = new_pool();
+
+        // Mask where the left half is set.
+        let masks = NdTensor::from([[[1., 0.], [1., 0.]]]);
+        let boxes = NdTensor::from([[0., 0., 4., 4.]]);
+        let output = paste_masks(&pool, masks.view(), boxes.view(), [4, 6], Some(0.5)).unwrap();
+
+        let expected = NdTensor::from([[
+            [1., 1., 0., 0., 0., 0.],
+            [1., 1., 0., 0., 0., 0.],
+            [1., 1., 0., 0., 0., 0.],
+            [1., 1., 0., 0., 0., 0.],
+        ]]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_paste_masks_clips_to_image() {
+        let pool = new_pool();
+
+        // Boxes which extend past the image, or are empty.
+        let masks = NdTensor::full([2, 4, 4], 1.);
+        let boxes = NdTensor::from([[-10., -10., 20., 20.], [3., 3., 3., 5.]]);
+        let output = paste_masks(&pool, masks.view(), boxes.view(), [5, 5], Some(0.5)).unwrap();
+
+        assert!(output.slice::<2, _>(0).iter().all(|&x| x == 1.));
+        assert!(output.slice::<2, _>(1).iter().all(|&x| x == 0.));
+    }
+
+    #[test]
+    fn test_paste_masks_invalid() {
+        let pool = new_pool();
+        let masks = NdTensor::zeros([2, 4, 4]);
+
+        let boxes = NdTensor::zeros([1, 4]);
+        let result = paste_masks(&pool, masks.view(), boxes.view(), [5, 5], None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`masks` and `boxes` have different lengths"
+            ))
+        );
+
+        let boxes = NdTensor::zeros([2, 3]);
+        let result = paste_masks(&pool, masks.view(), boxes.view(), [5, 5], None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`boxes` last dimension should have size 4"
+            ))
+        );
+    }
+}
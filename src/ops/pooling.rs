@@ -8,6 +8,7 @@ use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView, T
 
 use crate::check_dims;
 use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output, Padding};
+use crate::slice_reductions::slice_max;
 use crate::tensor_pool::TensorPool;
 
 /// Calculate the output size and padding for a convolution or pooling operation.
@@ -44,15 +45,19 @@ pub fn calc_output_size_and_padding(
         return Err(OpError::InvalidValue("Strides must be > 0"));
     }
 
+    if k_h == 0 || k_w == 0 {
+        return Err(OpError::InvalidValue("Kernel size must be > 0"));
+    }
+
     let (out_h, out_w, padding) = match padding {
         Padding::Same => {
             let out_h = in_h.div_ceil(stride_h);
             let out_w = in_w.div_ceil(stride_w);
 
-            let pad_total_h =
-                ((out_h - 1) * stride_h + (k_h - 1) * dilation_y + 1).saturating_sub(in_h);
-            let pad_total_w =
-                ((out_w - 1) * stride_w + (k_w - 1) * dilation_x + 1).saturating_sub(in_w);
+            let pad_total_h = (out_h.saturating_sub(1) * stride_h + (k_h - 1) * dilation_y + 1)
+                .saturating_sub(in_h);
+            let pad_total_w = (out_w.saturating_sub(1) * stride_w + (k_w - 1) * dilation_x + 1)
+                .saturating_sub(in_w);
 
             let pad_top = pad_total_h / 2;
             let pad_left = pad_total_w / 2;
@@ -91,22 +96,54 @@ pub fn calc_output_size_and_padding(
 /// Number of channels processed together by the pooling kernel.
 const CHAN_GROUP_SIZE: usize = 4;
 
+/// Calculate the output size of a pooling operation along one axis using
+/// `ceil` rather than `floor` rounding.
+///
+/// The last pooling window is dropped if it would start in the end padding
+/// region. This matches PyTorch and the ONNX reference implementation.
+fn ceil_mode_output_size(
+    in_size: usize,
+    kernel_size: usize,
+    stride: usize,
+    dilation: usize,
+    [pad_start, pad_end]: [usize; 2],
+) -> usize {
+    let dilated_kernel = dilation * (kernel_size - 1) + 1;
+    let padded_in = in_size + pad_start + pad_end;
+    let out_size = (padded_in - dilated_kernel).div_ceil(stride) + 1;
+    if (out_size - 1) * stride >= in_size + pad_start {
+        out_size - 1
+    } else {
+        out_size
+    }
+}
+
+/// Spatial parameters for a pooling operation.
+#[derive(Clone, Debug)]
+struct PoolParams {
+    kernel_size: [usize; 2],
+    strides: [usize; 2],
+    dilations: [usize; 2],
+    padding: Padding,
+    ceil_mode: bool,
+}
+
 /// Generic pooling implementation.
 ///
 /// The value of each output point is computed by:
 ///
-/// - Collecting values from `input`, with a window size and stride determined
-///   by `kernel_size` and `strides` respectively, except for values that are part
-///   of the padding region.
+/// - Collecting values from `input`, with a window size, stride and dilation
+///   determined by `params`, except for values that are part of the padding
+///   region.
 /// - Folding the values using `fold`, starting with `fold_init`
 /// - Computing an average of the accumulated value using `average(accum,
-///   non_padding_count)`
-fn pool_impl<T: Copy + Send, F: Fn(T, T) -> T + Sync, A: Fn(T, usize) -> T + Sync>(
+///   non_padding_count, padded_count)`, where `padded_count` also includes
+///   elements in the padding region, but not elements which are past the end
+///   of the padding region due to `ceil_mode`.
+fn pool_impl<T: Copy + Send, F: Fn(T, T) -> T + Sync, A: Fn(T, usize, usize) -> T + Sync>(
     pool: &TensorPool,
     input: TensorView<T>,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
-    padding: Padding,
+    params: PoolParams,
     fold_init: T,
     fold: &F,
     average: &A,
@@ -117,26 +154,56 @@ where
     for<'a> &'a T: Sync,
 {
     let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
+    let PoolParams {
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+    } = params;
+    let use_ceil = ceil_mode && matches!(padding, Padding::Fixed(_));
     let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
         (in_h, in_w),
         (kernel_size[0], kernel_size[1]),
         (strides[0], strides[1]),
         padding,
-        None, /* dilations */
+        Some((dilations[0], dilations[1])),
     )?;
-    let [pad_top, pad_left, _pad_bottom, _pad_right] = fixed_padding;
+    let [pad_top, pad_left, pad_bottom, pad_right] = fixed_padding;
+    let (out_h, out_w) = if use_ceil {
+        (
+            ceil_mode_output_size(
+                in_h,
+                kernel_size[0],
+                strides[0],
+                dilations[0],
+                [pad_top, pad_bottom],
+            ),
+            ceil_mode_output_size(
+                in_w,
+                kernel_size[1],
+                strides[1],
+                dilations[1],
+                [pad_left, pad_right],
+            ),
+        )
+    } else {
+        (out_h, out_w)
+    };
     let mut output = Tensor::uninit_in(pool, [batch, in_c, out_h, out_w].as_slice());
 
     // Apply pooling to the channel indexes specified by `chans`.
     // Assuming `N` is chosen appropriately the inner loop should get unrolled /
     // autovectorized.
-    fn pool_chans<T: Copy, F: Fn(T, T) -> T, A: Fn(T, usize) -> T, const N: usize>(
+    #[allow(clippy::too_many_arguments)]
+    fn pool_chans<T: Copy, F: Fn(T, T) -> T, A: Fn(T, usize, usize) -> T, const N: usize>(
         mut out: NdTensorViewMut<MaybeUninit<T>, 3>,
         in_view: NdTensorView<T, 3>,
         chans: [usize; N],
         [kernel_h, kernel_w]: [usize; 2],
         [stride_h, stride_w]: [usize; 2],
-        [pad_top, pad_left]: [usize; 2],
+        [dilation_y, dilation_x]: [usize; 2],
+        [pad_top, pad_left, pad_bottom, pad_right]: [usize; 4],
         fold_init: T,
         fold: F,
         average: A,
@@ -149,11 +216,16 @@ where
             for out_x in 0..out_w {
                 let mut accumulator = [fold_init; N];
                 let mut non_pad_elements = 0;
+                let mut padded_elements = 0;
 
                 for k_y in 0..kernel_h {
                     for k_x in 0..kernel_w {
-                        let in_y = out_y * stride_h + k_y;
-                        let in_x = out_x * stride_w + k_x;
+                        let in_y = out_y * stride_h + k_y * dilation_y;
+                        let in_x = out_x * stride_w + k_x * dilation_x;
+                        if in_y < in_h + pad_top + pad_bottom && in_x < in_w + pad_left + pad_right
+                        {
+                            padded_elements += 1;
+                        }
                         if in_y >= pad_top
                             && in_y < in_h + pad_top
                             && in_x >= pad_left
@@ -177,8 +249,11 @@ where
                     //  - We checked all `chans` are in-bounds
                     //  - `out_y` and `out_x` are in 0..out_h, 0..out_w
                     unsafe {
-                        out.get_unchecked_mut([chan, out_y, out_x])
-                            .write(average(accumulator[i], non_pad_elements));
+                        out.get_unchecked_mut([chan, out_y, out_x]).write(average(
+                            accumulator[i],
+                            non_pad_elements,
+                            padded_elements,
+                        ));
                     }
                 }
             }
@@ -208,7 +283,8 @@ where
                     [chan, chan + 1, chan + 2, chan + 3],
                     kernel_size,
                     strides,
-                    [pad_top, pad_left],
+                    dilations,
+                    fixed_padding,
                     accum_init_val(),
                     fold,
                     average,
@@ -224,7 +300,8 @@ where
                     [chan],
                     kernel_size,
                     strides,
-                    [pad_top, pad_left],
+                    dilations,
+                    fixed_padding,
                     accum_init_val(),
                     fold,
                     average,
@@ -238,26 +315,32 @@ where
     Ok(output)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn average_pool(
     pool: &TensorPool,
     input: TensorView,
     kernel_size: [usize; 2],
     strides: [usize; 2],
+    dilations: [usize; 2],
     padding: Padding,
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> Result<Tensor, OpError> {
-    let kernel_len = kernel_size[0] * kernel_size[1];
     pool_impl(
         pool,
         input,
-        kernel_size,
-        strides,
-        padding,
+        PoolParams {
+            kernel_size,
+            strides,
+            dilations,
+            padding,
+            ceil_mode,
+        },
         0.,
         &|acc, x| acc + x,
-        &|acc, non_pad_elements| {
+        &|acc, non_pad_elements, padded_elements| {
             if count_include_pad {
-                acc / (kernel_len as f32)
+                acc / (padded_elements as f32)
             } else {
                 acc / (non_pad_elements as f32)
             }
@@ -271,6 +354,8 @@ pub struct AveragePool {
     pub padding: Padding,
     pub count_include_pad: bool,
     pub strides: [usize; 2],
+    pub dilations: [usize; 2],
+    pub ceil_mode: bool,
 }
 
 impl Operator for AveragePool {
@@ -285,8 +370,10 @@ impl Operator for AveragePool {
             input,
             self.kernel_size,
             self.strides,
+            self.dilations,
             self.padding.clone(),
             self.count_include_pad,
+            self.ceil_mode,
         )
         .into_op_result()
     }
@@ -356,22 +443,157 @@ impl Operator for GlobalAveragePool {
     }
 }
 
+pub fn global_max_pool(pool: &TensorPool, input: TensorView) -> Result<Tensor, OpError> {
+    let [batch, chans, _in_h, _in_w] = check_dims!(input, 4, "NCHW");
+    if input.is_empty() {
+        return Err(OpError::InvalidValue("Cannot pool empty tensor"));
+    }
+
+    let mut output = NdTensor::uninit_in(pool, [batch, chans, 1, 1]);
+    let mut n_init = 0;
+
+    for (in_item, out_item) in zip(
+        input.inner_iter::<2>(),
+        output.reshaped_mut([batch * chans]).iter_mut(),
+    ) {
+        let max = if let Some(data) = in_item.data() {
+            slice_max(data)
+        } else {
+            in_item.iter().fold(f32::NEG_INFINITY, |acc, x| acc.max(*x))
+        };
+        out_item.write(max);
+        n_init += 1;
+    }
+
+    assert!(n_init == output.len());
+    let output = unsafe { output.assume_init() };
+
+    Ok(output.into_dyn())
+}
+
+#[derive(Debug)]
+pub struct GlobalMaxPool {}
+
+impl Operator for GlobalMaxPool {
+    fn name(&self) -> &str {
+        "GlobalMaxPool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        global_max_pool(pool, input).into_op_result()
+    }
+}
+
+/// Apply Lp pooling to `input`.
+///
+/// Each output value is the Lp norm, `(sum(|x|^p))^(1/p)`, of the values in
+/// the corresponding window of the input.
+#[allow(clippy::too_many_arguments)]
+pub fn lp_pool(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: [usize; 2],
+    strides: [usize; 2],
+    dilations: [usize; 2],
+    padding: Padding,
+    ceil_mode: bool,
+    p: u32,
+) -> Result<Tensor, OpError> {
+    if p == 0 {
+        return Err(OpError::InvalidValue("`p` must be > 0"));
+    }
+    let params = PoolParams {
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+    };
+    match p {
+        1 => pool_impl(
+            pool,
+            input,
+            params,
+            0.,
+            &|acc, x| acc + x.abs(),
+            &|acc, _non_pad_count, _padded_count| acc,
+        ),
+        2 => pool_impl(
+            pool,
+            input,
+            params,
+            0.,
+            &|acc, x| acc + x * x,
+            &|acc, _non_pad_count, _padded_count| acc.sqrt(),
+        ),
+        _ => {
+            let inv_p = 1. / p as f32;
+            pool_impl(
+                pool,
+                input,
+                params,
+                0.,
+                &|acc, x| acc + x.abs().powi(p as i32),
+                &|acc, _non_pad_count, _padded_count| acc.powf(inv_p),
+            )
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LpPool {
+    pub kernel_size: [usize; 2],
+    pub padding: Padding,
+    pub strides: [usize; 2],
+    pub dilations: [usize; 2],
+    pub ceil_mode: bool,
+    pub p: u32,
+}
+
+impl Operator for LpPool {
+    fn name(&self) -> &str {
+        "LpPool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        lp_pool(
+            pool,
+            input,
+            self.kernel_size,
+            self.strides,
+            self.dilations,
+            self.padding.clone(),
+            self.ceil_mode,
+            self.p,
+        )
+        .into_op_result()
+    }
+}
+
 pub fn max_pool(
     pool: &TensorPool,
     input: TensorView,
     kernel_size: [usize; 2],
     strides: [usize; 2],
+    dilations: [usize; 2],
     padding: Padding,
+    ceil_mode: bool,
 ) -> Result<Tensor, OpError> {
     pool_impl(
         pool,
         input,
-        kernel_size,
-        strides,
-        padding,
+        PoolParams {
+            kernel_size,
+            strides,
+            dilations,
+            padding,
+            ceil_mode,
+        },
         f32::NEG_INFINITY,
         &|acc, x| acc.max(x),
-        &|x, _non_pad_count| x,
+        &|x, _non_pad_count, _padded_count| x,
     )
 }
 
@@ -380,6 +602,8 @@ pub struct MaxPool {
     pub kernel_size: [usize; 2],
     pub padding: Padding,
     pub strides: [usize; 2],
+    pub dilations: [usize; 2],
+    pub ceil_mode: bool,
 }
 
 impl Operator for MaxPool {
@@ -394,7 +618,9 @@ impl Operator for MaxPool {
             input,
             self.kernel_size,
             self.strides,
+            self.dilations,
             self.padding.clone(),
+            self.ceil_mode,
         )
         .into_op_result()
     }
@@ -411,7 +637,9 @@ mod tests {
     use super::calc_output_size_and_padding;
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
-    use crate::ops::{average_pool, global_average_pool, max_pool, OpError, Padding};
+    use crate::ops::{
+        average_pool, global_average_pool, global_max_pool, lp_pool, max_pool, OpError, Padding,
+    };
 
     #[test]
     fn test_average_pool() -> Result<(), Box<dyn Error>> {
@@ -485,8 +713,10 @@ mod tests {
                 input.view(),
                 case.kernel_size,
                 case.strides,
+                [1, 1], /* dilations */
                 [0, 0, 0, 0].into(),
                 false, /* count_include_pad */
+                false, /* ceil_mode */
             )
             .unwrap();
             expect_equal(&result, &case.expected)?;
@@ -527,8 +757,10 @@ mod tests {
             input.as_dyn(),
             [2, 2],
             [2, 2], /* stride */
+            [1, 1], /* dilations */
             [1, 1, 1, 1].into(),
             false, /* count_include_pad */
+            false, /* ceil_mode */
         )
         .unwrap();
         expect_eq_1e4(&result.view(), &expected.as_dyn())?;
@@ -546,8 +778,10 @@ mod tests {
             input.as_dyn(),
             [2, 2],
             [2, 2], /* stride */
+            [1, 1], /* dilations */
             [1, 1, 1, 1].into(),
-            true, /* count_include_pad */
+            true,  /* count_include_pad */
+            false, /* ceil_mode */
         )
         .unwrap();
         expect_eq_1e4(&result.view(), &expected_include_pad.as_dyn())?;
@@ -565,6 +799,85 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_global_max_pool() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 2, 2, 2], vec![1., -2., 3., 4., -10., -20., -5., -40.]);
+        let expected = Tensor::from_data(&[1, 2, 1, 1], vec![4., -5.]);
+        let result = global_max_pool(&pool, input.view()).unwrap();
+        expect_equal(&result, &expected)?;
+
+        // Non-contiguous input
+        let result = global_max_pool(&pool, input.permuted(&[0, 1, 3, 2])).unwrap();
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lp_pool() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(
+            &[1, 1, 2, 4],
+            vec![
+                1., -2., 3., 0., // Y=0
+                -2., 4., 0., 4., // Y=1
+            ],
+        );
+
+        struct Case {
+            p: u32,
+            expected: Tensor,
+        }
+
+        let cases = [
+            Case {
+                p: 1,
+                expected: Tensor::from_data(&[1, 1, 1, 2], vec![9., 7.]),
+            },
+            Case {
+                p: 2,
+                expected: Tensor::from_data(&[1, 1, 1, 2], vec![5., 5.]),
+            },
+            Case {
+                p: 3,
+                expected: Tensor::from_data(
+                    &[1, 1, 1, 2],
+                    vec![81f32.powf(1. / 3.), 91f32.powf(1. / 3.)],
+                ),
+            },
+        ];
+
+        for case in cases {
+            let result = lp_pool(
+                &pool,
+                input.view(),
+                [2, 2],
+                [2, 2],
+                [1, 1],
+                [0, 0, 0, 0].into(),
+                false, /* ceil_mode */
+                case.p,
+            )
+            .unwrap();
+            expect_equal(&result, &case.expected)?;
+        }
+
+        let result = lp_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [0, 0, 0, 0].into(),
+            false, /* ceil_mode */
+            0,
+        );
+        assert_eq!(result.err(), Some(OpError::InvalidValue("`p` must be > 0")));
+
+        Ok(())
+    }
+
     #[test]
     fn test_max_pool() -> Result<(), Box<dyn Error>> {
         let input = Tensor::from_data(
@@ -636,7 +949,9 @@ mod tests {
                 input.view(),
                 case.kernel_size,
                 case.strides,
+                [1, 1], /* dilations */
                 [0, 0, 0, 0].into(),
+                false, /* ceil_mode */
             )
             .unwrap();
             expect_equal(&result, &case.expected)?;
@@ -650,22 +965,210 @@ mod tests {
         let pool = new_pool();
         let input = Tensor::zeros(&[1, 1, 9, 9]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [0, 0, 0, 0].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [0, 0, 0, 0].into(),
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 4, 4]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [1, 1, 1, 1].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [1, 1, 1, 1].into(),
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [2, 2, 2, 2].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [2, 2, 2, 2].into(),
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 6, 6]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], Padding::Same).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            Padding::Same,
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [3, 3], Padding::Same).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [3, 3],
+            [1, 1],
+            Padding::Same,
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 3, 3]);
     }
 
+    #[test]
+    fn test_pool_ceil_mode() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(
+            &[1, 1, 3, 3],
+            vec![
+                1., 2., 3., // Y=0
+                4., 5., 6., // Y=1
+                7., 8., 9., // Y=2
+            ],
+        );
+
+        // With floor rounding, the last row and column are not covered by any
+        // window.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [0, 0, 0, 0].into(),
+            false, /* ceil_mode */
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[1, 1, 1, 1]);
+
+        // With ceil rounding, partial windows are added at the end.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [0, 0, 0, 0].into(),
+            true, /* ceil_mode */
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 2], vec![5., 6., 8., 9.]),
+        )?;
+
+        // Elements past the end of the input are excluded from the average,
+        // even if `count_include_pad` is true.
+        for count_include_pad in [false, true] {
+            let result = average_pool(
+                &pool,
+                input.view(),
+                [2, 2],
+                [2, 2],
+                [1, 1],
+                [0, 0, 0, 0].into(),
+                count_include_pad,
+                true, /* ceil_mode */
+            )
+            .unwrap();
+            expect_equal(
+                &result,
+                &Tensor::from_data(&[1, 1, 2, 2], vec![3., 4.5, 7.5, 9.]),
+            )?;
+        }
+
+        // Windows which would start in the end padding are dropped.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [1, 1, 1, 1].into(),
+            true, /* ceil_mode */
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[1, 1, 2, 2]);
+
+        // With `count_include_pad`, the explicit padding is counted but the
+        // area past the end of the padding is not.
+        let result = average_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [2, 2],
+            [1, 1],
+            [1, 1, 0, 0].into(),
+            true, /* count_include_pad */
+            true, /* ceil_mode */
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 2], vec![0.25, 1.25, 2.75, 7.]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_dilations() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(
+            &[1, 1, 4, 4],
+            vec![
+                1., 2., 3., 4., // Y=0
+                5., 6., 7., 8., // Y=1
+                9., 10., 11., 12., // Y=2
+                13., 14., 15., 16., // Y=3
+            ],
+        );
+
+        let result = max_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [1, 1],
+            [2, 2],
+            [0, 0, 0, 0].into(),
+            false, /* ceil_mode */
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 2], vec![11., 12., 15., 16.]),
+        )?;
+
+        let result = average_pool(
+            &pool,
+            input.view(),
+            [2, 2],
+            [1, 1],
+            [2, 2],
+            [0, 0, 0, 0].into(),
+            false, /* count_include_pad */
+            false, /* ceil_mode */
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 2], vec![6., 7., 10., 11.]),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_calc_output_size_and_padding() {
         struct Case {
@@ -753,6 +1256,15 @@ mod tests {
                 padding: Padding::Same,
                 expected: Err(OpError::InvalidValue("Dilations must be > 0")),
             },
+            // Zero kernel size
+            Case {
+                in_size: (5, 5),
+                dilations: (1, 1),
+                strides: (1, 1),
+                kernel_size: (0, 3),
+                padding: Padding::Same,
+                expected: Err(OpError::InvalidValue("Kernel size must be > 0")),
+            },
             // Incorrect padding length
             Case {
                 in_size: (5, 5),
@@ -0,0 +1,473 @@
+//! Property tests which compare operators against naive reference
+//! implementations, using randomly generated shapes and layouts.
+//!
+//! Many operators have fast paths for common cases, such as contiguous inputs
+//! or broadcasts which can be expressed as cycles and repeats of an input.
+//! The unit tests for each operator cover a handful of hand-picked shapes,
+//! whereas these tests generate many combinations of shapes, strides and
+//! broadcasting to check that all paths agree with the general case.
+
+use proptest::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, Tensor, TensorView};
+
+use crate::ops::layout::transpose;
+use crate::ops::tests::new_pool;
+use crate::ops::{
+    add, add_in_place, concat, expand, mul, mul_in_place, reduce_max, reduce_mean, reduce_min,
+    reduce_sum, slice, sub, sub_in_place, where_op,
+};
+
+/// Maximum number of dimensions in generated tensors.
+const MAX_NDIM: usize = 4;
+
+/// Maximum size of each dimension in generated tensors.
+const MAX_DIM_SIZE: usize = 5;
+
+/// Test input with a given logical shape, whose storage may use a different
+/// dimension order.
+///
+/// Viewing the data with `perm` applied yields a tensor with non-contiguous
+/// strides whenever `perm` is not the identity permutation.
+#[derive(Clone, Debug)]
+struct TestTensor {
+    data: Tensor<i32>,
+    perm: Vec<usize>,
+}
+
+impl TestTensor {
+    fn view(&self) -> TensorView<'_, i32> {
+        self.data.permuted(self.perm.as_slice())
+    }
+
+    fn shape(&self) -> Vec<usize> {
+        self.view().shape().to_vec()
+    }
+}
+
+/// Generate a shape with up to [MAX_NDIM] dimensions.
+fn any_shape() -> impl Strategy<Value = Vec<usize>> {
+    prop::collection::vec(1..=MAX_DIM_SIZE, 0..=MAX_NDIM)
+}
+
+/// Generate a tensor with a given logical shape and a random layout.
+fn tensor_with_shape(shape: Vec<usize>) -> impl Strategy<Value = TestTensor> {
+    let ndim = shape.len();
+    let len: usize = shape.iter().product();
+    (
+        Just((0..ndim).collect::<Vec<_>>()).prop_shuffle(),
+        prop::collection::vec(-10i32..10, len),
+    )
+        .prop_map(move |(perm, data)| {
+            let mut storage_shape = vec![0; ndim];
+            for (dim, &storage_dim) in perm.iter().enumerate() {
+                storage_shape[storage_dim] = shape[dim];
+            }
+            TestTensor {
+                data: Tensor::from_data(&storage_shape, data),
+                perm,
+            }
+        })
+}
+
+fn any_tensor() -> impl Strategy<Value = TestTensor> {
+    any_shape().prop_flat_map(tensor_with_shape)
+}
+
+/// Generate a pair of shapes which are broadcast-compatible.
+///
+/// The first shape is always the shape that both inputs broadcast to. The
+/// second is formed by removing leading dimensions and replacing other
+/// dimensions with 1.
+fn broadcast_shape_pair() -> impl Strategy<Value = (Vec<usize>, Vec<usize>)> {
+    any_shape().prop_flat_map(|shape| {
+        let ndim = shape.len();
+        (0..=ndim, prop::collection::vec(any::<bool>(), ndim)).prop_map(move |(skip, ones)| {
+            let other = shape
+                .iter()
+                .zip(ones)
+                .skip(skip)
+                .map(|(&size, one)| if one { 1 } else { size })
+                .collect();
+            (shape.clone(), other)
+        })
+    })
+}
+
+/// Generate a pair of tensors whose shapes can be broadcast together.
+///
+/// The first tensor has the broadcast shape. Set `swap` to randomly exchange
+/// the tensors.
+fn broadcast_tensor_pair(swap: bool) -> impl Strategy<Value = (TestTensor, TestTensor)> {
+    (broadcast_shape_pair(), any::<bool>()).prop_flat_map(move |((a, b), do_swap)| {
+        let pair = (tensor_with_shape(a), tensor_with_shape(b));
+        pair.prop_map(move |(a, b)| if swap && do_swap { (b, a) } else { (a, b) })
+    })
+}
+
+/// Generate a tensor and a set of axes to reduce over, which may be negative.
+fn tensor_and_axes() -> impl Strategy<Value = (TestTensor, Vec<i32>)> {
+    any_tensor().prop_flat_map(|tensor| {
+        let ndim = tensor.shape().len();
+        let axes = prop::sample::subsequence((0..ndim).collect::<Vec<_>>(), 0..=ndim);
+        let negate = prop::collection::vec(any::<bool>(), ndim);
+        (Just(tensor), axes, negate).prop_map(move |(tensor, axes, negate)| {
+            let axes = axes
+                .into_iter()
+                .zip(negate)
+                .map(|(axis, neg)| {
+                    if neg {
+                        axis as i32 - ndim as i32
+                    } else {
+                        axis as i32
+                    }
+                })
+                .collect();
+            (tensor, axes)
+        })
+    })
+}
+
+/// Map an index into a broadcast output to the corresponding index in an
+/// input of shape `shape`.
+fn broadcast_index(index: &[usize], shape: &[usize]) -> Vec<usize> {
+    let pad = index.len() - shape.len();
+    index[pad..]
+        .iter()
+        .zip(shape)
+        .map(|(&idx, &size)| if size == 1 { 0 } else { idx })
+        .collect()
+}
+
+/// Compute the shape that `shapes` broadcast to, assuming they are
+/// compatible.
+fn reference_broadcast_shape(shapes: &[&[usize]]) -> Vec<usize> {
+    let ndim = shapes.iter().map(|s| s.len()).max().unwrap_or(0);
+    (0..ndim)
+        .map(|dim| {
+            shapes
+                .iter()
+                .filter_map(|s| (dim + s.len()).checked_sub(ndim).map(|d| s[d]))
+                .max()
+                .unwrap_or(1)
+        })
+        .collect()
+}
+
+fn reference_binary_op<F: Fn(i32, i32) -> i32>(
+    a: TensorView<i32>,
+    b: TensorView<i32>,
+    op: F,
+) -> Tensor<i32> {
+    let out_shape = reference_broadcast_shape(&[a.shape(), b.shape()]);
+    Tensor::from_fn(&out_shape, |index| {
+        let x = a[broadcast_index(index, a.shape())];
+        let y = b[broadcast_index(index, b.shape())];
+        op(x, y)
+    })
+}
+
+/// Reduce `input` over `axes` (or all axes if empty) using `op`.
+///
+/// Returns `(output, count)` where `count` is the number of elements that
+/// were combined to produce each output.
+fn reference_reduce<F: Fn(i32, i32) -> i32>(
+    input: TensorView<i32>,
+    axes: &[i32],
+    keep_dims: bool,
+    op: F,
+) -> (Tensor<i32>, usize) {
+    let ndim = input.ndim();
+    let reduced: Vec<bool> = (0..ndim)
+        .map(|dim| {
+            axes.is_empty()
+                || axes
+                    .iter()
+                    .any(|&axis| (axis + ndim as i32) as usize % ndim == dim)
+        })
+        .collect();
+    let kept_shape: Vec<usize> = input
+        .shape()
+        .iter()
+        .zip(&reduced)
+        .map(|(&size, &r)| if r { 1 } else { size })
+        .collect();
+
+    let mut output = Tensor::<Option<i32>>::full(&kept_shape, None);
+    for index in input.indices() {
+        let out_index: Vec<usize> = index
+            .iter()
+            .zip(&reduced)
+            .map(|(&idx, &r)| if r { 0 } else { idx })
+            .collect();
+        let x = input[&index];
+        let acc = &mut output[out_index];
+        *acc = Some(acc.map(|acc| op(acc, x)).unwrap_or(x));
+    }
+
+    let out_shape: Vec<usize> = if keep_dims {
+        kept_shape
+    } else {
+        input
+            .shape()
+            .iter()
+            .zip(&reduced)
+            .filter(|(_, &r)| !r)
+            .map(|(&size, _)| size)
+            .collect()
+    };
+    let count = input.len() / output.len().max(1);
+    let output = output.iter().map(|x| x.unwrap()).collect::<Vec<_>>();
+    (Tensor::from_data(&out_shape, output), count)
+}
+
+/// Return the indices selected along a dimension of size `size` by an ONNX
+/// Slice with the given start, end and step.
+fn reference_slice_indices(size: usize, start: i32, end: i32, step: i32) -> Vec<usize> {
+    let size = size as i32;
+    let resolve = |idx: i32| if idx < 0 { idx + size } else { idx };
+    if step > 0 {
+        let start = resolve(start).clamp(0, size);
+        let end = resolve(end).clamp(0, size);
+        (start..end)
+            .step_by(step as usize)
+            .map(|i| i as usize)
+            .collect()
+    } else {
+        let start = resolve(start).clamp(0, size - 1);
+        let end = resolve(end).clamp(-1, size - 1);
+        let mut indices = Vec::new();
+        let mut i = start;
+        while i > end {
+            indices.push(i as usize);
+            i += step;
+        }
+        indices
+    }
+}
+
+/// Generate a tensor and per-axis `(start, end, step)` slice parameters.
+fn tensor_and_slice() -> impl Strategy<Value = (TestTensor, Vec<(i32, i32, i32)>)> {
+    any_tensor().prop_flat_map(|tensor| {
+        let params: Vec<_> = tensor
+            .shape()
+            .iter()
+            .map(|&size| {
+                let size = size as i32;
+                (
+                    -size - 1..=size + 1,
+                    -size - 1..=size + 1,
+                    prop::sample::select(vec![-3, -2, -1, 1, 2, 3]),
+                )
+            })
+            .collect();
+        (Just(tensor), params)
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_binary_ops_match_reference((a, b) in broadcast_tensor_pair(true /* swap */)) {
+        let pool = new_pool();
+        let (a, b) = (a.view(), b.view());
+
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x + y);
+        prop_assert_eq!(add(&pool, a.view(), b.view()).unwrap(), expected);
+
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x - y);
+        prop_assert_eq!(sub(&pool, a.view(), b.view()).unwrap(), expected);
+
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x * y);
+        prop_assert_eq!(mul(&pool, a.view(), b.view()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_binary_ops_in_place_match_reference((a, b) in broadcast_tensor_pair(false /* swap */)) {
+        let (a, b) = (a.view(), b.view());
+
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x + y);
+        let mut result = a.to_tensor();
+        add_in_place(result.view_mut(), b.view());
+        prop_assert_eq!(result, expected);
+
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x - y);
+        let mut result = a.to_tensor();
+        sub_in_place(result.view_mut(), b.view());
+        prop_assert_eq!(result, expected);
+
+        // Also test in-place updates of a non-contiguous tensor.
+        let expected = reference_binary_op(a.view(), b.view(), |x, y| x * y);
+        let mut storage = a.transposed().to_tensor();
+        let reversed: Vec<usize> = (0..a.ndim()).rev().collect();
+        mul_in_place(storage.permuted_mut(reversed.as_slice()), b.view());
+        prop_assert_eq!(storage.transposed().to_tensor(), expected);
+    }
+
+    #[test]
+    fn test_where_matches_reference(
+        (x, y) in broadcast_tensor_pair(true /* swap */),
+        cond in any_tensor(),
+    ) {
+        let pool = new_pool();
+        let (x, y) = (x.view(), y.view());
+        let cond = cond.view();
+        let shapes = [cond.shape(), x.shape(), y.shape()];
+        let cond_compatible = crate::ops::is_broadcast_compatible(&shapes);
+        let result = where_op(&pool, cond.view(), x.view(), y.view());
+
+        if !cond_compatible {
+            prop_assert!(result.is_err());
+            return Ok(());
+        }
+
+        let out_shape = reference_broadcast_shape(&shapes);
+        let expected = Tensor::from_fn(&out_shape, |index| {
+            if cond[broadcast_index(index, cond.shape())] != 0 {
+                x[broadcast_index(index, x.shape())]
+            } else {
+                y[broadcast_index(index, y.shape())]
+            }
+        });
+        prop_assert_eq!(result.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_reductions_match_reference((input, axes) in tensor_and_axes(), keep_dims: bool) {
+        let pool = new_pool();
+        let input = input.view();
+        let axes_arg = Some(axes.as_slice());
+
+        let (expected, _) = reference_reduce(input.view(), &axes, keep_dims, |x, y| x + y);
+        let result = reduce_sum(&pool, input.view(), axes_arg, keep_dims).unwrap();
+        prop_assert_eq!(result, expected);
+
+        let (expected, _) = reference_reduce(input.view(), &axes, keep_dims, i32::min);
+        let result = reduce_min(&pool, input.view(), axes_arg, keep_dims).unwrap();
+        prop_assert_eq!(result, expected);
+
+        let (expected, _) = reference_reduce(input.view(), &axes, keep_dims, i32::max);
+        let result = reduce_max(&pool, input.view(), axes_arg, keep_dims).unwrap();
+        prop_assert_eq!(result, expected);
+
+        let (sum, count) = reference_reduce(input.view(), &axes, keep_dims, |x, y| x + y);
+        let float_input = input.map(|&x| x as f32);
+        let result = reduce_mean(&pool, float_input.view(), axes_arg, keep_dims).unwrap();
+        prop_assert_eq!(result.shape(), sum.shape());
+        for (&actual, &sum) in result.iter().zip(sum.iter()) {
+            let expected = sum as f32 / count as f32;
+            prop_assert!((actual - expected).abs() < 1e-5, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_transpose_matches_reference(
+        (input, perm) in any_tensor().prop_flat_map(|input| {
+            let ndim = input.shape().len();
+            (Just(input), Just((0..ndim).collect::<Vec<_>>()).prop_shuffle())
+        })
+    ) {
+        let pool = new_pool();
+        let input = input.view();
+        let out_shape: Vec<usize> = perm.iter().map(|&dim| input.size(dim)).collect();
+        let expected = Tensor::from_fn(&out_shape, |index| {
+            let mut in_index = vec![0; index.len()];
+            for (out_dim, &in_dim) in perm.iter().enumerate() {
+                in_index[in_dim] = index[out_dim];
+            }
+            input[in_index]
+        });
+        let result = transpose(&pool, input.view(), Some(&perm)).unwrap();
+        prop_assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_expand_matches_reference((to_shape, from_shape) in broadcast_shape_pair()) {
+        let pool = new_pool();
+        let len = from_shape.iter().product::<usize>() as i32;
+        let input = Tensor::from_data(&from_shape, (0..len).collect::<Vec<_>>());
+        let shape_arg = NdTensor::from_data(
+            [to_shape.len()],
+            to_shape.iter().map(|&size| size as i32).collect::<Vec<_>>(),
+        );
+        let expected = Tensor::from_fn(&to_shape, |index| {
+            input[broadcast_index(index, input.shape())]
+        });
+        let result = expand(&pool, input.view(), &shape_arg.view()).unwrap();
+        prop_assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_slice_matches_reference((input, params) in tensor_and_slice()) {
+        let pool = new_pool();
+        let input = input.view();
+        let ndim = input.ndim();
+
+        let indices: Vec<Vec<usize>> = params
+            .iter()
+            .zip(input.shape())
+            .map(|(&(start, end, step), &size)| reference_slice_indices(size, start, end, step))
+            .collect();
+        let out_shape: Vec<usize> = indices.iter().map(|idx| idx.len()).collect();
+        let expected = Tensor::from_fn(&out_shape, |index| {
+            let in_index: Vec<usize> = index
+                .iter()
+                .zip(&indices)
+                .map(|(&i, dim_indices)| dim_indices[i])
+                .collect();
+            input[in_index]
+        });
+
+        let starts = NdTensor::from_data([ndim], params.iter().map(|p| p.0).collect::<Vec<_>>());
+        let ends = NdTensor::from_data([ndim], params.iter().map(|p| p.1).collect::<Vec<_>>());
+        let steps = NdTensor::from_data([ndim], params.iter().map(|p| p.2).collect::<Vec<_>>());
+        let result = slice(
+            &pool,
+            input.view(),
+            &starts.view(),
+            &ends.view(),
+            None,
+            Some(&steps.view()),
+        )
+        .unwrap();
+        prop_assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_concat_matches_reference(
+        (inputs, axis) in (any_shape().prop_filter("non-scalar", |s| !s.is_empty()), 1..4usize)
+            .prop_flat_map(|(shape, n_inputs)| {
+                let ndim = shape.len();
+                (Just(shape), 0..ndim, prop::collection::vec(1..=MAX_DIM_SIZE, n_inputs))
+            })
+            .prop_flat_map(|(shape, axis, axis_sizes)| {
+                let inputs: Vec<_> = axis_sizes
+                    .into_iter()
+                    .map(|size| {
+                        let mut shape = shape.clone();
+                        shape[axis] = size;
+                        tensor_with_shape(shape)
+                    })
+                    .collect();
+                (inputs, Just(axis))
+            })
+    ) {
+        let pool = new_pool();
+        let views: Vec<_> = inputs.iter().map(|t| t.view()).collect();
+
+        let mut out_shape = views[0].shape().to_vec();
+        out_shape[axis] = views.iter().map(|v| v.size(axis)).sum();
+        let expected = Tensor::from_fn(&out_shape, |index| {
+            let mut index = index.to_vec();
+            for view in &views {
+                if index[axis] < view.size(axis) {
+                    return view[&index];
+                }
+                index[axis] -= view.size(axis);
+            }
+            unreachable!()
+        });
+
+        let result = concat(&pool, &views, axis as isize).unwrap();
+        prop_assert_eq!(result, expected);
+    }
+}
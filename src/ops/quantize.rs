@@ -0,0 +1,749 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensorView, Tensor, TensorView};
+use rten_vecmath::vec_dot_packed;
+
+use crate::check_dims;
+use crate::ops::pooling::calc_output_size_and_padding;
+use crate::ops::{DataType, InputList, IntoOpResult, OpError, Operator, Output, Padding};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// A 2D matrix of block-quantized values packed into `i32` words.
+///
+/// `data` has shape `[rows, words]` and each `i32` word packs `32 / bits`
+/// unsigned quantized values, with the first value in the least significant
+/// bits. The dequantized matrix has `cols = words * 32 / bits` columns.
+///
+/// Each row is divided into blocks of `block_size` columns. `scales` and
+/// `zero_points` have shape `[rows, ceil(cols / block_size)]` and specify the
+/// quantization parameters for each block. If `zero_points` is not provided,
+/// the zero point is `2^(bits - 1)`.
+///
+/// This is the layout used by the `GatherBlockQuantized` and `MatMulNBits`
+/// operators.
+pub(crate) struct BlockQuantizedMatrix<'a> {
+    data: NdTensorView<'a, i32, 2>,
+    scales: NdTensorView<'a, f32, 2>,
+    zero_points: Option<NdTensorView<'a, i32, 2>>,
+    bits: u32,
+    block_size: usize,
+}
+
+impl<'a> BlockQuantizedMatrix<'a> {
+    /// Validate the shapes of quantized data and quantization parameters.
+    pub fn new(
+        data: NdTensorView<'a, i32, 2>,
+        scales: NdTensorView<'a, f32, 2>,
+        zero_points: Option<NdTensorView<'a, i32, 2>>,
+        bits: u32,
+        block_size: usize,
+    ) -> Result<Self, OpError> {
+        if bits != 4 && bits != 8 {
+            return Err(OpError::InvalidValue("`bits` must be 4 or 8"));
+        }
+        if block_size == 0 {
+            return Err(OpError::InvalidValue("`block_size` must be positive"));
+        }
+
+        let matrix = BlockQuantizedMatrix {
+            data,
+            scales,
+            zero_points,
+            bits,
+            block_size,
+        };
+        let n_blocks = matrix.cols().div_ceil(block_size);
+
+        if scales.shape() != [matrix.rows(), n_blocks] {
+            return Err(OpError::IncompatibleInputShapes(
+                "`scales` shape does not match `data` and `block_size`",
+            ));
+        }
+        if zero_points.is_some_and(|zp| zp.shape() != [matrix.rows(), n_blocks]) {
+            return Err(OpError::IncompatibleInputShapes(
+                "`zero_points` shape does not match `scales`",
+            ));
+        }
+
+        Ok(matrix)
+    }
+
+    /// Return the number of rows in the dequantized matrix.
+    pub fn rows(&self) -> usize {
+        self.data.size(0)
+    }
+
+    /// Return the number of columns in the dequantized matrix.
+    pub fn cols(&self) -> usize {
+        self.data.size(1) * self.vals_per_word()
+    }
+
+    fn vals_per_word(&self) -> usize {
+        (32 / self.bits) as usize
+    }
+
+    /// Dequantize row `row` into `out`, which must have length [Self::cols].
+    pub fn dequantize_row(&self, row: usize, out: &mut [f32]) {
+        assert_eq!(out.len(), self.cols());
+
+        let bits = self.bits;
+        let vals_per_word = self.vals_per_word();
+        let mask = (1u32 << bits) - 1;
+        let default_zero_point = 1 << (bits - 1);
+
+        for (block, out_block) in out.chunks_mut(self.block_size).enumerate() {
+            let scale = self.scales[[row, block]];
+            let zero_point = self
+                .zero_points
+                .map(|zp| zp[[row, block]])
+                .unwrap_or(default_zero_point);
+            let col_offset = block * self.block_size;
+            for (i, out) in out_block.iter_mut().enumerate() {
+                let col = col_offset + i;
+                let word = self.data[[row, col / vals_per_word]] as u32;
+                let shift = (col % vals_per_word) as u32 * bits;
+                let quant = ((word >> shift) & mask) as i32;
+                *out = (quant - zero_point) as f32 * scale;
+            }
+        }
+    }
+
+    /// Return true if blocks start at word boundaries, which is required by
+    /// [Self::dot_row].
+    pub fn blocks_are_word_aligned(&self) -> bool {
+        self.block_size % self.vals_per_word() == 0
+    }
+
+    /// Compute the dot product of the dequantized row `row` with `a`.
+    ///
+    /// This unpacks values directly from the quantized words without writing
+    /// the dequantized row to memory, using SIMD operations via
+    /// [vec_dot_packed]. The scale is applied once per block rather than once
+    /// per element.
+    ///
+    /// `a` may be shorter than [Self::cols], in which case the remaining
+    /// columns are ignored. Panics if blocks are not word-aligned.
+    pub fn dot_row(&self, row: usize, a: &[f32]) -> f32 {
+        assert!(self.blocks_are_word_aligned());
+        assert!(a.len() <= self.cols());
+
+        let bits = self.bits;
+        let vals_per_word = self.vals_per_word();
+        let words_per_block = self.block_size / vals_per_word;
+        let mask = (1u32 << bits) - 1;
+        let default_zero_point = 1 << (bits - 1);
+
+        let row_data = self.data.slice::<1, _>(row).to_contiguous();
+        let words = row_data.data().unwrap();
+
+        let mut sum = 0.;
+        for (block, a_block) in a.chunks(self.block_size).enumerate() {
+            let scale = self.scales[[row, block]];
+            let zero_point = self
+                .zero_points
+                .map(|zp| zp[[row, block]])
+                .unwrap_or(default_zero_point);
+            let word_offset = block * words_per_block;
+
+            // If `a` ends part-way through a word, the values in that word
+            // are handled separately.
+            let n_words = a_block.len() / vals_per_word;
+            let (a_words, a_rem) = a_block.split_at(n_words * vals_per_word);
+            let block_words = &words[word_offset..word_offset + n_words];
+            let mut dot = vec_dot_packed(block_words, bits, zero_point, a_words);
+
+            if !a_rem.is_empty() {
+                let word = words[word_offset + n_words] as u32;
+                for (j, &a_val) in a_rem.iter().enumerate() {
+                    let quant = ((word >> (j as u32 * bits)) & mask) as i32;
+                    dot += (quant - zero_point) as f32 * a_val;
+                }
+            }
+            sum += scale * dot;
+        }
+        sum
+    }
+}
+
+/// Scale and zero point of a quantized tensor.
+///
+/// Each of `scale` and `zero_point` is either a scalar, for per-tensor
+/// quantization, or a vector with one value per channel.
+#[derive(Clone)]
+pub struct QuantParams<'a> {
+    pub scale: TensorView<'a, f32>,
+    pub zero_point: TensorView<'a, i32>,
+}
+
+impl<'a> QuantParams<'a> {
+    pub fn new(scale: TensorView<'a, f32>, zero_point: TensorView<'a, i32>) -> Self {
+        QuantParams { scale, zero_point }
+    }
+
+    /// Return the scale and zero point of a per-tensor quantized value.
+    fn per_tensor(&self) -> Result<(f32, i32), OpError> {
+        match (self.scale.item(), self.zero_point.item()) {
+            (Some(&scale), Some(&zero_point)) => Ok((scale, zero_point)),
+            _ => Err(OpError::InvalidValue(
+                "Scale and zero point must be scalars",
+            )),
+        }
+    }
+
+    /// Return the scales and zero points for each of `channels` channels.
+    fn per_channel(&self, channels: usize) -> Result<(Vec<f32>, Vec<i32>), OpError> {
+        fn expand<T: Copy>(param: TensorView<T>, channels: usize) -> Option<Vec<T>> {
+            match param.ndim() {
+                0 => param.item().map(|&x| vec![x; channels]),
+                1 if param.len() == channels => Some(param.to_vec()),
+                1 if param.len() == 1 => Some(vec![param[[0]]; channels]),
+                _ => None,
+            }
+        }
+        match (
+            expand(self.scale.view(), channels),
+            expand(self.zero_point.view(), channels),
+        ) {
+            (Some(scales), Some(zero_points)) => Ok((scales, zero_points)),
+            _ => Err(OpError::IncompatibleInputShapes(
+                "Scale and zero point must be scalars or have one value per output channel",
+            )),
+        }
+    }
+}
+
+/// Return the range of values representable by a quantized output type.
+fn quantized_range(dtype: DataType) -> Result<(i32, i32), OpError> {
+    match dtype {
+        DataType::Int8 => Ok((i8::MIN as i32, i8::MAX as i32)),
+        DataType::UInt8 => Ok((u8::MIN as i32, u8::MAX as i32)),
+        _ => Err(OpError::InvalidValue("Output type must be int8 or uint8")),
+    }
+}
+
+/// Convert an `i32` accumulator to a quantized output value.
+///
+/// `scale` is the combined scale `input_scale * weight_scale / output_scale`.
+#[inline]
+fn requantize(acc: i32, scale: f32, zero_point: i32, (min, max): (i32, i32)) -> i32 {
+    ((acc as f32 * scale).round_ties_even() as i32)
+        .saturating_add(zero_point)
+        .clamp(min, max)
+}
+
+/// Multiply a quantized `[..., M, K]` matrix `a` by a quantized `[K, N]`
+/// matrix `b` and return a quantized `[..., M, N]` result.
+///
+/// `b` may be quantized per-tensor or per-column. Products are accumulated in
+/// `i32` and then each output is requantized using
+/// `a_scale * b_scale[n] / y_scale` and clamped to the range of
+/// `output_dtype`, which must be `Int8` or `UInt8`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__QLinearMatMul.html>.
+pub fn qlinear_matmul(
+    pool: &TensorPool,
+    a: TensorView<i32>,
+    a_quant: QuantParams,
+    b: NdTensorView<i32, 2>,
+    b_quant: QuantParams,
+    y_quant: QuantParams,
+    output_dtype: DataType,
+) -> Result<Tensor<i32>, OpError> {
+    let range = quantized_range(output_dtype)?;
+    if a.ndim() < 1 {
+        return Err(OpError::InvalidValue("`a` must have at least 1 dimension"));
+    }
+
+    let [k, n] = b.shape();
+    if a.size(a.ndim() - 1) != k {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of `a` do not match rows of `b`",
+        ));
+    }
+
+    let (a_scale, a_zero) = a_quant.per_tensor()?;
+    let (b_scales, b_zeros) = b_quant.per_channel(n)?;
+    let (y_scale, y_zero) = y_quant.per_tensor()?;
+
+    let a_prefix = &a.shape()[..a.ndim() - 1];
+    let m: usize = a_prefix.iter().product();
+    let out_shape = [a_prefix, &[n]].concat();
+    if m == 0 || n == 0 {
+        return Ok(Tensor::full(&out_shape, y_zero.clamp(range.0, range.1)));
+    }
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+    let a_data = a.data().unwrap();
+
+    // Subtract zero points up front so the inner loop is a plain integer
+    // multiply-accumulate.
+    let b_centered: Vec<i32> = b
+        .indices()
+        .map(|[row, col]| b[[row, col]] - b_zeros[col])
+        .collect();
+    let scales: Vec<f32> = b_scales.iter().map(|bs| a_scale * bs / y_scale).collect();
+
+    let mut output = Tensor::zeros_in(pool, &out_shape);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(n)
+        .zip(a_data.par_chunks(k))
+        .for_each(|(out_row, a_row)| {
+            let mut acc = vec![0i32; n];
+            for (&a_val, b_row) in a_row.iter().zip(b_centered.chunks(n)) {
+                let a_val = a_val - a_zero;
+                if a_val == 0 {
+                    continue;
+                }
+                for (acc, &b_val) in acc.iter_mut().zip(b_row) {
+                    *acc += a_val * b_val;
+                }
+            }
+            for (i, out) in out_row.iter_mut().enumerate() {
+                *out = requantize(acc[i], scales[i], y_zero, range);
+            }
+        });
+
+    Ok(output)
+}
+
+/// Matrix multiplication of quantized tensors.
+///
+/// See [qlinear_matmul].
+#[derive(Debug)]
+pub struct QLinearMatMul {
+    pub output_dtype: DataType,
+}
+
+impl Operator for QLinearMatMul {
+    fn name(&self) -> &str {
+        "QLinearMatMul"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let a = inputs.require_as::<i32>(0)?;
+        let a_quant = QuantParams::new(inputs.require_as(1)?, inputs.require_as(2)?);
+        let b = inputs.require_as::<i32>(3)?;
+        let b_quant = QuantParams::new(inputs.require_as(4)?, inputs.require_as(5)?);
+        let y_quant = QuantParams::new(inputs.require_as(6)?, inputs.require_as(7)?);
+
+        if b.ndim() != 2 {
+            return Err(OpError::UnsupportedValue(
+                "QLinearMatMul only supports 2D `b` inputs",
+            ));
+        }
+
+        qlinear_matmul(
+            pool,
+            a,
+            a_quant,
+            b.nd_view(),
+            b_quant,
+            y_quant,
+            self.output_dtype,
+        )
+        .into_op_result()
+    }
+}
+
+/// Perform a 2D convolution of a quantized `[N, C, H, W]` input with a
+/// quantized `[M, C / groups, kH, kW]` kernel.
+///
+/// The kernel may be quantized per-tensor or per-output-channel. `bias` is an
+/// optional `i32` vector of length `M` which is added to the accumulator
+/// before requantization. Padded positions are treated as having the value of
+/// the input zero point.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__QLinearConv.html>.
+#[allow(clippy::too_many_arguments)]
+pub fn qlinear_conv(
+    pool: &TensorPool,
+    input: TensorView<i32>,
+    input_quant: QuantParams,
+    kernel: TensorView<i32>,
+    kernel_quant: QuantParams,
+    bias: Option<TensorView<i32>>,
+    output_quant: QuantParams,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    output_dtype: DataType,
+) -> Result<Tensor<i32>, OpError> {
+    let range = quantized_range(output_dtype)?;
+    let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
+    let [out_c, k_in_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
+    check_dims!(bias?, 1);
+
+    if groups == 0 || in_c % groups != 0 || out_c % groups != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels and output channels must be divisible by group count",
+        ));
+    }
+    if in_c / groups != k_in_c {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels (per group) does not match kernel input channels",
+        ));
+    }
+    if bias.as_ref().is_some_and(|b| b.size(0) != out_c) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Bias length does not match kernel output channels",
+        ));
+    }
+    let [stride_y, stride_x]: [usize; 2] = strides
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 2 stride values"))?;
+    let [dilation_y, dilation_x]: [usize; 2] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 2 dilation values"))?;
+
+    let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
+        (in_h, in_w),
+        (k_h, k_w),
+        (stride_y, stride_x),
+        padding,
+        Some((dilation_y, dilation_x)),
+    )?;
+    let [pad_top, pad_left, _pad_bottom, _pad_right] = fixed_padding;
+
+    let (x_scale, x_zero) = input_quant.per_tensor()?;
+    let (w_scales, w_zeros) = kernel_quant.per_channel(out_c)?;
+    let (y_scale, y_zero) = output_quant.per_tensor()?;
+    let scales: Vec<f32> = w_scales.iter().map(|ws| x_scale * ws / y_scale).collect();
+
+    let mut output = Tensor::zeros_in(pool, &[batch, out_c, out_h, out_w]);
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    // Subtract zero points up front. Padded positions then contribute zero
+    // to the accumulator and are skipped.
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let x_centered: Vec<i32> = input.iter().map(|x| x - x_zero).collect();
+    let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
+    let kernel_len = k_in_c * k_h * k_w;
+    let w_centered: Vec<i32> = kernel
+        .data()
+        .unwrap()
+        .chunks(kernel_len)
+        .zip(&w_zeros)
+        .flat_map(|(chan, &zero)| chan.iter().map(move |w| w - zero))
+        .collect();
+
+    let out_c_per_group = out_c / groups;
+    let in_plane_len = in_h * in_w;
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(out_h * out_w)
+        .enumerate()
+        .for_each(|(plane, out_plane)| {
+            let n = plane / out_c;
+            let oc = plane % out_c;
+            let group = oc / out_c_per_group;
+            let bias = bias.as_ref().map(|b| b[[oc]]).unwrap_or(0);
+            let mut acc = vec![bias; out_h * out_w];
+
+            for k_c in 0..k_in_c {
+                let in_chan = group * k_in_c + k_c;
+                let in_plane = &x_centered[(n * in_c + in_chan) * in_plane_len..][..in_plane_len];
+                let w_offset = oc * kernel_len + k_c * k_h * k_w;
+
+                for ky in 0..k_h {
+                    for kx in 0..k_w {
+                        let w_val = w_centered[w_offset + ky * k_w + kx];
+                        if w_val == 0 {
+                            continue;
+                        }
+                        for oy in 0..out_h {
+                            let Some(iy) = (oy * stride_y + ky * dilation_y)
+                                .checked_sub(pad_top)
+                                .filter(|&iy| iy < in_h)
+                            else {
+                                continue;
+                            };
+                            let in_row = &in_plane[iy * in_w..][..in_w];
+                            let acc_row = &mut acc[oy * out_w..][..out_w];
+                            for (ox, acc) in acc_row.iter_mut().enumerate() {
+                                let Some(ix) = (ox * stride_x + kx * dilation_x)
+                                    .checked_sub(pad_left)
+                                    .filter(|&ix| ix < in_w)
+                                else {
+                                    continue;
+                                };
+                                *acc += w_val * in_row[ix];
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (out, &acc) in out_plane.iter_mut().zip(&acc) {
+                *out = requantize(acc, scales[oc], y_zero, range);
+            }
+        });
+
+    Ok(output)
+}
+
+/// Convolution of quantized tensors.
+///
+/// See [qlinear_conv].
+#[derive(Debug)]
+pub struct QLinearConv {
+    pub groups: usize,
+    pub dilations: Vec<usize>,
+    pub padding: Padding,
+    pub strides: Vec<usize>,
+    pub output_dtype: DataType,
+}
+
+impl Operator for QLinearConv {
+    fn name(&self) -> &str {
+        "QLinearConv"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as::<i32>(0)?;
+        let input_quant = QuantParams::new(inputs.require_as(1)?, inputs.require_as(2)?);
+        let kernel = inputs.require_as::<i32>(3)?;
+        let kernel_quant = QuantParams::new(inputs.require_as(4)?, inputs.require_as(5)?);
+        let output_quant = QuantParams::new(inputs.require_as(6)?, inputs.require_as(7)?);
+        let bias = inputs.get_as::<i32>(8)?;
+
+        qlinear_conv(
+            pool,
+            input,
+            input_quant,
+            kernel,
+            kernel_quant,
+            bias,
+            output_quant,
+            self.padding.clone(),
+            self.groups,
+            &self.strides,
+            &self.dilations,
+            self.output_dtype,
+        )
+        .into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::prelude::*;
+    use rten_tensor::tensor;
+
+    use crate::ops::tests::new_pool;
+    use crate::ops::{qlinear_conv, qlinear_matmul, DataType, OpError, Padding, QuantParams};
+
+    #[test]
+    fn test_qlinear_matmul() {
+        let pool = new_pool();
+
+        let a = tensor!((2, 2); [130, 132, 126, 128]);
+        let a_scale = tensor!(0.5);
+        let a_zero = tensor!(128);
+        let b = tensor!((2, 3); [1, 2, 3, 4, 5, 6]);
+        let y_scale = tensor!(0.25);
+        let y_zero = tensor!(10);
+
+        // Per-tensor weight quantization.
+        let b_scale = tensor!(1.0);
+        let b_zero = tensor!(0);
+        let result = qlinear_matmul(
+            &pool,
+            a.view(),
+            QuantParams::new(a_scale.view(), a_zero.view()),
+            b.nd_view(),
+            QuantParams::new(b_scale.view(), b_zero.view()),
+            QuantParams::new(y_scale.view(), y_zero.view()),
+            DataType::UInt8,
+        )
+        .unwrap();
+
+        // Centered `a` is [[2, 4], [-2, 0]], so the accumulator is
+        // [[18, 24, 30], [-2, -4, -6]] and the scale is 0.5 / 0.25 = 2.
+        assert_eq!(result, tensor!((2, 3); [46, 58, 70, 6, 2, 0]));
+
+        // Per-column weight quantization, with a signed output.
+        let b_scale = tensor!([1.0, 0.5, 0.25]);
+        let b_zero = tensor!([0, 1, 2]);
+        let result = qlinear_matmul(
+            &pool,
+            a.view(),
+            QuantParams::new(a_scale.view(), a_zero.view()),
+            b.nd_view(),
+            QuantParams::new(b_scale.view(), b_zero.view()),
+            QuantParams::new(y_scale.view(), y_zero.view()),
+            DataType::Int8,
+        )
+        .unwrap();
+
+        // Centered `b` is [[1, 1, 1], [4, 4, 4]], so the accumulator is
+        // [[18, 18, 18], [-2, -2, -2]] and the scales are [2, 1, 0.5].
+        assert_eq!(result, tensor!((2, 3); [46, 28, 19, 6, 8, 9]));
+    }
+
+    #[test]
+    fn test_qlinear_matmul_invalid() {
+        let pool = new_pool();
+        let a = tensor!((2, 2); [1, 2, 3, 4]);
+        let b = tensor!((2, 3); [1, 2, 3, 4, 5, 6]);
+        let scale = tensor!(1.0);
+        let zero = tensor!(0);
+        let quant = || QuantParams::new(scale.view(), zero.view());
+
+        let result = qlinear_matmul(
+            &pool,
+            a.view(),
+            quant(),
+            b.nd_view(),
+            quant(),
+            quant(),
+            DataType::Float,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Output type must be int8 or uint8"))
+        );
+
+        let b_scale = tensor!([1.0, 2.0]);
+        let result = qlinear_matmul(
+            &pool,
+            a.view(),
+            quant(),
+            b.nd_view(),
+            QuantParams::new(b_scale.view(), zero.view()),
+            quant(),
+            DataType::UInt8,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Scale and zero point must be scalars or have one value per output channel"
+            ))
+        );
+
+        let a_scale = tensor!([1.0, 2.0]);
+        let result = qlinear_matmul(
+            &pool,
+            a.view(),
+            QuantParams::new(a_scale.view(), zero.view()),
+            b.nd_view(),
+            quant(),
+            quant(),
+            DataType::UInt8,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Scale and zero point must be scalars"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_qlinear_conv() {
+        let pool = new_pool();
+
+        // 1x1x3x3 input with a zero point of 1.
+        let input = tensor!((1, 1, 3, 3); [2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let x_scale = tensor!(1.0);
+        let x_zero = tensor!(1);
+
+        // Two 2x2 output channels with per-channel scales and zero points.
+        let kernel = tensor!((2, 1, 2, 2); [
+            1, 1, 1, 1, // Channel 0
+            3, 2, 2, 2, // Channel 1
+        ]);
+        let w_scale = tensor!([1.0, 0.5]);
+        let w_zero = tensor!([0, 2]);
+        let bias = tensor!([0, 4]);
+        let y_scale = tensor!(1.0);
+        let y_zero = tensor!(0);
+
+        let result = qlinear_conv(
+            &pool,
+            input.view(),
+            QuantParams::new(x_scale.view(), x_zero.view()),
+            kernel.view(),
+            QuantParams::new(w_scale.view(), w_zero.view()),
+            Some(bias.view()),
+            QuantParams::new(y_scale.view(), y_zero.view()),
+            [0, 0, 0, 0].into(),
+            1, /* groups */
+            &[1, 1],
+            &[1, 1],
+            DataType::UInt8,
+        )
+        .unwrap();
+
+        // Channel 0 sums each 2x2 window of the centered input
+        // [[1, 2, 3], [4, 5, 6], [7, 8, 9]]. Channel 1 picks the top-left
+        // value of each window, adds the bias and halves the result, rounding
+        // half to even.
+        assert_eq!(
+            result,
+            tensor!((1, 2, 2, 2); [
+                12, 16, 24, 28, // Channel 0
+                2, 3, 4, 4, // Channel 1
+            ])
+        );
+
+        // Padded positions use the input zero point, and outputs are clamped
+        // to the range of the output type.
+        let kernel = tensor!((1, 1, 1, 1); [100]);
+        let w_scale = tensor!(1.0);
+        let w_zero = tensor!(0);
+        let result = qlinear_conv(
+            &pool,
+            input.view(),
+            QuantParams::new(x_scale.view(), x_zero.view()),
+            kernel.view(),
+            QuantParams::new(w_scale.view(), w_zero.view()),
+            None,
+            QuantParams::new(y_scale.view(), y_zero.view()),
+            [1, 1, 1, 1].into(),
+            1, /* groups */
+            &[2, 2],
+            &[1, 1],
+            DataType::Int8,
+        )
+        .unwrap();
+        assert_eq!(result, tensor!((1, 1, 3, 3); [0, 0, 0, 0, 127, 0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_qlinear_conv_invalid() {
+        let pool = new_pool();
+        let input = tensor!((1, 2, 2, 2); [1, 2, 3, 4, 5, 6, 7, 8]);
+        let kernel = tensor!((1, 1, 1, 1); [1]);
+        let scale = tensor!(1.0);
+        let zero = tensor!(0);
+        let quant = || QuantParams::new(scale.view(), zero.view());
+
+        let result = qlinear_conv(
+            &pool,
+            input.view(),
+            quant(),
+            kernel.view(),
+            quant(),
+            None,
+            quant(),
+            Padding::zero::<2>(),
+            1, /* groups */
+            &[1, 1],
+            &[1, 1],
+            DataType::UInt8,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Input channels (per group) does not match kernel input channels"
+            ))
+        );
+    }
+}
@@ -1,11 +1,31 @@
 use fastrand::Rng;
 use fastrand_contrib::RngExt;
 use rten_tensor::prelude::*;
-use rten_tensor::Tensor;
+use rten_tensor::{NdTensor, NdTensorView, Tensor};
 
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{DataType, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::static_dims;
 use crate::tensor_pool::TensorPool;
 
+/// Create the random number generator for a random operator.
+///
+/// If `seed` is `None`, the generator is seeded from a non-deterministic
+/// source.
+fn new_rng(seed: Option<f32>) -> Rng {
+    if let Some(seed) = seed {
+        Rng::with_seed(seed.to_bits() as u64)
+    } else {
+        Rng::new()
+    }
+}
+
+/// Convert a seed passed to [Operator::with_seed] to the `f32` seed used by
+/// random operators.
+fn seed_to_f32(seed: u64) -> f32 {
+    // Keep the top 24 bits, which can be represented exactly.
+    (seed >> 40) as f32
+}
+
 #[derive(Debug)]
 pub struct RandomUniform {
     pub low: f32,
@@ -24,15 +44,22 @@ impl Operator for RandomUniform {
         "RandomUniform"
     }
 
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(RandomUniform {
+            shape: self.shape.clone(),
+            seed: Some(seed_to_f32(seed)),
+            ..*self
+        }))
+    }
+
     fn run(&self, pool: &TensorPool, _inputs: InputList) -> Result<Vec<Output>, OpError> {
         let scale_value = |val: f32| self.low + val * (self.high - self.low);
         let shape = self.shape.as_slice();
 
-        let mut rng = if let Some(seed) = self.seed {
-            Rng::with_seed(seed.to_bits() as u64)
-        } else {
-            Rng::new()
-        };
+        let mut rng = new_rng(self.seed);
         Tensor::from_simple_fn_in(pool, shape, || scale_value(rng.f32())).into_op_result()
     }
 }
@@ -51,6 +78,16 @@ impl Operator for RandomUniformLike {
         "RandomUniformLike"
     }
 
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(RandomUniformLike {
+            seed: Some(seed_to_f32(seed)),
+            ..*self
+        }))
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let op = RandomUniform {
@@ -81,14 +118,21 @@ impl Operator for RandomNormal {
         "RandomNormal"
     }
 
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(RandomNormal {
+            shape: self.shape.clone(),
+            seed: Some(seed_to_f32(seed)),
+            ..*self
+        }))
+    }
+
     fn run(&self, pool: &TensorPool, _inputs: InputList) -> Result<Vec<Output>, OpError> {
         let shape = self.shape.as_slice();
 
-        let mut rng = if let Some(seed) = self.seed {
-            Rng::with_seed(seed.to_bits() as u64)
-        } else {
-            Rng::new()
-        };
+        let mut rng = new_rng(self.seed);
 
         Tensor::from_simple_fn_in(pool, shape, || rng.f32_normal(self.mean, self.scale))
             .into_op_result()
@@ -109,6 +153,16 @@ impl Operator for RandomNormalLike {
         "RandomNormalLike"
     }
 
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(RandomNormalLike {
+            seed: Some(seed_to_f32(seed)),
+            ..*self
+        }))
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let op = RandomNormal {
@@ -121,18 +175,102 @@ impl Operator for RandomNormalLike {
     }
 }
 
+/// Draw `sample_size` samples from the categorical distributions in
+/// `input`, which has shape `[batch, classes]` and contains unnormalized log
+/// probabilities.
+///
+/// Returns a tensor of shape `[batch, sample_size]` containing class indices.
+pub fn multinomial(
+    pool: &TensorPool,
+    rng: &mut Rng,
+    input: NdTensorView<f32, 2>,
+    sample_size: usize,
+) -> NdTensor<i32, 2> {
+    let [batch, n_classes] = input.shape();
+    let mut output = NdTensor::zeros_in(pool, [batch, sample_size]);
+    if n_classes == 0 {
+        return output;
+    }
+
+    let mut cum_probs = Vec::with_capacity(n_classes);
+    for (logits, mut samples) in input.axis_iter(0).zip(output.axis_iter_mut(0)) {
+        // Compute the cumulative sum of unnormalized probabilities. The max
+        // is subtracted before exponentiating to avoid overflow.
+        let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let mut total = 0.;
+        cum_probs.clear();
+        cum_probs.extend(logits.iter().map(|&x| {
+            total += (x - max).exp();
+            total
+        }));
+
+        for sample in samples.iter_mut() {
+            let threshold = rng.f32() * total;
+            let class = cum_probs.partition_point(|&p| p <= threshold);
+            *sample = class.min(n_classes - 1) as i32;
+        }
+    }
+
+    output
+}
+
+#[derive(Debug)]
+pub struct Multinomial {
+    /// Element type of the output. Either `Int32` or `Int64`.
+    pub dtype: DataType,
+    pub sample_size: usize,
+
+    /// Random seed. See [RandomUniform::seed].
+    pub seed: Option<f32>,
+}
+
+impl Operator for Multinomial {
+    fn name(&self) -> &str {
+        "Multinomial"
+    }
+
+    fn with_seed(&self, seed: u64) -> Option<Box<dyn Operator + Send + Sync>> {
+        if self.seed.is_some() {
+            return None;
+        }
+        Some(Box::new(Multinomial {
+            seed: Some(seed_to_f32(seed)),
+            ..*self
+        }))
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let input = static_dims!(input, 2, "batch, classes")?;
+
+        let mut rng = new_rng(self.seed);
+        let samples = multinomial(pool, &mut rng, input, self.sample_size);
+
+        match self.dtype {
+            DataType::Int32 => samples.into_dyn().into_op_result(),
+            DataType::Int64 => {
+                let samples_i64 = samples.map_in(pool, |&x| x as i64);
+                samples_i64.into_dyn().into_op_result()
+            }
+            _ => Err(OpError::UnsupportedValue(
+                "Multinomial output type must be int32 or int64",
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
     use rten_tensor::prelude::*;
-    use rten_tensor::Tensor;
+    use rten_tensor::{NdTensor, Tensor};
 
     use crate::ops::operators::FloatOperators;
     use crate::ops::tests::{new_pool, run_op};
-    use crate::ops::{InputList, Operator};
+    use crate::ops::{DataType, InputList, OpError, Operator};
 
-    use super::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
+    use super::{Multinomial, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
 
     #[test]
     fn test_random_uniform() {
@@ -345,4 +483,82 @@ mod tests {
         let output: Tensor<f32> = run_op(&op, input.view()).unwrap();
         assert_eq!(output.shape(), &[5, 5]);
     }
+
+    #[test]
+    fn test_multinomial() {
+        let pool = new_pool();
+
+        // The first row has all probability mass on class 2. The second row
+        // has equal probabilities for classes 0 and 1.
+        let input = NdTensor::from([[f32::NEG_INFINITY, -100., 0.], [1., 1., f32::NEG_INFINITY]]);
+        let op = Multinomial {
+            dtype: DataType::Int32,
+            sample_size: 1000,
+            seed: Some(0.5),
+        };
+        let output: NdTensor<i32, 2> = run_op(&op, input.view()).unwrap();
+        assert_eq!(output.shape(), [2, 1000]);
+
+        assert!(output.slice::<1, _>(0).iter().all(|&x| x == 2));
+
+        let ones = output.slice::<1, _>(1).iter().filter(|&&x| x == 1).count();
+        let zeros = output.slice::<1, _>(1).iter().filter(|&&x| x == 0).count();
+        assert_eq!(zeros + ones, 1000);
+        assert!((400..600).contains(&ones), "count of class 1 was {ones}");
+
+        // A fixed seed produces the same output.
+        let output_2: NdTensor<i32, 2> = run_op(&op, input.view()).unwrap();
+        assert_eq!(output, output_2);
+
+        // Int64 output.
+        let op = Multinomial {
+            dtype: DataType::Int64,
+            ..op
+        };
+        let output_i64: NdTensor<i64, 2> = run_op(&op, input.view()).unwrap();
+        assert_eq!(output_i64, output.map(|&x| x as i64));
+
+        // Unsupported output type.
+        let op = Multinomial {
+            dtype: DataType::Float,
+            ..op
+        };
+        let result = op.run(&pool, (&input.into_dyn()).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue(
+                "Multinomial output type must be int32 or int64"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_with_seed() {
+        let pool = new_pool();
+        let op = RandomNormal {
+            mean: 0.,
+            scale: 1.,
+            shape: vec![5, 5],
+            seed: None,
+        };
+
+        let run_seeded = |seed: u64| -> Tensor {
+            let seeded_op = op.with_seed(seed).unwrap();
+            seeded_op
+                .run(&pool, InputList::new())
+                .unwrap()
+                .remove(0)
+                .try_into()
+                .unwrap()
+        };
+        assert_eq!(run_seeded(1 << 40), run_seeded(1 << 40));
+        assert_ne!(run_seeded(1 << 40), run_seeded(2 << 40));
+
+        // Operators with a seed attribute keep using it.
+        let op = RandomNormal {
+            seed: Some(0.5),
+            ..op
+        };
+        assert!(op.with_seed(1234).is_none());
+    }
 }
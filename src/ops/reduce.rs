@@ -5,13 +5,15 @@ use std::iter::zip;
 use rten_tensor;
 use rten_tensor::prelude::*;
 use rten_tensor::{DynIndices, NdTensor, NdTensorView, SliceItem, Tensor, TensorView};
+use rten_vecmath::{vec_sum_square, vec_sum_square_sub};
 
 use crate::number::Identities;
 use crate::ops::layout::squeeze_in_place;
 use crate::ops::{
-    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    index_to_i32, resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator,
+    Output,
 };
-use crate::slice_reductions::slice_sum;
+use crate::slice_reductions::{slice_sum, SliceSum};
 use crate::tensor_pool::TensorPool;
 
 /// Compute the indices of the max elements along an axis, according to a
@@ -30,6 +32,9 @@ fn select_max_index<T, Cmp: Fn(&T, &T) -> std::cmp::Ordering>(
         ));
     }
 
+    // Check up front that every index along the axis fits in the output.
+    index_to_i32(input.size(resolved_axis) - 1)?;
+
     let reduced_shape: Vec<usize> = input
         .shape()
         .iter()
@@ -160,33 +165,43 @@ impl Operator for CumSum {
         let axis: i32 = inputs.require_as_scalar(1)?;
         match input {
             Input::IntTensor(input) => cum_sum(pool, input, axis as isize).into_op_result(),
+            Input::Int64Tensor(input) => cum_sum(pool, input, axis as isize).into_op_result(),
             Input::FloatTensor(input) => cum_sum(pool, input, axis as isize).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
 
 /// Return the indices of nonzero elements in `input` as a `(dim, index)` tensor.
-pub fn nonzero<T: Default + PartialEq>(pool: &TensorPool, input: TensorView<T>) -> Tensor<i32> {
+pub fn nonzero<T: Default + PartialEq>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+) -> Result<Tensor<i32>, OpError> {
     // Special case for scalar inputs.
     if let (Some(item), 0) = (input.item(), input.ndim()) {
-        return Tensor::zeros(&[0, if *item != T::default() { 1 } else { 0 }]);
+        return Ok(Tensor::zeros(&[
+            0,
+            if *item != T::default() { 1 } else { 0 },
+        ]));
+    }
+
+    // Check up front that indices in every dimension fit in the output.
+    if let Some(&max_size) = input.shape().iter().max() {
+        index_to_i32(max_size.saturating_sub(1))?;
     }
 
     // Build up concatenated sequence of indices of non-zero entries.
     let nonzeros: Vec<i32> = zip(input.indices(), input.iter())
         .filter(|(_index, value)| **value != T::default())
-        .flat_map(|(index, _value)| {
-            index.into_iter().map(|dim_idx| {
-                assert!(dim_idx <= i32::MAX as usize);
-                dim_idx as i32
-            })
-        })
+        .flat_map(|(index, _value)| index.into_iter().map(|dim_idx| dim_idx as i32))
         .collect();
 
     // Transpose from `(index, dim)` to `(dim, index)`.
-    Tensor::from_data(&[nonzeros.len() / input.ndim(), input.ndim()], nonzeros)
-        .transposed()
-        .to_tensor_in(pool)
+    Ok(
+        Tensor::from_data(&[nonzeros.len() / input.ndim(), input.ndim()], nonzeros)
+            .transposed()
+            .to_tensor_in(pool),
+    )
 }
 
 #[derive(Debug)]
@@ -201,7 +216,9 @@ impl Operator for NonZero {
         let input = inputs.require(0)?;
         match input {
             Input::IntTensor(input) => nonzero(pool, input).into_op_result(),
+            Input::Int64Tensor(input) => nonzero(pool, input).into_op_result(),
             Input::FloatTensor(input) => nonzero(pool, input).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -270,11 +287,11 @@ fn reduce<T: Copy, R: Reducer<T>>(
     match (reduced_inner_dims, input.data()) {
         (Some(ndims), Some(input_data)) => {
             // Fast path for reducing over contiguous chunks of the input.
-            let slice_len = if ndims == input.ndim() {
-                input.len()
-            } else {
-                input.stride(input.ndim() - 1 - ndims)
-            };
+            //
+            // nb. The chunk length is computed from the sizes rather than
+            // strides of the reduced dims, as the stride of a size-1 dim in a
+            // contiguous tensor can be arbitrary.
+            let slice_len = input.shape()[input.ndim() - ndims..].iter().product();
 
             reduced_data.extend(
                 input_data
@@ -386,6 +403,10 @@ pub fn reduce_l2(
             let sum_of_squares: f32 = iter.map(|val| val * val).sum();
             sum_of_squares.sqrt()
         }
+
+        fn reduce_slice(&self, slice: &[f32]) -> f32 {
+            vec_sum_square(slice).sqrt()
+        }
     }
 
     reduce(pool, input, axes, keep_dims, L2Reducer {})
@@ -415,6 +436,58 @@ impl Operator for ReduceL2 {
     }
 }
 
+pub fn reduce_log_sum_exp(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Tensor, OpError> {
+    struct LogSumExpReducer {}
+    impl Reducer<f32> for LogSumExpReducer {
+        fn reduce<I: ExactSizeIterator<Item = f32>>(&self, iter: I) -> f32 {
+            // Compute `log(sum(exp(x)))` in one pass as `max + log(sum(exp(x
+            // - max)))`, rescaling the running sum whenever the maximum
+            // changes. This avoids overflow in `exp` for large inputs.
+            let (max, sum) = iter.fold((f32::NEG_INFINITY, 0.), |(max, sum), x| {
+                if x > max {
+                    (x, sum * (max - x).exp() + 1.)
+                } else if x == max {
+                    (max, sum + 1.)
+                } else {
+                    (max, sum + (x - max).exp())
+                }
+            });
+            max + sum.ln()
+        }
+    }
+
+    reduce(pool, input, axes, keep_dims, LogSumExpReducer {})
+}
+
+#[derive(Debug)]
+pub struct ReduceLogSumExp {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+}
+
+impl Operator for ReduceLogSumExp {
+    fn name(&self) -> &str {
+        "ReduceLogSumExp"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        reduce_log_sum_exp(
+            pool,
+            input,
+            axes.as_ref().map(|axis| &axis[..]),
+            self.keep_dims,
+        )
+        .into_op_result()
+    }
+}
+
 macro_rules! dispatch_reduce_op {
     ($pool:expr, $input:expr, $reduce_op:ident, $axes:expr, $keep_dims:expr) => {
         match $input {
@@ -432,6 +505,14 @@ macro_rules! dispatch_reduce_op {
                 $keep_dims,
             )
             .into_op_result(),
+            Input::Int64Tensor(input) => $reduce_op(
+                $pool,
+                input,
+                $axes.as_ref().map(|axis| &axis[..]),
+                $keep_dims,
+            )
+            .into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     };
 }
@@ -593,17 +674,21 @@ impl Operator for ReduceProd {
     }
 }
 
-pub fn reduce_sum<T: Copy + std::iter::Sum>(
+pub fn reduce_sum<T: SliceSum>(
     pool: &TensorPool,
     input: TensorView<T>,
     axes: Option<&[i32]>,
     keep_dims: bool,
 ) -> Result<Tensor<T>, OpError> {
     struct SumReducer {}
-    impl<T: std::iter::Sum> Reducer<T> for SumReducer {
+    impl<T: SliceSum> Reducer<T> for SumReducer {
         fn reduce<I: ExactSizeIterator<Item = T>>(&self, iter: I) -> T {
             iter.sum()
         }
+
+        fn reduce_slice(&self, slice: &[T]) -> T {
+            slice_sum(slice)
+        }
     }
     reduce(pool, input, axes, keep_dims, SumReducer {})
 }
@@ -626,17 +711,21 @@ impl Operator for ReduceSum {
     }
 }
 
-pub fn reduce_sum_square<T: Copy + std::ops::Mul<T, Output = T> + std::iter::Sum>(
+pub fn reduce_sum_square<T: SliceSum + std::ops::Mul<T, Output = T>>(
     pool: &TensorPool,
     input: TensorView<T>,
     axes: Option<&[i32]>,
     keep_dims: bool,
 ) -> Result<Tensor<T>, OpError> {
     struct SumSquareReducer {}
-    impl<T: Copy + std::iter::Sum + std::ops::Mul<Output = T>> Reducer<T> for SumSquareReducer {
+    impl<T: SliceSum + std::ops::Mul<Output = T>> Reducer<T> for SumSquareReducer {
         fn reduce<I: ExactSizeIterator<Item = T>>(&self, iter: I) -> T {
             iter.map(|x| x * x).sum()
         }
+
+        fn reduce_slice(&self, slice: &[T]) -> T {
+            T::slice_sum_square(slice)
+        }
     }
     reduce(pool, input, axes, keep_dims, SumSquareReducer {})
 }
@@ -659,6 +748,126 @@ impl Operator for ReduceSumSquare {
     }
 }
 
+/// Reducer which computes the variance of a set of values.
+///
+/// The divisor is `N - correction` where `N` is the number of values.
+struct VarReducer {
+    correction: usize,
+}
+
+impl VarReducer {
+    fn divisor(&self, len: usize) -> f32 {
+        len.saturating_sub(self.correction) as f32
+    }
+}
+
+impl Reducer<f32> for VarReducer {
+    fn reduce<I: ExactSizeIterator<Item = f32>>(&self, iter: I) -> f32 {
+        // Single-pass algorithm which shifts values by the first element.
+        // This avoids the catastrophic cancellation from computing
+        // `E[x^2] - E[x]^2` directly when the mean is large relative to the
+        // variance.
+        let len = iter.len();
+        let mut iter = iter.peekable();
+        let shift = iter.peek().copied().unwrap_or(0.);
+        let (sum_sq, sum) = iter.fold((0., 0.), |(sum_sq, sum): (f32, f32), x| {
+            let d = x - shift;
+            (sum_sq + d * d, sum + d)
+        });
+        let m2 = sum_sq - sum * sum / len as f32;
+        m2 / self.divisor(len)
+    }
+
+    fn reduce_slice(&self, slice: &[f32]) -> f32 {
+        // For contiguous inputs, use a two-pass algorithm with vectorized
+        // passes to compute the mean and then the sum of squared deviations.
+        let mean = slice_sum(slice) / slice.len() as f32;
+        let m2 = vec_sum_square_sub(slice, mean);
+        m2 / self.divisor(slice.len())
+    }
+}
+
+/// Compute the variance of elements along `axes`.
+///
+/// `correction` is subtracted from the number of elements to obtain the
+/// divisor. Use 0 for the population variance and 1 for the sample variance.
+pub fn reduce_var(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+    correction: usize,
+) -> Result<Tensor, OpError> {
+    reduce(pool, input, axes, keep_dims, VarReducer { correction })
+}
+
+#[derive(Debug)]
+pub struct ReduceVar {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+    pub correction: usize,
+}
+
+impl Operator for ReduceVar {
+    fn name(&self) -> &str {
+        "ReduceVar"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        reduce_var(
+            pool,
+            input,
+            axes.as_ref().map(|axis| &axis[..]),
+            self.keep_dims,
+            self.correction,
+        )
+        .into_op_result()
+    }
+}
+
+/// Compute the standard deviation of elements along `axes`.
+///
+/// See [`reduce_var`] for the meaning of `correction`.
+pub fn reduce_std(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+    correction: usize,
+) -> Result<Tensor, OpError> {
+    let mut output = reduce_var(pool, input, axes, keep_dims, correction)?;
+    output.apply(|x| x.sqrt());
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct ReduceStd {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+    pub correction: usize,
+}
+
+impl Operator for ReduceStd {
+    fn name(&self) -> &str {
+        "ReduceStd"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        reduce_std(
+            pool,
+            input,
+            axes.as_ref().map(|axis| &axis[..]),
+            self.keep_dims,
+            self.correction,
+        )
+        .into_op_result()
+    }
+}
+
 pub fn topk<T: Copy + Default + PartialOrd>(
     pool: &TensorPool,
     values: TensorView<T>,
@@ -686,6 +895,7 @@ pub fn topk<T: Copy + Default + PartialOrd>(
     if k > axis_size {
         return Err(OpError::InvalidValue("k > dimension size"));
     }
+    index_to_i32(axis_size - 1)?;
 
     let topk_cmp = |(a_val, a_idx): &(T, usize), (b_val, b_idx): &(T, usize)| -> Ordering {
         // NaN values are treated as greater than other values, for consistency
@@ -764,6 +974,12 @@ impl Operator for TopK {
                     topk(pool, values, k, self.axis, self.largest, self.sorted)?;
                 Ok([values.into(), indices.into()].into_iter().collect())
             }
+            Input::Int64Tensor(values) => {
+                let (values, indices) =
+                    topk(pool, values, k, self.axis, self.largest, self.sorted)?;
+                Ok([values.into(), indices.into()].into_iter().collect())
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -778,9 +994,10 @@ mod tests {
 
     use crate::ops::tests::{new_pool, run_op};
     use crate::ops::{
-        arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean, reduce_min,
-        reduce_prod, reduce_sum, reduce_sum_square, topk, OpError, Operator, ReduceL2, ReduceMax,
-        ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare,
+        arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_log_sum_exp, reduce_max, reduce_mean,
+        reduce_min, reduce_prod, reduce_std, reduce_sum, reduce_sum_square, reduce_var, topk,
+        OpError, Operator, ReduceL2, ReduceLogSumExp, ReduceMax, ReduceMean, ReduceMin, ReduceProd,
+        ReduceSum, ReduceSumSquare, ReduceVar,
     };
 
     #[test]
@@ -892,7 +1109,7 @@ mod tests {
     fn test_nonzero() {
         let pool = new_pool();
         let input = tensor!((2, 2); [0., 1., 1., 1.]);
-        let result = nonzero(&pool, input.view());
+        let result = nonzero(&pool, input.view()).unwrap();
         assert_eq!(result.shape(), &[2, 3]);
 
         // (dim, index) => (index, dim)
@@ -913,11 +1130,11 @@ mod tests {
     fn test_nonzero_scalar() {
         let pool = new_pool();
         let input = tensor!(3.);
-        let result = nonzero(&pool, input.view());
+        let result = nonzero(&pool, input.view()).unwrap();
         assert_eq!(result.shape(), &[0, 1]);
 
         let input = tensor!(0.);
-        let result = nonzero(&pool, input.view());
+        let result = nonzero(&pool, input.view()).unwrap();
         assert_eq!(result.shape(), &[0, 0]);
     }
 
@@ -942,6 +1159,7 @@ mod tests {
 
         let cases = [
             op_case!(ReduceL2),
+            op_case!(ReduceLogSumExp),
             op_case!(ReduceMax),
             op_case!(ReduceMean),
             op_case!(ReduceMin),
@@ -990,6 +1208,93 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reduce_var_std() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[2, 4], vec![1., 2., 3., 4., 2., 4., 6., 8.]);
+
+        // Contiguous lanes, population variance.
+        let result = reduce_var(&pool, input.view(), Some(&[1]), false, 0)?;
+        expect_equal(&result, &tensor!([1.25, 5.]))?;
+
+        // Contiguous lanes, sample variance.
+        let result = reduce_var(&pool, input.view(), Some(&[1]), true, 1)?;
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[2, 1], vec![5. / 3., 20. / 3.]),
+        )?;
+
+        // Non-contiguous lanes.
+        let result = reduce_var(&pool, input.view(), Some(&[0]), false, 0)?;
+        expect_equal(&result, &tensor!([0.25, 1., 2.25, 4.]))?;
+
+        // Standard deviation
+        let result = reduce_std(&pool, input.view(), Some(&[0]), false, 0)?;
+        expect_equal(&result, &tensor!([0.5, 1., 1.5, 2.]))?;
+
+        // Values with a large mean relative to the variance. A naive
+        // `E[x^2] - E[x]^2` computation loses all precision here.
+        let input = Tensor::from_data(
+            &[4, 2],
+            vec![
+                10000.5, 10000.5, 10001.5, 10001.5, 10000.5, 10000.5, 10001.5, 10001.5,
+            ],
+        );
+        let result = reduce_var(&pool, input.view(), Some(&[0]), false, 0)?;
+        expect_equal(&result, &tensor!([0.25, 0.25]))?;
+        let result = reduce_var(&pool, input.view(), None, false, 0)?;
+        expect_equal(&result, &Tensor::from_scalar(0.25))?;
+
+        // Operator with axes supplied as an input.
+        let op = ReduceVar {
+            axes: None,
+            keep_dims: false,
+            correction: 0,
+        };
+        let input = Tensor::from_data(&[2, 2], vec![1., 3., 5., 7.]);
+        let axes = tensor!([1]);
+        let result: Tensor<f32> = run_op(&op, (input.view(), axes.view()))?;
+        expect_equal(&result, &tensor!([1., 1.]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_log_sum_exp() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[2, 3], vec![0., 1., 2., -1., 0.5, 3.]);
+        let expected: Tensor = [[0., 1., 2.], [-1., 0.5, 3.]]
+            .iter()
+            .map(|row| row.iter().map(|x: &f32| x.exp()).sum::<f32>().ln())
+            .collect::<Vec<_>>()
+            .into();
+
+        let result =
+            reduce_log_sum_exp(&pool, input.view(), Some(&[1]), false /* keep_dims */)?;
+        expect_equal(&result, &expected)?;
+
+        let result =
+            reduce_log_sum_exp(&pool, input.view(), Some(&[1]), true /* keep_dims */)?;
+        let expected = expected.to_shape([2, 1].as_slice());
+        expect_equal(&result, &expected)?;
+
+        // Large inputs, where a naive `log(sum(exp(x)))` would overflow.
+        let input = tensor!([1000., 1000.]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false /* keep_dims */)?;
+        expect_equal(&result, &Tensor::from_scalar(1000. + 2f32.ln()))?;
+
+        // Infinite inputs
+        let input = tensor!([f32::NEG_INFINITY, f32::NEG_INFINITY]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false /* keep_dims */)?;
+        assert_eq!(result.item(), Some(&f32::NEG_INFINITY));
+
+        let input = tensor!([1., f32::INFINITY, f32::INFINITY]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false /* keep_dims */)?;
+        assert_eq!(result.item(), Some(&f32::INFINITY));
+
+        Ok(())
+    }
+
     #[test]
     fn test_reduce_mean() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1173,6 +1478,14 @@ mod tests {
             false, /* keep_dims */
         ));
         assert_eq!(result, input.iter().sum::<f32>());
+
+        // Contiguous tensor with a size-1 dim whose stride does not match
+        // the size of the inner dims.
+        let data = Tensor::from_data(&[4, 1], vec![1, 2, 3, 4]);
+        let input = data.permuted(&[1, 0]);
+        assert!(input.is_contiguous());
+        let result = reduce_sum(&pool, input, Some(&[1]), false /* keep_dims */).unwrap();
+        assert_eq!(result.to_vec(), &[10]);
     }
 
     #[test]
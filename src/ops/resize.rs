@@ -1,13 +1,15 @@
 use std::iter::zip;
 use std::mem::MaybeUninit;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::ops::Range;
 
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::tensor_pool::TensorPool;
+use crate::ops::{
+    index_to_i32, resolve_axes, Input, InputList, IntoOpResult, OpError, Operator, Output,
+};
+use crate::tensor_pool::{AutoReturn, TensorPool};
 use crate::{check_dims, static_dims};
 
 /// Specifies an output size for a resize operation.
@@ -28,6 +30,8 @@ pub enum ResizeTarget<'a> {
 ///   `coordinate_transformation_mode` attribute.
 /// - `length_original` is the size of the axis in the input
 /// - `length_resized` is the size of the axis in the output
+/// - `roi` is the normalized `[start, end]` region of the axis to resize.
+///   This is only used if `mode` is `TfCropAndResize`.
 ///
 /// See https://github.com/onnx/onnx/blob/v1.15.0/docs/Operators.md#resize
 /// for the formulae for different transform modes.
@@ -42,14 +46,28 @@ fn input_coord(
     mode: CoordTransformMode,
     length_original: usize,
     length_resized: usize,
+    roi: [f32; 2],
 ) -> f32 {
     type Ctm = CoordTransformMode;
     match mode {
         Ctm::HalfPixel => scale * (dest_coord as f32 + 0.5) - 0.5,
+        Ctm::PytorchHalfPixel if length_resized <= 1 => 0.,
+        Ctm::PytorchHalfPixel => scale * (dest_coord as f32 + 0.5) - 0.5,
         Ctm::Asymmetric => scale * dest_coord as f32,
+        Ctm::AlignCorners if length_resized <= 1 => 0.,
         Ctm::AlignCorners => {
             dest_coord as f32 * (length_original - 1) as f32 / (length_resized - 1) as f32
         }
+        Ctm::TfCropAndResize => {
+            let [start, end] = roi;
+            let max_coord = (length_original - 1) as f32;
+            if length_resized <= 1 {
+                0.5 * (start + end) * max_coord
+            } else {
+                start * max_coord
+                    + dest_coord as f32 * (end - start) * max_coord / (length_resized - 1) as f32
+            }
+        }
     }
 }
 
@@ -72,30 +90,26 @@ pub enum CoordTransformMode {
     HalfPixel,
     Asymmetric,
     AlignCorners,
-}
 
-const CHAN_GROUP_SIZE: usize = 4;
+    /// Same as `HalfPixel`, except that output axes of length 1 always map
+    /// to input coordinate 0.
+    PytorchHalfPixel,
+
+    /// Map output coordinates to a region of the input specified by the
+    /// `roi` input. Output coordinates which map to points outside the input
+    /// are set to the extrapolation value.
+    TfCropAndResize,
+}
 
 /// Interpolate between `a` and `b` according to `weight`.
 fn lerp(a: f32, b: f32, weight: f32) -> f32 {
     (1. - weight) * a + weight * b
 }
 
-/// Resize a group of channels in a CHW tensor using nearest neighbor resizing.
-fn nearest_resize(
-    input: NdTensorView<f32, 3>,
-    mut output: NdTensorViewMut<MaybeUninit<f32>, 3>,
-    mode: NearestMode,
-    coord_mode: CoordTransformMode,
-) {
-    let [chans, rows, cols] = output.shape();
-    let [_, in_rows, in_cols] = input.shape();
-
-    // Scale factors to map output coords to input coords.
-    let inv_scale_y = in_rows as f32 / rows as f32;
-    let inv_scale_x = in_cols as f32 / cols as f32;
-
-    let round_coord = |coord: f32| match mode {
+/// Map a fractional input coordinate to an integer coordinate for nearest
+/// neighbor resizing.
+fn round_coord(coord: f32, mode: NearestMode) -> usize {
+    match mode {
         NearestMode::Ceil => coord.ceil() as usize,
         NearestMode::Floor => coord as usize,
 
@@ -115,94 +129,342 @@ fn nearest_resize(
                 coord.round() as usize
             }
         }
-    };
+    }
+}
 
-    let mut n_init = 0;
-    for y in 0..rows {
-        let in_y = round_coord(
-            input_coord(y, inv_scale_y, coord_mode, in_rows, rows).clamp(0., in_rows as f32 - 1.),
-        );
-        for x in 0..cols {
-            let in_x = round_coord(
-                input_coord(x, inv_scale_x, coord_mode, in_cols, cols)
-                    .clamp(0., in_cols as f32 - 1.),
-            );
-
-            for c in 0..chans {
-                output[[c, y, x]].write(input[[c, in_y, in_x]]);
-                n_init += 1;
+/// Compute the input coordinate that is copied to each output coordinate
+/// along an axis, for nearest neighbor resizing.
+fn nearest_coords(
+    length_original: usize,
+    length_resized: usize,
+    mode: NearestMode,
+    coord_mode: CoordTransformMode,
+    roi: [f32; 2],
+) -> Vec<usize> {
+    // Scale factor to map output coords to input coords.
+    let inv_scale = length_original as f32 / length_resized as f32;
+
+    (0..length_resized)
+        .map(|i| {
+            let coord = input_coord(
+                i,
+                inv_scale,
+                coord_mode,
+                length_original,
+                length_resized,
+                roi,
+            )
+            .clamp(0., length_original as f32 - 1.);
+            round_coord(coord, mode)
+        })
+        .collect()
+}
+
+/// Input coordinates and interpolation weight for an output coordinate along
+/// an axis, for linear resizing.
+#[derive(Clone, Copy)]
+struct LinearCoord {
+    lo: usize,
+    hi: usize,
+
+    /// Weight of the value at `hi`.
+    weight: f32,
+}
+
+/// Compute the input coordinates and weights for each output coordinate along
+/// an axis, for linear resizing.
+fn linear_coords(
+    length_original: usize,
+    length_resized: usize,
+    coord_mode: CoordTransformMode,
+    roi: [f32; 2],
+) -> Vec<LinearCoord> {
+    // Scale factor to map output coords to input coords.
+    let inv_scale = length_original as f32 / length_resized as f32;
+
+    (0..length_resized)
+        .map(|i| {
+            let coord = input_coord(
+                i,
+                inv_scale,
+                coord_mode,
+                length_original,
+                length_resized,
+                roi,
+            )
+            .clamp(0., length_original as f32 - 1.);
+            let lo = coord as usize;
+            let hi = (lo + 1).min(length_original - 1);
+            LinearCoord {
+                lo,
+                hi,
+                weight: coord - lo as f32,
             }
-        }
+        })
+        .collect()
+}
+
+/// Resize a single channel of an image or volume using nearest neighbor
+/// resizing.
+///
+/// `input` and `output` are the contiguous data for the channel, and
+/// `in_shape` is the `[depth, height, width]` shape of the input channel. For
+/// 2D images the depth is 1. `zs`, `ys` and `xs` are the input coordinates
+/// for each output coordinate along each axis.
+fn nearest_resize(
+    input: &[f32],
+    output: &mut [MaybeUninit<f32>],
+    in_shape: [usize; 3],
+    zs: &[usize],
+    ys: &[usize],
+    xs: &[usize],
+) {
+    let [_, in_rows, in_cols] = in_shape;
+    debug_assert_eq!(output.len(), zs.len() * ys.len() * xs.len());
+
+    output
+        .par_chunks_mut(xs.len())
+        .enumerate()
+        .for_each(|(row, out_row)| {
+            let in_z = zs[row / ys.len()];
+            let in_y = ys[row % ys.len()];
+            let in_row = &input[(in_z * in_rows + in_y) * in_cols..][..in_cols];
+            for (out, &in_x) in zip(out_row, xs) {
+                out.write(in_row[in_x]);
+            }
+        });
+}
+
+/// Linearly interpolate between two rows.
+fn lerp_rows(out: &mut [f32], a: &[f32], b: &[f32], weight: f32) {
+    for (out, (&a, &b)) in zip(out, zip(a, b)) {
+        *out = lerp(a, b, weight);
     }
-    assert!(n_init == output.len());
 }
 
-/// Resize a group of channels in a CHW tensor using bilinear resizing.
-fn bilinear_resize(
-    input: NdTensorView<f32, 3>,
-    mut output: NdTensorViewMut<MaybeUninit<f32>, 3>,
-    coord_mode: CoordTransformMode,
+/// Resize a single channel of an image or volume using bilinear or trilinear
+/// resizing.
+///
+/// `input` and `output` are the contiguous data for the channel, and
+/// `in_shape` is the `[depth, height, width]` shape of the input channel. For
+/// 2D images the depth is 1. `zs`, `ys` and `xs` are the input coordinates
+/// for each output coordinate along each axis.
+fn linear_resize(
+    input: &[f32],
+    output: &mut [MaybeUninit<f32>],
+    in_shape: [usize; 3],
+    zs: &[LinearCoord],
+    ys: &[LinearCoord],
+    xs: &[LinearCoord],
 ) {
-    let [chans, rows, cols] = output.shape();
-    let [_, in_rows, in_cols] = input.shape();
-
-    // Scale factors to map output coords to input coords.
-    let inv_scale_y = in_rows as f32 / rows as f32;
-    let inv_scale_x = in_cols as f32 / cols as f32;
-
-    let mut n_init = 0;
-    for y in 0..rows {
-        let in_y =
-            input_coord(y, inv_scale_y, coord_mode, in_rows, rows).clamp(0., in_rows as f32 - 1.);
-        let in_y1 = in_y as usize;
-        let in_y2 = (in_y1 + 1).min(in_rows - 1);
-        let weight_y = in_y - (in_y1 as f32);
-
-        for x in 0..cols {
-            let in_x = input_coord(x, inv_scale_x, coord_mode, in_cols, cols)
-                .clamp(0., in_cols as f32 - 1.);
-            let in_x1 = in_x as usize;
-            let in_x2 = (in_x1 + 1).min(in_cols - 1);
-            let weight_x = in_x - (in_x1 as f32);
-
-            const N: usize = CHAN_GROUP_SIZE;
-            if chans == N {
-                let in_tl = input.get_array::<N>([0, in_y1, in_x1], 0);
-                let in_tr = input.get_array::<N>([0, in_y1, in_x2], 0);
-                let in_bl = input.get_array::<N>([0, in_y2, in_x1], 0);
-                let in_br = input.get_array::<N>([0, in_y2, in_x2], 0);
-
-                let mut out = [MaybeUninit::new(0.); N];
-                for c in 0..chans {
-                    // Interpolate in X direction
-                    let out_top = lerp(in_tl[c], in_tr[c], weight_x);
-                    let out_bottom = lerp(in_bl[c], in_br[c], weight_x);
-
-                    // Interpolate in Y direction
-                    out[c].write(lerp(out_top, out_bottom, weight_y));
-                    n_init += 1;
+    let [_, in_rows, in_cols] = in_shape;
+    debug_assert_eq!(output.len(), zs.len() * ys.len() * xs.len());
+
+    let in_row = |z: usize, y: usize| &input[(z * in_rows + y) * in_cols..][..in_cols];
+
+    output.par_chunks_mut(xs.len()).enumerate().for_each_init(
+        || (vec![0.; in_cols], vec![0.; in_cols]),
+        |(tmp_row, tmp_row_hi), (row, out_row)| {
+            let z = zs[row / ys.len()];
+            let y = ys[row % ys.len()];
+
+            // Interpolate in Y direction, then in Z direction for volumes.
+            // This is done for a whole row at a time so that the loops
+            // can be vectorized.
+            lerp_rows(tmp_row, in_row(z.lo, y.lo), in_row(z.lo, y.hi), y.weight);
+            if z.hi != z.lo {
+                lerp_rows(tmp_row_hi, in_row(z.hi, y.lo), in_row(z.hi, y.hi), y.weight);
+                for (lo, &hi) in zip(tmp_row.iter_mut(), tmp_row_hi.iter()) {
+                    *lo = lerp(*lo, hi, z.weight);
                 }
+            }
 
-                output.set_array([0, y, x], 0, out);
-            } else {
-                for c in 0..chans {
-                    let in_tl = input[[c, in_y1, in_x1]];
-                    let in_tr = input[[c, in_y1, in_x2]];
-                    let in_bl = input[[c, in_y2, in_x1]];
-                    let in_br = input[[c, in_y2, in_x2]];
-
-                    // Interpolate in X direction
-                    let out_top = lerp(in_tl, in_tr, weight_x);
-                    let out_bottom = lerp(in_bl, in_br, weight_x);
-
-                    // Interpolate in Y direction
-                    output[[c, y, x]].write(lerp(out_top, out_bottom, weight_y));
-                    n_init += 1;
+            // Interpolate in X direction
+            for (out, x) in zip(out_row, xs) {
+                out.write(lerp(tmp_row[x.lo], tmp_row[x.hi], x.weight));
+            }
+        },
+    );
+}
+
+/// Weights for computing each output coordinate along an axis as a weighted sum
+/// of a range of input coordinates.
+struct FilterWeights {
+    /// Range of input coordinates used for each output coordinate.
+    ranges: Vec<Range<usize>>,
+
+    /// Weights for each output coordinate. Entries for output coordinate `i`
+    /// start at `i * max_taps`.
+    weights: Vec<f32>,
+
+    max_taps: usize,
+}
+
+impl FilterWeights {
+    /// Compute weights for nearest neighbor resizing along an axis, given
+    /// the input coordinate for each output coordinate.
+    fn nearest(coords: &[usize]) -> FilterWeights {
+        FilterWeights {
+            ranges: coords.iter().map(|&coord| coord..coord + 1).collect(),
+            weights: vec![1.; coords.len()],
+            max_taps: 1,
+        }
+    }
+
+    /// Compute weights for linear resizing along an axis, given the input
+    /// coordinates and weights for each output coordinate.
+    fn linear(coords: &[LinearCoord]) -> FilterWeights {
+        let mut ranges = Vec::with_capacity(coords.len());
+        let mut weights = Vec::with_capacity(coords.len() * 2);
+        for coord in coords {
+            ranges.push(coord.lo..coord.hi + 1);
+            weights.extend([1. - coord.weight, coord.weight]);
+        }
+        FilterWeights {
+            ranges,
+            weights,
+            max_taps: 2,
+        }
+    }
+
+    /// Compute weights for linear resizing with anti-aliasing along an axis.
+    ///
+    /// When downscaling, the support of the triangle filter is widened by
+    /// the inverse of the scale factor, so that every input pixel contributes
+    /// to the output. This matches the behavior of Pillow's `Image.resize`
+    /// and PyTorch's `interpolate(..., antialias=True)`. Taps which fall
+    /// outside the input are excluded and the remaining weights are
+    /// re-normalized.
+    fn linear_antialias(
+        length_original: usize,
+        length_resized: usize,
+        coord_mode: CoordTransformMode,
+        roi: [f32; 2],
+    ) -> FilterWeights {
+        let inv_scale = length_original as f32 / length_resized as f32;
+        let support = inv_scale.max(1.);
+        let max_taps = support.ceil() as usize * 2 + 1;
+
+        let mut ranges = Vec::with_capacity(length_resized);
+        let mut weights = vec![0.; length_resized * max_taps];
+
+        for (i, out_weights) in weights.chunks_mut(max_taps).enumerate() {
+            let center = input_coord(
+                i,
+                inv_scale,
+                coord_mode,
+                length_original,
+                length_resized,
+                roi,
+            ) + 0.5;
+            let min = (center - support + 0.5).floor().max(0.) as usize;
+            let max = ((center + support + 0.5).floor().max(0.) as usize)
+                .min(length_original)
+                .min(min + max_taps);
+
+            let mut total = 0.;
+            for (j, w) in (min..max).zip(out_weights.iter_mut()) {
+                let x = (j as f32 - center + 0.5) / support;
+                *w = (1. - x.abs()).max(0.);
+                total += *w;
+            }
+            if total > 0. {
+                for w in out_weights.iter_mut() {
+                    *w /= total;
                 }
             }
+            ranges.push(min..max);
+        }
+
+        FilterWeights {
+            ranges,
+            weights,
+            max_taps,
         }
     }
-    assert!(n_init == output.len());
+
+    /// Return the number of output coordinates.
+    fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Return the input coordinates and weights for an output coordinate.
+    fn get(&self, index: usize) -> (Range<usize>, &[f32]) {
+        let range = self.ranges[index].clone();
+        let start = index * self.max_taps;
+        let weights = &self.weights[start..start + range.len()];
+        (range, weights)
+    }
+}
+
+/// Apply a filter along the middle axis of a contiguous `[outer, in_len, inner]`
+/// tensor, producing an `[outer, weights.len(), inner]` tensor.
+fn filter_axis(
+    input: &[f32],
+    output: &mut [f32],
+    in_len: usize,
+    inner: usize,
+    weights: &FilterWeights,
+) {
+    let out_len = weights.len();
+    output
+        .par_chunks_mut(inner)
+        .enumerate()
+        .for_each(|(i, out_row)| {
+            let outer = i / out_len;
+            let (range, weights) = weights.get(i % out_len);
+            out_row.fill(0.);
+            for (in_i, &w) in zip(range, weights) {
+                let in_row = &input[(outer * in_len + in_i) * inner..][..inner];
+                for (out, &val) in zip(out_row.iter_mut(), in_row) {
+                    *out += w * val;
+                }
+            }
+        });
+}
+
+/// Resize a single channel of an image or volume using linear resizing with
+/// anti-aliasing.
+///
+/// The resize is performed separably, along the X axis, then the Y axis and
+/// finally the Z axis for volumes.
+fn antialias_linear_resize(
+    input: &[f32],
+    output: &mut [MaybeUninit<f32>],
+    in_shape: [usize; 3],
+    weights_z: &FilterWeights,
+    weights_y: &FilterWeights,
+    weights_x: &FilterWeights,
+) {
+    let [in_depth, in_rows, in_cols] = in_shape;
+    let [depth, rows, cols] = [weights_z.len(), weights_y.len(), weights_x.len()];
+    debug_assert_eq!(output.len(), depth * rows * cols);
+
+    let mut tmp_x = vec![0.; in_depth * in_rows * cols];
+    tmp_x
+        .par_chunks_mut(cols)
+        .zip(input.par_chunks(in_cols))
+        .for_each(|(tmp_row, in_row)| {
+            for (x, tmp) in tmp_row.iter_mut().enumerate() {
+                let (range, weights) = weights_x.get(x);
+                *tmp = zip(&in_row[range], weights).map(|(val, w)| val * w).sum();
+            }
+        });
+
+    let mut tmp_y = vec![0.; in_depth * rows * cols];
+    filter_axis(&tmp_x, &mut tmp_y, in_rows, cols, weights_y);
+
+    let resized = if depth != in_depth {
+        let mut tmp_z = vec![0.; depth * rows * cols];
+        filter_axis(&tmp_y, &mut tmp_z, in_depth, rows * cols, weights_z);
+        tmp_z
+    } else {
+        tmp_y
+    };
+
+    for (out, &val) in zip(output, &resized) {
+        out.write(val);
+    }
 }
 
 /// Resize an NCHW image tensor to a given `[height, width]`.
@@ -211,7 +473,12 @@ fn bilinear_resize(
 pub fn resize_image(input: TensorView, size: [usize; 2]) -> Result<Tensor, OpError> {
     let [batch, chans, _height, _width] = check_dims!(input, 4);
     let [out_height, out_width] = size;
-    let out_shape = [batch, chans, out_height, out_width].map(|x| x as i32);
+    let out_shape = [
+        index_to_i32(batch)?,
+        index_to_i32(chans)?,
+        index_to_i32(out_height)?,
+        index_to_i32(out_width)?,
+    ];
     resize(
         &TensorPool::new(),
         input,
@@ -219,9 +486,29 @@ pub fn resize_image(input: TensorView, size: [usize; 2]) -> Result<Tensor, OpErr
         ResizeMode::Linear,
         CoordTransformMode::default(),
         NearestMode::default(),
+        false, /* antialias */
+        None,  /* roi */
+        0.,    /* extrapolation_value */
     )
 }
 
+/// Resize a tensor along any of its dimensions.
+///
+/// `target` specifies the output size for every dimension. Dimensions whose
+/// size is unchanged are left as-is. Resizing of the last 3 dimensions (eg.
+/// the spatial dimensions of NCHW or NCDHW tensors) is optimized, but any
+/// subset of dimensions can be resized.
+///
+/// If `antialias` is true and `mode` is [ResizeMode::Linear], an
+/// anti-aliasing filter is applied when downscaling. This has no effect for
+/// nearest-neighbor resizing.
+///
+/// If `coord_mode` is [CoordTransformMode::TfCropAndResize], `roi` specifies
+/// the region of the input to resize as normalized `[starts..., ends...]`
+/// coordinates for each dimension, and output elements which map to points
+/// outside the input are set to `extrapolation_value`. Otherwise these
+/// arguments are ignored.
+#[allow(clippy::too_many_arguments)]
 pub fn resize(
     pool: &TensorPool,
     input: TensorView,
@@ -229,11 +516,35 @@ pub fn resize(
     mode: ResizeMode,
     coord_mode: CoordTransformMode,
     nearest_mode: NearestMode,
+    antialias: bool,
+    roi: Option<NdTensorView<f32, 1>>,
+    extrapolation_value: f32,
 ) -> Result<Tensor, OpError> {
+    let ndim = input.ndim();
+    let crop = matches!(coord_mode, CoordTransformMode::TfCropAndResize);
+    let rois: Vec<[f32; 2]> = match roi {
+        Some(roi) if crop => {
+            if roi.len() != ndim * 2 {
+                return Err(OpError::IncompatibleInputShapes(
+                    "roi length should equal twice the input rank",
+                ));
+            }
+            (0..ndim).map(|i| [roi[[i]], roi[[ndim + i]]]).collect()
+        }
+        _ => vec![[0., 1.]; ndim],
+    };
+
     let sizes: NdTensor<i32, 1> = match target {
-        ResizeTarget::Scales(scales) => zip(input.shape().iter(), scales.iter())
-            .map(|(&in_size, scale)| ((in_size as f32) * scale).floor() as i32)
-            .collect(),
+        ResizeTarget::Scales(scales) => zip(zip(input.shape().iter(), &rois), scales.iter())
+            .map(|((&in_size, [start, end]), scale)| {
+                let size = ((in_size as f32) * (end - start) * scale).floor();
+                if size > i32::MAX as f32 {
+                    Err(OpError::UnsupportedValue("Output size exceeds i32::MAX"))
+                } else {
+                    Ok(size as i32)
+                }
+            })
+            .collect::<Result<_, _>>()?,
         ResizeTarget::Sizes(sizes) => sizes.to_tensor(),
     };
 
@@ -246,60 +557,236 @@ pub fn resize(
         return Err(OpError::InvalidValue("scales/sizes must be positive"));
     }
 
-    // The current implementation only supports NCHW tensors with scale factors
-    // other than 1.0 for the H and W dims.
-    let [batch, _chans, _height, _width] = check_dims!(input, 4, "NCHW");
-    let sizes_valid = zip(0..input.ndim(), input.shape().iter()).all(|(dim, &in_size)| {
-        dim == input.ndim() - 1 || dim == input.ndim() - 2 || sizes[[dim]] == in_size as i32
-    });
-    if !sizes_valid {
-        return Err(OpError::UnsupportedValue(
-            "only height and width dimensions can be resized",
-        ));
-    }
-
-    let sizes_usize: Vec<_> = sizes.iter().map(|size| *size as usize).collect();
-    let mut output = Tensor::uninit_in(pool, &sizes_usize);
+    let sizes: Vec<usize> = sizes.iter().map(|size| *size as usize).collect();
+    let mut output = Tensor::uninit_in(pool, &sizes);
 
     if output.is_empty() {
         // Safety: Empty output is already initialized.
         let output = unsafe { output.assume_init() };
         return Ok(output);
     }
+    if input.is_empty() {
+        return Err(OpError::InvalidValue(
+            "cannot resize an empty input to a non-empty size",
+        ));
+    }
 
-    let n_init = AtomicUsize::new(0);
-    for n in 0..batch {
-        let in_image = input.slice::<3, _>([n]);
-        let mut out_image = output.slice_mut::<3, _>([n]);
-
-        out_image
-            .axis_chunks_mut(0, CHAN_GROUP_SIZE)
-            .zip(in_image.axis_chunks(0, CHAN_GROUP_SIZE))
-            .par_bridge()
-            .for_each(|(mut out_chans, in_chans)| {
-                match mode {
-                    ResizeMode::Nearest => {
-                        nearest_resize(
-                            in_chans.nd_view(),
-                            out_chans.nd_view_mut(),
-                            nearest_mode,
-                            coord_mode,
-                        );
-                    }
-                    ResizeMode::Linear => {
-                        bilinear_resize(in_chans.nd_view(), out_chans.nd_view_mut(), coord_mode);
-                    }
-                };
-                n_init.fetch_add(out_chans.len(), Ordering::SeqCst);
-            });
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let in_data = input.data().unwrap();
+    let out_data = output.data_mut().unwrap();
+
+    // If only the last 3 axes are resized, all leading axes can be treated as
+    // channels which are resized independently. Otherwise resize each axis
+    // in turn.
+    let spatial_only = (0..ndim.saturating_sub(3))
+        .all(|axis| input.size(axis) == sizes[axis] && rois[axis] == [0., 1.]);
+
+    if spatial_only {
+        spatial_resize(
+            in_data,
+            out_data,
+            spatial_shape(input.shape()),
+            spatial_shape(&sizes),
+            spatial_rois(&rois),
+            mode,
+            coord_mode,
+            nearest_mode,
+            antialias,
+        );
+    } else {
+        separable_resize(
+            in_data,
+            out_data,
+            input.shape(),
+            &sizes,
+            &rois,
+            mode,
+            coord_mode,
+            nearest_mode,
+            antialias,
+        );
     }
 
-    assert!(n_init.load(Ordering::SeqCst) == output.len());
-    let output = unsafe { output.assume_init() };
+    // Safety: The resize kernels initialize every element of the output.
+    let mut output = unsafe { output.assume_init() };
+
+    if crop {
+        fill_extrapolated(&mut output, input.shape(), &rois, extrapolation_value);
+    }
 
     Ok(output)
 }
 
+/// Return the ROIs for the last 3 dims, padding with full ranges if there
+/// are fewer than 3 dims.
+///
+/// This is the ROI equivalent of [spatial_shape].
+fn spatial_rois(rois: &[[f32; 2]]) -> [[f32; 2]; 3] {
+    let mut spatial = [[0., 1.]; 3];
+    let n = rois.len().min(3);
+    spatial[3 - n..].copy_from_slice(&rois[rois.len() - n..]);
+    spatial
+}
+
+/// Set elements of a resized tensor which map to coordinates outside the
+/// input to `value`, for the `TfCropAndResize` coordinate transform mode.
+fn fill_extrapolated(output: &mut Tensor, in_shape: &[usize], rois: &[[f32; 2]], value: f32) {
+    let out_shape = output.shape().to_vec();
+    let data = output.data_mut().unwrap();
+
+    for (axis, (&length_original, &length_resized)) in zip(in_shape, &out_shape).enumerate() {
+        let inner: usize = out_shape[axis + 1..].iter().product();
+        let max_coord = (length_original - 1) as f32;
+        let outside: Vec<usize> = (0..length_resized)
+            .filter(|&i| {
+                let coord = input_coord(
+                    i,
+                    1., /* scale */
+                    CoordTransformMode::TfCropAndResize,
+                    length_original,
+                    length_resized,
+                    rois[axis],
+                );
+                !(0. ..=max_coord).contains(&coord)
+            })
+            .collect();
+        if outside.is_empty() {
+            continue;
+        }
+        for block in data.chunks_mut(length_resized * inner) {
+            for &i in &outside {
+                block[i * inner..(i + 1) * inner].fill(value);
+            }
+        }
+    }
+}
+
+/// Return the last 3 dims of `shape` as a `[depth, height, width]` array,
+/// padding with 1s if there are fewer than 3 dims.
+fn spatial_shape(shape: &[usize]) -> [usize; 3] {
+    let mut spatial = [1; 3];
+    let n = shape.len().min(3);
+    spatial[3 - n..].copy_from_slice(&shape[shape.len() - n..]);
+    spatial
+}
+
+/// Resize the last 3 dims of a contiguous tensor, treating all leading dims
+/// as channels.
+///
+/// `in_shape` and `out_shape` are the `[depth, height, width]` shapes of
+/// each channel.
+#[allow(clippy::too_many_arguments)]
+fn spatial_resize(
+    input: &[f32],
+    output: &mut [MaybeUninit<f32>],
+    in_shape: [usize; 3],
+    out_shape: [usize; 3],
+    rois: [[f32; 2]; 3],
+    mode: ResizeMode,
+    coord_mode: CoordTransformMode,
+    nearest_mode: NearestMode,
+    antialias: bool,
+) {
+    let [in_depth, in_rows, in_cols] = in_shape;
+    let [depth, rows, cols] = out_shape;
+    let [roi_z, roi_y, roi_x] = rois;
+
+    // Each channel is resized independently, and work is distributed across
+    // threads by channel and by output row within each channel.
+    let in_chans = input.par_chunks(in_depth * in_rows * in_cols);
+    let out_chans = output.par_chunks_mut(depth * rows * cols);
+    let downscale = depth < in_depth || rows < in_rows || cols < in_cols;
+
+    match mode {
+        ResizeMode::Nearest => {
+            let zs = nearest_coords(in_depth, depth, nearest_mode, coord_mode, roi_z);
+            let ys = nearest_coords(in_rows, rows, nearest_mode, coord_mode, roi_y);
+            let xs = nearest_coords(in_cols, cols, nearest_mode, coord_mode, roi_x);
+            out_chans.zip(in_chans).for_each(|(out_chan, in_chan)| {
+                nearest_resize(in_chan, out_chan, in_shape, &zs, &ys, &xs);
+            });
+        }
+        ResizeMode::Linear if antialias && downscale => {
+            let weights_z = FilterWeights::linear_antialias(in_depth, depth, coord_mode, roi_z);
+            let weights_y = FilterWeights::linear_antialias(in_rows, rows, coord_mode, roi_y);
+            let weights_x = FilterWeights::linear_antialias(in_cols, cols, coord_mode, roi_x);
+            out_chans.zip(in_chans).for_each(|(out_chan, in_chan)| {
+                antialias_linear_resize(
+                    in_chan, out_chan, in_shape, &weights_z, &weights_y, &weights_x,
+                );
+            });
+        }
+        ResizeMode::Linear => {
+            let zs = linear_coords(in_depth, depth, coord_mode, roi_z);
+            let ys = linear_coords(in_rows, rows, coord_mode, roi_y);
+            let xs = linear_coords(in_cols, cols, coord_mode, roi_x);
+            out_chans.zip(in_chans).for_each(|(out_chan, in_chan)| {
+                linear_resize(in_chan, out_chan, in_shape, &zs, &ys, &xs);
+            });
+        }
+    }
+}
+
+/// Resize a contiguous tensor of any rank by filtering along each resized
+/// axis in turn.
+///
+/// Nearest neighbor and (multi-)linear interpolation are separable, so this
+/// produces the same result as resizing all axes at once.
+#[allow(clippy::too_many_arguments)]
+fn separable_resize(
+    input: &[f32],
+    output: &mut [MaybeUninit<f32>],
+    in_shape: &[usize],
+    out_shape: &[usize],
+    rois: &[[f32; 2]],
+    mode: ResizeMode,
+    coord_mode: CoordTransformMode,
+    nearest_mode: NearestMode,
+    antialias: bool,
+) {
+    let mut shape = in_shape.to_vec();
+    let mut resized: Option<Vec<f32>> = None;
+
+    for axis in (0..shape.len()).rev() {
+        let (in_len, out_len) = (shape[axis], out_shape[axis]);
+        if in_len == out_len && rois[axis] == [0., 1.] {
+            continue;
+        }
+        let weights = match mode {
+            ResizeMode::Nearest => FilterWeights::nearest(&nearest_coords(
+                in_len,
+                out_len,
+                nearest_mode,
+                coord_mode,
+                rois[axis],
+            )),
+            ResizeMode::Linear if antialias && out_len < in_len => {
+                FilterWeights::linear_antialias(in_len, out_len, coord_mode, rois[axis])
+            }
+            ResizeMode::Linear => {
+                FilterWeights::linear(&linear_coords(in_len, out_len, coord_mode, rois[axis]))
+            }
+        };
+        let outer: usize = shape[..axis].iter().product();
+        let inner: usize = shape[axis + 1..].iter().product();
+        let mut tmp = vec![0.; outer * out_len * inner];
+        filter_axis(
+            resized.as_deref().unwrap_or(input),
+            &mut tmp,
+            in_len,
+            inner,
+            &weights,
+        );
+        shape[axis] = out_len;
+        resized = Some(tmp);
+    }
+
+    let resized = resized.as_deref().unwrap_or(input);
+    for (out, &val) in zip(output, resized) {
+        out.write(val);
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub enum ResizeMode {
     #[default]
@@ -312,6 +799,18 @@ pub struct Resize {
     pub mode: ResizeMode,
     pub coord_mode: CoordTransformMode,
     pub nearest_mode: NearestMode,
+
+    /// Apply an anti-aliasing filter when downscaling. This corresponds to
+    /// the `antialias` attribute added in ONNX opset 18.
+    pub antialias: bool,
+
+    /// Axes that the `scales` or `sizes` inputs apply to. If `None`, they
+    /// apply to all axes of the input.
+    pub axes: Option<Vec<i32>>,
+
+    /// Value for output elements which map to points outside the input, when
+    /// `coord_mode` is [CoordTransformMode::TfCropAndResize].
+    pub extrapolation_value: f32,
 }
 
 impl Default for Resize {
@@ -320,6 +819,9 @@ impl Default for Resize {
             mode: ResizeMode::Nearest,
             coord_mode: CoordTransformMode::default(),
             nearest_mode: NearestMode::default(),
+            antialias: false,
+            axes: None,
+            extrapolation_value: 0.,
         }
     }
 }
@@ -340,6 +842,26 @@ where
     Ok(tensor)
 }
 
+/// Expand `scales` or `sizes` values which apply to a subset of axes into
+/// values for every axis.
+///
+/// `all_values` contains the values to use for axes which are not in `axes`.
+fn values_for_axes<T: Copy>(
+    values: NdTensorView<T, 1>,
+    axes: &[i32],
+    mut all_values: Vec<T>,
+) -> Result<NdTensor<T, 1>, OpError> {
+    if values.len() != axes.len() {
+        return Err(OpError::IncompatibleInputShapes(
+            "scales/sizes length should equal number of axes",
+        ));
+    }
+    for (axis, &value) in zip(resolve_axes(all_values.len(), axes.iter())?, values.iter()) {
+        all_values[axis] = value;
+    }
+    Ok(NdTensor::from_vec(all_values))
+}
+
 impl Operator for Resize {
     fn name(&self) -> &str {
         "Resize"
@@ -349,8 +871,10 @@ impl Operator for Resize {
         let input = inputs.require_as(0)?;
 
         // The `roi` input is only used if the `coordinate_transformation_mode`
-        // ONNX attr is `tf_crop_and_resize`, which is not currently supported.
-        let _roi = get_optional_input::<f32>(&inputs, 1)?;
+        // ONNX attr is `tf_crop_and_resize`.
+        let roi = get_optional_input(&inputs, 1)?
+            .map(|roi| static_dims!(roi, 1))
+            .transpose()?;
 
         let scales = get_optional_input(&inputs, 2)?
             .map(|scales| static_dims!(scales, 1))
@@ -362,6 +886,36 @@ impl Operator for Resize {
             .map(ResizeTarget::Sizes);
         let target = scales.or(sizes).ok_or(OpError::MissingInputs)?;
 
+        let (all_scales, all_sizes);
+        let target = match (target, self.axes.as_deref()) {
+            (target, None) => target,
+            (ResizeTarget::Scales(scales), Some(axes)) => {
+                all_scales = values_for_axes(scales, axes, vec![1.; input.ndim()])?;
+                ResizeTarget::Scales(all_scales.view())
+            }
+            (ResizeTarget::Sizes(sizes), Some(axes)) => {
+                let in_sizes = input
+                    .shape()
+                    .iter()
+                    .map(|&size| index_to_i32(size))
+                    .collect::<Result<_, _>>()?;
+                all_sizes = values_for_axes(sizes, axes, in_sizes)?;
+                ResizeTarget::Sizes(all_sizes.view())
+            }
+        };
+
+        let all_roi: NdTensor<f32, 1>;
+        let roi = match (roi, self.axes.as_deref()) {
+            (Some(roi), Some(axes)) if roi.len() == axes.len() * 2 => {
+                let ndim = input.ndim();
+                let starts = values_for_axes(roi.slice(..axes.len()), axes, vec![0.; ndim])?;
+                let ends = values_for_axes(roi.slice(axes.len()..), axes, vec![1.; ndim])?;
+                all_roi = NdTensor::from_vec(starts.iter().chain(ends.iter()).copied().collect());
+                Some(all_roi.view())
+            }
+            (roi, _) => roi,
+        };
+
         resize(
             pool,
             input,
@@ -369,6 +923,9 @@ impl Operator for Resize {
             self.mode,
             self.coord_mode,
             self.nearest_mode,
+            self.antialias,
+            roi,
+            self.extrapolation_value,
         )
         .into_op_result()
     }
@@ -378,9 +935,11 @@ impl Operator for Resize {
 mod tests {
     use std::error::Error;
 
+    use rten_bench::run_bench;
     use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::{NdTensor, NdTensorView, Tensor};
+    use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
@@ -473,6 +1032,9 @@ mod tests {
                 ResizeMode::Nearest,
                 CoordTransformMode::HalfPixel,
                 NearestMode::RoundPreferFloor,
+                false, /* antialias */
+                None,  /* roi */
+                0.,    /* extrapolation_value */
             )
             .unwrap();
 
@@ -540,6 +1102,9 @@ mod tests {
                 ResizeMode::Nearest,
                 CoordTransformMode::Asymmetric,
                 case.mode,
+                false, /* antialias */
+                None,  /* roi */
+                0.,    /* extrapolation_value */
             )
             .unwrap();
 
@@ -662,6 +1227,9 @@ mod tests {
                 case.coord_transform_mode
                     .unwrap_or(CoordTransformMode::HalfPixel),
                 NearestMode::Floor,
+                false, /* antialias */
+                None,  /* roi */
+                0.,    /* extrapolation_value */
             )
             .unwrap();
 
@@ -671,6 +1239,224 @@ mod tests {
         Ok(())
     }
 
+    // Reference values for anti-aliased resizing can be computed with Pillow
+    // (`Image.resize` with `Image.BILINEAR`) or PyTorch
+    // (`interpolate(..., mode="bilinear", antialias=True)`).
+    #[test]
+    fn test_resize_linear_antialias() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            size: [usize; 2],
+            expected: Vec<f32>,
+        }
+
+        #[rustfmt::skip]
+        let image = Tensor::from_data(
+            &[1, 1, 4, 5],
+            vec![
+                0.1, 0.9, 0.3, 0.7, 0.2,
+                0.5, 0.4, 0.8, 0.0, 0.6,
+                0.2, 0.3, 0.9, 0.1, 0.4,
+                0.7, 0.6, 0.5, 0.8, 0.3,
+            ],
+        );
+
+        let cases = [
+            Case {
+                size: [2, 2],
+                expected: vec![0.487, 0.4195, 0.5065, 0.463],
+            },
+            Case {
+                size: [2, 3],
+                expected: vec![0.4204, 0.5365, 0.3633, 0.451, 0.581, 0.3857],
+            },
+            // Only the width is downscaled.
+            Case {
+                size: [4, 2],
+                expected: vec![0.5, 0.4591, 0.5045, 0.3909, 0.3955, 0.3864, 0.6182, 0.5636],
+            },
+            Case {
+                size: [1, 1],
+                expected: vec![0.4761],
+            },
+        ];
+
+        let pool = new_pool();
+        for case in cases {
+            let [height, width] = case.size;
+            let sizes = [1, 1, height as i32, width as i32];
+            let result = resize(
+                &pool,
+                image.view(),
+                ResizeTarget::Sizes(sizes.as_slice().into()),
+                ResizeMode::Linear,
+                CoordTransformMode::HalfPixel,
+                NearestMode::Floor,
+                true, /* antialias */
+                None, /* roi */
+                0.,   /* extrapolation_value */
+            )
+            .unwrap();
+
+            let expected = Tensor::from_data(&[1, 1, height, width], case.expected);
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        // When upscaling, anti-aliasing has no effect.
+        let sizes = [1, 1, 6, 8];
+        let resize_upscale = |antialias| {
+            resize(
+                &pool,
+                image.view(),
+                ResizeTarget::Sizes(sizes.as_slice().into()),
+                ResizeMode::Linear,
+                CoordTransformMode::HalfPixel,
+                NearestMode::Floor,
+                antialias,
+                None, /* roi */
+                0.,   /* extrapolation_value */
+            )
+            .unwrap()
+        };
+        expect_eq_1e4(&resize_upscale(true), &resize_upscale(false))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_3d() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            image: Tensor,
+            size: [usize; 3],
+            mode: ResizeMode,
+            antialias: bool,
+            expected: Vec<f32>,
+        }
+
+        let image_2x2x2 = Tensor::from_data(
+            &[1, 1, 2, 2, 2],
+            vec![0.1, 0.5, 0.3, 0.9, 0.2, 0.8, 0.6, 0.4],
+        );
+        let image_3x2x2 = Tensor::from_data(
+            &[1, 1, 3, 2, 2],
+            (0..12).map(|x| x as f32 / 10.).collect::<Vec<_>>(),
+        );
+
+        let cases = [
+            // Trilinear upscale
+            Case {
+                image: image_2x2x2.clone(),
+                size: [3, 3, 3],
+                mode: ResizeMode::Linear,
+                antialias: false,
+                expected: vec![
+                    0.1, 0.3, 0.5, 0.2, 0.45, 0.7, 0.3, 0.6, 0.9, // Z=0
+                    0.15, 0.4, 0.65, 0.3, 0.475, 0.65, 0.45, 0.55, 0.65, // Z=1
+                    0.2, 0.5, 0.8, 0.4, 0.5, 0.6, 0.6, 0.5, 0.4, // Z=2
+                ],
+            },
+            // Trilinear downscale
+            Case {
+                image: image_2x2x2.clone(),
+                size: [1, 1, 1],
+                mode: ResizeMode::Linear,
+                antialias: false,
+                expected: vec![0.475],
+            },
+            Case {
+                image: image_3x2x2.clone(),
+                size: [2, 1, 2],
+                mode: ResizeMode::Linear,
+                antialias: false,
+                expected: vec![0.2, 0.3, 0.8, 0.9],
+            },
+            // Trilinear downscale with anti-aliasing
+            Case {
+                image: image_3x2x2.clone(),
+                size: [1, 1, 2],
+                mode: ResizeMode::Linear,
+                antialias: true,
+                expected: vec![0.5, 0.6],
+            },
+            Case {
+                image: image_3x2x2.clone(),
+                size: [2, 2, 1],
+                mode: ResizeMode::Linear,
+                antialias: true,
+                expected: vec![0.2, 0.4, 0.7, 0.9],
+            },
+            // Nearest upscale
+            Case {
+                image: image_2x2x2.clone(),
+                size: [3, 3, 3],
+                mode: ResizeMode::Nearest,
+                antialias: false,
+                expected: vec![
+                    0.1, 0.1, 0.5, 0.1, 0.1, 0.5, 0.3, 0.3, 0.9, // Z=0
+                    0.1, 0.1, 0.5, 0.1, 0.1, 0.5, 0.3, 0.3, 0.9, // Z=1
+                    0.2, 0.2, 0.8, 0.2, 0.2, 0.8, 0.6, 0.6, 0.4, // Z=2
+                ],
+            },
+        ];
+
+        let pool = new_pool();
+        for case in cases {
+            let [depth, height, width] = case.size;
+            let sizes = [1, 1, depth as i32, height as i32, width as i32];
+            let result = resize(
+                &pool,
+                case.image.view(),
+                ResizeTarget::Sizes(sizes.as_slice().into()),
+                case.mode,
+                CoordTransformMode::HalfPixel,
+                NearestMode::RoundPreferFloor,
+                case.antialias,
+                None, /* roi */
+                0.,   /* extrapolation_value */
+            )
+            .unwrap();
+
+            let expected = Tensor::from_data(&[1, 1, depth, height, width], case.expected);
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_non_contiguous() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let image = Tensor::rand(&[2, 5, 3, 4], &mut rng);
+        let mut transposed = image.view();
+        transposed.permute(&[0, 1, 3, 2]);
+        assert!(!transposed.is_contiguous());
+
+        let contiguous = transposed.to_tensor();
+
+        let pool = new_pool();
+        let sizes = [2, 5, 7, 2];
+        for mode in [ResizeMode::Nearest, ResizeMode::Linear] {
+            let resize_image = |image| {
+                resize(
+                    &pool,
+                    image,
+                    ResizeTarget::Sizes(sizes.as_slice().into()),
+                    mode,
+                    CoordTransformMode::HalfPixel,
+                    NearestMode::RoundPreferFloor,
+                    false, /* antialias */
+                    None,  /* roi */
+                    0.,    /* extrapolation_value */
+                )
+                .unwrap()
+            };
+            let expected = resize_image(contiguous.view());
+            let result = resize_image(transposed.view());
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_resize_scales_sizes() {
         enum CaseOutput {
@@ -736,21 +1522,28 @@ mod tests {
                 sizes: None,
                 expected: CaseOutput::Error(OpError::InvalidValue("scales must have 1 dims")),
             },
-            // Values for scales/sizes and input shapes which are legal according to the spec,
-            // but not currently supported in our implementation.
+            // Resize non-spatial dimensions
             Case {
                 image: Tensor::from_data(&[1, 1, 2, 2], vec![0.2, 0.7, 0.3, 0.8]),
                 scales: Some(Tensor::from_vec(vec![2., 1., 3., 3.])),
                 sizes: None,
-                expected: CaseOutput::Error(OpError::UnsupportedValue(
-                    "only height and width dimensions can be resized",
-                )),
+                expected: CaseOutput::Shape(vec![2, 1, 6, 6]),
             },
+            // Resize input with fewer than 4 dims
             Case {
                 image: Tensor::from_vec(vec![1., 1.]),
-                scales: Some(Tensor::from_vec(vec![1.])),
+                scales: Some(Tensor::from_vec(vec![2.])),
                 sizes: None,
-                expected: CaseOutput::Error(OpError::InvalidValue("input must have 4 dims (NCHW)")),
+                expected: CaseOutput::Shape(vec![4]),
+            },
+            // Resize empty input to non-empty size
+            Case {
+                image: Tensor::from_data(&[1, 1, 0, 2], vec![]),
+                scales: None,
+                sizes: Some(Tensor::from_vec(vec![1, 1, 2, 2])),
+                expected: CaseOutput::Error(OpError::InvalidValue(
+                    "cannot resize an empty input to a non-empty size",
+                )),
             },
         ];
 
@@ -784,4 +1577,214 @@ mod tests {
             }
         }
     }
+
+    // Reference values for these tests are taken from the ONNX backend tests.
+    #[test]
+    fn test_resize_crop_and_pytorch_modes() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            coord_mode: CoordTransformMode,
+            roi: Option<Vec<f32>>,
+            axes: Option<Vec<i32>>,
+            sizes: Vec<i32>,
+            extrapolation_value: f32,
+            expected: Tensor,
+        }
+
+        let image = Tensor::from_data(
+            &[1, 1, 4, 4],
+            (1..=16).map(|x| x as f32).collect::<Vec<_>>(),
+        );
+
+        let cases = [
+            Case {
+                coord_mode: CoordTransformMode::TfCropAndResize,
+                roi: Some(vec![0., 0., 0.4, 0.6, 1., 1., 0.6, 0.8]),
+                axes: None,
+                sizes: vec![1, 1, 3, 3],
+                extrapolation_value: 0.,
+                expected: Tensor::from_data(
+                    &[1, 1, 3, 3],
+                    vec![7.6, 7.9, 8.2, 8.8, 9.1, 9.4, 10., 10.3, 10.6],
+                ),
+            },
+            // Crop with `axes` set. The ROI only covers the resized axes.
+            Case {
+                coord_mode: CoordTransformMode::TfCropAndResize,
+                roi: Some(vec![0.4, 0.6, 0.6, 0.8]),
+                axes: Some(vec![2, 3]),
+                sizes: vec![3, 3],
+                extrapolation_value: 0.,
+                expected: Tensor::from_data(
+                    &[1, 1, 3, 3],
+                    vec![7.6, 7.9, 8.2, 8.8, 9.1, 9.4, 10., 10.3, 10.6],
+                ),
+            },
+            // Crop with ROI extending outside the input
+            Case {
+                coord_mode: CoordTransformMode::TfCropAndResize,
+                roi: Some(vec![0., 0., 0.4, 0.6, 1., 1., 1.2, 1.7]),
+                axes: None,
+                sizes: vec![1, 1, 3, 3],
+                extrapolation_value: 10.,
+                expected: Tensor::from_data(
+                    &[1, 1, 3, 3],
+                    vec![7.6, 10., 10., 12.4, 10., 10., 10., 10., 10.],
+                ),
+            },
+            Case {
+                coord_mode: CoordTransformMode::PytorchHalfPixel,
+                roi: None,
+                axes: None,
+                sizes: vec![1, 1, 3, 1],
+                extrapolation_value: 0.,
+                expected: Tensor::from_data(&[1, 1, 3, 1], vec![1.6666666, 7., 12.333333]),
+            },
+        ];
+
+        let pool = new_pool();
+        for case in cases {
+            let op = Resize {
+                mode: ResizeMode::Linear,
+                coord_mode: case.coord_mode,
+                axes: case.axes,
+                extrapolation_value: case.extrapolation_value,
+                ..Resize::default()
+            };
+            let roi = case.roi.map(Tensor::from_vec);
+            let sizes = Tensor::from_vec(case.sizes);
+            let inputs = vec![
+                Some((&image).into()),
+                roi.as_ref().map(|roi| roi.into()),
+                None, // `scales`
+                Some((&sizes).into()),
+            ];
+            let result = op
+                .run(&pool, InputList::from_optional(inputs))?
+                .remove(0)
+                .into_tensor::<f32>()
+                .unwrap();
+            expect_eq_1e4(&result, &case.expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_non_spatial_axes() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let image = Tensor::rand(&[2, 3, 5, 7], &mut rng);
+        let pool = new_pool();
+
+        // Resizing the leading axes of a channels-last tensor should match
+        // resizing the trailing axes of the equivalent channels-first tensor.
+        for (mode, antialias, sizes) in [
+            (ResizeMode::Nearest, false, [2, 3, 9, 4]),
+            (ResizeMode::Linear, false, [2, 3, 9, 4]),
+            (ResizeMode::Linear, true, [2, 3, 9, 4]),
+            (ResizeMode::Linear, false, [3, 2, 2, 11]),
+        ] {
+            let resize_to = |input: TensorView, sizes: &[i32]| {
+                resize(
+                    &pool,
+                    input,
+                    ResizeTarget::Sizes(sizes.into()),
+                    mode,
+                    CoordTransformMode::HalfPixel,
+                    NearestMode::RoundPreferFloor,
+                    antialias,
+                    None, /* roi */
+                    0.,   /* extrapolation_value */
+                )
+            };
+            let expected = resize_to(image.view(), &sizes)?;
+
+            let mut channels_last = image.clone();
+            channels_last.permute(&[2, 3, 0, 1]);
+            let [n, c, h, w] = sizes;
+            let mut result = resize_to(channels_last.view(), &[h, w, n, c])?;
+            result.permute(&[2, 3, 0, 1]);
+
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_axes() {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 2, 2], vec![0.2, 0.7, 0.3, 0.8]);
+
+        let op = Resize {
+            axes: Some(vec![-1]),
+            ..Resize::default()
+        };
+        let scales = Tensor::from_vec(vec![2.]);
+        let inputs = vec![Some((&input).into()), None, Some((&scales).into())];
+        let result = op
+            .run(&pool, InputList::from_optional(inputs))
+            .unwrap()
+            .remove(0)
+            .into_tensor::<f32>()
+            .unwrap();
+        assert_eq!(
+            result,
+            Tensor::from_data(&[1, 2, 4], vec![0.2, 0.2, 0.7, 0.7, 0.3, 0.3, 0.8, 0.8])
+        );
+
+        let op = Resize {
+            axes: Some(vec![1]),
+            ..Resize::default()
+        };
+        let sizes = Tensor::from_vec(vec![1]);
+        let inputs = vec![Some((&input).into()), None, None, Some((&sizes).into())];
+        let result = op
+            .run(&pool, InputList::from_optional(inputs))
+            .unwrap()
+            .remove(0)
+            .into_tensor::<f32>()
+            .unwrap();
+        assert_eq!(result, Tensor::from_data(&[1, 1, 2], vec![0.2, 0.7]));
+
+        let sizes = Tensor::from_vec(vec![1, 1]);
+        let inputs = vec![Some((&input).into()), None, None, Some((&sizes).into())];
+        let result = op.run(&pool, InputList::from_optional(inputs));
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "scales/sizes length should equal number of axes"
+            ))
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_resize() {
+        let mut rng = XorShiftRng::new(1234);
+        let image = Tensor::rand(&[1, 3, 1024, 1024], &mut rng);
+        let pool = new_pool();
+
+        for (mode, antialias, sizes) in [
+            (ResizeMode::Nearest, false, [1, 3, 224, 224]),
+            (ResizeMode::Linear, false, [1, 3, 224, 224]),
+            (ResizeMode::Linear, false, [1, 3, 2048, 2048]),
+            (ResizeMode::Linear, true, [1, 3, 224, 224]),
+        ] {
+            let desc = format!("resize {:?} antialias={} {:?}", mode, antialias, sizes);
+            run_bench(20, Some(&desc), || {
+                resize(
+                    &pool,
+                    image.view(),
+                    ResizeTarget::Sizes(sizes.as_slice().into()),
+                    mode,
+                    CoordTransformMode::HalfPixel,
+                    NearestMode::RoundPreferFloor,
+                    antialias,
+                    None, /* roi */
+                    0.,   /* extrapolation_value */
+                )
+                .unwrap();
+            });
+        }
+    }
 }
@@ -0,0 +1,594 @@
+//! Operators which create and access sequences of tensors.
+//!
+//! See <https://onnx.ai/onnx/intro/concepts.html#sequence>.
+
+use rten_tensor::prelude::*;
+use rten_tensor::{DynLayout, NdTensorView, Tensor, TensorView};
+
+use crate::ops::concat::{concat, stack};
+use crate::ops::slice::select;
+use crate::ops::split::split;
+use crate::ops::{
+    resolve_axis, resolve_index, DataType, Input, InputList, IntoOpResult, OpError, Operator,
+    Output,
+};
+use crate::tensor_pool::TensorPool;
+
+/// An ordered list of tensors which have the same element type.
+///
+/// The tensors in a sequence may have different shapes. When a sequence is
+/// used as an [Input] or [Output], its shape is `[len]`.
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    dtype: DataType,
+    items: Vec<Output>,
+    layout: DynLayout,
+}
+
+impl Sequence {
+    /// Create an empty sequence of tensors with a given element type.
+    pub fn new(dtype: DataType) -> Sequence {
+        Sequence {
+            dtype,
+            items: Vec::new(),
+            layout: DynLayout::from_shape(&[0]),
+        }
+    }
+
+    /// Create a sequence from a list of tensors.
+    ///
+    /// Fails if any of the items is not a tensor with element type `dtype`.
+    pub fn from_items(dtype: DataType, items: Vec<Output>) -> Result<Sequence, OpError> {
+        let mut seq = Sequence::new(dtype);
+        for item in items {
+            seq.push(item)?;
+        }
+        Ok(seq)
+    }
+
+    /// Return the element type of tensors in this sequence.
+    pub fn dtype(&self) -> DataType {
+        self.dtype
+    }
+
+    /// Return the number of tensors in this sequence.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Return true if this sequence contains no tensors.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Return the tensor at `index`, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Output> {
+        self.items.get(index)
+    }
+
+    /// Return an iterator over the tensors in this sequence.
+    pub fn iter(&self) -> impl Iterator<Item = &Output> {
+        self.items.iter()
+    }
+
+    /// Insert a tensor at `index`, shifting all items after it.
+    ///
+    /// Fails if `item` is not a tensor with this sequence's element type or
+    /// `index` is greater than the length of the sequence.
+    pub fn insert(&mut self, index: usize, item: Output) -> Result<(), OpError> {
+        if matches!(item, Output::Sequence(_)) || item.dtype() != self.dtype {
+            return Err(OpError::IncorrectInputType);
+        }
+        if index > self.items.len() {
+            return Err(OpError::InvalidValue("Sequence position is out of bounds"));
+        }
+        self.items.insert(index, item);
+        self.layout = DynLayout::from_shape(&[self.items.len()]);
+        Ok(())
+    }
+
+    /// Append a tensor to the end of this sequence.
+    ///
+    /// See [Sequence::insert] for details of when this fails.
+    pub fn push(&mut self, item: Output) -> Result<(), OpError> {
+        self.insert(self.items.len(), item)
+    }
+
+    /// Consume this sequence and return its tensors.
+    pub fn into_items(self) -> Vec<Output> {
+        self.items
+    }
+
+    pub(crate) fn layout(&self) -> &DynLayout {
+        &self.layout
+    }
+}
+
+impl PartialEq for Sequence {
+    fn eq(&self, other: &Sequence) -> bool {
+        self.dtype == other.dtype && self.items == other.items
+    }
+}
+
+/// Read the optional position input for [SequenceInsert] and resolve it to
+/// a non-negative index.
+///
+/// Positions are in `[-len, len]`, where negative values count back from the
+/// end of the sequence and `len` appends to it.
+fn read_insert_position(
+    inputs: &InputList,
+    index: usize,
+    len: usize,
+) -> Result<Option<usize>, OpError> {
+    let Some(pos) = inputs.get_as_index(index)? else {
+        return Ok(None);
+    };
+    let pos = pos
+        .item()
+        .copied()
+        .ok_or(OpError::InvalidValue("Expected scalar value"))? as isize;
+    let pos = if pos < 0 { pos + len as isize } else { pos };
+    if pos < 0 || pos > len as isize {
+        return Err(OpError::InvalidValue("Sequence position is out of bounds"));
+    }
+    Ok(Some(pos as usize))
+}
+
+/// Return views of the tensors in a sequence with element type `T`.
+fn typed_items<'a, T>(seq: &'a Sequence) -> Result<Vec<TensorView<'a, T>>, OpError>
+where
+    TensorView<'a, T>: TryFrom<&'a Output, Error = OpError>,
+{
+    seq.iter().map(|item| item.try_into()).collect()
+}
+
+#[derive(Debug)]
+pub struct SequenceConstruct {}
+
+impl Operator for SequenceConstruct {
+    fn name(&self) -> &str {
+        "SequenceConstruct"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let first = inputs.require(0)?;
+        let mut seq = Sequence::new(first.dtype());
+        for input in inputs.iter() {
+            if matches!(input, Input::Sequence(_)) {
+                return Err(OpError::IncorrectInputType);
+            }
+            seq.push(input.to_output_in(pool))?;
+        }
+        Ok([seq.into()].into())
+    }
+}
+
+#[derive(Debug)]
+pub struct SequenceInsert {}
+
+impl Operator for SequenceInsert {
+    fn name(&self) -> &str {
+        "SequenceInsert"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let seq: &Sequence = inputs.require(0)?.try_into()?;
+        let item = inputs.require(1)?;
+        if matches!(item, Input::Sequence(_)) {
+            return Err(OpError::IncorrectInputType);
+        }
+        let pos = read_insert_position(&inputs, 2, seq.len())?.unwrap_or(seq.len());
+
+        let mut output = seq.clone();
+        output.insert(pos, item.to_output_in(pool))?;
+        Ok([output.into()].into())
+    }
+}
+
+#[derive(Debug)]
+pub struct SequenceAt {}
+
+impl Operator for SequenceAt {
+    fn name(&self) -> &str {
+        "SequenceAt"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let seq: &Sequence = inputs.require(0)?.try_into()?;
+        let pos = inputs
+            .require_as_index(1)?
+            .item()
+            .copied()
+            .ok_or(OpError::InvalidValue("Expected scalar value"))?;
+        let pos = resolve_index(seq.len(), pos as isize)
+            .ok_or(OpError::InvalidValue("Sequence position is out of bounds"))?;
+        let item = &seq.items[pos];
+        Input::from(item).to_output_in(pool).into_op_result()
+    }
+}
+
+#[derive(Debug)]
+pub struct SequenceLength {}
+
+impl Operator for SequenceLength {
+    fn name(&self) -> &str {
+        "SequenceLength"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let seq: &Sequence = inputs.require(0)?.try_into()?;
+        let len: i32 = seq
+            .len()
+            .try_into()
+            .map_err(|_| OpError::UnsupportedValue("Sequence length exceeds i32::MAX"))?;
+
+        let mut output = Tensor::zeros_in(pool, &[]);
+        output[[]] = len;
+        output.into_op_result()
+    }
+}
+
+/// Concatenate the tensors in a sequence along an existing axis, or along a
+/// new axis if `new_axis` is true.
+pub fn concat_from_sequence(
+    pool: &TensorPool,
+    seq: &Sequence,
+    axis: isize,
+    new_axis: bool,
+) -> Result<Output, OpError> {
+    if seq.is_empty() {
+        return Err(OpError::InvalidValue(
+            "ConcatFromSequence requires a non-empty sequence",
+        ));
+    }
+
+    fn concat_items<'a, T: Copy>(
+        pool: &TensorPool,
+        items: &[TensorView<'a, T>],
+        axis: isize,
+        new_axis: bool,
+    ) -> Result<Tensor<T>, OpError> {
+        if new_axis {
+            stack(pool, items, axis)
+        } else {
+            concat(pool, items, axis)
+        }
+    }
+
+    match seq.dtype() {
+        DataType::Float => {
+            concat_items(pool, &typed_items::<f32>(seq)?, axis, new_axis).map(Output::from)
+        }
+        DataType::Int32 => {
+            concat_items(pool, &typed_items::<i32>(seq)?, axis, new_axis).map(Output::from)
+        }
+        DataType::Int64 => {
+            concat_items(pool, &typed_items::<i64>(seq)?, axis, new_axis).map(Output::from)
+        }
+        _ => Err(OpError::IncorrectInputType),
+    }
+}
+
+#[derive(Debug)]
+pub struct ConcatFromSequence {
+    pub axis: isize,
+    pub new_axis: bool,
+}
+
+impl Operator for ConcatFromSequence {
+    fn name(&self) -> &str {
+        "ConcatFromSequence"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let seq: &Sequence = inputs.require(0)?.try_into()?;
+        concat_from_sequence(pool, seq, self.axis, self.new_axis).into_op_result()
+    }
+}
+
+/// Split a tensor into a sequence of tensors along `axis`.
+///
+/// If `split` is a scalar, the input is split into chunks of that size, with
+/// the last chunk being smaller if the dimension size is not evenly
+/// divisible. If `split` is a vector, it specifies the size of each chunk. If
+/// `split` is `None`, the input is split into chunks of size 1, and the split
+/// axis is removed unless `keep_dims` is true.
+pub fn split_to_sequence<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    axis: isize,
+    split_sizes: Option<TensorView<i32>>,
+    keep_dims: bool,
+) -> Result<Vec<Tensor<T>>, OpError> {
+    let axis = resolve_axis(input.ndim(), axis)?;
+    let size = input.size(axis);
+
+    let Some(split_sizes) = split_sizes else {
+        if keep_dims {
+            let sizes = vec![1; size];
+            return split(
+                pool,
+                input,
+                axis as isize,
+                &NdTensorView::from(sizes.as_slice()),
+            );
+        }
+        return Ok((0..size)
+            .map(|i| select(&input, axis, i).to_tensor_in(pool))
+            .collect());
+    };
+
+    let sizes: Vec<i32> = match split_sizes.ndim() {
+        0 => {
+            let chunk_size = *split_sizes.item().unwrap();
+            if chunk_size <= 0 {
+                return Err(OpError::InvalidValue("Split size must be > 0"));
+            }
+            let chunk_size = chunk_size as usize;
+            (0..size)
+                .step_by(chunk_size)
+                .map(|start| chunk_size.min(size - start) as i32)
+                .collect()
+        }
+        1 => split_sizes.iter().copied().collect(),
+        _ => {
+            return Err(OpError::InvalidValue(
+                "Split sizes must be a scalar or vector",
+            ))
+        }
+    };
+    split(
+        pool,
+        input,
+        axis as isize,
+        &NdTensorView::from(sizes.as_slice()),
+    )
+}
+
+#[derive(Debug)]
+pub struct SplitToSequence {
+    pub axis: isize,
+    pub keep_dims: bool,
+}
+
+impl Operator for SplitToSequence {
+    fn name(&self) -> &str {
+        "SplitToSequence"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require(0)?;
+        let split_sizes = inputs.get_as_index(1)?;
+        let split_sizes = split_sizes.as_ref().map(|s| s.view());
+
+        let dtype = input.dtype();
+        let items: Vec<Output> = match input {
+            Input::FloatTensor(t) => {
+                split_to_sequence(pool, t, self.axis, split_sizes, self.keep_dims)?
+                    .into_iter()
+                    .map(Output::from)
+                    .collect()
+            }
+            Input::IntTensor(t) => {
+                split_to_sequence(pool, t, self.axis, split_sizes, self.keep_dims)?
+                    .into_iter()
+                    .map(Output::from)
+                    .collect()
+            }
+            Input::Int64Tensor(t) => {
+                split_to_sequence(pool, t, self.axis, split_sizes, self.keep_dims)?
+                    .into_iter()
+                    .map(Output::from)
+                    .collect()
+            }
+            Input::StringTensor(_) | Input::Sequence(_) | Input::BFloat16Tensor(_) => {
+                return Err(OpError::IncorrectInputType)
+            }
+        };
+        Sequence::from_items(dtype, items)
+            .map(Output::from)
+            .into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::{tensor, Tensor};
+
+    use super::{
+        ConcatFromSequence, Sequence, SequenceAt, SequenceConstruct, SequenceInsert,
+        SequenceLength, SplitToSequence,
+    };
+    use crate::ops::tests::new_pool;
+    use crate::ops::{DataType, Input, InputList, OpError, Operator, Output};
+
+    fn float_sequence(items: &[Tensor<f32>]) -> Sequence {
+        Sequence::from_items(
+            DataType::Float,
+            items.iter().cloned().map(Output::from).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sequence() {
+        let mut seq = Sequence::new(DataType::Float);
+        assert!(seq.is_empty());
+
+        seq.push(tensor!([1., 2.]).into()).unwrap();
+        seq.insert(0, tensor!(0.).into()).unwrap();
+        assert_eq!(seq.len(), 2);
+        assert_eq!(Output::from(seq.clone()).shape(), &[2]);
+        assert_eq!(seq.get(0), Some(&Output::from(tensor!(0.))));
+
+        // Items must have the sequence's element type.
+        let result = seq.push(Tensor::from_scalar(1i32).into());
+        assert_eq!(result, Err(OpError::IncorrectInputType));
+
+        let result = seq.insert(3, tensor!(1.).into());
+        assert_eq!(
+            result,
+            Err(OpError::InvalidValue("Sequence position is out of bounds"))
+        );
+    }
+
+    #[test]
+    fn test_sequence_construct_insert_at_length() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        let a = tensor!([1., 2.]);
+        let b = tensor!([3.]);
+        let mut outputs = SequenceConstruct {}.run(&pool, (&a, &b).into())?;
+        let seq = outputs.remove(0).into_sequence().unwrap();
+        assert_eq!(seq, float_sequence(&[a.clone(), b.clone()]));
+
+        // Insert at the start, using a negative position.
+        let c = tensor!([4., 5., 6.]);
+        let pos = Tensor::from_scalar(-2i64);
+        let mut outputs = SequenceInsert {}.run(&pool, (&seq, &c, &pos).into())?;
+        let seq = outputs.remove(0).into_sequence().unwrap();
+        assert_eq!(seq, float_sequence(&[c.clone(), a.clone(), b.clone()]));
+
+        // Append when position is omitted.
+        let mut outputs = SequenceInsert {}.run(&pool, (&seq, &a).into())?;
+        let appended = outputs.remove(0).into_sequence().unwrap();
+        assert_eq!(appended.len(), 4);
+        assert_eq!(appended.get(3), Some(&Output::from(a.clone())));
+
+        let pos = Tensor::from_scalar(-1);
+        let mut outputs = SequenceAt {}.run(&pool, (&seq, &pos).into())?;
+        assert_eq!(outputs.remove(0).into_float(), Some(b.clone()));
+
+        let mut outputs = SequenceLength {}.run(&pool, (&seq).into())?;
+        assert_eq!(outputs.remove(0).into_int(), Some(Tensor::from_scalar(3)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_invalid() {
+        let pool = new_pool();
+        let a = tensor!([1., 2.]);
+        let seq = float_sequence(std::slice::from_ref(&a));
+
+        let int_input = tensor!([1, 2]);
+        let result = SequenceConstruct {}.run(&pool, (&a, &int_input).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
+
+        let result = SequenceInsert {}.run(&pool, (&seq, &int_input).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
+
+        let pos = Tensor::from_scalar(2);
+        let result = SequenceInsert {}.run(&pool, (&seq, &a, &pos).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Sequence position is out of bounds"))
+        );
+
+        let pos = Tensor::from_scalar(1);
+        let result = SequenceAt {}.run(&pool, (&seq, &pos).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Sequence position is out of bounds"))
+        );
+
+        let result = SequenceLength {}.run(&pool, (&a).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
+    }
+
+    #[test]
+    fn test_concat_from_sequence() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let seq = float_sequence(&[tensor!((1, 2); [1., 2.]), tensor!((1, 2); [3., 4.])]);
+
+        let op = ConcatFromSequence {
+            axis: 0,
+            new_axis: false,
+        };
+        let mut outputs = op.run(&pool, (&seq).into())?;
+        let result = outputs.remove(0).into_float().unwrap();
+        assert_eq!(result, tensor!((2, 2); [1., 2., 3., 4.]));
+
+        let op = ConcatFromSequence {
+            axis: -1,
+            new_axis: true,
+        };
+        let mut outputs = op.run(&pool, (&seq).into())?;
+        let result = outputs.remove(0).into_float().unwrap();
+        assert_eq!(result, tensor!((1, 2, 2); [1., 3., 2., 4.]));
+
+        let empty = Sequence::new(DataType::Float);
+        let result = op.run(&pool, (&empty).into());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "ConcatFromSequence requires a non-empty sequence"
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_to_sequence() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!((2, 3); [1., 2., 3., 4., 5., 6.]);
+
+        let split_items = |op: &SplitToSequence, split: Option<Input>| {
+            let inputs = InputList::from_optional(vec![Some((&input).into()), split]);
+            let mut outputs = op.run(&pool, inputs).unwrap();
+            let seq = outputs.remove(0).into_sequence().unwrap();
+            seq.into_items()
+                .into_iter()
+                .map(|item| item.into_float().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // No split, remove the split axis.
+        let op = SplitToSequence {
+            axis: 1,
+            keep_dims: false,
+        };
+        let items = split_items(&op, None);
+        assert_eq!(
+            items,
+            [tensor!([1., 4.]), tensor!([2., 5.]), tensor!([3., 6.])]
+        );
+
+        // No split, keep the split axis.
+        let op = SplitToSequence {
+            axis: 0,
+            keep_dims: true,
+        };
+        let items = split_items(&op, None);
+        assert_eq!(
+            items,
+            [tensor!((1, 3); [1., 2., 3.]), tensor!((1, 3); [4., 5., 6.])]
+        );
+
+        // Scalar split, with a smaller final chunk.
+        let op = SplitToSequence {
+            axis: 1,
+            keep_dims: false,
+        };
+        let split = Tensor::from_scalar(2);
+        let items = split_items(&op, Some((&split).into()));
+        assert_eq!(
+            items,
+            [tensor!((2, 2); [1., 2., 4., 5.]), tensor!((2, 1); [3., 6.])]
+        );
+
+        // Vector split
+        let split = tensor!([1, 2]);
+        let items = split_items(&op, Some((&split).into()));
+        assert_eq!(
+            items,
+            [tensor!((2, 1); [1., 4.]), tensor!((2, 2); [2., 3., 5., 6.])]
+        );
+
+        Ok(())
+    }
+}
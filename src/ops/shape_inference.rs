@@ -0,0 +1,181 @@
+//! Helpers for inferring operator output shapes from input shapes, without
+//! running the operator.
+//!
+//! See [Operator::infer_shapes](crate::ops::Operator::infer_shapes).
+
+use crate::graph::Dimension;
+use crate::ops::{OpError, SymbolicShape};
+
+/// Infer the output shape of an operator whose output has the same shape as
+/// its first input.
+pub fn infer_unary_shape(inputs: &[Option<&[Dimension]>]) -> Option<Vec<SymbolicShape>> {
+    let input = inputs.first().copied().flatten()?;
+    Some([input.to_vec()].into())
+}
+
+/// Broadcast a pair of dimensions.
+///
+/// Returns `Ok(None)` if the result cannot be determined statically, which
+/// happens if the dimensions have different symbolic sizes.
+fn broadcast_dims(a: &Dimension, b: &Dimension) -> Result<Option<Dimension>, OpError> {
+    let dim = match (a, b) {
+        (Dimension::Fixed(1), other) | (other, Dimension::Fixed(1)) => other.clone(),
+        (Dimension::Fixed(a_size), Dimension::Fixed(b_size)) => {
+            if a_size != b_size {
+                return Err(OpError::IncompatibleInputShapes("Cannot broadcast inputs"));
+            }
+            Dimension::Fixed(*a_size)
+        }
+        // A symbolic size must either match a fixed size, or be 1, in which
+        // case it is broadcast to the fixed size.
+        (Dimension::Fixed(size), Dimension::Symbolic(_))
+        | (Dimension::Symbolic(_), Dimension::Fixed(size)) => Dimension::Fixed(*size),
+        (Dimension::Symbolic(a_name), Dimension::Symbolic(b_name)) => {
+            if a_name != b_name {
+                return Ok(None);
+            }
+            a.clone()
+        }
+    };
+    Ok(Some(dim))
+}
+
+/// Broadcast two shapes which may contain symbolic dimensions.
+///
+/// This follows the same rules as
+/// [broadcast_shapes](crate::ops::binary_elementwise::broadcast_shapes), but returns an error if
+/// the shapes are known to be incompatible, and `Ok(None)` if the result
+/// cannot be determined statically.
+pub fn broadcast_symbolic_shapes(
+    a: &[Dimension],
+    b: &[Dimension],
+) -> Result<Option<SymbolicShape>, OpError> {
+    let out_ndim = a.len().max(b.len());
+    let one = Dimension::Fixed(1);
+    let mut result = Vec::with_capacity(out_ndim);
+    for i in 0..out_ndim {
+        let a_dim = (i + a.len()).checked_sub(out_ndim).map_or(&one, |i| &a[i]);
+        let b_dim = (i + b.len()).checked_sub(out_ndim).map_or(&one, |i| &b[i]);
+        let Some(dim) = broadcast_dims(a_dim, b_dim)? else {
+            return Ok(None);
+        };
+        result.push(dim);
+    }
+    Ok(Some(result))
+}
+
+/// Infer the output shape of an operator which broadcasts all of its inputs
+/// to a common shape.
+pub fn infer_broadcast_shape(
+    inputs: &[Option<&[Dimension]>],
+) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+    let mut shape = Vec::new();
+    for input in inputs {
+        let Some(input) = input else {
+            return Ok(None);
+        };
+        let Some(broadcast) = broadcast_symbolic_shapes(&shape, input)? else {
+            return Ok(None);
+        };
+        shape = broadcast;
+    }
+    Ok(Some([shape].into()))
+}
+
+/// Infer the output shape of a matrix multiplication of two inputs.
+pub fn infer_matmul_shape(
+    inputs: &[Option<&[Dimension]>],
+) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+    let (Some(Some(a)), Some(Some(b))) = (inputs.first(), inputs.get(1)) else {
+        return Ok(None);
+    };
+    if a.len() < 2 || b.len() < 2 {
+        return Err(OpError::InvalidValue("Inputs must have >= 2 dimensions"));
+    }
+
+    let [a_rows, a_cols] = [&a[a.len() - 2], &a[a.len() - 1]];
+    let [b_rows, b_cols] = [&b[b.len() - 2], &b[b.len() - 1]];
+    if let (Dimension::Fixed(a_cols), Dimension::Fixed(b_rows)) = (a_cols, b_rows) {
+        if a_cols != b_rows {
+            return Err(OpError::IncompatibleInputShapes(
+                "Columns of first matrix does not match rows of second matrix",
+            ));
+        }
+    }
+
+    let Some(mut out_shape) = broadcast_symbolic_shapes(&a[..a.len() - 2], &b[..b.len() - 2])?
+    else {
+        return Ok(None);
+    };
+    out_shape.push(a_rows.clone());
+    out_shape.push(b_cols.clone());
+    Ok(Some([out_shape].into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{broadcast_symbolic_shapes, infer_matmul_shape};
+    use crate::graph::Dimension;
+    use crate::ops::OpError;
+
+    fn fixed(size: usize) -> Dimension {
+        Dimension::Fixed(size)
+    }
+
+    fn sym(name: &str) -> Dimension {
+        Dimension::Symbolic(name.to_string())
+    }
+
+    #[test]
+    fn test_broadcast_symbolic_shapes() {
+        // Fixed shapes
+        assert_eq!(
+            broadcast_symbolic_shapes(&[fixed(2), fixed(1)], &[fixed(3)]),
+            Ok(Some(vec![fixed(2), fixed(3)]))
+        );
+        assert_eq!(
+            broadcast_symbolic_shapes(&[fixed(2)], &[fixed(3)]),
+            Err(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))
+        );
+
+        // Mixed symbolic and fixed shapes
+        assert_eq!(
+            broadcast_symbolic_shapes(&[sym("batch"), fixed(1)], &[fixed(4)]),
+            Ok(Some(vec![sym("batch"), fixed(4)]))
+        );
+        assert_eq!(
+            broadcast_symbolic_shapes(&[sym("batch"), fixed(4)], &[fixed(3), fixed(4)]),
+            Ok(Some(vec![fixed(3), fixed(4)]))
+        );
+        assert_eq!(
+            broadcast_symbolic_shapes(&[sym("batch")], &[sym("batch")]),
+            Ok(Some(vec![sym("batch")]))
+        );
+
+        // Different symbols cannot be resolved statically.
+        assert_eq!(
+            broadcast_symbolic_shapes(&[sym("batch")], &[sym("seq")]),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_infer_matmul_shape() {
+        let a = [sym("batch"), fixed(1), fixed(4), fixed(8)];
+        let b = [fixed(3), fixed(8), fixed(5)];
+        assert_eq!(
+            infer_matmul_shape(&[Some(&a), Some(&b)]),
+            Ok(Some(vec![vec![sym("batch"), fixed(3), fixed(4), fixed(5)]]))
+        );
+
+        let b = [fixed(7), fixed(5)];
+        assert_eq!(
+            infer_matmul_shape(&[Some(&a), Some(&b)]),
+            Err(OpError::IncompatibleInputShapes(
+                "Columns of first matrix does not match rows of second matrix"
+            ))
+        );
+
+        assert_eq!(infer_matmul_shape(&[Some(&a), None]), Ok(None));
+    }
+}
@@ -3,9 +3,23 @@ use std::iter::zip;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, SliceItem, SliceRange, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::static_dims;
+use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
 use crate::tensor_pool::TensorPool;
+use crate::{map_input, map_output, static_dims};
+
+/// Convert a slice start or end index to an `isize`.
+///
+/// Models often use `INT_MAX` and `-INT_MAX` to mean "to the end" or "from
+/// the start" of a dimension, and the converter clamps these from i64 to i32.
+/// Such values are widened so that they keep their meaning for dimensions
+/// with more than `i32::MAX` elements.
+fn widen_index(index: i32) -> isize {
+    match index {
+        i32::MAX => isize::MAX,
+        index if index <= -i32::MAX => isize::MIN,
+        _ => index as isize,
+    }
+}
 
 /// Compute the effective starts, ends and steps for each input dimension in
 /// a Slice operation.
@@ -41,11 +55,29 @@ fn slice_ranges(
         };
 
         let step = steps.map(|s| s[[i]]).unwrap_or(1);
-        ranges[axis] = SliceRange::new(*start as isize, Some(*end as isize), step as isize);
+        let mut start = widen_index(*start);
+        if step < 0 {
+            // When stepping backwards, ONNX clamps a start index before the
+            // first element to the first element, whereas NumPy-style slicing
+            // treats it as an empty range.
+            start = start.max(-(input_shape[axis] as isize));
+        }
+        ranges[axis] = SliceRange::new(start, Some(widen_index(*end)), step as isize);
     }
     Ok(ranges)
 }
 
+/// Return the slice of `view` at `index` along `axis`, removing the axis.
+pub(crate) fn select<'a, T>(
+    view: &TensorView<'a, T>,
+    axis: usize,
+    index: usize,
+) -> TensorView<'a, T> {
+    let mut view = view.clone();
+    view.move_axis(axis, 0);
+    view.slice_dyn([index])
+}
+
 /// Return a copy of a tensor which only retains a subset of a given dimension.
 pub fn slice<T: Copy>(
     pool: &TensorPool,
@@ -87,30 +119,27 @@ impl Operator for Slice {
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
 
-        let starts = inputs.require_as::<i32>(1)?;
+        let starts = inputs.require_as_index(1)?;
         let starts = static_dims!(starts, 1)?;
 
-        let ends = inputs.require_as::<i32>(2)?;
+        let ends = inputs.require_as_index(2)?;
         let ends = static_dims!(ends, 1)?;
 
-        let axes = inputs
-            .get_as::<i32>(3)?
+        let axes = inputs.get_as_index(3)?;
+        let axes = axes
+            .as_ref()
             .map(|axes| static_dims!(axes, 1))
             .transpose()?;
 
-        let steps = inputs
-            .get_as::<i32>(4)?
+        let steps = inputs.get_as_index(4)?;
+        let steps = steps
+            .as_ref()
             .map(|steps| static_dims!(steps, 1))
             .transpose()?;
 
-        let result: Result<Output, OpError> = match input {
-            Input::FloatTensor(input) => {
-                slice(pool, input, &starts, &ends, axes.as_ref(), steps.as_ref()).map(|t| t.into())
-            }
-            Input::IntTensor(input) => {
-                slice(pool, input, &starts, &ends, axes.as_ref(), steps.as_ref()).map(|t| t.into())
-            }
-        };
+        let result: Result<Output, OpError> = map_input!(input, input, {
+            slice(pool, input, &starts, &ends, axes.as_ref(), steps.as_ref()).map(|t| t.into())
+        });
         result.into_op_result()
     }
 
@@ -124,42 +153,38 @@ impl Operator for Slice {
         input: Output,
         other: InputList,
     ) -> Result<Output, OpError> {
-        let starts = other.require_as::<i32>(0)?;
+        let starts = other.require_as_index(0)?;
         let starts = static_dims!(starts, 1)?;
 
-        let ends = other.require_as::<i32>(1)?;
+        let ends = other.require_as_index(1)?;
         let ends = static_dims!(ends, 1)?;
 
-        let axes = other
-            .get_as::<i32>(2)?
+        let axes = other.get_as_index(2)?;
+        let axes = axes
+            .as_ref()
             .map(|axes| static_dims!(axes, 1))
             .transpose()?;
-        let steps = other
-            .get_as::<i32>(3)?
+        let steps = other.get_as_index(3)?;
+        let steps = steps
+            .as_ref()
             .map(|steps| static_dims!(steps, 1))
             .transpose()?;
 
         // Fall back to copying if non-default steps are given.
         if let Some(steps) = steps {
             if steps.iter().any(|step| *step != 1) {
-                let mut inputs: Vec<_> = vec![(&input).into()];
-                inputs.extend(other.iter());
+                let mut inputs: Vec<_> = vec![Some((&input).into())];
+                inputs.extend(other.iter_optional());
                 return self
-                    .run(pool, InputList::from(&inputs))
+                    .run(pool, InputList::from_optional(inputs))
                     .map(|mut outputs| outputs.remove(0));
             }
         }
 
-        match input {
-            Output::IntTensor(mut output) => {
-                slice_in_place(&mut output, &starts, &ends, axes.as_ref())?;
-                Ok(output.into())
-            }
-            Output::FloatTensor(mut output) => {
-                slice_in_place(&mut output, &starts, &ends, axes.as_ref())?;
-                Ok(output.into())
-            }
-        }
+        map_output!(input, mut output, {
+            slice_in_place(&mut output, &starts, &ends, axes.as_ref())?;
+            Ok(output.into())
+        })
     }
 }
 
@@ -172,8 +197,9 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::Tensor;
 
+    use super::slice_ranges;
     use crate::ops::tests::new_pool;
-    use crate::ops::{slice, slice_in_place};
+    use crate::ops::{slice, slice_in_place, InputList, Operator, Output, Slice};
 
     fn from_slice<T: Copy>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
@@ -448,6 +474,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_slice_ranges_large_dim() {
+        // Dimension size that does not fit in an i32.
+        let dim_size = 1 << 32;
+
+        for (start, end) in [(-i32::MAX, i32::MAX), (i32::MIN, i32::MAX)] {
+            let starts = &[start];
+            let ends = &[end];
+            let ranges =
+                slice_ranges(&[dim_size], &starts.into(), &ends.into(), None, None).unwrap();
+            assert_eq!(ranges[0].resolve_clamped(dim_size), 0..dim_size);
+        }
+
+        // Slice with negative step, from the end to the start.
+        let starts = &[i32::MAX];
+        let ends = &[i32::MIN];
+        let steps = &[-1];
+        let ranges = slice_ranges(
+            &[dim_size],
+            &starts.into(),
+            &ends.into(),
+            None,
+            Some(&steps.into()),
+        )
+        .unwrap();
+        assert_eq!(ranges[0].steps(dim_size), dim_size);
+    }
+
     #[test]
     fn test_slice_with_step() {
         let input = from_slice(&[1, 2, 3, 4, 5]);
@@ -493,6 +547,21 @@ mod tests {
                 expected_shape: &[5],
                 expected_elements: &[5, 4, 3, 2, 1],
             },
+            // Negative step with start before the first element
+            Case {
+                start: -100,
+                end: -100,
+                step: -1,
+                expected_shape: &[1],
+                expected_elements: &[1],
+            },
+            Case {
+                start: -100,
+                end: 0,
+                step: -1,
+                expected_shape: &[0],
+                expected_elements: &[],
+            },
         ];
 
         let pool = new_pool();
@@ -517,4 +586,69 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_slice_in_place_omitted_axes() {
+        let pool = new_pool();
+        let input = from_slice(&[1, 2, 3, 4, 5]);
+        let starts = from_slice(&[0]);
+        let ends = from_slice(&[5]);
+        let steps = from_slice(&[2]);
+
+        // Run in-place with an omitted `axes` input followed by a `steps`
+        // input. This falls back to a copying slice, which must preserve the
+        // positions of inputs.
+        let result = Slice {}
+            .run_in_place(
+                &pool,
+                Output::IntTensor(input),
+                InputList::from_optional(vec![
+                    Some(starts.view().into()),
+                    Some(ends.view().into()),
+                    None,
+                    Some(steps.view().into()),
+                ]),
+            )
+            .unwrap()
+            .into_int()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[1, 3, 5]);
+    }
+    #[test]
+    fn test_slice_int64() {
+        let pool = new_pool();
+        let input = from_slice(&[1i64, 2, 3, 4, 5]);
+
+        // ONNX exporters use `INT64_MAX` for an unbounded end.
+        let starts = from_slice(&[1i64]);
+        let ends = from_slice(&[i64::MAX]);
+        let axes = from_slice(&[0i64]);
+
+        let result = Slice {}
+            .run(
+                &pool,
+                InputList::from(&[
+                    input.view().into(),
+                    starts.view().into(),
+                    ends.view().into(),
+                    axes.view().into(),
+                ]),
+            )
+            .unwrap()
+            .remove(0)
+            .into_int64()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[2, 3, 4, 5]);
+
+        let result = Slice {}
+            .run_in_place(
+                &pool,
+                Output::Int64Tensor(input),
+                InputList::from(&[starts.view().into(), ends.view().into()]),
+            )
+            .unwrap()
+            .into_int64()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[2, 3, 4, 5]);
+    }
 }
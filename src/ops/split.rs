@@ -1,9 +1,9 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, SliceItem, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, InputList, OpError, Operator, Output};
-use crate::static_dims;
+use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
 use crate::tensor_pool::TensorPool;
+use crate::{map_input, static_dims};
 
 pub fn split<T: Copy>(
     pool: &TensorPool,
@@ -47,9 +47,35 @@ pub fn split<T: Copy>(
     Ok(outputs)
 }
 
+/// Return the sizes of the chunks produced by splitting a dimension of size
+/// `size` into `num_outputs` parts.
+///
+/// All chunks have size `ceil(size / num_outputs)`, except the last, which
+/// may be smaller if `size` is not evenly divisible.
+fn even_split_sizes(size: usize, num_outputs: usize) -> Result<Vec<i32>, OpError> {
+    if num_outputs == 0 {
+        return Err(OpError::InvalidValue("num_outputs must be > 0"));
+    }
+    let chunk_size = size.div_ceil(num_outputs);
+    let last_size = size as isize - (chunk_size * (num_outputs - 1)) as isize;
+    if last_size < 0 {
+        return Err(OpError::InvalidValue(
+            "Dimension size is too small to split into num_outputs chunks",
+        ));
+    }
+
+    let mut sizes = vec![chunk_size as i32; num_outputs - 1];
+    sizes.push(last_size as i32);
+    Ok(sizes)
+}
+
 #[derive(Debug)]
 pub struct Split {
     pub axis: isize,
+
+    /// Number of equal-sized outputs to split the input into. This is used
+    /// when split sizes are not provided as an input.
+    pub num_outputs: Option<u32>,
 }
 
 impl Operator for Split {
@@ -58,12 +84,29 @@ impl Operator for Split {
     }
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
-        let input = inputs.require_as::<f32>(0)?;
-        let splits = inputs.require_as::<i32>(1)?;
-        let splits = static_dims!(splits, 1)?;
-
-        split(pool, input, self.axis, &splits)
-            .map(|tensors| tensors.into_iter().map(|t| t.into()).collect())
+        let input = inputs.require(0)?;
+
+        let even_splits;
+        let splits = match (inputs.get_as::<i32>(1)?, self.num_outputs) {
+            (Some(splits), None) => static_dims!(splits, 1)?,
+            (None, Some(num_outputs)) => {
+                let axis = resolve_axis(input.shape().len(), self.axis)?;
+                even_splits = even_split_sizes(input.shape()[axis], num_outputs as usize)?;
+                NdTensorView::from(even_splits.as_slice())
+            }
+            (Some(_), Some(_)) => {
+                return Err(OpError::InvalidValue(
+                    "Only one of `split` input or `num_outputs` may be specified",
+                ))
+            }
+            (None, None) => {
+                return Err(OpError::MissingInputs);
+            }
+        };
+
+        map_input!(input, input, {
+            split(pool, input, self.axis, &splits).into_op_result()
+        })
     }
 }
 
@@ -73,7 +116,9 @@ mod tests {
     use rten_tensor::tensor;
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{split, OpError};
+    use crate::ops::{split, InputList, OpError, Operator, Split};
+
+    use super::even_split_sizes;
 
     #[test]
     fn test_split() {
@@ -127,4 +172,57 @@ mod tests {
             Some(OpError::InvalidValue("Split sizes must be >= 0"))
         );
     }
+
+    #[test]
+    fn test_split_num_outputs() {
+        let pool = new_pool();
+
+        let input = tensor!((2, 5); [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let op = Split {
+            axis: 1,
+            num_outputs: Some(3),
+        };
+        let results = op.run(&pool, InputList::from(&[(&input).into()])).unwrap();
+
+        assert_eq!(results.len(), 3);
+        let results: Vec<_> = results.into_iter().map(|r| r.into_int().unwrap()).collect();
+        assert_eq!(results[0], tensor!((2, 2); [0, 1, 5, 6]));
+        assert_eq!(results[1], tensor!((2, 2); [2, 3, 7, 8]));
+        assert_eq!(results[2], tensor!((2, 1); [4, 9]));
+
+        // Split sizes and `num_outputs` are mutually exclusive.
+        let splits = tensor!([2, 3]);
+        let result = op.run(&pool, InputList::from(&[(&input).into(), (&splits).into()]));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Only one of `split` input or `num_outputs` may be specified"
+            ))
+        );
+
+        // One of them must be provided.
+        let op = Split {
+            axis: 1,
+            num_outputs: None,
+        };
+        let result = op.run(&pool, InputList::from(&[(&input).into()]));
+        assert_eq!(result.err(), Some(OpError::MissingInputs));
+    }
+
+    #[test]
+    fn test_even_split_sizes() {
+        assert_eq!(even_split_sizes(6, 3), Ok(vec![2, 2, 2]));
+        assert_eq!(even_split_sizes(7, 3), Ok(vec![3, 3, 1]));
+        assert_eq!(even_split_sizes(0, 2), Ok(vec![0, 0]));
+        assert_eq!(
+            even_split_sizes(5, 4),
+            Err(OpError::InvalidValue(
+                "Dimension size is too small to split into num_outputs chunks"
+            ))
+        );
+        assert_eq!(
+            even_split_sizes(5, 0),
+            Err(OpError::InvalidValue("num_outputs must be > 0"))
+        );
+    }
 }
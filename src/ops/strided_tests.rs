@@ -0,0 +1,530 @@
+//! Tests which check that operators produce the same results for
+//! non-contiguous inputs as for contiguous inputs with the same values.
+//!
+//! Operators usually have a fast path for contiguous inputs and either copy
+//! or iterate over other inputs. The unit tests for individual operators
+//! mostly use contiguous inputs, so these tests run every operator in a
+//! table with inputs that have been sliced or transposed.
+
+use rten_tensor::prelude::*;
+use rten_tensor::rng::XorShiftRng;
+use rten_tensor::test_util::expect_equal;
+use rten_tensor::{Layout as _, SliceItem, Tensor, TensorView};
+
+use crate::op_registry::{OpAttrs, OpRegistry};
+use crate::ops::tests::new_pool;
+use crate::ops::{Input, InputList, Output};
+
+/// Storage layout used for the non-contiguous inputs of a test case.
+#[derive(Clone, Copy, Debug)]
+enum Layout {
+    /// Elements are stored with a gap between them in every dimension, as
+    /// if the input was a slice of a larger tensor.
+    Sliced,
+
+    /// The order of dimensions in storage is reversed.
+    Transposed,
+}
+
+/// Copy of a tensor which uses a non-contiguous layout.
+struct StridedTensor<T> {
+    data: Tensor<T>,
+    layout: Layout,
+}
+
+impl<T: Clone + Default> StridedTensor<T> {
+    fn new(tensor: &Tensor<T>, layout: Layout) -> Self {
+        let data = match layout {
+            Layout::Sliced => {
+                let mut shape = tensor.shape().to_vec();
+                shape.push(2);
+                let mut data = Tensor::zeros(&shape);
+                data.slice_mut_dyn(Self::slice_items(tensor.ndim()).as_slice())
+                    .copy_from(tensor);
+                data
+            }
+            Layout::Transposed => tensor.transposed().to_tensor(),
+        };
+        StridedTensor { data, layout }
+    }
+
+    fn slice_items(ndim: usize) -> Vec<SliceItem> {
+        let mut items = vec![SliceItem::full_range(); ndim];
+        items.push(SliceItem::Index(0));
+        items
+    }
+
+    fn view(&self) -> TensorView<'_, T> {
+        match self.layout {
+            Layout::Sliced => self
+                .data
+                .slice_dyn(Self::slice_items(self.data.ndim() - 1).as_slice()),
+            Layout::Transposed => self.data.transposed(),
+        }
+    }
+}
+
+enum StridedInput {
+    Float(StridedTensor<f32>),
+    Int(StridedTensor<i32>),
+}
+
+impl StridedInput {
+    fn new(value: &Output, layout: Layout) -> Self {
+        match value {
+            Output::FloatTensor(t) => StridedInput::Float(StridedTensor::new(t, layout)),
+            Output::IntTensor(t) => StridedInput::Int(StridedTensor::new(t, layout)),
+            _ => panic!("unsupported input type"),
+        }
+    }
+
+    fn as_input(&self) -> Input<'_> {
+        match self {
+            StridedInput::Float(t) => t.view().into(),
+            StridedInput::Int(t) => t.view().into(),
+        }
+    }
+}
+
+fn as_input(value: &Output) -> Input<'_> {
+    match value {
+        Output::FloatTensor(t) => t.view().into(),
+        Output::IntTensor(t) => t.view().into(),
+        _ => panic!("unsupported input type"),
+    }
+}
+
+/// Operator to run with a list of contiguous inputs.
+struct Case {
+    op_type: &'static str,
+    attrs: OpAttrs,
+    inputs: Vec<Option<Output>>,
+}
+
+impl Case {
+    fn new(op_type: &'static str, inputs: impl IntoIterator<Item = Output>) -> Case {
+        Case {
+            op_type,
+            attrs: OpAttrs::new(),
+            inputs: inputs.into_iter().map(Some).collect(),
+        }
+    }
+
+    fn with_attrs(mut self, attrs: OpAttrs) -> Case {
+        self.attrs = attrs;
+        self
+    }
+
+    fn with_optional_inputs(mut self, inputs: Vec<Option<Output>>) -> Case {
+        self.inputs = inputs;
+        self
+    }
+}
+
+fn float(rng: &mut XorShiftRng, shape: &[usize]) -> Output {
+    Tensor::<f32>::rand(shape, rng).into()
+}
+
+fn int(shape: &[usize], values: &[i32]) -> Output {
+    Tensor::from_data(shape, values.to_vec()).into()
+}
+
+fn int_vec(values: &[i32]) -> Output {
+    int(&[values.len()], values)
+}
+
+/// Generate a tensor of integers in `[0, max)`.
+fn int_rand(rng: &mut XorShiftRng, shape: &[usize], max: i32) -> Output {
+    let values = Tensor::<f32>::rand(shape, rng).map(|x| (x * max as f32) as i32);
+    values.into()
+}
+
+fn cases(rng: &mut XorShiftRng) -> Vec<Case> {
+    let shape = [2, 3, 4, 5];
+    let mut cases = Vec::new();
+
+    for op in [
+        "Abs",
+        "Ceil",
+        "Cos",
+        "Elu",
+        "Erf",
+        "Exp",
+        "Floor",
+        "Gelu",
+        "HardSigmoid",
+        "HardSwish",
+        "Identity",
+        "LeakyRelu",
+        "Mish",
+        "Neg",
+        "Relu",
+        "Round",
+        "Selu",
+        "Sigmoid",
+        "Sign",
+        "Sin",
+        "Softplus",
+        "Softsign",
+        "Sqrt",
+        "Tan",
+        "Tanh",
+    ] {
+        cases.push(Case::new(op, [float(rng, &shape)]));
+    }
+
+    for op in [
+        "Add", "Sub", "Mul", "Div", "Pow", "Max", "Min", "Equal", "Greater", "Less",
+    ] {
+        cases.push(Case::new(op, [float(rng, &shape), float(rng, &shape)]));
+        cases.push(Case::new(op, [float(rng, &shape), float(rng, &[4, 5])]));
+    }
+    cases.push(Case::new(
+        "Sum",
+        [float(rng, &shape), float(rng, &shape), float(rng, &[5])],
+    ));
+    cases.push(Case::new(
+        "Where",
+        [
+            int_rand(rng, &shape, 2),
+            float(rng, &shape),
+            float(rng, &[3, 1, 5]),
+        ],
+    ));
+    cases.push(Case::new(
+        "Clip",
+        [
+            float(rng, &shape),
+            Tensor::from_scalar(0.25f32).into(),
+            Tensor::from_scalar(0.75f32).into(),
+        ],
+    ));
+
+    for op in [
+        "ReduceL2",
+        "ReduceLogSumExp",
+        "ReduceMax",
+        "ReduceMean",
+        "ReduceMin",
+        "ReduceProd",
+        "ReduceSum",
+        "ReduceSumSquare",
+    ] {
+        cases.push(Case::new(op, [float(rng, &shape)]));
+        cases.push(Case::new(op, [float(rng, &shape), int_vec(&[1, 3])]));
+    }
+    for op in [
+        "ArgMax",
+        "ArgMin",
+        "Softmax",
+        "LogSoftmax",
+        "LpNormalization",
+    ] {
+        for axis in [-1, 1] {
+            cases.push(
+                Case::new(op, [float(rng, &shape)]).with_attrs(OpAttrs::new().with("axis", axis)),
+            );
+        }
+    }
+    cases.push(Case::new("CumSum", [float(rng, &shape), int(&[], &[2])]));
+    cases.push(Case::new("TopK", [float(rng, &shape), int_vec(&[2])]));
+    cases.push(Case::new("MeanVarianceNormalization", [float(rng, &shape)]));
+    cases.push(Case::new(
+        "LayerNormalization",
+        [float(rng, &shape), float(rng, &[5]), float(rng, &[5])],
+    ));
+    cases.push(Case::new(
+        "InstanceNormalization",
+        [float(rng, &shape), float(rng, &[3]), float(rng, &[3])],
+    ));
+    cases.push(Case::new(
+        "BatchNormalization",
+        [
+            float(rng, &shape),
+            float(rng, &[3]),
+            float(rng, &[3]),
+            float(rng, &[3]),
+            float(rng, &[3]),
+        ],
+    ));
+
+    cases.push(Case::new(
+        "MatMul",
+        [float(rng, &[2, 3, 4]), float(rng, &[4, 6])],
+    ));
+    cases.push(Case::new(
+        "MatMul",
+        [float(rng, &[2, 3, 4]), float(rng, &[2, 4, 6])],
+    ));
+    cases.push(Case::new(
+        "Gemm",
+        [float(rng, &[3, 4]), float(rng, &[4, 6]), float(rng, &[6])],
+    ));
+    cases.push(
+        Case::new(
+            "Gemm",
+            [
+                float(rng, &[4, 3]),
+                float(rng, &[6, 4]),
+                float(rng, &[3, 6]),
+            ],
+        )
+        .with_attrs(
+            OpAttrs::new()
+                .with("transpose_a", true)
+                .with("transpose_b", true),
+        ),
+    );
+
+    cases.push(Case::new(
+        "Conv",
+        [
+            float(rng, &[1, 3, 6, 7]),
+            float(rng, &[4, 3, 3, 3]),
+            float(rng, &[4]),
+        ],
+    ));
+    cases.push(
+        Case::new(
+            "Conv",
+            [
+                float(rng, &[1, 4, 6, 7]),
+                float(rng, &[4, 1, 3, 3]),
+                float(rng, &[4]),
+            ],
+        )
+        .with_attrs(
+            OpAttrs::new()
+                .with("groups", 4)
+                .with("pads", [1usize, 1, 1, 1]),
+        ),
+    );
+    cases.push(Case::new(
+        "Conv",
+        [float(rng, &[1, 3, 6, 7]), float(rng, &[4, 3, 1, 1])],
+    ));
+    cases.push(
+        Case::new("Conv", [float(rng, &[1, 3, 7]), float(rng, &[4, 3, 3])]).with_attrs(
+            OpAttrs::new()
+                .with("pads", [0usize; 2])
+                .with("strides", [1usize])
+                .with("dilations", [1usize]),
+        ),
+    );
+    cases.push(
+        Case::new(
+            "Conv",
+            [float(rng, &[1, 3, 4, 5, 6]), float(rng, &[4, 3, 2, 2, 2])],
+        )
+        .with_attrs(
+            OpAttrs::new()
+                .with("pads", [0usize; 6])
+                .with("strides", [1usize; 3])
+                .with("dilations", [1usize; 3]),
+        ),
+    );
+    cases.push(Case::new(
+        "ConvTranspose",
+        [
+            float(rng, &[1, 3, 4, 5]),
+            float(rng, &[3, 2, 3, 3]),
+            float(rng, &[2]),
+        ],
+    ));
+    cases.push(
+        Case::new(
+            "ConvTranspose",
+            [float(rng, &[1, 3, 5]), float(rng, &[3, 2, 3])],
+        )
+        .with_attrs(
+            OpAttrs::new()
+                .with("pads", [0usize; 2])
+                .with("strides", [2usize]),
+        ),
+    );
+    for op in ["MaxPool", "AveragePool"] {
+        cases.push(
+            Case::new(op, [float(rng, &[1, 3, 6, 7])])
+                .with_attrs(OpAttrs::new().with("kernel_size", [2usize, 2])),
+        );
+    }
+    for op in ["GlobalAveragePool", "GlobalMaxPool"] {
+        cases.push(Case::new(op, [float(rng, &[1, 3, 6, 7])]));
+    }
+    for mode in ["nearest", "linear"] {
+        cases.push(
+            Case::new("Resize", [])
+                .with_attrs(OpAttrs::new().with("mode", mode))
+                .with_optional_inputs(vec![
+                    Some(float(rng, &[1, 3, 4, 5])),
+                    None,
+                    Some(Tensor::from([1., 1., 2., 1.5f32]).into()),
+                ]),
+        );
+    }
+    cases.push(Case::new(
+        "GridSample",
+        [float(rng, &[1, 3, 4, 5]), float(rng, &[1, 2, 3, 2])],
+    ));
+
+    for (op, gates) in [("GRU", 3), ("LSTM", 4)] {
+        let hidden_size = 4;
+        cases.push(
+            Case::new(
+                op,
+                [
+                    float(rng, &[5, 2, 3]),
+                    float(rng, &[1, gates * hidden_size, 3]),
+                    float(rng, &[1, gates * hidden_size, hidden_size]),
+                    float(rng, &[1, 2 * gates * hidden_size]),
+                ],
+            )
+            .with_attrs(
+                OpAttrs::new()
+                    .with("hidden_size", hidden_size)
+                    .with("linear_before_reset", true),
+            ),
+        );
+    }
+
+    cases.push(
+        Case::new("Transpose", [float(rng, &shape)])
+            .with_attrs(OpAttrs::new().with("perm", [0usize, 2, 3, 1])),
+    );
+    cases.push(
+        Case::new("Concat", [float(rng, &shape), float(rng, &[2, 1, 4, 5])])
+            .with_attrs(OpAttrs::new().with("axis", 1)),
+    );
+    cases.push(
+        Case::new("Split", [float(rng, &shape), int_vec(&[1, 2])])
+            .with_attrs(OpAttrs::new().with("axis", 1)),
+    );
+    cases.push(Case::new(
+        "Slice",
+        [
+            float(rng, &shape),
+            int_vec(&[1, 0]),
+            int_vec(&[3, 4]),
+            int_vec(&[1, 3]),
+            int_vec(&[1, 2]),
+        ],
+    ));
+    cases.push(
+        Case::new("Gather", [float(rng, &shape), int(&[2, 2], &[0, 2, 1, 1])])
+            .with_attrs(OpAttrs::new().with("axis", 1)),
+    );
+    cases.push(
+        Case::new(
+            "GatherElements",
+            [float(rng, &shape), int_rand(rng, &[2, 3, 4, 2], 5)],
+        )
+        .with_attrs(OpAttrs::new().with("axis", -1)),
+    );
+    cases.push(
+        Case::new(
+            "ScatterElements",
+            [
+                float(rng, &shape),
+                int(&[2, 3, 4, 1], &[1; 24]),
+                float(rng, &[2, 3, 4, 1]),
+            ],
+        )
+        .with_attrs(OpAttrs::new().with("axis", -1)),
+    );
+    cases.push(Case::new(
+        "Pad",
+        [float(rng, &shape), int_vec(&[0, 1, 0, 2, 0, 0, 1, 1])],
+    ));
+    cases.push(Case::new(
+        "Tile",
+        [float(rng, &shape), int_vec(&[1, 2, 1, 2])],
+    ));
+    cases.push(Case::new(
+        "Expand",
+        [float(rng, &[3, 1, 5]), int_vec(&[2, 3, 4, 5])],
+    ));
+    cases.push(Case::new(
+        "Reshape",
+        [float(rng, &shape), int_vec(&[6, -1])],
+    ));
+    cases.push(Case::new("Flatten", [float(rng, &shape)]));
+    cases.push(Case::new(
+        "Squeeze",
+        [float(rng, &[2, 1, 4, 5]), int_vec(&[1])],
+    ));
+    cases.push(Case::new(
+        "Unsqueeze",
+        [float(rng, &shape), int_vec(&[0, 3])],
+    ));
+    cases.push(Case::new("Trilu", [float(rng, &shape), int(&[], &[1])]));
+    cases.push(
+        Case::new("Cast", [float(rng, &shape)]).with_attrs(OpAttrs::new().with("to", "int32")),
+    );
+    cases.push(Case::new("NonZero", [int_rand(rng, &shape, 2)]));
+
+    cases
+}
+
+fn expect_outputs_equal(actual: &Output, expected: &Output) -> Result<(), String> {
+    match (actual, expected) {
+        (Output::FloatTensor(actual), Output::FloatTensor(expected)) => {
+            expect_equal(actual, expected).map_err(|err| err.to_string())
+        }
+        _ if actual == expected => Ok(()),
+        _ => Err(format!("expected {:?}, got {:?}", expected, actual)),
+    }
+}
+
+#[test]
+fn test_ops_with_non_contiguous_inputs() {
+    let pool = new_pool();
+    let registry = OpRegistry::with_all_ops();
+    let mut rng = XorShiftRng::new(1234);
+    let mut failures = Vec::new();
+
+    for case in cases(&mut rng) {
+        let op = registry
+            .read_op(case.op_type, &case.attrs)
+            .unwrap_or_else(|err| panic!("failed to create {}: {:?}", case.op_type, err));
+
+        let inputs = InputList::from_optional(
+            case.inputs
+                .iter()
+                .map(|input| input.as_ref().map(as_input))
+                .collect(),
+        );
+        let expected = op
+            .run(&pool, inputs)
+            .unwrap_or_else(|err| panic!("{} failed: {:?}", case.op_type, err));
+
+        for layout in [Layout::Sliced, Layout::Transposed] {
+            let strided: Vec<_> = case
+                .inputs
+                .iter()
+                .map(|input| input.as_ref().map(|x| StridedInput::new(x, layout)))
+                .collect();
+            let inputs = InputList::from_optional(
+                strided
+                    .iter()
+                    .map(|input| input.as_ref().map(|x| x.as_input()))
+                    .collect(),
+            );
+
+            let result = match op.run(&pool, inputs) {
+                Ok(result) => result,
+                Err(err) => {
+                    failures.push(format!("{} ({:?}): {:?}", case.op_type, layout, err));
+                    continue;
+                }
+            };
+            assert_eq!(result.len(), expected.len());
+            for (actual, expected) in result.iter().zip(&expected) {
+                if let Err(err) = expect_outputs_equal(actual, expected) {
+                    failures.push(format!("{} ({:?}): {}", case.op_type, layout, err));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "failures:\n{}", failures.join("\n"));
+}
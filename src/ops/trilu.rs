@@ -1,7 +1,8 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::map_input;
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
 use crate::tensor_pool::TensorPool;
 
 pub fn trilu<T: Copy + Default>(
@@ -47,10 +48,9 @@ impl Operator for Trilu {
         let input = inputs.require(0)?;
         let k = inputs.get_as_scalar(1)?.unwrap_or(0);
 
-        match input {
-            Input::FloatTensor(input) => trilu(pool, input, k, self.upper).into_op_result(),
-            Input::IntTensor(input) => trilu(pool, input, k, self.upper).into_op_result(),
-        }
+        map_input!(input, input, {
+            trilu(pool, input, k, self.upper).into_op_result()
+        })
     }
 }
 
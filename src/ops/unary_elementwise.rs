@@ -6,13 +6,17 @@ use std::mem::MaybeUninit;
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 use rten_vecmath::{
-    erf as erf_scalar, exp as exp_scalar, sigmoid as sigmoid_scalar, tanh as tanh_scalar, vec_erf,
-    vec_erf_in_place, vec_exp, vec_exp_in_place, vec_sigmoid, vec_sigmoid_in_place, vec_tanh,
-    vec_tanh_in_place,
+    erf as erf_scalar, exp as exp_scalar, gelu as gelu_scalar, gelu_tanh as gelu_tanh_scalar,
+    log as log_scalar, sigmoid as sigmoid_scalar, softplus as softplus_scalar, tanh as tanh_scalar,
+    vec_erf, vec_erf_in_place, vec_exp, vec_exp_in_place, vec_gelu, vec_gelu_in_place,
+    vec_gelu_tanh, vec_gelu_tanh_in_place, vec_log, vec_log_in_place, vec_sigmoid,
+    vec_sigmoid_in_place, vec_softplus, vec_softplus_in_place, vec_tanh, vec_tanh_in_place,
 };
 
+use crate::graph::Dimension;
 use crate::number::AsBool;
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::shape_inference::infer_unary_shape;
+use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, SymbolicShape};
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
 /// Trait for operators which take a single float tensor and apply a function
@@ -48,6 +52,13 @@ impl<Op: UnaryFloatOp + Debug> Operator for Op {
         true
     }
 
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
     fn run_in_place(
         &self,
         _pool: &TensorPool,
@@ -80,6 +91,8 @@ macro_rules! unary_numeric_op {
                 match input {
                     Input::FloatTensor(input) => $view_impl(pool, input).into_op_result(),
                     Input::IntTensor(input) => $view_impl(pool, input).into_op_result(),
+                    Input::Int64Tensor(input) => $view_impl(pool, input).into_op_result(),
+                    _ => Err(OpError::IncorrectInputType),
                 }
             }
 
@@ -87,6 +100,13 @@ macro_rules! unary_numeric_op {
                 true
             }
 
+            fn infer_shapes(
+                &self,
+                inputs: &[Option<&[Dimension]>],
+            ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+                Ok(infer_unary_shape(inputs))
+            }
+
             fn run_in_place(
                 &self,
                 _pool: &TensorPool,
@@ -102,6 +122,11 @@ macro_rules! unary_numeric_op {
                         $mut_impl(input.view_mut());
                         Ok(input.into())
                     }
+                    Output::Int64Tensor(mut input) => {
+                        $mut_impl(input.view_mut());
+                        Ok(input.into())
+                    }
+                    _ => Err(OpError::IncorrectInputType),
                 }
             }
         }
@@ -200,6 +225,13 @@ macro_rules! parallel_unary_float_op {
                 true
             }
 
+            fn infer_shapes(
+                &self,
+                inputs: &[Option<&[Dimension]>],
+            ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+                Ok(infer_unary_shape(inputs))
+            }
+
             fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
                 $func_name(pool, inputs.require_as(0)?).into_op_result()
             }
@@ -242,6 +274,12 @@ impl AbsValue for i32 {
     }
 }
 
+impl AbsValue for i64 {
+    fn abs(&self) -> i64 {
+        (*self).abs()
+    }
+}
+
 pub fn abs<T: AbsValue>(pool: &TensorPool, input: TensorView<T>) -> Tensor<T> {
     input.map_in(pool, |x| x.abs())
 }
@@ -256,6 +294,30 @@ unary_float_op!(Asin, asin, asin_in_place, |val: f32| val.asin());
 unary_float_op!(Atan, atan, atan_in_place, |val: f32| val.atan());
 unary_float_op!(Ceil, ceil, ceil_in_place, |val: f32| val.ceil());
 
+#[derive(Debug)]
+pub struct Celu {
+    pub alpha: f32,
+}
+
+impl UnaryFloatOp for Celu {
+    fn name(&self) -> &str {
+        "Celu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        // See https://onnx.ai/onnx/operators/onnx__Celu.html.
+        val.max(0.) + (self.alpha * ((val / self.alpha).exp() - 1.)).min(0.)
+    }
+}
+
+pub fn celu(pool: &TensorPool, input: TensorView, alpha: f32) -> Tensor {
+    Celu { alpha }.map(pool, input)
+}
+
+pub fn celu_in_place(input: TensorViewMut, alpha: f32) {
+    Celu { alpha }.apply(input)
+}
+
 /// Numeric value with a finite minimum and maximum and operations to clamp
 /// values.
 pub trait Clamp: Copy + PartialOrd {
@@ -299,6 +361,16 @@ impl Clamp for i32 {
     }
 }
 
+impl Clamp for i64 {
+    fn min_val() -> Self {
+        i64::MIN
+    }
+
+    fn max_val() -> Self {
+        i64::MAX
+    }
+}
+
 impl Clamp for f32 {
     fn min_val() -> Self {
         f32::MIN
@@ -350,6 +422,12 @@ impl Operator for Clip {
                 let max = inputs.get_as_scalar(2)?;
                 clip(pool, input, min, max).into_op_result()
             }
+            Input::Int64Tensor(input) => {
+                let min = inputs.get_as_scalar(1)?;
+                let max = inputs.get_as_scalar(2)?;
+                clip(pool, input, min, max).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -357,6 +435,13 @@ impl Operator for Clip {
         true
     }
 
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
     fn run_in_place(
         &self,
         _pool: &TensorPool,
@@ -376,6 +461,13 @@ impl Operator for Clip {
                 clip_in_place(&mut input, min, max);
                 Ok(input.into())
             }
+            Output::Int64Tensor(mut input) => {
+                let min = other.get_as_scalar(0)?;
+                let max = other.get_as_scalar(1)?;
+                clip_in_place(&mut input, min, max);
+                Ok(input.into())
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 }
@@ -435,6 +527,64 @@ parallel_unary_float_op!(
 );
 unary_float_op!(Floor, floor, floor_in_place, |val: f32| val.floor());
 
+/// Gaussian Error Linear Unit.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Gelu.html>.
+#[derive(Debug)]
+pub struct Gelu {
+    /// Use the faster but less accurate tanh approximation instead of the
+    /// exact erf-based formulation.
+    pub approximate: bool,
+}
+
+impl Operator for Gelu {
+    fn name(&self) -> &str {
+        "Gelu"
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        gelu(pool, inputs.require_as(0)?, self.approximate).into_op_result()
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _: InputList,
+    ) -> Result<Output, OpError> {
+        let mut tensor = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        gelu_in_place(tensor.view_mut(), self.approximate);
+        Ok(tensor.into())
+    }
+}
+
+pub fn gelu(pool: &TensorPool, input: TensorView, approximate: bool) -> Tensor {
+    if approximate {
+        par_unary_op(pool, input, vec_gelu_tanh)
+    } else {
+        par_unary_op(pool, input, vec_gelu)
+    }
+}
+
+pub fn gelu_in_place(input: TensorViewMut, approximate: bool) {
+    if approximate {
+        par_unary_op_in_place(input, vec_gelu_tanh_in_place, gelu_tanh_scalar);
+    } else {
+        par_unary_op_in_place(input, vec_gelu_in_place, gelu_scalar);
+    }
+}
+
 #[derive(Debug)]
 pub struct HardSigmoid {
     pub alpha: f32,
@@ -503,7 +653,17 @@ impl UnaryFloatOp for LeakyRelu {
     }
 }
 
-unary_float_op!(Log, log, log_in_place, |val: f32| val.ln());
+parallel_unary_float_op!(
+    Log,
+    log,
+    log_in_place,
+    vec_log,
+    vec_log_in_place,
+    log_scalar
+);
+
+unary_float_op!(Mish, mish, mish_in_place, |val: f32| val
+    * tanh_scalar(softplus_scalar(val)));
 
 pub fn neg<T: Copy + std::ops::Neg<Output = T>>(
     pool: &TensorPool,
@@ -543,6 +703,13 @@ impl Operator for Not {
         true
     }
 
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
     fn run_in_place(
         &self,
         _pool: &TensorPool,
@@ -555,6 +722,50 @@ impl Operator for Not {
     }
 }
 
+pub fn bitwise_not(pool: &TensorPool, input: TensorView<i32>) -> Tensor<i32> {
+    input.map_in(pool, |x| !x)
+}
+
+pub fn bitwise_not_in_place(mut input: TensorViewMut<i32>) {
+    input.apply(|x| !x);
+}
+
+#[derive(Debug)]
+pub struct BitwiseNot {}
+
+impl Operator for BitwiseNot {
+    fn name(&self) -> &str {
+        "BitwiseNot"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as::<i32>(0)?;
+        bitwise_not(pool, input).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn infer_shapes(
+        &self,
+        inputs: &[Option<&[Dimension]>],
+    ) -> Result<Option<Vec<SymbolicShape>>, OpError> {
+        Ok(infer_unary_shape(inputs))
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _: InputList,
+    ) -> Result<Output, OpError> {
+        let mut output = input.into_int().ok_or(OpError::IncorrectInputType)?;
+        bitwise_not_in_place(output.view_mut());
+        Ok(output.into())
+    }
+}
+
 unary_float_op!(Reciprocal, reciprocal, reciprocal_in_place, |val: f32| 1.
     / val);
 unary_float_op!(Relu, relu, relu_in_place, |val: f32| val.max(0.));
@@ -583,6 +794,37 @@ pub fn round_in_place(x: TensorViewMut) {
     Round {}.apply(x)
 }
 
+/// Scaled exponential linear unit.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Selu.html>.
+#[derive(Debug)]
+pub struct Selu {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+
+impl UnaryFloatOp for Selu {
+    fn name(&self) -> &str {
+        "Selu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if val > 0. {
+            self.gamma * val
+        } else {
+            self.gamma * self.alpha * (val.exp() - 1.)
+        }
+    }
+}
+
+pub fn selu(pool: &TensorPool, input: TensorView, alpha: f32, gamma: f32) -> Tensor {
+    Selu { alpha, gamma }.map(pool, input)
+}
+
+pub fn selu_in_place(input: TensorViewMut, alpha: f32, gamma: f32) {
+    Selu { alpha, gamma }.apply(input)
+}
+
 parallel_unary_float_op!(
     Sigmoid,
     sigmoid,
@@ -602,17 +844,29 @@ pub trait Signum: Copy {
     fn signum(self) -> Self;
 }
 
-macro_rules! impl_signum {
-    ($type:ident) => {
-        impl Signum for $type {
-            fn signum(self) -> Self {
-                $type::signum(self)
-            }
+impl Signum for i32 {
+    fn signum(self) -> Self {
+        i32::signum(self)
+    }
+}
+
+impl Signum for i64 {
+    fn signum(self) -> Self {
+        i64::signum(self)
+    }
+}
+
+impl Signum for f32 {
+    fn signum(self) -> Self {
+        // `f32::signum` returns 1 for +0.0 and -1 for -0.0, whereas the ONNX
+        // `Sign` operator returns 0 for both. NaN inputs return NaN.
+        if self == 0. {
+            0.
+        } else {
+            f32::signum(self)
         }
-    };
+    }
 }
-impl_signum!(i32);
-impl_signum!(f32);
 
 pub fn sign<T: Signum>(pool: &TensorPool, input: TensorView<T>) -> Tensor<T> {
     input.map_in(pool, |x| x.signum())
@@ -624,9 +878,16 @@ pub fn sign_in_place<T: Signum>(mut input: TensorViewMut<T>) {
 
 unary_numeric_op!(Sign, sign, sign_in_place);
 unary_float_op!(Sqrt, sqrt, sqrt_in_place, |val: f32| val.sqrt());
-unary_float_op!(Softplus, softplus, softplus_in_place, |val: f32| {
-    val.exp().ln_1p()
-});
+parallel_unary_float_op!(
+    Softplus,
+    softplus,
+    softplus_in_place,
+    vec_softplus,
+    vec_softplus_in_place,
+    softplus_scalar
+);
+unary_float_op!(Softsign, softsign, softsign_in_place, |val: f32| val
+    / (1. + val.abs()));
 unary_float_op!(Tan, tan, tan_in_place, |val: f32| val.tan());
 parallel_unary_float_op!(
     Tanh,
@@ -637,6 +898,33 @@ parallel_unary_float_op!(
     tanh_scalar
 );
 
+#[derive(Debug)]
+pub struct ThresholdedRelu {
+    pub alpha: f32,
+}
+
+impl UnaryFloatOp for ThresholdedRelu {
+    fn name(&self) -> &str {
+        "ThresholdedRelu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if val > self.alpha {
+            val
+        } else {
+            0.
+        }
+    }
+}
+
+pub fn thresholded_relu(pool: &TensorPool, input: TensorView, alpha: f32) -> Tensor {
+    ThresholdedRelu { alpha }.map(pool, input)
+}
+
+pub fn thresholded_relu_in_place(input: TensorViewMut, alpha: f32) {
+    ThresholdedRelu { alpha }.apply(input)
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -648,12 +936,15 @@ mod tests {
 
     use crate::ops::tests::new_pool;
     use crate::ops::{
-        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil, clip,
-        clip_in_place, cos, cos_in_place, elu, elu_in_place, erf, erf_in_place, exp, exp_in_place,
-        floor, hard_sigmoid, hard_swish, leaky_relu, leaky_relu_in_place, log, log_in_place, neg,
-        neg_in_place, not, not_in_place, reciprocal, relu, relu_in_place, round, round_in_place,
-        sigmoid, sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, softplus,
-        softplus_in_place, sqrt, sqrt_in_place, tan, tan_in_place, tanh, tanh_in_place,
+        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, bitwise_not,
+        bitwise_not_in_place, ceil, celu, celu_in_place, clip, clip_in_place, cos, cos_in_place,
+        elu, elu_in_place, erf, erf_in_place, exp, exp_in_place, floor, gelu, gelu_in_place,
+        hard_sigmoid, hard_swish, leaky_relu, leaky_relu_in_place, log, log_in_place, mish,
+        mish_in_place, neg, neg_in_place, not, not_in_place, reciprocal, relu, relu_in_place,
+        round, round_in_place, selu, selu_in_place, sigmoid, sigmoid_in_place, sign, sign_in_place,
+        sin, sin_in_place, softplus, softplus_in_place, softsign, softsign_in_place, sqrt,
+        sqrt_in_place, tan, tan_in_place, tanh, tanh_in_place, thresholded_relu,
+        thresholded_relu_in_place, Clip, InputList, Operator, Output,
     };
 
     /// Define a test for a simple unary operator which applies the function
@@ -760,6 +1051,30 @@ mod tests {
         assert!(eq_with_nans(result.view(), expected.view()));
     }
 
+    #[test]
+    fn test_celu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        for alpha in [1.0, 0.5] {
+            let input = tensor!([-5., -2., -1., -0.5, 0., 0.5, 1., 2., 5.]);
+            let expected = input.map(|&x: &f32| {
+                if x >= 0. {
+                    x
+                } else {
+                    alpha * ((x / alpha).exp() - 1.)
+                }
+            });
+
+            let actual = celu(&pool, input.view(), alpha);
+            expect_equal(&actual, &expected)?;
+
+            let mut input = input.clone();
+            celu_in_place(input.view_mut(), alpha);
+            expect_equal(&input, &expected)?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_clip() -> Result<(), Box<dyn Error>> {
         struct Case {
@@ -803,6 +1118,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_clip_op_omitted_min() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!((2, 2); [-5., -2., 3., 20.]);
+        let max = tensor!(5.);
+        let expected = tensor!((2, 2); [-5., -2., 3., 5.]);
+        let op = Clip {};
+
+        // Explicitly omitted `min` input, followed by `max` input.
+        let result = op
+            .run(
+                &pool,
+                InputList::from_optional(vec![
+                    Some(input.view().into()),
+                    None,
+                    Some(max.view().into()),
+                ]),
+            )?
+            .remove(0)
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &expected)?;
+
+        let result = op
+            .run_in_place(
+                &pool,
+                Output::FloatTensor(input.clone()),
+                InputList::from_optional(vec![None, Some(max.view().into())]),
+            )?
+            .into_float()
+            .unwrap();
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
     // TODO: Eliminate the duplication for tests that apply the operator
     // in-place vs returning a new tensor.
 
@@ -940,6 +1291,36 @@ mod tests {
         assert!(eq_with_nans(result.view(), expected.view()));
     }
 
+    #[test]
+    fn test_gelu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!([-2.0, -0.5, 0., 0.5, 2.0]);
+
+        // Exact erf-based GELU.
+        let expected = tensor!([-0.045500264, -0.15426877, 0., 0.34573123, 1.9544997]);
+        let result = gelu(&pool, input.view(), false);
+        expect_equal_with_tolerance(&result, &expected, 1e-6, 0.)?;
+
+        let mut in_place = input.clone();
+        gelu_in_place(in_place.view_mut(), false);
+        expect_equal_with_tolerance(&in_place, &expected, 1e-6, 0.)?;
+
+        // Tanh approximation.
+        let expected = tensor!([-0.045402307, -0.154286, 0., 0.345714, 1.9545977]);
+        let result = gelu(&pool, input.view(), true);
+        expect_equal_with_tolerance(&result, &expected, 1e-6, 0.)?;
+
+        // Non-contiguous input, which uses the scalar fallback when run
+        // in-place.
+        let mut input = tensor!((2, 2); [-2.0, 0.5, -0.5, 2.0]);
+        input.transpose();
+        let expected = input.map(|x| gelu(&pool, tensor!([*x]).view(), true)[[0]]);
+        gelu_in_place(input.view_mut(), true);
+        expect_equal_with_tolerance(&input, &expected, 1e-6, 0.)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_hard_sigmoid() -> Result<(), Box<dyn Error>> {
         let input = tensor!([-4., -3., -1., 0., 1., 3., 4.]);
@@ -1012,6 +1393,11 @@ mod tests {
         Ok(())
     }
 
+    test_unary_op!(test_mish, mish, mish_in_place, |x: &f32| x * x
+        .exp()
+        .ln_1p()
+        .tanh());
+
     #[test]
     fn test_neg() {
         let pool = new_pool();
@@ -1029,6 +1415,19 @@ mod tests {
         assert_eq!(input, expected);
     }
 
+    #[test]
+    fn test_bitwise_not() {
+        let pool = new_pool();
+        let input = tensor!([0, 1, -1, 0x0f0f]);
+        let expected = tensor!([-1, -2, 0, !0x0f0f]);
+        let result = bitwise_not(&pool, input.view());
+        assert_eq!(result, expected);
+
+        let mut input = input.clone();
+        bitwise_not_in_place(input.view_mut());
+        assert_eq!(input, expected);
+    }
+
     #[test]
     fn test_not() {
         let pool = new_pool();
@@ -1092,6 +1491,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_selu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let alpha = 1.673_263_2;
+        let gamma = 1.050_701;
+        let input = tensor!([-5., -1., -0.5, 0., 0.5, 1., 5.]);
+        let expected = input.map(|&x: &f32| {
+            if x > 0. {
+                gamma * x
+            } else {
+                gamma * (alpha * x.exp() - alpha)
+            }
+        });
+
+        let actual = selu(&pool, input.view(), alpha, gamma);
+        expect_equal(&actual, &expected)?;
+
+        let mut input = input.clone();
+        selu_in_place(input.view_mut(), alpha, gamma);
+        expect_equal(&input, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_sigmoid() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1111,7 +1534,33 @@ mod tests {
         Ok(())
     }
 
-    test_unary_op!(test_sign, sign, sign_in_place, |x: &f32| x.signum());
+    #[test]
+    fn test_sign() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // Float input. Zero maps to zero, regardless of its sign.
+        let input = tensor!([-2.5, -0., 0., 0.1, 3.]);
+        let expected = tensor!([-1., 0., 0., 1., 1.]);
+        let result = sign(&pool, input.view());
+        expect_equal(&result, &expected)?;
+        assert!(sign(&pool, tensor!([f32::NAN]).view())[[0]].is_nan());
+
+        let mut input = input.clone();
+        sign_in_place(input.view_mut());
+        expect_equal(&input, &expected)?;
+
+        // Int input
+        let input = tensor!([i32::MIN, -5, 0, 7, i32::MAX]);
+        let expected = tensor!([-1, -1, 0, 1, 1]);
+        assert_eq!(sign(&pool, input.view()), expected);
+
+        let mut input = input.clone();
+        sign_in_place(input.view_mut());
+        assert_eq!(input, expected);
+
+        Ok(())
+    }
+
     test_unary_op!(test_sin, sin, sin_in_place, |x: &f32| x.sin());
     test_unary_op!(test_softplus, softplus, softplus_in_place, |x: &f32| {
         x.exp().ln_1p()
@@ -1136,6 +1585,24 @@ mod tests {
         Ok(())
     }
 
+    test_unary_op!(test_softsign, softsign, softsign_in_place, |x: &f32| x
+        / (1. + x.abs()));
     test_unary_op!(test_tan, tan, tan_in_place, |x: &f32| x.tan());
     test_unary_op!(test_tanh, tanh, tanh_in_place, |x: &f32| x.tanh());
+
+    #[test]
+    fn test_thresholded_relu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = tensor!([-2., 0., 0.5, 1., 1.5, 3.]);
+        let expected = tensor!([0., 0., 0., 0., 1.5, 3.]);
+
+        let actual = thresholded_relu(&pool, input.view(), 1.0);
+        expect_equal(&actual, &expected)?;
+
+        let mut input = input.clone();
+        thresholded_relu_in_place(input.view_mut(), 1.0);
+        expect_equal(&input, &expected)?;
+
+        Ok(())
+    }
 }
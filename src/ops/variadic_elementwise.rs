@@ -103,6 +103,11 @@ macro_rules! run_typed_op {
                 let inputs: Vec<TensorView<i32>> = typed_views(&$inputs)?;
                 $op($pool, &inputs).into_op_result()
             }
+            Input::Int64Tensor(_) => {
+                let inputs: Vec<TensorView<i64>> = typed_views(&$inputs)?;
+                $op($pool, &inputs).into_op_result()
+            }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
 }
@@ -0,0 +1,874 @@
+//! Graph optimizations which are applied when a model is loaded.
+
+use std::collections::HashMap;
+
+use rten_tensor::prelude::*;
+
+use crate::graph::{Constant, Graph, Node, NodeId, OperatorNode};
+use crate::op_registry::{OpAttrs, OpRegistry};
+use crate::ops::{InputList, Output};
+use crate::tensor_pool::TensorPool;
+
+/// Index of the operators which produce and consume each value in a graph.
+struct ValueUsers {
+    producers: HashMap<NodeId, NodeId>,
+    consumer_count: HashMap<NodeId, usize>,
+}
+
+impl ValueUsers {
+    fn new(graph: &Graph, graph_outputs: &[NodeId]) -> ValueUsers {
+        let mut producers = HashMap::new();
+        let mut consumer_count = HashMap::new();
+
+        for (op_id, node) in graph.iter() {
+            let Node::Operator(op) = node else {
+                continue;
+            };
+            for output in op.outputs().iter().flatten() {
+                producers.insert(*output, op_id);
+            }
+            for input in op.inputs().iter().flatten() {
+                *consumer_count.entry(*input).or_insert(0) += 1;
+            }
+        }
+
+        // Graph outputs count as an extra consumer, so that values which are
+        // returned to the caller are never fused away.
+        for output in graph_outputs {
+            *consumer_count.entry(*output).or_insert(0) += 1;
+        }
+
+        ValueUsers {
+            producers,
+            consumer_count,
+        }
+    }
+
+    /// Return true if `value_id` is only used as an input to one operator.
+    fn has_single_use(&self, value_id: NodeId) -> bool {
+        self.consumer_count.get(&value_id).copied() == Some(1)
+    }
+}
+
+/// Return the operator of type `op_type` which produces `value_id`, if its
+/// output is not used elsewhere in the graph.
+fn fusable_producer<'a>(
+    graph: &'a Graph,
+    users: &ValueUsers,
+    value_id: NodeId,
+    op_type: &str,
+) -> Option<&'a OperatorNode> {
+    if !users.has_single_use(value_id) {
+        return None;
+    }
+    let op_id = users.producers.get(&value_id)?;
+    match graph.get_node(*op_id) {
+        Some(Node::Operator(op)) if op.operator().name() == op_type => Some(op),
+        _ => None,
+    }
+}
+
+/// Return the two inputs of a binary operator.
+fn binary_inputs(op: &OperatorNode) -> Option<[NodeId; 2]> {
+    match op.inputs() {
+        [Some(a), Some(b)] => Some([*a, *b]),
+        _ => None,
+    }
+}
+
+/// Return the value of `value_id` if it is a float constant with a single
+/// element.
+fn scalar_const(graph: &Graph, value_id: NodeId) -> Option<f32> {
+    let Some(Node::Constant(Constant::Float(node))) = graph.get_node(value_id) else {
+        return None;
+    };
+    let view = node.view();
+    (view.len() == 1).then(|| view.iter().copied().next().unwrap())
+}
+
+/// Return true if `value_id` is a float constant with a single element that
+/// is approximately equal to `expected`.
+fn is_scalar_const(graph: &Graph, value_id: NodeId, expected: f32) -> bool {
+    scalar_const(graph, value_id).is_some_and(|x| (x - expected).abs() <= 1e-4 * expected.abs())
+}
+
+/// Match `Add(Erf(x / sqrt(2)), 1)` where `add_out` is the output of the
+/// `Add`, and return `x`. The division may also be expressed as
+/// multiplication by `1 / sqrt(2)`.
+fn match_erf_plus_one(graph: &Graph, users: &ValueUsers, add_out: NodeId) -> Option<NodeId> {
+    let add = fusable_producer(graph, users, add_out, "Add")?;
+    let [a, b] = binary_inputs(add)?;
+    let erf_out = if is_scalar_const(graph, b, 1.0) {
+        a
+    } else if is_scalar_const(graph, a, 1.0) {
+        b
+    } else {
+        return None;
+    };
+
+    let erf = fusable_producer(graph, users, erf_out, "Erf")?;
+    let [Some(erf_in)] = erf.inputs() else {
+        return None;
+    };
+
+    if let Some(div) = fusable_producer(graph, users, *erf_in, "Div") {
+        let [x, divisor] = binary_inputs(div)?;
+        return is_scalar_const(graph, divisor, std::f32::consts::SQRT_2).then_some(x);
+    }
+
+    let mul = fusable_producer(graph, users, *erf_in, "Mul")?;
+    let [a, b] = binary_inputs(mul)?;
+    let scale = std::f32::consts::FRAC_1_SQRT_2;
+    if is_scalar_const(graph, b, scale) {
+        Some(a)
+    } else if is_scalar_const(graph, a, scale) {
+        Some(b)
+    } else {
+        None
+    }
+}
+
+/// Match a `Mul` of `x` and `Add(Erf(x / sqrt(2)), 1)`, in either order.
+fn match_x_times_erf_plus_one(
+    graph: &Graph,
+    users: &ValueUsers,
+    lhs: NodeId,
+    rhs: NodeId,
+) -> Option<NodeId> {
+    [(lhs, rhs), (rhs, lhs)]
+        .into_iter()
+        .find_map(|(x, add_out)| (match_erf_plus_one(graph, users, add_out)? == x).then_some(x))
+}
+
+/// Match the decomposed exact GELU, `0.5 * x * (1 + erf(x / sqrt(2)))`, for
+/// the `Mul` operator `op`, and return `x`.
+///
+/// This recognizes both of the orderings produced by common exporters:
+/// `(x * (1 + erf(..))) * 0.5` and `(x * 0.5) * (1 + erf(..))`.
+fn match_gelu(graph: &Graph, users: &ValueUsers, op: &OperatorNode) -> Option<NodeId> {
+    if op.operator().name() != "Mul" {
+        return None;
+    }
+    let [a, b] = binary_inputs(op)?;
+
+    // `(x * (1 + erf(x / sqrt(2)))) * 0.5`
+    for (prod, half) in [(a, b), (b, a)] {
+        if !is_scalar_const(graph, half, 0.5) {
+            continue;
+        }
+        let Some(mul) = fusable_producer(graph, users, prod, "Mul") else {
+            continue;
+        };
+        let Some([lhs, rhs]) = binary_inputs(mul) else {
+            continue;
+        };
+        if let Some(x) = match_x_times_erf_plus_one(graph, users, lhs, rhs) {
+            return Some(x);
+        }
+    }
+
+    // `(x * 0.5) * (1 + erf(x / sqrt(2)))`
+    for (half_x, add_out) in [(a, b), (b, a)] {
+        let Some(mul) = fusable_producer(graph, users, half_x, "Mul") else {
+            continue;
+        };
+        let Some([lhs, rhs]) = binary_inputs(mul) else {
+            continue;
+        };
+        let x = if is_scalar_const(graph, rhs, 0.5) {
+            lhs
+        } else if is_scalar_const(graph, lhs, 0.5) {
+            rhs
+        } else {
+            continue;
+        };
+        if match_erf_plus_one(graph, users, add_out) == Some(x) {
+            return Some(x);
+        }
+    }
+
+    None
+}
+
+/// Replace subgraphs which compute the exact GELU function using primitive
+/// operators with a single `Gelu` operator.
+///
+/// The final `Mul` of each matched subgraph is replaced by `Gelu`, keeping
+/// its name and output. As with [fuse_attention], the other operators in the
+/// subgraph are left in place and subgraphs whose intermediate values are used
+/// elsewhere are not fused.
+///
+/// Fusion is skipped if `registry` does not include the `Gelu` operator.
+/// Returns the number of subgraphs that were fused.
+pub(crate) fn fuse_gelu(
+    graph: &mut Graph,
+    registry: &OpRegistry,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    graph_outputs: &[NodeId],
+) -> usize {
+    let users = ValueUsers::new(graph, graph_outputs);
+    let matches: Vec<(NodeId, NodeId)> = graph
+        .iter()
+        .filter_map(|(op_id, node)| match node {
+            Node::Operator(op) => match_gelu(graph, &users, op).map(|x| (op_id, x)),
+            _ => None,
+        })
+        .collect();
+
+    let mut n_fused = 0;
+    for (op_id, x) in matches {
+        let attrs = OpAttrs::new().with("approximate", false);
+        let Ok(op) = registry.read_op("Gelu", &attrs) else {
+            break;
+        };
+        if graph.replace_op(op_id, op, &[Some(x)]) {
+            op_attrs.insert(op_id, attrs);
+            n_fused += 1;
+        }
+    }
+    n_fused
+}
+
+/// Match a multiplication or division of `x` by a scalar constant, where
+/// `value_id` is the output, and return `x` and the factor it is multiplied
+/// by. If there is no such operator, `value_id` is returned with a factor
+/// of 1.
+///
+/// Repeated scaling, such as `(x * a) / b`, is combined into one factor.
+fn strip_scale(graph: &Graph, users: &ValueUsers, value_id: NodeId) -> (NodeId, f32) {
+    let mut x = value_id;
+    let mut scale = 1.0;
+    loop {
+        if let Some([a, b]) = fusable_producer(graph, users, x, "Mul").and_then(binary_inputs) {
+            if let Some(c) = scalar_const(graph, b) {
+                (x, scale) = (a, scale * c);
+                continue;
+            } else if let Some(c) = scalar_const(graph, a) {
+                (x, scale) = (b, scale * c);
+                continue;
+            }
+        }
+        if let Some([a, b]) = fusable_producer(graph, users, x, "Div").and_then(binary_inputs) {
+            if let Some(c) = scalar_const(graph, b) {
+                (x, scale) = (a, scale / c);
+                continue;
+            }
+        }
+        return (x, scale);
+    }
+}
+
+/// Inputs and attributes of a fused attention operator.
+struct AttentionMatch {
+    query: NodeId,
+    key_t: NodeId,
+    value: NodeId,
+    mask: Option<NodeId>,
+    scale: f32,
+}
+
+/// Match `MatMul(Q, Kᵀ)`, scaled by a constant, where `scores` is the
+/// output, and return `(Q, Kᵀ, scale)`.
+///
+/// The scale may be applied to the output of the `MatMul`, or to either of
+/// its inputs. Exporters for older versions of PyTorch typically scale `Q`
+/// before the `MatMul`, and some models scale both `Q` and `K`.
+fn match_attention_scores(
+    graph: &Graph,
+    users: &ValueUsers,
+    scores: NodeId,
+) -> Option<(NodeId, NodeId, f32)> {
+    let (scores, scale) = strip_scale(graph, users, scores);
+    let matmul = fusable_producer(graph, users, scores, "MatMul")?;
+    let [query, key_t] = binary_inputs(matmul)?;
+    let (query, q_scale) = strip_scale(graph, users, query);
+    let (key_t, k_scale) = strip_scale(graph, users, key_t);
+    Some((query, key_t, scale * q_scale * k_scale))
+}
+
+/// Match `MatMul(Softmax(scale * MatMul(Q, Kᵀ) + mask), V)` for the final
+/// `MatMul` operator `op`. The mask is optional.
+///
+/// This only matches the structure of the batched matrix multiplications,
+/// so it recognizes the layouts used by different exporters. `Q`, `Kᵀ` and
+/// `V` are typically produced by `Reshape` and `Transpose` operators.
+/// Modern exports have 4D `[batch, heads, seq, head_size]` inputs and
+/// compute `Kᵀ` by swapping the last two axes of `K`. Older exports from
+/// PyTorch often compute `Kᵀ` directly from the reshaped key projection
+/// using a `[0, 2, 3, 1]` permutation, or merge the batch and head
+/// dimensions to use 3D batched `MatMul`s. In each case the `Transpose`
+/// operators are kept and their outputs are passed to the fused operator as
+/// views.
+fn match_attention(
+    graph: &Graph,
+    users: &ValueUsers,
+    op_attrs: &HashMap<NodeId, OpAttrs>,
+    op: &OperatorNode,
+) -> Option<AttentionMatch> {
+    if op.operator().name() != "MatMul" {
+        return None;
+    }
+    let [weights, value] = binary_inputs(op)?;
+
+    // The softmax must be over the key sequence, which is the last axis.
+    let softmax = fusable_producer(graph, users, weights, "Softmax")?;
+    let softmax_id = users.producers.get(&weights)?;
+    let axis: isize = op_attrs.get(softmax_id)?.get_or("axis", -1).ok()?;
+    let [Some(softmax_in)] = softmax.inputs() else {
+        return None;
+    };
+    let ndim = graph
+        .get_node(*softmax_in)
+        .and_then(|node| node.shape())
+        .map(|shape| shape.len() as isize);
+    if axis != -1 && Some(axis + 1) != ndim {
+        return None;
+    }
+
+    if let Some([a, b]) = fusable_producer(graph, users, *softmax_in, "Add").and_then(binary_inputs)
+    {
+        if let Some((scores, mask)) = [(a, b), (b, a)]
+            .into_iter()
+            .find_map(|(scores, mask)| Some((match_attention_scores(graph, users, scores)?, mask)))
+        {
+            let (query, key_t, scale) = scores;
+            return Some(AttentionMatch {
+                query,
+                key_t,
+                value,
+                mask: Some(mask),
+                scale,
+            });
+        }
+    }
+
+    let (query, key_t, scale) = match_attention_scores(graph, users, *softmax_in)?;
+    Some(AttentionMatch {
+        query,
+        key_t,
+        value,
+        mask: None,
+        scale,
+    })
+}
+
+/// Replace subgraphs which compute scaled dot-product attention using
+/// `MatMul`, `Softmax` and elementwise operators with a single
+/// `ScaledDotProductAttention` operator.
+///
+/// The final `MatMul` of each matched subgraph is replaced, keeping its name
+/// and output. The other operators in the subgraph are left in place, but are
+/// no longer used to compute any of the graph outputs. Subgraphs whose
+/// intermediate values are used elsewhere, or are in `graph_outputs`, are not
+/// fused. `op_attrs` is used to check the axis of `Softmax` operators.
+///
+/// Fusion is skipped if `registry` does not include the
+/// `ScaledDotProductAttention` operator. Returns the number of subgraphs that
+/// were fused.
+pub(crate) fn fuse_attention(
+    graph: &mut Graph,
+    registry: &OpRegistry,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    graph_outputs: &[NodeId],
+) -> usize {
+    let users = ValueUsers::new(graph, graph_outputs);
+    let matches: Vec<(NodeId, AttentionMatch)> = graph
+        .iter()
+        .filter_map(|(op_id, node)| match node {
+            Node::Operator(op) => match_attention(graph, &users, op_attrs, op).map(|m| (op_id, m)),
+            _ => None,
+        })
+        .collect();
+
+    let mut n_fused = 0;
+    for (op_id, m) in matches {
+        let attrs = OpAttrs::new().with("scale", m.scale);
+        let Ok(op) = registry.read_op("ScaledDotProductAttention", &attrs) else {
+            break;
+        };
+        let inputs = [Some(m.query), Some(m.key_t), Some(m.value), m.mask];
+        if graph.replace_op(op_id, op, &inputs) {
+            op_attrs.insert(op_id, attrs);
+            n_fused += 1;
+        }
+    }
+    n_fused
+}
+
+/// Return true if an operator node can be evaluated at load time when all
+/// of its inputs are constants.
+///
+/// Custom operators may have side effects and operators which generate
+/// random values must produce different values on each run, so these are
+/// not folded.
+fn is_foldable(graph: &Graph, op: &OperatorNode) -> bool {
+    op.custom_op_type().is_none()
+        && op.operator().with_seed(0).is_none()
+        && op
+            .inputs()
+            .iter()
+            .flatten()
+            .all(|id| matches!(graph.get_node(*id), Some(Node::Constant(_))))
+}
+
+/// Evaluate an operator whose inputs are all constants.
+///
+/// Returns `None` if the operator fails, or if any of its used outputs is not
+/// a tensor.
+fn eval_constant_op(graph: &Graph, op: &OperatorNode) -> Option<Vec<(NodeId, Output)>> {
+    let inputs: InputList = op
+        .inputs()
+        .iter()
+        .map(|id| match id.and_then(|id| graph.get_node(id)) {
+            Some(Node::Constant(constant)) => Some(constant.as_input()),
+            _ => None,
+        })
+        .collect();
+    let pool = TensorPool::new();
+    let mut outputs = op.operator().run(&pool, inputs).ok()?;
+
+    let mut values = Vec::new();
+    for (i, output_id) in op.outputs().iter().enumerate().rev() {
+        let output = (i < outputs.len()).then(|| outputs.remove(i));
+        let Some(output_id) = output_id else {
+            continue;
+        };
+        match output {
+            Some(Output::Sequence(_)) | None => return None,
+            Some(output) => values.push((*output_id, output)),
+        }
+    }
+    Some(values)
+}
+
+/// Replace operators whose inputs are all constants with the values they
+/// produce.
+///
+/// Operators that only depend on constants via other operators are folded
+/// too. The inputs of folded operators are left in the graph, even if they
+/// are no longer used. Returns the number of operators that were folded.
+pub(crate) fn fold_constants(graph: &mut Graph, op_attrs: &mut HashMap<NodeId, OpAttrs>) -> usize {
+    let mut n_folded = 0;
+
+    // Operators that become foldable as a result of folding their inputs are
+    // handled in the next pass.
+    loop {
+        let foldable: Vec<NodeId> = graph
+            .iter()
+            .filter_map(|(op_id, node)| match node {
+                Node::Operator(op) if is_foldable(graph, op) => Some(op_id),
+                _ => None,
+            })
+            .collect();
+
+        let mut folded_any = false;
+        for op_id in foldable {
+            let Some(Node::Operator(op)) = graph.get_node(op_id) else {
+                continue;
+            };
+            let Some(values) = eval_constant_op(graph, op) else {
+                continue;
+            };
+            for (output_id, value) in values {
+                match value {
+                    Output::FloatTensor(t) => graph.replace_with_constant(output_id, t),
+                    Output::IntTensor(t) => graph.replace_with_constant(output_id, t),
+                    Output::Int64Tensor(t) => graph.replace_with_constant(output_id, t),
+                    Output::StringTensor(t) => graph.replace_with_constant(output_id, t),
+                    Output::Sequence(_) => unreachable!(),
+                };
+            }
+            graph.remove_node(op_id);
+            op_attrs.remove(&op_id);
+            n_folded += 1;
+            folded_any = true;
+        }
+
+        if !folded_any {
+            break;
+        }
+    }
+    n_folded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal_with_tolerance;
+    use rten_tensor::{tensor, Tensor};
+
+    use super::{fold_constants, fuse_attention, fuse_gelu};
+    use crate::graph::{Dimension, Graph, Node, NodeId};
+    use crate::op_registry::OpAttrs;
+    use crate::ops::{Add, Div, Erf, MatMul, Mul, Operator, Reshape, Softmax, Transpose};
+    use crate::OpRegistry;
+
+    /// Add a binary operator to `graph` and return the ID of its output.
+    fn add_binary_op(
+        graph: &mut Graph,
+        op: Box<dyn Operator + Send + Sync>,
+        a: NodeId,
+        b: NodeId,
+    ) -> NodeId {
+        let out = graph.add_value(None, None);
+        graph.add_op(None, op, &[Some(a), Some(b)], &[Some(out)]);
+        out
+    }
+
+    /// Build a graph computing `0.5 * x * (1 + erf(x / sqrt(2)))`.
+    ///
+    /// If `half_first` is true, `x` is multiplied by `0.5` before being
+    /// multiplied by the erf term. Returns `(graph, input, erf_out, output)`.
+    fn decomposed_gelu_graph(half_first: bool) -> (Graph, NodeId, NodeId, NodeId) {
+        let mut g = Graph::new();
+        let x = g.add_value(Some("x"), None);
+        let sqrt_2 = g.add_constant(None, tensor!(std::f32::consts::SQRT_2));
+        let one = g.add_constant(None, tensor!(1.0f32));
+        let half = g.add_constant(None, tensor!(0.5f32));
+
+        let div_out = add_binary_op(&mut g, Box::new(Div {}), x, sqrt_2);
+        let erf_out = g.add_value(None, None);
+        g.add_op(None, Box::new(Erf {}), &[Some(div_out)], &[Some(erf_out)]);
+        let add_out = add_binary_op(&mut g, Box::new(Add {}), one, erf_out);
+
+        let output = if half_first {
+            let half_x = add_binary_op(&mut g, Box::new(Mul {}), x, half);
+            add_binary_op(&mut g, Box::new(Mul {}), half_x, add_out)
+        } else {
+            let mul_out = add_binary_op(&mut g, Box::new(Mul {}), add_out, x);
+            add_binary_op(&mut g, Box::new(Mul {}), mul_out, half)
+        };
+
+        (g, x, erf_out, output)
+    }
+
+    /// Add an operator with one output to `graph` and return the ID of the
+    /// output.
+    fn add_op(
+        graph: &mut Graph,
+        op: Box<dyn Operator + Send + Sync>,
+        inputs: &[NodeId],
+        out_shape: Option<&[usize]>,
+    ) -> NodeId {
+        let shape = out_shape.map(|s| s.iter().copied().map(Dimension::Fixed).collect());
+        let out = graph.add_value(None, shape);
+        let inputs: Vec<_> = inputs.iter().copied().map(Some).collect();
+        graph.add_op(None, op, &inputs, &[Some(out)]);
+        out
+    }
+
+    fn add_reshape(graph: &mut Graph, x: NodeId, shape: &[i32]) -> NodeId {
+        let shape = graph.add_constant(None, Tensor::from(shape.to_vec()));
+        add_op(
+            graph,
+            Box::new(Reshape { allow_zero: false }),
+            &[x, shape],
+            None,
+        )
+    }
+
+    fn add_transpose(graph: &mut Graph, x: NodeId, perm: &[usize]) -> NodeId {
+        let perm = Some(perm.to_vec());
+        add_op(graph, Box::new(Transpose { perm }), &[x], None)
+    }
+
+    /// Layouts of attention subgraphs produced by different exporters.
+    #[derive(Clone, Copy, Debug)]
+    enum AttentionLayout {
+        /// 4D `[batch, heads, seq, head_size]` inputs, with the scale applied
+        /// to the scores and `Kᵀ` computed by swapping the last two axes of
+        /// `K`.
+        Modern,
+        /// 4D inputs, with the scale applied to `Q` and `Kᵀ` computed
+        /// directly from the reshaped key projection.
+        Legacy4D,
+        /// 3D `[batch * heads, seq, head_size]` inputs, as used by older
+        /// exports of `nn.MultiheadAttention`.
+        Legacy3D,
+    }
+
+    struct AttentionGraph {
+        graph: Graph,
+        op_attrs: HashMap<NodeId, OpAttrs>,
+        inputs: Vec<(NodeId, Tensor)>,
+        weights: NodeId,
+        output: NodeId,
+    }
+
+    /// Build a graph which computes attention from the query, key and value
+    /// projections using separate `MatMul`, `Softmax` and elementwise
+    /// operators.
+    fn attention_graph(layout: AttentionLayout, with_mask: bool) -> AttentionGraph {
+        let [batch, seq, heads, head_size] = [2, 5, 3, 4];
+        let mut rng = XorShiftRng::new(1234);
+        let mut g = Graph::new();
+        let mut inputs = Vec::new();
+
+        let input_shape = match layout {
+            AttentionLayout::Modern | AttentionLayout::Legacy4D => [batch, seq, heads * head_size],
+            AttentionLayout::Legacy3D => [seq, batch, heads * head_size],
+        };
+        let [x_q, x_k, x_v] = ["q", "k", "v"].map(|name| {
+            let id = g.add_value(Some(name), None);
+            inputs.push((id, Tensor::<f32>::rand(&input_shape, &mut rng)));
+            id
+        });
+
+        let [b, s, h, d] = [batch, seq, heads, head_size].map(|x| x as i32);
+        let (query, key_t, value, scores_shape) = match layout {
+            AttentionLayout::Modern => {
+                let [q, k, v] = [x_q, x_k, x_v].map(|x| {
+                    let x = add_reshape(&mut g, x, &[b, s, h, d]);
+                    add_transpose(&mut g, x, &[0, 2, 1, 3])
+                });
+                let k_t = add_transpose(&mut g, k, &[0, 1, 3, 2]);
+                (q, k_t, v, vec![batch, heads, seq, seq])
+            }
+            AttentionLayout::Legacy4D => {
+                let [q, k, v] = [x_q, x_k, x_v].map(|x| add_reshape(&mut g, x, &[b, s, h, d]));
+                let q = add_transpose(&mut g, q, &[0, 2, 1, 3]);
+                let k_t = add_transpose(&mut g, k, &[0, 2, 3, 1]);
+                let v = add_transpose(&mut g, v, &[0, 2, 1, 3]);
+                (q, k_t, v, vec![batch, heads, seq, seq])
+            }
+            AttentionLayout::Legacy3D => {
+                let [q, k, v] = [x_q, x_k, x_v].map(|x| add_reshape(&mut g, x, &[s, b * h, d]));
+                let q = add_transpose(&mut g, q, &[1, 0, 2]);
+                let k_t = add_transpose(&mut g, k, &[1, 2, 0]);
+                let v = add_transpose(&mut g, v, &[1, 0, 2]);
+                (q, k_t, v, vec![batch * heads, seq, seq])
+            }
+        };
+
+        let scale = g.add_constant(None, tensor!(0.5f32));
+        let scores = match layout {
+            AttentionLayout::Modern => {
+                let scores = add_binary_op(&mut g, Box::new(MatMul {}), query, key_t);
+                let sqrt_d = g.add_constant(None, tensor!(2.0f32));
+                add_binary_op(&mut g, Box::new(Div {}), scores, sqrt_d)
+            }
+            AttentionLayout::Legacy4D | AttentionLayout::Legacy3D => {
+                let query = add_binary_op(&mut g, Box::new(Mul {}), query, scale);
+                add_binary_op(&mut g, Box::new(MatMul {}), query, key_t)
+            }
+        };
+
+        // Older exports use a positive softmax axis, which requires the rank
+        // of the scores to be known.
+        let (scores, axis) = if with_mask {
+            let mask_shape = match layout {
+                AttentionLayout::Modern | AttentionLayout::Legacy4D => vec![batch, 1, 1, seq],
+                AttentionLayout::Legacy3D => vec![seq, seq],
+            };
+            let mask = g.add_value(Some("mask"), None);
+            inputs.push((mask, Tensor::<f32>::rand(&mask_shape, &mut rng)));
+            let add_inputs = match layout {
+                AttentionLayout::Legacy4D => [mask, scores],
+                _ => [scores, mask],
+            };
+            let scores = add_op(&mut g, Box::new(Add {}), &add_inputs, Some(&scores_shape));
+            (scores, scores_shape.len() as i32 - 1)
+        } else {
+            (scores, -1)
+        };
+
+        let weights = g.add_value(None, None);
+        let softmax_id = g.add_op(
+            None,
+            Box::new(Softmax {
+                axis: axis as isize,
+            }),
+            &[Some(scores)],
+            &[Some(weights)],
+        );
+        let output = add_binary_op(&mut g, Box::new(MatMul {}), weights, value);
+
+        let op_attrs = HashMap::from([(softmax_id, OpAttrs::new().with("axis", axis))]);
+
+        AttentionGraph {
+            graph: g,
+            op_attrs,
+            inputs,
+            weights,
+            output,
+        }
+    }
+
+    fn count_ops(graph: &Graph, op_type: &str) -> usize {
+        graph
+            .iter()
+            .filter(
+                |(_, node)| matches!(node, Node::Operator(op) if op.operator().name() == op_type),
+            )
+            .count()
+    }
+
+    #[test]
+    fn test_fold_constants() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let x = graph.add_value(Some("x"), None);
+        let two = graph.add_constant(None, tensor!(2.0f32));
+        let three = graph.add_constant(None, tensor!(3.0f32));
+
+        // `(2 * 3) + 2` only depends on constants and is folded across two
+        // passes. The final `Add` depends on an input and is kept.
+        let six = add_binary_op(&mut graph, Box::new(Mul {}), two, three);
+        let eight = add_binary_op(&mut graph, Box::new(Add {}), six, two);
+        let output = add_binary_op(&mut graph, Box::new(Add {}), x, eight);
+
+        let mut op_attrs = HashMap::new();
+        let n_folded = fold_constants(&mut graph, &mut op_attrs);
+        assert_eq!(n_folded, 2);
+        assert_eq!(count_ops(&graph, "Mul"), 0);
+        assert_eq!(count_ops(&graph, "Add"), 1);
+        assert!(matches!(graph.get_node(eight), Some(Node::Constant(_))));
+
+        let input = tensor!([1., 2.]);
+        let result = graph
+            .run(&[(x, input.view().into())], &[output], None)?
+            .remove(0)
+            .into_float()
+            .unwrap();
+        assert_eq!(result.to_vec(), &[9., 10.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_gelu() -> Result<(), Box<dyn Error>> {
+        let registry = OpRegistry::with_all_ops();
+        let input = Tensor::arange(-3., 3., Some(0.25));
+
+        for half_first in [false, true] {
+            let (mut graph, x, _, output) = decomposed_gelu_graph(half_first);
+            let expected = graph
+                .run(&[(x, input.view().into())], &[output], None)?
+                .remove(0)
+                .into_float()
+                .unwrap();
+
+            let mut op_attrs = HashMap::new();
+            let n_fused = fuse_gelu(&mut graph, &registry, &mut op_attrs, &[output]);
+            assert_eq!(n_fused, 1);
+            assert_eq!(count_ops(&graph, "Gelu"), 1);
+            assert_eq!(op_attrs.len(), 1);
+
+            let result = graph
+                .run(&[(x, input.view().into())], &[output], None)?
+                .remove(0)
+                .into_float()
+                .unwrap();
+            expect_equal_with_tolerance(&result, &expected, 1e-5, 1e-5)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_gelu_skips_shared_intermediate() {
+        let registry = OpRegistry::with_all_ops();
+        let (mut graph, _, erf_out, output) = decomposed_gelu_graph(false);
+
+        // If an intermediate value is also a graph output, it must still be
+        // computed, so the subgraph is not fused.
+        let mut op_attrs = HashMap::new();
+        let n_fused = fuse_gelu(&mut graph, &registry, &mut op_attrs, &[output, erf_out]);
+        assert_eq!(n_fused, 0);
+        assert_eq!(count_ops(&graph, "Gelu"), 0);
+    }
+
+    #[test]
+    fn test_fuse_attention() -> Result<(), Box<dyn Error>> {
+        let registry = OpRegistry::with_all_ops();
+
+        for layout in [
+            AttentionLayout::Modern,
+            AttentionLayout::Legacy4D,
+            AttentionLayout::Legacy3D,
+        ] {
+            for with_mask in [false, true] {
+                let AttentionGraph {
+                    mut graph,
+                    mut op_attrs,
+                    inputs,
+                    output,
+                    ..
+                } = attention_graph(layout, with_mask);
+                let inputs: Vec<_> = inputs
+                    .iter()
+                    .map(|(id, value)| (*id, value.view().into()))
+                    .collect();
+                let expected = graph
+                    .run(&inputs, &[output], None)?
+                    .remove(0)
+                    .into_float()
+                    .unwrap();
+
+                let n_fused = fuse_attention(&mut graph, &registry, &mut op_attrs, &[output]);
+                assert_eq!(n_fused, 1, "layout {:?} not fused", layout);
+                assert_eq!(count_ops(&graph, "ScaledDotProductAttention"), 1);
+
+                // The unfused operators are no longer used to compute the
+                // output.
+                let input_ids: Vec<_> = inputs.iter().map(|(id, _)| *id).collect();
+                let plan = graph.plan_summary(&input_ids, &[output])?;
+                assert!(plan.contains("ScaledDotProductAttention"));
+                assert!(!plan.contains("Softmax") && !plan.contains("MatMul"));
+
+                let result = graph
+                    .run(&inputs, &[output], None)?
+                    .remove(0)
+                    .into_float()
+                    .unwrap();
+                expect_equal_with_tolerance(&result, &expected, 1e-5, 1e-5)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_attention_skips_unsupported_subgraphs() {
+        let registry = OpRegistry::with_all_ops();
+
+        // If the attention weights are also a graph output, they must still
+        // be computed, so the subgraph is not fused.
+        let AttentionGraph {
+            mut graph,
+            mut op_attrs,
+            weights,
+            output,
+            ..
+        } = attention_graph(AttentionLayout::Modern, true);
+        let n_fused = fuse_attention(&mut graph, &registry, &mut op_attrs, &[output, weights]);
+        assert_eq!(n_fused, 0);
+
+        // Softmax over an axis other than the last is not attention.
+        let AttentionGraph {
+            mut graph,
+            mut op_attrs,
+            output,
+            ..
+        } = attention_graph(AttentionLayout::Modern, false);
+        for attrs in op_attrs.values_mut() {
+            attrs.insert("axis", 2);
+        }
+        let n_fused = fuse_attention(&mut graph, &registry, &mut op_attrs, &[output]);
+        assert_eq!(n_fused, 0);
+    }
+
+    #[test]
+    fn test_fuse_gelu_requires_registered_op() {
+        let mut registry = OpRegistry::new();
+        registry.register_op::<Mul>();
+        let (mut graph, _, _, output) = decomposed_gelu_graph(false);
+
+        let mut op_attrs = HashMap::new();
+        let n_fused = fuse_gelu(&mut graph, &registry, &mut op_attrs, &[output]);
+        assert_eq!(n_fused, 0);
+    }
+}
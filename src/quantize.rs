@@ -0,0 +1,1031 @@
+//! Post-training quantization of models.
+//!
+//! [quantize_model] converts a float model into one where the weights of
+//! `MatMul` operators are stored as 8 or 4-bit integers, reducing the size of
+//! the model by up to 4x or 8x. This provides a Rust alternative to
+//! quantizing models with Python tooling before conversion.
+//!
+//! By default this is weight-only quantization. Quantization parameters are
+//! computed from the weight values alone, so no calibration data is needed.
+//! Quantized weights are stored in the packed layout used by the
+//! `MatMulNBits` operator, which dequantizes weights on the fly, and
+//! activations are kept in float.
+//!
+//! If [QuantizeOptions::quantize_activations] is set, the inputs and outputs
+//! of `MatMul` and `Conv` operators are also quantized to 8 bits, and the
+//! operators are replaced with `QLinearMatMul` and `QLinearConv`. The ranges
+//! of the activations are calibrated by running the float model with a set of
+//! representative inputs. Each quantized operator is preceded by operators
+//! which quantize its input and followed by operators which dequantize its
+//! output, so the rest of the model continues to use float values.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+
+use crate::graph::{Constant, Graph, Node, NodeId, RunError};
+use crate::model::{Model, ModelLoadError};
+use crate::model_builder::ModelBuildError;
+use crate::op_registry::{OpAttrs, OpRegistry};
+use crate::ops::{Add, Cast, Clip, DataType, Div, Input, MatMulNBits, Mul, Operator, Round, Sub};
+
+/// Method used to choose the range of values which is mapped to the range of
+/// quantized values.
+///
+/// For weights, the range is determined from the weights themselves. For
+/// activations, a range is determined from the values observed for each
+/// calibration input, and these are combined by taking the overall minimum
+/// and maximum (`MinMax`) or the mean (`Percentile`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CalibrationMethod {
+    /// Use the minimum and maximum values. No values are clipped.
+    MinMax,
+
+    /// Use the given upper percentile (eg. `99.9`) of values as the maximum
+    /// and the corresponding lower percentile as the minimum.
+    ///
+    /// Values outside the range are clipped. This trades larger errors for a
+    /// small number of outliers against better precision for other values.
+    Percentile(f32),
+}
+
+/// Specifies which weights share quantization parameters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Granularity {
+    /// Use one scale and zero point for the whole weight tensor.
+    PerTensor,
+
+    /// Use a separate scale and zero point for each output channel (column
+    /// of `MatMul` weights or first dimension of `Conv` weights).
+    ///
+    /// Activations always use per-tensor quantization.
+    PerChannel,
+}
+
+/// Options for [quantize_model].
+#[derive(Clone, Debug)]
+pub struct QuantizeOptions {
+    /// Number of bits per quantized value. Must be 4 or 8.
+    pub bits: u32,
+
+    pub granularity: Granularity,
+    pub method: CalibrationMethod,
+
+    /// Weights with fewer elements than this are left in float, as the
+    /// savings are not worth the loss of accuracy.
+    pub min_elements: usize,
+
+    /// Quantize the activations of `MatMul` and `Conv` operators as well as
+    /// their weights, using ranges calibrated from the inputs passed to
+    /// [quantize_model]. Requires `bits` to be 8.
+    pub quantize_activations: bool,
+}
+
+impl Default for QuantizeOptions {
+    fn default() -> QuantizeOptions {
+        QuantizeOptions {
+            bits: 8,
+            granularity: Granularity::PerChannel,
+            method: CalibrationMethod::MinMax,
+            min_elements: 1024,
+            quantize_activations: false,
+        }
+    }
+}
+
+/// Result of [quantize_model].
+pub struct QuantizedModel {
+    /// The quantized model, in `.rten` format.
+    pub data: Vec<u8>,
+
+    /// Number of weight tensors that were quantized.
+    pub quantized_weights: usize,
+
+    /// Number of operators whose activations were quantized.
+    pub quantized_activations: usize,
+
+    /// Maximum absolute difference between each output of the float and
+    /// quantized models, across all validation inputs. Entries correspond
+    /// to [Model::output_ids]. Outputs which are not float tensors have an
+    /// error of zero.
+    pub max_output_error: Vec<f32>,
+}
+
+/// Errors reported by [quantize_model].
+#[derive(Debug)]
+pub enum QuantizeError {
+    /// The quantization options are invalid.
+    InvalidOptions(&'static str),
+
+    /// The quantized model could not be serialized.
+    SerializeFailed(ModelBuildError),
+
+    /// The quantized model could not be loaded.
+    LoadFailed(ModelLoadError),
+
+    /// Running the model with validation inputs failed.
+    RunFailed(RunError),
+}
+
+impl Display for QuantizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantizeError::InvalidOptions(e) => write!(f, "invalid options: {e}"),
+            QuantizeError::SerializeFailed(e) => write!(f, "failed to serialize model: {e}"),
+            QuantizeError::LoadFailed(e) => write!(f, "failed to load quantized model: {e}"),
+            QuantizeError::RunFailed(e) => write!(f, "validation run failed: {e}"),
+        }
+    }
+}
+
+impl Error for QuantizeError {}
+
+/// Quantize the weights, and optionally activations, of a float model.
+///
+/// The weights of `MatMul` operators whose second input is a 2D float
+/// constant with at least [QuantizeOptions::min_elements] elements are
+/// quantized, and the operators are replaced with `MatMulNBits`.
+///
+/// `calibration_inputs` is a set of representative inputs for the model.
+/// Each item specifies values for the model's inputs, using the node IDs from
+/// `model`. If [QuantizeOptions::quantize_activations] is set, the float
+/// model is first run with each item to calibrate the ranges of activations.
+/// `MatMul` and `Conv` operators whose activations were calibrated are
+/// replaced with `QLinearMatMul` and `QLinearConv` instead of `MatMulNBits`.
+///
+/// After quantization, the float and quantized models are run with each item
+/// and the differences between their outputs are reported in
+/// [QuantizedModel::max_output_error]. This can be used to check that the
+/// accuracy of the quantized model is acceptable.
+pub fn quantize_model<'a, I>(
+    model: &Model,
+    calibration_inputs: I,
+    options: &QuantizeOptions,
+) -> Result<QuantizedModel, QuantizeError>
+where
+    I: IntoIterator<Item = Vec<(NodeId, Input<'a>)>>,
+{
+    if options.bits != 4 && options.bits != 8 {
+        return Err(QuantizeError::InvalidOptions("`bits` must be 4 or 8"));
+    }
+    if let CalibrationMethod::Percentile(pct) = options.method {
+        if !(50. ..=100.).contains(&pct) {
+            return Err(QuantizeError::InvalidOptions(
+                "percentile must be between 50 and 100",
+            ));
+        }
+    }
+    if options.quantize_activations && options.bits != 8 {
+        return Err(QuantizeError::InvalidOptions(
+            "quantizing activations requires `bits` to be 8",
+        ));
+    }
+
+    let data = model.serialize().map_err(QuantizeError::SerializeFailed)?;
+    let mut quantized = Model::load(data).map_err(QuantizeError::LoadFailed)?;
+
+    // Node IDs may differ between the models, so inputs are mapped by their
+    // position in the input list.
+    let quantized_input_id = |id: NodeId| -> Result<NodeId, QuantizeError> {
+        model
+            .input_ids()
+            .iter()
+            .position(|input_id| *input_id == id)
+            .map(|pos| quantized.input_ids()[pos])
+            .ok_or(QuantizeError::RunFailed(RunError::InvalidNodeId))
+    };
+    let inputs: Vec<_> = calibration_inputs.into_iter().collect();
+    let quantized_inputs = inputs
+        .iter()
+        .map(|inputs| {
+            inputs
+                .iter()
+                .map(|(id, input)| Ok((quantized_input_id(*id)?, input.clone())))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut quantized_activations = 0;
+    if options.quantize_activations {
+        let candidates = quantized.activation_candidates(options);
+        let ranges = calibrate(&quantized, &candidates, &quantized_inputs, options.method)?;
+        quantized_activations = quantized.quantize_activations(&candidates, &ranges, options);
+    }
+    let quantized_weights = quantized_activations + quantized.quantize_weights(options);
+    quantized.remove_unused_nodes();
+    let data = quantized
+        .serialize()
+        .map_err(QuantizeError::SerializeFailed)?;
+
+    let mut max_output_error = vec![0.; model.output_ids().len()];
+    for (inputs, quantized_inputs) in inputs.iter().zip(&quantized_inputs) {
+        let expected = model
+            .run(inputs, model.output_ids(), None)
+            .map_err(QuantizeError::RunFailed)?;
+        let actual = quantized
+            .run(quantized_inputs, quantized.output_ids(), None)
+            .map_err(QuantizeError::RunFailed)?;
+
+        for ((max_error, expected), actual) in
+            max_output_error.iter_mut().zip(&expected).zip(&actual)
+        {
+            let (Some(expected), Some(actual)) = (expected.as_float_ref(), actual.as_float_ref())
+            else {
+                continue;
+            };
+            if expected.shape() != actual.shape() {
+                continue;
+            }
+            for (x, y) in expected.iter().zip(actual.iter()) {
+                *max_error = f32::max(*max_error, (x - y).abs());
+            }
+        }
+    }
+
+    Ok(QuantizedModel {
+        data,
+        quantized_weights,
+        quantized_activations,
+        max_output_error,
+    })
+}
+
+/// Run `model` with each of `inputs` and return the range of each activation
+/// used by `candidates`.
+///
+/// Activations which are not float tensors are omitted from the result.
+fn calibrate(
+    model: &Model,
+    candidates: &[ActivationCandidate],
+    inputs: &[Vec<(NodeId, Input)>],
+    method: CalibrationMethod,
+) -> Result<HashMap<NodeId, (f32, f32)>, QuantizeError> {
+    let mut value_ids: Vec<NodeId> = candidates
+        .iter()
+        .flat_map(|c| [c.input, c.output])
+        .collect();
+    value_ids.sort();
+    value_ids.dedup();
+
+    let mut observed: HashMap<NodeId, Vec<(f32, f32)>> = HashMap::new();
+    for inputs in inputs {
+        let values = model
+            .run(inputs, &value_ids, None)
+            .map_err(QuantizeError::RunFailed)?;
+        for (&id, value) in value_ids.iter().zip(&values) {
+            if let Some(value) = value.as_float_ref() {
+                let mut values = value.to_vec();
+                observed
+                    .entry(id)
+                    .or_default()
+                    .push(value_range(&mut values, method));
+            }
+        }
+    }
+
+    Ok(observed
+        .into_iter()
+        .map(|(id, ranges)| (id, merge_ranges(&ranges, method)))
+        .collect())
+}
+
+/// Combine the ranges of an activation observed for each calibration input.
+fn merge_ranges(ranges: &[(f32, f32)], method: CalibrationMethod) -> (f32, f32) {
+    match method {
+        CalibrationMethod::MinMax => ranges.iter().fold((0., 0.), |(min, max), &(x_min, x_max)| {
+            (f32::min(min, x_min), f32::max(max, x_max))
+        }),
+        CalibrationMethod::Percentile(_) => {
+            let (min_sum, max_sum) = ranges.iter().fold((0., 0.), |(min, max), &(x_min, x_max)| {
+                (min + x_min, max + x_max)
+            });
+            let count = ranges.len().max(1) as f32;
+            (min_sum / count, max_sum / count)
+        }
+    }
+}
+
+/// Return the `(min, max)` range of `values` to map to the quantized range.
+///
+/// The range is extended to include zero, so that zero can be represented
+/// exactly.
+fn value_range(values: &mut [f32], method: CalibrationMethod) -> (f32, f32) {
+    let (min, max) = match method {
+        CalibrationMethod::MinMax => values.iter().fold((f32::MAX, f32::MIN), |(min, max), &x| {
+            (min.min(x), max.max(x))
+        }),
+        CalibrationMethod::Percentile(pct) if !values.is_empty() => {
+            let last = values.len() - 1;
+            let hi_index = ((pct / 100.) * last as f32).round() as usize;
+            let lo_index = last - hi_index;
+            let (_, &mut max, _) = values.select_nth_unstable_by(hi_index, f32::total_cmp);
+            let (_, &mut min, _) = values.select_nth_unstable_by(lo_index, f32::total_cmp);
+            (min, max)
+        }
+        CalibrationMethod::Percentile(_) => (0., 0.),
+    };
+    (min.min(0.), max.max(0.))
+}
+
+/// Compute the scale and zero point which map `[min, max]` to the range of
+/// unsigned `bits`-bit integers.
+fn quant_params(min: f32, max: f32, bits: u32) -> (f32, i32) {
+    let q_max = ((1 << bits) - 1) as f32;
+    let scale = (max - min) / q_max;
+    if scale == 0. {
+        return (1., 0);
+    }
+    let zero_point = (-min / scale).round().clamp(0., q_max) as i32;
+    (scale, zero_point)
+}
+
+/// Quantize `x` using the given scale and zero point, and clamp the result to
+/// `[0, q_max]`.
+fn quantize_value(x: f32, scale: f32, zero_point: i32, q_max: i32) -> i32 {
+    ((x / scale).round() as i32 + zero_point).clamp(0, q_max)
+}
+
+/// Compute the scale and zero point for each row of a `[channels, values]`
+/// matrix, according to [QuantizeOptions::granularity].
+fn channel_params(channels: NdTensorView<f32, 2>, options: &QuantizeOptions) -> Vec<(f32, i32)> {
+    let bits = options.bits;
+    match options.granularity {
+        Granularity::PerTensor => {
+            let mut values = channels.to_vec();
+            let (min, max) = value_range(&mut values, options.method);
+            vec![quant_params(min, max, bits); channels.size(0)]
+        }
+        Granularity::PerChannel => (0..channels.size(0))
+            .map(|row| {
+                let mut values = channels.slice::<1, _>(row).to_vec();
+                let (min, max) = value_range(&mut values, options.method);
+                quant_params(min, max, bits)
+            })
+            .collect(),
+    }
+}
+
+/// Weights quantized into the layout used by `MatMulNBits`.
+struct QuantizedWeights {
+    /// Packed quantized values with shape `[N, words]`.
+    data: NdTensor<i32, 2>,
+
+    /// Scales with shape `[N, 1]`.
+    scales: NdTensor<f32, 2>,
+
+    /// Zero points with shape `[N, 1]`.
+    zero_points: NdTensor<i32, 2>,
+
+    /// Block size, which covers all columns of `data`.
+    block_size: usize,
+}
+
+/// Quantize a `[K, N]` weight matrix for use as the second input of a
+/// `MatMul`.
+///
+/// Each output channel is stored as one row of the result, with one block
+/// covering the whole row.
+fn quantize_matmul_weights(
+    weights: NdTensorView<f32, 2>,
+    options: &QuantizeOptions,
+) -> QuantizedWeights {
+    let [k, n] = weights.shape();
+    let bits = options.bits;
+    let vals_per_word = (32 / bits) as usize;
+    let words = k.div_ceil(vals_per_word);
+    let q_max = (1 << bits) - 1;
+
+    // Transpose so that each output channel is contiguous.
+    let channels = weights.transposed().to_tensor();
+
+    let params = channel_params(channels.view(), options);
+
+    let mut data = NdTensor::zeros([n, words]);
+    for (row, &(scale, zero_point)) in params.iter().enumerate() {
+        for (col, &x) in channels.slice::<1, _>(row).iter().enumerate() {
+            let quant = quantize_value(x, scale, zero_point, q_max) as u32;
+            let shift = (col % vals_per_word) as u32 * bits;
+            let word = &mut data[[row, col / vals_per_word]];
+            *word = (*word as u32 | (quant << shift)) as i32;
+        }
+    }
+
+    QuantizedWeights {
+        data,
+        scales: NdTensor::from_data(
+            [n, 1],
+            params.iter().map(|(scale, _)| *scale).collect::<Vec<_>>(),
+        ),
+        zero_points: NdTensor::from_data(
+            [n, 1],
+            params.iter().map(|(_, zp)| *zp).collect::<Vec<_>>(),
+        ),
+        block_size: words * vals_per_word,
+    }
+}
+
+/// Replace `MatMul` operators which have constant float weights with
+/// `MatMulNBits` operators that use quantized weights.
+///
+/// The original weights are not removed from the graph. Returns the number
+/// of operators that were replaced.
+pub(crate) fn quantize_weights(
+    graph: &mut Graph,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    options: &QuantizeOptions,
+) -> usize {
+    let candidates: Vec<(NodeId, NodeId, NodeId)> = graph
+        .iter()
+        .filter_map(|(op_id, node)| {
+            let Node::Operator(op_node) = node else {
+                return None;
+            };
+            if op_node.operator().name() != "MatMul" || op_node.custom_op_type().is_some() {
+                return None;
+            }
+            let &[Some(input), Some(weights)] = op_node.inputs() else {
+                return None;
+            };
+            match graph.get_node(weights) {
+                Some(Node::Constant(Constant::Float(w)))
+                    if w.view().ndim() == 2 && w.view().len() >= options.min_elements =>
+                {
+                    Some((op_id, input, weights))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+
+    for &(op_id, input, weights_id) in &candidates {
+        let Some(weights @ Node::Constant(Constant::Float(w))) = graph.get_node(weights_id) else {
+            continue;
+        };
+        let quantized = quantize_matmul_weights(w.view().nd_view(), options);
+        let name = weights.name().map(|name| name.to_string());
+        let const_name = |suffix: &str| name.as_ref().map(|name| format!("{name}_{suffix}"));
+
+        let data_id = graph.add_constant(
+            const_name("quantized").as_deref(),
+            quantized.data.into_dyn(),
+        );
+        let scales_id =
+            graph.add_constant(const_name("scales").as_deref(), quantized.scales.into_dyn());
+        let zero_points_id = graph.add_constant(
+            const_name("zero_points").as_deref(),
+            quantized.zero_points.into_dyn(),
+        );
+
+        let op = MatMulNBits {
+            bits: options.bits,
+            block_size: quantized.block_size,
+        };
+        graph.replace_op(
+            op_id,
+            Box::new(op),
+            &[
+                Some(input),
+                Some(data_id),
+                Some(scales_id),
+                Some(zero_points_id),
+            ],
+        );
+        op_attrs.insert(
+            op_id,
+            OpAttrs::new()
+                .with("bits", options.bits)
+                .with("block_size", quantized.block_size as u32),
+        );
+    }
+
+    candidates.len()
+}
+
+/// An operator whose activations can be quantized.
+///
+/// See [activation_candidates].
+pub(crate) struct ActivationCandidate {
+    op_id: NodeId,
+
+    /// Type of the quantized operator.
+    qlinear_op: &'static str,
+
+    /// Attributes of the quantized operator.
+    attrs: OpAttrs,
+
+    input: NodeId,
+    weights: NodeId,
+    bias: Option<NodeId>,
+    output: NodeId,
+}
+
+/// Return the `MatMul` and `Conv` operators which can be replaced by
+/// `QLinearMatMul` and `QLinearConv`.
+///
+/// These are operators whose weights are float constants with at least
+/// [QuantizeOptions::min_elements] elements. `Conv` operators must have 2D
+/// kernels and a constant bias, if any.
+pub(crate) fn activation_candidates(
+    graph: &Graph,
+    op_attrs: &HashMap<NodeId, OpAttrs>,
+    options: &QuantizeOptions,
+) -> Vec<ActivationCandidate> {
+    let float_const_ndim = |id: NodeId| match graph.get_node(id) {
+        Some(Node::Constant(Constant::Float(w))) => Some(w.view().ndim()),
+        _ => None,
+    };
+    let is_weights = |id: NodeId, ndim: usize| match graph.get_node(id) {
+        Some(Node::Constant(Constant::Float(w))) => {
+            w.view().ndim() == ndim && w.view().len() >= options.min_elements
+        }
+        _ => false,
+    };
+
+    graph
+        .iter()
+        .filter_map(|(op_id, node)| {
+            let Node::Operator(op_node) = node else {
+                return None;
+            };
+            if op_node.custom_op_type().is_some() {
+                return None;
+            }
+            let &[Some(output)] = op_node.outputs() else {
+                return None;
+            };
+            let (qlinear_op, attrs, input, weights, bias) =
+                match (op_node.operator().name(), op_node.inputs()) {
+                    ("MatMul", &[Some(input), Some(weights)]) if is_weights(weights, 2) => {
+                        ("QLinearMatMul", OpAttrs::new(), input, weights, None)
+                    }
+                    ("Conv", &[Some(input), Some(weights), ref bias @ ..])
+                        if is_weights(weights, 4) =>
+                    {
+                        let bias = match bias {
+                            [] | [None] => None,
+                            [Some(bias)] if float_const_ndim(*bias) == Some(1) => Some(*bias),
+                            _ => return None,
+                        };
+                        let attrs = op_attrs.get(&op_id)?.clone();
+                        ("QLinearConv", attrs, input, weights, bias)
+                    }
+                    _ => return None,
+                };
+            Some(ActivationCandidate {
+                op_id,
+                qlinear_op,
+                attrs: attrs.with("output_dtype", "uint8"),
+                input,
+                weights,
+                bias,
+                output,
+            })
+        })
+        .collect()
+}
+
+/// Quantize `weights` with a separate scale and zero point for each index
+/// along `axis`, according to [QuantizeOptions::granularity].
+///
+/// Returns the quantized values, scales and zero points.
+fn quantize_per_channel(
+    weights: TensorView<f32>,
+    axis: usize,
+    options: &QuantizeOptions,
+) -> (Tensor<i32>, Vec<f32>, Vec<i32>) {
+    let q_max = (1 << options.bits) - 1;
+
+    // Move the channel axis first so that each channel is contiguous.
+    let mut channels = weights.view();
+    channels.move_axis(axis, 0);
+    let channels = channels.to_tensor();
+    let n_channels = channels.size(0);
+    let channel_len = channels.len().checked_div(n_channels).unwrap_or(0);
+    let params = channel_params(channels.reshaped([n_channels, channel_len]), options);
+
+    let mut quantized = channels.map(|_| 0);
+    for ((channel, quant_channel), &(scale, zero_point)) in channels
+        .data()
+        .unwrap()
+        .chunks(channel_len.max(1))
+        .zip(quantized.data_mut().unwrap().chunks_mut(channel_len.max(1)))
+        .zip(&params)
+    {
+        for (quant, &x) in quant_channel.iter_mut().zip(channel) {
+            *quant = quantize_value(x, scale, zero_point, q_max);
+        }
+    }
+    quantized.move_axis(0, axis);
+
+    (
+        quantized.to_tensor(),
+        params.iter().map(|(scale, _)| *scale).collect(),
+        params.iter().map(|(_, zp)| *zp).collect(),
+    )
+}
+
+/// Add an operator with a single output to `graph`, and return the ID of the
+/// output.
+fn add_op(
+    graph: &mut Graph,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    name: Option<String>,
+    op: Box<dyn Operator + Send + Sync>,
+    attrs: OpAttrs,
+    inputs: &[Option<NodeId>],
+) -> NodeId {
+    let output = graph.add_value(None, None);
+    let op_id = graph.add_op(name.as_deref(), op, inputs, &[Some(output)]);
+    op_attrs.insert(op_id, attrs);
+    output
+}
+
+/// Replace operators found by [activation_candidates] with quantized
+/// operators, using the calibrated activation ranges in `ranges`.
+///
+/// The input of each quantized operator is quantized to `uint8` using
+/// `Div`, `Round`, `Add`, `Clip` and `Cast` operators, and the output is
+/// dequantized using `Cast`, `Sub` and `Mul`. The final `Mul` replaces the
+/// original operator, which keeps its outputs, and the quantized operator
+/// takes the original operator's name. Candidates whose activations were not
+/// calibrated are skipped.
+///
+/// The original weights are not removed from the graph. Returns the number
+/// of operators that were replaced.
+pub(crate) fn quantize_activations(
+    graph: &mut Graph,
+    op_attrs: &mut HashMap<NodeId, OpAttrs>,
+    candidates: &[ActivationCandidate],
+    ranges: &HashMap<NodeId, (f32, f32)>,
+    options: &QuantizeOptions,
+) -> usize {
+    let registry = OpRegistry::with_all_ops();
+    let q_max = 255.;
+    let mut n_quantized = 0;
+
+    for candidate in candidates {
+        let (Some(&(in_min, in_max)), Some(&(out_min, out_max))) =
+            (ranges.get(&candidate.input), ranges.get(&candidate.output))
+        else {
+            continue;
+        };
+        let Ok(qlinear_op) = registry.read_op(candidate.qlinear_op, &candidate.attrs) else {
+            continue;
+        };
+        let float_const = |id: NodeId| match graph.get_node(id) {
+            Some(Node::Constant(Constant::Float(w))) => Some(w.view().to_tensor()),
+            _ => None,
+        };
+        let Some(weights) = float_const(candidate.weights) else {
+            continue;
+        };
+        let bias = candidate.bias.and_then(float_const);
+
+        let (x_scale, x_zero) = quant_params(in_min, in_max, 8);
+        let (y_scale, y_zero) = quant_params(out_min, out_max, 8);
+
+        // `MatMul` weights have shape `[K, N]` and `Conv` weights have shape
+        // `[M, C, kH, kW]`, where `N` and `M` are the output channels.
+        let channel_axis = if candidate.qlinear_op == "QLinearMatMul" {
+            1
+        } else {
+            0
+        };
+        let (w_quant, w_scales, w_zeros) =
+            quantize_per_channel(weights.view(), channel_axis, options);
+
+        // The bias is added to the `i32` accumulator, whose scale is the
+        // product of the input and weight scales.
+        let bias = bias.map(|bias| {
+            Tensor::from_vec(
+                bias.iter()
+                    .zip(&w_scales)
+                    .map(|(b, w_scale)| (b / (x_scale * w_scale)).round() as i32)
+                    .collect(),
+            )
+        });
+
+        let op_name = graph
+            .get_node(candidate.op_id)
+            .and_then(|node| node.name())
+            .map(|name| name.to_string());
+        let node_name = |suffix: &str| op_name.as_ref().map(|name| format!("{name}_{suffix}"));
+
+        let x_scale_id = graph.add_constant(None, Tensor::from_scalar(x_scale));
+        let x_zero_id = graph.add_constant(None, Tensor::from_scalar(x_zero));
+        let x_zero_float_id = graph.add_constant(None, Tensor::from_scalar(x_zero as f32));
+        let q_min_id = graph.add_constant(None, Tensor::from_scalar(0.));
+        let q_max_id = graph.add_constant(None, Tensor::from_scalar(q_max));
+        let w_quant_id = graph.add_constant(node_name("weights").as_deref(), w_quant);
+        let w_scales_id = graph.add_constant(None, Tensor::from_vec(w_scales));
+        let w_zeros_id = graph.add_constant(None, Tensor::from_vec(w_zeros));
+        let y_scale_id = graph.add_constant(None, Tensor::from_scalar(y_scale));
+        let y_zero_id = graph.add_constant(None, Tensor::from_scalar(y_zero));
+        let y_zero_float_id = graph.add_constant(None, Tensor::from_scalar(y_zero as f32));
+        let bias_id = bias.map(|bias| graph.add_constant(node_name("bias").as_deref(), bias));
+
+        // Quantize input.
+        let x = add_op(
+            graph,
+            op_attrs,
+            node_name("quantize_div"),
+            Box::new(Div {}),
+            OpAttrs::new(),
+            &[Some(candidate.input), Some(x_scale_id)],
+        );
+        let x = add_op(
+            graph,
+            op_attrs,
+            node_name("quantize_round"),
+            Box::new(Round {}),
+            OpAttrs::new(),
+            &[Some(x)],
+        );
+        let x = add_op(
+            graph,
+            op_attrs,
+            node_name("quantize_add"),
+            Box::new(Add {}),
+            OpAttrs::new(),
+            &[Some(x), Some(x_zero_float_id)],
+        );
+        let x = add_op(
+            graph,
+            op_attrs,
+            node_name("quantize_clip"),
+            Box::new(Clip {}),
+            OpAttrs::new(),
+            &[Some(x), Some(q_min_id), Some(q_max_id)],
+        );
+        let x_quant = add_op(
+            graph,
+            op_attrs,
+            node_name("quantize_cast"),
+            Box::new(Cast {
+                to: DataType::Int32,
+            }),
+            OpAttrs::new().with("to", "int32"),
+            &[Some(x)],
+        );
+
+        let mut qlinear_inputs = vec![
+            Some(x_quant),
+            Some(x_scale_id),
+            Some(x_zero_id),
+            Some(w_quant_id),
+            Some(w_scales_id),
+            Some(w_zeros_id),
+            Some(y_scale_id),
+            Some(y_zero_id),
+        ];
+        if bias_id.is_some() {
+            qlinear_inputs.push(bias_id);
+        }
+        let y_quant = add_op(
+            graph,
+            op_attrs,
+            op_name.clone(),
+            qlinear_op,
+            candidate.attrs.clone(),
+            &qlinear_inputs,
+        );
+
+        // Dequantize output.
+        let y = add_op(
+            graph,
+            op_attrs,
+            node_name("dequantize_cast"),
+            Box::new(Cast {
+                to: DataType::Float,
+            }),
+            OpAttrs::new().with("to", "float"),
+            &[Some(y_quant)],
+        );
+        let y = add_op(
+            graph,
+            op_attrs,
+            node_name("dequantize_sub"),
+            Box::new(Sub {}),
+            OpAttrs::new(),
+            &[Some(y), Some(y_zero_float_id)],
+        );
+        graph.replace_op(
+            candidate.op_id,
+            Box::new(Mul {}),
+            &[Some(y), Some(y_scale_id)],
+        );
+        graph.set_node_name(candidate.op_id, node_name("dequantize_mul").as_deref());
+        op_attrs.insert(candidate.op_id, OpAttrs::new());
+
+        n_quantized += 1;
+    }
+
+    n_quantized
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use super::{
+        merge_ranges, quantize_matmul_weights, quantize_model, value_range, CalibrationMethod,
+        Granularity, QuantizeError, QuantizeOptions,
+    };
+    use crate::model_builder::ModelBuilder;
+    use crate::op_registry::OpAttrs;
+    use crate::ops::{matmul, matmul_nbits};
+    use crate::{Model, TensorPool};
+
+    #[test]
+    fn test_value_range() {
+        let mut values: Vec<f32> = (1..=1000).map(|x| x as f32).collect();
+        assert_eq!(
+            value_range(&mut values, CalibrationMethod::MinMax),
+            (0., 1000.)
+        );
+
+        let mut values: Vec<f32> = (-500..=500).map(|x| x as f32).collect();
+        assert_eq!(
+            value_range(&mut values, CalibrationMethod::Percentile(99.)),
+            (-490., 490.)
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        let ranges = [(-1., 2.), (-3., 4.)];
+        assert_eq!(merge_ranges(&ranges, CalibrationMethod::MinMax), (-3., 4.));
+        assert_eq!(
+            merge_ranges(&ranges, CalibrationMethod::Percentile(99.)),
+            (-2., 3.)
+        );
+    }
+
+    #[test]
+    fn test_quantize_matmul_weights() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = TensorPool::new();
+        let weights = NdTensor::<f32, 2>::rand([30, 5], &mut rng).map(|x| x - 0.25);
+        let input = Tensor::<f32>::rand(&[4, 30], &mut rng);
+        let expected = matmul(&pool, input.view(), weights.as_dyn())?;
+
+        for (bits, granularity, tolerance) in [
+            (8, Granularity::PerChannel, 0.05),
+            (8, Granularity::PerTensor, 0.05),
+            (4, Granularity::PerChannel, 0.5),
+        ] {
+            let options = QuantizeOptions {
+                bits,
+                granularity,
+                ..Default::default()
+            };
+            let quantized = quantize_matmul_weights(weights.view(), &options);
+            let result = matmul_nbits(
+                &pool,
+                input.view(),
+                quantized.data.view(),
+                quantized.scales.view(),
+                Some(quantized.zero_points.view()),
+                bits,
+                quantized.block_size,
+            )?;
+            assert_eq!(result.shape(), expected.shape());
+            for (x, y) in result.iter().zip(expected.iter()) {
+                assert!((x - y).abs() < tolerance, "{x} != {y}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantize_model() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let weights = Tensor::<f32>::rand(&[64, 32], &mut rng);
+
+        let mut builder = ModelBuilder::new();
+        let input_id = builder.add_value("input", None);
+        let weights_id = builder.add_float_constant(&weights);
+        let output_id = builder.add_value("output", None);
+        builder.add_input(input_id);
+        builder.add_output(output_id);
+        builder
+            .add_operator(
+                "matmul",
+                "MatMul",
+                &OpAttrs::new(),
+                &[Some(input_id), Some(weights_id)],
+                &[output_id],
+            )
+            .unwrap();
+        let model = Model::load(builder.finish())?;
+
+        let input = Tensor::<f32>::rand(&[2, 64], &mut rng);
+        let validation_inputs = [vec![(model.input_ids()[0], input.view().into())]];
+        let quantized = quantize_model(&model, validation_inputs, &QuantizeOptions::default())?;
+
+        assert_eq!(quantized.quantized_weights, 1);
+        assert_eq!(quantized.max_output_error.len(), 1);
+        assert!(quantized.max_output_error[0] > 0.);
+        assert!(quantized.max_output_error[0] < 0.1);
+
+        let quantized_model = Model::load(quantized.data)?;
+        let matmul_id = quantized_model.node_id("matmul")?;
+        let attrs = quantized_model.operator_attrs(matmul_id).unwrap();
+        assert_eq!(attrs.require::<u32>("bits")?, 8);
+        assert!(quantized_model.total_params() < model.total_params());
+
+        let result = quantize_model(
+            &model,
+            [],
+            &QuantizeOptions {
+                bits: 3,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(QuantizeError::InvalidOptions(_))));
+
+        Ok(())
+    }
+    #[test]
+    fn test_quantize_model_activations() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let conv_weights = Tensor::<f32>::rand(&[4, 2, 3, 3], &mut rng).map(|x| x - 0.5);
+        let conv_bias = Tensor::<f32>::rand(&[4], &mut rng);
+        let matmul_weights = Tensor::<f32>::rand(&[8, 5], &mut rng).map(|x| x - 0.5);
+
+        let mut builder = ModelBuilder::new();
+        let input_id = builder.add_value("input", None);
+        let conv_weights_id = builder.add_float_constant(&conv_weights);
+        let conv_bias_id = builder.add_float_constant(&conv_bias);
+        let conv_out_id = builder.add_value("conv_out", None);
+        let matmul_weights_id = builder.add_float_constant(&matmul_weights);
+        let output_id = builder.add_value("output", None);
+        builder.add_input(input_id);
+        builder.add_output(output_id);
+        builder
+            .add_operator(
+                "conv",
+                "Conv",
+                &OpAttrs::new().with("pads", vec![1u32; 4]),
+                &[Some(input_id), Some(conv_weights_id), Some(conv_bias_id)],
+                &[conv_out_id],
+            )
+            .unwrap();
+        builder
+            .add_operator(
+                "matmul",
+                "MatMul",
+                &OpAttrs::new(),
+                &[Some(conv_out_id), Some(matmul_weights_id)],
+                &[output_id],
+            )
+            .unwrap();
+        let model = Model::load(builder.finish())?;
+
+        let inputs: Vec<_> = (0..4)
+            .map(|_| Tensor::<f32>::rand(&[1, 2, 8, 8], &mut rng))
+            .collect();
+        let calibration_inputs = inputs
+            .iter()
+            .map(|input| vec![(model.input_ids()[0], input.view().into())]);
+        let options = QuantizeOptions {
+            min_elements: 0,
+            quantize_activations: true,
+            ..Default::default()
+        };
+        let quantized = quantize_model(&model, calibration_inputs, &options)?;
+
+        assert_eq!(quantized.quantized_activations, 2);
+        assert_eq!(quantized.quantized_weights, 2);
+        assert!(quantized.max_output_error[0] > 0.);
+        assert!(quantized.max_output_error[0] < 0.1);
+
+        let quantized_model = Model::load(quantized.data)?;
+        for name in ["conv", "matmul"] {
+            let op_id = quantized_model.node_id(name)?;
+            let attrs = quantized_model.operator_attrs(op_id).unwrap();
+            assert_eq!(attrs.require::<&str>("output_dtype")?, "uint8");
+        }
+
+        let result = quantize_model(
+            &model,
+            [],
+            &QuantizeOptions {
+                bits: 4,
+                quantize_activations: true,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(QuantizeError::InvalidOptions(_))));
+
+        Ok(())
+    }
+}
@@ -18,13 +18,13 @@ pub const ENUM_MIN_OPERATOR_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_TYPE: u8 = 101;
+pub const ENUM_MAX_OPERATOR_TYPE: u8 = 144;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 102] = [
+pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 145] = [
     OperatorType::Add,
     OperatorType::ArgMin,
     OperatorType::ArgMax,
@@ -127,6 +127,49 @@ pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 102] = [
     OperatorType::RandomNormalLike,
     OperatorType::Softplus,
     OperatorType::GatherND,
+    OperatorType::ReduceLogSumExp,
+    OperatorType::EyeLike,
+    OperatorType::LpNormalization,
+    OperatorType::MeanVarianceNormalization,
+    OperatorType::GatherBlockQuantized,
+    OperatorType::GridSample,
+    OperatorType::MatMulNBits,
+    OperatorType::Normalize,
+    OperatorType::ChannelShuffle,
+    OperatorType::PermuteChannels,
+    OperatorType::ReduceVar,
+    OperatorType::ReduceStd,
+    OperatorType::GlobalMaxPool,
+    OperatorType::LpPool,
+    OperatorType::Dropout,
+    OperatorType::Gelu,
+    OperatorType::Mish,
+    OperatorType::Softsign,
+    OperatorType::Selu,
+    OperatorType::Celu,
+    OperatorType::ThresholdedRelu,
+    OperatorType::ImageToTensor,
+    OperatorType::BitwiseAnd,
+    OperatorType::BitwiseOr,
+    OperatorType::BitwiseXor,
+    OperatorType::BitwiseNot,
+    OperatorType::BitShift,
+    OperatorType::CastLike,
+    OperatorType::Multinomial,
+    OperatorType::Scan,
+    OperatorType::SequenceConstruct,
+    OperatorType::SequenceInsert,
+    OperatorType::SequenceAt,
+    OperatorType::SequenceLength,
+    OperatorType::ConcatFromSequence,
+    OperatorType::SplitToSequence,
+    OperatorType::Optional,
+    OperatorType::OptionalHasElement,
+    OperatorType::OptionalGetElement,
+    OperatorType::Custom,
+    OperatorType::QLinearConv,
+    OperatorType::QLinearMatMul,
+    OperatorType::ScaledDotProductAttention,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -236,9 +279,52 @@ impl OperatorType {
     pub const RandomNormalLike: Self = Self(99);
     pub const Softplus: Self = Self(100);
     pub const GatherND: Self = Self(101);
+    pub const ReduceLogSumExp: Self = Self(102);
+    pub const EyeLike: Self = Self(103);
+    pub const LpNormalization: Self = Self(104);
+    pub const MeanVarianceNormalization: Self = Self(105);
+    pub const GatherBlockQuantized: Self = Self(106);
+    pub const GridSample: Self = Self(107);
+    pub const MatMulNBits: Self = Self(108);
+    pub const Normalize: Self = Self(109);
+    pub const ChannelShuffle: Self = Self(110);
+    pub const PermuteChannels: Self = Self(111);
+    pub const ReduceVar: Self = Self(112);
+    pub const ReduceStd: Self = Self(113);
+    pub const GlobalMaxPool: Self = Self(114);
+    pub const LpPool: Self = Self(115);
+    pub const Dropout: Self = Self(116);
+    pub const Gelu: Self = Self(117);
+    pub const Mish: Self = Self(118);
+    pub const Softsign: Self = Self(119);
+    pub const Selu: Self = Self(120);
+    pub const Celu: Self = Self(121);
+    pub const ThresholdedRelu: Self = Self(122);
+    pub const ImageToTensor: Self = Self(123);
+    pub const BitwiseAnd: Self = Self(124);
+    pub const BitwiseOr: Self = Self(125);
+    pub const BitwiseXor: Self = Self(126);
+    pub const BitwiseNot: Self = Self(127);
+    pub const BitShift: Self = Self(128);
+    pub const CastLike: Self = Self(129);
+    pub const Multinomial: Self = Self(130);
+    pub const Scan: Self = Self(131);
+    pub const SequenceConstruct: Self = Self(132);
+    pub const SequenceInsert: Self = Self(133);
+    pub const SequenceAt: Self = Self(134);
+    pub const SequenceLength: Self = Self(135);
+    pub const ConcatFromSequence: Self = Self(136);
+    pub const SplitToSequence: Self = Self(137);
+    pub const Optional: Self = Self(138);
+    pub const OptionalHasElement: Self = Self(139);
+    pub const OptionalGetElement: Self = Self(140);
+    pub const Custom: Self = Self(141);
+    pub const QLinearConv: Self = Self(142);
+    pub const QLinearMatMul: Self = Self(143);
+    pub const ScaledDotProductAttention: Self = Self(144);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 101;
+    pub const ENUM_MAX: u8 = 144;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::Add,
         Self::ArgMin,
@@ -342,6 +428,49 @@ impl OperatorType {
         Self::RandomNormalLike,
         Self::Softplus,
         Self::GatherND,
+        Self::ReduceLogSumExp,
+        Self::EyeLike,
+        Self::LpNormalization,
+        Self::MeanVarianceNormalization,
+        Self::GatherBlockQuantized,
+        Self::GridSample,
+        Self::MatMulNBits,
+        Self::Normalize,
+        Self::ChannelShuffle,
+        Self::PermuteChannels,
+        Self::ReduceVar,
+        Self::ReduceStd,
+        Self::GlobalMaxPool,
+        Self::LpPool,
+        Self::Dropout,
+        Self::Gelu,
+        Self::Mish,
+        Self::Softsign,
+        Self::Selu,
+        Self::Celu,
+        Self::ThresholdedRelu,
+        Self::ImageToTensor,
+        Self::BitwiseAnd,
+        Self::BitwiseOr,
+        Self::BitwiseXor,
+        Self::BitwiseNot,
+        Self::BitShift,
+        Self::CastLike,
+        Self::Multinomial,
+        Self::Scan,
+        Self::SequenceConstruct,
+        Self::SequenceInsert,
+        Self::SequenceAt,
+        Self::SequenceLength,
+        Self::ConcatFromSequence,
+        Self::SplitToSequence,
+        Self::Optional,
+        Self::OptionalHasElement,
+        Self::OptionalGetElement,
+        Self::Custom,
+        Self::QLinearConv,
+        Self::QLinearMatMul,
+        Self::ScaledDotProductAttention,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -448,6 +577,49 @@ impl OperatorType {
             Self::RandomNormalLike => Some("RandomNormalLike"),
             Self::Softplus => Some("Softplus"),
             Self::GatherND => Some("GatherND"),
+            Self::ReduceLogSumExp => Some("ReduceLogSumExp"),
+            Self::EyeLike => Some("EyeLike"),
+            Self::LpNormalization => Some("LpNormalization"),
+            Self::MeanVarianceNormalization => Some("MeanVarianceNormalization"),
+            Self::GatherBlockQuantized => Some("GatherBlockQuantized"),
+            Self::GridSample => Some("GridSample"),
+            Self::MatMulNBits => Some("MatMulNBits"),
+            Self::Normalize => Some("Normalize"),
+            Self::ChannelShuffle => Some("ChannelShuffle"),
+            Self::PermuteChannels => Some("PermuteChannels"),
+            Self::ReduceVar => Some("ReduceVar"),
+            Self::ReduceStd => Some("ReduceStd"),
+            Self::GlobalMaxPool => Some("GlobalMaxPool"),
+            Self::LpPool => Some("LpPool"),
+            Self::Dropout => Some("Dropout"),
+            Self::Gelu => Some("Gelu"),
+            Self::Mish => Some("Mish"),
+            Self::Softsign => Some("Softsign"),
+            Self::Selu => Some("Selu"),
+            Self::Celu => Some("Celu"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
+            Self::ImageToTensor => Some("ImageToTensor"),
+            Self::BitwiseAnd => Some("BitwiseAnd"),
+            Self::BitwiseOr => Some("BitwiseOr"),
+            Self::BitwiseXor => Some("BitwiseXor"),
+            Self::BitwiseNot => Some("BitwiseNot"),
+            Self::BitShift => Some("BitShift"),
+            Self::CastLike => Some("CastLike"),
+            Self::Multinomial => Some("Multinomial"),
+            Self::Scan => Some("Scan"),
+            Self::SequenceConstruct => Some("SequenceConstruct"),
+            Self::SequenceInsert => Some("SequenceInsert"),
+            Self::SequenceAt => Some("SequenceAt"),
+            Self::SequenceLength => Some("SequenceLength"),
+            Self::ConcatFromSequence => Some("ConcatFromSequence"),
+            Self::SplitToSequence => Some("SplitToSequence"),
+            Self::Optional => Some("Optional"),
+            Self::OptionalHasElement => Some("OptionalHasElement"),
+            Self::OptionalGetElement => Some("OptionalGetElement"),
+            Self::Custom => Some("Custom"),
+            Self::QLinearConv => Some("QLinearConv"),
+            Self::QLinearMatMul => Some("QLinearMatMul"),
+            Self::ScaledDotProductAttention => Some("ScaledDotProductAttention"),
             _ => None,
         }
     }
@@ -697,13 +869,22 @@ pub const ENUM_MIN_DATA_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_DATA_TYPE: u8 = 1;
+pub const ENUM_MAX_DATA_TYPE: u8 = 7;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_DATA_TYPE: [DataType; 2] = [DataType::Int32, DataType::Float];
+pub const ENUM_VALUES_DATA_TYPE: [DataType; 8] = [
+    DataType::Int32,
+    DataType::Float,
+    DataType::Int64,
+    DataType::String,
+    DataType::Int8,
+    DataType::UInt8,
+    DataType::Bool,
+    DataType::BFloat16,
+];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
@@ -712,15 +893,36 @@ pub struct DataType(pub u8);
 impl DataType {
     pub const Int32: Self = Self(0);
     pub const Float: Self = Self(1);
+    pub const Int64: Self = Self(2);
+    pub const String: Self = Self(3);
+    pub const Int8: Self = Self(4);
+    pub const UInt8: Self = Self(5);
+    pub const Bool: Self = Self(6);
+    pub const BFloat16: Self = Self(7);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float];
+    pub const ENUM_MAX: u8 = 7;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int32,
+        Self::Float,
+        Self::Int64,
+        Self::String,
+        Self::Int8,
+        Self::UInt8,
+        Self::Bool,
+        Self::BFloat16,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::Int32 => Some("Int32"),
             Self::Float => Some("Float"),
+            Self::Int64 => Some("Int64"),
+            Self::String => Some("String"),
+            Self::Int8 => Some("Int8"),
+            Self::UInt8 => Some("UInt8"),
+            Self::Bool => Some("Bool"),
+            Self::BFloat16 => Some("BFloat16"),
             _ => None,
         }
     }
@@ -786,16 +988,18 @@ pub const ENUM_MIN_COORD_TRANSFORM_MODE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_COORD_TRANSFORM_MODE: u8 = 2;
+pub const ENUM_MAX_COORD_TRANSFORM_MODE: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COORD_TRANSFORM_MODE: [CoordTransformMode; 3] = [
+pub const ENUM_VALUES_COORD_TRANSFORM_MODE: [CoordTransformMode; 5] = [
     CoordTransformMode::HalfPixel,
     CoordTransformMode::Asymmetric,
     CoordTransformMode::AlignCorners,
+    CoordTransformMode::PytorchHalfPixel,
+    CoordTransformMode::TfCropAndResize,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -806,17 +1010,26 @@ impl CoordTransformMode {
     pub const HalfPixel: Self = Self(0);
     pub const Asymmetric: Self = Self(1);
     pub const AlignCorners: Self = Self(2);
+    pub const PytorchHalfPixel: Self = Self(3);
+    pub const TfCropAndResize: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] =
-        &[Self::HalfPixel, Self::Asymmetric, Self::AlignCorners];
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::HalfPixel,
+        Self::Asymmetric,
+        Self::AlignCorners,
+        Self::PytorchHalfPixel,
+        Self::TfCropAndResize,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::HalfPixel => Some("HalfPixel"),
             Self::Asymmetric => Some("Asymmetric"),
             Self::AlignCorners => Some("AlignCorners"),
+            Self::PytorchHalfPixel => Some("PytorchHalfPixel"),
+            Self::TfCropAndResize => Some("TfCropAndResize"),
             _ => None,
         }
     }
@@ -1074,13 +1287,13 @@ pub const ENUM_MIN_OPERATOR_ATTRS: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 36;
+pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 61;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 37] = [
+pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 62] = [
     OperatorAttrs::NONE,
     OperatorAttrs::ArgMaxAttrs,
     OperatorAttrs::AveragePoolAttrs,
@@ -1118,6 +1331,31 @@ pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 37] = [
     OperatorAttrs::RandomNormalAttrs,
     OperatorAttrs::RandomNormalLikeAttrs,
     OperatorAttrs::GatherNDAttrs,
+    OperatorAttrs::EyeLikeAttrs,
+    OperatorAttrs::PadAttrs,
+    OperatorAttrs::LpNormalizationAttrs,
+    OperatorAttrs::MeanVarianceNormalizationAttrs,
+    OperatorAttrs::GatherBlockQuantizedAttrs,
+    OperatorAttrs::GridSampleAttrs,
+    OperatorAttrs::MatMulNBitsAttrs,
+    OperatorAttrs::NormalizeAttrs,
+    OperatorAttrs::ChannelShuffleAttrs,
+    OperatorAttrs::PermuteChannelsAttrs,
+    OperatorAttrs::ReduceVarAttrs,
+    OperatorAttrs::LpPoolAttrs,
+    OperatorAttrs::GeluAttrs,
+    OperatorAttrs::SeluAttrs,
+    OperatorAttrs::ImageToTensorAttrs,
+    OperatorAttrs::BitShiftAttrs,
+    OperatorAttrs::MultinomialAttrs,
+    OperatorAttrs::DropoutAttrs,
+    OperatorAttrs::ScanAttrs,
+    OperatorAttrs::ConcatFromSequenceAttrs,
+    OperatorAttrs::SplitToSequenceAttrs,
+    OperatorAttrs::CustomOperatorAttrs,
+    OperatorAttrs::QLinearConvAttrs,
+    OperatorAttrs::QLinearMatMulAttrs,
+    OperatorAttrs::ScaledDotProductAttentionAttrs,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1162,9 +1400,34 @@ impl OperatorAttrs {
     pub const RandomNormalAttrs: Self = Self(34);
     pub const RandomNormalLikeAttrs: Self = Self(35);
     pub const GatherNDAttrs: Self = Self(36);
+    pub const EyeLikeAttrs: Self = Self(37);
+    pub const PadAttrs: Self = Self(38);
+    pub const LpNormalizationAttrs: Self = Self(39);
+    pub const MeanVarianceNormalizationAttrs: Self = Self(40);
+    pub const GatherBlockQuantizedAttrs: Self = Self(41);
+    pub const GridSampleAttrs: Self = Self(42);
+    pub const MatMulNBitsAttrs: Self = Self(43);
+    pub const NormalizeAttrs: Self = Self(44);
+    pub const ChannelShuffleAttrs: Self = Self(45);
+    pub const PermuteChannelsAttrs: Self = Self(46);
+    pub const ReduceVarAttrs: Self = Self(47);
+    pub const LpPoolAttrs: Self = Self(48);
+    pub const GeluAttrs: Self = Self(49);
+    pub const SeluAttrs: Self = Self(50);
+    pub const ImageToTensorAttrs: Self = Self(51);
+    pub const BitShiftAttrs: Self = Self(52);
+    pub const MultinomialAttrs: Self = Self(53);
+    pub const DropoutAttrs: Self = Self(54);
+    pub const ScanAttrs: Self = Self(55);
+    pub const ConcatFromSequenceAttrs: Self = Self(56);
+    pub const SplitToSequenceAttrs: Self = Self(57);
+    pub const CustomOperatorAttrs: Self = Self(58);
+    pub const QLinearConvAttrs: Self = Self(59);
+    pub const QLinearMatMulAttrs: Self = Self(60);
+    pub const ScaledDotProductAttentionAttrs: Self = Self(61);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 36;
+    pub const ENUM_MAX: u8 = 61;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ArgMaxAttrs,
@@ -1203,6 +1466,31 @@ impl OperatorAttrs {
         Self::RandomNormalAttrs,
         Self::RandomNormalLikeAttrs,
         Self::GatherNDAttrs,
+        Self::EyeLikeAttrs,
+        Self::PadAttrs,
+        Self::LpNormalizationAttrs,
+        Self::MeanVarianceNormalizationAttrs,
+        Self::GatherBlockQuantizedAttrs,
+        Self::GridSampleAttrs,
+        Self::MatMulNBitsAttrs,
+        Self::NormalizeAttrs,
+        Self::ChannelShuffleAttrs,
+        Self::PermuteChannelsAttrs,
+        Self::ReduceVarAttrs,
+        Self::LpPoolAttrs,
+        Self::GeluAttrs,
+        Self::SeluAttrs,
+        Self::ImageToTensorAttrs,
+        Self::BitShiftAttrs,
+        Self::MultinomialAttrs,
+        Self::DropoutAttrs,
+        Self::ScanAttrs,
+        Self::ConcatFromSequenceAttrs,
+        Self::SplitToSequenceAttrs,
+        Self::CustomOperatorAttrs,
+        Self::QLinearConvAttrs,
+        Self::QLinearMatMulAttrs,
+        Self::ScaledDotProductAttentionAttrs,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -1244,6 +1532,31 @@ impl OperatorAttrs {
             Self::RandomNormalAttrs => Some("RandomNormalAttrs"),
             Self::RandomNormalLikeAttrs => Some("RandomNormalLikeAttrs"),
             Self::GatherNDAttrs => Some("GatherNDAttrs"),
+            Self::EyeLikeAttrs => Some("EyeLikeAttrs"),
+            Self::PadAttrs => Some("PadAttrs"),
+            Self::LpNormalizationAttrs => Some("LpNormalizationAttrs"),
+            Self::MeanVarianceNormalizationAttrs => Some("MeanVarianceNormalizationAttrs"),
+            Self::GatherBlockQuantizedAttrs => Some("GatherBlockQuantizedAttrs"),
+            Self::GridSampleAttrs => Some("GridSampleAttrs"),
+            Self::MatMulNBitsAttrs => Some("MatMulNBitsAttrs"),
+            Self::NormalizeAttrs => Some("NormalizeAttrs"),
+            Self::ChannelShuffleAttrs => Some("ChannelShuffleAttrs"),
+            Self::PermuteChannelsAttrs => Some("PermuteChannelsAttrs"),
+            Self::ReduceVarAttrs => Some("ReduceVarAttrs"),
+            Self::LpPoolAttrs => Some("LpPoolAttrs"),
+            Self::GeluAttrs => Some("GeluAttrs"),
+            Self::SeluAttrs => Some("SeluAttrs"),
+            Self::ImageToTensorAttrs => Some("ImageToTensorAttrs"),
+            Self::BitShiftAttrs => Some("BitShiftAttrs"),
+            Self::MultinomialAttrs => Some("MultinomialAttrs"),
+            Self::DropoutAttrs => Some("DropoutAttrs"),
+            Self::ScanAttrs => Some("ScanAttrs"),
+            Self::ConcatFromSequenceAttrs => Some("ConcatFromSequenceAttrs"),
+            Self::SplitToSequenceAttrs => Some("SplitToSequenceAttrs"),
+            Self::CustomOperatorAttrs => Some("CustomOperatorAttrs"),
+            Self::QLinearConvAttrs => Some("QLinearConvAttrs"),
+            Self::QLinearMatMulAttrs => Some("QLinearMatMulAttrs"),
+            Self::ScaledDotProductAttentionAttrs => Some("ScaledDotProductAttentionAttrs"),
             _ => None,
         }
     }
@@ -1399,43 +1712,41 @@ pub struct ScalarUnionTableOffset {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_NMSBOX_ORDER: u8 = 0;
+pub const ENUM_MIN_BIT_SHIFT_DIRECTION: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_NMSBOX_ORDER: u8 = 1;
+pub const ENUM_MAX_BIT_SHIFT_DIRECTION: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_NMSBOX_ORDER: [NMSBoxOrder; 2] = [
-    NMSBoxOrder::TopLeftBottomRight,
-    NMSBoxOrder::CenterWidthHeight,
-];
+pub const ENUM_VALUES_BIT_SHIFT_DIRECTION: [BitShiftDirection; 2] =
+    [BitShiftDirection::Left, BitShiftDirection::Right];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct NMSBoxOrder(pub u8);
+pub struct BitShiftDirection(pub u8);
 #[allow(non_upper_case_globals)]
-impl NMSBoxOrder {
-    pub const TopLeftBottomRight: Self = Self(0);
-    pub const CenterWidthHeight: Self = Self(1);
+impl BitShiftDirection {
+    pub const Left: Self = Self(0);
+    pub const Right: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
     pub const ENUM_MAX: u8 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::TopLeftBottomRight, Self::CenterWidthHeight];
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Left, Self::Right];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::TopLeftBottomRight => Some("TopLeftBottomRight"),
-            Self::CenterWidthHeight => Some("CenterWidthHeight"),
+            Self::Left => Some("Left"),
+            Self::Right => Some("Right"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for NMSBoxOrder {
+impl core::fmt::Debug for BitShiftDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1444,7 +1755,7 @@ impl core::fmt::Debug for NMSBoxOrder {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
+impl<'a> flatbuffers::Follow<'a> for BitShiftDirection {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1453,15 +1764,15 @@ impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
     }
 }
 
-impl flatbuffers::Push for NMSBoxOrder {
-    type Output = NMSBoxOrder;
+impl flatbuffers::Push for BitShiftDirection {
+    type Output = BitShiftDirection;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for NMSBoxOrder {
+impl flatbuffers::EndianScalar for BitShiftDirection {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1475,7 +1786,7 @@ impl flatbuffers::EndianScalar for NMSBoxOrder {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
+impl<'a> flatbuffers::Verifiable for BitShiftDirection {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1486,58 +1797,63 @@ impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for NMSBoxOrder {}
+impl flatbuffers::SimpleToVerifyInSlice for BitShiftDirection {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
+pub const ENUM_MIN_CUSTOM_ATTR_TYPE: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_SCATTER_REDUCTION: u8 = 4;
+pub const ENUM_MAX_CUSTOM_ATTR_TYPE: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_SCATTER_REDUCTION: [ScatterReduction; 5] = [
-    ScatterReduction::None,
-    ScatterReduction::Add,
-    ScatterReduction::Mul,
-    ScatterReduction::Min,
-    ScatterReduction::Max,
+pub const ENUM_VALUES_CUSTOM_ATTR_TYPE: [CustomAttrType; 5] = [
+    CustomAttrType::Int,
+    CustomAttrType::Float,
+    CustomAttrType::String,
+    CustomAttrType::Ints,
+    CustomAttrType::Floats,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ScatterReduction(pub u8);
+pub struct CustomAttrType(pub u8);
 #[allow(non_upper_case_globals)]
-impl ScatterReduction {
-    pub const None: Self = Self(0);
-    pub const Add: Self = Self(1);
-    pub const Mul: Self = Self(2);
-    pub const Min: Self = Self(3);
-    pub const Max: Self = Self(4);
+impl CustomAttrType {
+    pub const Int: Self = Self(0);
+    pub const Float: Self = Self(1);
+    pub const String: Self = Self(2);
+    pub const Ints: Self = Self(3);
+    pub const Floats: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
     pub const ENUM_MAX: u8 = 4;
-    pub const ENUM_VALUES: &'static [Self] =
-        &[Self::None, Self::Add, Self::Mul, Self::Min, Self::Max];
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int,
+        Self::Float,
+        Self::String,
+        Self::Ints,
+        Self::Floats,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::None => Some("None"),
-            Self::Add => Some("Add"),
-            Self::Mul => Some("Mul"),
-            Self::Min => Some("Min"),
-            Self::Max => Some("Max"),
+            Self::Int => Some("Int"),
+            Self::Float => Some("Float"),
+            Self::String => Some("String"),
+            Self::Ints => Some("Ints"),
+            Self::Floats => Some("Floats"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for ScatterReduction {
+impl core::fmt::Debug for CustomAttrType {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1546,7 +1862,7 @@ impl core::fmt::Debug for ScatterReduction {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
+impl<'a> flatbuffers::Follow<'a> for CustomAttrType {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1555,15 +1871,15 @@ impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
     }
 }
 
-impl flatbuffers::Push for ScatterReduction {
-    type Output = ScatterReduction;
+impl flatbuffers::Push for CustomAttrType {
+    type Output = CustomAttrType;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for ScatterReduction {
+impl flatbuffers::EndianScalar for CustomAttrType {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1577,7 +1893,7 @@ impl flatbuffers::EndianScalar for ScatterReduction {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ScatterReduction {
+impl<'a> flatbuffers::Verifiable for CustomAttrType {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1588,59 +1904,51 @@ impl<'a> flatbuffers::Verifiable for ScatterReduction {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ScatterReduction {}
+impl flatbuffers::SimpleToVerifyInSlice for CustomAttrType {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_NODE_KIND: u8 = 0;
+pub const ENUM_MIN_GRID_SAMPLE_PADDING: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_NODE_KIND: u8 = 3;
+pub const ENUM_MAX_GRID_SAMPLE_PADDING: u8 = 2;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_NODE_KIND: [NodeKind; 4] = [
-    NodeKind::NONE,
-    NodeKind::OperatorNode,
-    NodeKind::ConstantNode,
-    NodeKind::ValueNode,
+pub const ENUM_VALUES_GRID_SAMPLE_PADDING: [GridSamplePadding; 3] = [
+    GridSamplePadding::Zeros,
+    GridSamplePadding::Border,
+    GridSamplePadding::Reflection,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct NodeKind(pub u8);
+pub struct GridSamplePadding(pub u8);
 #[allow(non_upper_case_globals)]
-impl NodeKind {
-    pub const NONE: Self = Self(0);
-    pub const OperatorNode: Self = Self(1);
-    pub const ConstantNode: Self = Self(2);
-    pub const ValueNode: Self = Self(3);
+impl GridSamplePadding {
+    pub const Zeros: Self = Self(0);
+    pub const Border: Self = Self(1);
+    pub const Reflection: Self = Self(2);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 3;
-    pub const ENUM_VALUES: &'static [Self] = &[
-        Self::NONE,
-        Self::OperatorNode,
-        Self::ConstantNode,
-        Self::ValueNode,
-    ];
+    pub const ENUM_MAX: u8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Zeros, Self::Border, Self::Reflection];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::NONE => Some("NONE"),
-            Self::OperatorNode => Some("OperatorNode"),
-            Self::ConstantNode => Some("ConstantNode"),
-            Self::ValueNode => Some("ValueNode"),
+            Self::Zeros => Some("Zeros"),
+            Self::Border => Some("Border"),
+            Self::Reflection => Some("Reflection"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for NodeKind {
+impl core::fmt::Debug for GridSamplePadding {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1649,7 +1957,7 @@ impl core::fmt::Debug for NodeKind {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for NodeKind {
+impl<'a> flatbuffers::Follow<'a> for GridSamplePadding {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1658,15 +1966,15 @@ impl<'a> flatbuffers::Follow<'a> for NodeKind {
     }
 }
 
-impl flatbuffers::Push for NodeKind {
-    type Output = NodeKind;
+impl flatbuffers::Push for GridSamplePadding {
+    type Output = GridSamplePadding;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for NodeKind {
+impl flatbuffers::EndianScalar for GridSamplePadding {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1680,7 +1988,7 @@ impl flatbuffers::EndianScalar for NodeKind {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for NodeKind {
+impl<'a> flatbuffers::Verifiable for GridSamplePadding {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1691,53 +1999,48 @@ impl<'a> flatbuffers::Verifiable for NodeKind {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for NodeKind {}
-pub struct NodeKindUnionTableOffset {}
-
+impl flatbuffers::SimpleToVerifyInSlice for GridSamplePadding {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_CONSTANT_DATA: u8 = 0;
+pub const ENUM_MIN_NMSBOX_ORDER: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_CONSTANT_DATA: u8 = 2;
+pub const ENUM_MAX_NMSBOX_ORDER: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 3] = [
-    ConstantData::NONE,
-    ConstantData::FloatData,
-    ConstantData::IntData,
+pub const ENUM_VALUES_NMSBOX_ORDER: [NMSBoxOrder; 2] = [
+    NMSBoxOrder::TopLeftBottomRight,
+    NMSBoxOrder::CenterWidthHeight,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ConstantData(pub u8);
+pub struct NMSBoxOrder(pub u8);
 #[allow(non_upper_case_globals)]
-impl ConstantData {
-    pub const NONE: Self = Self(0);
-    pub const FloatData: Self = Self(1);
-    pub const IntData: Self = Self(2);
+impl NMSBoxOrder {
+    pub const TopLeftBottomRight: Self = Self(0);
+    pub const CenterWidthHeight: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::FloatData, Self::IntData];
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::TopLeftBottomRight, Self::CenterWidthHeight];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::NONE => Some("NONE"),
-            Self::FloatData => Some("FloatData"),
-            Self::IntData => Some("IntData"),
+            Self::TopLeftBottomRight => Some("TopLeftBottomRight"),
+            Self::CenterWidthHeight => Some("CenterWidthHeight"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for ConstantData {
+impl core::fmt::Debug for NMSBoxOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1746,7 +2049,7 @@ impl core::fmt::Debug for ConstantData {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for ConstantData {
+impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1755,15 +2058,15 @@ impl<'a> flatbuffers::Follow<'a> for ConstantData {
     }
 }
 
-impl flatbuffers::Push for ConstantData {
-    type Output = ConstantData;
+impl flatbuffers::Push for NMSBoxOrder {
+    type Output = NMSBoxOrder;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for ConstantData {
+impl flatbuffers::EndianScalar for NMSBoxOrder {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1777,7 +2080,7 @@ impl flatbuffers::EndianScalar for ConstantData {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ConstantData {
+impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1788,576 +2091,524 @@ impl<'a> flatbuffers::Verifiable for ConstantData {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ConstantData {}
-pub struct ConstantDataUnionTableOffset {}
+impl flatbuffers::SimpleToVerifyInSlice for NMSBoxOrder {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_PAD_OP_MODE: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_PAD_OP_MODE: u8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PAD_OP_MODE: [PadOpMode; 4] = [
+    PadOpMode::Constant,
+    PadOpMode::Reflect,
+    PadOpMode::Edge,
+    PadOpMode::Wrap,
+];
 
-pub enum ArgMaxAttrsOffset {}
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PadOpMode(pub u8);
+#[allow(non_upper_case_globals)]
+impl PadOpMode {
+    pub const Constant: Self = Self(0);
+    pub const Reflect: Self = Self(1);
+    pub const Edge: Self = Self(2);
+    pub const Wrap: Self = Self(3);
 
-pub struct ArgMaxAttrs<'a> {
-    pub _tab: flatbuffers::Table<'a>,
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 3;
+    pub const ENUM_VALUES: &'static [Self] =
+        &[Self::Constant, Self::Reflect, Self::Edge, Self::Wrap];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Constant => Some("Constant"),
+            Self::Reflect => Some("Reflect"),
+            Self::Edge => Some("Edge"),
+            Self::Wrap => Some("Wrap"),
+            _ => None,
+        }
+    }
 }
-
-impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
-    type Inner = ArgMaxAttrs<'a>;
+impl core::fmt::Debug for PadOpMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for PadOpMode {
+    type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl<'a> ArgMaxAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
-
+impl flatbuffers::Push for PadOpMode {
+    type Output = PadOpMode;
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ArgMaxAttrs { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ArgMaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ArgMaxAttrs<'bldr>> {
-        let mut builder = ArgMaxAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_keep_dims(args.keep_dims);
-        builder.finish()
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
+}
 
+impl flatbuffers::EndianScalar for PadOpMode {
+    type Scalar = u8;
     #[inline]
-    pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ArgMaxAttrs::VT_AXIS, Some(0)).unwrap() }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
     }
     #[inline]
-    pub fn keep_dims(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, Some(false))
-                .unwrap()
-        }
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl flatbuffers::Verifiable for ArgMaxAttrs<'_> {
+impl<'a> flatbuffers::Verifiable for PadOpMode {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
-            .finish();
-        Ok(())
-    }
-}
-pub struct ArgMaxAttrsArgs {
-    pub axis: i32,
-    pub keep_dims: bool,
-}
-impl<'a> Default for ArgMaxAttrsArgs {
-    #[inline]
-    fn default() -> Self {
-        ArgMaxAttrsArgs {
-            axis: 0,
-            keep_dims: false,
-        }
+        u8::run_verifier(v, pos)
     }
 }
 
-pub struct ArgMaxAttrsBuilder<'a: 'b, 'b> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> ArgMaxAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(ArgMaxAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
-        self.fbb_
-            .push_slot::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, keep_dims, false);
-    }
-    #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ArgMaxAttrsBuilder<'a, 'b> {
-        let start = _fbb.start_table();
-        ArgMaxAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for PadOpMode {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_SCATTER_REDUCTION: u8 = 4;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SCATTER_REDUCTION: [ScatterReduction; 5] = [
+    ScatterReduction::None,
+    ScatterReduction::Add,
+    ScatterReduction::Mul,
+    ScatterReduction::Min,
+    ScatterReduction::Max,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ScatterReduction(pub u8);
+#[allow(non_upper_case_globals)]
+impl ScatterReduction {
+    pub const None: Self = Self(0);
+    pub const Add: Self = Self(1);
+    pub const Mul: Self = Self(2);
+    pub const Min: Self = Self(3);
+    pub const Max: Self = Self(4);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] =
+        &[Self::None, Self::Add, Self::Mul, Self::Min, Self::Max];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::None => Some("None"),
+            Self::Add => Some("Add"),
+            Self::Mul => Some("Mul"),
+            Self::Min => Some("Min"),
+            Self::Max => Some("Max"),
+            _ => None,
         }
     }
-    #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ArgMaxAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
-    }
 }
-
-impl core::fmt::Debug for ArgMaxAttrs<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ArgMaxAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("keep_dims", &self.keep_dims());
-        ds.finish()
+impl core::fmt::Debug for ScatterReduction {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
     }
 }
-pub enum AveragePoolAttrsOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct AveragePoolAttrs<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
-    type Inner = AveragePoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
+    type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl<'a> AveragePoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
-
+impl flatbuffers::Push for ScatterReduction {
+    type Output = ScatterReduction;
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        AveragePoolAttrs { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args AveragePoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<AveragePoolAttrs<'bldr>> {
-        let mut builder = AveragePoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
-        }
-        builder.add_count_include_pad(args.count_include_pad);
-        builder.add_pad_mode(args.pad_mode);
-        builder.finish()
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
+}
 
+impl flatbuffers::EndianScalar for ScatterReduction {
+    type Scalar = u8;
     #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    AveragePoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pad_mode(&self) -> PadMode {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<PadMode>(AveragePoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    AveragePoolAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    AveragePoolAttrs::VT_STRIDES,
-                    None,
-                )
-        }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
     }
     #[inline]
-    pub fn count_include_pad(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(AveragePoolAttrs::VT_COUNT_INCLUDE_PAD, Some(false))
-                .unwrap()
-        }
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
+impl<'a> flatbuffers::Verifiable for ScatterReduction {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "kernel_size",
-                Self::VT_KERNEL_SIZE,
-                true,
-            )?
-            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<bool>("count_include_pad", Self::VT_COUNT_INCLUDE_PAD, false)?
-            .finish();
-        Ok(())
-    }
-}
-pub struct AveragePoolAttrsArgs<'a> {
-    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub pad_mode: PadMode,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub count_include_pad: bool,
-}
-impl<'a> Default for AveragePoolAttrsArgs<'a> {
-    #[inline]
-    fn default() -> Self {
-        AveragePoolAttrsArgs {
-            kernel_size: None, // required field
-            pad_mode: PadMode::Same,
-            pads: None,
-            strides: None,
-            count_include_pad: false,
-        }
+        u8::run_verifier(v, pos)
     }
 }
 
-pub struct AveragePoolAttrsBuilder<'a: 'b, 'b> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> AveragePoolAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_kernel_size(
-        &mut self,
-        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            AveragePoolAttrs::VT_KERNEL_SIZE,
-            kernel_size,
-        );
-    }
-    #[inline]
-    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
-        self.fbb_
-            .push_slot::<PadMode>(AveragePoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(AveragePoolAttrs::VT_PADS, pads);
-    }
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(AveragePoolAttrs::VT_STRIDES, strides);
-    }
-    #[inline]
-    pub fn add_count_include_pad(&mut self, count_include_pad: bool) {
-        self.fbb_.push_slot::<bool>(
-            AveragePoolAttrs::VT_COUNT_INCLUDE_PAD,
-            count_include_pad,
-            false,
-        );
-    }
-    #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> AveragePoolAttrsBuilder<'a, 'b> {
-        let start = _fbb.start_table();
-        AveragePoolAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for ScatterReduction {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_NODE_KIND: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_NODE_KIND: u8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_NODE_KIND: [NodeKind; 4] = [
+    NodeKind::NONE,
+    NodeKind::OperatorNode,
+    NodeKind::ConstantNode,
+    NodeKind::ValueNode,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct NodeKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl NodeKind {
+    pub const NONE: Self = Self(0);
+    pub const OperatorNode: Self = Self(1);
+    pub const ConstantNode: Self = Self(2);
+    pub const ValueNode: Self = Self(3);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::OperatorNode,
+        Self::ConstantNode,
+        Self::ValueNode,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::OperatorNode => Some("OperatorNode"),
+            Self::ConstantNode => Some("ConstantNode"),
+            Self::ValueNode => Some("ValueNode"),
+            _ => None,
         }
     }
-    #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<AveragePoolAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, AveragePoolAttrs::VT_KERNEL_SIZE, "kernel_size");
-        flatbuffers::WIPOffset::new(o.value())
-    }
 }
-
-impl core::fmt::Debug for AveragePoolAttrs<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("AveragePoolAttrs");
-        ds.field("kernel_size", &self.kernel_size());
-        ds.field("pad_mode", &self.pad_mode());
-        ds.field("pads", &self.pads());
-        ds.field("strides", &self.strides());
-        ds.field("count_include_pad", &self.count_include_pad());
-        ds.finish()
+impl core::fmt::Debug for NodeKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
     }
 }
-pub enum BatchNormalizationAttrsOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct BatchNormalizationAttrs<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for BatchNormalizationAttrs<'a> {
-    type Inner = BatchNormalizationAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for NodeKind {
+    type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl<'a> BatchNormalizationAttrs<'a> {
-    pub const VT_EPSILON: flatbuffers::VOffsetT = 4;
-
+impl flatbuffers::Push for NodeKind {
+    type Output = NodeKind;
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        BatchNormalizationAttrs { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args BatchNormalizationAttrsArgs,
-    ) -> flatbuffers::WIPOffset<BatchNormalizationAttrs<'bldr>> {
-        let mut builder = BatchNormalizationAttrsBuilder::new(_fbb);
-        builder.add_epsilon(args.epsilon);
-        builder.finish()
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
+}
 
+impl flatbuffers::EndianScalar for NodeKind {
+    type Scalar = u8;
     #[inline]
-    pub fn epsilon(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(BatchNormalizationAttrs::VT_EPSILON, Some(0.0))
-                .unwrap()
-        }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl flatbuffers::Verifiable for BatchNormalizationAttrs<'_> {
+impl<'a> flatbuffers::Verifiable for NodeKind {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
-            .finish();
-        Ok(())
-    }
-}
-pub struct BatchNormalizationAttrsArgs {
-    pub epsilon: f32,
-}
-impl<'a> Default for BatchNormalizationAttrsArgs {
-    #[inline]
-    fn default() -> Self {
-        BatchNormalizationAttrsArgs { epsilon: 0.0 }
+        u8::run_verifier(v, pos)
     }
 }
 
-pub struct BatchNormalizationAttrsBuilder<'a: 'b, 'b> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> BatchNormalizationAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_epsilon(&mut self, epsilon: f32) {
-        self.fbb_
-            .push_slot::<f32>(BatchNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+impl flatbuffers::SimpleToVerifyInSlice for NodeKind {}
+pub struct NodeKindUnionTableOffset {}
+
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_COMPRESSION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_COMPRESSION: u8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COMPRESSION: [Compression; 2] = [Compression::Zstd, Compression::Lz4];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Compression(pub u8);
+#[allow(non_upper_case_globals)]
+impl Compression {
+    pub const Zstd: Self = Self(0);
+    pub const Lz4: Self = Self(1);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Zstd, Self::Lz4];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Zstd => Some("Zstd"),
+            Self::Lz4 => Some("Lz4"),
+            _ => None,
+        }
     }
-    #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> BatchNormalizationAttrsBuilder<'a, 'b> {
-        let start = _fbb.start_table();
-        BatchNormalizationAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
+}
+impl core::fmt::Debug for Compression {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
         }
     }
+}
+impl<'a> flatbuffers::Follow<'a> for Compression {
+    type Inner = Self;
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<BatchNormalizationAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl core::fmt::Debug for BatchNormalizationAttrs<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("BatchNormalizationAttrs");
-        ds.field("epsilon", &self.epsilon());
-        ds.finish()
+impl flatbuffers::Push for Compression {
+    type Output = Compression;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
-pub enum CastAttrsOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct CastAttrs<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
 
-impl<'a> flatbuffers::Follow<'a> for CastAttrs<'a> {
-    type Inner = CastAttrs<'a>;
+impl flatbuffers::EndianScalar for Compression {
+    type Scalar = u8;
     #[inline]
-    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl<'a> CastAttrs<'a> {
-    pub const VT_TO: flatbuffers::VOffsetT = 4;
-
-    #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        CastAttrs { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args CastAttrsArgs,
-    ) -> flatbuffers::WIPOffset<CastAttrs<'bldr>> {
-        let mut builder = CastAttrsBuilder::new(_fbb);
-        builder.add_to(args.to);
-        builder.finish()
-    }
-
-    #[inline]
-    pub fn to(&self) -> DataType {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<DataType>(CastAttrs::VT_TO, Some(DataType::Int32))
-                .unwrap()
-        }
-    }
-}
-
-impl flatbuffers::Verifiable for CastAttrs<'_> {
+impl<'a> flatbuffers::Verifiable for Compression {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<DataType>("to", Self::VT_TO, false)?
-            .finish();
-        Ok(())
+        u8::run_verifier(v, pos)
     }
 }
-pub struct CastAttrsArgs {
-    pub to: DataType,
+
+impl flatbuffers::SimpleToVerifyInSlice for Compression {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_CONSTANT_DATA: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_CONSTANT_DATA: u8 = 5;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 6] = [
+    ConstantData::NONE,
+    ConstantData::FloatData,
+    ConstantData::IntData,
+    ConstantData::StringData,
+    ConstantData::CompressedData,
+    ConstantData::BFloat16Data,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ConstantData(pub u8);
+#[allow(non_upper_case_globals)]
+impl ConstantData {
+    pub const NONE: Self = Self(0);
+    pub const FloatData: Self = Self(1);
+    pub const IntData: Self = Self(2);
+    pub const StringData: Self = Self(3);
+    pub const CompressedData: Self = Self(4);
+    pub const BFloat16Data: Self = Self(5);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 5;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::FloatData,
+        Self::IntData,
+        Self::StringData,
+        Self::CompressedData,
+        Self::BFloat16Data,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::FloatData => Some("FloatData"),
+            Self::IntData => Some("IntData"),
+            Self::StringData => Some("StringData"),
+            Self::CompressedData => Some("CompressedData"),
+            Self::BFloat16Data => Some("BFloat16Data"),
+            _ => None,
+        }
+    }
 }
-impl<'a> Default for CastAttrsArgs {
-    #[inline]
-    fn default() -> Self {
-        CastAttrsArgs {
-            to: DataType::Int32,
+impl core::fmt::Debug for ConstantData {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
         }
     }
 }
-
-pub struct CastAttrsBuilder<'a: 'b, 'b> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+impl<'a> flatbuffers::Follow<'a> for ConstantData {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
 }
-impl<'a: 'b, 'b> CastAttrsBuilder<'a, 'b> {
+
+impl flatbuffers::Push for ConstantData {
+    type Output = ConstantData;
     #[inline]
-    pub fn add_to(&mut self, to: DataType) {
-        self.fbb_
-            .push_slot::<DataType>(CastAttrs::VT_TO, to, DataType::Int32);
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
+}
+
+impl flatbuffers::EndianScalar for ConstantData {
+    type Scalar = u8;
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CastAttrsBuilder<'a, 'b> {
-        let start = _fbb.start_table();
-        CastAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
-        }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<CastAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl core::fmt::Debug for CastAttrs<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("CastAttrs");
-        ds.field("to", &self.to());
-        ds.finish()
+impl<'a> flatbuffers::Verifiable for ConstantData {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
     }
 }
-pub enum ConcatAttrsOffset {}
+
+impl flatbuffers::SimpleToVerifyInSlice for ConstantData {}
+pub struct ConstantDataUnionTableOffset {}
+
+pub enum ArgMaxAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConcatAttrs<'a> {
+pub struct ArgMaxAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
-    type Inner = ConcatAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
+    type Inner = ArgMaxAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2366,20 +2617,22 @@ impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
     }
 }
 
-impl<'a> ConcatAttrs<'a> {
+impl<'a> ArgMaxAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConcatAttrs { _tab: table }
+        ArgMaxAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ConcatAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
-        let mut builder = ConcatAttrsBuilder::new(_fbb);
+        args: &'args ArgMaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ArgMaxAttrs<'bldr>> {
+        let mut builder = ArgMaxAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
+        builder.add_keep_dims(args.keep_dims);
         builder.finish()
     }
 
@@ -2388,11 +2641,22 @@ impl<'a> ConcatAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe { self._tab.get::<i32>(ArgMaxAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, Some(false))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for ConcatAttrs<'_> {
+impl flatbuffers::Verifiable for ArgMaxAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2401,60 +2665,71 @@ impl flatbuffers::Verifiable for ConcatAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConcatAttrsArgs {
+pub struct ArgMaxAttrsArgs {
     pub axis: i32,
+    pub keep_dims: bool,
 }
-impl<'a> Default for ConcatAttrsArgs {
+impl<'a> Default for ArgMaxAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConcatAttrsArgs { axis: 0 }
+        ArgMaxAttrsArgs {
+            axis: 0,
+            keep_dims: false,
+        }
     }
 }
 
-pub struct ConcatAttrsBuilder<'a: 'b, 'b> {
+pub struct ArgMaxAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ConcatAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ArgMaxAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
+        self.fbb_.push_slot::<i32>(ArgMaxAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConcatAttrsBuilder<'a, 'b> {
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ArgMaxAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ConcatAttrsBuilder {
+        ArgMaxAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ArgMaxAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConcatAttrs<'_> {
+impl core::fmt::Debug for ArgMaxAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConcatAttrs");
+        let mut ds = f.debug_struct("ArgMaxAttrs");
         ds.field("axis", &self.axis());
+        ds.field("keep_dims", &self.keep_dims());
         ds.finish()
     }
 }
-pub enum IntScalarOffset {}
+pub enum AveragePoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IntScalar<'a> {
+pub struct AveragePoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
-    type Inner = IntScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
+    type Inner = AveragePoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2463,33 +2738,132 @@ impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
     }
 }
 
-impl<'a> IntScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> AveragePoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IntScalar { _tab: table }
+        AveragePoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args IntScalarArgs,
-    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
-        let mut builder = IntScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args AveragePoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<AveragePoolAttrs<'bldr>> {
+        let mut builder = AveragePoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_count_include_pad(args.count_include_pad);
+        builder.add_pad_mode(args.pad_mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> i32 {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
-    }
-}
-
-impl flatbuffers::Verifiable for IntScalar<'_> {
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pad_mode(&self) -> PadMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadMode>(AveragePoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn count_include_pad(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AveragePoolAttrs::VT_COUNT_INCLUDE_PAD, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AveragePoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2497,61 +2871,151 @@ impl flatbuffers::Verifiable for IntScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("value", Self::VT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("count_include_pad", Self::VT_COUNT_INCLUDE_PAD, false)?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct IntScalarArgs {
-    pub value: i32,
+pub struct AveragePoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub count_include_pad: bool,
+    pub ceil_mode: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for IntScalarArgs {
+impl<'a> Default for AveragePoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        IntScalarArgs { value: 0 }
+        AveragePoolAttrsArgs {
+            kernel_size: None, // required field
+            pad_mode: PadMode::Same,
+            pads: None,
+            strides: None,
+            count_include_pad: false,
+            ceil_mode: false,
+            dilations: None,
+        }
     }
 }
 
-pub struct IntScalarBuilder<'a: 'b, 'b> {
+pub struct AveragePoolAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> IntScalarBuilder<'a, 'b> {
+impl<'a: 'b, 'b> AveragePoolAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_value(&mut self, value: i32) {
-        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            AveragePoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IntScalarBuilder<'a, 'b> {
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(AveragePoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(AveragePoolAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(AveragePoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_count_include_pad(&mut self, count_include_pad: bool) {
+        self.fbb_.push_slot::<bool>(
+            AveragePoolAttrs::VT_COUNT_INCLUDE_PAD,
+            count_include_pad,
+            false,
+        );
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(AveragePoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            AveragePoolAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> AveragePoolAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        IntScalarBuilder {
+        AveragePoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<AveragePoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, AveragePoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IntScalar<'_> {
+impl core::fmt::Debug for AveragePoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IntScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("AveragePoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("count_include_pad", &self.count_include_pad());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
-pub enum FloatScalarOffset {}
+pub enum BatchNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FloatScalar<'a> {
+pub struct BatchNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
-    type Inner = FloatScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for BatchNormalizationAttrs<'a> {
+    type Inner = BatchNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2560,37 +3024,37 @@ impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
     }
 }
 
-impl<'a> FloatScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> BatchNormalizationAttrs<'a> {
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FloatScalar { _tab: table }
+        BatchNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FloatScalarArgs,
-    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
-        let mut builder = FloatScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args BatchNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<BatchNormalizationAttrs<'bldr>> {
+        let mut builder = BatchNormalizationAttrsBuilder::new(_fbb);
+        builder.add_epsilon(args.epsilon);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> f32 {
+    pub fn epsilon(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
+                .get::<f32>(BatchNormalizationAttrs::VT_EPSILON, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for FloatScalar<'_> {
+impl flatbuffers::Verifiable for BatchNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2598,62 +3062,64 @@ impl flatbuffers::Verifiable for FloatScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("value", Self::VT_VALUE, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
             .finish();
         Ok(())
     }
 }
-pub struct FloatScalarArgs {
-    pub value: f32,
+pub struct BatchNormalizationAttrsArgs {
+    pub epsilon: f32,
 }
-impl<'a> Default for FloatScalarArgs {
+impl<'a> Default for BatchNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        FloatScalarArgs { value: 0.0 }
+        BatchNormalizationAttrsArgs { epsilon: 0.0 }
     }
 }
 
-pub struct FloatScalarBuilder<'a: 'b, 'b> {
+pub struct BatchNormalizationAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FloatScalarBuilder<'a, 'b> {
+impl<'a: 'b, 'b> BatchNormalizationAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_value(&mut self, value: f32) {
+    pub fn add_epsilon(&mut self, epsilon: f32) {
         self.fbb_
-            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
+            .push_slot::<f32>(BatchNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FloatScalarBuilder<'a, 'b> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> BatchNormalizationAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FloatScalarBuilder {
+        BatchNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<BatchNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FloatScalar<'_> {
+impl core::fmt::Debug for BatchNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FloatScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("BatchNormalizationAttrs");
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
-pub enum ConstantOfShapeAttrsOffset {}
+pub enum BitShiftAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConstantOfShapeAttrs<'a> {
+pub struct BitShiftAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
-    type Inner = ConstantOfShapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for BitShiftAttrs<'a> {
+    type Inner = BitShiftAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2662,82 +3128,40 @@ impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
     }
 }
 
-impl<'a> ConstantOfShapeAttrs<'a> {
-    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
-    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+impl<'a> BitShiftAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConstantOfShapeAttrs { _tab: table }
+        BitShiftAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ConstantOfShapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
-        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.value {
-            builder.add_value(x);
-        }
-        builder.add_value_type(args.value_type);
+        args: &'args BitShiftAttrsArgs,
+    ) -> flatbuffers::WIPOffset<BitShiftAttrs<'bldr>> {
+        let mut builder = BitShiftAttrsBuilder::new(_fbb);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn value_type(&self) -> Scalar {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn value(&self) -> flatbuffers::Table<'a> {
+    pub fn direction(&self) -> BitShiftDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    ConstantOfShapeAttrs::VT_VALUE,
-                    None,
+                .get::<BitShiftDirection>(
+                    BitShiftAttrs::VT_DIRECTION,
+                    Some(BitShiftDirection::Left),
                 )
                 .unwrap()
         }
     }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
-        if self.value_type() == Scalar::IntScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { IntScalar::init_from_table(u) })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
-        if self.value_type() == Scalar::FloatScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { FloatScalar::init_from_table(u) })
-        } else {
-            None
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
+impl flatbuffers::Verifiable for BitShiftAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2745,123 +3169,67 @@ impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_union::<Scalar, _>(
-                "value_type",
-                Self::VT_VALUE_TYPE,
-                "value",
-                Self::VT_VALUE,
-                true,
-                |key, v, pos| match key {
-                    Scalar::IntScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
-                            "Scalar::IntScalar",
-                            pos,
-                        ),
-                    Scalar::FloatScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
-                            "Scalar::FloatScalar",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
-            )?
+            .visit_field::<BitShiftDirection>("direction", Self::VT_DIRECTION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConstantOfShapeAttrsArgs {
-    pub value_type: Scalar,
-    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct BitShiftAttrsArgs {
+    pub direction: BitShiftDirection,
 }
-impl<'a> Default for ConstantOfShapeAttrsArgs {
+impl<'a> Default for BitShiftAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConstantOfShapeAttrsArgs {
-            value_type: Scalar::NONE,
-            value: None, // required field
+        BitShiftAttrsArgs {
+            direction: BitShiftDirection::Left,
         }
     }
 }
 
-pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b> {
+pub struct BitShiftAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ConstantOfShapeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> BitShiftAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_value_type(&mut self, value_type: Scalar) {
-        self.fbb_.push_slot::<Scalar>(
-            ConstantOfShapeAttrs::VT_VALUE_TYPE,
-            value_type,
-            Scalar::NONE,
+    pub fn add_direction(&mut self, direction: BitShiftDirection) {
+        self.fbb_.push_slot::<BitShiftDirection>(
+            BitShiftAttrs::VT_DIRECTION,
+            direction,
+            BitShiftDirection::Left,
         );
     }
     #[inline]
-    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
-    }
-    #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> ConstantOfShapeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BitShiftAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ConstantOfShapeAttrsBuilder {
+        BitShiftAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<BitShiftAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
+impl core::fmt::Debug for BitShiftAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
-        ds.field("value_type", &self.value_type());
-        match self.value_type() {
-            Scalar::IntScalar => {
-                if let Some(x) = self.value_as_int_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            Scalar::FloatScalar => {
-                if let Some(x) = self.value_as_float_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("value", &x)
-            }
-        };
+        let mut ds = f.debug_struct("BitShiftAttrs");
+        ds.field("direction", &self.direction());
         ds.finish()
     }
 }
-pub enum ConvAttrsOffset {}
+pub enum CastAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvAttrs<'a> {
+pub struct CastAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
-    type Inner = ConvAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CastAttrs<'a> {
+    type Inner = CastAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2870,97 +3238,37 @@ impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
     }
 }
 
-impl<'a> ConvAttrs<'a> {
-    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_PADS: flatbuffers::VOffsetT = 6;
-    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+impl<'a> CastAttrs<'a> {
+    pub const VT_TO: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvAttrs { _tab: table }
+        CastAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ConvAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
-        let mut builder = ConvAttrsBuilder::new(_fbb);
-        if let Some(x) = args.dilations {
-            builder.add_dilations(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        builder.add_groups(args.groups);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        builder.add_pad_mode(args.pad_mode);
+        args: &'args CastAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CastAttrs<'bldr>> {
+        let mut builder = CastAttrsBuilder::new(_fbb);
+        builder.add_to(args.to);
         builder.finish()
     }
 
     #[inline]
-    pub fn pad_mode(&self) -> PadMode {
+    pub fn to(&self) -> DataType {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<PadMode>(ConvAttrs::VT_PAD_MODE, Some(PadMode::Same))
+                .get::<DataType>(CastAttrs::VT_TO, Some(DataType::Int32))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn groups(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_STRIDES,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_DILATIONS,
-                    None,
-                )
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for ConvAttrs<'_> {
+impl flatbuffers::Verifiable for CastAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2968,114 +3276,64 @@ impl flatbuffers::Verifiable for ConvAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "dilations",
-                Self::VT_DILATIONS,
-                false,
-            )?
+            .visit_field::<DataType>("to", Self::VT_TO, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvAttrsArgs<'a> {
-    pub pad_mode: PadMode,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub groups: u32,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct CastAttrsArgs {
+    pub to: DataType,
 }
-impl<'a> Default for ConvAttrsArgs<'a> {
+impl<'a> Default for CastAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConvAttrsArgs {
-            pad_mode: PadMode::Same,
-            pads: None,
-            groups: 0,
-            strides: None,
-            dilations: None,
+        CastAttrsArgs {
+            to: DataType::Int32,
         }
     }
 }
 
-pub struct ConvAttrsBuilder<'a: 'b, 'b> {
+pub struct CastAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ConvAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
-        self.fbb_
-            .push_slot::<PadMode>(ConvAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
-    }
-    #[inline]
-    pub fn add_groups(&mut self, groups: u32) {
-        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
-    }
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
-    }
+impl<'a: 'b, 'b> CastAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_dilations(
-        &mut self,
-        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
+    pub fn add_to(&mut self, to: DataType) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
+            .push_slot::<DataType>(CastAttrs::VT_TO, to, DataType::Int32);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConvAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CastAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ConvAttrsBuilder {
+        CastAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CastAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvAttrs<'_> {
+impl core::fmt::Debug for CastAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvAttrs");
-        ds.field("pad_mode", &self.pad_mode());
-        ds.field("pads", &self.pads());
-        ds.field("groups", &self.groups());
-        ds.field("strides", &self.strides());
-        ds.field("dilations", &self.dilations());
+        let mut ds = f.debug_struct("CastAttrs");
+        ds.field("to", &self.to());
         ds.finish()
     }
 }
-pub enum ConvTransposeAttrsOffset {}
+pub enum ChannelShuffleAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvTransposeAttrs<'a> {
+pub struct ChannelShuffleAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
-    type Inner = ConvTransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ChannelShuffleAttrs<'a> {
+    type Inner = ChannelShuffleAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3084,71 +3342,37 @@ impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
     }
 }
 
-impl<'a> ConvTransposeAttrs<'a> {
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
-    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+impl<'a> ChannelShuffleAttrs<'a> {
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvTransposeAttrs { _tab: table }
+        ChannelShuffleAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ConvTransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
-        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        builder.add_pad_mode(args.pad_mode);
+        args: &'args ChannelShuffleAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ChannelShuffleAttrs<'bldr>> {
+        let mut builder = ChannelShuffleAttrsBuilder::new(_fbb);
+        builder.add_groups(args.groups);
         builder.finish()
     }
 
     #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_STRIDES,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn pad_mode(&self) -> PadMode {
+    pub fn groups(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, Some(PadMode::Fixed))
+                .get::<u32>(ChannelShuffleAttrs::VT_GROUPS, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
+impl flatbuffers::Verifiable for ChannelShuffleAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3156,92 +3380,64 @@ impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvTransposeAttrsArgs<'a> {
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub pad_mode: PadMode,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct ChannelShuffleAttrsArgs {
+    pub groups: u32,
 }
-impl<'a> Default for ConvTransposeAttrsArgs<'a> {
+impl<'a> Default for ChannelShuffleAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConvTransposeAttrsArgs {
-            strides: None,
-            pad_mode: PadMode::Fixed,
-            pads: None,
-        }
+        ChannelShuffleAttrsArgs { groups: 0 }
     }
 }
 
-pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b> {
+pub struct ChannelShuffleAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ConvTransposeAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
-    }
-    #[inline]
-    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
-        self.fbb_
-            .push_slot::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, pad_mode, PadMode::Fixed);
-    }
+impl<'a: 'b, 'b> ChannelShuffleAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_groups(&mut self, groups: u32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
+            .push_slot::<u32>(ChannelShuffleAttrs::VT_GROUPS, groups, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> ConvTransposeAttrsBuilder<'a, 'b> {
+    ) -> ChannelShuffleAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ConvTransposeAttrsBuilder {
+        ChannelShuffleAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ChannelShuffleAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvTransposeAttrs<'_> {
+impl core::fmt::Debug for ChannelShuffleAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvTransposeAttrs");
-        ds.field("strides", &self.strides());
-        ds.field("pad_mode", &self.pad_mode());
-        ds.field("pads", &self.pads());
+        let mut ds = f.debug_struct("ChannelShuffleAttrs");
+        ds.field("groups", &self.groups());
         ds.finish()
     }
 }
-pub enum EluAttrsOffset {}
+pub enum ConcatAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EluAttrs<'a> {
+pub struct ConcatAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
-    type Inner = EluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
+    type Inner = ConcatAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3250,33 +3446,33 @@ impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
     }
 }
 
-impl<'a> EluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> ConcatAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EluAttrs { _tab: table }
+        ConcatAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args EluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
-        let mut builder = EluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args ConcatAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
+        let mut builder = ConcatAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
+        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for EluAttrs<'_> {
+impl flatbuffers::Verifiable for ConcatAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3284,61 +3480,61 @@ impl flatbuffers::Verifiable for EluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct EluAttrsArgs {
-    pub alpha: f32,
+pub struct ConcatAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for EluAttrsArgs {
+impl<'a> Default for ConcatAttrsArgs {
     #[inline]
     fn default() -> Self {
-        EluAttrsArgs { alpha: 0.0 }
+        ConcatAttrsArgs { axis: 0 }
     }
 }
 
-pub struct EluAttrsBuilder<'a: 'b, 'b> {
+pub struct ConcatAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> EluAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ConcatAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EluAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConcatAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        EluAttrsBuilder {
+        ConcatAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EluAttrs<'_> {
+impl core::fmt::Debug for ConcatAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("ConcatAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum FlattenAttrsOffset {}
+pub enum IntScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FlattenAttrs<'a> {
+pub struct IntScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
-    type Inner = FlattenAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
+    type Inner = IntScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3347,37 +3543,33 @@ impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
     }
 }
 
-impl<'a> FlattenAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> IntScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FlattenAttrs { _tab: table }
+        IntScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FlattenAttrsArgs,
-    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
-        let mut builder = FlattenAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args IntScalarArgs,
+    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
+        let mut builder = IntScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn value(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for FlattenAttrs<'_> {
+impl flatbuffers::Verifiable for IntScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3385,61 +3577,61 @@ impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<i32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct FlattenAttrsArgs {
-    pub axis: i32,
+pub struct IntScalarArgs {
+    pub value: i32,
 }
-impl<'a> Default for FlattenAttrsArgs {
+impl<'a> Default for IntScalarArgs {
     #[inline]
     fn default() -> Self {
-        FlattenAttrsArgs { axis: 0 }
+        IntScalarArgs { value: 0 }
     }
 }
 
-pub struct FlattenAttrsBuilder<'a: 'b, 'b> {
+pub struct IntScalarBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FlattenAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> IntScalarBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
+    pub fn add_value(&mut self, value: i32) {
+        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FlattenAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IntScalarBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FlattenAttrsBuilder {
+        IntScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FlattenAttrs<'_> {
+impl core::fmt::Debug for IntScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FlattenAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("IntScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum LayerNormalizationAttrsOffset {}
+pub enum FloatScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LayerNormalizationAttrs<'a> {
+pub struct FloatScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
-    type Inner = LayerNormalizationAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
+    type Inner = FloatScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3448,50 +3640,37 @@ impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
     }
 }
 
-impl<'a> LayerNormalizationAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
+impl<'a> FloatScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LayerNormalizationAttrs { _tab: table }
+        FloatScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args LayerNormalizationAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
-        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
-        builder.add_epsilon(args.epsilon);
-        builder.add_axis(args.axis);
+        args: &'args FloatScalarArgs,
+    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
+        let mut builder = FloatScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn epsilon(&self) -> f32 {
+    pub fn value(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
+                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
+impl flatbuffers::Verifiable for FloatScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3499,75 +3678,62 @@ impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
+            .visit_field::<f32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LayerNormalizationAttrsArgs {
-    pub axis: i32,
-    pub epsilon: f32,
+pub struct FloatScalarArgs {
+    pub value: f32,
 }
-impl<'a> Default for LayerNormalizationAttrsArgs {
+impl<'a> Default for FloatScalarArgs {
     #[inline]
     fn default() -> Self {
-        LayerNormalizationAttrsArgs {
-            axis: 0,
-            epsilon: 0.0,
-        }
+        FloatScalarArgs { value: 0.0 }
     }
 }
 
-pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b> {
+pub struct FloatScalarBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> LayerNormalizationAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_
-            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
-    }
+impl<'a: 'b, 'b> FloatScalarBuilder<'a, 'b> {
     #[inline]
-    pub fn add_epsilon(&mut self, epsilon: f32) {
+    pub fn add_value(&mut self, value: f32) {
         self.fbb_
-            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> LayerNormalizationAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FloatScalarBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        LayerNormalizationAttrsBuilder {
+        FloatScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
+impl core::fmt::Debug for FloatScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LayerNormalizationAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("epsilon", &self.epsilon());
+        let mut ds = f.debug_struct("FloatScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum GatherAttrsOffset {}
+pub enum ConcatFromSequenceAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherAttrs<'a> {
+pub struct ConcatFromSequenceAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
-    type Inner = GatherAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConcatFromSequenceAttrs<'a> {
+    type Inner = ConcatFromSequenceAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3576,20 +3742,22 @@ impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
     }
 }
 
-impl<'a> GatherAttrs<'a> {
+impl<'a> ConcatFromSequenceAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_NEW_AXIS: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherAttrs { _tab: table }
+        ConcatFromSequenceAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GatherAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
-        let mut builder = GatherAttrsBuilder::new(_fbb);
+        args: &'args ConcatFromSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'bldr>> {
+        let mut builder = ConcatFromSequenceAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
+        builder.add_new_axis(args.new_axis);
         builder.finish()
     }
 
@@ -3598,11 +3766,27 @@ impl<'a> GatherAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<i32>(ConcatFromSequenceAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn new_axis(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, Some(false))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for GatherAttrs<'_> {
+impl flatbuffers::Verifiable for ConcatFromSequenceAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3611,60 +3795,74 @@ impl flatbuffers::Verifiable for GatherAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("new_axis", Self::VT_NEW_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GatherAttrsArgs {
+pub struct ConcatFromSequenceAttrsArgs {
     pub axis: i32,
+    pub new_axis: bool,
 }
-impl<'a> Default for GatherAttrsArgs {
+impl<'a> Default for ConcatFromSequenceAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GatherAttrsArgs { axis: 0 }
+        ConcatFromSequenceAttrsArgs {
+            axis: 0,
+            new_axis: false,
+        }
     }
 }
 
-pub struct GatherAttrsBuilder<'a: 'b, 'b> {
+pub struct ConcatFromSequenceAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GatherAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ConcatFromSequenceAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
+        self.fbb_
+            .push_slot::<i32>(ConcatFromSequenceAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GatherAttrsBuilder<'a, 'b> {
+    pub fn add_new_axis(&mut self, new_axis: bool) {
+        self.fbb_
+            .push_slot::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, new_axis, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ConcatFromSequenceAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        GatherAttrsBuilder {
+        ConcatFromSequenceAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherAttrs<'_> {
+impl core::fmt::Debug for ConcatFromSequenceAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherAttrs");
+        let mut ds = f.debug_struct("ConcatFromSequenceAttrs");
         ds.field("axis", &self.axis());
+        ds.field("new_axis", &self.new_axis());
         ds.finish()
     }
 }
-pub enum GatherNDAttrsOffset {}
+pub enum ConstantOfShapeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherNDAttrs<'a> {
+pub struct ConstantOfShapeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
-    type Inner = GatherNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
+    type Inner = ConstantOfShapeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3673,37 +3871,82 @@ impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
     }
 }
 
-impl<'a> GatherNDAttrs<'a> {
-    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
+impl<'a> ConstantOfShapeAttrs<'a> {
+    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherNDAttrs { _tab: table }
+        ConstantOfShapeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GatherNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
-        let mut builder = GatherNDAttrsBuilder::new(_fbb);
-        builder.add_batch_dims(args.batch_dims);
+        args: &'args ConstantOfShapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
+        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.value {
+            builder.add_value(x);
+        }
+        builder.add_value_type(args.value_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn batch_dims(&self) -> i32 {
+    pub fn value_type(&self) -> Scalar {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
+                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn value(&self) -> flatbuffers::Table<'a> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    ConstantOfShapeAttrs::VT_VALUE,
+                    None,
+                )
                 .unwrap()
         }
     }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
+        if self.value_type() == Scalar::IntScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { IntScalar::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
+        if self.value_type() == Scalar::FloatScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { FloatScalar::init_from_table(u) })
+        } else {
+            None
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
+impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3711,62 +3954,123 @@ impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
+            .visit_union::<Scalar, _>(
+                "value_type",
+                Self::VT_VALUE_TYPE,
+                "value",
+                Self::VT_VALUE,
+                true,
+                |key, v, pos| match key {
+                    Scalar::IntScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
+                            "Scalar::IntScalar",
+                            pos,
+                        ),
+                    Scalar::FloatScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
+                            "Scalar::FloatScalar",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GatherNDAttrsArgs {
-    pub batch_dims: i32,
+pub struct ConstantOfShapeAttrsArgs {
+    pub value_type: Scalar,
+    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for GatherNDAttrsArgs {
+impl<'a> Default for ConstantOfShapeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GatherNDAttrsArgs { batch_dims: 0 }
+        ConstantOfShapeAttrsArgs {
+            value_type: Scalar::NONE,
+            value: None, // required field
+        }
     }
 }
 
-pub struct GatherNDAttrsBuilder<'a: 'b, 'b> {
+pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GatherNDAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_batch_dims(&mut self, batch_dims: i32) {
-        self.fbb_
-            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
-    }
+impl<'a: 'b, 'b> ConstantOfShapeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GatherNDAttrsBuilder<'a, 'b> {
-        let start = _fbb.start_table();
-        GatherNDAttrsBuilder {
+    pub fn add_value_type(&mut self, value_type: Scalar) {
+        self.fbb_.push_slot::<Scalar>(
+            ConstantOfShapeAttrs::VT_VALUE_TYPE,
+            value_type,
+            Scalar::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ConstantOfShapeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ConstantOfShapeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherNDAttrs<'_> {
+impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherNDAttrs");
-        ds.field("batch_dims", &self.batch_dims());
+        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
+        ds.field("value_type", &self.value_type());
+        match self.value_type() {
+            Scalar::IntScalar => {
+                if let Some(x) = self.value_as_int_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            Scalar::FloatScalar => {
+                if let Some(x) = self.value_as_float_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("value", &x)
+            }
+        };
         ds.finish()
     }
 }
-pub enum GemmAttrsOffset {}
+pub enum ConvAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GemmAttrs<'a> {
+pub struct ConvAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
-    type Inner = GemmAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
+    type Inner = ConvAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3775,72 +4079,97 @@ impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
     }
 }
 
-impl<'a> GemmAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
-    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
-    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
+impl<'a> ConvAttrs<'a> {
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GemmAttrs { _tab: table }
+        ConvAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GemmAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
-        let mut builder = GemmAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
-        builder.add_alpha(args.alpha);
-        builder.add_transpose_b(args.transpose_b);
-        builder.add_transpose_a(args.transpose_a);
+        args: &'args ConvAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
+        let mut builder = ConvAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        builder.add_pad_mode(args.pad_mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn pad_mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
+                .get::<PadMode>(ConvAttrs::VT_PAD_MODE, Some(PadMode::Same))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn beta(&self) -> f32 {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_PADS,
+                    None,
+                )
+        }
     }
     #[inline]
-    pub fn transpose_a(&self) -> bool {
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_STRIDES,
+                    None,
+                )
         }
     }
     #[inline]
-    pub fn transpose_b(&self) -> bool {
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_DILATIONS,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for GemmAttrs<'_> {
+impl flatbuffers::Verifiable for ConvAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3848,89 +4177,114 @@ impl flatbuffers::Verifiable for GemmAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
-            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
-            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GemmAttrsArgs {
-    pub alpha: f32,
-    pub beta: f32,
-    pub transpose_a: bool,
-    pub transpose_b: bool,
+pub struct ConvAttrsArgs<'a> {
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for GemmAttrsArgs {
+impl<'a> Default for ConvAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GemmAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
-            transpose_a: false,
-            transpose_b: false,
+        ConvAttrsArgs {
+            pad_mode: PadMode::Same,
+            pads: None,
+            groups: 0,
+            strides: None,
+            dilations: None,
         }
     }
 }
 
-pub struct GemmAttrsBuilder<'a: 'b, 'b> {
+pub struct ConvAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GemmAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ConvAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(ConvAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
     }
     #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn add_transpose_a(&mut self, transpose_a: bool) {
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
     }
     #[inline]
-    pub fn add_transpose_b(&mut self, transpose_b: bool) {
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
         self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GemmAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConvAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        GemmAttrsBuilder {
+        ConvAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GemmAttrs<'_> {
+impl core::fmt::Debug for ConvAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GemmAttrs");
-        ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
-        ds.field("transpose_a", &self.transpose_a());
-        ds.field("transpose_b", &self.transpose_b());
+        let mut ds = f.debug_struct("ConvAttrs");
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
-pub enum GRUAttrsOffset {}
+pub enum ConvTransposeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GRUAttrs<'a> {
+pub struct ConvTransposeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
-    type Inner = GRUAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
+    type Inner = ConvTransposeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3939,63 +4293,135 @@ impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
     }
 }
 
-impl<'a> GRUAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
-    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
+impl<'a> ConvTransposeAttrs<'a> {
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 10;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 12;
+    pub const VT_OUTPUT_PADDING: flatbuffers::VOffsetT = 14;
+    pub const VT_OUTPUT_SHAPE: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GRUAttrs { _tab: table }
+        ConvTransposeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GRUAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
-        let mut builder = GRUAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_linear_before_reset(args.linear_before_reset);
-        builder.add_direction(args.direction);
-        builder.finish()
-    }
-
-    #[inline]
-    pub fn direction(&self) -> RNNDirection {
+        args: &'args ConvTransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
+        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.output_shape {
+            builder.add_output_shape(x);
+        }
+        if let Some(x) = args.output_padding {
+            builder.add_output_padding(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_pad_mode(args.pad_mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_STRIDES,
+                    None,
+                )
         }
     }
     #[inline]
-    pub fn hidden_size(&self) -> u32 {
+    pub fn pad_mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
+                .get::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, Some(PadMode::Fixed))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn linear_before_reset(&self) -> bool {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ConvTransposeAttrs::VT_GROUPS, Some(1))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn output_padding(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_OUTPUT_PADDING,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn output_shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_OUTPUT_SHAPE,
+                    None,
+                )
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for GRUAttrs<'_> {
+impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4003,85 +4429,155 @@ impl flatbuffers::Verifiable for GRUAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
-            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "output_padding",
+                Self::VT_OUTPUT_PADDING,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "output_shape",
+                Self::VT_OUTPUT_SHAPE,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GRUAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
-    pub linear_before_reset: bool,
+pub struct ConvTransposeAttrsArgs<'a> {
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub output_padding: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub output_shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for GRUAttrsArgs {
+impl<'a> Default for ConvTransposeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GRUAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-            linear_before_reset: false,
+        ConvTransposeAttrsArgs {
+            strides: None,
+            pad_mode: PadMode::Fixed,
+            pads: None,
+            dilations: None,
+            groups: 1,
+            output_padding: None,
+            output_shape: None,
         }
     }
 }
 
-pub struct GRUAttrsBuilder<'a: 'b, 'b> {
+pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GRUAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ConvTransposeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            GRUAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
     }
     #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
         self.fbb_
-            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+            .push_slot::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, pad_mode, PadMode::Fixed);
     }
     #[inline]
-    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GRUAttrsBuilder<'a, 'b> {
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(ConvTransposeAttrs::VT_GROUPS, groups, 1);
+    }
+    #[inline]
+    pub fn add_output_padding(
+        &mut self,
+        output_padding: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_OUTPUT_PADDING,
+            output_padding,
+        );
+    }
+    #[inline]
+    pub fn add_output_shape(
+        &mut self,
+        output_shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_OUTPUT_SHAPE,
+            output_shape,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ConvTransposeAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        GRUAttrsBuilder {
+        ConvTransposeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GRUAttrs<'_> {
+impl core::fmt::Debug for ConvTransposeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GRUAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
-        ds.field("linear_before_reset", &self.linear_before_reset());
+        let mut ds = f.debug_struct("ConvTransposeAttrs");
+        ds.field("strides", &self.strides());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("dilations", &self.dilations());
+        ds.field("groups", &self.groups());
+        ds.field("output_padding", &self.output_padding());
+        ds.field("output_shape", &self.output_shape());
         ds.finish()
     }
 }
-pub enum HardSigmoidAttrsOffset {}
+pub enum CustomAttrOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct HardSigmoidAttrs<'a> {
+pub struct CustomAttr<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
-    type Inner = HardSigmoidAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CustomAttr<'a> {
+    type Inner = CustomAttr<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4090,126 +4586,267 @@ impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
     }
 }
 
-impl<'a> HardSigmoidAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+impl<'a> CustomAttr<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_INT_VALUE: flatbuffers::VOffsetT = 8;
+    pub const VT_FLOAT_VALUE: flatbuffers::VOffsetT = 10;
+    pub const VT_STRING_VALUE: flatbuffers::VOffsetT = 12;
+    pub const VT_INTS: flatbuffers::VOffsetT = 14;
+    pub const VT_FLOATS: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        HardSigmoidAttrs { _tab: table }
+        CustomAttr { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args HardSigmoidAttrsArgs,
-    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
-        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
-        builder.add_alpha(args.alpha);
+        args: &'args CustomAttrArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CustomAttr<'bldr>> {
+        let mut builder = CustomAttrBuilder::new(_fbb);
+        builder.add_int_value(args.int_value);
+        if let Some(x) = args.floats {
+            builder.add_floats(x);
+        }
+        if let Some(x) = args.ints {
+            builder.add_ints(x);
+        }
+        if let Some(x) = args.string_value {
+            builder.add_string_value(x);
+        }
+        builder.add_float_value(args.float_value);
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.add_value_type(args.value_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn name(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttr::VT_NAME, None)
         }
     }
+
     #[inline]
-    pub fn beta(&self) -> f32 {
+    pub fn value_type(&self) -> CustomAttrType {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
+                .get::<CustomAttrType>(CustomAttr::VT_VALUE_TYPE, Some(CustomAttrType::Int))
                 .unwrap()
         }
     }
-}
 
-impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
-    #[inline]
-    fn run_verifier(
-        v: &mut flatbuffers::Verifier,
-        pos: usize,
-    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-        use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
-            .finish();
-        Ok(())
-    }
-}
-pub struct HardSigmoidAttrsArgs {
-    pub alpha: f32,
-    pub beta: f32,
-}
-impl<'a> Default for HardSigmoidAttrsArgs {
     #[inline]
-    fn default() -> Self {
-        HardSigmoidAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
+    pub fn int_value(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(CustomAttr::VT_INT_VALUE, Some(0))
+                .unwrap()
         }
     }
-}
 
-pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b> {
+    #[inline]
+    pub fn float_value(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(CustomAttr::VT_FLOAT_VALUE, Some(0.0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn string_value(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttr::VT_STRING_VALUE, None)
+        }
+    }
+
+    #[inline]
+    pub fn ints(&self) -> Option<flatbuffers::Vector<'a, i64>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i64>>>(
+                    CustomAttr::VT_INTS,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn floats(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    CustomAttr::VT_FLOATS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for CustomAttr<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<CustomAttrType>("value_type", Self::VT_VALUE_TYPE, false)?
+            .visit_field::<i64>("int_value", Self::VT_INT_VALUE, false)?
+            .visit_field::<f32>("float_value", Self::VT_FLOAT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "string_value",
+                Self::VT_STRING_VALUE,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i64>>>(
+                "ints",
+                Self::VT_INTS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "floats",
+                Self::VT_FLOATS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct CustomAttrArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value_type: CustomAttrType,
+    pub int_value: i64,
+    pub float_value: f32,
+    pub string_value: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub ints: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i64>>>,
+    pub floats: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for CustomAttrArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CustomAttrArgs {
+            name: None,
+            value_type: CustomAttrType::Int,
+            int_value: 0,
+            float_value: 0.0,
+            string_value: None,
+            ints: None,
+            floats: None,
+        }
+    }
+}
+
+pub struct CustomAttrBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> HardSigmoidAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CustomAttrBuilder<'a, 'b> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_NAME, name);
     }
     #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
+    pub fn add_value_type(&mut self, value_type: CustomAttrType) {
+        self.fbb_.push_slot::<CustomAttrType>(
+            CustomAttr::VT_VALUE_TYPE,
+            value_type,
+            CustomAttrType::Int,
+        );
+    }
+    #[inline]
+    pub fn add_int_value(&mut self, int_value: i64) {
         self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
+            .push_slot::<i64>(CustomAttr::VT_INT_VALUE, int_value, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> HardSigmoidAttrsBuilder<'a, 'b> {
+    pub fn add_float_value(&mut self, float_value: f32) {
+        self.fbb_
+            .push_slot::<f32>(CustomAttr::VT_FLOAT_VALUE, float_value, 0.0);
+    }
+    #[inline]
+    pub fn add_string_value(&mut self, string_value: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            CustomAttr::VT_STRING_VALUE,
+            string_value,
+        );
+    }
+    #[inline]
+    pub fn add_ints(&mut self, ints: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i64>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_INTS, ints);
+    }
+    #[inline]
+    pub fn add_floats(&mut self, floats: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_FLOATS, floats);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CustomAttrBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        HardSigmoidAttrsBuilder {
+        CustomAttrBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CustomAttr<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for HardSigmoidAttrs<'_> {
+impl core::fmt::Debug for CustomAttr<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("HardSigmoidAttrs");
-        ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
+        let mut ds = f.debug_struct("CustomAttr");
+        ds.field("name", &self.name());
+        ds.field("value_type", &self.value_type());
+        ds.field("int_value", &self.int_value());
+        ds.field("float_value", &self.float_value());
+        ds.field("string_value", &self.string_value());
+        ds.field("ints", &self.ints());
+        ds.field("floats", &self.floats());
         ds.finish()
     }
 }
-pub enum LeakyReluAttrsOffset {}
+pub enum CustomOperatorAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LeakyReluAttrs<'a> {
+pub struct CustomOperatorAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
-    type Inner = LeakyReluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CustomOperatorAttrs<'a> {
+    type Inner = CustomOperatorAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4218,37 +4855,71 @@ impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
     }
 }
 
-impl<'a> LeakyReluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> CustomOperatorAttrs<'a> {
+    pub const VT_DOMAIN: flatbuffers::VOffsetT = 4;
+    pub const VT_OP_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LeakyReluAttrs { _tab: table }
+        CustomOperatorAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args LeakyReluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
-        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args CustomOperatorAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CustomOperatorAttrs<'bldr>> {
+        let mut builder = CustomOperatorAttrsBuilder::new(_fbb);
+        if let Some(x) = args.attrs {
+            builder.add_attrs(x);
+        }
+        if let Some(x) = args.op_type {
+            builder.add_op_type(x);
+        }
+        if let Some(x) = args.domain {
+            builder.add_domain(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn domain(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomOperatorAttrs::VT_DOMAIN, None)
+        }
+    }
+
+    #[inline]
+    pub fn op_type(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomOperatorAttrs::VT_OP_TYPE, None)
+        }
+    }
+
+    #[inline]
+    pub fn attrs(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr>>,
+            >>(CustomOperatorAttrs::VT_ATTRS, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
+impl flatbuffers::Verifiable for CustomOperatorAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4256,62 +4927,97 @@ impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("op_type", Self::VT_OP_TYPE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CustomAttr>>,
+            >>("attrs", Self::VT_ATTRS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LeakyReluAttrsArgs {
-    pub alpha: f32,
+pub struct CustomOperatorAttrsArgs<'a> {
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub op_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub attrs: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr<'a>>>,
+        >,
+    >,
 }
-impl<'a> Default for LeakyReluAttrsArgs {
+impl<'a> Default for CustomOperatorAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        LeakyReluAttrsArgs { alpha: 0.0 }
+        CustomOperatorAttrsArgs {
+            domain: None,
+            op_type: None,
+            attrs: None,
+        }
     }
 }
 
-pub struct LeakyReluAttrsBuilder<'a: 'b, 'b> {
+pub struct CustomOperatorAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> LeakyReluAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CustomOperatorAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
+    pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomOperatorAttrs::VT_DOMAIN, domain);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LeakyReluAttrsBuilder<'a, 'b> {
+    pub fn add_op_type(&mut self, op_type: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            CustomOperatorAttrs::VT_OP_TYPE,
+            op_type,
+        );
+    }
+    #[inline]
+    pub fn add_attrs(
+        &mut self,
+        attrs: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<CustomAttr<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomOperatorAttrs::VT_ATTRS, attrs);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> CustomOperatorAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        LeakyReluAttrsBuilder {
+        CustomOperatorAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CustomOperatorAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LeakyReluAttrs<'_> {
+impl core::fmt::Debug for CustomOperatorAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LeakyReluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("CustomOperatorAttrs");
+        ds.field("domain", &self.domain());
+        ds.field("op_type", &self.op_type());
+        ds.field("attrs", &self.attrs());
         ds.finish()
     }
 }
-pub enum LSTMAttrsOffset {}
+pub enum DropoutAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LSTMAttrs<'a> {
+pub struct DropoutAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
-    type Inner = LSTMAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DropoutAttrs<'a> {
+    type Inner = DropoutAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4320,50 +5026,35 @@ impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
     }
 }
 
-impl<'a> LSTMAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+impl<'a> DropoutAttrs<'a> {
+    pub const VT_SEED: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LSTMAttrs { _tab: table }
+        DropoutAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args LSTMAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
-        let mut builder = LSTMAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_direction(args.direction);
+        args: &'args DropoutAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DropoutAttrs<'bldr>> {
+        let mut builder = DropoutAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn direction(&self) -> RNNDirection {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn hidden_size(&self) -> u32 {
+    pub fn seed(&self) -> Option<i32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(DropoutAttrs::VT_SEED, None) }
     }
 }
 
-impl flatbuffers::Verifiable for LSTMAttrs<'_> {
+impl flatbuffers::Verifiable for DropoutAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4371,76 +5062,62 @@ impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<i32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LSTMAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
+pub struct DropoutAttrsArgs {
+    pub seed: Option<i32>,
 }
-impl<'a> Default for LSTMAttrsArgs {
+impl<'a> Default for DropoutAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LSTMAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-        }
+        DropoutAttrsArgs { seed: None }
     }
 }
 
-pub struct LSTMAttrsBuilder<'a: 'b, 'b> {
+pub struct DropoutAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> LSTMAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            LSTMAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
-    }
+impl<'a: 'b, 'b> DropoutAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+    pub fn add_seed(&mut self, seed: i32) {
         self.fbb_
-            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+            .push_slot_always::<i32>(DropoutAttrs::VT_SEED, seed);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LSTMAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DropoutAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        LSTMAttrsBuilder {
+        DropoutAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DropoutAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LSTMAttrs<'_> {
+impl core::fmt::Debug for DropoutAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LSTMAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
+        let mut ds = f.debug_struct("DropoutAttrs");
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum MaxPoolAttrsOffset {}
+pub enum EluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MaxPoolAttrs<'a> {
+pub struct EluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
-    type Inner = MaxPoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
+    type Inner = EluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4449,89 +5126,33 @@ impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
     }
 }
 
-impl<'a> MaxPoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+impl<'a> EluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MaxPoolAttrs { _tab: table }
+        EluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args MaxPoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
-        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
-        }
-        builder.add_pad_mode(args.pad_mode);
+        args: &'args EluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
+        let mut builder = EluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pad_mode(&self) -> PadMode {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<PadMode>(MaxPoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_STRIDES,
-                    None,
-                )
-        }
+        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
+impl flatbuffers::Verifiable for EluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4539,110 +5160,61 @@ impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "kernel_size",
-                Self::VT_KERNEL_SIZE,
-                true,
-            )?
-            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct MaxPoolAttrsArgs<'a> {
-    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub pad_mode: PadMode,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct EluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for MaxPoolAttrsArgs<'a> {
+impl<'a> Default for EluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        MaxPoolAttrsArgs {
-            kernel_size: None, // required field
-            pad_mode: PadMode::Same,
-            pads: None,
-            strides: None,
-        }
+        EluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct MaxPoolAttrsBuilder<'a: 'b, 'b> {
+pub struct EluAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> MaxPoolAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_kernel_size(
-        &mut self,
-        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MaxPoolAttrs::VT_KERNEL_SIZE,
-            kernel_size,
-        );
-    }
-    #[inline]
-    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
-        self.fbb_
-            .push_slot::<PadMode>(MaxPoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
-    }
+impl<'a: 'b, 'b> EluAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MaxPoolAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EluAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        MaxPoolAttrsBuilder {
+        EluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MaxPoolAttrs<'_> {
+impl core::fmt::Debug for EluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MaxPoolAttrs");
-        ds.field("kernel_size", &self.kernel_size());
-        ds.field("pad_mode", &self.pad_mode());
-        ds.field("pads", &self.pads());
-        ds.field("strides", &self.strides());
+        let mut ds = f.debug_struct("EluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum ModAttrsOffset {}
+pub enum EyeLikeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ModAttrs<'a> {
+pub struct EyeLikeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
-    type Inner = ModAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EyeLikeAttrs<'a> {
+    type Inner = EyeLikeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4651,37 +5223,44 @@ impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
     }
 }
 
-impl<'a> ModAttrs<'a> {
-    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
+impl<'a> EyeLikeAttrs<'a> {
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_K: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ModAttrs { _tab: table }
+        EyeLikeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ModAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
-        let mut builder = ModAttrsBuilder::new(_fbb);
-        builder.add_fmod(args.fmod);
+        args: &'args EyeLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<EyeLikeAttrs<'bldr>> {
+        let mut builder = EyeLikeAttrsBuilder::new(_fbb);
+        builder.add_k(args.k);
+        if let Some(x) = args.dtype {
+            builder.add_dtype(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn fmod(&self) -> bool {
+    pub fn dtype(&self) -> Option<DataType> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<DataType>(EyeLikeAttrs::VT_DTYPE, None) }
+    }
+    #[inline]
+    pub fn k(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(EyeLikeAttrs::VT_K, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for ModAttrs<'_> {
+impl flatbuffers::Verifiable for EyeLikeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4689,61 +5268,69 @@ impl flatbuffers::Verifiable for ModAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<i32>("k", Self::VT_K, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ModAttrsArgs {
-    pub fmod: bool,
+pub struct EyeLikeAttrsArgs {
+    pub dtype: Option<DataType>,
+    pub k: i32,
 }
-impl<'a> Default for ModAttrsArgs {
+impl<'a> Default for EyeLikeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ModAttrsArgs { fmod: false }
+        EyeLikeAttrsArgs { dtype: None, k: 0 }
     }
 }
 
-pub struct ModAttrsBuilder<'a: 'b, 'b> {
+pub struct EyeLikeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ModAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> EyeLikeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_fmod(&mut self, fmod: bool) {
-        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
+    pub fn add_dtype(&mut self, dtype: DataType) {
+        self.fbb_
+            .push_slot_always::<DataType>(EyeLikeAttrs::VT_DTYPE, dtype);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ModAttrsBuilder<'a, 'b> {
+    pub fn add_k(&mut self, k: i32) {
+        self.fbb_.push_slot::<i32>(EyeLikeAttrs::VT_K, k, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EyeLikeAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ModAttrsBuilder {
+        EyeLikeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EyeLikeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ModAttrs<'_> {
+impl core::fmt::Debug for EyeLikeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ModAttrs");
-        ds.field("fmod", &self.fmod());
+        let mut ds = f.debug_struct("EyeLikeAttrs");
+        ds.field("dtype", &self.dtype());
+        ds.field("k", &self.k());
         ds.finish()
     }
 }
-pub enum NonMaxSuppressionAttrsOffset {}
+pub enum FlattenAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct NonMaxSuppressionAttrs<'a> {
+pub struct FlattenAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
-    type Inner = NonMaxSuppressionAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
+    type Inner = FlattenAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4752,40 +5339,37 @@ impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
     }
 }
 
-impl<'a> NonMaxSuppressionAttrs<'a> {
-    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+impl<'a> FlattenAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        NonMaxSuppressionAttrs { _tab: table }
+        FlattenAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args NonMaxSuppressionAttrsArgs,
-    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
-        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
-        builder.add_box_order(args.box_order);
+        args: &'args FlattenAttrsArgs,
+    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
+        let mut builder = FlattenAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn box_order(&self) -> NMSBoxOrder {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NMSBoxOrder>(
-                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
-                    Some(NMSBoxOrder::TopLeftBottomRight),
-                )
+                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4793,69 +5377,61 @@ impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct NonMaxSuppressionAttrsArgs {
-    pub box_order: NMSBoxOrder,
+pub struct FlattenAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for NonMaxSuppressionAttrsArgs {
+impl<'a> Default for FlattenAttrsArgs {
     #[inline]
     fn default() -> Self {
-        NonMaxSuppressionAttrsArgs {
-            box_order: NMSBoxOrder::TopLeftBottomRight,
-        }
+        FlattenAttrsArgs { axis: 0 }
     }
 }
 
-pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b> {
+pub struct FlattenAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FlattenAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
-        self.fbb_.push_slot::<NMSBoxOrder>(
-            NonMaxSuppressionAttrs::VT_BOX_ORDER,
-            box_order,
-            NMSBoxOrder::TopLeftBottomRight,
-        );
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FlattenAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        NonMaxSuppressionAttrsBuilder {
+        FlattenAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+impl core::fmt::Debug for FlattenAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
-        ds.field("box_order", &self.box_order());
+        let mut ds = f.debug_struct("FlattenAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum OneHotAttrsOffset {}
+pub enum LayerNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OneHotAttrs<'a> {
+pub struct LayerNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
-    type Inner = OneHotAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
+    type Inner = LayerNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4864,19 +5440,21 @@ impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
     }
 }
 
-impl<'a> OneHotAttrs<'a> {
+impl<'a> LayerNormalizationAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OneHotAttrs { _tab: table }
+        LayerNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args OneHotAttrsArgs,
-    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
-        let mut builder = OneHotAttrsBuilder::new(_fbb);
+        args: &'args LayerNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
+        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
+        builder.add_epsilon(args.epsilon);
         builder.add_axis(args.axis);
         builder.finish()
     }
@@ -4886,11 +5464,26 @@ impl<'a> OneHotAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn epsilon(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4899,60 +5492,74 @@ impl flatbuffers::Verifiable for OneHotAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
             .finish();
         Ok(())
     }
 }
-pub struct OneHotAttrsArgs {
+pub struct LayerNormalizationAttrsArgs {
     pub axis: i32,
+    pub epsilon: f32,
 }
-impl<'a> Default for OneHotAttrsArgs {
+impl<'a> Default for LayerNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        OneHotAttrsArgs { axis: 0 }
+        LayerNormalizationAttrsArgs {
+            axis: 0,
+            epsilon: 0.0,
+        }
     }
 }
 
-pub struct OneHotAttrsBuilder<'a: 'b, 'b> {
+pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> OneHotAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LayerNormalizationAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+        self.fbb_
+            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OneHotAttrsBuilder<'a, 'b> {
+    pub fn add_epsilon(&mut self, epsilon: f32) {
+        self.fbb_
+            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> LayerNormalizationAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        OneHotAttrsBuilder {
+        LayerNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for OneHotAttrs<'_> {
+impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("OneHotAttrs");
+        let mut ds = f.debug_struct("LayerNormalizationAttrs");
         ds.field("axis", &self.axis());
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
-pub enum RandomNormalAttrsOffset {}
+pub enum GatherAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalAttrs<'a> {
+pub struct GatherAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
-    type Inner = RandomNormalAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
+    type Inner = GatherAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4961,78 +5568,33 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
+impl<'a> GatherAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalAttrs { _tab: table }
+        GatherAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args RandomNormalAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
-        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
-        }
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        args: &'args GatherAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
+        let mut builder = GatherAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn scale(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
-    }
-    #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomNormalAttrs::VT_SHAPE,
-                    None,
-                )
-        }
+        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
+impl flatbuffers::Verifiable for GatherAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5040,97 +5602,61 @@ impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalAttrsArgs<'a> {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct GatherAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for RandomNormalAttrsArgs<'a> {
+impl<'a> Default for GatherAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomNormalAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
-            shape: None,
-        }
+        GatherAttrsArgs { axis: 0 }
     }
 }
 
-pub struct RandomNormalAttrsBuilder<'a: 'b, 'b> {
+pub struct GatherAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RandomNormalAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
-    }
-    #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
-    }
-    #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
-        self.fbb_
-            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
-    }
+impl<'a: 'b, 'b> GatherAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> RandomNormalAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GatherAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        RandomNormalAttrsBuilder {
+        GatherAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalAttrs<'_> {
+impl core::fmt::Debug for GatherAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
-        ds.field("shape", &self.shape());
+        let mut ds = f.debug_struct("GatherAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum RandomNormalLikeAttrsOffset {}
+pub enum GatherNDAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalLikeAttrs<'a> {
+pub struct GatherNDAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
-    type Inner = RandomNormalLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
+    type Inner = GatherNDAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5139,61 +5665,37 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalLikeAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> GatherNDAttrs<'a> {
+    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalLikeAttrs { _tab: table }
+        GatherNDAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args RandomNormalLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
-        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        args: &'args GatherNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
+        let mut builder = GatherNDAttrsBuilder::new(_fbb);
+        builder.add_batch_dims(args.batch_dims);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
+    pub fn batch_dims(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
+                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn scale(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
-    }
-}
-
-impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
+}
+
+impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5201,84 +5703,62 @@ impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalLikeAttrsArgs {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
+pub struct GatherNDAttrsArgs {
+    pub batch_dims: i32,
 }
-impl<'a> Default for RandomNormalLikeAttrsArgs {
+impl<'a> Default for GatherNDAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomNormalLikeAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
-        }
+        GatherNDAttrsArgs { batch_dims: 0 }
     }
 }
 
-pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b> {
+pub struct GatherNDAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RandomNormalLikeAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
-    }
-    #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
-    }
+impl<'a: 'b, 'b> GatherNDAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_batch_dims(&mut self, batch_dims: i32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
+            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> RandomNormalLikeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GatherNDAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        RandomNormalLikeAttrsBuilder {
+        GatherNDAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
+impl core::fmt::Debug for GatherNDAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("GatherNDAttrs");
+        ds.field("batch_dims", &self.batch_dims());
         ds.finish()
     }
 }
-pub enum RandomUniformAttrsOffset {}
+pub enum GatherBlockQuantizedAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformAttrs<'a> {
+pub struct GatherBlockQuantizedAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
-    type Inner = RandomUniformAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherBlockQuantizedAttrs<'a> {
+    type Inner = GatherBlockQuantizedAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5287,78 +5767,50 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformAttrs<'a> {
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
-    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
-    pub const VT_LOW: flatbuffers::VOffsetT = 8;
-    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+impl<'a> GatherBlockQuantizedAttrs<'a> {
+    pub const VT_BITS: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformAttrs { _tab: table }
+        GatherBlockQuantizedAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args RandomUniformAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
-        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
-        }
+        args: &'args GatherBlockQuantizedAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherBlockQuantizedAttrs<'bldr>> {
+        let mut builder = GatherBlockQuantizedAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.add_bits(args.bits);
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomUniformAttrs::VT_SHAPE,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn high(&self) -> f32 {
+    pub fn bits(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .get::<u32>(GatherBlockQuantizedAttrs::VT_BITS, Some(4))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn block_size(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .get::<u32>(GatherBlockQuantizedAttrs::VT_BLOCK_SIZE, Some(128))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+impl flatbuffers::Verifiable for GatherBlockQuantizedAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5366,97 +5818,75 @@ impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<u32>("bits", Self::VT_BITS, false)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformAttrsArgs<'a> {
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct GatherBlockQuantizedAttrsArgs {
+    pub bits: u32,
+    pub block_size: u32,
 }
-impl<'a> Default for RandomUniformAttrsArgs<'a> {
+impl<'a> Default for GatherBlockQuantizedAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomUniformAttrsArgs {
-            shape: None,
-            high: 0.0,
-            low: 0.0,
-            seed: None,
+        GatherBlockQuantizedAttrsArgs {
+            bits: 4,
+            block_size: 128,
         }
     }
 }
 
-pub struct RandomUniformAttrsBuilder<'a: 'b, 'b> {
+pub struct GatherBlockQuantizedAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RandomUniformAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
-    }
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
-    }
+impl<'a: 'b, 'b> GatherBlockQuantizedAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_low(&mut self, low: f32) {
+    pub fn add_bits(&mut self, bits: u32) {
         self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+            .push_slot::<u32>(GatherBlockQuantizedAttrs::VT_BITS, bits, 4);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_block_size(&mut self, block_size: u32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+            .push_slot::<u32>(GatherBlockQuantizedAttrs::VT_BLOCK_SIZE, block_size, 128);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> RandomUniformAttrsBuilder<'a, 'b> {
+    ) -> GatherBlockQuantizedAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        RandomUniformAttrsBuilder {
+        GatherBlockQuantizedAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherBlockQuantizedAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformAttrs<'_> {
+impl core::fmt::Debug for GatherBlockQuantizedAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformAttrs");
-        ds.field("shape", &self.shape());
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("GatherBlockQuantizedAttrs");
+        ds.field("bits", &self.bits());
+        ds.field("block_size", &self.block_size());
         ds.finish()
     }
 }
-pub enum RandomUniformLikeAttrsOffset {}
+pub enum GeluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformLikeAttrs<'a> {
+pub struct GeluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
-    type Inner = RandomUniformLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
+    type Inner = GeluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5465,61 +5895,37 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformLikeAttrs<'a> {
-    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
-    pub const VT_LOW: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> GeluAttrs<'a> {
+    pub const VT_APPROXIMATE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformLikeAttrs { _tab: table }
+        GeluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args RandomUniformLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
-        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
+        args: &'args GeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
+        let mut builder = GeluAttrsBuilder::new(_fbb);
+        builder.add_approximate(args.approximate);
         builder.finish()
     }
 
     #[inline]
-    pub fn high(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn approximate(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
+                .get::<bool>(GeluAttrs::VT_APPROXIMATE, Some(false))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+impl flatbuffers::Verifiable for GeluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5527,84 +5933,62 @@ impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<bool>("approximate", Self::VT_APPROXIMATE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformLikeAttrsArgs {
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct GeluAttrsArgs {
+    pub approximate: bool,
 }
-impl<'a> Default for RandomUniformLikeAttrsArgs {
+impl<'a> Default for GeluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomUniformLikeAttrsArgs {
-            high: 0.0,
-            low: 0.0,
-            seed: None,
-        }
+        GeluAttrsArgs { approximate: false }
     }
 }
 
-pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b> {
+pub struct GeluAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RandomUniformLikeAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
-    }
-    #[inline]
-    pub fn add_low(&mut self, low: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
-    }
+impl<'a: 'b, 'b> GeluAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_approximate(&mut self, approximate: bool) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+            .push_slot::<bool>(GeluAttrs::VT_APPROXIMATE, approximate, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> RandomUniformLikeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GeluAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        RandomUniformLikeAttrsBuilder {
+        GeluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+impl core::fmt::Debug for GeluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("GeluAttrs");
+        ds.field("approximate", &self.approximate());
         ds.finish()
     }
 }
-pub enum ReduceMeanAttrsOffset {}
+pub enum GemmAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReduceMeanAttrs<'a> {
+pub struct GemmAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
-    type Inner = ReduceMeanAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
+    type Inner = GemmAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5613,54 +5997,72 @@ impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
     }
 }
 
-impl<'a> ReduceMeanAttrs<'a> {
-    pub const VT_AXES: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+impl<'a> GemmAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
+    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReduceMeanAttrs { _tab: table }
+        GemmAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ReduceMeanAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
-        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
-        if let Some(x) = args.axes {
-            builder.add_axes(x);
-        }
-        builder.add_keep_dims(args.keep_dims);
+        args: &'args GemmAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
+        let mut builder = GemmAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
+        builder.add_transpose_b(args.transpose_b);
+        builder.add_transpose_a(args.transpose_a);
         builder.finish()
     }
 
     #[inline]
-    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    ReduceMeanAttrs::VT_AXES,
-                    None,
-                )
+                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
         }
     }
     #[inline]
-    pub fn keep_dims(&self) -> bool {
+    pub fn beta(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
+    }
+    #[inline]
+    pub fn transpose_a(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn transpose_b(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+impl flatbuffers::Verifiable for GemmAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5668,77 +6070,89 @@ impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
-                "axes",
-                Self::VT_AXES,
-                false,
-            )?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
+            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReduceMeanAttrsArgs<'a> {
-    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
-    pub keep_dims: bool,
+pub struct GemmAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
+    pub transpose_a: bool,
+    pub transpose_b: bool,
 }
-impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+impl<'a> Default for GemmAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReduceMeanAttrsArgs {
-            axes: None,
-            keep_dims: false,
+        GemmAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
+            transpose_a: false,
+            transpose_b: false,
         }
     }
 }
 
-pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b> {
+pub struct GemmAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ReduceMeanAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> GemmAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
+    }
+    #[inline]
+    pub fn add_transpose_a(&mut self, transpose_a: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
     }
     #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+    pub fn add_transpose_b(&mut self, transpose_b: bool) {
         self.fbb_
-            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReduceMeanAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GemmAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ReduceMeanAttrsBuilder {
+        GemmAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+impl core::fmt::Debug for GemmAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReduceMeanAttrs");
-        ds.field("axes", &self.axes());
-        ds.field("keep_dims", &self.keep_dims());
+        let mut ds = f.debug_struct("GemmAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
+        ds.field("transpose_a", &self.transpose_a());
+        ds.field("transpose_b", &self.transpose_b());
         ds.finish()
     }
 }
-pub enum ReshapeAttrsOffset {}
+pub enum GRUAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReshapeAttrs<'a> {
+pub struct GRUAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
-    type Inner = ReshapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
+    type Inner = GRUAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5747,37 +6161,63 @@ impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
     }
 }
 
-impl<'a> ReshapeAttrs<'a> {
-    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+impl<'a> GRUAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReshapeAttrs { _tab: table }
+        GRUAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ReshapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
-        let mut builder = ReshapeAttrsBuilder::new(_fbb);
-        builder.add_allow_zero(args.allow_zero);
+        args: &'args GRUAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
+        let mut builder = GRUAttrsBuilder::new(_fbb);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_linear_before_reset(args.linear_before_reset);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn allow_zero(&self) -> bool {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn linear_before_reset(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+impl flatbuffers::Verifiable for GRUAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5785,62 +6225,85 @@ impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReshapeAttrsArgs {
-    pub allow_zero: bool,
+pub struct GRUAttrsArgs {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub linear_before_reset: bool,
 }
-impl<'a> Default for ReshapeAttrsArgs {
+impl<'a> Default for GRUAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReshapeAttrsArgs { allow_zero: false }
+        GRUAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            linear_before_reset: false,
+        }
     }
 }
 
-pub struct ReshapeAttrsBuilder<'a: 'b, 'b> {
+pub struct GRUAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ReshapeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> GRUAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            GRUAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReshapeAttrsBuilder<'a, 'b> {
+    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
+        self.fbb_
+            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GRUAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ReshapeAttrsBuilder {
+        GRUAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReshapeAttrs<'_> {
+impl core::fmt::Debug for GRUAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReshapeAttrs");
-        ds.field("allow_zero", &self.allow_zero());
+        let mut ds = f.debug_struct("GRUAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("linear_before_reset", &self.linear_before_reset());
         ds.finish()
     }
 }
-pub enum ResizeAttrsOffset {}
+pub enum GridSampleAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ResizeAttrs<'a> {
+pub struct GridSampleAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
-    type Inner = ResizeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GridSampleAttrs<'a> {
+    type Inner = GridSampleAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5849,23 +6312,23 @@ impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
     }
 }
 
-impl<'a> ResizeAttrs<'a> {
+impl<'a> GridSampleAttrs<'a> {
     pub const VT_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+    pub const VT_PADDING_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_ALIGN_CORNERS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ResizeAttrs { _tab: table }
+        GridSampleAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ResizeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
-        let mut builder = ResizeAttrsBuilder::new(_fbb);
-        builder.add_nearest_mode(args.nearest_mode);
-        builder.add_coord_mode(args.coord_mode);
+        args: &'args GridSampleAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GridSampleAttrs<'bldr>> {
+        let mut builder = GridSampleAttrsBuilder::new(_fbb);
+        builder.add_align_corners(args.align_corners);
+        builder.add_padding_mode(args.padding_mode);
         builder.add_mode(args.mode);
         builder.finish()
     }
@@ -5877,38 +6340,38 @@ impl<'a> ResizeAttrs<'a> {
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .get::<ResizeMode>(GridSampleAttrs::VT_MODE, Some(ResizeMode::Nearest))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn coord_mode(&self) -> CoordTransformMode {
+    pub fn padding_mode(&self) -> GridSamplePadding {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<CoordTransformMode>(
-                    ResizeAttrs::VT_COORD_MODE,
-                    Some(CoordTransformMode::HalfPixel),
+                .get::<GridSamplePadding>(
+                    GridSampleAttrs::VT_PADDING_MODE,
+                    Some(GridSamplePadding::Zeros),
                 )
                 .unwrap()
         }
     }
     #[inline]
-    pub fn nearest_mode(&self) -> NearestMode {
+    pub fn align_corners(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .get::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+impl flatbuffers::Verifiable for GridSampleAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5917,87 +6380,84 @@ impl flatbuffers::Verifiable for ResizeAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
-            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
-            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<GridSamplePadding>("padding_mode", Self::VT_PADDING_MODE, false)?
+            .visit_field::<bool>("align_corners", Self::VT_ALIGN_CORNERS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ResizeAttrsArgs {
+pub struct GridSampleAttrsArgs {
     pub mode: ResizeMode,
-    pub coord_mode: CoordTransformMode,
-    pub nearest_mode: NearestMode,
+    pub padding_mode: GridSamplePadding,
+    pub align_corners: bool,
 }
-impl<'a> Default for ResizeAttrsArgs {
+impl<'a> Default for GridSampleAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ResizeAttrsArgs {
+        GridSampleAttrsArgs {
             mode: ResizeMode::Nearest,
-            coord_mode: CoordTransformMode::HalfPixel,
-            nearest_mode: NearestMode::Floor,
+            padding_mode: GridSamplePadding::Zeros,
+            align_corners: false,
         }
     }
 }
 
-pub struct ResizeAttrsBuilder<'a: 'b, 'b> {
+pub struct GridSampleAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ResizeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> GridSampleAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_mode(&mut self, mode: ResizeMode) {
         self.fbb_
-            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
+            .push_slot::<ResizeMode>(GridSampleAttrs::VT_MODE, mode, ResizeMode::Nearest);
     }
     #[inline]
-    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
-        self.fbb_.push_slot::<CoordTransformMode>(
-            ResizeAttrs::VT_COORD_MODE,
-            coord_mode,
-            CoordTransformMode::HalfPixel,
+    pub fn add_padding_mode(&mut self, padding_mode: GridSamplePadding) {
+        self.fbb_.push_slot::<GridSamplePadding>(
+            GridSampleAttrs::VT_PADDING_MODE,
+            padding_mode,
+            GridSamplePadding::Zeros,
         );
     }
     #[inline]
-    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
-        self.fbb_.push_slot::<NearestMode>(
-            ResizeAttrs::VT_NEAREST_MODE,
-            nearest_mode,
-            NearestMode::Floor,
-        );
+    pub fn add_align_corners(&mut self, align_corners: bool) {
+        self.fbb_
+            .push_slot::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, align_corners, false);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResizeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GridSampleAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ResizeAttrsBuilder {
+        GridSampleAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GridSampleAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ResizeAttrs<'_> {
+impl core::fmt::Debug for GridSampleAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ResizeAttrs");
+        let mut ds = f.debug_struct("GridSampleAttrs");
         ds.field("mode", &self.mode());
-        ds.field("coord_mode", &self.coord_mode());
-        ds.field("nearest_mode", &self.nearest_mode());
+        ds.field("padding_mode", &self.padding_mode());
+        ds.field("align_corners", &self.align_corners());
         ds.finish()
     }
 }
-pub enum ScatterElementsAttrsOffset {}
+pub enum HardSigmoidAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterElementsAttrs<'a> {
+pub struct HardSigmoidAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
-    type Inner = ScatterElementsAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
+    type Inner = HardSigmoidAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6006,53 +6466,50 @@ impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
     }
 }
 
-impl<'a> ScatterElementsAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+impl<'a> HardSigmoidAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterElementsAttrs { _tab: table }
+        HardSigmoidAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ScatterElementsAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
-        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_reduction(args.reduction);
+        args: &'args HardSigmoidAttrsArgs,
+    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
+        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn beta(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(
-                    ScatterElementsAttrs::VT_REDUCTION,
-                    Some(ScatterReduction::None),
-                )
+                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6060,78 +6517,75 @@ impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterElementsAttrsArgs {
-    pub axis: i32,
-    pub reduction: ScatterReduction,
+pub struct HardSigmoidAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
 }
-impl<'a> Default for ScatterElementsAttrsArgs {
+impl<'a> Default for HardSigmoidAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ScatterElementsAttrsArgs {
-            axis: 0,
-            reduction: ScatterReduction::None,
+        HardSigmoidAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
         }
     }
 }
 
-pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b> {
+pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ScatterElementsAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> HardSigmoidAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterElementsAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_
+            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> ScatterElementsAttrsBuilder<'a, 'b> {
+    ) -> HardSigmoidAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ScatterElementsAttrsBuilder {
+        HardSigmoidAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+impl core::fmt::Debug for HardSigmoidAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterElementsAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("HardSigmoidAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
         ds.finish()
     }
 }
-pub enum ScatterNDAttrsOffset {}
+pub enum ImageToTensorAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterNDAttrs<'a> {
+pub struct ImageToTensorAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
-    type Inner = ScatterNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ImageToTensorAttrs<'a> {
+    type Inner = ImageToTensorAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6140,37 +6594,73 @@ impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
     }
 }
 
-impl<'a> ScatterNDAttrs<'a> {
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+impl<'a> ImageToTensorAttrs<'a> {
+    pub const VT_SCALE: flatbuffers::VOffsetT = 4;
+    pub const VT_MEAN: flatbuffers::VOffsetT = 6;
+    pub const VT_STD: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterNDAttrs { _tab: table }
+        ImageToTensorAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ScatterNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
-        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
-        builder.add_reduction(args.reduction);
+        args: &'args ImageToTensorAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ImageToTensorAttrs<'bldr>> {
+        let mut builder = ImageToTensorAttrsBuilder::new(_fbb);
+        if let Some(x) = args.std {
+            builder.add_std(x);
+        }
+        if let Some(x) = args.mean {
+            builder.add_mean(x);
+        }
+        builder.add_scale(args.scale);
         builder.finish()
     }
 
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn scale(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .get::<f32>(ImageToTensorAttrs::VT_SCALE, Some(1.0))
                 .unwrap()
         }
     }
+
+    #[inline]
+    pub fn mean(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    ImageToTensorAttrs::VT_MEAN,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn std(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    ImageToTensorAttrs::VT_STD,
+                    None,
+                )
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+impl flatbuffers::Verifiable for ImageToTensorAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6178,67 +6668,92 @@ impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "mean",
+                Self::VT_MEAN,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "std",
+                Self::VT_STD,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterNDAttrsArgs {
-    pub reduction: ScatterReduction,
+pub struct ImageToTensorAttrsArgs<'a> {
+    pub scale: f32,
+    pub mean: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub std: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
 }
-impl<'a> Default for ScatterNDAttrsArgs {
+impl<'a> Default for ImageToTensorAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ScatterNDAttrsArgs {
-            reduction: ScatterReduction::None,
+        ImageToTensorAttrsArgs {
+            scale: 1.0,
+            mean: None,
+            std: None,
         }
     }
 }
 
-pub struct ScatterNDAttrsBuilder<'a: 'b, 'b> {
+pub struct ImageToTensorAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ScatterNDAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ImageToTensorAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterNDAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(ImageToTensorAttrs::VT_SCALE, scale, 1.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScatterNDAttrsBuilder<'a, 'b> {
+    pub fn add_mean(&mut self, mean: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ImageToTensorAttrs::VT_MEAN, mean);
+    }
+    #[inline]
+    pub fn add_std(&mut self, std: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ImageToTensorAttrs::VT_STD, std);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ImageToTensorAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ScatterNDAttrsBuilder {
+        ImageToTensorAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ImageToTensorAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterNDAttrs<'_> {
+impl core::fmt::Debug for ImageToTensorAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterNDAttrs");
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("ImageToTensorAttrs");
+        ds.field("scale", &self.scale());
+        ds.field("mean", &self.mean());
+        ds.field("std", &self.std());
         ds.finish()
     }
 }
-pub enum SoftmaxAttrsOffset {}
+pub enum LeakyReluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SoftmaxAttrs<'a> {
+pub struct LeakyReluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
-    type Inner = SoftmaxAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
+    type Inner = LeakyReluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6247,37 +6762,37 @@ impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
     }
 }
 
-impl<'a> SoftmaxAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> LeakyReluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SoftmaxAttrs { _tab: table }
+        LeakyReluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args SoftmaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
-        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args LeakyReluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
+        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6285,61 +6800,62 @@ impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SoftmaxAttrsArgs {
-    pub axis: i32,
+pub struct LeakyReluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for SoftmaxAttrsArgs {
+impl<'a> Default for LeakyReluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SoftmaxAttrsArgs { axis: 0 }
+        LeakyReluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct SoftmaxAttrsBuilder<'a: 'b, 'b> {
+pub struct LeakyReluAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> SoftmaxAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LeakyReluAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_
+            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SoftmaxAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LeakyReluAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        SoftmaxAttrsBuilder {
+        LeakyReluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SoftmaxAttrs<'_> {
+impl core::fmt::Debug for LeakyReluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SoftmaxAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("LeakyReluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum SplitAttrsOffset {}
+pub enum LpNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SplitAttrs<'a> {
+pub struct LpNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
-    type Inner = SplitAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LpNormalizationAttrs<'a> {
+    type Inner = LpNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6348,19 +6864,21 @@ impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
     }
 }
 
-impl<'a> SplitAttrs<'a> {
+impl<'a> LpNormalizationAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_P: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SplitAttrs { _tab: table }
+        LpNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args SplitAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
-        let mut builder = SplitAttrsBuilder::new(_fbb);
+        args: &'args LpNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LpNormalizationAttrs<'bldr>> {
+        let mut builder = LpNormalizationAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
         builder.add_axis(args.axis);
         builder.finish()
     }
@@ -6370,73 +6888,98 @@ impl<'a> SplitAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<i32>(LpNormalizationAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
     }
-}
-
-impl flatbuffers::Verifiable for SplitAttrs<'_> {
     #[inline]
-    fn run_verifier(
-        v: &mut flatbuffers::Verifier,
-        pos: usize,
-    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    pub fn p(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(LpNormalizationAttrs::VT_P, Some(2))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for LpNormalizationAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<i32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SplitAttrsArgs {
+pub struct LpNormalizationAttrsArgs {
     pub axis: i32,
+    pub p: i32,
 }
-impl<'a> Default for SplitAttrsArgs {
+impl<'a> Default for LpNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SplitAttrsArgs { axis: 0 }
+        LpNormalizationAttrsArgs { axis: 0, p: 2 }
     }
 }
 
-pub struct SplitAttrsBuilder<'a: 'b, 'b> {
+pub struct LpNormalizationAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> SplitAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LpNormalizationAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+        self.fbb_
+            .push_slot::<i32>(LpNormalizationAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SplitAttrsBuilder<'a, 'b> {
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(LpNormalizationAttrs::VT_P, p, 2);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> LpNormalizationAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        SplitAttrsBuilder {
+        LpNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SplitAttrs<'_> {
+impl core::fmt::Debug for LpNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SplitAttrs");
+        let mut ds = f.debug_struct("LpNormalizationAttrs");
         ds.field("axis", &self.axis());
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum TopKAttrsOffset {}
+pub enum LpPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TopKAttrs<'a> {
+pub struct LpPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
-    type Inner = TopKAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LpPoolAttrs<'a> {
+    type Inner = LpPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6445,59 +6988,128 @@ impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
     }
 }
 
-impl<'a> TopKAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
-    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+impl<'a> LpPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 14;
+    pub const VT_P: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TopKAttrs { _tab: table }
+        LpPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TopKAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
-        let mut builder = TopKAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_sorted(args.sorted);
-        builder.add_largest(args.largest);
+        args: &'args LpPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LpPoolAttrs<'bldr>> {
+        let mut builder = LpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_pad_mode(args.pad_mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
     }
     #[inline]
-    pub fn largest(&self) -> bool {
+    pub fn pad_mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .get::<PadMode>(LpPoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn sorted(&self) -> bool {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LpPoolAttrs::VT_CEIL_MODE, Some(false))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn p(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(LpPoolAttrs::VT_P, Some(2)).unwrap() }
+    }
 }
 
-impl flatbuffers::Verifiable for TopKAttrs<'_> {
+impl flatbuffers::Verifiable for LpPoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6505,81 +7117,143 @@ impl flatbuffers::Verifiable for TopKAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
-            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<i32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TopKAttrsArgs {
-    pub axis: i32,
-    pub largest: bool,
-    pub sorted: bool,
+pub struct LpPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub p: i32,
 }
-impl<'a> Default for TopKAttrsArgs {
+impl<'a> Default for LpPoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TopKAttrsArgs {
-            axis: 0,
-            largest: false,
-            sorted: false,
+        LpPoolAttrsArgs {
+            kernel_size: None, // required field
+            pad_mode: PadMode::Same,
+            pads: None,
+            strides: None,
+            ceil_mode: false,
+            dilations: None,
+            p: 2,
         }
     }
 }
 
-pub struct TopKAttrsBuilder<'a: 'b, 'b> {
+pub struct LpPoolAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TopKAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LpPoolAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            LpPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
     }
     #[inline]
-    pub fn add_largest(&mut self, largest: bool) {
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
         self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+            .push_slot::<PadMode>(LpPoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
     }
     #[inline]
-    pub fn add_sorted(&mut self, sorted: bool) {
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TopKAttrsBuilder<'a, 'b> {
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(LpPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(LpPoolAttrs::VT_P, p, 2);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LpPoolAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TopKAttrsBuilder {
+        LpPoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, LpPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TopKAttrs<'_> {
+impl core::fmt::Debug for LpPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TopKAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("largest", &self.largest());
-        ds.field("sorted", &self.sorted());
+        let mut ds = f.debug_struct("LpPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("dilations", &self.dilations());
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum TransposeAttrsOffset {}
+pub enum LSTMAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TransposeAttrs<'a> {
+pub struct LSTMAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
-    type Inner = TransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
+    type Inner = LSTMAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6588,41 +7262,50 @@ impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
     }
 }
 
-impl<'a> TransposeAttrs<'a> {
-    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+impl<'a> LSTMAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TransposeAttrs { _tab: table }
+        LSTMAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
-        let mut builder = TransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.perm {
-            builder.add_perm(x);
-        }
+        args: &'args LSTMAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
+        let mut builder = LSTMAttrsBuilder::new(_fbb);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    TransposeAttrs::VT_PERM,
-                    None,
-                )
+                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6630,66 +7313,76 @@ impl flatbuffers::Verifiable for TransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "perm",
-                Self::VT_PERM,
-                false,
-            )?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TransposeAttrsArgs<'a> {
-    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct LSTMAttrsArgs {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
 }
-impl<'a> Default for TransposeAttrsArgs<'a> {
+impl<'a> Default for LSTMAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TransposeAttrsArgs { perm: None }
+        LSTMAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+        }
     }
 }
 
-pub struct TransposeAttrsBuilder<'a: 'b, 'b> {
+pub struct LSTMAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TransposeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LSTMAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            LSTMAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransposeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LSTMAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TransposeAttrsBuilder {
+        LSTMAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TransposeAttrs<'_> {
+impl core::fmt::Debug for LSTMAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TransposeAttrs");
-        ds.field("perm", &self.perm());
+        let mut ds = f.debug_struct("LSTMAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
         ds.finish()
     }
 }
-pub enum TriluAttrsOffset {}
+pub enum MatMulNBitsAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TriluAttrs<'a> {
+pub struct MatMulNBitsAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
-    type Inner = TriluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MatMulNBitsAttrs<'a> {
+    type Inner = MatMulNBitsAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6698,37 +7391,50 @@ impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
     }
 }
 
-impl<'a> TriluAttrs<'a> {
-    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+impl<'a> MatMulNBitsAttrs<'a> {
+    pub const VT_BITS: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TriluAttrs { _tab: table }
+        MatMulNBitsAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TriluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
-        let mut builder = TriluAttrsBuilder::new(_fbb);
-        builder.add_upper(args.upper);
+        args: &'args MatMulNBitsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<MatMulNBitsAttrs<'bldr>> {
+        let mut builder = MatMulNBitsAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.add_bits(args.bits);
         builder.finish()
     }
 
     #[inline]
-    pub fn upper(&self) -> bool {
+    pub fn bits(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .get::<u32>(MatMulNBitsAttrs::VT_BITS, Some(4))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(MatMulNBitsAttrs::VT_BLOCK_SIZE, Some(128))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for TriluAttrs<'_> {
+impl flatbuffers::Verifiable for MatMulNBitsAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6736,62 +7442,75 @@ impl flatbuffers::Verifiable for TriluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .visit_field::<u32>("bits", Self::VT_BITS, false)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TriluAttrsArgs {
-    pub upper: bool,
+pub struct MatMulNBitsAttrsArgs {
+    pub bits: u32,
+    pub block_size: u32,
 }
-impl<'a> Default for TriluAttrsArgs {
+impl<'a> Default for MatMulNBitsAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TriluAttrsArgs { upper: false }
+        MatMulNBitsAttrsArgs {
+            bits: 4,
+            block_size: 128,
+        }
     }
 }
 
-pub struct TriluAttrsBuilder<'a: 'b, 'b> {
+pub struct MatMulNBitsAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TriluAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> MatMulNBitsAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_upper(&mut self, upper: bool) {
+    pub fn add_bits(&mut self, bits: u32) {
         self.fbb_
-            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+            .push_slot::<u32>(MatMulNBitsAttrs::VT_BITS, bits, 4);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TriluAttrsBuilder<'a, 'b> {
+    pub fn add_block_size(&mut self, block_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(MatMulNBitsAttrs::VT_BLOCK_SIZE, block_size, 128);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MatMulNBitsAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TriluAttrsBuilder {
+        MatMulNBitsAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MatMulNBitsAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TriluAttrs<'_> {
+impl core::fmt::Debug for MatMulNBitsAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TriluAttrs");
-        ds.field("upper", &self.upper());
+        let mut ds = f.debug_struct("MatMulNBitsAttrs");
+        ds.field("bits", &self.bits());
+        ds.field("block_size", &self.block_size());
         ds.finish()
     }
 }
-pub enum OperatorNodeOffset {}
+pub enum MaxPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OperatorNode<'a> {
+pub struct MaxPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
-    type Inner = OperatorNode<'a>;
+impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
+    type Inner = MaxPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6800,640 +7519,535 @@ impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
     }
 }
 
-impl<'a> OperatorNode<'a> {
-    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
-    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
-    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
-    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
+impl<'a> MaxPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OperatorNode { _tab: table }
+        MaxPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args OperatorNodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
-        let mut builder = OperatorNodeBuilder::new(_fbb);
-        if let Some(x) = args.outputs {
-            builder.add_outputs(x);
+        args: &'args MaxPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
+        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
         }
-        if let Some(x) = args.inputs {
-            builder.add_inputs(x);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
         }
-        if let Some(x) = args.attrs {
-            builder.add_attrs(x);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
         }
-        builder.add_attrs_type(args.attrs_type);
-        builder.add_type_(args.type_);
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_pad_mode(args.pad_mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn type_(&self) -> OperatorType {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
                 .unwrap()
         }
     }
     #[inline]
-    pub fn attrs_type(&self) -> OperatorAttrs {
+    pub fn pad_mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
+                .get::<PadMode>(MaxPoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    OperatorNode::VT_ATTRS,
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_PADS,
                     None,
                 )
         }
     }
     #[inline]
-    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_INPUTS,
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_STRIDES,
                     None,
                 )
         }
     }
     #[inline]
-    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn ceil_mode(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_OUTPUTS,
-                    None,
-                )
+                .get::<bool>(MaxPoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
         }
     }
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ArgMaxAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_DILATIONS,
+                    None,
+                )
         }
     }
+}
 
+impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { AveragePoolAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MaxPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for MaxPoolAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MaxPoolAttrsArgs {
+            kernel_size: None, // required field
+            pad_mode: PadMode::Same,
+            pads: None,
+            strides: None,
+            ceil_mode: false,
+            dilations: None,
         }
     }
+}
 
+pub struct MaxPoolAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MaxPoolAttrsBuilder<'a, 'b> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { BatchNormalizationAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MaxPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::CastAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { CastAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(MaxPoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ConcatAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ConvAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(MaxPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ConvTransposeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_DILATIONS, dilations);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { FlattenAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MaxPoolAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        MaxPoolAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
         }
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { GatherAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
+        flatbuffers::WIPOffset::new(o.value())
     }
+}
 
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GemmAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { GemmAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+impl core::fmt::Debug for MaxPoolAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MaxPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("dilations", &self.dilations());
+        ds.finish()
     }
+}
+pub enum MeanVarianceNormalizationAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
 
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GRUAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { GRUAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
+pub struct MeanVarianceNormalizationAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
 
+impl<'a> flatbuffers::Follow<'a> for MeanVarianceNormalizationAttrs<'a> {
+    type Inner = MeanVarianceNormalizationAttrs<'a>;
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { LeakyReluAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
 
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { LSTMAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
+impl<'a> MeanVarianceNormalizationAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { MaxPoolAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MeanVarianceNormalizationAttrs { _tab: table }
     }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ReduceMeanAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MeanVarianceNormalizationAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MeanVarianceNormalizationAttrs<'bldr>> {
+        let mut builder = MeanVarianceNormalizationAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
         }
+        builder.finish()
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ReshapeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    MeanVarianceNormalizationAttrs::VT_AXES,
+                    None,
+                )
         }
     }
+}
 
+impl flatbuffers::Verifiable for MeanVarianceNormalizationAttrs<'_> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ResizeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .finish();
+        Ok(())
     }
-
+}
+pub struct MeanVarianceNormalizationAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+}
+impl<'a> Default for MeanVarianceNormalizationAttrsArgs<'a> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SplitAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { SplitAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    fn default() -> Self {
+        MeanVarianceNormalizationAttrsArgs { axes: None }
     }
+}
 
+pub struct MeanVarianceNormalizationAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MeanVarianceNormalizationAttrsBuilder<'a, 'b> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { SoftmaxAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MeanVarianceNormalizationAttrs::VT_AXES,
+            axes,
+        );
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { TransposeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MeanVarianceNormalizationAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        MeanVarianceNormalizationAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
         }
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ModAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ModAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn finish(self) -> flatbuffers::WIPOffset<MeanVarianceNormalizationAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
     }
+}
 
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ScatterElementsAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+impl core::fmt::Debug for MeanVarianceNormalizationAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MeanVarianceNormalizationAttrs");
+        ds.field("axes", &self.axes());
+        ds.finish()
     }
+}
+pub enum ModAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
 
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { OneHotAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
+pub struct ModAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
 
+impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
+    type Inner = ModAttrs<'a>;
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TopKAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { TopKAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
+
+impl<'a> ModAttrs<'a> {
+    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { HardSigmoidAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ModAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ModAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
+        let mut builder = ModAttrsBuilder::new(_fbb);
+        builder.add_fmod(args.fmod);
+        builder.finish()
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TriluAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { TriluAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn fmod(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
+                .unwrap()
         }
     }
+}
 
+impl flatbuffers::Verifiable for ModAttrs<'_> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ScatterNDAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
+            .finish();
+        Ok(())
     }
-
+}
+pub struct ModAttrsArgs {
+    pub fmod: bool,
+}
+impl<'a> Default for ModAttrsArgs {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    fn default() -> Self {
+        ModAttrsArgs { fmod: false }
     }
+}
 
+pub struct ModAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ModAttrsBuilder<'a, 'b> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { LayerNormalizationAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn add_fmod(&mut self, fmod: bool) {
+        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
     }
-
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RandomUniformAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ModAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ModAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
         }
     }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ModAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ModAttrs");
+        ds.field("fmod", &self.fmod());
+        ds.finish()
+    }
+}
+pub enum MultinomialAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MultinomialAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
 
+impl<'a> flatbuffers::Follow<'a> for MultinomialAttrs<'a> {
+    type Inner = MultinomialAttrs<'a>;
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EluAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { EluAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
+
+impl<'a> MultinomialAttrs<'a> {
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_SAMPLE_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MultinomialAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MultinomialAttrsArgs,
+    ) -> flatbuffers::WIPOffset<MultinomialAttrs<'bldr>> {
+        let mut builder = MultinomialAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
         }
+        builder.add_sample_size(args.sample_size);
+        builder.add_dtype(args.dtype);
+        builder.finish()
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RandomNormalAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn dtype(&self) -> DataType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<DataType>(MultinomialAttrs::VT_DTYPE, Some(DataType::Int32))
+                .unwrap()
         }
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn sample_size(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(MultinomialAttrs::VT_SAMPLE_SIZE, Some(1))
+                .unwrap()
         }
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
-            self.attrs().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { GatherNDAttrs::init_from_table(t) }
-            })
-        } else {
-            None
-        }
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(MultinomialAttrs::VT_SEED, None) }
     }
 }
 
-impl flatbuffers::Verifiable for OperatorNode<'_> {
+impl flatbuffers::Verifiable for MultinomialAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7441,170 +8055,4951 @@ impl flatbuffers::Verifiable for OperatorNode<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-     .visit_field::<OperatorType>("type_", Self::VT_TYPE_, false)?
-     .visit_union::<OperatorAttrs, _>("attrs_type", Self::VT_ATTRS_TYPE, "attrs", Self::VT_ATTRS, false, |key, v, pos| {
-        match key {
-          OperatorAttrs::ArgMaxAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ArgMaxAttrs>>("OperatorAttrs::ArgMaxAttrs", pos),
-          OperatorAttrs::AveragePoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AveragePoolAttrs>>("OperatorAttrs::AveragePoolAttrs", pos),
-          OperatorAttrs::BatchNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BatchNormalizationAttrs>>("OperatorAttrs::BatchNormalizationAttrs", pos),
-          OperatorAttrs::CastAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CastAttrs>>("OperatorAttrs::CastAttrs", pos),
-          OperatorAttrs::ConcatAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConcatAttrs>>("OperatorAttrs::ConcatAttrs", pos),
-          OperatorAttrs::ConstantOfShapeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConstantOfShapeAttrs>>("OperatorAttrs::ConstantOfShapeAttrs", pos),
-          OperatorAttrs::ConvAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConvAttrs>>("OperatorAttrs::ConvAttrs", pos),
-          OperatorAttrs::ConvTransposeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConvTransposeAttrs>>("OperatorAttrs::ConvTransposeAttrs", pos),
-          OperatorAttrs::FlattenAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FlattenAttrs>>("OperatorAttrs::FlattenAttrs", pos),
-          OperatorAttrs::GatherAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherAttrs>>("OperatorAttrs::GatherAttrs", pos),
-          OperatorAttrs::GemmAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GemmAttrs>>("OperatorAttrs::GemmAttrs", pos),
-          OperatorAttrs::GRUAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GRUAttrs>>("OperatorAttrs::GRUAttrs", pos),
-          OperatorAttrs::LeakyReluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LeakyReluAttrs>>("OperatorAttrs::LeakyReluAttrs", pos),
-          OperatorAttrs::LSTMAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LSTMAttrs>>("OperatorAttrs::LSTMAttrs", pos),
-          OperatorAttrs::MaxPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MaxPoolAttrs>>("OperatorAttrs::MaxPoolAttrs", pos),
-          OperatorAttrs::ReduceMeanAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReduceMeanAttrs>>("OperatorAttrs::ReduceMeanAttrs", pos),
-          OperatorAttrs::ReshapeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReshapeAttrs>>("OperatorAttrs::ReshapeAttrs", pos),
-          OperatorAttrs::ResizeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResizeAttrs>>("OperatorAttrs::ResizeAttrs", pos),
-          OperatorAttrs::SplitAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SplitAttrs>>("OperatorAttrs::SplitAttrs", pos),
-          OperatorAttrs::SoftmaxAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SoftmaxAttrs>>("OperatorAttrs::SoftmaxAttrs", pos),
-          OperatorAttrs::TransposeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TransposeAttrs>>("OperatorAttrs::TransposeAttrs", pos),
-          OperatorAttrs::ModAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ModAttrs>>("OperatorAttrs::ModAttrs", pos),
-          OperatorAttrs::ScatterElementsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScatterElementsAttrs>>("OperatorAttrs::ScatterElementsAttrs", pos),
-          OperatorAttrs::OneHotAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OneHotAttrs>>("OperatorAttrs::OneHotAttrs", pos),
-          OperatorAttrs::TopKAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TopKAttrs>>("OperatorAttrs::TopKAttrs", pos),
-          OperatorAttrs::HardSigmoidAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HardSigmoidAttrs>>("OperatorAttrs::HardSigmoidAttrs", pos),
-          OperatorAttrs::TriluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TriluAttrs>>("OperatorAttrs::TriluAttrs", pos),
-          OperatorAttrs::ScatterNDAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScatterNDAttrs>>("OperatorAttrs::ScatterNDAttrs", pos),
-          OperatorAttrs::NonMaxSuppressionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NonMaxSuppressionAttrs>>("OperatorAttrs::NonMaxSuppressionAttrs", pos),
-          OperatorAttrs::LayerNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LayerNormalizationAttrs>>("OperatorAttrs::LayerNormalizationAttrs", pos),
-          OperatorAttrs::RandomUniformAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomUniformAttrs>>("OperatorAttrs::RandomUniformAttrs", pos),
-          OperatorAttrs::EluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EluAttrs>>("OperatorAttrs::EluAttrs", pos),
-          OperatorAttrs::RandomUniformLikeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomUniformLikeAttrs>>("OperatorAttrs::RandomUniformLikeAttrs", pos),
-          OperatorAttrs::RandomNormalAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomNormalAttrs>>("OperatorAttrs::RandomNormalAttrs", pos),
-          OperatorAttrs::RandomNormalLikeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomNormalLikeAttrs>>("OperatorAttrs::RandomNormalLikeAttrs", pos),
-          OperatorAttrs::GatherNDAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherNDAttrs>>("OperatorAttrs::GatherNDAttrs", pos),
-          _ => Ok(()),
-        }
-     })?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>("inputs", Self::VT_INPUTS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>("outputs", Self::VT_OUTPUTS, false)?
-     .finish();
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<i32>("sample_size", Self::VT_SAMPLE_SIZE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct OperatorNodeArgs<'a> {
-    pub type_: OperatorType,
-    pub attrs_type: OperatorAttrs,
-    pub attrs: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
-    pub inputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
-    pub outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+pub struct MultinomialAttrsArgs {
+    pub dtype: DataType,
+    pub sample_size: i32,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for OperatorNodeArgs<'a> {
+impl<'a> Default for MultinomialAttrsArgs {
     #[inline]
     fn default() -> Self {
-        OperatorNodeArgs {
-            type_: OperatorType::Add,
-            attrs_type: OperatorAttrs::NONE,
-            attrs: None,
-            inputs: None,
-            outputs: None,
+        MultinomialAttrsArgs {
+            dtype: DataType::Int32,
+            sample_size: 1,
+            seed: None,
         }
     }
 }
 
-pub struct OperatorNodeBuilder<'a: 'b, 'b> {
+pub struct MultinomialAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> OperatorNodeBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_type_(&mut self, type_: OperatorType) {
-        self.fbb_
-            .push_slot::<OperatorType>(OperatorNode::VT_TYPE_, type_, OperatorType::Add);
-    }
-    #[inline]
-    pub fn add_attrs_type(&mut self, attrs_type: OperatorAttrs) {
-        self.fbb_.push_slot::<OperatorAttrs>(
-            OperatorNode::VT_ATTRS_TYPE,
-            attrs_type,
-            OperatorAttrs::NONE,
-        );
-    }
+impl<'a: 'b, 'b> MultinomialAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_attrs(&mut self, attrs: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_dtype(&mut self, dtype: DataType) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_ATTRS, attrs);
+            .push_slot::<DataType>(MultinomialAttrs::VT_DTYPE, dtype, DataType::Int32);
     }
     #[inline]
-    pub fn add_inputs(&mut self, inputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+    pub fn add_sample_size(&mut self, sample_size: i32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_INPUTS, inputs);
+            .push_slot::<i32>(MultinomialAttrs::VT_SAMPLE_SIZE, sample_size, 1);
     }
     #[inline]
-    pub fn add_outputs(&mut self, outputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+    pub fn add_seed(&mut self, seed: f32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_OUTPUTS, outputs);
+            .push_slot_always::<f32>(MultinomialAttrs::VT_SEED, seed);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OperatorNodeBuilder<'a, 'b> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MultinomialAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        OperatorNodeBuilder {
+        MultinomialAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<OperatorNode<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MultinomialAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for OperatorNode<'_> {
+impl core::fmt::Debug for MultinomialAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("OperatorNode");
-        ds.field("type_", &self.type_());
-        ds.field("attrs_type", &self.attrs_type());
-        match self.attrs_type() {
-            OperatorAttrs::ArgMaxAttrs => {
-                if let Some(x) = self.attrs_as_arg_max_attrs() {
-                    ds.field("attrs", &x)
-                } else {
-                    ds.field(
-                        "attrs",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            OperatorAttrs::AveragePoolAttrs => {
-                if let Some(x) = self.attrs_as_average_pool_attrs() {
-                    ds.field("attrs", &x)
-                } else {
-                    ds.field(
-                        "attrs",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            OperatorAttrs::BatchNormalizationAttrs => {
-                if let Some(x) = self.attrs_as_batch_normalization_attrs() {
-                    ds.field("attrs", &x)
-                } else {
-                    ds.field(
-                        "attrs",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            OperatorAttrs::CastAttrs => {
-                if let Some(x) = self.attrs_as_cast_attrs() {
-                    ds.field("attrs", &x)
-                } else {
-                    ds.field(
-                        "attrs",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            OperatorAttrs::ConcatAttrs => {
-                if let Some(x) = self.attrs_as_concat_attrs() {
+        let mut ds = f.debug_struct("MultinomialAttrs");
+        ds.field("dtype", &self.dtype());
+        ds.field("sample_size", &self.sample_size());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum NonMaxSuppressionAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NonMaxSuppressionAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
+    type Inner = NonMaxSuppressionAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> NonMaxSuppressionAttrs<'a> {
+    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        NonMaxSuppressionAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args NonMaxSuppressionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
+        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
+        builder.add_box_order(args.box_order);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn box_order(&self) -> NMSBoxOrder {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<NMSBoxOrder>(
+                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
+                    Some(NMSBoxOrder::TopLeftBottomRight),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct NonMaxSuppressionAttrsArgs {
+    pub box_order: NMSBoxOrder,
+}
+impl<'a> Default for NonMaxSuppressionAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        NonMaxSuppressionAttrsArgs {
+            box_order: NMSBoxOrder::TopLeftBottomRight,
+        }
+    }
+}
+
+pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
+        self.fbb_.push_slot::<NMSBoxOrder>(
+            NonMaxSuppressionAttrs::VT_BOX_ORDER,
+            box_order,
+            NMSBoxOrder::TopLeftBottomRight,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        NonMaxSuppressionAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
+        ds.field("box_order", &self.box_order());
+        ds.finish()
+    }
+}
+pub enum NormalizeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NormalizeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NormalizeAttrs<'a> {
+    type Inner = NormalizeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> NormalizeAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_STD: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        NormalizeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args NormalizeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<NormalizeAttrs<'bldr>> {
+        let mut builder = NormalizeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.std {
+            builder.add_std(x);
+        }
+        if let Some(x) = args.mean {
+            builder.add_mean(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mean(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    NormalizeAttrs::VT_MEAN,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn std(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    NormalizeAttrs::VT_STD,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for NormalizeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "mean",
+                Self::VT_MEAN,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "std",
+                Self::VT_STD,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct NormalizeAttrsArgs<'a> {
+    pub mean: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub std: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for NormalizeAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        NormalizeAttrsArgs {
+            mean: None,
+            std: None,
+        }
+    }
+}
+
+pub struct NormalizeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NormalizeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mean(&mut self, mean: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(NormalizeAttrs::VT_MEAN, mean);
+    }
+    #[inline]
+    pub fn add_std(&mut self, std: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(NormalizeAttrs::VT_STD, std);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NormalizeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        NormalizeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<NormalizeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for NormalizeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("NormalizeAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("std", &self.std());
+        ds.finish()
+    }
+}
+pub enum OneHotAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OneHotAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
+    type Inner = OneHotAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> OneHotAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OneHotAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args OneHotAttrsArgs,
+    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
+        let mut builder = OneHotAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+}
+
+impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct OneHotAttrsArgs {
+    pub axis: i32,
+}
+impl<'a> Default for OneHotAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        OneHotAttrsArgs { axis: 0 }
+    }
+}
+
+pub struct OneHotAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OneHotAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OneHotAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        OneHotAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for OneHotAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("OneHotAttrs");
+        ds.field("axis", &self.axis());
+        ds.finish()
+    }
+}
+pub enum PadAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PadAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PadAttrs<'a> {
+    type Inner = PadAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> PadAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PadAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PadAttrsArgs,
+    ) -> flatbuffers::WIPOffset<PadAttrs<'bldr>> {
+        let mut builder = PadAttrsBuilder::new(_fbb);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> PadOpMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadOpMode>(PadAttrs::VT_MODE, Some(PadOpMode::Constant))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PadAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<PadOpMode>("mode", Self::VT_MODE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct PadAttrsArgs {
+    pub mode: PadOpMode,
+}
+impl<'a> Default for PadAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        PadAttrsArgs {
+            mode: PadOpMode::Constant,
+        }
+    }
+}
+
+pub struct PadAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PadAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: PadOpMode) {
+        self.fbb_
+            .push_slot::<PadOpMode>(PadAttrs::VT_MODE, mode, PadOpMode::Constant);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PadAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        PadAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<PadAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for PadAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("PadAttrs");
+        ds.field("mode", &self.mode());
+        ds.finish()
+    }
+}
+pub enum PermuteChannelsAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PermuteChannelsAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PermuteChannelsAttrs<'a> {
+    type Inner = PermuteChannelsAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> PermuteChannelsAttrs<'a> {
+    pub const VT_ORDER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PermuteChannelsAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PermuteChannelsAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<PermuteChannelsAttrs<'bldr>> {
+        let mut builder = PermuteChannelsAttrsBuilder::new(_fbb);
+        if let Some(x) = args.order {
+            builder.add_order(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn order(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    PermuteChannelsAttrs::VT_ORDER,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PermuteChannelsAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "order",
+                Self::VT_ORDER,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct PermuteChannelsAttrsArgs<'a> {
+    pub order: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for PermuteChannelsAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PermuteChannelsAttrsArgs { order: None }
+    }
+}
+
+pub struct PermuteChannelsAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PermuteChannelsAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_order(&mut self, order: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(PermuteChannelsAttrs::VT_ORDER, order);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> PermuteChannelsAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        PermuteChannelsAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<PermuteChannelsAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for PermuteChannelsAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("PermuteChannelsAttrs");
+        ds.field("order", &self.order());
+        ds.finish()
+    }
+}
+pub enum QLinearConvAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QLinearConvAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QLinearConvAttrs<'a> {
+    type Inner = QLinearConvAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> QLinearConvAttrs<'a> {
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+    pub const VT_OUTPUT_DTYPE: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        QLinearConvAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args QLinearConvAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<QLinearConvAttrs<'bldr>> {
+        let mut builder = QLinearConvAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        builder.add_output_dtype(args.output_dtype);
+        builder.add_pad_mode(args.pad_mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn pad_mode(&self) -> PadMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadMode>(QLinearConvAttrs::VT_PAD_MODE, Some(PadMode::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    QLinearConvAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(QLinearConvAttrs::VT_GROUPS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    QLinearConvAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    QLinearConvAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn output_dtype(&self) -> DataType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<DataType>(QLinearConvAttrs::VT_OUTPUT_DTYPE, Some(DataType::UInt8))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for QLinearConvAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<DataType>("output_dtype", Self::VT_OUTPUT_DTYPE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct QLinearConvAttrsArgs<'a> {
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub output_dtype: DataType,
+}
+impl<'a> Default for QLinearConvAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        QLinearConvAttrsArgs {
+            pad_mode: PadMode::Same,
+            pads: None,
+            groups: 0,
+            strides: None,
+            dilations: None,
+            output_dtype: DataType::UInt8,
+        }
+    }
+}
+
+pub struct QLinearConvAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QLinearConvAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(QLinearConvAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(QLinearConvAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(QLinearConvAttrs::VT_GROUPS, groups, 0);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(QLinearConvAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            QLinearConvAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
+    pub fn add_output_dtype(&mut self, output_dtype: DataType) {
+        self.fbb_.push_slot::<DataType>(
+            QLinearConvAttrs::VT_OUTPUT_DTYPE,
+            output_dtype,
+            DataType::UInt8,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> QLinearConvAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        QLinearConvAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<QLinearConvAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for QLinearConvAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("QLinearConvAttrs");
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
+        ds.field("output_dtype", &self.output_dtype());
+        ds.finish()
+    }
+}
+pub enum QLinearMatMulAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QLinearMatMulAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QLinearMatMulAttrs<'a> {
+    type Inner = QLinearMatMulAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> QLinearMatMulAttrs<'a> {
+    pub const VT_OUTPUT_DTYPE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        QLinearMatMulAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args QLinearMatMulAttrsArgs,
+    ) -> flatbuffers::WIPOffset<QLinearMatMulAttrs<'bldr>> {
+        let mut builder = QLinearMatMulAttrsBuilder::new(_fbb);
+        builder.add_output_dtype(args.output_dtype);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn output_dtype(&self) -> DataType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<DataType>(QLinearMatMulAttrs::VT_OUTPUT_DTYPE, Some(DataType::UInt8))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for QLinearMatMulAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<DataType>("output_dtype", Self::VT_OUTPUT_DTYPE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct QLinearMatMulAttrsArgs {
+    pub output_dtype: DataType,
+}
+impl<'a> Default for QLinearMatMulAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        QLinearMatMulAttrsArgs {
+            output_dtype: DataType::UInt8,
+        }
+    }
+}
+
+pub struct QLinearMatMulAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QLinearMatMulAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_output_dtype(&mut self, output_dtype: DataType) {
+        self.fbb_.push_slot::<DataType>(
+            QLinearMatMulAttrs::VT_OUTPUT_DTYPE,
+            output_dtype,
+            DataType::UInt8,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> QLinearMatMulAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        QLinearMatMulAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<QLinearMatMulAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for QLinearMatMulAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("QLinearMatMulAttrs");
+        ds.field("output_dtype", &self.output_dtype());
+        ds.finish()
+    }
+}
+pub enum RandomNormalAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomNormalAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
+    type Inner = RandomNormalAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomNormalAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomNormalAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args RandomNormalAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
+        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mean(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
+    }
+    #[inline]
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    RandomNormalAttrs::VT_SHAPE,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomNormalAttrsArgs<'a> {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for RandomNormalAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RandomNormalAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+            shape: None,
+        }
+    }
+}
+
+pub struct RandomNormalAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RandomNormalAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mean(&mut self, mean: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> RandomNormalAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        RandomNormalAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomNormalAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomNormalAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
+        ds.field("shape", &self.shape());
+        ds.finish()
+    }
+}
+pub enum RandomNormalLikeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomNormalLikeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
+    type Inner = RandomNormalLikeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomNormalLikeAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomNormalLikeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args RandomNormalLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
+        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mean(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomNormalLikeAttrsArgs {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomNormalLikeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RandomNormalLikeAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RandomNormalLikeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mean(&mut self, mean: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> RandomNormalLikeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        RandomNormalLikeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum RandomUniformAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomUniformAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
+    type Inner = RandomUniformAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomUniformAttrs<'a> {
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
+    pub const VT_LOW: flatbuffers::VOffsetT = 8;
+    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomUniformAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args RandomUniformAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
+        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    RandomUniformAttrs::VT_SHAPE,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                false,
+            )?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformAttrsArgs<'a> {
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformAttrsArgs {
+            shape: None,
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RandomUniformAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
+    }
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> RandomUniformAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        RandomUniformAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformAttrs");
+        ds.field("shape", &self.shape());
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum RandomUniformLikeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomUniformLikeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
+    type Inner = RandomUniformLikeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomUniformLikeAttrs<'a> {
+    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
+    pub const VT_LOW: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomUniformLikeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args RandomUniformLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
+        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformLikeAttrsArgs {
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformLikeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformLikeAttrsArgs {
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RandomUniformLikeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> RandomUniformLikeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        RandomUniformLikeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum ReduceMeanAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReduceMeanAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
+    type Inner = ReduceMeanAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReduceMeanAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReduceMeanAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ReduceMeanAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
+        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ReduceMeanAttrs::VT_AXES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReduceMeanAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub keep_dims: bool,
+}
+impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReduceMeanAttrsArgs {
+            axes: None,
+            keep_dims: false,
+        }
+    }
+}
+
+pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReduceMeanAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReduceMeanAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ReduceMeanAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReduceMeanAttrs");
+        ds.field("axes", &self.axes());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.finish()
+    }
+}
+pub enum ReduceVarAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReduceVarAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReduceVarAttrs<'a> {
+    type Inner = ReduceVarAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReduceVarAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+    pub const VT_CORRECTION: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReduceVarAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ReduceVarAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReduceVarAttrs<'bldr>> {
+        let mut builder = ReduceVarAttrsBuilder::new(_fbb);
+        builder.add_correction(args.correction);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ReduceVarAttrs::VT_AXES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceVarAttrs::VT_KEEP_DIMS, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn correction(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ReduceVarAttrs::VT_CORRECTION, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReduceVarAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<u32>("correction", Self::VT_CORRECTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReduceVarAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub keep_dims: bool,
+    pub correction: u32,
+}
+impl<'a> Default for ReduceVarAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReduceVarAttrsArgs {
+            axes: None,
+            keep_dims: false,
+            correction: 0,
+        }
+    }
+}
+
+pub struct ReduceVarAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReduceVarAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceVarAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReduceVarAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn add_correction(&mut self, correction: u32) {
+        self.fbb_
+            .push_slot::<u32>(ReduceVarAttrs::VT_CORRECTION, correction, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReduceVarAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ReduceVarAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceVarAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReduceVarAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReduceVarAttrs");
+        ds.field("axes", &self.axes());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.field("correction", &self.correction());
+        ds.finish()
+    }
+}
+pub enum ReshapeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReshapeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
+    type Inner = ReshapeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReshapeAttrs<'a> {
+    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReshapeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ReshapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
+        let mut builder = ReshapeAttrsBuilder::new(_fbb);
+        builder.add_allow_zero(args.allow_zero);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn allow_zero(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReshapeAttrsArgs {
+    pub allow_zero: bool,
+}
+impl<'a> Default for ReshapeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ReshapeAttrsArgs { allow_zero: false }
+    }
+}
+
+pub struct ReshapeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReshapeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReshapeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ReshapeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReshapeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReshapeAttrs");
+        ds.field("allow_zero", &self.allow_zero());
+        ds.finish()
+    }
+}
+pub enum ResizeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResizeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
+    type Inner = ResizeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ResizeAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+    pub const VT_ANTIALIAS: flatbuffers::VOffsetT = 10;
+    pub const VT_AXES: flatbuffers::VOffsetT = 12;
+    pub const VT_EXTRAPOLATION_VALUE: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ResizeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ResizeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
+        let mut builder = ResizeAttrsBuilder::new(_fbb);
+        builder.add_extrapolation_value(args.extrapolation_value);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_antialias(args.antialias);
+        builder.add_nearest_mode(args.nearest_mode);
+        builder.add_coord_mode(args.coord_mode);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> ResizeMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn coord_mode(&self) -> CoordTransformMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CoordTransformMode>(
+                    ResizeAttrs::VT_COORD_MODE,
+                    Some(CoordTransformMode::HalfPixel),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn nearest_mode(&self) -> NearestMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn antialias(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ResizeAttrs::VT_ANTIALIAS, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ResizeAttrs::VT_AXES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn extrapolation_value(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ResizeAttrs::VT_EXTRAPOLATION_VALUE, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
+            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<bool>("antialias", Self::VT_ANTIALIAS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<f32>("extrapolation_value", Self::VT_EXTRAPOLATION_VALUE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ResizeAttrsArgs<'a> {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+    pub antialias: bool,
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub extrapolation_value: f32,
+}
+impl<'a> Default for ResizeAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ResizeAttrsArgs {
+            mode: ResizeMode::Nearest,
+            coord_mode: CoordTransformMode::HalfPixel,
+            nearest_mode: NearestMode::Floor,
+            antialias: false,
+            axes: None,
+            extrapolation_value: 0.0,
+        }
+    }
+}
+
+pub struct ResizeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ResizeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: ResizeMode) {
+        self.fbb_
+            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
+    }
+    #[inline]
+    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
+        self.fbb_.push_slot::<CoordTransformMode>(
+            ResizeAttrs::VT_COORD_MODE,
+            coord_mode,
+            CoordTransformMode::HalfPixel,
+        );
+    }
+    #[inline]
+    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
+        self.fbb_.push_slot::<NearestMode>(
+            ResizeAttrs::VT_NEAREST_MODE,
+            nearest_mode,
+            NearestMode::Floor,
+        );
+    }
+    #[inline]
+    pub fn add_antialias(&mut self, antialias: bool) {
+        self.fbb_
+            .push_slot::<bool>(ResizeAttrs::VT_ANTIALIAS, antialias, false);
+    }
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ResizeAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn add_extrapolation_value(&mut self, extrapolation_value: f32) {
+        self.fbb_.push_slot::<f32>(
+            ResizeAttrs::VT_EXTRAPOLATION_VALUE,
+            extrapolation_value,
+            0.0,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResizeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ResizeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ResizeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ResizeAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("coord_mode", &self.coord_mode());
+        ds.field("nearest_mode", &self.nearest_mode());
+        ds.field("antialias", &self.antialias());
+        ds.field("axes", &self.axes());
+        ds.field("extrapolation_value", &self.extrapolation_value());
+        ds.finish()
+    }
+}
+pub enum ScaledDotProductAttentionAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScaledDotProductAttentionAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScaledDotProductAttentionAttrs<'a> {
+    type Inner = ScaledDotProductAttentionAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScaledDotProductAttentionAttrs<'a> {
+    pub const VT_SCALE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScaledDotProductAttentionAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ScaledDotProductAttentionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScaledDotProductAttentionAttrs<'bldr>> {
+        let mut builder = ScaledDotProductAttentionAttrsBuilder::new(_fbb);
+        builder.add_scale(args.scale);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ScaledDotProductAttentionAttrs::VT_SCALE, Some(1.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScaledDotProductAttentionAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScaledDotProductAttentionAttrsArgs {
+    pub scale: f32,
+}
+impl<'a> Default for ScaledDotProductAttentionAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScaledDotProductAttentionAttrsArgs { scale: 1.0 }
+    }
+}
+
+pub struct ScaledDotProductAttentionAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScaledDotProductAttentionAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(ScaledDotProductAttentionAttrs::VT_SCALE, scale, 1.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ScaledDotProductAttentionAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ScaledDotProductAttentionAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScaledDotProductAttentionAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScaledDotProductAttentionAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScaledDotProductAttentionAttrs");
+        ds.field("scale", &self.scale());
+        ds.finish()
+    }
+}
+pub enum ScanAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScanAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScanAttrs<'a> {
+    type Inner = ScanAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScanAttrs<'a> {
+    pub const VT_BODY: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_SCAN_INPUTS: flatbuffers::VOffsetT = 6;
+    pub const VT_SCAN_INPUT_AXES: flatbuffers::VOffsetT = 8;
+    pub const VT_SCAN_INPUT_DIRECTIONS: flatbuffers::VOffsetT = 10;
+    pub const VT_SCAN_OUTPUT_AXES: flatbuffers::VOffsetT = 12;
+    pub const VT_SCAN_OUTPUT_DIRECTIONS: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScanAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ScanAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ScanAttrs<'bldr>> {
+        let mut builder = ScanAttrsBuilder::new(_fbb);
+        if let Some(x) = args.scan_output_directions {
+            builder.add_scan_output_directions(x);
+        }
+        if let Some(x) = args.scan_output_axes {
+            builder.add_scan_output_axes(x);
+        }
+        if let Some(x) = args.scan_input_directions {
+            builder.add_scan_input_directions(x);
+        }
+        if let Some(x) = args.scan_input_axes {
+            builder.add_scan_input_axes(x);
+        }
+        builder.add_num_scan_inputs(args.num_scan_inputs);
+        if let Some(x) = args.body {
+            builder.add_body(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn body(&self) -> Graph<'a> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Graph>>(ScanAttrs::VT_BODY, None)
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn num_scan_inputs(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(ScanAttrs::VT_NUM_SCAN_INPUTS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn scan_input_axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ScanAttrs::VT_SCAN_INPUT_AXES,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn scan_input_directions(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ScanAttrs::VT_SCAN_INPUT_DIRECTIONS,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn scan_output_axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ScanAttrs::VT_SCAN_OUTPUT_AXES,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn scan_output_directions(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ScanAttrs::VT_SCAN_OUTPUT_DIRECTIONS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScanAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>("body", Self::VT_BODY, true)?
+            .visit_field::<i32>("num_scan_inputs", Self::VT_NUM_SCAN_INPUTS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "scan_input_axes",
+                Self::VT_SCAN_INPUT_AXES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "scan_input_directions",
+                Self::VT_SCAN_INPUT_DIRECTIONS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "scan_output_axes",
+                Self::VT_SCAN_OUTPUT_AXES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "scan_output_directions",
+                Self::VT_SCAN_OUTPUT_DIRECTIONS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScanAttrsArgs<'a> {
+    pub body: Option<flatbuffers::WIPOffset<Graph<'a>>>,
+    pub num_scan_inputs: i32,
+    pub scan_input_axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub scan_input_directions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub scan_output_axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub scan_output_directions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+}
+impl<'a> Default for ScanAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ScanAttrsArgs {
+            body: None, // required field
+            num_scan_inputs: 0,
+            scan_input_axes: None,
+            scan_input_directions: None,
+            scan_output_axes: None,
+            scan_output_directions: None,
+        }
+    }
+}
+
+pub struct ScanAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScanAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_body(&mut self, body: flatbuffers::WIPOffset<Graph<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Graph>>(ScanAttrs::VT_BODY, body);
+    }
+    #[inline]
+    pub fn add_num_scan_inputs(&mut self, num_scan_inputs: i32) {
+        self.fbb_
+            .push_slot::<i32>(ScanAttrs::VT_NUM_SCAN_INPUTS, num_scan_inputs, 0);
+    }
+    #[inline]
+    pub fn add_scan_input_axes(
+        &mut self,
+        scan_input_axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ScanAttrs::VT_SCAN_INPUT_AXES,
+            scan_input_axes,
+        );
+    }
+    #[inline]
+    pub fn add_scan_input_directions(
+        &mut self,
+        scan_input_directions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ScanAttrs::VT_SCAN_INPUT_DIRECTIONS,
+            scan_input_directions,
+        );
+    }
+    #[inline]
+    pub fn add_scan_output_axes(
+        &mut self,
+        scan_output_axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ScanAttrs::VT_SCAN_OUTPUT_AXES,
+            scan_output_axes,
+        );
+    }
+    #[inline]
+    pub fn add_scan_output_directions(
+        &mut self,
+        scan_output_directions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ScanAttrs::VT_SCAN_OUTPUT_DIRECTIONS,
+            scan_output_directions,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScanAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ScanAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScanAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, ScanAttrs::VT_BODY, "body");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScanAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScanAttrs");
+        ds.field("body", &self.body());
+        ds.field("num_scan_inputs", &self.num_scan_inputs());
+        ds.field("scan_input_axes", &self.scan_input_axes());
+        ds.field("scan_input_directions", &self.scan_input_directions());
+        ds.field("scan_output_axes", &self.scan_output_axes());
+        ds.field("scan_output_directions", &self.scan_output_directions());
+        ds.finish()
+    }
+}
+pub enum ScatterElementsAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterElementsAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
+    type Inner = ScatterElementsAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScatterElementsAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterElementsAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ScatterElementsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
+        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(
+                    ScatterElementsAttrs::VT_REDUCTION,
+                    Some(ScatterReduction::None),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterElementsAttrsArgs {
+    pub axis: i32,
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterElementsAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterElementsAttrsArgs {
+            axis: 0,
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScatterElementsAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterElementsAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ScatterElementsAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ScatterElementsAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterElementsAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum ScatterNDAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterNDAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
+    type Inner = ScatterNDAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScatterNDAttrs<'a> {
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterNDAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ScatterNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
+        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterNDAttrsArgs {
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterNDAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterNDAttrsArgs {
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterNDAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScatterNDAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterNDAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScatterNDAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ScatterNDAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterNDAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterNDAttrs");
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum SeluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SeluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SeluAttrs<'a> {
+    type Inner = SeluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SeluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_GAMMA: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SeluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args SeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SeluAttrs<'bldr>> {
+        let mut builder = SeluAttrsBuilder::new(_fbb);
+        builder.add_gamma(args.gamma);
+        builder.add_alpha(args.alpha);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn gamma(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_GAMMA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SeluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("gamma", Self::VT_GAMMA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SeluAttrsArgs {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+impl<'a> Default for SeluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SeluAttrsArgs {
+            alpha: 0.0,
+            gamma: 0.0,
+        }
+    }
+}
+
+pub struct SeluAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SeluAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn add_gamma(&mut self, gamma: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_GAMMA, gamma, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SeluAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        SeluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SeluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SeluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SeluAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("gamma", &self.gamma());
+        ds.finish()
+    }
+}
+pub enum SoftmaxAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SoftmaxAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
+    type Inner = SoftmaxAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SoftmaxAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SoftmaxAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args SoftmaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
+        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SoftmaxAttrsArgs {
+    pub axis: i32,
+}
+impl<'a> Default for SoftmaxAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SoftmaxAttrsArgs { axis: 0 }
+    }
+}
+
+pub struct SoftmaxAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SoftmaxAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SoftmaxAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        SoftmaxAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SoftmaxAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SoftmaxAttrs");
+        ds.field("axis", &self.axis());
+        ds.finish()
+    }
+}
+pub enum SplitAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SplitAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
+    type Inner = SplitAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SplitAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SplitAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args SplitAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
+        let mut builder = SplitAttrsBuilder::new(_fbb);
+        if let Some(x) = args.num_outputs {
+            builder.add_num_outputs(x);
+        }
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn num_outputs(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(SplitAttrs::VT_NUM_OUTPUTS, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for SplitAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("num_outputs", Self::VT_NUM_OUTPUTS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SplitAttrsArgs {
+    pub axis: i32,
+    pub num_outputs: Option<u32>,
+}
+impl<'a> Default for SplitAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SplitAttrsArgs {
+            axis: 0,
+            num_outputs: None,
+        }
+    }
+}
+
+pub struct SplitAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SplitAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_num_outputs(&mut self, num_outputs: u32) {
+        self.fbb_
+            .push_slot_always::<u32>(SplitAttrs::VT_NUM_OUTPUTS, num_outputs);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SplitAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        SplitAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SplitAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SplitAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("num_outputs", &self.num_outputs());
+        ds.finish()
+    }
+}
+pub enum SplitToSequenceAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SplitToSequenceAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SplitToSequenceAttrs<'a> {
+    type Inner = SplitToSequenceAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SplitToSequenceAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SplitToSequenceAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args SplitToSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'bldr>> {
+        let mut builder = SplitToSequenceAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SplitToSequenceAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, Some(true))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SplitToSequenceAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SplitToSequenceAttrsArgs {
+    pub axis: i32,
+    pub keep_dims: bool,
+}
+impl<'a> Default for SplitToSequenceAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SplitToSequenceAttrsArgs {
+            axis: 0,
+            keep_dims: true,
+        }
+    }
+}
+
+pub struct SplitToSequenceAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SplitToSequenceAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot::<i32>(SplitToSequenceAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, keep_dims, true);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> SplitToSequenceAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        SplitToSequenceAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SplitToSequenceAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SplitToSequenceAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.finish()
+    }
+}
+pub enum TopKAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TopKAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
+    type Inner = TopKAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TopKAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TopKAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TopKAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
+        let mut builder = TopKAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_sorted(args.sorted);
+        builder.add_largest(args.largest);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn largest(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TopKAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TopKAttrsArgs {
+    pub axis: i32,
+    pub largest: bool,
+    pub sorted: bool,
+}
+impl<'a> Default for TopKAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TopKAttrsArgs {
+            axis: 0,
+            largest: false,
+            sorted: false,
+        }
+    }
+}
+
+pub struct TopKAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TopKAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_largest(&mut self, largest: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TopKAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TopKAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TopKAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TopKAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("largest", &self.largest());
+        ds.field("sorted", &self.sorted());
+        ds.finish()
+    }
+}
+pub enum TransposeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransposeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
+    type Inner = TransposeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TransposeAttrs<'a> {
+    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TransposeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
+        let mut builder = TransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.perm {
+            builder.add_perm(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    TransposeAttrs::VT_PERM,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "perm",
+                Self::VT_PERM,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TransposeAttrsArgs<'a> {
+    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for TransposeAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransposeAttrsArgs { perm: None }
+    }
+}
+
+pub struct TransposeAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TransposeAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransposeAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TransposeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TransposeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TransposeAttrs");
+        ds.field("perm", &self.perm());
+        ds.finish()
+    }
+}
+pub enum TriluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TriluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
+    type Inner = TriluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TriluAttrs<'a> {
+    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TriluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TriluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
+        let mut builder = TriluAttrsBuilder::new(_fbb);
+        builder.add_upper(args.upper);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn upper(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TriluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TriluAttrsArgs {
+    pub upper: bool,
+}
+impl<'a> Default for TriluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TriluAttrsArgs { upper: false }
+    }
+}
+
+pub struct TriluAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TriluAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_upper(&mut self, upper: bool) {
+        self.fbb_
+            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TriluAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TriluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TriluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TriluAttrs");
+        ds.field("upper", &self.upper());
+        ds.finish()
+    }
+}
+pub enum OperatorNodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OperatorNode<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
+    type Inner = OperatorNode<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> OperatorNode<'a> {
+    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
+    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
+    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OperatorNode { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args OperatorNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
+        let mut builder = OperatorNodeBuilder::new(_fbb);
+        if let Some(x) = args.outputs {
+            builder.add_outputs(x);
+        }
+        if let Some(x) = args.inputs {
+            builder.add_inputs(x);
+        }
+        if let Some(x) = args.attrs {
+            builder.add_attrs(x);
+        }
+        builder.add_attrs_type(args.attrs_type);
+        builder.add_type_(args.type_);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn type_(&self) -> OperatorType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs_type(&self) -> OperatorAttrs {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    OperatorNode::VT_ATTRS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_INPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_OUTPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ArgMaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { AveragePoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { BatchNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CastAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CastAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConcatAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvTransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FlattenAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GatherAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GemmAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GemmAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GRUAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GRUAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LeakyReluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LSTMAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MaxPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReduceMeanAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReshapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ResizeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SplitAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SplitAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SoftmaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ModAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ModAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ScatterElementsAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { OneHotAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TopKAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TopKAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { HardSigmoidAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TriluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TriluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ScatterNDAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LayerNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RandomUniformAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { EluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RandomNormalAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GatherNDAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_eye_like_attrs(&self) -> Option<EyeLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EyeLikeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { EyeLikeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_pad_attrs(&self) -> Option<PadAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::PadAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { PadAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lp_normalization_attrs(&self) -> Option<LpNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LpNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_mean_variance_normalization_attrs(
+        &self,
+    ) -> Option<MeanVarianceNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MeanVarianceNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MeanVarianceNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gather_block_quantized_attrs(&self) -> Option<GatherBlockQuantizedAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherBlockQuantizedAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GatherBlockQuantizedAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_grid_sample_attrs(&self) -> Option<GridSampleAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GridSampleAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GridSampleAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_mat_mul_nbits_attrs(&self) -> Option<MatMulNBitsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MatMulNBitsAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MatMulNBitsAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_normalize_attrs(&self) -> Option<NormalizeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::NormalizeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { NormalizeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_channel_shuffle_attrs(&self) -> Option<ChannelShuffleAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ChannelShuffleAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ChannelShuffleAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_permute_channels_attrs(&self) -> Option<PermuteChannelsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::PermuteChannelsAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { PermuteChannelsAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reduce_var_attrs(&self) -> Option<ReduceVarAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReduceVarAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReduceVarAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lp_pool_attrs(&self) -> Option<LpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LpPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GeluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GeluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_selu_attrs(&self) -> Option<SeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SeluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SeluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_image_to_tensor_attrs(&self) -> Option<ImageToTensorAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ImageToTensorAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ImageToTensorAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_bit_shift_attrs(&self) -> Option<BitShiftAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BitShiftAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { BitShiftAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_multinomial_attrs(&self) -> Option<MultinomialAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MultinomialAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MultinomialAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_dropout_attrs(&self) -> Option<DropoutAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DropoutAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { DropoutAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_scan_attrs(&self) -> Option<ScanAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScanAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ScanAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_concat_from_sequence_attrs(&self) -> Option<ConcatFromSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConcatFromSequenceAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConcatFromSequenceAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_split_to_sequence_attrs(&self) -> Option<SplitToSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SplitToSequenceAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SplitToSequenceAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_custom_operator_attrs(&self) -> Option<CustomOperatorAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CustomOperatorAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CustomOperatorAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_qlinear_conv_attrs(&self) -> Option<QLinearConvAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::QLinearConvAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { QLinearConvAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_qlinear_mat_mul_attrs(&self) -> Option<QLinearMatMulAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::QLinearMatMulAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { QLinearMatMulAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_scaled_dot_product_attention_attrs(
+        &self,
+    ) -> Option<ScaledDotProductAttentionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScaledDotProductAttentionAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ScaledDotProductAttentionAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for OperatorNode<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+     .visit_field::<OperatorType>("type_", Self::VT_TYPE_, false)?
+     .visit_union::<OperatorAttrs, _>("attrs_type", Self::VT_ATTRS_TYPE, "attrs", Self::VT_ATTRS, false, |key, v, pos| {
+        match key {
+          OperatorAttrs::ArgMaxAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ArgMaxAttrs>>("OperatorAttrs::ArgMaxAttrs", pos),
+          OperatorAttrs::AveragePoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AveragePoolAttrs>>("OperatorAttrs::AveragePoolAttrs", pos),
+          OperatorAttrs::BatchNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BatchNormalizationAttrs>>("OperatorAttrs::BatchNormalizationAttrs", pos),
+          OperatorAttrs::CastAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CastAttrs>>("OperatorAttrs::CastAttrs", pos),
+          OperatorAttrs::ConcatAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConcatAttrs>>("OperatorAttrs::ConcatAttrs", pos),
+          OperatorAttrs::ConstantOfShapeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConstantOfShapeAttrs>>("OperatorAttrs::ConstantOfShapeAttrs", pos),
+          OperatorAttrs::ConvAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConvAttrs>>("OperatorAttrs::ConvAttrs", pos),
+          OperatorAttrs::ConvTransposeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConvTransposeAttrs>>("OperatorAttrs::ConvTransposeAttrs", pos),
+          OperatorAttrs::FlattenAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FlattenAttrs>>("OperatorAttrs::FlattenAttrs", pos),
+          OperatorAttrs::GatherAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherAttrs>>("OperatorAttrs::GatherAttrs", pos),
+          OperatorAttrs::GemmAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GemmAttrs>>("OperatorAttrs::GemmAttrs", pos),
+          OperatorAttrs::GRUAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GRUAttrs>>("OperatorAttrs::GRUAttrs", pos),
+          OperatorAttrs::LeakyReluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LeakyReluAttrs>>("OperatorAttrs::LeakyReluAttrs", pos),
+          OperatorAttrs::LSTMAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LSTMAttrs>>("OperatorAttrs::LSTMAttrs", pos),
+          OperatorAttrs::MaxPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MaxPoolAttrs>>("OperatorAttrs::MaxPoolAttrs", pos),
+          OperatorAttrs::ReduceMeanAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReduceMeanAttrs>>("OperatorAttrs::ReduceMeanAttrs", pos),
+          OperatorAttrs::ReshapeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReshapeAttrs>>("OperatorAttrs::ReshapeAttrs", pos),
+          OperatorAttrs::ResizeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResizeAttrs>>("OperatorAttrs::ResizeAttrs", pos),
+          OperatorAttrs::SplitAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SplitAttrs>>("OperatorAttrs::SplitAttrs", pos),
+          OperatorAttrs::SoftmaxAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SoftmaxAttrs>>("OperatorAttrs::SoftmaxAttrs", pos),
+          OperatorAttrs::TransposeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TransposeAttrs>>("OperatorAttrs::TransposeAttrs", pos),
+          OperatorAttrs::ModAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ModAttrs>>("OperatorAttrs::ModAttrs", pos),
+          OperatorAttrs::ScatterElementsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScatterElementsAttrs>>("OperatorAttrs::ScatterElementsAttrs", pos),
+          OperatorAttrs::OneHotAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OneHotAttrs>>("OperatorAttrs::OneHotAttrs", pos),
+          OperatorAttrs::TopKAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TopKAttrs>>("OperatorAttrs::TopKAttrs", pos),
+          OperatorAttrs::HardSigmoidAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HardSigmoidAttrs>>("OperatorAttrs::HardSigmoidAttrs", pos),
+          OperatorAttrs::TriluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TriluAttrs>>("OperatorAttrs::TriluAttrs", pos),
+          OperatorAttrs::ScatterNDAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScatterNDAttrs>>("OperatorAttrs::ScatterNDAttrs", pos),
+          OperatorAttrs::NonMaxSuppressionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NonMaxSuppressionAttrs>>("OperatorAttrs::NonMaxSuppressionAttrs", pos),
+          OperatorAttrs::LayerNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LayerNormalizationAttrs>>("OperatorAttrs::LayerNormalizationAttrs", pos),
+          OperatorAttrs::RandomUniformAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomUniformAttrs>>("OperatorAttrs::RandomUniformAttrs", pos),
+          OperatorAttrs::EluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EluAttrs>>("OperatorAttrs::EluAttrs", pos),
+          OperatorAttrs::RandomUniformLikeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomUniformLikeAttrs>>("OperatorAttrs::RandomUniformLikeAttrs", pos),
+          OperatorAttrs::RandomNormalAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomNormalAttrs>>("OperatorAttrs::RandomNormalAttrs", pos),
+          OperatorAttrs::RandomNormalLikeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomNormalLikeAttrs>>("OperatorAttrs::RandomNormalLikeAttrs", pos),
+          OperatorAttrs::GatherNDAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherNDAttrs>>("OperatorAttrs::GatherNDAttrs", pos),
+          OperatorAttrs::EyeLikeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EyeLikeAttrs>>("OperatorAttrs::EyeLikeAttrs", pos),
+          OperatorAttrs::PadAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PadAttrs>>("OperatorAttrs::PadAttrs", pos),
+          OperatorAttrs::LpNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpNormalizationAttrs>>("OperatorAttrs::LpNormalizationAttrs", pos),
+          OperatorAttrs::MeanVarianceNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MeanVarianceNormalizationAttrs>>("OperatorAttrs::MeanVarianceNormalizationAttrs", pos),
+          OperatorAttrs::GatherBlockQuantizedAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherBlockQuantizedAttrs>>("OperatorAttrs::GatherBlockQuantizedAttrs", pos),
+          OperatorAttrs::GridSampleAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GridSampleAttrs>>("OperatorAttrs::GridSampleAttrs", pos),
+          OperatorAttrs::MatMulNBitsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MatMulNBitsAttrs>>("OperatorAttrs::MatMulNBitsAttrs", pos),
+          OperatorAttrs::NormalizeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NormalizeAttrs>>("OperatorAttrs::NormalizeAttrs", pos),
+          OperatorAttrs::ChannelShuffleAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ChannelShuffleAttrs>>("OperatorAttrs::ChannelShuffleAttrs", pos),
+          OperatorAttrs::PermuteChannelsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PermuteChannelsAttrs>>("OperatorAttrs::PermuteChannelsAttrs", pos),
+          OperatorAttrs::ReduceVarAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReduceVarAttrs>>("OperatorAttrs::ReduceVarAttrs", pos),
+          OperatorAttrs::LpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpPoolAttrs>>("OperatorAttrs::LpPoolAttrs", pos),
+          OperatorAttrs::GeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GeluAttrs>>("OperatorAttrs::GeluAttrs", pos),
+          OperatorAttrs::SeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeluAttrs>>("OperatorAttrs::SeluAttrs", pos),
+          OperatorAttrs::ImageToTensorAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ImageToTensorAttrs>>("OperatorAttrs::ImageToTensorAttrs", pos),
+          OperatorAttrs::BitShiftAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BitShiftAttrs>>("OperatorAttrs::BitShiftAttrs", pos),
+          OperatorAttrs::MultinomialAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MultinomialAttrs>>("OperatorAttrs::MultinomialAttrs", pos),
+          OperatorAttrs::DropoutAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DropoutAttrs>>("OperatorAttrs::DropoutAttrs", pos),
+          OperatorAttrs::ScanAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScanAttrs>>("OperatorAttrs::ScanAttrs", pos),
+          OperatorAttrs::ConcatFromSequenceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConcatFromSequenceAttrs>>("OperatorAttrs::ConcatFromSequenceAttrs", pos),
+          OperatorAttrs::SplitToSequenceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SplitToSequenceAttrs>>("OperatorAttrs::SplitToSequenceAttrs", pos),
+          OperatorAttrs::CustomOperatorAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CustomOperatorAttrs>>("OperatorAttrs::CustomOperatorAttrs", pos),
+          OperatorAttrs::QLinearConvAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QLinearConvAttrs>>("OperatorAttrs::QLinearConvAttrs", pos),
+          OperatorAttrs::QLinearMatMulAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QLinearMatMulAttrs>>("OperatorAttrs::QLinearMatMulAttrs", pos),
+          OperatorAttrs::ScaledDotProductAttentionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScaledDotProductAttentionAttrs>>("OperatorAttrs::ScaledDotProductAttentionAttrs", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>("inputs", Self::VT_INPUTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>("outputs", Self::VT_OUTPUTS, false)?
+     .finish();
+        Ok(())
+    }
+}
+pub struct OperatorNodeArgs<'a> {
+    pub type_: OperatorType,
+    pub attrs_type: OperatorAttrs,
+    pub attrs: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub inputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+}
+impl<'a> Default for OperatorNodeArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        OperatorNodeArgs {
+            type_: OperatorType::Add,
+            attrs_type: OperatorAttrs::NONE,
+            attrs: None,
+            inputs: None,
+            outputs: None,
+        }
+    }
+}
+
+pub struct OperatorNodeBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OperatorNodeBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_type_(&mut self, type_: OperatorType) {
+        self.fbb_
+            .push_slot::<OperatorType>(OperatorNode::VT_TYPE_, type_, OperatorType::Add);
+    }
+    #[inline]
+    pub fn add_attrs_type(&mut self, attrs_type: OperatorAttrs) {
+        self.fbb_.push_slot::<OperatorAttrs>(
+            OperatorNode::VT_ATTRS_TYPE,
+            attrs_type,
+            OperatorAttrs::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_attrs(&mut self, attrs: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_ATTRS, attrs);
+    }
+    #[inline]
+    pub fn add_inputs(&mut self, inputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_INPUTS, inputs);
+    }
+    #[inline]
+    pub fn add_outputs(&mut self, outputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(OperatorNode::VT_OUTPUTS, outputs);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OperatorNodeBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        OperatorNodeBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<OperatorNode<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for OperatorNode<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("OperatorNode");
+        ds.field("type_", &self.type_());
+        ds.field("attrs_type", &self.attrs_type());
+        match self.attrs_type() {
+            OperatorAttrs::ArgMaxAttrs => {
+                if let Some(x) = self.attrs_as_arg_max_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::AveragePoolAttrs => {
+                if let Some(x) = self.attrs_as_average_pool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::BatchNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_batch_normalization_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::CastAttrs => {
+                if let Some(x) = self.attrs_as_cast_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ConcatAttrs => {
+                if let Some(x) = self.attrs_as_concat_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7643,8 +13038,258 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::FlattenAttrs => {
-                if let Some(x) = self.attrs_as_flatten_attrs() {
+            OperatorAttrs::FlattenAttrs => {
+                if let Some(x) = self.attrs_as_flatten_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GatherAttrs => {
+                if let Some(x) = self.attrs_as_gather_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GemmAttrs => {
+                if let Some(x) = self.attrs_as_gemm_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GRUAttrs => {
+                if let Some(x) = self.attrs_as_gruattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LeakyReluAttrs => {
+                if let Some(x) = self.attrs_as_leaky_relu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LSTMAttrs => {
+                if let Some(x) = self.attrs_as_lstmattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::MaxPoolAttrs => {
+                if let Some(x) = self.attrs_as_max_pool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ReduceMeanAttrs => {
+                if let Some(x) = self.attrs_as_reduce_mean_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ReshapeAttrs => {
+                if let Some(x) = self.attrs_as_reshape_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ResizeAttrs => {
+                if let Some(x) = self.attrs_as_resize_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SplitAttrs => {
+                if let Some(x) = self.attrs_as_split_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SoftmaxAttrs => {
+                if let Some(x) = self.attrs_as_softmax_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TransposeAttrs => {
+                if let Some(x) = self.attrs_as_transpose_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ModAttrs => {
+                if let Some(x) = self.attrs_as_mod_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ScatterElementsAttrs => {
+                if let Some(x) = self.attrs_as_scatter_elements_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::OneHotAttrs => {
+                if let Some(x) = self.attrs_as_one_hot_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TopKAttrs => {
+                if let Some(x) = self.attrs_as_top_kattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::HardSigmoidAttrs => {
+                if let Some(x) = self.attrs_as_hard_sigmoid_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TriluAttrs => {
+                if let Some(x) = self.attrs_as_trilu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ScatterNDAttrs => {
+                if let Some(x) = self.attrs_as_scatter_ndattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::NonMaxSuppressionAttrs => {
+                if let Some(x) = self.attrs_as_non_max_suppression_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LayerNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_layer_normalization_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RandomUniformAttrs => {
+                if let Some(x) = self.attrs_as_random_uniform_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::EluAttrs => {
+                if let Some(x) = self.attrs_as_elu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RandomUniformLikeAttrs => {
+                if let Some(x) = self.attrs_as_random_uniform_like_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RandomNormalAttrs => {
+                if let Some(x) = self.attrs_as_random_normal_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7653,8 +13298,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::GatherAttrs => {
-                if let Some(x) = self.attrs_as_gather_attrs() {
+            OperatorAttrs::RandomNormalLikeAttrs => {
+                if let Some(x) = self.attrs_as_random_normal_like_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7663,8 +13308,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::GemmAttrs => {
-                if let Some(x) = self.attrs_as_gemm_attrs() {
+            OperatorAttrs::GatherNDAttrs => {
+                if let Some(x) = self.attrs_as_gather_ndattrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7673,8 +13318,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::GRUAttrs => {
-                if let Some(x) = self.attrs_as_gruattrs() {
+            OperatorAttrs::EyeLikeAttrs => {
+                if let Some(x) = self.attrs_as_eye_like_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7683,8 +13328,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::LeakyReluAttrs => {
-                if let Some(x) = self.attrs_as_leaky_relu_attrs() {
+            OperatorAttrs::PadAttrs => {
+                if let Some(x) = self.attrs_as_pad_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7693,8 +13338,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::LSTMAttrs => {
-                if let Some(x) = self.attrs_as_lstmattrs() {
+            OperatorAttrs::LpNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_lp_normalization_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7703,8 +13348,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::MaxPoolAttrs => {
-                if let Some(x) = self.attrs_as_max_pool_attrs() {
+            OperatorAttrs::MeanVarianceNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_mean_variance_normalization_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7713,8 +13358,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ReduceMeanAttrs => {
-                if let Some(x) = self.attrs_as_reduce_mean_attrs() {
+            OperatorAttrs::GatherBlockQuantizedAttrs => {
+                if let Some(x) = self.attrs_as_gather_block_quantized_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7723,8 +13368,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ReshapeAttrs => {
-                if let Some(x) = self.attrs_as_reshape_attrs() {
+            OperatorAttrs::GridSampleAttrs => {
+                if let Some(x) = self.attrs_as_grid_sample_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7733,8 +13378,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ResizeAttrs => {
-                if let Some(x) = self.attrs_as_resize_attrs() {
+            OperatorAttrs::MatMulNBitsAttrs => {
+                if let Some(x) = self.attrs_as_mat_mul_nbits_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7743,8 +13388,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::SplitAttrs => {
-                if let Some(x) = self.attrs_as_split_attrs() {
+            OperatorAttrs::NormalizeAttrs => {
+                if let Some(x) = self.attrs_as_normalize_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7753,8 +13398,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::SoftmaxAttrs => {
-                if let Some(x) = self.attrs_as_softmax_attrs() {
+            OperatorAttrs::ChannelShuffleAttrs => {
+                if let Some(x) = self.attrs_as_channel_shuffle_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7763,8 +13408,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TransposeAttrs => {
-                if let Some(x) = self.attrs_as_transpose_attrs() {
+            OperatorAttrs::PermuteChannelsAttrs => {
+                if let Some(x) = self.attrs_as_permute_channels_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7773,8 +13418,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ModAttrs => {
-                if let Some(x) = self.attrs_as_mod_attrs() {
+            OperatorAttrs::ReduceVarAttrs => {
+                if let Some(x) = self.attrs_as_reduce_var_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7783,8 +13428,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ScatterElementsAttrs => {
-                if let Some(x) = self.attrs_as_scatter_elements_attrs() {
+            OperatorAttrs::LpPoolAttrs => {
+                if let Some(x) = self.attrs_as_lp_pool_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7793,8 +13438,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::OneHotAttrs => {
-                if let Some(x) = self.attrs_as_one_hot_attrs() {
+            OperatorAttrs::GeluAttrs => {
+                if let Some(x) = self.attrs_as_gelu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7803,8 +13448,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TopKAttrs => {
-                if let Some(x) = self.attrs_as_top_kattrs() {
+            OperatorAttrs::SeluAttrs => {
+                if let Some(x) = self.attrs_as_selu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7813,8 +13458,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::HardSigmoidAttrs => {
-                if let Some(x) = self.attrs_as_hard_sigmoid_attrs() {
+            OperatorAttrs::ImageToTensorAttrs => {
+                if let Some(x) = self.attrs_as_image_to_tensor_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7823,8 +13468,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TriluAttrs => {
-                if let Some(x) = self.attrs_as_trilu_attrs() {
+            OperatorAttrs::BitShiftAttrs => {
+                if let Some(x) = self.attrs_as_bit_shift_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7833,8 +13478,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ScatterNDAttrs => {
-                if let Some(x) = self.attrs_as_scatter_ndattrs() {
+            OperatorAttrs::MultinomialAttrs => {
+                if let Some(x) = self.attrs_as_multinomial_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7843,8 +13488,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::NonMaxSuppressionAttrs => {
-                if let Some(x) = self.attrs_as_non_max_suppression_attrs() {
+            OperatorAttrs::DropoutAttrs => {
+                if let Some(x) = self.attrs_as_dropout_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7853,8 +13498,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::LayerNormalizationAttrs => {
-                if let Some(x) = self.attrs_as_layer_normalization_attrs() {
+            OperatorAttrs::ScanAttrs => {
+                if let Some(x) = self.attrs_as_scan_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7863,8 +13508,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::RandomUniformAttrs => {
-                if let Some(x) = self.attrs_as_random_uniform_attrs() {
+            OperatorAttrs::ConcatFromSequenceAttrs => {
+                if let Some(x) = self.attrs_as_concat_from_sequence_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7873,8 +13518,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::EluAttrs => {
-                if let Some(x) = self.attrs_as_elu_attrs() {
+            OperatorAttrs::SplitToSequenceAttrs => {
+                if let Some(x) = self.attrs_as_split_to_sequence_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7883,8 +13528,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::RandomUniformLikeAttrs => {
-                if let Some(x) = self.attrs_as_random_uniform_like_attrs() {
+            OperatorAttrs::CustomOperatorAttrs => {
+                if let Some(x) = self.attrs_as_custom_operator_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7893,8 +13538,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::RandomNormalAttrs => {
-                if let Some(x) = self.attrs_as_random_normal_attrs() {
+            OperatorAttrs::QLinearConvAttrs => {
+                if let Some(x) = self.attrs_as_qlinear_conv_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7903,8 +13548,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::RandomNormalLikeAttrs => {
-                if let Some(x) = self.attrs_as_random_normal_like_attrs() {
+            OperatorAttrs::QLinearMatMulAttrs => {
+                if let Some(x) = self.attrs_as_qlinear_mat_mul_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7913,8 +13558,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::GatherNDAttrs => {
-                if let Some(x) = self.attrs_as_gather_ndattrs() {
+            OperatorAttrs::ScaledDotProductAttentionAttrs => {
+                if let Some(x) = self.attrs_as_scaled_dot_product_attention_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7933,15 +13578,634 @@ impl core::fmt::Debug for OperatorNode<'_> {
         ds.finish()
     }
 }
-pub enum FloatDataOffset {}
+pub enum FloatDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FloatData<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FloatData<'a> {
+    type Inner = FloatData<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FloatData<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FloatData { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FloatDataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FloatData<'bldr>> {
+        let mut builder = FloatDataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    FloatData::VT_DATA,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FloatData<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "data",
+                Self::VT_DATA,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FloatDataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for FloatDataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FloatDataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct FloatDataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FloatDataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FloatData::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FloatDataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FloatDataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FloatData<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, FloatData::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FloatData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FloatData");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum IntDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct IntData<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for IntData<'a> {
+    type Inner = IntData<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> IntData<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        IntData { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args IntDataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<IntData<'bldr>> {
+        let mut builder = IntDataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    IntData::VT_DATA,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for IntData<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "data",
+                Self::VT_DATA,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct IntDataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+}
+impl<'a> Default for IntDataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        IntDataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct IntDataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> IntDataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(IntData::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IntDataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        IntDataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<IntData<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, IntData::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for IntData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("IntData");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum StringDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StringData<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StringData<'a> {
+    type Inner = StringData<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> StringData<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        StringData { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args StringDataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<StringData<'bldr>> {
+        let mut builder = StringDataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(StringData::VT_DATA, None)
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for StringData<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("data", Self::VT_DATA, true)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct StringDataArgs<'a> {
+    pub data: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+}
+impl<'a> Default for StringDataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        StringDataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct StringDataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> StringDataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(
+        &mut self,
+        data: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(StringData::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> StringDataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        StringDataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<StringData<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, StringData::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for StringData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("StringData");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum CompressedDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CompressedData<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CompressedData<'a> {
+    type Inner = CompressedData<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> CompressedData<'a> {
+    pub const VT_COMPRESSION: flatbuffers::VOffsetT = 4;
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_DATA: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        CompressedData { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args CompressedDataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CompressedData<'bldr>> {
+        let mut builder = CompressedDataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.add_dtype(args.dtype);
+        builder.add_compression(args.compression);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn compression(&self) -> Compression {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<Compression>(CompressedData::VT_COMPRESSION, Some(Compression::Zstd))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn dtype(&self) -> DataType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<DataType>(CompressedData::VT_DTYPE, Some(DataType::Int32))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                    CompressedData::VT_DATA,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for CompressedData<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<Compression>("compression", Self::VT_COMPRESSION, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                "data",
+                Self::VT_DATA,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct CompressedDataArgs<'a> {
+    pub compression: Compression,
+    pub dtype: DataType,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for CompressedDataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CompressedDataArgs {
+            compression: Compression::Zstd,
+            dtype: DataType::Int32,
+            data: None,
+        }
+    }
+}
+
+pub struct CompressedDataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CompressedDataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_compression(&mut self, compression: Compression) {
+        self.fbb_.push_slot::<Compression>(
+            CompressedData::VT_COMPRESSION,
+            compression,
+            Compression::Zstd,
+        );
+    }
+    #[inline]
+    pub fn add_dtype(&mut self, dtype: DataType) {
+        self.fbb_
+            .push_slot::<DataType>(CompressedData::VT_DTYPE, dtype, DataType::Int32);
+    }
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedData::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CompressedDataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        CompressedDataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<CompressedData<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for CompressedData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("CompressedData");
+        ds.field("compression", &self.compression());
+        ds.field("dtype", &self.dtype());
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum BFloat16DataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BFloat16Data<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BFloat16Data<'a> {
+    type Inner = BFloat16Data<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> BFloat16Data<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        BFloat16Data { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BFloat16DataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<BFloat16Data<'bldr>> {
+        let mut builder = BFloat16DataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, u16> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(
+                    BFloat16Data::VT_DATA,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for BFloat16Data<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>(
+                "data",
+                Self::VT_DATA,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct BFloat16DataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for BFloat16DataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BFloat16DataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct BFloat16DataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BFloat16DataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u16>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(BFloat16Data::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BFloat16DataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        BFloat16DataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<BFloat16Data<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, BFloat16Data::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for BFloat16Data<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("BFloat16Data");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum ConstantNodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FloatData<'a> {
+pub struct ConstantNode<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FloatData<'a> {
-    type Inner = FloatData<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConstantNode<'a> {
+    type Inner = ConstantNode<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7950,42 +14214,142 @@ impl<'a> flatbuffers::Follow<'a> for FloatData<'a> {
     }
 }
 
-impl<'a> FloatData<'a> {
-    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+impl<'a> ConstantNode<'a> {
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_DATA: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FloatData { _tab: table }
+        ConstantNode { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FloatDataArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FloatData<'bldr>> {
-        let mut builder = FloatDataBuilder::new(_fbb);
+        args: &'args ConstantNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConstantNode<'bldr>> {
+        let mut builder = ConstantNodeBuilder::new(_fbb);
         if let Some(x) = args.data {
             builder.add_data(x);
         }
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        builder.add_data_type(args.data_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn data(&self) -> flatbuffers::Vector<'a, f32> {
+    pub fn shape(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    FloatData::VT_DATA,
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConstantNode::VT_SHAPE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data_type(&self) -> ConstantData {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ConstantData>(ConstantNode::VT_DATA_TYPE, Some(ConstantData::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Table<'a> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    ConstantNode::VT_DATA,
                     None,
                 )
                 .unwrap()
         }
     }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_float_data(&self) -> Option<FloatData<'a>> {
+        if self.data_type() == ConstantData::FloatData {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { FloatData::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_int_data(&self) -> Option<IntData<'a>> {
+        if self.data_type() == ConstantData::IntData {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { IntData::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_string_data(&self) -> Option<StringData<'a>> {
+        if self.data_type() == ConstantData::StringData {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { StringData::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_compressed_data(&self) -> Option<CompressedData<'a>> {
+        if self.data_type() == ConstantData::CompressedData {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { CompressedData::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_bfloat_16_data(&self) -> Option<BFloat16Data<'a>> {
+        if self.data_type() == ConstantData::BFloat16Data {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { BFloat16Data::init_from_table(u) })
+        } else {
+            None
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for FloatData<'_> {
+impl flatbuffers::Verifiable for ConstantNode<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7993,69 +14357,179 @@ impl flatbuffers::Verifiable for FloatData<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                true,
+            )?
+            .visit_union::<ConstantData, _>(
+                "data_type",
+                Self::VT_DATA_TYPE,
                 "data",
                 Self::VT_DATA,
                 true,
+                |key, v, pos| match key {
+                    ConstantData::FloatData => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatData>>(
+                            "ConstantData::FloatData",
+                            pos,
+                        ),
+                    ConstantData::IntData => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntData>>(
+                            "ConstantData::IntData",
+                            pos,
+                        ),
+                    ConstantData::StringData => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<StringData>>(
+                            "ConstantData::StringData",
+                            pos,
+                        ),
+                    ConstantData::CompressedData => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<CompressedData>>(
+                            "ConstantData::CompressedData",
+                            pos,
+                        ),
+                    ConstantData::BFloat16Data => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<BFloat16Data>>(
+                            "ConstantData::BFloat16Data",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
             )?
             .finish();
         Ok(())
     }
 }
-pub struct FloatDataArgs<'a> {
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+pub struct ConstantNodeArgs<'a> {
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub data_type: ConstantData,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for FloatDataArgs<'a> {
+impl<'a> Default for ConstantNodeArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FloatDataArgs {
+        ConstantNodeArgs {
+            shape: None, // required field
+            data_type: ConstantData::NONE,
             data: None, // required field
         }
     }
 }
 
-pub struct FloatDataBuilder<'a: 'b, 'b> {
+pub struct ConstantNodeBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FloatDataBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ConstantNodeBuilder<'a, 'b> {
     #[inline]
-    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FloatData::VT_DATA, data);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantNode::VT_SHAPE, shape);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FloatDataBuilder<'a, 'b> {
+    pub fn add_data_type(&mut self, data_type: ConstantData) {
+        self.fbb_.push_slot::<ConstantData>(
+            ConstantNode::VT_DATA_TYPE,
+            data_type,
+            ConstantData::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantNode::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConstantNodeBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FloatDataBuilder {
+        ConstantNodeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FloatData<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantNode<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, FloatData::VT_DATA, "data");
+        self.fbb_.required(o, ConstantNode::VT_SHAPE, "shape");
+        self.fbb_.required(o, ConstantNode::VT_DATA, "data");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FloatData<'_> {
+impl core::fmt::Debug for ConstantNode<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FloatData");
-        ds.field("data", &self.data());
+        let mut ds = f.debug_struct("ConstantNode");
+        ds.field("shape", &self.shape());
+        ds.field("data_type", &self.data_type());
+        match self.data_type() {
+            ConstantData::FloatData => {
+                if let Some(x) = self.data_as_float_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ConstantData::IntData => {
+                if let Some(x) = self.data_as_int_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ConstantData::StringData => {
+                if let Some(x) = self.data_as_string_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ConstantData::CompressedData => {
+                if let Some(x) = self.data_as_compressed_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ConstantData::BFloat16Data => {
+                if let Some(x) = self.data_as_bfloat_16_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("data", &x)
+            }
+        };
         ds.finish()
     }
 }
-pub enum IntDataOffset {}
+pub enum DimOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IntData<'a> {
+pub struct Dim<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IntData<'a> {
-    type Inner = IntData<'a>;
+impl<'a> flatbuffers::Follow<'a> for Dim<'a> {
+    type Inner = Dim<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8064,42 +14538,47 @@ impl<'a> flatbuffers::Follow<'a> for IntData<'a> {
     }
 }
 
-impl<'a> IntData<'a> {
-    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+impl<'a> Dim<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+    pub const VT_NAME: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IntData { _tab: table }
+        Dim { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args IntDataArgs<'args>,
-    ) -> flatbuffers::WIPOffset<IntData<'bldr>> {
-        let mut builder = IntDataBuilder::new(_fbb);
-        if let Some(x) = args.data {
-            builder.add_data(x);
+        args: &'args DimArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Dim<'bldr>> {
+        let mut builder = DimBuilder::new(_fbb);
+        if let Some(x) = args.name {
+            builder.add_name(x);
         }
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn data(&self) -> flatbuffers::Vector<'a, i32> {
+    pub fn value(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(Dim::VT_VALUE, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    IntData::VT_DATA,
-                    None,
-                )
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Dim::VT_NAME, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for IntData<'_> {
+impl flatbuffers::Verifiable for Dim<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8107,69 +14586,72 @@ impl flatbuffers::Verifiable for IntData<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
-                "data",
-                Self::VT_DATA,
-                true,
-            )?
+            .visit_field::<u32>("value", Self::VT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
             .finish();
         Ok(())
     }
 }
-pub struct IntDataArgs<'a> {
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+pub struct DimArgs<'a> {
+    pub value: u32,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for IntDataArgs<'a> {
+impl<'a> Default for DimArgs<'a> {
     #[inline]
     fn default() -> Self {
-        IntDataArgs {
-            data: None, // required field
+        DimArgs {
+            value: 0,
+            name: None,
         }
     }
 }
 
-pub struct IntDataBuilder<'a: 'b, 'b> {
+pub struct DimBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> IntDataBuilder<'a, 'b> {
+impl<'a: 'b, 'b> DimBuilder<'a, 'b> {
     #[inline]
-    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+    pub fn add_value(&mut self, value: u32) {
+        self.fbb_.push_slot::<u32>(Dim::VT_VALUE, value, 0);
+    }
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(IntData::VT_DATA, data);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Dim::VT_NAME, name);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IntDataBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DimBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        IntDataBuilder {
+        DimBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IntData<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Dim<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, IntData::VT_DATA, "data");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IntData<'_> {
+impl core::fmt::Debug for Dim<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IntData");
-        ds.field("data", &self.data());
+        let mut ds = f.debug_struct("Dim");
+        ds.field("value", &self.value());
+        ds.field("name", &self.name());
         ds.finish()
     }
 }
-pub enum ConstantNodeOffset {}
+pub enum ValueNodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConstantNode<'a> {
+pub struct ValueNode<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConstantNode<'a> {
-    type Inner = ConstantNode<'a>;
+impl<'a> flatbuffers::Follow<'a> for ValueNode<'a> {
+    type Inner = ValueNode<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8178,100 +14660,63 @@ impl<'a> flatbuffers::Follow<'a> for ConstantNode<'a> {
     }
 }
 
-impl<'a> ConstantNode<'a> {
+impl<'a> ValueNode<'a> {
     pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
-    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_DATA: flatbuffers::VOffsetT = 8;
+    pub const VT_OPTIONAL: flatbuffers::VOffsetT = 6;
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConstantNode { _tab: table }
+        ValueNode { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ConstantNodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConstantNode<'bldr>> {
-        let mut builder = ConstantNodeBuilder::new(_fbb);
-        if let Some(x) = args.data {
-            builder.add_data(x);
-        }
+        args: &'args ValueNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ValueNode<'bldr>> {
+        let mut builder = ValueNodeBuilder::new(_fbb);
         if let Some(x) = args.shape {
             builder.add_shape(x);
         }
-        builder.add_data_type(args.data_type);
+        if let Some(x) = args.dtype {
+            builder.add_dtype(x);
+        }
+        builder.add_optional(args.optional);
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> flatbuffers::Vector<'a, u32> {
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConstantNode::VT_SHAPE,
-                    None,
-                )
-                .unwrap()
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim>>,
+            >>(ValueNode::VT_SHAPE, None)
         }
     }
     #[inline]
-    pub fn data_type(&self) -> ConstantData {
+    pub fn optional(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ConstantData>(ConstantNode::VT_DATA_TYPE, Some(ConstantData::NONE))
+                .get::<bool>(ValueNode::VT_OPTIONAL, Some(false))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn data(&self) -> flatbuffers::Table<'a> {
+    pub fn dtype(&self) -> Option<DataType> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    ConstantNode::VT_DATA,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_float_data(&self) -> Option<FloatData<'a>> {
-        if self.data_type() == ConstantData::FloatData {
-            let u = self.data();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { FloatData::init_from_table(u) })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_int_data(&self) -> Option<IntData<'a>> {
-        if self.data_type() == ConstantData::IntData {
-            let u = self.data();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { IntData::init_from_table(u) })
-        } else {
-            None
-        }
+        unsafe { self._tab.get::<DataType>(ValueNode::VT_DTYPE, None) }
     }
 }
 
-impl flatbuffers::Verifiable for ConstantNode<'_> {
+impl flatbuffers::Verifiable for ValueNode<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8279,134 +14724,91 @@ impl flatbuffers::Verifiable for ConstantNode<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                true,
-            )?
-            .visit_union::<ConstantData, _>(
-                "data_type",
-                Self::VT_DATA_TYPE,
-                "data",
-                Self::VT_DATA,
-                true,
-                |key, v, pos| match key {
-                    ConstantData::FloatData => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatData>>(
-                            "ConstantData::FloatData",
-                            pos,
-                        ),
-                    ConstantData::IntData => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntData>>(
-                            "ConstantData::IntData",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
-            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Dim>>,
+            >>("shape", Self::VT_SHAPE, false)?
+            .visit_field::<bool>("optional", Self::VT_OPTIONAL, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConstantNodeArgs<'a> {
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub data_type: ConstantData,
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct ValueNodeArgs<'a> {
+    pub shape: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim<'a>>>>,
+    >,
+    pub optional: bool,
+    pub dtype: Option<DataType>,
 }
-impl<'a> Default for ConstantNodeArgs<'a> {
+impl<'a> Default for ValueNodeArgs<'a> {
     #[inline]
-    fn default() -> Self {
-        ConstantNodeArgs {
-            shape: None, // required field
-            data_type: ConstantData::NONE,
-            data: None, // required field
+    fn default() -> Self {
+        ValueNodeArgs {
+            shape: None,
+            optional: false,
+            dtype: None,
         }
     }
 }
 
-pub struct ConstantNodeBuilder<'a: 'b, 'b> {
+pub struct ValueNodeBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ConstantNodeBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ValueNodeBuilder<'a, 'b> {
     #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_shape(
+        &mut self,
+        shape: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Dim<'b>>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantNode::VT_SHAPE, shape);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ValueNode::VT_SHAPE, shape);
     }
     #[inline]
-    pub fn add_data_type(&mut self, data_type: ConstantData) {
-        self.fbb_.push_slot::<ConstantData>(
-            ConstantNode::VT_DATA_TYPE,
-            data_type,
-            ConstantData::NONE,
-        );
+    pub fn add_optional(&mut self, optional: bool) {
+        self.fbb_
+            .push_slot::<bool>(ValueNode::VT_OPTIONAL, optional, false);
     }
     #[inline]
-    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_dtype(&mut self, dtype: DataType) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantNode::VT_DATA, data);
+            .push_slot_always::<DataType>(ValueNode::VT_DTYPE, dtype);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConstantNodeBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ValueNodeBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ConstantNodeBuilder {
+        ValueNodeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantNode<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ValueNode<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, ConstantNode::VT_SHAPE, "shape");
-        self.fbb_.required(o, ConstantNode::VT_DATA, "data");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConstantNode<'_> {
+impl core::fmt::Debug for ValueNode<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConstantNode");
+        let mut ds = f.debug_struct("ValueNode");
         ds.field("shape", &self.shape());
-        ds.field("data_type", &self.data_type());
-        match self.data_type() {
-            ConstantData::FloatData => {
-                if let Some(x) = self.data_as_float_data() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            ConstantData::IntData => {
-                if let Some(x) = self.data_as_int_data() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("data", &x)
-            }
-        };
+        ds.field("optional", &self.optional());
+        ds.field("dtype", &self.dtype());
         ds.finish()
     }
 }
-pub enum DimOffset {}
+pub enum NodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Dim<'a> {
+pub struct Node<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Dim<'a> {
-    type Inner = Dim<'a>;
+impl<'a> flatbuffers::Follow<'a> for Node<'a> {
+    type Inner = Node<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8415,47 +14817,109 @@ impl<'a> flatbuffers::Follow<'a> for Dim<'a> {
     }
 }
 
-impl<'a> Dim<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
-    pub const VT_NAME: flatbuffers::VOffsetT = 6;
+impl<'a> Node<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_DATA: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Dim { _tab: table }
+        Node { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args DimArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Dim<'bldr>> {
-        let mut builder = DimBuilder::new(_fbb);
+        args: &'args NodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Node<'bldr>> {
+        let mut builder = NodeBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
         if let Some(x) = args.name {
             builder.add_name(x);
         }
-        builder.add_value(args.value);
+        builder.add_data_type(args.data_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> u32 {
+    pub fn name(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(Dim::VT_VALUE, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_NAME, None)
+        }
     }
     #[inline]
-    pub fn name(&self) -> Option<&'a str> {
+    pub fn data_type(&self) -> NodeKind {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Dim::VT_NAME, None)
+                .get::<NodeKind>(Node::VT_DATA_TYPE, Some(NodeKind::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Node::VT_DATA, None)
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_operator_node(&self) -> Option<OperatorNode<'a>> {
+        if self.data_type() == NodeKind::OperatorNode {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { OperatorNode::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_constant_node(&self) -> Option<ConstantNode<'a>> {
+        if self.data_type() == NodeKind::ConstantNode {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConstantNode::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_value_node(&self) -> Option<ValueNode<'a>> {
+        if self.data_type() == NodeKind::ValueNode {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ValueNode::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
 }
 
-impl flatbuffers::Verifiable for Dim<'_> {
+impl flatbuffers::Verifiable for Node<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8463,72 +14927,140 @@ impl flatbuffers::Verifiable for Dim<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<u32>("value", Self::VT_VALUE, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_union::<NodeKind, _>(
+                "data_type",
+                Self::VT_DATA_TYPE,
+                "data",
+                Self::VT_DATA,
+                false,
+                |key, v, pos| match key {
+                    NodeKind::OperatorNode => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<OperatorNode>>(
+                            "NodeKind::OperatorNode",
+                            pos,
+                        ),
+                    NodeKind::ConstantNode => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ConstantNode>>(
+                            "NodeKind::ConstantNode",
+                            pos,
+                        ),
+                    NodeKind::ValueNode => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ValueNode>>(
+                            "NodeKind::ValueNode",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct DimArgs<'a> {
-    pub value: u32,
+pub struct NodeArgs<'a> {
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data_type: NodeKind,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for DimArgs<'a> {
+impl<'a> Default for NodeArgs<'a> {
     #[inline]
     fn default() -> Self {
-        DimArgs {
-            value: 0,
+        NodeArgs {
             name: None,
+            data_type: NodeKind::NONE,
+            data: None,
         }
     }
 }
 
-pub struct DimBuilder<'a: 'b, 'b> {
+pub struct NodeBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> DimBuilder<'a, 'b> {
+impl<'a: 'b, 'b> NodeBuilder<'a, 'b> {
     #[inline]
-    pub fn add_value(&mut self, value: u32) {
-        self.fbb_.push_slot::<u32>(Dim::VT_VALUE, value, 0);
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_NAME, name);
     }
     #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_data_type(&mut self, data_type: NodeKind) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Dim::VT_NAME, name);
+            .push_slot::<NodeKind>(Node::VT_DATA_TYPE, data_type, NodeKind::NONE);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DimBuilder<'a, 'b> {
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        DimBuilder {
+        NodeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Dim<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Dim<'_> {
+impl core::fmt::Debug for Node<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Dim");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("Node");
         ds.field("name", &self.name());
+        ds.field("data_type", &self.data_type());
+        match self.data_type() {
+            NodeKind::OperatorNode => {
+                if let Some(x) = self.data_as_operator_node() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            NodeKind::ConstantNode => {
+                if let Some(x) = self.data_as_constant_node() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            NodeKind::ValueNode => {
+                if let Some(x) = self.data_as_value_node() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("data", &x)
+            }
+        };
         ds.finish()
     }
 }
-pub enum ValueNodeOffset {}
+pub enum GraphOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ValueNode<'a> {
+pub struct Graph<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ValueNode<'a> {
-    type Inner = ValueNode<'a>;
+impl<'a> flatbuffers::Follow<'a> for Graph<'a> {
+    type Inner = Graph<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8537,39 +15069,73 @@ impl<'a> flatbuffers::Follow<'a> for ValueNode<'a> {
     }
 }
 
-impl<'a> ValueNode<'a> {
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+impl<'a> Graph<'a> {
+    pub const VT_NODES: flatbuffers::VOffsetT = 4;
+    pub const VT_INPUTS: flatbuffers::VOffsetT = 6;
+    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ValueNode { _tab: table }
+        Graph { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ValueNodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ValueNode<'bldr>> {
-        let mut builder = ValueNodeBuilder::new(_fbb);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
+        args: &'args GraphArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Graph<'bldr>> {
+        let mut builder = GraphBuilder::new(_fbb);
+        if let Some(x) = args.outputs {
+            builder.add_outputs(x);
+        }
+        if let Some(x) = args.inputs {
+            builder.add_inputs(x);
+        }
+        if let Some(x) = args.nodes {
+            builder.add_nodes(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim<'a>>>> {
+    pub fn nodes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim>>,
-            >>(ValueNode::VT_SHAPE, None)
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node>>,
+            >>(Graph::VT_NODES, None)
+        }
+    }
+    #[inline]
+    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Graph::VT_INPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Graph::VT_OUTPUTS,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for ValueNode<'_> {
+impl flatbuffers::Verifiable for Graph<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8578,70 +15144,98 @@ impl flatbuffers::Verifiable for ValueNode<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Dim>>,
-            >>("shape", Self::VT_SHAPE, false)?
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Node>>,
+            >>("nodes", Self::VT_NODES, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "inputs",
+                Self::VT_INPUTS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "outputs",
+                Self::VT_OUTPUTS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct ValueNodeArgs<'a> {
-    pub shape: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim<'a>>>>,
+pub struct GraphArgs<'a> {
+    pub nodes: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>>,
     >,
+    pub inputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for ValueNodeArgs<'a> {
+impl<'a> Default for GraphArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ValueNodeArgs { shape: None }
+        GraphArgs {
+            nodes: None,
+            inputs: None,
+            outputs: None,
+        }
     }
 }
 
-pub struct ValueNodeBuilder<'a: 'b, 'b> {
+pub struct GraphBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ValueNodeBuilder<'a, 'b> {
+impl<'a: 'b, 'b> GraphBuilder<'a, 'b> {
     #[inline]
-    pub fn add_shape(
+    pub fn add_nodes(
         &mut self,
-        shape: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Dim<'b>>>,
+        nodes: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Node<'b>>>,
         >,
     ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ValueNode::VT_SHAPE, shape);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_NODES, nodes);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ValueNodeBuilder<'a, 'b> {
+    pub fn add_inputs(&mut self, inputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_INPUTS, inputs);
+    }
+    #[inline]
+    pub fn add_outputs(&mut self, outputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_OUTPUTS, outputs);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GraphBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ValueNodeBuilder {
+        GraphBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ValueNode<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Graph<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ValueNode<'_> {
+impl core::fmt::Debug for Graph<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ValueNode");
-        ds.field("shape", &self.shape());
+        let mut ds = f.debug_struct("Graph");
+        ds.field("nodes", &self.nodes());
+        ds.field("inputs", &self.inputs());
+        ds.field("outputs", &self.outputs());
         ds.finish()
     }
 }
-pub enum NodeOffset {}
+pub enum OpsetImportOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Node<'a> {
+pub struct OpsetImport<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Node<'a> {
-    type Inner = Node<'a>;
+impl<'a> flatbuffers::Follow<'a> for OpsetImport<'a> {
+    type Inner = OpsetImport<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8650,250 +15244,125 @@ impl<'a> flatbuffers::Follow<'a> for Node<'a> {
     }
 }
 
-impl<'a> Node<'a> {
-    pub const VT_NAME: flatbuffers::VOffsetT = 4;
-    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_DATA: flatbuffers::VOffsetT = 8;
+impl<'a> OpsetImport<'a> {
+    pub const VT_DOMAIN: flatbuffers::VOffsetT = 4;
+    pub const VT_VERSION: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Node { _tab: table }
+        OpsetImport { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args NodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Node<'bldr>> {
-        let mut builder = NodeBuilder::new(_fbb);
-        if let Some(x) = args.data {
-            builder.add_data(x);
+        args: &'args OpsetImportArgs<'args>,
+    ) -> flatbuffers::WIPOffset<OpsetImport<'bldr>> {
+        let mut builder = OpsetImportBuilder::new(_fbb);
+        builder.add_version(args.version);
+        if let Some(x) = args.domain {
+            builder.add_domain(x);
         }
-        if let Some(x) = args.name {
-            builder.add_name(x);
-        }
-        builder.add_data_type(args.data_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn name(&self) -> Option<&'a str> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_NAME, None)
-        }
-    }
-    #[inline]
-    pub fn data_type(&self) -> NodeKind {
+    pub fn domain(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NodeKind>(Node::VT_DATA_TYPE, Some(NodeKind::NONE))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(OpsetImport::VT_DOMAIN, None)
         }
     }
     #[inline]
-    pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
+    pub fn version(&self) -> i64 {
         // Safety:
         // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Node::VT_DATA, None)
-        }
-    }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_operator_node(&self) -> Option<OperatorNode<'a>> {
-        if self.data_type() == NodeKind::OperatorNode {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { OperatorNode::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_constant_node(&self) -> Option<ConstantNode<'a>> {
-        if self.data_type() == NodeKind::ConstantNode {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ConstantNode::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_value_node(&self) -> Option<ValueNode<'a>> {
-        if self.data_type() == NodeKind::ValueNode {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ValueNode::init_from_table(t) }
-            })
-        } else {
-            None
-        }
-    }
-}
-
-impl flatbuffers::Verifiable for Node<'_> {
-    #[inline]
-    fn run_verifier(
-        v: &mut flatbuffers::Verifier,
-        pos: usize,
-    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-        use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-            .visit_union::<NodeKind, _>(
-                "data_type",
-                Self::VT_DATA_TYPE,
-                "data",
-                Self::VT_DATA,
-                false,
-                |key, v, pos| match key {
-                    NodeKind::OperatorNode => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<OperatorNode>>(
-                            "NodeKind::OperatorNode",
-                            pos,
-                        ),
-                    NodeKind::ConstantNode => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ConstantNode>>(
-                            "NodeKind::ConstantNode",
-                            pos,
-                        ),
-                    NodeKind::ValueNode => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ValueNode>>(
-                            "NodeKind::ValueNode",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
-            )?
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(OpsetImport::VT_VERSION, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for OpsetImport<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+            .visit_field::<i64>("version", Self::VT_VERSION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct NodeArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub data_type: NodeKind,
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct OpsetImportArgs<'a> {
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub version: i64,
 }
-impl<'a> Default for NodeArgs<'a> {
+impl<'a> Default for OpsetImportArgs<'a> {
     #[inline]
     fn default() -> Self {
-        NodeArgs {
-            name: None,
-            data_type: NodeKind::NONE,
-            data: None,
+        OpsetImportArgs {
+            domain: None,
+            version: 0,
         }
     }
 }
 
-pub struct NodeBuilder<'a: 'b, 'b> {
+pub struct OpsetImportBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> NodeBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_NAME, name);
-    }
+impl<'a: 'b, 'b> OpsetImportBuilder<'a, 'b> {
     #[inline]
-    pub fn add_data_type(&mut self, data_type: NodeKind) {
+    pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<NodeKind>(Node::VT_DATA_TYPE, data_type, NodeKind::NONE);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(OpsetImport::VT_DOMAIN, domain);
     }
     #[inline]
-    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_version(&mut self, version: i64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_DATA, data);
+            .push_slot::<i64>(OpsetImport::VT_VERSION, version, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OpsetImportBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        NodeBuilder {
+        OpsetImportBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<OpsetImport<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Node<'_> {
+impl core::fmt::Debug for OpsetImport<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Node");
-        ds.field("name", &self.name());
-        ds.field("data_type", &self.data_type());
-        match self.data_type() {
-            NodeKind::OperatorNode => {
-                if let Some(x) = self.data_as_operator_node() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            NodeKind::ConstantNode => {
-                if let Some(x) = self.data_as_constant_node() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            NodeKind::ValueNode => {
-                if let Some(x) = self.data_as_value_node() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("data", &x)
-            }
-        };
+        let mut ds = f.debug_struct("OpsetImport");
+        ds.field("domain", &self.domain());
+        ds.field("version", &self.version());
         ds.finish()
     }
 }
-pub enum GraphOffset {}
+pub enum MetadataPropOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Graph<'a> {
+pub struct MetadataProp<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Graph<'a> {
-    type Inner = Graph<'a>;
+impl<'a> flatbuffers::Follow<'a> for MetadataProp<'a> {
+    type Inner = MetadataProp<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8902,73 +15371,52 @@ impl<'a> flatbuffers::Follow<'a> for Graph<'a> {
     }
 }
 
-impl<'a> Graph<'a> {
-    pub const VT_NODES: flatbuffers::VOffsetT = 4;
-    pub const VT_INPUTS: flatbuffers::VOffsetT = 6;
-    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 8;
+impl<'a> MetadataProp<'a> {
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Graph { _tab: table }
+        MetadataProp { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GraphArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Graph<'bldr>> {
-        let mut builder = GraphBuilder::new(_fbb);
-        if let Some(x) = args.outputs {
-            builder.add_outputs(x);
-        }
-        if let Some(x) = args.inputs {
-            builder.add_inputs(x);
+        args: &'args MetadataPropArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MetadataProp<'bldr>> {
+        let mut builder = MetadataPropBuilder::new(_fbb);
+        if let Some(x) = args.value {
+            builder.add_value(x);
         }
-        if let Some(x) = args.nodes {
-            builder.add_nodes(x);
+        if let Some(x) = args.key {
+            builder.add_key(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn nodes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node>>,
-            >>(Graph::VT_NODES, None)
-        }
-    }
-    #[inline]
-    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn key(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    Graph::VT_INPUTS,
-                    None,
-                )
+                .get::<flatbuffers::ForwardsUOffset<&str>>(MetadataProp::VT_KEY, None)
         }
     }
     #[inline]
-    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn value(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    Graph::VT_OUTPUTS,
-                    None,
-                )
+                .get::<flatbuffers::ForwardsUOffset<&str>>(MetadataProp::VT_VALUE, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for Graph<'_> {
+impl flatbuffers::Verifiable for MetadataProp<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8976,87 +15424,61 @@ impl flatbuffers::Verifiable for Graph<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Node>>,
-            >>("nodes", Self::VT_NODES, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "inputs",
-                Self::VT_INPUTS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "outputs",
-                Self::VT_OUTPUTS,
-                false,
-            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GraphArgs<'a> {
-    pub nodes: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>>,
-    >,
-    pub inputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct MetadataPropArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for GraphArgs<'a> {
+impl<'a> Default for MetadataPropArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GraphArgs {
-            nodes: None,
-            inputs: None,
-            outputs: None,
+        MetadataPropArgs {
+            key: None,
+            value: None,
         }
     }
 }
 
-pub struct GraphBuilder<'a: 'b, 'b> {
+pub struct MetadataPropBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GraphBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_nodes(
-        &mut self,
-        nodes: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Node<'b>>>,
-        >,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_NODES, nodes);
-    }
+impl<'a: 'b, 'b> MetadataPropBuilder<'a, 'b> {
     #[inline]
-    pub fn add_inputs(&mut self, inputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_INPUTS, inputs);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MetadataProp::VT_KEY, key);
     }
     #[inline]
-    pub fn add_outputs(&mut self, outputs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_OUTPUTS, outputs);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MetadataProp::VT_VALUE, value);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GraphBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MetadataPropBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        GraphBuilder {
+        MetadataPropBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Graph<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MetadataProp<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Graph<'_> {
+impl core::fmt::Debug for MetadataProp<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Graph");
-        ds.field("nodes", &self.nodes());
-        ds.field("inputs", &self.inputs());
-        ds.field("outputs", &self.outputs());
+        let mut ds = f.debug_struct("MetadataProp");
+        ds.field("key", &self.key());
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
@@ -9086,6 +15508,11 @@ impl<'a> Metadata<'a> {
     pub const VT_MODEL_REPOSITORY: flatbuffers::VOffsetT = 14;
     pub const VT_RUN_ID: flatbuffers::VOffsetT = 16;
     pub const VT_RUN_URL: flatbuffers::VOffsetT = 18;
+    pub const VT_PRODUCER_NAME: flatbuffers::VOffsetT = 20;
+    pub const VT_PRODUCER_VERSION: flatbuffers::VOffsetT = 22;
+    pub const VT_DOC_STRING: flatbuffers::VOffsetT = 24;
+    pub const VT_OPSET_IMPORTS: flatbuffers::VOffsetT = 26;
+    pub const VT_METADATA_PROPS: flatbuffers::VOffsetT = 28;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -9097,6 +15524,21 @@ impl<'a> Metadata<'a> {
         args: &'args MetadataArgs<'args>,
     ) -> flatbuffers::WIPOffset<Metadata<'bldr>> {
         let mut builder = MetadataBuilder::new(_fbb);
+        if let Some(x) = args.metadata_props {
+            builder.add_metadata_props(x);
+        }
+        if let Some(x) = args.opset_imports {
+            builder.add_opset_imports(x);
+        }
+        if let Some(x) = args.doc_string {
+            builder.add_doc_string(x);
+        }
+        if let Some(x) = args.producer_version {
+            builder.add_producer_version(x);
+        }
+        if let Some(x) = args.producer_name {
+            builder.add_producer_name(x);
+        }
         if let Some(x) = args.run_url {
             builder.add_run_url(x);
         }
@@ -9204,6 +15646,62 @@ impl<'a> Metadata<'a> {
                 .get::<flatbuffers::ForwardsUOffset<&str>>(Metadata::VT_RUN_URL, None)
         }
     }
+    #[inline]
+    pub fn producer_name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Metadata::VT_PRODUCER_NAME, None)
+        }
+    }
+    #[inline]
+    pub fn producer_version(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Metadata::VT_PRODUCER_VERSION, None)
+        }
+    }
+    #[inline]
+    pub fn doc_string(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Metadata::VT_DOC_STRING, None)
+        }
+    }
+    #[inline]
+    pub fn opset_imports(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpsetImport<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpsetImport>>,
+            >>(Metadata::VT_OPSET_IMPORTS, None)
+        }
+    }
+    #[inline]
+    pub fn metadata_props(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MetadataProp<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MetadataProp>>,
+            >>(Metadata::VT_METADATA_PROPS, None)
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for Metadata<'_> {
@@ -9238,6 +15736,27 @@ impl flatbuffers::Verifiable for Metadata<'_> {
             )?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>("run_id", Self::VT_RUN_ID, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>("run_url", Self::VT_RUN_URL, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "producer_name",
+                Self::VT_PRODUCER_NAME,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "producer_version",
+                Self::VT_PRODUCER_VERSION,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "doc_string",
+                Self::VT_DOC_STRING,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<OpsetImport>>,
+            >>("opset_imports", Self::VT_OPSET_IMPORTS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MetadataProp>>,
+            >>("metadata_props", Self::VT_METADATA_PROPS, false)?
             .finish();
         Ok(())
     }
@@ -9251,6 +15770,19 @@ pub struct MetadataArgs<'a> {
     pub model_repository: Option<flatbuffers::WIPOffset<&'a str>>,
     pub run_id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub run_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub producer_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub producer_version: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub doc_string: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub opset_imports: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpsetImport<'a>>>,
+        >,
+    >,
+    pub metadata_props: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MetadataProp<'a>>>,
+        >,
+    >,
 }
 impl<'a> Default for MetadataArgs<'a> {
     #[inline]
@@ -9264,6 +15796,11 @@ impl<'a> Default for MetadataArgs<'a> {
             model_repository: None,
             run_id: None,
             run_url: None,
+            producer_name: None,
+            producer_version: None,
+            doc_string: None,
+            opset_imports: None,
+            metadata_props: None,
         }
     }
 }
@@ -9318,6 +15855,49 @@ impl<'a: 'b, 'b> MetadataBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(Metadata::VT_RUN_URL, run_url);
     }
     #[inline]
+    pub fn add_producer_name(&mut self, producer_name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Metadata::VT_PRODUCER_NAME,
+            producer_name,
+        );
+    }
+    #[inline]
+    pub fn add_producer_version(&mut self, producer_version: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Metadata::VT_PRODUCER_VERSION,
+            producer_version,
+        );
+    }
+    #[inline]
+    pub fn add_doc_string(&mut self, doc_string: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Metadata::VT_DOC_STRING, doc_string);
+    }
+    #[inline]
+    pub fn add_opset_imports(
+        &mut self,
+        opset_imports: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<OpsetImport<'b>>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Metadata::VT_OPSET_IMPORTS,
+            opset_imports,
+        );
+    }
+    #[inline]
+    pub fn add_metadata_props(
+        &mut self,
+        metadata_props: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MetadataProp<'b>>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Metadata::VT_METADATA_PROPS,
+            metadata_props,
+        );
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MetadataBuilder<'a, 'b> {
         let start = _fbb.start_table();
         MetadataBuilder {
@@ -9343,6 +15923,11 @@ impl core::fmt::Debug for Metadata<'_> {
         ds.field("model_repository", &self.model_repository());
         ds.field("run_id", &self.run_id());
         ds.field("run_url", &self.run_url());
+        ds.field("producer_name", &self.producer_name());
+        ds.field("producer_version", &self.producer_version());
+        ds.field("doc_string", &self.doc_string());
+        ds.field("opset_imports", &self.opset_imports());
+        ds.field("metadata_props", &self.metadata_props());
         ds.finish()
     }
 }
@@ -1,5 +1,7 @@
 //! Optimized reductions of slices of numbers.
 
+use rten_vecmath::{vec_sum, vec_sum_square};
+
 use crate::number::MinMax;
 
 /// Return the sum of a slice of numbers.
@@ -25,8 +27,55 @@ pub fn slice_max<T: Copy + MinMax>(xs: &[T]) -> T {
         .fold(T::min_val(), |x, y| x.max(y))
 }
 
+/// Numeric types which support optimized sums of slices.
+///
+/// For `f32` these use the SIMD primitives from `rten_vecmath`.
+pub trait SliceSum: Copy + std::iter::Sum {
+    /// Return the sum of elements in `xs`.
+    fn slice_sum(xs: &[Self]) -> Self;
+
+    /// Return the sum of the squares of elements in `xs`.
+    fn slice_sum_square(xs: &[Self]) -> Self;
+}
+
+impl SliceSum for f32 {
+    fn slice_sum(xs: &[f32]) -> f32 {
+        vec_sum(xs)
+    }
+
+    fn slice_sum_square(xs: &[f32]) -> f32 {
+        vec_sum_square(xs)
+    }
+}
+
+impl SliceSum for i32 {
+    fn slice_sum(xs: &[i32]) -> i32 {
+        chunked_sum(xs)
+    }
+
+    fn slice_sum_square(xs: &[i32]) -> i32 {
+        xs.iter().map(|x| x * x).sum()
+    }
+}
+
+impl SliceSum for i64 {
+    fn slice_sum(xs: &[i64]) -> i64 {
+        chunked_sum(xs)
+    }
+
+    fn slice_sum_square(xs: &[i64]) -> i64 {
+        xs.iter().map(|x| x * x).sum()
+    }
+}
+
 /// Return the sum of a slice of numbers.
-pub fn slice_sum<T: Copy + Default + std::ops::Add<Output = T>>(xs: &[T]) -> T {
+pub fn slice_sum<T: SliceSum>(xs: &[T]) -> T {
+    T::slice_sum(xs)
+}
+
+/// Return the sum of a slice of numbers, using a loop that is written to
+/// encourage autovectorization.
+fn chunked_sum<T: Copy + Default + std::ops::Add<Output = T>>(xs: &[T]) -> T {
     const CHUNK_SIZE: usize = 8;
     xs.chunks(CHUNK_SIZE)
         .map(|chunk| {
@@ -48,7 +97,7 @@ mod tests {
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::ApproxEq;
 
-    use super::{slice_max, slice_sum};
+    use super::{chunked_sum, slice_max, slice_sum, SliceSum};
 
     #[test]
     fn test_slice_max() {
@@ -68,5 +117,22 @@ mod tests {
             .take(256)
             .collect();
         assert!(xs.iter().sum::<f32>().approx_eq(&slice_sum(&xs)));
+        assert!(xs.iter().sum::<f32>().approx_eq(&chunked_sum(&xs)));
+
+        let ints: Vec<i32> = (0..100).collect();
+        assert_eq!(slice_sum(&ints), 4950);
+    }
+
+    #[test]
+    fn test_slice_sum_square() {
+        let mut rng = XorShiftRng::new(1234);
+        let xs: Vec<_> = std::iter::from_fn(|| Some(rng.next_f32()))
+            .take(256)
+            .collect();
+        let expected: f32 = xs.iter().map(|x| x * x).sum();
+        assert!(expected.approx_eq(&f32::slice_sum_square(&xs)));
+
+        let ints: Vec<i32> = (0..10).collect();
+        assert_eq!(i32::slice_sum_square(&ints), 285);
     }
 }
@@ -0,0 +1,249 @@
+//! Sparse matrices and sparse-dense matrix multiplication.
+//!
+//! Models whose weights have been pruned can contain matrices where most
+//! elements are zero. Storing these in a compressed format such as
+//! [CsrMatrix] reduces memory usage, and matrix multiplication can skip the
+//! zero elements.
+//!
+//! When a model is loaded with
+//! [`ModelOptions::prepack_weights`](crate::ModelOptions::prepack_weights)
+//! enabled, constant `MatMul` weights whose [density] is at or below
+//! [SPARSE_DENSITY_THRESHOLD] are converted to this format automatically.
+
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView};
+
+use crate::ops::OpError;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Maximum fraction of non-zero elements for which a weight matrix is stored
+/// and multiplied in sparse form.
+///
+/// Sparse matrix multiplication does less arithmetic than dense GEMM, but
+/// each operation is much more expensive since it cannot use the same
+/// vectorized, cache-blocked kernels. It therefore only pays off for quite
+/// sparse matrices.
+pub const SPARSE_DENSITY_THRESHOLD: f32 = 0.1;
+
+/// Return the fraction of elements in `mat` which are non-zero.
+///
+/// Returns 1.0 for an empty matrix.
+pub fn density(mat: NdTensorView<f32, 2>) -> f32 {
+    if mat.is_empty() {
+        return 1.;
+    }
+    let non_zero = mat.iter().filter(|x| **x != 0.).count();
+    non_zero as f32 / mat.len() as f32
+}
+
+/// A sparse matrix stored in Compressed Sparse Row (CSR) format.
+///
+/// The non-zero elements of row `i` are `values[row_offsets[i]..row_offsets[i + 1]]`,
+/// and their column indices are stored at the same positions in `col_indices`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<u32>,
+    values: Vec<f32>,
+}
+
+impl CsrMatrix {
+    /// Create a sparse matrix from the non-zero elements of a dense matrix.
+    ///
+    /// Panics if the number of columns exceeds `u32::MAX`.
+    pub fn from_dense(mat: NdTensorView<f32, 2>) -> CsrMatrix {
+        let [rows, cols] = mat.shape();
+        assert!(cols <= u32::MAX as usize, "too many columns");
+
+        let mut row_offsets = Vec::with_capacity(rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+
+        row_offsets.push(0);
+        for row in mat.inner_iter::<1>() {
+            for (col, &x) in row.iter().enumerate() {
+                if x != 0. {
+                    col_indices.push(col as u32);
+                    values.push(x);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Return the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Return the number of non-zero elements which are stored.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Return the column indices and values of the non-zero elements in a row.
+    pub fn row(&self, index: usize) -> (&[u32], &[f32]) {
+        let range = self.row_offsets[index]..self.row_offsets[index + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+
+    /// Convert this matrix back to a dense matrix.
+    pub fn to_dense(&self) -> NdTensor<f32, 2> {
+        let mut dense = NdTensor::zeros([self.rows, self.cols]);
+        for row in 0..self.rows {
+            let (cols, values) = self.row(row);
+            for (&col, &x) in cols.iter().zip(values) {
+                dense[[row, col as usize]] = x;
+            }
+        }
+        dense
+    }
+}
+
+/// Compute the product `a @ b` of a dense matrix `a` and a sparse matrix `b`,
+/// where `b_t` is the transpose of `b`.
+///
+/// Using the transpose of `b` means that each output element is computed as
+/// a dot product between a row of `a` and a sparse row of `b_t`, so output
+/// elements can be computed independently. Weights of linear layers are often
+/// stored in this transposed form already.
+pub fn sparse_matmul(
+    pool: &TensorPool,
+    a: NdTensorView<f32, 2>,
+    b_t: &CsrMatrix,
+) -> Result<NdTensor<f32, 2>, OpError> {
+    let [a_rows, a_cols] = a.shape();
+    if a_cols != b_t.cols() {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+    let b_cols = b_t.rows();
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+    let a_data = a.data().unwrap();
+
+    let mut output = NdTensor::zeros_in(pool, [a_rows, b_cols]);
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    // Number of output elements computed by each parallel task.
+    const CHUNK_SIZE: usize = 256;
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(CHUNK_SIZE)
+        .enumerate()
+        .for_each(|(chunk_idx, out_chunk)| {
+            let start = chunk_idx * CHUNK_SIZE;
+            for (i, out) in out_chunk.iter_mut().enumerate() {
+                let (row, col) = ((start + i) / b_cols, (start + i) % b_cols);
+                let a_row = &a_data[row * a_cols..][..a_cols];
+                let (indices, values) = b_t.row(col);
+                *out = indices
+                    .iter()
+                    .zip(values)
+                    .map(|(&k, &x)| a_row[k as usize] * x)
+                    .sum();
+            }
+        });
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::NdTensor;
+
+    use super::{density, sparse_matmul, CsrMatrix};
+    use crate::ops::{matmul_2d, OpError};
+    use crate::tensor_pool::TensorPool;
+
+    /// Generate a random matrix where roughly `density` of elements are
+    /// non-zero.
+    fn random_sparse(rng: &mut XorShiftRng, shape: [usize; 2], density: f32) -> NdTensor<f32, 2> {
+        let mut mat = NdTensor::rand(shape, rng);
+        let mask = NdTensor::<f32, 2>::rand(shape, rng);
+        for (x, m) in mat.iter_mut().zip(mask.iter()) {
+            if *m >= density {
+                *x = 0.;
+            }
+        }
+        mat
+    }
+
+    #[test]
+    fn test_csr_matrix() {
+        let dense = NdTensor::from([[0., 1., 0.], [0., 0., 0.], [2., 0., 3.]]);
+        let sparse = CsrMatrix::from_dense(dense.view());
+
+        assert_eq!(sparse.rows(), 3);
+        assert_eq!(sparse.cols(), 3);
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.row(0), (&[1][..], &[1.][..]));
+        assert_eq!(sparse.row(1), (&[][..], &[][..]));
+        assert_eq!(sparse.row(2), (&[0, 2][..], &[2., 3.][..]));
+        assert_eq!(sparse.to_dense(), dense);
+
+        assert_eq!(density(dense.view()), 3. / 9.);
+        assert_eq!(density(NdTensor::<f32, 2>::zeros([0, 3]).view()), 1.);
+    }
+
+    #[test]
+    fn test_sparse_matmul() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = TensorPool::new();
+        let mut rng = XorShiftRng::new(1234);
+
+        for (m, k, n) in [(1, 64, 32), (5, 17, 300), (16, 128, 64), (0, 4, 4)] {
+            let a = NdTensor::rand([m, k], &mut rng);
+            let b = random_sparse(&mut rng, [k, n], 0.1);
+            let b_t = CsrMatrix::from_dense(b.transposed());
+
+            let expected = matmul_2d(&pool, a.view(), b.view()).unwrap();
+            let result = sparse_matmul(&pool, a.view(), &b_t).unwrap();
+            expect_equal(&result, &expected)?;
+
+            // Non-contiguous `a`.
+            let a_t = a.transposed().to_tensor();
+            let result = sparse_matmul(&pool, a_t.transposed(), &b_t).unwrap();
+            expect_equal(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_matmul_invalid() {
+        let pool = TensorPool::new();
+        let a = NdTensor::<f32, 2>::zeros([2, 3]);
+        let b_t = CsrMatrix::from_dense(NdTensor::zeros([4, 2]).view());
+        let result = sparse_matmul(&pool, a.view(), &b_t);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Columns of first matrix does not match rows of second matrix"
+            ))
+        );
+    }
+}
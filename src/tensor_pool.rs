@@ -1,8 +1,12 @@
 use std::cell::RefCell;
 use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
 
 use rten_tensor::{Alloc, CowData, MutLayout, TensorBase};
 
+use crate::memory_plan::MemoryPlan;
+use crate::ops::Output;
+
 /// A memory buffer that can be used to satisfy a future allocation from
 /// a [TensorPool].
 struct Buffer {
@@ -75,6 +79,11 @@ impl Buffer {
     }
 }
 
+// Safety: A `Buffer` uniquely owns an allocation which contains no values, as
+// the vec is cleared in `Buffer::from_vec`, so it can be moved between threads
+// regardless of the original element type.
+unsafe impl Send for Buffer {}
+
 impl Drop for Buffer {
     fn drop(&mut self) {
         (self.drop)(self);
@@ -100,6 +109,12 @@ impl Drop for Buffer {
 /// tensor can be wrapped using `tensor.auto_return(pool)`. The [PoolRef] smart
 /// pointer can also be used with other container types, by implementing the
 /// [ExtractBuffer] trait for them.
+///
+/// Buffers are `Vec`s with the natural alignment of their element type. A
+/// `Vec` must be deallocated with the layout it was allocated with, so the
+/// pool cannot hand out over-aligned buffers and kernels must not assume any
+/// stronger alignment. Code which benefits from aligned data, such as GEMM
+/// packing, aligns its data within the buffer instead.
 pub struct TensorPool {
     /// List of buffers currently in the pool.
     buffers: RefCell<Vec<Buffer>>,
@@ -170,6 +185,22 @@ impl TensorPool {
         self.buffers.borrow_mut().push(Buffer::from_vec(vec));
     }
 
+    /// Add the data buffers of an operator output to the pool.
+    pub(crate) fn add_output(&self, output: Output) {
+        match output {
+            Output::FloatTensor(t) => t.extract_buffer().map(|buf| self.add(buf)),
+            Output::IntTensor(t) => t.extract_buffer().map(|buf| self.add(buf)),
+            Output::Int64Tensor(t) => t.extract_buffer().map(|buf| self.add(buf)),
+            Output::StringTensor(t) => t.extract_buffer().map(|buf| self.add(buf)),
+            Output::Sequence(seq) => {
+                for item in seq.into_items() {
+                    self.add_output(item);
+                }
+                None
+            }
+        };
+    }
+
     /// Return the total number of allocation requests.
     pub fn alloc_count(&self) -> usize {
         *self.alloc_count.borrow()
@@ -204,6 +235,142 @@ impl Default for TensorPool {
     }
 }
 
+/// A pool of buffers which is kept alive across model runs.
+///
+/// By default each run of a model starts with an empty [TensorPool], so
+/// buffers for intermediate values are allocated on every run. When an arena
+/// is passed via [RunOptions::arena](crate::RunOptions::arena), buffers are
+/// taken from the arena at the start of the run and returned to it at the
+/// end, so that steady-state inference with fixed input shapes does not need
+/// to allocate.
+///
+/// An arena can be pre-sized from a [MemoryPlan] using
+/// [`from_plan`](BufferArena::from_plan), so that the first run also avoids
+/// allocating buffers for intermediate values. Model outputs are owned by the
+/// caller. Their buffers can be returned using
+/// [`reclaim`](BufferArena::reclaim) once they are no longer needed.
+///
+/// Cloning an arena creates a new reference to the same set of buffers.
+#[derive(Clone, Default)]
+pub struct BufferArena {
+    pool: Arc<Mutex<TensorPool>>,
+}
+
+impl BufferArena {
+    /// Create an empty arena.
+    pub fn new() -> BufferArena {
+        BufferArena::default()
+    }
+
+    /// Create an arena with a buffer preallocated for each slot in the static
+    /// arena described by `plan`.
+    ///
+    /// See [MemoryPlan::slot_sizes].
+    pub fn from_plan(plan: &MemoryPlan) -> BufferArena {
+        let pool = TensorPool::new();
+        for &size in plan.slot_sizes() {
+            // All tensor element types currently have the same size and
+            // alignment, so the buffers can be used for any of them.
+            let len = size / std::mem::size_of::<f32>();
+            pool.add(Vec::<f32>::with_capacity(len));
+        }
+        BufferArena {
+            pool: Arc::new(Mutex::new(pool)),
+        }
+    }
+
+    /// Return the buffer of a model output to the arena, so it can be re-used
+    /// by a future run.
+    pub fn reclaim(&self, output: Output) {
+        self.pool.lock().unwrap().add_output(output);
+    }
+
+    /// Return the number of buffers currently in the arena.
+    pub fn len(&self) -> usize {
+        self.pool.lock().unwrap().len()
+    }
+
+    /// Return true if the arena contains no buffers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the total number of allocation requests made from the arena
+    /// across all runs.
+    pub fn alloc_count(&self) -> usize {
+        self.pool.lock().unwrap().alloc_count()
+    }
+
+    /// Return the number of allocation requests that were fulfilled using
+    /// buffers in the arena, across all runs.
+    pub fn hit_count(&self) -> usize {
+        self.pool.lock().unwrap().hit_count()
+    }
+
+    /// Take the buffers from the arena for use in a run. They are returned
+    /// when the guard is dropped.
+    pub(crate) fn take_pool(&self) -> ArenaPool<'_> {
+        let pool = std::mem::take(&mut *self.pool.lock().unwrap());
+        ArenaPool {
+            arena: Some(self),
+            pool,
+        }
+    }
+}
+
+impl std::fmt::Debug for BufferArena {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BufferArena")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// A [TensorPool] used during a graph run, which is returned to the
+/// [BufferArena] it was taken from, if any, when dropped.
+pub(crate) struct ArenaPool<'a> {
+    arena: Option<&'a BufferArena>,
+    pool: TensorPool,
+}
+
+impl<'a> ArenaPool<'a> {
+    /// Create a pool which is not associated with an arena.
+    pub(crate) fn new() -> ArenaPool<'a> {
+        ArenaPool {
+            arena: None,
+            pool: TensorPool::new(),
+        }
+    }
+}
+
+impl<'a> Deref for ArenaPool<'a> {
+    type Target = TensorPool;
+
+    fn deref(&self) -> &TensorPool {
+        &self.pool
+    }
+}
+
+impl<'a> Drop for ArenaPool<'a> {
+    fn drop(&mut self) {
+        if let Some(arena) = self.arena {
+            let pool = std::mem::take(&mut self.pool);
+            let mut arena_pool = arena.pool.lock().unwrap();
+
+            // Keep the arena's statistics cumulative across runs.
+            *pool.alloc_count.borrow_mut() += arena_pool.alloc_count();
+            *pool.hit_count.borrow_mut() += arena_pool.hit_count();
+
+            // Buffers may have been returned via `BufferArena::reclaim` during
+            // the run.
+            for buf in arena_pool.buffers.take() {
+                pool.buffers.borrow_mut().push(buf);
+            }
+            *arena_pool = pool;
+        }
+    }
+}
+
 /// Trait for extracting the data buffer from a tensor or other container.
 ///
 /// This is used to extract the buffer from a container that is no longer
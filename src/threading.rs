@@ -4,7 +4,10 @@ use std::sync::OnceLock;
 /// A wrapper around the Rayon thread pool used to run models.
 ///
 /// On platforms where threads are not supported (eg. WebAssembly) this runs
-/// operations directly on the main thread.
+/// operations directly on the main thread. Operators may still run in parallel
+/// using Rayon's global thread pool, if one has been created. In WebAssembly
+/// builds with the `wasm_threads` feature, this is done from JS by calling
+/// `initThreadPool`.
 pub struct ThreadPool {
     /// The wrapped thread pool, or None if we failed to construct one.
     pool: Option<rayon::ThreadPool>,
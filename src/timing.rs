@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
 
 use smallvec::SmallVec;
 
@@ -7,7 +9,7 @@ use smallvec::SmallVec;
 ///
 /// Tables can be formatted using [Table::display] to get a wrapper that
 /// implements [Display].
-trait Table {
+pub(crate) trait Table {
     /// Return the number of rows in this table.
     fn rows(&self) -> usize;
 
@@ -39,7 +41,7 @@ trait Table {
     }
 }
 
-struct DisplayTable<'a, T: Table> {
+pub(crate) struct DisplayTable<'a, T: Table> {
     table: &'a T,
     indent: usize,
 }
@@ -376,3 +378,309 @@ pub enum TimingSort {
     #[default]
     ByTime,
 }
+
+/// Execution record for a single operator in a profiled graph run.
+#[derive(Clone, Debug)]
+pub struct ProfileRecord {
+    /// ID of the operator node in the graph
+    pub node_id: usize,
+
+    /// Name of the operator node in the graph
+    pub node_name: String,
+
+    /// Operator type (eg. `MatMul`)
+    pub op_type: String,
+
+    /// Shapes of the operator's inputs. Omitted inputs have a shape of `None`.
+    pub input_shapes: Vec<Option<Vec<usize>>>,
+
+    /// Shapes of the operator's outputs
+    pub output_shapes: Vec<Vec<usize>>,
+
+    /// Execution time of the operator in milliseconds
+    pub elapsed_ms: f32,
+}
+
+/// Aggregated statistics for all runs of an operator type in a profiled graph
+/// run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpTypeSummary {
+    /// Operator type (eg. `MatMul`)
+    pub op_type: String,
+
+    /// Number of times an operator of this type was run
+    pub count: usize,
+
+    /// Total execution time of all operators of this type, in milliseconds
+    pub total_ms: f32,
+}
+
+/// Per-operator timings and shapes collected from a graph run.
+///
+/// Profiles are collected by setting [RunOptions::profiler](crate::RunOptions::profiler)
+/// and retrieved using [Profiler::take_profile] after the run completes.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    records: Vec<ProfileRecord>,
+    total_ms: f32,
+}
+
+impl Profile {
+    pub(crate) fn new(records: Vec<ProfileRecord>, total_ms: f32) -> Profile {
+        Profile { records, total_ms }
+    }
+
+    /// Return records for each operator that was run, in execution order.
+    pub fn records(&self) -> &[ProfileRecord] {
+        &self.records
+    }
+
+    /// Return the total time for the graph run in milliseconds.
+    pub fn total_ms(&self) -> f32 {
+        self.total_ms
+    }
+
+    /// Aggregate execution times by operator type.
+    pub fn summary_by_op_type(&self, sort: TimingSort) -> Vec<OpTypeSummary> {
+        let mut summaries: Vec<OpTypeSummary> = Vec::new();
+        let mut index_by_type: HashMap<&str, usize> = HashMap::new();
+        for record in &self.records {
+            let index = *index_by_type
+                .entry(record.op_type.as_str())
+                .or_insert_with(|| {
+                    summaries.push(OpTypeSummary {
+                        op_type: record.op_type.clone(),
+                        count: 0,
+                        total_ms: 0.,
+                    });
+                    summaries.len() - 1
+                });
+            summaries[index].count += 1;
+            summaries[index].total_ms += record.elapsed_ms;
+        }
+
+        match sort {
+            TimingSort::ByName => summaries.sort_by(|a, b| a.op_type.cmp(&b.op_type)),
+            TimingSort::ByTime => {
+                summaries.sort_by(|a, b| a.total_ms.total_cmp(&b.total_ms).reverse())
+            }
+        }
+        summaries
+    }
+
+    /// Format the profile as a text report.
+    ///
+    /// The report contains a table of execution times aggregated by operator
+    /// type, sorted according to `sort`, followed by a table of all operator
+    /// runs sorted by descending execution time.
+    pub fn to_text(&self, sort: TimingSort) -> String {
+        let summary = OpTypeSummaryTable {
+            rows: self.summary_by_op_type(sort),
+            total_ms: self.total_ms,
+        };
+
+        let mut records: Vec<&ProfileRecord> = self.records.iter().collect();
+        records.sort_by(|a, b| a.elapsed_ms.total_cmp(&b.elapsed_ms).reverse());
+        let nodes = ProfileRecordTable { rows: records };
+
+        format!(
+            "Total time: {:.3}ms\n\n{}\n{}",
+            self.total_ms,
+            summary.display(0 /* indent */),
+            nodes.display(0 /* indent */)
+        )
+    }
+
+    /// Format the profile as a JSON document.
+    ///
+    /// The document is an object with `total_ms`, `op_types` and `nodes`
+    /// fields. `op_types` is sorted by descending total time and `nodes` is
+    /// in execution order.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        write!(json, "{{\"total_ms\":{},\"op_types\":[", self.total_ms).unwrap();
+        for (i, summary) in self
+            .summary_by_op_type(TimingSort::ByTime)
+            .iter()
+            .enumerate()
+        {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"op_type\":{},\"count\":{},\"total_ms\":{}}}",
+                json_string(&summary.op_type),
+                summary.count,
+                summary.total_ms
+            )
+            .unwrap();
+        }
+        json.push_str("],\"nodes\":[");
+        for (i, record) in self.records.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let input_shapes: Vec<String> = record
+                .input_shapes
+                .iter()
+                .map(|shape| {
+                    shape
+                        .as_ref()
+                        .map(|s| json_shape(s))
+                        .unwrap_or("null".to_string())
+                })
+                .collect();
+            let output_shapes: Vec<String> =
+                record.output_shapes.iter().map(|s| json_shape(s)).collect();
+            write!(
+                json,
+                "{{\"id\":{},\"name\":{},\"op_type\":{},\"elapsed_ms\":{},\
+                 \"input_shapes\":[{}],\"output_shapes\":[{}]}}",
+                record.node_id,
+                json_string(&record.node_name),
+                json_string(&record.op_type),
+                record.elapsed_ms,
+                input_shapes.join(","),
+                output_shapes.join(",")
+            )
+            .unwrap();
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+/// Format a string as a quoted JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => write!(out, "\\u{:04x}", ch as u32).unwrap(),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Format a tensor shape as a JSON array.
+fn json_shape(shape: &[usize]) -> String {
+    shape_to_string(shape).replace(' ', "")
+}
+
+/// [Display]-able table of execution times aggregated by operator type.
+struct OpTypeSummaryTable {
+    rows: Vec<OpTypeSummary>,
+    total_ms: f32,
+}
+
+impl Table for OpTypeSummaryTable {
+    fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn headings(&self) -> &[&str] {
+        &["Op type", "Count", "Total (ms)", "Mean (ms)", "% of run"]
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let row = self.rows.get(row).expect("invalid row");
+        match col {
+            0 => row.op_type.clone(),
+            1 => row.count.to_string(),
+            2 => format!("{:.3}", row.total_ms),
+            3 => format!("{:.3}", row.total_ms / row.count as f32),
+            4 => format!("{:.2}", row.total_ms / self.total_ms * 100.),
+            _ => panic!("invalid column"),
+        }
+    }
+}
+
+/// [Display]-able table of individual operator runs.
+struct ProfileRecordTable<'a> {
+    rows: Vec<&'a ProfileRecord>,
+}
+
+impl<'a> Table for ProfileRecordTable<'a> {
+    fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn headings(&self) -> &[&str] {
+        &[
+            "Node",
+            "Op type",
+            "Time (ms)",
+            "Input shapes",
+            "Output shapes",
+        ]
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let row = self.rows.get(row).expect("invalid row");
+        match col {
+            0 => row.node_name.clone(),
+            1 => row.op_type.clone(),
+            2 => format!("{:.3}", row.elapsed_ms),
+            3 => {
+                let shapes: Vec<InputShape> = row
+                    .input_shapes
+                    .iter()
+                    .map(|s| s.as_deref().map(SmallVec::from_slice))
+                    .collect();
+                shapes_to_string(&shapes)
+            }
+            4 => {
+                let shapes: Vec<String> = row
+                    .output_shapes
+                    .iter()
+                    .map(|s| shape_to_string(s))
+                    .collect();
+                shapes.join(", ")
+            }
+            _ => panic!("invalid column"),
+        }
+    }
+}
+
+/// Handle used to collect a [Profile] from a graph run.
+///
+/// To profile a run, create a `Profiler`, set it as the value of
+/// [RunOptions::profiler](crate::RunOptions::profiler), run the model and
+/// then call [Profiler::take_profile]. A profiler can be re-used across runs,
+/// in which case it holds the profile from the most recent run.
+#[derive(Clone, Default)]
+pub struct Profiler {
+    profile: Arc<Mutex<Option<Profile>>>,
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    /// Take the profile collected from the most recent run.
+    ///
+    /// Returns `None` if no run has completed since the profiler was created
+    /// or the profile was last taken.
+    pub fn take_profile(&self) -> Option<Profile> {
+        self.profile.lock().unwrap().take()
+    }
+
+    pub(crate) fn set_profile(&self, profile: Profile) {
+        *self.profile.lock().unwrap() = Some(profile);
+    }
+}
+
+impl fmt::Debug for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profiler").finish_non_exhaustive()
+    }
+}
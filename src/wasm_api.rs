@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::iter::zip;
 use std::rc::Rc;
 
@@ -6,14 +7,27 @@ use rten_tensor::prelude::*;
 use rten_tensor::rng::XorShiftRng;
 use wasm_bindgen::prelude::*;
 
-use crate::graph::Dimension;
+use crate::graph::{Dimension, RunOptions};
 use crate::model;
+use crate::model_metadata::ModelMetadata;
 use crate::ops::{matmul, Input, Output};
 use crate::tensor_pool::TensorPool;
+use crate::timing::{Profile, Profiler, TimingSort};
+
+// Export `initThreadPool` function which JS code must call, and await, to
+// start the Web Workers used for parallel execution before running models.
+#[cfg(feature = "wasm_threads")]
+pub use wasm_bindgen_rayon::init_thread_pool;
 
 #[wasm_bindgen]
 pub struct Model {
     model: model::Model,
+
+    /// Profiler used for runs, if profiling is enabled.
+    profiler: Option<Profiler>,
+
+    /// Profile collected from the most recent run, if profiling is enabled.
+    last_profile: RefCell<Option<Profile>>,
 }
 
 #[wasm_bindgen]
@@ -22,7 +36,44 @@ impl Model {
     #[wasm_bindgen(constructor)]
     pub fn new(model_data: Vec<u8>) -> Result<Model, String> {
         let model = model::Model::load(model_data).map_err(|e| e.to_string())?;
-        Ok(Model { model })
+        Ok(Model {
+            model,
+            profiler: None,
+            last_profile: RefCell::new(None),
+        })
+    }
+
+    /// Enable or disable collection of per-operator timings and shapes in
+    /// subsequent calls to `run`.
+    ///
+    /// The profile for the most recent run can be retrieved using
+    /// `profileReport` or `profileJson`.
+    #[wasm_bindgen(js_name = setProfiling)]
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.profiler = enabled.then(Profiler::new);
+        self.last_profile.replace(None);
+    }
+
+    /// Return a text report of the profile collected from the most recent
+    /// run, or `undefined` if profiling was not enabled.
+    ///
+    /// Operator types are sorted by descending total time.
+    #[wasm_bindgen(js_name = profileReport)]
+    pub fn profile_report(&self) -> Option<String> {
+        self.last_profile
+            .borrow()
+            .as_ref()
+            .map(|profile| profile.to_text(TimingSort::ByTime))
+    }
+
+    /// Return the profile collected from the most recent run as a JSON
+    /// string, or `undefined` if profiling was not enabled.
+    #[wasm_bindgen(js_name = profileJson)]
+    pub fn profile_json(&self) -> Option<String> {
+        self.last_profile
+            .borrow()
+            .as_ref()
+            .map(|profile| profile.to_json())
     }
 
     /// Find the ID of a node in the graph from its name.
@@ -37,6 +88,7 @@ impl Model {
     #[wasm_bindgen(js_name = nodeInfo)]
     pub fn node_info(&self, id: usize) -> Option<NodeInfo> {
         self.model.node_info(id).map(|ni| NodeInfo {
+            id,
             name: ni.name().map(|n| n.to_string()),
             shape: ni.shape(),
         })
@@ -58,6 +110,50 @@ impl Model {
         self.model.output_ids().into()
     }
 
+    /// Return details of all the model's inputs.
+    ///
+    /// This is equivalent to calling `node_info` for each ID returned by
+    /// `input_ids`.
+    pub fn inputs(&self) -> Vec<NodeInfo> {
+        self.model
+            .input_ids()
+            .iter()
+            .filter_map(|&id| self.node_info(id))
+            .collect()
+    }
+
+    /// Return details of all the model's outputs.
+    ///
+    /// This is equivalent to calling `node_info` for each ID returned by
+    /// `output_ids`.
+    pub fn outputs(&self) -> Vec<NodeInfo> {
+        self.model
+            .output_ids()
+            .iter()
+            .filter_map(|&id| self.node_info(id))
+            .collect()
+    }
+
+    /// Return metadata about the model, such as its license and the source
+    /// of its weights.
+    pub fn metadata(&self) -> Metadata {
+        Metadata::from_model_metadata(self.model.metadata())
+    }
+
+    /// Replace the value of a constant (eg. a weight) in the model.
+    ///
+    /// `name` is the name of the constant node, as used by `findNode`.
+    #[wasm_bindgen(js_name = overrideConstant)]
+    pub fn override_constant(&mut self, name: &str, value: &Tensor) -> Result<(), String> {
+        let id = self
+            .model
+            .find_node(name)
+            .ok_or_else(|| format!("Node \"{}\" not found", name))?;
+        self.model
+            .override_constant(id, (*value.data).clone())
+            .map_err(|err| format!("{:?}", err))
+    }
+
     /// Execute the model, passing `input` as the tensor values for the node
     /// IDs specified by `input_ids` and calculating the values of the nodes
     /// specified by `output_ids`.
@@ -72,7 +168,14 @@ impl Model {
             input.iter().map(|tensor| (&*tensor.data).into()),
         )
         .collect();
-        let result = self.model.run(&inputs[..], output_ids, None);
+        let opts = RunOptions {
+            profiler: self.profiler.clone(),
+            ..Default::default()
+        };
+        let result = self.model.run(&inputs[..], output_ids, Some(opts));
+        if let Some(profiler) = self.profiler.as_ref() {
+            self.last_profile.replace(profiler.take_profile());
+        }
         match result {
             Ok(outputs) => {
                 let mut list = Vec::new();
@@ -89,12 +192,18 @@ impl Model {
 /// Metadata about a node in the model.
 #[wasm_bindgen]
 pub struct NodeInfo {
+    id: usize,
     name: Option<String>,
     shape: Option<Vec<Dimension>>,
 }
 
 #[wasm_bindgen]
 impl NodeInfo {
+    /// Returns the ID of the node, for use with `Model.run`.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
     /// Returns the name of a node in the graph, if it has one.
     pub fn name(&self) -> Option<String> {
         self.name.clone()
@@ -117,6 +226,101 @@ impl NodeInfo {
                 .collect()
         })
     }
+
+    /// Returns the names of the dimensions in the node's shape.
+    ///
+    /// Symbolic dimensions are identified by name. Fixed-size dimensions
+    /// have an empty name.
+    #[wasm_bindgen(js_name = dimNames)]
+    pub fn dim_names(&self) -> Option<Vec<String>> {
+        self.shape.as_ref().map(|dims| {
+            dims.iter()
+                .map(|dim| match dim {
+                    Dimension::Fixed(_) => String::new(),
+                    Dimension::Symbolic(name) => name.clone(),
+                })
+                .collect()
+        })
+    }
+}
+
+/// Metadata about a model, such as the license and where it came from.
+///
+/// See the Rust `ModelMetadata` type for details of each field.
+#[wasm_bindgen]
+pub struct Metadata {
+    onnx_hash: Option<String>,
+    description: Option<String>,
+    license: Option<String>,
+    commit: Option<String>,
+    code_repository: Option<String>,
+    model_repository: Option<String>,
+    run_id: Option<String>,
+    run_url: Option<String>,
+}
+
+impl Metadata {
+    fn from_model_metadata(metadata: &ModelMetadata) -> Metadata {
+        let to_owned = |s: Option<&str>| s.map(|s| s.to_string());
+        Metadata {
+            onnx_hash: to_owned(metadata.onnx_hash()),
+            description: to_owned(metadata.description()),
+            license: to_owned(metadata.license()),
+            commit: to_owned(metadata.commit()),
+            code_repository: to_owned(metadata.code_repository()),
+            model_repository: to_owned(metadata.model_repository()),
+            run_id: to_owned(metadata.run_id()),
+            run_url: to_owned(metadata.run_url()),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Metadata {
+    /// Returns the SHA-256 hash of the ONNX model this model was converted from.
+    #[wasm_bindgen(js_name = onnxHash)]
+    pub fn onnx_hash(&self) -> Option<String> {
+        self.onnx_hash.clone()
+    }
+
+    /// Returns a short description of what the model does.
+    pub fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    /// Returns the license identifier for the model.
+    pub fn license(&self) -> Option<String> {
+        self.license.clone()
+    }
+
+    /// Returns the commit of the code repository used to create the model.
+    pub fn commit(&self) -> Option<String> {
+        self.commit.clone()
+    }
+
+    /// Returns the URL of the repository containing the model's code.
+    #[wasm_bindgen(js_name = codeRepository)]
+    pub fn code_repository(&self) -> Option<String> {
+        self.code_repository.clone()
+    }
+
+    /// Returns the URL of the repository where the model is hosted.
+    #[wasm_bindgen(js_name = modelRepository)]
+    pub fn model_repository(&self) -> Option<String> {
+        self.model_repository.clone()
+    }
+
+    /// Returns the ID of the training run that produced the model.
+    #[wasm_bindgen(js_name = runId)]
+    pub fn run_id(&self) -> Option<String> {
+        self.run_id.clone()
+    }
+
+    /// Returns the URL of the training run that produced the model.
+    #[wasm_bindgen(js_name = runUrl)]
+    pub fn run_url(&self) -> Option<String> {
+        self.run_url.clone()
+    }
 }
 
 /// A wrapper around a multi-dimensional array model input or output.
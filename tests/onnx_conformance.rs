@@ -0,0 +1,481 @@
+//! Conformance tests which run test cases from the ONNX backend test suite.
+//!
+//! The test cases must first be converted using `tools/convert-onnx-tests.py`.
+//! The directory containing the converted cases is then specified using the
+//! `RTEN_ONNX_TESTS` environment variable:
+//!
+//! ```text
+//! python tools/convert-onnx-tests.py path/to/onnx/backend/test/data/node onnx-tests/
+//! RTEN_ONNX_TESTS=onnx-tests/ cargo test --release --test onnx_conformance -- --nocapture
+//! ```
+//!
+//! A report of the results for each operator is printed when the tests
+//! complete. Cases can be filtered by name using `RTEN_ONNX_TESTS_FILTER`. The
+//! test is skipped if `RTEN_ONNX_TESTS` is not set.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rten::{Input, Model, Output};
+use rten_tensor::prelude::*;
+use rten_tensor::Tensor;
+
+/// Relative tolerance used when comparing float outputs. This matches the
+/// default used by the ONNX backend test runner.
+const RTOL: f32 = 1e-3;
+
+/// Absolute tolerance used when comparing float outputs.
+const ATOL: f32 = 1e-7;
+
+/// Field values in a protobuf message.
+enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed32(&'a [u8]),
+}
+
+/// Read a base-128 varint from the start of `buf`, advancing it.
+fn read_varint(buf: &mut &[u8]) -> Result<u64, String> {
+    let mut val = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = buf.split_first().ok_or("unexpected end of varint")?;
+        *buf = rest;
+        val |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(val);
+        }
+        shift += 7;
+    }
+}
+
+/// Split the first `len` bytes from `buf`, advancing it.
+fn read_bytes<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if buf.len() < len {
+        return Err("unexpected end of field".into());
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(data)
+}
+
+/// Decode the `(field, value)` pairs in a protobuf message.
+fn decode_message(mut buf: &[u8]) -> Result<Vec<(u32, FieldValue<'_>)>, String> {
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let field = (key >> 3) as u32;
+        let value = match key & 0x7 {
+            0 => FieldValue::Varint(read_varint(&mut buf)?),
+            // 64-bit fields (eg. `double_data`) are not used.
+            1 => {
+                read_bytes(&mut buf, 8)?;
+                continue;
+            }
+            2 => {
+                let len = read_varint(&mut buf)? as usize;
+                FieldValue::Bytes(read_bytes(&mut buf, len)?)
+            }
+            5 => FieldValue::Fixed32(read_bytes(&mut buf, 4)?),
+            wire_type => return Err(format!("unsupported wire type {}", wire_type)),
+        };
+        fields.push((field, value));
+    }
+    Ok(fields)
+}
+
+/// Read the values of a repeated integer field, which may be packed or not.
+fn read_int_values(values: &mut Vec<i64>, value: &FieldValue) -> Result<(), String> {
+    match value {
+        FieldValue::Varint(val) => values.push(*val as i64),
+        FieldValue::Bytes(mut data) => {
+            while !data.is_empty() {
+                values.push(read_varint(&mut data)? as i64);
+            }
+        }
+        _ => return Err("unexpected wire type for integer field".into()),
+    }
+    Ok(())
+}
+
+/// Read the values of a repeated float field, which may be packed or not.
+fn read_float_values(values: &mut Vec<f32>, value: &FieldValue) -> Result<(), String> {
+    let data = match value {
+        FieldValue::Fixed32(data) | FieldValue::Bytes(data) => data,
+        _ => return Err("unexpected wire type for float field".into()),
+    };
+    values.extend(
+        data.chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap())),
+    );
+    Ok(())
+}
+
+// `TensorProto.DataType` values.
+const DTYPE_FLOAT: u64 = 1;
+const DTYPE_INT32: u64 = 6;
+const DTYPE_INT64: u64 = 7;
+const DTYPE_BOOL: u64 = 9;
+
+/// Decode a serialized ONNX `TensorProto` into a tensor.
+///
+/// Float, int32, int64 and bool tensors are supported. Bool tensors are
+/// converted to int32, which is how RTen represents booleans.
+fn decode_tensor(buf: &[u8]) -> Result<Output, String> {
+    let mut dims = Vec::new();
+    let mut dtype = 0;
+    let mut float_data = Vec::new();
+    let mut int_data = Vec::new();
+    let mut raw_data: Option<&[u8]> = None;
+
+    for (field, value) in decode_message(buf)? {
+        match (field, &value) {
+            (1, _) => read_int_values(&mut dims, &value)?,
+            (2, FieldValue::Varint(val)) => dtype = *val,
+            (4, _) => read_float_values(&mut float_data, &value)?,
+            // `int32_data` and `int64_data`
+            (5 | 7, _) => read_int_values(&mut int_data, &value)?,
+            (9, FieldValue::Bytes(data)) => raw_data = Some(data),
+            (12, _) => return Err("external tensor data is not supported".into()),
+            _ => {}
+        }
+    }
+
+    let shape: Vec<usize> = dims.iter().map(|&d| d as usize).collect();
+    let from_raw = |elt_size: usize| -> Vec<&[u8]> {
+        raw_data
+            .unwrap_or_default()
+            .chunks_exact(elt_size)
+            .collect()
+    };
+
+    let output = match dtype {
+        DTYPE_FLOAT => {
+            let data = if raw_data.is_some() {
+                from_raw(4)
+                    .into_iter()
+                    .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                    .collect()
+            } else {
+                float_data
+            };
+            make_tensor(&shape, data)?.into()
+        }
+        DTYPE_INT32 | DTYPE_BOOL => {
+            let data = if raw_data.is_some() && dtype == DTYPE_BOOL {
+                from_raw(1).into_iter().map(|b| b[0] as i32).collect()
+            } else if raw_data.is_some() {
+                from_raw(4)
+                    .into_iter()
+                    .map(|b| i32::from_le_bytes(b.try_into().unwrap()))
+                    .collect()
+            } else {
+                int_data.into_iter().map(|x| x as i32).collect()
+            };
+            make_tensor(&shape, data)?.into()
+        }
+        DTYPE_INT64 => {
+            let data = if raw_data.is_some() {
+                from_raw(8)
+                    .into_iter()
+                    .map(|b| i64::from_le_bytes(b.try_into().unwrap()))
+                    .collect()
+            } else {
+                int_data
+            };
+            make_tensor(&shape, data)?.into()
+        }
+        _ => return Err(format!("unsupported tensor data type {}", dtype)),
+    };
+    Ok(output)
+}
+
+/// Create a tensor, checking that the length of `data` matches `shape`.
+fn make_tensor<T>(shape: &[usize], data: Vec<T>) -> Result<Tensor<T>, String> {
+    if shape.iter().product::<usize>() != data.len() {
+        return Err("tensor data does not match shape".into());
+    }
+    Ok(Tensor::from_data(shape, data))
+}
+
+/// Compare an actual output against the expected value.
+fn compare_output(actual: &Output, expected: &Output) -> Result<(), String> {
+    if actual.shape() != expected.shape() {
+        return Err(format!(
+            "shape mismatch. Expected {:?} actual {:?}",
+            expected.shape(),
+            actual.shape()
+        ));
+    }
+
+    match (actual, expected) {
+        (Output::FloatTensor(actual), Output::FloatTensor(expected)) => {
+            let mismatch = actual.iter().zip(expected.iter()).position(|(&x, &y)| {
+                let both_nan = x.is_nan() && y.is_nan();
+                !both_nan && x != y && (x - y).abs() > ATOL + RTOL * y.abs()
+            });
+            if let Some(index) = mismatch {
+                let x = actual.iter().nth(index).unwrap();
+                let y = expected.iter().nth(index).unwrap();
+                return Err(format!(
+                    "value mismatch at index {}. Expected {} actual {}",
+                    index, y, x
+                ));
+            }
+        }
+        (Output::IntTensor(actual), Output::IntTensor(expected)) => {
+            if actual != expected {
+                return Err("value mismatch".into());
+            }
+        }
+        (Output::Int64Tensor(actual), Output::Int64Tensor(expected)) => {
+            if actual != expected {
+                return Err("value mismatch".into());
+            }
+        }
+        // RTen may produce int32 values where ONNX uses int64.
+        (Output::IntTensor(actual), Output::Int64Tensor(expected)) => {
+            if !actual
+                .iter()
+                .zip(expected.iter())
+                .all(|(&x, &y)| x as i64 == y)
+            {
+                return Err("value mismatch".into());
+            }
+        }
+        _ => return Err("output type mismatch".into()),
+    }
+    Ok(())
+}
+
+/// Result of running a single test case.
+enum Outcome {
+    Pass,
+
+    /// The model ran, but produced incorrect outputs.
+    Fail(String),
+
+    /// The model could not be loaded or run.
+    Error(String),
+
+    /// The ONNX model could not be converted to `.rten` format.
+    NotConverted(String),
+}
+
+/// Return the paths of files in `dir` named `{prefix}_{index}.pb`, in index
+/// order.
+fn numbered_files(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    loop {
+        let path = dir.join(format!("{}_{}.pb", prefix, files.len()));
+        if !path.exists() {
+            return files;
+        }
+        files.push(path);
+    }
+}
+
+/// Run all the data sets for a converted test case.
+fn run_test_case(case_dir: &Path, conversion_error: Option<&str>) -> Outcome {
+    if let Some(err) = conversion_error {
+        return Outcome::NotConverted(err.to_string());
+    }
+
+    let model = match Model::load_file(case_dir.join("model.rten")) {
+        Ok(model) => model,
+        Err(err) => return Outcome::Error(format!("failed to load model: {}", err)),
+    };
+
+    let mut data_sets: Vec<PathBuf> = fs::read_dir(case_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("test_data_set_"))
+        })
+        .collect();
+    data_sets.sort();
+
+    for data_set in data_sets {
+        let read_tensors = |prefix: &str| -> Result<Vec<Output>, String> {
+            numbered_files(&data_set, prefix)
+                .iter()
+                .map(|path| {
+                    let buf = fs::read(path).map_err(|err| err.to_string())?;
+                    decode_tensor(&buf)
+                })
+                .collect()
+        };
+        let (inputs, expected) = match (read_tensors("input"), read_tensors("output")) {
+            (Ok(inputs), Ok(outputs)) => (inputs, outputs),
+            (Err(err), _) | (_, Err(err)) => {
+                return Outcome::Error(format!("failed to read test data: {}", err))
+            }
+        };
+
+        let inputs: Vec<(usize, Input)> = model
+            .input_ids()
+            .iter()
+            .copied()
+            .zip(inputs.iter().map(Input::from))
+            .collect();
+        let outputs = match model.run(&inputs, model.output_ids(), None) {
+            Ok(outputs) => outputs,
+            Err(err) => return Outcome::Error(err.to_string()),
+        };
+        if outputs.len() != expected.len() {
+            return Outcome::Fail(format!(
+                "expected {} outputs but model produced {}",
+                expected.len(),
+                outputs.len()
+            ));
+        }
+        for (i, (actual, expected)) in outputs.iter().zip(expected.iter()).enumerate() {
+            if let Err(err) = compare_output(actual, expected) {
+                return Outcome::Fail(format!("output {}: {}", i, err));
+            }
+        }
+    }
+
+    Outcome::Pass
+}
+
+/// Counts of test case outcomes for an operator.
+#[derive(Default)]
+struct OpSummary {
+    pass: usize,
+    fail: usize,
+    error: usize,
+    not_converted: usize,
+}
+
+#[test]
+fn test_onnx_conformance() -> Result<(), Box<dyn Error>> {
+    let Some(test_dir) = std::env::var_os("RTEN_ONNX_TESTS") else {
+        println!("Skipping ONNX conformance tests as RTEN_ONNX_TESTS is not set");
+        return Ok(());
+    };
+    let filter = std::env::var("RTEN_ONNX_TESTS_FILTER").ok();
+
+    let mut case_dirs: Vec<PathBuf> = fs::read_dir(&test_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    case_dirs.sort();
+
+    let mut summaries: BTreeMap<String, OpSummary> = BTreeMap::new();
+    let mut problems = Vec::new();
+
+    for case_dir in case_dirs {
+        let name = case_dir.file_name().unwrap().to_string_lossy().to_string();
+        if filter.as_ref().is_some_and(|filter| !name.contains(filter)) {
+            continue;
+        }
+        let Ok(case_json) = fs::read_to_string(case_dir.join("case.json")) else {
+            continue;
+        };
+        let case_info: serde_json::Value = serde_json::from_str(&case_json)?;
+        let conversion_error = case_info["conversion_error"].as_str();
+        let op_types: Vec<&str> = case_info["op_types"]
+            .as_array()
+            .map(|types| types.iter().filter_map(|t| t.as_str()).collect())
+            .unwrap_or_default();
+
+        let outcome = run_test_case(&case_dir, conversion_error);
+        for op_type in op_types {
+            let summary = summaries.entry(op_type.to_string()).or_default();
+            match &outcome {
+                Outcome::Pass => summary.pass += 1,
+                Outcome::Fail(_) => summary.fail += 1,
+                Outcome::Error(_) => summary.error += 1,
+                Outcome::NotConverted(_) => summary.not_converted += 1,
+            }
+        }
+        match outcome {
+            Outcome::Pass => {}
+            Outcome::Fail(msg) => problems.push(format!("{}: failed: {}", name, msg)),
+            Outcome::Error(msg) => problems.push(format!("{}: error: {}", name, msg)),
+            Outcome::NotConverted(msg) => {
+                problems.push(format!("{}: not converted: {}", name, msg))
+            }
+        }
+    }
+
+    println!(
+        "{:<30} {:>6} {:>6} {:>6} {:>14}",
+        "Operator", "Pass", "Fail", "Error", "Not converted"
+    );
+    for (op_type, summary) in &summaries {
+        println!(
+            "{:<30} {:>6} {:>6} {:>6} {:>14}",
+            op_type, summary.pass, summary.fail, summary.error, summary.not_converted
+        );
+    }
+
+    let fully_passing = summaries
+        .values()
+        .filter(|s| s.fail == 0 && s.error == 0 && s.not_converted == 0)
+        .count();
+    println!();
+    println!(
+        "{} of {} operators pass all test cases",
+        fully_passing,
+        summaries.len()
+    );
+
+    if !problems.is_empty() {
+        println!();
+        for problem in problems {
+            println!("{}", problem);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_tensor() {
+    // TensorProto with dims [2, 2], data type float and packed `float_data`.
+    let mut buf = vec![0x0a, 0x02, 0x02, 0x02, 0x10, 0x01, 0x22, 0x10];
+    for x in [1.0f32, 2.0, 3.0, 4.0] {
+        buf.extend(x.to_le_bytes());
+    }
+    let tensor = decode_tensor(&buf).unwrap();
+    assert_eq!(tensor.shape(), &[2, 2]);
+    assert_eq!(
+        tensor.as_float_ref().unwrap().to_vec(),
+        &[1.0, 2.0, 3.0, 4.0]
+    );
+
+    // TensorProto with dims [3], data type int64 and `raw_data`.
+    let mut buf = vec![0x08, 0x03, 0x10, 0x07, 0x4a, 0x18];
+    for x in [1i64, -2, 3] {
+        buf.extend(x.to_le_bytes());
+    }
+    let tensor = decode_tensor(&buf).unwrap();
+    assert_eq!(tensor.as_int64_ref().unwrap().to_vec(), &[1, -2, 3]);
+
+    // Mismatch between shape and data.
+    let buf = [0x08, 0x03, 0x10, 0x01, 0x22, 0x04, 0, 0, 0x80, 0x3f];
+    assert!(decode_tensor(&buf).is_err());
+
+    // Unsupported data type.
+    let buf = [0x08, 0x01, 0x10, 0x0b];
+    assert!(decode_tensor(&buf).is_err());
+}
+
+#[test]
+fn test_compare_output() {
+    let x: Output = Tensor::from_data(&[2], vec![1.0, 2.0]).into();
+    let y: Output = Tensor::from_data(&[2], vec![1.0, 2.0001]).into();
+    let z: Output = Tensor::from_data(&[2], vec![1.0, 2.1]).into();
+    let w: Output = Tensor::from_data(&[1, 2], vec![1.0, 2.0]).into();
+
+    assert!(compare_output(&x, &y).is_ok());
+    assert!(compare_output(&x, &z).is_err());
+    assert!(compare_output(&x, &w).is_err());
+}
@@ -0,0 +1,77 @@
+from argparse import ArgumentParser
+import json
+import os
+import shutil
+
+import onnx
+
+from rten_convert.converter import generate_metadata, graph_from_onnx_graph, write_model
+
+
+def convert_test_case(case_dir: str, out_dir: str):
+    """
+    Convert an ONNX backend test case to the layout used by RTen's conformance
+    tests.
+
+    The output directory contains the converted `model.rten`, a copy of the
+    test data sets and a `case.json` file listing the operator types used by
+    the model. If conversion fails, `case.json` records the error instead of
+    the model being written.
+    """
+    os.makedirs(out_dir, exist_ok=True)
+
+    model_path = os.path.join(case_dir, "model.onnx")
+    model = onnx.load(model_path)
+    op_types = sorted({node.op_type for node in model.graph.node})
+    case_info: dict = {"op_types": op_types}
+
+    try:
+        graph = graph_from_onnx_graph(model.graph)
+        metadata = generate_metadata(model_path, onnx_model=model)
+        write_model(graph, metadata, os.path.join(out_dir, "model.rten"))
+    except Exception as ex:
+        case_info["conversion_error"] = str(ex) or type(ex).__name__
+
+    for name in os.listdir(case_dir):
+        if name.startswith("test_data_set_"):
+            shutil.copytree(
+                os.path.join(case_dir, name),
+                os.path.join(out_dir, name),
+                dirs_exist_ok=True,
+            )
+
+    with open(os.path.join(out_dir, "case.json"), "w") as case_file:
+        json.dump(case_info, case_file)
+
+
+def main():
+    parser = ArgumentParser(
+        description="""
+Convert ONNX backend test cases for use with RTen's conformance tests.
+
+The input directory is expected to contain one subdirectory per test case, as
+in the `onnx/backend/test/data/node` directory of the ONNX repository. Each
+case contains a `model.onnx` file and `test_data_set_N` directories with
+`input_N.pb` and `output_N.pb` tensors.
+"""
+    )
+    parser.add_argument("test_dir", help="Directory containing ONNX test cases")
+    parser.add_argument("out_dir", help="Directory to write converted test cases to")
+    parser.add_argument(
+        "--filter", help="Only convert test cases whose name contains this string"
+    )
+    args = parser.parse_args()
+
+    case_names = sorted(os.listdir(args.test_dir))
+    for name in case_names:
+        case_dir = os.path.join(args.test_dir, name)
+        if not os.path.isfile(os.path.join(case_dir, "model.onnx")):
+            continue
+        if args.filter and args.filter not in name:
+            continue
+        print(f"Converting {name}")
+        convert_test_case(case_dir, os.path.join(args.out_dir, name))
+
+
+if __name__ == "__main__":
+    main()